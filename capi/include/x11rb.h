@@ -0,0 +1,68 @@
+/*
+ * C API for x11rb's pure-Rust X11 connection.
+ *
+ * All functions deal in raw X11 packets. Buffers that are returned by this
+ * library must be freed with x11rb_free(). A connection may be used from
+ * multiple threads at the same time.
+ */
+
+#ifndef X11RB_H
+#define X11RB_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Return values */
+#define X11RB_SUCCESS 0
+#define X11RB_X11_ERROR 1
+#define X11RB_EMPTY 2
+#define X11RB_IDS_EXHAUSTED 3
+#define X11RB_CONNECTION_ERROR (-1)
+#define X11RB_INVALID_ARGUMENT (-2)
+#define X11RB_INTERNAL_ERROR (-3)
+
+typedef struct X11rbConnection x11rb_connection_t;
+
+/* Connect to the display (NULL: $DISPLAY). Returns NULL on failure. */
+x11rb_connection_t *x11rb_connect(const char *display, int *screen);
+void x11rb_disconnect(x11rb_connection_t *conn);
+
+/* The setup in wire format. The buffer belongs to the connection. */
+const uint8_t *x11rb_get_setup(const x11rb_connection_t *conn, size_t *length);
+int x11rb_generate_id(const x11rb_connection_t *conn, uint32_t *id);
+/* Reserve the IDs start + n * step for n < count, e.g. for a helper library. */
+int x11rb_reserve_ids(const x11rb_connection_t *conn, uint32_t count,
+                      uint32_t *start, uint32_t *step);
+int x11rb_flush(const x11rb_connection_t *conn);
+
+/* Send a complete request, including its header. The length must be a
+ * multiple of four and match the length field, unless the request is longer
+ * than the length field allows and uses BIG-REQUESTS. */
+int x11rb_send_request(const x11rb_connection_t *conn, const uint8_t *request,
+                       size_t length, int has_reply, uint64_t *sequence);
+
+/* Handling the response of a request. Each request must be handled once. */
+int x11rb_wait_for_reply(const x11rb_connection_t *conn, uint64_t sequence,
+                         uint8_t **buffer, size_t *length);
+int x11rb_check_request(const x11rb_connection_t *conn, uint64_t sequence,
+                        uint8_t **buffer, size_t *length);
+void x11rb_discard_reply(const x11rb_connection_t *conn, uint64_t sequence,
+                         int has_reply, int errors_as_events);
+
+/* Events and errors (first byte zero) that are not handled otherwise. */
+int x11rb_wait_for_event(const x11rb_connection_t *conn, uint8_t **buffer,
+                         size_t *length);
+int x11rb_poll_for_event(const x11rb_connection_t *conn, uint8_t **buffer,
+                         size_t *length);
+
+void x11rb_free(uint8_t *buffer, size_t length);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* X11RB_H */
@@ -0,0 +1,549 @@
+//! A C ABI for x11rb's pure-Rust X11 connection.
+//!
+//! This crate builds a `cdylib` and a `staticlib` that allow programs written in other languages
+//! to use [`RustConnection`] as the transport for the X11 protocol. This is useful where libxcb
+//! cannot be used. The ABI is not compatible with `xcb_connection_t`. It is a small custom API
+//! that is described in `include/x11rb.h`.
+//!
+//! The API deals in raw bytes: Requests are sent as complete byte buffers and replies, errors,
+//! and events are returned as byte buffers that must be freed with [`x11rb_free`]. The length
+//! field of requests is handled like in x11rb, i.e. large requests automatically use the
+//! BIG-REQUESTS extension. Passing file descriptors is not supported.
+//!
+//! Invalid arguments that can be detected, e.g. a request whose length field is wrong, are
+//! reported with [`X11RB_INVALID_ARGUMENT`]. Panics do not unwind into C code, but are reported
+//! as [`X11RB_INTERNAL_ERROR`].
+
+#![forbid(
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    rust_2018_idioms,
+    unused_results
+)]
+
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::io::IoSlice;
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use x11rb::connection::{Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind};
+use x11rb::dyn_connection::DynConnection;
+use x11rb::errors::ReplyOrIdError;
+use x11rb::id_range::IdRange;
+use x11rb::rust_connection::RustConnection;
+use x11rb::x11_utils::Serialize;
+
+/// The operation succeeded and, if applicable, a reply or an event was returned.
+pub const X11RB_SUCCESS: c_int = 0;
+/// The X11 server sent an error, which was returned instead of a reply.
+pub const X11RB_X11_ERROR: c_int = 1;
+/// There was nothing to return, e.g. no event is available.
+pub const X11RB_EMPTY: c_int = 2;
+/// All X11 IDs of the connection are in use.
+pub const X11RB_IDS_EXHAUSTED: c_int = 3;
+/// The connection failed. Afterwards, the connection can only be disconnected.
+pub const X11RB_CONNECTION_ERROR: c_int = -1;
+/// An argument was invalid, e.g. a NULL pointer or a request that is too short.
+pub const X11RB_INVALID_ARGUMENT: c_int = -2;
+/// x11rb panicked. This is a bug in x11rb. The connection might not be usable afterwards.
+pub const X11RB_INTERNAL_ERROR: c_int = -3;
+
+/// A connection to an X11 server.
+///
+/// This is an opaque type for C code.
+#[derive(Debug)]
+pub struct X11rbConnection {
+    conn: RustConnection,
+    setup: Vec<u8>,
+}
+
+/// Run `f` and return `on_panic` if it panics.
+///
+/// Unwinding out of an `extern "C"` function aborts the process, so every entry point of this
+/// library runs its code through this function.
+fn catch_panic<R>(on_panic: R, f: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(on_panic)
+}
+
+/// Check that `request` is a complete request with a correct length field.
+///
+/// Requests that are too long for the length field use the BIG-REQUESTS extension. The length
+/// field of these requests is ignored.
+fn is_valid_request(request: &[u8]) -> bool {
+    if request.len() < 4 || request.len() % 4 != 0 {
+        return false;
+    }
+    match u16::try_from(request.len() / 4) {
+        Ok(wire_length) => u16::from_ne_bytes([request[2], request[3]]) == wire_length,
+        Err(_) => true,
+    }
+}
+
+/// Hand a buffer over to C code.
+///
+/// The buffer has to be freed with [`x11rb_free`].
+unsafe fn return_buffer(buffer: Vec<u8>, out: *mut *mut u8, out_length: *mut usize) {
+    let buffer = buffer.into_boxed_slice();
+    *out_length = buffer.len();
+    *out = Box::into_raw(buffer) as *mut u8;
+}
+
+/// Connect to the X11 server.
+///
+/// `display` describes the display to connect to, e.g. `:0`. If it is NULL, the `DISPLAY`
+/// environment variable is used. If `screen` is not NULL, the number of the preferred screen is
+/// stored there.
+///
+/// Returns NULL if the connection could not be established.
+///
+/// # Safety
+///
+/// `display` must be NULL or a valid NUL-terminated string. `screen` must be NULL or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_connect(
+    display: *const c_char,
+    screen: *mut c_int,
+) -> *mut X11rbConnection {
+    catch_panic(ptr::null_mut(), || {
+        let display = if display.is_null() {
+            None
+        } else {
+            match CStr::from_ptr(display).to_str() {
+                Ok(display) => Some(display),
+                Err(_) => return ptr::null_mut(),
+            }
+        };
+        let (conn, screen_num) = match RustConnection::connect(display) {
+            Ok(result) => result,
+            Err(_) => return ptr::null_mut(),
+        };
+        if !screen.is_null() {
+            *screen = screen_num as c_int;
+        }
+        let setup = conn.setup().serialize();
+        Box::into_raw(Box::new(X11rbConnection { conn, setup }))
+    })
+}
+
+/// Close the connection and free all resources associated with it.
+///
+/// # Safety
+///
+/// `conn` must be NULL or a connection returned by [`x11rb_connect`] that was not yet
+/// disconnected.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_disconnect(conn: *mut X11rbConnection) {
+    catch_panic((), || {
+        if !conn.is_null() {
+            drop(Box::from_raw(conn));
+        }
+    })
+}
+
+/// Get the setup information that the X11 server sent, in its wire format.
+///
+/// The returned buffer belongs to the connection and stays valid until it is disconnected.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `length` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_get_setup(
+    conn: *const X11rbConnection,
+    length: *mut usize,
+) -> *const u8 {
+    catch_panic(ptr::null(), || {
+        let conn = &*conn;
+        *length = conn.setup.len();
+        conn.setup.as_ptr()
+    })
+}
+
+/// Generate a new X11 identifier.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `id` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_generate_id(conn: *const X11rbConnection, id: *mut u32) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || match (*conn).conn.generate_id() {
+        Ok(value) => {
+            *id = value;
+            X11RB_SUCCESS
+        }
+        Err(ReplyOrIdError::IdsExhausted) => X11RB_IDS_EXHAUSTED,
+        Err(_) => X11RB_CONNECTION_ERROR,
+    })
+}
+
+/// Reserve a block of `count` X11 identifiers for a helper that allocates IDs itself.
+///
+/// The identifiers are `*start + n * *step` for `n < count`. They are never returned by
+/// [`x11rb_generate_id`], so the helper can use them without colliding with other users of the
+/// connection. See [`IdRange::reserve`] for details.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `start` and `step` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_reserve_ids(
+    conn: *const X11rbConnection,
+    count: u32,
+    start: *mut u32,
+    step: *mut u32,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        match IdRange::reserve(&(*conn).conn, count) {
+            Ok(range) => {
+                *start = range.start();
+                *step = range.step();
+                X11RB_SUCCESS
+            }
+            Err(ReplyOrIdError::IdsExhausted) => X11RB_IDS_EXHAUSTED,
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Send all pending requests to the X11 server.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_flush(conn: *const X11rbConnection) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || match (*conn).conn.flush() {
+        Ok(()) => X11RB_SUCCESS,
+        Err(_) => X11RB_CONNECTION_ERROR,
+    })
+}
+
+/// Send a request.
+///
+/// `request` contains the complete request, including its header. Its length must be a multiple
+/// of four and match the request's length field, unless the request is too long for the length
+/// field and uses the BIG-REQUESTS extension. Otherwise, [`X11RB_INVALID_ARGUMENT`] is returned.
+/// If `has_reply` is non-zero, the request must be one that generates a reply. The sequence
+/// number of the request is stored in `sequence`. Its response must be handled with
+/// [`x11rb_wait_for_reply`], [`x11rb_check_request`], or [`x11rb_discard_reply`].
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `request` must be valid for reads of `length` bytes.
+/// `sequence` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_send_request(
+    conn: *const X11rbConnection,
+    request: *const u8,
+    length: usize,
+    has_reply: c_int,
+    sequence: *mut u64,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        if request.is_null() {
+            return X11RB_INVALID_ARGUMENT;
+        }
+        let request = slice::from_raw_parts(request, length);
+        if !is_valid_request(request) {
+            return X11RB_INVALID_ARGUMENT;
+        }
+        let kind = if has_reply != 0 {
+            RequestKind::HasResponse
+        } else {
+            RequestKind::IsVoid
+        };
+        let conn = &(*conn).conn;
+        match conn.dyn_send_request(&[IoSlice::new(request)], Vec::new(), kind, false) {
+            Ok(seq) => {
+                *sequence = seq;
+                X11RB_SUCCESS
+            }
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Wait for the reply to a request.
+///
+/// Returns [`X11RB_SUCCESS`] and the reply or [`X11RB_X11_ERROR`] and the error in `buffer`. The
+/// buffer must be freed with [`x11rb_free`].
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `buffer` and `length` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_wait_for_reply(
+    conn: *const X11rbConnection,
+    sequence: u64,
+    buffer: *mut *mut u8,
+    length: *mut usize,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        match (*conn).conn.wait_for_reply_or_raw_error(sequence) {
+            Ok(ReplyOrError::Reply(reply)) => {
+                return_buffer(reply, buffer, length);
+                X11RB_SUCCESS
+            }
+            Ok(ReplyOrError::Error(error)) => {
+                return_buffer(error, buffer, length);
+                X11RB_X11_ERROR
+            }
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Check whether a request without a reply caused an error.
+///
+/// Returns [`X11RB_SUCCESS`] if the request succeeded or [`X11RB_X11_ERROR`] and the error in
+/// `buffer`. The buffer must be freed with [`x11rb_free`].
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `buffer` and `length` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_check_request(
+    conn: *const X11rbConnection,
+    sequence: u64,
+    buffer: *mut *mut u8,
+    length: *mut usize,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        match (*conn).conn.check_for_raw_error(sequence) {
+            Ok(None) => X11RB_SUCCESS,
+            Ok(Some(error)) => {
+                return_buffer(error, buffer, length);
+                X11RB_X11_ERROR
+            }
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Ignore the reply to a request.
+///
+/// If `errors_as_events` is non-zero, an error caused by the request is returned as an event.
+/// Otherwise, it is ignored as well.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_discard_reply(
+    conn: *const X11rbConnection,
+    sequence: u64,
+    has_reply: c_int,
+    errors_as_events: c_int,
+) {
+    catch_panic((), || {
+        let kind = if has_reply != 0 {
+            RequestKind::HasResponse
+        } else {
+            RequestKind::IsVoid
+        };
+        let mode = if errors_as_events != 0 {
+            DiscardMode::DiscardReply
+        } else {
+            DiscardMode::DiscardReplyAndError
+        };
+        (*conn).conn.discard_reply(sequence, kind, mode);
+    })
+}
+
+/// Wait for the next event or error.
+///
+/// The event is stored in `buffer` and must be freed with [`x11rb_free`]. Errors can be told
+/// apart from events by their first byte being zero.
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `buffer` and `length` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_wait_for_event(
+    conn: *const X11rbConnection,
+    buffer: *mut *mut u8,
+    length: *mut usize,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        match (*conn).conn.wait_for_raw_event() {
+            Ok(event) => {
+                return_buffer(event, buffer, length);
+                X11RB_SUCCESS
+            }
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Get the next event or error, if one is available.
+///
+/// Returns [`X11RB_EMPTY`] if no event is available. Otherwise, this is like
+/// [`x11rb_wait_for_event`].
+///
+/// # Safety
+///
+/// `conn` must be a valid connection. `buffer` and `length` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_poll_for_event(
+    conn: *const X11rbConnection,
+    buffer: *mut *mut u8,
+    length: *mut usize,
+) -> c_int {
+    catch_panic(X11RB_INTERNAL_ERROR, || {
+        match (*conn).conn.poll_for_raw_event() {
+            Ok(Some(event)) => {
+                return_buffer(event, buffer, length);
+                X11RB_SUCCESS
+            }
+            Ok(None) => X11RB_EMPTY,
+            Err(_) => X11RB_CONNECTION_ERROR,
+        }
+    })
+}
+
+/// Free a buffer that was returned by this library.
+///
+/// # Safety
+///
+/// `buffer` must be NULL or a buffer that was returned together with `length` by this library
+/// and that was not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn x11rb_free(buffer: *mut u8, length: usize) {
+    catch_panic((), || {
+        if !buffer.is_null() {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer, length)));
+        }
+    })
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::ptr;
+
+    use x11rb::protocol::xproto::{ImageOrder, Setup};
+    use x11rb::rust_connection::{DefaultStream, RustConnection};
+    use x11rb::x11_utils::Serialize;
+
+    use super::*;
+
+    fn connect() -> (X11rbConnection, UnixStream) {
+        let setup = Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0x0040_0000,
+            resource_id_mask: 0x001f_ffff,
+            motion_buffer_size: 0,
+            maximum_request_length: u16::MAX,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 8,
+            max_keycode: 255,
+            vendor: Vec::new(),
+            pixmap_formats: Vec::new(),
+            roots: Vec::new(),
+        };
+        let (client, server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let setup_bytes = setup.serialize();
+        let conn = RustConnection::for_connected_stream(stream, setup).unwrap();
+        let conn = X11rbConnection {
+            conn,
+            setup: setup_bytes,
+        };
+        (conn, server)
+    }
+
+    fn send(conn: &X11rbConnection, request: &[u8], has_reply: c_int) -> (c_int, u64) {
+        let mut sequence = 0;
+        let result = unsafe {
+            x11rb_send_request(
+                conn,
+                request.as_ptr(),
+                request.len(),
+                has_reply,
+                &mut sequence,
+            )
+        };
+        (result, sequence)
+    }
+
+    #[test]
+    fn send_request_rejects_invalid_requests() {
+        let (conn, _server) = connect();
+        let mut sequence = 0;
+        let result = unsafe { x11rb_send_request(&conn, ptr::null(), 4, 0, &mut sequence) };
+        assert_eq!(result, X11RB_INVALID_ARGUMENT);
+
+        // GetInputFocus, which is a single word long
+        let mut request = [43, 0, 0, 0];
+        request[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        assert_eq!(send(&conn, &request[..3], 1).0, X11RB_INVALID_ARGUMENT);
+
+        // The length field does not match the length
+        let mut long_request = [0; 8];
+        long_request[..4].copy_from_slice(&request);
+        assert_eq!(send(&conn, &long_request, 1).0, X11RB_INVALID_ARGUMENT);
+        assert_eq!(send(&conn, &long_request[..6], 1).0, X11RB_INVALID_ARGUMENT);
+
+        // Nothing was sent for the invalid requests
+        assert_eq!(send(&conn, &request, 1), (X11RB_SUCCESS, 1));
+    }
+
+    #[test]
+    fn send_request_and_wait_for_reply() {
+        let (conn, mut server) = connect();
+        let mut request = [43, 0, 0, 0];
+        request[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        let (result, sequence) = send(&conn, &request, 1);
+        assert_eq!((result, sequence), (X11RB_SUCCESS, 1));
+        assert_eq!(unsafe { x11rb_flush(&conn) }, X11RB_SUCCESS);
+
+        let mut sent = [0; 4];
+        server.read_exact(&mut sent).unwrap();
+        assert_eq!(sent, request);
+
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        reply[8] = 42;
+        server.write_all(&reply).unwrap();
+
+        let (mut buffer, mut length) = (ptr::null_mut(), 0);
+        let result = unsafe { x11rb_wait_for_reply(&conn, sequence, &mut buffer, &mut length) };
+        assert_eq!(result, X11RB_SUCCESS);
+        assert_eq!(unsafe { slice::from_raw_parts(buffer, length) }, &reply[..]);
+        unsafe { x11rb_free(buffer, length) };
+    }
+
+    #[test]
+    fn generate_id() {
+        let (conn, _server) = connect();
+        let mut id = 0;
+        assert_eq!(unsafe { x11rb_generate_id(&conn, &mut id) }, X11RB_SUCCESS);
+        assert_eq!(id, 0x0040_0000);
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        assert_eq!(
+            catch_panic(X11RB_INTERNAL_ERROR, || panic!("oops")),
+            X11RB_INTERNAL_ERROR
+        );
+        assert_eq!(
+            catch_panic(X11RB_INTERNAL_ERROR, || X11RB_SUCCESS),
+            X11RB_SUCCESS
+        );
+    }
+}
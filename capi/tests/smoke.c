@@ -0,0 +1,51 @@
+/*
+ * Smoke test for the C API. It needs a running X11 server, e.g.:
+ *
+ *   cargo build -p x11rb-capi
+ *   cc -Wall -Werror -Icapi/include capi/tests/smoke.c target/debug/libx11rb_capi.a \
+ *       -lpthread -ldl -lm -o target/capi-smoke
+ *   xvfb-run -a target/capi-smoke
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "x11rb.h"
+
+#define CHECK(cond)                                                     \
+    do {                                                                \
+        if (!(cond)) {                                                  \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,      \
+                    __LINE__, #cond);                                   \
+            exit(1);                                                    \
+        }                                                               \
+    } while (0)
+
+int main(void)
+{
+    int screen;
+    size_t length;
+    uint8_t *buffer;
+    uint64_t sequence;
+    uint16_t length_field = 1;
+    uint8_t request[8] = { 43 /* GetInputFocus */ };
+    x11rb_connection_t *conn = x11rb_connect(NULL, &screen);
+
+    CHECK(conn != NULL);
+    CHECK(x11rb_get_setup(conn, &length) != NULL);
+    CHECK(length >= 8);
+
+    memcpy(&request[2], &length_field, sizeof(length_field));
+    CHECK(x11rb_send_request(conn, request, 3, 1, &sequence) == X11RB_INVALID_ARGUMENT);
+    CHECK(x11rb_send_request(conn, request, 8, 1, &sequence) == X11RB_INVALID_ARGUMENT);
+    CHECK(x11rb_send_request(conn, request, 4, 1, &sequence) == X11RB_SUCCESS);
+
+    CHECK(x11rb_wait_for_reply(conn, sequence, &buffer, &length) == X11RB_SUCCESS);
+    CHECK(length == 32);
+    CHECK(buffer[0] == 1);
+    x11rb_free(buffer, length);
+
+    x11rb_disconnect(conn);
+    return 0;
+}
@@ -0,0 +1,27 @@
+// This program runs the checks from x11rb::conformance against the connection returned by
+// x11rb::connect(). It serves as an example for testing custom `Connection` implementations and
+// as an integration test for the connections provided by x11rb.
+
+extern crate x11rb;
+
+use x11rb::conformance::{run_all_checks, CheckOutcome};
+
+fn main() {
+    let (conn, _) = x11rb::connect(None).unwrap();
+
+    let mut success = true;
+    for (name, result) in run_all_checks(&conn) {
+        match result {
+            Ok(CheckOutcome::Passed) => println!("{}: passed", name),
+            Ok(CheckOutcome::Skipped(reason)) => println!("{}: skipped ({})", name, reason),
+            Err(err) => {
+                println!("{}: FAILED: {}", name, err);
+                success = false;
+            }
+        }
+    }
+
+    if !success {
+        std::process::exit(1);
+    }
+}
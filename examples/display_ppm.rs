@@ -3,7 +3,7 @@
 
 use x11rb::connection::Connection;
 use x11rb::errors::ReplyOrIdError;
-use x11rb::image::{ColorComponent, Image, PixelLayout};
+use x11rb::image::{Image, PixelFormat, PixelLayout};
 use x11rb::protocol::xproto::{
     AtomEnum, ConnectionExt, CreateGCAux, CreateWindowAux, PropMode, Screen, VisualClass, Visualid,
     Window, WindowClass,
@@ -128,11 +128,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let pixel_layout = check_visual(screen, screen.root_visual);
 
     // Convert the image from the PPM format into the server's native format.
-    let ppm_layout = PixelLayout::new(
-        ColorComponent::new(8, 16)?,
-        ColorComponent::new(8, 8)?,
-        ColorComponent::new(8, 0)?,
-    );
+    let ppm_layout = PixelFormat::XRGB8888.layout();
     let image = image.reencode(ppm_layout, pixel_layout, conn.setup())?;
 
     let atoms = Atoms::new(conn)?.reply()?;
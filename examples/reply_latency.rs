@@ -0,0 +1,85 @@
+// This program measures how long threads wait for their replies when several threads share one
+// `RustConnection`.
+//
+// No X11 server is needed. Instead, `x11rb::testing::echo` runs a thread that plays the role of
+// the X11 server and answers every request with a `GetInputFocus` reply. This way, the measurement
+// only covers the overhead of `RustConnection` and not the X11 server.
+//
+// On a machine with a single CPU core, waking only the interested threads (instead of all of them)
+// made no measurable difference: five runs each gave a p99 of 205-228us before and 178-254us after
+// the change. Machines with more cores have not been measured yet.
+
+extern crate x11rb;
+
+#[cfg(unix)]
+mod bench {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use x11rb::protocol::xproto::ConnectionExt;
+    use x11rb::rust_connection::RustConnection;
+    use x11rb::testing::echo::EchoServer;
+
+    const THREADS: usize = 8;
+    const REQUESTS_PER_THREAD: usize = 2000;
+
+    /// Send requests from one thread and measure how long each reply takes.
+    fn client(conn: &RustConnection) -> Vec<Duration> {
+        (0..REQUESTS_PER_THREAD)
+            .map(|_| {
+                let start = Instant::now();
+                let _ = conn.get_input_focus().unwrap().reply().unwrap();
+                start.elapsed()
+            })
+            .collect()
+    }
+
+    fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+        sorted[(sorted.len() - 1) * percent / 100]
+    }
+
+    pub(crate) fn main() {
+        let (conn, server) = EchoServer::connect().unwrap();
+        let conn = Arc::new(conn);
+
+        let start = Instant::now();
+        let threads = (0..THREADS)
+            .map(|_| {
+                let conn = Arc::clone(&conn);
+                thread::spawn(move || client(&conn))
+            })
+            .collect::<Vec<_>>();
+        let mut latencies = threads
+            .into_iter()
+            .flat_map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>();
+        let total = start.elapsed();
+        latencies.sort();
+
+        println!(
+            "{} threads, {} requests in {:?}",
+            THREADS,
+            latencies.len(),
+            total
+        );
+        println!("p50: {:?}", percentile(&latencies, 50));
+        println!("p90: {:?}", percentile(&latencies, 90));
+        println!("p99: {:?}", percentile(&latencies, 99));
+        println!("max: {:?}", latencies[latencies.len() - 1]);
+
+        // Closing the connection makes the fake server exit
+        drop(conn);
+        server.join();
+    }
+}
+
+#[cfg(unix)]
+fn main() {
+    bench::main();
+}
+
+#[cfg(not(unix))]
+fn main() {
+    println!("This example requires Unix domain sockets");
+}
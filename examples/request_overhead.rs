@@ -0,0 +1,91 @@
+// This program measures how long it takes to send requests through the `ConnectionExt` methods.
+//
+// No X11 server is needed. The requests go to a `FakeConnection` that only records them, so the
+// measurement covers the code between calling e.g. `conn.get_input_focus()` and handing the bytes
+// to the connection: building the request struct, serializing it, and creating the cookie. The
+// time for recording the request is included as well and dominates for small requests, so compare
+// the numbers between runs of this program and not to absolute figures. Run it with `cargo run
+// --release --features testing --example request_overhead`.
+
+extern crate x11rb;
+
+use std::time::{Duration, Instant};
+
+use x11rb::errors::ConnectionError;
+use x11rb::protocol::xproto::{
+    ChangeWindowAttributesAux, ConnectionExt, EventMask, ImageFormat, Segment,
+};
+use x11rb::testing::FakeConnection;
+
+const REQUESTS: u32 = 1_000_000;
+
+/// The recorded requests are dropped after this many requests, outside of the measurement.
+const BATCH: u32 = 10_000;
+
+/// Send `REQUESTS` requests via `send` and print the time per request.
+///
+/// Returns the number of bytes that were sent, so that the work cannot be optimised away.
+fn measure(
+    name: &str,
+    conn: &FakeConnection,
+    mut send: impl FnMut() -> Result<(), ConnectionError>,
+) -> usize {
+    let mut elapsed = Duration::ZERO;
+    let mut bytes = 0;
+    for _ in 0..REQUESTS / BATCH {
+        let start = Instant::now();
+        for _ in 0..BATCH {
+            send().unwrap();
+        }
+        elapsed += start.elapsed();
+        let sent = conn.take_sent_requests();
+        assert_eq!(sent.len(), BATCH as usize);
+        bytes += sent
+            .iter()
+            .map(|request| request.bytes.len())
+            .sum::<usize>();
+    }
+    println!(
+        "{:<24} {:>6.1} ns/request",
+        name,
+        elapsed.as_nanos() as f64 / f64::from(REQUESTS)
+    );
+    bytes
+}
+
+fn main() {
+    let conn = FakeConnection::default();
+    let mut bytes = 0;
+    let aux = ChangeWindowAttributesAux::new()
+        .background_pixel(0x00ff_0000)
+        .event_mask(EventMask::EXPOSURE | EventMask::STRUCTURE_NOTIFY);
+    let segments = [Segment {
+        x1: 0,
+        y1: 0,
+        x2: 100,
+        y2: 100,
+    }; 16];
+    let image = [0; 64];
+
+    bytes += measure("get_input_focus", &conn, || {
+        let _ = conn.get_input_focus()?;
+        Ok(())
+    });
+    bytes += measure("map_window", &conn, || {
+        let _ = conn.map_window(42)?;
+        Ok(())
+    });
+    bytes += measure("change_window_attributes", &conn, || {
+        let _ = conn.change_window_attributes(42, &aux)?;
+        Ok(())
+    });
+    bytes += measure("poly_segment (16)", &conn, || {
+        let _ = conn.poly_segment(42, 43, &segments)?;
+        Ok(())
+    });
+    bytes += measure("put_image (64 bytes)", &conn, || {
+        let _ = conn.put_image(ImageFormat::Z_PIXMAP, 42, 43, 4, 4, 0, 0, 0, 32, &image)?;
+        Ok(())
+    });
+    println!("{} bytes in total", bytes);
+}
@@ -2,6 +2,7 @@ extern crate x11rb;
 
 use x11rb::connection::Connection;
 use x11rb::cursor::Handle as CursorHandle;
+use x11rb::first_frame::DeferredMap;
 use x11rb::protocol::xproto::*;
 use x11rb::protocol::Event;
 use x11rb::resource_manager::Database;
@@ -112,7 +113,38 @@ fn main() {
 
     conn.create_gc(gc_id, win_id, &gc_aux).unwrap();
 
-    conn.map_window(win_id).unwrap();
+    // Draw the first frame into a pixmap before mapping the window, so that the window never
+    // shows up without its contents.
+    let pixmap = conn.generate_id().unwrap();
+    let background_gc = conn.generate_id().unwrap();
+    conn.create_pixmap(screen.root_depth, pixmap, win_id, width, height)
+        .unwrap();
+    conn.create_gc(
+        background_gc,
+        pixmap,
+        &CreateGCAux::new().foreground(screen.white_pixel),
+    )
+    .unwrap();
+    let full = Rectangle {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+    conn.poly_fill_rectangle(pixmap, background_gc, &[full])
+        .unwrap();
+    draw_lines(conn, pixmap, gc_id, width, height);
+    DeferredMap::new(win_id)
+        .map_with_contents(conn, pixmap)
+        .unwrap();
+    conn.free_gc(background_gc).unwrap();
+    conn.free_pixmap(pixmap).unwrap();
+    // Let the X11 server paint newly exposed areas white again
+    conn.change_window_attributes(
+        win_id,
+        &ChangeWindowAttributesAux::new().background_pixel(screen.white_pixel),
+    )
+    .unwrap();
 
     conn.flush().unwrap();
 
@@ -123,25 +155,8 @@ fn main() {
             Event::Expose(event) => {
                 if event.count == 0 {
                     // There already is a white background because we set background_pixel to white
-                    // when creating the window.
-                    let (width, height): (i16, i16) = (width as _, height as _);
-                    let points = [
-                        Point {
-                            x: width,
-                            y: height,
-                        },
-                        Point { x: -10, y: -10 },
-                        Point {
-                            x: -10,
-                            y: height + 10,
-                        },
-                        Point {
-                            x: width + 10,
-                            y: -10,
-                        },
-                    ];
-                    conn.poly_line(CoordMode::ORIGIN, win_id, gc_id, &points)
-                        .unwrap();
+                    // after mapping the window.
+                    draw_lines(conn, win_id, gc_id, width, height);
                     conn.flush().unwrap();
                 }
             }
@@ -162,4 +177,25 @@ fn main() {
     }
 }
 
+fn draw_lines(conn: &impl Connection, drawable: Drawable, gc: Gcontext, width: u16, height: u16) {
+    let (width, height): (i16, i16) = (width as _, height as _);
+    let points = [
+        Point {
+            x: width,
+            y: height,
+        },
+        Point { x: -10, y: -10 },
+        Point {
+            x: -10,
+            y: height + 10,
+        },
+        Point {
+            x: width + 10,
+            y: -10,
+        },
+    ];
+    conn.poly_line(CoordMode::ORIGIN, drawable, gc, &points)
+        .unwrap();
+}
+
 include!("integration_test_util/util.rs");
@@ -0,0 +1,22 @@
+// This program runs the checks from x11rb::wm_conformance against the window manager that is
+// running on the display given by $DISPLAY. Start it after the window manager, e.g. in Xvfb.
+
+extern crate x11rb;
+
+use std::time::Duration;
+
+use x11rb::wm_conformance::WmConformance;
+
+fn main() {
+    let (conn, screen_num) = x11rb::connect(None).unwrap();
+
+    let report = WmConformance::new(&conn, screen_num)
+        .unwrap()
+        .timeout(Duration::from_secs(2))
+        .run_all();
+    print!("{}", report);
+
+    if !report.is_success() {
+        std::process::exit(1);
+    }
+}
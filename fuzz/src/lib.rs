@@ -0,0 +1,148 @@
+//! Feed arbitrary bytes to the parsers of x11rb.
+//!
+//! None of the functions in here may panic for any input. They are used by the fuzz targets in
+//! `fuzz_targets/` and by the `no_panic_parsing` test of x11rb.
+
+use std::convert::TryFrom;
+
+use x11rb::protocol::{self, Event, Request};
+use x11rb::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, X11Error,
+};
+
+/// The extensions of the fake X11 server, in the order of their major opcodes
+const EXTENSIONS: [&str; 30] = [
+    protocol::bigreq::X11_EXTENSION_NAME,
+    protocol::composite::X11_EXTENSION_NAME,
+    protocol::damage::X11_EXTENSION_NAME,
+    protocol::dpms::X11_EXTENSION_NAME,
+    protocol::dri2::X11_EXTENSION_NAME,
+    protocol::dri3::X11_EXTENSION_NAME,
+    protocol::ge::X11_EXTENSION_NAME,
+    protocol::glx::X11_EXTENSION_NAME,
+    protocol::present::X11_EXTENSION_NAME,
+    protocol::randr::X11_EXTENSION_NAME,
+    protocol::record::X11_EXTENSION_NAME,
+    protocol::render::X11_EXTENSION_NAME,
+    protocol::res::X11_EXTENSION_NAME,
+    protocol::screensaver::X11_EXTENSION_NAME,
+    protocol::shape::X11_EXTENSION_NAME,
+    protocol::shm::X11_EXTENSION_NAME,
+    protocol::sync::X11_EXTENSION_NAME,
+    protocol::xc_misc::X11_EXTENSION_NAME,
+    protocol::xevie::X11_EXTENSION_NAME,
+    protocol::xf86dri::X11_EXTENSION_NAME,
+    protocol::xf86vidmode::X11_EXTENSION_NAME,
+    protocol::xfixes::X11_EXTENSION_NAME,
+    protocol::xinerama::X11_EXTENSION_NAME,
+    protocol::xinput::X11_EXTENSION_NAME,
+    protocol::xkb::X11_EXTENSION_NAME,
+    protocol::xprint::X11_EXTENSION_NAME,
+    protocol::xselinux::X11_EXTENSION_NAME,
+    protocol::xtest::X11_EXTENSION_NAME,
+    protocol::xv::X11_EXTENSION_NAME,
+    protocol::xvmc::X11_EXTENSION_NAME,
+];
+
+/// Pretends that the X11 server supports all extensions.
+///
+/// Extension number `n` gets major opcode `128 + n`, its events start at `64 + 4 * n` and its
+/// errors at `128 + 4 * n`. Since some extensions have more than four events or errors, the
+/// ranges overlap, which just means that some codes are parsed as another extension's.
+#[derive(Debug, Clone, Copy)]
+pub struct AllExtensions;
+
+impl AllExtensions {
+    fn info(index: usize) -> Option<(&'static str, ExtensionInformation)> {
+        let name = *EXTENSIONS.get(index)?;
+        let index = u8::try_from(index).unwrap();
+        let info = ExtensionInformation {
+            major_opcode: 128 + index,
+            first_event: 64 + 4 * index,
+            first_error: 128 + 4 * index,
+        };
+        Some((name, info))
+    }
+
+    // Find the extension with the largest start of a range that contains `code`
+    fn find(code: u8, start: u8, step: u8) -> Option<(&'static str, ExtensionInformation)> {
+        let index = code.checked_sub(start)? / step;
+        Self::info(usize::from(index).min(EXTENSIONS.len() - 1))
+    }
+}
+
+impl ExtInfoProvider for AllExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        Self::info(usize::from(major_opcode.checked_sub(128)?))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        Self::find(event_code, 64, 4)
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        Self::find(error_code, 128, 4)
+    }
+}
+
+/// Claims that every code belongs to an extension whose codes start above it.
+///
+/// A buggy [`ExtInfoProvider`] must not make parsing panic, either.
+#[derive(Debug, Clone, Copy)]
+pub struct WrongExtensions;
+
+impl ExtInfoProvider for WrongExtensions {
+    fn get_from_major_opcode(&self, _major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        AllExtensions::info(EXTENSIONS.len() - 1)
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.get_from_major_opcode(event_code)
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.get_from_major_opcode(error_code)
+    }
+}
+
+/// Parse the data as an event and as an error.
+pub fn parse_event(data: &[u8]) {
+    let providers: [&dyn ExtInfoProvider; 2] = [&AllExtensions, &WrongExtensions];
+    for provider in providers.iter() {
+        let _ = Event::parse(data, *provider);
+        let _ = X11Error::try_parse(data, *provider);
+    }
+}
+
+// Parse a request and return the data after it
+fn parse_request_impl(data: &[u8]) -> Option<(Request<'_>, &[u8])> {
+    // The first two bytes say how much of the data belongs to the request
+    let (split, data) = data.split_at(data.len().min(2));
+    let split = usize::from(u16::from_ne_bytes(<[u8; 2]>::try_from(split).ok()?));
+    let (request, rest) = data.split_at(split.min(data.len()));
+    for big_requests in [BigRequests::Enabled, BigRequests::NotEnabled] {
+        if let Ok((header, body)) = parse_request_header(request, big_requests) {
+            let _ = Request::parse(header, body, &mut Vec::new(), &WrongExtensions);
+            if let Ok(request) = Request::parse(header, body, &mut Vec::new(), &AllExtensions) {
+                return Some((request, rest));
+            }
+        }
+    }
+    None
+}
+
+/// Parse the data as a request.
+pub fn parse_request(data: &[u8]) {
+    if let Some((request, _)) = parse_request_impl(data) {
+        let _ = request.into_owned();
+    }
+}
+
+/// Parse a request from the beginning of the data and the rest as its reply.
+pub fn parse_reply(data: &[u8]) {
+    if let Some((request, reply)) = parse_request_impl(data) {
+        if let Some(parser) = request.reply_parser() {
+            let _ = parser(reply, &mut Vec::new());
+        }
+    }
+}
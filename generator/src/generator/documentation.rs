@@ -0,0 +1,159 @@
+//! Generation of the runtime protocol documentation for `x11rb::protocol_docs`.
+
+use xcbgen::defs as xcbdefs;
+
+use super::output::Output;
+
+/// Generate `documentation.rs`, which contains the documentation of requests, replies and events.
+pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module) {
+    let mut requests = Output::new();
+    let mut replies = Output::new();
+    let mut events = Output::new();
+
+    for ns in module.sorted_namespaces() {
+        let has_feature = super::ext_has_feature(&ns.header);
+        for def in ns.src_order_defs.borrow().iter() {
+            let (table, name, doc) = match def {
+                xcbdefs::Def::Request(request_def) => {
+                    if let Some(doc) = request_def
+                        .reply
+                        .as_ref()
+                        .and_then(|reply| reply.doc.as_ref())
+                    {
+                        emit_entry(
+                            &mut replies,
+                            has_feature,
+                            &ns.header,
+                            &request_def.name,
+                            doc,
+                        );
+                    }
+                    (&mut requests, &request_def.name, request_def.doc.as_ref())
+                }
+                xcbdefs::Def::Event(xcbdefs::EventDef::Full(event_def)) => {
+                    (&mut events, &event_def.name, event_def.doc.as_ref())
+                }
+                // Copies of events, e.g. `KeyRelease`, share the documentation of the original
+                xcbdefs::Def::Event(xcbdefs::EventDef::Copy(event_copy_def)) => {
+                    let original = event_copy_def.get_original_full_def();
+                    if let Some(ref doc) = original.doc {
+                        emit_entry(
+                            &mut events,
+                            has_feature,
+                            &ns.header,
+                            &event_copy_def.name,
+                            doc,
+                        );
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            if let Some(doc) = doc {
+                emit_entry(table, has_feature, &ns.header, name, doc);
+            }
+        }
+    }
+
+    outln!(
+        out,
+        "//! Documentation of requests, replies and events for `crate::protocol_docs`."
+    );
+    outln!(out, "");
+    outln!(
+        out,
+        "use crate::protocol_docs::{{Documentation, ErrorDoc, FieldDoc}};"
+    );
+    outln!(out, "");
+    emit_table(out, "requests", requests);
+    emit_table(out, "replies", replies);
+    emit_table(out, "events", events);
+}
+
+/// Emit a static containing the given `Documentation`s.
+fn emit_table(out: &mut Output, kind: &str, entries: Output) {
+    outln!(
+        out,
+        "/// The documentation of all {} that are known to this build of x11rb.",
+        kind
+    );
+    outln!(
+        out,
+        "pub static {}: &[Documentation] = &[",
+        kind.to_ascii_uppercase()
+    );
+    out!(out.indent(), "{}", entries.into_data());
+    outln!(out, "];");
+}
+
+/// Emit a `Documentation` for the item `name` in the namespace `header`.
+fn emit_entry(out: &mut Output, has_feature: bool, header: &str, name: &str, doc: &xcbdefs::Doc) {
+    if has_feature {
+        outln!(out, "#[cfg(feature = \"{}\")]", header);
+    }
+    outln!(out, "Documentation {{");
+    out.indented(|out| {
+        outln!(out, "module: \"{}\",", header);
+        outln!(out, "name: \"{}\",", name);
+        outln!(out, "brief: {},", optional_text(doc.brief.as_deref()));
+        outln!(
+            out,
+            "description: {},",
+            optional_text(doc.description.as_deref())
+        );
+        let fields = doc
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "FieldDoc {{ name: {:?}, doc: {} }}",
+                    field.name,
+                    optional_text(field.doc.as_deref())
+                )
+            })
+            .collect::<Vec<_>>();
+        outln!(out, "fields: &[{}],", fields.join(", "));
+        let errors = doc
+            .errors
+            .iter()
+            .map(|error| {
+                format!(
+                    "ErrorDoc {{ error: {:?}, doc: {} }}",
+                    error.type_,
+                    optional_text(error.doc.as_deref())
+                )
+            })
+            .collect::<Vec<_>>();
+        outln!(out, "errors: &[{}],", errors.join(", "));
+    });
+    outln!(out, "}},");
+}
+
+/// Turn a text from the XML into an `Option<&str>` literal.
+fn optional_text(text: Option<&str>) -> String {
+    match text.map(clean_text) {
+        Some(text) if !text.is_empty() => format!("Some({:?})", text),
+        _ => String::from("None"),
+    }
+}
+
+/// Remove the indentation of the XML from a text.
+///
+/// Lines are joined into paragraphs, which are separated by empty lines.
+fn clean_text(text: &str) -> String {
+    let mut paragraphs = vec![String::new()];
+    for line in text.trim().split('\n').map(str::trim) {
+        let paragraph = paragraphs.last_mut().unwrap();
+        if line.is_empty() {
+            if !paragraph.is_empty() {
+                paragraphs.push(String::new());
+            }
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(line);
+        }
+    }
+    paragraphs.join("\n\n")
+}
@@ -112,7 +112,10 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
                         ns.header
                     );
                     out.indented(|out| {
-                        outln!(out, "match error_code - ext_info.first_error {{");
+                        outln!(
+                            out,
+                            "match error_code.wrapping_sub(ext_info.first_error) {{"
+                        );
                         for err_name in error_defs.iter().map(|def| def.name()) {
                             outln!(
                                 out.indent(),
@@ -257,7 +260,10 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                                 "match *event.get(1).ok_or(ParseError::InsufficientData)? {{"
                             );
                         } else {
-                            outln!(out, "match event_code - ext_info.first_event {{");
+                            outln!(
+                                out,
+                                "match event_code.wrapping_sub(ext_info.first_event) {{"
+                            );
                         }
                         for event_def in event_defs.iter() {
                             if event_def.is_xge() {
@@ -345,6 +351,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
             out,
             "/// Get the sequence number contained in this X11 event",
         );
+        outln!(out, "#[doc(alias = \"sequence_number\")]");
         outln!(out, "pub fn wire_sequence_number(&self) -> Option<u16> {{");
         out.indented(|out| {
             outln!(out, "match self {{");
@@ -428,7 +435,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
 
         outln!(
             out,
-            r"/// Get the response type of this X11 event
+            r#"/// Get the response type of this X11 event
 pub fn response_type(&self) -> u8 {{
     self.raw_response_type() & 0x7f
 }}
@@ -445,14 +452,192 @@ pub fn server_generated(&self) -> bool {{
 ///
 /// If this function returns true, then this event comes from another client via
 /// the `SendEvent` request. Otherwise, it was generated by the X11 server.
+#[doc(alias = "is_from_send_event")]
 pub fn sent_event(&self) -> bool {{
     self.raw_response_type() & 0x80 != 0
-}}"
+}}"#
+        );
+        outln!(out, "");
+
+        outln!(
+            out,
+            "/// Get the name of the extension that this X11 event belongs to",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is the name that is used with `QueryExtension`, e.g. `\"XInputExtension\"`.",
+        );
+        outln!(
+            out,
+            "/// Events of the core protocol, errors, and unknown events return `None`.",
+        );
+        outln!(out, "pub fn extension(&self) -> Option<&'static str> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) => None,");
+            outln!(out.indent(), "Event::Error(_) => None,");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in event_defs.iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    if ns.ext_info.is_some() {
+                        outln!(
+                            out.indent(),
+                            "Event::{}{}(_) => Some({}::X11_EXTENSION_NAME),",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                            ns.header,
+                        );
+                    } else {
+                        outln!(
+                            out.indent(),
+                            "Event::{}{}(_) => None,",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                        );
+                    }
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Get the window that this X11 event is about");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is a best-effort guess based on the fields of the event: A field called",
         );
+        outln!(
+            out,
+            "/// `window` is preferred, then a field called `event`, and otherwise the first field",
+        );
+        outln!(
+            out,
+            "/// of type `WINDOW` is used. For example, this returns the `event` window of a",
+        );
+        outln!(
+            out,
+            "/// `ButtonPress` event and the `owner` of a `SelectionRequest` event. Events without",
+        );
+        outln!(
+            out,
+            "/// a window, errors, and unknown events return `None`."
+        );
+        outln!(out, "pub fn window(&self) -> Option<xproto::Window> {{",);
+        out.indented(|out| {
+            generate_field_match(out, &namespaces, |event_def| {
+                find_field(event_def, "WINDOW", &["window", "event"])
+            });
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Get the server time contained in this X11 event");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is the value of a field called `time`, a field called `timestamp`, or",
+        );
+        outln!(
+            out,
+            "/// otherwise the first field of type `TIMESTAMP`. Events without a timestamp,",
+        );
+        outln!(out, "/// errors, and unknown events return `None`.");
+        outln!(
+            out,
+            "pub fn server_time(&self) -> Option<xproto::Timestamp> {{",
+        );
+        out.indented(|out| {
+            generate_field_match(out, &namespaces, |event_def| {
+                find_field(event_def, "TIMESTAMP", &["time", "timestamp"])
+            });
+        });
+        outln!(out, "}}");
     });
     outln!(out, "}}");
 }
 
+/// Generate a `match` on an event that returns the field chosen by `get_field`, if any.
+fn generate_field_match(
+    out: &mut Output,
+    namespaces: &[std::rc::Rc<xcbgen::defs::Namespace>],
+    get_field: impl Fn(&xcbgen::defs::EventFullDef) -> Option<String>,
+) {
+    outln!(out, "match self {{");
+    outln!(out.indent(), "Event::Unknown(_) => None,");
+    outln!(out.indent(), "Event::Error(_) => None,");
+    for ns in namespaces.iter() {
+        let event_defs = sorted_events(ns);
+        let has_feature = super::ext_has_feature(&ns.header);
+        for event_def in event_defs.iter() {
+            if has_feature {
+                outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+            }
+            match get_field(&event_def.get_original_full_def()) {
+                Some(field) => outln!(
+                    out.indent(),
+                    "Event::{}{}(value) => Some(value.{}),",
+                    get_ns_name_prefix(ns),
+                    event_def.name(),
+                    field,
+                ),
+                None => outln!(
+                    out.indent(),
+                    "Event::{}{}(_) => None,",
+                    get_ns_name_prefix(ns),
+                    event_def.name(),
+                ),
+            }
+        }
+    }
+    outln!(out, "}}");
+}
+
+/// Find a field of an event with the given type.
+///
+/// Fields with one of the `preferred` names are used first, in the given order. Otherwise, the
+/// first field of the type is used.
+fn find_field(
+    event_def: &xcbgen::defs::EventFullDef,
+    type_name: &str,
+    preferred: &[&str],
+) -> Option<String> {
+    let fields = event_def.fields.borrow();
+    let typed_fields = fields
+        .iter()
+        .filter_map(|field| match field {
+            xcbgen::defs::FieldDef::Normal(normal_field) => {
+                let name = match normal_field.type_.type_.get_resolved() {
+                    xcbgen::defs::TypeRef::Xid(xid_type_def) => {
+                        xid_type_def.upgrade().unwrap().name.clone()
+                    }
+                    xcbgen::defs::TypeRef::Alias(type_alias_def) => {
+                        type_alias_def.upgrade().unwrap().new_name.clone()
+                    }
+                    _ => return None,
+                };
+                if name == type_name {
+                    Some(normal_field.name.as_str())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    preferred
+        .iter()
+        .find(|name| typed_fields.contains(name))
+        .or_else(|| typed_fields.first())
+        .map(|name| super::namespace::to_rust_variable_name(name))
+}
+
 fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     let mut errors: Vec<_> = ns
         .error_defs
@@ -0,0 +1,270 @@
+//! Generation of runtime metadata about requests for `x11rb::dynamic`.
+
+use std::collections::BTreeMap;
+
+use xcbgen::defs as xcbdefs;
+
+use super::output::Output;
+
+/// Generate `introspection.rs`, which describes the wire format of all requests and replies.
+pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module) {
+    let mut structs = BTreeMap::new();
+    let mut requests = Output::new();
+
+    for ns in module.sorted_namespaces() {
+        let extension = match ns.ext_info {
+            Some(ref ext_info) => format!("Some(\"{}\")", ext_info.xname),
+            None => String::from("None"),
+        };
+        for def in ns.src_order_defs.borrow().iter() {
+            let request_def = match def {
+                xcbdefs::Def::Request(request_def) => request_def,
+                _ => continue,
+            };
+            if super::ext_has_feature(&ns.header) {
+                outln!(requests, "#[cfg(feature = \"{}\")]", ns.header);
+            }
+            outln!(requests, "RequestInfo {{");
+            requests.indented(|out| {
+                outln!(out, "module: \"{}\",", ns.header);
+                outln!(out, "extension: {},", extension);
+                outln!(out, "name: \"{}\",", request_def.name);
+                outln!(out, "opcode: {},", request_def.opcode);
+                let fields = fields_info(&request_def.fields.borrow(), &mut structs);
+                outln!(out, "fields: &[{}],", fields);
+                match request_def.reply {
+                    Some(ref reply) => {
+                        let fields = fields_info(&reply.fields.borrow(), &mut structs);
+                        outln!(out, "reply: Some(&[{}]),", fields);
+                    }
+                    None => outln!(out, "reply: None,"),
+                }
+            });
+            outln!(requests, "}},");
+        }
+    }
+
+    outln!(
+        out,
+        "//! Descriptions of all requests and replies for `crate::dynamic`."
+    );
+    outln!(out, "");
+    outln!(
+        out,
+        "// Descriptions of structs are unused if only disabled extensions use them",
+    );
+    outln!(out, "#![allow(dead_code)]");
+    outln!(out, "");
+    outln!(
+        out,
+        "use crate::dynamic::{{BitCase, FieldInfo, FieldKind, FieldType, ListLength, \
+         RequestInfo}};"
+    );
+    outln!(out, "");
+    for (const_name, fields) in structs.iter() {
+        outln!(out, "const {}: &[FieldInfo] = &[{}];", const_name, fields);
+    }
+    outln!(out, "");
+    outln!(
+        out,
+        "/// All requests that are known to this build of x11rb."
+    );
+    outln!(out, "pub static REQUESTS: &[RequestInfo] = &[");
+    out!(out.indent(), "{}", requests.into_data());
+    outln!(out, "];");
+}
+
+/// Describe a list of fields as the contents of a `&[FieldInfo]`.
+fn fields_info(fields: &[xcbdefs::FieldDef], structs: &mut BTreeMap<String, String>) -> String {
+    let mut result = Vec::new();
+    for field in fields {
+        let (name, kind) = match field {
+            // Not present on the wire
+            xcbdefs::FieldDef::VirtualLen(_) => continue,
+            xcbdefs::FieldDef::Pad(pad_field) => match pad_field.kind {
+                xcbdefs::PadKind::Bytes(bytes) => ("", format!("FieldKind::Pad({})", bytes)),
+                xcbdefs::PadKind::Align(align) => ("", format!("FieldKind::Align({})", align)),
+            },
+            xcbdefs::FieldDef::Normal(normal_field) => {
+                let kind = match field_type(&normal_field.type_, structs) {
+                    Some(type_) => format!("FieldKind::Value({})", type_),
+                    None => String::from("FieldKind::Unsupported"),
+                };
+                (normal_field.name.as_str(), kind)
+            }
+            xcbdefs::FieldDef::List(list_field) => {
+                let type_ = field_type(&list_field.element_type, structs);
+                let length = match list_field.length_expr {
+                    None => Some(String::from("ListLength::Remaining")),
+                    Some(xcbdefs::Expression::Value(value)) => {
+                        Some(format!("ListLength::Fixed({})", value))
+                    }
+                    Some(xcbdefs::Expression::FieldRef(ref field_ref)) => {
+                        Some(format!("ListLength::Field(\"{}\")", field_ref.field_name))
+                    }
+                    Some(_) => Some(String::from("ListLength::Expression")),
+                };
+                let kind = match (type_, length) {
+                    (Some(type_), Some(length)) => format!(
+                        "FieldKind::List {{ element: {}, length: {} }}",
+                        type_, length
+                    ),
+                    _ => String::from("FieldKind::Unsupported"),
+                };
+                (list_field.name.as_str(), kind)
+            }
+            xcbdefs::FieldDef::Switch(switch_field) => {
+                let kind = switch_info(switch_field, structs)
+                    .unwrap_or_else(|| String::from("FieldKind::Unsupported"));
+                (switch_field.name.as_str(), kind)
+            }
+            xcbdefs::FieldDef::Fd(fd_field) => (
+                fd_field.name.as_str(),
+                String::from("FieldKind::Unsupported"),
+            ),
+            xcbdefs::FieldDef::FdList(fd_list_field) => (
+                fd_list_field.name.as_str(),
+                String::from("FieldKind::Unsupported"),
+            ),
+            // The value is computed from other fields, but it is still described so that replies
+            // can be parsed (e.g. `response_type`)
+            xcbdefs::FieldDef::Expr(expr_field) => {
+                let kind = match field_type(&expr_field.type_, structs) {
+                    Some(type_) => format!("FieldKind::Value({})", type_),
+                    None => String::from("FieldKind::Unsupported"),
+                };
+                (expr_field.name.as_str(), kind)
+            }
+        };
+        result.push(format!(
+            "FieldInfo {{ name: \"{}\", kind: {} }}",
+            name, kind
+        ));
+    }
+    result.join(", ")
+}
+
+/// Describe a `<switch>` with `<bitcase>`s that is controlled by a single field.
+fn switch_info(
+    switch_field: &xcbdefs::SwitchField,
+    structs: &mut BTreeMap<String, String>,
+) -> Option<String> {
+    if switch_field.kind != xcbdefs::SwitchKind::BitCase {
+        return None;
+    }
+    let mask = match switch_field.expr {
+        xcbdefs::Expression::FieldRef(ref field_ref) => &field_ref.field_name,
+        _ => return None,
+    };
+    let mut cases = Vec::new();
+    for case in switch_field.cases.iter() {
+        let mut case_mask = 0;
+        for expr in case.exprs.iter() {
+            case_mask |= constant_value(expr)?;
+        }
+        let fields = fields_info(&case.fields.borrow(), structs);
+        cases.push(format!(
+            "BitCase {{ mask: {}, fields: &[{}] }}",
+            case_mask, fields
+        ));
+    }
+    Some(format!(
+        "FieldKind::Switch {{ mask: \"{}\", cases: &[{}] }}",
+        mask,
+        cases.join(", ")
+    ))
+}
+
+/// Evaluate an expression that does not depend on any fields.
+fn constant_value(expr: &xcbdefs::Expression) -> Option<u32> {
+    match expr {
+        xcbdefs::Expression::Value(value) => Some(*value),
+        xcbdefs::Expression::Bit(bit) => Some(1 << bit),
+        xcbdefs::Expression::EnumRef(enum_ref) => {
+            let enum_def = match enum_ref.enum_.get_resolved() {
+                xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
+                _ => return None,
+            };
+            let item = enum_def
+                .items
+                .iter()
+                .find(|item| item.name == enum_ref.variant)?;
+            Some(match item.value {
+                xcbdefs::EnumValue::Value(value) => value,
+                xcbdefs::EnumValue::Bit(bit) => 1 << bit,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Get the `FieldType` for a type, if it is supported.
+fn field_type(
+    type_: &xcbdefs::FieldValueType,
+    structs: &mut BTreeMap<String, String>,
+) -> Option<String> {
+    type_ref_type(type_.type_.get_resolved(), structs)
+}
+
+fn type_ref_type(
+    type_ref: &xcbdefs::TypeRef,
+    structs: &mut BTreeMap<String, String>,
+) -> Option<String> {
+    match type_ref {
+        xcbdefs::TypeRef::BuiltIn(builtin) => {
+            let name = match builtin {
+                xcbdefs::BuiltInType::Card8 => "Card8",
+                xcbdefs::BuiltInType::Card16 => "Card16",
+                xcbdefs::BuiltInType::Card32 => "Card32",
+                xcbdefs::BuiltInType::Card64 => "Card64",
+                xcbdefs::BuiltInType::Int8 => "Int8",
+                xcbdefs::BuiltInType::Int16 => "Int16",
+                xcbdefs::BuiltInType::Int32 => "Int32",
+                xcbdefs::BuiltInType::Int64 => "Int64",
+                xcbdefs::BuiltInType::Byte => "Byte",
+                xcbdefs::BuiltInType::Bool => "Bool",
+                xcbdefs::BuiltInType::Char => "Char",
+                xcbdefs::BuiltInType::Float => "Float",
+                xcbdefs::BuiltInType::Double => "Double",
+                xcbdefs::BuiltInType::Void => "Byte",
+            };
+            Some(format!("FieldType::{}", name))
+        }
+        xcbdefs::TypeRef::Xid(_) | xcbdefs::TypeRef::XidUnion(_) => {
+            Some(String::from("FieldType::Card32"))
+        }
+        xcbdefs::TypeRef::Alias(alias) => {
+            let original = alias.upgrade().unwrap().get_original_type();
+            type_ref_type(&original, structs)
+        }
+        xcbdefs::TypeRef::Struct(struct_def) => {
+            let struct_def = struct_def.upgrade().unwrap();
+            let ns = struct_def.namespace.upgrade().unwrap();
+            let const_name = format!("{}_{}", ns.header, struct_def.name).to_ascii_uppercase();
+            if !structs.contains_key(&const_name) {
+                // Only structs with a fixed size consisting of simple values are supported
+                struct_def.size()?;
+                let fields = struct_def.fields.borrow();
+                let simple = fields.iter().all(|field| match field {
+                    xcbdefs::FieldDef::Pad(_) | xcbdefs::FieldDef::Normal(_) => true,
+                    _ => false,
+                });
+                if !simple {
+                    return None;
+                }
+                let info = fields_info(&fields, structs);
+                if info.contains("FieldKind::Unsupported") {
+                    return None;
+                }
+                let _ = structs.insert(const_name.clone(), info);
+            }
+            Some(format!(
+                "FieldType::Struct {{ name: \"{}\", fields: {} }}",
+                struct_def.name, const_name
+            ))
+        }
+        xcbdefs::TypeRef::Enum(_)
+        | xcbdefs::TypeRef::Union(_)
+        | xcbdefs::TypeRef::EventStruct(_) => None,
+    }
+}
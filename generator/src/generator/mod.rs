@@ -1,18 +1,24 @@
 #![allow(clippy::match_like_matches_macro)]
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 #[macro_use]
 mod output;
+mod documentation;
 mod error_events;
+mod introspection;
 mod namespace;
+mod resources;
 mod special_cases;
 
 use output::Output;
 
-pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String> {
+pub(crate) fn generate(
+    module: &xcbgen::defs::Module,
+    proto_version: &str,
+) -> HashMap<PathBuf, String> {
     let mut out_map = HashMap::new();
 
     let mut main_out = Output::new();
@@ -32,7 +38,7 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String
     outln!(main_out, "use std::borrow::Cow;");
     outln!(main_out, "use std::convert::TryInto;");
     outln!(main_out, "use crate::errors::ParseError;");
-    outln!(main_out, "use crate::utils::RawFdContainer;");
+    outln!(main_out, "use crate::utils::OwnedFd;");
     outln!(main_out, "use crate::x11_utils::{{TryParse, X11Error}};");
     outln!(
         main_out,
@@ -47,7 +53,13 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String
     let mut enum_cases = HashMap::new();
     for ns in module.sorted_namespaces() {
         let mut ns_out = Output::new();
-        namespace::generate(&ns, &caches, &mut ns_out, &mut enum_cases);
+        namespace::generate(
+            &ns,
+            &caches,
+            namespace::Target::X11rb,
+            &mut ns_out,
+            &mut enum_cases,
+        );
         out_map.insert(
             PathBuf::from(format!("{}.rs", ns.header)),
             ns_out.into_data(),
@@ -58,8 +70,48 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String
         }
         outln!(main_out, "pub mod {};", ns.header);
     }
+    outln!(main_out, "#[cfg(feature = \"protocol-docs\")]");
+    outln!(main_out, "pub mod documentation;");
+    outln!(main_out, "#[cfg(feature = \"dynamic\")]");
+    outln!(main_out, "pub mod introspection;");
+    outln!(main_out, "");
+    outln!(
+        main_out,
+        "/// The version of xcb-proto that this code was generated from."
+    );
+    outln!(main_out, "///");
+    outln!(
+        main_out,
+        "/// Each extension module has an `X11_XML_VERSION` constant with the version of the \
+         extension"
+    );
+    outln!(
+        main_out,
+        "/// that it implements. Servers might only support older versions."
+    );
+    outln!(
+        main_out,
+        "pub const XCB_PROTO_VERSION: &str = \"{}\";",
+        proto_version
+    );
     outln!(main_out, "");
 
+    let mut documentation_out = Output::new();
+    write_code_header(&mut documentation_out);
+    documentation::generate(&mut documentation_out, module);
+    out_map.insert(
+        PathBuf::from("documentation.rs"),
+        documentation_out.into_data(),
+    );
+
+    let mut introspection_out = Output::new();
+    write_code_header(&mut introspection_out);
+    introspection::generate(&mut introspection_out, module);
+    out_map.insert(
+        PathBuf::from("introspection.rs"),
+        introspection_out.into_data(),
+    );
+
     namespace::generate_request_reply_enum(&mut main_out, module, enum_cases);
     error_events::generate(&mut main_out, module);
 
@@ -67,6 +119,40 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String
     out_map
 }
 
+/// Generate modules for the given namespaces that are used outside of x11rb.
+///
+/// The generated code refers to x11rb via `x11rb::` and imports all other namespaces from
+/// `x11rb::protocol`. No `mod.rs` is generated and the namespaces are not added to the `Request`,
+/// `Reply` and `Event` enums of x11rb.
+pub(crate) fn generate_out_of_tree(
+    module: &xcbgen::defs::Module,
+    namespaces: &HashSet<String>,
+) -> HashMap<PathBuf, String> {
+    let caches = RefCell::new(namespace::Caches::default());
+    caches.borrow_mut().gather_enum_infos(module);
+
+    let mut out_map = HashMap::new();
+    let mut enum_cases = HashMap::new();
+    for ns in module.sorted_namespaces() {
+        if !namespaces.contains(&ns.header) {
+            continue;
+        }
+        let mut ns_out = Output::new();
+        namespace::generate(
+            &ns,
+            &caches,
+            namespace::Target::OutOfTree(namespaces),
+            &mut ns_out,
+            &mut enum_cases,
+        );
+        out_map.insert(
+            PathBuf::from(format!("{}.rs", ns.header)),
+            ns_out.into_data(),
+        );
+    }
+    out_map
+}
+
 fn ext_has_feature(name: &str) -> bool {
     match name {
         "bigreq" | "ge" | "xc_misc" | "xproto" => false,
@@ -207,3 +293,94 @@ pub(crate) fn get_ns_name_prefix(ns: &xcbgen::defs::Namespace) -> String {
         String::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    /// Generate the out-of-tree module for the given XML description of an extension.
+    fn generate_test_extension(xml: &str) -> String {
+        let module = xcbgen::defs::Module::new();
+        let mut parser = xcbgen::Parser::new(module.clone());
+        let doc = roxmltree::Document::parse(xml).unwrap();
+        let ns = parser
+            .parse_namespace(doc.root().first_element_child().unwrap())
+            .unwrap();
+        xcbgen::resolve(&module).unwrap();
+        let namespaces = [ns.header.clone()].iter().cloned().collect::<HashSet<_>>();
+        let mut generated = super::generate_out_of_tree(&module, &namespaces);
+        generated.remove(Path::new("test.rs")).unwrap()
+    }
+
+    const PAIR: &str = r#"
+        <struct name="Pair">
+            <field type="CARD16" name="first" />
+            <field type="CARD16" name="second" />
+        </struct>"#;
+
+    #[test]
+    fn list_at_start_of_request() {
+        let code = generate_test_extension(&format!(
+            r#"<xcb header="test" extension-xname="TEST" extension-name="Test"
+                    major-version="1" minor-version="0">
+                {}
+                <request name="SetPairs" opcode="1">
+                    <list type="Pair" name="pairs" />
+                </request>
+            </xcb>"#,
+            PAIR
+        ));
+        // Every element after the first one is parsed from the rest of the request
+        assert!(code.contains("let (v, new_remaining) = Pair::try_parse(remaining)?;"));
+        assert!(!code.contains("Pair::try_parse(value)"));
+    }
+
+    #[test]
+    fn fixed_length_list() {
+        let code = generate_test_extension(&format!(
+            r#"<xcb header="test" extension-xname="TEST" extension-name="Test"
+                    major-version="1" minor-version="0">
+                {}
+                <struct name="Pairs">
+                    <list type="Pair" name="pairs"><value>2</value></list>
+                </struct>
+            </xcb>"#,
+            PAIR
+        ));
+        assert!(code.contains("let (pairs_0, remaining) = Pair::try_parse(remaining)?;"));
+        assert!(code.contains("let (pairs_1, remaining) = Pair::try_parse(remaining)?;"));
+    }
+
+    #[test]
+    fn strict_enum_check() {
+        let code = generate_test_extension(
+            r#"<xcb header="test" extension-xname="TEST" extension-name="Test"
+                    major-version="1" minor-version="0">
+                <enum name="Kind">
+                    <item name="A"><value>0</value></item>
+                    <item name="B"><value>2</value></item>
+                    <item name="AlsoB"><value>2</value></item>
+                    <item name="C"><value>4</value></item>
+                    <item name="D"><value>5</value></item>
+                    <item name="E"><value>6</value></item>
+                </enum>
+                <enum name="Flags">
+                    <item name="None"><value>0</value></item>
+                    <item name="X"><bit>0</bit></item>
+                    <item name="Y"><bit>2</bit></item>
+                    <item name="Any"><value>128</value></item>
+                </enum>
+                <struct name="Thing">
+                    <field type="CARD8" name="kind" enum="Kind" />
+                    <field type="CARD8" name="flags" enum="Flags" />
+                </struct>
+            </xcb>"#,
+        );
+        // Duplicate values are listed once, zero is covered by the bits
+        assert!(code.contains("if matches!(self.0, 0 | 2 | 4..=6) {"));
+        assert!(code.contains("if self.0 & !0x5 == 0 || matches!(self.0, 128) {"));
+        assert!(code.contains("Kind::check_known(kind)?;"));
+        assert!(code.contains("Flags::check_known(flags)?;"));
+    }
+}
@@ -14,7 +14,8 @@ use std::rc::Rc;
 use xcbgen::defs as xcbdefs;
 
 use super::output::Output;
-use super::{get_ns_name_prefix, special_cases};
+use super::resources::ResourceInfo;
+use super::{get_ns_name_prefix, resources, special_cases};
 
 #[derive(Debug, Default)]
 pub(super) struct PerModuleEnumCases {
@@ -22,6 +23,8 @@ pub(super) struct PerModuleEnumCases {
     request_variants: Vec<String>,
     /// Lines that belong in definition of Request::parse.
     request_parse_cases: Vec<String>,
+    /// Lines that belong in the definition of get_request_name.
+    request_name_cases: Vec<String>,
     /// Lines that belong in the definition of Request::reply_parser.
     reply_parse_cases: Vec<String>,
     /// Lines that belong in the definition of Request::into_owned.
@@ -34,14 +37,27 @@ pub(super) struct PerModuleEnumCases {
 
 type EnumCases = HashMap<String, PerModuleEnumCases>;
 
+/// Where the generated code is used.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Target<'a> {
+    /// The code is part of `x11rb::protocol`.
+    X11rb,
+    /// The code is part of another crate that depends on x11rb.
+    ///
+    /// The given namespaces are generated next to each other. All other namespaces are taken
+    /// from `x11rb::protocol`.
+    OutOfTree(&'a HashSet<String>),
+}
+
 /// Generate a Rust module for namespace `ns`.
 pub(super) fn generate(
     ns: &xcbdefs::Namespace,
     caches: &RefCell<Caches>,
+    target: Target<'_>,
     out: &mut Output,
     enum_cases: &mut EnumCases,
 ) {
-    NamespaceGenerator::new(ns, caches).generate(out, enum_cases);
+    NamespaceGenerator::new(ns, caches, target).generate(out, enum_cases);
 }
 
 /// Generate the Request and Reply enums containing all possible requests and replies, respectively.
@@ -94,7 +110,7 @@ pub(super) fn generate_request_reply_enum(
                 "// Might not be used if none of the extensions that use FD passing is enabled",
             );
             outln!(out, "#[allow(unused_variables)]");
-            outln!(out, "fds: &mut Vec<RawFdContainer>,");
+            outln!(out, "fds: &mut Vec<OwnedFd>,");
             outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
         });
         outln!(out, ") -> Result<Self, ParseError> {{");
@@ -225,6 +241,84 @@ pub(super) fn generate_request_reply_enum(
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(
+        out,
+        "/// Get the name of the request with the given opcodes, e.g. `\"xproto::CreateWindow\"`."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// The name of an extension request can only be found if the extension is known to the"
+    );
+    outln!(
+        out,
+        "/// `ext_info_provider`. `None` is returned for unknown requests."
+    );
+    outln!(out, "pub fn get_request_name(");
+    out.indented(|out| {
+        outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out, "major_opcode: u8,");
+        outln!(out, "minor_opcode: u16,");
+    });
+    outln!(out, ") -> Option<&'static str> {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "// Core protocol requests have major opcodes below 128"
+        );
+        outln!(out, "if major_opcode < 128 {{");
+        out.indented(|out| {
+            outln!(out, "return match major_opcode {{");
+            out.indented(|out| {
+                let xproto_ns = module.namespace("xproto").unwrap();
+                let xproto_cases = enum_cases
+                    .get_mut(&xproto_ns.header)
+                    .unwrap()
+                    .request_name_cases
+                    .drain(..);
+                for case in xproto_cases {
+                    outln!(out, "{}", case);
+                }
+                outln!(out, "_ => None,");
+            });
+            outln!(out, "}};");
+        });
+        outln!(out, "}}");
+        outln!(out, "// Extension requests have 8 bit minor opcodes");
+        outln!(out, "let minor_opcode: u8 = minor_opcode.try_into().ok()?;");
+        outln!(
+            out,
+            "match ext_info_provider.get_from_major_opcode(major_opcode) {{"
+        );
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                let name_cases = &mut enum_cases.get_mut(&ns.header).unwrap().request_name_cases;
+                if name_cases.is_empty() {
+                    continue;
+                }
+
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(
+                    out,
+                    "Some(({}::X11_EXTENSION_NAME, _)) => match minor_opcode {{",
+                    ns.header
+                );
+                out.indented(|out| {
+                    for case in name_cases.drain(..) {
+                        outln!(out, "{}", case);
+                    }
+                    outln!(out, "_ => None,");
+                });
+                outln!(out, "}},");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
     outln!(out, "/// Enumeration of all possible X11 replies.");
     outln!(out, "#[derive(Debug)]");
     // clippy::large_enum_variant for XkbGetKbdByNameReply.
@@ -275,6 +369,76 @@ pub(super) fn generate_request_reply_enum(
     outln!(out, "");
 }
 
+/// Does the X11 server send more than one reply to this request?
+fn has_multiple_replies(request_def: &xcbdefs::RequestDef) -> bool {
+    let ns = request_def.namespace.upgrade().unwrap();
+    matches!(
+        (ns.header.as_str(), request_def.name.as_str()),
+        ("xproto", "ListFontsWithInfo") | ("record", "EnableContext")
+    )
+}
+
+/// Get the `#[cfg]` attribute that allows pruning the given request.
+///
+/// With the `request-pruning` feature, only requests that are enabled via
+/// `--cfg x11rb_request="<module>::<Request>"` are compiled. Requests that x11rb itself needs are
+/// always available.
+fn request_cfg(header: &str, name: &str) -> Option<String> {
+    const ALWAYS_AVAILABLE: &[(&str, &str)] = &[
+        ("bigreq", "Enable"),
+        ("record", "EnableContext"),
+        ("xc_misc", "GetXIDRange"),
+        ("xproto", "ChangeProperty"),
+        ("xproto", "GetInputFocus"),
+        ("xproto", "InternAtom"),
+        ("xproto", "ListFontsWithInfo"),
+        ("xproto", "QueryExtension"),
+    ];
+    if ALWAYS_AVAILABLE.contains(&(header, name)) {
+        None
+    } else {
+        Some(format!(
+            "#[cfg(any(not(feature = \"request-pruning\"), x11rb_request = \"{}::{}\"))]",
+            header, name,
+        ))
+    }
+}
+
+/// Put the given `#[cfg]` attribute in front of each top-level item in `code`.
+fn add_cfg_to_items(code: String, cfg: Option<&str>) -> String {
+    let cfg = match cfg {
+        Some(cfg) => cfg,
+        None => return code,
+    };
+    let mut result = String::with_capacity(code.len());
+    let mut item_boundary = true;
+    for line in code.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        let top_level = !trimmed.is_empty() && !trimmed.starts_with(' ');
+        if item_boundary && top_level && !trimmed.starts_with('}') {
+            result.push_str(cfg);
+            result.push('\n');
+            item_boundary = false;
+        }
+        if trimmed.is_empty() {
+            item_boundary = true;
+        } else if top_level && !trimmed.starts_with("//") && !trimmed.starts_with("#[") {
+            item_boundary = trimmed.starts_with('}') || trimmed.ends_with(';');
+        }
+        result.push_str(line);
+    }
+    result
+}
+
+/// The kinds of methods that serialize a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SerializeMode {
+    /// `serialize()`, which collects the pieces of the request for a connection.
+    Pieces,
+    /// `write_to()`, which passes the bytes to a callback and leaves the length field at zero.
+    Write,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum IovecConversion {
     // No conversion is required.
@@ -489,28 +653,48 @@ impl Caches {
 struct NamespaceGenerator<'ns, 'c> {
     ns: &'ns xcbdefs::Namespace,
     caches: &'c RefCell<Caches>,
+    target: Target<'c>,
 
     /// `Option` or `std::option::Option`
     option_name: &'static str,
+
+    /// `crate` or `x11rb`
+    crate_name: &'static str,
 }
 
 impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     #[inline]
-    fn new(ns: &'ns xcbdefs::Namespace, caches: &'c RefCell<Caches>) -> Self {
+    fn new(ns: &'ns xcbdefs::Namespace, caches: &'c RefCell<Caches>, target: Target<'c>) -> Self {
         let option_name = if ns.header == "present" {
             "std::option::Option"
         } else {
             "Option"
         };
+        let crate_name = match target {
+            Target::X11rb => "crate",
+            Target::OutOfTree(_) => "x11rb",
+        };
         NamespaceGenerator {
             ns,
             caches,
+            target,
             option_name,
+            crate_name,
         }
     }
 
     fn generate(&self, out: &mut Output, enum_cases: &mut EnumCases) {
-        super::write_code_header(out);
+        match self.target {
+            Target::X11rb => super::write_code_header(out),
+            Target::OutOfTree(_) => {
+                outln!(
+                    out,
+                    "// This file contains generated code. Do not edit directly.",
+                );
+                outln!(out, "// It was generated by x11rb-generator.");
+                outln!(out, "");
+            }
+        }
         if let Some(info) = &self.ns.ext_info {
             outln!(out, "//! Bindings to the `{}` X11 extension.", info.name);
         } else {
@@ -532,6 +716,16 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
         outln!(out, "");
         outln!(out, "#![allow(clippy::too_many_arguments)]");
+        if let Target::X11rb = self.target {
+            outln!(
+                out,
+                "// Pruned requests leave behind imports and helpers that are only used by them",
+            );
+            outln!(
+                out,
+                "#![cfg_attr(feature = \"request-pruning\", allow(unused_imports, dead_code))]",
+            );
+        }
         outln!(out, "");
         outln!(out, "#[allow(unused_imports)]");
         outln!(out, "use std::borrow::Cow;");
@@ -542,30 +736,35 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::utils::{{RawFdContainer, pretty_print_bitmask, pretty_print_enum}};"
+            "use {}::utils::{{OwnedFd, pretty_print_bitmask, pretty_print_enum}};",
+            self.crate_name,
         );
         outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::x11_utils::{{Request, RequestHeader, Serialize, TryParse, TryParseFd, \
-             TryIntoUSize}};"
+            "use {}::x11_utils::{{Request, RequestHeader, Serialize, TryParse, TryParseFd, \
+             TryIntoUSize}};",
+            self.crate_name,
         );
         outln!(
             out,
-            "use crate::connection::{{BufWithFds, PiecewiseBuf, RequestConnection}};"
+            "use {}::connection::{{BufWithFds, PiecewiseBuf, RequestConnection}};",
+            self.crate_name,
         );
         outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::cookie::{{Cookie, CookieWithFds, VoidCookie}};"
+            "use {}::cookie::{{Cookie, CookieWithFds, VoidCookie}};",
+            self.crate_name,
         );
-        if self.ns.header == "xproto" {
-            outln!(out, "use crate::cookie::ListFontsWithInfoCookie;");
-        }
-        if self.ns.header == "record" {
-            outln!(out, "use crate::cookie::RecordEnableContextCookie;");
+        if self.ns.header == "xproto" || self.ns.header == "record" {
+            outln!(out, "use crate::cookie::CookieStream;");
         }
-        outln!(out, "use crate::errors::{{ConnectionError, ParseError}};");
+        outln!(
+            out,
+            "use {}::errors::{{ConnectionError, ParseError}};",
+            self.crate_name,
+        );
 
         let mut imports = self
             .ns
@@ -576,7 +775,12 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             .collect::<Vec<_>>();
         imports.sort();
         for import in imports.iter() {
-            outln!(out, "use super::{};", import);
+            match self.target {
+                Target::OutOfTree(generated) if !generated.contains(import) => {
+                    outln!(out, "use x11rb::protocol::{};", import)
+                }
+                _ => outln!(out, "use super::{};", import),
+            }
         }
 
         if let Some(ref ext_info) = self.ns.ext_info {
@@ -665,6 +869,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
         }
 
+        for resource in resources::for_namespace(&self.ns.header) {
+            self.generate_resource_wrapper(resource, out);
+        }
+
         let trait_out = trait_out.into_data();
 
         outln!(
@@ -713,13 +921,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
         let deducible_fields = gather_deducible_fields(&*request_fields);
 
-        if switch_fields.len() == 1 {
-            if let Some(aux_start_align) = switch_fields[0].required_start_align {
-                assert_eq!(aux_start_align.offset(), 0);
-            }
-            self.generate_aux(request_def, switch_fields[0], &function_name, out);
-        }
-
+        // The opcode is always available, everything else can be pruned
         outln!(out, "/// Opcode for the {} request", name);
         outln!(
             out,
@@ -728,6 +930,29 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             request_def.opcode,
         );
 
+        let request_cfg = match self.target {
+            Target::X11rb => request_cfg(&self.ns.header, &name),
+            // There is no `request-pruning` feature outside of x11rb
+            Target::OutOfTree(_) => None,
+        };
+        let with_cfg = |case: String| match request_cfg {
+            Some(ref cfg) => format!("{}\n{}", cfg, case),
+            None => case,
+        };
+        let final_out = out;
+        let final_trait_out = trait_out;
+        let mut request_out = Output::new();
+        let mut request_trait_out = Output::new();
+        let out = &mut request_out;
+        let trait_out = &mut request_trait_out;
+
+        if switch_fields.len() == 1 {
+            if let Some(aux_start_align) = switch_fields[0].required_start_align {
+                assert_eq!(aux_start_align.offset(), 0);
+            }
+            self.generate_aux(request_def, switch_fields[0], &function_name, out);
+        }
+
         let gathered = self.gather_request_fields(request_def, &deducible_fields);
 
         self.emit_request_struct(request_def, &name, &deducible_fields, &gathered, out);
@@ -737,15 +962,22 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         } else {
             ""
         };
-        enum_cases.request_variants.push(format!(
+        enum_cases.request_variants.push(with_cfg(format!(
             "{ns_prefix}{name}({header}::{name}Request{lifetime}),",
             ns_prefix = ns_prefix,
             name = name,
             header = self.ns.header,
             lifetime = lifetime_block
+        )));
+        // Names are always available, even for pruned requests
+        enum_cases.request_name_cases.push(format!(
+            "{header}::{opcode_name}_REQUEST => Some(\"{header}::{name}\"),",
+            header = self.ns.header,
+            opcode_name = super::camel_case_to_upper_snake(&name),
+            name = name,
         ));
         if gathered.has_fds() {
-            enum_cases.request_parse_cases.push(format!(
+            enum_cases.request_parse_cases.push(with_cfg(format!(
                 "{header}::{opcode_name}_REQUEST => return \
                  Ok(Request::{ns_prefix}{name}({header}::{name}Request::\
                  try_parse_request_fd(header, remaining, fds)?)),",
@@ -753,9 +985,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 opcode_name = super::camel_case_to_upper_snake(&name),
                 ns_prefix = ns_prefix,
                 name = name,
-            ));
+            )));
         } else {
-            enum_cases.request_parse_cases.push(format!(
+            enum_cases.request_parse_cases.push(with_cfg(format!(
                 "{header}::{opcode_name}_REQUEST => return \
                  Ok(Request::{ns_prefix}{name}({header}::{name}Request::try_parse_request(header, \
                  remaining)?)),",
@@ -763,7 +995,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 opcode_name = super::camel_case_to_upper_snake(&name),
                 ns_prefix = ns_prefix,
                 name = name,
-            ));
+            )));
         }
         self.emit_request_function(request_def, &name, &function_name, &gathered, out);
         self.emit_request_trait_function(request_def, &name, &function_name, &gathered, trait_out);
@@ -774,19 +1006,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
         if let Some(ref reply) = request_def.reply {
             let reply_struct_name = format!("{}Reply", name);
-            enum_cases.reply_variants.push(format!(
+            enum_cases.reply_variants.push(with_cfg(format!(
                 "{ns_prefix}{name}({header}::{name}Reply),",
                 ns_prefix = ns_prefix,
                 name = name,
                 header = self.ns.header,
-            ));
-            enum_cases.reply_parse_cases.push(format!(
+            )));
+            enum_cases.reply_parse_cases.push(with_cfg(format!(
                 "Request::{ns_prefix}{name}(_) => Some({header}::{name}Request::parse_reply),",
                 ns_prefix = ns_prefix,
                 name = name,
                 header = self.ns.header,
-            ));
-            enum_cases.reply_from_cases.push(format!(
+            )));
+            enum_cases.reply_from_cases.push(with_cfg(format!(
                 r#"impl From<{header}::{name}Reply> for Reply {{
   fn from(reply: {header}::{name}Reply) -> Reply {{
     Reply::{ns_prefix}{name}(reply)
@@ -795,7 +1027,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 ns_prefix = ns_prefix,
                 name = name,
                 header = self.ns.header,
-            ));
+            )));
             let reply_fields = reply.fields.borrow();
             let mut reply_derives = Derives::all();
             self.filter_derives_for_fields(&mut reply_derives, &*reply_fields, false);
@@ -816,26 +1048,295 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
             outln!(out, "");
         } else {
-            enum_cases.reply_parse_cases.push(format!(
+            enum_cases.reply_parse_cases.push(with_cfg(format!(
                 "Request::{ns_prefix}{name}(_) => None,",
                 ns_prefix = ns_prefix,
                 name = name,
-            ));
+            )));
         }
 
         if gathered.needs_lifetime {
-            enum_cases.request_into_owned_cases.push(format!(
+            enum_cases.request_into_owned_cases.push(with_cfg(format!(
                 "Request::{ns_prefix}{name}(req) => Request::{ns_prefix}{name}(req.into_owned()),",
                 ns_prefix = ns_prefix,
                 name = name,
-            ));
+            )));
         } else {
-            enum_cases.request_into_owned_cases.push(format!(
+            enum_cases.request_into_owned_cases.push(with_cfg(format!(
                 "Request::{ns_prefix}{name}(req) => Request::{ns_prefix}{name}(req),",
                 ns_prefix = ns_prefix,
                 name = name,
-            ));
+            )));
+        }
+
+        let request_out = add_cfg_to_items(request_out.into_data(), request_cfg.as_deref());
+        let request_trait_out =
+            add_cfg_to_items(request_trait_out.into_data(), request_cfg.as_deref());
+        out!(final_out, "{}", request_out);
+        out!(final_trait_out, "{}", request_trait_out);
+    }
+
+    /// Generate a wrapper type that frees a resource in `Drop`.
+    fn generate_resource_wrapper(&self, info: &ResourceInfo, out: &mut Output) {
+        let resource = info.resource_name;
+        let lower_name = super::camel_case_to_lower_snake(resource);
+        let wrapper = format!("{}Wrapper", resource);
+        let free_function = super::camel_case_to_lower_snake(&to_rust_type_name(info.free_request));
+        let request_defs = self.ns.request_defs.borrow();
+        let free_request = &request_defs[info.free_request];
+        let free_fields = gather_deducible_fields(&*free_request.fields.borrow());
+        let free_args = self.gather_request_fields(free_request, &free_fields).args;
+        assert_eq!(free_args.len(), 1);
+
+        let mut wrapper_out = Output::new();
+        let out2 = &mut wrapper_out;
+        outln!(out2, "/// A RAII-like wrapper around a [`{}`].", resource);
+        outln!(out2, "///");
+        outln!(
+            out2,
+            "/// Instances of this struct represent a `{}` that is freed in `Drop` with [`{}`].",
+            resource,
+            free_function,
+        );
+        outln!(out2, "///");
+        outln!(
+            out2,
+            "/// Any errors during `Drop` are silently ignored. Most likely an error here means \
+             that the X11"
+        );
+        outln!(
+            out2,
+            "/// connection is broken and later requests will also fail."
+        );
+        outln!(out2, "#[derive(Debug)]");
+        outln!(
+            out2,
+            "pub struct {}<'c, C: RequestConnection + ?Sized>(&'c C, {});",
+            wrapper,
+            resource,
+        );
+        outln!(out2, "");
+        outln!(
+            out2,
+            "impl<'c, C: RequestConnection + ?Sized> {}<'c, C> {{",
+            wrapper,
+        );
+        out2.indented(|out| {
+            outln!(
+                out,
+                "/// Assume ownership of the given resource and free it in `Drop`."
+            );
+            outln!(
+                out,
+                "pub fn for_{}(conn: &'c C, id: {}) -> Self {{",
+                lower_name,
+                resource,
+            );
+            outln!(out.indent(), "{}(conn, id)", wrapper);
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(out, "/// Get the XID of the wrapped resource.");
+            outln!(out, "pub fn {}(&self) -> {} {{", lower_name, resource);
+            outln!(out.indent(), "self.1");
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(
+                out,
+                "/// Take the XID of the wrapped resource without freeing the resource."
+            );
+            outln!(out, "pub fn into_{}(self) -> {} {{", lower_name, resource);
+            out.indented(|out| {
+                outln!(out, "let id = self.1;");
+                outln!(out, "std::mem::forget(self);");
+                outln!(out, "id");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out2, "}}");
+        outln!(out2, "");
+
+        outln!(
+            out2,
+            "impl<'c, C: {}::connection::Connection + ?Sized> {}<'c, C> {{",
+            self.crate_name,
+            wrapper,
+        );
+        out2.indented(|out| {
+            for (i, create) in info.create_requests.iter().enumerate() {
+                if i != 0 {
+                    outln!(out, "");
+                }
+                self.emit_resource_wrapper_constructor(info, create, &request_defs, out);
+            }
+        });
+        outln!(out2, "}}");
+        outln!(out2, "");
+
+        outln!(
+            out2,
+            "impl<C: RequestConnection + ?Sized> From<&{}<'_, C>> for {} {{",
+            wrapper,
+            resource,
+        );
+        out2.indented(|out| {
+            outln!(out, "fn from(from: &{}<'_, C>) -> Self {{", wrapper);
+            outln!(out.indent(), "from.1");
+            outln!(out, "}}");
+        });
+        outln!(out2, "}}");
+        outln!(out2, "");
+
+        outln!(
+            out2,
+            "impl<C: RequestConnection + ?Sized> Drop for {}<'_, C> {{",
+            wrapper,
+        );
+        out2.indented(|out| {
+            outln!(out, "fn drop(&mut self) {{");
+            outln!(out.indent(), "let _ = {}(self.0, self.1);", free_function);
+            outln!(out, "}}");
+        });
+        outln!(out2, "}}");
+        outln!(out2, "");
+
+        let cfg = match self.target {
+            Target::X11rb => Some("#[cfg(not(feature = \"request-pruning\"))]"),
+            Target::OutOfTree(_) => None,
+        };
+        let wrapper_out = add_cfg_to_items(wrapper_out.into_data(), cfg);
+        out!(out, "{}", wrapper_out);
+    }
+
+    /// Generate the functions of a resource wrapper that create a new resource.
+    fn emit_resource_wrapper_constructor(
+        &self,
+        info: &ResourceInfo,
+        create: &resources::CreateInfo,
+        request_defs: &HashMap<String, Rc<xcbdefs::RequestDef>>,
+        out: &mut Output,
+    ) {
+        let request_def = &request_defs[create.request_name];
+        assert!(request_def.reply.is_none());
+        let function_name =
+            super::camel_case_to_lower_snake(&to_rust_type_name(create.request_name));
+        let deducible_fields = gather_deducible_fields(&*request_def.fields.borrow());
+        let gathered = self.gather_request_fields(request_def, &deducible_fields);
+        assert!(gathered
+            .args
+            .iter()
+            .any(|(name, _)| name == create.created_field));
+        assert!(gathered.generics.iter().all(|(name, _)| name != "C"));
+
+        let mut generic_params = Vec::new();
+        if gathered.needs_lifetime {
+            generic_params.push("'input".to_string());
+        }
+        generic_params.extend(gathered.generics.iter().map(|(name, _)| name.clone()));
+        let generic_params = if generic_params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", generic_params.join(", "))
+        };
+
+        let mut args = String::from("conn: &'c C");
+        let mut call_args = String::from("conn");
+        let mut forward_args = String::from("conn");
+        for (arg_name, arg_type) in gathered.args.iter() {
+            call_args.push_str(", ");
+            call_args.push_str(arg_name);
+            if arg_name != create.created_field {
+                args.push_str(", ");
+                args.push_str(arg_name);
+                args.push_str(": ");
+                args.push_str(&arg_type.as_argument());
+                forward_args.push_str(", ");
+                forward_args.push_str(arg_name);
+            }
         }
+        let where_clause = |out: &mut Output| {
+            if !gathered.generics.is_empty() {
+                outln!(out, "where");
+                for (param_name, where_) in gathered.generics.iter() {
+                    outln!(out.indent(), "{}: {},", param_name, where_);
+                }
+            }
+        };
+
+        outln!(
+            out,
+            "/// Create a new `{}` and return a wrapper and a cookie.",
+            info.resource_name,
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is a thin wrapper around [`{}`] that allocates an XID for the new \
+             resource.",
+            function_name,
+        );
+        outln!(
+            out,
+            "/// The returned wrapper frees the resource in `Drop`. The cookie comes from the \
+             request."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Errors can come from allocating the XID or from sending the request."
+        );
+        outln!(
+            out,
+            "pub fn {}_and_get_cookie{}({}) -> Result<(Self, VoidCookie<'c, C>), \
+             {}::errors::ReplyOrIdError>",
+            function_name,
+            generic_params,
+            args,
+            self.crate_name,
+        );
+        where_clause(out);
+        outln!(out, "{{");
+        out.indented(|out| {
+            outln!(out, "let {} = conn.generate_id()?;", create.created_field);
+            outln!(out, "let cookie = {}({})?;", function_name, call_args);
+            outln!(
+                out,
+                "Ok((Self::for_{}(conn, {}), cookie))",
+                super::camel_case_to_lower_snake(info.resource_name),
+                create.created_field,
+            );
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(
+            out,
+            "/// Create a new `{}` and return a wrapper.",
+            info.resource_name,
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Like [`Self::{}_and_get_cookie`], but errors from the request are handled as \
+             events.",
+            function_name,
+        );
+        outln!(
+            out,
+            "pub fn {}{}({}) -> Result<Self, {}::errors::ReplyOrIdError>",
+            function_name,
+            generic_params,
+            args,
+            self.crate_name,
+        );
+        where_clause(out);
+        outln!(out, "{{");
+        outln!(
+            out.indent(),
+            "Ok(Self::{}_and_get_cookie({})?.0)",
+            function_name,
+            forward_args,
+        );
+        outln!(out, "}}");
     }
 
     fn generate_aux(
@@ -906,533 +1407,815 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "");
     }
 
-    fn emit_request_struct(
+    /// Emit a method that serializes a request.
+    ///
+    /// See `SerializeMode` for the kinds of methods. Returns the number of pieces that
+    /// `serialize()` produces.
+    fn emit_request_serialize(
         &self,
         request_def: &xcbdefs::RequestDef,
         name: &str,
         deducible_fields: &HashMap<String, DeducibleField>,
         gathered: &GatheredRequestFields,
+        mode: SerializeMode,
         out: &mut Output,
-    ) {
+    ) -> usize {
         let ns = request_def.namespace.upgrade().unwrap();
         let is_xproto = ns.header == "xproto";
-        let is_send_event = is_xproto && request_def.name == "SendEvent";
+        let serialize_lifetime_block = if gathered.needs_lifetime {
+            ""
+        } else {
+            "'input, "
+        };
 
-        if let Some(ref doc) = request_def.doc {
-            self.emit_doc(doc, out);
+        match mode {
+            SerializeMode::Pieces => {
+                outln!(
+                    out,
+                    "/// Serialize this request into bytes for the provided connection",
+                );
+                outln!(
+                    out,
+                    "fn serialize<{lifetime}Conn>(self, conn: &Conn) -> {return_type}",
+                    lifetime = serialize_lifetime_block,
+                    return_type = if is_xproto {
+                        "BufWithFds<PiecewiseBuf<'input>>"
+                    } else {
+                        "Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>"
+                    }
+                );
+                outln!(out, "where");
+                outln!(out.indent(), "Conn: RequestConnection + ?Sized,");
+            }
+            SerializeMode::Write => {
+                outln!(
+                    out,
+                    "/// Pass the bytes of this request to `write`, without the length field",
+                );
+                outln!(
+                    out,
+                    "fn write_to(self, {}write: &mut dyn FnMut(&[u8]))",
+                    if is_xproto { "" } else { "major_opcode: u8, " },
+                );
+            }
         }
+        let mut request_slices = Vec::new();
+        outln!(out, "{{");
+        out.indented(|out| {
+            if mode == SerializeMode::Write {
+                // The opcode comes from the caller
+            } else if ns.ext_info.is_some() {
+                outln!(
+                    out,
+                    "let extension_information = \
+                     conn.extension_information(X11_EXTENSION_NAME)?",
+                );
+                outln!(
+                    out.indent(),
+                    ".ok_or(ConnectionError::UnsupportedExtension)?;"
+                );
+            } else {
+                // Silence a warning about an unused `conn`.
+                outln!(out, "let _ = conn;");
+            }
 
-        let mut derives = Derives::all();
-        self.filter_derives_for_fields(&mut derives, &request_def.fields.borrow(), true);
-        let derives = derives.to_list();
-        if !derives.is_empty() {
-            outln!(out, "#[derive({})]", derives.join(", "));
-        }
-
-        let (struct_lifetime_block, serialize_lifetime_block, parse_lifetime_block) =
-            if gathered.needs_lifetime {
-                ("<'input>", "", "'input ")
-            } else {
-                ("", "'input, ", "")
-            };
-
-        let has_members = !gathered.request_args.is_empty();
-        let has_cows = gathered
-            .request_args
-            .iter()
-            .any(|arg| arg.1.needs_any_cow());
-        let members_start = if has_members { " {" } else { ";" };
-        outln!(
-            out,
-            "pub struct {name}Request{lifetime}{members_start}",
-            name = name,
-            lifetime = struct_lifetime_block,
-            members_start = members_start
-        );
-        out.indented(|out| {
-            for (member_name, member_type) in gathered.request_args.iter() {
-                outln!(out, "pub {name}: {type},",
-                       name=member_name,
-                       type=member_type.as_field());
-            }
-        });
-        if has_members {
-            outln!(out, "}}",);
-        }
+            let fields = request_def.fields.borrow();
 
-        // Methods implemented on every request
-        outln!(
-            out,
-            "impl{lifetime} {name}Request{lifetime} {{",
-            lifetime = struct_lifetime_block,
-            name = name
-        );
-        out.indented(|out| {
-            outln!(
-                out,
-                "/// Serialize this request into bytes for the provided connection",
-            );
-            outln!(
-                out,
-                "fn serialize<{lifetime}Conn>(self, conn: &Conn) -> {return_type}",
-                lifetime = serialize_lifetime_block,
-                return_type = if is_xproto {
-                    "BufWithFds<PiecewiseBuf<'input>>"
+            let has_expr_fields = fields.iter().any(|field| {
+                if let xcbdefs::FieldDef::Expr(_) = field {
+                    true
                 } else {
-                    "Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>"
+                    false
                 }
-            );
-            outln!(out, "where");
-            outln!(out.indent(), "Conn: RequestConnection + ?Sized,");
-            outln!(out, "{{");
-            out.indented(|out| {
-                if ns.ext_info.is_some() {
-                    outln!(
-                        out,
-                        "let extension_information = \
-                         conn.extension_information(X11_EXTENSION_NAME)?",
-                    );
-                    outln!(
-                        out.indent(),
-                        ".ok_or(ConnectionError::UnsupportedExtension)?;"
-                    );
-                } else {
-                    // Silence a warning about an unused `conn`.
-                    outln!(out, "let _ = conn;");
+            });
+            // Calculate `VirtualLen` field values because they
+            // may be used by <exprfield>s.
+            if has_expr_fields {
+                for field in fields.iter() {
+                    if let xcbdefs::FieldDef::VirtualLen(virtual_len_field) = field {
+                        outln!(
+                            out,
+                            "let {} = u32::try_from(self.{}.len()).unwrap();",
+                            to_rust_variable_name(&virtual_len_field.name),
+                            to_rust_variable_name(&virtual_len_field.list_name),
+                        );
+                    }
                 }
+            }
 
-                let fields = request_def.fields.borrow();
+            outln!(out, "let length_so_far = 0;");
 
-                let has_expr_fields = fields.iter().any(|field| {
-                    if let xcbdefs::FieldDef::Expr(_) = field {
-                        true
-                    } else {
-                        false
-                    }
-                });
-                // Calculate `VirtualLen` field values because they
-                // may be used by <exprfield>s.
-                if has_expr_fields {
-                    for field in fields.iter() {
-                        if let xcbdefs::FieldDef::VirtualLen(virtual_len_field) = field {
+            let mut request_size = fields
+                .iter()
+                .try_fold(0, |sum, field| Some(sum + field.size()?));
+
+            let request_slices = &mut request_slices;
+            let mut fixed_fields_bytes = Vec::new();
+            let mut num_fixed_len_slices = 0;
+            let mut pad_count = 0;
+
+            for (field_i, field) in fields.iter().enumerate() {
+                let mut next_slice = None;
+
+                let mut tmp_out = Output::new();
+                self.emit_assert_for_field_serialize(
+                    field,
+                    deducible_fields,
+                    |field_name| {
+                        let rust_field_name = to_rust_variable_name(field_name);
+                        if !deducible_fields.contains_key(field_name) {
+                            format!("self.{}", rust_field_name)
+                        } else {
+                            rust_field_name
+                        }
+                    },
+                    &mut tmp_out,
+                );
+                match field {
+                    xcbdefs::FieldDef::Pad(pad_field) => match pad_field.kind {
+                        xcbdefs::PadKind::Bytes(bytes) => {
+                            for _ in 0..bytes {
+                                fixed_fields_bytes.push(String::from("0"));
+                            }
+                        }
+                        xcbdefs::PadKind::Align(align) => {
                             outln!(
-                                out,
-                                "let {} = u32::try_from(self.{}.len()).unwrap();",
-                                to_rust_variable_name(&virtual_len_field.name),
-                                to_rust_variable_name(&virtual_len_field.list_name),
+                                tmp_out,
+                                "let padding{} = &[0; {}][..({} - (length_so_far % {})) % {}];",
+                                pad_count,
+                                align - 1,
+                                align,
+                                align,
+                                align,
                             );
+                            next_slice =
+                                Some((format!("padding{}", pad_count), IovecConversion::Into));
+                            pad_count += 1;
                         }
-                    }
-                }
-
-                outln!(out, "let length_so_far = 0;");
-
-                let mut request_size = fields
-                    .iter()
-                    .try_fold(0, |sum, field| Some(sum + field.size()?));
-
-                let mut request_slices = Vec::new();
-                let mut fixed_fields_bytes = Vec::new();
-                let mut num_fixed_len_slices = 0;
-                let mut pad_count = 0;
-
-                for (field_i, field) in fields.iter().enumerate() {
-                    let mut next_slice = None;
-
-                    let mut tmp_out = Output::new();
-                    self.emit_assert_for_field_serialize(
-                        field,
-                        deducible_fields,
-                        |field_name| {
-                            let rust_field_name = to_rust_variable_name(field_name);
-                            if !deducible_fields.contains_key(field_name) {
-                                format!("self.{}", rust_field_name)
+                    },
+                    xcbdefs::FieldDef::Normal(normal_field) => {
+                        if normal_field.name == "major_opcode" {
+                            if ns.ext_info.is_some() && mode == SerializeMode::Write {
+                                fixed_fields_bytes.push(String::from("major_opcode"));
+                            } else if ns.ext_info.is_some() {
+                                fixed_fields_bytes
+                                    .push(String::from("extension_information.major_opcode"));
                             } else {
-                                rust_field_name
-                            }
-                        },
-                        &mut tmp_out,
-                    );
-                    match field {
-                        xcbdefs::FieldDef::Pad(pad_field) => match pad_field.kind {
-                            xcbdefs::PadKind::Bytes(bytes) => {
-                                for _ in 0..bytes {
-                                    fixed_fields_bytes.push(String::from("0"));
-                                }
-                            }
-                            xcbdefs::PadKind::Align(align) => {
-                                outln!(
-                                    tmp_out,
-                                    "let padding{} = &[0; {}][..({} - (length_so_far % {})) % {}];",
-                                    pad_count,
-                                    align - 1,
-                                    align,
-                                    align,
-                                    align,
-                                );
-                                next_slice =
-                                    Some((format!("padding{}", pad_count), IovecConversion::Into));
-                                pad_count += 1;
-                            }
-                        },
-                        xcbdefs::FieldDef::Normal(normal_field) => {
-                            if normal_field.name == "major_opcode" {
-                                if ns.ext_info.is_some() {
-                                    fixed_fields_bytes
-                                        .push(String::from("extension_information.major_opcode"));
-                                } else {
-                                    fixed_fields_bytes.push(format!(
-                                        "{}_REQUEST",
-                                        super::camel_case_to_upper_snake(&name),
-                                    ));
-                                }
-                            } else if normal_field.name == "minor_opcode" {
-                                assert!(ns.ext_info.is_some());
                                 fixed_fields_bytes.push(format!(
                                     "{}_REQUEST",
                                     super::camel_case_to_upper_snake(&name),
                                 ));
-                            } else if normal_field.name == "length" {
-                                // the actual length will be calculated later
-                                fixed_fields_bytes.push(String::from("0"));
-                                fixed_fields_bytes.push(String::from("0"));
+                            }
+                        } else if normal_field.name == "minor_opcode" {
+                            assert!(ns.ext_info.is_some());
+                            fixed_fields_bytes.push(format!(
+                                "{}_REQUEST",
+                                super::camel_case_to_upper_snake(&name),
+                            ));
+                        } else if normal_field.name == "length" {
+                            // the actual length will be calculated later
+                            fixed_fields_bytes.push(String::from("0"));
+                            fixed_fields_bytes.push(String::from("0"));
+                        } else {
+                            let rust_field_name = to_rust_variable_name(&normal_field.name);
+
+                            let was_deduced = if let Some(deducible_field) =
+                                deducible_fields.get(&normal_field.name)
+                            {
+                                self.emit_calc_deducible_field(
+                                    field,
+                                    deducible_field,
+                                    |field_name| {
+                                        let rust_field_name = to_rust_variable_name(field_name);
+                                        if !deducible_fields.contains_key(field_name) {
+                                            format!("self.{}", rust_field_name)
+                                        } else {
+                                            rust_field_name
+                                        }
+                                    },
+                                    &rust_field_name,
+                                    out,
+                                );
+                                true
                             } else {
-                                let rust_field_name = to_rust_variable_name(&normal_field.name);
+                                false
+                            };
 
-                                let was_deduced = if let Some(deducible_field) =
-                                    deducible_fields.get(&normal_field.name)
-                                {
-                                    self.emit_calc_deducible_field(
-                                        field,
-                                        deducible_field,
-                                        |field_name| {
-                                            let rust_field_name = to_rust_variable_name(field_name);
-                                            if !deducible_fields.contains_key(field_name) {
-                                                format!("self.{}", rust_field_name)
-                                            } else {
-                                                rust_field_name
-                                            }
-                                        },
-                                        &rust_field_name,
-                                        out,
-                                    );
-                                    true
+                            let bytes_name = postfix_var_name(&rust_field_name, "bytes");
+                            if let Some(field_size) = normal_field.type_.size() {
+                                let field_name = if was_deduced {
+                                    // If we deduced this value it comes from a local.
+                                    rust_field_name
                                 } else {
-                                    false
+                                    // Otherwise a member.
+                                    format!("self.{}", rust_field_name)
                                 };
 
-                                let bytes_name = postfix_var_name(&rust_field_name, "bytes");
-                                if let Some(field_size) = normal_field.type_.size() {
-                                    let field_name = if was_deduced {
-                                        // If we deduced this value it comes from a local.
-                                        rust_field_name
-                                    } else {
-                                        // Otherwise a member.
-                                        format!("self.{}", rust_field_name)
-                                    };
-
-                                    outln!(
-                                        out,
-                                        "let {} = {};",
-                                        bytes_name,
-                                        self.emit_value_serialize(
-                                            &normal_field.type_,
-                                            &field_name,
-                                            was_deduced,
-                                        ),
-                                    );
-                                    for i in 0..field_size {
-                                        fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
-                                    }
-                                } else {
-                                    outln!(
-                                        tmp_out,
-                                        "let {} = self.{}.serialize();",
-                                        bytes_name,
-                                        rust_field_name,
-                                    );
-                                    next_slice = Some((bytes_name, IovecConversion::Into));
-                                }
-                            }
-                        }
-                        xcbdefs::FieldDef::List(list_field) => {
-                            let rust_field_name = to_rust_variable_name(&list_field.name);
-                            let list_length = list_field.length();
-                            if self.rust_value_type_is_u8(&list_field.element_type) {
-                                let conversion = if list_length.is_some() {
-                                    // If this is a fixed length array we need to erase its length.
-                                    IovecConversion::CowStripLength
-                                } else {
-                                    IovecConversion::None
-                                };
-                                next_slice =
-                                    Some((format!("self.{}", rust_field_name), conversion));
-                            } else {
-                                assert_eq!(
-                                    list_length, None,
-                                    "fixed length arrays in requests must be [u8; N]"
+                                outln!(
+                                    out,
+                                    "let {} = {};",
+                                    bytes_name,
+                                    self.emit_value_serialize(
+                                        &normal_field.type_,
+                                        &field_name,
+                                        was_deduced,
+                                    ),
                                 );
-                                if self.can_use_simple_list_parsing(&list_field.element_type) {
-                                    let bytes_name = postfix_var_name(&rust_field_name, "bytes");
-                                    outln!(
-                                        tmp_out,
-                                        "let {} = self.{}.serialize();",
-                                        bytes_name,
-                                        rust_field_name,
-                                    );
-                                    next_slice = Some((bytes_name, IovecConversion::Into));
-                                } else {
-                                    let bytes_name = postfix_var_name(&rust_field_name, "bytes");
-                                    outln!(tmp_out, "let mut {} = Vec::new();", bytes_name);
-                                    outln!(tmp_out, "for element in {}.iter() {{", rust_field_name);
-                                    tmp_out.indented(|tmp_out| {
-                                        self.emit_value_serialize_into(
-                                            &list_field.element_type,
-                                            "element",
-                                            false,
-                                            &bytes_name,
-                                            tmp_out,
-                                        );
-                                    });
-                                    outln!(tmp_out, "}}");
-                                    next_slice = Some((bytes_name, IovecConversion::Into));
-                                }
-                            }
-                        }
-                        xcbdefs::FieldDef::Switch(switch_field) => {
-                            let rust_field_name = to_rust_variable_name(&switch_field.name);
-                            let bytes_name = postfix_var_name(&rust_field_name, "bytes");
-                            outln!(
-                                tmp_out,
-                                "let {} = self.{}.serialize({});",
-                                bytes_name,
-                                rust_field_name,
-                                self.ext_params_to_call_args(
-                                    false,
-                                    |name| {
-                                        if deducible_fields.get(name).is_some() {
-                                            to_rust_variable_name(name)
-                                        } else {
-                                            format!("self.{}", to_rust_variable_name(name))
-                                        }
-                                    },
-                                    &*switch_field.external_params.borrow(),
-                                )
-                            );
-                            if let Some(field_size) = switch_field.size() {
                                 for i in 0..field_size {
                                     fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
                                 }
                             } else {
+                                outln!(
+                                    tmp_out,
+                                    "let {} = self.{}.serialize();",
+                                    bytes_name,
+                                    rust_field_name,
+                                );
                                 next_slice = Some((bytes_name, IovecConversion::Into));
                             }
                         }
-                        xcbdefs::FieldDef::Fd(_) => {}
-                        xcbdefs::FieldDef::FdList(_) => {}
-                        xcbdefs::FieldDef::Expr(expr_field) => {
-                            let rust_field_name = to_rust_variable_name(&expr_field.name);
-                            let bytes_name = postfix_var_name(&rust_field_name, "bytes");
-                            let type_ = self.field_value_type_to_rust_type(&expr_field.type_);
-                            if type_ == "bool" {
+                    }
+                    xcbdefs::FieldDef::List(list_field) => {
+                        let rust_field_name = to_rust_variable_name(&list_field.name);
+                        let list_length = list_field.length();
+                        if self.rust_value_type_is_u8(&list_field.element_type) {
+                            let conversion = if list_length.is_some() {
+                                // If this is a fixed length array we need to erase its length.
+                                IovecConversion::CowStripLength
+                            } else {
+                                IovecConversion::None
+                            };
+                            next_slice = Some((format!("self.{}", rust_field_name), conversion));
+                        } else {
+                            assert_eq!(
+                                list_length, None,
+                                "fixed length arrays in requests must be [u8; N]"
+                            );
+                            if self.can_use_simple_list_parsing(&list_field.element_type) {
+                                let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                                 outln!(
-                                    out,
-                                    "let {} = {} != 0;",
+                                    tmp_out,
+                                    "let {} = self.{}.serialize();",
+                                    bytes_name,
                                     rust_field_name,
-                                    self.expr_to_str(
-                                        &expr_field.expr,
-                                        to_rust_variable_name,
-                                        true,
-                                        true,
-                                        true,
-                                    ),
                                 );
+                                next_slice = Some((bytes_name, IovecConversion::Into));
                             } else {
-                                // the only case found in the XML definitions is with a bool
-                                unreachable!();
+                                let bytes_name = postfix_var_name(&rust_field_name, "bytes");
+                                outln!(tmp_out, "let mut {} = Vec::new();", bytes_name);
+                                outln!(tmp_out, "for element in {}.iter() {{", rust_field_name);
+                                tmp_out.indented(|tmp_out| {
+                                    self.emit_value_serialize_into(
+                                        &list_field.element_type,
+                                        "element",
+                                        false,
+                                        &bytes_name,
+                                        tmp_out,
+                                    );
+                                });
+                                outln!(tmp_out, "}}");
+                                next_slice = Some((bytes_name, IovecConversion::Into));
+                            }
+                        }
+                    }
+                    xcbdefs::FieldDef::Switch(switch_field) => {
+                        let rust_field_name = to_rust_variable_name(&switch_field.name);
+                        let bytes_name = postfix_var_name(&rust_field_name, "bytes");
+                        outln!(
+                            tmp_out,
+                            "let {} = self.{}.serialize({});",
+                            bytes_name,
+                            rust_field_name,
+                            self.ext_params_to_call_args(
+                                false,
+                                |name| {
+                                    if deducible_fields.get(name).is_some() {
+                                        to_rust_variable_name(name)
+                                    } else {
+                                        format!("self.{}", to_rust_variable_name(name))
+                                    }
+                                },
+                                &*switch_field.external_params.borrow(),
+                            )
+                        );
+                        if let Some(field_size) = switch_field.size() {
+                            for i in 0..field_size {
+                                fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
                             }
-                            let field_size = expr_field.type_.size().unwrap();
+                        } else {
+                            next_slice = Some((bytes_name, IovecConversion::Into));
+                        }
+                    }
+                    xcbdefs::FieldDef::Fd(_) => {}
+                    xcbdefs::FieldDef::FdList(_) => {}
+                    xcbdefs::FieldDef::Expr(expr_field) => {
+                        let rust_field_name = to_rust_variable_name(&expr_field.name);
+                        let bytes_name = postfix_var_name(&rust_field_name, "bytes");
+                        let type_ = self.field_value_type_to_rust_type(&expr_field.type_);
+                        if type_ == "bool" {
                             outln!(
                                 out,
-                                "let {} = {};",
-                                bytes_name,
-                                self.emit_value_serialize(
-                                    &expr_field.type_,
-                                    &rust_field_name,
-                                    false,
+                                "let {} = {} != 0;",
+                                rust_field_name,
+                                self.expr_to_str(
+                                    &expr_field.expr,
+                                    to_rust_variable_name,
+                                    true,
+                                    true,
+                                    true,
                                 ),
                             );
-                            for i in 0..field_size {
-                                fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
-                            }
+                        } else {
+                            // the only case found in the XML definitions is with a bool
+                            unreachable!();
+                        }
+                        let field_size = expr_field.type_.size().unwrap();
+                        outln!(
+                            out,
+                            "let {} = {};",
+                            bytes_name,
+                            self.emit_value_serialize(&expr_field.type_, &rust_field_name, false,),
+                        );
+                        for i in 0..field_size {
+                            fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
                         }
-                        xcbdefs::FieldDef::VirtualLen(_) => {}
                     }
+                    xcbdefs::FieldDef::VirtualLen(_) => {}
+                }
 
-                    // The XML does not describe trailing padding in requests. Requests
-                    // are implicitly padded to a four byte boundary.
-                    if next_slice.is_none() && field_i == (fields.len() - 1) {
-                        if let Some(ref mut request_size) = request_size {
-                            let req_size_rem = *request_size % 4;
-                            if req_size_rem != 0 {
-                                let pad_size = 4 - req_size_rem;
-                                for _ in 0..pad_size {
-                                    fixed_fields_bytes.push(String::from("0"));
-                                }
-                                *request_size += pad_size;
+                // The XML does not describe trailing padding in requests. Requests
+                // are implicitly padded to a four byte boundary.
+                if next_slice.is_none() && field_i == (fields.len() - 1) {
+                    if let Some(ref mut request_size) = request_size {
+                        let req_size_rem = *request_size % 4;
+                        if req_size_rem != 0 {
+                            let pad_size = 4 - req_size_rem;
+                            for _ in 0..pad_size {
+                                fixed_fields_bytes.push(String::from("0"));
                             }
+                            *request_size += pad_size;
                         }
                     }
+                }
 
-                    if next_slice.is_some() || field_i == (fields.len() - 1) {
-                        if !fixed_fields_bytes.is_empty() {
-                            let maybe_mut = if num_fixed_len_slices == 0 {
-                                // contains the length field, which will be modified
-                                "mut "
-                            } else {
-                                ""
-                            };
+                if next_slice.is_some() || field_i == (fields.len() - 1) {
+                    if !fixed_fields_bytes.is_empty() {
+                        let maybe_mut = if num_fixed_len_slices == 0 {
+                            // contains the length field, which will be modified
+                            "mut "
+                        } else {
+                            ""
+                        };
+                        if mode == SerializeMode::Write {
+                            outln!(out, "write(&[");
+                        } else {
                             outln!(
                                 out,
                                 "let {}request{} = vec![",
                                 maybe_mut,
                                 num_fixed_len_slices,
                             );
-                            for byte in fixed_fields_bytes.iter() {
-                                outln!(out.indent(), "{},", byte);
-                            }
+                        }
+                        for byte in fixed_fields_bytes.iter() {
+                            outln!(out.indent(), "{},", byte);
+                        }
+                        if mode == SerializeMode::Write {
+                            outln!(out, "]);");
+                            outln!(
+                                out,
+                                "let length_so_far = length_so_far + {};",
+                                fixed_fields_bytes.len(),
+                            );
+                        } else {
                             outln!(out, "];");
                             outln!(
                                 out,
                                 "let length_so_far = length_so_far + request{}.len();",
                                 num_fixed_len_slices,
                             );
-                            request_slices.push(format!("request{}.into()", num_fixed_len_slices));
-                            fixed_fields_bytes.clear();
-                            num_fixed_len_slices += 1;
                         }
-                        if let Some((next_slice, conversion)) = next_slice {
+                        request_slices.push(format!("request{}.into()", num_fixed_len_slices));
+                        fixed_fields_bytes.clear();
+                        num_fixed_len_slices += 1;
+                    }
+                    if let Some((next_slice, conversion)) = next_slice {
+                        outln!(
+                            tmp_out,
+                            "let length_so_far = length_so_far + {}.len();",
+                            next_slice,
+                        );
+                        if mode == SerializeMode::Write {
+                            outln!(tmp_out, "write(&{}[..]);", next_slice);
+                        }
+                        match conversion {
+                            IovecConversion::None => request_slices.push(next_slice),
+                            IovecConversion::Into => {
+                                request_slices.push(format!("{}.into()", next_slice))
+                            }
+                            IovecConversion::CowStripLength => {
+                                request_slices.push(format!("Cow::Owned({}.to_vec())", next_slice))
+                            }
+                        }
+                    }
+                }
+
+                out!(out, "{}", tmp_out.into_data());
+            }
+            // The XML does not describe trailing padding in requests. Requests
+            // are implicitly padded to a four byte boundary.
+            if let Some(request_size) = request_size {
+                let req_size_rem = request_size % 4;
+                if req_size_rem != 0 {
+                    assert_eq!(pad_count, 0);
+                    outln!(out, "let padding = [0; {}];", 4 - req_size_rem);
+                    outln!(out, "let length_so_far = length_so_far + padding.len();");
+                    if mode == SerializeMode::Write {
+                        outln!(out, "write(&padding);");
+                    }
+                    request_slices.push(String::from("(&padding).into()"));
+                }
+            } else {
+                outln!(
+                    out,
+                    "let padding{} = &[0; 3][..(4 - (length_so_far % 4)) % 4];",
+                    pad_count,
+                );
+                outln!(
+                    out,
+                    "let length_so_far = length_so_far + padding{}.len();",
+                    pad_count,
+                );
+                if mode == SerializeMode::Write {
+                    outln!(out, "write(padding{});", pad_count);
+                }
+                request_slices.push(format!("padding{}.into()", pad_count));
+            }
+
+            outln!(out, "assert_eq!(length_so_far % 4, 0);");
+            if mode == SerializeMode::Write {
+                // The caller sets the length field
+                return;
+            }
+            // Set the length in the request.
+            // If it does not fit into u16, compute_length_field will use BigRequests.
+            outln!(
+                out,
+                "let length = u16::try_from(length_so_far / 4).unwrap_or(0);",
+            );
+            outln!(
+                out,
+                "request0[2..4].copy_from_slice(&length.to_ne_bytes());",
+            );
+
+            let fds_arg = if gathered.fd_lists.is_empty() {
+                format!(
+                    "vec![{}]",
+                    gathered
+                        .single_fds
+                        .iter()
+                        .enumerate()
+                        .map(|(i, single_fd)| {
+                            let sep = if i == 0 { "" } else { ", " };
+                            format!("{}self.{}", sep, single_fd)
+                        })
+                        .collect::<String>(),
+                )
+            } else if gathered.fd_lists.len() == 1 && gathered.single_fds.is_empty() {
+                format!("self.{}", gathered.fd_lists[0])
+            } else {
+                outln!(out, "let mut fds = Vec::new();");
+                for field in fields.iter() {
+                    match field {
+                        xcbdefs::FieldDef::Fd(fd_field) => {
+                            outln!(out, "fds.push({});", to_rust_variable_name(&fd_field.name));
+                        }
+                        xcbdefs::FieldDef::FdList(fd_list_field) => {
                             outln!(
-                                tmp_out,
-                                "let length_so_far = length_so_far + {}.len();",
-                                next_slice,
+                                out,
+                                "fds.extend({});",
+                                to_rust_variable_name(&fd_list_field.name)
                             );
-                            match conversion {
-                                IovecConversion::None => request_slices.push(next_slice),
-                                IovecConversion::Into => {
-                                    request_slices.push(format!("{}.into()", next_slice))
-                                }
-                                IovecConversion::CowStripLength => request_slices
-                                    .push(format!("Cow::Owned({}.to_vec())", next_slice)),
-                            }
+                        }
+                        _ => {}
+                    }
+                }
+                String::from("fds")
+            };
+
+            let mut slices_arg = String::new();
+            for (i, request_slices) in request_slices.iter().enumerate() {
+                if i != 0 {
+                    slices_arg.push_str(", ");
+                }
+                slices_arg.push_str(request_slices);
+            }
+
+            let result = format!(
+                "(vec![{slices}], {fds})",
+                slices = slices_arg,
+                fds = fds_arg,
+            );
+            if is_xproto {
+                outln!(out, "{}", result);
+            } else {
+                outln!(out, "Ok({})", result);
+            }
+        });
+        outln!(out, "}}");
+        request_slices.len()
+    }
+
+    /// Emit `serialize_into()` and `serialize_into_slice()`, which are based on `write_to()`.
+    fn emit_request_serialize_into(&self, is_xproto: bool, out: &mut Output) {
+        let (opcode_param, opcode_arg) = if is_xproto {
+            ("", "")
+        } else {
+            ("major_opcode: u8, ", "major_opcode, ")
+        };
+        outln!(out, "/// Serialize this request and append it to `bytes`.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Requests that are too long for the normal length field use the BIG-REQUESTS \
+             encoding.",
+        );
+        if !is_xproto {
+            outln!(out, "///");
+            outln!(
+                out,
+                "/// `major_opcode` is the major opcode of the extension on the X11 server.",
+            );
+        }
+        outln!(
+            out,
+            "pub fn serialize_into(self, {}bytes: &mut Vec<u8>) {{",
+            opcode_param,
+        );
+        outln!(
+            out.indent(),
+            "{}::x11_utils::serialize_request_into(bytes, |write| self.write_to({}write));",
+            self.crate_name,
+            opcode_arg,
+        );
+        outln!(out, "}}");
+        outln!(
+            out,
+            "/// Serialize this request into the beginning of `buffer`.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Returns the number of bytes that were written. Nothing is written if the \
+             request does",
+        );
+        outln!(out, "/// not fit into `buffer`.");
+        outln!(
+            out,
+            "pub fn serialize_into_slice(self, {}buffer: &mut [u8]) -> \
+             Result<usize, {}::errors::BufferTooSmall> {{",
+            opcode_param,
+            self.crate_name,
+        );
+        out.indented(|out| {
+            outln!(out, "let wire_length = self.wire_length();");
+            outln!(
+                out,
+                "{}::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {{",
+                self.crate_name,
+            );
+            outln!(out.indent(), "self.write_to({}write)", opcode_arg);
+            outln!(out, "}})");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit a `wire_length()` method that computes the length of the serialized request.
+    ///
+    /// This follows the same steps as the `serialize()` method emitted by
+    /// `emit_request_struct()`, but only adds up the sizes.
+    fn emit_request_wire_length(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        deducible_fields: &HashMap<String, DeducibleField>,
+        out: &mut Output,
+    ) {
+        let fields = request_def.fields.borrow();
+        let request_size = fields
+            .iter()
+            .try_fold(0, |sum, field| Some(sum + field.size()?));
+
+        // The length is the sum of `terms` and `fixed_size`
+        let mut terms: Vec<String> = Vec::new();
+        let mut fixed_size = 0;
+        fn push(terms: &mut Vec<String>, fixed_size: &mut u32, term: String) {
+            if *fixed_size != 0 {
+                terms.push(fixed_size.to_string());
+                *fixed_size = 0;
+            }
+            terms.push(term);
+        }
+        fn sum(terms: &[String], fixed_size: u32) -> String {
+            let mut terms = terms.to_vec();
+            if fixed_size != 0 || terms.is_empty() {
+                terms.push(fixed_size.to_string());
+            }
+            terms.join(" + ")
+        }
+
+        outln!(
+            out,
+            "/// Get the length in bytes of this request when it is sent to the X11 server."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// A request can only be sent if this is at most \
+             [`RequestConnection::maximum_request_bytes()`]."
+        );
+        outln!(
+            out,
+            "/// The four bytes that the BIG-REQUESTS extension adds to long requests are not \
+             included."
+        );
+        outln!(out, "pub fn wire_length(&self) -> usize {{");
+        out.indented(|out| {
+            for field in fields.iter() {
+                if let Some(size) = field.size() {
+                    fixed_size += size;
+                    continue;
+                }
+                match field {
+                    xcbdefs::FieldDef::Pad(pad_field) => {
+                        if let xcbdefs::PadKind::Align(align) = pad_field.kind {
+                            outln!(out, "let length_so_far = {};", sum(&terms, fixed_size));
+                            terms = vec![
+                                String::from("length_so_far"),
+                                format!("({} - (length_so_far % {})) % {}", align, align, align),
+                            ];
+                            fixed_size = 0;
                         }
                     }
-
-                    out!(out, "{}", tmp_out.into_data());
-                }
-                // The XML does not describe trailing padding in requests. Requests
-                // are implicitly padded to a four byte boundary.
-                if let Some(request_size) = request_size {
-                    let req_size_rem = request_size % 4;
-                    if req_size_rem != 0 {
-                        assert_eq!(pad_count, 0);
-                        outln!(out, "let padding = [0; {}];", 4 - req_size_rem);
-                        outln!(out, "let length_so_far = length_so_far + padding.len();");
-                        request_slices.push(String::from("(&padding).into()"));
+                    xcbdefs::FieldDef::Normal(normal_field) => {
+                        let rust_field_name = to_rust_variable_name(&normal_field.name);
+                        push(&mut terms, &mut fixed_size, format!("self.{}.serialize().len()", rust_field_name));
                     }
-                } else {
-                    outln!(
-                        out,
-                        "let padding{} = &[0; 3][..(4 - (length_so_far % 4)) % 4];",
-                        pad_count,
-                    );
-                    outln!(
-                        out,
-                        "let length_so_far = length_so_far + padding{}.len();",
-                        pad_count,
-                    );
-                    request_slices.push(format!("padding{}.into()", pad_count));
-                }
-
-                outln!(out, "assert_eq!(length_so_far % 4, 0);");
-                // Set the length in the request.
-                // If it does not fit into u16, compute_length_field will use BigRequests.
-                outln!(
-                    out,
-                    "let length = u16::try_from(length_so_far / 4).unwrap_or(0);",
-                );
-                outln!(
-                    out,
-                    "request0[2..4].copy_from_slice(&length.to_ne_bytes());",
-                );
-
-                let fds_arg = if gathered.fd_lists.is_empty() {
-                    format!(
-                        "vec![{}]",
-                        gathered
-                            .single_fds
-                            .iter()
-                            .enumerate()
-                            .map(|(i, single_fd)| {
-                                let sep = if i == 0 { "" } else { ", " };
-                                format!("{}self.{}", sep, single_fd)
-                            })
-                            .collect::<String>(),
-                    )
-                } else if gathered.fd_lists.len() == 1 && gathered.single_fds.is_empty() {
-                    format!("self.{}", gathered.fd_lists[0])
-                } else {
-                    outln!(out, "let mut fds = Vec::new();");
-                    for field in fields.iter() {
-                        match field {
-                            xcbdefs::FieldDef::Fd(fd_field) => {
-                                outln!(out, "fds.push({});", to_rust_variable_name(&fd_field.name));
+                    xcbdefs::FieldDef::List(list_field) => {
+                        let rust_field_name = to_rust_variable_name(&list_field.name);
+                        if let Some(element_size) = list_field.element_type.size() {
+                            if element_size == 1 {
+                                push(&mut terms, &mut fixed_size, format!("self.{}.len()", rust_field_name));
+                            } else {
+                                push(&mut terms, &mut fixed_size, format!(
+                                    "self.{}.len() * {}",
+                                    rust_field_name, element_size
+                                ));
                             }
-                            xcbdefs::FieldDef::FdList(fd_list_field) => {
-                                outln!(
+                        } else {
+                            push(&mut terms, &mut fixed_size, format!(
+                                "self.{}.iter().map(|element| element.serialize().len()).sum::<usize>()",
+                                rust_field_name,
+                            ));
+                        }
+                    }
+                    xcbdefs::FieldDef::Switch(switch_field) => {
+                        let external_params = switch_field.external_params.borrow();
+                        // Deduced switch parameters are computed from other members
+                        for ext_param in external_params.iter() {
+                            if let Some(deducible_field) = deducible_fields.get(&ext_param.name) {
+                                let param_field = fields
+                                    .iter()
+                                    .find(|field| field.name() == Some(&ext_param.name))
+                                    .unwrap();
+                                self.emit_calc_deducible_field(
+                                    param_field,
+                                    deducible_field,
+                                    |field_name| format!("self.{}", to_rust_variable_name(field_name)),
+                                    &to_rust_variable_name(&ext_param.name),
                                     out,
-                                    "fds.extend({});",
-                                    to_rust_variable_name(&fd_list_field.name)
                                 );
                             }
-                            _ => {}
                         }
+                        push(&mut terms, &mut fixed_size, format!(
+                            "self.{}.serialize({}).len()",
+                            to_rust_variable_name(&switch_field.name),
+                            self.ext_params_to_call_args(
+                                false,
+                                |name| {
+                                    if deducible_fields.get(name).is_some() {
+                                        to_rust_variable_name(name)
+                                    } else {
+                                        format!("self.{}", to_rust_variable_name(name))
+                                    }
+                                },
+                                &*external_params,
+                            ),
+                        ));
                     }
-                    String::from("fds")
-                };
-
-                let mut slices_arg = String::new();
-                for (i, request_slices) in request_slices.iter().enumerate() {
-                    if i != 0 {
-                        slices_arg.push_str(", ");
-                    }
-                    slices_arg.push_str(request_slices);
+                    _ => unreachable!("{:?} has a fixed size", field),
                 }
+            }
+
+            // Requests are implicitly padded to a four byte boundary.
+            if let Some(request_size) = request_size {
+                outln!(out, "{}", (request_size + 3) / 4 * 4);
+            } else {
+                outln!(out, "let length_so_far = {};", sum(&terms, fixed_size));
+                outln!(out, "length_so_far + (4 - (length_so_far % 4)) % 4");
+            }
+        });
+        outln!(out, "}}");
+    }
+
+    fn emit_request_struct(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        deducible_fields: &HashMap<String, DeducibleField>,
+        gathered: &GatheredRequestFields,
+        out: &mut Output,
+    ) {
+        let ns = request_def.namespace.upgrade().unwrap();
+        let is_xproto = ns.header == "xproto";
+        let is_send_event = is_xproto && request_def.name == "SendEvent";
+
+        if let Some(ref doc) = request_def.doc {
+            self.emit_doc(doc, out);
+        }
+
+        let mut derives = Derives::all();
+        self.filter_derives_for_fields(&mut derives, &request_def.fields.borrow(), true);
+        let derives = derives.to_list();
+        if !derives.is_empty() {
+            outln!(out, "#[derive({})]", derives.join(", "));
+        }
+
+        let (struct_lifetime_block, parse_lifetime_block) = if gathered.needs_lifetime {
+            ("<'input>", "'input ")
+        } else {
+            ("", "")
+        };
+
+        let has_members = !gathered.request_args.is_empty();
+        let has_cows = gathered
+            .request_args
+            .iter()
+            .any(|arg| arg.1.needs_any_cow());
+        let members_start = if has_members { " {" } else { ";" };
+        outln!(
+            out,
+            "pub struct {name}Request{lifetime}{members_start}",
+            name = name,
+            lifetime = struct_lifetime_block,
+            members_start = members_start
+        );
+        out.indented(|out| {
+            for (member_name, member_type) in gathered.request_args.iter() {
+                outln!(out, "pub {name}: {type},",
+                       name=member_name,
+                       type=member_type.as_field());
+            }
+        });
+        if has_members {
+            outln!(out, "}}",);
+        }
 
-                let result = format!(
-                    "(vec![{slices}], {fds})",
-                    slices = slices_arg,
-                    fds = fds_arg,
+        // Methods implemented on every request
+        outln!(
+            out,
+            "impl{lifetime} {name}Request{lifetime} {{",
+            lifetime = struct_lifetime_block,
+            name = name
+        );
+        out.indented(|out| {
+            let num_slices = self.emit_request_serialize(
+                request_def,
+                name,
+                deducible_fields,
+                gathered,
+                SerializeMode::Pieces,
+                out,
+            );
+            let is_xproto = ns.header == "xproto";
+            let has_fds = !gathered.single_fds.is_empty() || !gathered.fd_lists.is_empty();
+            if !has_fds {
+                let _ = self.emit_request_serialize(
+                    request_def,
+                    name,
+                    deducible_fields,
+                    gathered,
+                    SerializeMode::Write,
+                    out,
                 );
-                if is_xproto {
-                    outln!(out, "{}", result);
-                } else {
-                    outln!(out, "Ok({})", result);
-                }
-            });
-            outln!(out, "}}");
+                self.emit_request_serialize_into(is_xproto, out);
+            }
+
+            self.emit_request_wire_length(request_def, deducible_fields, out);
 
             // Sending method
-            let is_xproto = ns.header == "xproto";
-            let is_list_fonts_with_info = request_def.name == "ListFontsWithInfo" && is_xproto;
-            let is_record_enable_context =
-                request_def.name == "EnableContext" && ns.header == "record";
-            let ret_type = if is_list_fonts_with_info || is_record_enable_context {
+            let multiple_replies = has_multiple_replies(request_def);
+            let ret_type = if multiple_replies {
                 assert!(request_def.reply.is_some());
-                match (is_list_fonts_with_info, is_record_enable_context) {
-                    (true, _) => "ListFontsWithInfoCookie<'_, Conn>".to_string(),
-                    (_, true) => "RecordEnableContextCookie<'_, Conn>".to_string(),
-                    _ => unreachable!(),
-                }
+                format!("CookieStream<'_, Conn, {}Reply>", name)
             } else {
                 match (request_def.reply.is_some(), gathered.reply_has_fds) {
                     (false, _) => "VoidCookie<'_, Conn>".to_string(),
@@ -1440,6 +2223,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     (true, true) => format!("CookieWithFds<'_, Conn, {}Reply>", name),
                 }
             };
+            outln!(out, "#[inline]");
             outln!(
                 out,
                 "pub fn send<Conn>(self, conn: &Conn) -> Result<{}, ConnectionError>",
@@ -1454,21 +2238,16 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     "let (bytes, fds) = self.serialize(conn){};",
                     if is_xproto { "" } else { "?" }
                 );
-                outln!(
-                    out,
-                    "let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();"
-                );
+                // An array instead of a Vec saves an allocation per request
+                let slices = (0..num_slices)
+                    .map(|i| format!("IoSlice::new(&bytes[{}])", i))
+                    .collect::<Vec<_>>();
+                outln!(out, "let slices = [{}];", slices.join(", "));
 
-                if is_list_fonts_with_info || is_record_enable_context {
-                    let cookie = match (is_list_fonts_with_info, is_record_enable_context) {
-                        (true, _) => "ListFontsWithInfoCookie",
-                        (_, true) => "RecordEnableContextCookie",
-                        _ => unreachable!(),
-                    };
+                if multiple_replies {
                     outln!(
                         out,
-                        "Ok({}::new(conn.send_request_with_reply(&slices, fds)?))",
-                        cookie,
+                        "Ok(CookieStream::new(conn.send_request_with_reply(&slices, fds)?))",
                     )
                 } else if request_def.reply.is_some() {
                     if gathered.reply_has_fds {
@@ -1492,7 +2271,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(
                     out,
                     "pub fn try_parse_request_fd(header: RequestHeader, value: &{lifetime}[u8], \
-                     fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {{",
+                     fds: &mut Vec<OwnedFd>) -> Result<Self, ParseError> {{",
                     lifetime = parse_lifetime_block,
                 );
             } else {
@@ -1563,6 +2342,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         .unwrap_or(false)
                     {
                         self.emit_field_post_parse(field, out);
+                    } else {
+                        self.emit_deducible_field_check(field, out);
                     }
 
                     if !seen_complete_header {
@@ -1643,6 +2424,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
         });
         outln!(out, "}}");
+        if let (Target::OutOfTree(_), Some(_)) = (self.target, &request_def.reply) {
+            // The reply cannot be turned into `x11rb::protocol::Reply`
+            return;
+        }
         outln!(
             out,
             "impl{lifetime} Request for {name}Request{lifetime} {{",
@@ -1666,10 +2451,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out: &mut Output,
     ) {
         let ns = request_def.namespace.upgrade().unwrap();
-        let is_list_fonts_with_info =
-            request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
         let is_send_event = request_def.name == "SendEvent" && ns.header == "xproto";
-        let is_record_enable_context = request_def.name == "EnableContext" && ns.header == "record";
 
         let needs_lifetime = gathered.needs_lifetime && !is_send_event;
 
@@ -1685,14 +2467,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
 
         let ret_lifetime = if needs_lifetime { "'c" } else { "'_" };
-        let ret_type = if is_list_fonts_with_info || is_record_enable_context {
+        let ret_type = if has_multiple_replies(request_def) {
             assert!(request_def.reply.is_some());
             assert!(!gathered.reply_has_fds);
-            if is_list_fonts_with_info {
-                format!("ListFontsWithInfoCookie<{}, Conn>", ret_lifetime)
-            } else {
-                format!("RecordEnableContextCookie<{}, Conn>", ret_lifetime)
-            }
+            format!("CookieStream<{}, Conn, {}Reply>", ret_lifetime, name)
         } else {
             match (request_def.reply.is_some(), gathered.reply_has_fds) {
                 (false, _) => format!("VoidCookie<{}, Conn>", ret_lifetime),
@@ -1717,6 +2495,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let Some(ref doc) = request_def.doc {
             self.emit_doc(doc, out);
         }
+        outln!(out, "#[inline]");
         outln!(
             out,
             "pub fn {}<{}>({}) -> Result<{}, ConnectionError>",
@@ -1769,10 +2548,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out: &mut Output,
     ) {
         let ns = request_def.namespace.upgrade().unwrap();
-        let is_list_fonts_with_info =
-            request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
         let is_send_event = request_def.name == "SendEvent" && ns.header == "xproto";
-        let is_record_enable_context = request_def.name == "EnableContext" && ns.header == "record";
         let needs_lifetime = gathered.needs_lifetime && !is_send_event;
 
         let mut generic_params = String::new();
@@ -1791,14 +2567,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
 
         let ret_lifetime = if needs_lifetime { "'c" } else { "'_" };
-        let ret_type = if is_list_fonts_with_info || is_record_enable_context {
+        let ret_type = if has_multiple_replies(request_def) {
             assert!(request_def.reply.is_some());
             assert!(!gathered.reply_has_fds);
-            if is_list_fonts_with_info {
-                format!("ListFontsWithInfoCookie<{}, Self>", ret_lifetime)
-            } else {
-                format!("RecordEnableContextCookie<{}, Self>", ret_lifetime)
-            }
+            format!("CookieStream<{}, Self, {}Reply>", ret_lifetime, name)
         } else {
             match (request_def.reply.is_some(), gathered.reply_has_fds) {
                 (false, _) => format!("VoidCookie<{}, Self>", ret_lifetime),
@@ -1829,6 +2601,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let Some(ref doc) = request_def.doc {
             self.emit_doc(doc, out);
         }
+        outln!(out, "#[inline]");
         outln!(
             out,
             "fn {}{}{}({}) -> Result<{}, ConnectionError>",
@@ -2360,8 +3133,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let rust_name = self.get_enum_rust_name(enum_def);
 
         let enum_info = self.caches.borrow().enum_info(enum_def);
-        let max_value_size = enum_info.max_value_size.unwrap();
-        let global_enum_size = max_value_size.max(enum_info.wire_size.unwrap_or((0, 0)).1);
+        let global_enum_size = self.global_enum_size(enum_def);
 
         if let Some((min_wire_size, max_wire_size)) = enum_info.wire_size {
             assert!(max_wire_size >= global_enum_size);
@@ -2545,13 +3317,109 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         });
         outln!(out, "}}");
 
+        // Enums that never appear on the wire are never parsed
+        if enum_info.wire_size.is_some() && global_enum_size != 1 {
+            self.generate_enum_check_known(enum_def, &rust_name, out);
+        }
+
         if ok_for_bitmask {
-            outln!(out, "bitmask_binop!({}, {});", rust_name, raw_type);
+            let macro_path = match self.target {
+                Target::X11rb => "",
+                Target::OutOfTree(_) => "x11rb::",
+            };
+            outln!(
+                out,
+                "{}bitmask_binop!({}, {});",
+                macro_path,
+                rust_name,
+                raw_type
+            );
         }
 
         outln!(out, "");
     }
 
+    /// Get the size in bits of the integer type that represents the given enum.
+    ///
+    /// A size of 1 means that the enum is represented by a `bool`.
+    fn global_enum_size(&self, enum_def: &xcbdefs::EnumDef) -> u8 {
+        let enum_info = self.caches.borrow().enum_info(enum_def);
+        let max_value_size = enum_info.max_value_size.unwrap();
+        max_value_size.max(enum_info.wire_size.unwrap_or((0, 0)).1)
+    }
+
+    /// Emit the `check_known()` function that is used by `strict_parsing`.
+    fn generate_enum_check_known(
+        &self,
+        enum_def: &xcbdefs::EnumDef,
+        rust_name: &str,
+        out: &mut Output,
+    ) {
+        let mut values = Vec::new();
+        let mut bits = 0u32;
+        for enum_item in enum_def.items.iter() {
+            match enum_item.value {
+                xcbdefs::EnumValue::Value(value) => values.push(value),
+                xcbdefs::EnumValue::Bit(bit) => bits |= 1 << bit,
+            }
+        }
+        values.sort_unstable();
+        values.dedup();
+
+        let mut conditions = Vec::new();
+        if bits != 0 {
+            // Every combination of bits is fine, including zero
+            values.retain(|value| value & !bits != 0);
+            conditions.push(format!("self.0 & !{:#x} == 0", bits));
+        }
+        if !values.is_empty() {
+            // Runs of consecutive values become ranges
+            let mut patterns = Vec::new();
+            let mut start = 0;
+            while start < values.len() {
+                let mut end = start;
+                while end + 1 < values.len() && values[end + 1] == values[end] + 1 {
+                    end += 1;
+                }
+                if end - start >= 2 {
+                    patterns.push(format!(
+                        "{}..={}",
+                        format_literal_integer(values[start]),
+                        format_literal_integer(values[end]),
+                    ));
+                } else {
+                    for value in &values[start..=end] {
+                        patterns.push(format_literal_integer(*value));
+                    }
+                }
+                start = end + 1;
+            }
+            conditions.push(format!("matches!(self.0, {})", patterns.join(" | ")));
+        }
+
+        outln!(out, "#[cfg(feature = \"strict_parsing\")]");
+        outln!(out, "impl {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// Fail with `ParseError::InvalidValue` if this is not a known value.",
+            );
+            outln!(
+                out,
+                "pub(crate) fn check_known(self) -> Result<(), ParseError> {{",
+            );
+            out.indented(|out| {
+                outln!(out, "if {} {{", conditions.join(" || "));
+                outln!(out.indent(), "Ok(())");
+                outln!(out, "}} else {{");
+                outln!(out.indent(), "Err(ParseError::InvalidValue)");
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
     fn generate_type_alias_def(&self, type_alias_def: &xcbdefs::TypeAliasDef, out: &mut Output) {
         let rust_new_name = self.get_type_alias_rust_name(&type_alias_def);
         outln!(
@@ -2633,6 +3501,24 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
         );
 
+        // Fields with raw values are printed via their enum type if that is possible, so that the
+        // output shows names instead of numbers.
+        let debug_enum_types = fields
+            .iter()
+            .filter(|field| self.field_is_visible(field, &deducible_fields))
+            .filter_map(|field| match field {
+                xcbdefs::FieldDef::Normal(normal_field) => self
+                    .debug_enum_type(&normal_field.type_)
+                    .map(|enum_type| (normal_field.name.as_str(), enum_type)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let mut derives = derives;
+        let custom_debug = derives.debug && !debug_enum_types.is_empty();
+        if custom_debug {
+            derives.debug = false;
+        }
+
         if let Some(doc) = doc {
             self.emit_doc(doc, out);
         }
@@ -2657,6 +3543,44 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
         outln!(out, "}}");
 
+        if custom_debug {
+            outln!(out, "impl std::fmt::Debug for {} {{", name);
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+                );
+                out.indented(|out| {
+                    outln!(out, "fmt.debug_struct(\"{}\")", name);
+                    for field in fields.iter() {
+                        if !self.field_is_visible(field, &deducible_fields) {
+                            continue;
+                        }
+                        let field_name = field.name().unwrap();
+                        if skip_length_field && field_name == "length" {
+                            continue;
+                        }
+                        let rust_field_name = to_rust_variable_name(field_name);
+                        let value = match debug_enum_types.get(field_name) {
+                            Some(enum_type) => {
+                                format!("{}::from(self.{})", enum_type, rust_field_name)
+                            }
+                            None => format!("self.{}", rust_field_name),
+                        };
+                        outln!(
+                            out.indent(),
+                            ".field(\"{}\", &{})",
+                            rust_field_name.trim_start_matches("r#"),
+                            value,
+                        );
+                    }
+                    outln!(out.indent(), ".finish()");
+                });
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        }
+
         let has_fds = fields.iter().any(|field| match field {
             xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => true,
             _ => false,
@@ -2673,7 +3597,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "impl TryParseFd for {} {{", name);
                 outln!(
                     out.indent(),
-                    "fn try_parse_fd<'a>({}: &'a [u8], fds: &mut Vec<RawFdContainer>) -> \
+                    "fn try_parse_fd<'a>({}: &'a [u8], fds: &mut Vec<OwnedFd>) -> \
                      Result<(Self, &'a [u8]), ParseError> {{",
                     input_name,
                 );
@@ -2726,6 +3650,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                             .unwrap_or(false)
                         {
                             self.emit_field_post_parse(field, out);
+                        } else {
+                            self.emit_deducible_field_check(field, out);
                         }
                     }
                     let field_names = fields
@@ -2755,9 +3681,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                             outln!(out, "let _ = remaining;");
                             outln!(
                                 out,
-                                "let remaining = initial_value.get({} + length as usize * 4..)",
-                                minimum
+                                "let end = {}::wire::length_field_end({}, length)?;",
+                                self.crate_name,
+                                minimum,
                             );
+                            outln!(out, "let remaining = initial_value.get(end..)");
                             outln!(out.indent(), ".ok_or(ParseError::InsufficientData)?;");
                         }
                     }
@@ -3609,11 +4537,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         outln!(out, "}}");
                     }
                     outln!(out, "match parse_result {{");
+                    outln!(out.indent(), "#[cfg(not(feature = \"strict_parsing\"))]");
                     outln!(
                         out.indent(),
                         "None => Ok(({}::InvalidValue(switch_expr), &[])),",
                         name,
                     );
+                    outln!(out.indent(), "#[cfg(feature = \"strict_parsing\")]");
+                    outln!(out.indent(), "None => Err(ParseError::InvalidValue),");
                     outln!(
                         out.indent(),
                         "Some(result) => Ok((result, outer_remaining)),",
@@ -3992,32 +4923,25 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out: &mut Output,
     ) {
         match field {
-            xcbdefs::FieldDef::Pad(pad_field) => {
-                let pad_size = match pad_field.kind {
-                    xcbdefs::PadKind::Bytes(size) => size.to_string(),
-                    xcbdefs::PadKind::Align(align) => {
-                        outln!(out, "// Align offset to multiple of {}", align);
-                        outln!(
-                            out,
-                            "let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;",
-                        );
-                        outln!(
-                            out,
-                            "let misalignment = ({} - (offset % {})) % {};",
-                            align,
-                            align,
-                            align,
-                        );
-                        "misalignment".into()
-                    }
-                };
-                outln!(
-                    out,
-                    "let remaining = {from}.get({pad}..).ok_or(ParseError::InsufficientData)?;",
-                    from = from,
-                    pad = pad_size,
-                );
-            }
+            xcbdefs::FieldDef::Pad(pad_field) => match pad_field.kind {
+                xcbdefs::PadKind::Bytes(size) => {
+                    outln!(
+                        out,
+                        "let remaining = {from}.get({pad}..).ok_or(ParseError::InsufficientData)?;",
+                        from = from,
+                        pad = size,
+                    );
+                }
+                xcbdefs::PadKind::Align(align) => {
+                    outln!(
+                        out,
+                        "let remaining = {}::wire::align(value, {}, {})?;",
+                        self.crate_name,
+                        from,
+                        align,
+                    );
+                }
+            },
             xcbdefs::FieldDef::Normal(normal_field) => {
                 let rust_field_name = to_rust_variable_name(&normal_field.name);
                 outln!(
@@ -4035,8 +4959,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     if let Some(list_length) = list_field.length() {
                         outln!(
                             out,
-                            "let ({}, remaining) = crate::x11_utils::parse_u8_list({}, {})?;",
+                            "let ({}, remaining) = {}::x11_utils::parse_u8_list({}, {})?;",
                             rust_field_name,
+                            self.crate_name,
                             from,
                             list_length,
                         );
@@ -4062,9 +4987,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     } else if let Some(ref length_expr) = list_field.length_expr {
                         outln!(
                             out,
-                            "let ({}, remaining) = crate::x11_utils::parse_u8_list({}, \
+                            "let ({}, remaining) = {}::x11_utils::parse_u8_list({}, \
                              {}.try_to_usize()?)?;",
                             rust_field_name,
+                            self.crate_name,
                             from,
                             self.expr_to_str(
                                 length_expr,
@@ -4104,9 +5030,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         self.type_to_rust_type(list_field.element_type.type_.get_resolved());
                     outln!(
                         out,
-                        "let ({}, remaining) = crate::x11_utils::parse_list::<{}>(remaining, \
+                        "let ({}, remaining) = {}::x11_utils::parse_list::<{}>(remaining, \
                          {}.try_to_usize()?)?;",
                         rust_field_name,
+                        self.crate_name,
                         rust_element_type,
                         self.expr_to_str(
                             list_field.length_expr.as_ref().unwrap(),
@@ -4119,12 +5046,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 } else if let Some(list_len) = list_field.length() {
                     for i in 0..list_len {
                         let tmp_name = format!("{}_{}", rust_field_name, i);
+                        // Only the first element starts at `from`
+                        let element_from = if i == 0 { from } else { "remaining" };
                         outln!(
                             out,
                             "let ({}_{}, remaining) = {};",
                             rust_field_name,
                             i,
-                            self.emit_value_parse(&list_field.element_type, from),
+                            self.emit_value_parse(&list_field.element_type, element_from),
                         );
                         self.emit_value_post_parse(&list_field.element_type, &tmp_name, out);
                     }
@@ -4149,8 +5078,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         );
                         outln!(
                             out,
-                            "let mut {} = Vec::with_capacity(list_length);",
-                            rust_field_name
+                            "let mut {} = Vec::with_capacity({}::x11_utils::list_capacity(\
+                             remaining, list_length)?);",
+                            rust_field_name,
+                            self.crate_name,
                         );
                         outln!(out, "for _ in 0..list_length {{");
                     } else {
@@ -4162,7 +5093,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         outln!(
                             out,
                             "let (v, new_remaining) = {};",
-                            self.emit_value_parse(&list_field.element_type, from),
+                            self.emit_value_parse(&list_field.element_type, "remaining"),
                         );
                         self.emit_value_post_parse(&list_field.element_type, "v", out);
                         outln!(out, "remaining = new_remaining;");
@@ -4286,6 +5217,33 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let xcbdefs::FieldValueSet::Enum(_) = type_.value_set {
             // Handle turning things into enum instances.
             outln!(out, "let {var} = {var}.into();", var = var_name);
+            self.emit_enum_check(type_, var_name, out);
+        }
+    }
+
+    /// Check the value of a field that is not kept in the parsed struct.
+    ///
+    /// Such fields are only used to compute other fields, so they keep their integer type.
+    fn emit_deducible_field_check(&self, field: &xcbdefs::FieldDef, out: &mut Output) {
+        if let xcbdefs::FieldDef::Normal(normal_field) = field {
+            let rust_field_name = to_rust_variable_name(&normal_field.name);
+            let value = format!("{}.into()", rust_field_name);
+            self.emit_enum_check(&normal_field.type_, &value, out);
+        }
+    }
+
+    /// With `strict_parsing`, fail if `value` is not a known value of the field's enum.
+    fn emit_enum_check(&self, type_: &xcbdefs::FieldValueType, value: &str, out: &mut Output) {
+        if let Some(enum_def) = self.use_enum_type_in_field(type_) {
+            if self.global_enum_size(&enum_def) != 1 {
+                outln!(out, "#[cfg(feature = \"strict_parsing\")]");
+                outln!(
+                    out,
+                    "{}::check_known({})?;",
+                    self.field_value_type_to_rust_type(type_),
+                    value,
+                );
+            }
         }
     }
 
@@ -5419,23 +6377,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     let rust_field_name = to_rust_variable_name(&fd_field.name);
                     let generic_param = format!("{}", char::from(letter_iter.next().unwrap()));
                     let preamble_part = format!(
-                        "let {}: RawFdContainer = {}.into();",
+                        "let {}: OwnedFd = {}.into();",
                         rust_field_name, rust_field_name,
                     );
                     args.push((rust_field_name.clone(), Type::Simple(generic_param.clone())));
-                    request_args.push((rust_field_name, Type::Simple("RawFdContainer".into())));
-                    generics.push((generic_param, "Into<RawFdContainer>".into()));
+                    request_args.push((rust_field_name, Type::Simple("OwnedFd".into())));
+                    generics.push((generic_param, "Into<OwnedFd>".into()));
                     preamble.push(preamble_part);
                     single_fds.push(fd_field.name.clone());
                 }
                 xcbdefs::FieldDef::FdList(fd_list_field) => {
                     let rust_field_name = to_rust_variable_name(&fd_list_field.name);
-                    args.push((
-                        rust_field_name.clone(),
-                        Type::Simple("Vec<RawFdContainer>".into()),
-                    ));
-                    request_args
-                        .push((rust_field_name, Type::Simple("Vec<RawFdContainer>".into())));
+                    args.push((rust_field_name.clone(), Type::Simple("Vec<OwnedFd>".into())));
+                    request_args.push((rust_field_name, Type::Simple("Vec<OwnedFd>".into())));
                     fd_lists.push(fd_list_field.name.clone());
                 }
                 xcbdefs::FieldDef::Expr(_) => unreachable!(),
@@ -5502,6 +6456,45 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     }
 
     /// Returns the Rust type for `field`.
+    /// Returns the enum type that is used to print a field in `Debug` implementations.
+    ///
+    /// This is the enum of a field that is a bitmask or that can hold special values, if the
+    /// raw value of the field can be converted into the enum.
+    fn debug_enum_type(&self, value_type: &xcbdefs::FieldValueType) -> Option<String> {
+        let enum_ = match value_type.value_set {
+            xcbdefs::FieldValueSet::AltEnum(ref enum_)
+            | xcbdefs::FieldValueSet::Mask(ref enum_)
+            | xcbdefs::FieldValueSet::AltMask(ref enum_) => enum_,
+            xcbdefs::FieldValueSet::None | xcbdefs::FieldValueSet::Enum(_) => return None,
+        };
+        let enum_def = match enum_.get_resolved() {
+            xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
+            _ => unreachable!(),
+        };
+        let field_size = match value_type.type_.get_resolved().get_original_type() {
+            xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card8)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Byte)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Char) => 8,
+            xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card16) => 16,
+            xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card32)
+            | xcbdefs::TypeRef::Xid(_)
+            | xcbdefs::TypeRef::XidUnion(_) => 32,
+            _ => return None,
+        };
+        let enum_info = self.caches.borrow().enum_info(&enum_def);
+        let enum_size = enum_info
+            .max_value_size
+            .unwrap()
+            .max(enum_info.wire_size.unwrap_or((0, 0)).1);
+        // Enums with a bool representation cannot be created from integers and the
+        // conversion from larger integers could fail.
+        if enum_size == 1 || field_size > enum_size {
+            return None;
+        }
+        let ns = enum_def.namespace.upgrade().unwrap();
+        Some(self.type_name_to_rust_type(&self.get_enum_rust_name(&enum_def), &ns))
+    }
+
     fn field_to_rust_type(&self, field: &xcbdefs::FieldDef, switch_prefix: &str) -> String {
         match field {
             xcbdefs::FieldDef::Pad(_) => unreachable!(),
@@ -5519,8 +6512,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             xcbdefs::FieldDef::Switch(switch_field) => {
                 format!("{}{}", switch_prefix, to_rust_type_name(&switch_field.name))
             }
-            xcbdefs::FieldDef::Fd(_) => "RawFdContainer".into(),
-            xcbdefs::FieldDef::FdList(_) => "Vec<RawFdContainer>".into(),
+            xcbdefs::FieldDef::Fd(_) => "OwnedFd".into(),
+            xcbdefs::FieldDef::FdList(_) => "Vec<OwnedFd>".into(),
             xcbdefs::FieldDef::Expr(_) => unreachable!(),
             xcbdefs::FieldDef::VirtualLen(_) => unreachable!(),
         }
@@ -5702,9 +6695,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     }
                 }
                 xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => {
-                    // RawFdContainer cannot be cloned
+                    // OwnedFd can neither be cloned nor compared
                     derives.clone = false;
                     derives.copy = false;
+                    derives.partial_eq = false;
+                    derives.eq = false;
                 }
                 xcbdefs::FieldDef::Expr(_) => {}
                 xcbdefs::FieldDef::VirtualLen(_) => {}
@@ -6233,7 +7228,7 @@ fn to_rust_enum_type_name(name: &str) -> String {
 
 /// Converts a name from the XML to a Rust variable
 /// name (snake_case).
-fn to_rust_variable_name(name: &str) -> String {
+pub(super) fn to_rust_variable_name(name: &str) -> String {
     if name == "type" {
         "type_".into()
     } else if name == "match" {
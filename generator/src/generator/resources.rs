@@ -0,0 +1,117 @@
+/// A server-side resource that gets a RAII wrapper type.
+pub(super) struct ResourceInfo {
+    /// The name of the resource's XID type, e.g. `Pixmap`.
+    pub(super) resource_name: &'static str,
+    /// The requests that create a new instance of the resource.
+    pub(super) create_requests: &'static [CreateInfo],
+    /// The request that frees the resource.
+    ///
+    /// This request must have the resource's XID as its only field.
+    pub(super) free_request: &'static str,
+}
+
+/// A request that creates a resource.
+pub(super) struct CreateInfo {
+    /// The name of the request in the XML.
+    pub(super) request_name: &'static str,
+    /// The name of the field that contains the XID of the new resource.
+    pub(super) created_field: &'static str,
+}
+
+const fn create(request_name: &'static str, created_field: &'static str) -> CreateInfo {
+    CreateInfo {
+        request_name,
+        created_field,
+    }
+}
+
+const XPROTO: &[ResourceInfo] = &[
+    ResourceInfo {
+        resource_name: "Window",
+        create_requests: &[create("CreateWindow", "wid")],
+        free_request: "DestroyWindow",
+    },
+    ResourceInfo {
+        resource_name: "Pixmap",
+        create_requests: &[create("CreatePixmap", "pid")],
+        free_request: "FreePixmap",
+    },
+    ResourceInfo {
+        resource_name: "Font",
+        create_requests: &[create("OpenFont", "fid")],
+        free_request: "CloseFont",
+    },
+    ResourceInfo {
+        resource_name: "Gcontext",
+        create_requests: &[create("CreateGC", "cid")],
+        free_request: "FreeGC",
+    },
+    ResourceInfo {
+        resource_name: "Colormap",
+        create_requests: &[create("CreateColormap", "mid")],
+        free_request: "FreeColormap",
+    },
+    ResourceInfo {
+        resource_name: "Cursor",
+        create_requests: &[
+            create("CreateCursor", "cid"),
+            create("CreateGlyphCursor", "cid"),
+        ],
+        free_request: "FreeCursor",
+    },
+];
+
+const RENDER: &[ResourceInfo] = &[
+    ResourceInfo {
+        resource_name: "Picture",
+        create_requests: &[
+            create("CreatePicture", "pid"),
+            create("CreateSolidFill", "picture"),
+            create("CreateLinearGradient", "picture"),
+            create("CreateRadialGradient", "picture"),
+            create("CreateConicalGradient", "picture"),
+        ],
+        free_request: "FreePicture",
+    },
+    ResourceInfo {
+        resource_name: "Glyphset",
+        create_requests: &[create("CreateGlyphSet", "gsid")],
+        free_request: "FreeGlyphSet",
+    },
+];
+
+const XFIXES: &[ResourceInfo] = &[ResourceInfo {
+    resource_name: "Region",
+    create_requests: &[
+        create("CreateRegion", "region"),
+        create("CreateRegionFromBitmap", "region"),
+        create("CreateRegionFromWindow", "region"),
+        create("CreateRegionFromGC", "region"),
+        create("CreateRegionFromPicture", "region"),
+    ],
+    free_request: "DestroyRegion",
+}];
+
+const DAMAGE: &[ResourceInfo] = &[ResourceInfo {
+    resource_name: "Damage",
+    create_requests: &[create("Create", "damage")],
+    free_request: "Destroy",
+}];
+
+const SHM: &[ResourceInfo] = &[ResourceInfo {
+    resource_name: "Seg",
+    create_requests: &[create("Attach", "shmseg"), create("AttachFd", "shmseg")],
+    free_request: "Detach",
+}];
+
+/// Get the resources of the namespace with the given header that get a wrapper type.
+pub(super) fn for_namespace(header: &str) -> &'static [ResourceInfo] {
+    match header {
+        "xproto" => XPROTO,
+        "render" => RENDER,
+        "xfixes" => XFIXES,
+        "damage" => DAMAGE,
+        "shm" => SHM,
+        _ => &[],
+    }
+}
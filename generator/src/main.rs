@@ -9,8 +9,10 @@
 )]
 #![forbid(unsafe_code)]
 
+use std::collections::HashSet;
 use std::io::{Read as _, Write as _};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 mod generator;
 
@@ -92,7 +94,10 @@ fn list_xmls(dir_path: &Path) -> Result<Vec<PathBuf>, Error> {
     Ok(files)
 }
 
-fn load_namespace(path: &Path, parser: &mut xcbgen::Parser) -> Result<(), Error> {
+fn load_namespace(
+    path: &Path,
+    parser: &mut xcbgen::Parser,
+) -> Result<Rc<xcbgen::defs::Namespace>, Error> {
     let file_bytes = read_file(path)?;
     let file_string = String::from_utf8(file_bytes).map_err(|e| Error::FileIsNotUtf8 {
         path: path.to_path_buf(),
@@ -107,8 +112,7 @@ fn load_namespace(path: &Path, parser: &mut xcbgen::Parser) -> Result<(), Error>
         .map_err(|e| Error::XcbParseFailed {
             path: path.to_path_buf(),
             error: e,
-        })?;
-    Ok(())
+        })
 }
 
 /// Writes `data` to `file_path` if the file does not exist or
@@ -145,15 +149,45 @@ fn replace_file_if_different(file_path: &Path, data: &[u8]) -> Result<(), Error>
     Ok(())
 }
 
+/// Get the version of xcb-proto from the `configure.ac` next to the directory with the XML files.
+fn read_proto_version(input_dir_path: &Path) -> Option<String> {
+    let configure = input_dir_path.parent()?.join("configure.ac");
+    let configure = String::from_utf8(read_file(&configure).ok()?).ok()?;
+    // AC_INIT([XCB Proto], 1.14, [xcb@lists.freedesktop.org])
+    let args = &configure[configure.find("AC_INIT(")? + "AC_INIT(".len()..];
+    let version = args.split(',').nth(1)?;
+    Some(
+        version
+            .trim()
+            .trim_matches(|c| c == '[' || c == ']')
+            .to_string(),
+    )
+}
+
+fn usage(program: &str) -> u8 {
+    eprintln!("USAGE:");
+    eprintln!("    {} <INPUT_DIR> <OUTPUT_DIR>", program);
+    eprintln!(
+        "    {} --out-of-tree <INPUT_DIR> <OUTPUT_DIR> <XML_FILE>...",
+        program
+    );
+    eprintln!();
+    eprintln!("With --out-of-tree, only the given XML files are generated into modules that");
+    eprintln!("use x11rb as a dependency. INPUT_DIR contains the XML files that x11rb was");
+    eprintln!("generated from and that the given files can import.");
+    1
+}
+
 fn main2() -> Result<u8, Error> {
     let args: Vec<_> = std::env::args_os().collect();
-    if args.len() != 3 {
-        eprintln!("USAGE:");
-        eprintln!("    {} <INPUT_DIR> <OUTPUT_DIR>", args[0].to_string_lossy());
-        return Ok(1);
+    let program = args[0].to_string_lossy();
+    let out_of_tree = args.get(1).map(|arg| arg == "--out-of-tree") == Some(true);
+    let args = if out_of_tree { &args[2..] } else { &args[1..] };
+    if (!out_of_tree && args.len() != 2) || (out_of_tree && args.len() < 3) {
+        return Ok(usage(&program));
     }
-    let input_dir_path = Path::new(&args[1]);
-    let output_dir_path = Path::new(&args[2]);
+    let input_dir_path = Path::new(&args[0]);
+    let output_dir_path = Path::new(&args[1]);
 
     let xml_files = list_xmls(input_dir_path)?;
     let module = xcbgen::defs::Module::new();
@@ -162,6 +196,12 @@ fn main2() -> Result<u8, Error> {
         println!("Loading {:?}", file_path);
         load_namespace(file_path, &mut parser)?;
     }
+    let mut out_of_tree_namespaces = HashSet::new();
+    for file_path in args[2..].iter().map(Path::new) {
+        println!("Loading {:?}", file_path);
+        let ns = load_namespace(file_path, &mut parser)?;
+        let _ = out_of_tree_namespaces.insert(ns.header.clone());
+    }
 
     //eprintln!("{:#?}", module);
     println!("{} XMLs loaded", module.namespaces.borrow().len());
@@ -169,7 +209,12 @@ fn main2() -> Result<u8, Error> {
     xcbgen::resolve(&module).map_err(|e| Error::XcbResolveFailed { error: e })?;
     println!("Resolved successfully");
 
-    let generated = generator::generate(&module);
+    let generated = if out_of_tree {
+        generator::generate_out_of_tree(&module, &out_of_tree_namespaces)
+    } else {
+        let proto_version = read_proto_version(input_dir_path);
+        generator::generate(&module, proto_version.as_deref().unwrap_or("unknown"))
+    };
     for (file_name, file_data) in generated.iter() {
         let mut file_path = PathBuf::from(output_dir_path);
         file_path.push(file_name);
@@ -0,0 +1,477 @@
+//! Building blocks for making the pointer easier to see.
+//!
+//! Users with low vision often cannot find the default cursor, which is small and has little
+//! contrast on many backgrounds. This module provides two aids:
+//!
+//! - [`CursorImage`] renders large cursors with a thick outline on the client side. They are
+//!   uploaded as ARGB cursors with the RENDER extension, so they are not limited to the two
+//!   colors of core cursors.
+//! - [`Crosshair`] shows two thin lines across the whole screen that cross at the pointer. The
+//!   lines are transparent for input, so they do not get in the way of clicks.
+//!
+//! ```
+//! use x11rb::accessibility::{CursorImage, CursorShape, CursorStyle, Crosshair};
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::protocol::xproto::{ChangeWindowAttributesAux, ConnectionExt as _, Window};
+//!
+//! fn assist(conn: &impl Connection, screen_num: usize, window: Window)
+//!     -> Result<(), ReplyOrIdError>
+//! {
+//!     let screen = &conn.setup().roots[screen_num];
+//!     let image = CursorImage::render(CursorShape::Arrow, &CursorStyle::high_contrast(96));
+//!     let cursor = image.create_cursor(conn, screen.root)?;
+//!     conn.change_window_attributes(window, &ChangeWindowAttributesAux::new().cursor(cursor))?;
+//!     conn.free_cursor(cursor)?;
+//!
+//!     let crosshair = Crosshair::new(conn, screen_num, 3, screen.white_pixel)?;
+//!     crosshair.show()?;
+//!     loop {
+//!         let _position = crosshair.update()?;
+//!         std::thread::sleep(std::time::Duration::from_millis(15));
+//!     }
+//! }
+//! ```
+//!
+//! The code in this module is only available when the `render` and `shape` features of the
+//! library are enabled.
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::render::{self, CreatePictureAux, Pictformat, PictureWrapper};
+use crate::protocol::shape::{self, SK, SO};
+use crate::protocol::xproto::{
+    self, ClipOrdering, ConfigureWindowAux, CreateGCAux, CreateWindowAux, Cursor, Drawable,
+    GcontextWrapper, ImageFormat, PixmapWrapper, StackMode, Window, WindowClass, WindowWrapper,
+};
+use crate::{COPY_DEPTH_FROM_PARENT, COPY_FROM_PARENT};
+
+/// The corners of the arrow, relative to the size of the cursor.
+const ARROW: [(f64, f64); 7] = [
+    (0.0, 0.0),
+    (0.0, 0.8),
+    (0.2, 0.63),
+    (0.34, 0.95),
+    (0.46, 0.9),
+    (0.32, 0.58),
+    (0.57, 0.58),
+];
+
+/// Half of the width of the arms of the crosshair, relative to the size of the cursor.
+const CROSSHAIR_ARM: f64 = 0.08;
+
+/// The shape of a cursor rendered by [`CursorImage::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorShape {
+    /// The usual arrow, pointing to the top left corner.
+    Arrow,
+    /// A cross with the hotspot in its center.
+    Crosshair,
+}
+
+impl CursorShape {
+    /// Check whether a point is inside of the shape, in coordinates from zero to one.
+    fn contains(self, (x, y): (f64, f64)) -> bool {
+        match self {
+            CursorShape::Arrow => inside_polygon(&ARROW, (x, y)),
+            CursorShape::Crosshair => {
+                (0.0..=1.0).contains(&x)
+                    && (0.0..=1.0).contains(&y)
+                    && ((x - 0.5).abs() <= CROSSHAIR_ARM || (y - 0.5).abs() <= CROSSHAIR_ARM)
+            }
+        }
+    }
+}
+
+/// The size and colors of a cursor rendered by [`CursorImage::render`].
+///
+/// Colors are given as `0xAARRGGBB` without premultiplied alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorStyle {
+    /// The width and height of the cursor in pixels, including the outline.
+    pub size: u16,
+    /// The color of the inside of the shape.
+    pub fill: u32,
+    /// The color of the outline around the shape.
+    pub outline: u32,
+    /// The width of the outline in pixels.
+    pub outline_width: u16,
+}
+
+impl CursorStyle {
+    /// Get a white cursor with a black outline of the given size, which is visible on any
+    /// background.
+    pub fn high_contrast(size: u16) -> Self {
+        Self {
+            size,
+            fill: 0xffff_ffff,
+            outline: 0xff00_0000,
+            outline_width: std::cmp::max(size / 24, 1),
+        }
+    }
+
+    /// Set the colors of the inside and of the outline.
+    pub fn colors(mut self, fill: u32, outline: u32) -> Self {
+        self.fill = fill;
+        self.outline = outline;
+        self
+    }
+}
+
+/// A cursor image with premultiplied ARGB pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorImage {
+    /// The width of the image.
+    pub width: u16,
+    /// The height of the image.
+    pub height: u16,
+    /// The x coordinate of the hotspot.
+    pub x_hot: u16,
+    /// The y coordinate of the hotspot.
+    pub y_hot: u16,
+    /// The pixels in rows from top to bottom as `0xAARRGGBB` with premultiplied alpha.
+    pub pixels: Vec<u32>,
+}
+
+impl CursorImage {
+    /// Render a cursor of the given shape and style.
+    pub fn render(shape: CursorShape, style: &CursorStyle) -> Self {
+        let size = usize::from(std::cmp::max(style.size, 1));
+        let border = usize::from(style.outline_width);
+        // The shape is scaled into the area inside of the outline
+        let inner = size.saturating_sub(2 * border) as f64;
+        let fill = (0..size * size)
+            .map(|index| {
+                let x = ((index % size) as f64 - border as f64 + 0.5) / inner;
+                let y = ((index / size) as f64 - border as f64 + 0.5) / inner;
+                inner > 0.0 && shape.contains((x, y))
+            })
+            .collect::<Vec<_>>();
+        let outline = dilate(&fill, size, border);
+        let pixels = fill
+            .iter()
+            .zip(&outline)
+            .map(|(&fill, &outline)| match (fill, outline) {
+                (true, _) => premultiply(style.fill),
+                (false, true) => premultiply(style.outline),
+                (false, false) => 0,
+            })
+            .collect();
+        let hotspot = match shape {
+            CursorShape::Arrow => style.outline_width,
+            CursorShape::Crosshair => (size / 2) as u16,
+        };
+        let size = size as u16;
+        Self {
+            width: size,
+            height: size,
+            x_hot: hotspot,
+            y_hot: hotspot,
+            pixels,
+        }
+    }
+
+    /// Upload the image and create a cursor from it.
+    ///
+    /// `drawable` is used to find the screen, e.g. the root window. The cursor has to be freed
+    /// with `FreeCursor` once it is no longer needed. If the X11 server does not support ARGB
+    /// pictures, [`ConnectionError::UnsupportedExtension`] is returned.
+    ///
+    /// This function waits for the reply to `QueryPictFormats`.
+    pub fn create_cursor<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        drawable: Drawable,
+    ) -> Result<Cursor, ReplyOrIdError> {
+        let formats = render::query_pict_formats(conn)?.reply()?;
+        let format = find_argb32_format(&formats).ok_or(ConnectionError::UnsupportedExtension)?;
+        let pixmap = PixmapWrapper::create_pixmap(conn, 32, drawable, self.width, self.height)?;
+        let gc = GcontextWrapper::create_gc(conn, pixmap.pixmap(), &CreateGCAux::new())?;
+        let data = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| pixel.to_ne_bytes())
+            .collect::<Vec<_>>();
+        let _ = xproto::put_image(
+            conn,
+            ImageFormat::Z_PIXMAP,
+            pixmap.pixmap(),
+            gc.gcontext(),
+            self.width,
+            self.height,
+            0,
+            0,
+            0,
+            32,
+            &data,
+        )?;
+        let picture = PictureWrapper::create_picture(
+            conn,
+            pixmap.pixmap(),
+            format,
+            &CreatePictureAux::new(),
+        )?;
+        let cursor = conn.generate_id()?;
+        let _ = render::create_cursor(conn, cursor, picture.picture(), self.x_hot, self.y_hot)?;
+        Ok(cursor)
+    }
+}
+
+/// Check whether a point is inside of a polygon with the even-odd rule.
+fn inside_polygon(polygon: &[(f64, f64)], (x, y): (f64, f64)) -> bool {
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &current in polygon {
+        let ((x1, y1), (x2, y2)) = (previous, current);
+        if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Grow a square mask by the given number of pixels in each direction.
+fn dilate(mask: &[bool], size: usize, radius: usize) -> Vec<bool> {
+    let grow = |mask: &[bool], index: fn(usize, usize, usize) -> usize| {
+        let mut result = vec![false; mask.len()];
+        for line in 0..size {
+            for position in 0..size {
+                let start = position.saturating_sub(radius);
+                let end = std::cmp::min(position + radius, size - 1);
+                result[index(size, line, position)] =
+                    (start..=end).any(|other| mask[index(size, line, other)]);
+            }
+        }
+        result
+    };
+    let rows = grow(mask, |size, row, column| row * size + column);
+    grow(&rows, |size, column, row| row * size + column)
+}
+
+/// Convert a color to premultiplied alpha.
+fn premultiply(color: u32) -> u32 {
+    let alpha = color >> 24;
+    let channel = |shift: u32| (((color >> shift) & 0xff) * alpha / 0xff) << shift;
+    alpha << 24 | channel(16) | channel(8) | channel(0)
+}
+
+/// Find the standard `ARGB32` picture format.
+fn find_argb32_format(formats: &render::QueryPictFormatsReply) -> Option<Pictformat> {
+    formats
+        .formats
+        .iter()
+        .find(|format| {
+            format.type_ == render::PictType::DIRECT
+                && format.depth == 32
+                && format.direct.alpha_shift == 24
+                && format.direct.alpha_mask == 0xff
+                && format.direct.red_shift == 16
+                && format.direct.red_mask == 0xff
+                && format.direct.green_shift == 8
+                && format.direct.green_mask == 0xff
+                && format.direct.blue_shift == 0
+                && format.direct.blue_mask == 0xff
+        })
+        .map(|format| format.id)
+}
+
+/// Two lines across the screen that cross at the pointer.
+///
+/// The lines are override-redirect windows whose input shape is empty, so clicks go to the
+/// windows below them. The windows are destroyed when this object is dropped.
+#[derive(Debug)]
+pub struct Crosshair<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    root: Window,
+    thickness: u16,
+    horizontal: WindowWrapper<'c, C>,
+    vertical: WindowWrapper<'c, C>,
+}
+
+impl<'c, C: Connection + ?Sized> Crosshair<'c, C> {
+    /// Create the lines on the given screen with the given thickness and color.
+    ///
+    /// The windows are created unmapped and have to be shown with [`Crosshair::show`]. If the
+    /// X11 server does not support the SHAPE extension, [`ConnectionError::UnsupportedExtension`]
+    /// is returned.
+    pub fn new(
+        conn: &'c C,
+        screen_num: usize,
+        thickness: u16,
+        pixel: u32,
+    ) -> Result<Self, ReplyOrIdError> {
+        if conn
+            .extension_information(shape::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Err(ConnectionError::UnsupportedExtension.into());
+        }
+        let screen = &conn.setup().roots[screen_num];
+        let thickness = std::cmp::max(thickness, 1);
+        let line = |width, height| -> Result<_, ReplyOrIdError> {
+            let aux = CreateWindowAux::new()
+                .override_redirect(1)
+                .background_pixel(pixel);
+            let window = WindowWrapper::create_window(
+                conn,
+                COPY_DEPTH_FROM_PARENT,
+                screen.root,
+                0,
+                0,
+                width,
+                height,
+                0,
+                WindowClass::INPUT_OUTPUT,
+                COPY_FROM_PARENT,
+                &aux,
+            )?;
+            let _ = shape::rectangles(
+                conn,
+                SO::SET,
+                SK::INPUT,
+                ClipOrdering::UNSORTED,
+                window.window(),
+                0,
+                0,
+                &[],
+            )?;
+            Ok(window)
+        };
+        Ok(Self {
+            conn,
+            root: screen.root,
+            thickness,
+            horizontal: line(screen.width_in_pixels, thickness)?,
+            vertical: line(thickness, screen.height_in_pixels)?,
+        })
+    }
+
+    /// Get the horizontal and the vertical line.
+    pub fn windows(&self) -> (Window, Window) {
+        (self.horizontal.window(), self.vertical.window())
+    }
+
+    /// Map the lines above all other windows.
+    pub fn show(&self) -> Result<(), ConnectionError> {
+        let aux = ConfigureWindowAux::new().stack_mode(StackMode::ABOVE);
+        for window in [self.horizontal.window(), self.vertical.window()] {
+            let _ = xproto::configure_window(self.conn, window, &aux)?;
+            let _ = xproto::map_window(self.conn, window)?;
+        }
+        self.conn.flush()
+    }
+
+    /// Unmap the lines.
+    pub fn hide(&self) -> Result<(), ConnectionError> {
+        for window in [self.horizontal.window(), self.vertical.window()] {
+            let _ = xproto::unmap_window(self.conn, window)?;
+        }
+        self.conn.flush()
+    }
+
+    /// Let the lines cross at the given position in root window coordinates.
+    ///
+    /// The lines are also raised above other windows that were mapped in the meantime.
+    pub fn move_to(&self, x: i16, y: i16) -> Result<(), ConnectionError> {
+        let offset = i32::from(self.thickness / 2);
+        let horizontal = ConfigureWindowAux::new()
+            .x(0)
+            .y(i32::from(y) - offset)
+            .stack_mode(StackMode::ABOVE);
+        let _ = xproto::configure_window(self.conn, self.horizontal.window(), &horizontal)?;
+        let vertical = ConfigureWindowAux::new()
+            .x(i32::from(x) - offset)
+            .y(0)
+            .stack_mode(StackMode::ABOVE);
+        let _ = xproto::configure_window(self.conn, self.vertical.window(), &vertical)?;
+        self.conn.flush()
+    }
+
+    /// Move the lines to the current position of the pointer.
+    ///
+    /// This waits for the reply to `QueryPointer` and should be called periodically. Returns the
+    /// position of the pointer. The lines are not moved if the pointer is on another screen.
+    pub fn update(&self) -> Result<(i16, i16), ReplyError> {
+        let pointer = xproto::query_pointer(self.conn, self.root)?.reply()?;
+        if pointer.same_screen {
+            self.move_to(pointer.root_x, pointer.root_y)?;
+        }
+        Ok((pointer.root_x, pointer.root_y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{premultiply, CursorImage, CursorShape, CursorStyle};
+
+    fn pixel(image: &CursorImage, x: u16, y: u16) -> u32 {
+        image.pixels[usize::from(y) * usize::from(image.width) + usize::from(x)]
+    }
+
+    #[test]
+    fn premultiplied_colors() {
+        assert_eq!(premultiply(0xff12_3456), 0xff12_3456);
+        assert_eq!(premultiply(0x80ff_ff00), 0x8080_8000);
+        assert_eq!(premultiply(0x00ff_ffff), 0);
+    }
+
+    #[test]
+    fn arrow() {
+        let style = CursorStyle::high_contrast(48);
+        let image = CursorImage::render(CursorShape::Arrow, &style);
+        assert_eq!((image.width, image.height), (48, 48));
+        assert_eq!((image.x_hot, image.y_hot), (2, 2));
+        assert_eq!(image.pixels.len(), 48 * 48);
+        // The tip is filled and the outline is left of it
+        assert_eq!(pixel(&image, 3, 6), 0xffff_ffff);
+        assert_eq!(pixel(&image, 0, 6), 0xff00_0000);
+        assert_eq!(pixel(&image, 47, 0), 0);
+        assert_eq!(pixel(&image, 0, 47), 0);
+    }
+
+    #[test]
+    fn crosshair() {
+        let style = CursorStyle::high_contrast(31).colors(0xffff_ff00, 0xff00_00ff);
+        let image = CursorImage::render(CursorShape::Crosshair, &style);
+        assert_eq!((image.x_hot, image.y_hot), (15, 15));
+        assert_eq!(pixel(&image, 15, 15), 0xffff_ff00);
+        assert_eq!(pixel(&image, 15, 0), 0xff00_00ff);
+        assert_eq!(pixel(&image, 2, 2), 0);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn crosshair_follows_position() {
+        use super::Crosshair;
+        use crate::protocol::{shape, xproto};
+        use crate::testing::FakeConnection;
+        use crate::x11_utils::ExtensionInformation;
+
+        let conn = FakeConnection::default();
+        conn.add_extension(
+            shape::X11_EXTENSION_NAME,
+            ExtensionInformation {
+                major_opcode: 129,
+                first_event: 64,
+                first_error: 0,
+            },
+        );
+        let crosshair = Crosshair::new(&conn, 0, 4, 0xff_ffff).unwrap();
+        let requests = conn.take_sent_requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[1].minor_opcode(), shape::RECTANGLES_REQUEST);
+        assert_eq!(requests[1].bytes[5], u8::from(shape::SK::INPUT));
+
+        crosshair.move_to(100, 50).unwrap();
+        let requests = conn.take_sent_requests();
+        let (horizontal, vertical) = crosshair.windows();
+        assert_eq!(requests[0].bytes[4..8], horizontal.to_ne_bytes());
+        // x, y and stack mode of the horizontal line
+        assert_eq!(requests[0].bytes[12..16], 0u32.to_ne_bytes());
+        assert_eq!(requests[0].bytes[16..20], 48u32.to_ne_bytes());
+        assert_eq!(requests[1].bytes[4..8], vertical.to_ne_bytes());
+        assert_eq!(requests[1].bytes[12..16], 98u32.to_ne_bytes());
+        assert_eq!(requests[0].bytes[0], xproto::CONFIGURE_WINDOW_REQUEST);
+    }
+}
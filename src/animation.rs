@@ -0,0 +1,448 @@
+//! Animated changes of window geometry.
+//!
+//! Window managers with animated transitions, e.g. when a tiling layout changes, move and resize
+//! windows in small steps via `ConfigureWindow`. [`GeometryAnimator`] keeps track of the running
+//! [`GeometryAnimation`]s and sends the intermediate geometries.
+//!
+//! Steps can either be driven by a timer, or be synchronised to the vertical blank of the screen
+//! via the Present extension (requires the `present` feature). In the latter case, the animator
+//! asks for a `PresentCompleteNotify` event via `PresentNotifyMSC` and takes the next step when
+//! the event arrives.
+//!
+//! ```
+//! use std::time::{Duration, Instant};
+//! use x11rb::animation::{Easing, GeometryAnimation, GeometryAnimator};
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::protocol::xproto::{Rectangle, Window};
+//!
+//! fn slide(conn: &impl Connection, window: Window, from: Rectangle, to: Rectangle)
+//!     -> Result<(), ConnectionError>
+//! {
+//!     let mut animator = GeometryAnimator::new(Duration::from_millis(16));
+//!     let duration = Duration::from_millis(200);
+//!     animator.animate(GeometryAnimation::new(window, from, to, duration, Easing::EaseOut));
+//!     animator.step(conn, Instant::now())?;
+//!     while let Some(timeout) = animator.timeout(Instant::now()) {
+//!         // A real program would wait for events with this timeout instead
+//!         std::thread::sleep(timeout);
+//!         animator.step(conn, Instant::now())?;
+//!     }
+//!     conn.flush()
+//! }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+#[cfg(feature = "present")]
+use crate::errors::ReplyOrIdError;
+#[cfg(feature = "present")]
+use crate::protocol::present;
+use crate::protocol::xproto::{self, ConfigureWindowAux, Rectangle, Window};
+#[cfg(feature = "present")]
+use crate::protocol::Event;
+
+/// An easing function that maps the linear progress of an animation to the actual progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Start slowly and speed up (cubic).
+    EaseIn,
+    /// Start fast and slow down (cubic).
+    EaseOut,
+    /// Start slowly, speed up, and slow down again (cubic).
+    EaseInOut,
+}
+
+impl Easing {
+    /// Apply the easing function to a progress between 0 and 1.
+    ///
+    /// The result is also between 0 and 1. Values outside of this range are clamped.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// An animated change of a window's geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeometryAnimation {
+    window: Window,
+    from: Rectangle,
+    to: Rectangle,
+    duration: Duration,
+    easing: Easing,
+    start: Option<Instant>,
+}
+
+impl GeometryAnimation {
+    /// Create an animation of a window from one geometry to another.
+    ///
+    /// The animation starts when it is first stepped by a [`GeometryAnimator`].
+    pub fn new(
+        window: Window,
+        from: Rectangle,
+        to: Rectangle,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        Self {
+            window,
+            from,
+            to,
+            duration,
+            easing,
+            start: None,
+        }
+    }
+
+    /// Create an animation that starts at the given time instead of the first step.
+    pub fn starting_at(mut self, start: Instant) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Get the window that is animated.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the geometry that the window has at the end of the animation.
+    pub fn target(&self) -> Rectangle {
+        self.to
+    }
+
+    /// Get the progress of the animation at the given time, between 0 and 1.
+    ///
+    /// Animations that did not start yet have a progress of 0.
+    pub fn progress(&self, now: Instant) -> f64 {
+        let start = match self.start {
+            Some(start) => start,
+            None => return 0.0,
+        };
+        if self.duration == Duration::from_secs(0) {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(start);
+        (elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+    }
+
+    /// Check if the animation is finished at the given time.
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.progress(now) >= 1.0
+    }
+
+    /// Get the geometry of the window at the given time.
+    ///
+    /// The width and height are at least 1, since X11 does not allow empty windows.
+    pub fn geometry_at(&self, now: Instant) -> Rectangle {
+        let progress = self.progress(now);
+        if progress >= 1.0 {
+            return self.to;
+        }
+        let eased = self.easing.apply(progress);
+        let lerp =
+            |from: i32, to: i32| (f64::from(from) + f64::from(to - from) * eased).round() as i32;
+        let from = self.from;
+        let to = self.to;
+        Rectangle {
+            x: lerp(from.x.into(), to.x.into()) as i16,
+            y: lerp(from.y.into(), to.y.into()) as i16,
+            width: lerp(from.width.into(), to.width.into()).max(1) as u16,
+            height: lerp(from.height.into(), to.height.into()).max(1) as u16,
+        }
+    }
+}
+
+/// Presenting-related state for synchronising steps to the vertical blank.
+#[cfg(feature = "present")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PresentSync {
+    window: Window,
+    eid: present::Event,
+    serial: u32,
+    pending: bool,
+}
+
+/// A collection of running geometry animations.
+///
+/// Call [`step()`](Self::step) after adding animations. Afterwards, either call `step()` again
+/// whenever [`timeout()`](Self::timeout) expires, or pass events to `handle_event()` when the
+/// steps are synchronised via `sync_to_present()`.
+#[derive(Debug, Clone)]
+pub struct GeometryAnimator {
+    animations: Vec<GeometryAnimation>,
+    frame_interval: Duration,
+    last_step: Option<Instant>,
+    #[cfg(feature = "present")]
+    present: Option<PresentSync>,
+}
+
+impl GeometryAnimator {
+    /// Create a new animator that steps at most once per `frame_interval` when driven by a
+    /// timer.
+    pub fn new(frame_interval: Duration) -> Self {
+        Self {
+            animations: Vec::new(),
+            frame_interval,
+            last_step: None,
+            #[cfg(feature = "present")]
+            present: None,
+        }
+    }
+
+    /// Add an animation.
+    ///
+    /// A running animation of the same window is replaced.
+    pub fn animate(&mut self, animation: GeometryAnimation) {
+        let _ = self.cancel(animation.window);
+        self.animations.push(animation);
+    }
+
+    /// Stop the animation of a window.
+    ///
+    /// The window keeps the geometry of the last step. The stopped animation is returned.
+    pub fn cancel(&mut self, window: Window) -> Option<GeometryAnimation> {
+        let index = self
+            .animations
+            .iter()
+            .position(|animation| animation.window == window)?;
+        Some(self.animations.remove(index))
+    }
+
+    /// Get the current geometry of an animated window.
+    ///
+    /// This is useful for starting a new animation of a window from wherever it currently is.
+    pub fn current_geometry(&self, window: Window, now: Instant) -> Option<Rectangle> {
+        self.animations
+            .iter()
+            .find(|animation| animation.window == window)
+            .map(|animation| animation.geometry_at(now))
+    }
+
+    /// Check if any animations are running.
+    pub fn is_active(&self) -> bool {
+        !self.animations.is_empty()
+    }
+
+    /// Get the time until the next step should happen when driven by a timer.
+    ///
+    /// Returns `None` if no animations are running or if steps are synchronised via Present.
+    pub fn timeout(&self, now: Instant) -> Option<Duration> {
+        if !self.is_active() || self.is_present_synced() {
+            return None;
+        }
+        let elapsed = match self.last_step {
+            Some(last_step) => now.saturating_duration_since(last_step),
+            None => return Some(Duration::from_secs(0)),
+        };
+        Some(self.frame_interval.checked_sub(elapsed).unwrap_or_default())
+    }
+
+    /// Move all animated windows to their geometry at the given time.
+    ///
+    /// Finished animations are removed after their final geometry was sent. When synchronised
+    /// via Present, this also asks for the event that triggers the next step.
+    pub fn step<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        now: Instant,
+    ) -> Result<(), ConnectionError> {
+        self.last_step = Some(now);
+        for animation in self.animations.iter_mut() {
+            let _ = animation.start.get_or_insert(now);
+            let geometry = animation.geometry_at(now);
+            let aux = ConfigureWindowAux::new()
+                .x(i32::from(geometry.x))
+                .y(i32::from(geometry.y))
+                .width(u32::from(geometry.width))
+                .height(u32::from(geometry.height));
+            let _ = xproto::configure_window(conn, animation.window, &aux)?;
+        }
+        self.animations
+            .retain(|animation| !animation.is_finished(now));
+        self.request_present_notify(conn)
+    }
+
+    /// Synchronise steps to the vertical blank of the CRTC that shows the given window.
+    ///
+    /// This selects `PresentCompleteNotify` events on `window`. The Present extension must be
+    /// supported by the X11 server.
+    #[cfg(feature = "present")]
+    pub fn sync_to_present<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+    ) -> Result<(), ReplyOrIdError> {
+        let eid = conn.generate_id()?;
+        let _ = present::select_input(conn, eid, window, present::EventMask::COMPLETE_NOTIFY)?;
+        self.present = Some(PresentSync {
+            window,
+            eid,
+            serial: 0,
+            pending: false,
+        });
+        Ok(())
+    }
+
+    /// Take the next step if the event is the `PresentCompleteNotify` that was requested by the
+    /// previous step.
+    ///
+    /// Returns whether the event was handled.
+    #[cfg(feature = "present")]
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+        now: Instant,
+    ) -> Result<bool, ConnectionError> {
+        let sync = match (&mut self.present, event) {
+            (Some(sync), Event::PresentCompleteNotify(event))
+                if sync.pending && event.event == sync.eid && event.serial == sync.serial =>
+            {
+                sync
+            }
+            _ => return Ok(false),
+        };
+        sync.pending = false;
+        if self.is_active() {
+            self.step(conn, now)?;
+        }
+        Ok(true)
+    }
+
+    #[cfg(feature = "present")]
+    fn is_present_synced(&self) -> bool {
+        self.present.is_some()
+    }
+
+    #[cfg(not(feature = "present"))]
+    fn is_present_synced(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "present")]
+    fn request_present_notify<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<(), ConnectionError> {
+        let active = self.is_active();
+        match &mut self.present {
+            Some(sync) if active && !sync.pending => {
+                sync.serial = sync.serial.wrapping_add(1);
+                sync.pending = true;
+                // A target MSC of 0 with a divisor of 1 means "the next vertical blank"
+                let _ = present::notify_msc(conn, sync.window, sync.serial, 0, 1, 0)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(not(feature = "present"))]
+    fn request_present_notify<C: Connection + ?Sized>(
+        &mut self,
+        _conn: &C,
+    ) -> Result<(), ConnectionError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::{Easing, GeometryAnimation, GeometryAnimator};
+    use crate::protocol::xproto::Rectangle;
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn easing_endpoints() {
+        for &easing in &[
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn interpolate_geometry() {
+        let start = Instant::now();
+        let animation = GeometryAnimation::new(
+            1,
+            rect(0, 100, 100, 10),
+            rect(100, -100, 300, 1),
+            Duration::from_millis(100),
+            Easing::Linear,
+        );
+        assert_eq!(animation.progress(start), 0.0);
+        let animation = animation.starting_at(start);
+        assert_eq!(animation.geometry_at(start), rect(0, 100, 100, 10));
+        assert_eq!(
+            animation.geometry_at(start + Duration::from_millis(50)),
+            rect(50, 0, 200, 6)
+        );
+        assert!(!animation.is_finished(start + Duration::from_millis(99)));
+        assert!(animation.is_finished(start + Duration::from_millis(100)));
+        assert_eq!(
+            animation.geometry_at(start + Duration::from_secs(1)),
+            rect(100, -100, 300, 1)
+        );
+    }
+
+    #[test]
+    fn animations_replace_each_other() {
+        let start = Instant::now();
+        let mut animator = GeometryAnimator::new(Duration::from_millis(10));
+        assert_eq!(animator.timeout(start), None);
+        let animation = |to| {
+            GeometryAnimation::new(
+                1,
+                rect(0, 0, 1, 1),
+                to,
+                Duration::from_secs(1),
+                Easing::Linear,
+            )
+            .starting_at(start)
+        };
+        animator.animate(animation(rect(10, 10, 1, 1)));
+        animator.animate(animation(rect(20, 20, 1, 1)));
+        assert_eq!(
+            animator.current_geometry(1, start + Duration::from_millis(500)),
+            Some(rect(10, 10, 1, 1))
+        );
+        assert_eq!(animator.timeout(start), Some(Duration::from_secs(0)));
+        assert!(animator.cancel(1).is_some());
+        assert!(!animator.is_active());
+    }
+}
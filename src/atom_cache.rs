@@ -0,0 +1,427 @@
+//! Interning many atoms at once and caching the results.
+//!
+//! Every `InternAtom` request needs a round trip to the X11 server. Waiting for each reply
+//! before sending the next request adds up quickly when dozens of atoms are needed, e.g. for
+//! EWMH. [`intern_atoms`] sends all requests first and only then waits for the replies.
+//!
+//! [`AtomCache`] additionally remembers the mapping between names and atoms in both directions,
+//! so that later lookups do not need any requests. For a fixed list of atoms that is known at
+//! compile time, [`atom_manager!`](crate::atom_manager) is an alternative.
+//!
+//! Large applications might use hundreds of atoms, but most of them only in rare cases. Interning
+//! all of them at startup wastes time, and interning each one when it is first needed costs a
+//! round trip per atom. [`AtomManifest`] takes a list of all atoms that might be needed and
+//! interns them in batches when one atom of a batch is first used. Its [`ManifestMetrics`] tell
+//! how many of the declared atoms were actually needed.
+//!
+//! ```
+//! use x11rb::atom_cache::AtomCache;
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Atom;
+//!
+//! fn print_state(conn: &impl Connection, cache: &mut AtomCache, states: &[Atom])
+//!     -> Result<(), ReplyError>
+//! {
+//!     let _ = cache.intern_atoms(conn, &["_NET_WM_STATE_FULLSCREEN", "_NET_WM_STATE_HIDDEN"])?;
+//!     for name in cache.atom_names(conn, states)? {
+//!         println!("{}", String::from_utf8_lossy(&name));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::predefined_atoms::{predefined_atom_by_name, predefined_name};
+use crate::protocol::xproto::{self, Atom};
+use crate::NONE;
+
+/// Intern a list of atoms with a single round trip.
+///
+/// All `InternAtom` requests are sent before waiting for the first reply. The atoms are returned
+/// in the same order as the names. If `only_if_exists` is true, atoms that do not exist yet are
+/// returned as `NONE`.
+pub fn intern_atoms<C: RequestConnection + ?Sized, N: AsRef<[u8]>>(
+    conn: &C,
+    only_if_exists: bool,
+    names: &[N],
+) -> Result<Vec<Atom>, ReplyError> {
+    let cookies = names
+        .iter()
+        .map(|name| xproto::intern_atom(conn, only_if_exists, name.as_ref()))
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    cookies
+        .into_iter()
+        .map(|cookie| Ok(cookie.reply()?.atom))
+        .collect()
+}
+
+/// A cache for the mapping between atom names and atoms.
+///
+/// Atoms are never destroyed while the X11 server is running, so the cache never has to be
+/// invalidated. A cache must only be used with a single X11 server.
+#[derive(Debug, Clone, Default)]
+pub struct AtomCache {
+    atoms: HashMap<Vec<u8>, Atom>,
+    names: HashMap<Atom, Vec<u8>>,
+}
+
+impl AtomCache {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the atom for a name if it is cached or predefined.
+    pub fn atom(&self, name: &[u8]) -> Option<Atom> {
+        self.atoms
+            .get(name)
+            .copied()
+            .or_else(|| predefined_atom_by_name(name))
+    }
+
+    /// Get the name of an atom if it is cached or predefined.
+    pub fn name(&self, atom: Atom) -> Option<&[u8]> {
+        self.names
+            .get(&atom)
+            .map(|name| &name[..])
+            .or_else(|| predefined_name(atom).map(str::as_bytes))
+    }
+
+    /// Intern an atom, using the cached value if available.
+    pub fn intern<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        name: &[u8],
+    ) -> Result<Atom, ReplyError> {
+        Ok(self.intern_atoms(conn, &[name])?[0])
+    }
+
+    /// Intern a list of atoms, using cached values where available.
+    ///
+    /// The requests for all names that are not cached are sent in one batch. The atoms are
+    /// returned in the same order as the names.
+    pub fn intern_atoms<C: RequestConnection + ?Sized, N: AsRef<[u8]>>(
+        &mut self,
+        conn: &C,
+        names: &[N],
+    ) -> Result<Vec<Atom>, ReplyError> {
+        let missing = self.missing(names.iter().map(AsRef::as_ref), |cache, name| {
+            cache.atom(name).is_some()
+        });
+        let atoms = intern_atoms(conn, false, &missing)?;
+        for (name, atom) in missing.into_iter().zip(atoms) {
+            self.insert(name.to_vec(), atom);
+        }
+        Ok(names
+            .iter()
+            .map(|name| self.atom(name.as_ref()).unwrap())
+            .collect())
+    }
+
+    /// Get the name of an atom, using the cached value if available.
+    pub fn atom_name<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        atom: Atom,
+    ) -> Result<Vec<u8>, ReplyError> {
+        Ok(self.atom_names(conn, &[atom])?.remove(0))
+    }
+
+    /// Get the names of a list of atoms, using cached values where available.
+    ///
+    /// The `GetAtomName` requests for all atoms that are not cached are sent in one batch. The
+    /// names are returned in the same order as the atoms. An X11 error is returned if one of the
+    /// atoms does not exist.
+    pub fn atom_names<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        atoms: &[Atom],
+    ) -> Result<Vec<Vec<u8>>, ReplyError> {
+        let missing = self.missing(atoms.iter().copied(), |cache, atom| {
+            cache.name(*atom).is_some()
+        });
+        let cookies = missing
+            .iter()
+            .map(|&atom| xproto::get_atom_name(conn, atom))
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+        for (atom, cookie) in missing.into_iter().zip(cookies) {
+            self.insert(cookie.reply()?.name, atom);
+        }
+        Ok(atoms
+            .iter()
+            .map(|&atom| self.name(atom).unwrap().to_vec())
+            .collect())
+    }
+
+    /// Get the items that are not cached yet, without duplicates.
+    fn missing<T: PartialEq>(
+        &self,
+        items: impl Iterator<Item = T>,
+        is_cached: impl Fn(&Self, &T) -> bool,
+    ) -> Vec<T> {
+        let mut missing = Vec::new();
+        for item in items {
+            if !is_cached(self, &item) && !missing.contains(&item) {
+                missing.push(item);
+            }
+        }
+        missing
+    }
+
+    fn insert(&mut self, name: Vec<u8>, atom: Atom) {
+        if atom == NONE {
+            return;
+        }
+        let _ = self.names.insert(atom, name.clone());
+        let _ = self.atoms.insert(name, atom);
+    }
+}
+
+/// The default number of atoms that [`AtomManifest`] interns in one batch.
+const DEFAULT_BATCH_SIZE: usize = 32;
+
+/// Statistics about the use of an [`AtomManifest`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ManifestMetrics {
+    /// The number of atoms in the manifest.
+    pub declared: usize,
+    /// The number of atoms that were interned, including atoms that were not used yet, but are
+    /// in the same batch as a used atom.
+    pub interned: usize,
+    /// The number of different atoms of the manifest that were used.
+    pub used: usize,
+    /// The number of different atoms that were used, but are missing from the manifest.
+    pub unlisted: usize,
+    /// The number of round trips to the X11 server.
+    pub round_trips: usize,
+}
+
+/// A list of all atoms that an application might use, interned lazily in batches.
+///
+/// The manifest is split into batches of consecutive atoms. When an atom is used for the first
+/// time, all atoms of its batch are interned with a single round trip. Atoms that are usually
+/// needed together should thus be declared next to each other. Atoms that are not in the
+/// manifest can still be used, but each of them needs its own round trip.
+///
+/// ```
+/// use x11rb::atom_cache::AtomManifest;
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+///
+/// fn fullscreen(conn: &impl Connection, atoms: &mut AtomManifest) -> Result<(), ReplyError> {
+///     // The other atoms of the batch are interned as well
+///     let fullscreen = atoms.intern(conn, b"_NET_WM_STATE_FULLSCREEN")?;
+///     let metrics = atoms.metrics();
+///     println!("{} of {} atoms used", metrics.used, metrics.declared);
+///     Ok(())
+/// }
+///
+/// let atoms = AtomManifest::new(&["_NET_WM_STATE", "_NET_WM_STATE_FULLSCREEN", "XdndAware"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtomManifest {
+    names: Vec<Vec<u8>>,
+    index: HashMap<Vec<u8>, usize>,
+    used: Vec<bool>,
+    unlisted: HashSet<Vec<u8>>,
+    batch_size: usize,
+    cache: AtomCache,
+    metrics: ManifestMetrics,
+}
+
+impl AtomManifest {
+    /// Create a manifest of the given atoms without interning any of them.
+    ///
+    /// Duplicate names are ignored.
+    pub fn new<N: AsRef<[u8]>>(names: &[N]) -> Self {
+        let mut index = HashMap::new();
+        let mut unique = Vec::new();
+        for name in names {
+            let name = name.as_ref();
+            if !index.contains_key(name) {
+                let _ = index.insert(name.to_vec(), unique.len());
+                unique.push(name.to_vec());
+            }
+        }
+        Self {
+            used: vec![false; unique.len()],
+            metrics: ManifestMetrics {
+                declared: unique.len(),
+                ..Default::default()
+            },
+            names: unique,
+            index,
+            unlisted: HashSet::new(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            cache: AtomCache::new(),
+        }
+    }
+
+    /// Set the number of atoms that are interned together.
+    ///
+    /// The default is 32. A batch size of one interns each atom on its own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "The batch size must not be zero");
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Get the atom for a name, interning it and the rest of its batch if necessary.
+    ///
+    /// This function waits for the replies to the `InternAtom` requests.
+    pub fn intern<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        name: &[u8],
+    ) -> Result<Atom, ReplyError> {
+        let index = self.index.get(name).copied();
+        if let Some(atom) = self.cache.atom(name) {
+            self.mark_used(index, name);
+            return Ok(atom);
+        }
+        let batch = match index {
+            Some(index) => {
+                let start = index - index % self.batch_size;
+                let end = std::cmp::min(start + self.batch_size, self.names.len());
+                self.names[start..end]
+                    .iter()
+                    .filter(|name| self.cache.atom(name).is_none())
+                    .cloned()
+                    .collect()
+            }
+            None => vec![name.to_vec()],
+        };
+        let _ = self.cache.intern_atoms(conn, &batch)?;
+        self.metrics.interned += batch.len();
+        self.metrics.round_trips += 1;
+        self.mark_used(index, name);
+        Ok(self.cache.atom(name).unwrap_or(NONE))
+    }
+
+    /// Get the atom for a name if it was already interned or is predefined.
+    ///
+    /// This does not count as a use of the atom.
+    pub fn atom(&self, name: &[u8]) -> Option<Atom> {
+        self.cache.atom(name)
+    }
+
+    /// Get the name of an atom if it was already interned or is predefined.
+    pub fn name(&self, atom: Atom) -> Option<&[u8]> {
+        self.cache.name(atom)
+    }
+
+    /// Get statistics about the use of the manifest.
+    pub fn metrics(&self) -> ManifestMetrics {
+        ManifestMetrics {
+            unlisted: self.unlisted.len(),
+            ..self.metrics
+        }
+    }
+
+    /// Iterate over the atoms of the manifest that were not used so far.
+    ///
+    /// After a typical run of an application, these are candidates for removal from the
+    /// manifest.
+    pub fn unused(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.names
+            .iter()
+            .zip(&self.used)
+            .filter(|(_, used)| !**used)
+            .map(|(name, _)| &name[..])
+    }
+
+    /// Iterate over the atoms that were used, but are missing from the manifest.
+    pub fn unlisted(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.unlisted.iter().map(|name| &name[..])
+    }
+
+    fn mark_used(&mut self, index: Option<usize>, name: &[u8]) {
+        match index {
+            Some(index) if !self.used[index] => {
+                self.used[index] = true;
+                self.metrics.used += 1;
+            }
+            Some(_) => {}
+            None => {
+                let _ = self.unlisted.insert(name.to_vec());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AtomCache;
+
+    #[test]
+    fn lookups() {
+        let mut cache = AtomCache::new();
+        cache.insert(b"FOO".to_vec(), 300);
+        cache.insert(b"BAR".to_vec(), 0);
+        assert_eq!(cache.atom(b"FOO"), Some(300));
+        assert_eq!(cache.name(300), Some(&b"FOO"[..]));
+        assert_eq!(cache.atom(b"BAR"), None);
+        assert_eq!(cache.name(0), None);
+        assert_eq!(cache.atom(b"WM_NAME"), Some(39));
+        assert_eq!(cache.name(31), Some(&b"STRING"[..]));
+    }
+
+    #[test]
+    fn missing_without_duplicates() {
+        let mut cache = AtomCache::new();
+        cache.insert(b"FOO".to_vec(), 300);
+        let names: [&[u8]; 4] = [b"BAR", b"FOO", b"BAZ", b"BAR"];
+        let missing = cache.missing(names.iter().copied(), |cache, name| {
+            cache.atoms.contains_key(*name)
+        });
+        assert_eq!(missing, [&b"BAR"[..], &b"BAZ"[..]]);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn manifest_interns_batches() {
+        use super::{AtomManifest, ManifestMetrics};
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let conn = FakeConnection::default();
+        for atom in 300..305u32 {
+            let mut reply = vec![0; 32];
+            reply[8..12].copy_from_slice(&atom.to_ne_bytes());
+            conn.respond(xproto::INTERN_ATOM_REQUEST, None, Response::Reply(reply));
+        }
+        let names: [&[u8]; 6] = [b"A", b"B", b"C", b"D", b"WM_NAME", b"A"];
+        let mut manifest = AtomManifest::new(&names).batch_size(2);
+
+        // The batch of C also contains D, and the predefined WM_NAME needs no request
+        assert_eq!(manifest.intern(&conn, b"C").unwrap(), 300);
+        assert_eq!(manifest.atom(b"D"), Some(301));
+        assert_eq!(manifest.intern(&conn, b"D").unwrap(), 301);
+        assert_eq!(manifest.intern(&conn, b"WM_NAME").unwrap(), 39);
+        assert_eq!(manifest.intern(&conn, b"B").unwrap(), 303);
+        assert_eq!(manifest.atom(b"A"), Some(302));
+        assert_eq!(manifest.intern(&conn, b"E").unwrap(), 304);
+        assert_eq!(conn.sent_requests().len(), 5);
+
+        assert_eq!(
+            manifest.metrics(),
+            ManifestMetrics {
+                declared: 5,
+                interned: 5,
+                used: 4,
+                unlisted: 1,
+                round_trips: 3,
+            }
+        );
+        assert_eq!(manifest.unused().collect::<Vec<_>>(), [b"A"]);
+        assert_eq!(manifest.unlisted().collect::<Vec<_>>(), [b"E"]);
+    }
+}
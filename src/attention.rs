@@ -0,0 +1,362 @@
+//! Windows that demand the user's attention.
+//!
+//! There are two ways for a window to ask for attention, e.g. because a chat message arrived:
+//! The urgency hint in the ICCCM `WM_HINTS` property, and the `_NET_WM_STATE_DEMANDS_ATTENTION`
+//! state of the EWMH specification. Applications usually set one of them, so taskbars have to
+//! check both. [`set_urgency`] and [`set_demands_attention`] set them, and [`AttentionTracker`]
+//! reports which windows currently demand attention.
+//!
+//! ```
+//! use x11rb::attention::{AttentionChange, AttentionTracker};
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn run(conn: &impl Connection, clients: &[Window]) -> Result<(), ReplyError> {
+//!     // `AttentionTracker::event_mask()` was selected on all clients
+//!     let mut tracker = AttentionTracker::new(conn)?;
+//!     for &client in clients {
+//!         tracker.track(conn, client)?;
+//!     }
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         match tracker.handle_event(conn, &event)? {
+//!             Some(AttentionChange::Demanded(window)) => println!("{} wants attention", window),
+//!             Some(AttentionChange::Cleared(window)) => println!("{} is fine", window),
+//!             None => {}
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::ReplyError;
+use crate::properties::{send_root_message, RequestSource, WmHints};
+use crate::protocol::xproto::{self, Atom, AtomEnum, EventMask, Property, Window};
+use crate::protocol::Event;
+
+atom_manager! {
+    AttentionAtoms: AttentionAtomsCookie {
+        _NET_WM_STATE,
+        _NET_WM_STATE_DEMANDS_ATTENTION,
+    }
+}
+
+/// The values of the `action` field of `_NET_WM_STATE` client messages.
+const NET_WM_STATE_REMOVE: u32 = 0;
+const NET_WM_STATE_ADD: u32 = 1;
+
+/// Set or clear the urgency hint in the `WM_HINTS` property of a window.
+///
+/// The other hints of the window are kept. This waits for the current `WM_HINTS`.
+pub fn set_urgency<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    urgent: bool,
+) -> Result<VoidCookie<'_, C>, ReplyError> {
+    let mut hints = WmHints::get(conn, window)?
+        .reply()
+        .unwrap_or_else(|_| WmHints::new());
+    hints.urgent = urgent;
+    Ok(hints.set(conn, window)?)
+}
+
+/// Ask the window manager to add or remove the `_NET_WM_STATE_DEMANDS_ATTENTION` state of a
+/// window.
+///
+/// This sends a `_NET_WM_STATE` client message to `root`. The window manager usually clears the
+/// state itself once the window gets activated.
+pub fn set_demands_attention<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    window: Window,
+    demands_attention: bool,
+    source: RequestSource,
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    let atom = xproto::intern_atom(conn, false, b"_NET_WM_STATE_DEMANDS_ATTENTION")?
+        .reply()?
+        .atom;
+    let action = if demands_attention {
+        NET_WM_STATE_ADD
+    } else {
+        NET_WM_STATE_REMOVE
+    };
+    send_root_message(
+        conn,
+        root,
+        window,
+        b"_NET_WM_STATE",
+        [action, atom, 0, source.into(), 0],
+    )
+}
+
+/// A change of the attention state of a tracked window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttentionChange {
+    /// The window now demands attention.
+    Demanded(Window),
+    /// The window no longer demands attention.
+    Cleared(Window),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrackedWindow {
+    window: Window,
+    /// The urgency hint in `WM_HINTS` is set.
+    urgent: bool,
+    /// `_NET_WM_STATE` contains `_NET_WM_STATE_DEMANDS_ATTENTION`.
+    demands_attention: bool,
+}
+
+impl TrackedWindow {
+    fn wants_attention(&self) -> bool {
+        self.urgent || self.demands_attention
+    }
+}
+
+/// Tracks which windows demand attention.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone)]
+pub struct AttentionTracker {
+    atoms: AttentionAtoms,
+    windows: Vec<TrackedWindow>,
+}
+
+impl AttentionTracker {
+    /// Create a new instance that does not track any windows.
+    pub fn new<C: RequestConnection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        Ok(Self {
+            atoms: AttentionAtoms::new(conn)?.reply()?,
+            windows: Vec::new(),
+        })
+    }
+
+    /// Get the events that have to be selected on tracked windows.
+    ///
+    /// `StructureNotify` is only needed for automatically untracking destroyed windows.
+    pub fn event_mask() -> EventMask {
+        EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY
+    }
+
+    /// Start tracking a window.
+    ///
+    /// This reads the current state of the window. Nothing happens if the window is already
+    /// tracked or if it does not exist anymore.
+    pub fn track<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+    ) -> Result<(), ReplyError> {
+        if self.find(window).is_some() {
+            return Ok(());
+        }
+        let urgent = xproto::get_property(
+            conn,
+            false,
+            window,
+            AtomEnum::WM_HINTS,
+            AtomEnum::WM_HINTS,
+            0,
+            9,
+        )?;
+        let state = self.read_state(conn, window)?;
+        let urgent = match ignore_x11_error(urgent.reply())? {
+            Some(reply) => is_urgent(&reply),
+            None => return Ok(()),
+        };
+        if let Some(demands_attention) = state {
+            self.windows.push(TrackedWindow {
+                window,
+                urgent,
+                demands_attention,
+            });
+        }
+        Ok(())
+    }
+
+    /// Stop tracking a window.
+    pub fn untrack(&mut self, window: Window) {
+        self.windows.retain(|tracked| tracked.window != window);
+    }
+
+    /// Check if the given window demands attention.
+    ///
+    /// Windows that are not tracked never demand attention.
+    pub fn demands_attention(&self, window: Window) -> bool {
+        self.windows
+            .iter()
+            .any(|tracked| tracked.window == window && tracked.wants_attention())
+    }
+
+    /// Get all tracked windows that demand attention.
+    pub fn windows_demanding_attention(&self) -> impl Iterator<Item = Window> + '_ {
+        self.windows
+            .iter()
+            .filter(|tracked| tracked.wants_attention())
+            .map(|tracked| tracked.window)
+    }
+
+    /// Handle an event and report whether a tracked window started or stopped demanding
+    /// attention.
+    ///
+    /// When a relevant property changes, its new value is read from the X11 server. Destroyed
+    /// windows are no longer tracked.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<Option<AttentionChange>, ReplyError> {
+        let (window, update) = match event {
+            Event::PropertyNotify(event) => {
+                let window = event.window;
+                let old = match self.find(window) {
+                    Some(tracked) => *tracked,
+                    None => return Ok(None),
+                };
+                let deleted = event.state == Property::DELETE;
+                let new = if event.atom == Atom::from(AtomEnum::WM_HINTS) {
+                    let urgent = if deleted {
+                        false
+                    } else {
+                        let reply = xproto::get_property(
+                            conn,
+                            false,
+                            window,
+                            AtomEnum::WM_HINTS,
+                            AtomEnum::WM_HINTS,
+                            0,
+                            9,
+                        )?
+                        .reply();
+                        matches!(ignore_x11_error(reply)?, Some(reply) if is_urgent(&reply))
+                    };
+                    TrackedWindow { urgent, ..old }
+                } else if event.atom == self.atoms._NET_WM_STATE {
+                    let demands_attention = if deleted {
+                        false
+                    } else {
+                        self.read_state(conn, window)?.unwrap_or(false)
+                    };
+                    TrackedWindow {
+                        demands_attention,
+                        ..old
+                    }
+                } else {
+                    return Ok(None);
+                };
+                (window, Some(new))
+            }
+            Event::DestroyNotify(event) => (event.window, None),
+            _ => return Ok(None),
+        };
+        let tracked = match self.find(window) {
+            Some(tracked) => tracked,
+            None => return Ok(None),
+        };
+        let wanted_attention = tracked.wants_attention();
+        let wants_attention = match update {
+            Some(new) => {
+                *tracked = new;
+                new.wants_attention()
+            }
+            None => {
+                self.untrack(window);
+                false
+            }
+        };
+        Ok(match (wanted_attention, wants_attention) {
+            (false, true) => Some(AttentionChange::Demanded(window)),
+            (true, false) => Some(AttentionChange::Cleared(window)),
+            _ => None,
+        })
+    }
+
+    /// Check if `_NET_WM_STATE_DEMANDS_ATTENTION` is set on the window.
+    ///
+    /// Returns `None` if the window does not exist.
+    fn read_state<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        window: Window,
+    ) -> Result<Option<bool>, ReplyError> {
+        let reply = xproto::get_property(
+            conn,
+            false,
+            window,
+            self.atoms._NET_WM_STATE,
+            AtomEnum::ATOM,
+            0,
+            u32::MAX,
+        )?
+        .reply();
+        Ok(ignore_x11_error(reply)?.map(|reply| {
+            reply
+                .value32()
+                .into_iter()
+                .flatten()
+                .any(|atom| atom == self.atoms._NET_WM_STATE_DEMANDS_ATTENTION)
+        }))
+    }
+
+    fn find(&mut self, window: Window) -> Option<&mut TrackedWindow> {
+        self.windows
+            .iter_mut()
+            .find(|tracked| tracked.window == window)
+    }
+}
+
+/// Check if the urgency hint is set in a `WM_HINTS` property.
+fn is_urgent(reply: &xproto::GetPropertyReply) -> bool {
+    matches!(WmHints::from_reply(reply), Ok(hints) if hints.urgent)
+}
+
+/// Turn X11 errors into `None`, since they mean that the window was destroyed.
+fn ignore_x11_error<T>(result: Result<T, ReplyError>) -> Result<Option<T>, ReplyError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ReplyError::X11Error(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AttentionAtoms, AttentionTracker, TrackedWindow};
+
+    fn tracked(window: u32, urgent: bool, demands_attention: bool) -> TrackedWindow {
+        TrackedWindow {
+            window,
+            urgent,
+            demands_attention,
+        }
+    }
+
+    #[test]
+    fn either_hint_demands_attention() {
+        let mut tracker = AttentionTracker {
+            atoms: AttentionAtoms {
+                _NET_WM_STATE: 1,
+                _NET_WM_STATE_DEMANDS_ATTENTION: 2,
+            },
+            windows: vec![
+                tracked(10, false, false),
+                tracked(11, true, false),
+                tracked(12, false, true),
+                tracked(13, true, true),
+            ],
+        };
+        assert!(!tracker.demands_attention(10));
+        assert!(tracker.demands_attention(11));
+        assert!(tracker.demands_attention(12));
+        assert!(!tracker.demands_attention(42));
+        let windows = tracker.windows_demanding_attention().collect::<Vec<_>>();
+        assert_eq!(windows, [11, 12, 13]);
+
+        tracker.untrack(12);
+        assert!(!tracker.demands_attention(12));
+        let windows = tracker.windows_demanding_attention().collect::<Vec<_>>();
+        assert_eq!(windows, [11, 13]);
+    }
+}
@@ -0,0 +1,225 @@
+//! Window attributes that preserve window contents.
+//!
+//! The `bit_gravity`, `backing_store` and `save_under` attributes of a window control whether the
+//! X11 server keeps the contents of the window when it is resized, obscured or covered by a
+//! short-lived window. Servers are free to ignore them: Each [`Screen`] announces in its
+//! `backing_stores` and `save_unders` fields what the server supports. Many X11 servers, e.g. Xorg
+//! without the `+bs` option, do not support backing store at all.
+//!
+//! A client that sets these attributes still has to handle `Expose` events. Even a server that
+//! honors backing store might discard the contents, e.g. when it runs out of memory. Bit gravity
+//! cannot be queried at all, so it should only be seen as an optimisation that reduces the area
+//! that has to be redrawn.
+//!
+//! ```
+//! use x11rb::backing_store::{ContentAttributes, ContentPreservation};
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::protocol::xproto::{BackingStore, Gravity, Window};
+//!
+//! fn preserve(conn: &impl Connection, screen: usize, window: Window) -> Result<(), ConnectionError> {
+//!     let support = ContentPreservation::from_screen(&conn.setup().roots[screen]);
+//!     let attributes = ContentAttributes::new()
+//!         .bit_gravity(Gravity::NORTH_WEST)
+//!         .backing_store(BackingStore::WHEN_MAPPED)
+//!         .restrict_to(&support);
+//!     if attributes.backing_store == BackingStore::NOT_USEFUL {
+//!         println!("The contents of the window are lost when it is obscured");
+//!     }
+//!     attributes.set(conn, window)?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::{
+    self, BackingStore, ChangeWindowAttributesAux, CreateWindowAux, GetWindowAttributesReply,
+    Gravity, Screen, Window,
+};
+
+/// Rank the values of [`BackingStore`] by how much of the window contents they preserve.
+fn rank(backing_store: BackingStore) -> u32 {
+    match backing_store {
+        BackingStore::ALWAYS => 2,
+        BackingStore::WHEN_MAPPED => 1,
+        _ => 0,
+    }
+}
+
+/// What a screen supports for preserving window contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentPreservation {
+    /// The most that the screen does for the `backing_store` attribute of windows.
+    pub backing_stores: BackingStore,
+    /// Whether the screen honors the `save_under` attribute of windows.
+    pub save_unders: bool,
+}
+
+impl ContentPreservation {
+    /// Get what the given screen supports.
+    pub fn from_screen(screen: &Screen) -> Self {
+        Self {
+            backing_stores: screen.backing_stores,
+            save_unders: screen.save_unders,
+        }
+    }
+
+    /// Check if the screen maintains backing store for windows that are unmapped.
+    pub fn backing_store_always(&self) -> bool {
+        self.backing_stores == BackingStore::ALWAYS
+    }
+
+    /// Check if the screen maintains backing store at least for mapped windows.
+    pub fn backing_store_when_mapped(&self) -> bool {
+        rank(self.backing_stores) >= rank(BackingStore::WHEN_MAPPED)
+    }
+
+    /// Get the backing store that a window with the given `backing_store` attribute actually
+    /// gets.
+    pub fn effective_backing_store(&self, requested: BackingStore) -> BackingStore {
+        if rank(requested) <= rank(self.backing_stores) {
+            requested
+        } else {
+            self.backing_stores
+        }
+    }
+
+    /// Get whether a window with the given `save_under` attribute actually gets save-under.
+    pub fn effective_save_under(&self, requested: bool) -> bool {
+        requested && self.save_unders
+    }
+}
+
+/// The attributes of a window that control how its contents are preserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentAttributes {
+    /// Which part of the contents is kept when the window is resized.
+    pub bit_gravity: Gravity,
+    /// Whether the server should keep the contents of obscured parts of the window.
+    pub backing_store: BackingStore,
+    /// Whether the server should keep the contents of the windows below this one.
+    ///
+    /// This is useful for short-lived windows like menus and tooltips.
+    pub save_under: bool,
+}
+
+impl Default for ContentAttributes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContentAttributes {
+    /// Create the attributes that a new window has by default.
+    ///
+    /// With these attributes, the server does not preserve any contents.
+    pub fn new() -> Self {
+        Self {
+            bit_gravity: Gravity::BIT_FORGET,
+            backing_store: BackingStore::NOT_USEFUL,
+            save_under: false,
+        }
+    }
+
+    /// Get the attributes of a window from the reply to `GetWindowAttributes`.
+    pub fn from_reply(reply: &GetWindowAttributesReply) -> Self {
+        Self {
+            bit_gravity: reply.bit_gravity,
+            backing_store: reply.backing_store,
+            save_under: reply.save_under,
+        }
+    }
+
+    /// Set the bit gravity.
+    pub fn bit_gravity(mut self, bit_gravity: Gravity) -> Self {
+        self.bit_gravity = bit_gravity;
+        self
+    }
+
+    /// Set the backing store.
+    pub fn backing_store(mut self, backing_store: BackingStore) -> Self {
+        self.backing_store = backing_store;
+        self
+    }
+
+    /// Set whether save-under is used.
+    pub fn save_under(mut self, save_under: bool) -> Self {
+        self.save_under = save_under;
+        self
+    }
+
+    /// Reduce the attributes to what the screen actually supports.
+    ///
+    /// The bit gravity is kept, since the server does not say whether it honors it.
+    pub fn restrict_to(self, support: &ContentPreservation) -> Self {
+        Self {
+            bit_gravity: self.bit_gravity,
+            backing_store: support.effective_backing_store(self.backing_store),
+            save_under: support.effective_save_under(self.save_under),
+        }
+    }
+
+    /// Add the attributes to the attributes of a new window.
+    pub fn apply_to_create(&self, aux: CreateWindowAux) -> CreateWindowAux {
+        aux.bit_gravity(self.bit_gravity)
+            .backing_store(self.backing_store)
+            .save_under(u32::from(self.save_under))
+    }
+
+    /// Add the attributes to a change of window attributes.
+    pub fn apply_to_change(&self, aux: ChangeWindowAttributesAux) -> ChangeWindowAttributesAux {
+        aux.bit_gravity(self.bit_gravity)
+            .backing_store(self.backing_store)
+            .save_under(u32::from(self.save_under))
+    }
+
+    /// Set the attributes on an existing window.
+    pub fn set<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        window: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let aux = self.apply_to_change(ChangeWindowAttributesAux::new());
+        xproto::change_window_attributes(conn, window, &aux)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ContentAttributes, ContentPreservation};
+    use crate::protocol::xproto::{BackingStore, Gravity};
+
+    #[test]
+    fn restrict_attributes() {
+        let attributes = ContentAttributes::new()
+            .bit_gravity(Gravity::STATIC)
+            .backing_store(BackingStore::ALWAYS)
+            .save_under(true);
+
+        let none = ContentPreservation {
+            backing_stores: BackingStore::NOT_USEFUL,
+            save_unders: false,
+        };
+        assert_eq!(
+            attributes.restrict_to(&none),
+            ContentAttributes::new().bit_gravity(Gravity::STATIC)
+        );
+
+        let when_mapped = ContentPreservation {
+            backing_stores: BackingStore::WHEN_MAPPED,
+            save_unders: true,
+        };
+        assert!(when_mapped.backing_store_when_mapped());
+        assert!(!when_mapped.backing_store_always());
+        assert_eq!(
+            attributes.restrict_to(&when_mapped),
+            attributes.backing_store(BackingStore::WHEN_MAPPED)
+        );
+        assert_eq!(
+            when_mapped.effective_backing_store(BackingStore::NOT_USEFUL),
+            BackingStore::NOT_USEFUL
+        );
+    }
+}
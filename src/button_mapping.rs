@@ -0,0 +1,189 @@
+//! Reading and changing the pointer button mapping, e.g. for left-handed mode.
+//!
+//! The X11 server maps the physical buttons of a pointer device to logical buttons. Swapping
+//! logical buttons 1 and 3 makes the right button act as the primary one. The core protocol only
+//! knows a single mapping for the core pointer, which [`set_core_left_handed`] changes. With the
+//! `xinput` feature, [`set_left_handed`] changes individual input devices instead.
+//!
+//! ```
+//! use x11rb::button_mapping::set_core_left_handed;
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::MappingStatus;
+//!
+//! fn make_left_handed(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     if set_core_left_handed(conn, true)? == MappingStatus::BUSY {
+//!         println!("Release all buttons and try again");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(feature = "xinput")]
+use std::convert::TryFrom;
+
+use crate::connection::RequestConnection;
+use crate::errors::ReplyError;
+#[cfg(feature = "xinput")]
+use crate::protocol::xinput::{self, DeviceId, DeviceType, XIChangePropertyAux};
+use crate::protocol::xproto::{self, MappingStatus};
+#[cfg(feature = "xinput")]
+use crate::protocol::xproto::{AtomEnum, PropMode};
+#[cfg(feature = "xinput")]
+use crate::NONE;
+
+/// The device property that the libinput driver uses for left-handed mode.
+#[cfg(feature = "xinput")]
+const LIBINPUT_LEFT_HANDED: &[u8] = b"libinput Left Handed Enabled";
+
+/// Check whether the given button map has buttons 1 and 3 swapped.
+pub fn is_left_handed(map: &[u8]) -> bool {
+    matches!(map, [3, _, 1, ..])
+}
+
+/// Swap the entries for buttons 1 and 3 in a button map if needed.
+///
+/// Returns whether the map was changed. All other buttons are left alone, so that custom
+/// mappings of e.g. extra mouse buttons are kept. Maps with less than three buttons are never
+/// changed.
+pub fn set_left_handed_map(map: &mut [u8], left_handed: bool) -> bool {
+    if map.len() < 3 || is_left_handed(map) == left_handed {
+        return false;
+    }
+    map.swap(0, 2);
+    true
+}
+
+/// Get the button map of the core pointer.
+///
+/// The first entry is the logical button for the physical button 1, and so on.
+pub fn pointer_mapping<C: RequestConnection + ?Sized>(conn: &C) -> Result<Vec<u8>, ReplyError> {
+    Ok(xproto::get_pointer_mapping(conn)?.reply()?.map)
+}
+
+/// Swap buttons 1 and 3 of the core pointer, or undo this.
+///
+/// The X11 server refuses to change the mapping while one of the affected buttons is held down.
+/// In this case, `MappingStatus::BUSY` is returned and the caller should try again later.
+pub fn set_core_left_handed<C: RequestConnection + ?Sized>(
+    conn: &C,
+    left_handed: bool,
+) -> Result<MappingStatus, ReplyError> {
+    let mut map = pointer_mapping(conn)?;
+    if !set_left_handed_map(&mut map, left_handed) {
+        return Ok(MappingStatus::SUCCESS);
+    }
+    Ok(xproto::set_pointer_mapping(conn, &map)?.reply()?.status)
+}
+
+/// How [`set_device_left_handed`] changed a device.
+#[cfg(feature = "xinput")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mechanism {
+    /// The `libinput Left Handed Enabled` property of the device was changed.
+    LibinputProperty,
+    /// The button map of the device was changed, with the given result.
+    ButtonMap(MappingStatus),
+}
+
+/// The devices that [`set_left_handed`] should change.
+#[cfg(feature = "xinput")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Devices {
+    /// All slave pointer devices, e.g. mice and touchpads, except for the XTEST pointer.
+    AllPointers,
+    /// Only the given devices.
+    Only(Vec<DeviceId>),
+}
+
+/// Swap buttons 1 and 3 of a single input device, or undo this.
+///
+/// If the device is handled by the libinput driver, its `libinput Left Handed Enabled` property
+/// is changed, since libinput would otherwise apply its own setting on top of the button map.
+/// Other devices get their button map changed via XInput.
+#[cfg(feature = "xinput")]
+pub fn set_device_left_handed<C: RequestConnection + ?Sized>(
+    conn: &C,
+    device: DeviceId,
+    left_handed: bool,
+) -> Result<Mechanism, ReplyError> {
+    let atom = xproto::intern_atom(conn, true, LIBINPUT_LEFT_HANDED)?;
+    let properties = xinput::xi_list_properties(conn, device)?;
+    let atom = atom.reply()?.atom;
+    if atom != NONE && properties.reply()?.properties.contains(&atom) {
+        xinput::xi_change_property(
+            conn,
+            device,
+            PropMode::REPLACE,
+            atom,
+            AtomEnum::INTEGER.into(),
+            1,
+            &XIChangePropertyAux::Data8(vec![left_handed.into()]),
+        )?
+        .check()?;
+        return Ok(Mechanism::LibinputProperty);
+    }
+
+    // XInput 1 device IDs are a single byte; the X11 server never hands out larger IDs.
+    let device = u8::try_from(device).unwrap_or(u8::MAX);
+    let mut map = xinput::get_device_button_mapping(conn, device)?
+        .reply()?
+        .map;
+    if !set_left_handed_map(&mut map, left_handed) {
+        return Ok(Mechanism::ButtonMap(MappingStatus::SUCCESS));
+    }
+    let reply = xinput::set_device_button_mapping(conn, device, &map)?.reply()?;
+    Ok(Mechanism::ButtonMap(reply.status))
+}
+
+/// Swap buttons 1 and 3 of several input devices, or undo this.
+///
+/// Returns how each device was changed. See [`set_device_left_handed`] for details.
+#[cfg(feature = "xinput")]
+pub fn set_left_handed<C: RequestConnection + ?Sized>(
+    conn: &C,
+    devices: &Devices,
+    left_handed: bool,
+) -> Result<Vec<(DeviceId, Mechanism)>, ReplyError> {
+    let devices = match devices {
+        Devices::Only(devices) => devices.clone(),
+        Devices::AllPointers => xinput::xi_query_device(conn, xinput::Device::ALL)?
+            .reply()?
+            .infos
+            .into_iter()
+            .filter(|info| {
+                info.type_ == DeviceType::SLAVE_POINTER && !info.name.ends_with(b"XTEST pointer")
+            })
+            .map(|info| info.deviceid)
+            .collect(),
+    };
+    devices
+        .into_iter()
+        .map(|device| Ok((device, set_device_left_handed(conn, device, left_handed)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_left_handed, set_left_handed_map};
+
+    #[test]
+    fn swap_buttons() {
+        let mut map = [1, 2, 3, 4, 5, 9, 8];
+        assert!(!is_left_handed(&map));
+        assert!(!set_left_handed_map(&mut map, false));
+        assert!(set_left_handed_map(&mut map, true));
+        assert_eq!(map, [3, 2, 1, 4, 5, 9, 8]);
+        assert!(is_left_handed(&map));
+        assert!(!set_left_handed_map(&mut map, true));
+        assert!(set_left_handed_map(&mut map, false));
+        assert_eq!(map, [1, 2, 3, 4, 5, 9, 8]);
+    }
+
+    #[test]
+    fn short_maps_are_not_changed() {
+        let mut map = [1, 2];
+        assert!(!set_left_handed_map(&mut map, true));
+        assert_eq!(map, [1, 2]);
+    }
+}
@@ -0,0 +1,128 @@
+//! A source of the current time that can be replaced in tests.
+//!
+//! Most time-based helpers in x11rb, e.g. [`ResizeDebouncer`](crate::resize::ResizeDebouncer),
+//! never read the time themselves. They take the current time as a `now: Instant` argument
+//! instead, so that the application decides where it comes from. Code that wants to be testable
+//! gets the time from a [`Clock`] and passes it on. Helpers that have to measure time on their own,
+//! like the [`middleware`](crate::middleware) layers, accept a [`Clock`] as well.
+//!
+//! In production, [`SystemClock`] returns [`Instant::now`]. In tests, a [`ManualClock`] only
+//! moves when it is told to, which makes time-dependent logic deterministic:
+//!
+//! ```
+//! use std::time::Duration;
+//! use x11rb::clock::{Clock, ManualClock};
+//! use x11rb::resize::{ResizeDebouncer, ResizeEvent};
+//!
+//! let clock = ManualClock::new();
+//! let mut resize = ResizeDebouncer::new(1, 2, Duration::from_millis(100));
+//! assert!(resize.configure(640, 480, clock.now()).is_some());
+//!
+//! clock.advance(Duration::from_millis(99));
+//! assert_eq!(resize.settle(clock.now()), None);
+//! clock.advance(Duration::from_millis(1));
+//! assert_eq!(
+//!     resize.settle(clock.now()),
+//!     Some(ResizeEvent::Settled { width: 640, height: 480 })
+//! );
+//! ```
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A source of the current time.
+pub trait Clock: Debug + Send + Sync {
+    /// Get the current time.
+    fn now(&self) -> Instant;
+}
+
+/// The clock of the operating system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when it is told to.
+///
+/// Clones of a `ManualClock` share their time, so a test can keep one clone and give another one
+/// to the code under test.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    /// Create a new clock that starts at the current time of the operating system.
+    ///
+    /// The start time does not matter for most tests, since [`Instant`]s can only be compared
+    /// with each other.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Create a new clock that starts at the given time.
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Move the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+
+    /// Set the clock to the given time.
+    ///
+    /// Other than [`Instant::now`], this allows the clock to go backwards.
+    pub fn set(&self, now: Instant) {
+        *self.now.lock().unwrap() = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Clock, ManualClock};
+
+    #[test]
+    fn clones_share_time() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let other = clock.clone();
+        other.advance(Duration::from_secs(2));
+        assert_eq!(clock.now() - start, Duration::from_secs(2));
+        clock.set(start);
+        assert_eq!(other.now(), start);
+    }
+}
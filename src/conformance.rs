@@ -0,0 +1,403 @@
+//! Checks that a [`Connection`] implementation behaves like the connections in this library.
+//!
+//! The functions in this module send real requests and thus need a connection to a running X11
+//! server. They are meant to be called from a test of a custom `Connection` implementation (e.g.
+//! a wrapper around another connection or a connection for an async runtime):
+//!
+//! ```no_run
+//! use x11rb::conformance::{run_all_checks, CheckOutcome, ConnectionConformance};
+//!
+//! let (conn, _) = x11rb::connect(None).unwrap();
+//! match conn.check_sequence_numbers().unwrap() {
+//!     CheckOutcome::Passed => println!("Sequence numbers work"),
+//!     CheckOutcome::Skipped(reason) => println!("Check skipped: {}", reason),
+//! }
+//!
+//! // Or just run everything
+//! for (name, result) in run_all_checks(&conn) {
+//!     println!("{}: {:?}", name, result);
+//! }
+//! ```
+//!
+//! The checks only clean up after themselves if they succeed. Since some of them leave events or
+//! errors in the connection's queue when they fail, it is best to use a new connection for each
+//! check.
+//!
+//! Checks that need a specific X11 extension are only available if the corresponding feature of
+//! this library is enabled. If the X11 server does not support the extension, the check is
+//! skipped.
+//!
+//! The code in this module is only available when the `conformance` feature of the library is
+//! enabled.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::connection::{Connection, SequenceNumber};
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{self, ConnectionExt as _};
+use crate::protocol::{ErrorKind, Event};
+use crate::wrapper::ConnectionExt as _;
+use crate::x11_utils::X11Error;
+
+/// The result of a successful conformance check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckOutcome {
+    /// The connection behaved as expected.
+    Passed,
+    /// The check could not be run, e.g. because the X11 server lacks a required extension.
+    Skipped(&'static str),
+}
+
+/// A conformance check failed.
+#[derive(Debug)]
+pub enum ConformanceError {
+    /// Communication with the X11 server failed.
+    ConnectionError(ConnectionError),
+    /// The X11 server sent an error that was not expected by the check.
+    ReplyOrIdError(ReplyOrIdError),
+    /// The connection did not behave as expected.
+    Failed(String),
+}
+
+impl Error for ConformanceError {}
+
+impl fmt::Display for ConformanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConformanceError::ConnectionError(e) => write!(f, "{}", e),
+            ConformanceError::ReplyOrIdError(e) => write!(f, "{}", e),
+            ConformanceError::Failed(msg) => write!(f, "Conformance check failed: {}", msg),
+        }
+    }
+}
+
+impl From<ConnectionError> for ConformanceError {
+    fn from(err: ConnectionError) -> Self {
+        ConformanceError::ConnectionError(err)
+    }
+}
+
+impl From<ReplyError> for ConformanceError {
+    fn from(err: ReplyError) -> Self {
+        ConformanceError::ReplyOrIdError(err.into())
+    }
+}
+
+impl From<ReplyOrIdError> for ConformanceError {
+    fn from(err: ReplyOrIdError) -> Self {
+        ConformanceError::ReplyOrIdError(err)
+    }
+}
+
+/// Return a `ConformanceError::Failed` if the condition does not hold.
+pub(crate) fn ensure(
+    condition: bool,
+    msg: impl FnOnce() -> String,
+) -> Result<(), ConformanceError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(ConformanceError::Failed(msg()))
+    }
+}
+
+/// A window id that is never valid.
+const INVALID_WINDOW: xproto::Window = 0;
+
+/// Check that an X11 error has the expected kind and sequence number.
+fn check_x11_error(
+    error: &X11Error,
+    kind: ErrorKind,
+    sequence: SequenceNumber,
+) -> Result<(), ConformanceError> {
+    ensure(error.error_kind == kind, || {
+        format!("Expected a {:?} error, got {:?}", kind, error.error_kind)
+    })?;
+    // X11 only transmits the lower 16 bits of the sequence number
+    ensure(error.sequence == sequence as u16, || {
+        format!(
+            "Expected an error for request {}, got one for {}",
+            sequence, error.sequence
+        )
+    })
+}
+
+/// Get all errors that are queued as events after waiting for all requests to be processed.
+fn collect_errors<C: Connection + ?Sized>(
+    conn: &C,
+) -> Result<Vec<(X11Error, SequenceNumber)>, ConformanceError> {
+    conn.sync()?;
+    let mut errors = Vec::new();
+    while let Some((event, seqno)) = conn.poll_for_event_with_sequence()? {
+        if let Event::Error(error) = event {
+            errors.push((error, seqno));
+        }
+    }
+    Ok(errors)
+}
+
+/// Check that sequence numbers are assigned correctly and replies reach the right cookie.
+///
+/// This sends a mix of requests with and without replies and checks that consecutive requests get
+/// consecutive sequence numbers. The replies are then fetched in reverse order to check that they
+/// are not mixed up.
+pub fn check_sequence_numbers<C: Connection + ?Sized>(
+    conn: &C,
+) -> Result<CheckOutcome, ConformanceError> {
+    const ROUNDS: usize = 50;
+    let mut last = None;
+    let mut check_next = |seqno: SequenceNumber| {
+        let previous = last.replace(seqno);
+        match previous {
+            Some(previous) if previous + 1 != seqno => Err(ConformanceError::Failed(format!(
+                "Sequence number {} follows {}",
+                seqno, previous
+            ))),
+            _ => Ok(()),
+        }
+    };
+
+    let mut cookies = Vec::with_capacity(ROUNDS);
+    for i in 0..ROUNDS {
+        let void = conn.no_operation()?;
+        check_next(void.sequence_number())?;
+        void.check()?;
+        // Use different atoms so that the replies can be told apart
+        let atom = xproto::Atom::from(xproto::AtomEnum::PRIMARY) + i as u32;
+        let cookie = conn.get_atom_name(atom)?;
+        check_next(cookie.sequence_number())?;
+        cookies.push((atom, cookie));
+    }
+    let expected_names = cookies
+        .iter()
+        .map(|(atom, _)| Ok(conn.get_atom_name(*atom)?.reply()?.name))
+        .collect::<Result<Vec<_>, ConformanceError>>()?;
+    for ((_, cookie), expected) in cookies.into_iter().zip(expected_names).rev() {
+        let seqno = cookie.sequence_number();
+        let reply = cookie.reply()?;
+        ensure(reply.sequence == seqno as u16, || {
+            format!(
+                "Reply to request {} has sequence number {}",
+                seqno, reply.sequence
+            )
+        })?;
+        ensure(reply.name == expected, || {
+            format!("Reply to request {} has the wrong content", seqno)
+        })?;
+    }
+    Ok(CheckOutcome::Passed)
+}
+
+/// Check that X11 errors are delivered to the right place.
+///
+/// This causes errors for requests with and without replies and checks that they appear where
+/// the documentation of [`crate::cookie`] says they should: In the cookie when the error is
+/// checked, as an event when it is not, and nowhere when it is ignored.
+pub fn check_error_routing<C: Connection + ?Sized>(
+    conn: &C,
+) -> Result<CheckOutcome, ConformanceError> {
+    // Make sure no old events confuse the check
+    let _ = collect_errors(conn)?;
+
+    // Checked errors for requests with and without reply
+    let cookie = conn.get_geometry(INVALID_WINDOW)?;
+    let seqno = cookie.sequence_number();
+    match cookie.reply() {
+        Err(ReplyError::X11Error(error)) => check_x11_error(&error, ErrorKind::Drawable, seqno)?,
+        Err(err) => return Err(err.into()),
+        Ok(_) => return Err(ConformanceError::Failed("GetGeometry did not fail".into())),
+    }
+    let cookie = conn.destroy_window(INVALID_WINDOW)?;
+    let seqno = cookie.sequence_number();
+    match cookie.check() {
+        Err(ReplyError::X11Error(error)) => check_x11_error(&error, ErrorKind::Window, seqno)?,
+        Err(err) => return Err(err.into()),
+        Ok(()) => {
+            return Err(ConformanceError::Failed(
+                "DestroyWindow did not fail".into(),
+            ))
+        }
+    }
+
+    // Unchecked errors end up as events
+    let cookie = conn.destroy_window(INVALID_WINDOW)?;
+    let unchecked_void = cookie.sequence_number();
+    drop(cookie);
+    let cookie = conn.get_geometry(INVALID_WINDOW)?;
+    let unchecked_reply = cookie.sequence_number();
+    ensure(cookie.raw_reply_unchecked()?.is_none(), || {
+        "Got a reply for an invalid GetGeometry".into()
+    })?;
+
+    // Ignored errors vanish
+    conn.destroy_window(INVALID_WINDOW)?.ignore_error();
+    conn.get_geometry(INVALID_WINDOW)?
+        .discard_reply_and_errors();
+
+    let errors = collect_errors(conn)?;
+    ensure(errors.len() == 2, || {
+        format!("Expected two errors as events, got {:?}", errors)
+    })?;
+    check_x11_error(&errors[0].0, ErrorKind::Window, unchecked_void)?;
+    check_x11_error(&errors[1].0, ErrorKind::Drawable, unchecked_reply)?;
+    ensure(
+        errors[0].1 == unchecked_void && errors[1].1 == unchecked_reply,
+        || format!("Errors have the wrong sequence numbers: {:?}", errors),
+    )?;
+
+    Ok(CheckOutcome::Passed)
+}
+
+/// Check that requests larger than what fits into the normal length field can be sent.
+///
+/// This sets a large property on a new window via the BIG-REQUESTS extension and reads it back.
+///
+/// The check is skipped if the X11 server does not accept large enough requests.
+pub fn check_big_requests<C: Connection + ?Sized>(
+    conn: &C,
+) -> Result<CheckOutcome, ConformanceError> {
+    // Exceed the 2^16 * 4 bytes that can be represented without BIG-REQUESTS
+    const SIZE: usize = 300_000;
+    if conn.maximum_request_bytes() < SIZE + 100 {
+        return Ok(CheckOutcome::Skipped("BIG-REQUESTS is not supported"));
+    }
+
+    let data = (0..SIZE).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    let window = conn.generate_id()?;
+    let root = conn.setup().roots[0].root;
+    conn.create_window(
+        0,
+        window,
+        root,
+        0,
+        0,
+        1,
+        1,
+        0,
+        xproto::WindowClass::INPUT_ONLY,
+        0,
+        &Default::default(),
+    )?
+    .check()?;
+    conn.change_property8(
+        xproto::PropMode::REPLACE,
+        window,
+        xproto::AtomEnum::WM_NAME,
+        xproto::AtomEnum::STRING,
+        &data,
+    )?
+    .check()?;
+    let reply = conn
+        .get_property(
+            false,
+            window,
+            xproto::AtomEnum::WM_NAME,
+            xproto::AtomEnum::STRING,
+            0,
+            u32::MAX,
+        )?
+        .reply()?;
+    conn.destroy_window(window)?.check()?;
+    ensure(reply.value == data, || {
+        format!(
+            "Property has length {} instead of {} or wrong content",
+            reply.value.len(),
+            SIZE
+        )
+    })?;
+    Ok(CheckOutcome::Passed)
+}
+
+/// Check that replies carrying file descriptors are received correctly.
+///
+/// This uses the `CreateSegment` request of the MIT-SHM extension (version 1.2), which is answered
+/// with a reply containing one file descriptor. The received file descriptor is then sent back to
+/// the X11 server with `AttachFd`, so that sending file descriptors is checked as well.
+///
+/// The check is skipped if the X11 server does not support MIT-SHM 1.2.
+#[cfg(feature = "shm")]
+pub fn check_reply_with_fds<C: Connection + ?Sized>(
+    conn: &C,
+) -> Result<CheckOutcome, ConformanceError> {
+    use crate::protocol::shm;
+
+    if conn
+        .extension_information(shm::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(CheckOutcome::Skipped("MIT-SHM is not supported"));
+    }
+    let version = shm::query_version(conn)?.reply()?;
+    if (version.major_version, version.minor_version) < (1, 2) {
+        return Ok(CheckOutcome::Skipped("MIT-SHM 1.2 is not supported"));
+    }
+
+    // First check the raw reply to see how many FDs the connection handed out
+    let seg = conn.generate_id()?;
+    let (reply, fds) = shm::create_segment(conn, seg, 4096, true)?.raw_reply()?;
+    ensure(fds.len() == 1, || {
+        format!("Expected one FD with the reply, got {}", fds.len())
+    })?;
+    ensure(reply.as_ref().get(1) == Some(&1), || {
+        "The reply's nfd field does not match the received FDs".to_string()
+    })?;
+    shm::detach(conn, seg)?.check()?;
+
+    // Then check the parsed reply and send the FD back to the X11 server
+    let seg = conn.generate_id()?;
+    let reply = shm::create_segment(conn, seg, 4096, true)?.reply()?;
+    shm::detach(conn, seg)?.check()?;
+    let seg = conn.generate_id()?;
+    shm::attach_fd(conn, seg, reply.shm_fd, true)?.check()?;
+    shm::detach(conn, seg)?.check()?;
+
+    Ok(CheckOutcome::Passed)
+}
+
+/// Conformance checks as methods on a connection.
+///
+/// This trait is implemented for all [`Connection`]s. Each method forwards to the function with
+/// the same name in this module.
+pub trait ConnectionConformance: Connection {
+    /// See [`check_sequence_numbers`].
+    fn check_sequence_numbers(&self) -> Result<CheckOutcome, ConformanceError> {
+        check_sequence_numbers(self)
+    }
+
+    /// See [`check_error_routing`].
+    fn check_error_routing(&self) -> Result<CheckOutcome, ConformanceError> {
+        check_error_routing(self)
+    }
+
+    /// See [`check_big_requests`].
+    fn check_big_requests(&self) -> Result<CheckOutcome, ConformanceError> {
+        check_big_requests(self)
+    }
+
+    /// See [`check_reply_with_fds`].
+    #[cfg(feature = "shm")]
+    fn check_reply_with_fds(&self) -> Result<CheckOutcome, ConformanceError> {
+        check_reply_with_fds(self)
+    }
+}
+
+impl<C: Connection + ?Sized> ConnectionConformance for C {}
+
+/// Run all available checks on the given connection.
+///
+/// The result contains the name of each check together with its result. Checks that need a
+/// feature of this library that is not enabled are not included.
+pub fn run_all_checks<C: Connection + ?Sized>(
+    conn: &C,
+) -> Vec<(&'static str, Result<CheckOutcome, ConformanceError>)> {
+    #[cfg_attr(not(feature = "shm"), allow(unused_mut))]
+    let mut result = vec![
+        ("sequence_numbers", check_sequence_numbers(conn)),
+        ("error_routing", check_error_routing(conn)),
+        ("big_requests", check_big_requests(conn)),
+    ];
+    #[cfg(feature = "shm")]
+    result.push(("reply_with_fds", check_reply_with_fds(conn)));
+    result
+}
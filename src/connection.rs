@@ -9,9 +9,10 @@ use std::io::IoSlice;
 
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::middleware::{Layer, Layered};
 use crate::protocol::xproto::Setup;
 use crate::protocol::Event;
-use crate::utils::RawFdContainer;
+use crate::utils::OwnedFd;
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
 
 /// Number type used for referring to things that were sent to the server in responses from the
@@ -25,7 +26,7 @@ pub type SequenceNumber = u64;
 
 // Used to avoid too-complex types.
 /// A combination of a buffer and a list of file descriptors.
-pub type BufWithFds<B> = (B, Vec<RawFdContainer>);
+pub type BufWithFds<B> = (B, Vec<OwnedFd>);
 /// An event and its sequence number.
 pub type EventAndSeqNumber = (Event, SequenceNumber);
 /// The raw bytes of an event and its sequence number.
@@ -79,7 +80,7 @@ pub trait RequestConnection {
     fn send_request_with_reply<R>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<Cookie<'_, Self, R>, ConnectionError>
     where
         R: TryParse;
@@ -106,7 +107,7 @@ pub trait RequestConnection {
     fn send_request_with_reply_with_fds<R>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
     where
         R: TryParseFd;
@@ -133,7 +134,7 @@ pub trait RequestConnection {
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<VoidCookie<'_, Self>, ConnectionError>;
 
     /// A reply to an error should be discarded.
@@ -180,9 +181,7 @@ pub trait RequestConnection {
     fn wait_for_reply_or_error(&self, sequence: SequenceNumber) -> Result<Self::Buf, ReplyError> {
         match self.wait_for_reply_or_raw_error(sequence)? {
             ReplyOrError::Reply(reply) => Ok(reply),
-            ReplyOrError::Error(error) => {
-                Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
-            }
+            ReplyOrError::Error(error) => Err(parse_and_release_error(self, error)),
         }
     }
 
@@ -197,6 +196,41 @@ pub trait RequestConnection {
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<Self::Buf>, ConnectionError>;
 
+    /// Check whether the reply to a request was already received, without blocking.
+    ///
+    /// The given sequence number identifies the request for which replies are expected. If
+    /// neither a reply nor an error was received yet, `None` is returned. If the X11 server
+    /// answered the request with an error, that error is returned as an `Err`.
+    ///
+    /// This function does not flush the connection. Use `Connection::flush()` before polling,
+    /// otherwise the request might never reach the X11 server.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn poll_for_reply_or_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ReplyError> {
+        match self.poll_for_reply_or_raw_error(sequence)? {
+            None => Ok(None),
+            Some(ReplyOrError::Reply(reply)) => Ok(Some(reply)),
+            Some(ReplyOrError::Error(error)) => Err(parse_and_release_error(self, error)),
+        }
+    }
+
+    /// Check whether the reply to a request was already received, without blocking.
+    ///
+    /// The given sequence number identifies the request for which replies are expected. If
+    /// neither a reply nor an error was received yet, `None` is returned.
+    ///
+    /// This function does not flush the connection. Use `Connection::flush()` before polling,
+    /// otherwise the request might never reach the X11 server.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Self::Buf>>, ConnectionError>;
+
     /// Wait for the reply to a request.
     ///
     /// The given sequence number identifies the request for which replies are expected. If the X11
@@ -220,9 +254,7 @@ pub trait RequestConnection {
     ) -> Result<BufWithFds<Self::Buf>, ReplyError> {
         match self.wait_for_reply_with_fds_raw(sequence)? {
             ReplyOrError::Reply(reply) => Ok(reply),
-            ReplyOrError::Error(error) => {
-                Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
-            }
+            ReplyOrError::Error(error) => Err(parse_and_release_error(self, error)),
         }
     }
 
@@ -243,7 +275,7 @@ pub trait RequestConnection {
     /// Users of this library will most likely not want to use this function directly.
     fn check_for_error(&self, sequence: SequenceNumber) -> Result<(), ReplyError> {
         match self.check_for_raw_error(sequence)? {
-            Some(err) => Err(self.parse_error(err.as_ref())?.into()),
+            Some(err) => Err(parse_and_release_error(self, err)),
             None => Ok(()),
         }
     }
@@ -293,6 +325,28 @@ pub trait RequestConnection {
 
     /// Parse a generic event.
     fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError>;
+
+    /// Give the buffer of a packet that was received and is no longer needed back.
+    ///
+    /// [`Cookie::reply`] and friends call this after parsing a reply or an error, so that a
+    /// connection can recycle its buffers, e.g. [`RustConnection`] with a [`BufferPool`]. It can
+    /// also be called for raw events and replies. The default implementation drops the buffer.
+    ///
+    /// [`RustConnection`]: crate::rust_connection::RustConnection
+    /// [`BufferPool`]: crate::rust_connection::BufferPool
+    fn release_buffer(&self, buffer: Self::Buf) {
+        drop(buffer);
+    }
+}
+
+/// Parse an X11 error and give its buffer back via [`RequestConnection::release_buffer`].
+fn parse_and_release_error<C: RequestConnection + ?Sized>(conn: &C, error: C::Buf) -> ReplyError {
+    let result = conn.parse_error(error.as_ref());
+    conn.release_buffer(error);
+    match result {
+        Ok(error) => error.into(),
+        Err(error) => error.into(),
+    }
 }
 
 /// A connection to an X11 server.
@@ -315,6 +369,10 @@ pub trait Connection: RequestConnection {
     }
 
     /// Wait for a new raw/unparsed event from the X11 server.
+    ///
+    /// Implementations should send all pending requests to the X11 server before blocking, unless
+    /// configured otherwise via [`FlushMode`]. All other `wait_for_*event*` functions are
+    /// implemented in terms of this function.
     fn wait_for_raw_event_with_sequence(
         &self,
     ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
@@ -348,7 +406,8 @@ pub trait Connection: RequestConnection {
     /// called, all pending requests are sent.
     ///
     /// You do not have to call this method before `wait_for_reply()`. If the request you want to
-    /// wait for was not yet sent, it will be sent by `wait_for_reply()`.
+    /// wait for was not yet sent, it will be sent by `wait_for_reply()`. Likewise,
+    /// `wait_for_event()` flushes by default. See [`FlushMode`] for details.
     fn flush(&self) -> Result<(), ConnectionError>;
 
     /// Get the setup information sent by the X11 server.
@@ -362,6 +421,16 @@ pub trait Connection: RequestConnection {
     /// called to generate an identifier. Next, `xproto::create_window` can be called to
     /// actually create the window.
     fn generate_id(&self) -> Result<u32, ReplyOrIdError>;
+
+    /// Wrap this connection in a [`Layer`] that sees all requests and events.
+    ///
+    /// See the [`crate::middleware`] module for details.
+    fn wrap<L: Layer>(self, layer: L) -> Layered<Self, L>
+    where
+        Self: Sized,
+    {
+        Layered::new(self, layer)
+    }
 }
 
 /// Does a request have a response?
@@ -382,6 +451,26 @@ pub enum DiscardMode {
     DiscardReplyAndError,
 }
 
+/// Should pending requests be flushed before blocking for an event?
+///
+/// Waiting for a reply always sends the corresponding request first, since the reply could
+/// otherwise never arrive. Waiting for an event is different: The event might be caused by
+/// another client, so there is no strict need to send anything. However, most of the time an
+/// application waits for an event after sending some requests and expects the X11 server to
+/// have seen these requests.
+///
+/// The connections provided by this library default to [`FlushMode::AutoFlush`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FlushMode {
+    /// Flush all pending requests before blocking in `wait_for_event()` and friends.
+    #[default]
+    AutoFlush,
+    /// Only flush when [`Connection::flush`] is called or when waiting for a reply.
+    ///
+    /// This is useful for code that does its own batching of requests.
+    NoAutoFlush,
+}
+
 /// Check the request length and use BIG-REQUESTS if necessary.
 ///
 /// Users of this library will most likely not want to use this function directly.
@@ -402,7 +491,7 @@ pub enum DiscardMode {
 /// use x11rb::connection::{BufWithFds, RequestConnection, SequenceNumber, compute_length_field};
 /// use x11rb::cookie::{Cookie, CookieWithFds, VoidCookie};
 /// use x11rb::errors::{ParseError, ConnectionError};
-/// use x11rb::utils::RawFdContainer;
+/// use x11rb::utils::OwnedFd;
 /// use x11rb::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 /// # use x11rb::connection::ReplyOrError;
 ///
@@ -431,6 +520,10 @@ pub enum DiscardMode {
 ///     # -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
 ///     #    unimplemented!()
 ///     # }
+///     # fn poll_for_reply_or_raw_error(&self, sequence: SequenceNumber)
+///     # -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+///     #    unimplemented!()
+///     # }
 ///     # fn wait_for_reply(&self, sequence: SequenceNumber)
 ///     # -> Result<Option<Vec<u8>>, x11rb::errors::ConnectionError> {
 ///     #    unimplemented!()
@@ -456,26 +549,26 @@ pub enum DiscardMode {
 ///     #     unimplemented!()
 ///     # }
 ///
-///     fn send_request_with_reply<R>(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>)
+///     fn send_request_with_reply<R>(&self, bufs: &[IoSlice], fds: Vec<OwnedFd>)
 ///     -> Result<Cookie<Self, R>, ConnectionError>
 ///     where R: TryParse {
 ///         Ok(Cookie::new(self, self.send_request(bufs, fds, true, false)?))
 ///     }
 ///
-///     fn send_request_with_reply_with_fds<R>(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>)
+///     fn send_request_with_reply_with_fds<R>(&self, bufs: &[IoSlice], fds: Vec<OwnedFd>)
 ///     -> Result<CookieWithFds<Self, R>, ConnectionError>
 ///     where R: TryParseFd {
 ///         Ok(CookieWithFds::new(self, self.send_request(bufs, fds, true, true)?))
 ///     }
 ///
-///     fn send_request_without_reply(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>)
+///     fn send_request_without_reply(&self, bufs: &[IoSlice], fds: Vec<OwnedFd>)
 ///     -> Result<VoidCookie<Self>, ConnectionError> {
 ///         Ok(VoidCookie::new(self, self.send_request(bufs, fds, false, false)?))
 ///     }
 /// }
 ///
 /// impl MyConnection {
-///     fn send_request(&self, bufs: &[IoSlice], fds: Vec<RawFdContainer>,
+///     fn send_request(&self, bufs: &[IoSlice], fds: Vec<OwnedFd>,
 ///                     has_reply: bool, reply_has_fds: bool)
 ///     -> Result<SequenceNumber, ConnectionError>
 ///     {
@@ -485,8 +578,9 @@ pub enum DiscardMode {
 ///     }
 /// }
 /// ```
+#[inline]
 pub fn compute_length_field<'b>(
-    conn: &impl RequestConnection,
+    conn: &(impl RequestConnection + ?Sized),
     request_buffers: &'b [IoSlice<'b>],
     storage: &'b mut (Vec<IoSlice<'b>>, [u8; 8]),
 ) -> Result<&'b [IoSlice<'b>], ConnectionError> {
@@ -513,6 +607,22 @@ pub fn compute_length_field<'b>(
         return Ok(request_buffers);
     }
 
+    compute_big_request_length_field(conn, request_buffers, storage, wire_length)
+}
+
+/// The part of [`compute_length_field`] for requests that need the BIG-REQUESTS extension.
+///
+/// This is split out so that the common case of small requests can be inlined without it.
+#[cold]
+fn compute_big_request_length_field<'b>(
+    conn: &(impl RequestConnection + ?Sized),
+    request_buffers: &'b [IoSlice<'b>],
+    storage: &'b mut (Vec<IoSlice<'b>>, [u8; 8]),
+    wire_length: usize,
+) -> Result<&'b [IoSlice<'b>], ConnectionError> {
+    let length = wire_length * 4;
+    let first_buf = &request_buffers[0];
+
     // Check that the total length is not too large
     if length > conn.maximum_request_bytes() {
         return Err(ConnectionError::MaximumRequestLengthExceeded);
@@ -5,8 +5,8 @@
 //!
 //! For requests without a reply, you get a [`VoidCookie`]. Requests with a reply are represented
 //! by a [`Cookie`] or a [`CookieWithFds`] if the reply also contains file descriptors.
-//! Additionally, there are two special cases for requests which generate more than one reply:
-//! [`ListFontsWithInfoCookie`] and [`RecordEnableContextCookie`].
+//! Requests which generate more than one reply, like `ListFontsWithInfo`, are represented by a
+//! [`CookieStream`].
 //!
 //! # Handling X11 errors
 //!
@@ -47,7 +47,7 @@
 use std::marker::PhantomData;
 
 use crate::connection::{BufWithFds, DiscardMode, RequestConnection, RequestKind, SequenceNumber};
-use crate::errors::{ConnectionError, ReplyError};
+use crate::errors::{ConnectionError, ParseError, ReplyError};
 #[cfg(feature = "record")]
 use crate::protocol::record::EnableContextReply;
 use crate::protocol::xproto::ListFontsWithInfoReply;
@@ -113,6 +113,11 @@ where
             DiscardMode::DiscardReplyAndError,
         )
     }
+
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.consume().1
+    }
 }
 
 impl<C> Drop for VoidCookie<'_, C>
@@ -175,6 +180,17 @@ where
     }
 }
 
+/// Parse a reply and give its buffer back to the connection.
+fn parse_and_release<C, R>(conn: &C, buf: C::Buf) -> Result<R, ParseError>
+where
+    C: RequestConnection + ?Sized,
+    R: TryParse,
+{
+    let result = R::try_parse(buf.as_ref()).map(|r| r.0);
+    conn.release_buffer(buf);
+    result
+}
+
 /// A handle to a response from the X11 server.
 ///
 /// When sending a request to the X11 server, this library returns a `Cookie`. This `Cookie` can
@@ -226,13 +242,33 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError> {
-        Ok(R::try_parse(self.raw_reply()?.as_ref())?.0)
+        let conn = self.raw_cookie.connection;
+        let buf = self.raw_reply()?;
+        Ok(parse_and_release(conn, buf)?)
+    }
+
+    /// Get the reply that the server sent if it was already received, without blocking.
+    ///
+    /// If the reply was not received yet, the cookie is given back as `Ok(Err(cookie))`, so that
+    /// polling can be repeated later. This does not flush the connection; call
+    /// `Connection::flush()` before polling.
+    pub fn poll_for_reply(self) -> Result<Result<R, Self>, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let buf = match conn.poll_for_reply_or_error(self.sequence_number()) {
+            Ok(None) => return Ok(Err(self)),
+            Ok(Some(buf)) => Ok(buf),
+            Err(err) => Err(err),
+        };
+        // The reply was consumed, so drop() must not discard it
+        let _ = self.raw_cookie.into_sequence_number();
+        Ok(Ok(parse_and_release(conn, buf?)?))
     }
 
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<R>, ConnectionError> {
+        let conn = self.raw_cookie.connection;
         self.raw_reply_unchecked()?
-            .map(|buf| R::try_parse(buf.as_ref()).map(|r| r.0))
+            .map(|buf| parse_and_release(conn, buf))
             .transpose()
             .map_err(Into::into)
     }
@@ -302,103 +338,132 @@ where
 
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError> {
+        let conn = self.raw_cookie.connection;
         let (buffer, mut fds) = self.raw_reply()?;
-        Ok(R::try_parse_fd(buffer.as_ref(), &mut fds)?.0)
+        let result = R::try_parse_fd(buffer.as_ref(), &mut fds).map(|r| r.0);
+        conn.release_buffer(buffer);
+        Ok(result?)
+    }
+
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.raw_cookie.into_sequence_number()
     }
 }
 
-macro_rules! multiple_reply_cookie {
-    (
-        $(#[$meta:meta])*
-        pub struct $name:ident for $reply:ident
-    ) => {
-        $(#[$meta])*
-        #[derive(Debug)]
-        pub struct $name<'a, C: RequestConnection + ?Sized>(Option<RawCookie<'a, C>>);
-
-        impl<'c, C> $name<'c, C>
-        where
-            C: RequestConnection + ?Sized,
-        {
-            pub(crate) fn new(
-                cookie: Cookie<'c, C, $reply>,
-            ) -> Self {
-                Self(Some(cookie.raw_cookie))
-            }
+/// A reply to a request for which the X11 server sends more than one reply.
+///
+/// The X11 server keeps sending replies until it sends a reply that marks the end of the stream.
+pub trait MultiReply: TryParse {
+    /// Does this reply mark the end of the stream of replies?
+    ///
+    /// Such a reply does not carry any information and is not returned by [`CookieStream`].
+    fn is_last(&self) -> bool;
+}
 
-            /// Get the sequence number of the request that generated this cookie.
-            pub fn sequence_number(&self) -> Option<SequenceNumber> {
-                self.0.as_ref().map(|x| x.sequence_number)
-            }
-        }
+impl MultiReply for ListFontsWithInfoReply {
+    fn is_last(&self) -> bool {
+        self.name.is_empty()
+    }
+}
 
-        impl<C> Iterator for $name<'_, C>
-        where
-            C: RequestConnection + ?Sized,
-        {
-            type Item = Result<$reply, ReplyError>;
-
-            fn next(&mut self) -> Option<Self::Item> {
-                let cookie = match self.0.take() {
-                    None => return None,
-                    Some(cookie) => cookie,
-                };
-                let reply = cookie
-                    .connection
-                    .wait_for_reply_or_error(cookie.sequence_number);
-                let reply = match reply {
-                    Err(e) => return Some(Err(e)),
-                    Ok(v) => v,
-                };
-                let reply = $reply::try_parse(reply.as_ref());
-                match reply {
-                    // Is this an indicator that no more replies follow?
-                    Ok(ref reply) if Self::is_last(&reply.0) => None,
-                    Ok(reply) => {
-                        self.0 = Some(cookie);
-                        Some(Ok(reply.0))
-                    }
-                    Err(e) => Some(Err(e.into())),
-                }
-            }
-        }
+#[cfg(feature = "record")]
+impl MultiReply for EnableContextReply {
+    fn is_last(&self) -> bool {
+        // FIXME: There does not seem to be an enumeration of the category values, (value 5 is
+        // EndOfData)
+        self.category == 5
     }
 }
 
-multiple_reply_cookie!(
-    /// A handle to the replies to a `ListFontsWithInfo` request.
-    ///
-    /// `ListFontsWithInfo` generated more than one reply, but `Cookie` only allows getting one reply.
-    /// This structure implements `Iterator` and allows to get all the replies.
-    pub struct ListFontsWithInfoCookie for ListFontsWithInfoReply
-);
+/// A handle to the replies to a request that generates more than one reply.
+///
+/// Requests like `ListFontsWithInfo` cause a stream of replies, but `Cookie` only allows getting
+/// one reply. This structure implements `Iterator` and yields the replies until the X11 server
+/// signals the end of the stream or sends an error. Dropping it before the end discards the
+/// remaining replies.
+#[derive(Debug)]
+pub struct CookieStream<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    raw_cookie: Option<RawCookie<'a, C>>,
+    phantom: PhantomData<R>,
+}
 
-impl<C> ListFontsWithInfoCookie<'_, C>
+impl<'c, C, R> CookieStream<'c, C, R>
 where
+    R: MultiReply,
     C: RequestConnection + ?Sized,
 {
-    fn is_last(reply: &ListFontsWithInfoReply) -> bool {
-        reply.name.is_empty()
+    /// Construct a new cookie stream from the cookie for the first reply.
+    pub fn new(cookie: Cookie<'c, C, R>) -> Self {
+        Self {
+            raw_cookie: Some(cookie.raw_cookie),
+            phantom: PhantomData,
+        }
     }
-}
 
-#[cfg(feature = "record")]
-multiple_reply_cookie!(
-    /// A handle to the replies to a `record::EnableContext` request.
+    /// Get the sequence number of the request that generated this cookie.
     ///
-    /// `EnableContext` generated more than one reply, but `Cookie` only allows getting one reply.
-    /// This structure implements `Iterator` and allows to get all the replies.
-    pub struct RecordEnableContextCookie for EnableContextReply
-);
+    /// Returns `None` once the last reply was received.
+    pub fn sequence_number(&self) -> Option<SequenceNumber> {
+        self.raw_cookie.as_ref().map(|x| x.sequence_number)
+    }
 
-#[cfg(feature = "record")]
-impl<C> RecordEnableContextCookie<'_, C>
+    /// Discard all remaining replies to the request, even errors.
+    ///
+    /// Without this function, an error is treated as an event after the stream is dropped.
+    pub fn discard_reply_and_errors(mut self) {
+        if let Some(cookie) = self.raw_cookie.take() {
+            let conn = cookie.connection;
+            conn.discard_reply(
+                cookie.into_sequence_number(),
+                RequestKind::HasResponse,
+                DiscardMode::DiscardReplyAndError,
+            )
+        }
+    }
+}
+
+impl<C, R> Iterator for CookieStream<'_, C, R>
 where
+    R: MultiReply,
     C: RequestConnection + ?Sized,
 {
-    fn is_last(reply: &EnableContextReply) -> bool {
-        // FIXME: There does not seem to be an enumeration of the category values, (value 5 is
-        // EndOfData)
-        reply.category == 5
+    type Item = Result<R, ReplyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cookie = self.raw_cookie.take()?;
+        let reply = cookie
+            .connection
+            .wait_for_reply_or_error(cookie.sequence_number);
+        let reply = match reply {
+            // No further replies follow an error
+            Err(e) => {
+                let _ = cookie.into_sequence_number();
+                return Some(Err(e));
+            }
+            Ok(v) => v,
+        };
+        match parse_and_release::<_, R>(cookie.connection, reply) {
+            // Is this an indicator that no more replies follow?
+            Ok(reply) if reply.is_last() => {
+                let _ = cookie.into_sequence_number();
+                None
+            }
+            Ok(reply) => {
+                self.raw_cookie = Some(cookie);
+                Some(Ok(reply))
+            }
+            Err(e) => Some(Err(e.into())),
+        }
     }
 }
+
+/// A handle to the replies to a `ListFontsWithInfo` request.
+pub type ListFontsWithInfoCookie<'a, C> = CookieStream<'a, C, ListFontsWithInfoReply>;
+
+/// A handle to the replies to a `record::EnableContext` request.
+#[cfg(feature = "record")]
+pub type RecordEnableContextCookie<'a, C> = CookieStream<'a, C, EnableContextReply>;
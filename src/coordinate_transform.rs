@@ -0,0 +1,129 @@
+//! Mapping absolute input devices, e.g. touchscreens, to a single monitor.
+//!
+//! The X11 server scales the coordinates of absolute input devices to the whole screen. With
+//! several monitors, a touchscreen thus has to be restricted to the part of the screen that its
+//! monitor shows. This is done via the `Coordinate Transformation Matrix` device property, which
+//! contains a 3x3 matrix of 32 bit floats in row-major order. [`map_to_monitor`] computes this
+//! matrix from a monitor rectangle and sets the property.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::coordinate_transform::map_to_monitor;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xinput::DeviceId;
+//! use x11rb::protocol::xproto::Rectangle;
+//!
+//! fn map_touchscreen(conn: &impl Connection, device: DeviceId) -> Result<(), ReplyError> {
+//!     // The touchscreen belongs to the monitor to the right of a 1920x1080 monitor
+//!     let monitor = Rectangle { x: 1920, y: 0, width: 1280, height: 800 };
+//!     map_to_monitor(conn, device, monitor, &conn.setup().roots[0])
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::errors::ReplyError;
+#[cfg(feature = "randr")]
+use crate::protocol::randr::MonitorInfo;
+use crate::protocol::xinput::{self, DeviceId, XIChangePropertyAux};
+use crate::protocol::xproto::{self, PropMode, Rectangle, Screen};
+
+/// The transformation matrix that does not change any coordinates.
+pub const IDENTITY: [f32; 9] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+/// Compute the transformation matrix that maps a device to the given part of the screen.
+///
+/// `screen_width` and `screen_height` are the size of the whole screen in pixels, e.g.
+/// `width_in_pixels` and `height_in_pixels` of the root window's [`Screen`].
+pub fn transformation_matrix(
+    monitor: Rectangle,
+    screen_width: u16,
+    screen_height: u16,
+) -> [f32; 9] {
+    let (screen_width, screen_height) = (f32::from(screen_width), f32::from(screen_height));
+    [
+        f32::from(monitor.width) / screen_width,
+        0.0,
+        f32::from(monitor.x) / screen_width,
+        0.0,
+        f32::from(monitor.height) / screen_height,
+        f32::from(monitor.y) / screen_height,
+        0.0,
+        0.0,
+        1.0,
+    ]
+}
+
+/// Get the rectangle of a RandR monitor.
+#[cfg(feature = "randr")]
+pub fn monitor_rectangle(monitor: &MonitorInfo) -> Rectangle {
+    Rectangle {
+        x: monitor.x,
+        y: monitor.y,
+        width: monitor.width,
+        height: monitor.height,
+    }
+}
+
+/// Set the `Coordinate Transformation Matrix` property of an input device.
+///
+/// The matrix is given in row-major order.
+pub fn set_transformation_matrix<C: RequestConnection + ?Sized>(
+    conn: &C,
+    device: DeviceId,
+    matrix: &[f32; 9],
+) -> Result<(), ReplyError> {
+    let property = xproto::intern_atom(conn, false, b"Coordinate Transformation Matrix")?;
+    let float = xproto::intern_atom(conn, false, b"FLOAT")?;
+    let (property, float) = (property.reply()?.atom, float.reply()?.atom);
+    // Floats are sent as their bit patterns, so that they are byte-swapped like integers
+    let items = XIChangePropertyAux::Data32(matrix.iter().map(|value| value.to_bits()).collect());
+    xinput::xi_change_property(conn, device, PropMode::REPLACE, property, float, 9, &items)?.check()
+}
+
+/// Restrict an absolute input device to the given monitor.
+pub fn map_to_monitor<C: RequestConnection + ?Sized>(
+    conn: &C,
+    device: DeviceId,
+    monitor: Rectangle,
+    screen: &Screen,
+) -> Result<(), ReplyError> {
+    let matrix = transformation_matrix(monitor, screen.width_in_pixels, screen.height_in_pixels);
+    set_transformation_matrix(conn, device, &matrix)
+}
+
+/// Let an input device cover the whole screen again.
+pub fn reset<C: RequestConnection + ?Sized>(conn: &C, device: DeviceId) -> Result<(), ReplyError> {
+    set_transformation_matrix(conn, device, &IDENTITY)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{transformation_matrix, IDENTITY};
+    use crate::protocol::xproto::Rectangle;
+
+    #[test]
+    fn whole_screen() {
+        let screen = Rectangle {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+        };
+        assert_eq!(transformation_matrix(screen, 1920, 1080), IDENTITY);
+    }
+
+    #[test]
+    fn right_monitor() {
+        // A 1000x500 monitor to the right of a 1000x1000 monitor
+        let monitor = Rectangle {
+            x: 1000,
+            y: 250,
+            width: 1000,
+            height: 500,
+        };
+        assert_eq!(
+            transformation_matrix(monitor, 2000, 1000),
+            [0.5, 0.0, 0.5, 0.0, 0.5, 0.25, 0.0, 0.0, 1.0]
+        );
+    }
+}
@@ -93,10 +93,54 @@ static CORE_CURSORS: &[(&str, u16)] = &[
     ("xterm", 76),
 ];
 
+/// Names from the cursor naming specification and from CSS that have an equivalent core cursor
+///
+/// Cursor themes usually provide these names as symlinks. When no theme does, the core cursor is
+/// used instead.
+static CURSOR_ALIASES: &[(&str, &str)] = &[
+    ("all-scroll", "fleur"),
+    ("cell", "plus"),
+    ("col-resize", "sb_h_double_arrow"),
+    ("default", "left_ptr"),
+    ("e-resize", "right_side"),
+    ("ew-resize", "sb_h_double_arrow"),
+    ("grabbing", "fleur"),
+    ("hand", "hand2"),
+    ("help", "question_arrow"),
+    ("ibeam", "xterm"),
+    ("left_ptr_watch", "watch"),
+    ("move", "fleur"),
+    ("n-resize", "top_side"),
+    ("ne-resize", "top_right_corner"),
+    ("not-allowed", "circle"),
+    ("ns-resize", "sb_v_double_arrow"),
+    ("nw-resize", "top_left_corner"),
+    ("pointer", "hand2"),
+    ("progress", "watch"),
+    ("row-resize", "sb_v_double_arrow"),
+    ("s-resize", "bottom_side"),
+    ("se-resize", "bottom_right_corner"),
+    ("size_all", "fleur"),
+    ("size_hor", "sb_h_double_arrow"),
+    ("size_ver", "sb_v_double_arrow"),
+    ("sw-resize", "bottom_left_corner"),
+    ("text", "xterm"),
+    ("w-resize", "left_side"),
+    ("wait", "watch"),
+];
+
+/// The search path that libXcursor uses when `$XCURSOR_PATH` is not set
+const DEFAULT_CURSOR_PATH: &str =
+    "~/.local/share/icons:~/.icons:/usr/share/icons:/usr/share/pixmaps:/usr/X11R6/lib/X11/icons";
+
 /// Find a core cursor based on its name
 ///
-/// This function checks a built-in list of known names.
-fn cursor_shape_to_id(name: &str) -> Option<u16> {
+/// This function checks a built-in list of known names and their aliases.
+pub(crate) fn cursor_shape_to_id(name: &str) -> Option<u16> {
+    let name = CURSOR_ALIASES
+        .iter()
+        .find(|&&(alias, _)| alias == name)
+        .map_or(name, |&(_, core_name)| core_name);
     CORE_CURSORS
         .iter()
         .filter(|&(name2, _)| name == *name2)
@@ -107,9 +151,6 @@ fn cursor_shape_to_id(name: &str) -> Option<u16> {
 /// An error that occurred while searching
 #[derive(Debug)]
 pub(crate) enum Error {
-    /// `$HOME` is not set
-    NoHomeDir,
-
     /// No cursor file could be found
     NothingFound,
 }
@@ -214,18 +255,16 @@ mod test_parse_inherits {
 }
 
 /// Find a cursor file based on the name of a cursor theme and the name of the cursor.
+///
+/// The search follows libXcursor: `$XCURSOR_PATH` lists the directories that contain themes and
+/// inherited themes are searched depth-first.
 pub(crate) fn find_cursor(theme: &str, name: &str) -> Result<Cursor<File>, Error> {
-    let home = match var_os("HOME") {
-        Some(home) => home,
-        None => return Err(Error::NoHomeDir),
-    };
-    let cursor_path = var("XCURSOR_PATH").unwrap_or_else(|_| {
-        "~/.icons:/usr/share/icons:/usr/share/pixmaps:/usr/X11R6/lib/X11/icons".into()
-    });
+    let home = var_os("HOME");
+    let cursor_path = var("XCURSOR_PATH").unwrap_or_else(|_| DEFAULT_CURSOR_PATH.into());
     let open_cursor = |file: &Path| File::open(file);
     let parse_inherits = |file: &Path| parse_inherits(file);
     find_cursor_impl(
-        &home,
+        home.as_deref(),
         &cursor_path,
         theme,
         name,
@@ -235,7 +274,7 @@ pub(crate) fn find_cursor(theme: &str, name: &str) -> Result<Cursor<File>, Error
 }
 
 fn find_cursor_impl<F, G, H>(
-    home: &OsStr,
+    home: Option<&OsStr>,
     cursor_path: &str,
     theme: &str,
     name: &str,
@@ -252,57 +291,86 @@ where
         }
     }
 
-    let cursor_path = cursor_path.split(':').collect::<Vec<_>>();
-
-    let mut next_inherits = Vec::new();
-    let mut last_inherits = vec![theme.into()];
-    while !last_inherits.is_empty() {
-        for theme in last_inherits {
-            for path in &cursor_path {
-                // Calculate the path to the theme's directory
-                let mut theme_dir = PathBuf::new();
-                // Does the path begin with '~'?
-                if path.starts_with('~') {
-                    theme_dir.push(&home);
-                    let mut path = &path[1..];
-                    // Skip a path separator if there is one
-                    if path.chars().next().map(std::path::is_separator) == Some(true) {
-                        path = &path[1..];
-                    }
-                    theme_dir.push(path);
-                } else {
-                    theme_dir.push(path);
-                }
-                theme_dir.push(&theme);
-
-                // Find the cursor in the theme
-                let mut cursor_file = theme_dir.clone();
-                cursor_file.push("cursors");
-                cursor_file.push(name);
-                if let Ok(file) = open_cursor(&cursor_file) {
-                    return Ok(Cursor::File(file));
-                }
-
-                // Get the theme's index.theme file and parse its 'Inherits' line
-                let mut index = theme_dir;
-                index.push("index.theme");
-                if let Ok(res) = parse_inherits(&index) {
-                    next_inherits.extend(res);
+    // Calculate the directories that contain themes
+    let theme_dirs = cursor_path
+        .split(':')
+        .filter(|path| !path.is_empty())
+        .filter_map(|path| {
+            // Does the path begin with '~'? Such entries are skipped without $HOME.
+            if let Some(mut path) = path.strip_prefix('~') {
+                // Skip a path separator if there is one
+                if path.chars().next().map(std::path::is_separator) == Some(true) {
+                    path = &path[1..];
                 }
+                home.map(|home| Path::new(home).join(path))
+            } else {
+                Some(PathBuf::from(path))
             }
-        }
+        })
+        .collect::<Vec<_>>();
+
+    let mut visited = Vec::new();
+    scan_theme(
+        &theme_dirs,
+        theme,
+        name,
+        &mut visited,
+        &mut open_cursor,
+        &mut parse_inherits,
+    )
+    .map(Cursor::File)
+    .ok_or(Error::NothingFound)
+}
 
-        last_inherits = next_inherits;
-        next_inherits = Vec::new();
+// Search a theme and then the themes that it inherits from
+fn scan_theme<F, G, H>(
+    theme_dirs: &[PathBuf],
+    theme: &str,
+    name: &str,
+    visited: &mut Vec<String>,
+    open_cursor: &mut G,
+    parse_inherits: &mut H,
+) -> Option<F>
+where
+    G: FnMut(&Path) -> Result<F, IOError>,
+    H: FnMut(&Path) -> Result<Vec<String>, IOError>,
+{
+    // Themes that inherit from each other would otherwise make us loop forever
+    if visited.iter().any(|visited| visited == theme) {
+        return None;
+    }
+    visited.push(theme.to_string());
+
+    // Find the cursor in the theme, in any of the directories
+    for dir in theme_dirs {
+        let cursor_file = dir.join(theme).join("cursors").join(name);
+        if let Ok(file) = open_cursor(&cursor_file) {
+            return Some(file);
+        }
     }
 
-    Err(Error::NothingFound)
+    // Only the first index.theme file with an 'Inherits' line counts
+    let inherits = theme_dirs
+        .iter()
+        .filter_map(|dir| parse_inherits(&dir.join(theme).join("index.theme")).ok())
+        .find(|inherits| !inherits.is_empty())
+        .unwrap_or_default();
+    inherits.iter().find_map(|inherited| {
+        scan_theme(
+            theme_dirs,
+            inherited,
+            name,
+            visited,
+            open_cursor,
+            parse_inherits,
+        )
+    })
 }
 
 // FIXME: Make these tests pass on Windows; problem is "/" vs "\\" in paths
 #[cfg(all(test, unix))]
 mod test_find_cursor {
-    use super::{find_cursor_impl, Cursor, Error};
+    use super::{cursor_shape_to_id, find_cursor_impl, Cursor, Error};
     use crate::errors::ConnectionError;
     use std::io::{Error as IOError, ErrorKind};
     use std::path::Path;
@@ -311,7 +379,7 @@ mod test_find_cursor {
     fn core_cursor() {
         let cb1 = |_: &Path| -> Result<(), _> { unimplemented!() };
         let cb2 = |_: &Path| unimplemented!();
-        match find_cursor_impl("unused".as_ref(), "unused", "core", "heart", cb1, cb2).unwrap() {
+        match find_cursor_impl(None, "unused", "core", "heart", cb1, cb2).unwrap() {
             Cursor::CoreChar(31) => {}
             e => panic!("Unexpected result {:?}", e),
         }
@@ -333,7 +401,7 @@ mod test_find_cursor {
             Ok(Vec::new())
         };
         match find_cursor_impl(
-            "home".as_ref(),
+            Some("home".as_ref()),
             "path:~/some/:/entries",
             "theme",
             "theCursor",
@@ -381,7 +449,7 @@ mod test_find_cursor {
             }
         };
         match find_cursor_impl(
-            "home".as_ref(),
+            Some("home".as_ref()),
             "path:base:tail",
             "theTheme",
             "theCursor",
@@ -406,4 +474,64 @@ mod test_find_cursor {
             ]
         );
     }
+
+    #[test]
+    fn inherit_depth_first() {
+        let mut opened = Vec::new();
+        let mut inherit_parsed = Vec::new();
+        let cb1 = |path: &Path| -> Result<(), _> {
+            opened.push(path.to_str().unwrap().to_owned());
+            Err(IOError::new(
+                ErrorKind::Other,
+                ConnectionError::UnknownError,
+            ))
+        };
+        let cb2 = |path: &Path| {
+            inherit_parsed.push(path.to_str().unwrap().to_owned());
+            match path.to_str().unwrap() {
+                "a/theTheme/index.theme" => Ok(vec!["first".into(), "second".into()]),
+                "b/theTheme/index.theme" => Ok(vec!["ignored".into()]),
+                "a/first/index.theme" => Ok(vec!["nested".into()]),
+                // Loops are not followed
+                "a/nested/index.theme" => Ok(vec!["theTheme".into()]),
+                _ => Err(IOError::new(ErrorKind::NotFound, "no index.theme")),
+            }
+        };
+        match find_cursor_impl(None, "a::~/home:b", "theTheme", "theCursor", cb1, cb2) {
+            Err(Error::NothingFound) => {}
+            e => panic!("Unexpected result {:?}", e),
+        }
+        assert_eq!(
+            opened,
+            &[
+                "a/theTheme/cursors/theCursor",
+                "b/theTheme/cursors/theCursor",
+                "a/first/cursors/theCursor",
+                "b/first/cursors/theCursor",
+                "a/nested/cursors/theCursor",
+                "b/nested/cursors/theCursor",
+                "a/second/cursors/theCursor",
+                "b/second/cursors/theCursor",
+            ]
+        );
+        assert_eq!(
+            inherit_parsed,
+            &[
+                "a/theTheme/index.theme",
+                "a/first/index.theme",
+                "a/nested/index.theme",
+                "a/second/index.theme",
+                "b/second/index.theme",
+            ]
+        );
+    }
+
+    #[test]
+    fn core_cursor_aliases() {
+        assert_eq!(cursor_shape_to_id("watch"), Some(75));
+        assert_eq!(cursor_shape_to_id("wait"), Some(75));
+        assert_eq!(cursor_shape_to_id("pointer"), Some(30));
+        assert_eq!(cursor_shape_to_id("default"), Some(34));
+        assert_eq!(cursor_shape_to_id("no-such-cursor"), None);
+    }
 }
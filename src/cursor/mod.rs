@@ -14,6 +14,9 @@ use std::fs::File;
 
 mod find_cursor;
 mod parse_cursor;
+mod watcher;
+
+pub use watcher::Watcher;
 
 /// The level of cursor support of the X11 server
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,7 +33,7 @@ enum RenderSupport {
 
 /// A cookie for creating a `Handle`
 #[derive(Debug)]
-pub struct Cookie<'a, 'b, C: Connection> {
+pub struct Cookie<'a, 'b, C: Connection + ?Sized> {
     conn: &'a C,
     screen: &'a xproto::Screen,
     resource_database: &'b Database,
@@ -40,7 +43,7 @@ pub struct Cookie<'a, 'b, C: Connection> {
     )>,
 }
 
-impl<C: Connection> Cookie<'_, '_, C> {
+impl<C: Connection + ?Sized> Cookie<'_, '_, C> {
     /// Get the handle from the replies from the X11 server
     pub fn reply(self) -> Result<Handle, ReplyOrIdError> {
         let mut render_version = (0, 0);
@@ -97,7 +100,8 @@ impl<C: Connection> Cookie<'_, '_, C> {
         };
         let theme = resource_database
             .get_string("Xcursor.theme", "")
-            .map(|theme| theme.to_string());
+            .map(|theme| theme.to_string())
+            .or_else(|| std::env::var("XCURSOR_THEME").ok());
         let cursor_size = match resource_database.get_value("Xcursor.size", "") {
             Ok(Some(value)) => value,
             _ => 0,
@@ -142,7 +146,7 @@ impl Handle {
     /// If you want this function not to block, you should prefetch the RENDER extension's data on
     /// the connection.
     #[allow(clippy::new_ret_no_self)]
-    pub fn new<'a, 'b, C: Connection>(
+    pub fn new<'a, 'b, C: Connection + ?Sized>(
         conn: &'a C,
         screen: usize,
         resource_database: &'b Database,
@@ -168,6 +172,10 @@ impl Handle {
 
     /// Loads the specified cursor, either from the cursor theme or by falling back to the X11
     /// "cursor" font.
+    ///
+    /// Themes are searched for in the directories listed in `$XCURSOR_PATH`. If neither the theme
+    /// nor the themes it inherits from nor the "default" theme contain the cursor, a core cursor
+    /// with the same name or a name with the same meaning (e.g. "wait" for "watch") is used.
     pub fn load_cursor<C>(&self, conn: &C, name: &str) -> Result<xproto::Cursor, ReplyOrIdError>
     where
         C: Connection,
@@ -183,25 +191,27 @@ fn open_cursor(theme: &Option<String>, name: &str) -> Option<find_cursor::Cursor
         }
     }
     if let Ok(cursor) = find_cursor::find_cursor("default", name) {
-        Some(cursor)
-    } else {
-        None
+        return Some(cursor);
     }
+    // No theme has this cursor, fall back to the cursor font like libXcursor
+    find_cursor::cursor_shape_to_id(name).map(find_cursor::Cursor::CoreChar)
 }
 
-fn create_core_cursor<C: Connection>(
+fn create_core_cursor<C: Connection + ?Sized>(
     conn: &C,
     cursor_font: Font,
     cursor: u16,
 ) -> Result<xproto::Cursor, ReplyOrIdError> {
+    // Each cursor in the font consists of a glyph and the following mask glyph
+    let glyph = cursor * 2;
     let result = conn.generate_id()?;
     let _ = xproto::create_glyph_cursor(
         conn,
         result,
         cursor_font,
         cursor_font,
-        cursor,
-        cursor + 1,
+        glyph,
+        glyph + 1,
         // foreground color
         0,
         0,
@@ -214,7 +224,7 @@ fn create_core_cursor<C: Connection>(
     Ok(result)
 }
 
-fn create_render_cursor<C: Connection>(
+fn create_render_cursor<C: Connection + ?Sized>(
     conn: &C,
     handle: &Handle,
     image: &parse_cursor::Image,
@@ -272,7 +282,7 @@ fn create_render_cursor<C: Connection>(
     })
 }
 
-fn load_cursor<C: Connection>(
+fn load_cursor<C: Connection + ?Sized>(
     conn: &C,
     handle: &Handle,
     name: &str,
@@ -0,0 +1,553 @@
+//! Keeping a cursor [`Handle`] up to date with the desktop settings.
+//!
+//! Desktop environments announce the cursor theme and size via XSETTINGS and via the
+//! `Xcursor.theme` and `Xcursor.size` resources. Both can change while a program is running, e.g.
+//! when the user picks another theme. [`Watcher`] follows these changes and updates its
+//! [`Handle`], so that newly loaded cursors use the current theme. Cursors that were loaded
+//! before are not changed by the X11 server; they have to be loaded again when the watcher
+//! reports that cursors were invalidated.
+
+use std::convert::TryFrom;
+
+use super::{get_cursor_size, Handle};
+use crate::connection::Connection;
+use crate::errors::{ReplyError, ReplyOrIdError};
+#[cfg(feature = "randr")]
+use crate::protocol::randr::{self, MonitorInfo, NotifyMask};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangeWindowAttributesAux, EventMask, Screen, Window,
+};
+use crate::protocol::Event;
+use crate::resource_manager::Database;
+use crate::NONE;
+
+/// The XSETTINGS byte order value for most significant byte first.
+const MSB_FIRST: u8 = 1;
+
+/// The cursor related settings from XSETTINGS.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct XSettings {
+    /// `Gtk/CursorThemeName`
+    theme: Option<String>,
+    /// `Gtk/CursorThemeSize`
+    size: Option<u32>,
+    /// `Xft/DPI`, in 1024ths of a dot per inch
+    dpi: Option<u32>,
+}
+
+/// A cursor over the data of the `_XSETTINGS_SETTINGS` property.
+struct Reader<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.data.len() {
+            return None;
+        }
+        let (result, remaining) = self.data.split_at(len);
+        self.data = remaining;
+        Some(result)
+    }
+
+    /// Read bytes that are padded to a multiple of four bytes.
+    fn padded(&mut self, len: usize) -> Option<&'a [u8]> {
+        let result = self.bytes(len)?;
+        let _ = self.bytes((4 - len % 4) % 4)?;
+        Some(result)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = <[u8; 2]>::try_from(self.bytes(2)?).ok()?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = <[u8; 4]>::try_from(self.bytes(4)?).ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+}
+
+/// Parse the cursor related settings from the contents of the `_XSETTINGS_SETTINGS` property.
+///
+/// Returns `None` if the data is malformed.
+fn parse_xsettings(data: &[u8]) -> Option<XSettings> {
+    let mut reader = Reader {
+        data,
+        big_endian: *data.first()? == MSB_FIRST,
+    };
+    let _ = reader.bytes(4)?; // byte order and padding
+    let _serial = reader.u32()?;
+    let count = reader.u32()?;
+    let mut settings = XSettings::default();
+    for _ in 0..count {
+        let kind = reader.bytes(2)?[0];
+        let name_len = reader.u16()?;
+        let name = reader.padded(name_len.into())?;
+        let _last_change_serial = reader.u32()?;
+        match kind {
+            // Integer
+            0 => {
+                // Negative values make no sense for any of the settings used here
+                let value = u32::try_from(reader.u32()? as i32).ok();
+                match name {
+                    b"Gtk/CursorThemeSize" => settings.size = value,
+                    b"Xft/DPI" => settings.dpi = value,
+                    _ => {}
+                }
+            }
+            // String
+            1 => {
+                let len = usize::try_from(reader.u32()?).ok()?;
+                let value = reader.padded(len)?;
+                if name == b"Gtk/CursorThemeName" {
+                    settings.theme = String::from_utf8(value.to_vec()).ok();
+                }
+            }
+            // Color
+            2 => {
+                let _ = reader.bytes(8)?;
+            }
+            _ => return None,
+        }
+    }
+    Some(settings)
+}
+
+/// Get the current settings from the XSETTINGS manager with the given window.
+fn read_xsettings<C: Connection + ?Sized>(
+    conn: &C,
+    owner: Window,
+    property: Atom,
+) -> Result<XSettings, ReplyError> {
+    if owner == NONE {
+        return Ok(XSettings::default());
+    }
+    let reply =
+        match xproto::get_property(conn, false, owner, property, property, 0, u32::MAX)?.reply() {
+            Ok(reply) => reply,
+            // The manager went away in the meantime
+            Err(ReplyError::X11Error(_)) => return Ok(XSettings::default()),
+            Err(err) => return Err(err),
+        };
+    Ok(parse_xsettings(&reply.value).unwrap_or_default())
+}
+
+/// Add the given events to this client's event mask of a window.
+///
+/// Returns `false` if the window does not exist.
+fn select_events<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    mask: EventMask,
+) -> Result<bool, ReplyError> {
+    let attributes = match xproto::get_window_attributes(conn, window)?.reply() {
+        Ok(attributes) => attributes,
+        Err(ReplyError::X11Error(_)) => return Ok(false),
+        Err(err) => return Err(err),
+    };
+    let aux =
+        ChangeWindowAttributesAux::new().event_mask(attributes.your_event_mask | u32::from(mask));
+    match xproto::change_window_attributes(conn, window, &aux)?.check() {
+        Ok(()) => Ok(true),
+        Err(ReplyError::X11Error(_)) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Get the monitors of the screen, or nothing if RandR 1.5 is not available.
+#[cfg(feature = "randr")]
+fn query_monitors<C: Connection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<MonitorInfo>, ReplyError> {
+    if conn
+        .extension_information(randr::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(Vec::new());
+    }
+    match randr::get_monitors(conn, root, true)?.reply() {
+        Ok(reply) => Ok(reply.monitors),
+        Err(ReplyError::X11Error(_)) => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Atoms {
+    /// The selection `_XSETTINGS_S[screen]` that the XSETTINGS manager owns.
+    selection: Atom,
+    /// The property `_XSETTINGS_SETTINGS` that contains the settings.
+    settings: Atom,
+    /// The type of the client message that announces a new selection owner.
+    manager: Atom,
+}
+
+type InvalidatedCallback = Box<dyn FnMut(&Handle) + Send>;
+
+/// Follows changes of the cursor theme and size and keeps a [`Handle`] up to date.
+///
+/// The settings are taken from the following places, with the first one taking precedence:
+/// - The `XCURSOR_SIZE` environment variable for the size.
+/// - The `Gtk/CursorThemeName` and `Gtk/CursorThemeSize` XSETTINGS.
+/// - The `Xcursor.theme` and `Xcursor.size` resources.
+/// - A size based on the `Xft/DPI` XSETTING, the `Xft.dpi` resource, or the screen size.
+///
+/// The watcher selects the events it needs on the root window and on the window of the
+/// XSETTINGS manager. All events have to be passed to [`Watcher::handle_event`].
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::cursor::Watcher;
+/// use x11rb::errors::ReplyOrIdError;
+/// use x11rb::protocol::xproto::{self, ChangeWindowAttributesAux, Window};
+///
+/// fn run(conn: &impl Connection, screen: usize, window: Window) -> Result<(), ReplyOrIdError> {
+///     let mut watcher = Watcher::new(conn, screen)?;
+///     let mut cursor = watcher.handle().load_cursor(conn, "left_ptr")?;
+///     let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+///     xproto::change_window_attributes(conn, window, &aux)?;
+///     loop {
+///         let event = conn.wait_for_event()?;
+///         if watcher.handle_event(conn, &event)? {
+///             xproto::free_cursor(conn, cursor)?;
+///             cursor = watcher.handle().load_cursor(conn, "left_ptr")?;
+///             let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+///             xproto::change_window_attributes(conn, window, &aux)?;
+///         }
+///     }
+/// }
+/// ```
+pub struct Watcher {
+    root: Window,
+    /// The screen with its current size.
+    screen: Screen,
+    atoms: Atoms,
+    /// The window of the current XSETTINGS manager, or `NONE`.
+    settings_owner: Window,
+    settings: XSettings,
+    database: Database,
+    handle: Handle,
+    #[cfg(feature = "randr")]
+    monitors: Vec<MonitorInfo>,
+    callback: Option<InvalidatedCallback>,
+}
+
+impl std::fmt::Debug for Watcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("root", &self.root)
+            .field("settings_owner", &self.settings_owner)
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Watcher {
+    /// Create a new watcher for the given screen.
+    ///
+    /// This loads the current settings and creates a cursor handle for them.
+    pub fn new<C: Connection + ?Sized>(conn: &C, screen: usize) -> Result<Self, ReplyOrIdError> {
+        let selection_name = format!("_XSETTINGS_S{}", screen);
+        let selection = xproto::intern_atom(conn, false, selection_name.as_bytes())?;
+        let settings = xproto::intern_atom(conn, false, b"_XSETTINGS_SETTINGS")?;
+        let manager = xproto::intern_atom(conn, false, b"MANAGER")?;
+        let atoms = Atoms {
+            selection: selection.reply()?.atom,
+            settings: settings.reply()?.atom,
+            manager: manager.reply()?.atom,
+        };
+
+        let screen_info = conn.setup().roots[screen].clone();
+        let root = screen_info.root;
+        // PropertyChange for RESOURCE_MANAGER, StructureNotify for MANAGER messages and the size
+        let _ = select_events(
+            conn,
+            root,
+            EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY,
+        )?;
+        #[cfg(feature = "randr")]
+        if conn
+            .extension_information(randr::X11_EXTENSION_NAME)?
+            .is_some()
+        {
+            let mask =
+                NotifyMask::SCREEN_CHANGE | NotifyMask::CRTC_CHANGE | NotifyMask::OUTPUT_CHANGE;
+            randr::select_input(conn, root, mask)?.check()?;
+        }
+
+        let database = Database::new_from_default(conn)?;
+        let handle = Handle::new(conn, screen, &database)?.reply()?;
+        let mut watcher = Self {
+            root,
+            screen: screen_info,
+            atoms,
+            settings_owner: NONE,
+            settings: XSettings::default(),
+            database,
+            handle,
+            #[cfg(feature = "randr")]
+            monitors: query_monitors(conn, root)?,
+            callback: None,
+        };
+        watcher.watch_settings_owner(conn)?;
+        let _ = watcher.update_handle();
+        Ok(watcher)
+    }
+
+    /// Get the cursor handle for the current settings.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Call the given function whenever the cursor theme or size changes.
+    ///
+    /// The function gets the updated handle. All cursors that were loaded before should be
+    /// loaded again. This replaces any previously set function.
+    pub fn on_invalidated<F>(&mut self, callback: F)
+    where
+        F: FnMut(&Handle) + Send + 'static,
+    {
+        self.callback = Some(Box::new(callback));
+    }
+
+    /// Update the settings based on an event.
+    ///
+    /// Returns `true` if the cursor theme or size changed, in which case cursors should be loaded
+    /// again. The callback set via [`Watcher::on_invalidated`] is called before returning.
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ReplyOrIdError> {
+        #[allow(unused_mut)]
+        let mut monitors_changed = false;
+        match event {
+            Event::PropertyNotify(event)
+                if event.window == self.root
+                    && event.atom == Atom::from(AtomEnum::RESOURCE_MANAGER) =>
+            {
+                self.database = Database::new_from_default(conn)?;
+            }
+            Event::PropertyNotify(event)
+                if event.window == self.settings_owner && event.atom == self.atoms.settings =>
+            {
+                self.settings = read_xsettings(conn, self.settings_owner, self.atoms.settings)?;
+            }
+            Event::ClientMessage(event)
+                if event.window == self.root
+                    && event.type_ == self.atoms.manager
+                    && event.format == 32
+                    && event.data.as_data32()[1] == self.atoms.selection =>
+            {
+                self.watch_settings_owner(conn)?;
+            }
+            Event::DestroyNotify(event)
+                if self.settings_owner != NONE && event.window == self.settings_owner =>
+            {
+                self.settings_owner = NONE;
+                self.settings = XSettings::default();
+            }
+            Event::ConfigureNotify(event) if event.window == self.root => {
+                self.screen.width_in_pixels = event.width;
+                self.screen.height_in_pixels = event.height;
+            }
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(event) if event.root == self.root => {
+                self.screen.width_in_pixels = event.width;
+                self.screen.height_in_pixels = event.height;
+                monitors_changed = self.update_monitors(conn)?;
+            }
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => monitors_changed = self.update_monitors(conn)?,
+            _ => return Ok(false),
+        }
+        // Since the cursor size depends on the monitor, a monitor change invalidates all cursors
+        let changed = self.update_handle() || monitors_changed;
+        if changed {
+            if let Some(callback) = &mut self.callback {
+                callback(&self.handle);
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Get the monitors of the screen.
+    ///
+    /// This is empty if the X11 server does not support RandR 1.5.
+    #[cfg(feature = "randr")]
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        &self.monitors
+    }
+
+    /// Get the cursor size to use on the given monitor.
+    ///
+    /// The size of the handle is meant for the configured DPI (`Xft.dpi`, or 96 if not set). It
+    /// is scaled by the ratio between the physical DPI of the monitor and the configured DPI.
+    /// Monitors that do not report their physical size get the size of the handle.
+    #[cfg(feature = "randr")]
+    pub fn cursor_size_for_monitor(&self, monitor: &MonitorInfo) -> u32 {
+        let size = self.handle.cursor_size;
+        if monitor.width_in_millimeters == 0 {
+            return size;
+        }
+        let dpi = f64::from(monitor.width) * 25.4 / f64::from(monitor.width_in_millimeters);
+        let reference = match self.dpi() {
+            0 => 96.0,
+            dpi => f64::from(dpi),
+        };
+        ((f64::from(size) * dpi / reference).round() as u32).max(1)
+    }
+
+    /// Load a cursor in the size for the given monitor.
+    ///
+    /// See [`Watcher::cursor_size_for_monitor`] and [`Handle::load_cursor`].
+    #[cfg(feature = "randr")]
+    pub fn load_cursor_for_monitor<C: Connection>(
+        &self,
+        conn: &C,
+        name: &str,
+        monitor: &MonitorInfo,
+    ) -> Result<xproto::Cursor, ReplyOrIdError> {
+        let handle = Handle {
+            root: self.handle.root,
+            cursor_font: self.handle.cursor_font,
+            picture_format: self.handle.picture_format,
+            render_support: self.handle.render_support,
+            theme: self.handle.theme.clone(),
+            cursor_size: self.cursor_size_for_monitor(monitor),
+        };
+        handle.load_cursor(conn, name)
+    }
+
+    /// Look up the current XSETTINGS manager and load its settings.
+    fn watch_settings_owner<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ReplyError> {
+        let owner = xproto::get_selection_owner(conn, self.atoms.selection)?
+            .reply()?
+            .owner;
+        let mask = EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY;
+        self.settings_owner = if owner != NONE && select_events(conn, owner, mask)? {
+            owner
+        } else {
+            NONE
+        };
+        self.settings = read_xsettings(conn, self.settings_owner, self.atoms.settings)?;
+        Ok(())
+    }
+
+    /// Get the configured DPI, or 0 if it is not set.
+    fn dpi(&self) -> u32 {
+        match self.settings.dpi {
+            Some(dpi) => dpi / 1024,
+            None => match self.database.get_value("Xft.dpi", "") {
+                Ok(Some(value)) => value,
+                _ => 0,
+            },
+        }
+    }
+
+    /// Apply the current settings to the handle and report whether something changed.
+    fn update_handle(&mut self) -> bool {
+        let theme = self.settings.theme.clone().or_else(|| {
+            self.database
+                .get_string("Xcursor.theme", "")
+                .map(|theme| theme.to_string())
+        });
+        let cursor_size = match self.settings.size {
+            Some(size) => size,
+            None => match self.database.get_value("Xcursor.size", "") {
+                Ok(Some(value)) => value,
+                _ => 0,
+            },
+        };
+        let cursor_size = get_cursor_size(cursor_size, self.dpi(), &self.screen);
+        if theme == self.handle.theme && cursor_size == self.handle.cursor_size {
+            return false;
+        }
+        self.handle.theme = theme;
+        self.handle.cursor_size = cursor_size;
+        true
+    }
+
+    /// Query the monitors again and report whether they changed.
+    #[cfg(feature = "randr")]
+    fn update_monitors<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<bool, ReplyError> {
+        let monitors = query_monitors(conn, self.root)?;
+        if monitors == self.monitors {
+            return Ok(false);
+        }
+        self.monitors = monitors;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_xsettings, XSettings};
+
+    fn setting(kind: u8, name: &[u8], value: &[u8], big_endian: bool) -> Vec<u8> {
+        let mut result = vec![kind, 0];
+        let len = name.len() as u16;
+        result.extend(if big_endian {
+            len.to_be_bytes()
+        } else {
+            len.to_le_bytes()
+        });
+        result.extend(name);
+        while result.len() % 4 != 0 {
+            result.push(0);
+        }
+        result.extend([0; 4]); // last change serial
+        result.extend(value);
+        result
+    }
+
+    #[test]
+    fn parse_lsb_first() {
+        let mut data = vec![0, 0, 0, 0, 7, 0, 0, 0, 4, 0, 0, 0];
+        data.extend(setting(
+            1,
+            b"Gtk/CursorThemeName",
+            b"\x07\0\0\0Adwaita\0",
+            false,
+        ));
+        data.extend(setting(0, b"Gtk/CursorThemeSize", &[48, 0, 0, 0], false));
+        data.extend(setting(2, b"Gtk/Color", &[1; 8], false));
+        data.extend(setting(0, b"Xft/DPI", &[0, 0, 2, 0], false));
+        assert_eq!(
+            parse_xsettings(&data),
+            Some(XSettings {
+                theme: Some("Adwaita".to_string()),
+                size: Some(48),
+                dpi: Some(128 * 1024),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_msb_first() {
+        let mut data = vec![1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 2];
+        data.extend(setting(0, b"Gtk/CursorThemeSize", &[0, 0, 0, 32], true));
+        data.extend(setting(0, b"Xft/DPI", &[255, 255, 255, 255], true));
+        assert_eq!(
+            parse_xsettings(&data),
+            Some(XSettings {
+                theme: None,
+                size: Some(32),
+                dpi: None,
+            })
+        );
+        // Truncated data is rejected
+        assert_eq!(parse_xsettings(&data[..data.len() - 1]), None);
+    }
+}
@@ -0,0 +1,620 @@
+//! A report about the X11 server for bug reports.
+//!
+//! When users report a bug, the first questions are usually about their X11 server: Which server
+//! is it, which extensions does it support, how are the monitors set up? [`diagnose`] collects
+//! this information into a [`Report`] that can be printed with its [`Display`](fmt::Display)
+//! implementation:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//!
+//! fn print_report(conn: &impl Connection) -> Result<(), ConnectionError> {
+//!     eprintln!("{}", x11rb::diagnose::diagnose(conn)?);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! All requests are sent in a few batches, so this needs about four round trips independent of
+//! the number of extensions. Monitors are only reported with the `randr` feature of the library
+//! and input devices only with the `xinput` feature.
+
+use std::fmt;
+
+use crate::atom_cache::AtomCache;
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, AccessControl, Atom, Host, VisualClass, Visualid, Window};
+use crate::NONE;
+
+/// Information about the X11 server, see [`diagnose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The vendor of the X11 server, e.g. `The X.Org Foundation`.
+    pub vendor: String,
+    /// The vendor-specific release number of the X11 server.
+    pub release_number: u32,
+    /// The version of the core protocol.
+    pub protocol_version: (u16, u16),
+    /// The maximum size of a request in bytes.
+    pub maximum_request_bytes: usize,
+    /// The extensions that the X11 server supports, sorted by name.
+    pub extensions: Vec<ExtensionReport>,
+    /// The screens of the X11 server.
+    pub screens: Vec<ScreenReport>,
+    /// The input devices, or `None` if the XInputExtension is not available.
+    pub input_devices: Option<Vec<InputDeviceReport>>,
+    /// The host-based access control of the X11 server, or `None` if this client may not query
+    /// it.
+    pub access_control: Option<AccessControlReport>,
+}
+
+/// An extension, see [`Report::extensions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionReport {
+    /// The name of the extension.
+    pub name: String,
+    /// The major opcode of the extension, which appears in errors of its requests.
+    pub major_opcode: u8,
+    /// The first event code of the extension, or zero if it has no events.
+    pub first_event: u8,
+    /// The first error code of the extension, or zero if it has no errors.
+    pub first_error: u8,
+    /// The version of the extension.
+    ///
+    /// This is only known for some extensions that x11rb supports and whose feature is enabled.
+    pub version: Option<(u32, u32)>,
+}
+
+/// A screen, see [`Report::screens`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenReport {
+    /// The root window of the screen.
+    pub root: Window,
+    /// The size of the screen in pixels when connecting.
+    pub size_in_pixels: (u16, u16),
+    /// The physical size of the screen in millimeters when connecting.
+    pub size_in_millimeters: (u16, u16),
+    /// The depth of the root window.
+    pub root_depth: u8,
+    /// The visual of the root window.
+    pub root_visual: Visualid,
+    /// The visuals of the screen.
+    pub visuals: Vec<VisualReport>,
+    /// The monitors of the screen, or `None` if they are not known.
+    ///
+    /// This needs RandR 1.5 and the `randr` feature of the library.
+    pub monitors: Option<Vec<MonitorReport>>,
+}
+
+/// A visual, see [`ScreenReport::visuals`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisualReport {
+    /// The ID of the visual.
+    pub id: Visualid,
+    /// The depth of the visual.
+    pub depth: u8,
+    /// The class of the visual.
+    pub class: VisualClass,
+    /// The number of significant bits per color channel.
+    pub bits_per_rgb_value: u8,
+}
+
+/// A monitor, see [`ScreenReport::monitors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorReport {
+    /// The name of the monitor, e.g. `DP-1`.
+    pub name: String,
+    /// Whether this is the primary monitor.
+    pub primary: bool,
+    /// The position of the monitor on the screen.
+    pub position: (i16, i16),
+    /// The size of the monitor in pixels.
+    pub size_in_pixels: (u16, u16),
+    /// The physical size of the monitor in millimeters.
+    pub size_in_millimeters: (u32, u32),
+}
+
+/// An input device, see [`Report::input_devices`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDeviceReport {
+    /// The ID of the device.
+    pub id: u8,
+    /// The name of the device.
+    pub name: String,
+    /// The type of the device, e.g. `MOUSE` or `TOUCHSCREEN`, if it has one.
+    pub device_type: Option<String>,
+    /// How the device is used, e.g. `IS_X_POINTER`.
+    pub usage: String,
+}
+
+/// The host-based access control, see [`Report::access_control`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessControlReport {
+    /// Whether access control is enabled.
+    ///
+    /// If it is disabled, e.g. with `xhost +`, any client that can reach the X11 server may
+    /// connect.
+    pub enabled: bool,
+    /// The hosts that may connect without authentication.
+    pub hosts: Vec<Host>,
+}
+
+/// Collect a [`Report`] about the X11 server.
+///
+/// Information that the X11 server refuses to give, e.g. because this is an untrusted client, is
+/// left out of the report. Only errors of the connection itself are returned.
+pub fn diagnose<C: Connection + ?Sized>(conn: &C) -> Result<Report, ConnectionError> {
+    let setup = conn.setup();
+
+    // First batch: The list of extensions. The extensions whose version is queried below are
+    // prefetched, so that their requests do not wait for QueryExtension one at a time.
+    prefetch_versioned_extensions(conn)?;
+    let extensions = xproto::list_extensions(conn)?;
+    let hosts = xproto::list_hosts(conn)?;
+    let names: Vec<String> = optional(extensions.reply())?
+        .map(|reply| {
+            reply
+                .names
+                .into_iter()
+                .map(|name| String::from_utf8_lossy(&name.name).into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Second batch: Everything that depends on the extensions
+    let query_cookies = names
+        .iter()
+        .map(|name| xproto::query_extension(conn, name.as_bytes()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let version_cookies = names
+        .iter()
+        .map(|name| query_version(conn, name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let monitor_cookies = setup
+        .roots
+        .iter()
+        .map(|screen| query_monitors(conn, screen.root, &names))
+        .collect::<Result<Vec<_>, _>>()?;
+    let devices_cookie = query_input_devices(conn, &names)?;
+
+    let mut extensions = Vec::with_capacity(names.len());
+    for ((name, query), version) in names.into_iter().zip(query_cookies).zip(version_cookies) {
+        let query = optional(query.reply())?;
+        let version = match version {
+            Some(version) => optional(version())?,
+            None => None,
+        };
+        if let Some(query) = query.filter(|query| query.present) {
+            extensions.push(ExtensionReport {
+                name,
+                major_opcode: query.major_opcode,
+                first_event: query.first_event,
+                first_error: query.first_error,
+                version,
+            });
+        }
+    }
+    extensions.sort_by(|a, b| a.name.cmp(&b.name));
+    let access_control = optional(hosts.reply())?.map(|reply| AccessControlReport {
+        enabled: reply.mode == AccessControl::ENABLE,
+        hosts: reply.hosts,
+    });
+    let mut monitors = monitor_cookies
+        .into_iter()
+        .map(|cookie| match cookie {
+            Some(cookie) => optional(cookie()),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut input_devices = match devices_cookie {
+        Some(cookie) => optional(cookie())?,
+        None => None,
+    };
+
+    // Third batch: The names of the monitors and of the device types
+    let mut atoms: Vec<Atom> = monitors.iter().flatten().flatten().map(|m| m.0).collect();
+    atoms.extend(input_devices.iter().flatten().map(|device| device.0));
+    atoms.retain(|&atom| atom != NONE);
+    let atom_names = optional(AtomCache::new().atom_names(conn, &atoms))?.unwrap_or_default();
+    let atom_name = |atom: Atom| {
+        atoms
+            .iter()
+            .position(|&a| a == atom)
+            .and_then(|index| atom_names.get(index))
+            .map(|name| String::from_utf8_lossy(name).into_owned())
+    };
+    for (atom, monitor) in monitors.iter_mut().flatten().flatten() {
+        monitor.name = atom_name(*atom).unwrap_or_else(|| format!("atom {}", atom));
+    }
+    for (atom, device) in input_devices.iter_mut().flatten() {
+        device.device_type = atom_name(*atom);
+    }
+
+    let screens = setup
+        .roots
+        .iter()
+        .zip(monitors)
+        .map(|(screen, monitors)| ScreenReport {
+            root: screen.root,
+            size_in_pixels: (screen.width_in_pixels, screen.height_in_pixels),
+            size_in_millimeters: (screen.width_in_millimeters, screen.height_in_millimeters),
+            root_depth: screen.root_depth,
+            root_visual: screen.root_visual,
+            visuals: screen
+                .allowed_depths
+                .iter()
+                .flat_map(|depth| {
+                    depth.visuals.iter().map(move |visual| VisualReport {
+                        id: visual.visual_id,
+                        depth: depth.depth,
+                        class: visual.class,
+                        bits_per_rgb_value: visual.bits_per_rgb_value,
+                    })
+                })
+                .collect(),
+            monitors: monitors.map(|monitors| monitors.into_iter().map(|m| m.1).collect()),
+        })
+        .collect();
+
+    Ok(Report {
+        vendor: String::from_utf8_lossy(&setup.vendor).into_owned(),
+        release_number: setup.release_number,
+        protocol_version: (setup.protocol_major_version, setup.protocol_minor_version),
+        maximum_request_bytes: conn.maximum_request_bytes(),
+        extensions,
+        screens,
+        input_devices: input_devices
+            .map(|devices| devices.into_iter().map(|device| device.1).collect()),
+        access_control,
+    })
+}
+
+/// Turn X11 errors into `None`, since the report just leaves out what the server refuses.
+fn optional<T>(result: Result<T, ReplyError>) -> Result<Option<T>, ConnectionError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ReplyError::X11Error(_)) => Ok(None),
+        Err(ReplyError::ConnectionError(err)) => Err(err),
+    }
+}
+
+/// A request that was sent and a function that waits for its reply.
+type Pending<'c, T> = Box<dyn FnOnce() -> Result<T, ReplyError> + 'c>;
+
+/// A monitor together with the atom of its name.
+type NamedMonitor = (Atom, MonitorReport);
+
+/// An input device together with the atom of its type.
+type TypedInputDevice = (Atom, InputDeviceReport);
+
+macro_rules! version_queries {
+    ($($feature:literal $ext:ident: |$conn:ident| $request:expr, |$reply:ident| $version:expr;)*) => {
+        fn prefetch_versioned_extensions<C>(conn: &C) -> Result<(), ConnectionError>
+        where
+            C: RequestConnection + ?Sized,
+        {
+            $(
+                #[cfg(feature = $feature)]
+                conn.prefetch_extension_information(crate::protocol::$ext::X11_EXTENSION_NAME)?;
+            )*
+            let _ = conn;
+            Ok(())
+        }
+
+        /// Send the request for the version of an extension, if x11rb knows how to.
+        fn query_version<'c, C>(
+            conn: &'c C,
+            name: &str,
+        ) -> Result<Option<Pending<'c, (u32, u32)>>, ConnectionError>
+        where
+            C: RequestConnection + ?Sized,
+        {
+            $(
+                #[cfg(feature = $feature)]
+                {
+                    if name == crate::protocol::$ext::X11_EXTENSION_NAME {
+                        let $conn = conn;
+                        let cookie = $request?;
+                        return Ok(Some(Box::new(move || {
+                            let $reply = cookie.reply()?;
+                            Ok($version)
+                        })));
+                    }
+                }
+            )*
+            let _ = (conn, name);
+            Ok(None)
+        }
+    };
+}
+
+version_queries! {
+    "composite" composite: |conn| crate::protocol::composite::query_version(conn, 0, 4),
+        |reply| (reply.major_version, reply.minor_version);
+    "damage" damage: |conn| crate::protocol::damage::query_version(conn, 1, 1),
+        |reply| (reply.major_version, reply.minor_version);
+    "dri3" dri3: |conn| crate::protocol::dri3::query_version(conn, 1, 2),
+        |reply| (reply.major_version, reply.minor_version);
+    "present" present: |conn| crate::protocol::present::query_version(conn, 1, 2),
+        |reply| (reply.major_version, reply.minor_version);
+    "randr" randr: |conn| crate::protocol::randr::query_version(conn, 1, 6),
+        |reply| (reply.major_version, reply.minor_version);
+    "render" render: |conn| crate::protocol::render::query_version(conn, 0, 11),
+        |reply| (reply.major_version, reply.minor_version);
+    "shape" shape: |conn| crate::protocol::shape::query_version(conn),
+        |reply| (reply.major_version.into(), reply.minor_version.into());
+    "shm" shm: |conn| crate::protocol::shm::query_version(conn),
+        |reply| (reply.major_version.into(), reply.minor_version.into());
+    "sync" sync: |conn| crate::protocol::sync::initialize(conn, 3, 1),
+        |reply| (reply.major_version.into(), reply.minor_version.into());
+    "xfixes" xfixes: |conn| crate::protocol::xfixes::query_version(conn, 6, 0),
+        |reply| (reply.major_version, reply.minor_version);
+    "xinput" xinput: |conn| crate::protocol::xinput::xi_query_version(conn, 2, 4),
+        |reply| (reply.major_version.into(), reply.minor_version.into());
+    "xkb" xkb: |conn| crate::protocol::xkb::use_extension(conn, 1, 0),
+        |reply| (reply.server_major.into(), reply.server_minor.into());
+    "xtest" xtest: |conn| crate::protocol::xtest::get_version(conn, 2, 2),
+        |reply| (reply.major_version.into(), reply.minor_version.into());
+}
+
+/// Send the request for the monitors of a screen, if RandR is available.
+///
+/// The monitors are returned together with the atoms of their names.
+fn query_monitors<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    root: Window,
+    extensions: &[String],
+) -> Result<Option<Pending<'c, Vec<NamedMonitor>>>, ConnectionError> {
+    #[cfg(feature = "randr")]
+    {
+        use crate::protocol::randr;
+
+        if extensions
+            .iter()
+            .any(|ext| ext == randr::X11_EXTENSION_NAME)
+        {
+            let cookie = randr::get_monitors(conn, root, true)?;
+            return Ok(Some(Box::new(move || {
+                Ok(cookie
+                    .reply()?
+                    .monitors
+                    .into_iter()
+                    .map(|monitor| {
+                        let report = MonitorReport {
+                            name: String::new(),
+                            primary: monitor.primary,
+                            position: (monitor.x, monitor.y),
+                            size_in_pixels: (monitor.width, monitor.height),
+                            size_in_millimeters: (
+                                monitor.width_in_millimeters,
+                                monitor.height_in_millimeters,
+                            ),
+                        };
+                        (monitor.name, report)
+                    })
+                    .collect())
+            })));
+        }
+    }
+    let _ = (conn, root, extensions);
+    Ok(None)
+}
+
+/// Send the request for the input devices, if the XInputExtension is available.
+///
+/// The devices are returned together with the atoms of their types.
+fn query_input_devices<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    extensions: &[String],
+) -> Result<Option<Pending<'c, Vec<TypedInputDevice>>>, ConnectionError> {
+    #[cfg(feature = "xinput")]
+    {
+        use crate::protocol::xinput;
+
+        if extensions
+            .iter()
+            .any(|ext| ext == xinput::X11_EXTENSION_NAME)
+        {
+            let cookie = xinput::list_input_devices(conn)?;
+            return Ok(Some(Box::new(move || {
+                let reply = cookie.reply()?;
+                Ok(reply
+                    .devices
+                    .into_iter()
+                    .zip(reply.names)
+                    .map(|(device, name)| {
+                        let report = InputDeviceReport {
+                            id: device.device_id,
+                            name: String::from_utf8_lossy(&name.name).into_owned(),
+                            device_type: None,
+                            usage: format!("{:?}", device.device_use),
+                        };
+                        (device.device_type, report)
+                    })
+                    .collect())
+            })));
+        }
+    }
+    let _ = (conn, extensions);
+    Ok(None)
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "X11 server: {} (release {}), protocol {}.{}",
+            self.vendor, self.release_number, self.protocol_version.0, self.protocol_version.1
+        )?;
+        writeln!(
+            f,
+            "Maximum request size: {} bytes",
+            self.maximum_request_bytes
+        )?;
+        writeln!(f, "Extensions:")?;
+        for ext in &self.extensions {
+            write!(
+                f,
+                "  {} (opcode {}, first event {}, first error {})",
+                ext.name, ext.major_opcode, ext.first_event, ext.first_error
+            )?;
+            match ext.version {
+                Some((major, minor)) => writeln!(f, ", version {}.{}", major, minor)?,
+                None => writeln!(f)?,
+            }
+        }
+        for (index, screen) in self.screens.iter().enumerate() {
+            writeln!(
+                f,
+                "Screen {}: root {:#x}, {}x{} pixels, {}x{} mm, depth {}, visual {:#x}",
+                index,
+                screen.root,
+                screen.size_in_pixels.0,
+                screen.size_in_pixels.1,
+                screen.size_in_millimeters.0,
+                screen.size_in_millimeters.1,
+                screen.root_depth,
+                screen.root_visual
+            )?;
+            let mut depths: Vec<u8> = screen.visuals.iter().map(|v| v.depth).collect();
+            depths.dedup();
+            for depth in depths {
+                let count = screen.visuals.iter().filter(|v| v.depth == depth).count();
+                writeln!(f, "  {} visuals with depth {}", count, depth)?;
+            }
+            match &screen.monitors {
+                Some(monitors) => {
+                    for monitor in monitors {
+                        writeln!(
+                            f,
+                            "  Monitor {}{}: {}x{}+{}+{}, {}x{} mm",
+                            monitor.name,
+                            if monitor.primary { " (primary)" } else { "" },
+                            monitor.size_in_pixels.0,
+                            monitor.size_in_pixels.1,
+                            monitor.position.0,
+                            monitor.position.1,
+                            monitor.size_in_millimeters.0,
+                            monitor.size_in_millimeters.1
+                        )?;
+                    }
+                }
+                None => writeln!(f, "  Monitors unknown")?,
+            }
+        }
+        match &self.input_devices {
+            Some(devices) => {
+                writeln!(f, "Input devices:")?;
+                for device in devices {
+                    write!(f, "  {}: {} ({}", device.id, device.name, device.usage)?;
+                    match &device.device_type {
+                        Some(device_type) => writeln!(f, ", {})", device_type)?,
+                        None => writeln!(f, ")")?,
+                    }
+                }
+            }
+            None => writeln!(f, "Input devices unknown")?,
+        }
+        match &self.access_control {
+            Some(access) if access.enabled => writeln!(
+                f,
+                "Access control enabled, {} hosts allowed",
+                access.hosts.len()
+            ),
+            Some(access) => writeln!(
+                f,
+                "Access control DISABLED, any client may connect ({} hosts listed)",
+                access.hosts.len()
+            ),
+            None => writeln!(f, "Access control unknown"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{diagnose, AccessControlReport, ExtensionReport};
+    use crate::protocol::xproto;
+    use crate::testing::{FakeConnection, Response};
+
+    #[test]
+    fn report() {
+        let conn = FakeConnection::default();
+        let mut reply = vec![0; 32];
+        reply[1] = 2;
+        reply.extend(b"\x03FOO\x03BAR");
+        conn.respond(
+            xproto::LIST_EXTENSIONS_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+        conn.respond(
+            xproto::LIST_HOSTS_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::ACCESS_ERROR,
+                bad_value: 0,
+            },
+        );
+        conn.respond(
+            xproto::QUERY_EXTENSION_REQUEST,
+            None,
+            Response::Reply(vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 130, 90, 140]),
+        );
+        conn.respond(
+            xproto::QUERY_EXTENSION_REQUEST,
+            None,
+            Response::Reply(vec![0; 12]),
+        );
+
+        let report = diagnose(&conn).unwrap();
+        assert_eq!(report.vendor, "x11rb");
+        assert_eq!(report.protocol_version, (11, 0));
+        assert_eq!(
+            report.extensions,
+            [ExtensionReport {
+                name: "FOO".into(),
+                major_opcode: 130,
+                first_event: 90,
+                first_error: 140,
+                version: None,
+            }]
+        );
+        assert_eq!(report.screens.len(), 1);
+        assert_eq!(report.screens[0].size_in_pixels, (1024, 768));
+        assert_eq!(report.screens[0].visuals.len(), 1);
+        assert_eq!(report.screens[0].monitors, None);
+        assert_eq!(report.input_devices, None);
+        assert_eq!(report.access_control, None);
+
+        let text = report.to_string();
+        assert!(text.contains("FOO (opcode 130, first event 90, first error 140)"));
+        assert!(text.contains("Access control unknown"));
+    }
+
+    #[test]
+    fn access_control_disabled() {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::LIST_EXTENSIONS_REQUEST,
+            None,
+            Response::Reply(vec![0; 32]),
+        );
+        conn.respond(
+            xproto::LIST_HOSTS_REQUEST,
+            None,
+            Response::Reply(vec![0; 32]),
+        );
+        let report = diagnose(&conn).unwrap();
+        assert!(report.extensions.is_empty());
+        assert_eq!(
+            report.access_control,
+            Some(AccessControlReport {
+                enabled: false,
+                hosts: Vec::new(),
+            })
+        );
+        assert!(report.to_string().contains("Access control DISABLED"));
+    }
+}
@@ -0,0 +1,333 @@
+//! Saving the state of a window for bug reports.
+//!
+//! [`dump_window`] captures the content of a window into a PNG file and writes its geometry,
+//! attributes, and properties into a JSON file next to it. This is meant as a debugging aid, e.g.
+//! for window managers or test harnesses that want to attach the state of a misbehaving window to
+//! a bug report.
+//!
+//! This module is only available when the `image` feature is enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::dump::dump_window;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn check_window(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+//!     if !window_looks_right(window) {
+//!         // Writes window.png and window.json
+//!         dump_window(conn, window, "window.png")?;
+//!     }
+//!     Ok(())
+//! }
+//! # fn window_looks_right(_: Window) -> bool { true }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::atom_cache::AtomCache;
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::image::Image;
+use crate::protocol::xproto::{self, AtomEnum, GetPropertyReply, MapState, Window};
+use crate::wrapper::ConnectionExt as _;
+
+/// Save the content and the state of a window.
+///
+/// The content of the window is written as a PNG image to `path`. A JSON file with the same name,
+/// but with the extension `.json`, receives the window's geometry, its attributes, and the values
+/// of all its properties.
+///
+/// The content can only be captured if the window is viewable, completely on screen, and its
+/// visual is `TrueColor` or `DirectColor`. Otherwise, no image is written and the `image` field in
+/// the JSON file is `null`. Parts of the window that are obscured by other windows might contain
+/// garbage, unless the X11 server uses backing store for the window.
+///
+/// This function waits for the replies to all necessary requests.
+pub fn dump_window<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    path: impl AsRef<Path>,
+) -> Result<(), ReplyError> {
+    let path = path.as_ref();
+    let attributes = xproto::get_window_attributes(conn, window)?;
+    let geometry = xproto::get_geometry(conn, window)?;
+    let properties = xproto::list_properties(conn, window)?;
+    let (attributes, geometry) = (attributes.reply()?, geometry.reply()?);
+    let properties = properties.reply()?.atoms;
+
+    let mut cache = AtomCache::new();
+    let names = cache.atom_names(conn, &properties)?;
+    let mut values = Vec::with_capacity(properties.len());
+    for (property, name) in properties.iter().zip(names) {
+        match conn.get_property_full(false, window, *property, AtomEnum::ANY) {
+            Ok(reply) if reply.format != 0 => values.push((name, reply)),
+            // The property was deleted in the meantime
+            Ok(_) | Err(ReplyError::X11Error(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    let types = values
+        .iter()
+        .map(|(_, reply)| reply.type_)
+        .collect::<Vec<_>>();
+    let types = cache.atom_names(conn, &types)?;
+
+    let image = if attributes.map_state == MapState::VIEWABLE {
+        capture(conn, window, geometry.width, geometry.height)?
+    } else {
+        None
+    };
+    if let Some(png) = &image {
+        fs::write(path, png)?;
+    }
+
+    let mut json = String::new();
+    let _ = writeln!(json, "{{");
+    let _ = writeln!(json, "  \"window\": {},", window);
+    let _ = writeln!(
+        json,
+        "  \"geometry\": {{\"x\": {}, \"y\": {}, \"width\": {}, \"height\": {}, \
+         \"border_width\": {}, \"depth\": {}}},",
+        geometry.x,
+        geometry.y,
+        geometry.width,
+        geometry.height,
+        geometry.border_width,
+        geometry.depth
+    );
+    let map_state = match attributes.map_state {
+        MapState::UNMAPPED => "unmapped",
+        MapState::UNVIEWABLE => "unviewable",
+        _ => "viewable",
+    };
+    let _ = writeln!(
+        json,
+        "  \"attributes\": {{\"map_state\": \"{}\", \"override_redirect\": {}, \
+         \"input_only\": {}, \"visual\": {}, \"colormap\": {}, \"all_event_masks\": {}, \
+         \"your_event_mask\": {}}},",
+        map_state,
+        attributes.override_redirect,
+        attributes.class == xproto::WindowClass::INPUT_ONLY,
+        attributes.visual,
+        attributes.colormap,
+        attributes.all_event_masks,
+        attributes.your_event_mask
+    );
+    match (&image, path.file_name()) {
+        (Some(_), Some(name)) => {
+            let name = json_string(name.to_string_lossy().as_bytes());
+            let _ = writeln!(json, "  \"image\": {},", name);
+        }
+        _ => {
+            let _ = writeln!(json, "  \"image\": null,");
+        }
+    }
+    let _ = writeln!(json, "  \"properties\": [");
+    for (index, ((name, reply), type_)) in values.iter().zip(types).enumerate() {
+        let separator = if index + 1 < values.len() { "," } else { "" };
+        let _ = writeln!(
+            json,
+            "    {{\"name\": {}, \"type\": {}, \"format\": {}, \"value\": {}}}{}",
+            json_string(name),
+            json_string(&type_),
+            reply.format,
+            property_value(reply, &type_),
+            separator
+        );
+    }
+    let _ = writeln!(json, "  ]");
+    let _ = writeln!(json, "}}");
+    fs::write(path.with_extension("json"), json)?;
+    Ok(())
+}
+
+/// Get the content of a window as a PNG image.
+///
+/// Returns `None` if the visual of the window is not supported.
+fn capture<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    width: u16,
+    height: u16,
+) -> Result<Option<Vec<u8>>, ReplyError> {
+    let (image, layout) = match Image::get_with_layout(conn, window, 0, 0, width, height) {
+        Ok(result) => result,
+        // The visual is not supported or the window is not completely on screen
+        Err(ReplyError::ConnectionError(ConnectionError::ParseError(_)))
+        | Err(ReplyError::X11Error(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut rgb = Vec::with_capacity(3 * usize::from(width) * usize::from(height));
+    for y in 0..height {
+        for x in 0..width {
+            let (red, green, blue) = layout.decode(image.get_pixel(x, y));
+            rgb.extend_from_slice(&[(red >> 8) as u8, (green >> 8) as u8, (blue >> 8) as u8]);
+        }
+    }
+    Ok(Some(encode_png(u32::from(width), u32::from(height), &rgb)))
+}
+
+/// Format the value of a property as JSON.
+///
+/// Text is written as a string, everything else as a list of numbers.
+fn property_value(reply: &GetPropertyReply, type_name: &[u8]) -> String {
+    let is_text = matches!(type_name, b"STRING" | b"UTF8_STRING" | b"COMPOUND_TEXT");
+    if reply.format == 8 && is_text {
+        return json_string(&reply.value);
+    }
+    let values = match reply.format {
+        8 => reply
+            .value
+            .iter()
+            .map(|&v| u32::from(v))
+            .collect::<Vec<_>>(),
+        16 => reply
+            .value16()
+            .into_iter()
+            .flatten()
+            .map(u32::from)
+            .collect(),
+        _ => reply.value32().into_iter().flatten().collect(),
+    };
+    let values = values.iter().map(u32::to_string).collect::<Vec<_>>();
+    format!("[{}]", values.join(", "))
+}
+
+/// Format bytes as a JSON string, replacing invalid UTF-8.
+fn json_string(value: &[u8]) -> String {
+    let mut result = String::from("\"");
+    for c in String::from_utf8_lossy(value).chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(result, "\\u{:04x}", u32::from(c));
+            }
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Encode an 8-bit RGB image as PNG.
+///
+/// The image data is not compressed, since this only needs to be good enough for debugging.
+pub(crate) fn encode_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let stride = 3 * width as usize;
+    // Every scanline starts with the filter type, 0 meaning no filter
+    let mut raw = Vec::with_capacity((stride + 1) * height as usize);
+    for line in rgb.chunks_exact(stride.max(1)).take(height as usize) {
+        raw.push(0);
+        raw.extend_from_slice(line);
+    }
+
+    // A zlib stream with uncompressed deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = u16::try_from(block.len()).unwrap();
+        zlib.push(u8::from(blocks.peek().is_none()));
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bits per sample, RGB, default compression, filtering, and no interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &zlib);
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let len = u32::try_from(data.len()).expect("PNG chunk is too large");
+    png.extend_from_slice(&len.to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod test {
+    use super::{adler32, crc32, encode_png, json_string, property_value};
+    use crate::protocol::xproto::GetPropertyReply;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn png_structure() {
+        let png = encode_png(2, 1, &[255, 0, 0, 0, 255, 0]);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 1]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+        // Signature, IHDR, IDAT with a single stored block of 7 bytes, IEND
+        assert_eq!(png.len(), 8 + 25 + 12 + 2 + 5 + 7 + 4 + 12);
+    }
+
+    #[test]
+    fn json_values() {
+        assert_eq!(json_string(b"a\"b\\c\n\x01"), r#""a\"b\\c\n\u0001""#);
+        let reply = GetPropertyReply {
+            format: 32,
+            sequence: 0,
+            length: 0,
+            type_: 6,
+            bytes_after: 0,
+            value_len: 2,
+            value: [1u32, 2].iter().flat_map(|v| v.to_ne_bytes()).collect(),
+        };
+        assert_eq!(property_value(&reply, b"CARDINAL"), "[1, 2]");
+        let reply = GetPropertyReply {
+            format: 8,
+            value_len: 2,
+            value: b"hi".to_vec(),
+            ..reply
+        };
+        assert_eq!(property_value(&reply, b"STRING"), r#""hi""#);
+        assert_eq!(property_value(&reply, b"FOO"), "[104, 105]");
+    }
+}
@@ -0,0 +1,581 @@
+//! Using connections as trait objects.
+//!
+//! [`RequestConnection`] and [`Connection`] have generic methods and an associated `Buf` type, so
+//! `&dyn Connection` is not possible. This is a problem for plugin architectures that want to
+//! hand a connection to code that does not know its concrete type.
+//!
+//! [`DynConnection`] is the object-safe part of these traits. It is implemented for every
+//! [`Connection`] and `dyn DynConnection` in turn implements [`Connection`], so all the generated
+//! request functions and the helpers of this crate can be used with `&dyn DynConnection`:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::dyn_connection::DynConnection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::ConnectionExt as _;
+//!
+//! // A plugin entry point that does not know the connection type
+//! fn plugin_main(conn: &dyn DynConnection) -> Result<(), ReplyError> {
+//!     let root = conn.setup().roots[0].root;
+//!     let tree = conn.query_tree(root)?.reply()?;
+//!     println!("The root window has {} children", tree.children.len());
+//!     Ok(())
+//! }
+//!
+//! fn load_plugin(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     plugin_main(conn)
+//! }
+//! ```
+//!
+//! The methods of [`DynConnection`] are only the building blocks for this and have a `dyn_`
+//! prefix so that they do not clash with the methods of [`Connection`]. Replies and events are
+//! copied into a `Vec<u8>` when they pass through the trait object.
+
+use std::io::IoSlice;
+
+use crate::connection::{
+    BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError, RequestConnection,
+    RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::Event;
+use crate::utils::OwnedFd;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+/// The object-safe part of [`Connection`].
+///
+/// See the [module documentation](self) for details. Users of this library will most likely not
+/// want to call these methods directly, but instead use the [`Connection`] implementation of
+/// `dyn DynConnection`.
+pub trait DynConnection {
+    /// Send a request to the server and return its sequence number.
+    ///
+    /// See [`RequestConnection::send_request_with_reply`].
+    fn dyn_send_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+        kind: RequestKind,
+        reply_has_fds: bool,
+    ) -> Result<SequenceNumber, ConnectionError>;
+
+    /// See [`RequestConnection::discard_reply`].
+    fn dyn_discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode);
+
+    /// See [`RequestConnection::prefetch_extension_information`].
+    fn dyn_prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError>;
+
+    /// See [`RequestConnection::extension_information`].
+    fn dyn_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply_or_raw_error`].
+    fn dyn_wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::poll_for_reply_or_raw_error`].
+    fn dyn_poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply`].
+    fn dyn_wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply_with_fds_raw`].
+    fn dyn_wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::check_for_raw_error`].
+    fn dyn_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::prefetch_maximum_request_bytes`].
+    fn dyn_prefetch_maximum_request_bytes(&self);
+
+    /// See [`RequestConnection::maximum_request_bytes`].
+    fn dyn_maximum_request_bytes(&self) -> usize;
+
+    /// See [`RequestConnection::parse_error`].
+    fn dyn_parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError>;
+
+    /// See [`RequestConnection::parse_event`].
+    fn dyn_parse_event(&self, event: &[u8]) -> Result<Event, ParseError>;
+
+    /// See [`Connection::wait_for_raw_event_with_sequence`].
+    fn dyn_wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError>;
+
+    /// See [`Connection::poll_for_raw_event_with_sequence`].
+    fn dyn_poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError>;
+
+    /// See [`Connection::flush`].
+    fn dyn_flush(&self) -> Result<(), ConnectionError>;
+
+    /// See [`Connection::setup`].
+    fn dyn_setup(&self) -> &Setup;
+
+    /// See [`Connection::generate_id`].
+    fn dyn_generate_id(&self) -> Result<u32, ReplyOrIdError>;
+}
+
+fn to_vec<B: AsRef<[u8]>>(buf: B) -> Vec<u8> {
+    buf.as_ref().to_vec()
+}
+
+impl<C: Connection> DynConnection for C {
+    fn dyn_send_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+        kind: RequestKind,
+        reply_has_fds: bool,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        // The reply type does not matter, only the sequence number is kept
+        Ok(match (kind, reply_has_fds) {
+            (RequestKind::IsVoid, _) => self
+                .send_request_without_reply(bufs, fds)?
+                .into_sequence_number(),
+            (RequestKind::HasResponse, false) => self
+                .send_request_with_reply::<RawReply>(bufs, fds)?
+                .into_sequence_number(),
+            (RequestKind::HasResponse, true) => self
+                .send_request_with_reply_with_fds::<RawReply>(bufs, fds)?
+                .into_sequence_number(),
+        })
+    }
+
+    fn dyn_discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.discard_reply(sequence, kind, mode)
+    }
+
+    fn dyn_prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.prefetch_extension_information(extension_name)
+    }
+
+    fn dyn_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.extension_information(extension_name)
+    }
+
+    fn dyn_wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        Ok(match self.wait_for_reply_or_raw_error(sequence)? {
+            ReplyOrError::Reply(reply) => ReplyOrError::Reply(to_vec(reply)),
+            ReplyOrError::Error(error) => ReplyOrError::Error(to_vec(error)),
+        })
+    }
+
+    fn dyn_poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .poll_for_reply_or_raw_error(sequence)?
+            .map(|result| match result {
+                ReplyOrError::Reply(reply) => ReplyOrError::Reply(to_vec(reply)),
+                ReplyOrError::Error(error) => ReplyOrError::Error(to_vec(error)),
+            }))
+    }
+
+    fn dyn_wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        Ok(self.wait_for_reply(sequence)?.map(to_vec))
+    }
+
+    fn dyn_wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        Ok(match self.wait_for_reply_with_fds_raw(sequence)? {
+            ReplyOrError::Reply((reply, fds)) => ReplyOrError::Reply((to_vec(reply), fds)),
+            ReplyOrError::Error(error) => ReplyOrError::Error(to_vec(error)),
+        })
+    }
+
+    fn dyn_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        Ok(self.check_for_raw_error(sequence)?.map(to_vec))
+    }
+
+    fn dyn_prefetch_maximum_request_bytes(&self) {
+        self.prefetch_maximum_request_bytes()
+    }
+
+    fn dyn_maximum_request_bytes(&self) -> usize {
+        self.maximum_request_bytes()
+    }
+
+    fn dyn_parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.parse_error(error)
+    }
+
+    fn dyn_parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.parse_event(event)
+    }
+
+    fn dyn_wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        let (event, seq) = self.wait_for_raw_event_with_sequence()?;
+        Ok((to_vec(event), seq))
+    }
+
+    fn dyn_poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .poll_for_raw_event_with_sequence()?
+            .map(|(event, seq)| (to_vec(event), seq)))
+    }
+
+    fn dyn_flush(&self) -> Result<(), ConnectionError> {
+        self.flush()
+    }
+
+    fn dyn_setup(&self) -> &Setup {
+        self.setup()
+    }
+
+    fn dyn_generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.generate_id()
+    }
+}
+
+/// Placeholder reply type for sending requests whose reply is only handled as raw bytes.
+///
+/// This is used by [`DynConnection::dyn_send_request`], which immediately turns the cookie into a
+/// sequence number, so this is never parsed.
+#[derive(Debug)]
+pub(crate) struct RawReply;
+
+impl TryParse for RawReply {
+    fn try_parse(_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        unreachable!("RawReply is never parsed")
+    }
+}
+
+macro_rules! impl_connection_for_dyn {
+    ($($bounds:tt)*) => {
+        impl<'a> RequestConnection for dyn DynConnection $($bounds)* + 'a {
+            type Buf = Vec<u8>;
+
+            fn send_request_with_reply<R>(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<OwnedFd>,
+            ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+            where
+                R: TryParse,
+            {
+                let seq = self.dyn_send_request(bufs, fds, RequestKind::HasResponse, false)?;
+                Ok(Cookie::new(self, seq))
+            }
+
+            fn send_request_with_reply_with_fds<R>(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<OwnedFd>,
+            ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+            where
+                R: TryParseFd,
+            {
+                let seq = self.dyn_send_request(bufs, fds, RequestKind::HasResponse, true)?;
+                Ok(CookieWithFds::new(self, seq))
+            }
+
+            fn send_request_without_reply(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<OwnedFd>,
+            ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+                let seq = self.dyn_send_request(bufs, fds, RequestKind::IsVoid, false)?;
+                Ok(VoidCookie::new(self, seq))
+            }
+
+            fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+                self.dyn_discard_reply(sequence, kind, mode)
+            }
+
+            fn prefetch_extension_information(
+                &self,
+                extension_name: &'static str,
+            ) -> Result<(), ConnectionError> {
+                self.dyn_prefetch_extension_information(extension_name)
+            }
+
+            fn extension_information(
+                &self,
+                extension_name: &'static str,
+            ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+                self.dyn_extension_information(extension_name)
+            }
+
+            fn wait_for_reply_or_raw_error(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply_or_raw_error(sequence)
+            }
+
+            fn poll_for_reply_or_raw_error(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+                self.dyn_poll_for_reply_or_raw_error(sequence)
+            }
+
+            fn wait_for_reply(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<Option<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply(sequence)
+            }
+
+            fn wait_for_reply_with_fds_raw(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply_with_fds_raw(sequence)
+            }
+
+            fn check_for_raw_error(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<Option<Vec<u8>>, ConnectionError> {
+                self.dyn_check_for_raw_error(sequence)
+            }
+
+            fn prefetch_maximum_request_bytes(&self) {
+                self.dyn_prefetch_maximum_request_bytes()
+            }
+
+            fn maximum_request_bytes(&self) -> usize {
+                self.dyn_maximum_request_bytes()
+            }
+
+            fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+                self.dyn_parse_error(error)
+            }
+
+            fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+                self.dyn_parse_event(event)
+            }
+        }
+
+        impl<'a> Connection for dyn DynConnection $($bounds)* + 'a {
+            fn wait_for_raw_event_with_sequence(
+                &self,
+            ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_raw_event_with_sequence()
+            }
+
+            fn poll_for_raw_event_with_sequence(
+                &self,
+            ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+                self.dyn_poll_for_raw_event_with_sequence()
+            }
+
+            fn flush(&self) -> Result<(), ConnectionError> {
+                self.dyn_flush()
+            }
+
+            fn setup(&self) -> &Setup {
+                self.dyn_setup()
+            }
+
+            fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+                self.dyn_generate_id()
+            }
+        }
+    };
+}
+
+impl_connection_for_dyn!();
+impl_connection_for_dyn!(+ Send);
+impl_connection_for_dyn!(+ Send + Sync);
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::io::IoSlice;
+
+    use super::DynConnection;
+    use crate::connection::{
+        BufWithFds, DiscardMode, RawEventAndSeqNumber, ReplyOrError, RequestKind, SequenceNumber,
+    };
+    use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+    use crate::protocol::xproto::{self, ConnectionExt as _, Setup};
+    use crate::protocol::Event;
+    use crate::utils::OwnedFd;
+    use crate::x11_utils::{ExtensionInformation, X11Error};
+
+    /// Records the requests that are sent and the replies that are discarded.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        sent: RefCell<Vec<(Vec<u8>, RequestKind)>>,
+        discarded: RefCell<Vec<SequenceNumber>>,
+    }
+
+    impl DynConnection for Recorder {
+        fn dyn_send_request(
+            &self,
+            bufs: &[IoSlice<'_>],
+            _fds: Vec<OwnedFd>,
+            kind: RequestKind,
+            _reply_has_fds: bool,
+        ) -> Result<SequenceNumber, ConnectionError> {
+            let mut sent = self.sent.borrow_mut();
+            sent.push((
+                bufs.iter().flat_map(|buf| buf.iter().copied()).collect(),
+                kind,
+            ));
+            Ok(sent.len() as SequenceNumber)
+        }
+
+        fn dyn_discard_reply(
+            &self,
+            sequence: SequenceNumber,
+            _kind: RequestKind,
+            _mode: DiscardMode,
+        ) {
+            self.discarded.borrow_mut().push(sequence);
+        }
+
+        fn dyn_prefetch_extension_information(
+            &self,
+            _extension_name: &'static str,
+        ) -> Result<(), ConnectionError> {
+            Ok(())
+        }
+
+        fn dyn_extension_information(
+            &self,
+            _extension_name: &'static str,
+        ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+            Ok(None)
+        }
+
+        fn dyn_wait_for_reply_or_raw_error(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_poll_for_reply_or_raw_error(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_wait_for_reply(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<Option<Vec<u8>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_wait_for_reply_with_fds_raw(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_check_for_raw_error(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<Option<Vec<u8>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_prefetch_maximum_request_bytes(&self) {}
+
+        fn dyn_maximum_request_bytes(&self) -> usize {
+            1 << 16
+        }
+
+        fn dyn_parse_error(&self, _error: &[u8]) -> Result<X11Error, ParseError> {
+            unimplemented!()
+        }
+
+        fn dyn_parse_event(&self, _event: &[u8]) -> Result<Event, ParseError> {
+            unimplemented!()
+        }
+
+        fn dyn_wait_for_raw_event_with_sequence(
+            &self,
+        ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+            unimplemented!()
+        }
+
+        fn dyn_poll_for_raw_event_with_sequence(
+            &self,
+        ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+            Ok(None)
+        }
+
+        fn dyn_flush(&self) -> Result<(), ConnectionError> {
+            Ok(())
+        }
+
+        fn dyn_setup(&self) -> &Setup {
+            unimplemented!()
+        }
+
+        fn dyn_generate_id(&self) -> Result<u32, ReplyOrIdError> {
+            Ok(42)
+        }
+    }
+
+    #[test]
+    fn requests_through_trait_object() {
+        let recorder = Recorder::default();
+        let conn: &dyn DynConnection = &recorder;
+
+        let _ = conn.bell(50).unwrap();
+        let cookie = conn.get_input_focus().unwrap();
+        drop(cookie);
+
+        let sent = recorder.sent.borrow();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].0, [xproto::BELL_REQUEST, 50, 1, 0]);
+        assert_eq!(sent[0].1, RequestKind::IsVoid);
+        assert_eq!(sent[1].0, [xproto::GET_INPUT_FOCUS_REQUEST, 0, 1, 0]);
+        assert_eq!(sent[1].1, RequestKind::HasResponse);
+        // Dropping the cookies discards their replies
+        assert_eq!(*recorder.discarded.borrow(), [1, 2]);
+    }
+}
@@ -0,0 +1,355 @@
+//! Serializing requests from a map of field values.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use super::{
+    find_request, DynamicError, FieldInfo, FieldKind, FieldType, ListLength, RequestInfo, Value,
+};
+
+/// A request that was built by [`build_request`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltRequest {
+    info: &'static RequestInfo,
+    bytes: Vec<u8>,
+}
+
+impl BuiltRequest {
+    /// Get the description of the request.
+    pub fn info(&self) -> &'static RequestInfo {
+        self.info
+    }
+
+    /// Get the serialized request.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get the serialized request.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Set the major opcode of the request.
+    ///
+    /// The major opcode of an extension request is only known after querying the extension from
+    /// the X11 server. [`build_request`] sets it to zero for extension requests. Use this function
+    /// to set the real value before sending the request.
+    pub fn set_major_opcode(&mut self, major_opcode: u8) {
+        self.bytes[0] = major_opcode;
+    }
+}
+
+/// Build a request from its name and the values of its fields.
+///
+/// The request is looked up with [`find_request`]. The header fields `major_opcode`,
+/// `minor_opcode`, and `length` are filled in automatically. Additionally, the following fields
+/// can be left out and are computed:
+///
+/// - Fields that contain the length of a list (see [`ListLength::Field`]).
+/// - The bitmask of a [`FieldKind::Switch`]. The mask has the bits set for all cases for which a
+///   field has a value.
+///
+/// Lists of bytes or characters can also be given as [`Value::Str`]. Values are serialized in
+/// native endianness, like in the rest of x11rb. The major opcode of an extension request is zero
+/// and must be set with [`BuiltRequest::set_major_opcode`] before it is sent. [`send_request`]
+/// does this automatically.
+///
+/// [`send_request`]: super::send_request
+pub fn build_request(
+    name: &str,
+    fields: &HashMap<String, Value>,
+) -> Result<BuiltRequest, DynamicError> {
+    let info = find_request(name).ok_or_else(|| DynamicError::UnknownRequest(name.to_string()))?;
+
+    let mut values: HashMap<&str, Value> = HashMap::new();
+    for (name, value) in fields.iter() {
+        if is_header_field(name) || !has_field(info.fields, name) {
+            return Err(DynamicError::UnknownField(name.clone()));
+        }
+        let _ = values.insert(name.as_str(), value.clone());
+    }
+    let (major_opcode, minor_opcode) = match info.extension {
+        Some(_) => (0, Some(info.opcode)),
+        None => (info.opcode, None),
+    };
+    let _ = values.insert("major_opcode", major_opcode.into());
+    if let Some(minor_opcode) = minor_opcode {
+        let _ = values.insert("minor_opcode", minor_opcode.into());
+    }
+    let _ = values.insert("length", Value::Int(0));
+    deduce_values(info.fields, &mut values);
+
+    let mut bytes = Vec::new();
+    write_fields(info.fields, &values, &mut bytes)?;
+    pad_to(&mut bytes, 4);
+    // A length of zero means that the connection has to use the BIG-REQUESTS extension
+    let length = u16::try_from(bytes.len() / 4).unwrap_or(0);
+    bytes[2..4].copy_from_slice(&length.to_ne_bytes());
+
+    Ok(BuiltRequest { info, bytes })
+}
+
+/// Append zero bytes until the length is a multiple of `align`.
+fn pad_to(bytes: &mut Vec<u8>, align: usize) {
+    let padding = (align - bytes.len() % align) % align;
+    bytes.resize(bytes.len() + padding, 0);
+}
+
+fn is_header_field(name: &str) -> bool {
+    ["major_opcode", "minor_opcode", "length"].contains(&name)
+}
+
+/// Check if a field with the given name exists, including fields of a switch.
+fn has_field(fields: &[FieldInfo], name: &str) -> bool {
+    fields.iter().any(|field| {
+        field.name == name
+            || match field.kind {
+                FieldKind::Switch { cases, .. } => {
+                    cases.iter().any(|case| has_field(case.fields, name))
+                }
+                _ => false,
+            }
+    })
+}
+
+/// Fill in the values of list length fields and switch masks that were not provided.
+fn deduce_values(fields: &'static [FieldInfo], values: &mut HashMap<&str, Value>) {
+    for field in fields {
+        match field.kind {
+            FieldKind::List {
+                length: ListLength::Field(length_field),
+                ..
+            } => {
+                let length = match values.get(field.name) {
+                    Some(Value::List(list)) => list.len(),
+                    Some(Value::Str(string)) => string.len(),
+                    _ => continue,
+                };
+                let _ = values
+                    .entry(length_field)
+                    .or_insert(Value::Int(length as i64));
+            }
+            FieldKind::Switch { mask, cases } => {
+                for case in cases {
+                    deduce_values(case.fields, values);
+                }
+                if !values.contains_key(mask) {
+                    let bits = cases
+                        .iter()
+                        .filter(|case| {
+                            case.fields
+                                .iter()
+                                .any(|field| values.contains_key(field.name))
+                        })
+                        .fold(0, |bits, case| bits | case.mask);
+                    let _ = values.insert(mask, Value::Int(bits.into()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn write_fields(
+    fields: &'static [FieldInfo],
+    values: &HashMap<&str, Value>,
+    bytes: &mut Vec<u8>,
+) -> Result<(), DynamicError> {
+    for field in fields {
+        let value = values.get(field.name);
+        match field.kind {
+            FieldKind::Pad(count) => bytes.resize(bytes.len() + count, 0),
+            FieldKind::Align(align) => pad_to(bytes, align),
+            FieldKind::Value(type_) => {
+                let value = value.ok_or(DynamicError::MissingField(field.name))?;
+                write_value(type_, value, field.name, bytes)?;
+            }
+            FieldKind::List { element, length } => {
+                let string_bytes: Vec<Value>;
+                let list = match value {
+                    Some(Value::List(list)) => list,
+                    Some(Value::Str(string)) if is_byte_type(element) => {
+                        string_bytes = string.bytes().map(Value::from).collect();
+                        &string_bytes
+                    }
+                    Some(_) => return Err(DynamicError::InvalidValue(field.name)),
+                    None => return Err(DynamicError::MissingField(field.name)),
+                };
+                let expected_length = match length {
+                    ListLength::Fixed(length) => Some(length as i64),
+                    ListLength::Field(length_field) => match values.get(length_field) {
+                        Some(Value::Int(length)) => Some(*length),
+                        _ => return Err(DynamicError::InvalidValue(length_field)),
+                    },
+                    ListLength::Remaining | ListLength::Expression => None,
+                };
+                if matches!(expected_length, Some(length) if length != list.len() as i64) {
+                    return Err(DynamicError::InvalidValue(field.name));
+                }
+                for value in list {
+                    write_value(element, value, field.name, bytes)?;
+                }
+            }
+            FieldKind::Switch { mask, cases } => {
+                let bits = match values.get(mask) {
+                    Some(Value::Int(bits)) => *bits,
+                    _ => return Err(DynamicError::InvalidValue(mask)),
+                };
+                for case in cases {
+                    if i64::from(case.mask) & bits != 0 {
+                        write_fields(case.fields, values, bytes)?;
+                    }
+                }
+            }
+            FieldKind::Unsupported => return Err(DynamicError::Unsupported(field.name)),
+        }
+    }
+    Ok(())
+}
+
+/// Check if a string can be used as the value of a list with the given element type.
+fn is_byte_type(type_: FieldType) -> bool {
+    matches!(type_, FieldType::Card8 | FieldType::Byte | FieldType::Char)
+}
+
+fn write_value(
+    type_: FieldType,
+    value: &Value,
+    name: &'static str,
+    bytes: &mut Vec<u8>,
+) -> Result<(), DynamicError> {
+    fn convert<T: TryFrom<i64>>(value: i64, name: &'static str) -> Result<T, DynamicError> {
+        T::try_from(value).map_err(|_| DynamicError::InvalidValue(name))
+    }
+
+    let value = match (type_, value) {
+        (FieldType::Float, Value::Float(value)) => {
+            bytes.extend((*value as f32).to_ne_bytes());
+            return Ok(());
+        }
+        (FieldType::Double, Value::Float(value)) => {
+            bytes.extend(value.to_ne_bytes());
+            return Ok(());
+        }
+        (FieldType::Struct { fields, .. }, Value::List(list)) => {
+            let mut values = HashMap::new();
+            let mut list = list.iter();
+            for field in fields.iter().filter(|field| !field.name.is_empty()) {
+                let value = list.next().ok_or(DynamicError::InvalidValue(name))?;
+                let _ = values.insert(field.name, value.clone());
+            }
+            if list.next().is_some() {
+                return Err(DynamicError::InvalidValue(name));
+            }
+            return write_fields(fields, &values, bytes);
+        }
+        (_, Value::Int(value)) => *value,
+        (_, _) => return Err(DynamicError::InvalidValue(name)),
+    };
+    match type_ {
+        FieldType::Card8 | FieldType::Byte | FieldType::Char => {
+            bytes.push(convert::<u8>(value, name)?)
+        }
+        FieldType::Bool => bytes.push(u8::from(convert::<bool>(value, name)?)),
+        FieldType::Int8 => bytes.extend(convert::<i8>(value, name)?.to_ne_bytes()),
+        FieldType::Card16 => bytes.extend(convert::<u16>(value, name)?.to_ne_bytes()),
+        FieldType::Int16 => bytes.extend(convert::<i16>(value, name)?.to_ne_bytes()),
+        FieldType::Card32 => bytes.extend(convert::<u32>(value, name)?.to_ne_bytes()),
+        FieldType::Int32 => bytes.extend(convert::<i32>(value, name)?.to_ne_bytes()),
+        FieldType::Card64 => bytes.extend(convert::<u64>(value, name)?.to_ne_bytes()),
+        FieldType::Int64 => bytes.extend(value.to_ne_bytes()),
+        FieldType::Float => bytes.extend((value as f32).to_ne_bytes()),
+        FieldType::Double => bytes.extend((value as f64).to_ne_bytes()),
+        FieldType::Struct { .. } => return Err(DynamicError::InvalidValue(name)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::build_request;
+    use crate::dynamic::{DynamicError, Value};
+    use crate::protocol::xproto::{ConfigureWindowRequest, InternAtomRequest, StackMode};
+    use crate::x11_utils::{parse_request_header, BigRequests};
+
+    fn fields(values: &[(&str, Value)]) -> HashMap<String, Value> {
+        values
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn build_configure_window() {
+        let fields = fields(&[
+            ("window", 0x1234.into()),
+            ("x", (-10).into()),
+            ("width", 200.into()),
+            ("stack_mode", 1.into()),
+        ]);
+        let request = build_request("ConfigureWindow", &fields).unwrap();
+        let (header, body) =
+            parse_request_header(request.bytes(), BigRequests::NotEnabled).unwrap();
+        assert_eq!(header.major_opcode, 12);
+        let parsed = ConfigureWindowRequest::try_parse_request(header, body).unwrap();
+        assert_eq!(parsed.window, 0x1234);
+        assert_eq!(parsed.value_list.x, Some(-10));
+        assert_eq!(parsed.value_list.y, None);
+        assert_eq!(parsed.value_list.width, Some(200));
+        assert_eq!(parsed.value_list.stack_mode, Some(StackMode::BELOW));
+    }
+
+    #[test]
+    fn build_intern_atom() {
+        let name = b"WM_NAME".iter().map(|&c| Value::from(c)).collect();
+        let list_fields = fields(&[("only_if_exists", true.into()), ("name", Value::List(name))]);
+        let request = build_request("InternAtom", &list_fields).unwrap();
+        assert_eq!(request.bytes().len() % 4, 0);
+        // Strings can be used for lists of characters
+        let string_fields = fields(&[("only_if_exists", true.into()), ("name", "WM_NAME".into())]);
+        assert_eq!(
+            build_request("InternAtom", &string_fields),
+            Ok(request.clone())
+        );
+        let (header, body) =
+            parse_request_header(request.bytes(), BigRequests::NotEnabled).unwrap();
+        let parsed = InternAtomRequest::try_parse_request(header, body).unwrap();
+        assert!(parsed.only_if_exists);
+        assert_eq!(&*parsed.name, b"WM_NAME");
+    }
+
+    #[test]
+    fn build_errors() {
+        let build = |name, values: &[(&str, Value)]| build_request(name, &fields(values));
+        assert_eq!(
+            build("NoSuchRequest", &[]),
+            Err(DynamicError::UnknownRequest("NoSuchRequest".to_string()))
+        );
+        assert_eq!(
+            build("MapWindow", &[("length", 2.into())]),
+            Err(DynamicError::UnknownField("length".to_string()))
+        );
+        assert_eq!(
+            build("MapWindow", &[]),
+            Err(DynamicError::MissingField("window"))
+        );
+        assert_eq!(
+            build("MapWindow", &[("window", (-1).into())]),
+            Err(DynamicError::InvalidValue("window"))
+        );
+        assert_eq!(
+            build(
+                "InternAtom",
+                &[
+                    ("only_if_exists", 0.into()),
+                    ("name_len", 1.into()),
+                    ("name", Value::List(Vec::new()))
+                ]
+            ),
+            Err(DynamicError::InvalidValue("name"))
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Runtime descriptions of requests and building requests from their names.
+//!
+//! Normally, requests are built via the generated code in [`crate::protocol`], which requires
+//! knowing the request at compile time. Tools like bindings generators for other languages or an
+//! interactive REPL instead want to look up requests by name and inspect their fields at runtime.
+//! This module provides descriptions of the wire format of all requests and their replies (see
+//! [`requests()`] and [`find_request()`]) and allows building a request from its name and a map
+//! of field values with [`build_request()`]. Such a request can be sent with [`send_request()`]
+//! and its reply is turned into a map of field values by [`parse_reply()`]. This allows writing
+//! generic tools, e.g. a command line program that sends arbitrary requests.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use x11rb::dynamic::{build_request, Value};
+//!
+//! let mut fields = HashMap::new();
+//! fields.insert("window".to_string(), Value::Int(0x1234));
+//! fields.insert("x".to_string(), Value::Int(10));
+//! fields.insert("width".to_string(), Value::Int(200));
+//! // `value_mask` is computed from the fields that are present
+//! let request = build_request("ConfigureWindow", &fields)?;
+//! assert_eq!(request.bytes().len(), 20);
+//! # Ok::<(), x11rb::dynamic::DynamicError>(())
+//! ```
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::collections::HashMap;
+//! use x11rb::dynamic::{build_request, send_request};
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let mut fields = HashMap::new();
+//! fields.insert("only_if_exists".to_string(), false.into());
+//! fields.insert("name".to_string(), "WM_PROTOCOLS".into());
+//! let request = build_request("InternAtom", &fields)?;
+//! let reply = send_request(&conn, request)?.reply()?;
+//! println!("{:?}", reply.unwrap()["atom"]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only parts of the protocol that consist of plain values can be described. Fields of other
+//! kinds are described as [`FieldKind::Unsupported`] and requests containing them cannot be
+//! built.
+
+mod build;
+mod parse;
+mod send;
+
+pub use build::{build_request, BuiltRequest};
+pub use parse::parse_reply;
+pub use send::{send_request, DynamicCookie};
+
+use crate::protocol::introspection::REQUESTS;
+
+/// Description of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestInfo {
+    /// The module in [`crate::protocol`] that the request belongs to, e.g. `"xproto"`.
+    pub module: &'static str,
+    /// The name of the extension for `QueryExtension`, or `None` for core requests.
+    pub extension: Option<&'static str>,
+    /// The name of the request, e.g. `"CreateWindow"`.
+    pub name: &'static str,
+    /// The major opcode of a core request or the minor opcode of an extension request.
+    pub opcode: u8,
+    /// The fields of the request in wire order, including the request header.
+    pub fields: &'static [FieldInfo],
+    /// The fields of the reply in wire order, or `None` if the request has no reply.
+    pub reply: Option<&'static [FieldInfo]>,
+}
+
+/// Description of a single field of a request, reply, or struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The name of the field. Padding has an empty name.
+    pub name: &'static str,
+    /// What the field contains.
+    pub kind: FieldKind,
+}
+
+/// The contents of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// The given number of padding bytes.
+    Pad(usize),
+    /// Padding until the next multiple of the given number of bytes.
+    Align(usize),
+    /// A single value.
+    Value(FieldType),
+    /// A list of values.
+    List {
+        /// The type of the elements.
+        element: FieldType,
+        /// How the length of the list is determined.
+        length: ListLength,
+    },
+    /// Optional fields whose presence is described by the bits of another field.
+    Switch {
+        /// The name of the field containing the bitmask.
+        mask: &'static str,
+        /// The possible cases.
+        cases: &'static [BitCase],
+    },
+    /// A field that cannot be described, e.g. a file descriptor.
+    Unsupported,
+}
+
+/// A case of a [`FieldKind::Switch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitCase {
+    /// The fields are present if any of these bits is set.
+    pub mask: u32,
+    /// The fields of this case.
+    pub fields: &'static [FieldInfo],
+}
+
+/// The type of a value on the wire.
+///
+/// Types like `WINDOW` or `ATOM` are described by their representation, e.g. as `Card32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    /// An unsigned 8 bit integer.
+    Card8,
+    /// An unsigned 16 bit integer.
+    Card16,
+    /// An unsigned 32 bit integer.
+    Card32,
+    /// An unsigned 64 bit integer.
+    Card64,
+    /// A signed 8 bit integer.
+    Int8,
+    /// A signed 16 bit integer.
+    Int16,
+    /// A signed 32 bit integer.
+    Int32,
+    /// A signed 64 bit integer.
+    Int64,
+    /// A byte without further meaning.
+    Byte,
+    /// A boolean, encoded as a single byte.
+    Bool,
+    /// A character, encoded as a single byte.
+    Char,
+    /// A 32 bit floating point number.
+    Float,
+    /// A 64 bit floating point number.
+    Double,
+    /// A struct of fixed size.
+    Struct {
+        /// The name of the struct.
+        name: &'static str,
+        /// The fields of the struct.
+        fields: &'static [FieldInfo],
+    },
+}
+
+/// How the length of a list is determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListLength {
+    /// The list always has the given number of elements.
+    Fixed(usize),
+    /// The number of elements is the value of the given field.
+    Field(&'static str),
+    /// The list extends until the end of the request or reply.
+    Remaining,
+    /// The length is computed from other fields in a way that cannot be described here.
+    Expression,
+}
+
+/// The value of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An integer. This is used for all fields of kind [`FieldKind::Value`], except structs and
+    /// floating point numbers.
+    Int(i64),
+    /// A floating point number for fields of type [`FieldType::Float`] or [`FieldType::Double`].
+    Float(f64),
+    /// A string. This can be used instead of a [`Value::List`] for lists of bytes or characters.
+    Str(String),
+    /// A list of values. This is used for [`FieldKind::List`] and for structs, whose fields are
+    /// listed in order without padding.
+    List(Vec<Value>),
+}
+
+macro_rules! value_from_int {
+    ($($type:ty)*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Self {
+                    Value::Int(value.into())
+                }
+            }
+        )*
+    }
+}
+
+value_from_int!(bool u8 i8 u16 i16 u32 i32 i64);
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Float(value.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Self {
+        Value::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+/// An error while building a request dynamically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicError {
+    /// There is no request with the given name.
+    UnknownRequest(String),
+    /// The request has no field with the given name.
+    UnknownField(String),
+    /// A value for the given field is needed, but was not provided.
+    MissingField(&'static str),
+    /// The value of the given field does not fit its type.
+    InvalidValue(&'static str),
+    /// The given field cannot be built dynamically.
+    Unsupported(&'static str),
+}
+
+impl std::error::Error for DynamicError {}
+
+impl std::fmt::Display for DynamicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicError::UnknownRequest(name) => write!(f, "Unknown request {}", name),
+            DynamicError::UnknownField(name) => write!(f, "Unknown field {}", name),
+            DynamicError::MissingField(name) => write!(f, "Missing value for field {}", name),
+            DynamicError::InvalidValue(name) => write!(f, "Invalid value for field {}", name),
+            DynamicError::Unsupported(name) => write!(f, "Field {} is not supported", name),
+        }
+    }
+}
+
+/// Get the descriptions of all requests that are known to this build of x11rb.
+///
+/// This only contains the requests of extensions whose feature is enabled.
+pub fn requests() -> &'static [RequestInfo] {
+    REQUESTS
+}
+
+/// Find a request by its name.
+///
+/// The name has the form `module::Request`, e.g. `composite::GetOverlayWindow`. For requests of
+/// the core protocol, the module can be left out, e.g. `CreateWindow` instead of
+/// `xproto::CreateWindow`.
+pub fn find_request(name: &str) -> Option<&'static RequestInfo> {
+    let (module, name) = match name.find("::") {
+        Some(pos) => (&name[..pos], &name[pos + 2..]),
+        None => ("xproto", name),
+    };
+    REQUESTS
+        .iter()
+        .find(|request| request.module == module && request.name == name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_request, FieldKind, FieldType};
+
+    #[test]
+    fn find_core_request() {
+        let request = find_request("CreateWindow").unwrap();
+        assert_eq!(request, find_request("xproto::CreateWindow").unwrap());
+        assert_eq!(request.extension, None);
+        assert_eq!(request.opcode, 1);
+        assert_eq!(request.reply, None);
+        assert_eq!(request.fields[0].name, "major_opcode");
+        assert_eq!(request.fields[1].name, "depth");
+        assert_eq!(request.fields[1].kind, FieldKind::Value(FieldType::Card8));
+    }
+
+    #[test]
+    fn find_unknown_request() {
+        assert_eq!(find_request("DoesNotExist"), None);
+        assert_eq!(find_request("nosuchext::CreateWindow"), None);
+        // Extension requests need their module
+        assert_eq!(find_request("QueryVersion"), None);
+    }
+}
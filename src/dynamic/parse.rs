@@ -0,0 +1,236 @@
+//! Parsing replies into a map of field values.
+
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+
+use super::{FieldInfo, FieldKind, FieldType, ListLength, RequestInfo, Value};
+use crate::errors::ParseError;
+
+/// Parse a reply to the given request into a map of its fields.
+///
+/// Padding and the fields `response_type`, `sequence`, and `length` are not included. Lists of
+/// characters become a [`Value::Str`] if they are valid UTF-8. Parsing stops at the first field
+/// that cannot be described (see [`FieldKind::Unsupported`] and [`ListLength::Expression`]), so
+/// the result only contains the fields before it.
+///
+/// Returns `None` if the request does not have a reply.
+pub fn parse_reply(
+    info: &RequestInfo,
+    reply: &[u8],
+) -> Result<Option<HashMap<String, Value>>, ParseError> {
+    let fields = match info.reply {
+        Some(fields) => fields,
+        None => return Ok(None),
+    };
+    let mut values = HashMap::new();
+    let mut remaining = reply;
+    let _ = parse_fields(fields, reply.len(), &mut remaining, &mut values)?;
+    for name in ["response_type", "sequence", "length"] {
+        let _ = values.remove(name);
+    }
+    Ok(Some(values))
+}
+
+/// Parse fields and add them to `values`.
+///
+/// `total` is the length of the whole reply, which is needed for alignment. Returns `false` if
+/// a field was reached that cannot be parsed.
+fn parse_fields(
+    fields: &[FieldInfo],
+    total: usize,
+    remaining: &mut &[u8],
+    values: &mut HashMap<String, Value>,
+) -> Result<bool, ParseError> {
+    for field in fields {
+        match field.kind {
+            FieldKind::Pad(count) => skip(remaining, count)?,
+            FieldKind::Align(align) => {
+                let offset = total - remaining.len();
+                skip(remaining, (align - offset % align) % align)?;
+            }
+            FieldKind::Value(type_) => {
+                let value = parse_value(type_, total, remaining)?;
+                let _ = values.insert(field.name.to_string(), value);
+            }
+            FieldKind::List { element, length } => {
+                let length = match length {
+                    ListLength::Fixed(length) => length,
+                    ListLength::Field(length_field) => match values.get(length_field) {
+                        Some(Value::Int(length)) => {
+                            usize::try_from(*length).or(Err(ParseError::ConversionFailed))?
+                        }
+                        _ => return Err(ParseError::InvalidExpression),
+                    },
+                    ListLength::Remaining => match type_size(element) {
+                        Some(size) => remaining.len() / size,
+                        None => return Ok(false),
+                    },
+                    ListLength::Expression => return Ok(false),
+                };
+                let list = (0..length)
+                    .map(|_| parse_value(element, total, remaining))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let value = if element == FieldType::Char {
+                    string_from_list(list)
+                } else {
+                    Value::List(list)
+                };
+                let _ = values.insert(field.name.to_string(), value);
+            }
+            FieldKind::Switch { mask, cases } => {
+                let bits = match values.get(mask) {
+                    Some(Value::Int(bits)) => *bits,
+                    _ => return Err(ParseError::InvalidExpression),
+                };
+                for case in cases {
+                    if i64::from(case.mask) & bits != 0
+                        && !parse_fields(case.fields, total, remaining, values)?
+                    {
+                        return Ok(false);
+                    }
+                }
+            }
+            FieldKind::Unsupported => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+fn skip(remaining: &mut &[u8], count: usize) -> Result<(), ParseError> {
+    let _ = take(remaining, count)?;
+    Ok(())
+}
+
+fn take<'a>(remaining: &mut &'a [u8], count: usize) -> Result<&'a [u8], ParseError> {
+    if remaining.len() < count {
+        return Err(ParseError::InsufficientData);
+    }
+    let (value, rest) = remaining.split_at(count);
+    *remaining = rest;
+    Ok(value)
+}
+
+/// Get the size of a value of the given type on the wire.
+fn type_size(type_: FieldType) -> Option<usize> {
+    Some(match type_ {
+        FieldType::Card8
+        | FieldType::Int8
+        | FieldType::Byte
+        | FieldType::Bool
+        | FieldType::Char => 1,
+        FieldType::Card16 | FieldType::Int16 => 2,
+        FieldType::Card32 | FieldType::Int32 | FieldType::Float => 4,
+        FieldType::Card64 | FieldType::Int64 | FieldType::Double => 8,
+        FieldType::Struct { fields, .. } => fields.iter().try_fold(0, |size, field| {
+            Some(
+                size + match field.kind {
+                    FieldKind::Pad(count) => count,
+                    FieldKind::Value(type_) => type_size(type_)?,
+                    _ => return None,
+                },
+            )
+        })?,
+    })
+}
+
+fn parse_value(type_: FieldType, total: usize, remaining: &mut &[u8]) -> Result<Value, ParseError> {
+    macro_rules! parse_int {
+        ($type:ty) => {{
+            let bytes = take(remaining, std::mem::size_of::<$type>())?;
+            <$type>::from_ne_bytes(bytes.try_into().unwrap())
+        }};
+    }
+
+    Ok(match type_ {
+        FieldType::Card8 | FieldType::Byte | FieldType::Char => Value::from(parse_int!(u8)),
+        FieldType::Bool => Value::from(parse_int!(u8) != 0),
+        FieldType::Int8 => Value::from(parse_int!(i8)),
+        FieldType::Card16 => Value::from(parse_int!(u16)),
+        FieldType::Int16 => Value::from(parse_int!(i16)),
+        FieldType::Card32 => Value::from(parse_int!(u32)),
+        FieldType::Int32 => Value::from(parse_int!(i32)),
+        FieldType::Card64 => {
+            Value::Int(i64::try_from(parse_int!(u64)).or(Err(ParseError::ConversionFailed))?)
+        }
+        FieldType::Int64 => Value::from(parse_int!(i64)),
+        FieldType::Float => Value::from(parse_int!(f32)),
+        FieldType::Double => Value::from(parse_int!(f64)),
+        FieldType::Struct { fields, .. } => {
+            let mut values = HashMap::new();
+            if !parse_fields(fields, total, remaining, &mut values)? {
+                return Err(ParseError::InvalidValue);
+            }
+            let list = fields
+                .iter()
+                .filter(|field| !field.name.is_empty())
+                .map(|field| values.remove(field.name).unwrap())
+                .collect();
+            Value::List(list)
+        }
+    })
+}
+
+/// Turn a list of characters into a string, if it is valid UTF-8.
+fn string_from_list(list: Vec<Value>) -> Value {
+    let bytes = list
+        .iter()
+        .map(|value| match value {
+            Value::Int(value) => *value as u8,
+            _ => unreachable!(),
+        })
+        .collect();
+    match String::from_utf8(bytes) {
+        Ok(string) => Value::Str(string),
+        Err(_) => Value::List(list),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_reply;
+    use crate::dynamic::{find_request, Value};
+
+    #[test]
+    fn parse_get_geometry() {
+        let mut reply = vec![1, 24];
+        reply.extend(7u16.to_ne_bytes());
+        reply.extend(0u32.to_ne_bytes());
+        reply.extend(0x123u32.to_ne_bytes());
+        reply.extend((-5i16).to_ne_bytes());
+        reply.extend(10i16.to_ne_bytes());
+        reply.extend(640u16.to_ne_bytes());
+        reply.extend(480u16.to_ne_bytes());
+        reply.extend(1u16.to_ne_bytes());
+        reply.resize(32, 0);
+
+        let info = find_request("GetGeometry").unwrap();
+        let values = parse_reply(info, &reply).unwrap().unwrap();
+        assert_eq!(values.len(), 7);
+        assert_eq!(values["depth"], Value::Int(24));
+        assert_eq!(values["root"], Value::Int(0x123));
+        assert_eq!(values["x"], Value::Int(-5));
+        assert_eq!(values["width"], Value::Int(640));
+        assert_eq!(values["border_width"], Value::Int(1));
+    }
+
+    #[test]
+    fn parse_get_atom_name() {
+        let mut reply = vec![1, 0];
+        reply.extend(1u16.to_ne_bytes());
+        reply.extend(2u32.to_ne_bytes());
+        reply.extend(7u16.to_ne_bytes());
+        reply.resize(32, 0);
+        reply.extend(b"WM_NAME\0");
+
+        let info = find_request("GetAtomName").unwrap();
+        let values = parse_reply(info, &reply).unwrap().unwrap();
+        assert_eq!(values["name_len"], Value::Int(7));
+        assert_eq!(values["name"], Value::Str("WM_NAME".to_string()));
+    }
+
+    #[test]
+    fn parse_without_reply() {
+        let info = find_request("MapWindow").unwrap();
+        assert_eq!(parse_reply(info, &[]), Ok(None));
+    }
+}
@@ -0,0 +1,128 @@
+//! Sending dynamically built requests.
+
+use std::collections::HashMap;
+use std::io::IoSlice;
+
+use super::{parse_reply, BuiltRequest, RequestInfo, Value};
+use crate::connection::RequestConnection;
+use crate::cookie::{Cookie, VoidCookie};
+use crate::dyn_connection::RawReply;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::utils::OwnedFd;
+
+/// A cookie for a request that was sent with [`send_request`].
+#[derive(Debug)]
+pub struct DynamicCookie<'a, C: RequestConnection + ?Sized> {
+    info: &'static RequestInfo,
+    cookie: CookieKind<'a, C>,
+}
+
+#[derive(Debug)]
+enum CookieKind<'a, C: RequestConnection + ?Sized> {
+    Void(VoidCookie<'a, C>),
+    Reply(Cookie<'a, C, RawReply>),
+}
+
+impl<C: RequestConnection + ?Sized> DynamicCookie<'_, C> {
+    /// Get the description of the request.
+    pub fn info(&self) -> &'static RequestInfo {
+        self.info
+    }
+
+    /// Get the sequence number of the request.
+    pub fn sequence_number(&self) -> u64 {
+        match self.cookie {
+            CookieKind::Void(ref cookie) => cookie.sequence_number(),
+            CookieKind::Reply(ref cookie) => cookie.sequence_number(),
+        }
+    }
+
+    /// Wait for the response to the request.
+    ///
+    /// For requests with a reply, the reply is parsed with [`parse_reply`]. For requests without
+    /// a reply, this checks for an error and returns `None`.
+    pub fn reply(self) -> Result<Option<HashMap<String, Value>>, ReplyError> {
+        match self.cookie {
+            CookieKind::Void(cookie) => {
+                cookie.check()?;
+                Ok(None)
+            }
+            CookieKind::Reply(cookie) => {
+                let reply = cookie.raw_reply()?;
+                Ok(parse_reply(self.info, reply.as_ref())?)
+            }
+        }
+    }
+}
+
+/// Send a request that was built with [`build_request`](super::build_request).
+///
+/// For extension requests, the major opcode is looked up on the connection. If the X11 server
+/// does not support the extension, [`ConnectionError::UnsupportedExtension`] is returned.
+pub fn send_request<C: RequestConnection + ?Sized>(
+    conn: &C,
+    mut request: BuiltRequest,
+) -> Result<DynamicCookie<'_, C>, ConnectionError> {
+    let info = request.info();
+    if let Some(extension) = info.extension {
+        let extension = conn
+            .extension_information(extension)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        request.set_major_opcode(extension.major_opcode);
+    }
+    let bufs = [IoSlice::new(request.bytes())];
+    let fds: Vec<OwnedFd> = Vec::new();
+    let cookie = if info.reply.is_some() {
+        CookieKind::Reply(conn.send_request_with_reply(&bufs, fds)?)
+    } else {
+        CookieKind::Void(conn.send_request_without_reply(&bufs, fds)?)
+    };
+    Ok(DynamicCookie { info, cookie })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::send_request;
+    use crate::dynamic::{build_request, Value};
+    use crate::protocol::xproto;
+    use crate::testing::{FakeConnection, Response};
+
+    #[test]
+    fn send_request_with_reply() {
+        // GetInputFocus reply with revert_to = Parent and focus = 0x42
+        let mut reply = vec![1, 2, 0, 0, 0, 0, 0, 0];
+        reply.extend(0x42u32.to_ne_bytes());
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::GET_INPUT_FOCUS_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+        let request = build_request("GetInputFocus", &HashMap::new()).unwrap();
+        let values = send_request(&conn, request)
+            .unwrap()
+            .reply()
+            .unwrap()
+            .unwrap();
+        assert_eq!(values["revert_to"], Value::Int(2));
+        assert_eq!(values["focus"], Value::Int(0x42));
+        let length = 1u16.to_ne_bytes();
+        assert_eq!(
+            conn.sent_requests()[0].bytes,
+            [xproto::GET_INPUT_FOCUS_REQUEST, 0, length[0], length[1]]
+        );
+    }
+
+    #[test]
+    fn send_request_without_reply() {
+        let conn = FakeConnection::default();
+        let mut fields = HashMap::new();
+        let _ = fields.insert("window".to_string(), Value::Int(1));
+        let request = build_request("MapWindow", &fields).unwrap();
+        let cookie = send_request(&conn, request).unwrap();
+        assert_eq!(cookie.sequence_number(), 1);
+        assert!(cookie.reply().unwrap().is_none());
+    }
+}
@@ -0,0 +1,270 @@
+//! Human-readable descriptions of X11 errors.
+//!
+//! Xlib describes errors with `XGetErrorText()`, e.g. as `BadWindow (invalid Window parameter)`.
+//! The functions in this module produce the same texts, so that messages for users look like the
+//! ones they know from other X11 programs:
+//!
+//! ```
+//! use x11rb::error_text::describe_error;
+//! use x11rb::x11_utils::X11Error;
+//!
+//! fn report(error: &X11Error) {
+//!     // E.g. "BadWindow (invalid Window parameter) in ConfigureWindow"
+//!     eprintln!("{}", describe_error(error));
+//! }
+//! ```
+//!
+//! The texts are taken from Xlib's error database for the core protocol and for the extensions
+//! that x11rb supports.
+
+use std::borrow::Cow;
+
+use crate::protocol::{get_request_name, ErrorKind};
+use crate::x11_utils::{ExtInfoProvider, X11Error};
+
+/// Get the text that Xlib uses for an error kind.
+///
+/// `None` is returned for [`ErrorKind::Unknown`].
+pub fn error_kind_text(error_kind: ErrorKind) -> Option<&'static str> {
+    let text = match error_kind {
+        ErrorKind::Unknown(_) => return None,
+        ErrorKind::Request => "BadRequest (invalid request code or no such operation)",
+        ErrorKind::Value => "BadValue (integer parameter out of range for operation)",
+        ErrorKind::Window => "BadWindow (invalid Window parameter)",
+        ErrorKind::Pixmap => "BadPixmap (invalid Pixmap parameter)",
+        ErrorKind::Atom => "BadAtom (invalid Atom parameter)",
+        ErrorKind::Cursor => "BadCursor (invalid Cursor parameter)",
+        ErrorKind::Font => "BadFont (invalid Font parameter)",
+        ErrorKind::Match => "BadMatch (invalid parameter attributes)",
+        ErrorKind::Drawable => "BadDrawable (invalid Pixmap or Window parameter)",
+        ErrorKind::Access => "BadAccess (attempt to access private resource denied)",
+        ErrorKind::Alloc => "BadAlloc (insufficient resources for operation)",
+        ErrorKind::Colormap => "BadColor (invalid Colormap parameter)",
+        ErrorKind::GContext => "BadGC (invalid GC parameter)",
+        ErrorKind::IDChoice => "BadIDChoice (invalid resource ID chosen for this connection)",
+        ErrorKind::Name => "BadName (named color or font does not exist)",
+        ErrorKind::Length => "BadLength (poly request too large or internal Xlib length error)",
+        ErrorKind::Implementation => "BadImplementation (server does not implement operation)",
+        #[cfg(feature = "damage")]
+        ErrorKind::DamageBadDamage => "BadDamage (invalid Damage parameter)",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadContext => "GLXBadContext",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadContextState => "GLXBadContextState",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadDrawable => "GLXBadDrawable",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadPixmap => "GLXBadPixmap",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadContextTag => "GLXBadContextTag",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadCurrentWindow => "GLXBadCurrentWindow",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadRenderRequest => "GLXBadRenderRequest",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadLargeRequest => "GLXBadLargeRequest",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxUnsupportedPrivateRequest => "GLXUnsupportedPrivateRequest",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadFBConfig => "GLXBadFBConfig",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadPbuffer => "GLXBadPbuffer",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadCurrentDrawable => "GLXBadCurrentDrawable",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxBadWindow => "GLXBadWindow",
+        #[cfg(feature = "glx")]
+        ErrorKind::GlxGLXBadProfileARB => "GLXBadProfileARB",
+        #[cfg(feature = "randr")]
+        ErrorKind::RandrBadOutput => "BadRROutput (invalid Output parameter)",
+        #[cfg(feature = "randr")]
+        ErrorKind::RandrBadCrtc => "BadRRCrtc (invalid Crtc parameter)",
+        #[cfg(feature = "randr")]
+        ErrorKind::RandrBadMode => "BadRRMode (invalid Mode parameter)",
+        #[cfg(feature = "randr")]
+        ErrorKind::RandrBadProvider => "BadRRProvider (invalid Provider parameter)",
+        #[cfg(feature = "record")]
+        ErrorKind::RecordBadContext => "XRecordBadContext",
+        #[cfg(feature = "render")]
+        ErrorKind::RenderPictFormat => "RenderBadFormat (invalid PictFormat parameter)",
+        #[cfg(feature = "render")]
+        ErrorKind::RenderPicture => "RenderBadPicture (invalid Picture parameter)",
+        #[cfg(feature = "render")]
+        ErrorKind::RenderPictOp => "RenderBadPictOp (invalid PictOp parameter)",
+        #[cfg(feature = "render")]
+        ErrorKind::RenderGlyphSet => "RenderBadGlyphSet (invalid GlyphSet parameter)",
+        #[cfg(feature = "render")]
+        ErrorKind::RenderGlyph => "RenderBadGlyph (invalid Glyph parameter)",
+        #[cfg(feature = "shm")]
+        ErrorKind::ShmBadSeg => "BadShmSeg (invalid shared segment parameter)",
+        #[cfg(feature = "sync")]
+        ErrorKind::SyncCounter => "XSyncBadCounter",
+        #[cfg(feature = "sync")]
+        ErrorKind::SyncAlarm => "XSyncBadAlarm",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeBadClock => "XF86VidModeBadClock",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeBadHTimings => "XF86VidModeBadHTimings",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeBadVTimings => "XF86VidModeBadVTimings",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeModeUnsuitable => "XF86VidModeModeUnsuitable",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeExtensionDisabled => "XF86VidModeExtensionDisabled",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeClientNotLocal => "XF86VidModeClientNotLocal",
+        #[cfg(feature = "xf86vidmode")]
+        ErrorKind::Xf86vidmodeZoomLocked => "XF86VidModeZoomLocked",
+        #[cfg(feature = "xfixes")]
+        ErrorKind::XfixesBadRegion => "BadRegion (invalid Region parameter)",
+        #[cfg(feature = "xinput")]
+        ErrorKind::XinputDevice => "XI_BadDevice (invalid Device parameter)",
+        #[cfg(feature = "xinput")]
+        ErrorKind::XinputEvent => "XI_BadEvent (invalid Event parameter)",
+        #[cfg(feature = "xinput")]
+        ErrorKind::XinputMode => "XI_BadMode (invalid Mode parameter)",
+        #[cfg(feature = "xinput")]
+        ErrorKind::XinputDeviceBusy => "XI_DeviceBusy",
+        #[cfg(feature = "xinput")]
+        ErrorKind::XinputClass => "XI_BadClass (invalid Class parameter)",
+        #[cfg(feature = "xkb")]
+        ErrorKind::XkbKeyboard => "XkbBadKeyboard",
+        #[cfg(feature = "xprint")]
+        ErrorKind::XprintBadContext => "XPBadContext (Print Context invalid or missing)",
+        #[cfg(feature = "xprint")]
+        ErrorKind::XprintBadSequence => "XPBadSequence (Illegal sequence of XP operations)",
+        #[cfg(feature = "xv")]
+        ErrorKind::XvBadPort => "XvBadPort",
+        #[cfg(feature = "xv")]
+        ErrorKind::XvBadEncoding => "XvBadEncoding",
+        #[cfg(feature = "xv")]
+        ErrorKind::XvBadControl => "XvBadControl",
+    };
+    Some(text)
+}
+
+/// Get the text for the error with the given error code, like `XGetErrorText()`.
+///
+/// Errors of extensions are looked up via the `ext_info_provider`, which is usually the
+/// connection. Like with Xlib, the text of an unknown error is just its error code.
+pub fn error_text(error_code: u8, ext_info_provider: &dyn ExtInfoProvider) -> Cow<'static, str> {
+    let error_kind = ErrorKind::from_wire_error_code(error_code, ext_info_provider);
+    match error_kind_text(error_kind) {
+        Some(text) => Cow::Borrowed(text),
+        None => Cow::Owned(error_code.to_string()),
+    }
+}
+
+/// Get the name of the request with the given opcodes for messages to users.
+///
+/// Requests of the core protocol are named without a prefix, e.g. `"ConfigureWindow"`. Since
+/// many extensions have requests with the same name, requests of extensions keep the name of the
+/// extension's module, e.g. `"render::CreatePicture"`.
+pub fn request_name(
+    major_opcode: u8,
+    minor_opcode: u16,
+    ext_info_provider: &dyn ExtInfoProvider,
+) -> Option<&'static str> {
+    get_request_name(ext_info_provider, major_opcode, minor_opcode).map(strip_core_prefix)
+}
+
+fn strip_core_prefix(request_name: &'static str) -> &'static str {
+    request_name
+        .strip_prefix("xproto::")
+        .unwrap_or(request_name)
+}
+
+/// Describe an error for users, e.g. as `BadWindow (invalid Window parameter) in ConfigureWindow`.
+pub fn describe_error(error: &X11Error) -> String {
+    let text = match error_kind_text(error.error_kind) {
+        Some(text) => Cow::Borrowed(text),
+        None => Cow::Owned(error.error_code.to_string()),
+    };
+    match error.request_name {
+        Some(name) => format!("{} in {}", text, strip_core_prefix(name)),
+        None => format!(
+            "{} in request with major opcode {} and minor opcode {}",
+            text, error.major_opcode, error.minor_opcode
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{describe_error, error_text, request_name};
+    use crate::protocol::{xproto, ErrorKind};
+    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, X11Error};
+
+    // Pretends that RENDER is the only extension
+    struct Render;
+
+    const RENDER: ExtensionInformation = ExtensionInformation {
+        major_opcode: 139,
+        first_event: 0,
+        first_error: 142,
+    };
+
+    impl ExtInfoProvider for Render {
+        fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+            Some(("RENDER", RENDER)).filter(|_| major_opcode == RENDER.major_opcode)
+        }
+
+        fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+
+        fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+            Some(("RENDER", RENDER)).filter(|_| error_code >= RENDER.first_error)
+        }
+    }
+
+    #[test]
+    fn core_errors() {
+        assert_eq!(
+            error_text(3, &Render),
+            "BadWindow (invalid Window parameter)"
+        );
+        assert_eq!(
+            error_text(17, &Render),
+            "BadImplementation (server does not implement operation)"
+        );
+        assert_eq!(error_text(100, &Render), "100");
+        assert_eq!(
+            request_name(xproto::CONFIGURE_WINDOW_REQUEST, 0, &Render),
+            Some("ConfigureWindow")
+        );
+        assert_eq!(request_name(200, 0, &Render), None);
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn extension_errors() {
+        assert_eq!(
+            error_text(143, &Render),
+            "RenderBadPicture (invalid Picture parameter)"
+        );
+        assert_eq!(request_name(139, 4, &Render), Some("render::CreatePicture"));
+    }
+
+    #[test]
+    fn describe() {
+        let mut error = X11Error {
+            error_kind: ErrorKind::Window,
+            error_code: xproto::WINDOW_ERROR,
+            sequence: 1,
+            bad_value: 42,
+            minor_opcode: 0,
+            major_opcode: xproto::CONFIGURE_WINDOW_REQUEST,
+            request_name: Some("xproto::ConfigureWindow"),
+        };
+        assert_eq!(
+            describe_error(&error),
+            "BadWindow (invalid Window parameter) in ConfigureWindow"
+        );
+        error.request_name = None;
+        assert_eq!(
+            describe_error(&error),
+            "BadWindow (invalid Window parameter) in request with major opcode 12 and minor opcode 0"
+        );
+    }
+}
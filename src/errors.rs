@@ -1,5 +1,7 @@
 //! This module contains the current mess that is error handling.
 
+use std::path::PathBuf;
+
 use crate::protocol::xproto::{SetupAuthenticate, SetupFailed};
 use crate::x11_utils::X11Error;
 
@@ -83,6 +85,11 @@ pub enum ParseError {
 
     /// Some file descriptors were expected, but not enough were received.
     MissingFileDescriptors,
+
+    /// A list is longer than allowed.
+    ///
+    /// See [`crate::x11_utils::with_max_list_length`] for details.
+    TooLarge,
 }
 
 impl std::error::Error for ParseError {}
@@ -102,6 +109,7 @@ impl std::fmt::Display for ParseError {
                 write!(f, "A value could not be parsed into an enumeration")
             }
             ParseError::MissingFileDescriptors => write!(f, "Missing file descriptors"),
+            ParseError::TooLarge => write!(f, "A list is longer than the allowed maximum"),
         }
     }
 }
@@ -148,6 +156,13 @@ pub enum ConnectError {
 
     /// The server rejected the connection with a `SetupFailed` message.
     SetupFailed(SetupFailed),
+
+    /// The server rejected the connection, see [`SetupRejected`] for details.
+    ///
+    /// This is used instead of [`ConnectError::SetupFailed`] and
+    /// [`ConnectError::SetupAuthenticate`] when the library looked up the authorization itself,
+    /// e.g. by [`crate::rust_connection::RustConnection::connect`].
+    SetupRejected(SetupRejected),
 }
 
 impl std::error::Error for ConnectError {}
@@ -176,6 +191,7 @@ impl std::fmt::Display for ConnectError {
             ConnectError::SetupAuthenticate(err) => {
                 display(f, "X11 authentication failed", &err.reason)
             }
+            ConnectError::SetupRejected(err) => err.fmt(f),
         }
     }
 }
@@ -192,6 +208,160 @@ impl From<std::io::Error> for ConnectError {
     }
 }
 
+/// A guess why the X11 server rejected a connection, based on the reason that it sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetupFailureKind {
+    /// The server requires authorization, but none was sent.
+    ///
+    /// The X.Org server sends "No protocol specified" in this case.
+    MissingAuthorization,
+    /// The authorization was rejected, e.g. "Invalid MIT-MAGIC-COOKIE-1 key".
+    InvalidAuthorization,
+    /// The server does not support the authorization protocol that was used.
+    UnsupportedAuthorization,
+    /// The server does not accept more clients.
+    TooManyClients,
+    /// The server does not support the version of the X11 protocol that was requested.
+    ProtocolVersionMismatch,
+    /// The reason is not known.
+    Other,
+}
+
+impl SetupFailureKind {
+    /// Classify the reason that the X11 server sent in `SetupFailed` or `SetupAuthenticate`.
+    pub fn from_reason(reason: &[u8]) -> Self {
+        let reason = String::from_utf8_lossy(reason).to_ascii_lowercase();
+        if reason.starts_with("no protocol specified")
+            || reason.contains("no authorization protocol")
+        {
+            SetupFailureKind::MissingAuthorization
+        } else if reason.contains("protocol not supported") {
+            SetupFailureKind::UnsupportedAuthorization
+        } else if (reason.contains("invalid") && reason.contains("key"))
+            || reason.contains("authorization")
+        {
+            SetupFailureKind::InvalidAuthorization
+        } else if reason.contains("maximum number of clients") {
+            SetupFailureKind::TooManyClients
+        } else if reason.contains("protocol version mismatch") {
+            SetupFailureKind::ProtocolVersionMismatch
+        } else {
+            SetupFailureKind::Other
+        }
+    }
+}
+
+/// Where the authorization for a connection came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuthorizationSource {
+    /// The authorization was given explicitly.
+    Explicit,
+    /// The location of the `.Xauthority` file is not known, because neither `XAUTHORITY` nor
+    /// `HOME` is set.
+    NoFile,
+    /// An `.Xauthority` file was searched for an entry.
+    File {
+        /// The path of the file.
+        path: PathBuf,
+        /// The entry that was used, in the format of `xauth list` without the secret data, e.g.
+        /// `myhost/unix:0  MIT-MAGIC-COOKIE-1`. `None` if no entry matched.
+        entry: Option<String>,
+    },
+    /// The `.Xauthority` file could not be read.
+    UnreadableFile {
+        /// The path of the file.
+        path: PathBuf,
+        /// The error that occurred while reading.
+        error: String,
+    },
+}
+
+/// The X11 server rejected a connection.
+///
+/// Besides what the server sent, this contains what authorization was tried, so that users get
+/// a hint about what went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupRejected {
+    /// Whether the server sent `SetupAuthenticate` instead of `SetupFailed`.
+    ///
+    /// `SetupAuthenticate` asks for further authentication, which is not supported.
+    pub authenticate: bool,
+    /// The reason that the server sent.
+    pub reason: Vec<u8>,
+    /// The name of the authorization protocol that was sent; empty if none was sent.
+    pub auth_name: Vec<u8>,
+    /// Where the authorization came from.
+    pub auth_source: AuthorizationSource,
+}
+
+impl SetupRejected {
+    /// Guess why the connection was rejected.
+    pub fn kind(&self) -> SetupFailureKind {
+        SetupFailureKind::from_reason(&self.reason)
+    }
+
+    /// Get a hint for fixing the problem, if there is one.
+    pub fn hint(&self) -> Option<String> {
+        let no_auth_hint = match &self.auth_source {
+            AuthorizationSource::Explicit => "No authorization was given".to_string(),
+            AuthorizationSource::NoFile => {
+                "Neither XAUTHORITY nor HOME is set, so no .Xauthority file was found".to_string()
+            }
+            AuthorizationSource::File { path, .. } => format!(
+                "{} contains no entry for this display; check XAUTHORITY and `xauth list`",
+                path.display()
+            ),
+            AuthorizationSource::UnreadableFile { path, error } => {
+                format!("{} could not be read: {}", path.display(), error)
+            }
+        };
+        let hint = match self.kind() {
+            SetupFailureKind::MissingAuthorization if self.auth_name.is_empty() => no_auth_hint,
+            SetupFailureKind::MissingAuthorization | SetupFailureKind::InvalidAuthorization => {
+                match &self.auth_source {
+                    AuthorizationSource::File {
+                        path,
+                        entry: Some(entry),
+                    } => format!(
+                        "The entry '{}' in {} was rejected; it might be outdated, e.g. because \
+                         the X11 server was restarted",
+                        entry,
+                        path.display()
+                    ),
+                    _ => "The authorization was rejected".to_string(),
+                }
+            }
+            SetupFailureKind::UnsupportedAuthorization => format!(
+                "The X11 server does not support {}",
+                String::from_utf8_lossy(&self.auth_name)
+            ),
+            SetupFailureKind::TooManyClients => {
+                "Too many clients are connected to the X11 server".to_string()
+            }
+            _ => return None,
+        };
+        Some(hint)
+    }
+}
+
+impl std::fmt::Display for SetupRejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let prefix = if self.authenticate {
+            "X11 authentication failed"
+        } else {
+            "X11 setup failed"
+        };
+        let reason = String::from_utf8_lossy(&self.reason);
+        write!(f, "{}: '{}'", prefix, reason.trim_end())?;
+        if let Some(hint) = self.hint() {
+            write!(f, " ({})", hint)?;
+        }
+        Ok(())
+    }
+}
+
 /// An error that occurred on an already established X11 connection
 #[derive(Debug)]
 #[non_exhaustive]
@@ -228,6 +398,12 @@ pub enum ConnectionError {
 
     /// An I/O error occurred on the connection.
     IOError(std::io::Error),
+
+    /// A request was refused by a connection wrapper before it was sent.
+    ///
+    /// This is used by [`crate::restricted_connection::Restrictions`] and other layers from
+    /// [`crate::middleware`].
+    RequestDenied,
 }
 
 impl std::error::Error for ConnectionError {}
@@ -242,6 +418,7 @@ impl std::fmt::Display for ConnectionError {
                 write!(f, "Maximum request length exceeded")
             }
             ConnectionError::FDPassingFailed => write!(f, "FD passing failed"),
+            ConnectionError::RequestDenied => write!(f, "Request denied"),
             ConnectionError::ParseError(err) => err.fmt(f),
             ConnectionError::IOError(err) => err.fmt(f),
         }
@@ -275,7 +452,7 @@ impl std::fmt::Display for ReplyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ReplyError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyError::X11Error(e) => write!(f, "X11 error: {}", e),
         }
     }
 }
@@ -320,7 +497,7 @@ impl std::fmt::Display for ReplyOrIdError {
         match self {
             ReplyOrIdError::IdsExhausted => f.write_str("X11 IDs have been exhausted"),
             ReplyOrIdError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyOrIdError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyOrIdError::X11Error(e) => write!(f, "X11 error: {}", e),
         }
     }
 }
@@ -353,3 +530,88 @@ impl From<ReplyError> for ReplyOrIdError {
         }
     }
 }
+
+/// A buffer is too small to hold a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// The number of bytes that the request needs.
+    pub required: usize,
+}
+
+impl std::error::Error for BufferTooSmall {}
+
+impl std::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The buffer is too small for the request, which needs {} bytes",
+            self.required
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{AuthorizationSource, SetupFailureKind, SetupRejected};
+
+    #[test]
+    fn classify_reasons() {
+        for (reason, kind) in &[
+            (
+                &b"No protocol specified\n"[..],
+                SetupFailureKind::MissingAuthorization,
+            ),
+            (
+                b"Invalid MIT-MAGIC-COOKIE-1 key",
+                SetupFailureKind::InvalidAuthorization,
+            ),
+            (
+                b"Protocol not supported by server\n",
+                SetupFailureKind::UnsupportedAuthorization,
+            ),
+            (
+                b"Maximum number of clients reached",
+                SetupFailureKind::TooManyClients,
+            ),
+            (b"\xff\xfe", SetupFailureKind::Other),
+        ] {
+            assert_eq!(SetupFailureKind::from_reason(reason), *kind);
+        }
+    }
+
+    #[test]
+    fn rejection_hints() {
+        let mut rejected = SetupRejected {
+            authenticate: false,
+            reason: b"No protocol specified\n".to_vec(),
+            auth_name: Vec::new(),
+            auth_source: AuthorizationSource::File {
+                path: PathBuf::from("/tmp/xauth"),
+                entry: None,
+            },
+        };
+        assert_eq!(
+            rejected.to_string(),
+            "X11 setup failed: 'No protocol specified' (/tmp/xauth contains no entry for this \
+             display; check XAUTHORITY and `xauth list`)"
+        );
+
+        rejected.reason = b"Invalid MIT-MAGIC-COOKIE-1 key".to_vec();
+        rejected.auth_name = b"MIT-MAGIC-COOKIE-1".to_vec();
+        rejected.auth_source = AuthorizationSource::File {
+            path: PathBuf::from("/tmp/xauth"),
+            entry: Some("host/unix:0  MIT-MAGIC-COOKIE-1".to_string()),
+        };
+        assert_eq!(
+            rejected.hint().unwrap(),
+            "The entry 'host/unix:0  MIT-MAGIC-COOKIE-1' in /tmp/xauth was rejected; it might be \
+             outdated, e.g. because the X11 server was restarted"
+        );
+
+        rejected.reason = b"something else".to_vec();
+        assert_eq!(rejected.hint(), None);
+        assert_eq!(rejected.to_string(), "X11 setup failed: 'something else'");
+    }
+}
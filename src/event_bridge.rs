@@ -0,0 +1,353 @@
+//! Forwarding events from one connection to another.
+//!
+//! An XEmbed embedder forwards key events to the embedded client, an input proxy forwards the
+//! input of its window to the application behind it, and a nested server's frontend forwards
+//! events into the nested server. In all cases, the events have to be rewritten before they are
+//! sent again: they have to name the windows of the receiving side, and their timestamps should
+//! be in the time of the receiving X11 server.
+//!
+//! [`EventBridge`] does this. It maps windows of the source connection to windows of the target
+//! connection and sends rewritten events with `SendEvent`:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::event_bridge::EventBridge;
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn proxy(
+//!     source: &impl Connection,
+//!     target: &impl Connection,
+//!     (source_window, target_window, target_root): (Window, Window, Window),
+//! ) -> Result<(), ConnectionError> {
+//!     let mut bridge = EventBridge::new(target_root);
+//!     bridge.map_window(source_window, target_window);
+//!     bridge.select_input(source, source_window)?;
+//!     source.flush()?;
+//!     loop {
+//!         let event = source.wait_for_event()?;
+//!         if bridge.forward(target, &event)? {
+//!             target.flush()?;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Sent events have the "sent" flag set, so the receiver can tell them apart from real input.
+//! Some toolkits ignore such events. Input that has to look real has to be faked with the XTEST
+//! extension instead, see e.g. `x11rb::testing::forward`.
+//!
+//! Atoms are not rewritten, so events that contain atoms, e.g. `PropertyNotify`, are not
+//! forwarded. Coordinates relative to the event window are kept, so the target window should have
+//! the same size as the source window. Root coordinates are kept as well.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::{self, ChangeWindowAttributesAux, EventMask, Timestamp, Window};
+use crate::protocol::Event;
+use crate::send_event::{self, Delivery};
+use crate::server_time::ServerClock;
+use crate::{CURRENT_TIME, NONE};
+
+/// How the timestamps of forwarded events are rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timestamps {
+    /// Keep the timestamps, e.g. if both connections are to the same X11 server.
+    Keep,
+    /// Replace all timestamps with `CurrentTime`.
+    Current,
+    /// Add the given number of milliseconds to the timestamps.
+    ///
+    /// `CurrentTime` is kept.
+    Offset(i32),
+}
+
+impl Timestamps {
+    /// Get the offset between the times of two X11 servers.
+    ///
+    /// Returns `None` if one of the clocks was not calibrated yet.
+    pub fn between(source: &ServerClock, target: &ServerClock) -> Option<Self> {
+        let now = Instant::now();
+        let offset = target
+            .to_timestamp(now)?
+            .wrapping_sub(source.to_timestamp(now)?);
+        Some(Timestamps::Offset(offset as i32))
+    }
+
+    /// Rewrite a timestamp.
+    pub fn apply(self, time: Timestamp) -> Timestamp {
+        match self {
+            Timestamps::Keep => time,
+            Timestamps::Current => CURRENT_TIME,
+            Timestamps::Offset(_) if time == CURRENT_TIME => CURRENT_TIME,
+            Timestamps::Offset(offset) => time.wrapping_add(offset as u32),
+        }
+    }
+}
+
+/// The events that [`EventBridge`] forwards by default.
+fn default_events() -> EventMask {
+    EventMask::KEY_PRESS
+        | EventMask::KEY_RELEASE
+        | EventMask::BUTTON_PRESS
+        | EventMask::BUTTON_RELEASE
+        | EventMask::POINTER_MOTION
+        | EventMask::ENTER_WINDOW
+        | EventMask::LEAVE_WINDOW
+        | EventMask::FOCUS_CHANGE
+}
+
+/// Forwards events of some windows on one connection to windows on another connection.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct EventBridge {
+    windows: HashMap<Window, Window>,
+    target_root: Window,
+    events: EventMask,
+    delivery: Delivery,
+    timestamps: Timestamps,
+}
+
+impl EventBridge {
+    /// Create a bridge that does not forward anything until windows are mapped.
+    ///
+    /// `target_root` replaces the root window in forwarded events. By default, key, button,
+    /// motion, crossing and focus events are forwarded to the client that created the target
+    /// window, and timestamps are kept.
+    pub fn new(target_root: Window) -> Self {
+        Self {
+            windows: HashMap::new(),
+            target_root,
+            events: default_events(),
+            delivery: Delivery::Owner,
+            timestamps: Timestamps::Keep,
+        }
+    }
+
+    /// Only forward the events in the given mask.
+    ///
+    /// Key, button, motion, crossing, focus and exposure events are supported.
+    pub fn events(mut self, events: EventMask) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Set which clients receive the forwarded events.
+    pub fn delivery(mut self, delivery: Delivery) -> Self {
+        self.delivery = delivery;
+        self
+    }
+
+    /// Set how timestamps are rewritten.
+    pub fn timestamps(mut self, timestamps: Timestamps) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Change how timestamps are rewritten, e.g. after the server clocks were calibrated again.
+    pub fn set_timestamps(&mut self, timestamps: Timestamps) {
+        self.timestamps = timestamps;
+    }
+
+    /// Forward events of `source` to `target`.
+    ///
+    /// Returns the window that events of `source` were forwarded to before, if any.
+    pub fn map_window(&mut self, source: Window, target: Window) -> Option<Window> {
+        self.windows.insert(source, target)
+    }
+
+    /// Stop forwarding events of `source`.
+    pub fn unmap_window(&mut self, source: Window) -> Option<Window> {
+        self.windows.remove(&source)
+    }
+
+    /// Get the window that events of `source` are forwarded to.
+    pub fn target_of(&self, source: Window) -> Option<Window> {
+        self.windows.get(&source).copied()
+    }
+
+    /// Select the forwarded events on a window of the source connection.
+    ///
+    /// This replaces the events that the source connection selected on the window before. Only
+    /// one client can select button presses on a window, so this fails with an `Access` error if
+    /// someone else already did.
+    pub fn select_input<'c, C: RequestConnection + ?Sized>(
+        &self,
+        source: &'c C,
+        window: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let aux = ChangeWindowAttributesAux::new().event_mask(self.events);
+        xproto::change_window_attributes(source, window, &aux)
+    }
+
+    /// Rewrite an event of the source connection for the target connection.
+    ///
+    /// Returns the target window and the rewritten event, or `None` if the event is not
+    /// forwarded. Other windows in the event, e.g. the child window, are mapped as well. If they
+    /// are not known, they are replaced with `None`.
+    pub fn translate(&self, event: &Event) -> Option<(Window, [u8; 32])> {
+        macro_rules! input_event {
+            ($event:expr, $mask:expr) => {{
+                let mut event = *$event;
+                let target = self.target(event.event, $mask)?;
+                event.event = target;
+                event.root = self.target_root;
+                event.child = self.map_or_none(event.child);
+                event.time = self.timestamps.apply(event.time);
+                Some((target, event.into()))
+            }};
+        }
+        match event {
+            Event::KeyPress(e) => input_event!(e, EventMask::KEY_PRESS),
+            Event::KeyRelease(e) => input_event!(e, EventMask::KEY_RELEASE),
+            Event::ButtonPress(e) => input_event!(e, EventMask::BUTTON_PRESS),
+            Event::ButtonRelease(e) => input_event!(e, EventMask::BUTTON_RELEASE),
+            Event::MotionNotify(e) => input_event!(e, EventMask::POINTER_MOTION),
+            Event::EnterNotify(e) => input_event!(e, EventMask::ENTER_WINDOW),
+            Event::LeaveNotify(e) => input_event!(e, EventMask::LEAVE_WINDOW),
+            Event::FocusIn(e) | Event::FocusOut(e) => {
+                let mut event = *e;
+                event.event = self.target(event.event, EventMask::FOCUS_CHANGE)?;
+                Some((event.event, event.into()))
+            }
+            Event::Expose(e) => {
+                let mut event = *e;
+                event.window = self.target(event.window, EventMask::EXPOSURE)?;
+                Some((event.window, event.into()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Forward an event of the source connection to the target connection.
+    ///
+    /// Returns whether the event was forwarded. The caller has to flush the target connection.
+    pub fn forward<C: RequestConnection + ?Sized>(
+        &self,
+        target: &C,
+        event: &Event,
+    ) -> Result<bool, ConnectionError> {
+        match self.translate(event) {
+            Some((window, event)) => {
+                let _ = send_event::send(target, window, self.delivery, event)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Get the target window for an event of the given kind on `source`.
+    fn target(&self, source: Window, mask: EventMask) -> Option<Window> {
+        if u32::from(self.events) & u32::from(mask) == 0 {
+            return None;
+        }
+        self.target_of(source)
+    }
+
+    fn map_or_none(&self, window: Window) -> Window {
+        self.target_of(window).unwrap_or(NONE)
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{EventBridge, Timestamps};
+    use crate::protocol::xproto::{
+        self, ButtonPressEvent, EventMask, FocusInEvent, NotifyDetail, NotifyMode,
+    };
+    use crate::protocol::Event;
+    use crate::testing::FakeConnection;
+    use crate::CURRENT_TIME;
+
+    fn button_press(window: u32, child: u32) -> Event {
+        Event::ButtonPress(ButtonPressEvent {
+            response_type: xproto::BUTTON_PRESS_EVENT,
+            detail: 1,
+            sequence: 17,
+            time: 1000,
+            root: 1,
+            event: window,
+            child,
+            root_x: 50,
+            root_y: 60,
+            event_x: 5,
+            event_y: 6,
+            state: Default::default(),
+            same_screen: true,
+        })
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_ne_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(Timestamps::Keep.apply(5), 5);
+        assert_eq!(Timestamps::Current.apply(5), CURRENT_TIME);
+        assert_eq!(Timestamps::Offset(-10).apply(5), u32::MAX - 4);
+        assert_eq!(Timestamps::Offset(10).apply(CURRENT_TIME), CURRENT_TIME);
+    }
+
+    #[test]
+    fn events_are_rewritten() {
+        let mut bridge = EventBridge::new(0x200).timestamps(Timestamps::Offset(500));
+        assert_eq!(bridge.map_window(10, 0x300), None);
+        let _ = bridge.map_window(11, 0x301);
+
+        let (target, event) = bridge.translate(&button_press(10, 11)).unwrap();
+        assert_eq!(target, 0x300);
+        assert_eq!(event[0], xproto::BUTTON_PRESS_EVENT);
+        assert_eq!(u32_at(&event, 4), 1500);
+        assert_eq!(u32_at(&event, 8), 0x200);
+        assert_eq!(u32_at(&event, 12), 0x300);
+        assert_eq!(u32_at(&event, 16), 0x301);
+
+        // Unknown child windows are removed, unknown event windows are not forwarded
+        let (_, event) = bridge.translate(&button_press(11, 12)).unwrap();
+        assert_eq!(u32_at(&event, 16), 0);
+        assert_eq!(bridge.translate(&button_press(12, 0)), None);
+
+        // Focus events are forwarded, but not selected ones
+        let focus = Event::FocusIn(FocusInEvent {
+            response_type: xproto::FOCUS_IN_EVENT,
+            detail: NotifyDetail::NONLINEAR,
+            sequence: 0,
+            event: 10,
+            mode: NotifyMode::NORMAL,
+        });
+        assert_eq!(bridge.translate(&focus).unwrap().0, 0x300);
+        let bridge = bridge.events(EventMask::BUTTON_PRESS);
+        assert_eq!(bridge.translate(&focus), None);
+    }
+
+    #[test]
+    fn forward_with_send_event() {
+        let conn = FakeConnection::default();
+        let mut bridge = EventBridge::new(0x200);
+        let _ = bridge.map_window(10, 0x300);
+        assert!(bridge.forward(&conn, &button_press(10, 0)).unwrap());
+        assert_eq!(bridge.unmap_window(10), Some(0x300));
+        assert!(!bridge.forward(&conn, &button_press(10, 0)).unwrap());
+
+        let requests = conn.sent_requests();
+        assert_eq!(requests.len(), 1);
+        let bytes = &requests[0].bytes;
+        assert_eq!(bytes[0], xproto::SEND_EVENT_REQUEST);
+        // Delivered to the owner of the target window, with the sequence number removed
+        assert_eq!(u32_at(bytes, 4), 0x300);
+        assert_eq!(u32_at(bytes, 8), 0);
+        assert_eq!(bytes[12], xproto::BUTTON_PRESS_EVENT);
+        assert_eq!(bytes[14..16], [0, 0]);
+    }
+}
@@ -80,3 +80,17 @@
 //! a dedicated event loop for fetching events from the X11 server. All other threads can now
 //! freely use the X11 connection without events possibly getting stuck and only being processed
 //! later.
+//!
+//!
+//! ## Wakers
+//!
+//! Reactors that are built around [`std::task::Waker`], e.g. the main loop of a game engine or a
+//! custom executor, can use
+//! [`RustConnection::poll_for_reply_with_waker`](../rust_connection/struct.RustConnection.html#method.poll_for_reply_with_waker)
+//! and
+//! [`RustConnection::poll_for_event_with_waker`](../rust_connection/struct.RustConnection.html#method.poll_for_event_with_waker).
+//! These never block. If nothing is available yet, they register the waker, which is woken once
+//! the reply or an event was read from the connection. When the stream becomes readable, the
+//! reactor calls
+//! [`RustConnection::read_available_packets`](../rust_connection/struct.RustConnection.html#method.read_available_packets),
+//! which reads without blocking and wakes the interested wakers.
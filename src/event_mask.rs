@@ -0,0 +1,205 @@
+//! Read-modify-write helpers for window event masks.
+//!
+//! `ChangeWindowAttributes` replaces the whole event mask of a window. Code that only wants to
+//! add `PropertyChange` to a window thus easily removes bits that some other part of the
+//! program selected. [`EventMaskCache`] remembers the event mask of each window and only changes
+//! the requested bits. Windows that are not in the cache are queried with
+//! `GetWindowAttributes` first.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::event_mask::EventMaskCache;
+//! use x11rb::protocol::xproto::{EventMask, Window};
+//!
+//! fn watch_title(
+//!     conn: &impl Connection,
+//!     cache: &mut EventMaskCache,
+//!     window: Window,
+//! ) -> Result<(), ReplyError> {
+//!     // Other bits that are already selected on `window` are kept
+//!     cache.add_to_event_mask(conn, window, EventMask::PROPERTY_CHANGE)?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::connection::RequestConnection;
+use crate::errors::ReplyError;
+use crate::protocol::xproto::{self, ChangeWindowAttributesAux, EventMask, Window};
+
+/// A cache of the event masks that this client selected on windows.
+///
+/// All changes to event masks must go through the same cache for it to stay correct. When a
+/// window is destroyed, [`forget()`](Self::forget) should be called to keep the cache small.
+#[derive(Debug, Default, Clone)]
+pub struct EventMaskCache {
+    masks: HashMap<Window, u32>,
+}
+
+impl EventMaskCache {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the event mask of the given window.
+    ///
+    /// If the window is not in the cache, its event mask is queried from the X11 server.
+    pub fn event_mask<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+    ) -> Result<EventMask, ReplyError> {
+        Ok(self.lookup(conn, window)?.into())
+    }
+
+    /// Add the given bits to the event mask of the window.
+    ///
+    /// No request is sent if all bits are already selected.
+    pub fn add_to_event_mask<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+        bits: EventMask,
+    ) -> Result<(), ReplyError> {
+        let old = self.lookup(conn, window)?;
+        self.set(conn, window, old, old | u32::from(bits))
+    }
+
+    /// Remove the given bits from the event mask of the window.
+    ///
+    /// No request is sent if none of the bits are selected.
+    pub fn remove_from_event_mask<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+        bits: EventMask,
+    ) -> Result<(), ReplyError> {
+        let old = self.lookup(conn, window)?;
+        self.set(conn, window, old, old & !u32::from(bits))
+    }
+
+    /// Remove a window from the cache.
+    ///
+    /// This should be called when the window was destroyed. It is also needed when the event
+    /// mask of the window was changed without this cache, so that it is queried again.
+    pub fn forget(&mut self, window: Window) {
+        let _ = self.masks.remove(&window);
+    }
+
+    fn lookup<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+    ) -> Result<u32, ReplyError> {
+        if let Some(mask) = self.masks.get(&window) {
+            return Ok(*mask);
+        }
+        let mask = xproto::get_window_attributes(conn, window)?
+            .reply()?
+            .your_event_mask;
+        let _ = self.masks.insert(window, mask);
+        Ok(mask)
+    }
+
+    fn set<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        window: Window,
+        old: u32,
+        new: u32,
+    ) -> Result<(), ReplyError> {
+        if old != new {
+            let aux = ChangeWindowAttributesAux::new().event_mask(new);
+            let _ = xproto::change_window_attributes(conn, window, &aux)?;
+            let _ = self.masks.insert(window, new);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryInto;
+
+    use super::EventMaskCache;
+    use crate::protocol::xproto::{self, EventMask};
+    use crate::testing::{FakeConnection, Response};
+
+    const WINDOW: u32 = 42;
+
+    /// Answer the next `GetWindowAttributes` request with the given event mask.
+    fn respond_with_mask(conn: &FakeConnection, mask: EventMask) {
+        let mut reply = vec![0; 44];
+        reply[36..40].copy_from_slice(&u32::from(mask).to_ne_bytes());
+        conn.respond(
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+    }
+
+    /// Get the event masks that were set via `ChangeWindowAttributes` and forget all requests.
+    fn take_changed_masks(conn: &FakeConnection) -> Vec<u32> {
+        conn.take_sent_requests()
+            .iter()
+            .filter(|request| request.major_opcode() == xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST)
+            .map(|request| {
+                assert_eq!(request.bytes[4..8], WINDOW.to_ne_bytes());
+                u32::from_ne_bytes(request.bytes[12..16].try_into().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keep_other_bits() {
+        let conn = FakeConnection::default();
+        let mut cache = EventMaskCache::new();
+        respond_with_mask(&conn, EventMask::STRUCTURE_NOTIFY);
+
+        cache
+            .add_to_event_mask(&conn, WINDOW, EventMask::PROPERTY_CHANGE)
+            .unwrap();
+        let both = EventMask::STRUCTURE_NOTIFY | EventMask::PROPERTY_CHANGE;
+        assert_eq!(take_changed_masks(&conn), [u32::from(both)]);
+
+        // The mask is cached and nothing changes, so no requests are sent
+        cache
+            .add_to_event_mask(&conn, WINDOW, EventMask::PROPERTY_CHANGE)
+            .unwrap();
+        assert!(conn.sent_requests().is_empty());
+
+        cache
+            .remove_from_event_mask(&conn, WINDOW, EventMask::STRUCTURE_NOTIFY)
+            .unwrap();
+        assert_eq!(
+            take_changed_masks(&conn),
+            [u32::from(EventMask::PROPERTY_CHANGE)]
+        );
+        cache
+            .remove_from_event_mask(&conn, WINDOW, EventMask::STRUCTURE_NOTIFY)
+            .unwrap();
+        assert!(conn.sent_requests().is_empty());
+    }
+
+    #[test]
+    fn forget_queries_again() {
+        let conn = FakeConnection::default();
+        let mut cache = EventMaskCache::new();
+        respond_with_mask(&conn, EventMask::EXPOSURE);
+        assert_eq!(
+            cache.event_mask(&conn, WINDOW).unwrap(),
+            EventMask::EXPOSURE
+        );
+
+        cache.forget(WINDOW);
+        respond_with_mask(&conn, EventMask::KEY_PRESS);
+        assert_eq!(
+            cache.event_mask(&conn, WINDOW).unwrap(),
+            EventMask::KEY_PRESS
+        );
+        assert_eq!(conn.sent_requests().len(), 2);
+    }
+}
@@ -0,0 +1,264 @@
+//! Looking ahead at events without consuming them.
+//!
+//! Dispatchers sometimes want to know which events follow the current one, e.g. to skip a
+//! `MotionNotify` event if another one is already waiting, or to tell a key repeat from a real
+//! key release. The connection only hands out events one after another. [`EventQueue`] moves the
+//! events that the connection already received into its own queue, where they can be inspected
+//! before they are handed out in their original order:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::event_queue::EventQueue;
+//! use x11rb::protocol::Event;
+//!
+//! fn event_loop(conn: &impl Connection) -> Result<(), ConnectionError> {
+//!     let mut queue = EventQueue::new();
+//!     loop {
+//!         let event = queue.wait_for_event(conn)?;
+//!         if let Event::MotionNotify(_) = event {
+//!             if let Some(Event::MotionNotify(_)) = queue.peek(conn)? {
+//!                 // Only handle the latest position
+//!                 continue;
+//!             }
+//!         }
+//!         // Handle the event
+//!     }
+//! }
+//! ```
+//!
+//! A handler that took an event which turns out not to be meant for it, e.g. a drag and drop
+//! handler in a chain of dispatchers, can put it back with [`EventQueue::push_back_event`]. The
+//! next consumer then gets it first, like with `XPutBackEvent` of Xlib.
+//!
+//! All events have to be taken from the queue once it is used. Events that are taken from the
+//! connection directly would overtake the events in the queue.
+
+use std::collections::VecDeque;
+
+use crate::connection::{Connection, EventAndSeqNumber, SequenceNumber};
+use crate::errors::ConnectionError;
+use crate::protocol::Event;
+
+/// A queue of events that can be inspected before they are handled.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Default)]
+pub struct EventQueue {
+    events: VecDeque<EventAndSeqNumber>,
+    last_sequence: SequenceNumber,
+}
+
+impl EventQueue {
+    /// Create an empty queue.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Move all events that the connection received so far into the queue.
+    ///
+    /// This does not block. Returns the number of events in the queue afterwards.
+    pub fn fetch<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<usize, ConnectionError> {
+        while let Some(event) = conn.poll_for_event_with_sequence()? {
+            self.events.push_back(event);
+        }
+        Ok(self.events.len())
+    }
+
+    /// Get the next event without removing it from the queue.
+    ///
+    /// This does not block. Returns `None` if no event was received yet.
+    pub fn peek<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Option<&Event>, ConnectionError> {
+        if self.events.is_empty() {
+            let _ = self.fetch(conn)?;
+        }
+        Ok(self.events.front().map(|(event, _)| event))
+    }
+
+    /// Look at the pending events in order until `f` returns `true`.
+    ///
+    /// All events that the connection received so far are moved into the queue first. Returns
+    /// whether `f` returned `true` for any of them. No event is removed from the queue.
+    pub fn peek_events<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        f: impl FnMut(&Event) -> bool,
+    ) -> Result<bool, ConnectionError> {
+        let _ = self.fetch(conn)?;
+        Ok(self.iter().any(f))
+    }
+
+    /// Iterate over the events in the queue, starting with the next one.
+    ///
+    /// Events that the connection received, but that were not fetched yet, are not included.
+    pub fn iter(&self) -> impl Iterator<Item = &Event> + '_ {
+        self.events.iter().map(|(event, _)| event)
+    }
+
+    /// Get the number of events in the queue.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Put an event back to the front of the queue, so that it is the next event to be taken.
+    ///
+    /// The event gets the sequence number of the event that was taken last, since usually that
+    /// event is put back.
+    pub fn push_back_event(&mut self, event: Event) {
+        self.push_back_event_with_sequence((event, self.last_sequence));
+    }
+
+    /// Put an event with the given sequence number back to the front of the queue.
+    pub fn push_back_event_with_sequence(&mut self, event: EventAndSeqNumber) {
+        self.events.push_front(event);
+    }
+
+    /// Take the next event from the queue or, if the queue is empty, from the connection.
+    ///
+    /// This does not block.
+    pub fn poll_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Option<Event>, ConnectionError> {
+        Ok(self
+            .poll_for_event_with_sequence(conn)?
+            .map(|(event, _)| event))
+    }
+
+    /// Take the next event together with its sequence number without blocking.
+    pub fn poll_for_event_with_sequence<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Option<EventAndSeqNumber>, ConnectionError> {
+        let event = match self.events.pop_front() {
+            Some(event) => Some(event),
+            None => conn.poll_for_event_with_sequence()?,
+        };
+        if let Some((_, sequence)) = event {
+            self.last_sequence = sequence;
+        }
+        Ok(event)
+    }
+
+    /// Take the next event from the queue or wait for one from the connection.
+    pub fn wait_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Event, ConnectionError> {
+        Ok(self.wait_for_event_with_sequence(conn)?.0)
+    }
+
+    /// Take the next event together with its sequence number, waiting for it if necessary.
+    pub fn wait_for_event_with_sequence<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<EventAndSeqNumber, ConnectionError> {
+        let event = match self.events.pop_front() {
+            Some(event) => event,
+            None => conn.wait_for_event_with_sequence()?,
+        };
+        self.last_sequence = event.1;
+        Ok(event)
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::EventQueue;
+    use crate::protocol::xproto::{self, MotionNotifyEvent};
+    use crate::protocol::Event;
+    use crate::testing::FakeConnection;
+
+    fn motion(x: i16) -> MotionNotifyEvent {
+        MotionNotifyEvent {
+            response_type: xproto::MOTION_NOTIFY_EVENT,
+            detail: xproto::Motion::NORMAL,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: 2,
+            child: 0,
+            root_x: x,
+            root_y: 0,
+            event_x: x,
+            event_y: 0,
+            state: Default::default(),
+            same_screen: true,
+        }
+    }
+
+    fn x(event: Event) -> i16 {
+        match event {
+            Event::MotionNotify(event) => event.event_x,
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn peeking_keeps_order() {
+        let conn = FakeConnection::default();
+        let mut queue = EventQueue::new();
+        assert!(queue.peek(&conn).unwrap().is_none());
+
+        for i in 1..=3 {
+            conn.push_event(motion(i));
+        }
+        assert!(matches!(
+            queue.peek(&conn).unwrap(),
+            Some(Event::MotionNotify(_))
+        ));
+        assert_eq!(queue.len(), 3);
+        let mut seen = Vec::new();
+        let found = queue
+            .peek_events(&conn, |event| {
+                seen.push(x(event.clone()));
+                seen.len() == 2
+            })
+            .unwrap();
+        assert!(found);
+        assert_eq!(seen, [1, 2]);
+
+        // Events that arrive later are handed out after the queued ones
+        conn.push_event(motion(4));
+        assert_eq!(x(queue.wait_for_event(&conn).unwrap()), 1);
+        assert_eq!(queue.fetch(&conn).unwrap(), 3);
+        let rest = std::iter::from_fn(|| queue.poll_for_event(&conn).unwrap())
+            .map(x)
+            .collect::<Vec<_>>();
+        assert_eq!(rest, [2, 3, 4]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn push_back() {
+        let conn = FakeConnection::default();
+        let mut queue = EventQueue::new();
+        conn.push_event(motion(1));
+        conn.push_event(motion(2));
+
+        let (event, sequence) = queue.wait_for_event_with_sequence(&conn).unwrap();
+        queue.push_back_event(event);
+        assert_eq!(queue.len(), 1);
+        let (event, again) = queue.wait_for_event_with_sequence(&conn).unwrap();
+        assert_eq!((x(event), again), (1, sequence));
+        assert_eq!(x(queue.wait_for_event(&conn).unwrap()), 2);
+
+        queue.push_back_event_with_sequence((Event::MotionNotify(motion(3)), 42));
+        assert_eq!(
+            queue
+                .poll_for_event_with_sequence(&conn)
+                .unwrap()
+                .unwrap()
+                .1,
+            42
+        );
+    }
+}
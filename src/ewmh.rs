@@ -0,0 +1,299 @@
+//! One-line requests to an EWMH window manager.
+//!
+//! The functions in this module send the messages from the [`wm`](crate::wm) module with the
+//! values that applications should use: They identify the sender as a normal application and pass
+//! on the timestamp of the user interaction that caused the request, so that the window manager's
+//! focus stealing prevention works as intended. Pagers and taskbars, or applications that need
+//! more control, can use the message types from the [`wm`](crate::wm) module directly.
+//!
+//! Not every window manager supports every message, and there might be no window manager at all.
+//! The functions take the window manager that [`detect_wm`](crate::wm::detect_wm) found and only
+//! send a message if the window manager lists it in `_NET_SUPPORTED`. Otherwise, they fall back
+//! to core protocol requests with a similar effect, if there are any. The returned
+//! [`RequestPath`] tells which way was taken:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::ewmh::{self, RequestPath};
+//! use x11rb::protocol::xproto::ButtonPressEvent;
+//! use x11rb::wm::{DetectedWm, MoveResizeDirection, WmAtoms};
+//!
+//! /// Handle a click on the title bar of a window with client-side decorations.
+//! fn title_bar_clicked(
+//!     conn: &impl Connection,
+//!     atoms: &WmAtoms,
+//!     wm: Option<&DetectedWm>,
+//!     press: &ButtonPressEvent,
+//! ) -> Result<(), ReplyError> {
+//!     let direction = MoveResizeDirection::Move;
+//!     if ewmh::request_moveresize(conn, atoms, wm, press.event, direction, press)?
+//!         == RequestPath::Unsupported
+//!     {
+//!         // Follow the pointer and move the window without the window manager's help
+//!     }
+//!     conn.flush()?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+use crate::properties::RequestSource;
+use crate::protocol::xproto::{
+    self, Atom, ButtonPressEvent, ConfigureWindowAux, InputFocus, StackMode, Timestamp, Window,
+};
+use crate::send_event::send_protocol_message;
+use crate::wm::{
+    DetectedWm, MoveResizeDirection, NetActiveWindow, NetCloseWindow, NetCurrentDesktop,
+    NetMoveResizeWindow, NetWmMoveResize, WmAtoms,
+};
+
+/// How a request to the window manager was carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestPath {
+    /// The window manager supports the EWMH message, so it was sent.
+    Ewmh,
+    /// The EWMH message is not supported, so core protocol requests were sent instead.
+    Core,
+    /// The EWMH message is not supported and there is no fallback, so nothing was sent.
+    Unsupported,
+}
+
+/// Check whether the window manager lists the given message in `_NET_SUPPORTED`.
+fn supports(wm: Option<&DetectedWm>, message: Atom) -> bool {
+    matches!(wm, Some(wm) if wm.wm_supports(message))
+}
+
+/// Ask the window manager to activate a window.
+///
+/// `timestamp` is the time of the user interaction that caused the request, e.g. of a click on a
+/// notification. The window manager might only mark the window as demanding attention if the
+/// timestamp is missing or old.
+///
+/// Without `_NET_ACTIVE_WINDOW`, the window is mapped, raised and focused directly.
+pub fn request_activate<C: RequestConnection + ?Sized>(
+    conn: &C,
+    atoms: &WmAtoms,
+    wm: Option<&DetectedWm>,
+    root: Window,
+    window: Window,
+    timestamp: Timestamp,
+) -> Result<RequestPath, ConnectionError> {
+    if supports(wm, atoms.net_active_window()) {
+        let _ = NetActiveWindow::new(window)
+            .timestamp(timestamp)
+            .send(conn, atoms, root)?;
+        return Ok(RequestPath::Ewmh);
+    }
+    let _ = xproto::map_window(conn, window)?;
+    let aux = ConfigureWindowAux::new().stack_mode(StackMode::ABOVE);
+    let _ = xproto::configure_window(conn, window, &aux)?;
+    let _ = xproto::set_input_focus(conn, InputFocus::PARENT, window, timestamp)?;
+    Ok(RequestPath::Core)
+}
+
+/// Ask the window manager to close a window like the user clicked its close button.
+///
+/// Without `_NET_CLOSE_WINDOW`, `WM_DELETE_WINDOW` is sent to the window directly. Applications
+/// that do not list this protocol in `WM_PROTOCOLS` ignore it.
+pub fn request_close<C: RequestConnection + ?Sized>(
+    conn: &C,
+    atoms: &WmAtoms,
+    wm: Option<&DetectedWm>,
+    root: Window,
+    window: Window,
+    timestamp: Timestamp,
+) -> Result<RequestPath, ConnectionError> {
+    if supports(wm, atoms.net_close_window()) {
+        let _ = NetCloseWindow::new(window)
+            .timestamp(timestamp)
+            .source(RequestSource::Application)
+            .send(conn, atoms, root)?;
+        return Ok(RequestPath::Ewmh);
+    }
+    let _ = send_protocol_message(
+        conn,
+        window,
+        atoms.wm_protocols(),
+        atoms.wm_delete_window(),
+        timestamp,
+        [0; 3],
+    )?;
+    Ok(RequestPath::Core)
+}
+
+/// Let the window manager move or resize a window with the pointer.
+///
+/// `press` is the button press that starts the action. Its implicit pointer grab is released first,
+/// so that the window manager can grab the pointer. The root window, the pointer position and the
+/// button are taken from the event.
+///
+/// Without `_NET_WM_MOVERESIZE`, nothing is sent and the pointer grab is kept, so that the caller
+/// can follow the pointer itself.
+pub fn request_moveresize<C: RequestConnection + ?Sized>(
+    conn: &C,
+    atoms: &WmAtoms,
+    wm: Option<&DetectedWm>,
+    window: Window,
+    direction: MoveResizeDirection,
+    press: &ButtonPressEvent,
+) -> Result<RequestPath, ConnectionError> {
+    if !supports(wm, atoms.net_wm_moveresize()) {
+        return Ok(RequestPath::Unsupported);
+    }
+    let _ = xproto::ungrab_pointer(conn, press.time)?;
+    let position = (i32::from(press.root_x), i32::from(press.root_y));
+    let _ = NetWmMoveResize::new(window, direction, position, press.detail.into())
+        .send(conn, atoms, press.root)?;
+    Ok(RequestPath::Ewmh)
+}
+
+/// Ask the window manager to move and resize a window.
+///
+/// The position is the position of the window's frame, as given by the `win_gravity` of
+/// `WM_NORMAL_HINTS`. Without `_NET_MOVERESIZE_WINDOW`, a `ConfigureWindow` request is sent
+/// instead. A window manager that does not support the message might interpret its position
+/// differently, e.g. as the position of the window inside of the frame.
+pub fn request_geometry<C: RequestConnection + ?Sized>(
+    conn: &C,
+    atoms: &WmAtoms,
+    wm: Option<&DetectedWm>,
+    root: Window,
+    window: Window,
+    (x, y): (i32, i32),
+    (width, height): (u32, u32),
+) -> Result<RequestPath, ConnectionError> {
+    if supports(wm, atoms.net_moveresize_window()) {
+        let _ = NetMoveResizeWindow::new(window)
+            .position(x, y)
+            .size(width, height)
+            .send(conn, atoms, root)?;
+        return Ok(RequestPath::Ewmh);
+    }
+    let aux = ConfigureWindowAux::new()
+        .x(x)
+        .y(y)
+        .width(width)
+        .height(height);
+    let _ = xproto::configure_window(conn, window, &aux)?;
+    Ok(RequestPath::Core)
+}
+
+/// Ask the window manager to switch to another desktop.
+///
+/// `desktop` is the index of the desktop, starting at zero. Without `_NET_CURRENT_DESKTOP`,
+/// there are no desktops to switch between, so nothing is sent.
+pub fn request_desktop_change<C: RequestConnection + ?Sized>(
+    conn: &C,
+    atoms: &WmAtoms,
+    wm: Option<&DetectedWm>,
+    root: Window,
+    desktop: u32,
+    timestamp: Timestamp,
+) -> Result<RequestPath, ConnectionError> {
+    if !supports(wm, atoms.net_current_desktop()) {
+        return Ok(RequestPath::Unsupported);
+    }
+    let _ = NetCurrentDesktop::new(desktop)
+        .timestamp(timestamp)
+        .send(conn, atoms, root)?;
+    Ok(RequestPath::Ewmh)
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{request_activate, request_desktop_change, RequestPath};
+    use crate::protocol::xproto;
+    use crate::testing::{FakeConnection, Response};
+    use crate::wm::{detect_wm, DetectedWm, WmAtoms};
+
+    fn atoms(conn: &FakeConnection) -> WmAtoms {
+        for atom in 1..=15u32 {
+            let mut reply = vec![0; 32];
+            reply[8..12].copy_from_slice(&atom.to_ne_bytes());
+            conn.respond(xproto::INTERN_ATOM_REQUEST, None, Response::Reply(reply));
+        }
+        WmAtoms::new(conn).unwrap()
+    }
+
+    fn property_reply(format: u8, values: &[u32]) -> Response {
+        let mut reply = vec![0; 32];
+        reply[1] = format;
+        reply[16..20].copy_from_slice(&(values.len() as u32).to_ne_bytes());
+        for value in values {
+            reply.extend(value.to_ne_bytes());
+        }
+        Response::Reply(reply)
+    }
+
+    fn wm(conn: &FakeConnection, supported: &[u32]) -> DetectedWm {
+        for _ in 0..3 {
+            conn.respond(
+                xproto::INTERN_ATOM_REQUEST,
+                None,
+                Response::Reply(vec![0; 32]),
+            );
+        }
+        for reply in [
+            property_reply(32, &[0x100]),
+            property_reply(32, &[0x100]),
+            property_reply(0, &[]),
+            property_reply(32, supported),
+        ] {
+            conn.respond(xproto::GET_PROPERTY_REQUEST, None, reply);
+        }
+        detect_wm(conn, 0).unwrap().unwrap()
+    }
+
+    fn opcodes(conn: &FakeConnection) -> Vec<u8> {
+        conn.take_sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect()
+    }
+
+    #[test]
+    fn activate_uses_supported_message() {
+        let conn = FakeConnection::default();
+        let atoms = atoms(&conn);
+        let wm = wm(&conn, &[atoms.net_active_window()]);
+        let _ = conn.take_sent_requests();
+
+        let path = request_activate(&conn, &atoms, Some(&wm), 1, 2, 3).unwrap();
+        assert_eq!(path, RequestPath::Ewmh);
+        assert_eq!(opcodes(&conn), [xproto::SEND_EVENT_REQUEST]);
+    }
+
+    #[test]
+    fn activate_falls_back_to_core_requests() {
+        let conn = FakeConnection::default();
+        let atoms = atoms(&conn);
+        let wm = wm(&conn, &[atoms.net_close_window()]);
+        let _ = conn.take_sent_requests();
+
+        for wm in &[None, Some(&wm)] {
+            let path = request_activate(&conn, &atoms, *wm, 1, 2, 3).unwrap();
+            assert_eq!(path, RequestPath::Core);
+            assert_eq!(
+                opcodes(&conn),
+                [
+                    xproto::MAP_WINDOW_REQUEST,
+                    xproto::CONFIGURE_WINDOW_REQUEST,
+                    xproto::SET_INPUT_FOCUS_REQUEST,
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported_desktop_change_sends_nothing() {
+        let conn = FakeConnection::default();
+        let atoms = atoms(&conn);
+        let _ = conn.take_sent_requests();
+
+        let path = request_desktop_change(&conn, &atoms, None, 1, 2, 3).unwrap();
+        assert_eq!(path, RequestPath::Unsupported);
+        assert!(conn.sent_requests().is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+//! Coalescing `Expose` events into one redraw per window.
+//!
+//! When parts of a window become visible, the X11 server sends a series of `Expose` events, one
+//! per exposed rectangle. The `count` field of each event says how many more events follow for
+//! the same window. Redrawing for each event individually wastes time, so toolkits collect the
+//! rectangles until `count` is zero and then redraw the whole region at once. [`ExposeCoalescer`]
+//! does this bookkeeping.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::expose::ExposeCoalescer;
+//!
+//! fn event_loop(conn: &impl Connection) -> Result<(), ConnectionError> {
+//!     let mut expose = ExposeCoalescer::new();
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         let handled = expose.handle_event(&event, |region| {
+//!             let bbox = region.bounding_box();
+//!             println!("Redrawing {:?} of window {}", bbox, region.window);
+//!         });
+//!         if !handled {
+//!             // Handle other events
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::protocol::xproto::{ExposeEvent, Rectangle, Window};
+use crate::protocol::Event;
+
+/// The combined area of a series of `Expose` events for one window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedrawRegion {
+    /// The window that has to be redrawn.
+    pub window: Window,
+    /// The exposed rectangles. Rectangles that are covered by another one are left out.
+    pub rectangles: Vec<Rectangle>,
+}
+
+impl RedrawRegion {
+    /// Get the smallest rectangle that contains all exposed rectangles.
+    pub fn bounding_box(&self) -> Rectangle {
+        let (x1, y1, x2, y2) = self.rectangles.iter().map(corners).fold(
+            (i32::MAX, i32::MAX, i32::MIN, i32::MIN),
+            |(ax1, ay1, ax2, ay2), (x1, y1, x2, y2)| {
+                (ax1.min(x1), ay1.min(y1), ax2.max(x2), ay2.max(y2))
+            },
+        );
+        if x1 > x2 {
+            return Rectangle {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            };
+        }
+        // All rectangles start at a non-negative i16 coordinate, so only the size can overflow
+        Rectangle {
+            x: x1 as i16,
+            y: y1 as i16,
+            width: (x2 - x1).min(i32::from(u16::MAX)) as u16,
+            height: (y2 - y1).min(i32::from(u16::MAX)) as u16,
+        }
+    }
+}
+
+/// Collects the rectangles of `Expose` events until the last event of a series arrives.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default)]
+pub struct ExposeCoalescer {
+    pending: HashMap<Window, Vec<Rectangle>>,
+}
+
+impl ExposeCoalescer {
+    /// Create a new instance without pending rectangles.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add the rectangle of an `Expose` event.
+    ///
+    /// Returns the combined region of the window if this was the last event of a series, i.e.
+    /// its `count` is zero.
+    pub fn add(&mut self, event: &ExposeEvent) -> Option<RedrawRegion> {
+        let rect = Rectangle {
+            x: i16::try_from(event.x).unwrap_or(i16::MAX),
+            y: i16::try_from(event.y).unwrap_or(i16::MAX),
+            width: event.width,
+            height: event.height,
+        };
+        let rects = self.pending.entry(event.window).or_default();
+        add_rectangle(rects, rect);
+        if event.count != 0 {
+            return None;
+        }
+        let rectangles = self.pending.remove(&event.window).unwrap_or_default();
+        Some(RedrawRegion {
+            window: event.window,
+            rectangles,
+        })
+    }
+
+    /// Handle an event and call `redraw` when a window has to be redrawn.
+    ///
+    /// Returns `true` if the event was an `Expose` event. Pending rectangles of windows that are
+    /// destroyed are forgotten.
+    pub fn handle_event<F>(&mut self, event: &Event, redraw: F) -> bool
+    where
+        F: FnOnce(&RedrawRegion),
+    {
+        match event {
+            Event::Expose(event) => {
+                if let Some(region) = self.add(event) {
+                    redraw(&region);
+                }
+                true
+            }
+            Event::DestroyNotify(event) => {
+                self.forget(event.window);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Forget the pending rectangles of a window.
+    pub fn forget(&mut self, window: Window) {
+        let _ = self.pending.remove(&window);
+    }
+
+    /// Does the given window have rectangles that wait for the last event of their series?
+    pub fn is_pending(&self, window: Window) -> bool {
+        self.pending.contains_key(&window)
+    }
+}
+
+fn corners(rect: &Rectangle) -> (i32, i32, i32, i32) {
+    let (x, y) = (i32::from(rect.x), i32::from(rect.y));
+    (x, y, x + i32::from(rect.width), y + i32::from(rect.height))
+}
+
+fn contains(outer: &Rectangle, inner: &Rectangle) -> bool {
+    let (ox1, oy1, ox2, oy2) = corners(outer);
+    let (ix1, iy1, ix2, iy2) = corners(inner);
+    ox1 <= ix1 && oy1 <= iy1 && ix2 <= ox2 && iy2 <= oy2
+}
+
+/// Add a rectangle to a list, unless it is empty or covered by one of the rectangles.
+fn add_rectangle(rects: &mut Vec<Rectangle>, rect: Rectangle) {
+    if rect.width == 0 || rect.height == 0 || rects.iter().any(|r| contains(r, &rect)) {
+        return;
+    }
+    rects.retain(|r| !contains(&rect, r));
+    rects.push(rect);
+}
+
+#[cfg(test)]
+mod test {
+    use super::ExposeCoalescer;
+    use crate::protocol::xproto::{DestroyNotifyEvent, ExposeEvent, Rectangle};
+    use crate::protocol::Event;
+
+    fn expose(window: u32, x: u16, y: u16, width: u16, height: u16, count: u16) -> Event {
+        Event::Expose(ExposeEvent {
+            response_type: 12,
+            sequence: 0,
+            window,
+            x,
+            y,
+            width,
+            height,
+            count,
+        })
+    }
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn coalesce_series() {
+        let mut coalescer = ExposeCoalescer::new();
+        let mut redraws = Vec::new();
+        let events = [
+            expose(1, 0, 0, 10, 10, 3),
+            expose(2, 5, 5, 5, 5, 0),
+            expose(1, 2, 2, 3, 3, 2),
+            expose(1, 20, 0, 10, 10, 1),
+            expose(1, 0, 0, 10, 20, 0),
+        ];
+        for event in events.iter() {
+            assert!(coalescer.handle_event(event, |region| redraws.push(region.clone())));
+        }
+        assert_eq!(redraws.len(), 2);
+        assert_eq!(redraws[0].window, 2);
+        assert_eq!(redraws[1].window, 1);
+        // The smaller rectangles are covered by the last one
+        assert_eq!(
+            redraws[1].rectangles,
+            [rect(20, 0, 10, 10), rect(0, 0, 10, 20)]
+        );
+        assert_eq!(redraws[1].bounding_box(), rect(0, 0, 30, 20));
+        assert!(!coalescer.is_pending(1));
+    }
+
+    #[test]
+    fn destroyed_window_is_forgotten() {
+        let mut coalescer = ExposeCoalescer::new();
+        assert!(coalescer.handle_event(&expose(1, 0, 0, 10, 10, 1), |_| unreachable!()));
+        assert!(coalescer.is_pending(1));
+        let destroy = Event::DestroyNotify(DestroyNotifyEvent {
+            response_type: 17,
+            sequence: 0,
+            event: 1,
+            window: 1,
+        });
+        assert!(!coalescer.handle_event(&destroy, |_| unreachable!()));
+        assert!(!coalescer.is_pending(1));
+    }
+}
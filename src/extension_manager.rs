@@ -1,19 +1,84 @@
 //! Helper for implementing `RequestConnection::extension_information()`.
 
 use std::collections::{hash_map::Entry as HashMapEntry, HashMap};
+use std::sync::Mutex;
 
 use crate::connection::{RequestConnection, SequenceNumber};
 use crate::cookie::Cookie;
 use crate::errors::{ConnectionError, ReplyError};
-use crate::protocol::xproto::{ConnectionExt, QueryExtensionReply};
+use crate::protocol::xproto::{ConnectionExt, QueryExtensionReply, Setup};
 use crate::x11_utils::{ExtInfoProvider, ExtensionInformation};
 
+/// Extension information that is shared between all connections of this process.
+type SharedCache = HashMap<ServerIdentity, HashMap<&'static str, Option<ExtensionInformation>>>;
+
+static SHARED_CACHE: Mutex<Option<SharedCache>> = Mutex::new(None);
+
+/// Identifies an X11 server, so that connections to it can share extension information.
+///
+/// Programs that open many short-lived connections, e.g. a screenshot tool that is run from a
+/// script, send the same `QueryExtension` requests on every connection. With a shared cache, only
+/// the first connection to a server in a process asks; later connections that are identified in
+/// the same way use the cached answers.
+///
+/// A server is identified by its display name together with the vendor and release number from
+/// its setup. If a different X11 server with the same vendor and release starts on the same
+/// display while the process runs, and it assigns different opcodes, the cached information is
+/// wrong. Use [`ServerIdentity::forget`] when that can happen.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerIdentity {
+    display: String,
+    vendor: Vec<u8>,
+    release_number: u32,
+}
+
+impl ServerIdentity {
+    /// Identify the server on the given display that sent the given setup.
+    ///
+    /// `display` should be the display name that was used for connecting, e.g. the value of
+    /// `$DISPLAY`.
+    pub fn new(display: &str, setup: &Setup) -> Self {
+        Self {
+            display: display.to_string(),
+            vendor: setup.vendor.clone(),
+            release_number: setup.release_number,
+        }
+    }
+
+    /// Remove the cached extension information of this server.
+    pub fn forget(&self) {
+        if let Some(cache) = SHARED_CACHE.lock().unwrap().as_mut() {
+            let _ = cache.remove(self);
+        }
+    }
+
+    fn lookup(&self, extension_name: &str) -> Option<Option<ExtensionInformation>> {
+        SHARED_CACHE
+            .lock()
+            .unwrap()
+            .as_ref()?
+            .get(self)?
+            .get(extension_name)
+            .copied()
+    }
+
+    fn store(&self, extension_name: &'static str, info: Option<ExtensionInformation>) {
+        let _ = SHARED_CACHE
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .entry(self.clone())
+            .or_default()
+            .insert(extension_name, info);
+    }
+}
+
 /// Helper for implementing `RequestConnection::extension_information()`.
 ///
 /// This helps with implementing `RequestConnection`. Most likely, you do not need this in your own
 /// code, unless you really want to implement your own X11 connection.
 #[derive(Debug, Default)]
-pub struct ExtensionManager(HashMap<&'static str, CheckState>);
+pub struct ExtensionManager(HashMap<&'static str, CheckState>, Option<ServerIdentity>);
 
 #[derive(Debug)]
 enum CheckState {
@@ -24,9 +89,25 @@ enum CheckState {
 }
 
 impl ExtensionManager {
+    /// Share the extension information with other connections to the same X11 server.
+    ///
+    /// Afterwards, extensions that were already queried by another connection to the server are
+    /// not queried again, and the answers to the queries of this connection are shared. See
+    /// [`ServerIdentity`] for the details.
+    pub fn share_with(&mut self, identity: ServerIdentity) {
+        for (name, state) in self.0.iter() {
+            match state {
+                CheckState::Present(info) => identity.store(name, Some(*info)),
+                CheckState::Missing => identity.store(name, None),
+                CheckState::Prefetched(_) | CheckState::Error => {}
+            }
+        }
+        self.1 = Some(identity);
+    }
+
     /// If the extension has not prefetched yet, sends a `QueryExtension`
     /// requests, adds a field to the hash map and returns a reference to it.
-    fn prefetch_extension_information_aux<C: RequestConnection>(
+    fn prefetch_extension_information_aux<C: RequestConnection + ?Sized>(
         &mut self,
         conn: &C,
         extension_name: &'static str,
@@ -35,6 +116,15 @@ impl ExtensionManager {
             // Extension already checked, return the cached value
             HashMapEntry::Occupied(entry) => Ok(entry.into_mut()),
             HashMapEntry::Vacant(entry) => {
+                let shared = self
+                    .1
+                    .as_ref()
+                    .and_then(|identity| identity.lookup(extension_name));
+                match shared {
+                    Some(Some(info)) => return Ok(entry.insert(CheckState::Present(info))),
+                    Some(None) => return Ok(entry.insert(CheckState::Missing)),
+                    None => {}
+                }
                 let cookie = conn.query_extension(extension_name.as_bytes())?;
                 Ok(entry.insert(CheckState::Prefetched(cookie.into_sequence_number())))
             }
@@ -43,7 +133,7 @@ impl ExtensionManager {
 
     /// Prefetchs an extension sending a `QueryExtension` without waiting for
     /// the reply.
-    pub fn prefetch_extension_information<C: RequestConnection>(
+    pub fn prefetch_extension_information<C: RequestConnection + ?Sized>(
         &mut self,
         conn: &C,
         extension_name: &'static str,
@@ -56,11 +146,12 @@ impl ExtensionManager {
     /// An implementation of `RequestConnection::extension_information()`.
     ///
     /// The given connection is used for sending a `QueryExtension` request if needed.
-    pub fn extension_information<C: RequestConnection>(
+    pub fn extension_information<C: RequestConnection + ?Sized>(
         &mut self,
         conn: &C,
         extension_name: &'static str,
     ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        let identity = self.1.clone();
         let entry = self.prefetch_extension_information_aux(conn, extension_name)?;
         match entry {
             CheckState::Prefetched(sequence_number) => {
@@ -82,9 +173,15 @@ impl ExtensionManager {
                                 first_error: info.first_error,
                             };
                             *entry = CheckState::Present(info);
+                            if let Some(identity) = identity {
+                                identity.store(extension_name, Some(info));
+                            }
                             Ok(Some(info))
                         } else {
                             *entry = CheckState::Missing;
+                            if let Some(identity) = identity {
+                                identity.store(extension_name, None);
+                            }
                             Ok(None)
                         }
                     }
@@ -148,134 +245,33 @@ impl ExtInfoProvider for ExtensionManager {
 
 #[cfg(test)]
 mod test {
-    use std::cell::RefCell;
-    use std::io::IoSlice;
-
-    use crate::connection::{
-        BufWithFds, DiscardMode, ReplyOrError, RequestConnection, RequestKind, SequenceNumber,
-    };
-    use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
-    use crate::errors::{ConnectionError, ParseError};
-    use crate::utils::RawFdContainer;
-    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, TryParse, TryParseFd};
-
-    use super::{CheckState, ExtensionManager};
-
-    struct FakeConnection(RefCell<SequenceNumber>);
-
-    impl RequestConnection for FakeConnection {
-        type Buf = Vec<u8>;
-
-        fn send_request_with_reply<R>(
-            &self,
-            _bufs: &[IoSlice<'_>],
-            _fds: Vec<RawFdContainer>,
-        ) -> Result<Cookie<'_, Self, R>, ConnectionError>
-        where
-            R: TryParse,
-        {
-            Ok(Cookie::new(self, 1))
-        }
-
-        fn send_request_with_reply_with_fds<R>(
-            &self,
-            _bufs: &[IoSlice<'_>],
-            _fds: Vec<RawFdContainer>,
-        ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
-        where
-            R: TryParseFd,
-        {
-            unimplemented!()
-        }
-
-        fn send_request_without_reply(
-            &self,
-            _bufs: &[IoSlice<'_>],
-            _fds: Vec<RawFdContainer>,
-        ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
-            unimplemented!()
-        }
-
-        fn discard_reply(&self, _sequence: SequenceNumber, _kind: RequestKind, _mode: DiscardMode) {
-            unimplemented!()
-        }
-
-        fn prefetch_extension_information(
-            &self,
-            _extension_name: &'static str,
-        ) -> Result<(), ConnectionError> {
-            unimplemented!();
-        }
-
-        fn extension_information(
-            &self,
-            _extension_name: &'static str,
-        ) -> Result<Option<ExtensionInformation>, ConnectionError> {
-            unimplemented!()
-        }
-
-        fn wait_for_reply_or_raw_error(
-            &self,
-            sequence: SequenceNumber,
-        ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
-            // Code should only ask once for the reply to a request. Check that this is the case
-            // (by requiring monotonically increasing sequence numbers here).
-            let mut last = self.0.borrow_mut();
-            assert!(
-                *last < sequence,
-                "Last sequence number that was awaited was {}, but now {}",
-                *last,
-                sequence
-            );
-            *last = sequence;
-            // Then return an error, because that's what the #[test] below needs.
-            Err(ConnectionError::UnknownError)
-        }
-
-        fn wait_for_reply(
-            &self,
-            _sequence: SequenceNumber,
-        ) -> Result<Option<Vec<u8>>, ConnectionError> {
-            unimplemented!()
-        }
-
-        fn wait_for_reply_with_fds_raw(
-            &self,
-            _sequence: SequenceNumber,
-        ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
-            unimplemented!()
-        }
-
-        fn check_for_raw_error(
-            &self,
-            _sequence: SequenceNumber,
-        ) -> Result<Option<Vec<u8>>, ConnectionError> {
-            unimplemented!()
-        }
-
-        fn maximum_request_bytes(&self) -> usize {
-            0
-        }
-
-        fn prefetch_maximum_request_bytes(&self) {
-            unimplemented!()
-        }
-
-        fn parse_error(&self, _error: &[u8]) -> Result<crate::x11_utils::X11Error, ParseError> {
-            unimplemented!()
-        }
-
-        fn parse_event(&self, _event: &[u8]) -> Result<crate::protocol::Event, ParseError> {
-            unimplemented!()
-        }
+    use crate::errors::ConnectionError;
+    use crate::protocol::xproto;
+    use crate::testing::{FakeConnection, Response};
+    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation};
+
+    use super::{CheckState, ExtensionManager, ServerIdentity};
+
+    /// Get a connection that answers the next `QueryExtension` request with an error.
+    fn failing_connection() -> FakeConnection {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::QUERY_EXTENSION_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::VALUE_ERROR,
+                bad_value: 0,
+            },
+        );
+        conn
     }
 
     #[test]
     fn test_double_await() {
-        let conn = FakeConnection(RefCell::new(0));
+        let conn = failing_connection();
         let mut ext_info = ExtensionManager::default();
 
-        // Ask for an extension info. FakeConnection will return an error.
+        // Ask for an extension info. The X11 server answers with an error.
         match ext_info.extension_information(&conn, "whatever") {
             Err(ConnectionError::UnknownError) => {}
             r => panic!("Unexpected result: {:?}", r),
@@ -283,6 +279,7 @@ mod test {
 
         // Ask again for the extension information. ExtensionInformation should not try to get the
         // reply again, because that would just hang. Once upon a time, this caused a hang.
+        // FakeConnection panics when a response is waited for twice.
         match ext_info.extension_information(&conn, "whatever") {
             Err(ConnectionError::UnknownError) => {}
             r => panic!("Unexpected result: {:?}", r),
@@ -307,4 +304,49 @@ mod test {
         assert_eq!(ext_info.get_from_event_code(5), Some(("present", info)));
         assert_eq!(ext_info.get_from_error_code(6), Some(("present", info)));
     }
+
+    #[test]
+    fn test_shared_cache() {
+        let info = ExtensionInformation {
+            major_opcode: 140,
+            first_event: 90,
+            first_error: 150,
+        };
+        let identity = |release_number| ServerIdentity {
+            display: ":test_shared_cache".to_string(),
+            vendor: b"The X.Org Foundation".to_vec(),
+            release_number,
+        };
+
+        let mut first = ExtensionManager::default();
+        let _ = first.0.insert("present", CheckState::Present(info));
+        let _ = first.0.insert("missing", CheckState::Missing);
+        first.share_with(identity(12_101_004));
+
+        // These come from the cache, so no requests are sent
+        let conn = FakeConnection::default();
+        let mut second = ExtensionManager::default();
+        second.share_with(identity(12_101_004));
+        assert_eq!(
+            second.extension_information(&conn, "present").unwrap(),
+            Some(info)
+        );
+        assert_eq!(
+            second.extension_information(&conn, "missing").unwrap(),
+            None
+        );
+        assert!(conn.sent_requests().is_empty());
+
+        // Another server on the same display does not share the cache
+        let mut third = ExtensionManager::default();
+        third.share_with(identity(12_101_005));
+        let conn = failing_connection();
+        assert!(third.extension_information(&conn, "present").is_err());
+
+        identity(12_101_004).forget();
+        let conn = failing_connection();
+        let mut fourth = ExtensionManager::default();
+        fourth.share_with(identity(12_101_004));
+        assert!(fourth.extension_information(&conn, "present").is_err());
+    }
 }
@@ -0,0 +1,212 @@
+//! Mapping windows only once their first frame is ready.
+//!
+//! When a window is mapped, the X11 server paints it with its background before the application
+//! had a chance to draw anything. For applications that take a while to render their first
+//! frame, this shows up as a white (or black) flash. [`DeferredMap`] avoids this in one of two
+//! ways:
+//!
+//! - The first frame is drawn into a pixmap, which is used as the window's background while it
+//!   is mapped, see [`DeferredMap::map_with_contents`].
+//! - The first frame is presented with the Present extension while the window is unmapped and the
+//!   window is mapped once the `PresentCompleteNotify` event arrives, see
+//!   [`DeferredMap::handle_event`].
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::first_frame::DeferredMap;
+//! use x11rb::protocol::xproto::{Pixmap, Window};
+//!
+//! fn show(conn: &impl Connection, window: Window, first_frame: Pixmap) -> Result<(), ConnectionError> {
+//!     // `first_frame` contains what the window should show initially
+//!     let mut map = DeferredMap::new(window);
+//!     map.map_with_contents(conn, first_frame)?;
+//!     conn.flush()
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::{self, BackPixmap, ChangeWindowAttributesAux, Pixmap, Window};
+use crate::protocol::Event;
+
+/// Delays mapping a window until its first frame is ready.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeferredMap {
+    window: Window,
+    mapped: bool,
+}
+
+impl DeferredMap {
+    /// Create a new instance for the given unmapped window.
+    pub fn new(window: Window) -> Self {
+        Self {
+            window,
+            mapped: false,
+        }
+    }
+
+    /// Get the window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Was the window already mapped by this instance?
+    pub fn is_mapped(&self) -> bool {
+        self.mapped
+    }
+
+    /// Map the window so that it initially shows the contents of the given pixmap.
+    ///
+    /// The pixmap must have the size and depth of the window. It is used as the window's
+    /// background while the window is mapped. Afterwards, the background is set to `None`, so
+    /// that the X11 server does not paint over the window's contents later. The pixmap is not
+    /// needed after this function returns and can be freed.
+    ///
+    /// Nothing happens if the window was already mapped.
+    pub fn map_with_contents<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        contents: Pixmap,
+    ) -> Result<(), ConnectionError> {
+        if self.mapped {
+            return Ok(());
+        }
+        let aux = ChangeWindowAttributesAux::new().background_pixmap(contents);
+        let _ = xproto::change_window_attributes(conn, self.window, &aux)?;
+        self.map_now(conn)?;
+        let aux = ChangeWindowAttributesAux::new().background_pixmap(BackPixmap::NONE);
+        let _ = xproto::change_window_attributes(conn, self.window, &aux)?;
+        Ok(())
+    }
+
+    /// Map the window, unless this was already done.
+    pub fn map_now<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<(), ConnectionError> {
+        if !self.mapped {
+            let _ = xproto::map_window(conn, self.window)?;
+            self.mapped = true;
+        }
+        Ok(())
+    }
+
+    /// Handle an event and map the window once its first frame was presented.
+    ///
+    /// When a `PresentCompleteNotify` event for the window arrives, the window is mapped. For
+    /// this, the application has to select `CompleteNotify` events with `SelectInput` from the
+    /// Present extension. Without the `present` feature, this function never maps the window.
+    ///
+    /// Returns `true` if the window was mapped by this call.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ConnectionError> {
+        if self.mapped || !self.is_first_frame_event(event) {
+            return Ok(false);
+        }
+        self.map_now(conn)?;
+        Ok(true)
+    }
+
+    #[cfg(feature = "present")]
+    fn is_first_frame_event(&self, event: &Event) -> bool {
+        matches!(event, Event::PresentCompleteNotify(e) if e.window == self.window)
+    }
+
+    #[cfg(not(feature = "present"))]
+    fn is_first_frame_event(&self, _event: &Event) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryInto;
+
+    use super::DeferredMap;
+    use crate::protocol::xproto;
+    use crate::protocol::Event;
+    use crate::testing::FakeConnection;
+
+    const WINDOW: u32 = 42;
+
+    #[test]
+    fn map_with_contents() {
+        let conn = FakeConnection::default();
+        let mut map = DeferredMap::new(WINDOW);
+        map.map_with_contents(&conn, 7).unwrap();
+        assert!(map.is_mapped());
+
+        let sent = conn.take_sent_requests();
+        let opcodes = sent.iter().map(|r| r.major_opcode()).collect::<Vec<_>>();
+        assert_eq!(
+            opcodes,
+            [
+                xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST,
+                xproto::MAP_WINDOW_REQUEST,
+                xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST,
+            ]
+        );
+        // The background is set to the pixmap while mapping and to None afterwards
+        let background =
+            |index: usize| u32::from_ne_bytes(sent[index].bytes[12..16].try_into().unwrap());
+        assert_eq!(background(0), 7);
+        assert_eq!(background(2), 0);
+
+        // Mapping again does nothing
+        map.map_with_contents(&conn, 7).unwrap();
+        map.map_now(&conn).unwrap();
+        assert!(conn.sent_requests().is_empty());
+    }
+
+    #[test]
+    fn other_events_do_not_map() {
+        let conn = FakeConnection::default();
+        let mut map = DeferredMap::new(WINDOW);
+        let event = Event::Unknown(vec![0; 32]);
+        assert!(!map.handle_event(&conn, &event).unwrap());
+        assert!(!map.is_mapped());
+        assert!(conn.sent_requests().is_empty());
+    }
+
+    #[cfg(feature = "present")]
+    #[test]
+    fn map_on_complete_notify() {
+        use crate::protocol::present::{CompleteKind, CompleteMode, CompleteNotifyEvent};
+
+        let complete = |window| {
+            Event::PresentCompleteNotify(CompleteNotifyEvent {
+                response_type: 35,
+                extension: 0,
+                sequence: 0,
+                length: 0,
+                event_type: 1,
+                kind: CompleteKind::PIXMAP,
+                mode: CompleteMode::COPY,
+                event: 0,
+                window,
+                serial: 0,
+                ust: 0,
+                msc: 0,
+            })
+        };
+        let conn = FakeConnection::default();
+        let mut map = DeferredMap::new(WINDOW);
+        assert!(!map.handle_event(&conn, &complete(WINDOW + 1)).unwrap());
+        assert!(conn.sent_requests().is_empty());
+
+        assert!(map.handle_event(&conn, &complete(WINDOW)).unwrap());
+        let sent = conn.take_sent_requests();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].major_opcode(), xproto::MAP_WINDOW_REQUEST);
+
+        // The window is only mapped once
+        assert!(!map.handle_event(&conn, &complete(WINDOW)).unwrap());
+        assert!(conn.sent_requests().is_empty());
+    }
+}
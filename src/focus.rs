@@ -0,0 +1,344 @@
+//! Tracking which window has the keyboard focus.
+//!
+//! The X11 server reports focus changes with `FocusIn` and `FocusOut` events. Their `detail` and
+//! `mode` fields describe how the focus moved relative to the window, but most programs only want
+//! to know whether one of their windows currently receives keyboard input. [`FocusTracker`]
+//! combines these events into simple "gained focus" and "lost focus" notifications:
+//!
+//! - A window has the focus if the focus is on the window itself or on one of its descendants.
+//!   Moving the focus between the window and its descendants does not change anything.
+//! - When the focus is `PointerRoot` (or on an ancestor of the window) and the pointer is inside
+//!   the window, the window receives keyboard input and is thus also considered focused.
+//! - Events caused by keyboard grabs (mode `Grab` and `Ungrab`) are ignored. A window keeps the
+//!   focus while e.g. a menu or a global shortcut temporarily grabs the keyboard.
+//!
+//! For this, `FocusChange` events have to be selected on the tracked windows. To also detect the
+//! pointer case, `EnterWindow` and `LeaveWindow` events are needed, see
+//! [`FocusTracker::event_mask`].
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::focus::{FocusChange, FocusTracker};
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn run(conn: &impl Connection, window: Window) -> Result<(), ConnectionError> {
+//!     // `window` was created with `FocusTracker::event_mask()` in its event mask
+//!     let mut tracker = FocusTracker::new();
+//!     tracker.track(window);
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         match tracker.handle_event(&event) {
+//!             Some(FocusChange::Gained(window)) => println!("{} gained focus", window),
+//!             Some(FocusChange::Lost(window)) => println!("{} lost focus", window),
+//!             None => {}
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::protocol::xproto::{
+    EnterNotifyEvent, EventMask, FocusInEvent, NotifyDetail, NotifyMode, Window,
+};
+use crate::protocol::Event;
+
+/// A change of the focus of a tracked window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusChange {
+    /// The window now has the focus.
+    Gained(Window),
+    /// The window no longer has the focus.
+    Lost(Window),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrackedWindow {
+    window: Window,
+    /// The focus is on the window or one of its descendants.
+    focus_within: bool,
+    /// The window receives keyboard input because the pointer is inside of it.
+    pointer_focus: bool,
+}
+
+impl TrackedWindow {
+    fn has_focus(&self) -> bool {
+        self.focus_within || self.pointer_focus
+    }
+}
+
+/// Tracks the keyboard focus of a set of windows.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Default)]
+pub struct FocusTracker {
+    windows: Vec<TrackedWindow>,
+}
+
+impl FocusTracker {
+    /// Create a new instance that does not track any windows.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the events that have to be selected on tracked windows.
+    pub fn event_mask() -> EventMask {
+        EventMask::FOCUS_CHANGE | EventMask::ENTER_WINDOW | EventMask::LEAVE_WINDOW
+    }
+
+    /// Start tracking the focus of a window.
+    ///
+    /// The window is assumed to not have the focus. Nothing happens if the window is already
+    /// tracked.
+    pub fn track(&mut self, window: Window) {
+        if self.find(window).is_none() {
+            self.windows.push(TrackedWindow {
+                window,
+                focus_within: false,
+                pointer_focus: false,
+            });
+        }
+    }
+
+    /// Stop tracking the focus of a window, e.g. because it was destroyed.
+    pub fn untrack(&mut self, window: Window) {
+        self.windows.retain(|tracked| tracked.window != window);
+    }
+
+    /// Check if the given window has the focus.
+    ///
+    /// Windows that are not tracked never have the focus.
+    pub fn has_focus(&self, window: Window) -> bool {
+        self.windows
+            .iter()
+            .any(|tracked| tracked.window == window && tracked.has_focus())
+    }
+
+    /// Get the tracked window that has the focus, if any.
+    pub fn focused(&self) -> Option<Window> {
+        self.windows
+            .iter()
+            .find(|tracked| tracked.has_focus())
+            .map(|tracked| tracked.window)
+    }
+
+    /// Handle an event and report whether the focus of a tracked window changed.
+    ///
+    /// Events that are not about the focus of a tracked window are ignored.
+    pub fn handle_event(&mut self, event: &Event) -> Option<FocusChange> {
+        let (window, update): (_, fn(&mut TrackedWindow)) = match event {
+            Event::FocusIn(event) => (event.event, focus_update(event, true)?),
+            Event::FocusOut(event) => (event.event, focus_update(event, false)?),
+            Event::EnterNotify(event) => (event.event, crossing_update(event, true)?),
+            Event::LeaveNotify(event) => (event.event, crossing_update(event, false)?),
+            _ => return None,
+        };
+        let tracked = self.find(window)?;
+        let had_focus = tracked.has_focus();
+        update(tracked);
+        match (had_focus, tracked.has_focus()) {
+            (false, true) => Some(FocusChange::Gained(window)),
+            (true, false) => Some(FocusChange::Lost(window)),
+            _ => None,
+        }
+    }
+
+    fn find(&mut self, window: Window) -> Option<&mut TrackedWindow> {
+        self.windows
+            .iter_mut()
+            .find(|tracked| tracked.window == window)
+    }
+}
+
+/// Get the state change described by a `FocusIn` (`focus_in` is `true`) or `FocusOut` event.
+fn focus_update(event: &FocusInEvent, focus_in: bool) -> Option<fn(&mut TrackedWindow)> {
+    if event.mode == NotifyMode::GRAB || event.mode == NotifyMode::UNGRAB {
+        return None;
+    }
+    match event.detail {
+        // Keyboard input was received because of the pointer case before, but now the focus is
+        // within the window
+        NotifyDetail::ANCESTOR | NotifyDetail::VIRTUAL if focus_in => Some(|tracked| {
+            tracked.focus_within = true;
+            tracked.pointer_focus = false;
+        }),
+        NotifyDetail::ANCESTOR
+        | NotifyDetail::VIRTUAL
+        | NotifyDetail::NONLINEAR
+        | NotifyDetail::NONLINEAR_VIRTUAL => {
+            if focus_in {
+                Some(|tracked| tracked.focus_within = true)
+            } else {
+                Some(|tracked| tracked.focus_within = false)
+            }
+        }
+        NotifyDetail::POINTER => {
+            if focus_in {
+                Some(|tracked| tracked.pointer_focus = true)
+            } else {
+                Some(|tracked| tracked.pointer_focus = false)
+            }
+        }
+        // `Inferior` means that the focus moved between the window and one of its descendants.
+        // `PointerRoot` and `None` are only reported on root windows.
+        _ => None,
+    }
+}
+
+/// Get the state change described by an `EnterNotify` (`enter` is `true`) or `LeaveNotify` event.
+fn crossing_update(event: &EnterNotifyEvent, enter: bool) -> Option<fn(&mut TrackedWindow)> {
+    // The lowest bit tells whether the focus is on the window or an ancestor of it
+    let focus = event.same_screen_focus & 1 != 0;
+    if !focus || event.detail == NotifyDetail::INFERIOR {
+        return None;
+    }
+    if enter {
+        Some(|tracked| tracked.pointer_focus = !tracked.focus_within)
+    } else {
+        Some(|tracked| tracked.pointer_focus = false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FocusChange, FocusTracker};
+    use crate::protocol::xproto::{
+        EnterNotifyEvent, FocusInEvent, NotifyDetail, NotifyMode, Window, ENTER_NOTIFY_EVENT,
+        FOCUS_IN_EVENT, FOCUS_OUT_EVENT, LEAVE_NOTIFY_EVENT,
+    };
+    use crate::protocol::Event;
+
+    fn focus_event(
+        focus_in: bool,
+        window: Window,
+        detail: NotifyDetail,
+        mode: NotifyMode,
+    ) -> Event {
+        let event = FocusInEvent {
+            response_type: if focus_in {
+                FOCUS_IN_EVENT
+            } else {
+                FOCUS_OUT_EVENT
+            },
+            detail,
+            sequence: 0,
+            event: window,
+            mode,
+        };
+        if focus_in {
+            Event::FocusIn(event)
+        } else {
+            Event::FocusOut(event)
+        }
+    }
+
+    fn crossing_event(enter: bool, window: Window, focus: bool) -> Event {
+        let event = EnterNotifyEvent {
+            response_type: if enter {
+                ENTER_NOTIFY_EVENT
+            } else {
+                LEAVE_NOTIFY_EVENT
+            },
+            detail: NotifyDetail::NONLINEAR,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: window,
+            child: 0,
+            root_x: 0,
+            root_y: 0,
+            event_x: 0,
+            event_y: 0,
+            state: 0,
+            mode: NotifyMode::NORMAL,
+            same_screen_focus: 2 | u8::from(focus),
+        };
+        if enter {
+            Event::EnterNotify(event)
+        } else {
+            Event::LeaveNotify(event)
+        }
+    }
+
+    #[test]
+    fn gain_and_lose_focus() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        let event = focus_event(true, 10, NotifyDetail::NONLINEAR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Gained(10)));
+        assert!(tracker.has_focus(10));
+        assert_eq!(tracker.focused(), Some(10));
+
+        // Moving the focus into a child window changes nothing
+        let event = focus_event(false, 10, NotifyDetail::INFERIOR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), None);
+        let event = focus_event(true, 10, NotifyDetail::INFERIOR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), None);
+
+        let event = focus_event(false, 10, NotifyDetail::NONLINEAR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Lost(10)));
+        assert_eq!(tracker.focused(), None);
+    }
+
+    #[test]
+    fn grabs_are_ignored() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        let event = focus_event(true, 10, NotifyDetail::NONLINEAR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Gained(10)));
+        let event = focus_event(false, 10, NotifyDetail::NONLINEAR, NotifyMode::GRAB);
+        assert_eq!(tracker.handle_event(&event), None);
+        let event = focus_event(true, 10, NotifyDetail::NONLINEAR, NotifyMode::UNGRAB);
+        assert_eq!(tracker.handle_event(&event), None);
+        assert!(tracker.has_focus(10));
+    }
+
+    #[test]
+    fn pointer_root_focus() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        // The focus is PointerRoot and the pointer enters the window
+        let event = crossing_event(true, 10, true);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Gained(10)));
+        // Explicitly focusing the window keeps the focus
+        let event = focus_event(true, 10, NotifyDetail::ANCESTOR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), None);
+        // ...even when the pointer leaves
+        let event = crossing_event(false, 10, true);
+        assert_eq!(tracker.handle_event(&event), None);
+        assert!(tracker.has_focus(10));
+
+        let event = focus_event(false, 10, NotifyDetail::ANCESTOR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Lost(10)));
+    }
+
+    #[test]
+    fn focus_pointer_detail() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        let event = focus_event(true, 10, NotifyDetail::POINTER, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Gained(10)));
+        let event = focus_event(false, 10, NotifyDetail::POINTER, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), Some(FocusChange::Lost(10)));
+    }
+
+    #[test]
+    fn crossing_without_focus_is_ignored() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        let event = crossing_event(true, 10, false);
+        assert_eq!(tracker.handle_event(&event), None);
+    }
+
+    #[test]
+    fn untracked_windows_are_ignored() {
+        let mut tracker = FocusTracker::new();
+        tracker.track(10);
+        let event = focus_event(true, 20, NotifyDetail::NONLINEAR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), None);
+        assert!(!tracker.has_focus(20));
+
+        tracker.untrack(10);
+        let event = focus_event(true, 10, NotifyDetail::NONLINEAR, NotifyMode::NORMAL);
+        assert_eq!(tracker.handle_event(&event), None);
+    }
+}
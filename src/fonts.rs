@@ -0,0 +1,242 @@
+//! Listing server-side fonts and managing the font path.
+//!
+//! Core fonts are rendered by the X11 server, which looks them up in its font path. Listing them
+//! with `ListFontsWithInfo` results in one reply per font, terminated by a reply without a name.
+//! [`list_fonts_with_info`] turns this into an iterator over [`FontInfo`]s with the names already
+//! decoded. The font path functions are useful for legacy environments that still install their
+//! own core fonts, similar to `xset fp+`.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::fonts::{add_font_path_element, list_fonts_with_info};
+//!
+//! fn fixed_fonts(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     add_font_path_element(conn, "/usr/share/fonts/misc", false)?;
+//!     for font in list_fonts_with_info(conn, "-misc-fixed-*", 100)? {
+//!         let font = font?;
+//!         println!("{}: {} pixels high", font.name, font.font_ascent + font.font_descent);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::cookie::{CookieStream, VoidCookie};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, Atom, Charinfo, Fontprop, ListFontsWithInfoReply, Str};
+
+/// Decode a name that the X11 server sent.
+fn decode(name: &[u8]) -> String {
+    String::from_utf8_lossy(name).into_owned()
+}
+
+/// Information about a font, as reported by `ListFontsWithInfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontInfo {
+    /// The name of the font.
+    pub name: String,
+    /// The extent of the font above the baseline.
+    pub font_ascent: i16,
+    /// The extent of the font below the baseline.
+    pub font_descent: i16,
+    /// The minimum of the metrics of all characters.
+    pub min_bounds: Charinfo,
+    /// The maximum of the metrics of all characters.
+    pub max_bounds: Charinfo,
+    /// The character that is drawn for characters that do not exist in the font.
+    pub default_char: u16,
+    /// Whether all characters between the first and the last character exist.
+    pub all_chars_exist: bool,
+    /// The properties of the font, e.g. `FONT` with its full name.
+    pub properties: Vec<Fontprop>,
+    /// The number of fonts that the X11 server expects to still follow. This is only a hint.
+    pub remaining_hint: u32,
+}
+
+impl FontInfo {
+    /// Get the value of the font property with the given name.
+    pub fn property(&self, name: Atom) -> Option<u32> {
+        self.properties
+            .iter()
+            .find(|prop| prop.name == name)
+            .map(|prop| prop.value)
+    }
+}
+
+impl From<ListFontsWithInfoReply> for FontInfo {
+    fn from(reply: ListFontsWithInfoReply) -> Self {
+        Self {
+            name: decode(&reply.name),
+            font_ascent: reply.font_ascent,
+            font_descent: reply.font_descent,
+            min_bounds: reply.min_bounds,
+            max_bounds: reply.max_bounds,
+            default_char: reply.default_char,
+            all_chars_exist: reply.all_chars_exist,
+            properties: reply.properties,
+            remaining_hint: reply.replies_hint,
+        }
+    }
+}
+
+/// An iterator over the fonts that `ListFontsWithInfo` found.
+///
+/// The iterator ends after the last font or after the first error.
+#[derive(Debug)]
+pub struct FontInfoIter<'c, C: RequestConnection + ?Sized>(
+    CookieStream<'c, C, ListFontsWithInfoReply>,
+);
+
+impl<C: RequestConnection + ?Sized> Iterator for FontInfoIter<'_, C> {
+    type Item = Result<FontInfo, ReplyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|reply| reply.map(FontInfo::from))
+    }
+}
+
+/// List the names of the fonts that match the given pattern.
+///
+/// The pattern may contain the wildcards `*` and `?`. At most `max_names` names are returned.
+pub fn list_fonts<C: RequestConnection + ?Sized>(
+    conn: &C,
+    pattern: &str,
+    max_names: u16,
+) -> Result<Vec<String>, ReplyError> {
+    let reply = xproto::list_fonts(conn, max_names, pattern.as_bytes())?.reply()?;
+    Ok(reply.names.iter().map(|name| decode(&name.name)).collect())
+}
+
+/// List the fonts that match the given pattern together with information about them.
+///
+/// The X11 server sends the information about each font in a separate reply. The returned
+/// iterator waits for them one at a time.
+pub fn list_fonts_with_info<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    pattern: &str,
+    max_names: u16,
+) -> Result<FontInfoIter<'c, C>, ConnectionError> {
+    let cookie = xproto::list_fonts_with_info(conn, max_names, pattern.as_bytes())?;
+    Ok(FontInfoIter(cookie))
+}
+
+/// Get the directories and font servers that the X11 server searches for fonts.
+pub fn get_font_path<C: RequestConnection + ?Sized>(conn: &C) -> Result<Vec<String>, ReplyError> {
+    let reply = xproto::get_font_path(conn)?.reply()?;
+    Ok(reply
+        .path
+        .iter()
+        .map(|element| decode(&element.name))
+        .collect())
+}
+
+/// Set the directories and font servers that the X11 server searches for fonts.
+///
+/// The X11 server checks the elements and fails with a `Value` error if one of them cannot be
+/// used. An empty path restores the default font path of the X11 server.
+pub fn set_font_path<'c, C, S>(
+    conn: &'c C,
+    path: &[S],
+) -> Result<VoidCookie<'c, C>, ConnectionError>
+where
+    C: RequestConnection + ?Sized,
+    S: AsRef<str>,
+{
+    let path = path
+        .iter()
+        .map(|element| Str {
+            name: element.as_ref().as_bytes().to_vec(),
+        })
+        .collect::<Vec<_>>();
+    xproto::set_font_path(conn, &path)
+}
+
+/// Add an element to the font path, unless it is already part of it.
+///
+/// The element is added to the front of the font path if `prepend` is `true` and to the end
+/// otherwise. Returns whether the font path was changed. This waits for the X11 server to accept
+/// the new font path.
+pub fn add_font_path_element<C: RequestConnection + ?Sized>(
+    conn: &C,
+    element: &str,
+    prepend: bool,
+) -> Result<bool, ReplyError> {
+    let mut path = get_font_path(conn)?;
+    if !add_element(&mut path, element, prepend) {
+        return Ok(false);
+    }
+    set_font_path(conn, &path)?.check()?;
+    Ok(true)
+}
+
+/// Remove an element from the font path.
+///
+/// Returns whether the element was part of the font path. This waits for the X11 server to accept
+/// the new font path.
+pub fn remove_font_path_element<C: RequestConnection + ?Sized>(
+    conn: &C,
+    element: &str,
+) -> Result<bool, ReplyError> {
+    let mut path = get_font_path(conn)?;
+    if !remove_element(&mut path, element) {
+        return Ok(false);
+    }
+    set_font_path(conn, &path)?.check()?;
+    Ok(true)
+}
+
+/// Normalise a font path element for comparisons. A trailing slash does not matter.
+fn normalise(element: &str) -> &str {
+    match element.strip_suffix('/') {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => element,
+    }
+}
+
+fn add_element(path: &mut Vec<String>, element: &str, prepend: bool) -> bool {
+    if path.iter().any(|e| normalise(e) == normalise(element)) {
+        return false;
+    }
+    if prepend {
+        path.insert(0, element.to_string());
+    } else {
+        path.push(element.to_string());
+    }
+    true
+}
+
+fn remove_element(path: &mut Vec<String>, element: &str) -> bool {
+    let len = path.len();
+    path.retain(|e| normalise(e) != normalise(element));
+    path.len() != len
+}
+
+#[cfg(test)]
+mod test {
+    use super::{add_element, remove_element};
+
+    #[test]
+    fn modify_font_path() {
+        let mut path = vec![
+            "/usr/share/fonts/misc/".to_string(),
+            "built-ins".to_string(),
+        ];
+        assert!(!add_element(&mut path, "/usr/share/fonts/misc", true));
+        assert!(add_element(&mut path, "/opt/fonts", true));
+        assert!(add_element(&mut path, "unix/:7100", false));
+        assert_eq!(
+            path,
+            [
+                "/opt/fonts",
+                "/usr/share/fonts/misc/",
+                "built-ins",
+                "unix/:7100"
+            ]
+        );
+
+        assert!(remove_element(&mut path, "/usr/share/fonts/misc"));
+        assert!(!remove_element(&mut path, "/usr/share/fonts/misc"));
+        assert_eq!(path, ["/opt/fonts", "built-ins", "unix/:7100"]);
+    }
+}
@@ -0,0 +1,262 @@
+//! Capturing the frame that a window presents next.
+//!
+//! Visual regression tests of GUI applications compare screenshots with reference images. Taking
+//! the screenshot at an arbitrary point in time is flaky, since the application might be in the
+//! middle of rendering. [`capture_next_frame`] instead waits until the window presents its next
+//! frame with the Present extension and captures it with `GetImage`:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::frame_capture::capture_next_frame;
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn screenshot(conn: &impl Connection, window: Window) -> Result<Vec<u8>, ReplyOrIdError> {
+//!     let frame = capture_next_frame(conn, window)?;
+//!     println!("Captured frame {} at MSC {}", frame.serial, frame.msc);
+//!     Ok(frame.image.data().to_vec())
+//! }
+//! ```
+//!
+//! The application that owns the window does not need to cooperate, since any client may select
+//! Present events on any window. However, only frames that are presented with `PresentPixmap` are
+//! noticed. Drawing directly into the window does not count as a frame.
+//!
+//! The code in this module is only available when the `image` and `present` features of the
+//! library are enabled.
+
+use crate::connection::{Connection, SequenceNumber};
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::image::Image;
+use crate::protocol::present::{self, CompleteKind, CompleteMode, CompleteNotifyEvent};
+use crate::protocol::xproto::{self, ImageFormat, Window};
+use crate::protocol::Event;
+
+/// A frame that was captured by [`capture_next_frame`].
+#[derive(Debug)]
+pub struct CapturedFrame {
+    /// The serial number that the application passed to `PresentPixmap` for this frame.
+    pub serial: u32,
+    /// The media stream counter at which the frame was shown.
+    pub msc: u64,
+    /// The time in microseconds at which the frame was shown.
+    pub ust: u64,
+    /// How the frame was shown, e.g. by copying or by flipping.
+    pub mode: CompleteMode,
+    /// The contents of the window.
+    pub image: Image<'static>,
+}
+
+/// Wait until `window` presents its next frame and capture it.
+///
+/// The window must be viewable and completely on screen, otherwise `GetImage` fails with a
+/// `Match` error. Frames that the X11 server skipped are not captured.
+///
+/// The application might present another frame before the X11 server processes the `GetImage`
+/// request. In this case, the image shows that later frame and the returned serial number, MSC
+/// and UST are the ones of that frame, so that they always describe what the image shows.
+///
+/// This function waits for events on the connection and discards all events that do not belong
+/// to it. Use a separate connection for capturing, as test harnesses usually do.
+pub fn capture_next_frame<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<CapturedFrame, ReplyOrIdError> {
+    let eid = conn.generate_id()?;
+    let _ = present::select_input(conn, eid, window, present::EventMask::COMPLETE_NOTIFY)?;
+    let result = capture(conn, window, eid);
+    // Deselecting all events also frees the event ID again
+    let deselect = present::select_input(conn, eid, window, present::EventMask::NO_EVENT);
+    let frame = result?;
+    let _ = deselect?;
+    Ok(frame)
+}
+
+fn capture<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    eid: present::Event,
+) -> Result<CapturedFrame, ReplyOrIdError> {
+    conn.flush()?;
+    let mut frame = loop {
+        let (event, _) = conn.wait_for_event_with_sequence()?;
+        if let Some(frame) = presented_frame(&event, eid) {
+            break frame;
+        }
+    };
+
+    let geometry = xproto::get_geometry(conn, window)?.reply()?;
+    let cookie = xproto::get_image(
+        conn,
+        ImageFormat::Z_PIXMAP,
+        window,
+        0,
+        0,
+        geometry.width,
+        geometry.height,
+        !0,
+    )?;
+    let image_sequence = cookie.sequence_number();
+    let reply = cookie.reply()?;
+
+    // Events that the X11 server sent before it processed GetImage were received before its
+    // reply. The last frame among them is the one that the image shows.
+    frame = latest_frame_before(conn, eid, image_sequence)?.unwrap_or(frame);
+
+    Ok(CapturedFrame {
+        serial: frame.serial,
+        msc: frame.msc,
+        ust: frame.ust,
+        mode: frame.mode,
+        image: Image::get_from_reply(conn.setup(), geometry.width, geometry.height, reply)?,
+    })
+}
+
+/// Get the last frame that was presented before the request with the given sequence number.
+///
+/// This only looks at events that were already received.
+fn latest_frame_before<C: Connection + ?Sized>(
+    conn: &C,
+    eid: present::Event,
+    sequence: SequenceNumber,
+) -> Result<Option<CompleteNotifyEvent>, ConnectionError> {
+    let mut latest = None;
+    while let Some((event, event_sequence)) = conn.poll_for_event_with_sequence()? {
+        // An event carries the sequence number of the last request that the X11 server processed
+        if event_sequence >= sequence {
+            break;
+        }
+        if let Some(frame) = presented_frame(&event, eid) {
+            latest = Some(frame);
+        }
+    }
+    Ok(latest)
+}
+
+/// Check whether the event tells that a frame was shown.
+fn presented_frame(event: &Event, eid: present::Event) -> Option<CompleteNotifyEvent> {
+    match event {
+        Event::PresentCompleteNotify(event)
+            if event.event == eid
+                && event.kind == CompleteKind::PIXMAP
+                && event.mode != CompleteMode::SKIP =>
+        {
+            Some(*event)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::capture_next_frame;
+    use crate::connection::Connection;
+    use crate::protocol::present::{self, CompleteKind, CompleteMode};
+    use crate::protocol::xproto::{self, Format, GE_GENERIC_EVENT};
+    use crate::testing::{FakeConnection, Response};
+    use crate::x11_utils::ExtensionInformation;
+
+    const PRESENT_OPCODE: u8 = 148;
+    const WINDOW: u32 = 0x0040_0001;
+
+    fn connection() -> FakeConnection {
+        let mut setup = FakeConnection::default().setup().clone();
+        setup.pixmap_formats.push(Format {
+            depth: 24,
+            bits_per_pixel: 32,
+            scanline_pad: 32,
+        });
+        let conn = FakeConnection::new(setup);
+        conn.add_extension(
+            present::X11_EXTENSION_NAME,
+            ExtensionInformation {
+                major_opcode: PRESENT_OPCODE,
+                first_event: 0,
+                first_error: 0,
+            },
+        );
+        conn
+    }
+
+    fn complete_notify(eid: u32, mode: CompleteMode, serial: u32) -> Vec<u8> {
+        let mut event = vec![0; 40];
+        event[0] = GE_GENERIC_EVENT;
+        event[1] = PRESENT_OPCODE;
+        event[4..8].copy_from_slice(&2u32.to_ne_bytes());
+        event[8..10].copy_from_slice(&present::COMPLETE_NOTIFY_EVENT.to_ne_bytes());
+        event[10] = u8::from(CompleteKind::PIXMAP);
+        event[11] = u8::from(mode);
+        event[12..16].copy_from_slice(&eid.to_ne_bytes());
+        event[16..20].copy_from_slice(&WINDOW.to_ne_bytes());
+        event[20..24].copy_from_slice(&serial.to_ne_bytes());
+        event[24..32].copy_from_slice(&(1000 * u64::from(serial)).to_ne_bytes());
+        event[32..40].copy_from_slice(&u64::from(serial).to_ne_bytes());
+        event
+    }
+
+    fn respond_with_image(conn: &FakeConnection) {
+        let mut geometry = vec![0; 32];
+        geometry[1] = 24;
+        geometry[16..18].copy_from_slice(&2u16.to_ne_bytes());
+        geometry[18..20].copy_from_slice(&1u16.to_ne_bytes());
+        conn.respond(
+            xproto::GET_GEOMETRY_REQUEST,
+            None,
+            Response::Reply(geometry),
+        );
+        let mut image = vec![0; 32];
+        image[1] = 24;
+        image.extend([0xff; 8]);
+        conn.respond(xproto::GET_IMAGE_REQUEST, None, Response::Reply(image));
+    }
+
+    #[test]
+    fn captures_latest_frame() {
+        let conn = connection();
+        let eid = conn.setup().resource_id_base;
+        // Events of other selections and skipped frames are ignored
+        conn.push_raw_event(complete_notify(eid + 1, CompleteMode::COPY, 1));
+        conn.push_raw_event(complete_notify(eid, CompleteMode::SKIP, 2));
+        conn.push_raw_event(complete_notify(eid, CompleteMode::FLIP, 3));
+        // This frame is shown before GetImage is processed
+        conn.push_raw_event(complete_notify(eid, CompleteMode::COPY, 4));
+        respond_with_image(&conn);
+
+        let frame = capture_next_frame(&conn, WINDOW).unwrap();
+        assert_eq!(frame.serial, 4);
+        assert_eq!(frame.msc, 4);
+        assert_eq!(frame.ust, 4000);
+        assert_eq!(frame.mode, CompleteMode::COPY);
+        assert_eq!((frame.image.width(), frame.image.height()), (2, 1));
+        assert_eq!(frame.image.data(), [0xff; 8]);
+
+        let requests = conn.sent_requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].minor_opcode(), present::SELECT_INPUT_REQUEST);
+        assert_eq!(requests[3].minor_opcode(), present::SELECT_INPUT_REQUEST);
+        assert_eq!(requests[3].bytes[12..16], 0u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn image_errors_are_reported() {
+        let conn = connection();
+        let eid = conn.setup().resource_id_base;
+        conn.push_raw_event(complete_notify(eid, CompleteMode::COPY, 1));
+        conn.respond(
+            xproto::GET_GEOMETRY_REQUEST,
+            None,
+            Response::Reply(vec![0; 32]),
+        );
+        conn.respond(
+            xproto::GET_IMAGE_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::MATCH_ERROR,
+                bad_value: 0,
+            },
+        );
+        assert!(capture_next_frame(&conn, WINDOW).is_err());
+        // The selection is removed anyway
+        assert_eq!(conn.sent_requests().len(), 4);
+    }
+}
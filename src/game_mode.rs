@@ -0,0 +1,266 @@
+//! RAII guard for fullscreen games.
+//!
+//! Games usually want the same handful of things while they are running: The compositor should
+//! not redirect the game's window, the screen saver and display power management should not kick
+//! in while the user is only using a gamepad, and the pointer should neither leave the window nor
+//! be visible. [`GameMode`] does all of this and undoes it again when it is dropped.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::game_mode::{GameMode, GameModeOptions};
+//! use x11rb::protocol::xproto::Window;
+//!
+//! fn play(conn: &impl Connection, window: Window) -> Result<(), ReplyOrIdError> {
+//!     let options = GameModeOptions {
+//!         confine_pointer: true,
+//!         hide_pointer: true,
+//!     };
+//!     let game_mode = GameMode::new(conn, window, options)?;
+//!     if !game_mode.is_pointer_confined() {
+//!         println!("Could not grab the pointer");
+//!     }
+//!     // Run the game here; everything is restored when `game_mode` is dropped
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Disabling display power management requires the `dpms` feature and is skipped if the X11
+//! server does not support the DPMS extension.
+
+use std::convert::TryFrom;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+#[cfg(feature = "dpms")]
+use crate::protocol::dpms;
+use crate::protocol::xproto::{
+    self, AtomEnum, ChangeWindowAttributesAux, Cursor, GetScreenSaverReply, GrabMode, GrabStatus,
+    PropMode, Window,
+};
+use crate::CURRENT_TIME;
+use crate::NONE;
+
+/// The things that [`GameMode`] should do besides the ones that it always does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GameModeOptions {
+    /// Grab the pointer so that it cannot leave the window.
+    pub confine_pointer: bool,
+    /// Make the pointer invisible while it is inside the window.
+    pub hide_pointer: bool,
+}
+
+/// Settings for a fullscreen game that are undone when this object is dropped.
+///
+/// Creating a `GameMode`:
+/// - sets `_NET_WM_BYPASS_COMPOSITOR` to 1 on the window, asking the compositor to not redirect
+///   it,
+/// - disables the screen saver via `SetScreenSaver`,
+/// - disables DPMS if it is enabled (requires the `dpms` feature),
+/// - optionally hides the pointer by giving the window an invisible cursor,
+/// - optionally grabs the pointer with the window as `confine_to`.
+///
+/// Note that the screen saver and DPMS settings are global for the X11 server, so they stay
+/// disabled if the program exits without dropping the `GameMode`. The cursor of the window is
+/// reset to `None`, i.e. the cursor of its parent, afterwards.
+#[derive(Debug)]
+pub struct GameMode<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    window: Window,
+    bypass_compositor: xproto::Atom,
+    screen_saver: Option<GetScreenSaverReply>,
+    dpms_was_enabled: bool,
+    invisible_cursor: Option<Cursor>,
+    pointer_confined: bool,
+}
+
+impl<'c, C: Connection + ?Sized> GameMode<'c, C> {
+    /// Enter game mode for the given window.
+    ///
+    /// The pointer can only be grabbed if the window is viewable and nobody else grabbed it. A
+    /// failing grab is not an error; use [`GameMode::is_pointer_confined`] to check for it.
+    pub fn new(
+        conn: &'c C,
+        window: Window,
+        options: GameModeOptions,
+    ) -> Result<Self, ReplyOrIdError> {
+        let bypass_compositor = xproto::intern_atom(conn, false, b"_NET_WM_BYPASS_COMPOSITOR")?;
+        let screen_saver = xproto::get_screen_saver(conn)?;
+        let bypass_compositor = bypass_compositor.reply()?.atom;
+
+        // From here on, dropping `game_mode` undoes everything that was already done
+        let mut game_mode = Self {
+            conn,
+            window,
+            bypass_compositor,
+            screen_saver: None,
+            dpms_was_enabled: false,
+            invisible_cursor: None,
+            pointer_confined: false,
+        };
+
+        let _ = xproto::change_property(
+            conn,
+            PropMode::REPLACE,
+            window,
+            bypass_compositor,
+            AtomEnum::CARDINAL,
+            32,
+            1,
+            &1u32.to_ne_bytes(),
+        )?;
+
+        let screen_saver = screen_saver.reply()?;
+        let _ = xproto::set_screen_saver(
+            conn,
+            0,
+            saturate(screen_saver.interval),
+            screen_saver.prefer_blanking,
+            screen_saver.allow_exposures,
+        )?;
+        game_mode.screen_saver = Some(screen_saver);
+
+        game_mode.dpms_was_enabled = disable_dpms(conn)?;
+
+        if options.hide_pointer {
+            let cursor = create_invisible_cursor(conn, window)?;
+            game_mode.invisible_cursor = Some(cursor);
+            let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+            let _ = xproto::change_window_attributes(conn, window, &aux)?;
+        }
+
+        if options.confine_pointer {
+            let status = xproto::grab_pointer(
+                conn,
+                true,
+                window,
+                0u16,
+                GrabMode::ASYNC,
+                GrabMode::ASYNC,
+                window,
+                game_mode.invisible_cursor.unwrap_or(NONE),
+                CURRENT_TIME,
+            )?
+            .reply()?
+            .status;
+            game_mode.pointer_confined = status == GrabStatus::SUCCESS;
+        }
+
+        Ok(game_mode)
+    }
+
+    /// Get the window that is in game mode.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Check if the pointer was successfully grabbed.
+    ///
+    /// This is always `false` if [`GameModeOptions::confine_pointer`] was not set.
+    pub fn is_pointer_confined(&self) -> bool {
+        self.pointer_confined
+    }
+
+    /// Leave game mode and report any errors.
+    ///
+    /// This is the same as dropping this object, except that errors are not ignored.
+    pub fn restore(mut self) -> Result<(), ConnectionError> {
+        let result = self.do_restore();
+        std::mem::forget(self);
+        result
+    }
+
+    fn do_restore(&mut self) -> Result<(), ConnectionError> {
+        let conn = self.conn;
+        if self.pointer_confined {
+            let _ = xproto::ungrab_pointer(conn, CURRENT_TIME)?;
+        }
+        if let Some(cursor) = self.invisible_cursor.take() {
+            let aux = ChangeWindowAttributesAux::new().cursor(NONE);
+            let _ = xproto::change_window_attributes(conn, self.window, &aux)?;
+            let _ = xproto::free_cursor(conn, cursor)?;
+        }
+        if self.dpms_was_enabled {
+            enable_dpms(conn)?;
+        }
+        if let Some(screen_saver) = self.screen_saver.take() {
+            let _ = xproto::set_screen_saver(
+                conn,
+                saturate(screen_saver.timeout),
+                saturate(screen_saver.interval),
+                screen_saver.prefer_blanking,
+                screen_saver.allow_exposures,
+            )?;
+        }
+        let _ = xproto::delete_property(conn, self.window, self.bypass_compositor)?;
+        Ok(())
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for GameMode<'_, C> {
+    fn drop(&mut self) {
+        let _ = self.do_restore();
+    }
+}
+
+/// `GetScreenSaver` replies with unsigned values, but `SetScreenSaver` takes signed ones.
+fn saturate(value: u16) -> i16 {
+    i16::try_from(value).unwrap_or(i16::MAX)
+}
+
+/// Create a cursor that has no visible pixels.
+pub(crate) fn create_invisible_cursor<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Cursor, ReplyOrIdError> {
+    let pixmap = conn.generate_id()?;
+    let cursor = conn.generate_id()?;
+    // The content of the pixmap does not matter, since it is also used as the mask
+    let _ = xproto::create_pixmap(conn, 1, pixmap, window, 1, 1)?;
+    let _ = xproto::create_cursor(conn, cursor, pixmap, pixmap, 0, 0, 0, 0, 0, 0, 0, 0)?;
+    let _ = xproto::free_pixmap(conn, pixmap)?;
+    Ok(cursor)
+}
+
+/// Disable DPMS and return whether it was enabled before.
+#[cfg(feature = "dpms")]
+fn disable_dpms<C: Connection + ?Sized>(conn: &C) -> Result<bool, ReplyOrIdError> {
+    if conn
+        .extension_information(dpms::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    let enabled = dpms::info(conn)?.reply()?.state;
+    if enabled {
+        let _ = dpms::disable(conn)?;
+    }
+    Ok(enabled)
+}
+
+#[cfg(not(feature = "dpms"))]
+fn disable_dpms<C: Connection + ?Sized>(_conn: &C) -> Result<bool, ReplyOrIdError> {
+    Ok(false)
+}
+
+#[cfg(feature = "dpms")]
+fn enable_dpms<C: Connection + ?Sized>(conn: &C) -> Result<(), ConnectionError> {
+    dpms::enable(conn).map(|_| ())
+}
+
+#[cfg(not(feature = "dpms"))]
+fn enable_dpms<C: Connection + ?Sized>(_conn: &C) -> Result<(), ConnectionError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::saturate;
+
+    #[test]
+    fn saturate_screen_saver_values() {
+        assert_eq!(saturate(0), 0);
+        assert_eq!(saturate(600), 600);
+        assert_eq!(saturate(u16::MAX), i16::MAX);
+    }
+}
@@ -0,0 +1,395 @@
+//! RAII guards for grabs.
+//!
+//! Forgetting to release a grab has unpleasant consequences: While the server is grabbed, no other
+//! client is served, so the whole session freezes. A forgotten pointer or keyboard grab keeps all
+//! input away from other clients. The guards in this module send the grab request when they are
+//! created and the corresponding ungrab request when they are dropped, also on early returns and
+//! during panics.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::grab::GrabServerGuard;
+//! use x11rb::protocol::xproto::{ConfigureWindowAux, ConnectionExt as _, Window};
+//!
+//! fn move_together(conn: &impl Connection, windows: &[Window]) -> Result<(), ConnectionError> {
+//!     let _guard = GrabServerGuard::new(conn)?;
+//!     for (i, &window) in windows.iter().enumerate() {
+//!         let aux = ConfigureWindowAux::new().x(100 * i as i32);
+//!         conn.configure_window(window, &aux)?;
+//!     }
+//!     // The server is ungrabbed when `_guard` is dropped, also if an error is returned above
+//!     Ok(())
+//! }
+//! ```
+//!
+//! By default, errors of the ungrab request are ignored. With `check_ungrab_with()`, the ungrab
+//! request is checked when the guard is dropped and errors are passed to a callback. This waits
+//! for the X11 server to process the request. The underlying mechanism is available for other
+//! requests as [`RequestOnDrop`].
+
+use std::fmt;
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, GrabKeyboardRequest, GrabPointerRequest, GrabStatus, Window};
+use crate::CURRENT_TIME;
+
+/// A callback that receives errors of a request that was sent on drop.
+pub type ErrorHandler<'c> = Box<dyn FnOnce(ReplyError) + 'c>;
+
+type SendRequest<'c, C> = Box<dyn FnOnce(&'c C) -> Result<VoidCookie<'c, C>, ConnectionError> + 'c>;
+
+/// Send a request when this object is dropped.
+///
+/// This is the building block of the grab guards in this module, but it can also be used for
+/// other requests that have to be sent reliably, e.g. to free a resource.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyOrIdError;
+/// use x11rb::grab::RequestOnDrop;
+/// use x11rb::protocol::xproto::{self, ConnectionExt as _};
+///
+/// fn draw(conn: &impl Connection, screen_num: usize) -> Result<(), ReplyOrIdError> {
+///     let screen = &conn.setup().roots[screen_num];
+///     let pixmap = conn.generate_id()?;
+///     conn.create_pixmap(screen.root_depth, pixmap, screen.root, 10, 10)?;
+///     let _free = RequestOnDrop::new(conn, move |conn| xproto::free_pixmap(conn, pixmap))
+///         .check_with(|err| eprintln!("Could not free the pixmap: {:?}", err));
+///     // Draw to the pixmap...
+///     Ok(())
+/// }
+/// ```
+pub struct RequestOnDrop<'c, C: RequestConnection + ?Sized> {
+    conn: &'c C,
+    request: Option<SendRequest<'c, C>>,
+    error_handler: Option<ErrorHandler<'c>>,
+}
+
+impl<'c, C: RequestConnection + ?Sized> RequestOnDrop<'c, C> {
+    /// Send the request produced by `request` when the returned object is dropped.
+    pub fn new<F>(conn: &'c C, request: F) -> Self
+    where
+        F: FnOnce(&'c C) -> Result<VoidCookie<'c, C>, ConnectionError> + 'c,
+    {
+        Self {
+            conn,
+            request: Some(Box::new(request)),
+            error_handler: None,
+        }
+    }
+
+    /// Check the request when it is sent on drop and pass any error to the given function.
+    ///
+    /// Checking the request waits for the X11 server to process it. Without this, errors are
+    /// handled like for any other request whose [`VoidCookie`] is dropped: Depending on the
+    /// connection, they are ignored or show up as events.
+    pub fn check_with<F>(mut self, error_handler: F) -> Self
+    where
+        F: FnOnce(ReplyError) + 'c,
+    {
+        self.error_handler = Some(Box::new(error_handler));
+        self
+    }
+
+    /// Get the connection that the request is sent to.
+    pub fn connection(&self) -> &'c C {
+        self.conn
+    }
+
+    /// Send the request now instead of on drop.
+    ///
+    /// The error handler set with [`RequestOnDrop::check_with`] is not used. Instead, the cookie
+    /// of the request is returned.
+    pub fn send(mut self) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let request = self.request.take().expect("the request is only taken once");
+        request(self.conn)
+    }
+
+    /// Do not send the request.
+    pub fn cancel(mut self) {
+        self.request = None;
+    }
+}
+
+impl<C: RequestConnection + ?Sized> Drop for RequestOnDrop<'_, C> {
+    fn drop(&mut self) {
+        let request = match self.request.take() {
+            Some(request) => request,
+            None => return,
+        };
+        let cookie = request(self.conn);
+        if let Some(error_handler) = self.error_handler.take() {
+            if let Err(err) = cookie.map_err(ReplyError::from).and_then(VoidCookie::check) {
+                error_handler(err);
+            }
+        }
+    }
+}
+
+impl<C: RequestConnection + ?Sized> fmt::Debug for RequestOnDrop<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestOnDrop")
+            .field("pending", &self.request.is_some())
+            .field("checked", &self.error_handler.is_some())
+            .finish()
+    }
+}
+
+/// An error that occurred while grabbing the pointer or the keyboard.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GrabError {
+    /// Sending the grab request or receiving its reply failed.
+    Reply(ReplyError),
+    /// The X11 server refused the grab, e.g. because another client already grabbed the device.
+    Refused(GrabStatus),
+}
+
+impl std::error::Error for GrabError {}
+
+impl fmt::Display for GrabError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GrabError::Reply(err) => err.fmt(f),
+            GrabError::Refused(status) => write!(f, "The grab failed with status {:?}", status),
+        }
+    }
+}
+
+impl From<ReplyError> for GrabError {
+    fn from(err: ReplyError) -> Self {
+        GrabError::Reply(err)
+    }
+}
+
+impl From<ConnectionError> for GrabError {
+    fn from(err: ConnectionError) -> Self {
+        GrabError::Reply(err.into())
+    }
+}
+
+/// Check the status of a `GrabPointer` or `GrabKeyboard` reply.
+fn check_status(status: GrabStatus) -> Result<(), GrabError> {
+    if status == GrabStatus::SUCCESS {
+        Ok(())
+    } else {
+        Err(GrabError::Refused(status))
+    }
+}
+
+/// A server grab that is released when this object is dropped.
+#[derive(Debug)]
+pub struct GrabServerGuard<'c, C: RequestConnection + ?Sized>(RequestOnDrop<'c, C>);
+
+impl<'c, C: RequestConnection + ?Sized> GrabServerGuard<'c, C> {
+    /// Grab the server via `GrabServer`.
+    ///
+    /// `UngrabServer` is sent when the returned guard is dropped.
+    pub fn new(conn: &'c C) -> Result<Self, ConnectionError> {
+        let _ = xproto::grab_server(conn)?;
+        Ok(Self(RequestOnDrop::new(conn, xproto::ungrab_server)))
+    }
+
+    /// Check the `UngrabServer` request that is sent on drop and pass errors to the given function.
+    pub fn check_ungrab_with<F>(self, error_handler: F) -> Self
+    where
+        F: FnOnce(ReplyError) + 'c,
+    {
+        Self(self.0.check_with(error_handler))
+    }
+
+    /// Ungrab the server now.
+    pub fn ungrab(self) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.0.send()
+    }
+}
+
+/// A pointer grab that is released when this object is dropped.
+#[derive(Debug)]
+pub struct GrabPointerGuard<'c, C: RequestConnection + ?Sized> {
+    ungrab: RequestOnDrop<'c, C>,
+    grab_window: Window,
+}
+
+impl<'c, C: RequestConnection + ?Sized> GrabPointerGuard<'c, C> {
+    /// Grab the pointer by sending the given `GrabPointer` request.
+    ///
+    /// This waits for the reply. If the grab failed, e.g. because another client grabbed the
+    /// pointer, [`GrabError::Refused`] is returned. Otherwise, `UngrabPointer` is sent when the
+    /// returned guard is dropped.
+    pub fn new(conn: &'c C, request: GrabPointerRequest) -> Result<Self, GrabError> {
+        let grab_window = request.grab_window;
+        check_status(request.send(conn)?.reply()?.status)?;
+        let ungrab = RequestOnDrop::new(conn, |conn| xproto::ungrab_pointer(conn, CURRENT_TIME));
+        Ok(Self {
+            ungrab,
+            grab_window,
+        })
+    }
+
+    /// Get the window that the pointer is grabbed for.
+    pub fn grab_window(&self) -> Window {
+        self.grab_window
+    }
+
+    /// Check the `UngrabPointer` request that is sent on drop and pass errors to the given
+    /// function.
+    pub fn check_ungrab_with<F>(mut self, error_handler: F) -> Self
+    where
+        F: FnOnce(ReplyError) + 'c,
+    {
+        self.ungrab = self.ungrab.check_with(error_handler);
+        self
+    }
+
+    /// Ungrab the pointer now.
+    pub fn ungrab(self) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.ungrab.send()
+    }
+}
+
+/// A keyboard grab that is released when this object is dropped.
+#[derive(Debug)]
+pub struct GrabKeyboardGuard<'c, C: RequestConnection + ?Sized> {
+    ungrab: RequestOnDrop<'c, C>,
+    grab_window: Window,
+}
+
+impl<'c, C: RequestConnection + ?Sized> GrabKeyboardGuard<'c, C> {
+    /// Grab the keyboard by sending the given `GrabKeyboard` request.
+    ///
+    /// This waits for the reply. If the grab failed, e.g. because another client grabbed the
+    /// keyboard, [`GrabError::Refused`] is returned. Otherwise, `UngrabKeyboard` is sent when the
+    /// returned guard is dropped.
+    pub fn new(conn: &'c C, request: GrabKeyboardRequest) -> Result<Self, GrabError> {
+        let grab_window = request.grab_window;
+        check_status(request.send(conn)?.reply()?.status)?;
+        let ungrab = RequestOnDrop::new(conn, |conn| xproto::ungrab_keyboard(conn, CURRENT_TIME));
+        Ok(Self {
+            ungrab,
+            grab_window,
+        })
+    }
+
+    /// Get the window that the keyboard is grabbed for.
+    pub fn grab_window(&self) -> Window {
+        self.grab_window
+    }
+
+    /// Check the `UngrabKeyboard` request that is sent on drop and pass errors to the given
+    /// function.
+    pub fn check_ungrab_with<F>(mut self, error_handler: F) -> Self
+    where
+        F: FnOnce(ReplyError) + 'c,
+    {
+        self.ungrab = self.ungrab.check_with(error_handler);
+        self
+    }
+
+    /// Ungrab the keyboard now.
+    pub fn ungrab(self) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.ungrab.send()
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use std::cell::RefCell;
+
+    use super::{GrabError, GrabPointerGuard, GrabServerGuard};
+    use crate::errors::{ConnectionError, ReplyError};
+    use crate::protocol::xproto::{self, GrabMode, GrabPointerRequest, GrabStatus};
+    use crate::testing::{FakeConnection, Response};
+
+    fn opcodes(conn: &FakeConnection) -> Vec<u8> {
+        conn.take_sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect()
+    }
+
+    fn grab_pointer_request() -> GrabPointerRequest {
+        GrabPointerRequest {
+            owner_events: false,
+            grab_window: 1,
+            event_mask: 0,
+            pointer_mode: GrabMode::ASYNC,
+            keyboard_mode: GrabMode::ASYNC,
+            confine_to: 0,
+            cursor: 0,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn ungrab_server_on_drop() {
+        let conn = FakeConnection::default();
+        let result = (|| {
+            let _guard = GrabServerGuard::new(&conn)?;
+            assert_eq!(opcodes(&conn), [xproto::GRAB_SERVER_REQUEST]);
+            // An early return drops the guard
+            Err::<(), _>(ConnectionError::UnknownError)
+        })();
+        assert!(result.is_err());
+        assert_eq!(opcodes(&conn), [xproto::UNGRAB_SERVER_REQUEST]);
+    }
+
+    #[test]
+    fn checked_ungrab_reports_errors() {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::UNGRAB_SERVER_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::REQUEST_ERROR,
+                bad_value: 0,
+            },
+        );
+        let errors = RefCell::new(Vec::new());
+        let guard = GrabServerGuard::new(&conn)
+            .unwrap()
+            .check_ungrab_with(|err| errors.borrow_mut().push(err));
+        drop(guard);
+        let errors = errors.into_inner();
+        match &errors[..] {
+            [ReplyError::X11Error(err)] => {
+                assert_eq!(err.major_opcode, xproto::UNGRAB_SERVER_REQUEST)
+            }
+            errors => panic!("Unexpected errors {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn refused_pointer_grab() {
+        let conn = FakeConnection::default();
+        let mut reply = vec![0; 32];
+        reply[1] = GrabStatus::ALREADY_GRABBED.into();
+        conn.respond(xproto::GRAB_POINTER_REQUEST, None, Response::Reply(reply));
+        match GrabPointerGuard::new(&conn, grab_pointer_request()) {
+            Err(GrabError::Refused(GrabStatus::ALREADY_GRABBED)) => {}
+            result => panic!("Unexpected result {:?}", result.map(|_| ())),
+        }
+        // No UngrabPointer is sent for a failed grab
+        assert_eq!(opcodes(&conn), [xproto::GRAB_POINTER_REQUEST]);
+    }
+
+    #[test]
+    fn explicit_pointer_ungrab() {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::GRAB_POINTER_REQUEST,
+            None,
+            Response::Reply(vec![0; 32]),
+        );
+        let guard = GrabPointerGuard::new(&conn, grab_pointer_request()).unwrap();
+        assert_eq!(guard.grab_window(), 1);
+        guard.ungrab().unwrap().check().unwrap();
+        assert_eq!(
+            opcodes(&conn),
+            [xproto::GRAB_POINTER_REQUEST, xproto::UNGRAB_POINTER_REQUEST]
+        );
+    }
+}
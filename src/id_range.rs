@@ -0,0 +1,218 @@
+//! Reserving blocks of X11 IDs for code that does not use [`Connection::generate_id`].
+//!
+//! All resources that a client creates share the range of IDs that the X11 server assigned to
+//! the connection. When a helper library sends requests on the same connection, e.g. through the
+//! C ABI of `x11rb-capi`, but allocates IDs itself, its IDs can collide with the ones that x11rb
+//! hands out. [`IdRange::reserve`] avoids this by taking a block of IDs from the connection up
+//! front. The block is described by its first ID, the distance between IDs, and its length, so it
+//! can easily be passed to the helper, which then only allocates IDs from this block.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::id_range::IdRange;
+//!
+//! fn start_helper(conn: &impl Connection) -> Result<(), ReplyOrIdError> {
+//!     let mut ids = IdRange::reserve(conn, 1024)?;
+//!     // Give half of the IDs to another helper
+//!     let other = ids.split_off(512).expect("the range has enough IDs");
+//!     println!("Helper IDs: {:#x} + n * {}, n < {}", ids.start(), ids.step(), ids.len());
+//!     println!("Other IDs: {:#x} + n * {}, n < {}", other.start(), other.step(), other.len());
+//!     let window = ids.generate_id()?;
+//!     # let _ = window;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! IDs are not returned to the connection when an [`IdRange`] is dropped.
+
+use crate::connection::Connection;
+use crate::errors::ReplyOrIdError;
+
+/// A block of X11 IDs of the form `start + n * step` for `n < len`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    start: u32,
+    step: u32,
+    len: u32,
+    /// The number of IDs that were handed out via [`IdRange::generate_id`].
+    allocated: u32,
+}
+
+impl IdRange {
+    /// Reserve a block of `count` IDs from the connection.
+    ///
+    /// The IDs are taken via [`Connection::generate_id`], so they are never handed out by the
+    /// connection again. The connection usually generates consecutive IDs. When it has to switch
+    /// to a new range of IDs from the X11 server in between, the IDs before the switch cannot be
+    /// part of the block and are lost.
+    ///
+    /// Returns [`ReplyOrIdError::IdsExhausted`] if the connection does not have enough IDs left.
+    pub fn reserve<C: Connection + ?Sized>(conn: &C, count: u32) -> Result<Self, ReplyOrIdError> {
+        let mask = conn.setup().resource_id_mask;
+        // The lowest set bit of the mask is the distance between IDs
+        let step = mask & mask.wrapping_neg();
+        reserve_with(step, count, || conn.generate_id())
+    }
+
+    /// Describe a block of IDs that was reserved elsewhere.
+    ///
+    /// This is useful for receiving a block from another part of the program. None of the IDs
+    /// are considered to be allocated yet.
+    pub fn from_parts(start: u32, step: u32, len: u32) -> Self {
+        Self {
+            start,
+            step,
+            len,
+            allocated: 0,
+        }
+    }
+
+    /// Get the first ID of the block.
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Get the distance between two IDs of the block.
+    pub fn step(&self) -> u32 {
+        self.step
+    }
+
+    /// Get the number of IDs in the block, including the allocated ones.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Is the block empty?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the number of IDs that were already allocated.
+    pub fn allocated(&self) -> u32 {
+        self.allocated
+    }
+
+    /// Get the number of IDs that can still be allocated.
+    pub fn remaining(&self) -> u32 {
+        self.len - self.allocated
+    }
+
+    /// Check whether the given ID belongs to this block.
+    pub fn contains(&self, id: u32) -> bool {
+        match id.checked_sub(self.start) {
+            Some(offset) if self.step != 0 => {
+                offset % self.step == 0 && offset / self.step < self.len
+            }
+            Some(offset) => offset == 0 && self.len != 0,
+            None => false,
+        }
+    }
+
+    /// Allocate the next ID of the block.
+    ///
+    /// Returns [`ReplyOrIdError::IdsExhausted`] when all IDs were allocated.
+    pub fn generate_id(&mut self) -> Result<u32, ReplyOrIdError> {
+        if self.allocated == self.len {
+            return Err(ReplyOrIdError::IdsExhausted);
+        }
+        let id = self.start + self.allocated * self.step;
+        self.allocated += 1;
+        Ok(id)
+    }
+
+    /// Split off the last `count` IDs of the block into a new block.
+    ///
+    /// This allows partitioning a block further, e.g. for nested helpers. Returns `None` if fewer
+    /// than `count` IDs are still available.
+    pub fn split_off(&mut self, count: u32) -> Option<IdRange> {
+        if count > self.remaining() {
+            return None;
+        }
+        self.len -= count;
+        Some(IdRange::from_parts(
+            self.start + self.len * self.step,
+            self.step,
+            count,
+        ))
+    }
+}
+
+/// Collect `count` consecutive IDs that are `step` apart from `generate_id`.
+fn reserve_with<F>(step: u32, count: u32, mut generate_id: F) -> Result<IdRange, ReplyOrIdError>
+where
+    F: FnMut() -> Result<u32, ReplyOrIdError>,
+{
+    if count == 0 {
+        return Ok(IdRange::from_parts(0, step, 0));
+    }
+    let mut start = generate_id()?;
+    let mut last = start;
+    let mut len = 1;
+    while len < count {
+        let id = generate_id()?;
+        if Some(id) == last.checked_add(step) {
+            len += 1;
+        } else {
+            // The IDs are not consecutive, so start a new block
+            start = id;
+            len = 1;
+        }
+        last = id;
+    }
+    Ok(IdRange::from_parts(start, step, count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{reserve_with, IdRange};
+    use crate::errors::ReplyOrIdError;
+
+    #[test]
+    fn allocate_and_split() {
+        let mut range = IdRange::from_parts(0x400, 2, 4);
+        assert_eq!(range.generate_id().unwrap(), 0x400);
+        let other = range.split_off(2).unwrap();
+        assert_eq!(other, IdRange::from_parts(0x404, 2, 2));
+        assert!(range.split_off(2).is_none());
+        assert_eq!(
+            (range.len(), range.allocated(), range.remaining()),
+            (2, 1, 1)
+        );
+
+        assert_eq!(range.generate_id().unwrap(), 0x402);
+        assert!(matches!(
+            range.generate_id(),
+            Err(ReplyOrIdError::IdsExhausted)
+        ));
+        assert!(range.contains(0x402));
+        assert!(!range.contains(0x401));
+        assert!(!range.contains(0x404));
+        assert!(other.contains(0x406));
+    }
+
+    #[test]
+    fn reserve_skips_gaps() {
+        let mut ids = vec![0x10, 0x11, 0x50, 0x51, 0x52].into_iter();
+        let range = reserve_with(1, 3, || ids.next().ok_or(ReplyOrIdError::IdsExhausted)).unwrap();
+        assert_eq!(range, IdRange::from_parts(0x50, 1, 3));
+
+        let mut ids = vec![0x10, 0x11].into_iter();
+        let result = reserve_with(1, 3, || ids.next().ok_or(ReplyOrIdError::IdsExhausted));
+        assert!(matches!(result, Err(ReplyOrIdError::IdsExhausted)));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn reserve_from_connection() {
+        use crate::connection::Connection;
+        use crate::testing::FakeConnection;
+
+        let conn = FakeConnection::default();
+        let range = IdRange::reserve(&conn, 100).unwrap();
+        assert_eq!(range.start(), conn.setup().resource_id_base);
+        assert_eq!(range.step(), 1);
+        // The connection does not hand out reserved IDs again
+        assert_eq!(conn.generate_id().unwrap(), range.start() + 100);
+    }
+}
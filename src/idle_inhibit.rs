@@ -0,0 +1,249 @@
+//! RAII guard that keeps the screen saver away, e.g. during media playback.
+//!
+//! Video players and presentation tools have to prevent the screen saver from activating while
+//! the user only watches. The MIT-SCREEN-SAVER extension offers `Suspend` for this, but not every
+//! X11 server supports it. [`IdleInhibitor`] uses `Suspend` when possible and otherwise falls back
+//! to periodically resetting the idle timer. Callers drive both the same way: They call
+//! [`IdleInhibitor::keep_alive`] whenever [`IdleInhibitor::timeout`] expires.
+//!
+//! ```
+//! use std::time::Instant;
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::idle_inhibit::IdleInhibitor;
+//!
+//! fn play(conn: &impl Connection, mut next_frame: impl FnMut() -> bool) -> Result<(), ReplyError> {
+//!     let mut inhibitor = IdleInhibitor::new(conn)?;
+//!     println!("Keeping the screen on via {:?}", inhibitor.mechanism());
+//!     while next_frame() {
+//!         // Resets the idle timer if the timeout from `inhibitor.timeout()` expired
+//!         inhibitor.keep_alive(Instant::now())?;
+//!     }
+//!     // The screen saver works normally again once `inhibitor` is dropped
+//!     Ok(())
+//! }
+//! ```
+//!
+//! `Suspend` requires the `screensaver` feature and faking input requires the `xtest` feature.
+
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+#[cfg(feature = "screensaver")]
+use crate::protocol::screensaver;
+use crate::protocol::xproto::{self, ScreenSaver};
+#[cfg(feature = "xtest")]
+use crate::protocol::xtest;
+
+/// The longest time between two resets of the idle timer.
+const MAX_RESET_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How [`IdleInhibitor`] keeps the screen saver from activating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InhibitMechanism {
+    /// The screen saver is suspended via the `Suspend` request of the MIT-SCREEN-SAVER extension.
+    /// This also suspends DPMS.
+    Suspend,
+    /// A pointer motion by zero pixels is faked periodically via the XTEST extension. This
+    /// resets all idle timers of the X11 server, including the one of DPMS.
+    FakeMotion,
+    /// The screen saver is reset periodically via `ForceScreenSaver`. This does not affect DPMS
+    /// on all X11 servers.
+    ResetScreenSaver,
+}
+
+/// Keeps the screen saver from activating until this object is dropped.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct IdleInhibitor<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    mechanism: InhibitMechanism,
+    interval: Duration,
+    last_reset: Option<Instant>,
+}
+
+impl<'c, C: Connection + ?Sized> IdleInhibitor<'c, C> {
+    /// Start keeping the screen saver away with the best available mechanism.
+    ///
+    /// The mechanisms are tried in the order of [`InhibitMechanism`].
+    pub fn new(conn: &'c C) -> Result<Self, ReplyError> {
+        let mechanism = if suspend_supported(conn)? {
+            InhibitMechanism::Suspend
+        } else if fake_motion_supported(conn)? {
+            InhibitMechanism::FakeMotion
+        } else {
+            InhibitMechanism::ResetScreenSaver
+        };
+        Self::with_mechanism(conn, mechanism)
+    }
+
+    /// Start keeping the screen saver away with the given mechanism.
+    ///
+    /// The caller has to make sure that the X11 server supports the mechanism.
+    pub fn with_mechanism(conn: &'c C, mechanism: InhibitMechanism) -> Result<Self, ReplyError> {
+        let timeout = xproto::get_screen_saver(conn)?.reply()?.timeout;
+        let mut inhibitor = Self {
+            conn,
+            mechanism,
+            interval: reset_interval(timeout),
+            last_reset: None,
+        };
+        inhibitor.keep_alive(Instant::now())?;
+        Ok(inhibitor)
+    }
+
+    /// Get the mechanism that is used.
+    pub fn mechanism(&self) -> InhibitMechanism {
+        self.mechanism
+    }
+
+    /// Get the time until [`IdleInhibitor::keep_alive`] has to be called again.
+    ///
+    /// Returns `None` if the mechanism does not need to be refreshed.
+    pub fn timeout(&self, now: Instant) -> Option<Duration> {
+        if self.mechanism == InhibitMechanism::Suspend {
+            return None;
+        }
+        let next = self.last_reset? + self.interval;
+        Some(next.saturating_duration_since(now))
+    }
+
+    /// Reset the idle timer if necessary.
+    ///
+    /// This does nothing if the timeout did not expire yet. It is fine to call this function
+    /// more often than necessary, e.g. on every event.
+    pub fn keep_alive(&mut self, now: Instant) -> Result<(), ConnectionError> {
+        let due = match self.last_reset {
+            None => true,
+            Some(_) => self.timeout(now) == Some(Duration::from_secs(0)),
+        };
+        if !due {
+            return Ok(());
+        }
+        match self.mechanism {
+            InhibitMechanism::Suspend => suspend(self.conn, true)?,
+            InhibitMechanism::FakeMotion => fake_motion(self.conn)?,
+            InhibitMechanism::ResetScreenSaver => {
+                let _ = xproto::force_screen_saver(self.conn, ScreenSaver::RESET)?;
+            }
+        }
+        self.last_reset = Some(now);
+        self.conn.flush()
+    }
+
+    /// Stop keeping the screen saver away and report any errors.
+    ///
+    /// This is the same as dropping this object, except that errors are not ignored.
+    pub fn release(self) -> Result<(), ConnectionError> {
+        let result = self.do_release();
+        std::mem::forget(self);
+        result
+    }
+
+    fn do_release(&self) -> Result<(), ConnectionError> {
+        if self.mechanism == InhibitMechanism::Suspend {
+            suspend(self.conn, false)?;
+            self.conn.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for IdleInhibitor<'_, C> {
+    fn drop(&mut self) {
+        let _ = self.do_release();
+    }
+}
+
+/// Get how often the idle timer has to be reset for the given screen saver timeout in seconds.
+fn reset_interval(timeout: u16) -> Duration {
+    // A disabled screen saver does not disable other idle timers, e.g. the one of DPMS
+    let half_timeout = Duration::from_secs(u64::from(timeout)) / 2;
+    if timeout == 0 {
+        MAX_RESET_INTERVAL
+    } else {
+        half_timeout
+            .max(Duration::from_secs(1))
+            .min(MAX_RESET_INTERVAL)
+    }
+}
+
+#[cfg(feature = "screensaver")]
+fn suspend_supported<C: Connection + ?Sized>(conn: &C) -> Result<bool, ReplyError> {
+    if conn
+        .extension_information(screensaver::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    // Suspend was added in version 1.1
+    let version = screensaver::query_version(conn, 1, 1)?.reply()?;
+    Ok((version.server_major_version, version.server_minor_version) >= (1, 1))
+}
+
+#[cfg(not(feature = "screensaver"))]
+fn suspend_supported<C: Connection + ?Sized>(_conn: &C) -> Result<bool, ReplyError> {
+    Ok(false)
+}
+
+#[cfg(feature = "screensaver")]
+fn suspend<C: Connection + ?Sized>(conn: &C, suspend: bool) -> Result<(), ConnectionError> {
+    screensaver::suspend(conn, u32::from(suspend)).map(|_| ())
+}
+
+#[cfg(not(feature = "screensaver"))]
+fn suspend<C: Connection + ?Sized>(_conn: &C, _suspend: bool) -> Result<(), ConnectionError> {
+    Err(ConnectionError::UnsupportedExtension)
+}
+
+#[cfg(feature = "xtest")]
+fn fake_motion_supported<C: Connection + ?Sized>(conn: &C) -> Result<bool, ReplyError> {
+    Ok(conn
+        .extension_information(xtest::X11_EXTENSION_NAME)?
+        .is_some())
+}
+
+#[cfg(not(feature = "xtest"))]
+fn fake_motion_supported<C: Connection + ?Sized>(_conn: &C) -> Result<bool, ReplyError> {
+    Ok(false)
+}
+
+#[cfg(feature = "xtest")]
+fn fake_motion<C: Connection + ?Sized>(conn: &C) -> Result<(), ConnectionError> {
+    // A relative motion (detail 1) by zero pixels counts as activity, but does not move anything
+    xtest::fake_input(
+        conn,
+        xproto::MOTION_NOTIFY_EVENT,
+        1,
+        crate::CURRENT_TIME,
+        crate::NONE,
+        0,
+        0,
+        0,
+    )
+    .map(|_| ())
+}
+
+#[cfg(not(feature = "xtest"))]
+fn fake_motion<C: Connection + ?Sized>(_conn: &C) -> Result<(), ConnectionError> {
+    Err(ConnectionError::UnsupportedExtension)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::reset_interval;
+
+    #[test]
+    fn interval_from_timeout() {
+        let secs = Duration::from_secs;
+        assert_eq!(reset_interval(0), secs(30));
+        assert_eq!(reset_interval(1), secs(1));
+        assert_eq!(reset_interval(20), secs(10));
+        assert_eq!(reset_interval(600), secs(30));
+    }
+}
@@ -10,6 +10,9 @@
 //! content of the image, but only the way that it is laid out in memory (e.g. byte order and
 //! padding). Specifically, there is no support for converting an image to another `depth`.
 //!
+//! [`ConversionTables`] convert colors with 8 bits per component into formats with fewer bits,
+//! like those of 16 bit visuals, with gamma-correct rounding and optional [`Dither`]ing.
+//!
 //! The code in this module is only available when the `image` feature of the library is
 //! enabled.
 
@@ -31,9 +34,10 @@ use crate::connection::Connection;
 use crate::cookie::VoidCookie;
 use crate::errors::{ConnectionError, ParseError, ReplyError};
 use crate::protocol::xproto::{
-    Drawable, Format, Gcontext, GetImageReply, GetImageRequest, ImageFormat,
-    ImageOrder as XprotoImageOrder, PutImageRequest, Setup, VisualClass, Visualtype,
+    Drawable, Format, Gcontext, GetGeometryRequest, GetImageReply, GetImageRequest, ImageFormat,
+    ImageOrder as XprotoImageOrder, Rectangle, Setup, VisualClass, Visualid, Visualtype, Window,
 };
+use crate::wrapper::ConnectionExt as _;
 
 /// The description of a single color component.
 ///
@@ -173,6 +177,21 @@ impl PixelLayout {
         Self { red, green, blue }
     }
 
+    /// Get the red color component.
+    pub fn red(self) -> ColorComponent {
+        self.red
+    }
+
+    /// Get the green color component.
+    pub fn green(self) -> ColorComponent {
+        self.green
+    }
+
+    /// Get the blue color component.
+    pub fn blue(self) -> ColorComponent {
+        self.blue
+    }
+
     /// Create a new pixel layout
     ///
     /// This function errors if the visual has a different class than `TrueColor` or `DirectColor`,
@@ -239,6 +258,579 @@ impl PixelLayout {
     }
 }
 
+// Construct a color component in a constant. The values must satisfy the conditions of
+// `ColorComponent::new`.
+const fn component(width: u8, shift: u8) -> ColorComponent {
+    ColorComponent { width, shift }
+}
+
+// Compute a four character code as used by DRM, e.g. with DRI3.
+const fn fourcc_code(code: &[u8; 4]) -> u32 {
+    (code[0] as u32) | (code[1] as u32) << 8 | (code[2] as u32) << 16 | (code[3] as u32) << 24
+}
+
+/// A complete description of how a pixel is stored.
+///
+/// In addition to the [`PixelLayout`] of the color components, this describes an optional alpha
+/// channel and the number of bits that each pixel occupies in memory. Common formats are available
+/// as constants, which are named after the components from the most significant to the least
+/// significant bit of a pixel value, like the DRM formats of the Linux kernel. For example, the
+/// pixel values of [`PixelFormat::ARGB8888`] look like `0xAARRGGBB`.
+///
+/// ```
+/// # use x11rb::image::PixelFormat;
+/// let format = PixelFormat::from_masks(0xff00_0000, 0xff_0000, 0xff00, 0xff, 32)?;
+/// assert_eq!(format, PixelFormat::RGBA8888);
+/// assert_eq!(format.fourcc(), Some(u32::from_le_bytes(*b"RA24")));
+/// let pixel = format.convert_to(0x1122_3344, PixelFormat::ARGB8888);
+/// assert_eq!(pixel, 0x4411_2233);
+/// # Ok::<(), x11rb::errors::ParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelFormat {
+    layout: PixelLayout,
+    alpha: Option<ColorComponent>,
+    bits_per_pixel: BitsPerPixel,
+}
+
+macro_rules! pixel_formats {
+    ($($(#[$meta:meta])* $name:ident: $bits_per_pixel:ident, ($red:expr, $green:expr, $blue:expr), $alpha:expr, $fourcc:expr;)*) => {
+        impl PixelFormat {
+            $(
+                $(#[$meta])*
+                pub const $name: PixelFormat = PixelFormat {
+                    layout: PixelLayout {
+                        red: component($red.0, $red.1),
+                        green: component($green.0, $green.1),
+                        blue: component($blue.0, $blue.1),
+                    },
+                    alpha: $alpha,
+                    bits_per_pixel: BitsPerPixel::$bits_per_pixel,
+                };
+            )*
+
+            /// Get the DRM four character code of this format.
+            ///
+            /// Returns `None` if this is not one of the formats that are available as constants.
+            pub fn fourcc(self) -> Option<u32> {
+                $(
+                    if self == Self::$name {
+                        return Some(fourcc_code($fourcc));
+                    }
+                )*
+                None
+            }
+
+            /// Get the format for a DRM four character code.
+            ///
+            /// Only the formats that are available as constants are known.
+            pub fn from_fourcc(fourcc: u32) -> Option<Self> {
+                $(
+                    if fourcc == fourcc_code($fourcc) {
+                        return Some(Self::$name);
+                    }
+                )*
+                None
+            }
+        }
+    }
+}
+
+pixel_formats! {
+    /// 32 bit pixels of the form `0xAARRGGBB`. This is the `ARGB32` format of RENDER.
+    ARGB8888: B32, ((8, 16), (8, 8), (8, 0)), Some(component(8, 24)), b"AR24";
+    /// 32 bit pixels of the form `0x__RRGGBB`. This is the usual format of depth 24 visuals.
+    XRGB8888: B32, ((8, 16), (8, 8), (8, 0)), None, b"XR24";
+    /// 32 bit pixels of the form `0xAABBGGRR`.
+    ABGR8888: B32, ((8, 0), (8, 8), (8, 16)), Some(component(8, 24)), b"AB24";
+    /// 32 bit pixels of the form `0x__BBGGRR`.
+    XBGR8888: B32, ((8, 0), (8, 8), (8, 16)), None, b"XB24";
+    /// 32 bit pixels of the form `0xRRGGBBAA`.
+    RGBA8888: B32, ((8, 24), (8, 16), (8, 8)), Some(component(8, 0)), b"RA24";
+    /// 32 bit pixels of the form `0xRRGGBB__`.
+    RGBX8888: B32, ((8, 24), (8, 16), (8, 8)), None, b"RX24";
+    /// 32 bit pixels of the form `0xBBGGRRAA`.
+    BGRA8888: B32, ((8, 8), (8, 16), (8, 24)), Some(component(8, 0)), b"BA24";
+    /// 32 bit pixels of the form `0xBBGGRR__`.
+    BGRX8888: B32, ((8, 8), (8, 16), (8, 24)), None, b"BX24";
+    /// 16 bit pixels with 5 bits red, 6 bits green, and 5 bits blue, from most to least
+    /// significant bit. This is the usual format of depth 16 visuals.
+    RGB565: B16, ((5, 11), (6, 5), (5, 0)), None, b"RG16";
+    /// 32 bit pixels with 2 bits alpha and 10 bits for each color, from most to least significant
+    /// bit. Depth 30 visuals use this format without the alpha channel.
+    ARGB2101010: B32, ((10, 20), (10, 10), (10, 0)), Some(component(2, 30)), b"AR30";
+    /// 32 bit pixels with 2 unused bits and 10 bits for each color. This is the usual format of
+    /// depth 30 visuals.
+    XRGB2101010: B32, ((10, 20), (10, 10), (10, 0)), None, b"XR30";
+}
+
+impl PixelFormat {
+    /// Create a new pixel format.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the pixel values described by the color components and the alpha
+    /// channel do not fit into `bits_per_pixel`.
+    pub fn new(
+        layout: PixelLayout,
+        alpha: Option<ColorComponent>,
+        bits_per_pixel: BitsPerPixel,
+    ) -> Result<Self, ParseError> {
+        let format = Self {
+            layout,
+            alpha,
+            bits_per_pixel,
+        };
+        let bits = u32::from(u8::from(bits_per_pixel));
+        if bits < 32 && format.masks() >> bits != 0 {
+            Err(ParseError::InvalidValue)
+        } else {
+            Ok(format)
+        }
+    }
+
+    /// Create a new pixel format from the masks of the color components.
+    ///
+    /// An `alpha_mask` of zero means that there is no alpha channel. `bits_per_pixel` is the
+    /// number of bits that each pixel occupies in memory.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if one of the masks is malformed (see [`ColorComponent::from_mask`]),
+    /// or if `bits_per_pixel` is not valid or too small.
+    pub fn from_masks(
+        red_mask: u32,
+        green_mask: u32,
+        blue_mask: u32,
+        alpha_mask: u32,
+        bits_per_pixel: u8,
+    ) -> Result<Self, ParseError> {
+        let layout = PixelLayout::new(
+            ColorComponent::from_mask(red_mask)?,
+            ColorComponent::from_mask(green_mask)?,
+            ColorComponent::from_mask(blue_mask)?,
+        );
+        let alpha = match alpha_mask {
+            0 => None,
+            mask => Some(ColorComponent::from_mask(mask)?),
+        };
+        Self::new(layout, alpha, bits_per_pixel.try_into()?)
+    }
+
+    /// Create a new pixel format from a visual.
+    ///
+    /// The number of bits per pixel is not part of the visual, but is available from the
+    /// [`Format`] in the setup with the visual's depth.
+    ///
+    /// This function errors in the same cases as [`PixelLayout::from_visual_type`].
+    pub fn from_visual_type(visual: Visualtype, bits_per_pixel: u8) -> Result<Self, ParseError> {
+        Self::new(
+            PixelLayout::from_visual_type(visual)?,
+            None,
+            bits_per_pixel.try_into()?,
+        )
+    }
+
+    /// Create a new pixel format from the description of a RENDER picture format.
+    ///
+    /// RENDER describes components by an unshifted mask and a shift. Its `depth` is the number of
+    /// bits per pixel.
+    #[cfg(feature = "render")]
+    pub fn from_render_direct(
+        direct: crate::protocol::render::Directformat,
+        depth: u8,
+    ) -> Result<Self, ParseError> {
+        let mask = |mask: u16, shift: u16| {
+            u32::from(mask)
+                .checked_shl(shift.into())
+                .ok_or(ParseError::InvalidValue)
+        };
+        Self::from_masks(
+            mask(direct.red_mask, direct.red_shift)?,
+            mask(direct.green_mask, direct.green_shift)?,
+            mask(direct.blue_mask, direct.blue_shift)?,
+            mask(direct.alpha_mask, direct.alpha_shift)?,
+            depth,
+        )
+    }
+
+    /// Get the layout of the color components.
+    pub fn layout(self) -> PixelLayout {
+        self.layout
+    }
+
+    /// Get the alpha channel, if this format has one.
+    pub fn alpha(self) -> Option<ColorComponent> {
+        self.alpha
+    }
+
+    /// Get the number of bits that each pixel occupies in memory.
+    pub fn bits_per_pixel(self) -> BitsPerPixel {
+        self.bits_per_pixel
+    }
+
+    /// Get the depth of this pixel format, including the alpha channel.
+    pub fn depth(self) -> u8 {
+        self.layout.depth() + self.alpha.map_or(0, ColorComponent::width)
+    }
+
+    /// Get the bits of a pixel value that are used by this format.
+    pub fn masks(self) -> u32 {
+        let alpha = self.alpha.map_or(0, ColorComponent::mask);
+        self.layout.red.mask() | self.layout.green.mask() | self.layout.blue.mask() | alpha
+    }
+
+    /// Decode a pixel value into its red, green, blue, and alpha components.
+    ///
+    /// Each component is expanded to width 16. Formats without an alpha channel are opaque, i.e.
+    /// their alpha component is `0xffff`.
+    pub fn decode(self, pixel: u32) -> (u16, u16, u16, u16) {
+        let (red, green, blue) = self.layout.decode(pixel);
+        let alpha = self.alpha.map_or(0xffff, |alpha| alpha.decode(pixel));
+        (red, green, blue, alpha)
+    }
+
+    /// Encode a color value with alpha according to this format.
+    ///
+    /// The alpha component is dropped if this format does not have an alpha channel.
+    pub fn encode(self, (red, green, blue, alpha): (u16, u16, u16, u16)) -> u32 {
+        let alpha = self.alpha.map_or(0, |component| component.encode(alpha));
+        self.layout.encode((red, green, blue)) | alpha
+    }
+
+    /// Convert a pixel value from this format into the given format.
+    pub fn convert_to(self, pixel: u32, target: PixelFormat) -> u32 {
+        if self == target {
+            pixel
+        } else {
+            target.encode(self.decode(pixel))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_pixel_format {
+    use super::{BitsPerPixel, ColorComponent, PixelFormat, PixelLayout};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{VisualClass, Visualtype};
+
+    #[test]
+    fn from_masks() {
+        let format = PixelFormat::from_masks(0xff_0000, 0xff00, 0xff, 0xff00_0000, 32).unwrap();
+        assert_eq!(format, PixelFormat::ARGB8888);
+        assert_eq!(format.depth(), 32);
+        let format = PixelFormat::from_masks(0xf800, 0x7e0, 0x1f, 0, 16).unwrap();
+        assert_eq!(format, PixelFormat::RGB565);
+        assert_eq!(format.depth(), 16);
+        assert_eq!(
+            PixelFormat::from_masks(0xff_0000, 0xff00, 0xff, 0, 16),
+            Err(ParseError::InvalidValue)
+        );
+        assert_eq!(
+            PixelFormat::from_masks(0xff_0000, 0xff00, 0xff, 0, 12),
+            Err(ParseError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn from_visual_type() {
+        let visual = Visualtype {
+            visual_id: 1,
+            class: VisualClass::TRUE_COLOR,
+            bits_per_rgb_value: 8,
+            colormap_entries: 256,
+            red_mask: 0xff_0000,
+            green_mask: 0xff00,
+            blue_mask: 0xff,
+        };
+        let format = PixelFormat::from_visual_type(visual, 32).unwrap();
+        assert_eq!(format, PixelFormat::XRGB8888);
+        assert_eq!(format.alpha(), None);
+        assert_eq!(format.bits_per_pixel(), BitsPerPixel::B32);
+        assert_eq!(format.depth(), 24);
+    }
+
+    #[test]
+    fn fourcc() {
+        for format in [
+            PixelFormat::ARGB8888,
+            PixelFormat::XRGB8888,
+            PixelFormat::ABGR8888,
+            PixelFormat::XBGR8888,
+            PixelFormat::RGBA8888,
+            PixelFormat::RGBX8888,
+            PixelFormat::BGRA8888,
+            PixelFormat::BGRX8888,
+            PixelFormat::RGB565,
+            PixelFormat::ARGB2101010,
+            PixelFormat::XRGB2101010,
+        ] {
+            let fourcc = format.fourcc().unwrap();
+            assert_eq!(PixelFormat::from_fourcc(fourcc), Some(format));
+        }
+        assert_eq!(
+            PixelFormat::XRGB8888.fourcc(),
+            Some(u32::from_le_bytes(*b"XR24"))
+        );
+        assert_eq!(PixelFormat::from_fourcc(0), None);
+        let unusual = PixelFormat::new(
+            PixelLayout::new(
+                ColorComponent::new(4, 8).unwrap(),
+                ColorComponent::new(4, 4).unwrap(),
+                ColorComponent::new(4, 0).unwrap(),
+            ),
+            None,
+            BitsPerPixel::B16,
+        )
+        .unwrap();
+        assert_eq!(unusual.fourcc(), None);
+    }
+
+    #[test]
+    fn convert() {
+        let pixel = PixelFormat::ARGB8888.convert_to(0x8011_2233, PixelFormat::BGRX8888);
+        assert_eq!(pixel, 0x3322_1100);
+        let pixel = PixelFormat::BGRX8888.convert_to(0x3322_1100, PixelFormat::ARGB8888);
+        assert_eq!(pixel, 0xff11_2233);
+        let pixel = PixelFormat::XRGB8888.convert_to(0x00ff_8000, PixelFormat::RGB565);
+        assert_eq!(pixel, 0xfc00);
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn from_render_direct() {
+        let direct = crate::protocol::render::Directformat {
+            red_shift: 16,
+            red_mask: 0xff,
+            green_shift: 8,
+            green_mask: 0xff,
+            blue_shift: 0,
+            blue_mask: 0xff,
+            alpha_shift: 24,
+            alpha_mask: 0xff,
+        };
+        assert_eq!(
+            PixelFormat::from_render_direct(direct, 32),
+            Ok(PixelFormat::ARGB8888)
+        );
+    }
+}
+
+/// How colors are rounded to a pixel format with fewer bits per component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Round each pixel to the nearest color.
+    ///
+    /// Smooth gradients show visible bands, e.g. with 5 bits per component.
+    None,
+    /// Mix the two nearest colors in a 4x4 pattern (ordered dithering).
+    ///
+    /// This hides the bands of gradients. Since the pattern only depends on the position of a
+    /// pixel, parts of an image can be converted independently without visible seams.
+    Ordered,
+}
+
+/// The thresholds of a 4x4 Bayer matrix, scaled to `1..256`.
+const BAYER_4X4: [[u16; 4]; 4] = [
+    [8, 136, 40, 168],
+    [200, 72, 232, 104],
+    [56, 184, 24, 152],
+    [248, 120, 216, 88],
+];
+
+/// How an 8 bit value of one color component is encoded.
+#[derive(Debug, Clone, Copy)]
+struct TableEntry {
+    /// The bits of the nearest lower level, at the position of the component.
+    low: u32,
+    /// The bits of the nearest higher level.
+    high: u32,
+    /// Where the value lies between the two levels, from 0 (at `low`) to 256 (at `high`).
+    position: u16,
+}
+
+/// Convert an sRGB encoded value in `0.0..=1.0` into linear light.
+fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn channel_table(component: ColorComponent, gamma_correct: bool) -> Vec<TableEntry> {
+    let max = (1u32 << component.width()) - 1;
+    let to_light = |value: f64| {
+        if gamma_correct {
+            srgb_to_linear(value)
+        } else {
+            value
+        }
+    };
+    (0..=255u32)
+        .map(|value| {
+            let low = value * max / 255;
+            let high = (low + 1).min(max);
+            let position = if low == high || low * 255 == value * max {
+                0.0
+            } else {
+                let light = |level: u32| to_light(f64::from(level) / f64::from(max));
+                (to_light(f64::from(value) / 255.0) - light(low)) / (light(high) - light(low))
+            };
+            TableEntry {
+                low: low << component.shift(),
+                high: high << component.shift(),
+                position: (position * 256.0).round() as u16,
+            }
+        })
+        .collect()
+}
+
+/// Precomputed tables for converting colors with 8 bits per component into a [`PixelFormat`].
+///
+/// Converting an 8 bit component to fewer bits by truncation, e.g. for [`PixelFormat::RGB565`],
+/// makes gradients darker and shows visible bands. These tables round to the nearest level
+/// instead and support [`Dither`]ing between the two nearest levels. Formats with more bits per
+/// component, e.g. [`PixelFormat::XRGB2101010`], get the exact values.
+///
+/// With `gamma_correct`, the color components are treated as sRGB encoded, so that rounding and
+/// dithering are done in linear light. Dithered areas then have the same brightness as the
+/// original color. The alpha channel is always linear.
+///
+/// ```
+/// use x11rb::image::{ConversionTables, Dither, PixelFormat};
+///
+/// let tables = ConversionTables::new(PixelFormat::RGB565, true);
+/// // A gray that lies between two levels of the format becomes a pattern of both
+/// let pixels = (0..4)
+///     .map(|x| tables.encode((100, 100, 100, 255), x, 0, Dither::Ordered))
+///     .collect::<Vec<_>>();
+/// assert_ne!(pixels[0], pixels[1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConversionTables {
+    format: PixelFormat,
+    red: Vec<TableEntry>,
+    green: Vec<TableEntry>,
+    blue: Vec<TableEntry>,
+    alpha: Option<Vec<TableEntry>>,
+}
+
+impl ConversionTables {
+    /// Compute the tables for the given target format.
+    pub fn new(format: PixelFormat, gamma_correct: bool) -> Self {
+        let layout = format.layout();
+        Self {
+            format,
+            red: channel_table(layout.red(), gamma_correct),
+            green: channel_table(layout.green(), gamma_correct),
+            blue: channel_table(layout.blue(), gamma_correct),
+            alpha: format.alpha().map(|alpha| channel_table(alpha, false)),
+        }
+    }
+
+    /// Get the format that colors are converted into.
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Encode a color with 8 bits per component as a pixel value.
+    ///
+    /// `x` and `y` are the position of the pixel in the image, which selects the threshold for
+    /// [`Dither::Ordered`].
+    pub fn encode(
+        &self,
+        (red, green, blue, alpha): (u8, u8, u8, u8),
+        x: u16,
+        y: u16,
+        dither: Dither,
+    ) -> u32 {
+        let threshold = match dither {
+            Dither::None => 128,
+            Dither::Ordered => BAYER_4X4[usize::from(y % 4)][usize::from(x % 4)],
+        };
+        let lookup = |table: &[TableEntry], value: u8| {
+            let entry = table[usize::from(value)];
+            if entry.position >= threshold {
+                entry.high
+            } else {
+                entry.low
+            }
+        };
+        let alpha = self.alpha.as_ref().map_or(0, |table| lookup(table, alpha));
+        lookup(&self.red, red) | lookup(&self.green, green) | lookup(&self.blue, blue) | alpha
+    }
+}
+
+#[cfg(test)]
+mod test_dither {
+    use super::{ConversionTables, Dither, PixelFormat};
+
+    fn red(pixel: u32) -> u32 {
+        pixel >> 11
+    }
+
+    #[test]
+    fn rounds_to_nearest() {
+        let tables = ConversionTables::new(PixelFormat::RGB565, false);
+        // Truncation would give 0 for all of these
+        assert_eq!(red(tables.encode((4, 0, 0, 0), 0, 0, Dither::None)), 0);
+        assert_eq!(red(tables.encode((5, 0, 0, 0), 0, 0, Dither::None)), 1);
+        assert_eq!(red(tables.encode((255, 0, 0, 0), 0, 0, Dither::None)), 31);
+        assert_eq!(
+            tables.encode((255, 255, 255, 0), 3, 3, Dither::Ordered),
+            0xffff
+        );
+    }
+
+    #[test]
+    fn ordered_dither_keeps_average() {
+        for &gamma_correct in &[false, true] {
+            let tables = ConversionTables::new(PixelFormat::RGB565, gamma_correct);
+            for value in 0..=255u8 {
+                let sum: u32 = (0..16)
+                    .map(|i| red(tables.encode((value, 0, 0, 0), i % 4, i / 4, Dither::Ordered)))
+                    .sum();
+                let low = u32::from(value) * 31 / 255;
+                // All pixels use one of the two nearest levels
+                assert!(sum >= 16 * low && sum <= 16 * (low + 1), "{}", value);
+                if !gamma_correct {
+                    // The average is within a sixteenth of a step of the original value
+                    let expected = f64::from(value) * 31.0 / 255.0;
+                    assert!(
+                        (f64::from(sum) / 16.0 - expected).abs() <= 1.0 / 16.0,
+                        "{}",
+                        value
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gamma_correct_dither_uses_linear_light() {
+        let linear = ConversionTables::new(PixelFormat::RGB565, false);
+        let gamma = ConversionTables::new(PixelFormat::RGB565, true);
+        let count = |tables: &ConversionTables| {
+            (0..16)
+                .filter(|i| red(tables.encode((100, 0, 0, 0), i % 4, i / 4, Dither::Ordered)) == 13)
+                .count()
+        };
+        // Mixing in linear light needs the higher level less often to reach the same brightness
+        assert!(count(&gamma) < count(&linear));
+    }
+
+    #[test]
+    fn ten_bit_formats() {
+        let tables = ConversionTables::new(PixelFormat::ARGB2101010, true);
+        let pixel = tables.encode((255, 0, 0, 255), 1, 2, Dither::Ordered);
+        assert_eq!(pixel, 0xfff0_0000);
+        // 0x80 lies halfway between 513 and 514
+        let pixel = tables.encode((0, 0, 0x80, 0), 1, 2, Dither::Ordered);
+        assert!(pixel == 513 || pixel == 514, "{}", pixel);
+    }
+}
+
 // Compute the stride based on some information of the image
 fn compute_stride(width: u16, bits_per_pixel: BitsPerPixel, scanline_pad: ScanlinePad) -> usize {
     let value = usize::from(width) * usize::from(bits_per_pixel);
@@ -290,6 +882,139 @@ mod test_stride {
     }
 }
 
+// The byte order of the machine that this code is running on.
+fn host_byte_order() -> ImageOrder {
+    if cfg!(target_endian = "little") {
+        ImageOrder::LSBFirst
+    } else {
+        ImageOrder::MSBFirst
+    }
+}
+
+// Find the visual with the given ID on any screen.
+fn find_visual(setup: &Setup, id: Visualid) -> Option<&Visualtype> {
+    setup
+        .roots
+        .iter()
+        .flat_map(|screen| &screen.allowed_depths)
+        .flat_map(|depth| &depth.visuals)
+        .find(|visual| visual.visual_id == id)
+}
+
+// Find a visual for the given depth on the screen with the given root window. The root visual is
+// preferred, then TrueColor visuals.
+fn find_visual_for_depth(setup: &Setup, root: Window, depth: u8) -> Option<&Visualtype> {
+    let screen = setup.roots.iter().find(|screen| screen.root == root)?;
+    let visuals = screen
+        .allowed_depths
+        .iter()
+        .filter(|allowed| allowed.depth == depth)
+        .flat_map(|allowed| &allowed.visuals);
+    visuals
+        .clone()
+        .find(|visual| visual.visual_id == screen.root_visual)
+        .or_else(|| {
+            visuals
+                .clone()
+                .find(|visual| visual.class == VisualClass::TRUE_COLOR)
+        })
+}
+
+#[cfg(test)]
+mod test_find_visual {
+    use super::{find_visual, find_visual_for_depth};
+    use crate::protocol::xproto::{
+        BackingStore, Depth, ImageOrder, Screen, Setup, VisualClass, Visualtype,
+    };
+
+    fn visual(visual_id: u32, class: VisualClass) -> Visualtype {
+        Visualtype {
+            visual_id,
+            class,
+            bits_per_rgb_value: 8,
+            colormap_entries: 256,
+            red_mask: 0xff_0000,
+            green_mask: 0xff00,
+            blue_mask: 0xff,
+        }
+    }
+
+    fn setup() -> Setup {
+        let screen = Screen {
+            root: 10,
+            default_colormap: 0,
+            white_pixel: 0,
+            black_pixel: 0,
+            current_input_masks: 0,
+            width_in_pixels: 0,
+            height_in_pixels: 0,
+            width_in_millimeters: 0,
+            height_in_millimeters: 0,
+            min_installed_maps: 0,
+            max_installed_maps: 0,
+            root_visual: 2,
+            backing_stores: BackingStore::NOT_USEFUL,
+            save_unders: false,
+            root_depth: 24,
+            allowed_depths: vec![
+                Depth {
+                    depth: 24,
+                    visuals: vec![
+                        visual(1, VisualClass::DIRECT_COLOR),
+                        visual(2, VisualClass::TRUE_COLOR),
+                    ],
+                },
+                Depth {
+                    depth: 32,
+                    visuals: vec![
+                        visual(3, VisualClass::DIRECT_COLOR),
+                        visual(4, VisualClass::TRUE_COLOR),
+                    ],
+                },
+            ],
+        };
+        Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0,
+            resource_id_mask: 0xff,
+            motion_buffer_size: 0,
+            maximum_request_length: 0xffff,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: vec![],
+            pixmap_formats: vec![],
+            roots: vec![screen],
+        }
+    }
+
+    #[test]
+    fn by_id() {
+        let setup = setup();
+        assert_eq!(find_visual(&setup, 3).map(|v| v.visual_id), Some(3));
+        assert_eq!(find_visual(&setup, 5), None);
+    }
+
+    #[test]
+    fn for_depth() {
+        let setup = setup();
+        let find = |root, depth| find_visual_for_depth(&setup, root, depth).map(|v| v.visual_id);
+        // The root visual is preferred
+        assert_eq!(find(10, 24), Some(2));
+        // Otherwise a TrueColor visual
+        assert_eq!(find(10, 32), Some(4));
+        assert_eq!(find(10, 16), None);
+        assert_eq!(find(11, 24), None);
+    }
+}
+
 // Find the format with the given depth in `setup.pixmap_formats`.
 fn find_format(setup: &Setup, depth: u8) -> Result<&Format, ParseError> {
     setup
@@ -684,7 +1409,7 @@ impl<'a> Image<'a> {
     /// The returned image contains the rectangle with top left corner `(x, y)` and size `(width,
     /// height)` of the given `drawable`.
     pub fn get(
-        conn: &impl Connection,
+        conn: &(impl Connection + ?Sized),
         drawable: Drawable,
         x: i16,
         y: i16,
@@ -723,11 +1448,63 @@ impl<'a> Image<'a> {
             format.scanline_pad.try_into()?,
             reply.depth,
             format.bits_per_pixel.try_into()?,
-            ImageOrder::MSBFirst,
+            setup.image_byte_order.try_into()?,
             Cow::Owned(reply.data),
         )
     }
 
+    /// Get an image from the X11 server together with the layout of its pixels.
+    ///
+    /// Like [`Image::get`], this fetches the rectangle with top left corner `(x, y)` and size
+    /// `(width, height)` of the given `drawable`. Additionally, the visual of the drawable is used
+    /// to describe how pixel values are made up of colors. Pixmaps do not have a visual. For them,
+    /// the root visual of their screen is used if it has the right depth, otherwise the first
+    /// `TrueColor` visual with the right depth.
+    ///
+    /// The returned image uses the byte order of the machine that this code is running on, so that
+    /// e.g. the pixels of a 32 bits per pixel image can be read as native `u32`s.
+    ///
+    /// # Errors
+    ///
+    /// Besides errors from the X11 server, this fails with [`ParseError::InvalidValue`] if no
+    /// suitable visual is found or if it is not a `TrueColor` or `DirectColor` visual.
+    pub fn get_with_layout(
+        conn: &(impl Connection + ?Sized),
+        drawable: Drawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+    ) -> Result<(Self, PixelLayout), ReplyError> {
+        let setup = conn.setup();
+        let reply = GetImageRequest {
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            format: ImageFormat::Z_PIXMAP,
+            plane_mask: !0,
+        }
+        .send(conn)?
+        .reply()?;
+        let visual = if reply.visual != 0 {
+            find_visual(setup, reply.visual)
+        } else {
+            let geometry = GetGeometryRequest { drawable }.send(conn)?.reply()?;
+            find_visual_for_depth(setup, geometry.root, reply.depth)
+        };
+        let layout = PixelLayout::from_visual_type(*visual.ok_or(ParseError::InvalidValue)?)?;
+
+        let mut image = Self::get_from_reply(setup, width, height, reply)?;
+        if image.byte_order != host_byte_order() {
+            image = image
+                .convert(image.scanline_pad, image.bits_per_pixel, host_byte_order())
+                .into_owned();
+        }
+        Ok((image, layout))
+    }
+
     /// Put an image to the X11 server.
     ///
     /// This function sends a [`PutImage`](crate::protocol::xproto::PutImageRequest) request. This
@@ -736,7 +1513,7 @@ impl<'a> Image<'a> {
     /// The server's maximum request size is honored. This means that a too large `PutImage`
     /// request is automatically split up into smaller pieces. Thus, if this function returns an
     /// error, the image could already be partially sent.
-    pub fn put<'c, Conn: Connection>(
+    pub fn put<'c, Conn: Connection + ?Sized>(
         &self,
         conn: &'c Conn,
         drawable: Drawable,
@@ -745,39 +1522,18 @@ impl<'a> Image<'a> {
         dst_y: i16,
     ) -> Result<Vec<VoidCookie<'c, Conn>>, ConnectionError> {
         // Upload the image without exceeding the server's maximum request size
-        let max_bytes = conn.maximum_request_bytes();
-        let put_image_header = 24;
-        let stride = self.stride();
-        let lines_per_request = (max_bytes - put_image_header) / stride;
-        let mut result = Vec::with_capacity(
-            (usize::from(self.height()) + lines_per_request - 1) / lines_per_request,
-        );
-        let lines_per_request = lines_per_request.try_into().unwrap_or(u16::max_value());
-        assert!(lines_per_request > 0);
-
-        let (mut y_offset, mut byte_offset) = (0, 0);
-        while y_offset < self.height {
-            let next_lines = lines_per_request.min(self.height - y_offset);
-            let next_byte_offset = byte_offset + usize::from(next_lines) * stride;
-            let data = &self.data[byte_offset..next_byte_offset];
-            let request = PutImageRequest {
-                format: ImageFormat::Z_PIXMAP,
-                drawable,
-                gc,
-                width: self.width,
-                height: next_lines,
-                dst_x,
-                dst_y: dst_y + i16::try_from(y_offset).unwrap(),
-                left_pad: 0, // Must always be 0 for ZPixmap
-                depth: self.depth,
-                data: Cow::Borrowed(&data),
-            };
-            result.push(request.send(conn)?);
-
-            y_offset += next_lines;
-            byte_offset = next_byte_offset;
-        }
-        Ok(result)
+        conn.put_image_chunked(
+            ImageFormat::Z_PIXMAP,
+            drawable,
+            gc,
+            self.width,
+            self.height,
+            dst_x,
+            dst_y,
+            0, // Must always be 0 for ZPixmap
+            self.depth,
+            &self.data[..usize::from(self.height) * self.stride()],
+        )
     }
 
     /// Convert this image into the format specified by the other parameters.
@@ -854,6 +1610,36 @@ impl<'a> Image<'a> {
         }
     }
 
+    /// Convert this image into the format of the given tables, with dithering.
+    ///
+    /// Each pixel of this image is interpreted according to `own` and reduced to 8 bits per
+    /// component before the conversion, so this is meant for images with 8 bits per component.
+    /// The resulting image has the depth of the tables' format and is always in the native format
+    /// as described by `setup`.
+    pub fn reencode_dithered(
+        &self,
+        own: PixelFormat,
+        tables: &ConversionTables,
+        dither: Dither,
+        setup: &Setup,
+    ) -> Result<Image<'static>, ParseError> {
+        let (width, height) = (self.width(), self.height());
+        let mut result = Image::allocate_native(width, height, tables.format().depth(), setup)?;
+        for y in 0..height {
+            for x in 0..width {
+                let (red, green, blue, alpha) = own.decode(self.get_pixel(x, y));
+                let color = (
+                    (red >> 8) as u8,
+                    (green >> 8) as u8,
+                    (blue >> 8) as u8,
+                    (alpha >> 8) as u8,
+                );
+                result.put_pixel(x, y, tables.encode(color, x, y, dither));
+            }
+        }
+        Ok(result)
+    }
+
     /// Set a single pixel in this image.
     ///
     /// The pixel at position `(x, y)` will be set to the value `pixel`. `pixel` is truncated to
@@ -973,6 +1759,371 @@ impl<'a> Image<'a> {
             }
         }
     }
+
+    /// Get a view of a rectangular part of this image.
+    ///
+    /// The rectangle is clipped to the bounds of this image, so the resulting view may be smaller
+    /// than `rect` or even empty.
+    pub fn sub_image(&self, rect: Rectangle) -> SubImage<'_, 'a> {
+        let (x, y, width, height) = clip_rectangle(rect, self.width, self.height);
+        SubImage {
+            image: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Copy a rectangular part of another image into this image.
+    ///
+    /// The part `src_rect` of `src` is copied so that its top left corner ends up at `(dst_x,
+    /// dst_y)` in this image. Parts that are outside of either image are skipped.
+    ///
+    /// Pixel values are copied as they are, so both images should have the same depth and pixel
+    /// layout. If the images use different formats, see [`Image::convert`] and
+    /// [`Image::reencode`]. If both images have the same number of bits per pixel (at least 8) and
+    /// the same byte order, whole rows are copied at once.
+    ///
+    /// ```
+    /// # use x11rb::image::{BitsPerPixel, Image, ImageOrder, ScanlinePad};
+    /// # use x11rb::protocol::xproto::Rectangle;
+    /// let new = || {
+    ///     Image::allocate(4, 4, ScanlinePad::Pad8, 8, BitsPerPixel::B8, ImageOrder::LSBFirst)
+    /// };
+    /// let mut src = new();
+    /// src.put_pixel(1, 1, 42);
+    /// let mut dst = new();
+    /// let rect = Rectangle { x: 1, y: 1, width: 10, height: 10 };
+    /// dst.blit(&src, rect, 2, 3);
+    /// assert_eq!(dst.get_pixel(2, 3), 42);
+    /// ```
+    pub fn blit(&mut self, src: &Image<'_>, src_rect: Rectangle, dst_x: i16, dst_y: i16) {
+        let clipped = clip_copy(
+            (src.width, src.height),
+            src_rect,
+            (self.width, self.height),
+            (dst_x, dst_y),
+        );
+        let [src_x, src_y, dst_x, dst_y, width, height] = match clipped {
+            Some(clipped) => clipped,
+            None => return,
+        };
+        let bytes_per_pixel = match self.bits_per_pixel {
+            BitsPerPixel::B1 | BitsPerPixel::B4 => None,
+            bpp => Some(usize::from(u8::from(bpp)) / 8),
+        };
+        match bytes_per_pixel {
+            Some(bytes_per_pixel)
+                if src.bits_per_pixel == self.bits_per_pixel
+                    && (src.byte_order == self.byte_order || bytes_per_pixel == 1) =>
+            {
+                let (src_stride, dst_stride) = (src.stride(), self.stride());
+                let row_length = usize::from(width) * bytes_per_pixel;
+                let data = self.data.to_mut();
+                for row in 0..usize::from(height) {
+                    let src_start = (usize::from(src_y) + row) * src_stride
+                        + usize::from(src_x) * bytes_per_pixel;
+                    let dst_start = (usize::from(dst_y) + row) * dst_stride
+                        + usize::from(dst_x) * bytes_per_pixel;
+                    data[dst_start..dst_start + row_length]
+                        .copy_from_slice(&src.data[src_start..src_start + row_length]);
+                }
+            }
+            _ => {
+                for y in 0..height {
+                    for x in 0..width {
+                        let pixel = src.get_pixel(src_x + x, src_y + y);
+                        self.put_pixel(dst_x + x, dst_y + y, pixel);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw a color through an alpha mask onto this image.
+    ///
+    /// This is meant for drawing text that was rendered on the client side, e.g. with a glyph
+    /// atlas: Each value of `mask` describes how much a pixel is covered, from 0 (not at all) to
+    /// 255 (completely). The part `src_rect` of `mask` is drawn with its top left corner at `(dst_x,
+    /// dst_y)` and parts outside of the mask or this image are skipped. The pixels of this image
+    /// are interpreted according to `layout` and blended with `color`.
+    ///
+    /// Images with 32 bits per pixel and 8 bit color components (e.g. the usual depth 24 and 32
+    /// visuals) are handled a row at a time without decoding each pixel.
+    ///
+    /// ```
+    /// # use x11rb::image::{AlphaMask, BitsPerPixel, Image, ImageOrder, PixelFormat, ScanlinePad};
+    /// # use x11rb::protocol::xproto::Rectangle;
+    /// let mut image =
+    ///     Image::allocate(2, 1, ScanlinePad::Pad32, 24, BitsPerPixel::B32, ImageOrder::LSBFirst);
+    /// let glyph = AlphaMask::new(&[255, 128], 2, 1, 2)?;
+    /// let rect = Rectangle { x: 0, y: 0, width: 2, height: 1 };
+    /// let layout = PixelFormat::XRGB8888.layout();
+    /// image.blend_mask(&glyph, rect, 0, 0, (0xffff, 0, 0), layout);
+    /// assert_eq!(image.get_pixel(0, 0), 0xff_0000);
+    /// assert_eq!(image.get_pixel(1, 0), 0x80_0000);
+    /// # Ok::<(), x11rb::errors::ParseError>(())
+    /// ```
+    pub fn blend_mask(
+        &mut self,
+        mask: &AlphaMask<'_>,
+        src_rect: Rectangle,
+        dst_x: i16,
+        dst_y: i16,
+        color: (u16, u16, u16),
+        layout: PixelLayout,
+    ) {
+        let clipped = clip_copy(
+            (mask.width, mask.height),
+            src_rect,
+            (self.width, self.height),
+            (dst_x, dst_y),
+        );
+        let [src_x, src_y, dst_x, dst_y, width, height] = match clipped {
+            Some(clipped) => clipped,
+            None => return,
+        };
+        let mask_rows = (0..usize::from(height)).map(|row| {
+            let start = (usize::from(src_y) + row) * mask.stride + usize::from(src_x);
+            &mask.data[start..start + usize::from(width)]
+        });
+
+        let components = [
+            (layout.red, color.0),
+            (layout.green, color.1),
+            (layout.blue, color.2),
+        ];
+        let bytewise = self.bits_per_pixel == BitsPerPixel::B32
+            && components
+                .iter()
+                .all(|(component, _)| component.width == 8 && component.shift % 8 == 0);
+        if bytewise {
+            // Find the byte of each component in a pixel
+            let byte_order = self.byte_order;
+            let components = components.map(|(component, value)| {
+                let index = usize::from(component.shift / 8);
+                let index = match byte_order {
+                    ImageOrder::LSBFirst => index,
+                    ImageOrder::MSBFirst => 3 - index,
+                };
+                (index, (value >> 8) as u8)
+            });
+            let stride = self.stride();
+            let data = self.data.to_mut();
+            for (row, mask_row) in mask_rows.enumerate() {
+                let start = (usize::from(dst_y) + row) * stride + usize::from(dst_x) * 4;
+                let pixels = &mut data[start..start + usize::from(width) * 4];
+                for (pixel, &alpha) in pixels.chunks_exact_mut(4).zip(mask_row) {
+                    match alpha {
+                        0 => {}
+                        255 => {
+                            for &(index, value) in components.iter() {
+                                pixel[index] = value;
+                            }
+                        }
+                        _ => {
+                            for &(index, value) in components.iter() {
+                                pixel[index] =
+                                    blend(value.into(), pixel[index].into(), alpha) as u8;
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            let color_mask = layout.red.mask() | layout.green.mask() | layout.blue.mask();
+            for (row, mask_row) in (0..height).zip(mask_rows) {
+                for (x, &alpha) in (0..width).zip(mask_row) {
+                    if alpha == 0 {
+                        continue;
+                    }
+                    let (x, y) = (dst_x + x, dst_y + row);
+                    let pixel = self.get_pixel(x, y);
+                    let (red, green, blue) = layout.decode(pixel);
+                    let blended = layout.encode((
+                        blend(color.0.into(), red.into(), alpha) as u16,
+                        blend(color.1.into(), green.into(), alpha) as u16,
+                        blend(color.2.into(), blue.into(), alpha) as u16,
+                    ));
+                    self.put_pixel(x, y, (pixel & !color_mask) | blended);
+                }
+            }
+        }
+    }
+}
+
+// Blend `src` over `dst` with the given coverage.
+fn blend(src: u32, dst: u32, alpha: u8) -> u32 {
+    let alpha = u32::from(alpha);
+    (src * alpha + dst * (255 - alpha) + 127) / 255
+}
+
+/// An 8 bit alpha mask, e.g. a glyph atlas with pre-rendered text.
+///
+/// Each byte describes the coverage of one pixel from 0 (transparent) to 255 (opaque). This is
+/// the format that font rasterizers usually produce. See [`Image::blend_mask`].
+#[derive(Debug, Clone, Copy)]
+pub struct AlphaMask<'m> {
+    data: &'m [u8],
+    width: u16,
+    height: u16,
+    stride: usize,
+}
+
+impl<'m> AlphaMask<'m> {
+    /// Wrap existing coverage values in an `AlphaMask`.
+    ///
+    /// `stride` is the number of bytes from the start of one row to the start of the next row.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ParseError::InvalidValue`] if `stride < width` and with
+    /// [`ParseError::InsufficientData`] if `data` is too short.
+    pub fn new(data: &'m [u8], width: u16, height: u16, stride: usize) -> Result<Self, ParseError> {
+        if stride < usize::from(width) {
+            return Err(ParseError::InvalidValue);
+        }
+        let expected_size = match height {
+            0 => 0,
+            _ => usize::from(height - 1) * stride + usize::from(width),
+        };
+        if data.len() < expected_size {
+            return Err(ParseError::InsufficientData);
+        }
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+        })
+    }
+
+    /// The width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+}
+
+/// A rectangular part of an [`Image`].
+///
+/// This is a view that borrows the image. It is created by [`Image::sub_image`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubImage<'i, 'a> {
+    image: &'i Image<'a>,
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+impl<'i, 'a> SubImage<'i, 'a> {
+    /// Get the image that this is a part of.
+    pub fn image(&self) -> &'i Image<'a> {
+        self.image
+    }
+
+    /// Get the part of the image that this view shows.
+    pub fn rect(&self) -> Rectangle {
+        Rectangle {
+            // These values were clipped from an i16, so they fit into one
+            x: self.x.try_into().unwrap(),
+            y: self.y.try_into().unwrap(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Get the value of a single pixel.
+    ///
+    /// The coordinates are relative to the top left corner of this view.
+    pub fn get_pixel(&self, x: u16, y: u16) -> u32 {
+        assert!(x < self.width);
+        assert!(y < self.height);
+        self.image.get_pixel(self.x + x, self.y + y)
+    }
+
+    /// Copy this part of the image into a new image with the same format.
+    pub fn to_image(&self) -> Image<'static> {
+        let image = self.image;
+        let mut result = Image::allocate(
+            self.width,
+            self.height,
+            image.scanline_pad,
+            image.depth,
+            image.bits_per_pixel,
+            image.byte_order,
+        );
+        result.blit(image, self.rect(), 0, 0);
+        result
+    }
+}
+
+// Clip a copy of `src_rect` from an image of size `src_size` to position `dst` in an image of size
+// `dst_size`. Returns the source position, destination position, and size of the part that is
+// inside of both images, or `None` if nothing is left.
+fn clip_copy(
+    src_size: (u16, u16),
+    src_rect: Rectangle,
+    dst_size: (u16, u16),
+    dst: (i16, i16),
+) -> Option<[u16; 6]> {
+    let (mut src_x, mut src_y) = (i32::from(src_rect.x), i32::from(src_rect.y));
+    let (mut dst_x, mut dst_y) = (i32::from(dst.0), i32::from(dst.1));
+    let (mut width, mut height) = (i32::from(src_rect.width), i32::from(src_rect.height));
+
+    // Clip at the left and top of both images
+    let skip = 0.max(-src_x).max(-dst_x);
+    src_x += skip;
+    dst_x += skip;
+    width -= skip;
+    let skip = 0.max(-src_y).max(-dst_y);
+    src_y += skip;
+    dst_y += skip;
+    height -= skip;
+
+    // Clip at the right and bottom of both images
+    let width = width
+        .min(i32::from(src_size.0) - src_x)
+        .min(i32::from(dst_size.0) - dst_x);
+    let height = height
+        .min(i32::from(src_size.1) - src_y)
+        .min(i32::from(dst_size.1) - dst_y);
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    // All values are now known to be within the bounds of the images
+    Some([src_x, src_y, dst_x, dst_y, width, height].map(|value| value.try_into().unwrap()))
+}
+
+// Clip a rectangle to the size of an image. Returns the position and size of the result.
+fn clip_rectangle(rect: Rectangle, width: u16, height: u16) -> (u16, u16, u16, u16) {
+    fn clip(start: i16, length: u16, limit: u16) -> (u16, u16) {
+        let end = (i32::from(start) + i32::from(length)).min(i32::from(limit));
+        let start = i32::from(start).max(0).min(end.max(0));
+        let end = end.max(start);
+        // Both values are in the range 0..=limit
+        (start.try_into().unwrap(), (end - start).try_into().unwrap())
+    }
+    let (x, width) = clip(rect.x, rect.width, width);
+    let (y, height) = clip(rect.y, rect.height, height);
+    (x, y, width, height)
 }
 
 fn compute_depth_1_address(x: usize, order: ImageOrder) -> (usize, usize) {
@@ -983,6 +2134,177 @@ fn compute_depth_1_address(x: usize, order: ImageOrder) -> (usize, usize) {
     (x / 8, bit)
 }
 
+#[cfg(test)]
+mod test_blit {
+    use super::{BitsPerPixel, Image, ImageOrder, ScanlinePad};
+    use crate::protocol::xproto::Rectangle;
+
+    fn numbered(width: u16, height: u16, bpp: BitsPerPixel, order: ImageOrder) -> Image<'static> {
+        let mut image = Image::allocate(width, height, ScanlinePad::Pad32, 4, bpp, order);
+        for y in 0..height {
+            for x in 0..width {
+                image.put_pixel(x, y, u32::from(y * width + x) % 16);
+            }
+        }
+        image
+    }
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn pixels(image: &Image<'_>) -> Vec<Vec<u32>> {
+        (0..image.height())
+            .map(|y| (0..image.width()).map(|x| image.get_pixel(x, y)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn sub_image() {
+        let image = numbered(4, 3, BitsPerPixel::B8, ImageOrder::LSBFirst);
+        let sub = image.sub_image(rect(1, 1, 2, 2));
+        assert_eq!((sub.width(), sub.height()), (2, 2));
+        assert_eq!(sub.get_pixel(0, 0), 5);
+        assert_eq!(pixels(&sub.to_image()), [[5, 6], [9, 10]]);
+    }
+
+    #[test]
+    fn sub_image_clipped() {
+        let image = numbered(4, 3, BitsPerPixel::B8, ImageOrder::LSBFirst);
+        let sub = image.sub_image(rect(-1, 2, 3, 5));
+        assert_eq!(sub.rect(), rect(0, 2, 2, 1));
+        assert_eq!(pixels(&sub.to_image()), [[8, 9]]);
+        let sub = image.sub_image(rect(5, 5, 3, 5));
+        assert_eq!((sub.width(), sub.height()), (0, 0));
+    }
+
+    fn check_blit(src_bpp: BitsPerPixel, dst_bpp: BitsPerPixel, dst_order: ImageOrder) {
+        let src = numbered(4, 3, src_bpp, ImageOrder::MSBFirst);
+        let mut dst = Image::allocate(3, 3, ScanlinePad::Pad8, 4, dst_bpp, dst_order);
+        // Clipped on all sides: the source starts outside of src and ends outside of dst
+        dst.blit(&src, rect(-1, 1, 10, 10), 0, -1);
+        assert_eq!(pixels(&dst), [[0, 8, 9], [0, 0, 0], [0, 0, 0]]);
+        dst.blit(&src, rect(0, 0, 2, 2), 1, 1);
+        assert_eq!(pixels(&dst), [[0, 8, 9], [0, 0, 1], [0, 4, 5]]);
+        // Completely outside
+        dst.blit(&src, rect(0, 0, 2, 2), 3, 0);
+        dst.blit(&src, rect(4, 0, 2, 2), 0, 0);
+        assert_eq!(pixels(&dst), [[0, 8, 9], [0, 0, 1], [0, 4, 5]]);
+    }
+
+    #[test]
+    fn blit_rows() {
+        check_blit(BitsPerPixel::B8, BitsPerPixel::B8, ImageOrder::LSBFirst);
+        check_blit(BitsPerPixel::B16, BitsPerPixel::B16, ImageOrder::MSBFirst);
+        check_blit(BitsPerPixel::B32, BitsPerPixel::B32, ImageOrder::MSBFirst);
+    }
+
+    #[test]
+    fn blit_pixels() {
+        check_blit(BitsPerPixel::B4, BitsPerPixel::B4, ImageOrder::MSBFirst);
+        check_blit(BitsPerPixel::B16, BitsPerPixel::B16, ImageOrder::LSBFirst);
+        check_blit(BitsPerPixel::B8, BitsPerPixel::B32, ImageOrder::LSBFirst);
+    }
+}
+
+#[cfg(test)]
+mod test_blend_mask {
+    use super::{AlphaMask, BitsPerPixel, Image, ImageOrder, PixelFormat, ScanlinePad};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::Rectangle;
+
+    // A 3x2 glyph atlas
+    const ATLAS: [u8; 8] = [0, 128, 255, 0, 255, 64, 0, 0];
+
+    fn draw(bpp: BitsPerPixel, order: ImageOrder, format: PixelFormat) -> Image<'static> {
+        let mut image = Image::allocate(3, 2, ScanlinePad::Pad32, 24, bpp, order);
+        let background = format.encode((0, 0, 0xffff, 0xffff));
+        for (x, y) in [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)] {
+            image.put_pixel(x, y, background);
+        }
+        let mask = AlphaMask::new(&ATLAS, 3, 2, 4).unwrap();
+        let rect = Rectangle {
+            x: 0,
+            y: 0,
+            width: 3,
+            height: 2,
+        };
+        // Clipped on the left side
+        image.blend_mask(&mask, rect, -1, 0, (0xffff, 0, 0), format.layout());
+        image
+    }
+
+    fn check(bpp: BitsPerPixel, order: ImageOrder, format: PixelFormat, expected: [u32; 3]) {
+        let image = draw(bpp, order, format);
+        let pixels = [
+            image.get_pixel(0, 0),
+            image.get_pixel(1, 0),
+            image.get_pixel(2, 0),
+            image.get_pixel(0, 1),
+            image.get_pixel(1, 1),
+            image.get_pixel(2, 1),
+        ];
+        let [half, full, none] = expected;
+        let quarter = format.encode((0x4040, 0, 0xbfbf, 0xffff));
+        assert_eq!(pixels, [half, full, none, quarter, none, none]);
+    }
+
+    #[test]
+    fn bytewise() {
+        let expected = [0x80_007f, 0xff_0000, 0x00_00ff];
+        check(
+            BitsPerPixel::B32,
+            ImageOrder::LSBFirst,
+            PixelFormat::XRGB8888,
+            expected,
+        );
+        check(
+            BitsPerPixel::B32,
+            ImageOrder::MSBFirst,
+            PixelFormat::XRGB8888,
+            expected,
+        );
+        let expected = [0x7f00_8000, 0x00_00ff00, 0xff00_0000];
+        check(
+            BitsPerPixel::B32,
+            ImageOrder::LSBFirst,
+            PixelFormat::BGRX8888,
+            expected,
+        );
+    }
+
+    #[test]
+    fn per_pixel() {
+        let expected = [0x8000 | 0xf, 0xf800, 0x1f];
+        check(
+            BitsPerPixel::B16,
+            ImageOrder::LSBFirst,
+            PixelFormat::RGB565,
+            expected,
+        );
+    }
+
+    #[test]
+    fn new_mask() {
+        assert!(AlphaMask::new(&ATLAS, 3, 2, 4).is_ok());
+        assert!(AlphaMask::new(&ATLAS, 4, 2, 4).is_ok());
+        assert!(AlphaMask::new(&[], 4, 0, 4).is_ok());
+        assert_eq!(
+            AlphaMask::new(&ATLAS, 3, 3, 4).unwrap_err(),
+            ParseError::InsufficientData
+        );
+        assert_eq!(
+            AlphaMask::new(&ATLAS, 5, 1, 4).unwrap_err(),
+            ParseError::InvalidValue
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_image {
     use super::{BitsPerPixel, Image, ImageOrder, ParseError, ScanlinePad};
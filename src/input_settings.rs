@@ -0,0 +1,252 @@
+//! Saving and restoring the input settings of the core protocol.
+//!
+//! Games and test harnesses sometimes change global input settings, e.g. they turn off key repeat
+//! or pointer acceleration, or swap pointer buttons. These settings belong to the whole session,
+//! so they should be restored afterwards. [`InputSettingsSnapshot`] captures them with a single
+//! round trip and restores them later:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::input_settings::InputSettingsSnapshot;
+//! use x11rb::protocol::xproto::{self, AutoRepeatMode, ChangeKeyboardControlAux, MappingStatus};
+//!
+//! fn play(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     let snapshot = InputSettingsSnapshot::capture(conn)?;
+//!     let aux = ChangeKeyboardControlAux::new().auto_repeat_mode(AutoRepeatMode::OFF);
+//!     xproto::change_keyboard_control(conn, &aux)?;
+//!     // Run the game
+//!     if snapshot.restore(conn)? != MappingStatus::SUCCESS {
+//!         println!("Some keys or buttons are still pressed, try again later");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Only the settings of the core protocol are covered. The keyboard LEDs are not restored, since
+//! they show the state of e.g. Caps Lock. Settings of the XKB and XInput extensions, like the key
+//! repeat rate or per-device acceleration profiles, are not part of the snapshot.
+
+use crate::connection::RequestConnection;
+use crate::errors::ReplyError;
+use crate::protocol::xproto::{
+    self, AutoRepeatMode, ChangeKeyboardControlAux, GetKeyboardControlReply, Keycode, MappingStatus,
+};
+
+/// The input settings of the core protocol at some point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSettingsSnapshot {
+    /// Whether keys repeat at all.
+    pub auto_repeat: bool,
+    /// A bit vector of the keys that repeat, indexed by keycode.
+    pub auto_repeats: [u8; 32],
+    /// The volume of key clicks in percent.
+    pub key_click_percent: u8,
+    /// The volume of the bell in percent.
+    pub bell_percent: u8,
+    /// The pitch of the bell in Hz.
+    pub bell_pitch: u16,
+    /// The duration of the bell in milliseconds.
+    pub bell_duration: u16,
+    /// The pointer acceleration as numerator and denominator.
+    pub acceleration: (u16, u16),
+    /// The number of pixels that the pointer has to move at once before it is accelerated.
+    pub threshold: u16,
+    /// The button mapping of the core pointer.
+    pub button_mapping: Vec<u8>,
+    /// The number of keycodes for each of the eight modifiers.
+    pub keycodes_per_modifier: u8,
+    /// The keycodes of the modifiers, `keycodes_per_modifier` for each of them.
+    pub modifier_mapping: Vec<Keycode>,
+}
+
+impl InputSettingsSnapshot {
+    /// Get the current input settings.
+    ///
+    /// All requests are sent before waiting for the first reply.
+    pub fn capture<C: RequestConnection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        let keyboard = xproto::get_keyboard_control(conn)?;
+        let pointer = xproto::get_pointer_control(conn)?;
+        let buttons = xproto::get_pointer_mapping(conn)?;
+        let modifiers = xproto::get_modifier_mapping(conn)?;
+        let keyboard = keyboard.reply()?;
+        let pointer = pointer.reply()?;
+        let modifiers = modifiers.reply()?;
+        Ok(Self {
+            auto_repeat: keyboard.global_auto_repeat != AutoRepeatMode::OFF,
+            auto_repeats: keyboard.auto_repeats,
+            key_click_percent: keyboard.key_click_percent,
+            bell_percent: keyboard.bell_percent,
+            bell_pitch: keyboard.bell_pitch,
+            bell_duration: keyboard.bell_duration,
+            acceleration: (
+                pointer.acceleration_numerator,
+                pointer.acceleration_denominator,
+            ),
+            threshold: pointer.threshold,
+            button_mapping: buttons.reply()?.map,
+            keycodes_per_modifier: modifiers.keycodes_per_modifier(),
+            modifier_mapping: modifiers.keycodes,
+        })
+    }
+
+    /// Check whether the key with the given keycode repeats.
+    pub fn key_repeats(&self, keycode: Keycode) -> bool {
+        self.auto_repeats[usize::from(keycode / 8)] & (1 << (keycode % 8)) != 0
+    }
+
+    /// Change the input settings back to this snapshot.
+    ///
+    /// The button and modifier mappings cannot be changed while an affected button or key is
+    /// pressed. In this case, the returned status is [`MappingStatus::BUSY`] and `restore()`
+    /// should be called again later. All other settings are restored anyway.
+    ///
+    /// Only the repeat setting of keys that changed since the snapshot is sent, which needs
+    /// another round trip.
+    pub fn restore<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+    ) -> Result<MappingStatus, ReplyError> {
+        let current = xproto::get_keyboard_control(conn)?;
+        let global_mode = if self.auto_repeat {
+            AutoRepeatMode::ON
+        } else {
+            AutoRepeatMode::OFF
+        };
+        let aux = ChangeKeyboardControlAux::new()
+            .key_click_percent(i32::from(self.key_click_percent))
+            .bell_percent(i32::from(self.bell_percent))
+            .bell_pitch(i32::from(self.bell_pitch))
+            .bell_duration(i32::from(self.bell_duration))
+            .auto_repeat_mode(global_mode);
+        let _ = xproto::change_keyboard_control(conn, &aux)?;
+        let _ = xproto::change_pointer_control(
+            conn,
+            self.acceleration.0 as i16,
+            self.acceleration.1 as i16,
+            self.threshold as i16,
+            true,
+            true,
+        )?;
+        let buttons = xproto::set_pointer_mapping(conn, &self.button_mapping)?;
+        let modifiers = xproto::set_modifier_mapping(conn, &self.modifier_mapping)?;
+        self.restore_key_repeats(conn, &current.reply()?)?;
+        let buttons = buttons.reply()?.status;
+        let modifiers = modifiers.reply()?.status;
+        Ok(if buttons != MappingStatus::SUCCESS {
+            buttons
+        } else {
+            modifiers
+        })
+    }
+
+    fn restore_key_repeats<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        current: &GetKeyboardControlReply,
+    ) -> Result<(), ReplyError> {
+        // Keycodes below 8 do not exist
+        for keycode in 8..=Keycode::MAX {
+            let (index, bit) = (usize::from(keycode / 8), 1 << (keycode % 8));
+            let repeats = self.key_repeats(keycode);
+            if repeats == (current.auto_repeats[index] & bit != 0) {
+                continue;
+            }
+            let mode = if repeats {
+                AutoRepeatMode::ON
+            } else {
+                AutoRepeatMode::OFF
+            };
+            let aux = ChangeKeyboardControlAux::new()
+                .key(u32::from(keycode))
+                .auto_repeat_mode(mode);
+            let _ = xproto::change_keyboard_control(conn, &aux)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::InputSettingsSnapshot;
+    use crate::protocol::xproto::{self, MappingStatus};
+    use crate::testing::{FakeConnection, Response};
+
+    fn keyboard_control(auto_repeats: [u8; 32]) -> Response {
+        let mut reply = vec![0; 52];
+        reply[1] = 1;
+        reply[12] = 50;
+        reply[14..16].copy_from_slice(&400u16.to_ne_bytes());
+        reply[20..52].copy_from_slice(&auto_repeats);
+        Response::Reply(reply)
+    }
+
+    fn respond(conn: &FakeConnection, major_opcode: u8, reply: Vec<u8>) {
+        conn.respond(major_opcode, None, Response::Reply(reply));
+    }
+
+    #[test]
+    fn capture_and_restore() {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::GET_KEYBOARD_CONTROL_REQUEST,
+            None,
+            keyboard_control([0xff; 32]),
+        );
+        let mut pointer = vec![0; 32];
+        pointer[8..10].copy_from_slice(&2u16.to_ne_bytes());
+        pointer[10..12].copy_from_slice(&1u16.to_ne_bytes());
+        pointer[12..14].copy_from_slice(&4u16.to_ne_bytes());
+        respond(&conn, xproto::GET_POINTER_CONTROL_REQUEST, pointer);
+        let mut buttons = vec![0; 35];
+        buttons[1] = 3;
+        buttons[32..35].copy_from_slice(&[3, 2, 1]);
+        respond(&conn, xproto::GET_POINTER_MAPPING_REQUEST, buttons);
+        let mut modifiers = vec![0; 40];
+        modifiers[1] = 1;
+        modifiers[32] = 50;
+        respond(&conn, xproto::GET_MODIFIER_MAPPING_REQUEST, modifiers);
+
+        let snapshot = InputSettingsSnapshot::capture(&conn).unwrap();
+        assert!(snapshot.auto_repeat);
+        assert_eq!(snapshot.key_click_percent, 50);
+        assert_eq!(snapshot.bell_pitch, 400);
+        assert_eq!((snapshot.acceleration, snapshot.threshold), ((2, 1), 4));
+        assert_eq!(snapshot.button_mapping, [3, 2, 1]);
+        assert_eq!(snapshot.keycodes_per_modifier, 1);
+        assert_eq!(snapshot.modifier_mapping, [50, 0, 0, 0, 0, 0, 0, 0]);
+        let _ = conn.take_sent_requests();
+
+        // Two keys stopped repeating in the meantime
+        let mut auto_repeats = [0xff; 32];
+        auto_repeats[3] = 0xfc;
+        conn.respond(
+            xproto::GET_KEYBOARD_CONTROL_REQUEST,
+            None,
+            keyboard_control(auto_repeats),
+        );
+        respond(&conn, xproto::SET_POINTER_MAPPING_REQUEST, vec![0; 32]);
+        let mut busy = vec![0; 32];
+        busy[1] = u8::from(MappingStatus::BUSY);
+        respond(&conn, xproto::SET_MODIFIER_MAPPING_REQUEST, busy);
+        assert_eq!(snapshot.restore(&conn).unwrap(), MappingStatus::BUSY);
+
+        let opcodes = conn
+            .sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            opcodes,
+            [
+                xproto::GET_KEYBOARD_CONTROL_REQUEST,
+                xproto::CHANGE_KEYBOARD_CONTROL_REQUEST,
+                xproto::CHANGE_POINTER_CONTROL_REQUEST,
+                xproto::SET_POINTER_MAPPING_REQUEST,
+                xproto::SET_MODIFIER_MAPPING_REQUEST,
+                xproto::CHANGE_KEYBOARD_CONTROL_REQUEST,
+                xproto::CHANGE_KEYBOARD_CONTROL_REQUEST,
+            ]
+        );
+    }
+}
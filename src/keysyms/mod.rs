@@ -0,0 +1,410 @@
+//! Translating keycodes into keysyms and characters.
+//!
+//! `KeyPress` events only contain a keycode and the state of the modifiers. Which symbol a key
+//! produces is described by the keyboard mapping (`GetKeyboardMapping`) and the modifier mapping
+//! (`GetModifierMapping`). The core protocol specification describes how to pick a keysym from
+//! these, taking `Shift`, `Lock`, `Num_Lock`, and `Mode_switch` into account. [`KeyboardMapping`]
+//! implements these rules and keeps the mappings up to date when `MappingNotify` events arrive.
+//!
+//! [`keysym_to_char`] translates a keysym into the character that it types.
+//!
+//! This module does not implement the XKB extension. With XKB, the core keyboard mapping is still
+//! available, but only contains the first two groups of each key.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::keysyms::KeyboardMapping;
+//! use x11rb::protocol::Event;
+//!
+//! fn print_typed_text(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     let mut mapping = KeyboardMapping::new(conn)?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         let _ = mapping.handle_event(conn, &event)?;
+//!         if let Event::KeyPress(event) = event {
+//!             if let Some(c) = mapping.lookup_char(event.detail, event.state) {
+//!                 print!("{}", c);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::connection::Connection;
+use crate::errors::ReplyError;
+use crate::protocol::xproto::{self, GetModifierMappingReply, Keycode, Keysym, Mapping, ModMask};
+use crate::protocol::Event;
+use crate::NO_SYMBOL;
+
+mod table;
+
+const KEYSYM_MODE_SWITCH: Keysym = 0xff7e;
+const KEYSYM_NUM_LOCK: Keysym = 0xff7f;
+const KEYSYM_CAPS_LOCK: Keysym = 0xffe5;
+const KEYSYM_SHIFT_LOCK: Keysym = 0xffe6;
+
+/// The offset of keysyms that directly encode a Unicode code point.
+const UNICODE_OFFSET: Keysym = 0x0100_0000;
+
+/// The meaning of the `Lock` modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockMode {
+    /// `Lock` does not affect keysym selection.
+    None,
+    /// `Lock` converts letters to upper case.
+    CapsLock,
+    /// `Lock` acts like `Shift`.
+    ShiftLock,
+}
+
+/// A cached copy of the keyboard and modifier mappings of the X11 server.
+#[derive(Debug, Clone)]
+pub struct KeyboardMapping {
+    min_keycode: Keycode,
+    max_keycode: Keycode,
+    keysyms_per_keycode: u8,
+    keysyms: Vec<Keysym>,
+    modifiers: GetModifierMappingReply,
+    lock_mode: LockMode,
+    mode_switch_mask: u16,
+    num_lock_mask: u16,
+}
+
+impl KeyboardMapping {
+    /// Fetch the keyboard and modifier mappings from the X11 server.
+    pub fn new<C: Connection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        let setup = conn.setup();
+        let (min_keycode, max_keycode) = (setup.min_keycode, setup.max_keycode);
+        let keyboard =
+            xproto::get_keyboard_mapping(conn, min_keycode, max_keycode - min_keycode + 1)?;
+        let modifiers = xproto::get_modifier_mapping(conn)?;
+        let keyboard = keyboard.reply()?;
+        let mut result = Self {
+            min_keycode,
+            max_keycode,
+            keysyms_per_keycode: keyboard.keysyms_per_keycode,
+            keysyms: keyboard.keysyms,
+            modifiers: modifiers.reply()?,
+            lock_mode: LockMode::None,
+            mode_switch_mask: 0,
+            num_lock_mask: 0,
+        };
+        result.update_modifiers();
+        Ok(result)
+    }
+
+    /// Refresh the cached mappings when a `MappingNotify` event arrives.
+    ///
+    /// Returns `true` if the event was a `MappingNotify` event for the keyboard or modifier
+    /// mapping.
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ReplyError> {
+        let request = match event {
+            Event::MappingNotify(event) => event.request,
+            _ => return Ok(false),
+        };
+        if request == Mapping::KEYBOARD {
+            let keyboard = xproto::get_keyboard_mapping(
+                conn,
+                self.min_keycode,
+                self.max_keycode - self.min_keycode + 1,
+            )?
+            .reply()?;
+            self.keysyms_per_keycode = keyboard.keysyms_per_keycode;
+            self.keysyms = keyboard.keysyms;
+        } else if request == Mapping::MODIFIER {
+            self.modifiers = xproto::get_modifier_mapping(conn)?.reply()?;
+        } else {
+            return Ok(false);
+        }
+        // Both mappings are needed to figure out which modifiers are Mode_switch and Num_Lock
+        self.update_modifiers();
+        Ok(true)
+    }
+
+    /// Get all keysyms of a keycode.
+    ///
+    /// Returns an empty slice for keycodes outside of the server's keycode range.
+    pub fn keysyms(&self, keycode: Keycode) -> &[Keysym] {
+        if keycode < self.min_keycode || keycode > self.max_keycode {
+            return &[];
+        }
+        let per_keycode = usize::from(self.keysyms_per_keycode);
+        let start = usize::from(keycode - self.min_keycode) * per_keycode;
+        self.keysyms.get(start..start + per_keycode).unwrap_or(&[])
+    }
+
+    /// Get the keysym that a key produces with the given modifier state.
+    ///
+    /// This implements the rules of the core protocol specification. Returns [`NO_SYMBOL`] if
+    /// the key does not produce a keysym.
+    pub fn keycode_to_keysym(&self, keycode: Keycode, state: impl Into<u16>) -> Keysym {
+        let state = state.into();
+        let syms = self.keysyms(keycode);
+        // Trailing NoSymbol entries are ignored
+        let len = syms
+            .iter()
+            .rposition(|&sym| sym != NO_SYMBOL)
+            .map_or(0, |pos| pos + 1);
+        let syms = &syms[..len];
+        let group = match syms {
+            [] => return NO_SYMBOL,
+            [k] => [*k, NO_SYMBOL],
+            [k1, k2] => [*k1, *k2],
+            [k1, k2, rest @ ..] => {
+                if state & self.mode_switch_mask != 0 {
+                    [rest[0], rest.get(1).copied().unwrap_or(NO_SYMBOL)]
+                } else {
+                    [*k1, *k2]
+                }
+            }
+        };
+        let (lower, upper) = match group {
+            [k, NO_SYMBOL] => convert_case(k),
+            [k1, k2] => (k1, k2),
+        };
+
+        let shift = state & u16::from(ModMask::SHIFT) != 0;
+        let lock = state & u16::from(ModMask::LOCK) != 0;
+        if state & self.num_lock_mask != 0 && is_keypad(upper) {
+            if shift || (lock && self.lock_mode == LockMode::ShiftLock) {
+                lower
+            } else {
+                upper
+            }
+        } else if !shift && (!lock || self.lock_mode == LockMode::None) {
+            lower
+        } else if !shift && self.lock_mode == LockMode::CapsLock {
+            convert_case(lower).1
+        } else if shift && lock && self.lock_mode == LockMode::CapsLock {
+            convert_case(upper).1
+        } else {
+            upper
+        }
+    }
+
+    /// Get the character that a key types with the given modifier state.
+    ///
+    /// This is [`keycode_to_keysym`](Self::keycode_to_keysym) followed by [`keysym_to_char`].
+    /// The `Control` modifier is not taken into account.
+    pub fn lookup_char(&self, keycode: Keycode, state: impl Into<u16>) -> Option<char> {
+        keysym_to_char(self.keycode_to_keysym(keycode, state))
+    }
+
+    /// Figure out the meaning of `Lock` and which modifiers are `Mode_switch` and `Num_Lock`.
+    fn update_modifiers(&mut self) {
+        let (mut lock_mode, mut mode_switch_mask, mut num_lock_mask) = (LockMode::None, 0, 0);
+        let per_modifier = usize::from(self.modifiers.keycodes_per_modifier()).max(1);
+        for (index, keycodes) in self.modifiers.keycodes.chunks(per_modifier).enumerate() {
+            let mask = 1 << index;
+            let keysyms = keycodes
+                .iter()
+                .flat_map(|&keycode| self.keysyms(keycode).iter().copied());
+            for keysym in keysyms {
+                match (mask, keysym) {
+                    (2, KEYSYM_CAPS_LOCK) => lock_mode = LockMode::CapsLock,
+                    (2, KEYSYM_SHIFT_LOCK) if lock_mode == LockMode::None => {
+                        lock_mode = LockMode::ShiftLock
+                    }
+                    // Only Mod1 to Mod5 can be Mode_switch or Num_Lock
+                    (8..=128, KEYSYM_MODE_SWITCH) => mode_switch_mask |= mask,
+                    (8..=128, KEYSYM_NUM_LOCK) => num_lock_mask |= mask,
+                    _ => {}
+                }
+            }
+        }
+        self.lock_mode = lock_mode;
+        self.mode_switch_mask = mode_switch_mask;
+        self.num_lock_mask = num_lock_mask;
+    }
+}
+
+/// Check if a keysym is on the keypad.
+fn is_keypad(keysym: Keysym) -> bool {
+    (0xff80..=0xffbd).contains(&keysym)
+}
+
+/// Get the character that a keysym types.
+///
+/// This handles Latin-1 keysyms, the legacy keysyms listed in `keysymdef.h`, keysyms that
+/// directly encode a Unicode code point, and special keysyms like `Return`, `BackSpace`, and the
+/// keypad digits. Returns `None` for keysyms that do not type a character, e.g. `Shift_L`.
+pub fn keysym_to_char(keysym: Keysym) -> Option<char> {
+    let code_point = match keysym {
+        0x20..=0x7e | 0xa0..=0xff => keysym,
+        // BackSpace, Tab, Linefeed, Clear, Return, Escape, Delete, and KP_Multiply to KP_9
+        0xff08..=0xff0b | 0xff0d | 0xff1b | 0xffff | 0xffaa..=0xffb9 => keysym & 0x7f,
+        // KP_Space
+        0xff80 => 0x20,
+        // KP_Tab, KP_Enter, KP_Equal
+        0xff89 | 0xff8d | 0xffbd => keysym & 0x7f,
+        0x0100_0100..=0x0110_ffff => keysym - UNICODE_OFFSET,
+        _ => {
+            let keysym = u16::try_from(keysym).ok()?;
+            let index = table::KEYSYM_TO_UNICODE
+                .binary_search_by_key(&keysym, |&(sym, _)| sym)
+                .ok()?;
+            table::KEYSYM_TO_UNICODE[index].1.into()
+        }
+    };
+    std::char::from_u32(code_point)
+}
+
+/// Get the keysym that types a character.
+///
+/// Latin-1 characters and characters with a legacy keysym are mapped to that keysym. All other
+/// characters are mapped to the keysym that encodes their Unicode code point. Control characters
+/// are mapped to keysyms like `Return`.
+pub fn char_to_keysym(c: char) -> Keysym {
+    let code_point = u32::from(c);
+    match code_point {
+        0x20..=0x7e | 0xa0..=0xff => code_point,
+        0x08..=0x0b | 0x0d | 0x1b => code_point | 0xff00,
+        0x7f => 0xffff,
+        _ => table::KEYSYM_TO_UNICODE
+            .iter()
+            .find(|&&(_, cp)| u32::from(cp) == code_point)
+            .map_or(code_point + UNICODE_OFFSET, |&(sym, _)| sym.into()),
+    }
+}
+
+/// Get the lower and upper case variant of a keysym.
+///
+/// Keysyms that do not represent a cased letter are returned unchanged.
+pub fn convert_case(keysym: Keysym) -> (Keysym, Keysym) {
+    let c = match keysym_to_char(keysym) {
+        Some(c) if c.is_alphabetic() => c,
+        _ => return (keysym, keysym),
+    };
+    // Conversions that result in multiple characters, like `ß` to `SS`, are ignored
+    let lower = single_char(c.to_lowercase()).unwrap_or(c);
+    let upper = single_char(c.to_uppercase()).unwrap_or(c);
+    let to_keysym = |other: char| {
+        if other == c {
+            keysym
+        } else if keysym >= UNICODE_OFFSET {
+            u32::from(other) + UNICODE_OFFSET
+        } else {
+            char_to_keysym(other)
+        }
+    };
+    (to_keysym(lower), to_keysym(upper))
+}
+
+fn single_char(mut iter: impl Iterator<Item = char>) -> Option<char> {
+    match (iter.next(), iter.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{char_to_keysym, convert_case, keysym_to_char, KeyboardMapping, LockMode};
+    use crate::protocol::xproto::{GetModifierMappingReply, Keysym, ModMask};
+
+    const SHIFT: u16 = 1;
+    const LOCK: u16 = 2;
+    const MOD2: u16 = 16;
+    const MOD5: u16 = 128;
+
+    /// A keyboard with keycodes 8 to 12 and 4 keysyms per keycode.
+    fn mapping() -> KeyboardMapping {
+        #[rustfmt::skip]
+        let keysyms: Vec<Keysym> = vec![
+            // 8: a, A, ae, AE
+            0x61, 0, 0xe6, 0,
+            // 9: 1, exclam
+            0x31, 0x21, 0, 0,
+            // 10: KP_End, KP_1
+            0xff9c, 0xffb1, 0, 0,
+            // 11: Caps_Lock
+            0xffe5, 0, 0, 0,
+            // 12: Num_Lock, Mode_switch
+            0xff7f, 0, 0xff7e, 0,
+        ];
+        let modifiers = GetModifierMappingReply {
+            sequence: 0,
+            length: 0,
+            // Lock is Caps_Lock, Mod2 is Num_Lock, Mod5 is Mode_switch (via the third keysym)
+            keycodes: vec![0, 11, 0, 0, 12, 0, 0, 12],
+        };
+        let mut mapping = KeyboardMapping {
+            min_keycode: 8,
+            max_keycode: 12,
+            keysyms_per_keycode: 4,
+            keysyms,
+            modifiers,
+            lock_mode: LockMode::None,
+            mode_switch_mask: 0,
+            num_lock_mask: 0,
+        };
+        mapping.update_modifiers();
+        mapping
+    }
+
+    #[test]
+    fn modifier_meanings() {
+        let mapping = mapping();
+        assert_eq!(mapping.lock_mode, LockMode::CapsLock);
+        assert_eq!(mapping.num_lock_mask, MOD2 | MOD5);
+        assert_eq!(mapping.mode_switch_mask, MOD2 | MOD5);
+        assert_eq!(mapping.keysyms(7), []);
+        assert_eq!(mapping.keysyms(13), []);
+    }
+
+    #[test]
+    fn keysym_selection() {
+        let mut mapping = mapping();
+        mapping.mode_switch_mask = u16::from(ModMask::M5);
+        mapping.num_lock_mask = u16::from(ModMask::M2);
+        assert_eq!(mapping.keycode_to_keysym(8, 0u16), 0x61);
+        assert_eq!(mapping.keycode_to_keysym(8, SHIFT), 0x41);
+        assert_eq!(mapping.keycode_to_keysym(8, LOCK), 0x41);
+        assert_eq!(mapping.keycode_to_keysym(8, SHIFT | LOCK), 0x41);
+        assert_eq!(mapping.keycode_to_keysym(8, MOD5), 0xe6);
+        assert_eq!(mapping.keycode_to_keysym(8, MOD5 | SHIFT), 0xc6);
+        assert_eq!(mapping.keycode_to_keysym(9, 0u16), 0x31);
+        assert_eq!(mapping.keycode_to_keysym(9, SHIFT), 0x21);
+        assert_eq!(mapping.keycode_to_keysym(9, LOCK), 0x31);
+        assert_eq!(mapping.keycode_to_keysym(10, 0u16), 0xff9c);
+        assert_eq!(mapping.keycode_to_keysym(10, MOD2), 0xffb1);
+        assert_eq!(mapping.keycode_to_keysym(10, MOD2 | SHIFT), 0xff9c);
+        assert_eq!(mapping.lookup_char(10, MOD2), Some('1'));
+        assert_eq!(mapping.lookup_char(11, 0u16), None);
+    }
+
+    #[test]
+    fn keysym_chars() {
+        assert_eq!(keysym_to_char(0x61), Some('a'));
+        assert_eq!(keysym_to_char(0xe9), Some('é'));
+        assert_eq!(keysym_to_char(0x01a1), Some('Ą'));
+        assert_eq!(keysym_to_char(0x06c1), Some('а'));
+        assert_eq!(keysym_to_char(0x20ac), Some('€'));
+        assert_eq!(keysym_to_char(0x0100_263a), Some('☺'));
+        assert_eq!(keysym_to_char(0xff0d), Some('\r'));
+        assert_eq!(keysym_to_char(0xff80), Some(' '));
+        assert_eq!(keysym_to_char(0xffe1), None);
+        assert_eq!(char_to_keysym('a'), 0x61);
+        assert_eq!(char_to_keysym('Ą'), 0x01a1);
+        assert_eq!(char_to_keysym('☺'), 0x0100_263a);
+        assert_eq!(char_to_keysym('\r'), 0xff0d);
+    }
+
+    #[test]
+    fn case_conversion() {
+        assert_eq!(convert_case(0x61), (0x61, 0x41));
+        assert_eq!(convert_case(0x41), (0x61, 0x41));
+        assert_eq!(convert_case(0x31), (0x31, 0x31));
+        // Cyrillic a and A
+        assert_eq!(convert_case(0x06c1), (0x06c1, 0x06e1));
+        // Unicode keysyms stay in the Unicode range
+        assert_eq!(convert_case(0x0100_0101), (0x0100_0101, 0x0100_0100));
+    }
+}
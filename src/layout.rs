@@ -0,0 +1,356 @@
+//! Applying many geometry changes at once.
+//!
+//! When a tiling window manager changes its layout, many windows are moved and resized at the
+//! same time. Sending the `ConfigureWindow` requests one by one and flushing in between results
+//! in visible intermediate states. [`LayoutTransaction`] collects the changes and sends them all
+//! at once, optionally while the server is grabbed.
+//!
+//! Clients that support `_NET_WM_SYNC_REQUEST` can additionally tell the window manager when they
+//! finished redrawing at their new size. [`SyncClient`] tracks the sync counter of such a client,
+//! and [`PendingSync`] checks whether all clients caught up with a transaction.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::layout::{LayoutTransaction, SyncClient};
+//! use x11rb::protocol::xproto::Rectangle;
+//!
+//! fn tile(
+//!     conn: &impl Connection,
+//!     clients: &mut [(Option<SyncClient>, u32)],
+//!     area: Rectangle,
+//! ) -> Result<(), ReplyError> {
+//!     let width = area.width / clients.len() as u16;
+//!     let mut transaction = LayoutTransaction::new().grab_server(true);
+//!     for (index, (sync, window)) in clients.iter_mut().enumerate() {
+//!         let geometry = Rectangle {
+//!             x: area.x + (index as u16 * width) as i16,
+//!             width,
+//!             ..area
+//!         };
+//!         transaction.set_geometry(*window, geometry);
+//!         if let Some(sync) = sync {
+//!             transaction.sync(sync);
+//!         }
+//!     }
+//!     let pending = transaction.commit(conn, x11rb::CURRENT_TIME)?;
+//!     // Later, e.g. before drawing the next frame of a compositor
+//!     if pending.is_complete(conn)? {
+//!         println!("All clients redrew themselves");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::sync::{self, Counter, Int64};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, ConfigureWindowAux, EventMask, Rectangle, Timestamp,
+    Window,
+};
+
+atom_manager! {
+    SyncAtoms: SyncAtomsCookie {
+        WM_PROTOCOLS,
+        _NET_WM_SYNC_REQUEST,
+        _NET_WM_SYNC_REQUEST_COUNTER,
+    }
+}
+
+/// A client that supports the `_NET_WM_SYNC_REQUEST` protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncClient {
+    window: Window,
+    counter: Counter,
+    value: u64,
+    wm_protocols: Atom,
+    sync_request: Atom,
+}
+
+impl SyncClient {
+    /// Check if a client supports `_NET_WM_SYNC_REQUEST` and get its counter.
+    ///
+    /// Returns `None` if `_NET_WM_SYNC_REQUEST` is missing from the client's `WM_PROTOCOLS` or if
+    /// the client did not set `_NET_WM_SYNC_REQUEST_COUNTER`. The SYNC extension must already be
+    /// initialised via `Initialize`.
+    pub fn new<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<Option<Self>, ReplyError> {
+        let atoms = SyncAtoms::new(conn)?.reply()?;
+        let protocols = xproto::get_property(
+            conn,
+            false,
+            window,
+            atoms.WM_PROTOCOLS,
+            AtomEnum::ATOM,
+            0,
+            u32::MAX,
+        )?;
+        let counter = xproto::get_property(
+            conn,
+            false,
+            window,
+            atoms._NET_WM_SYNC_REQUEST_COUNTER,
+            AtomEnum::CARDINAL,
+            0,
+            1,
+        )?;
+        let supported = protocols
+            .reply()?
+            .value32()
+            .into_iter()
+            .flatten()
+            .any(|atom| atom == atoms._NET_WM_SYNC_REQUEST);
+        let counter = counter.reply()?.value32().and_then(|mut iter| iter.next());
+        let counter = match counter {
+            Some(counter) if supported && counter != 0 => counter,
+            _ => return Ok(None),
+        };
+        let value = sync::query_counter(conn, counter)?.reply()?.counter_value;
+        Ok(Some(Self {
+            window,
+            counter,
+            value: from_int64(value),
+            wm_protocols: atoms.WM_PROTOCOLS,
+            sync_request: atoms._NET_WM_SYNC_REQUEST,
+        }))
+    }
+
+    /// Get the client window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the sync counter of the client.
+    pub fn counter(&self) -> Counter {
+        self.counter
+    }
+}
+
+/// A collection of geometry changes that are sent together.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutTransaction {
+    changes: Vec<(Window, ConfigureWindowAux)>,
+    sync: Vec<(SyncClient, u64)>,
+    grab_server: bool,
+}
+
+impl LayoutTransaction {
+    /// Create a new, empty transaction.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set whether the server should be grabbed while the changes are sent.
+    ///
+    /// Grabbing the server makes sure that no other client, e.g. a compositor, sees only some of
+    /// the changes.
+    pub fn grab_server(mut self, grab_server: bool) -> Self {
+        self.grab_server = grab_server;
+        self
+    }
+
+    /// Add a change to a window.
+    ///
+    /// Changes to the same window are merged, with later values replacing earlier ones.
+    pub fn configure(&mut self, window: Window, aux: ConfigureWindowAux) {
+        match self.changes.iter_mut().find(|(w, _)| *w == window) {
+            Some((_, existing)) => *existing = merge(*existing, aux),
+            None => self.changes.push((window, aux)),
+        }
+    }
+
+    /// Move and resize a window.
+    pub fn set_geometry(&mut self, window: Window, geometry: Rectangle) {
+        let aux = ConfigureWindowAux::new()
+            .x(i32::from(geometry.x))
+            .y(i32::from(geometry.y))
+            .width(u32::from(geometry.width))
+            .height(u32::from(geometry.height));
+        self.configure(window, aux);
+    }
+
+    /// Send a `_NET_WM_SYNC_REQUEST` to the client before its window is configured.
+    ///
+    /// This increments the value that the client is asked to set its counter to.
+    pub fn sync(&mut self, client: &mut SyncClient) {
+        client.value = client.value.wrapping_add(1);
+        match self
+            .sync
+            .iter_mut()
+            .find(|(c, _)| c.window == client.window)
+        {
+            Some(entry) => *entry = (*client, client.value),
+            None => self.sync.push((*client, client.value)),
+        }
+    }
+
+    /// Check if the transaction contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Send all changes and flush the connection.
+    ///
+    /// `time` is the timestamp of the event that caused the layout change and is passed on in
+    /// `_NET_WM_SYNC_REQUEST` messages.
+    pub fn commit<C: Connection + ?Sized>(
+        self,
+        conn: &C,
+        time: Timestamp,
+    ) -> Result<PendingSync, ConnectionError> {
+        if self.grab_server {
+            let _ = xproto::grab_server(conn)?;
+        }
+        let result = self.send(conn, time);
+        if self.grab_server {
+            let _ = xproto::ungrab_server(conn)?;
+        }
+        conn.flush()?;
+        result?;
+        let counters = self
+            .sync
+            .iter()
+            .map(|(client, value)| (client.counter, *value))
+            .collect();
+        Ok(PendingSync { counters })
+    }
+
+    fn send<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        for (client, value) in self.sync.iter() {
+            let event = ClientMessageEvent {
+                response_type: xproto::CLIENT_MESSAGE_EVENT,
+                format: 32,
+                sequence: 0,
+                window: client.window,
+                type_: client.wm_protocols,
+                data: [
+                    client.sync_request,
+                    time,
+                    *value as u32,
+                    (*value >> 32) as u32,
+                    0,
+                ]
+                .into(),
+            };
+            let _ = xproto::send_event(conn, false, client.window, EventMask::NO_EVENT, event)?;
+        }
+        for (window, aux) in self.changes.iter() {
+            let _ = xproto::configure_window(conn, *window, aux)?;
+        }
+        Ok(())
+    }
+}
+
+/// The counter values that clients were asked to reach by a [`LayoutTransaction`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingSync {
+    counters: Vec<(Counter, u64)>,
+}
+
+impl PendingSync {
+    /// Get the counters together with the value that each of them has to reach.
+    ///
+    /// This can be used to create SYNC alarms instead of polling [`is_complete()`](Self::is_complete).
+    pub fn counters(&self) -> &[(Counter, u64)] {
+        &self.counters
+    }
+
+    /// Check if all clients set their counters to the requested values.
+    ///
+    /// Counters that no longer exist, e.g. because their client exited, are considered complete.
+    pub fn is_complete<C: RequestConnection + ?Sized>(&self, conn: &C) -> Result<bool, ReplyError> {
+        let cookies = self
+            .counters
+            .iter()
+            .map(|&(counter, value)| Ok((sync::query_counter(conn, counter)?, value)))
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+        let mut complete = true;
+        for (cookie, value) in cookies {
+            match cookie.reply() {
+                Ok(reply) => complete &= from_int64(reply.counter_value) >= value,
+                Err(ReplyError::X11Error(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(complete)
+    }
+}
+
+/// Merge two sets of changes, preferring the values from `new`.
+fn merge(old: ConfigureWindowAux, new: ConfigureWindowAux) -> ConfigureWindowAux {
+    ConfigureWindowAux {
+        x: new.x.or(old.x),
+        y: new.y.or(old.y),
+        width: new.width.or(old.width),
+        height: new.height.or(old.height),
+        border_width: new.border_width.or(old.border_width),
+        sibling: new.sibling.or(old.sibling),
+        stack_mode: new.stack_mode.or(old.stack_mode),
+    }
+}
+
+/// Interpret a counter value as unsigned, since `_NET_WM_SYNC_REQUEST` values only increase.
+fn from_int64(value: Int64) -> u64 {
+    (u64::from(value.hi as u32) << 32) | u64::from(value.lo)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_int64, merge, LayoutTransaction, SyncClient};
+    use crate::protocol::sync::Int64;
+    use crate::protocol::xproto::{ConfigureWindowAux, Rectangle, StackMode};
+
+    #[test]
+    fn merge_changes() {
+        let mut transaction = LayoutTransaction::new();
+        transaction.set_geometry(
+            1,
+            Rectangle {
+                x: 1,
+                y: 2,
+                width: 3,
+                height: 4,
+            },
+        );
+        transaction.configure(2, ConfigureWindowAux::new().stack_mode(StackMode::ABOVE));
+        transaction.configure(1, ConfigureWindowAux::new().x(10).border_width(0));
+        assert_eq!(transaction.changes.len(), 2);
+        assert_eq!(
+            transaction.changes[0].1,
+            ConfigureWindowAux::new()
+                .x(10)
+                .y(2)
+                .width(3)
+                .height(4)
+                .border_width(0)
+        );
+        assert_eq!(
+            merge(Default::default(), Default::default()),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn sync_values_increase() {
+        let mut client = SyncClient {
+            window: 1,
+            counter: 2,
+            value: u64::from(u32::MAX),
+            wm_protocols: 3,
+            sync_request: 4,
+        };
+        let mut transaction = LayoutTransaction::new();
+        transaction.sync(&mut client);
+        transaction.sync(&mut client);
+        assert_eq!(transaction.sync.len(), 1);
+        assert_eq!(transaction.sync[0].1, 0x1_0000_0001);
+        assert_eq!(from_int64(Int64 { hi: 1, lo: 1 }), 0x1_0000_0001);
+    }
+}
@@ -104,10 +104,41 @@
 //! * `resource_manager`: Enable the code in [crate::resource_manager] for loading and querying the
 //!   X11 resource database.
 //! * `image`: Enable the code in [crate::image] for working with pixel image data.
+//! * `conformance`: Enable the code in [crate::conformance] for checking custom implementations
+//!   of [`Connection`](crate::connection::Connection), and in [crate::wm_conformance] for checking
+//!   window managers.
+//! * `testing`: Enable the code in [crate::testing], a fake connection for unit tests.
+//! * `dynamic`: Enable the code in [crate::dynamic] for describing and building requests at
+//!   runtime.
+//! * `protocol-docs`: Enable the code in [crate::protocol_docs] for looking up the documentation
+//!   of requests, replies and events at runtime.
+//! * `strict_parsing`: Make parsing fail with [`ParseError::InvalidValue`](errors::ParseError)
+//!   when a field with an enum type has a value that is not part of the enum, or when the
+//!   discriminant of a switch matches none of its cases. Without this feature, such values are
+//!   kept and switches produce their `InvalidValue` variant.
+//! * `serde`: Implement `Serialize` and `Deserialize` for the types in `screen_layout` and
+//!   `macros`, so that monitor configurations and recorded input can be saved.
 //! * `dl-libxcb`: Enabling this feature will prevent from libxcb being linked to the
 //!   resulting executable. Instead libxcb will be dynamically loaded at runtime.
 //!   This feature adds the [`crate::xcb_ffi::load_libxcb`] function, that allows to load
 //!   libxcb and check for success or failure.
+//! * `request-pruning`: Only compile the requests that are explicitly enabled, see below.
+//!
+//! ### Pruning requests
+//!
+//! Tools that only use a few requests can reduce their binary size with the `request-pruning`
+//! feature. With this feature, each request's struct, reply, and functions are only compiled
+//! if the request is enabled via a `x11rb_request` cfg of the form `<module>::<Request>`, for
+//! example:
+//!
+//! ```text
+//! RUSTFLAGS='--cfg x11rb_request="xproto::CreateWindow" --cfg x11rb_request="xproto::MapWindow"'
+//! ```
+//!
+//! The opcode constants of all requests and the requests that x11rb uses internally (like
+//! `QueryExtension` and `InternAtom`) are always available. Helper modules that send other
+//! requests, e.g. [crate::properties], are not available with this feature. Since this feature
+//! removes API, it should only be enabled by the final binary and not by libraries.
 //!
 //! # Integrating x11rb with an Event Loop
 //!
@@ -118,7 +149,6 @@
     missing_copy_implementations,
     missing_debug_implementations,
     private_doc_tests,
-    rust_2018_idioms,
     //single_use_lifetimes,
     trivial_casts,
     trivial_numeric_casts,
@@ -135,6 +165,8 @@
     unused_qualifications,
     // Not everything in x11rb::protocol has doc comments
     missing_docs,
+    // serde's #[derive] generates an #[allow] for unused_extern_crates, which is part of this
+    rust_2018_idioms,
 )]
 #![allow(
     // This suggests a method that was stabilised in Rust 1.45, while our MSRV is 1.40.
@@ -152,21 +184,158 @@ pub mod utils;
 pub mod xcb_ffi;
 #[macro_use]
 pub mod x11_utils;
+#[cfg(all(
+    feature = "render",
+    feature = "shape",
+    not(feature = "request-pruning")
+))]
+pub mod accessibility;
+#[cfg(not(feature = "request-pruning"))]
+pub mod animation;
+#[cfg(not(feature = "request-pruning"))]
+pub mod atom_cache;
+#[cfg(not(feature = "request-pruning"))]
+pub mod attention;
+#[cfg(not(feature = "request-pruning"))]
+pub mod backing_store;
+#[cfg(not(feature = "request-pruning"))]
+pub mod button_mapping;
+pub mod clock;
+#[cfg(all(feature = "conformance", not(feature = "request-pruning")))]
+pub mod conformance;
 pub mod connection;
 pub mod cookie;
-#[cfg(feature = "cursor")]
+#[cfg(all(feature = "xinput", not(feature = "request-pruning")))]
+pub mod coordinate_transform;
+#[cfg(all(feature = "cursor", not(feature = "request-pruning")))]
 pub mod cursor;
+#[cfg(not(feature = "request-pruning"))]
+pub mod diagnose;
+#[cfg(all(feature = "image", not(feature = "request-pruning")))]
+pub mod dump;
+pub mod dyn_connection;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+pub mod error_text;
 pub mod errors;
+#[cfg(not(feature = "request-pruning"))]
+pub mod event_bridge;
+#[cfg(not(feature = "request-pruning"))]
+pub mod event_mask;
+pub mod event_queue;
+#[cfg(not(feature = "request-pruning"))]
+pub mod ewmh;
+pub mod expose;
 pub mod extension_manager;
-#[cfg(feature = "image")]
+#[cfg(not(feature = "request-pruning"))]
+pub mod first_frame;
+pub mod focus;
+#[cfg(not(feature = "request-pruning"))]
+pub mod fonts;
+#[cfg(all(
+    feature = "image",
+    feature = "present",
+    not(feature = "request-pruning")
+))]
+pub mod frame_capture;
+#[cfg(not(feature = "request-pruning"))]
+pub mod game_mode;
+#[cfg(not(feature = "request-pruning"))]
+pub mod grab;
+pub mod id_range;
+#[cfg(not(feature = "request-pruning"))]
+pub mod idle_inhibit;
+#[cfg(all(feature = "image", not(feature = "request-pruning")))]
 pub mod image;
+#[cfg(not(feature = "request-pruning"))]
+pub mod input_settings;
+#[cfg(not(feature = "request-pruning"))]
+pub mod keysyms;
+#[cfg(all(feature = "sync", not(feature = "request-pruning")))]
+pub mod layout;
+#[cfg(feature = "log")]
+pub mod logging;
+#[cfg(all(
+    feature = "record",
+    feature = "xtest",
+    not(feature = "request-pruning")
+))]
+pub mod macros;
+#[cfg(all(feature = "render", not(feature = "request-pruning")))]
+pub mod magnifier;
+pub mod middleware;
+#[cfg(all(feature = "composite", not(feature = "request-pruning")))]
+pub mod overlay_window;
+#[cfg(all(feature = "render", not(feature = "request-pruning")))]
+pub mod picture_pool;
+#[cfg(not(feature = "request-pruning"))]
+pub mod pixmap_cache;
+pub mod predefined_atoms;
+#[cfg(all(feature = "present", not(feature = "request-pruning")))]
+pub mod present_damage;
+#[cfg(not(feature = "request-pruning"))]
 pub mod properties;
+#[cfg(feature = "protocol-docs")]
+pub mod protocol_docs;
+pub mod raw_request;
+#[cfg(not(feature = "request-pruning"))]
+pub mod region_select;
+#[cfg(all(feature = "xinput", not(feature = "request-pruning")))]
+pub mod relative_pointer;
+pub mod resize;
+#[cfg(all(feature = "res", not(feature = "request-pruning")))]
+pub mod resource_monitor;
+pub mod restricted_connection;
+#[cfg(not(feature = "request-pruning"))]
+pub mod root_events;
 pub mod rust_connection;
+#[cfg(not(feature = "request-pruning"))]
+pub mod scanout;
+pub mod scoped;
+#[cfg(all(feature = "randr", not(feature = "request-pruning")))]
+pub mod screen_info;
+#[cfg(all(feature = "randr", not(feature = "request-pruning")))]
+pub mod screen_layout;
+#[cfg(all(feature = "xinput", not(feature = "request-pruning")))]
+pub mod scroll_emulation;
+#[cfg(all(feature = "xinput", not(feature = "request-pruning")))]
+pub mod seats;
+#[cfg(not(feature = "request-pruning"))]
+pub mod selection;
+#[cfg(not(feature = "request-pruning"))]
+pub mod send_event;
+#[cfg(not(feature = "request-pruning"))]
+pub mod server_time;
+#[cfg(all(
+    feature = "shm",
+    feature = "allow-unsafe-code",
+    unix,
+    not(feature = "request-pruning")
+))]
+pub mod shm;
+#[cfg(all(feature = "xinput", not(feature = "request-pruning")))]
+pub mod tablet;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(not(feature = "request-pruning"))]
+pub mod vsync;
+pub mod widget_state;
+#[cfg(not(feature = "request-pruning"))]
+pub mod window_manager;
+pub mod wire;
+#[cfg(not(feature = "request-pruning"))]
+pub mod wm;
+#[cfg(all(feature = "conformance", not(feature = "request-pruning")))]
+pub mod wm_conformance;
 pub mod wrapper;
+#[cfg(not(feature = "request-pruning"))]
+pub mod xembed;
+#[cfg(all(feature = "xim", not(feature = "request-pruning")))]
+pub mod xim;
 #[rustfmt::skip]
 #[allow(missing_docs)]
 pub mod protocol;
-#[cfg(feature = "resource_manager")]
+#[cfg(all(feature = "resource_manager", not(feature = "request-pruning")))]
 pub mod resource_manager;
 #[cfg(test)]
 mod test;
@@ -0,0 +1,162 @@
+//! Log output of x11rb via the `log` crate.
+//!
+//! When the `log` feature is enabled, [`RustConnection`](crate::rust_connection::RustConnection)
+//! reports what it does via the [`log`](https://docs.rs/log) crate. This is a small dependency
+//! that works with any logger implementation. The messages use the following targets, so that they
+//! can be filtered per area:
+//!
+//! - `x11rb::connect`: Establishing the connection, e.g. the display that is used, the
+//!   authorization method and the X11 server that was found.
+//! - `x11rb::io`: Requests that are sent and the replies and errors that are received.
+//! - `x11rb::events`: Events that are received.
+//!
+//! The raw bytes of requests and packets are dumped at the `trace` level. How many bytes of each
+//! packet are included can be configured via [`set_payload_dump`].
+//!
+//! ```
+//! use x11rb::logging::{set_payload_dump, PayloadDump};
+//!
+//! // Requests can be large, e.g. for images. Only show the beginning of each packet.
+//! set_payload_dump(PayloadDump::Prefix(16));
+//! ```
+
+use std::fmt;
+use std::io::IoSlice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::connection::SequenceNumber;
+
+/// The target for messages about establishing a connection.
+pub const CONNECT_TARGET: &str = "x11rb::connect";
+
+/// The target for messages about requests, replies and errors.
+pub const IO_TARGET: &str = "x11rb::io";
+
+/// The target for messages about events.
+pub const EVENTS_TARGET: &str = "x11rb::events";
+
+/// How many bytes of each packet are dumped at the `trace` level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PayloadDump {
+    /// Do not dump any bytes, only describe the packet.
+    None,
+    /// Dump at most the given number of bytes.
+    Prefix(usize),
+    /// Dump all bytes.
+    Full,
+}
+
+impl Default for PayloadDump {
+    fn default() -> Self {
+        PayloadDump::Prefix(32)
+    }
+}
+
+/// The current setting of [`set_payload_dump`] with `usize::MAX` meaning [`PayloadDump::Full`].
+static PAYLOAD_DUMP: AtomicUsize = AtomicUsize::new(32);
+
+/// Set how many bytes of each packet are dumped.
+///
+/// This setting applies to all connections. The default is [`PayloadDump::Prefix(32)`](PayloadDump::Prefix), which
+/// covers events and the fixed part of most replies.
+pub fn set_payload_dump(dump: PayloadDump) {
+    let limit = match dump {
+        PayloadDump::None => 0,
+        PayloadDump::Prefix(limit) => limit.min(usize::MAX - 1),
+        PayloadDump::Full => usize::MAX,
+    };
+    PAYLOAD_DUMP.store(limit, Ordering::Relaxed);
+}
+
+/// Get the current setting of [`set_payload_dump`].
+pub fn payload_dump() -> PayloadDump {
+    match PAYLOAD_DUMP.load(Ordering::Relaxed) {
+        0 => PayloadDump::None,
+        usize::MAX => PayloadDump::Full,
+        limit => PayloadDump::Prefix(limit),
+    }
+}
+
+/// Formats bytes as hex, limited by the current [`PayloadDump`] setting.
+struct Payload<'a>(&'a [u8]);
+
+impl fmt::Display for Payload<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = PAYLOAD_DUMP.load(Ordering::Relaxed);
+        for (index, byte) in self.0.iter().take(limit).enumerate() {
+            if index != 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        if self.0.len() > limit {
+            if limit != 0 {
+                f.write_str(" ... ")?;
+            }
+            write!(f, "({} bytes)", self.0.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Log a request that was just sent.
+pub(crate) fn sent_request(sequence: SequenceNumber, bufs: &[IoSlice<'_>]) {
+    if log::log_enabled!(target: IO_TARGET, log::Level::Trace) {
+        let bytes = bufs
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<_>>();
+        log::trace!(
+            target: IO_TARGET,
+            "Sent request {} with major opcode {}: {}",
+            sequence,
+            bytes.first().copied().unwrap_or_default(),
+            Payload(&bytes)
+        );
+    }
+}
+
+/// Log a packet that was just received.
+pub(crate) fn received_packet(sequence: SequenceNumber, packet: &[u8]) {
+    match packet[0] {
+        0 => log::debug!(
+            target: IO_TARGET,
+            "Received error {} for request {}: {}",
+            packet[1],
+            sequence,
+            Payload(packet)
+        ),
+        1 => log::trace!(
+            target: IO_TARGET,
+            "Received reply for request {}: {}",
+            sequence,
+            Payload(packet)
+        ),
+        kind => log::trace!(
+            target: EVENTS_TARGET,
+            "Received event {} after request {}: {}",
+            kind & 0x7f,
+            sequence,
+            Payload(packet)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{payload_dump, set_payload_dump, Payload, PayloadDump};
+
+    #[test]
+    fn payload_is_limited() {
+        let bytes = [1, 2, 0xab, 4];
+        set_payload_dump(PayloadDump::Prefix(2));
+        assert_eq!(payload_dump(), PayloadDump::Prefix(2));
+        assert_eq!(Payload(&bytes).to_string(), "01 02 ... (4 bytes)");
+        set_payload_dump(PayloadDump::None);
+        assert_eq!(Payload(&bytes).to_string(), "(4 bytes)");
+        set_payload_dump(PayloadDump::Full);
+        assert_eq!(payload_dump(), PayloadDump::Full);
+        assert_eq!(Payload(&bytes).to_string(), "01 02 ab 04");
+        set_payload_dump(PayloadDump::default());
+    }
+}
@@ -0,0 +1,458 @@
+//! Recording user input and replaying it later.
+//!
+//! A [`MacroRecorder`] uses the RECORD extension to capture key presses, button presses and
+//! pointer motion of all clients together with the time between them. The result is a [`Macro`],
+//! which can be replayed with the XTEST extension, optionally faster or slower than it was
+//! recorded. With the `serde` feature of the library, a [`Macro`] can be stored in any format that
+//! serde supports.
+//!
+//! The RECORD extension sends the recorded data as replies to a request that does not finish until
+//! recording stops. Thus, two connections are needed: one to control the recording and one that
+//! receives the data.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::macros::{InputAction, MacroRecorder};
+//!
+//! fn record_and_replay(
+//!     ctrl_conn: &impl Connection,
+//!     data_conn: &impl Connection,
+//! ) -> Result<(), ReplyOrIdError> {
+//!     let recorder = MacroRecorder::new(ctrl_conn)?;
+//!     // Record until Escape (keycode 9 with most keymaps) is pressed
+//!     let recorded = recorder.record(data_conn, |action| *action == InputAction::KeyPress(9))?;
+//!     // Replay at twice the original speed
+//!     recorded.replay(ctrl_conn, 2.0)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The code in this module is only available when the `record` and `xtest` features of the library
+//! are enabled.
+
+use std::time::Duration;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::record::{self, ClientSpec, Context, ExtRange, Range, Range16, Range8, CS};
+use crate::protocol::xproto::{self, Timestamp};
+use crate::protocol::xtest;
+use crate::x11_utils::TryParse;
+use crate::NONE;
+
+/// The category of replies with data that the X11 server sent.
+const FROM_SERVER: u8 = 0;
+
+/// A single input that can be recorded and replayed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    /// The key with the given keycode was pressed.
+    KeyPress(u8),
+    /// The key with the given keycode was released.
+    KeyRelease(u8),
+    /// The pointer button with the given number was pressed.
+    ButtonPress(u8),
+    /// The pointer button with the given number was released.
+    ButtonRelease(u8),
+    /// The pointer moved to the given position relative to the root window.
+    Motion {
+        /// The x coordinate of the pointer.
+        x: i16,
+        /// The y coordinate of the pointer.
+        y: i16,
+    },
+}
+
+impl InputAction {
+    /// Parse an input from a device event, or `None` if the event is not an input.
+    fn from_device_event(event: &[u8]) -> Result<Option<(Timestamp, Self)>, ParseError> {
+        let response_type = event.first().ok_or(ParseError::InsufficientData)? & 0x7f;
+        let result = match response_type {
+            xproto::KEY_PRESS_EVENT | xproto::KEY_RELEASE_EVENT => {
+                let (event, _) = xproto::KeyPressEvent::try_parse(event)?;
+                let action = if response_type == xproto::KEY_PRESS_EVENT {
+                    InputAction::KeyPress(event.detail)
+                } else {
+                    InputAction::KeyRelease(event.detail)
+                };
+                Some((event.time, action))
+            }
+            xproto::BUTTON_PRESS_EVENT | xproto::BUTTON_RELEASE_EVENT => {
+                let (event, _) = xproto::ButtonPressEvent::try_parse(event)?;
+                let action = if response_type == xproto::BUTTON_PRESS_EVENT {
+                    InputAction::ButtonPress(event.detail)
+                } else {
+                    InputAction::ButtonRelease(event.detail)
+                };
+                Some((event.time, action))
+            }
+            xproto::MOTION_NOTIFY_EVENT => {
+                let (event, _) = xproto::MotionNotifyEvent::try_parse(event)?;
+                let action = InputAction::Motion {
+                    x: event.root_x,
+                    y: event.root_y,
+                };
+                Some((event.time, action))
+            }
+            _ => None,
+        };
+        Ok(result)
+    }
+
+    /// Send this input to the X11 server as if it came from an input device.
+    fn fake<C: RequestConnection + ?Sized>(self, conn: &C) -> Result<(), ConnectionError> {
+        let (type_, detail, x, y) = match self {
+            InputAction::KeyPress(key) => (xproto::KEY_PRESS_EVENT, key, 0, 0),
+            InputAction::KeyRelease(key) => (xproto::KEY_RELEASE_EVENT, key, 0, 0),
+            InputAction::ButtonPress(button) => (xproto::BUTTON_PRESS_EVENT, button, 0, 0),
+            InputAction::ButtonRelease(button) => (xproto::BUTTON_RELEASE_EVENT, button, 0, 0),
+            // A detail of zero means that the position is absolute
+            InputAction::Motion { x, y } => (xproto::MOTION_NOTIFY_EVENT, 0, x, y),
+        };
+        let _ = xtest::fake_input(conn, type_, detail, 0, NONE, x, y, 0)?;
+        Ok(())
+    }
+}
+
+/// An input together with the time that passed since the previous input.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordedInput {
+    /// The time since the previous input in milliseconds, or zero for the first input.
+    pub delay: u32,
+    /// The input itself.
+    pub action: InputAction,
+}
+
+/// A sequence of recorded inputs.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Macro {
+    /// The inputs in the order in which they happened.
+    pub inputs: Vec<RecordedInput>,
+}
+
+impl Macro {
+    /// Create an empty macro.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the time from the first to the last input at the original speed.
+    pub fn duration(&self) -> Duration {
+        let millis = self.inputs.iter().map(|input| u64::from(input.delay)).sum();
+        Duration::from_millis(millis)
+    }
+
+    /// Replay the inputs with the XTEST extension.
+    ///
+    /// The delays between the inputs are divided by `speed`, so `2.0` replays twice as fast as the
+    /// inputs were recorded. This function blocks until all inputs were sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not a positive, finite number.
+    pub fn replay<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        speed: f64,
+    ) -> Result<(), ConnectionError> {
+        self.replay_with(conn, speed, std::thread::sleep)
+    }
+
+    /// Replay the inputs and call `sleep` to wait between them.
+    ///
+    /// This is like [`Macro::replay`], but the caller decides how to wait, e.g. to test code
+    /// without actually waiting. `sleep` is not called for delays of zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not a positive, finite number.
+    pub fn replay_with<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        speed: f64,
+        mut sleep: impl FnMut(Duration),
+    ) -> Result<(), ConnectionError> {
+        assert!(
+            speed.is_finite() && speed > 0.0,
+            "The replay speed must be positive, but is {}",
+            speed
+        );
+        for input in &self.inputs {
+            if input.delay != 0 {
+                // Earlier inputs have to arrive before waiting
+                conn.flush()?;
+                sleep(Duration::from_secs_f64(
+                    f64::from(input.delay) / 1000.0 / speed,
+                ));
+            }
+            input.action.fake(conn)?;
+        }
+        conn.flush()
+    }
+
+    /// Add the inputs in data that the RECORD extension sent.
+    ///
+    /// `last_time` is the time of the previous input and is updated. Returns the first input for
+    /// which `stop` returned `true`. That input is not added.
+    fn add_recorded_data(
+        &mut self,
+        mut data: &[u8],
+        last_time: &mut Option<Timestamp>,
+        stop: &mut impl FnMut(&InputAction) -> bool,
+    ) -> Result<Option<InputAction>, ParseError> {
+        // Device events are always 32 bytes long
+        while !data.is_empty() {
+            let event = data.get(..32).ok_or(ParseError::InsufficientData)?;
+            data = &data[32..];
+            if let Some((time, action)) = InputAction::from_device_event(event)? {
+                if stop(&action) {
+                    return Ok(Some(action));
+                }
+                let delay = match *last_time {
+                    // Server timestamps wrap around after about 49 days
+                    Some(last_time) => time.wrapping_sub(last_time),
+                    None => 0,
+                };
+                *last_time = Some(time);
+                self.inputs.push(RecordedInput { delay, action });
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A RECORD context that captures the input of all clients.
+///
+/// The context is freed when this object is dropped.
+#[derive(Debug)]
+pub struct MacroRecorder<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    context: Context,
+}
+
+impl<'c, C: Connection + ?Sized> MacroRecorder<'c, C> {
+    /// Create a RECORD context on the given control connection.
+    ///
+    /// Returns [`ConnectionError::UnsupportedExtension`] if the X11 server does not support the
+    /// RECORD extension. This function waits until the X11 server created the context.
+    pub fn new(conn: &'c C) -> Result<Self, ReplyOrIdError> {
+        if conn
+            .extension_information(record::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Err(ConnectionError::UnsupportedExtension.into());
+        }
+        let empty = Range8 { first: 0, last: 0 };
+        let empty_ext = ExtRange {
+            major: empty,
+            minor: Range16 { first: 0, last: 0 },
+        };
+        let range = Range {
+            core_requests: empty,
+            core_replies: empty,
+            ext_requests: empty_ext,
+            ext_replies: empty_ext,
+            delivered_events: empty,
+            device_events: Range8 {
+                first: xproto::KEY_PRESS_EVENT,
+                last: xproto::MOTION_NOTIFY_EVENT,
+            },
+            errors: empty,
+            client_started: false,
+            client_died: false,
+        };
+        let context = conn.generate_id()?;
+        let clients: [ClientSpec; 1] = [CS::ALL_CLIENTS.into()];
+        record::create_context(conn, context, 0, &clients, &[range])?.check()?;
+        Ok(Self { conn, context })
+    }
+
+    /// Get the RECORD context.
+    pub fn context(&self) -> Context {
+        self.context
+    }
+
+    /// Record inputs until `stop` returns `true` for one of them.
+    ///
+    /// The recorded data is received on `data_conn`, which has to be a different connection than
+    /// the control connection and is blocked while recording. The input for which `stop` returned
+    /// `true` is not part of the result.
+    pub fn record<D: Connection + ?Sized>(
+        &self,
+        data_conn: &D,
+        mut stop: impl FnMut(&InputAction) -> bool,
+    ) -> Result<Macro, ReplyError> {
+        let mut result = Macro::new();
+        let mut last_time = None;
+        let mut stopped = false;
+        for reply in record::enable_context(data_conn, self.context)? {
+            let reply = reply?;
+            // Data of byte-swapped clients cannot be parsed
+            if stopped || reply.category != FROM_SERVER || reply.client_swapped {
+                continue;
+            }
+            if result
+                .add_recorded_data(&reply.data, &mut last_time, &mut stop)?
+                .is_some()
+            {
+                // The X11 server ends the replies once the context is disabled
+                stopped = true;
+                let _ = record::disable_context(self.conn, self.context)?;
+                self.conn.flush()?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for MacroRecorder<'_, C> {
+    fn drop(&mut self) {
+        let _ = record::free_context(self.conn, self.context);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{InputAction, Macro, RecordedInput};
+    use crate::protocol::xproto::{self, KeyPressEvent, MotionNotifyEvent};
+
+    fn key(response_type: u8, detail: u8, time: u32) -> [u8; 32] {
+        KeyPressEvent {
+            response_type,
+            detail,
+            sequence: 0,
+            time,
+            root: 1,
+            event: 1,
+            child: 0,
+            root_x: 0,
+            root_y: 0,
+            event_x: 0,
+            event_y: 0,
+            state: Default::default(),
+            same_screen: true,
+        }
+        .into()
+    }
+
+    fn motion(x: i16, y: i16, time: u32) -> [u8; 32] {
+        MotionNotifyEvent {
+            response_type: xproto::MOTION_NOTIFY_EVENT,
+            detail: xproto::Motion::NORMAL,
+            sequence: 0,
+            time,
+            root: 1,
+            event: 1,
+            child: 0,
+            root_x: x,
+            root_y: y,
+            event_x: x,
+            event_y: y,
+            state: Default::default(),
+            same_screen: true,
+        }
+        .into()
+    }
+
+    fn recorded(delay: u32, action: InputAction) -> RecordedInput {
+        RecordedInput { delay, action }
+    }
+
+    #[test]
+    fn parse_recorded_data() {
+        let mut data = Vec::new();
+        data.extend(key(xproto::KEY_PRESS_EVENT, 38, 1000));
+        data.extend(motion(10, 20, 1050));
+        data.extend(key(xproto::KEY_RELEASE_EVENT, 38, 1200));
+
+        let mut result = Macro::new();
+        let mut last_time = None;
+        let stopped = result
+            .add_recorded_data(&data, &mut last_time, &mut |_| false)
+            .unwrap();
+        assert_eq!(stopped, None);
+        assert_eq!(
+            result.inputs,
+            [
+                recorded(0, InputAction::KeyPress(38)),
+                recorded(50, InputAction::Motion { x: 10, y: 20 }),
+                recorded(150, InputAction::KeyRelease(38)),
+            ]
+        );
+        assert_eq!(result.duration(), Duration::from_millis(200));
+
+        // Recording continues across replies and stops at the requested input
+        let mut data = Vec::new();
+        data.extend(key(xproto::KEY_PRESS_EVENT, 40, 1300));
+        data.extend(key(xproto::KEY_PRESS_EVENT, 9, 1400));
+        data.extend(key(xproto::KEY_PRESS_EVENT, 41, 1500));
+        let stopped = result
+            .add_recorded_data(&data, &mut last_time, &mut |action| {
+                *action == InputAction::KeyPress(9)
+            })
+            .unwrap();
+        assert_eq!(stopped, Some(InputAction::KeyPress(9)));
+        assert_eq!(result.inputs.len(), 4);
+        assert_eq!(result.inputs[3], recorded(100, InputAction::KeyPress(40)));
+    }
+
+    #[test]
+    fn truncated_data() {
+        let data = key(xproto::KEY_PRESS_EVENT, 38, 1000);
+        assert!(Macro::new()
+            .add_recorded_data(&data[..20], &mut None, &mut |_| false)
+            .is_err());
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn replay_scales_delays() {
+        use crate::protocol::xtest;
+        use crate::testing::FakeConnection;
+        use crate::x11_utils::ExtensionInformation;
+
+        let conn = FakeConnection::default();
+        conn.add_extension(
+            xtest::X11_EXTENSION_NAME,
+            ExtensionInformation {
+                major_opcode: 130,
+                first_event: 0,
+                first_error: 0,
+            },
+        );
+        let recorded = Macro {
+            inputs: vec![
+                recorded(0, InputAction::Motion { x: 5, y: 6 }),
+                recorded(100, InputAction::ButtonPress(1)),
+                recorded(300, InputAction::ButtonRelease(1)),
+            ],
+        };
+        let mut delays = Vec::new();
+        recorded
+            .replay_with(&conn, 2.0, |delay| delays.push(delay))
+            .unwrap();
+        assert_eq!(
+            delays,
+            [Duration::from_millis(50), Duration::from_millis(150)]
+        );
+
+        let requests = conn.take_sent_requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests
+            .iter()
+            .all(|request| request.minor_opcode() == xtest::FAKE_INPUT_REQUEST));
+        // Type and detail of the inputs
+        assert_eq!(requests[0].bytes[4..6], [xproto::MOTION_NOTIFY_EVENT, 0]);
+        assert_eq!(requests[1].bytes[4..6], [xproto::BUTTON_PRESS_EVENT, 1]);
+        assert_eq!(requests[2].bytes[4..6], [xproto::BUTTON_RELEASE_EVENT, 1]);
+        // Position of the motion
+        assert_eq!(requests[0].bytes[24..26], 5i16.to_ne_bytes());
+        assert_eq!(requests[0].bytes[26..28], 6i16.to_ne_bytes());
+    }
+}
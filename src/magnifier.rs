@@ -0,0 +1,349 @@
+//! Building blocks for a screen magnifier that follows the pointer.
+//!
+//! A magnifier periodically captures the area around the pointer and shows it enlarged in a
+//! floating window. [`Magnifier`] does this without transferring any image data to the client:
+//! The area is copied from the root window into a pixmap with `CopyArea` and scaled into the
+//! window with the RENDER extension. The window is moved next to the captured area, so that it
+//! does not capture itself.
+//!
+//! ```
+//! use std::time::Duration;
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::magnifier::{Magnifier, MagnifierGeometry};
+//!
+//! fn magnify(conn: &impl Connection, screen_num: usize) -> Result<(), ReplyOrIdError> {
+//!     let geometry = MagnifierGeometry::new(3.0, (300, 200));
+//!     let magnifier = Magnifier::new(conn, screen_num, geometry)?;
+//!     magnifier.show()?;
+//!     loop {
+//!         let _area = magnifier.update()?;
+//!         std::thread::sleep(Duration::from_millis(30));
+//!     }
+//! }
+//! ```
+//!
+//! Tools that want to process the captured image themselves can use [`MagnifierGeometry`] to
+//! compute the area and fetch it with `GetImage` or `ShmGetImage`.
+//!
+//! With a compositing manager, the contents of the root window might not reflect what is visible
+//! on the screen.
+//!
+//! The code in this module is only available when the `render` feature of the library is enabled.
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::render::{
+    self, CreatePictureAux, Fixed, PictOp, Pictformat, PictureWrapper, QueryPictFormatsReply,
+    Transform,
+};
+use crate::protocol::xproto::{
+    self, ConfigureWindowAux, CreateGCAux, CreateWindowAux, GcontextWrapper, PixmapWrapper,
+    Rectangle, StackMode, SubwindowMode, Visualid, Window, WindowClass, WindowWrapper,
+};
+use crate::{COPY_DEPTH_FROM_PARENT, COPY_FROM_PARENT, NONE};
+
+/// The distance between the captured area and the magnifier window.
+const WINDOW_GAP: i32 = 16;
+
+/// The size of the magnifier and how much it enlarges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MagnifierGeometry {
+    /// The factor by which the captured area is enlarged.
+    pub zoom: f64,
+    /// The size of the magnifier window.
+    pub size: (u16, u16),
+}
+
+impl MagnifierGeometry {
+    /// Create a new geometry with the given zoom factor and window size.
+    ///
+    /// The zoom factor has to be positive.
+    pub fn new(zoom: f64, size: (u16, u16)) -> Self {
+        assert!(zoom > 0.0, "The zoom factor has to be positive");
+        Self { zoom, size }
+    }
+
+    /// Get the size of the area that is captured.
+    pub fn source_size(&self) -> (u16, u16) {
+        let scale = |size: u16| (f64::from(size) / self.zoom).ceil().clamp(1.0, 65535.0) as u16;
+        (scale(self.size.0), scale(self.size.1))
+    }
+
+    /// Get the area that is captured for the given pointer position.
+    ///
+    /// The area is centred on the pointer, but stays inside of the screen with the given size.
+    pub fn source(&self, pointer: (i16, i16), screen_size: (u16, u16)) -> Rectangle {
+        let (width, height) = self.source_size();
+        let (width, height) = (width.min(screen_size.0), height.min(screen_size.1));
+        let place = |pointer: i16, size: u16, screen: u16| {
+            let start = i32::from(pointer) - i32::from(size) / 2;
+            start.min(i32::from(screen) - i32::from(size)).max(0) as i16
+        };
+        Rectangle {
+            x: place(pointer.0, width, screen_size.0),
+            y: place(pointer.1, height, screen_size.1),
+            width,
+            height,
+        }
+    }
+
+    /// Get the position of the magnifier window for the given captured area.
+    ///
+    /// The window is placed to the right of the area, or to the left if there is not enough space
+    /// on the right. Vertically, it is centred on the area. The window stays inside of the screen.
+    pub fn window_position(&self, source: Rectangle, screen_size: (u16, u16)) -> (i16, i16) {
+        let (width, height) = (i32::from(self.size.0), i32::from(self.size.1));
+        let (screen_width, screen_height) = (i32::from(screen_size.0), i32::from(screen_size.1));
+        let right = i32::from(source.x) + i32::from(source.width) + WINDOW_GAP;
+        let x = if right + width <= screen_width {
+            right
+        } else {
+            i32::from(source.x) - WINDOW_GAP - width
+        };
+        let y = i32::from(source.y) + i32::from(source.height) / 2 - height / 2;
+        let clamp = |pos: i32, size: i32, screen: i32| pos.min(screen - size).max(0) as i16;
+        (
+            clamp(x, width, screen_width),
+            clamp(y, height, screen_height),
+        )
+    }
+
+    /// Get the RENDER transformation that scales the captured area to the window size.
+    fn transform(&self) -> Transform {
+        let to_fixed = |value: f64| (value * 65536.0).round() as Fixed;
+        // RENDER maps from destination to source coordinates, so this shrinks by the zoom factor
+        Transform {
+            matrix11: to_fixed(1.0 / self.zoom),
+            matrix12: 0,
+            matrix13: 0,
+            matrix21: 0,
+            matrix22: to_fixed(1.0 / self.zoom),
+            matrix23: 0,
+            matrix31: 0,
+            matrix32: 0,
+            matrix33: to_fixed(1.0),
+        }
+    }
+}
+
+/// Find the picture format of the given visual.
+fn find_visual_format(formats: &QueryPictFormatsReply, visual: Visualid) -> Option<Pictformat> {
+    formats
+        .screens
+        .iter()
+        .flat_map(|screen| &screen.depths)
+        .flat_map(|depth| &depth.visuals)
+        .find(|pict_visual| pict_visual.visual == visual)
+        .map(|pict_visual| pict_visual.format)
+}
+
+/// A floating window that shows the area around the pointer enlarged.
+///
+/// All resources are freed when this object is dropped. See the [module documentation](self) for
+/// an example.
+#[derive(Debug)]
+pub struct Magnifier<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    root: Window,
+    screen_size: (u16, u16),
+    geometry: MagnifierGeometry,
+    source: PictureWrapper<'c, C>,
+    target: PictureWrapper<'c, C>,
+    gc: GcontextWrapper<'c, C>,
+    pixmap: PixmapWrapper<'c, C>,
+    window: WindowWrapper<'c, C>,
+}
+
+impl<'c, C: Connection + ?Sized> Magnifier<'c, C> {
+    /// Create a magnifier on the given screen.
+    ///
+    /// The window is created unmapped and has to be shown with [`Magnifier::show`]. If the X11
+    /// server does not support the RENDER extension for the root visual,
+    /// [`ConnectionError::UnsupportedExtension`] is returned.
+    pub fn new(
+        conn: &'c C,
+        screen_num: usize,
+        geometry: MagnifierGeometry,
+    ) -> Result<Self, ReplyOrIdError> {
+        if conn
+            .extension_information(render::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Err(ConnectionError::UnsupportedExtension.into());
+        }
+        let screen = &conn.setup().roots[screen_num];
+        let formats = render::query_pict_formats(conn)?.reply()?;
+        let format = find_visual_format(&formats, screen.root_visual)
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+
+        let (width, height) = geometry.size;
+        let window_aux = CreateWindowAux::new()
+            .override_redirect(1)
+            .background_pixel(screen.black_pixel);
+        let window = WindowWrapper::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            screen.root,
+            0,
+            0,
+            width,
+            height,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            COPY_FROM_PARENT,
+            &window_aux,
+        )?;
+        let (source_width, source_height) = geometry.source_size();
+        let pixmap = PixmapWrapper::create_pixmap(
+            conn,
+            screen.root_depth,
+            screen.root,
+            source_width,
+            source_height,
+        )?;
+        // Copy the contents of all windows, not just of the root window itself
+        let gc_aux = CreateGCAux::new()
+            .subwindow_mode(SubwindowMode::INCLUDE_INFERIORS)
+            .graphics_exposures(0);
+        let gc = GcontextWrapper::create_gc(conn, screen.root, &gc_aux)?;
+        let picture_aux = CreatePictureAux::new();
+        let source = PictureWrapper::create_picture(conn, pixmap.pixmap(), format, &picture_aux)?;
+        let _ = render::set_picture_transform(conn, source.picture(), geometry.transform())?;
+        let target = PictureWrapper::create_picture(conn, window.window(), format, &picture_aux)?;
+
+        Ok(Self {
+            conn,
+            root: screen.root,
+            screen_size: (screen.width_in_pixels, screen.height_in_pixels),
+            geometry,
+            source,
+            target,
+            gc,
+            pixmap,
+            window,
+        })
+    }
+
+    /// Get the magnifier window.
+    pub fn window(&self) -> Window {
+        self.window.window()
+    }
+
+    /// Get the geometry of the magnifier.
+    pub fn geometry(&self) -> MagnifierGeometry {
+        self.geometry
+    }
+
+    /// Map the magnifier window above all other windows.
+    pub fn show(&self) -> Result<(), ConnectionError> {
+        let aux = ConfigureWindowAux::new().stack_mode(StackMode::ABOVE);
+        let _ = xproto::configure_window(self.conn, self.window(), &aux)?;
+        let _ = xproto::map_window(self.conn, self.window())?;
+        self.conn.flush()
+    }
+
+    /// Unmap the magnifier window.
+    pub fn hide(&self) -> Result<(), ConnectionError> {
+        let _ = xproto::unmap_window(self.conn, self.window())?;
+        self.conn.flush()
+    }
+
+    /// Capture the area around the pointer, show it in the window and move the window next to
+    /// it.
+    ///
+    /// This waits for the reply to `QueryPointer` and should be called periodically. Returns the
+    /// captured area in root window coordinates.
+    pub fn update(&self) -> Result<Rectangle, ReplyError> {
+        let pointer = xproto::query_pointer(self.conn, self.root)?.reply()?;
+        let source = self
+            .geometry
+            .source((pointer.root_x, pointer.root_y), self.screen_size);
+        let _ = xproto::copy_area(
+            self.conn,
+            self.root,
+            self.pixmap.pixmap(),
+            self.gc.gcontext(),
+            source.x,
+            source.y,
+            0,
+            0,
+            source.width,
+            source.height,
+        )?;
+        let (width, height) = self.geometry.size;
+        let _ = render::composite(
+            self.conn,
+            PictOp::SRC,
+            self.source.picture(),
+            NONE,
+            self.target.picture(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            width,
+            height,
+        )?;
+        let (x, y) = self.geometry.window_position(source, self.screen_size);
+        let aux = ConfigureWindowAux::new().x(i32::from(x)).y(i32::from(y));
+        let _ = xproto::configure_window(self.conn, self.window(), &aux)?;
+        self.conn.flush()?;
+        Ok(source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MagnifierGeometry;
+    use crate::protocol::xproto::Rectangle;
+
+    const SCREEN: (u16, u16) = (1920, 1080);
+
+    #[test]
+    fn source_follows_pointer() {
+        let geometry = MagnifierGeometry::new(4.0, (300, 200));
+        assert_eq!(geometry.source_size(), (75, 50));
+        assert_eq!(
+            geometry.source((500, 500), SCREEN),
+            Rectangle {
+                x: 463,
+                y: 475,
+                width: 75,
+                height: 50
+            }
+        );
+        // The area stays inside of the screen
+        assert_eq!(
+            geometry.source((2, 1079), SCREEN),
+            Rectangle {
+                x: 0,
+                y: 1030,
+                width: 75,
+                height: 50
+            }
+        );
+    }
+
+    #[test]
+    fn window_next_to_source() {
+        let geometry = MagnifierGeometry::new(4.0, (300, 200));
+        let source = geometry.source((500, 500), SCREEN);
+        assert_eq!(geometry.window_position(source, SCREEN), (554, 400));
+
+        // Not enough space on the right
+        let source = geometry.source((1900, 10), SCREEN);
+        assert_eq!(source.x, 1845);
+        assert_eq!(geometry.window_position(source, SCREEN), (1529, 0));
+    }
+
+    #[test]
+    fn transform_scales_down() {
+        let transform = MagnifierGeometry::new(2.0, (100, 100)).transform();
+        assert_eq!(transform.matrix11, 0x8000);
+        assert_eq!(transform.matrix22, 0x8000);
+        assert_eq!(transform.matrix33, 0x10000);
+    }
+}
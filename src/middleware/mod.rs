@@ -0,0 +1,940 @@
+//! Composable layers that observe, modify, or deny requests and events.
+//!
+//! A [`Layer`] is called for every request that is sent through a connection and for every
+//! event that is received from it. Layers can be used for logging, rate limiting, security
+//! filtering and similar tasks. A connection is wrapped in a layer via [`Connection::wrap`]:
+//!
+//! ```
+//! use x11rb::connection::{Connection, RequestConnection};
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::middleware::{Layer, OutgoingRequest};
+//!
+//! /// A layer that logs the major opcode of each request.
+//! #[derive(Debug)]
+//! struct LogRequests;
+//!
+//! impl Layer for LogRequests {
+//!     fn on_request<C>(
+//!         &self,
+//!         _conn: &C,
+//!         request: &mut OutgoingRequest<'_>,
+//!     ) -> Result<(), ConnectionError>
+//!     where
+//!         C: RequestConnection + ?Sized,
+//!     {
+//!         println!("Sending request with major opcode {}", request.major_opcode());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! fn add_logging<C: Connection>(conn: C) -> impl Connection {
+//!     conn.wrap(LogRequests)
+//! }
+//! ```
+//!
+//! Wrapping a connection multiple times stacks layers: The layer that was added last sees
+//! requests first and events last. A tuple `(A, B)` of layers is also a layer which runs `A`
+//! before `B` for requests and `B` before `A` for events, so `conn.wrap((a, b))` behaves like
+//! `conn.wrap(b).wrap(a)`.
+//!
+//! The [`rate_limit`] module provides a layer that protects the X11 server from runaway request
+//! loops. The [`slow_reply`] module provides a layer that reports requests whose replies take long
+//! to arrive. The [`sync_audit`] module provides a layer that counts the places where the
+//! application blocks for a round trip to the X11 server, e.g. to keep them in check in CI. The
+//! [`trace`] module provides a layer that reports all requests together with their sequence numbers
+//! and the responses to them, e.g. for protocol debugging. The [`unparsed`] module provides a layer
+//! that keeps the events and errors that could not be parsed, e.g. for bug reports. The
+//! [`version_guard`] module provides a layer that refuses requests which are newer than what the
+//! X11 server supports. Outside of this module, [`crate::restricted_connection`] provides a layer
+//! that denies whole categories of requests, e.g. keyboard grabs.
+
+use std::borrow::Cow;
+use std::io::IoSlice;
+
+use crate::connection::{
+    BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError, RequestConnection,
+    RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::utils::OwnedFd;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+pub mod rate_limit;
+pub mod slow_reply;
+pub mod sync_audit;
+pub mod trace;
+pub mod unparsed;
+pub mod version_guard;
+
+/// A request that is about to be sent to the X11 server.
+///
+/// The bytes of the request are only copied when a layer asks for mutable access to them or
+/// needs them in one piece, so that requests which are passed on unchanged are sent as they are.
+#[derive(Debug)]
+pub struct OutgoingRequest<'a> {
+    bufs: &'a [IoSlice<'a>],
+    // The bytes of the request after a layer got mutable access to them
+    modified: Option<Vec<u8>>,
+    fds: Vec<OwnedFd>,
+    kind: RequestKind,
+}
+
+impl<'a> OutgoingRequest<'a> {
+    fn new(bufs: &'a [IoSlice<'a>], fds: Vec<OwnedFd>, kind: RequestKind) -> Self {
+        Self {
+            bufs,
+            modified: None,
+            fds,
+            kind,
+        }
+    }
+
+    /// Does this request have a response?
+    pub fn kind(&self) -> RequestKind {
+        self.kind
+    }
+
+    /// Get the major opcode of this request.
+    pub fn major_opcode(&self) -> u8 {
+        self.byte(0)
+    }
+
+    /// Get the minor opcode of this request.
+    ///
+    /// For core requests, this byte is part of the request's data instead.
+    pub fn minor_opcode(&self) -> u8 {
+        self.byte(1)
+    }
+
+    /// Get the length of this request in bytes.
+    pub fn length(&self) -> usize {
+        self.slices().map(<[u8]>::len).sum()
+    }
+
+    /// Get the bytes of this request in the pieces in which they will be sent.
+    pub fn slices(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        let (modified, bufs) = match &self.modified {
+            Some(bytes) => (Some(&bytes[..]), &[][..]),
+            None => (None, self.bufs),
+        };
+        modified.into_iter().chain(bufs.iter().map(|buf| &**buf))
+    }
+
+    /// Get the raw bytes of this request.
+    ///
+    /// This copies the request if it consists of more than one piece.
+    pub fn bytes(&self) -> Cow<'_, [u8]> {
+        match (&self.modified, self.bufs) {
+            (Some(bytes), _) => Cow::Borrowed(bytes),
+            (None, [buf]) => Cow::Borrowed(&buf[..]),
+            (None, bufs) => Cow::Owned(concat(bufs)),
+        }
+    }
+
+    /// Get mutable access to the raw bytes of this request.
+    ///
+    /// The first call copies the request. When changing the size of the request, the length field
+    /// must be updated accordingly. See [`RequestConnection::send_request_without_reply`] for the
+    /// requirements on the request.
+    pub fn bytes_mut(&mut self) -> &mut Vec<u8> {
+        let bufs = self.bufs;
+        self.modified.get_or_insert_with(|| concat(bufs))
+    }
+
+    /// Get the file descriptors that are sent with this request.
+    pub fn fds(&self) -> &[OwnedFd] {
+        &self.fds
+    }
+
+    /// Get mutable access to the file descriptors that are sent with this request.
+    pub fn fds_mut(&mut self) -> &mut Vec<OwnedFd> {
+        &mut self.fds
+    }
+
+    /// Get the byte at the given index, or zero if the request is too short.
+    fn byte(&self, index: usize) -> u8 {
+        self.slices().flatten().nth(index).copied().unwrap_or(0)
+    }
+}
+
+/// Copy the given pieces into one buffer.
+fn concat(bufs: &[IoSlice<'_>]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(bufs.iter().map(|buf| buf.len()).sum());
+    for buf in bufs {
+        bytes.extend_from_slice(buf);
+    }
+    bytes
+}
+
+/// An event that was received from the X11 server.
+///
+/// Like [`OutgoingRequest`], the event is only copied when a layer asks for mutable access to
+/// its bytes.
+#[derive(Debug)]
+pub struct IncomingEvent<'a> {
+    bytes: &'a [u8],
+    // The bytes of the event after a layer got mutable access to them
+    modified: Option<Vec<u8>>,
+}
+
+impl<'a> IncomingEvent<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            modified: None,
+        }
+    }
+
+    /// Get the raw bytes of this event.
+    pub fn bytes(&self) -> &[u8] {
+        self.modified.as_deref().unwrap_or(self.bytes)
+    }
+
+    /// Get mutable access to the raw bytes of this event.
+    ///
+    /// The first call copies the event. The application gets the modified event.
+    pub fn bytes_mut(&mut self) -> &mut Vec<u8> {
+        let bytes = self.bytes;
+        self.modified.get_or_insert_with(|| bytes.to_vec())
+    }
+}
+
+/// What should happen with an event that was received from the X11 server?
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EventAction {
+    /// Pass the event on to the next layer and eventually to the application.
+    Deliver,
+    /// Drop the event. The application will not see it.
+    Discard,
+}
+
+/// A response to a request that was received from the X11 server.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Response<'a> {
+    /// The raw bytes of a reply.
+    Reply(&'a [u8]),
+    /// The raw bytes of an error.
+    ///
+    /// Errors that are received as events are passed to [`Layer::on_event`] instead.
+    Error(&'a [u8]),
+}
+
+/// A packet from the X11 server that could not be parsed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UnparsedPacket<'a> {
+    /// An event that is not known to x11rb, e.g. because the extension that it belongs to was not
+    /// enabled at compile time. The application gets it as [`crate::protocol::Event::Unknown`].
+    UnknownEvent(&'a [u8]),
+    /// An event that could not be parsed.
+    Event(&'a [u8], ParseError),
+    /// An error that could not be parsed.
+    Error(&'a [u8], ParseError),
+}
+
+impl<'a> UnparsedPacket<'a> {
+    /// Get the raw bytes of the packet.
+    pub fn bytes(&self) -> &'a [u8] {
+        match *self {
+            UnparsedPacket::UnknownEvent(bytes)
+            | UnparsedPacket::Event(bytes, _)
+            | UnparsedPacket::Error(bytes, _) => bytes,
+        }
+    }
+
+    /// Get the error that occurred while parsing, if any.
+    pub fn error(&self) -> Option<ParseError> {
+        match *self {
+            UnparsedPacket::UnknownEvent(_) => None,
+            UnparsedPacket::Event(_, error) | UnparsedPacket::Error(_, error) => Some(error),
+        }
+    }
+}
+
+/// A layer that is called for requests and events passing through a connection.
+///
+/// All methods have default implementations that let everything pass unchanged.
+pub trait Layer {
+    /// Called before a request is sent.
+    ///
+    /// The request can be modified in place. When an error is returned, the request is not sent
+    /// and the error is passed on to the caller. `conn` is the wrapped connection and can for
+    /// example be used to look up the major opcode of extensions.
+    fn on_request<C>(
+        &self,
+        conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let _ = (conn, request);
+        Ok(())
+    }
+
+    /// Called after a request was sent, with the sequence number that it got.
+    ///
+    /// The file descriptors of the request were already passed on and are no longer available.
+    fn on_request_sent<C>(&self, conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let _ = (conn, request, sequence);
+    }
+
+    /// Called before the application blocks until the response to a request arrives.
+    ///
+    /// This happens when a reply is waited for or a request without reply is checked for
+    /// errors. Polling for a reply does not block and is not reported here.
+    fn on_wait(&self, sequence: SequenceNumber) {
+        let _ = sequence;
+    }
+
+    /// Called for each reply or error that the application waits for.
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        let _ = (sequence, response);
+    }
+
+    /// Called for each event that is received from the X11 server.
+    ///
+    /// The event can be modified via [`IncomingEvent::bytes_mut`].
+    fn on_event(&self, event: &mut IncomingEvent<'_>, sequence: SequenceNumber) -> EventAction {
+        let _ = (event, sequence);
+        EventAction::Deliver
+    }
+
+    /// Called when an event or an error that is parsed via the connection cannot be parsed.
+    ///
+    /// Replies are parsed by their cookies, which return parse errors to the caller instead.
+    fn on_unparsed(&self, packet: UnparsedPacket<'_>) {
+        let _ = packet;
+    }
+
+    /// Called when the application asks for information about an extension.
+    ///
+    /// `info` is what the wrapped connection reported. The returned value is passed on instead,
+    /// e.g. `None` hides an extension from the application.
+    fn on_extension_information(
+        &self,
+        extension_name: &'static str,
+        info: Option<ExtensionInformation>,
+    ) -> Option<ExtensionInformation> {
+        let _ = extension_name;
+        info
+    }
+}
+
+impl<A: Layer, B: Layer> Layer for (A, B) {
+    fn on_request<C>(
+        &self,
+        conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        self.0.on_request(conn, request)?;
+        self.1.on_request(conn, request)
+    }
+
+    fn on_request_sent<C>(&self, conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        self.0.on_request_sent(conn, request, sequence);
+        self.1.on_request_sent(conn, request, sequence);
+    }
+
+    fn on_wait(&self, sequence: SequenceNumber) {
+        self.1.on_wait(sequence);
+        self.0.on_wait(sequence);
+    }
+
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        self.1.on_response(sequence, response);
+        self.0.on_response(sequence, response);
+    }
+
+    fn on_event(&self, event: &mut IncomingEvent<'_>, sequence: SequenceNumber) -> EventAction {
+        match self.1.on_event(event, sequence) {
+            EventAction::Deliver => self.0.on_event(event, sequence),
+            EventAction::Discard => EventAction::Discard,
+        }
+    }
+
+    fn on_unparsed(&self, packet: UnparsedPacket<'_>) {
+        self.1.on_unparsed(packet);
+        self.0.on_unparsed(packet);
+    }
+
+    fn on_extension_information(
+        &self,
+        extension_name: &'static str,
+        info: Option<ExtensionInformation>,
+    ) -> Option<ExtensionInformation> {
+        let info = self.1.on_extension_information(extension_name, info);
+        self.0.on_extension_information(extension_name, info)
+    }
+}
+
+impl<L: Layer> Layer for Option<L> {
+    fn on_request<C>(
+        &self,
+        conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        match self {
+            Some(layer) => layer.on_request(conn, request),
+            None => Ok(()),
+        }
+    }
+
+    fn on_request_sent<C>(&self, conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        if let Some(layer) = self {
+            layer.on_request_sent(conn, request, sequence);
+        }
+    }
+
+    fn on_wait(&self, sequence: SequenceNumber) {
+        if let Some(layer) = self {
+            layer.on_wait(sequence);
+        }
+    }
+
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        if let Some(layer) = self {
+            layer.on_response(sequence, response);
+        }
+    }
+
+    fn on_event(&self, event: &mut IncomingEvent<'_>, sequence: SequenceNumber) -> EventAction {
+        match self {
+            Some(layer) => layer.on_event(event, sequence),
+            None => EventAction::Deliver,
+        }
+    }
+
+    fn on_unparsed(&self, packet: UnparsedPacket<'_>) {
+        if let Some(layer) = self {
+            layer.on_unparsed(packet);
+        }
+    }
+
+    fn on_extension_information(
+        &self,
+        extension_name: &'static str,
+        info: Option<ExtensionInformation>,
+    ) -> Option<ExtensionInformation> {
+        match self {
+            Some(layer) => layer.on_extension_information(extension_name, info),
+            None => info,
+        }
+    }
+}
+
+/// A connection that passes all requests and events through a [`Layer`].
+///
+/// Instances of this type are created via [`Connection::wrap`] or [`Layered::new`].
+#[derive(Debug)]
+pub struct Layered<C, L> {
+    inner: C,
+    layer: L,
+}
+
+impl<C, L> Layered<C, L> {
+    /// Wrap the given connection in the given layer.
+    pub fn new(inner: C, layer: L) -> Self {
+        Self { inner, layer }
+    }
+
+    /// Get a reference to the wrapped connection.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Get a reference to the layer.
+    pub fn layer(&self) -> &L {
+        &self.layer
+    }
+
+    /// Unwrap this connection and get the wrapped connection and the layer.
+    pub fn into_parts(self) -> (C, L) {
+        (self.inner, self.layer)
+    }
+}
+
+/// The buffer type of a [`Layered`] connection.
+#[derive(Debug)]
+pub enum LayeredBuf<B> {
+    /// A buffer of the wrapped connection.
+    Inner(B),
+    /// The bytes of an event that a layer modified.
+    Modified(Vec<u8>),
+}
+
+impl<B: AsRef<[u8]>> AsRef<[u8]> for LayeredBuf<B> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            LayeredBuf::Inner(buf) => buf.as_ref(),
+            LayeredBuf::Modified(bytes) => bytes,
+        }
+    }
+}
+
+impl<C: RequestConnection, L: Layer> Layered<C, L> {
+    /// Run the layer on the given request.
+    fn prepare_request<'a>(
+        &self,
+        bufs: &'a [IoSlice<'a>],
+        fds: Vec<OwnedFd>,
+        kind: RequestKind,
+    ) -> Result<OutgoingRequest<'a>, ConnectionError> {
+        let mut request = OutgoingRequest::new(bufs, fds, kind);
+        if request.length() < 4 {
+            return Err(ParseError::InsufficientData.into());
+        }
+        self.layer.on_request(&self.inner, &mut request)?;
+        if request.length() < 4 {
+            return Err(ParseError::InsufficientData.into());
+        }
+        Ok(request)
+    }
+
+    /// Send the request via `send` and tell the layer about it.
+    fn send<F>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+        kind: RequestKind,
+        send: F,
+    ) -> Result<SequenceNumber, ConnectionError>
+    where
+        F: FnOnce(&C, &[IoSlice<'_>], Vec<OwnedFd>) -> Result<SequenceNumber, ConnectionError>,
+    {
+        let mut request = self.prepare_request(bufs, fds, kind)?;
+        let fds = std::mem::take(&mut request.fds);
+        let sequence = match &request.modified {
+            Some(bytes) => send(&self.inner, &[IoSlice::new(bytes)], fds)?,
+            None => send(&self.inner, request.bufs, fds)?,
+        };
+        self.layer.on_request_sent(&self.inner, &request, sequence);
+        Ok(sequence)
+    }
+
+    /// Run the layer on the given event and get what should be passed on.
+    fn filter_event(&self, event: C::Buf, sequence: SequenceNumber) -> Option<LayeredBuf<C::Buf>> {
+        let mut incoming = IncomingEvent::new(event.as_ref());
+        let action = self.layer.on_event(&mut incoming, sequence);
+        let modified = incoming.modified;
+        match (action, modified) {
+            (EventAction::Deliver, None) => Some(LayeredBuf::Inner(event)),
+            (EventAction::Deliver, Some(bytes)) => {
+                self.inner.release_buffer(event);
+                Some(LayeredBuf::Modified(bytes))
+            }
+            (EventAction::Discard, _) => {
+                self.inner.release_buffer(event);
+                None
+            }
+        }
+    }
+}
+
+impl<C: RequestConnection, L: Layer> RequestConnection for Layered<C, L> {
+    type Buf = LayeredBuf<C::Buf>;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        let sequence = self.send(bufs, fds, RequestKind::HasResponse, |inner, bufs, fds| {
+            Ok(inner
+                .send_request_with_reply::<R>(bufs, fds)?
+                .into_sequence_number())
+        })?;
+        Ok(Cookie::new(self, sequence))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        let sequence = self.send(bufs, fds, RequestKind::HasResponse, |inner, bufs, fds| {
+            Ok(inner
+                .send_request_with_reply_with_fds::<R>(bufs, fds)?
+                .into_sequence_number())
+        })?;
+        Ok(CookieWithFds::new(self, sequence))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        let sequence = self.send(bufs, fds, RequestKind::IsVoid, |inner, bufs, fds| {
+            Ok(inner
+                .send_request_without_reply(bufs, fds)?
+                .into_sequence_number())
+        })?;
+        Ok(VoidCookie::new(self, sequence))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.inner.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.inner.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        let info = self.inner.extension_information(extension_name)?;
+        Ok(self.layer.on_extension_information(extension_name, info))
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Self::Buf>, ConnectionError> {
+        self.layer.on_wait(sequence);
+        let result = self.inner.wait_for_reply_or_raw_error(sequence)?;
+        let response = match &result {
+            ReplyOrError::Reply(reply) => Response::Reply(reply.as_ref()),
+            ReplyOrError::Error(error) => Response::Error(error.as_ref()),
+        };
+        self.layer.on_response(sequence, response);
+        Ok(wrap_reply_or_error(result))
+    }
+
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Self::Buf>>, ConnectionError> {
+        let result = match self.inner.poll_for_reply_or_raw_error(sequence)? {
+            None => return Ok(None),
+            Some(result) => result,
+        };
+        let response = match &result {
+            ReplyOrError::Reply(reply) => Response::Reply(reply.as_ref()),
+            ReplyOrError::Error(error) => Response::Error(error.as_ref()),
+        };
+        self.layer.on_response(sequence, response);
+        Ok(Some(wrap_reply_or_error(result)))
+    }
+
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.layer.on_wait(sequence);
+        let result = self.inner.wait_for_reply(sequence)?;
+        if let Some(reply) = &result {
+            self.layer
+                .on_response(sequence, Response::Reply(reply.as_ref()));
+        }
+        Ok(result.map(LayeredBuf::Inner))
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError> {
+        self.layer.on_wait(sequence);
+        let result = self.inner.wait_for_reply_with_fds_raw(sequence)?;
+        let response = match &result {
+            ReplyOrError::Reply((reply, _)) => Response::Reply(reply.as_ref()),
+            ReplyOrError::Error(error) => Response::Error(error.as_ref()),
+        };
+        self.layer.on_response(sequence, response);
+        Ok(match result {
+            ReplyOrError::Reply((reply, fds)) => {
+                ReplyOrError::Reply((LayeredBuf::Inner(reply), fds))
+            }
+            ReplyOrError::Error(error) => ReplyOrError::Error(LayeredBuf::Inner(error)),
+        })
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.layer.on_wait(sequence);
+        let result = self.inner.check_for_raw_error(sequence)?;
+        if let Some(error) = &result {
+            self.layer
+                .on_response(sequence, Response::Error(error.as_ref()));
+        }
+        Ok(result.map(LayeredBuf::Inner))
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.inner.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.inner.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        let result = self.inner.parse_error(error);
+        if let Err(err) = result {
+            self.layer.on_unparsed(UnparsedPacket::Error(error, err));
+        }
+        result
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<crate::protocol::Event, ParseError> {
+        let result = self.inner.parse_event(event);
+        match result {
+            Ok(crate::protocol::Event::Unknown(_)) => {
+                self.layer.on_unparsed(UnparsedPacket::UnknownEvent(event))
+            }
+            Err(err) => self.layer.on_unparsed(UnparsedPacket::Event(event, err)),
+            Ok(_) => {}
+        }
+        result
+    }
+
+    fn release_buffer(&self, buffer: Self::Buf) {
+        if let LayeredBuf::Inner(buffer) = buffer {
+            self.inner.release_buffer(buffer)
+        }
+    }
+}
+
+/// Turn a response of the wrapped connection into one of a [`Layered`] connection.
+fn wrap_reply_or_error<B: AsRef<[u8]> + std::fmt::Debug>(
+    result: ReplyOrError<B>,
+) -> ReplyOrError<LayeredBuf<B>> {
+    match result {
+        ReplyOrError::Reply(reply) => ReplyOrError::Reply(LayeredBuf::Inner(reply)),
+        ReplyOrError::Error(error) => ReplyOrError::Error(LayeredBuf::Inner(error)),
+    }
+}
+
+impl<C: Connection, L: Layer> Connection for Layered<C, L> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
+        loop {
+            let (event, seq) = self.inner.wait_for_raw_event_with_sequence()?;
+            if let Some(event) = self.filter_event(event, seq) {
+                return Ok((event, seq));
+            }
+        }
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError> {
+        while let Some((event, seq)) = self.inner.poll_for_raw_event_with_sequence()? {
+            if let Some(event) = self.filter_event(event, seq) {
+                return Ok(Some((event, seq)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.inner.flush()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.inner.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.inner.generate_id()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use std::io::IoSlice;
+
+    use super::{EventAction, IncomingEvent, Layer, Layered, OutgoingRequest};
+    use crate::connection::{Connection, RequestConnection, RequestKind, SequenceNumber};
+    use crate::errors::ConnectionError;
+    use crate::protocol::xproto::{self, ConnectionExt as _};
+    use crate::protocol::Event;
+    use crate::testing::FakeConnection;
+
+    /// Get the bytes of the requests that reached the connection.
+    fn sent_bytes<L>(conn: &Layered<FakeConnection, L>) -> Vec<Vec<u8>> {
+        conn.inner()
+            .sent_requests()
+            .into_iter()
+            .map(|request| request.bytes)
+            .collect()
+    }
+
+    /// A layer that records the major opcodes it sees and denies `Bell` requests.
+    #[derive(Debug, Default)]
+    struct DenyBell(RefCell<Vec<u8>>);
+
+    impl Layer for DenyBell {
+        fn on_request<C>(
+            &self,
+            _conn: &C,
+            request: &mut OutgoingRequest<'_>,
+        ) -> Result<(), ConnectionError>
+        where
+            C: RequestConnection + ?Sized,
+        {
+            self.0.borrow_mut().push(request.major_opcode());
+            if request.major_opcode() == xproto::BELL_REQUEST {
+                Err(ConnectionError::RequestDenied)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn on_event(
+            &self,
+            event: &mut IncomingEvent<'_>,
+            _sequence: SequenceNumber,
+        ) -> EventAction {
+            if event.bytes()[0] == xproto::KEY_PRESS_EVENT {
+                EventAction::Discard
+            } else {
+                EventAction::Deliver
+            }
+        }
+    }
+
+    /// A layer that replaces every `ForceScreenSaver` request with `NoOperation` and turns
+    /// `Expose` events into `NoExposure` events.
+    #[derive(Debug)]
+    struct Rewrite;
+
+    impl Layer for Rewrite {
+        fn on_request<C>(
+            &self,
+            _conn: &C,
+            request: &mut OutgoingRequest<'_>,
+        ) -> Result<(), ConnectionError>
+        where
+            C: RequestConnection + ?Sized,
+        {
+            if request.major_opcode() == xproto::FORCE_SCREEN_SAVER_REQUEST {
+                *request.bytes_mut() = vec![xproto::NO_OPERATION_REQUEST, 0, 1, 0];
+            }
+            Ok(())
+        }
+
+        fn on_event(
+            &self,
+            event: &mut IncomingEvent<'_>,
+            _sequence: SequenceNumber,
+        ) -> EventAction {
+            if event.bytes()[0] == xproto::EXPOSE_EVENT {
+                event.bytes_mut()[0] = xproto::NO_EXPOSURE_EVENT;
+            }
+            EventAction::Deliver
+        }
+    }
+
+    #[test]
+    fn deny_request() {
+        let conn = Layered::new(FakeConnection::default(), DenyBell::default());
+        match conn.bell(0) {
+            Err(ConnectionError::RequestDenied) => {}
+            r => panic!("Unexpected result: {:?}", r),
+        }
+        let _ = conn.ungrab_server().unwrap();
+        assert_eq!(
+            *conn.layer().0.borrow(),
+            [xproto::BELL_REQUEST, xproto::UNGRAB_SERVER_REQUEST]
+        );
+        assert_eq!(
+            sent_bytes(&conn),
+            [[xproto::UNGRAB_SERVER_REQUEST, 0, 1, 0]]
+        );
+    }
+
+    #[test]
+    fn layers_compose() {
+        // The rewriting layer runs first, so the other layer only sees the rewritten request
+        let layers = (Rewrite, DenyBell::default());
+        let conn = Layered::new(FakeConnection::default(), layers);
+        let _ = conn
+            .force_screen_saver(xproto::ScreenSaver::ACTIVE)
+            .unwrap();
+        assert_eq!(*conn.layer().1 .0.borrow(), [xproto::NO_OPERATION_REQUEST]);
+        assert_eq!(sent_bytes(&conn), [[xproto::NO_OPERATION_REQUEST, 0, 1, 0]]);
+    }
+
+    #[test]
+    fn event_filter() {
+        let layers = (None::<Rewrite>, DenyBell::default());
+        let key_press = [xproto::KEY_PRESS_EVENT; 32];
+        assert_eq!(
+            layers.on_event(&mut IncomingEvent::new(&key_press), 0),
+            EventAction::Discard
+        );
+        let expose = [xproto::EXPOSE_EVENT; 32];
+        assert_eq!(
+            layers.on_event(&mut IncomingEvent::new(&expose), 0),
+            EventAction::Deliver
+        );
+    }
+
+    #[test]
+    fn modify_events() {
+        let conn = Layered::new(FakeConnection::default(), (Rewrite, DenyBell::default()));
+        conn.inner()
+            .push_raw_event(vec![xproto::KEY_PRESS_EVENT; 32]);
+        let mut expose = vec![0; 32];
+        expose[0] = xproto::EXPOSE_EVENT;
+        conn.inner().push_raw_event(expose);
+        // The key press is dropped and the Expose event is rewritten
+        match conn.wait_for_event().unwrap() {
+            Event::NoExposure(_) => {}
+            event => panic!("Unexpected event: {:?}", event),
+        }
+        assert!(conn.poll_for_event().unwrap().is_none());
+    }
+
+    #[test]
+    fn requests_are_copied_on_write() {
+        let header = [xproto::CHANGE_PROPERTY_REQUEST, 0, 2, 0];
+        let body = [1, 2, 3, 4];
+        let bufs = [IoSlice::new(&header), IoSlice::new(&body)];
+        let mut request = OutgoingRequest::new(&bufs, Vec::new(), RequestKind::IsVoid);
+        assert_eq!(request.major_opcode(), xproto::CHANGE_PROPERTY_REQUEST);
+        assert_eq!(request.length(), 8);
+        assert_eq!(request.slices().count(), 2);
+        assert_eq!(request.bytes(), [header, body].concat());
+
+        request.bytes_mut()[4] = 42;
+        assert_eq!(
+            request.slices().collect::<Vec<_>>(),
+            [[&header[..], &[42, 2, 3, 4]].concat()]
+        );
+        assert!(matches!(request.bytes(), Cow::Borrowed(_)));
+    }
+}
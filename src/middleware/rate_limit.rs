@@ -0,0 +1,285 @@
+//! A layer that detects runaway request loops.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hasher;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::{Layer, OutgoingRequest};
+use crate::clock::{Clock, SystemClock};
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+
+/// What to do with a request that exceeds the configured rate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Send the request anyway. Only the warning callback is invoked.
+    None,
+    /// Sleep for the given duration before sending the request.
+    Sleep(Duration),
+    /// Do not send the request and fail with [`ConnectionError::RequestDenied`].
+    Deny,
+}
+
+/// Information about a request that was sent more often than allowed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RateLimitExceeded {
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request. For core requests, this is part of the request's data.
+    pub minor_opcode: u8,
+    /// The number of identical requests that were seen in the current time window.
+    pub count: usize,
+    /// The length of the time window.
+    pub window: Duration,
+}
+
+/// The maximum number of different requests that are counted in one time window.
+const MAX_TRACKED_REQUESTS: usize = 4096;
+
+type WarnCallback = Box<dyn Fn(&RateLimitExceeded) + Send + Sync>;
+
+#[derive(Debug)]
+struct State {
+    /// The start of the current time window, if a request was seen yet.
+    window_start: Option<Instant>,
+    counts: HashMap<u64, usize>,
+}
+
+/// A [`Layer`] that detects identical requests that are sent in a tight loop.
+///
+/// A buggy client might for example send thousands of identical `ConfigureWindow` requests per
+/// second. This layer counts identical requests (same bytes) in a time window. Once a request is
+/// seen more often than allowed, the warning callback is called once per window and the
+/// configured [`Backpressure`] is applied to this and further identical requests.
+///
+/// At most 4096 different requests are counted per window, so that the memory use stays bounded
+/// when many different requests are sent. Requests that are first seen after that are not counted
+/// until the next window starts.
+///
+/// ```
+/// use std::time::Duration;
+/// use x11rb::connection::Connection;
+/// use x11rb::middleware::rate_limit::{Backpressure, RateLimit};
+///
+/// fn protect<C: Connection>(conn: C) -> impl Connection {
+///     let limit = RateLimit::new(1000, Duration::from_secs(1))
+///         .backpressure(Backpressure::Sleep(Duration::from_millis(1)))
+///         .warn_with(|exceeded| eprintln!("Runaway request loop: {:?}", exceeded));
+///     conn.wrap(limit)
+/// }
+/// ```
+pub struct RateLimit {
+    max_identical: usize,
+    window: Duration,
+    backpressure: Backpressure,
+    warn: Option<WarnCallback>,
+    clock: Box<dyn Clock>,
+    state: Mutex<State>,
+}
+
+impl std::fmt::Debug for RateLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimit")
+            .field("max_identical", &self.max_identical)
+            .field("window", &self.window)
+            .field("backpressure", &self.backpressure)
+            .field("warn", &self.warn.is_some())
+            .field("clock", &self.clock)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl RateLimit {
+    /// Allow at most `max_identical` identical requests in each time window of length `window`.
+    ///
+    /// By default, requests exceeding this limit are still sent. Use
+    /// [`backpressure()`](Self::backpressure) and [`warn_with()`](Self::warn_with) to configure
+    /// what happens instead.
+    pub fn new(max_identical: usize, window: Duration) -> Self {
+        Self {
+            max_identical,
+            window,
+            backpressure: Backpressure::None,
+            warn: None,
+            clock: Box::new(SystemClock),
+            state: Mutex::new(State {
+                window_start: None,
+                counts: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Set what happens with requests that exceed the limit.
+    pub fn backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Set a callback that is called when a request first exceeds the limit in a time window.
+    pub fn warn_with<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RateLimitExceeded) + Send + Sync + 'static,
+    {
+        self.warn = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the clock that is used to measure the time windows.
+    ///
+    /// The default is [`SystemClock`]. A [`ManualClock`](crate::clock::ManualClock) makes tests
+    /// deterministic.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Count the given request and return how often it was seen in the current window.
+    fn count(&self, request: &OutgoingRequest<'_>) -> usize {
+        // Hash the pieces one by one so that the request does not have to be copied
+        let mut hasher = DefaultHasher::new();
+        request.slices().for_each(|slice| hasher.write(slice));
+        let key = hasher.finish();
+
+        let mut state = self.state.lock().unwrap();
+        let now = self.clock.now();
+        let expired = match state.window_start {
+            Some(start) => now.saturating_duration_since(start) >= self.window,
+            None => true,
+        };
+        if expired {
+            state.window_start = Some(now);
+            state.counts.clear();
+        }
+        let tracked = state.counts.len();
+        match state.counts.get_mut(&key) {
+            Some(count) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                if tracked < MAX_TRACKED_REQUESTS {
+                    let _ = state.counts.insert(key, 1);
+                }
+                1
+            }
+        }
+    }
+}
+
+impl Layer for RateLimit {
+    fn on_request<C>(
+        &self,
+        _conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let count = self.count(request);
+        if count <= self.max_identical {
+            return Ok(());
+        }
+        if count == self.max_identical + 1 {
+            if let Some(warn) = &self.warn {
+                warn(&RateLimitExceeded {
+                    major_opcode: request.major_opcode(),
+                    minor_opcode: request.minor_opcode(),
+                    count,
+                    window: self.window,
+                });
+            }
+        }
+        match self.backpressure {
+            Backpressure::None => Ok(()),
+            Backpressure::Sleep(duration) => {
+                std::thread::sleep(duration);
+                Ok(())
+            }
+            Backpressure::Deny => Err(ConnectionError::RequestDenied),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::{Backpressure, RateLimit};
+    use crate::clock::ManualClock;
+    use crate::connection::RequestKind;
+    use crate::errors::ConnectionError;
+    use crate::middleware::{Layer, OutgoingRequest};
+    use crate::testing::FakeConnection;
+
+    fn check(limit: &RateLimit, bytes: &[u8]) -> Result<(), ConnectionError> {
+        let bufs = [IoSlice::new(bytes)];
+        let mut request = OutgoingRequest::new(&bufs, Vec::new(), RequestKind::IsVoid);
+        limit.on_request(&FakeConnection::default(), &mut request)
+    }
+
+    #[test]
+    fn deny_identical_requests() {
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let warnings2 = Arc::clone(&warnings);
+        let limit = RateLimit::new(2, Duration::from_secs(3600))
+            .backpressure(Backpressure::Deny)
+            .warn_with(move |exceeded| {
+                assert_eq!(exceeded.major_opcode, 12);
+                let _ = warnings2.fetch_add(1, Ordering::SeqCst);
+            });
+        let configure = [12, 0, 2, 0, 1, 0, 0, 0];
+        let other = [12, 0, 2, 0, 2, 0, 0, 0];
+        assert!(check(&limit, &configure).is_ok());
+        assert!(check(&limit, &configure).is_ok());
+        assert!(check(&limit, &other).is_ok());
+        for _ in 0..3 {
+            match check(&limit, &configure) {
+                Err(ConnectionError::RequestDenied) => {}
+                r => panic!("Unexpected result: {:?}", r),
+            }
+        }
+        assert!(check(&limit, &other).is_ok());
+        assert_eq!(warnings.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn tracked_requests_are_bounded() {
+        let limit = RateLimit::new(1, Duration::from_secs(3600)).backpressure(Backpressure::Deny);
+        let request = |i: u32| {
+            let mut bytes = vec![127, 0, 2, 0];
+            bytes.extend(i.to_ne_bytes());
+            bytes
+        };
+        let max = super::MAX_TRACKED_REQUESTS as u32;
+        for i in 0..max + 10 {
+            assert!(check(&limit, &request(i)).is_ok());
+        }
+        assert_eq!(
+            limit.state.lock().unwrap().counts.len(),
+            super::MAX_TRACKED_REQUESTS
+        );
+        // Requests that are counted are still limited, the others are not
+        assert!(check(&limit, &request(0)).is_err());
+        assert!(check(&limit, &request(max)).is_ok());
+    }
+
+    #[test]
+    fn window_resets() {
+        let clock = ManualClock::new();
+        let limit = RateLimit::new(1, Duration::from_secs(1))
+            .backpressure(Backpressure::Deny)
+            .clock(clock.clone());
+        let request = [127, 0, 1, 0];
+        assert!(check(&limit, &request).is_ok());
+        clock.advance(Duration::from_millis(999));
+        assert!(check(&limit, &request).is_err());
+        clock.advance(Duration::from_millis(1));
+        assert!(check(&limit, &request).is_ok());
+    }
+}
@@ -0,0 +1,242 @@
+//! A layer that reports requests whose replies take long to arrive.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::{Layer, OutgoingRequest, Response};
+use crate::clock::{Clock, SystemClock};
+use crate::connection::{RequestConnection, RequestKind, SequenceNumber};
+
+/// The maximum number of requests that are tracked at the same time.
+const MAX_PENDING: usize = 4096;
+
+/// The maximum number of slow replies that are kept until they are taken.
+const MAX_RECORDED: usize = 256;
+
+/// Information about a request whose reply took longer than the threshold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SlowReply {
+    /// The sequence number of the request.
+    pub sequence: SequenceNumber,
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request. For core requests, this is part of the request's data.
+    pub minor_opcode: u8,
+    /// The time between sending the request and receiving its response.
+    pub elapsed: Duration,
+    /// Whether the response was an error instead of a reply.
+    pub is_error: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Pending {
+    major_opcode: u8,
+    minor_opcode: u8,
+    sent: Instant,
+}
+
+type ReportCallback = Box<dyn Fn(&SlowReply) + Send + Sync>;
+
+/// A [`Layer`] that detects requests whose replies take longer than a threshold.
+///
+/// Slow replies usually mean that the X11 server is busy, e.g. with a storm of synchronous
+/// `QueryTree` requests from some client. This layer remembers when each request with a reply was
+/// sent. When the application receives the reply or error and this took at least the threshold,
+/// the request is reported to the callback from [`report_with()`](Self::report_with) and recorded
+/// until [`take_slow_replies()`](Self::take_slow_replies) is called.
+///
+/// The time is measured until the application gets the response, so waiting for a reply long
+/// after sending the request is reported as well. Replies that are discarded, e.g. because their
+/// cookie was dropped, are never seen by this layer.
+///
+/// ```
+/// use std::time::Duration;
+/// use x11rb::connection::Connection;
+/// use x11rb::middleware::slow_reply::SlowReplyWatchdog;
+///
+/// fn watch<C: Connection>(conn: C) -> impl Connection {
+///     let watchdog = SlowReplyWatchdog::new(Duration::from_millis(100)).report_with(|slow| {
+///         eprintln!(
+///             "Request {} (opcode {}) took {:?}",
+///             slow.sequence, slow.major_opcode, slow.elapsed
+///         )
+///     });
+///     conn.wrap(watchdog)
+/// }
+/// ```
+pub struct SlowReplyWatchdog {
+    threshold: Duration,
+    report: Option<ReportCallback>,
+    clock: Box<dyn Clock>,
+    pending: Mutex<BTreeMap<SequenceNumber, Pending>>,
+    recorded: Mutex<Vec<SlowReply>>,
+}
+
+impl std::fmt::Debug for SlowReplyWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlowReplyWatchdog")
+            .field("threshold", &self.threshold)
+            .field("report", &self.report.is_some())
+            .field("clock", &self.clock)
+            .field("pending", &self.pending)
+            .field("recorded", &self.recorded)
+            .finish()
+    }
+}
+
+impl SlowReplyWatchdog {
+    /// Report replies that take at least `threshold` to arrive.
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            report: None,
+            clock: Box::new(SystemClock),
+            pending: Mutex::new(BTreeMap::new()),
+            recorded: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Set a callback that is called for each slow reply.
+    pub fn report_with<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&SlowReply) + Send + Sync + 'static,
+    {
+        self.report = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the clock that is used to measure how long replies take.
+    ///
+    /// The default is [`SystemClock`]. A [`ManualClock`](crate::clock::ManualClock) makes tests
+    /// deterministic.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Get the slow replies that were recorded since the last call.
+    ///
+    /// Only the most recent slow replies are kept if this is not called regularly.
+    pub fn take_slow_replies(&self) -> Vec<SlowReply> {
+        std::mem::take(&mut *self.recorded.lock().unwrap())
+    }
+
+    fn record(&self, slow: SlowReply) {
+        if let Some(report) = &self.report {
+            report(&slow);
+        }
+        let mut recorded = self.recorded.lock().unwrap();
+        if recorded.len() == MAX_RECORDED {
+            let _ = recorded.remove(0);
+        }
+        recorded.push(slow);
+    }
+}
+
+impl Layer for SlowReplyWatchdog {
+    fn on_request_sent<C>(&self, _conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        if request.kind() == RequestKind::IsVoid {
+            return;
+        }
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() == MAX_PENDING {
+            // The application does not wait for these replies
+            let oldest = *pending.keys().next().unwrap();
+            let _ = pending.remove(&oldest);
+        }
+        let _ = pending.insert(
+            sequence,
+            Pending {
+                major_opcode: request.major_opcode(),
+                minor_opcode: request.minor_opcode(),
+                sent: self.clock.now(),
+            },
+        );
+    }
+
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        let request = {
+            let mut pending = self.pending.lock().unwrap();
+            let request = pending.remove(&sequence);
+            // The responses to earlier requests already arrived. They were either discarded or
+            // are only fetched later, so their timing would not be meaningful.
+            *pending = pending.split_off(&sequence);
+            request
+        };
+        let request = match request {
+            Some(request) => request,
+            None => return,
+        };
+        let elapsed = self.clock.now().saturating_duration_since(request.sent);
+        if elapsed >= self.threshold {
+            self.record(SlowReply {
+                sequence,
+                major_opcode: request.major_opcode,
+                minor_opcode: request.minor_opcode,
+                elapsed,
+                is_error: matches!(response, Response::Error(_)),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+    use std::time::Duration;
+
+    use super::SlowReplyWatchdog;
+    use crate::clock::ManualClock;
+    use crate::connection::RequestKind;
+    use crate::middleware::{Layer, OutgoingRequest, Response};
+    use crate::testing::FakeConnection;
+
+    fn send(watchdog: &SlowReplyWatchdog, sequence: u64, opcode: u8, kind: RequestKind) {
+        let bytes = [opcode, 0, 1, 0];
+        let bufs = [IoSlice::new(&bytes)];
+        let request = OutgoingRequest::new(&bufs, Vec::new(), kind);
+        watchdog.on_request_sent(&FakeConnection::default(), &request, sequence);
+    }
+
+    #[test]
+    fn report_slow_replies() {
+        let watchdog = SlowReplyWatchdog::new(Duration::from_secs(0));
+        send(&watchdog, 1, 15, RequestKind::HasResponse);
+        send(&watchdog, 2, 104, RequestKind::IsVoid);
+        send(&watchdog, 3, 43, RequestKind::HasResponse);
+        send(&watchdog, 4, 20, RequestKind::HasResponse);
+
+        watchdog.on_response(3, Response::Error(&[0; 32]));
+        // The reply to the first request was already received by the connection
+        watchdog.on_response(1, Response::Reply(&[1; 32]));
+        watchdog.on_response(4, Response::Reply(&[1; 32]));
+        let slow = watchdog.take_slow_replies();
+        let slow = slow
+            .iter()
+            .map(|slow| (slow.sequence, slow.major_opcode, slow.is_error))
+            .collect::<Vec<_>>();
+        assert_eq!(slow, [(3, 43, true), (4, 20, false)]);
+        assert!(watchdog.take_slow_replies().is_empty());
+    }
+
+    #[test]
+    fn fast_replies_are_ignored() {
+        let clock = ManualClock::new();
+        let watchdog = SlowReplyWatchdog::new(Duration::from_secs(1)).clock(clock.clone());
+        send(&watchdog, 1, 43, RequestKind::HasResponse);
+        send(&watchdog, 2, 43, RequestKind::HasResponse);
+        clock.advance(Duration::from_millis(999));
+        watchdog.on_response(1, Response::Reply(&[1; 32]));
+        assert!(watchdog.take_slow_replies().is_empty());
+        clock.advance(Duration::from_millis(1));
+        watchdog.on_response(2, Response::Reply(&[1; 32]));
+        let slow = watchdog.take_slow_replies();
+        assert_eq!(slow.len(), 1);
+        assert_eq!(slow[0].elapsed, Duration::from_secs(1));
+        assert!(watchdog.pending.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! A layer that counts the places where the application blocks for a round trip.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::{Layer, OutgoingRequest};
+use crate::connection::{RequestConnection, SequenceNumber};
+
+/// The environment variable that enables logging in [`SyncAudit::from_env`].
+pub const SYNC_AUDIT_ENV: &str = "X11RB_SYNC_AUDIT";
+
+/// The maximum number of requests whose opcodes are remembered at the same time.
+const MAX_PENDING: usize = 4096;
+
+/// The maximum number of synchronous calls that are kept until they are reset.
+const MAX_RECORDED: usize = 256;
+
+/// Information about a place where the application blocked until a response arrived.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SyncCall {
+    /// The number of synchronous calls since the last reset, including this one.
+    pub count: usize,
+    /// The sequence number of the request.
+    pub sequence: SequenceNumber,
+    /// The major opcode of the request, if it was sent through this layer.
+    pub major_opcode: Option<u8>,
+    /// The minor opcode of the request, if it was sent through this layer. For core requests,
+    /// this is part of the request's data.
+    pub minor_opcode: Option<u8>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    count: usize,
+    recorded: Vec<SyncCall>,
+}
+
+type ReportCallback = Box<dyn Fn(&SyncCall) + Send + Sync>;
+
+/// A [`Layer`] that counts how often the application waits for a response.
+///
+/// Every wait for a reply and every check of a request without reply for errors blocks the
+/// application for a round trip to the X11 server. Code that is meant to be asynchronous, e.g.
+/// the drawing of a frame, should do this rarely. This layer counts these waits, so that tests can
+/// assert an upper bound via [`assert_at_most()`](Self::assert_at_most).
+///
+/// When logging is enabled, each wait is printed to stderr. [`SyncAudit::from_env`] enables it
+/// when the environment variable `X11RB_SYNC_AUDIT` is set, so that the audit can be switched on
+/// in CI without changing the code. To find out where a wait comes from, capture a backtrace in
+/// the callback from [`report_with()`](Self::report_with); it runs on the thread that is about to
+/// block.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::middleware::sync_audit::SyncAudit;
+/// use x11rb::protocol::xproto::ConnectionExt as _;
+///
+/// fn check<C: Connection>(conn: C) -> Result<(), ReplyError> {
+///     let conn = conn.wrap(SyncAudit::from_env());
+///     let root = conn.setup().roots[0].root;
+///     // Send both requests before waiting for the first reply, so that they are answered
+///     // in a single round trip
+///     let tree = conn.query_tree(root)?;
+///     let pointer = conn.query_pointer(root)?;
+///     let (_tree, _pointer) = (tree.reply()?, pointer.reply()?);
+///     conn.layer().assert_at_most(2);
+///     Ok(())
+/// }
+/// ```
+pub struct SyncAudit {
+    log: bool,
+    report: Option<ReportCallback>,
+    pending: Mutex<BTreeMap<SequenceNumber, (u8, u8)>>,
+    state: Mutex<State>,
+}
+
+impl std::fmt::Debug for SyncAudit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncAudit")
+            .field("log", &self.log)
+            .field("report", &self.report.is_some())
+            .field("pending", &self.pending)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl Default for SyncAudit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyncAudit {
+    /// Count synchronous calls without logging them.
+    pub fn new() -> Self {
+        Self {
+            log: false,
+            report: None,
+            pending: Mutex::new(BTreeMap::new()),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Count synchronous calls and log them if the environment variable `X11RB_SYNC_AUDIT` is set
+    /// to anything except an empty string or `0`.
+    pub fn from_env() -> Self {
+        let log = matches!(
+            std::env::var_os(SYNC_AUDIT_ENV),
+            Some(value) if !value.is_empty() && value != "0"
+        );
+        Self::new().log(log)
+    }
+
+    /// Set whether each synchronous call is printed to stderr.
+    pub fn log(mut self, log: bool) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// Set a callback that is called for each synchronous call before the application blocks.
+    pub fn report_with<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&SyncCall) + Send + Sync + 'static,
+    {
+        self.report = Some(Box::new(callback));
+        self
+    }
+
+    /// Get the number of synchronous calls since the last reset.
+    pub fn count(&self) -> usize {
+        self.state.lock().unwrap().count
+    }
+
+    /// Get the synchronous calls since the last reset.
+    ///
+    /// Only the first calls are kept if there are very many of them.
+    pub fn calls(&self) -> Vec<SyncCall> {
+        self.state.lock().unwrap().recorded.clone()
+    }
+
+    /// Start counting from zero again, e.g. at the start of the code path that is audited.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = State::default();
+    }
+
+    /// Panic if there were more than `max` synchronous calls since the last reset.
+    ///
+    /// The panic message lists the requests that were waited for.
+    #[track_caller]
+    pub fn assert_at_most(&self, max: usize) {
+        let state = self.state.lock().unwrap();
+        if state.count > max {
+            let calls = state
+                .recorded
+                .iter()
+                .map(describe)
+                .collect::<Vec<_>>()
+                .join("\n");
+            panic!(
+                "Expected at most {} synchronous calls, but there were {}:\n{}",
+                max, state.count, calls
+            );
+        }
+    }
+}
+
+impl Layer for SyncAudit {
+    fn on_request_sent<C>(&self, _conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() == MAX_PENDING {
+            let oldest = *pending.keys().next().unwrap();
+            let _ = pending.remove(&oldest);
+        }
+        let _ = pending.insert(sequence, (request.major_opcode(), request.minor_opcode()));
+    }
+
+    fn on_wait(&self, sequence: SequenceNumber) {
+        let opcodes = self.pending.lock().unwrap().remove(&sequence);
+        let call = {
+            let mut state = self.state.lock().unwrap();
+            state.count += 1;
+            let call = SyncCall {
+                count: state.count,
+                sequence,
+                major_opcode: opcodes.map(|(major, _)| major),
+                minor_opcode: opcodes.map(|(_, minor)| minor),
+            };
+            if state.recorded.len() < MAX_RECORDED {
+                state.recorded.push(call);
+            }
+            call
+        };
+        if self.log {
+            eprintln!("x11rb: {}", describe(&call));
+        }
+        if let Some(report) = &self.report {
+            report(&call);
+        }
+    }
+}
+
+fn describe(call: &SyncCall) -> String {
+    match (call.major_opcode, call.minor_opcode) {
+        (Some(major), Some(minor)) => format!(
+            "synchronous call #{} waits for request {} (opcode {}, minor {})",
+            call.count, call.sequence, major, minor
+        ),
+        _ => format!(
+            "synchronous call #{} waits for request {}",
+            call.count, call.sequence
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::{SyncAudit, SyncCall};
+    use crate::connection::RequestKind;
+    use crate::middleware::{Layer, OutgoingRequest};
+    use crate::testing::FakeConnection;
+
+    fn send(audit: &SyncAudit, sequence: u64, opcode: u8, kind: RequestKind) {
+        let bytes = [opcode, 0, 1, 0];
+        let bufs = [IoSlice::new(&bytes)];
+        let request = OutgoingRequest::new(&bufs, Vec::new(), kind);
+        audit.on_request_sent(&FakeConnection::default(), &request, sequence);
+    }
+
+    #[test]
+    fn count_waits() {
+        let audit = SyncAudit::new();
+        send(&audit, 1, 15, RequestKind::HasResponse);
+        send(&audit, 2, 104, RequestKind::IsVoid);
+        send(&audit, 3, 43, RequestKind::HasResponse);
+        audit.on_wait(3);
+        audit.on_wait(2);
+        audit.on_wait(7);
+        assert_eq!(audit.count(), 3);
+        assert_eq!(
+            audit.calls()[1],
+            SyncCall {
+                count: 2,
+                sequence: 2,
+                major_opcode: Some(104),
+                minor_opcode: Some(0),
+            }
+        );
+        assert_eq!(audit.calls()[2].major_opcode, None);
+        audit.assert_at_most(3);
+
+        audit.reset();
+        assert_eq!(audit.count(), 0);
+        assert!(audit.calls().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 1 synchronous calls, but there were 2")]
+    fn assert_at_most_panics() {
+        let audit = SyncAudit::new();
+        audit.on_wait(1);
+        audit.on_wait(2);
+        audit.assert_at_most(1);
+    }
+}
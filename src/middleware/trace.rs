@@ -0,0 +1,307 @@
+//! A layer that reports requests, replies, errors, and events.
+
+#[cfg(feature = "dynamic")]
+use std::sync::Mutex;
+
+use super::{EventAction, IncomingEvent, Layer, OutgoingRequest, Response};
+use crate::connection::{RequestConnection, SequenceNumber};
+
+/// Information about a request that was sent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RequestTrace {
+    /// The sequence number of the request.
+    pub sequence: SequenceNumber,
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request. For core requests, this is part of the request's data.
+    pub minor_opcode: u8,
+    /// The length of the request in bytes.
+    pub length: usize,
+    /// The module in [`crate::protocol`] that the request belongs to, e.g. `"xproto"`.
+    ///
+    /// This is only known with the `dynamic` feature.
+    pub module: Option<&'static str>,
+    /// The name of the request, e.g. `"CreateWindow"`.
+    ///
+    /// This is only known with the `dynamic` feature.
+    pub name: Option<&'static str>,
+}
+
+/// Something that passed through a [`Trace`] layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceEvent<'a> {
+    /// A request was sent.
+    Request(RequestTrace),
+    /// A reply to the request with the given sequence number was received.
+    Reply {
+        /// The sequence number of the request.
+        sequence: SequenceNumber,
+        /// The raw bytes of the reply.
+        bytes: &'a [u8],
+    },
+    /// An error for the request with the given sequence number was received.
+    ///
+    /// This includes errors that are received as events.
+    Error {
+        /// The sequence number of the request.
+        sequence: SequenceNumber,
+        /// The error code.
+        error_code: u8,
+        /// The raw bytes of the error.
+        bytes: &'a [u8],
+    },
+    /// An event was received.
+    Event {
+        /// The sequence number of the last request that the X11 server processed before
+        /// generating the event.
+        sequence: SequenceNumber,
+        /// The raw bytes of the event.
+        bytes: &'a [u8],
+    },
+}
+
+type TraceCallback = Box<dyn Fn(&TraceEvent<'_>) + Send + Sync>;
+
+/// A [`Layer`] that reports everything that passes through a connection to a callback.
+///
+/// Each request is reported with its sequence number, so that replies, errors, and events can be
+/// correlated with the code that sent the request, e.g. by creating `tracing` spans. With the
+/// `dynamic` feature, the names of requests are looked up as well. Note that this sends
+/// `QueryExtension` requests for all known extensions when the first extension request is seen.
+///
+/// Only replies and errors that the application waits for are reported. Replies that are
+/// discarded, e.g. because their cookie was dropped, are never seen by this layer.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::middleware::trace::{Trace, TraceEvent};
+///
+/// fn trace<C: Connection>(conn: C) -> impl Connection {
+///     conn.wrap(Trace::new(|event| match event {
+///         TraceEvent::Request(request) => eprintln!(
+///             "Request {} ({:?}) with {} bytes",
+///             request.sequence, request.name, request.length,
+///         ),
+///         TraceEvent::Error { sequence, error_code, .. } => {
+///             eprintln!("Error {} for request {}", error_code, sequence);
+///         }
+///         _ => {}
+///     }))
+/// }
+/// ```
+pub struct Trace {
+    callback: TraceCallback,
+    /// The major opcodes of known extensions, once they were looked up.
+    #[cfg(feature = "dynamic")]
+    extensions: Mutex<Option<Vec<(u8, &'static str)>>>,
+}
+
+impl std::fmt::Debug for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Trace").finish_non_exhaustive()
+    }
+}
+
+impl Trace {
+    /// Report everything that passes through the connection to the given callback.
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(&TraceEvent<'_>) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Box::new(callback),
+            #[cfg(feature = "dynamic")]
+            extensions: Mutex::new(None),
+        }
+    }
+
+    /// Look up the module and the name of a request.
+    #[cfg(feature = "dynamic")]
+    fn request_name<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        major_opcode: u8,
+        minor_opcode: u8,
+    ) -> Option<(&'static str, &'static str)> {
+        let requests = crate::dynamic::requests();
+        let info = if major_opcode < 128 {
+            requests
+                .iter()
+                .find(|info| info.extension.is_none() && info.opcode == major_opcode)
+        } else {
+            let mut extensions = self.extensions.lock().unwrap();
+            let extensions = extensions.get_or_insert_with(|| query_extensions(conn));
+            let &(_, extension) = extensions
+                .iter()
+                .find(|(major, _)| *major == major_opcode)?;
+            requests
+                .iter()
+                .find(|info| info.extension == Some(extension) && info.opcode == minor_opcode)
+        };
+        info.map(|info| (info.module, info.name))
+    }
+
+    #[cfg(not(feature = "dynamic"))]
+    fn request_name<C: RequestConnection + ?Sized>(
+        &self,
+        _conn: &C,
+        _major_opcode: u8,
+        _minor_opcode: u8,
+    ) -> Option<(&'static str, &'static str)> {
+        None
+    }
+}
+
+/// Get the major opcodes of all extensions that the X11 server supports.
+#[cfg(feature = "dynamic")]
+fn query_extensions<C: RequestConnection + ?Sized>(conn: &C) -> Vec<(u8, &'static str)> {
+    let mut names = crate::dynamic::requests()
+        .iter()
+        .filter_map(|info| info.extension)
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    // Send all QueryExtension requests before waiting for the first reply
+    for name in names.iter() {
+        let _ = conn.prefetch_extension_information(name);
+    }
+    names
+        .into_iter()
+        .filter_map(|name| match conn.extension_information(name) {
+            Ok(Some(info)) => Some((info.major_opcode, name)),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Layer for Trace {
+    fn on_request_sent<C>(&self, conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let (major_opcode, minor_opcode) = (request.major_opcode(), request.minor_opcode());
+        let name = self.request_name(conn, major_opcode, minor_opcode);
+        (self.callback)(&TraceEvent::Request(RequestTrace {
+            sequence,
+            major_opcode,
+            minor_opcode,
+            length: request.length(),
+            module: name.map(|(module, _)| module),
+            name: name.map(|(_, name)| name),
+        }));
+    }
+
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        let event = match response {
+            Response::Reply(bytes) => TraceEvent::Reply { sequence, bytes },
+            Response::Error(bytes) => TraceEvent::Error {
+                sequence,
+                error_code: bytes.get(1).copied().unwrap_or(0),
+                bytes,
+            },
+        };
+        (self.callback)(&event);
+    }
+
+    fn on_event(&self, event: &mut IncomingEvent<'_>, sequence: SequenceNumber) -> EventAction {
+        let event = event.bytes();
+        let event = match event.first() {
+            Some(0) => TraceEvent::Error {
+                sequence,
+                error_code: event.get(1).copied().unwrap_or(0),
+                bytes: event,
+            },
+            _ => TraceEvent::Event {
+                sequence,
+                bytes: event,
+            },
+        };
+        (self.callback)(&event);
+        EventAction::Deliver
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::{RequestTrace, Trace, TraceEvent};
+    use crate::middleware::{EventAction, IncomingEvent, Layer, Layered, Response};
+    use crate::protocol::xproto::{self, ConnectionExt as _};
+    use crate::testing::FakeConnection;
+
+    fn recording_trace() -> (Trace, Arc<Mutex<Vec<String>>>) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let log2 = Arc::clone(&log);
+        let trace = Trace::new(move |event| {
+            let entry = match event {
+                TraceEvent::Request(RequestTrace {
+                    sequence,
+                    major_opcode,
+                    length,
+                    name,
+                    ..
+                }) => format!(
+                    "request {} {} {} {:?}",
+                    sequence, major_opcode, length, name
+                ),
+                TraceEvent::Reply { sequence, .. } => format!("reply {}", sequence),
+                TraceEvent::Error {
+                    sequence,
+                    error_code,
+                    ..
+                } => format!("error {} {}", sequence, error_code),
+                TraceEvent::Event { sequence, bytes } => {
+                    format!("event {} {}", sequence, bytes[0])
+                }
+            };
+            log2.lock().unwrap().push(entry);
+        });
+        (trace, log)
+    }
+
+    #[test]
+    fn trace_requests() {
+        let (trace, log) = recording_trace();
+        let conn = Layered::new(FakeConnection::default(), trace);
+        let _ = conn.ungrab_server().unwrap();
+        let _ = conn.bell(0).unwrap();
+        let name = |name| {
+            if cfg!(feature = "dynamic") {
+                Some(name)
+            } else {
+                None
+            }
+        };
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                format!(
+                    "request 1 {} 4 {:?}",
+                    xproto::UNGRAB_SERVER_REQUEST,
+                    name("UngrabServer")
+                ),
+                format!("request 2 {} 4 {:?}", xproto::BELL_REQUEST, name("Bell")),
+            ]
+        );
+    }
+
+    #[test]
+    fn trace_responses() {
+        let (trace, log) = recording_trace();
+        trace.on_response(1, Response::Reply(&[1; 32]));
+        trace.on_response(2, Response::Error(&[0, 3, 0, 0]));
+        assert_eq!(
+            trace.on_event(&mut IncomingEvent::new(&[0, 9]), 3),
+            EventAction::Deliver
+        );
+        assert_eq!(
+            trace.on_event(&mut IncomingEvent::new(&[xproto::EXPOSE_EVENT; 32]), 4),
+            EventAction::Deliver
+        );
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["reply 1", "error 2 3", "error 3 9", "event 4 12"]
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! A layer that keeps the events and errors that could not be parsed.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use super::{Layer, UnparsedPacket};
+use crate::errors::ParseError;
+use crate::protocol::xproto::GE_GENERIC_EVENT;
+
+/// What kind of packet an [`UnparsedRecord`] is about.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UnparsedKind {
+    /// An event that is not known to x11rb.
+    UnknownEvent,
+    /// An event that could not be parsed.
+    Event,
+    /// An error that could not be parsed.
+    Error,
+}
+
+/// A copy of a packet that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnparsedRecord {
+    /// What kind of packet this is.
+    pub kind: UnparsedKind,
+    /// The error that occurred while parsing, if any.
+    pub error: Option<ParseError>,
+    /// The raw bytes of the packet.
+    pub bytes: Vec<u8>,
+}
+
+impl UnparsedRecord {
+    /// Get the response type of the packet, i.e. its first byte.
+    ///
+    /// For events, this is the event code; for errors, this is `0`.
+    pub fn response_type(&self) -> Option<u8> {
+        self.bytes.first().map(|code| code & 0x7f)
+    }
+
+    /// For generic events, get the major opcode of the extension and the event type.
+    pub fn generic_event(&self) -> Option<(u8, u16)> {
+        match self.bytes.get(..10)? {
+            [GE_GENERIC_EVENT, extension, _, _, _, _, _, _, low, high]
+                if self.kind != UnparsedKind::Error =>
+            {
+                Some((*extension, u16::from_ne_bytes([*low, *high])))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<UnparsedPacket<'_>> for UnparsedRecord {
+    fn from(packet: UnparsedPacket<'_>) -> Self {
+        let kind = match packet {
+            UnparsedPacket::UnknownEvent(_) => UnparsedKind::UnknownEvent,
+            UnparsedPacket::Event(..) => UnparsedKind::Event,
+            UnparsedPacket::Error(..) => UnparsedKind::Error,
+        };
+        Self {
+            kind,
+            error: packet.error(),
+            bytes: packet.bytes().to_vec(),
+        }
+    }
+}
+
+type ReportCallback = Box<dyn Fn(&UnparsedPacket<'_>) + Send + Sync>;
+
+/// A [`Layer`] that keeps the last events and errors that could not be parsed.
+///
+/// Events that x11rb does not know, e.g. from extensions that were not enabled at compile time,
+/// are delivered as [`crate::protocol::Event::Unknown`]. This layer keeps a copy of them and of
+/// all packets that failed to parse, together with the parse error. The last packets can be
+/// retrieved via [`UnparsedLog::records`], e.g. to attach them to a bug report. A callback can
+/// additionally be called for each packet, e.g. to log it.
+///
+/// Only events and errors that are parsed via the wrapped connection are seen by this layer.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::middleware::unparsed::UnparsedLog;
+///
+/// fn log_unparsed<C: Connection>(conn: C) -> impl Connection {
+///     conn.wrap(UnparsedLog::new(16).report_with(|packet| {
+///         eprintln!("Could not parse {:?}", packet);
+///     }))
+/// }
+/// ```
+pub struct UnparsedLog {
+    capacity: usize,
+    records: Mutex<VecDeque<UnparsedRecord>>,
+    callback: Option<ReportCallback>,
+}
+
+impl std::fmt::Debug for UnparsedLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnparsedLog")
+            .field("capacity", &self.capacity)
+            .field("records", &self.records)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UnparsedLog {
+    /// Keep the last `capacity` packets that could not be parsed.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+            callback: None,
+        }
+    }
+
+    /// Call the given function for every packet that could not be parsed.
+    pub fn report_with<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&UnparsedPacket<'_>) + Send + Sync + 'static,
+    {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Get a copy of the kept packets, oldest first.
+    pub fn records(&self) -> Vec<UnparsedRecord> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Forget all kept packets.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+impl Layer for UnparsedLog {
+    fn on_unparsed(&self, packet: UnparsedPacket<'_>) {
+        if let Some(callback) = &self.callback {
+            callback(&packet);
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap();
+        if records.len() == self.capacity {
+            let _ = records.pop_front();
+        }
+        records.push_back(packet.into());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::{UnparsedKind, UnparsedLog};
+    use crate::connection::RequestConnection;
+    use crate::errors::ParseError;
+    use crate::middleware::Layered;
+    use crate::protocol::{xproto, Event};
+    use crate::testing::FakeConnection;
+
+    #[test]
+    fn keep_last_packets() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = Arc::clone(&count);
+        let log = UnparsedLog::new(2).report_with(move |_| {
+            let _ = count2.fetch_add(1, Ordering::Relaxed);
+        });
+        let conn = Layered::new(FakeConnection::default(), log);
+
+        let mut expose = vec![0; 32];
+        expose[0] = xproto::EXPOSE_EVENT;
+        assert!(matches!(conn.parse_event(&expose), Ok(Event::Expose(_))));
+        assert!(matches!(
+            conn.parse_event(&[200; 32]),
+            Ok(Event::Unknown(_))
+        ));
+        assert!(conn.parse_event(&[xproto::EXPOSE_EVENT; 4]).is_err());
+        assert!(conn.parse_error(&[0, 1, 0]).is_err());
+
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+        let records = conn.layer().records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, UnparsedKind::Event);
+        assert_eq!(records[0].error, Some(ParseError::InsufficientData));
+        assert_eq!(records[0].response_type(), Some(xproto::EXPOSE_EVENT));
+        assert_eq!(records[1].kind, UnparsedKind::Error);
+        assert_eq!(records[1].bytes, [0, 1, 0]);
+        conn.layer().clear();
+        assert!(conn.layer().records().is_empty());
+    }
+
+    #[test]
+    fn generic_event() {
+        let log = UnparsedLog::new(1);
+        let conn = Layered::new(FakeConnection::default(), log);
+        let mut event = vec![0; 32];
+        event[0] = xproto::GE_GENERIC_EVENT;
+        event[1] = 140;
+        event[8..10].copy_from_slice(&7u16.to_ne_bytes());
+        assert!(matches!(conn.parse_event(&event), Ok(Event::Unknown(_))));
+        let records = conn.layer().records();
+        assert_eq!(records[0].kind, UnparsedKind::UnknownEvent);
+        assert_eq!(records[0].generic_event(), Some((140, 7)));
+    }
+}
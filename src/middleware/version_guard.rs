@@ -0,0 +1,291 @@
+//! A layer that refuses requests which the X11 server does not support.
+//!
+//! x11rb is generated from the newest protocol descriptions, but X11 servers often implement
+//! older versions of an extension. Sending a request that only exists in a newer version results
+//! in a `Request` error, which is usually only noticed much later. [`VersionGuard`] instead
+//! fails when sending such a request.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::Mutex;
+
+use super::{Layer, OutgoingRequest, Response};
+use crate::connection::{RequestConnection, SequenceNumber};
+use crate::errors::ConnectionError;
+
+/// Extension versions as `(major, minor)`.
+type Version = (u32, u32);
+
+/// Requests that were added after the first version of an extension.
+///
+/// Each entry contains the extension name, a range of minor opcodes and the version that
+/// introduced these requests.
+const KNOWN_REQUIREMENTS: &[(&str, u8, u8, Version)] = &[
+    // Composite: CreateRegionFromBorderClip and NameWindowPixmap
+    ("Composite", 5, 6, (0, 2)),
+    // Composite: GetOverlayWindow and ReleaseOverlayWindow
+    ("Composite", 7, 8, (0, 3)),
+    // DAMAGE: Add
+    ("DAMAGE", 4, 4, (1, 1)),
+    // DRI3: GetSupportedModifiers, PixmapFromBuffers and BuffersFromPixmap
+    ("DRI3", 6, 8, (1, 2)),
+    // RANDR: GetScreenSizeRange up to SetCrtcGamma
+    ("RANDR", 6, 24, (1, 2)),
+    // RANDR: GetScreenResourcesCurrent up to GetOutputPrimary
+    ("RANDR", 25, 31, (1, 3)),
+    // RANDR: Providers and their properties
+    ("RANDR", 32, 41, (1, 4)),
+    // RANDR: GetMonitors, SetMonitor and DeleteMonitor
+    ("RANDR", 42, 44, (1, 5)),
+    // RANDR: CreateLease and FreeLease
+    ("RANDR", 45, 46, (1, 6)),
+    // XFIXES: Regions and cursor names
+    ("XFIXES", 5, 27, (2, 0)),
+    // XFIXES: ExpandRegion
+    ("XFIXES", 28, 28, (3, 0)),
+    // XFIXES: HideCursor and ShowCursor
+    ("XFIXES", 29, 30, (4, 0)),
+    // XFIXES: CreatePointerBarrier and DeletePointerBarrier
+    ("XFIXES", 31, 32, (5, 0)),
+];
+
+/// Extensions whose `QueryVersion` request has minor opcode 0 and whose reply contains the major
+/// and minor version as 32 bit values at offsets 8 and 12.
+const QUERY_VERSION_EXTENSIONS: &[&str] = &[
+    "Composite",
+    "DAMAGE",
+    "DRI3",
+    "Present",
+    "RANDR",
+    "RENDER",
+    "XFIXES",
+];
+
+#[derive(Debug, Default)]
+struct State {
+    /// The versions that the X11 server agreed to.
+    versions: HashMap<&'static str, Version>,
+    /// `QueryVersion` requests whose reply was not yet seen.
+    pending: HashMap<SequenceNumber, &'static str>,
+}
+
+/// A [`Layer`] that refuses to send requests which are newer than the negotiated version of
+/// their extension.
+///
+/// The layer knows when requests of some common extensions were introduced, e.g. that RandR's
+/// `GetMonitors` requires version 1.5. More requirements can be added via
+/// [`require()`](Self::require).
+///
+/// The negotiated version of an extension is learned by watching the replies to its
+/// `QueryVersion` request. This works for the Composite, DAMAGE, DRI3, Present, RANDR, RENDER
+/// and XFIXES extensions. For other extensions, the version has to be provided via
+/// [`set_version()`](Self::set_version). As long as the version of an extension is unknown, all of
+/// its requests are sent.
+///
+/// Refused requests fail with [`ConnectionError::RequestDenied`].
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::middleware::version_guard::VersionGuard;
+///
+/// fn guard<C: Connection>(conn: C) -> impl Connection {
+///     // The request with minor opcode 3 of some extension was added in version 1.1
+///     conn.wrap(VersionGuard::new().require("EXAMPLE", 3, (1, 1)))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct VersionGuard {
+    requirements: Vec<(&'static str, u8, u8, Version)>,
+    state: Mutex<State>,
+}
+
+impl Default for VersionGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VersionGuard {
+    /// Create a new guard that knows the requirements of some common extensions.
+    pub fn new() -> Self {
+        Self {
+            requirements: KNOWN_REQUIREMENTS.to_vec(),
+            state: Default::default(),
+        }
+    }
+
+    /// Add the requirement that the request with the given minor opcode of the given extension
+    /// needs at least the given version.
+    ///
+    /// `extension_name` is the name that the X11 server knows the extension under, e.g.
+    /// [`crate::protocol::randr::X11_EXTENSION_NAME`].
+    pub fn require(
+        mut self,
+        extension_name: &'static str,
+        minor_opcode: u8,
+        version: (u32, u32),
+    ) -> Self {
+        self.requirements
+            .push((extension_name, minor_opcode, minor_opcode, version));
+        self
+    }
+
+    /// Set the version of an extension that the client and the X11 server agreed on.
+    pub fn set_version(&self, extension_name: &'static str, version: (u32, u32)) {
+        let _ = self
+            .state
+            .lock()
+            .unwrap()
+            .versions
+            .insert(extension_name, version);
+    }
+
+    /// Get the version of an extension that the client and the X11 server agreed on.
+    ///
+    /// Returns `None` if the version is not known.
+    pub fn version(&self, extension_name: &str) -> Option<(u32, u32)> {
+        self.state
+            .lock()
+            .unwrap()
+            .versions
+            .get(extension_name)
+            .copied()
+    }
+
+    /// Check whether the request with the given minor opcode of the given extension can be sent.
+    ///
+    /// This is `true` if the request has no known requirement, if the version of the extension
+    /// is not known, or if the version is new enough.
+    pub fn is_supported(&self, extension_name: &str, minor_opcode: u8) -> bool {
+        let version = match self.version(extension_name) {
+            Some(version) => version,
+            None => return true,
+        };
+        self.requirements
+            .iter()
+            .filter(|(name, first, last, _)| {
+                *name == extension_name && (*first..=*last).contains(&minor_opcode)
+            })
+            .all(|&(_, _, _, required)| version >= required)
+    }
+
+    /// Find the extension with the given major opcode among the ones that this guard knows.
+    fn extension_name<C>(&self, conn: &C, major_opcode: u8) -> Option<&'static str>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        if major_opcode < 128 {
+            return None;
+        }
+        let known = QUERY_VERSION_EXTENSIONS.iter().copied();
+        let required = self.requirements.iter().map(|&(name, _, _, _)| name);
+        known.chain(required).find(|name| {
+            matches!(
+                conn.extension_information(*name),
+                Ok(Some(info)) if info.major_opcode == major_opcode
+            )
+        })
+    }
+}
+
+/// Get the version from the reply to a `QueryVersion` request.
+fn parse_version(reply: &[u8]) -> Option<Version> {
+    let major = reply.get(8..12)?.try_into().ok()?;
+    let minor = reply.get(12..16)?.try_into().ok()?;
+    Some((u32::from_ne_bytes(major), u32::from_ne_bytes(minor)))
+}
+
+impl Layer for VersionGuard {
+    fn on_request<C>(
+        &self,
+        conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        match self.extension_name(conn, request.major_opcode()) {
+            Some(name) if !self.is_supported(name, request.minor_opcode()) => {
+                Err(ConnectionError::RequestDenied)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn on_request_sent<C>(&self, conn: &C, request: &OutgoingRequest<'_>, sequence: SequenceNumber)
+    where
+        C: RequestConnection + ?Sized,
+    {
+        if request.minor_opcode() != 0 {
+            return;
+        }
+        if let Some(name) = self.extension_name(conn, request.major_opcode()) {
+            if QUERY_VERSION_EXTENSIONS.contains(&name) {
+                let _ = self.state.lock().unwrap().pending.insert(sequence, name);
+            }
+        }
+    }
+
+    fn on_response(&self, sequence: SequenceNumber, response: Response<'_>) {
+        let mut state = self.state.lock().unwrap();
+        let name = match state.pending.remove(&sequence) {
+            Some(name) => name,
+            None => return,
+        };
+        if let Response::Reply(reply) = response {
+            if let Some(version) = parse_version(reply) {
+                let _ = state.versions.insert(name, version);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::VersionGuard;
+    use crate::middleware::{Layer, Response};
+
+    #[test]
+    fn known_requirements() {
+        let guard = VersionGuard::new();
+        // Without a known version, everything is allowed
+        assert!(guard.is_supported("RANDR", 42));
+
+        guard.set_version("RANDR", (1, 4));
+        assert!(guard.is_supported("RANDR", 41));
+        assert!(!guard.is_supported("RANDR", 42));
+        assert!(!guard.is_supported("RANDR", 46));
+        // Unknown requests are allowed
+        assert!(guard.is_supported("RANDR", 100));
+
+        guard.set_version("RANDR", (1, 6));
+        assert!(guard.is_supported("RANDR", 46));
+    }
+
+    #[test]
+    fn custom_requirement() {
+        let guard = VersionGuard::new().require("EXAMPLE", 3, (1, 1));
+        guard.set_version("EXAMPLE", (1, 0));
+        assert!(guard.is_supported("EXAMPLE", 2));
+        assert!(!guard.is_supported("EXAMPLE", 3));
+    }
+
+    #[test]
+    fn learn_version_from_reply() {
+        let guard = VersionGuard::new();
+        let _ = guard.state.lock().unwrap().pending.insert(7, "XFIXES");
+        let mut reply = vec![1, 0, 7, 0, 0, 0, 0, 0];
+        reply.extend_from_slice(&4u32.to_ne_bytes());
+        reply.extend_from_slice(&0u32.to_ne_bytes());
+        reply.resize(32, 0);
+
+        // Replies to other requests are ignored
+        guard.on_response(8, Response::Reply(&reply));
+        assert_eq!(guard.version("XFIXES"), None);
+
+        guard.on_response(7, Response::Reply(&reply));
+        assert_eq!(guard.version("XFIXES"), Some((4, 0)));
+        assert!(guard.is_supported("XFIXES", 30));
+        assert!(!guard.is_supported("XFIXES", 31));
+    }
+}
@@ -0,0 +1,171 @@
+//! RAII wrapper for the composite overlay window.
+//!
+//! Compositing managers draw into the composite overlay window, which sits above all other
+//! windows but below the screen saver. For input to reach the windows below, the overlay window
+//! has to be made transparent for input by setting its input shape to an empty region. Forgetting
+//! this step results in a desktop that does not react to any mouse clicks.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::overlay_window::OverlayWindow;
+//! use x11rb::protocol::xfixes::ConnectionExt as _;
+//!
+//! fn composite(conn: &impl Connection, screen_num: usize) -> Result<(), ReplyOrIdError> {
+//!     // XFixes has to be initialised before its requests can be used
+//!     let _ = conn.xfixes_query_version(5, 0)?.reply()?;
+//!     let root = conn.setup().roots[screen_num].root;
+//!     let overlay = OverlayWindow::new(conn, root)?;
+//!     println!("Drawing into window {}", overlay.window());
+//!     // The overlay window is released when `overlay` is dropped
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::protocol::composite;
+use crate::protocol::shape::SK;
+use crate::protocol::xfixes;
+use crate::protocol::xproto::Window;
+
+/// The composite overlay window of a screen, made transparent for input.
+///
+/// The overlay window is released via `ReleaseOverlayWindow` when this object is dropped.
+#[derive(Debug)]
+pub struct OverlayWindow<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    root: Window,
+    window: Window,
+}
+
+impl<'c, C: Connection + ?Sized> OverlayWindow<'c, C> {
+    /// Get the composite overlay window for the screen of the given root window.
+    ///
+    /// The input shape of the overlay window is set to an empty region, so that input events
+    /// reach the windows below it. This requires that the XFixes extension was initialised via
+    /// `QueryVersion`.
+    pub fn new(conn: &'c C, root: Window) -> Result<Self, ReplyOrIdError> {
+        let window = composite::get_overlay_window(conn, root)?
+            .reply()?
+            .overlay_win;
+        // From here on, dropping `overlay` releases the window on errors
+        let overlay = Self { conn, root, window };
+
+        let region = conn.generate_id()?;
+        let _ = xfixes::create_region(conn, region, &[])?;
+        let _ = xfixes::set_window_shape_region(conn, window, SK::INPUT, 0, 0, region)?;
+        let _ = xfixes::destroy_region(conn, region)?;
+        Ok(overlay)
+    }
+
+    /// Get the overlay window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the root window whose overlay window this is.
+    pub fn root(&self) -> Window {
+        self.root
+    }
+
+    /// Release the overlay window and report any errors.
+    ///
+    /// This is the same as dropping this object, except that errors are not ignored.
+    pub fn release(self) -> Result<(), ConnectionError> {
+        let result = composite::release_overlay_window(self.conn, self.root).map(|_| ());
+        std::mem::forget(self);
+        result
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for OverlayWindow<'_, C> {
+    fn drop(&mut self) {
+        let _ = composite::release_overlay_window(self.conn, self.root);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OverlayWindow;
+    use crate::protocol::shape::SK;
+    use crate::protocol::{composite, xfixes};
+    use crate::testing::{FakeConnection, Response};
+    use crate::x11_utils::ExtensionInformation;
+
+    const COMPOSITE: u8 = 140;
+    const XFIXES: u8 = 141;
+    const ROOT: u32 = 1;
+    const OVERLAY: u32 = 42;
+
+    /// A connection that knows Composite and XFixes and returns `OVERLAY` as the overlay window.
+    fn fake_connection() -> FakeConnection {
+        let conn = FakeConnection::default();
+        for (name, major_opcode) in [
+            (composite::X11_EXTENSION_NAME, COMPOSITE),
+            (xfixes::X11_EXTENSION_NAME, XFIXES),
+        ] {
+            let info = ExtensionInformation {
+                major_opcode,
+                first_event: 0,
+                first_error: 0,
+            };
+            conn.add_extension(name, info);
+        }
+        let mut reply = vec![0; 32];
+        reply[8..12].copy_from_slice(&OVERLAY.to_ne_bytes());
+        conn.respond(
+            COMPOSITE,
+            Some(composite::GET_OVERLAY_WINDOW_REQUEST),
+            Response::Reply(reply),
+        );
+        conn
+    }
+
+    /// Get the major and minor opcodes of the requests that were sent.
+    fn sent_opcodes(conn: &FakeConnection) -> Vec<(u8, u8)> {
+        conn.sent_requests()
+            .iter()
+            .map(|request| (request.major_opcode(), request.minor_opcode()))
+            .collect()
+    }
+
+    #[test]
+    fn input_passes_through() {
+        let conn = fake_connection();
+        let overlay = OverlayWindow::new(&conn, ROOT).unwrap();
+        assert_eq!(overlay.window(), OVERLAY);
+        assert_eq!(
+            sent_opcodes(&conn),
+            [
+                (COMPOSITE, composite::GET_OVERLAY_WINDOW_REQUEST),
+                (XFIXES, xfixes::CREATE_REGION_REQUEST),
+                (XFIXES, xfixes::SET_WINDOW_SHAPE_REGION_REQUEST),
+                (XFIXES, xfixes::DESTROY_REGION_REQUEST),
+            ]
+        );
+        // The input shape of the overlay window is set
+        let shape = &conn.sent_requests()[2].bytes;
+        assert_eq!(shape[4..8], OVERLAY.to_ne_bytes());
+        assert_eq!(shape[8], u8::from(SK::INPUT));
+
+        drop(overlay);
+        let release = conn.sent_requests().pop().unwrap();
+        assert_eq!(
+            (release.major_opcode(), release.minor_opcode()),
+            (COMPOSITE, composite::RELEASE_OVERLAY_WINDOW_REQUEST)
+        );
+        assert_eq!(release.bytes[4..8], ROOT.to_ne_bytes());
+    }
+
+    #[test]
+    fn release_only_once() {
+        let conn = fake_connection();
+        OverlayWindow::new(&conn, ROOT).unwrap().release().unwrap();
+        let releases = sent_opcodes(&conn)
+            .into_iter()
+            .filter(|&opcodes| opcodes == (COMPOSITE, composite::RELEASE_OVERLAY_WINDOW_REQUEST))
+            .count();
+        assert_eq!(releases, 1);
+    }
+}
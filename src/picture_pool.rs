@@ -0,0 +1,380 @@
+//! A pool of solid fill and gradient pictures.
+//!
+//! Compositors and other RENDER clients use solid fills and gradients as the source of
+//! `Composite` requests, e.g. for shadows, borders and dimming. Creating a picture for this and
+//! freeing it again in every frame costs requests and churns memory on the X11 server.
+//! [`PicturePool`] keeps such pictures around, keyed by their colors and stops, and frees the least
+//! recently used ones when it is full:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::picture_pool::PicturePool;
+//! use x11rb::protocol::render::{self, Color, PictOp, Picture};
+//! use x11rb::NONE;
+//!
+//! fn dim(
+//!     conn: &impl Connection,
+//!     pool: &mut PicturePool,
+//!     target: Picture,
+//!     width: u16,
+//!     height: u16,
+//! ) -> Result<(), ReplyOrIdError> {
+//!     // Half transparent black. The picture is only created in the first frame.
+//!     let color = Color { red: 0, green: 0, blue: 0, alpha: 0x8000 };
+//!     let source = pool.solid_fill(conn, color)?;
+//!     render::composite(conn, PictOp::OVER, source, NONE, target, 0, 0, 0, 0, 0, 0, width, height)?;
+//!     Ok(())
+//! }
+//!
+//! let mut pool = PicturePool::new(64);
+//! ```
+//!
+//! A picture that is returned by the pool may be freed when another picture is created. Use it
+//! before getting the next picture from the same pool.
+//!
+//! The code in this module is only available when the `render` feature of the library is enabled.
+
+use std::hash::{Hash, Hasher};
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::pixmap_cache::{CacheStats, LruMap};
+use crate::protocol::render::{self, Color, Fixed, Picture, Pointfix};
+use crate::x11_utils::Serialize;
+
+/// The description of a solid fill or gradient picture.
+///
+/// The fields correspond to the `CreateSolidFill`, `CreateLinearGradient`,
+/// `CreateRadialGradient` and `CreateConicalGradient` requests. For gradients, `stops` and
+/// `colors` must have the same length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fill {
+    /// A picture of infinite size that has the same color everywhere.
+    Solid(Color),
+    /// A gradient along the line from `p1` to `p2`.
+    Linear {
+        /// The point where the gradient starts.
+        p1: Pointfix,
+        /// The point where the gradient ends.
+        p2: Pointfix,
+        /// The positions of the color stops, from 0 to 1.
+        stops: Vec<Fixed>,
+        /// The colors at the stops.
+        colors: Vec<Color>,
+    },
+    /// A gradient between two circles.
+    Radial {
+        /// The center of the inner circle.
+        inner: Pointfix,
+        /// The center of the outer circle.
+        outer: Pointfix,
+        /// The radius of the inner circle.
+        inner_radius: Fixed,
+        /// The radius of the outer circle.
+        outer_radius: Fixed,
+        /// The positions of the color stops, from 0 to 1.
+        stops: Vec<Fixed>,
+        /// The colors at the stops.
+        colors: Vec<Color>,
+    },
+    /// A gradient around a center point.
+    Conical {
+        /// The center of the gradient.
+        center: Pointfix,
+        /// The angle where the gradient starts, in degrees.
+        angle: Fixed,
+        /// The positions of the color stops, from 0 to 1.
+        stops: Vec<Fixed>,
+        /// The colors at the stops.
+        colors: Vec<Color>,
+    },
+}
+
+// The generated types do not implement Hash, so hash their wire format instead
+impl Hash for Fill {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        fn hash_stops<H: Hasher>(stops: &[Fixed], colors: &[Color], state: &mut H) {
+            stops.hash(state);
+            for color in colors {
+                color.serialize().hash(state);
+            }
+        }
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Fill::Solid(color) => color.serialize().hash(state),
+            Fill::Linear {
+                p1,
+                p2,
+                stops,
+                colors,
+            } => {
+                p1.serialize().hash(state);
+                p2.serialize().hash(state);
+                hash_stops(stops, colors, state);
+            }
+            Fill::Radial {
+                inner,
+                outer,
+                inner_radius,
+                outer_radius,
+                stops,
+                colors,
+            } => {
+                inner.serialize().hash(state);
+                outer.serialize().hash(state);
+                inner_radius.hash(state);
+                outer_radius.hash(state);
+                hash_stops(stops, colors, state);
+            }
+            Fill::Conical {
+                center,
+                angle,
+                stops,
+                colors,
+            } => {
+                center.serialize().hash(state);
+                angle.hash(state);
+                hash_stops(stops, colors, state);
+            }
+        }
+    }
+}
+
+impl Fill {
+    /// Send the request that creates this fill as `picture`.
+    fn create<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        picture: Picture,
+    ) -> Result<(), ConnectionError> {
+        let _ = match self {
+            Fill::Solid(color) => render::create_solid_fill(conn, picture, *color)?,
+            Fill::Linear {
+                p1,
+                p2,
+                stops,
+                colors,
+            } => render::create_linear_gradient(conn, picture, *p1, *p2, stops, colors)?,
+            Fill::Radial {
+                inner,
+                outer,
+                inner_radius,
+                outer_radius,
+                stops,
+                colors,
+            } => render::create_radial_gradient(
+                conn,
+                picture,
+                *inner,
+                *outer,
+                *inner_radius,
+                *outer_radius,
+                stops,
+                colors,
+            )?,
+            Fill::Conical {
+                center,
+                angle,
+                stops,
+                colors,
+            } => render::create_conical_gradient(conn, picture, *center, *angle, stops, colors)?,
+        };
+        Ok(())
+    }
+}
+
+/// A pool of solid fill and gradient pictures.
+///
+/// The pool holds up to `capacity` pictures. These pictures have no pixel storage on the X11
+/// server, so unlike [`PixmapCache`](crate::pixmap_cache::PixmapCache), the pool limits their
+/// number instead of their memory.
+///
+/// The pool does not free its pictures when it is dropped, since it has no connection at that
+/// point. Use [`PicturePool::clear`] for that, or just close the connection.
+#[derive(Debug)]
+pub struct PicturePool {
+    capacity: usize,
+    pictures: LruMap<Fill, Picture>,
+    stats: CacheStats,
+}
+
+impl PicturePool {
+    /// Create an empty pool for up to `capacity` pictures.
+    ///
+    /// A capacity of zero is treated like one.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            pictures: LruMap::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Get the maximum number of pictures in the pool.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of pictures in the pool.
+    pub fn len(&self) -> usize {
+        self.pictures.len()
+    }
+
+    /// Check whether the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pictures.is_empty()
+    }
+
+    /// Get the hit and miss statistics of this pool.
+    ///
+    /// Evictions count the pictures that were freed to stay within the capacity.
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Get the picture for a fill, creating it if it is not in the pool.
+    ///
+    /// If the pool is full, this frees the least recently used picture.
+    pub fn get<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        fill: &Fill,
+    ) -> Result<Picture, ReplyOrIdError> {
+        if let Some(picture) = self.pictures.get(fill) {
+            self.stats.hits += 1;
+            return Ok(*picture);
+        }
+        self.stats.misses += 1;
+        let picture = conn.generate_id()?;
+        fill.create(conn, picture)?;
+        while self.pictures.len() >= self.capacity {
+            let (_, old) = self
+                .pictures
+                .pop_least_recently_used()
+                .expect("The pool is not empty");
+            self.stats.evictions += 1;
+            let _ = render::free_picture(conn, old)?;
+        }
+        let _ = self.pictures.insert(fill.clone(), picture);
+        Ok(picture)
+    }
+
+    /// Get a solid fill picture of the given color.
+    ///
+    /// This is a shortcut for [`PicturePool::get`] with [`Fill::Solid`].
+    pub fn solid_fill<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        color: Color,
+    ) -> Result<Picture, ReplyOrIdError> {
+        self.get(conn, &Fill::Solid(color))
+    }
+
+    /// Free all pictures in the pool.
+    ///
+    /// The statistics are kept.
+    pub fn clear<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        for picture in self.pictures.drain() {
+            let _ = render::free_picture(conn, picture)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{Fill, PicturePool};
+    use crate::pixmap_cache::CacheStats;
+    use crate::protocol::render::{self, Color, Pointfix};
+    use crate::testing::FakeConnection;
+    use crate::x11_utils::ExtensionInformation;
+
+    const RENDER_OPCODE: u8 = 139;
+
+    fn connection() -> FakeConnection {
+        let conn = FakeConnection::default();
+        conn.add_extension(
+            render::X11_EXTENSION_NAME,
+            ExtensionInformation {
+                major_opcode: RENDER_OPCODE,
+                first_event: 0,
+                first_error: 140,
+            },
+        );
+        conn
+    }
+
+    fn minor_opcodes(conn: &FakeConnection) -> Vec<u8> {
+        conn.take_sent_requests()
+            .iter()
+            .map(|request| request.minor_opcode())
+            .collect()
+    }
+
+    fn color(alpha: u16) -> Color {
+        Color {
+            red: 0,
+            green: 0,
+            blue: 0,
+            alpha,
+        }
+    }
+
+    #[test]
+    fn pictures_are_reused() {
+        let conn = connection();
+        let mut pool = PicturePool::new(4);
+        let solid = pool.solid_fill(&conn, color(0x8000)).unwrap();
+        let gradient = Fill::Linear {
+            p1: Pointfix { x: 0, y: 0 },
+            p2: Pointfix { x: 0, y: 10 << 16 },
+            stops: vec![0, 1 << 16],
+            colors: vec![color(0xffff), color(0)],
+        };
+        let linear = pool.get(&conn, &gradient).unwrap();
+        assert_ne!(solid, linear);
+        assert_eq!(
+            minor_opcodes(&conn),
+            [
+                render::CREATE_SOLID_FILL_REQUEST,
+                render::CREATE_LINEAR_GRADIENT_REQUEST
+            ]
+        );
+        assert_eq!(pool.solid_fill(&conn, color(0x8000)).unwrap(), solid);
+        assert_eq!(pool.get(&conn, &gradient.clone()).unwrap(), linear);
+        assert!(minor_opcodes(&conn).is_empty());
+        assert_eq!(
+            pool.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 2,
+                evictions: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn least_recently_used_is_freed() {
+        let conn = connection();
+        let mut pool = PicturePool::new(2);
+        let first = pool.solid_fill(&conn, color(1)).unwrap();
+        let second = pool.solid_fill(&conn, color(2)).unwrap();
+        assert_eq!(pool.solid_fill(&conn, color(1)).unwrap(), first);
+        let _ = conn.take_sent_requests();
+        let _ = pool.solid_fill(&conn, color(3)).unwrap();
+        let requests = conn.take_sent_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].minor_opcode(), render::FREE_PICTURE_REQUEST);
+        assert_eq!(requests[1].bytes[4..8], second.to_ne_bytes());
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.stats().evictions, 1);
+
+        pool.clear(&conn).unwrap();
+        assert!(pool.is_empty());
+        assert_eq!(
+            minor_opcodes(&conn),
+            [render::FREE_PICTURE_REQUEST, render::FREE_PICTURE_REQUEST]
+        );
+    }
+}
@@ -0,0 +1,477 @@
+//! A cache for pixmaps that are rendered repeatedly.
+//!
+//! Bars, docks and compositors often draw the same artwork over and over: icons, rendered text,
+//! window decorations. [`PixmapCache`] keeps such pixmaps on the X11 server under keys chosen by
+//! the application. Pixmaps use memory on the server, so the cache has a budget in bytes. When it
+//! is exceeded, the least recently used pixmaps are freed.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::pixmap_cache::PixmapCache;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, Gcontext, Pixmap, Rectangle};
+//!
+//! fn icon(
+//!     conn: &impl Connection,
+//!     cache: &mut PixmapCache<(String, u16)>,
+//!     gc: Gcontext,
+//!     name: &str,
+//!     size: u16,
+//! ) -> Result<Pixmap, ReplyOrIdError> {
+//!     let screen = &conn.setup().roots[0];
+//!     let key = (name.to_string(), size);
+//!     let depth = screen.root_depth;
+//!     cache.get_or_insert_with(conn, key, screen.root, size, size, depth, |conn, pixmap| {
+//!         // This is only called if the icon is not cached yet
+//!         let rect = Rectangle { x: 0, y: 0, width: size, height: size };
+//!         conn.poly_fill_rectangle(pixmap, gc, &[rect])?;
+//!         Ok(())
+//!     })
+//! }
+//!
+//! // Allow the cached icons to use up to 16 MiB on the X11 server
+//! let mut cache = PixmapCache::<(String, u16)>::new(16 << 20);
+//! ```
+//!
+//! A pixmap that is returned by the cache may be freed by a later insertion. Use it before
+//! inserting anything else into the same cache.
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::protocol::xproto::{self, Drawable, Pixmap, Setup};
+
+/// Estimate the memory that the X11 server uses for a pixmap.
+///
+/// This uses the pixmap formats of the server to find the bits per pixel and the padding of
+/// rows. Depths without a pixmap format are estimated like the next larger power of two.
+pub fn pixmap_size(setup: &Setup, width: u16, height: u16, depth: u8) -> usize {
+    let (bits_per_pixel, scanline_pad) = setup
+        .pixmap_formats
+        .iter()
+        .find(|format| format.depth == depth)
+        .map(|format| (format.bits_per_pixel, format.scanline_pad))
+        .unwrap_or_else(|| (depth.max(1).next_power_of_two(), 32));
+    let pad = usize::from(scanline_pad.max(8)).next_power_of_two();
+    let row_bits = usize::from(width) * usize::from(bits_per_pixel);
+    // The scanline pad is a power of two, so rounding up can use a mask
+    let row_bytes = ((row_bits + pad - 1) & !(pad - 1)) / 8;
+    row_bytes * usize::from(height)
+}
+
+/// Statistics about the use of a [`PixmapCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups that found a pixmap.
+    pub hits: u64,
+    /// The number of lookups that did not find a pixmap.
+    pub misses: u64,
+    /// The number of pixmaps that were freed to stay within the budget.
+    pub evictions: u64,
+}
+
+/// A map that keeps track of the order in which its entries were used.
+#[derive(Debug)]
+pub(crate) struct LruMap<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    // The keys ordered by their last use
+    order: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+impl<K: Clone + Eq + Hash, V> LruMap<K, V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.contains_key(key)
+    }
+
+    /// Get an entry and mark it as the most recently used one.
+    pub(crate) fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.clock += 1;
+        let (value, last_use) = self.entries.get_mut(key)?;
+        let key = self
+            .order
+            .remove(last_use)
+            .expect("Every entry is in the LRU order");
+        *last_use = self.clock;
+        let _ = self.order.insert(self.clock, key);
+        Some(value)
+    }
+
+    /// Insert an entry as the most recently used one and return the value it replaced.
+    pub(crate) fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let old = self.remove(&key);
+        self.clock += 1;
+        let _ = self.order.insert(self.clock, key.clone());
+        let _ = self.entries.insert(key, (value, self.clock));
+        old
+    }
+
+    pub(crate) fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let (value, last_use) = self.entries.remove(key)?;
+        let _ = self.order.remove(&last_use);
+        Some(value)
+    }
+
+    pub(crate) fn pop_least_recently_used(&mut self) -> Option<(K, V)> {
+        let oldest = *self.order.keys().next()?;
+        let key = self.order.remove(&oldest).expect("The key was just found");
+        let (value, _) = self
+            .entries
+            .remove(&key)
+            .expect("Every key in the LRU order has an entry");
+        Some((key, value))
+    }
+
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = V> + '_ {
+        self.order.clear();
+        self.entries.drain().map(|(_, (value, _))| value)
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    pixmap: Pixmap,
+    size: usize,
+}
+
+/// A cache of pixmaps with a budget for their memory on the X11 server.
+///
+/// The memory of each pixmap is estimated with [`pixmap_size`]. A single pixmap that is larger
+/// than the budget is still cached, but all other pixmaps are freed for it.
+///
+/// The cache does not free its pixmaps when it is dropped, since it has no connection at that
+/// point. Use [`PixmapCache::clear`] for that, or just close the connection.
+#[derive(Debug)]
+pub struct PixmapCache<K> {
+    budget: usize,
+    memory_usage: usize,
+    entries: LruMap<K, Entry>,
+    stats: CacheStats,
+}
+
+impl<K: Clone + Eq + Hash> PixmapCache<K> {
+    /// Create an empty cache whose pixmaps use at most about `budget` bytes of server memory.
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            memory_usage: 0,
+            entries: LruMap::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Get the budget in bytes.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Get the estimated memory usage of all cached pixmaps in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.memory_usage
+    }
+
+    /// Get the number of cached pixmaps.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the hit and miss statistics of this cache.
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Reset the hit and miss statistics to zero.
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// Look up the pixmap for a key.
+    ///
+    /// This counts as a hit or a miss and marks the pixmap as recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<Pixmap>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        match self.entries.get(key) {
+            Some(entry) => {
+                self.stats.hits += 1;
+                Some(entry.pixmap)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Check whether a key is cached, without counting a hit or a miss.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.contains(key)
+    }
+
+    /// Get the pixmap for a key, or create and draw it.
+    ///
+    /// If the key is not cached, a pixmap with the given size and depth is created on the screen
+    /// of `drawable` and `draw` is called to draw its contents. If `draw` fails, the pixmap is
+    /// freed and the error is returned. Otherwise, the pixmap is inserted into the cache, which
+    /// may free least recently used pixmaps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_or_insert_with<C, E>(
+        &mut self,
+        conn: &C,
+        key: K,
+        drawable: Drawable,
+        width: u16,
+        height: u16,
+        depth: u8,
+        draw: impl FnOnce(&C, Pixmap) -> Result<(), E>,
+    ) -> Result<Pixmap, E>
+    where
+        C: Connection + ?Sized,
+        E: From<ReplyOrIdError>,
+    {
+        if let Some(pixmap) = self.get(&key) {
+            return Ok(pixmap);
+        }
+        let pixmap = conn.generate_id()?;
+        let _ = xproto::create_pixmap(conn, depth, pixmap, drawable, width, height)
+            .map_err(ReplyOrIdError::from)?;
+        if let Err(err) = draw(conn, pixmap) {
+            let _ = xproto::free_pixmap(conn, pixmap);
+            return Err(err);
+        }
+        let size = pixmap_size(conn.setup(), width, height, depth);
+        self.insert(conn, key, pixmap, size)
+            .map_err(ReplyOrIdError::from)?;
+        Ok(pixmap)
+    }
+
+    /// Insert a pixmap that was created by the caller.
+    ///
+    /// The cache takes ownership of the pixmap and frees it when it is evicted or removed. `size`
+    /// is the estimated memory usage, see [`pixmap_size`]. A pixmap that was cached under the same
+    /// key before is freed.
+    pub fn insert<C>(
+        &mut self,
+        conn: &C,
+        key: K,
+        pixmap: Pixmap,
+        size: usize,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        self.memory_usage += size;
+        if let Some(old) = self.entries.insert(key, Entry { pixmap, size }) {
+            self.memory_usage -= old.size;
+            let _ = xproto::free_pixmap(conn, old.pixmap)?;
+        }
+        self.evict(conn)
+    }
+
+    /// Remove a pixmap from the cache and free it.
+    ///
+    /// Returns whether the key was cached.
+    pub fn remove<C, Q>(&mut self, conn: &C, key: &Q) -> Result<bool, ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        match self.entries.remove(key) {
+            Some(entry) => {
+                self.memory_usage -= entry.size;
+                let _ = xproto::free_pixmap(conn, entry.pixmap)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Free all cached pixmaps.
+    ///
+    /// The statistics are kept.
+    pub fn clear<C>(&mut self, conn: &C) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        self.memory_usage = 0;
+        for entry in self.entries.drain() {
+            let _ = xproto::free_pixmap(conn, entry.pixmap)?;
+        }
+        Ok(())
+    }
+
+    /// Change the budget, freeing pixmaps if the cache uses more memory than the new budget.
+    pub fn set_budget<C>(&mut self, conn: &C, budget: usize) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        self.budget = budget;
+        self.evict(conn)
+    }
+
+    /// Free least recently used pixmaps until the cache is within its budget.
+    ///
+    /// The most recently used pixmap is never freed.
+    fn evict<C>(&mut self, conn: &C) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        while self.memory_usage > self.budget && self.entries.len() > 1 {
+            let (_, entry) = self
+                .entries
+                .pop_least_recently_used()
+                .expect("The cache is not empty");
+            self.memory_usage -= entry.size;
+            self.stats.evictions += 1;
+            let _ = xproto::free_pixmap(conn, entry.pixmap)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{pixmap_size, CacheStats, PixmapCache};
+    use crate::connection::Connection;
+    use crate::errors::ReplyOrIdError;
+    use crate::protocol::xproto::{self, Format, Pixmap};
+    use crate::testing::FakeConnection;
+
+    fn opcodes(conn: &FakeConnection) -> Vec<u8> {
+        conn.take_sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect()
+    }
+
+    fn icon(
+        conn: &FakeConnection,
+        cache: &mut PixmapCache<&'static str>,
+        name: &'static str,
+    ) -> Pixmap {
+        cache
+            .get_or_insert_with(conn, name, 0x100, 10, 10, 24, |_, _| {
+                Ok::<_, ReplyOrIdError>(())
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn sizes() {
+        let mut setup = FakeConnection::default().setup().clone();
+        // Without pixmap formats, depth 24 is estimated as 32 bits per pixel
+        assert_eq!(pixmap_size(&setup, 10, 10, 24), 400);
+        assert_eq!(pixmap_size(&setup, 10, 10, 1), 40);
+        setup.pixmap_formats.push(Format {
+            depth: 24,
+            bits_per_pixel: 24,
+            scanline_pad: 32,
+        });
+        assert_eq!(pixmap_size(&setup, 10, 10, 24), 320);
+    }
+
+    #[test]
+    fn least_recently_used_is_evicted() {
+        let conn = FakeConnection::default();
+        // Room for two 10x10 pixmaps
+        let mut cache = PixmapCache::new(800);
+        let a = icon(&conn, &mut cache, "a");
+        let _ = icon(&conn, &mut cache, "b");
+        assert_eq!(
+            opcodes(&conn),
+            [xproto::CREATE_PIXMAP_REQUEST, xproto::CREATE_PIXMAP_REQUEST]
+        );
+        // Using "a" makes "b" the least recently used pixmap
+        assert_eq!(icon(&conn, &mut cache, "a"), a);
+        assert!(opcodes(&conn).is_empty());
+        let _ = icon(&conn, &mut cache, "c");
+        assert_eq!(
+            opcodes(&conn),
+            [xproto::CREATE_PIXMAP_REQUEST, xproto::FREE_PIXMAP_REQUEST]
+        );
+        assert!(cache.contains("a"));
+        assert!(!cache.contains("b"));
+        assert_eq!(cache.memory_usage(), 800);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 3,
+                evictions: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn oversized_pixmap_is_kept() {
+        let conn = FakeConnection::default();
+        let mut cache = PixmapCache::new(100);
+        let _ = icon(&conn, &mut cache, "a");
+        let _ = icon(&conn, &mut cache, "b");
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains("b"));
+        cache.set_budget(&conn, 0).unwrap();
+        assert_eq!(cache.len(), 1);
+        cache.clear(&conn).unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(cache.memory_usage(), 0);
+    }
+
+    #[test]
+    fn failed_drawing_frees_pixmap() {
+        let conn = FakeConnection::default();
+        let mut cache = PixmapCache::new(1000);
+        let result = cache.get_or_insert_with(&conn, "a", 0x100, 10, 10, 24, |_, _| {
+            Err(ReplyOrIdError::IdsExhausted)
+        });
+        assert!(matches!(result, Err(ReplyOrIdError::IdsExhausted)));
+        assert_eq!(
+            opcodes(&conn),
+            [xproto::CREATE_PIXMAP_REQUEST, xproto::FREE_PIXMAP_REQUEST]
+        );
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+//! Names and types of the predefined atoms.
+//!
+//! The core protocol predefines 68 atoms, which exist on every X11 server with the same values.
+//! [`AtomEnum`](crate::protocol::xproto::AtomEnum) has constants for them, but looking up their
+//! names would still need a `GetAtomName` request. The table in this module maps them to their
+//! names and to the type of the property that they name, without talking to the X11 server:
+//!
+//! ```
+//! use x11rb::predefined_atoms::{self, PredefinedAtomExt};
+//! use x11rb::protocol::xproto::{Atom, AtomEnum};
+//!
+//! let wm_name = Atom::from(AtomEnum::WM_NAME);
+//! assert_eq!(wm_name.predefined_name(), Some("WM_NAME"));
+//! assert_eq!(wm_name.predefined_type(), Some(AtomEnum::STRING.into()));
+//! assert_eq!(predefined_atoms::predefined_atom_by_name(b"CUT_BUFFER0"), Some(9));
+//! // Not a predefined atom
+//! assert_eq!(Atom::from(300u32).predefined_name(), None);
+//! ```
+//!
+//! [`AtomCache`](crate::atom_cache::AtomCache) uses this table, so it never sends requests for
+//! predefined atoms.
+
+use crate::protocol::xproto::Atom;
+
+/// A predefined atom of the core protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PredefinedAtom {
+    /// The value of the atom.
+    pub atom: Atom,
+    /// The name of the atom.
+    pub name: &'static str,
+    /// The type of the property that this atom names.
+    ///
+    /// For the font properties, e.g. `WEIGHT`, this is the type of their value in `QueryFont`
+    /// replies. This is `None` for atoms that do not name a property, e.g. types like `STRING`
+    /// and selections like `PRIMARY`.
+    pub type_: Option<Atom>,
+}
+
+macro_rules! predefined_atoms {
+    ($($atom:literal $name:ident $(: $type_:ident)?,)*) => {
+        // The atoms by name, for referring to types. Not all of them are types.
+        #[allow(dead_code)]
+        mod atoms {
+            $(pub(super) const $name: super::Atom = $atom;)*
+        }
+
+        /// All predefined atoms, sorted by their value.
+        ///
+        /// The atoms go from `1` to `68` without gaps, so `PREDEFINED_ATOMS[atom - 1]` describes
+        /// `atom`.
+        pub const PREDEFINED_ATOMS: [PredefinedAtom; 68] = [
+            $(PredefinedAtom {
+                atom: $atom,
+                name: stringify!($name),
+                type_: predefined_atoms!(@type $($type_)?),
+            },)*
+        ];
+    };
+    (@type) => { None };
+    (@type $type_:ident) => { Some(atoms::$type_) };
+}
+
+predefined_atoms! {
+    1 PRIMARY,
+    2 SECONDARY,
+    3 ARC,
+    4 ATOM,
+    5 BITMAP,
+    6 CARDINAL,
+    7 COLORMAP,
+    8 CURSOR,
+    9 CUT_BUFFER0: STRING,
+    10 CUT_BUFFER1: STRING,
+    11 CUT_BUFFER2: STRING,
+    12 CUT_BUFFER3: STRING,
+    13 CUT_BUFFER4: STRING,
+    14 CUT_BUFFER5: STRING,
+    15 CUT_BUFFER6: STRING,
+    16 CUT_BUFFER7: STRING,
+    17 DRAWABLE,
+    18 FONT,
+    19 INTEGER,
+    20 PIXMAP,
+    21 POINT,
+    22 RECTANGLE,
+    23 RESOURCE_MANAGER: STRING,
+    24 RGB_COLOR_MAP,
+    25 RGB_BEST_MAP: RGB_COLOR_MAP,
+    26 RGB_BLUE_MAP: RGB_COLOR_MAP,
+    27 RGB_DEFAULT_MAP: RGB_COLOR_MAP,
+    28 RGB_GRAY_MAP: RGB_COLOR_MAP,
+    29 RGB_GREEN_MAP: RGB_COLOR_MAP,
+    30 RGB_RED_MAP: RGB_COLOR_MAP,
+    31 STRING,
+    32 VISUALID,
+    33 WINDOW,
+    34 WM_COMMAND: STRING,
+    35 WM_HINTS: WM_HINTS,
+    36 WM_CLIENT_MACHINE: STRING,
+    37 WM_ICON_NAME: STRING,
+    38 WM_ICON_SIZE: WM_ICON_SIZE,
+    39 WM_NAME: STRING,
+    40 WM_NORMAL_HINTS: WM_SIZE_HINTS,
+    41 WM_SIZE_HINTS,
+    42 WM_ZOOM_HINTS: WM_SIZE_HINTS,
+    43 MIN_SPACE: INTEGER,
+    44 NORM_SPACE: INTEGER,
+    45 MAX_SPACE: INTEGER,
+    46 END_SPACE: INTEGER,
+    47 SUPERSCRIPT_X: INTEGER,
+    48 SUPERSCRIPT_Y: INTEGER,
+    49 SUBSCRIPT_X: INTEGER,
+    50 SUBSCRIPT_Y: INTEGER,
+    51 UNDERLINE_POSITION: INTEGER,
+    52 UNDERLINE_THICKNESS: CARDINAL,
+    53 STRIKEOUT_ASCENT: INTEGER,
+    54 STRIKEOUT_DESCENT: INTEGER,
+    55 ITALIC_ANGLE: INTEGER,
+    56 X_HEIGHT: INTEGER,
+    57 QUAD_WIDTH: INTEGER,
+    58 WEIGHT: CARDINAL,
+    59 POINT_SIZE: INTEGER,
+    60 RESOLUTION: CARDINAL,
+    61 COPYRIGHT: ATOM,
+    62 NOTICE: ATOM,
+    63 FONT_NAME: ATOM,
+    64 FAMILY_NAME: ATOM,
+    65 FULL_NAME: ATOM,
+    66 CAP_HEIGHT: INTEGER,
+    67 WM_CLASS: STRING,
+    68 WM_TRANSIENT_FOR: WINDOW,
+}
+
+/// Get the description of a predefined atom.
+pub const fn predefined_atom(atom: Atom) -> Option<PredefinedAtom> {
+    if atom >= 1 && atom as usize <= PREDEFINED_ATOMS.len() {
+        Some(PREDEFINED_ATOMS[atom as usize - 1])
+    } else {
+        None
+    }
+}
+
+/// Get the name of a predefined atom.
+pub const fn predefined_name(atom: Atom) -> Option<&'static str> {
+    match predefined_atom(atom) {
+        Some(predefined) => Some(predefined.name),
+        None => None,
+    }
+}
+
+/// Get the type of the property that a predefined atom names.
+///
+/// See [`PredefinedAtom::type_`].
+pub const fn predefined_type(atom: Atom) -> Option<Atom> {
+    match predefined_atom(atom) {
+        Some(predefined) => predefined.type_,
+        None => None,
+    }
+}
+
+/// Get the predefined atom with the given name.
+pub fn predefined_atom_by_name(name: &[u8]) -> Option<Atom> {
+    PREDEFINED_ATOMS
+        .iter()
+        .find(|predefined| predefined.name.as_bytes() == name)
+        .map(|predefined| predefined.atom)
+}
+
+/// Methods for looking up predefined atoms.
+pub trait PredefinedAtomExt {
+    /// Get the name of this atom if it is predefined.
+    fn predefined_name(self) -> Option<&'static str>;
+
+    /// Get the type of the property that this atom names if it is predefined.
+    fn predefined_type(self) -> Option<Atom>;
+}
+
+impl PredefinedAtomExt for Atom {
+    fn predefined_name(self) -> Option<&'static str> {
+        predefined_name(self)
+    }
+
+    fn predefined_type(self) -> Option<Atom> {
+        predefined_type(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{predefined_atom_by_name, predefined_name, predefined_type, PREDEFINED_ATOMS};
+    use crate::protocol::xproto::{Atom, AtomEnum};
+
+    #[test]
+    fn table_matches_atom_enum() {
+        for (index, predefined) in PREDEFINED_ATOMS.iter().enumerate() {
+            assert_eq!(predefined.atom as usize, index + 1);
+        }
+        let atoms = [
+            (AtomEnum::PRIMARY, "PRIMARY"),
+            (AtomEnum::CUT_BUFFE_R7, "CUT_BUFFER7"),
+            (AtomEnum::RGB_RED_MAP, "RGB_RED_MAP"),
+            (AtomEnum::WM_ZOOM_HINTS, "WM_ZOOM_HINTS"),
+            (AtomEnum::CAP_HEIGHT, "CAP_HEIGHT"),
+            (AtomEnum::WM_TRANSIENT_FOR, "WM_TRANSIENT_FOR"),
+        ];
+        for (atom, name) in atoms.iter() {
+            assert_eq!(predefined_name(Atom::from(*atom)), Some(*name));
+        }
+    }
+
+    #[test]
+    fn lookups() {
+        assert_eq!(predefined_name(0), None);
+        assert_eq!(predefined_name(69), None);
+        assert_eq!(predefined_type(4), None);
+        assert_eq!(predefined_type(40), Some(AtomEnum::WM_SIZE_HINTS.into()));
+        assert_eq!(predefined_type(68), Some(AtomEnum::WINDOW.into()));
+        assert_eq!(predefined_atom_by_name(b"WM_CLASS"), Some(67));
+        assert_eq!(predefined_atom_by_name(b"CUT_BUFFE_R0"), None);
+    }
+}
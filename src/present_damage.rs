@@ -0,0 +1,385 @@
+//! Utilities for passing accumulated damage to `present::pixmap`.
+//!
+//! The `PresentPixmap` request has an `update` parameter that describes which parts of a pixmap
+//! changed since the last frame and a `valid` parameter that describes which parts of the pixmap
+//! have defined contents. Both are XFixes regions. Passing a small update area allows the X11
+//! server to copy less data each frame.
+//!
+//! [`DamageAccumulator`] collects damaged rectangles, for example from `DamageNotify` events,
+//! and [`PresentRegions`] turns them into the regions for `PresentPixmap`. When the pixmap is
+//! rendered at a different scale than the damage was reported in (e.g. on HiDPI outputs), a
+//! [`DamageTransform`] maps the rectangles into pixmap coordinates.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::present_damage::{DamageAccumulator, DamageTransform, PresentRegions};
+//! use x11rb::protocol::present::{self, ConnectionExt as _};
+//! use x11rb::protocol::xproto::{Pixmap, Window};
+//!
+//! fn present_frame(
+//!     conn: &impl Connection,
+//!     regions: &PresentRegions,
+//!     damage: &mut DamageAccumulator,
+//!     window: Window,
+//!     pixmap: Pixmap,
+//!     serial: u32,
+//! ) -> Result<(), ReplyOrIdError> {
+//!     // The pixmap is rendered at twice the size of the damage coordinates
+//!     regions.set(conn, damage, DamageTransform::scale(2.0), (1920, 1080))?;
+//!     damage.clear();
+//!     conn.present_pixmap(
+//!         window,
+//!         pixmap,
+//!         serial,
+//!         regions.valid(),
+//!         regions.update(),
+//!         0,
+//!         0,
+//!         0u32,
+//!         0u32,
+//!         0u32,
+//!         present::Option::NONE.into(),
+//!         0,
+//!         0,
+//!         0,
+//!         &[],
+//!     )?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::protocol::xfixes::{self, Region};
+use crate::protocol::xproto::Rectangle;
+
+/// Number of rectangles above which the accumulated damage is collapsed into its bounding box.
+const DEFAULT_MAX_RECTANGLES: usize = 32;
+
+/// A transformation from damage coordinates into pixmap coordinates.
+///
+/// A point `(x, y)` is mapped to `(x * scale_x + offset_x, y * scale_y + offset_y)`. Rectangles
+/// are rounded outwards, so that the transformed rectangle covers all affected pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageTransform {
+    /// The horizontal scaling factor.
+    pub scale_x: f64,
+    /// The vertical scaling factor.
+    pub scale_y: f64,
+    /// The horizontal offset that is added after scaling.
+    pub offset_x: i32,
+    /// The vertical offset that is added after scaling.
+    pub offset_y: i32,
+}
+
+impl DamageTransform {
+    /// The transformation that does not change anything.
+    pub const IDENTITY: Self = Self {
+        scale_x: 1.0,
+        scale_y: 1.0,
+        offset_x: 0,
+        offset_y: 0,
+    };
+
+    /// A transformation that scales both directions by the given factor.
+    pub fn scale(factor: f64) -> Self {
+        Self {
+            scale_x: factor,
+            scale_y: factor,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Apply this transformation to the given rectangle.
+    pub fn apply(&self, rect: Rectangle) -> Rectangle {
+        let x1 = f64::from(rect.x) * self.scale_x + f64::from(self.offset_x);
+        let y1 = f64::from(rect.y) * self.scale_y + f64::from(self.offset_y);
+        let x2 = f64::from(i32::from(rect.x) + i32::from(rect.width)) * self.scale_x
+            + f64::from(self.offset_x);
+        let y2 = f64::from(i32::from(rect.y) + i32::from(rect.height)) * self.scale_y
+            + f64::from(self.offset_y);
+        from_corners(
+            x1.min(x2).floor() as i64,
+            y1.min(y2).floor() as i64,
+            x1.max(x2).ceil() as i64,
+            y1.max(y2).ceil() as i64,
+        )
+    }
+}
+
+impl Default for DamageTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Build a rectangle from its corners, saturating at the limits of the X11 coordinate types.
+fn from_corners(x1: i64, y1: i64, x2: i64, y2: i64) -> Rectangle {
+    let clamp_pos = |v: i64| v.clamp(i64::from(i16::MIN), i64::from(i16::MAX));
+    let (x1, y1) = (clamp_pos(x1), clamp_pos(y1));
+    let (x2, y2) = (clamp_pos(x2).max(x1), clamp_pos(y2).max(y1));
+    Rectangle {
+        x: x1 as i16,
+        y: y1 as i16,
+        width: (x2 - x1).min(i64::from(u16::MAX)) as u16,
+        height: (y2 - y1).min(i64::from(u16::MAX)) as u16,
+    }
+}
+
+fn corners(rect: &Rectangle) -> (i64, i64, i64, i64) {
+    let (x, y) = (i64::from(rect.x), i64::from(rect.y));
+    (x, y, x + i64::from(rect.width), y + i64::from(rect.height))
+}
+
+fn contains(outer: &Rectangle, inner: &Rectangle) -> bool {
+    let (ox1, oy1, ox2, oy2) = corners(outer);
+    let (ix1, iy1, ix2, iy2) = corners(inner);
+    ox1 <= ix1 && oy1 <= iy1 && ix2 <= ox2 && iy2 <= oy2
+}
+
+fn is_empty(rect: &Rectangle) -> bool {
+    rect.width == 0 || rect.height == 0
+}
+
+/// Compute the intersection of two rectangles.
+fn intersect(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    let (ax1, ay1, ax2, ay2) = corners(a);
+    let (bx1, by1, bx2, by2) = corners(b);
+    from_corners(ax1.max(bx1), ay1.max(by1), ax2.min(bx2), ay2.min(by2))
+}
+
+/// Compute the bounding box of some rectangles.
+fn bounding_box<'a>(rects: impl IntoIterator<Item = &'a Rectangle>) -> Option<Rectangle> {
+    rects
+        .into_iter()
+        .map(corners)
+        .fold(None, |acc, (x1, y1, x2, y2)| match acc {
+            None => Some((x1, y1, x2, y2)),
+            Some((ax1, ay1, ax2, ay2)) => {
+                Some((ax1.min(x1), ay1.min(y1), ax2.max(x2), ay2.max(y2)))
+            }
+        })
+        .map(|(x1, y1, x2, y2)| from_corners(x1, y1, x2, y2))
+}
+
+/// A collection of damaged rectangles.
+///
+/// Empty rectangles and rectangles that are completely covered by already damaged rectangles
+/// are ignored. When more than a maximum number of rectangles accumulate, they are collapsed
+/// into their bounding box, since sending many small rectangles is not worth it.
+#[derive(Debug, Clone)]
+pub struct DamageAccumulator {
+    rects: Vec<Rectangle>,
+    max_rectangles: usize,
+}
+
+impl Default for DamageAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DamageAccumulator {
+    /// Create a new, empty instance.
+    pub fn new() -> Self {
+        Self::with_max_rectangles(DEFAULT_MAX_RECTANGLES)
+    }
+
+    /// Create a new instance that collapses damage into a bounding box when more than the given
+    /// number of rectangles accumulate.
+    pub fn with_max_rectangles(max_rectangles: usize) -> Self {
+        Self {
+            rects: Vec::new(),
+            max_rectangles: max_rectangles.max(1),
+        }
+    }
+
+    /// Add a damaged rectangle.
+    pub fn add(&mut self, rect: Rectangle) {
+        if is_empty(&rect) || self.rects.iter().any(|r| contains(r, &rect)) {
+            return;
+        }
+        self.rects.retain(|r| !contains(&rect, r));
+        self.rects.push(rect);
+        if self.rects.len() > self.max_rectangles {
+            let bbox = bounding_box(&self.rects).expect("rects is not empty");
+            self.rects.clear();
+            self.rects.push(bbox);
+        }
+    }
+
+    /// Add the damaged area from a `DamageNotify` event.
+    #[cfg(feature = "damage")]
+    pub fn add_notify(&mut self, event: &crate::protocol::damage::NotifyEvent) {
+        self.add(event.area);
+    }
+
+    /// Is nothing damaged?
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Forget all accumulated damage.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Get the accumulated damage.
+    pub fn rectangles(&self) -> &[Rectangle] {
+        &self.rects
+    }
+
+    /// Get the bounding box of the accumulated damage.
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        bounding_box(&self.rects)
+    }
+
+    /// Get the accumulated damage transformed into pixmap coordinates and clipped to a pixmap of
+    /// the given size.
+    pub fn transformed(&self, transform: DamageTransform, size: (u16, u16)) -> Vec<Rectangle> {
+        let pixmap = Rectangle {
+            x: 0,
+            y: 0,
+            width: size.0,
+            height: size.1,
+        };
+        let mut result = DamageAccumulator::with_max_rectangles(self.max_rectangles);
+        self.rects
+            .iter()
+            .map(|rect| intersect(&transform.apply(*rect), &pixmap))
+            .for_each(|rect| result.add(rect));
+        result.rects
+    }
+}
+
+/// A pair of XFixes regions for the `valid` and `update` parameters of `PresentPixmap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentRegions {
+    valid: Region,
+    update: Region,
+}
+
+impl PresentRegions {
+    /// Create the regions on the X11 server.
+    ///
+    /// The XFixes extension must already be initialised via `QueryVersion`.
+    pub fn new<C: Connection + ?Sized>(conn: &C) -> Result<Self, ReplyOrIdError> {
+        let valid = conn.generate_id()?;
+        let update = conn.generate_id()?;
+        let _ = xfixes::create_region(conn, valid, &[])?;
+        let _ = xfixes::create_region(conn, update, &[])?;
+        Ok(Self { valid, update })
+    }
+
+    /// Get the region to pass as `valid` parameter.
+    pub fn valid(&self) -> Region {
+        self.valid
+    }
+
+    /// Get the region to pass as `update` parameter.
+    pub fn update(&self) -> Region {
+        self.update
+    }
+
+    /// Set the regions for presenting a pixmap of the given size.
+    ///
+    /// The `update` region is set to the transformed and clipped damage and the `valid` region
+    /// is set to the whole pixmap. If no damage accumulated, the update region is empty.
+    pub fn set<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        damage: &DamageAccumulator,
+        transform: DamageTransform,
+        size: (u16, u16),
+    ) -> Result<(), ConnectionError> {
+        let whole = Rectangle {
+            x: 0,
+            y: 0,
+            width: size.0,
+            height: size.1,
+        };
+        let _ = xfixes::set_region(conn, self.valid, &[whole])?;
+        let _ = xfixes::set_region(conn, self.update, &damage.transformed(transform, size))?;
+        Ok(())
+    }
+
+    /// Set the `valid` region to the given rectangles in pixmap coordinates.
+    ///
+    /// This is only needed for pixmaps that are partially undefined. Call this after
+    /// [`set()`](Self::set), since that resets the valid region.
+    pub fn set_valid<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        rectangles: &[Rectangle],
+    ) -> Result<(), ConnectionError> {
+        let _ = xfixes::set_region(conn, self.valid, rectangles)?;
+        Ok(())
+    }
+
+    /// Destroy the regions on the X11 server.
+    pub fn destroy<C: Connection + ?Sized>(self, conn: &C) -> Result<(), ConnectionError> {
+        let _ = xfixes::destroy_region(conn, self.valid)?;
+        let _ = xfixes::destroy_region(conn, self.update)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DamageAccumulator, DamageTransform};
+    use crate::protocol::xproto::Rectangle;
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn accumulate() {
+        let mut damage = DamageAccumulator::new();
+        damage.add(rect(10, 10, 5, 5));
+        damage.add(rect(11, 11, 2, 2));
+        damage.add(rect(0, 0, 0, 10));
+        assert_eq!(damage.rectangles(), [rect(10, 10, 5, 5)]);
+        damage.add(rect(0, 0, 20, 20));
+        assert_eq!(damage.rectangles(), [rect(0, 0, 20, 20)]);
+    }
+
+    #[test]
+    fn collapse_to_bounding_box() {
+        let mut damage = DamageAccumulator::with_max_rectangles(2);
+        damage.add(rect(0, 0, 1, 1));
+        damage.add(rect(10, 0, 1, 1));
+        assert_eq!(damage.rectangles().len(), 2);
+        damage.add(rect(0, 10, 1, 1));
+        assert_eq!(damage.rectangles(), [rect(0, 0, 11, 11)]);
+    }
+
+    #[test]
+    fn transform() {
+        let transform = DamageTransform::scale(1.5);
+        assert_eq!(transform.apply(rect(1, 1, 1, 1)), rect(1, 1, 2, 2));
+        let transform = DamageTransform {
+            offset_x: -10,
+            ..DamageTransform::scale(2.0)
+        };
+        assert_eq!(transform.apply(rect(1, 2, 3, 4)), rect(-8, 4, 6, 8));
+    }
+
+    #[test]
+    fn transformed_is_clipped() {
+        let mut damage = DamageAccumulator::new();
+        damage.add(rect(-5, -5, 10, 10));
+        damage.add(rect(50, 50, 10, 10));
+        damage.add(rect(90, 0, 20, 5));
+        assert_eq!(
+            damage.transformed(DamageTransform::IDENTITY, (100, 100)),
+            [rect(0, 0, 5, 5), rect(50, 50, 10, 10), rect(90, 0, 10, 5)]
+        );
+    }
+}
@@ -1,12 +1,195 @@
 //! Utility functions for working with X11 properties
+//!
+//! The values of properties with a known layout can be decoded from a `GetPropertyReply`, e.g.
+//! via [`WmClass::from_reply`], [`WmSizeHints::from_reply`], and [`WmHints::from_reply`]. To get
+//! the complete value of a property of unknown size, use
+//! [`get_property_full`](crate::wrapper::ConnectionExt::get_property_full).
 
-use std::convert::TryInto;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
 
-use crate::connection::RequestConnection;
+use crate::connection::{Connection, RequestConnection};
 use crate::cookie::{Cookie, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError};
+use crate::grab::GrabServerGuard;
 use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
-use crate::x11_utils::{Serialize, TryParse};
+use crate::protocol::ErrorKind;
+use crate::send_event::{client_message32, send_to_root};
+use crate::wrapper::ConnectionExt as _;
+use crate::x11_utils::{Serialize, TryParse, X11Error};
+
+// Typed values
+
+/// Get the complete value of a property of type `ATOM`, e.g. `WM_PROTOCOLS`.
+///
+/// An empty list is returned if the property is not set or has another type.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn get_atoms<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    property: impl Into<Atom>,
+) -> Result<Vec<Atom>, ReplyError> {
+    let reply = conn.get_property_full(false, window, property, AtomEnum::ATOM)?;
+    Ok(reply.value32().into_iter().flatten().collect())
+}
+
+/// Get the complete value of a property of type `UTF8_STRING`, e.g. `_NET_WM_NAME`.
+///
+/// Returns `None` if the property is not set or has another type. Invalid UTF-8 is replaced with
+/// U+FFFD.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn get_utf8_string<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    property: impl Into<Atom>,
+) -> Result<Option<String>, ReplyError> {
+    let utf8_string = xproto::intern_atom(conn, false, b"UTF8_STRING")?
+        .reply()?
+        .atom;
+    let reply = conn.get_property_full(false, window, property, utf8_string)?;
+    if reply.format != 8 {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&reply.value).into_owned()))
+}
+
+/// Get the states of a window from its `_NET_WM_STATE` property.
+///
+/// The states are atoms like `_NET_WM_STATE_FULLSCREEN`. An empty list is returned if the property
+/// is not set.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn get_net_wm_state<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Vec<Atom>, ReplyError> {
+    let property = xproto::intern_atom(conn, false, b"_NET_WM_STATE")?
+        .reply()?
+        .atom;
+    get_atoms(conn, window, property)
+}
+
+/// The values of properties of many windows, as returned by [`get_properties_bulk`].
+///
+/// The outer map is indexed by window and the inner map by property.
+pub type PropertyMap = HashMap<Window, HashMap<Atom, GetPropertyReply>>;
+
+/// Get the complete values of the given properties of all given windows.
+///
+/// Getting the properties one after another needs a round trip to the X11 server per property,
+/// which adds up quickly for e.g. a taskbar that looks at the names, icons and states of dozens of
+/// windows over a remote connection. This function sends all `GetProperty` requests before it
+/// waits for the first reply, so that it usually needs a single round trip. Only properties that
+/// are larger than the maximum request length need more requests.
+///
+/// Properties that are not set are missing from the result. Windows that were destroyed in the
+/// meantime are missing as well, since this is expected when enumerating other clients' windows.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn get_properties_bulk<C: RequestConnection + ?Sized>(
+    conn: &C,
+    windows: &[Window],
+    properties: &[Atom],
+) -> Result<PropertyMap, ReplyError> {
+    // The length is given in units of four bytes
+    let chunk_length = (conn.maximum_request_bytes() / 4)
+        .try_into()
+        .unwrap_or(u32::MAX);
+    let mut cookies = Vec::with_capacity(windows.len() * properties.len());
+    for &window in windows {
+        for &property in properties {
+            let cookie = xproto::get_property(
+                conn,
+                false,
+                window,
+                property,
+                AtomEnum::ANY,
+                0,
+                chunk_length,
+            )?;
+            cookies.push((window, property, cookie));
+        }
+    }
+    let mut result = PropertyMap::new();
+    for (window, property, cookie) in cookies {
+        let reply = match cookie.reply() {
+            Ok(reply) if reply.bytes_after != 0 => {
+                conn.get_property_full(false, window, property, AtomEnum::ANY)
+            }
+            reply => reply,
+        };
+        match reply {
+            Ok(reply) if reply.format == 0 => {}
+            Ok(reply) => {
+                let _ = result.entry(window).or_default().insert(property, reply);
+            }
+            Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Window => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(result)
+}
+
+/// Set the `WM_CLASS` property of a window.
+///
+/// `WM_CLASS` has the type `STRING`, which is Latin-1 and not UTF-8. It contains the instance name
+/// (usually the name of the executable, or the value of the `-name` argument) and the class name
+/// (usually the name of the application with a capital first letter), each terminated by a NUL
+/// byte. Characters that cannot be represented in Latin-1, and NUL characters, are replaced with
+/// `?`.
+///
+/// The property should be set before the window is mapped, since window managers might only read
+/// it at that time.
+pub fn set_wm_class<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    window: Window,
+    instance: &str,
+    class: &str,
+) -> Result<VoidCookie<'a, C>, ConnectionError> {
+    conn.change_property8(
+        xproto::PropMode::REPLACE,
+        window,
+        AtomEnum::WM_CLASS,
+        AtomEnum::STRING,
+        &encode_wm_class(instance, class),
+    )
+}
+
+/// Get the instance and class names from the `WM_CLASS` property of a window.
+///
+/// Returns `None` if the property is not set or has another type. The Latin-1 encoding of the
+/// property is converted to Rust strings. See [`WmClass`] for the raw bytes.
+///
+/// This function waits for the reply to the `GetProperty` request.
+pub fn get_wm_class<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<(String, String)>, ReplyError> {
+    let reply = WmClassCookie::new(conn, window)?.0.reply()?;
+    Ok(WmClass::from_reply(reply)
+        .ok()
+        .map(|wm_class| (latin1(wm_class.instance()), latin1(wm_class.class()))))
+}
+
+/// Encode the value of a `WM_CLASS` property.
+fn encode_wm_class(instance: &str, class: &str) -> Vec<u8> {
+    let mut value = Vec::with_capacity(instance.len() + class.len() + 2);
+    for name in &[instance, class] {
+        value.extend(name.chars().map(|c| match u8::try_from(u32::from(c)) {
+            Ok(0) | Err(_) => b'?',
+            Ok(byte) => byte,
+        }));
+        value.push(0);
+    }
+    value
+}
+
+/// Decode a Latin-1 string.
+fn latin1(bytes: &[u8]) -> String {
+    bytes.iter().copied().map(char::from).collect()
+}
 
 // WM_CLASS
 
@@ -81,7 +264,7 @@ pub struct WmClass(GetPropertyReply, usize);
 
 impl WmClass {
     /// Send a `GetProperty` request for the `WM_CLASS` property of the given window
-    pub fn get<C: RequestConnection>(
+    pub fn get<C: RequestConnection + ?Sized>(
         conn: &C,
         window: Window,
     ) -> Result<WmClassCookie<'_, C>, ConnectionError> {
@@ -268,7 +451,7 @@ impl WmSizeHints {
     }
 
     /// Send a `GetProperty` request for the given property of the given window
-    pub fn get<C: RequestConnection>(
+    pub fn get<C: RequestConnection + ?Sized>(
         conn: &C,
         window: Window,
         property: impl Into<Atom>,
@@ -277,7 +460,7 @@ impl WmSizeHints {
     }
 
     /// Send a `GetProperty` request for the `WM_NORMAL_HINTS` property of the given window
-    pub fn get_normal_hints<C: RequestConnection>(
+    pub fn get_normal_hints<C: RequestConnection + ?Sized>(
         conn: &C,
         window: Window,
     ) -> Result<WmSizeHintsCookie<'_, C>, ConnectionError> {
@@ -540,7 +723,7 @@ impl WmHints {
     }
 
     /// Send a `GetProperty` request for the `WM_HINTS` property of the given window
-    pub fn get<C: RequestConnection>(
+    pub fn get<C: RequestConnection + ?Sized>(
         conn: &C,
         window: Window,
     ) -> Result<WmHintsCookie<'_, C>, ConnectionError> {
@@ -570,8 +753,8 @@ impl WmHints {
             conn,
             xproto::PropMode::REPLACE,
             window,
-            xproto::AtomEnum::WM_HINTS,
-            xproto::AtomEnum::WM_HINTS,
+            AtomEnum::WM_HINTS,
+            AtomEnum::WM_HINTS,
             32,
             NUM_WM_HINTS_ELEMENTS,
             &data,
@@ -662,6 +845,732 @@ impl Serialize for WmHints {
     }
 }
 
+// _NET_WM_WINDOW_TYPE
+
+/// The functional type of a window from the `_NET_WM_WINDOW_TYPE` property of the EWMH
+/// specification.
+///
+/// Window managers use the type to decide on decorations, stacking and focus behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowType {
+    /// A desktop feature, e.g. a full screen window that shows the desktop icons.
+    Desktop,
+    /// A dock or panel.
+    Dock,
+    /// A toolbar that was torn off from the main window.
+    Toolbar,
+    /// A menu that was torn off from the main window.
+    Menu,
+    /// A small persistent utility window, e.g. a palette or toolbox.
+    Utility,
+    /// A splash screen that is shown while an application starts.
+    Splash,
+    /// A dialog window.
+    Dialog,
+    /// A menu that drops down from a menu bar.
+    DropdownMenu,
+    /// A popup menu, e.g. a context menu.
+    PopupMenu,
+    /// A tooltip.
+    Tooltip,
+    /// A notification bubble.
+    Notification,
+    /// The popup of a combo box.
+    Combo,
+    /// A window that is being dragged.
+    Dnd,
+    /// A normal top-level window.
+    Normal,
+}
+
+impl WindowType {
+    const ALL: [WindowType; 14] = [
+        WindowType::Desktop,
+        WindowType::Dock,
+        WindowType::Toolbar,
+        WindowType::Menu,
+        WindowType::Utility,
+        WindowType::Splash,
+        WindowType::Dialog,
+        WindowType::DropdownMenu,
+        WindowType::PopupMenu,
+        WindowType::Tooltip,
+        WindowType::Notification,
+        WindowType::Combo,
+        WindowType::Dnd,
+        WindowType::Normal,
+    ];
+
+    /// Get the name of the atom that represents this window type.
+    pub fn atom_name(self) -> &'static [u8] {
+        match self {
+            WindowType::Desktop => b"_NET_WM_WINDOW_TYPE_DESKTOP",
+            WindowType::Dock => b"_NET_WM_WINDOW_TYPE_DOCK",
+            WindowType::Toolbar => b"_NET_WM_WINDOW_TYPE_TOOLBAR",
+            WindowType::Menu => b"_NET_WM_WINDOW_TYPE_MENU",
+            WindowType::Utility => b"_NET_WM_WINDOW_TYPE_UTILITY",
+            WindowType::Splash => b"_NET_WM_WINDOW_TYPE_SPLASH",
+            WindowType::Dialog => b"_NET_WM_WINDOW_TYPE_DIALOG",
+            WindowType::DropdownMenu => b"_NET_WM_WINDOW_TYPE_DROPDOWN_MENU",
+            WindowType::PopupMenu => b"_NET_WM_WINDOW_TYPE_POPUP_MENU",
+            WindowType::Tooltip => b"_NET_WM_WINDOW_TYPE_TOOLTIP",
+            WindowType::Notification => b"_NET_WM_WINDOW_TYPE_NOTIFICATION",
+            WindowType::Combo => b"_NET_WM_WINDOW_TYPE_COMBO",
+            WindowType::Dnd => b"_NET_WM_WINDOW_TYPE_DND",
+            WindowType::Normal => b"_NET_WM_WINDOW_TYPE_NORMAL",
+        }
+    }
+
+    /// Get the window type that is represented by the atom with the given name.
+    pub fn from_atom_name(name: &[u8]) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|window_type| window_type.atom_name() == name)
+    }
+}
+
+/// Intern the atoms for `_NET_WM_WINDOW_TYPE` and for all values of [`WindowType`].
+fn intern_window_type_atoms<C: RequestConnection + ?Sized>(
+    conn: &C,
+) -> Result<(Atom, [Atom; 14]), ReplyError> {
+    let property = xproto::intern_atom(conn, false, b"_NET_WM_WINDOW_TYPE")?;
+    let mut cookies = Vec::with_capacity(WindowType::ALL.len());
+    for window_type in WindowType::ALL.iter() {
+        cookies.push(xproto::intern_atom(conn, false, window_type.atom_name())?);
+    }
+    let property = property.reply()?.atom;
+    let mut atoms = [0; 14];
+    for (atom, cookie) in atoms.iter_mut().zip(cookies) {
+        *atom = cookie.reply()?.atom;
+    }
+    Ok((property, atoms))
+}
+
+/// Set the `_NET_WM_WINDOW_TYPE` property of a window.
+///
+/// The types are listed in order of preference. A window manager uses the first type that it
+/// understands, so less common types should be followed by a more widely supported fallback,
+/// e.g. `[WindowType::Notification, WindowType::Utility]`.
+///
+/// This function waits for the replies to the necessary `InternAtom` requests.
+pub fn set_window_type<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    window: Window,
+    types: &[WindowType],
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    let (property, atoms) = intern_window_type_atoms(conn)?;
+    let value = types
+        .iter()
+        .map(|window_type| {
+            let index = WindowType::ALL
+                .iter()
+                .position(|other| other == window_type)
+                .unwrap();
+            atoms[index]
+        })
+        .collect::<Vec<_>>();
+    Ok(conn.change_property32(
+        xproto::PropMode::REPLACE,
+        window,
+        property,
+        AtomEnum::ATOM,
+        &value,
+    )?)
+}
+
+/// Get the `_NET_WM_WINDOW_TYPE` property of a window.
+///
+/// The types are returned in order of preference. Types that are not known to [`WindowType`] are
+/// skipped. An empty list is returned if the property is not set. In this case, window managers
+/// treat the window as [`WindowType::Dialog`] if it has `WM_TRANSIENT_FOR` set and as
+/// [`WindowType::Normal`] otherwise.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn get_window_type<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Vec<WindowType>, ReplyError> {
+    let (property, atoms) = intern_window_type_atoms(conn)?;
+    let reply = xproto::get_property(conn, false, window, property, AtomEnum::ATOM, 0, u32::MAX)?
+        .reply()?;
+    Ok(window_types_from_atoms(
+        &atoms,
+        reply.value32().into_iter().flatten(),
+    ))
+}
+
+/// Map atoms to window types, given the atoms in the order of [`WindowType::ALL`].
+fn window_types_from_atoms(
+    atoms: &[Atom; 14],
+    values: impl Iterator<Item = Atom>,
+) -> Vec<WindowType> {
+    values
+        .filter_map(|value| {
+            atoms
+                .iter()
+                .position(|&atom| atom == value)
+                .map(|index| WindowType::ALL[index])
+        })
+        .collect()
+}
+
+// Desktops
+
+/// The value of `_NET_WM_DESKTOP` for windows that are visible on all desktops.
+pub const ALL_DESKTOPS: u32 = 0xFFFF_FFFF;
+
+/// Who sends a request to the window manager.
+///
+/// The EWMH specification distinguishes between requests that come from normal applications and
+/// requests that come directly from the user via a pager or taskbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestSource {
+    /// The request comes from a normal application.
+    Application,
+    /// The request comes from a pager, taskbar or similar tool.
+    Pager,
+}
+
+impl From<RequestSource> for u32 {
+    fn from(source: RequestSource) -> u32 {
+        match source {
+            RequestSource::Application => 1,
+            RequestSource::Pager => 2,
+        }
+    }
+}
+
+/// Get the value of a `CARDINAL` property with a single element.
+fn get_cardinal<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    name: &[u8],
+) -> Result<Option<u32>, ReplyError> {
+    Ok(get_cardinals(conn, window, name, 1)?.first().copied())
+}
+
+/// Get up to `max_len` elements of a `CARDINAL` property.
+///
+/// An empty list is returned if the property is not set.
+fn get_cardinals<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    name: &[u8],
+    max_len: u32,
+) -> Result<Vec<u32>, ReplyError> {
+    let property = xproto::intern_atom(conn, false, name)?.reply()?.atom;
+    let reply = xproto::get_property(
+        conn,
+        false,
+        window,
+        property,
+        AtomEnum::CARDINAL,
+        0,
+        max_len,
+    )?
+    .reply()?;
+    Ok(reply.value32().into_iter().flatten().collect())
+}
+
+/// Send a client message to the root window that the window manager acts on.
+pub(crate) fn send_root_message<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    window: Window,
+    name: &[u8],
+    data: [u32; 5],
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    let type_ = xproto::intern_atom(conn, false, name)?.reply()?.atom;
+    Ok(send_to_root(
+        conn,
+        root,
+        client_message32(window, type_, data),
+    )?)
+}
+
+/// Get the number of desktops from the `_NET_NUMBER_OF_DESKTOPS` property of the root window.
+///
+/// Returns `None` if the window manager does not set the property.
+pub fn get_number_of_desktops<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Option<u32>, ReplyError> {
+    get_cardinal(conn, root, b"_NET_NUMBER_OF_DESKTOPS")
+}
+
+/// Get the index of the current desktop from the `_NET_CURRENT_DESKTOP` property of the root
+/// window.
+///
+/// Returns `None` if the window manager does not set the property.
+pub fn get_current_desktop<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Option<u32>, ReplyError> {
+    get_cardinal(conn, root, b"_NET_CURRENT_DESKTOP")
+}
+
+/// Get the names of the desktops from the `_NET_DESKTOP_NAMES` property of the root window.
+///
+/// The list may contain fewer or more names than there are desktops. Invalid UTF-8 is replaced
+/// with U+FFFD.
+pub fn get_desktop_names<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<String>, ReplyError> {
+    let property = xproto::intern_atom(conn, false, b"_NET_DESKTOP_NAMES")?;
+    let utf8_string = xproto::intern_atom(conn, false, b"UTF8_STRING")?;
+    let (property, utf8_string) = (property.reply()?.atom, utf8_string.reply()?.atom);
+    let reply =
+        xproto::get_property(conn, false, root, property, utf8_string, 0, u32::MAX)?.reply()?;
+    if reply.format != 8 {
+        return Ok(Vec::new());
+    }
+    Ok(parse_string_list(&reply.value))
+}
+
+/// Parse a list of null-terminated UTF-8 strings.
+///
+/// The terminator of the last string may be missing.
+fn parse_string_list(value: &[u8]) -> Vec<String> {
+    if value.is_empty() {
+        return Vec::new();
+    }
+    value
+        .strip_suffix(b"\0")
+        .unwrap_or(value)
+        .split(|&byte| byte == 0)
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+        .collect()
+}
+
+/// Get the desktop of a window from its `_NET_WM_DESKTOP` property.
+///
+/// [`ALL_DESKTOPS`] means that the window is visible on all desktops. Returns `None` if the
+/// property is not set.
+pub fn get_window_desktop<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<u32>, ReplyError> {
+    get_cardinal(conn, window, b"_NET_WM_DESKTOP")
+}
+
+/// Ask the window manager to switch to another desktop.
+///
+/// This sends a `_NET_CURRENT_DESKTOP` client message to `root`. `timestamp` should be the
+/// time of the user interaction that caused the switch.
+pub fn request_current_desktop<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    desktop: u32,
+    timestamp: xproto::Timestamp,
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    send_root_message(
+        conn,
+        root,
+        root,
+        b"_NET_CURRENT_DESKTOP",
+        [desktop, timestamp, 0, 0, 0],
+    )
+}
+
+/// Ask the window manager to move a window to another desktop.
+///
+/// This sends a `_NET_WM_DESKTOP` client message to `root`. Use [`ALL_DESKTOPS`] to make the
+/// window visible on all desktops.
+pub fn move_window_to_desktop<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    window: Window,
+    desktop: u32,
+    source: RequestSource,
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    send_root_message(
+        conn,
+        root,
+        window,
+        b"_NET_WM_DESKTOP",
+        [desktop, source.into(), 0, 0, 0],
+    )
+}
+
+// Desktop geometry and work area
+
+/// Get the size of the desktop from the `_NET_DESKTOP_GEOMETRY` property of the root window.
+///
+/// The desktop can be larger than the screen if the window manager supports large desktops.
+/// Returns `None` if the window manager does not set the property.
+pub fn get_desktop_geometry<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Option<(u32, u32)>, ReplyError> {
+    let value = get_cardinals(conn, root, b"_NET_DESKTOP_GEOMETRY", 2)?;
+    Ok(match value[..] {
+        [width, height] => Some((width, height)),
+        _ => None,
+    })
+}
+
+/// Get the top left corner of the visible area of each desktop from the `_NET_DESKTOP_VIEWPORT`
+/// property of the root window.
+///
+/// The list contains one entry per desktop. It is empty if the window manager does not set the
+/// property.
+pub fn get_desktop_viewport<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<(u32, u32)>, ReplyError> {
+    let value = get_cardinals(conn, root, b"_NET_DESKTOP_VIEWPORT", u32::MAX)?;
+    Ok(value
+        .chunks_exact(2)
+        .map(|chunk| (chunk[0], chunk[1]))
+        .collect())
+}
+
+/// Get the work area of each desktop from the `_NET_WORKAREA` property of the root window.
+///
+/// The work area is the part of the desktop that is not covered by panels and docks. The list
+/// contains one entry per desktop. It is empty if the window manager does not set the property.
+///
+/// With more than one monitor, the work area is the bounding box of the usable areas of all
+/// monitors. [`usable_area`] computes the usable area of a single monitor.
+pub fn get_workarea<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<xproto::Rectangle>, ReplyError> {
+    let value = get_cardinals(conn, root, b"_NET_WORKAREA", u32::MAX)?;
+    Ok(value
+        .chunks_exact(4)
+        .map(|chunk| xproto::Rectangle {
+            x: i16::try_from(chunk[0]).unwrap_or(i16::MAX),
+            y: i16::try_from(chunk[1]).unwrap_or(i16::MAX),
+            width: u16::try_from(chunk[2]).unwrap_or(u16::MAX),
+            height: u16::try_from(chunk[3]).unwrap_or(u16::MAX),
+        })
+        .collect())
+}
+
+/// Set the `_NET_WORKAREA` property of the root window.
+///
+/// This is meant for window managers. `workareas` contains one entry per desktop.
+pub fn set_workarea<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    workareas: &[xproto::Rectangle],
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    let property = xproto::intern_atom(conn, false, b"_NET_WORKAREA")?
+        .reply()?
+        .atom;
+    let value = workareas
+        .iter()
+        .flat_map(|area| {
+            [
+                u32::try_from(area.x.max(0)).unwrap(),
+                u32::try_from(area.y.max(0)).unwrap(),
+                area.width.into(),
+                area.height.into(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    Ok(conn.change_property32(
+        xproto::PropMode::REPLACE,
+        root,
+        property,
+        AtomEnum::CARDINAL,
+        &value,
+    )?)
+}
+
+/// Ask the window manager to change the size of the desktop.
+///
+/// This sends a `_NET_DESKTOP_GEOMETRY` client message to `root`.
+pub fn request_desktop_geometry<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    width: u32,
+    height: u32,
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    send_root_message(
+        conn,
+        root,
+        root,
+        b"_NET_DESKTOP_GEOMETRY",
+        [width, height, 0, 0, 0],
+    )
+}
+
+/// Ask the window manager to change the visible area of the current desktop.
+///
+/// This sends a `_NET_DESKTOP_VIEWPORT` client message to `root`.
+pub fn request_desktop_viewport<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    root: Window,
+    x: u32,
+    y: u32,
+) -> Result<VoidCookie<'a, C>, ReplyError> {
+    send_root_message(conn, root, root, b"_NET_DESKTOP_VIEWPORT", [x, y, 0, 0, 0])
+}
+
+/// The space that a window reserves at the edges of the screen.
+///
+/// This is the content of the `_NET_WM_STRUT_PARTIAL` property of the EWMH specification. All
+/// values are relative to the edges of the root window. For example, `left` is the width of the
+/// reserved area at the left edge and `left_start_y..=left_end_y` are the rows that it covers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Strut {
+    /// The width of the area at the left edge.
+    pub left: u32,
+    /// The width of the area at the right edge.
+    pub right: u32,
+    /// The height of the area at the top edge.
+    pub top: u32,
+    /// The height of the area at the bottom edge.
+    pub bottom: u32,
+    /// The first row of the area at the left edge.
+    pub left_start_y: u32,
+    /// The last row of the area at the left edge.
+    pub left_end_y: u32,
+    /// The first row of the area at the right edge.
+    pub right_start_y: u32,
+    /// The last row of the area at the right edge.
+    pub right_end_y: u32,
+    /// The first column of the area at the top edge.
+    pub top_start_x: u32,
+    /// The last column of the area at the top edge.
+    pub top_end_x: u32,
+    /// The first column of the area at the bottom edge.
+    pub bottom_start_x: u32,
+    /// The last column of the area at the bottom edge.
+    pub bottom_end_x: u32,
+}
+
+impl Strut {
+    /// Get the strut of a window.
+    ///
+    /// This reads `_NET_WM_STRUT_PARTIAL`. If it is not set, the older `_NET_WM_STRUT` is used,
+    /// whose areas cover the full edge. Returns `None` if neither property is set.
+    pub fn get<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<Option<Self>, ReplyError> {
+        let value = get_cardinals(conn, window, b"_NET_WM_STRUT_PARTIAL", 12)?;
+        if let Some(strut) = Self::from_values(&value) {
+            return Ok(Some(strut));
+        }
+        let value = get_cardinals(conn, window, b"_NET_WM_STRUT", 4)?;
+        Ok(Self::from_values(&value))
+    }
+
+    /// Construct a strut from the value of `_NET_WM_STRUT_PARTIAL` or `_NET_WM_STRUT`.
+    ///
+    /// Returns `None` if the value has the wrong length.
+    pub fn from_values(value: &[u32]) -> Option<Self> {
+        match *value {
+            [left, right, top, bottom] => Some(Self {
+                left,
+                right,
+                top,
+                bottom,
+                left_end_y: u32::MAX,
+                right_end_y: u32::MAX,
+                top_end_x: u32::MAX,
+                bottom_end_x: u32::MAX,
+                ..Default::default()
+            }),
+            [left, right, top, bottom, left_start_y, left_end_y, right_start_y, right_end_y, top_start_x, top_end_x, bottom_start_x, bottom_end_x] => {
+                Some(Self {
+                    left,
+                    right,
+                    top,
+                    bottom,
+                    left_start_y,
+                    left_end_y,
+                    right_start_y,
+                    right_end_y,
+                    top_start_x,
+                    top_end_x,
+                    bottom_start_x,
+                    bottom_end_x,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Compute the part of a monitor that is not covered by the struts of panels and docks.
+///
+/// `screen_width` and `screen_height` are the size of the root window, which the struts are
+/// relative to. A strut only shrinks the monitor if its area overlaps the monitor. The result is
+/// the largest rectangle inside `monitor` that does not overlap any strut.
+pub fn usable_area(
+    screen_width: u16,
+    screen_height: u16,
+    monitor: xproto::Rectangle,
+    struts: &[Strut],
+) -> xproto::Rectangle {
+    let (screen_width, screen_height) = (i64::from(screen_width), i64::from(screen_height));
+    let (mon_x1, mon_y1) = (i64::from(monitor.x), i64::from(monitor.y));
+    let mon_x2 = mon_x1 + i64::from(monitor.width);
+    let mon_y2 = mon_y1 + i64::from(monitor.height);
+    let (mut x1, mut y1, mut x2, mut y2) = (mon_x1, mon_y1, mon_x2, mon_y2);
+
+    // Does the range start..=end overlap the range from..to?
+    let overlaps =
+        |start: u32, end: u32, from: i64, to: i64| i64::from(start) < to && i64::from(end) >= from;
+    for strut in struts {
+        if strut.left != 0 && overlaps(strut.left_start_y, strut.left_end_y, mon_y1, mon_y2) {
+            x1 = x1.max(i64::from(strut.left).min(mon_x2));
+        }
+        if strut.right != 0 && overlaps(strut.right_start_y, strut.right_end_y, mon_y1, mon_y2) {
+            x2 = x2.min((screen_width - i64::from(strut.right)).max(mon_x1));
+        }
+        if strut.top != 0 && overlaps(strut.top_start_x, strut.top_end_x, mon_x1, mon_x2) {
+            y1 = y1.max(i64::from(strut.top).min(mon_y2));
+        }
+        if strut.bottom != 0 && overlaps(strut.bottom_start_x, strut.bottom_end_x, mon_x1, mon_x2) {
+            y2 = y2.min((screen_height - i64::from(strut.bottom)).max(mon_y1));
+        }
+    }
+    // All values are within the monitor, so they fit into the types of Rectangle
+    xproto::Rectangle {
+        x: i16::try_from(x1).unwrap(),
+        y: i16::try_from(y1).unwrap(),
+        width: u16::try_from((x2 - x1).max(0)).unwrap(),
+        height: u16::try_from((y2 - y1).max(0)).unwrap(),
+    }
+}
+
+/// Compute the usable area of each monitor from the struts of all clients.
+///
+/// The clients are taken from the `_NET_CLIENT_LIST` property of `root`. If the window manager
+/// does not set it, e.g. because the caller is the window manager, all children of `root` are
+/// used instead. Clients that disappear while their struts are read are ignored.
+///
+/// With the `randr` feature, the monitors are queried with the RandR extension. Otherwise, or if
+/// the X11 server does not support RandR 1.5, the whole root window is treated as a single
+/// monitor. The result contains one rectangle per monitor, see [`usable_area`].
+pub fn compute_workareas<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<xproto::Rectangle>, ReplyError> {
+    let client_list = xproto::intern_atom(conn, false, b"_NET_CLIENT_LIST")?;
+    let strut_partial = xproto::intern_atom(conn, false, b"_NET_WM_STRUT_PARTIAL")?;
+    let strut = xproto::intern_atom(conn, false, b"_NET_WM_STRUT")?;
+    let geometry = xproto::get_geometry(conn, root)?;
+    let (client_list, strut_partial, strut) = (
+        client_list.reply()?.atom,
+        strut_partial.reply()?.atom,
+        strut.reply()?.atom,
+    );
+    let geometry = geometry.reply()?;
+
+    let clients = xproto::get_property(
+        conn,
+        false,
+        root,
+        client_list,
+        AtomEnum::WINDOW,
+        0,
+        u32::MAX,
+    )?
+    .reply()?;
+    let mut clients = clients.value32().into_iter().flatten().collect::<Vec<_>>();
+    if clients.is_empty() {
+        clients = xproto::query_tree(conn, root)?.reply()?.children;
+    }
+
+    // Send all requests before waiting for the first reply
+    let cookies = clients
+        .iter()
+        .map(|&client| {
+            Ok((
+                xproto::get_property(
+                    conn,
+                    false,
+                    client,
+                    strut_partial,
+                    AtomEnum::CARDINAL,
+                    0,
+                    12,
+                )?,
+                xproto::get_property(conn, false, client, strut, AtomEnum::CARDINAL, 0, 4)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    let mut struts = Vec::new();
+    for (partial, full) in cookies {
+        let values = |reply: Result<GetPropertyReply, ReplyError>| match reply {
+            Ok(reply) => Ok(reply.value32().into_iter().flatten().collect::<Vec<_>>()),
+            // The client was destroyed in the meantime
+            Err(ReplyError::X11Error(_)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        };
+        let (partial, full) = (values(partial.reply())?, values(full.reply())?);
+        if let Some(strut) = Strut::from_values(&partial).or_else(|| Strut::from_values(&full)) {
+            struts.push(strut);
+        }
+    }
+
+    let monitors = get_monitors(conn, root)?.unwrap_or_else(|| {
+        vec![xproto::Rectangle {
+            x: 0,
+            y: 0,
+            width: geometry.width,
+            height: geometry.height,
+        }]
+    });
+    Ok(monitors
+        .into_iter()
+        .map(|monitor| usable_area(geometry.width, geometry.height, monitor, &struts))
+        .collect())
+}
+
+/// Get the rectangles of all monitors via RandR, if available.
+#[cfg(feature = "randr")]
+fn get_monitors<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Option<Vec<xproto::Rectangle>>, ReplyError> {
+    use crate::protocol::randr;
+
+    if conn
+        .extension_information(randr::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let monitors = match randr::get_monitors(conn, root, true)?.reply() {
+        Ok(reply) => reply.monitors,
+        // The server does not support RandR 1.5
+        Err(ReplyError::X11Error(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    if monitors.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(
+        monitors
+            .iter()
+            .map(|monitor| xproto::Rectangle {
+                x: monitor.x,
+                y: monitor.y,
+                width: monitor.width,
+                height: monitor.height,
+            })
+            .collect(),
+    ))
+}
+
+/// Get the rectangles of all monitors via RandR, if available.
+#[cfg(not(feature = "randr"))]
+fn get_monitors<C: RequestConnection + ?Sized>(
+    _conn: &C,
+    _root: Window,
+) -> Result<Option<Vec<xproto::Rectangle>>, ReplyError> {
+    Ok(None)
+}
+
 /// Parse an element of type `T` and turn it into an `Option` by checking if the given `bit` is set
 /// in `flags`.
 fn parse_with_flag<T: TryParse>(
@@ -677,12 +1586,377 @@ fn parse_with_flag<T: TryParse>(
     }
 }
 
+// Transactions
+
+/// A change of a property that failed when a [`PropertyTransaction`] was committed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailedChange {
+    /// The window whose property should have been changed.
+    pub window: Window,
+    /// The property that should have been changed.
+    pub property: Atom,
+    /// The error that the X11 server sent, e.g. a `Window` error for a destroyed window.
+    pub error: X11Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PropertyChange {
+    Set {
+        type_: Atom,
+        format: u8,
+        data: Vec<u8>,
+    },
+    Delete,
+}
+
+/// A collection of property changes on several windows that are applied together.
+///
+/// Window managers keep some properties consistent with each other, e.g. `_NET_CLIENT_LIST` on
+/// the root window and `_NET_WM_DESKTOP` on each client. Other clients like pagers should never
+/// see only some of these changes. [`commit()`](Self::commit) therefore sends all changes while
+/// the server is grabbed, and releases the grab also if sending fails.
+///
+/// This is best effort: A change to a window that was destroyed in the meantime fails, but the
+/// other changes are still applied. The failed changes are returned.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ConnectionError;
+/// use x11rb::properties::PropertyTransaction;
+/// use x11rb::protocol::xproto::{Atom, AtomEnum, Window};
+///
+/// fn move_to_desktop(
+///     conn: &impl Connection,
+///     (root, client_list, wm_desktop): (Window, Atom, Atom),
+///     clients: &[Window],
+///     moved: Window,
+///     desktop: u32,
+/// ) -> Result<(), ConnectionError> {
+///     let mut transaction = PropertyTransaction::new();
+///     transaction.set32(root, client_list, AtomEnum::WINDOW, clients);
+///     transaction.set32(moved, wm_desktop, AtomEnum::CARDINAL, &[desktop]);
+///     for failed in transaction.commit(conn)? {
+///         eprintln!("Could not update window {:#x}: {:?}", failed.window, failed.error);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PropertyTransaction {
+    changes: Vec<(Window, Atom, PropertyChange)>,
+}
+
+impl PropertyTransaction {
+    /// Create a new, empty transaction.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Replace a property with data in format 8, e.g. a string.
+    ///
+    /// A later change of the same property of the same window replaces this one.
+    pub fn set8(
+        &mut self,
+        window: Window,
+        property: impl Into<Atom>,
+        type_: impl Into<Atom>,
+        data: &[u8],
+    ) {
+        let change = PropertyChange::Set {
+            type_: type_.into(),
+            format: 8,
+            data: data.to_vec(),
+        };
+        self.push(window, property.into(), change);
+    }
+
+    /// Replace a property with data in format 32, e.g. a list of windows or a cardinal.
+    ///
+    /// A later change of the same property of the same window replaces this one.
+    pub fn set32(
+        &mut self,
+        window: Window,
+        property: impl Into<Atom>,
+        type_: impl Into<Atom>,
+        data: &[u32],
+    ) {
+        let change = PropertyChange::Set {
+            type_: type_.into(),
+            format: 32,
+            data: data.iter().flat_map(|value| value.to_ne_bytes()).collect(),
+        };
+        self.push(window, property.into(), change);
+    }
+
+    /// Delete a property.
+    ///
+    /// A later change of the same property of the same window replaces this one.
+    pub fn delete(&mut self, window: Window, property: impl Into<Atom>) {
+        self.push(window, property.into(), PropertyChange::Delete);
+    }
+
+    /// Check if the transaction contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn push(&mut self, window: Window, property: Atom, change: PropertyChange) {
+        let existing = self
+            .changes
+            .iter_mut()
+            .find(|(w, p, _)| (*w, *p) == (window, property));
+        match existing {
+            Some((_, _, existing)) => *existing = change,
+            None => self.changes.push((window, property, change)),
+        }
+    }
+
+    /// Send all changes while the server is grabbed.
+    ///
+    /// Returns the changes that the X11 server refused. This function waits for the X11 server to
+    /// process all requests.
+    pub fn commit<C: Connection + ?Sized>(
+        self,
+        conn: &C,
+    ) -> Result<Vec<FailedChange>, ConnectionError> {
+        let cookies = {
+            let _grab = GrabServerGuard::new(conn)?;
+            let mut cookies = Vec::with_capacity(self.changes.len());
+            for (window, property, change) in self.changes.iter() {
+                let cookie = match change {
+                    PropertyChange::Set {
+                        type_,
+                        format,
+                        data,
+                    } => {
+                        let length = data.len() / usize::from(format / 8);
+                        xproto::change_property(
+                            conn,
+                            xproto::PropMode::REPLACE,
+                            *window,
+                            *property,
+                            *type_,
+                            *format,
+                            length.try_into().unwrap_or(u32::MAX),
+                            data,
+                        )?
+                    }
+                    PropertyChange::Delete => xproto::delete_property(conn, *window, *property)?,
+                };
+                cookies.push((*window, *property, cookie));
+            }
+            cookies
+        };
+        let mut failed = Vec::new();
+        for (window, property, cookie) in cookies {
+            match cookie.check() {
+                Ok(()) => {}
+                Err(ReplyError::X11Error(error)) => failed.push(FailedChange {
+                    window,
+                    property,
+                    error,
+                }),
+                Err(ReplyError::ConnectionError(error)) => return Err(error),
+            }
+        }
+        Ok(failed)
+    }
+}
+
+// Client identification
+
+/// The properties that tell where the client of a window runs.
+///
+/// Session managers, task managers, and window managers use these properties to find the process
+/// behind a window, e.g. to kill a client that does not respond. Without them, a window looks
+/// anonymous. [`ClientProperties::set`] sets the same properties as Xlib's `XSetWMProperties`:
+///
+/// - `WM_CLIENT_MACHINE`, the name of the machine that the client runs on,
+/// - `WM_LOCALE_NAME`, the locale that the client uses for text properties, and
+/// - `_NET_WM_PID`, the process ID of the client. This is only meaningful together with
+///   `WM_CLIENT_MACHINE`, so it is only set if the host name is known.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::ClientProperties;
+/// use x11rb::protocol::xproto::Window;
+///
+/// fn setup_window(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+///     ClientProperties::from_environment().set(conn, window)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientProperties {
+    /// The host name for `WM_CLIENT_MACHINE`. Empty if it is not known.
+    pub client_machine: Vec<u8>,
+    /// The locale for `WM_LOCALE_NAME`, e.g. `en_US.UTF-8`.
+    pub locale_name: String,
+    /// The process ID for `_NET_WM_PID`.
+    pub pid: u32,
+}
+
+impl ClientProperties {
+    /// Get the properties of the current process.
+    ///
+    /// The host name comes from `gethostname()`. The locale is the first non-empty variable of
+    /// `$LC_ALL`, `$LC_CTYPE` and `$LANG`, or `C`, like the C library determines it.
+    pub fn from_environment() -> Self {
+        let hostname = gethostname::gethostname();
+        Self {
+            client_machine: hostname.to_string_lossy().into_owned().into_bytes(),
+            locale_name: locale_from_env(),
+            pid: std::process::id(),
+        }
+    }
+
+    /// Set these properties on a window.
+    ///
+    /// This should be done before the window is mapped. This function waits for the replies to the
+    /// `InternAtom` requests for `WM_LOCALE_NAME` and `_NET_WM_PID`.
+    pub fn set<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        window: Window,
+    ) -> Result<(), ReplyError> {
+        let net_wm_pid = xproto::intern_atom(conn, false, b"_NET_WM_PID")?;
+        let locale_name = xproto::intern_atom(conn, false, b"WM_LOCALE_NAME")?;
+        let _ = conn.change_property8(
+            xproto::PropMode::REPLACE,
+            window,
+            locale_name.reply()?.atom,
+            AtomEnum::STRING,
+            self.locale_name.as_bytes(),
+        )?;
+        if self.client_machine.is_empty() {
+            return Ok(());
+        }
+        let net_wm_pid = net_wm_pid.reply()?.atom;
+        let _ = conn.change_property8(
+            xproto::PropMode::REPLACE,
+            window,
+            AtomEnum::WM_CLIENT_MACHINE,
+            AtomEnum::STRING,
+            &self.client_machine,
+        )?;
+        let _ = conn.change_property32(
+            xproto::PropMode::REPLACE,
+            window,
+            net_wm_pid,
+            AtomEnum::CARDINAL,
+            &[self.pid],
+        )?;
+        Ok(())
+    }
+}
+
+/// Get the name of the current locale from the environment.
+pub(crate) fn locale_from_env() -> String {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| "C".to_string())
+}
+
+// Processes
+
+/// Get the ID of the process that created a window.
+///
+/// `hostname` is the name of the local machine, as returned by `gethostname()`. The process ID is
+/// only meaningful on the machine that the client runs on, so the `_NET_WM_PID` property is only
+/// trusted if the window's `WM_CLIENT_MACHINE` property is equal to `hostname`.
+///
+/// With the `res` feature, the X-Resource extension is asked for the process ID of the client
+/// that owns the window first. The X11 server knows this ID for local connections, so it cannot be
+/// faked by setting a wrong `_NET_WM_PID`. If the extension is not available or the server does
+/// not know the ID, this falls back to `_NET_WM_PID`.
+///
+/// Returns `None` if the process ID is not known.
+///
+/// This function waits for the replies to the necessary requests.
+pub fn window_pid<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    hostname: &[u8],
+) -> Result<Option<u32>, ReplyError> {
+    if let Some(pid) = query_client_pid(conn, window)? {
+        return Ok(Some(pid));
+    }
+    let property = xproto::intern_atom(conn, false, b"_NET_WM_PID")?;
+    let property = property.reply()?.atom;
+    let pid = xproto::get_property(conn, false, window, property, AtomEnum::CARDINAL, 0, 1)?;
+    let machine =
+        conn.get_property_full(false, window, AtomEnum::WM_CLIENT_MACHINE, AtomEnum::STRING)?;
+    let pid = pid.reply()?.value32().and_then(|mut iter| iter.next());
+    Ok(validate_pid(pid, &machine.value, hostname))
+}
+
+/// Only trust `_NET_WM_PID` if the client runs on the local machine.
+fn validate_pid(pid: Option<u32>, client_machine: &[u8], hostname: &[u8]) -> Option<u32> {
+    // Some clients include the terminating null byte
+    let client_machine = client_machine.strip_suffix(b"\0").unwrap_or(client_machine);
+    match pid {
+        Some(pid) if pid != 0 && !hostname.is_empty() && client_machine == hostname => Some(pid),
+        _ => None,
+    }
+}
+
+/// Get the process ID of the client that owns a window via the X-Resource extension.
+#[cfg(feature = "res")]
+fn query_client_pid<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<u32>, ReplyError> {
+    use crate::protocol::res;
+
+    if conn
+        .extension_information(res::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    // QueryClientIds was added in version 1.2
+    let version = res::query_version(conn, 1, 2)?.reply()?;
+    if (version.server_major, version.server_minor) < (1, 2) {
+        return Ok(None);
+    }
+    let mask = u32::from(u8::from(res::ClientIdMask::LOCAL_CLIENT_PID));
+    let spec = res::ClientIdSpec {
+        client: window,
+        mask,
+    };
+    let ids = match res::query_client_ids(conn, &[spec])?.reply() {
+        Ok(reply) => reply.ids,
+        // The window does not exist
+        Err(ReplyError::X11Error(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Ok(ids
+        .iter()
+        .filter(|id| id.spec.mask & mask != 0)
+        .find_map(|id| id.value.first().copied()))
+}
+
+/// Get the process ID of the client that owns a window via the X-Resource extension.
+#[cfg(not(feature = "res"))]
+fn query_client_pid<C: RequestConnection + ?Sized>(
+    _conn: &C,
+    _window: Window,
+) -> Result<Option<u32>, ReplyError> {
+    Ok(None)
+}
+
 #[cfg(test)]
 mod test {
     use std::convert::TryInto;
 
-    use super::{WmClass, WmHints, WmHintsState, WmSizeHints};
-    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity};
+    use super::{
+        encode_wm_class, latin1, parse_string_list, usable_area, validate_pid,
+        window_types_from_atoms, Strut, WindowType, WmClass, WmHints, WmHintsState, WmSizeHints,
+    };
+    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity, Rectangle};
     use crate::x11_utils::Serialize;
 
     fn get_property_reply(value: &[u8], format: u8, type_: impl Into<Atom>) -> GetPropertyReply {
@@ -716,6 +1990,131 @@ mod test {
         }
     }
 
+    #[cfg(feature = "testing")]
+    #[test]
+    fn client_properties() {
+        use super::ClientProperties;
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let mut properties = ClientProperties {
+            client_machine: b"host".to_vec(),
+            locale_name: "de_DE.UTF-8".to_string(),
+            pid: 1234,
+        };
+        for &expected in &[5, 3] {
+            let conn = FakeConnection::default();
+            for &atom in &[42u32, 43] {
+                let mut reply = vec![0; 32];
+                reply[8..12].copy_from_slice(&atom.to_ne_bytes());
+                conn.respond(xproto::INTERN_ATOM_REQUEST, None, Response::Reply(reply));
+            }
+            properties.set(&conn, 1).unwrap();
+            let requests = conn.take_sent_requests();
+            assert_eq!(requests.len(), expected);
+            assert_eq!(requests[2].bytes[8..12], 43u32.to_ne_bytes());
+            if expected == 5 {
+                // _NET_WM_PID with the interned atom
+                let pid = &requests[4].bytes;
+                assert_eq!(pid[8..12], 42u32.to_ne_bytes());
+                assert_eq!(pid[24..28], 1234u32.to_ne_bytes());
+            }
+            // Without a host name, the PID is meaningless
+            properties.client_machine.clear();
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn properties_bulk() {
+        use super::get_properties_bulk;
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let conn = FakeConnection::default();
+        let mut reply = vec![0; 36];
+        reply[1] = 8;
+        reply[8..12].copy_from_slice(&u32::from(AtomEnum::STRING).to_ne_bytes());
+        reply[16..20].copy_from_slice(&2u32.to_ne_bytes());
+        reply[32..34].copy_from_slice(b"ab");
+        let unset = Response::Reply(vec![0; 32]);
+        let bad_window = Response::Error {
+            error_code: xproto::WINDOW_ERROR,
+            bad_value: 2,
+        };
+        for response in [
+            Response::Reply(reply),
+            unset,
+            bad_window.clone(),
+            bad_window,
+        ] {
+            conn.respond(xproto::GET_PROPERTY_REQUEST, None, response);
+        }
+        let result = get_properties_bulk(&conn, &[1, 2], &[10, 11]).unwrap();
+        assert_eq!(conn.sent_requests().len(), 4);
+        // Only the property that is set on the window that exists is returned
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[&1].len(), 1);
+        assert_eq!(result[&1][&10].value, b"ab");
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn property_transaction() {
+        use super::PropertyTransaction;
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let conn = FakeConnection::default();
+        let mut transaction = PropertyTransaction::new();
+        transaction.set32(1, 10u32, AtomEnum::WINDOW, &[2, 3]);
+        transaction.set8(2, 11u32, AtomEnum::STRING, b"old");
+        transaction.delete(3, 12u32);
+        // Replaces the earlier change
+        transaction.set8(2, 11u32, AtomEnum::STRING, b"new");
+        conn.respond(
+            xproto::DELETE_PROPERTY_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::WINDOW_ERROR,
+                bad_value: 3,
+            },
+        );
+        let failed = transaction.commit(&conn).unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!((failed[0].window, failed[0].property), (3, 12));
+
+        let opcodes = conn
+            .sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            opcodes[..5],
+            [
+                xproto::GRAB_SERVER_REQUEST,
+                xproto::CHANGE_PROPERTY_REQUEST,
+                xproto::CHANGE_PROPERTY_REQUEST,
+                xproto::DELETE_PROPERTY_REQUEST,
+                xproto::UNGRAB_SERVER_REQUEST,
+            ]
+        );
+        assert!(conn.sent_requests()[2].bytes.ends_with(b"new\0"));
+    }
+
+    #[test]
+    fn wm_class_encoding() {
+        let value = encode_wm_class("xterm", "XTerm");
+        assert_eq!(value, b"xterm\0XTerm\0");
+        // Latin-1 instead of UTF-8, and no NUL inside of the names
+        let value = encode_wm_class("caf\u{e9}", "\u{263a}\0");
+        assert_eq!(value, b"caf\xe9\0??\0");
+        let wm_class =
+            WmClass::from_reply(get_property_reply(&value, 8, AtomEnum::STRING)).unwrap();
+        assert_eq!(latin1(wm_class.instance()), "caf\u{e9}");
+        assert_eq!(latin1(wm_class.class()), "??");
+    }
+
     #[test]
     fn test_wm_normal_hints() {
         // This is the value of some random xterm window.
@@ -800,4 +2199,105 @@ mod test {
 
         assert_eq!(input, wm_hints.serialize());
     }
+
+    #[test]
+    fn test_window_type_atom_names() {
+        for window_type in WindowType::ALL.iter() {
+            assert_eq!(
+                WindowType::from_atom_name(window_type.atom_name()),
+                Some(*window_type)
+            );
+        }
+        assert_eq!(WindowType::from_atom_name(b"_NET_WM_WINDOW_TYPE"), None);
+    }
+
+    #[test]
+    fn test_window_types_keep_order() {
+        let atoms = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23];
+        // 99 is an unknown type, e.g. _KDE_NET_WM_WINDOW_TYPE_OVERRIDE
+        let types = window_types_from_atoms(&atoms, vec![20, 99, 14, 23].into_iter());
+        assert_eq!(
+            types,
+            [
+                WindowType::Notification,
+                WindowType::Utility,
+                WindowType::Normal
+            ]
+        );
+    }
+
+    #[test]
+    fn test_desktop_names() {
+        let empty: [&str; 0] = [];
+        assert_eq!(parse_string_list(b""), empty);
+        assert_eq!(parse_string_list(b"\0"), [""]);
+        assert_eq!(parse_string_list(b"Web\0Mail\0"), ["Web", "Mail"]);
+        assert_eq!(parse_string_list(b"Web\0Mail"), ["Web", "Mail"]);
+        assert_eq!(
+            parse_string_list("Büro\0\0Spiele\0".as_bytes()),
+            ["Büro", "", "Spiele"]
+        );
+        assert_eq!(parse_string_list(b"a\xff\0"), ["a\u{fffd}"]);
+    }
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_strut_from_values() {
+        let strut = Strut::from_values(&[0, 0, 30, 0]).unwrap();
+        assert_eq!(strut.top, 30);
+        assert_eq!((strut.top_start_x, strut.top_end_x), (0, u32::MAX));
+        let strut = Strut::from_values(&[0, 0, 30, 0, 0, 0, 0, 0, 100, 199, 0, 0]).unwrap();
+        assert_eq!((strut.top_start_x, strut.top_end_x), (100, 199));
+        assert_eq!(Strut::from_values(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_usable_area() {
+        // Two monitors side by side, a panel at the top of the left one and a dock on the right
+        // edge of the right one
+        let left = rect(0, 0, 1920, 1080);
+        let right = rect(1920, 0, 1280, 1024);
+        let panel = Strut::from_values(&[0, 0, 30, 0, 0, 0, 0, 0, 0, 1919, 0, 0]).unwrap();
+        let dock = Strut::from_values(&[0, 64, 0, 0, 0, 0, 0, 1023, 0, 0, 0, 0]).unwrap();
+        let struts = [panel, dock];
+
+        assert_eq!(
+            usable_area(3200, 1080, left, &struts),
+            rect(0, 30, 1920, 1050)
+        );
+        assert_eq!(
+            usable_area(3200, 1080, right, &struts),
+            rect(1920, 0, 1216, 1024)
+        );
+        assert_eq!(usable_area(3200, 1080, left, &[]), left);
+    }
+
+    #[test]
+    fn test_usable_area_bottom_strut_on_shorter_monitor() {
+        // The bottom strut is relative to the root window, which is taller than the right monitor
+        let right = rect(1920, 0, 1280, 1024);
+        let bottom = Strut::from_values(&[0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 1920, 3199]).unwrap();
+        assert_eq!(
+            usable_area(3200, 1080, right, &[bottom]),
+            rect(1920, 0, 1280, 980)
+        );
+    }
+
+    #[test]
+    fn pid_is_only_trusted_locally() {
+        assert_eq!(validate_pid(Some(42), b"host", b"host"), Some(42));
+        assert_eq!(validate_pid(Some(42), b"host\0", b"host"), Some(42));
+        assert_eq!(validate_pid(Some(42), b"other", b"host"), None);
+        assert_eq!(validate_pid(Some(42), b"", b""), None);
+        assert_eq!(validate_pid(Some(0), b"host", b"host"), None);
+        assert_eq!(validate_pid(None, b"host", b"host"), None);
+    }
 }
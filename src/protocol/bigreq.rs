@@ -4,6 +4,8 @@
 //! Bindings to the `BigRequests` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -56,12 +58,51 @@ impl EnableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -77,6 +118,7 @@ impl EnableRequest {
 impl Request for EnableRequest {
     type Reply = EnableReply;
 }
+#[inline]
 pub fn enable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -104,7 +146,8 @@ impl TryParse for EnableReply {
         }
         let result = EnableReply { sequence, length, maximum_request_length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -112,6 +155,7 @@ impl TryParse for EnableReply {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[inline]
     fn bigreq_enable(&self) -> Result<Cookie<'_, Self, EnableReply>, ConnectionError>
     {
         enable(self)
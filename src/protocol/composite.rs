@@ -4,6 +4,8 @@
 //! Bindings to the `Composite` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -90,14 +92,27 @@ impl std::fmt::Debug for Redirect  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Redirect {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub client_major_version: u32,
     pub client_minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -129,12 +144,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -151,9 +215,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -165,6 +232,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -172,6 +240,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -187,7 +256,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -195,11 +265,13 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the RedirectWindow request
 pub const REDIRECT_WINDOW_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RedirectWindowRequest {
     pub window: xproto::Window,
     pub update: Redirect,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
 impl RedirectWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -231,12 +303,61 @@ impl RedirectWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        write(&[
+            major_opcode,
+            REDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -247,6 +368,8 @@ impl RedirectWindowRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (update, remaining) = u8::try_parse(remaining)?;
         let update = update.into();
+        #[cfg(feature = "strict_parsing")]
+        Redirect::check_known(update)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(RedirectWindowRequest {
@@ -255,9 +378,12 @@ impl RedirectWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
 impl Request for RedirectWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
+#[inline]
 pub fn redirect_window<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -271,11 +397,13 @@ where
 
 /// Opcode for the RedirectSubwindows request
 pub const REDIRECT_SUBWINDOWS_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RedirectSubwindowsRequest {
     pub window: xproto::Window,
     pub update: Redirect,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
 impl RedirectSubwindowsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -307,12 +435,61 @@ impl RedirectSubwindowsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        write(&[
+            major_opcode,
+            REDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -323,6 +500,8 @@ impl RedirectSubwindowsRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (update, remaining) = u8::try_parse(remaining)?;
         let update = update.into();
+        #[cfg(feature = "strict_parsing")]
+        Redirect::check_known(update)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(RedirectSubwindowsRequest {
@@ -331,9 +510,12 @@ impl RedirectSubwindowsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
 impl Request for RedirectSubwindowsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
+#[inline]
 pub fn redirect_subwindows<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -347,11 +529,13 @@ where
 
 /// Opcode for the UnredirectWindow request
 pub const UNREDIRECT_WINDOW_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnredirectWindowRequest {
     pub window: xproto::Window,
     pub update: Redirect,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
 impl UnredirectWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -383,12 +567,61 @@ impl UnredirectWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        write(&[
+            major_opcode,
+            UNREDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -399,6 +632,8 @@ impl UnredirectWindowRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (update, remaining) = u8::try_parse(remaining)?;
         let update = update.into();
+        #[cfg(feature = "strict_parsing")]
+        Redirect::check_known(update)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(UnredirectWindowRequest {
@@ -407,9 +642,12 @@ impl UnredirectWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
 impl Request for UnredirectWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
+#[inline]
 pub fn unredirect_window<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -423,11 +661,13 @@ where
 
 /// Opcode for the UnredirectSubwindows request
 pub const UNREDIRECT_SUBWINDOWS_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnredirectSubwindowsRequest {
     pub window: xproto::Window,
     pub update: Redirect,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
 impl UnredirectSubwindowsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -459,12 +699,61 @@ impl UnredirectSubwindowsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        write(&[
+            major_opcode,
+            UNREDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -475,6 +764,8 @@ impl UnredirectSubwindowsRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (update, remaining) = u8::try_parse(remaining)?;
         let update = update.into();
+        #[cfg(feature = "strict_parsing")]
+        Redirect::check_known(update)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(UnredirectSubwindowsRequest {
@@ -483,9 +774,12 @@ impl UnredirectSubwindowsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
 impl Request for UnredirectSubwindowsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
+#[inline]
 pub fn unredirect_subwindows<Conn>(conn: &Conn, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -499,11 +793,13 @@ where
 
 /// Opcode for the CreateRegionFromBorderClip request
 pub const CREATE_REGION_FROM_BORDER_CLIP_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateRegionFromBorderClipRequest {
     pub region: xfixes::Region,
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
 impl CreateRegionFromBorderClipRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -535,12 +831,61 @@ impl CreateRegionFromBorderClipRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize();
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            CREATE_REGION_FROM_BORDER_CLIP_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -557,9 +902,12 @@ impl CreateRegionFromBorderClipRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
 impl Request for CreateRegionFromBorderClipRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
+#[inline]
 pub fn create_region_from_border_clip<Conn>(conn: &Conn, region: xfixes::Region, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -573,11 +921,13 @@ where
 
 /// Opcode for the NameWindowPixmap request
 pub const NAME_WINDOW_PIXMAP_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NameWindowPixmapRequest {
     pub window: xproto::Window,
     pub pixmap: xproto::Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
 impl NameWindowPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -609,12 +959,61 @@ impl NameWindowPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        write(&[
+            major_opcode,
+            NAME_WINDOW_PIXMAP_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -631,9 +1030,12 @@ impl NameWindowPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
 impl Request for NameWindowPixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
+#[inline]
 pub fn name_window_pixmap<Conn>(conn: &Conn, window: xproto::Window, pixmap: xproto::Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -647,10 +1049,12 @@ where
 
 /// Opcode for the GetOverlayWindow request
 pub const GET_OVERLAY_WINDOW_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOverlayWindowRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 impl GetOverlayWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -677,12 +1081,56 @@ impl GetOverlayWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetOverlayWindowReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -697,9 +1145,12 @@ impl GetOverlayWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 impl Request for GetOverlayWindowRequest {
     type Reply = GetOverlayWindowReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
+#[inline]
 pub fn get_overlay_window<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetOverlayWindowReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -710,12 +1161,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOverlayWindowReply {
     pub sequence: u16,
     pub length: u32,
     pub overlay_win: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 impl TryParse for GetOverlayWindowReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -730,7 +1183,8 @@ impl TryParse for GetOverlayWindowReply {
         }
         let result = GetOverlayWindowReply { sequence, length, overlay_win };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -738,10 +1192,12 @@ impl TryParse for GetOverlayWindowReply {
 
 /// Opcode for the ReleaseOverlayWindow request
 pub const RELEASE_OVERLAY_WINDOW_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReleaseOverlayWindowRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
 impl ReleaseOverlayWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -768,12 +1224,56 @@ impl ReleaseOverlayWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            RELEASE_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -788,9 +1288,12 @@ impl ReleaseOverlayWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
 impl Request for ReleaseOverlayWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
+#[inline]
 pub fn release_overlay_window<Conn>(conn: &Conn, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -803,38 +1306,56 @@ where
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
+    #[inline]
     fn composite_query_version(&self, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, client_major_version, client_minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
+    #[inline]
     fn composite_redirect_window(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         redirect_window(self, window, update)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
+    #[inline]
     fn composite_redirect_subwindows(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         redirect_subwindows(self, window, update)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
+    #[inline]
     fn composite_unredirect_window(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         unredirect_window(self, window, update)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
+    #[inline]
     fn composite_unredirect_subwindows(&self, window: xproto::Window, update: Redirect) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         unredirect_subwindows(self, window, update)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
+    #[inline]
     fn composite_create_region_from_border_clip(&self, region: xfixes::Region, window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_region_from_border_clip(self, region, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
+    #[inline]
     fn composite_name_window_pixmap(&self, window: xproto::Window, pixmap: xproto::Pixmap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         name_window_pixmap(self, window, pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
+    #[inline]
     fn composite_get_overlay_window(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetOverlayWindowReply>, ConnectionError>
     {
         get_overlay_window(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
+    #[inline]
     fn composite_release_overlay_window(&self, window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         release_overlay_window(self, window)
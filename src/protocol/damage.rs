@@ -4,6 +4,8 @@
 //! Bindings to the `Damage` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -96,17 +98,30 @@ impl std::fmt::Debug for ReportLevel  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ReportLevel {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the BadDamage error
 pub const BAD_DAMAGE_ERROR: u8 = 0;
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub client_major_version: u32,
     pub client_minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -138,12 +153,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -160,9 +224,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -174,6 +241,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -181,6 +249,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -196,7 +265,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -204,12 +274,14 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the Create request
 pub const CREATE_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateRequest {
     pub damage: Damage,
     pub drawable: xproto::Drawable,
     pub level: ReportLevel,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
 impl CreateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -246,12 +318,66 @@ impl CreateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let level_bytes = u8::from(self.level).serialize();
+        write(&[
+            major_opcode,
+            CREATE_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            level_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -263,6 +389,8 @@ impl CreateRequest {
         let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
         let (level, remaining) = u8::try_parse(remaining)?;
         let level = level.into();
+        #[cfg(feature = "strict_parsing")]
+        ReportLevel::check_known(level)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(CreateRequest {
@@ -272,9 +400,12 @@ impl CreateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
 impl Request for CreateRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
+#[inline]
 pub fn create<Conn>(conn: &Conn, damage: Damage, drawable: xproto::Drawable, level: ReportLevel) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -289,10 +420,12 @@ where
 
 /// Opcode for the Destroy request
 pub const DESTROY_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyRequest {
     pub damage: Damage,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
 impl DestroyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -319,12 +452,56 @@ impl DestroyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -339,9 +516,12 @@ impl DestroyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
 impl Request for DestroyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
+#[inline]
 pub fn destroy<Conn>(conn: &Conn, damage: Damage) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -354,12 +534,14 @@ where
 
 /// Opcode for the Subtract request
 pub const SUBTRACT_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SubtractRequest {
     pub damage: Damage,
     pub repair: xfixes::Region,
     pub parts: xfixes::Region,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
 impl SubtractRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -396,12 +578,66 @@ impl SubtractRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize();
+        let repair_bytes = self.repair.serialize();
+        let parts_bytes = self.parts.serialize();
+        write(&[
+            major_opcode,
+            SUBTRACT_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            repair_bytes[0],
+            repair_bytes[1],
+            repair_bytes[2],
+            repair_bytes[3],
+            parts_bytes[0],
+            parts_bytes[1],
+            parts_bytes[2],
+            parts_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -420,9 +656,12 @@ impl SubtractRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
 impl Request for SubtractRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
+#[inline]
 pub fn subtract<Conn, A, B>(conn: &Conn, damage: Damage, repair: A, parts: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -441,11 +680,13 @@ where
 
 /// Opcode for the Add request
 pub const ADD_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AddRequest {
     pub drawable: xproto::Drawable,
     pub region: xfixes::Region,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
 impl AddRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -477,12 +718,61 @@ impl AddRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let region_bytes = self.region.serialize();
+        write(&[
+            major_opcode,
+            ADD_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -499,9 +789,12 @@ impl AddRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
 impl Request for AddRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
+#[inline]
 pub fn add<Conn>(conn: &Conn, drawable: xproto::Drawable, region: xfixes::Region) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -538,6 +831,8 @@ impl TryParse for NotifyEvent {
         let (area, remaining) = xproto::Rectangle::try_parse(remaining)?;
         let (geometry, remaining) = xproto::Rectangle::try_parse(remaining)?;
         let level = level.into();
+        #[cfg(feature = "strict_parsing")]
+        ReportLevel::check_known(level)?;
         let result = NotifyEvent { response_type, level, sequence, drawable, damage, timestamp, area, geometry };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -597,20 +892,96 @@ impl From<NotifyEvent> for [u8; 32] {
     }
 }
 
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Damage`].
+///
+/// Instances of this struct represent a `Damage` that is freed in `Drop` with [`destroy`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct DamageWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Damage);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> DamageWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_damage(conn: &'c C, id: Damage) -> Self {
+        DamageWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn damage(&self) -> Damage {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_damage(self) -> Damage {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> DamageWrapper<'c, C> {
+    /// Create a new `Damage` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_and_get_cookie(conn: &'c C, drawable: xproto::Drawable, level: ReportLevel) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let damage = conn.generate_id()?;
+        let cookie = create(conn, damage, drawable, level)?;
+        Ok((Self::for_damage(conn, damage), cookie))
+    }
+
+    /// Create a new `Damage` and return a wrapper.
+    ///
+    /// Like [`Self::create_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create(conn: &'c C, drawable: xproto::Drawable, level: ReportLevel) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_and_get_cookie(conn, drawable, level)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&DamageWrapper<'_, C>> for Damage {
+    fn from(from: &DamageWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for DamageWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = destroy(self.0, self.1);
+    }
+}
+
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
+    #[inline]
     fn damage_query_version(&self, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, client_major_version, client_minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
+    #[inline]
     fn damage_create(&self, damage: Damage, drawable: xproto::Drawable, level: ReportLevel) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create(self, damage, drawable, level)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
+    #[inline]
     fn damage_destroy(&self, damage: Damage) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy(self, damage)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
+    #[inline]
     fn damage_subtract<A, B>(&self, damage: Damage, repair: A, parts: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xfixes::Region>,
@@ -618,6 +989,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         subtract(self, damage, repair, parts)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
+    #[inline]
     fn damage_add(&self, drawable: xproto::Drawable, region: xfixes::Region) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         add(self, drawable, region)
@@ -0,0 +1,664 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Documentation of requests, replies and events for `crate::protocol_docs`.
+
+use crate::protocol_docs::{Documentation, ErrorDoc, FieldDoc};
+
+/// The documentation of all requests that are known to this build of x11rb.
+pub static REQUESTS: &[Documentation] = &[
+    Documentation {
+        module: "xproto",
+        name: "CreateWindow",
+        brief: Some("Creates a window"),
+        description: Some("Creates an unmapped window as child of the specified `parent` window. A CreateNotify event will be generated. The new window is placed on top in the stacking order with respect to siblings.\n\nThe coordinate system has the X axis horizontal and the Y axis vertical with the origin [0, 0] at the upper-left corner. Coordinates are integral, in terms of pixels, and coincide with pixel centers. Each window and pixmap has its own coordinate system. For a window, the origin is inside the border at the inside, upper-left corner.\n\nThe created window is not yet displayed (mapped), call `xcb_map_window` to display it.\n\nThe created window will initially use the same cursor as its parent."),
+        fields: &[FieldDoc { name: "wid", doc: Some("The ID with which you will refer to the new window, created by `xcb_generate_id`.") }, FieldDoc { name: "depth", doc: Some("Specifies the new window's depth (TODO: what unit?).\n\nThe special value `XCB_COPY_FROM_PARENT` means the depth is taken from the `parent` window.") }, FieldDoc { name: "visual", doc: Some("Specifies the id for the new window's visual.\n\nThe special value `XCB_COPY_FROM_PARENT` means the visual is taken from the `parent` window.") }, FieldDoc { name: "class", doc: None }, FieldDoc { name: "parent", doc: Some("The parent window of the new window.") }, FieldDoc { name: "border_width", doc: Some("TODO:\n\nMust be zero if the `class` is `InputOnly` or a `xcb_match_error_t` occurs.") }, FieldDoc { name: "x", doc: Some("The X coordinate of the new window.") }, FieldDoc { name: "y", doc: Some("The Y coordinate of the new window.") }, FieldDoc { name: "width", doc: Some("The width of the new window.") }, FieldDoc { name: "height", doc: Some("The height of the new window.") }],
+        errors: &[ErrorDoc { error: "Colormap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Cursor", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Pixmap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Alloc", doc: Some("The X server could not allocate the requested resources (no memory?).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ChangeWindowAttributes",
+        brief: Some("change window attributes"),
+        description: Some("Changes the attributes specified by `value_mask` for the specified `window`."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to change.") }, FieldDoc { name: "value_mask", doc: None }, FieldDoc { name: "value_list", doc: Some("Values for each of the attributes specified in the bitmask `value_mask`. The order has to correspond to the order of possible `value_mask` bits. See the example.") }],
+        errors: &[ErrorDoc { error: "Access", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Colormap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Cursor", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Pixmap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetWindowAttributes",
+        brief: Some("Gets window attributes"),
+        description: Some("Gets the current attributes for the specified `window`."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to get the attributes from.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }, ErrorDoc { error: "Drawable", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "DestroyWindow",
+        brief: Some("Destroys a window"),
+        description: Some("Destroys the specified window and all of its subwindows. A DestroyNotify event is generated for each destroyed window (a DestroyNotify event is first generated for any given window's inferiors). If the window was mapped, it will be automatically unmapped before destroying.\n\nCalling DestroyWindow on the root window will do nothing."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to destroy.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified window does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ChangeSaveSet",
+        brief: Some("Changes a client's save set"),
+        description: Some("TODO: explain what the save set is for.\n\nThis function either adds or removes the specified window to the client's (your application's) save set."),
+        fields: &[FieldDoc { name: "mode", doc: Some("Insert to add the specified window to the save set or Delete to delete it from the save set.") }, FieldDoc { name: "window", doc: Some("The window to add or delete to/from your save set.") }],
+        errors: &[ErrorDoc { error: "Match", doc: Some("You created the specified window. This does not make sense, you can only add windows created by other clients to your save set.") }, ErrorDoc { error: "Value", doc: Some("You specified an invalid mode.") }, ErrorDoc { error: "Window", doc: Some("The specified window does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ReparentWindow",
+        brief: Some("Reparents a window"),
+        description: Some("Makes the specified window a child of the specified parent window. If the window is mapped, it will automatically be unmapped before reparenting and re-mapped after reparenting. The window is placed in the stacking order on top with respect to sibling windows.\n\nAfter reparenting, a ReparentNotify event is generated."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to reparent.") }, FieldDoc { name: "parent", doc: Some("The new parent of the window.") }, FieldDoc { name: "x", doc: Some("The X position of the window within its new parent.") }, FieldDoc { name: "y", doc: Some("The Y position of the window within its new parent.") }],
+        errors: &[ErrorDoc { error: "Match", doc: Some("The new parent window is not on the same screen as the old parent window.\n\nThe new parent window is the specified window or an inferior of the specified window.\n\nThe new parent is InputOnly and the window is not.\n\nThe specified window has a ParentRelative background and the new parent window is not the same depth as the specified window.") }, ErrorDoc { error: "Window", doc: Some("The specified window does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "MapWindow",
+        brief: Some("Makes a window visible"),
+        description: Some("Maps the specified window. This means making the window visible (as long as its parent is visible).\n\nThis MapWindow request will be translated to a MapRequest request if a window manager is running. The window manager then decides to either map the window or not. Set the override-redirect window attribute to true if you want to bypass this mechanism.\n\nIf the window manager decides to map the window (or if no window manager is running), a MapNotify event is generated.\n\nIf the window becomes viewable and no earlier contents for it are remembered, the X server tiles the window with its background. If the window's background is undefined, the existing screen contents are not altered, and the X server generates zero or more Expose events.\n\nIf the window type is InputOutput, an Expose event will be generated when the window becomes visible. The normal response to an Expose event should be to repaint the window."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to make visible.") }],
+        errors: &[ErrorDoc { error: "Match", doc: Some("The specified window does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "UnmapWindow",
+        brief: Some("Makes a window invisible"),
+        description: Some("Unmaps the specified window. This means making the window invisible (and all its child windows).\n\nUnmapping a window leads to the `UnmapNotify` event being generated. Also, `Expose` events are generated for formerly obscured windows."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to make invisible.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified window does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ConfigureWindow",
+        brief: Some("Configures window attributes"),
+        description: Some("Configures a window's size, position, border width and stacking order."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window to configure.") }, FieldDoc { name: "value_mask", doc: Some("Bitmask of attributes to change.") }, FieldDoc { name: "value_list", doc: Some("New values, corresponding to the attributes in value_mask. The order has to correspond to the order of possible `value_mask` bits. See the example.") }],
+        errors: &[ErrorDoc { error: "Match", doc: Some("You specified a Sibling without also specifying StackMode or the window is not actually a Sibling.") }, ErrorDoc { error: "Window", doc: Some("The specified window does not exist. TODO: any other reason?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CirculateWindow",
+        brief: Some("Change window stacking order"),
+        description: Some("If `direction` is `XCB_CIRCULATE_RAISE_LOWEST`, the lowest mapped child (if any) will be raised to the top of the stack.\n\nIf `direction` is `XCB_CIRCULATE_LOWER_HIGHEST`, the highest mapped child will be lowered to the bottom of the stack."),
+        fields: &[FieldDoc { name: "direction", doc: None }, FieldDoc { name: "window", doc: Some("The window to raise/lower (depending on `direction`).") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }, ErrorDoc { error: "Value", doc: Some("The specified `direction` is invalid.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetGeometry",
+        brief: Some("Get current window geometry"),
+        description: Some("Gets the current geometry of the specified drawable (either `Window` or `Pixmap`)."),
+        fields: &[FieldDoc { name: "drawable", doc: Some("The drawable (`Window` or `Pixmap`) of which the geometry will be received.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryTree",
+        brief: Some("query the window tree"),
+        description: Some("Gets the root window ID, parent window ID and list of children windows for the specified `window`. The children are listed in bottom-to-top stacking order."),
+        fields: &[FieldDoc { name: "window", doc: Some("The `window` to query.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "InternAtom",
+        brief: Some("Get atom identifier by name"),
+        description: Some("Retrieves the identifier (xcb_atom_t TODO) for the atom with the specified name. Atoms are used in protocols like EWMH, for example to store window titles (`_NET_WM_NAME` atom) as property of a window.\n\nIf `only_if_exists` is 0, the atom will be created if it does not already exist. If `only_if_exists` is 1, `XCB_ATOM_NONE` will be returned if the atom does not yet exist."),
+        fields: &[FieldDoc { name: "name_len", doc: Some("The length of the following `name`.") }, FieldDoc { name: "name", doc: Some("The name of the atom.") }, FieldDoc { name: "only_if_exists", doc: Some("Return a valid atom id only if the atom already exists.") }],
+        errors: &[ErrorDoc { error: "Alloc", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("A value other than 0 or 1 was specified for `only_if_exists`.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ChangeProperty",
+        brief: Some("Changes a window property"),
+        description: Some("Sets or updates a property on the specified `window`. Properties are for example the window title (`WM_NAME`) or its minimum size (`WM_NORMAL_HINTS`). Protocols such as EWMH also use properties - for example EWMH defines the window title, encoded as UTF-8 string, in the `_NET_WM_NAME` property."),
+        fields: &[FieldDoc { name: "window", doc: Some("The window whose property you want to change.") }, FieldDoc { name: "mode", doc: None }, FieldDoc { name: "property", doc: Some("The property you want to change (an atom).") }, FieldDoc { name: "type", doc: Some("The type of the property you want to change (an atom).") }, FieldDoc { name: "format", doc: Some("Specifies whether the data should be viewed as a list of 8-bit, 16-bit or 32-bit quantities. Possible values are 8, 16 and 32. This information allows the X server to correctly perform byte-swap operations as necessary.") }, FieldDoc { name: "data_len", doc: Some("Specifies the number of elements (see `format`).") }, FieldDoc { name: "data", doc: Some("The property data.") }],
+        errors: &[ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }, ErrorDoc { error: "Atom", doc: Some("`property` or `type` do not refer to a valid atom.") }, ErrorDoc { error: "Alloc", doc: Some("The X server could not store the property (no memory?).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetProperty",
+        brief: Some("Gets a window property"),
+        description: Some("Gets the specified `property` from the specified `window`. Properties are for example the window title (`WM_NAME`) or its minimum size (`WM_NORMAL_HINTS`). Protocols such as EWMH also use properties - for example EWMH defines the window title, encoded as UTF-8 string, in the `_NET_WM_NAME` property.\n\nTODO: talk about `type`\n\nTODO: talk about `delete`\n\nTODO: talk about the offset/length thing. what's a valid use case?"),
+        fields: &[FieldDoc { name: "window", doc: Some("The window whose property you want to get.") }, FieldDoc { name: "delete", doc: Some("Whether the property should actually be deleted. For deleting a property, the specified `type` has to match the actual property type.") }, FieldDoc { name: "property", doc: Some("The property you want to get (an atom).") }, FieldDoc { name: "type", doc: Some("The type of the property you want to get (an atom).") }, FieldDoc { name: "long_offset", doc: Some("Specifies the offset (in 32-bit multiples) in the specified property where the data is to be retrieved.") }, FieldDoc { name: "long_length", doc: Some("Specifies how many 32-bit multiples of data should be retrieved (e.g. if you set `long_length` to 4, you will receive 16 bytes of data).") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }, ErrorDoc { error: "Atom", doc: Some("`property` or `type` do not refer to a valid atom.") }, ErrorDoc { error: "Value", doc: Some("The specified `long_offset` is beyond the actual property length (e.g. the property has a length of 3 bytes and you are setting `long_offset` to 1, resulting in a byte offset of 4).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "SetSelectionOwner",
+        brief: Some("Sets the owner of a selection"),
+        description: Some("Makes `window` the owner of the selection `selection` and updates the last-change time of the specified selection.\n\nTODO: briefly explain what a selection is."),
+        fields: &[FieldDoc { name: "selection", doc: Some("The selection.") }, FieldDoc { name: "owner", doc: Some("The new owner of the selection.\n\nThe special value `XCB_NONE` means that the selection will have no owner.") }, FieldDoc { name: "time", doc: Some("Timestamp to avoid race conditions when running X over the network.\n\nThe selection will not be changed if `time` is earlier than the current last-change time of the `selection` or is later than the current X server time. Otherwise, the last-change time is set to the specified time.\n\nThe special value `XCB_CURRENT_TIME` will be replaced with the current server time.") }],
+        errors: &[ErrorDoc { error: "Atom", doc: Some("`selection` does not refer to a valid atom.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetSelectionOwner",
+        brief: Some("Gets the owner of a selection"),
+        description: Some("Gets the owner of the specified selection.\n\nTODO: briefly explain what a selection is."),
+        fields: &[FieldDoc { name: "selection", doc: Some("The selection.") }],
+        errors: &[ErrorDoc { error: "Atom", doc: Some("`selection` does not refer to a valid atom.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "SendEvent",
+        brief: Some("send an event"),
+        description: Some("Identifies the `destination` window, determines which clients should receive the specified event and ignores any active grabs.\n\nThe `event` must be one of the core events or an event defined by an extension, so that the X server can correctly byte-swap the contents as necessary. The contents of `event` are otherwise unaltered and unchecked except for the `send_event` field which is forced to 'true'."),
+        fields: &[FieldDoc { name: "destination", doc: Some("The window to send this event to. Every client which selects any event within `event_mask` on `destination` will get the event.\n\nThe special value `XCB_SEND_EVENT_DEST_POINTER_WINDOW` refers to the window that contains the mouse pointer.\n\nThe special value `XCB_SEND_EVENT_DEST_ITEM_FOCUS` refers to the window which has the keyboard focus.") }, FieldDoc { name: "event_mask", doc: Some("Event_mask for determining which clients should receive the specified event. See `destination` and `propagate`.") }, FieldDoc { name: "propagate", doc: Some("If `propagate` is true and no clients have selected any event on `destination`, the destination is replaced with the closest ancestor of `destination` for which some client has selected a type in `event_mask` and for which no intervening window has that type in its do-not-propagate-mask. If no such window exists or if the window is an ancestor of the focus window and `InputFocus` was originally specified as the destination, the event is not sent to any clients. Otherwise, the event is reported to every client selecting on the final destination any of the types specified in `event_mask`.") }, FieldDoc { name: "event", doc: Some("The event to send to the specified `destination`.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `destination` window does not exist.") }, ErrorDoc { error: "Value", doc: Some("The given `event` is neither a core event nor an event defined by an extension.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GrabPointer",
+        brief: Some("Grab the pointer"),
+        description: Some("Actively grabs control of the pointer. Further pointer events are reported only to the grabbing client. Overrides any active pointer grab by this client."),
+        fields: &[FieldDoc { name: "event_mask", doc: Some("Specifies which pointer events are reported to the client.\n\nTODO: which values?") }, FieldDoc { name: "confine_to", doc: Some("Specifies the window to confine the pointer in (the user will not be able to move the pointer out of that window).\n\nThe special value `XCB_NONE` means don't confine the pointer.") }, FieldDoc { name: "cursor", doc: Some("Specifies the cursor that should be displayed or `XCB_NONE` to not change the cursor.") }, FieldDoc { name: "owner_events", doc: Some("If 1, the `grab_window` will still get the pointer events. If 0, events are not reported to the `grab_window`.") }, FieldDoc { name: "grab_window", doc: Some("Specifies the window on which the pointer should be grabbed.") }, FieldDoc { name: "time", doc: Some("The time argument allows you to avoid certain circumstances that come up if applications take a long time to respond or if there are long network delays. Consider a situation where you have two applications, both of which normally grab the pointer when clicked on. If both applications specify the timestamp from the event, the second application may wake up faster and successfully grab the pointer before the first application. The first application then will get an indication that the other application grabbed the pointer before its request was processed.\n\nThe special value `XCB_CURRENT_TIME` will be replaced with the current server time.") }, FieldDoc { name: "pointer_mode", doc: None }, FieldDoc { name: "keyboard_mode", doc: None }],
+        errors: &[ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "UngrabPointer",
+        brief: Some("release the pointer"),
+        description: Some("Releases the pointer and any queued events if you actively grabbed the pointer before using `xcb_grab_pointer`, `xcb_grab_button` or within a normal button press.\n\nEnterNotify and LeaveNotify events are generated."),
+        fields: &[FieldDoc { name: "time", doc: Some("Timestamp to avoid race conditions when running X over the network.\n\nThe pointer will not be released if `time` is earlier than the last-pointer-grab time or later than the current X server time.") }, FieldDoc { name: "name_len", doc: Some("Length (in bytes) of `name`.") }, FieldDoc { name: "name", doc: Some("A pattern describing an X core font.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GrabButton",
+        brief: Some("Grab pointer button(s)"),
+        description: Some("This request establishes a passive grab. The pointer is actively grabbed as described in GrabPointer, the last-pointer-grab time is set to the time at which the button was pressed (as transmitted in the ButtonPress event), and the ButtonPress event is reported if all of the following conditions are true:\n\nThe pointer is not grabbed and the specified button is logically pressed when the specified modifier keys are logically down, and no other buttons or modifier keys are logically down.\n\nThe grab-window contains the pointer.\n\nThe confine-to window (if any) is viewable.\n\nA passive grab on the same button/key combination does not exist on any ancestor of grab-window.\n\nThe interpretation of the remaining arguments is the same as for GrabPointer. The active grab is terminated automatically when the logical state of the pointer has all buttons released, independent of the logical state of modifier keys. Note that the logical state of a device (as seen by means of the protocol) may lag the physical state if device event processing is frozen. This request overrides all previous passive grabs by the same client on the same button/key combinations on the same window. A modifier of AnyModifier is equivalent to issuing the request for all possible modifier combinations (including the combination of no modifiers). It is not required that all specified modifiers have currently assigned keycodes. A button of AnyButton is equivalent to issuing the request for all possible buttons. Otherwise, it is not required that the button specified currently be assigned to a physical button.\n\nAn Access error is generated if some other client has already issued a GrabButton request with the same button/key combination on the same window. When using AnyModifier or AnyButton, the request fails completely (no grabs are established), and an Access error is generated if there is a conflicting grab for any combination. The request has no effect on an active grab."),
+        fields: &[FieldDoc { name: "owner_events", doc: Some("If 1, the `grab_window` will still get the pointer events. If 0, events are not reported to the `grab_window`.") }, FieldDoc { name: "grab_window", doc: Some("Specifies the window on which the pointer should be grabbed.") }, FieldDoc { name: "event_mask", doc: Some("Specifies which pointer events are reported to the client.\n\nTODO: which values?") }, FieldDoc { name: "confine_to", doc: Some("Specifies the window to confine the pointer in (the user will not be able to move the pointer out of that window).\n\nThe special value `XCB_NONE` means don't confine the pointer.") }, FieldDoc { name: "cursor", doc: Some("Specifies the cursor that should be displayed or `XCB_NONE` to not change the cursor.") }, FieldDoc { name: "modifiers", doc: Some("The modifiers to grab.\n\nUsing the special value `XCB_MOD_MASK_ANY` means grab the pointer with all possible modifier combinations.") }, FieldDoc { name: "pointer_mode", doc: None }, FieldDoc { name: "keyboard_mode", doc: None }, FieldDoc { name: "button", doc: None }],
+        errors: &[ErrorDoc { error: "Access", doc: Some("Another client has already issued a GrabButton with the same button/key combination on the same window.") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Cursor", doc: Some("The specified `cursor` does not exist.") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GrabKeyboard",
+        brief: Some("Grab the keyboard"),
+        description: Some("Actively grabs control of the keyboard and generates FocusIn and FocusOut events. Further key events are reported only to the grabbing client.\n\nAny active keyboard grab by this client is overridden. If the keyboard is actively grabbed by some other client, `AlreadyGrabbed` is returned. If `grab_window` is not viewable, `GrabNotViewable` is returned. If the keyboard is frozen by an active grab of another client, `GrabFrozen` is returned. If the specified `time` is earlier than the last-keyboard-grab time or later than the current X server time, `GrabInvalidTime` is returned. Otherwise, the last-keyboard-grab time is set to the specified time."),
+        fields: &[FieldDoc { name: "owner_events", doc: Some("If 1, the `grab_window` will still get the pointer events. If 0, events are not reported to the `grab_window`.") }, FieldDoc { name: "grab_window", doc: Some("Specifies the window on which the pointer should be grabbed.") }, FieldDoc { name: "time", doc: Some("Timestamp to avoid race conditions when running X over the network.\n\nThe special value `XCB_CURRENT_TIME` will be replaced with the current server time.") }, FieldDoc { name: "pointer_mode", doc: None }, FieldDoc { name: "keyboard_mode", doc: None }],
+        errors: &[ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GrabKey",
+        brief: Some("Grab keyboard key(s)"),
+        description: Some("Establishes a passive grab on the keyboard. In the future, the keyboard is actively grabbed (as for `GrabKeyboard`), the last-keyboard-grab time is set to the time at which the key was pressed (as transmitted in the KeyPress event), and the KeyPress event is reported if all of the following conditions are true:\n\nThe keyboard is not grabbed and the specified key (which can itself be a modifier key) is logically pressed when the specified modifier keys are logically down, and no other modifier keys are logically down.\n\nEither the grab_window is an ancestor of (or is) the focus window, or the grab_window is a descendant of the focus window and contains the pointer.\n\nA passive grab on the same key combination does not exist on any ancestor of grab_window.\n\nThe interpretation of the remaining arguments is as for XGrabKeyboard.  The active grab is terminated automatically when the logical state of the keyboard has the specified key released (independent of the logical state of the modifier keys), at which point a KeyRelease event is reported to the grabbing window.\n\nNote that the logical state of a device (as seen by client applications) may lag the physical state if device event processing is frozen.\n\nA modifiers argument of AnyModifier is equivalent to issuing the request for all possible modifier combinations (including the combination of no modifiers).  It is not required that all modifiers specified have currently assigned KeyCodes.  A keycode argument of AnyKey is equivalent to issuing the request for all possible KeyCodes.  Otherwise, the specified keycode must be in the range specified by min_keycode and max_keycode in the connection setup, or a BadValue error results.\n\nIf some other client has issued a XGrabKey with the same key combination on the same window, a BadAccess error results.  When using AnyModifier or AnyKey, the request fails completely, and a BadAccess error results (no grabs are established) if there is a conflicting grab for any combination."),
+        fields: &[FieldDoc { name: "owner_events", doc: Some("If 1, the `grab_window` will still get the pointer events. If 0, events are not reported to the `grab_window`.") }, FieldDoc { name: "grab_window", doc: Some("Specifies the window on which the pointer should be grabbed.") }, FieldDoc { name: "key", doc: Some("The keycode of the key to grab.\n\nThe special value `XCB_GRAB_ANY` means grab any key.") }, FieldDoc { name: "cursor", doc: Some("Specifies the cursor that should be displayed or `XCB_NONE` to not change the cursor.") }, FieldDoc { name: "modifiers", doc: Some("The modifiers to grab.\n\nUsing the special value `XCB_MOD_MASK_ANY` means grab the pointer with all possible modifier combinations.") }, FieldDoc { name: "pointer_mode", doc: None }, FieldDoc { name: "keyboard_mode", doc: None }],
+        errors: &[ErrorDoc { error: "Access", doc: Some("Another client has already issued a GrabKey with the same button/key combination on the same window.") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "UngrabKey",
+        brief: Some("release a key combination"),
+        description: Some("Releases the key combination on `grab_window` if you grabbed it using `xcb_grab_key` before."),
+        fields: &[FieldDoc { name: "key", doc: Some("The keycode of the specified key combination.\n\nUsing the special value `XCB_GRAB_ANY` means releasing all possible key codes.") }, FieldDoc { name: "grab_window", doc: Some("The window on which the grabbed key combination will be released.") }, FieldDoc { name: "modifiers", doc: Some("The modifiers of the specified key combination.\n\nUsing the special value `XCB_MOD_MASK_ANY` means releasing the key combination with every possible modifier combination.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `grab_window` does not exist.") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "AllowEvents",
+        brief: Some("release queued events"),
+        description: Some("Releases queued events if the client has caused a device (pointer/keyboard) to freeze due to grabbing it actively. This request has no effect if `time` is earlier than the last-grab time of the most recent active grab for this client or if `time` is later than the current X server time."),
+        fields: &[FieldDoc { name: "mode", doc: None }, FieldDoc { name: "time", doc: Some("Timestamp to avoid race conditions when running X over the network.\n\nThe special value `XCB_CURRENT_TIME` will be replaced with the current server time.") }],
+        errors: &[ErrorDoc { error: "Value", doc: Some("You specified an invalid `mode`.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryPointer",
+        brief: Some("get pointer coordinates"),
+        description: Some("Gets the root window the pointer is logically on and the pointer coordinates relative to the root window's origin."),
+        fields: &[FieldDoc { name: "window", doc: Some("A window to check if the pointer is on the same screen as `window` (see the `same_screen` field in the reply).") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `window` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "WarpPointer",
+        brief: Some("move mouse pointer"),
+        description: Some("Moves the mouse pointer to the specified position.\n\nIf `src_window` is not `XCB_NONE` (TODO), the move will only take place if the pointer is inside `src_window` and within the rectangle specified by (`src_x`, `src_y`, `src_width`, `src_height`). The rectangle coordinates are relative to `src_window`.\n\nIf `dst_window` is not `XCB_NONE` (TODO), the pointer will be moved to the offsets (`dst_x`, `dst_y`) relative to `dst_window`. If `dst_window` is `XCB_NONE` (TODO), the pointer will be moved by the offsets (`dst_x`, `dst_y`) relative to the current position of the pointer."),
+        fields: &[FieldDoc { name: "src_window", doc: Some("If `src_window` is not `XCB_NONE` (TODO), the move will only take place if the pointer is inside `src_window` and within the rectangle specified by (`src_x`, `src_y`, `src_width`, `src_height`). The rectangle coordinates are relative to `src_window`.") }, FieldDoc { name: "dst_window", doc: Some("If `dst_window` is not `XCB_NONE` (TODO), the pointer will be moved to the offsets (`dst_x`, `dst_y`) relative to `dst_window`. If `dst_window` is `XCB_NONE` (TODO), the pointer will be moved by the offsets (`dst_x`, `dst_y`) relative to the current position of the pointer.") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "SetInputFocus",
+        brief: Some("Sets input focus"),
+        description: Some("Changes the input focus and the last-focus-change time. If the specified `time` is earlier than the current last-focus-change time, the request is ignored (to avoid race conditions when running X over the network).\n\nA FocusIn and FocusOut event is generated when focus is changed."),
+        fields: &[FieldDoc { name: "focus", doc: Some("The window to focus. All keyboard events will be reported to this window. The window must be viewable (TODO), or a `xcb_match_error_t` occurs (TODO).\n\nIf `focus` is `XCB_NONE` (TODO), all keyboard events are discarded until a new focus window is set.\n\nIf `focus` is `XCB_POINTER_ROOT` (TODO), focus is on the root window of the screen on which the pointer is on currently.") }, FieldDoc { name: "time", doc: Some("Timestamp to avoid race conditions when running X over the network.\n\nThe special value `XCB_CURRENT_TIME` will be replaced with the current server time.") }, FieldDoc { name: "revert_to", doc: Some("Specifies what happens when the `focus` window becomes unviewable (if `focus` is neither `XCB_NONE` nor `XCB_POINTER_ROOT`).") }],
+        errors: &[ErrorDoc { error: "Window", doc: Some("The specified `focus` window does not exist.") }, ErrorDoc { error: "Match", doc: Some("The specified `focus` window is not viewable.") }, ErrorDoc { error: "Value", doc: Some("TODO: Reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "OpenFont",
+        brief: Some("opens a font"),
+        description: Some("Opens any X core font matching the given `name` (for example \"-misc-fixed-*\").\n\nNote that X core fonts are deprecated (but still supported) in favor of client-side rendering using Xft."),
+        fields: &[FieldDoc { name: "fid", doc: Some("The ID with which you will refer to the font, created by `xcb_generate_id`.") }, FieldDoc { name: "name_len", doc: Some("Length (in bytes) of `name`.") }, FieldDoc { name: "name", doc: Some("A pattern describing an X core font.") }],
+        errors: &[ErrorDoc { error: "Name", doc: Some("No font matches the given `name`.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryFont",
+        brief: Some("query font metrics"),
+        description: Some("Queries information associated with the font."),
+        fields: &[FieldDoc { name: "font", doc: Some("The fontable (Font or Graphics Context) to query.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryTextExtents",
+        brief: Some("get text extents"),
+        description: Some("Query text extents from the X11 server. This request returns the bounding box of the specified 16-bit character string in the specified `font` or the font contained in the specified graphics context.\n\n`font_ascent` is set to the maximum of the ascent metrics of all characters in the string. `font_descent` is set to the maximum of the descent metrics. `overall_width` is set to the sum of the character-width metrics of all characters in the string. For each character in the string, let W be the sum of the character-width metrics of all characters preceding it in the string. Let L be the left-side-bearing metric of the character plus W. Let R be the right-side-bearing metric of the character plus W. The lbearing member is set to the minimum L of all characters in the string. The rbearing member is set to the maximum R.\n\nFor fonts defined with linear indexing rather than 2-byte matrix indexing, each `xcb_char2b_t` structure is interpreted as a 16-bit number with byte1 as the most significant byte. If the font has no defined default character, undefined characters in the string are taken to have all zero metrics.\n\nCharacters with all zero metrics are ignored. If the font has no defined default_char, the undefined characters in the string are also ignored."),
+        fields: &[FieldDoc { name: "font", doc: Some("The `font` to calculate text extents in. You can also pass a graphics context.") }, FieldDoc { name: "string_len", doc: Some("The number of characters in `string`.") }, FieldDoc { name: "string", doc: Some("The text to get text extents for.") }],
+        errors: &[ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }, ErrorDoc { error: "Font", doc: Some("The specified `font` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ListFonts",
+        brief: Some("get matching font names"),
+        description: Some("Gets a list of available font names which match the given `pattern`."),
+        fields: &[FieldDoc { name: "pattern_len", doc: Some("The length (in bytes) of `pattern`.") }, FieldDoc { name: "pattern", doc: Some("A font pattern, for example \"-misc-fixed-*\".\n\nThe asterisk (*) is a wildcard for any number of characters. The question mark (?) is a wildcard for a single character. Use of uppercase or lowercase does not matter.") }, FieldDoc { name: "max_names", doc: Some("The maximum number of fonts to be returned.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ListFontsWithInfo",
+        brief: Some("get matching font names and information"),
+        description: Some("Gets a list of available font names which match the given `pattern`."),
+        fields: &[FieldDoc { name: "pattern_len", doc: Some("The length (in bytes) of `pattern`.") }, FieldDoc { name: "pattern", doc: Some("A font pattern, for example \"-misc-fixed-*\".\n\nThe asterisk (*) is a wildcard for any number of characters. The question mark (?) is a wildcard for a single character. Use of uppercase or lowercase does not matter.") }, FieldDoc { name: "max_names", doc: Some("The maximum number of fonts to be returned.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CreatePixmap",
+        brief: Some("Creates a pixmap"),
+        description: Some("Creates a pixmap. The pixmap can only be used on the same screen as `drawable` is on and only with drawables of the same `depth`."),
+        fields: &[FieldDoc { name: "depth", doc: Some("TODO") }, FieldDoc { name: "pid", doc: Some("The ID with which you will refer to the new pixmap, created by `xcb_generate_id`.") }, FieldDoc { name: "drawable", doc: Some("Drawable to get the screen from.") }, FieldDoc { name: "width", doc: Some("The width of the new pixmap.") }, FieldDoc { name: "height", doc: Some("The height of the new pixmap.") }],
+        errors: &[ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "Alloc", doc: Some("The X server could not allocate the requested resources (no memory?).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "FreePixmap",
+        brief: Some("Destroys a pixmap"),
+        description: Some("Deletes the association between the pixmap ID and the pixmap. The pixmap storage will be freed when there are no more references to it."),
+        fields: &[FieldDoc { name: "pixmap", doc: Some("The pixmap to destroy.") }],
+        errors: &[ErrorDoc { error: "Pixmap", doc: Some("The specified pixmap does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CreateGC",
+        brief: Some("Creates a graphics context"),
+        description: Some("Creates a graphics context. The graphics context can be used with any drawable that has the same root and depth as the specified drawable."),
+        fields: &[FieldDoc { name: "cid", doc: Some("The ID with which you will refer to the graphics context, created by `xcb_generate_id`.") }, FieldDoc { name: "drawable", doc: Some("Drawable to get the root/depth from.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Font", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Pixmap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Alloc", doc: Some("The X server could not allocate the requested resources (no memory?).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ChangeGC",
+        brief: Some("change graphics context components"),
+        description: Some("Changes the components specified by `value_mask` for the specified graphics context."),
+        fields: &[FieldDoc { name: "gc", doc: Some("The graphics context to change.") }, FieldDoc { name: "value_mask", doc: None }, FieldDoc { name: "value_list", doc: Some("Values for each of the components specified in the bitmask `value_mask`. The order has to correspond to the order of possible `value_mask` bits. See the example.") }],
+        errors: &[ErrorDoc { error: "Font", doc: Some("TODO: reasons?") }, ErrorDoc { error: "GContext", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Pixmap", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Alloc", doc: Some("The X server could not allocate the requested resources (no memory?).") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "FreeGC",
+        brief: Some("Destroys a graphics context"),
+        description: Some("Destroys the specified `gc` and all associated storage."),
+        fields: &[FieldDoc { name: "gc", doc: Some("The graphics context to destroy.") }],
+        errors: &[ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CopyArea",
+        brief: Some("copy areas"),
+        description: Some("Copies the specified rectangle from `src_drawable` to `dst_drawable`."),
+        fields: &[FieldDoc { name: "dst_drawable", doc: Some("The destination drawable (Window or Pixmap).") }, FieldDoc { name: "src_drawable", doc: Some("The source drawable (Window or Pixmap).") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.") }, FieldDoc { name: "src_x", doc: Some("The source X coordinate.") }, FieldDoc { name: "src_y", doc: Some("The source Y coordinate.") }, FieldDoc { name: "dst_x", doc: Some("The destination X coordinate.") }, FieldDoc { name: "dst_y", doc: Some("The destination Y coordinate.") }, FieldDoc { name: "width", doc: Some("The width of the area to copy (in pixels).") }, FieldDoc { name: "height", doc: Some("The height of the area to copy (in pixels).") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }, ErrorDoc { error: "Match", doc: Some("`src_drawable` has a different root or depth than `dst_drawable`.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "PolyLine",
+        brief: Some("draw lines"),
+        description: Some("Draws `points_len`-1 lines between each pair of points (point[i], point[i+1]) in the `points` array. The lines are drawn in the order listed in the array. They join correctly at all intermediate points, and if the first and last points coincide, the first and last lines also join correctly. For any given line, a pixel is not drawn more than once. If thin (zero line-width) lines intersect, the intersecting pixels are drawn multiple times. If wide lines intersect, the intersecting pixels are drawn only once, as though the entire request were a single, filled shape."),
+        fields: &[FieldDoc { name: "drawable", doc: Some("The drawable to draw the line(s) on.") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.") }, FieldDoc { name: "points_len", doc: Some("The number of `xcb_point_t` structures in `points`.") }, FieldDoc { name: "points", doc: Some("An array of points.") }, FieldDoc { name: "coordinate_mode", doc: None }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("TODO: reasons?") }, ErrorDoc { error: "GContext", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }, ErrorDoc { error: "Value", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "PolySegment",
+        brief: Some("draw lines"),
+        description: Some("Draws multiple, unconnected lines. For each segment, a line is drawn between (x1, y1) and (x2, y2). The lines are drawn in the order listed in the array of `xcb_segment_t` structures and does not perform joining at coincident endpoints. For any given line, a pixel is not drawn more than once. If lines intersect, the intersecting pixels are drawn multiple times.\n\nTODO: include the xcb_segment_t data structure\n\nTODO: an example"),
+        fields: &[FieldDoc { name: "drawable", doc: Some("A drawable (Window or Pixmap) to draw on.") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.\n\nTODO: document which attributes of a gc are used") }, FieldDoc { name: "segments_len", doc: Some("The number of `xcb_segment_t` structures in `segments`.") }, FieldDoc { name: "segments", doc: Some("An array of `xcb_segment_t` structures.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` does not exist.") }, ErrorDoc { error: "GContext", doc: Some("The specified `gc` does not exist.") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "PolyFillRectangle",
+        brief: Some("Fills rectangles"),
+        description: Some("Fills the specified rectangle(s) in the order listed in the array. For any given rectangle, each pixel is not drawn more than once. If rectangles intersect, the intersecting pixels are drawn multiple times."),
+        fields: &[FieldDoc { name: "drawable", doc: Some("The drawable (Window or Pixmap) to draw on.") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.\n\nThe following graphics context components are used: function, plane-mask, fill-style, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.\n\nThe following graphics context mode-dependent components are used: foreground, background, tile, stipple, tile-stipple-x-origin, and tile-stipple-y-origin.") }, FieldDoc { name: "rectangles_len", doc: Some("The number of `xcb_rectangle_t` structures in `rectangles`.") }, FieldDoc { name: "rectangles", doc: Some("The rectangles to fill.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ImageText8",
+        brief: Some("Draws text"),
+        description: Some("Fills the destination rectangle with the background pixel from `gc`, then paints the text with the foreground pixel from `gc`. The upper-left corner of the filled rectangle is at [x, y - font-ascent]. The width is overall-width, the height is font-ascent + font-descent. The overall-width, font-ascent and font-descent are as returned by `xcb_query_text_extents` (TODO).\n\nNote that using X core fonts is deprecated (but still supported) in favor of client-side rendering using Xft."),
+        fields: &[FieldDoc { name: "drawable", doc: Some("The drawable (Window or Pixmap) to draw text on.") }, FieldDoc { name: "string_len", doc: Some("The length of the `string`. Note that this parameter limited by 255 due to using 8 bits!") }, FieldDoc { name: "string", doc: Some("The string to draw. Only the first 255 characters are relevant due to the data type of `string_len`.") }, FieldDoc { name: "x", doc: Some("The x coordinate of the first character, relative to the origin of `drawable`.") }, FieldDoc { name: "y", doc: Some("The y coordinate of the first character, relative to the origin of `drawable`.") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.\n\nThe following graphics context components are used: plane-mask, foreground, background, font, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ImageText16",
+        brief: Some("Draws text"),
+        description: Some("Fills the destination rectangle with the background pixel from `gc`, then paints the text with the foreground pixel from `gc`. The upper-left corner of the filled rectangle is at [x, y - font-ascent]. The width is overall-width, the height is font-ascent + font-descent. The overall-width, font-ascent and font-descent are as returned by `xcb_query_text_extents` (TODO).\n\nNote that using X core fonts is deprecated (but still supported) in favor of client-side rendering using Xft."),
+        fields: &[FieldDoc { name: "drawable", doc: Some("The drawable (Window or Pixmap) to draw text on.") }, FieldDoc { name: "string_len", doc: Some("The length of the `string` in characters. Note that this parameter limited by 255 due to using 8 bits!") }, FieldDoc { name: "string", doc: Some("The string to draw. Only the first 255 characters are relevant due to the data type of `string_len`. Every character uses 2 bytes (hence the 16 in this request's name).") }, FieldDoc { name: "x", doc: Some("The x coordinate of the first character, relative to the origin of `drawable`.") }, FieldDoc { name: "y", doc: Some("The y coordinate of the first character, relative to the origin of `drawable`.") }, FieldDoc { name: "gc", doc: Some("The graphics context to use.\n\nThe following graphics context components are used: plane-mask, foreground, background, font, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.") }],
+        errors: &[ErrorDoc { error: "Drawable", doc: Some("The specified `drawable` (Window or Pixmap) does not exist.") }, ErrorDoc { error: "GContext", doc: Some("The specified graphics context does not exist.") }, ErrorDoc { error: "Match", doc: Some("TODO: reasons?") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "AllocColor",
+        brief: Some("Allocate a color"),
+        description: Some("Allocates a read-only colormap entry corresponding to the closest RGB value supported by the hardware. If you are using TrueColor, you can take a shortcut and directly calculate the color pixel value to avoid the round trip. But, for example, on 16-bit color setups (VNC), you can easily get the closest supported RGB value to the RGB value you are specifying."),
+        fields: &[FieldDoc { name: "cmap", doc: Some("TODO") }, FieldDoc { name: "red", doc: Some("The red value of your color.") }, FieldDoc { name: "green", doc: Some("The green value of your color.") }, FieldDoc { name: "blue", doc: Some("The blue value of your color.") }],
+        errors: &[ErrorDoc { error: "Colormap", doc: Some("The specified colormap `cmap` does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CreateGlyphCursor",
+        brief: Some("create cursor"),
+        description: Some("Creates a cursor from a font glyph. X provides a set of standard cursor shapes in a special font named cursor. Applications are encouraged to use this interface for their cursors because the font can be customized for the individual display type.\n\nAll pixels which are set to 1 in the source will use the foreground color (as specified by `fore_red`, `fore_green` and `fore_blue`). All pixels set to 0 will use the background color (as specified by `back_red`, `back_green` and `back_blue`)."),
+        fields: &[FieldDoc { name: "cid", doc: Some("The ID with which you will refer to the cursor, created by `xcb_generate_id`.") }, FieldDoc { name: "source_font", doc: Some("In which font to look for the cursor glyph.") }, FieldDoc { name: "mask_font", doc: Some("In which font to look for the mask glyph.") }, FieldDoc { name: "source_char", doc: Some("The glyph of `source_font` to use.") }, FieldDoc { name: "mask_char", doc: Some("The glyph of `mask_font` to use as a mask: Pixels which are set to 1 define which source pixels are displayed. All pixels which are set to 0 are not displayed.") }, FieldDoc { name: "fore_red", doc: Some("The red value of the foreground color.") }, FieldDoc { name: "fore_green", doc: Some("The green value of the foreground color.") }, FieldDoc { name: "fore_blue", doc: Some("The blue value of the foreground color.") }, FieldDoc { name: "back_red", doc: Some("The red value of the background color.") }, FieldDoc { name: "back_green", doc: Some("The green value of the background color.") }, FieldDoc { name: "back_blue", doc: Some("The blue value of the background color.") }],
+        errors: &[ErrorDoc { error: "Alloc", doc: Some("The X server could not allocate the requested resources (no memory?).") }, ErrorDoc { error: "Font", doc: Some("The specified `source_font` or `mask_font` does not exist.") }, ErrorDoc { error: "Value", doc: Some("Either `source_char` or `mask_char` are not defined in `source_font` or `mask_font`, respectively.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "FreeCursor",
+        brief: Some("Deletes a cursor"),
+        description: Some("Deletes the association between the cursor resource ID and the specified cursor. The cursor is freed when no other resource references it."),
+        fields: &[FieldDoc { name: "cursor", doc: Some("The cursor to destroy.") }],
+        errors: &[ErrorDoc { error: "Cursor", doc: Some("The specified cursor does not exist.") }],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryExtension",
+        brief: Some("check if extension is present"),
+        description: Some("Determines if the specified extension is present on this X11 server.\n\nEvery extension has a unique `major_opcode` to identify requests, the minor opcodes and request formats are extension-specific. If the extension provides events and errors, the `first_event` and `first_error` fields in the reply are set accordingly.\n\nThere should rarely be a need to use this request directly, XCB provides the `xcb_get_extension_data` function instead."),
+        fields: &[FieldDoc { name: "name_len", doc: Some("The length of `name` in bytes.") }, FieldDoc { name: "name", doc: Some("The name of the extension to query, for example \"RANDR\". This is case sensitive!") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "KillClient",
+        brief: Some("kills a client"),
+        description: Some("Forces a close down of the client that created the specified `resource`."),
+        fields: &[FieldDoc { name: "resource", doc: Some("Any resource belonging to the client (for example a Window), used to identify the client connection.\n\nThe special value of `XCB_KILL_ALL_TEMPORARY`, the resources of all clients that have terminated in `RetainTemporary` (TODO) are destroyed.") }],
+        errors: &[ErrorDoc { error: "Value", doc: Some("The specified `resource` does not exist.") }],
+    },
+];
+/// The documentation of all replies that are known to this build of x11rb.
+pub static REPLIES: &[Documentation] = &[
+    Documentation {
+        module: "xproto",
+        name: "GetWindowAttributes",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "override_redirect", doc: Some("Window managers should ignore this window if `override_redirect` is 1.") }, FieldDoc { name: "visual", doc: Some("The associated visual structure of `window`.") }, FieldDoc { name: "backing_planes", doc: Some("Planes to be preserved if possible.") }, FieldDoc { name: "backing_pixel", doc: Some("Value to be used when restoring planes.") }, FieldDoc { name: "save_under", doc: Some("Boolean, should bits under be saved?") }, FieldDoc { name: "colormap", doc: Some("Color map to be associated with window.") }, FieldDoc { name: "all_event_masks", doc: Some("Set of events all people have interest in.") }, FieldDoc { name: "your_event_mask", doc: Some("My event mask.") }, FieldDoc { name: "do_not_propagate_mask", doc: Some("Set of events that should not propagate.") }, FieldDoc { name: "backing_store", doc: None }, FieldDoc { name: "class", doc: None }, FieldDoc { name: "bit_gravity", doc: None }, FieldDoc { name: "win_gravity", doc: None }, FieldDoc { name: "map_state", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetGeometry",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "root", doc: Some("Root window of the screen containing `drawable`.") }, FieldDoc { name: "x", doc: Some("The X coordinate of `drawable`. If `drawable` is a window, the coordinate specifies the upper-left outer corner relative to its parent's origin. If `drawable` is a pixmap, the X coordinate is always 0.") }, FieldDoc { name: "y", doc: Some("The Y coordinate of `drawable`. If `drawable` is a window, the coordinate specifies the upper-left outer corner relative to its parent's origin. If `drawable` is a pixmap, the Y coordinate is always 0.") }, FieldDoc { name: "width", doc: Some("The width of `drawable`.") }, FieldDoc { name: "height", doc: Some("The height of `drawable`.") }, FieldDoc { name: "border_width", doc: Some("The border width (in pixels).") }, FieldDoc { name: "depth", doc: Some("The depth of the drawable (bits per pixel for the object).") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryTree",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "root", doc: Some("The root window of `window`.") }, FieldDoc { name: "parent", doc: Some("The parent window of `window`.") }, FieldDoc { name: "children_len", doc: Some("The number of child windows.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetProperty",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "format", doc: Some("Specifies whether the data should be viewed as a list of 8-bit, 16-bit, or 32-bit quantities. Possible values are 8, 16, and 32. This information allows the X server to correctly perform byte-swap operations as necessary.") }, FieldDoc { name: "type", doc: Some("The actual type of the property (an atom).") }, FieldDoc { name: "bytes_after", doc: Some("The number of bytes remaining to be read in the property if a partial read was performed.") }, FieldDoc { name: "value_len", doc: Some("The length of value. You should use the corresponding accessor instead of this field.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GetSelectionOwner",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "owner", doc: Some("The current selection owner window.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryPointer",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "same_screen", doc: Some("If `same_screen` is False, then the pointer is not on the same screen as the argument window, `child` is None, and `win_x` and `win_y` are zero. If `same_screen` is True, then `win_x` and `win_y` are the pointer coordinates relative to the argument window's origin, and child is the child containing the pointer, if any.") }, FieldDoc { name: "root", doc: Some("The root window the pointer is logically on.") }, FieldDoc { name: "child", doc: Some("The child window containing the pointer, if any, if `same_screen` is true. If `same_screen` is false, `XCB_NONE` is returned.") }, FieldDoc { name: "root_x", doc: Some("The pointer X position, relative to `root`.") }, FieldDoc { name: "root_y", doc: Some("The pointer Y position, relative to `root`.") }, FieldDoc { name: "win_x", doc: Some("The pointer X coordinate, relative to `child`, if `same_screen` is true. Zero otherwise.") }, FieldDoc { name: "win_y", doc: Some("The pointer Y coordinate, relative to `child`, if `same_screen` is true. Zero otherwise.") }, FieldDoc { name: "mask", doc: Some("The current logical state of the modifier keys and the buttons. Note that the logical state of a device (as seen by means of the protocol) may lag the physical state if device event processing is frozen.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryFont",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "min_bounds", doc: Some("minimum bounds over all existing char") }, FieldDoc { name: "max_bounds", doc: Some("maximum bounds over all existing char") }, FieldDoc { name: "min_char_or_byte2", doc: Some("first character") }, FieldDoc { name: "max_char_or_byte2", doc: Some("last character") }, FieldDoc { name: "default_char", doc: Some("char to print for undefined character") }, FieldDoc { name: "properties_len", doc: Some("how many properties there are") }, FieldDoc { name: "all_chars_exist", doc: Some("flag if all characters have nonzero size") }, FieldDoc { name: "font_ascent", doc: Some("baseline to top edge of raster") }, FieldDoc { name: "font_descent", doc: Some("baseline to bottom edge of raster") }, FieldDoc { name: "draw_direction", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ListFonts",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "names_len", doc: Some("The number of font names.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ListFontsWithInfo",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "name_len", doc: Some("The number of matched font names.") }, FieldDoc { name: "min_bounds", doc: Some("minimum bounds over all existing char") }, FieldDoc { name: "max_bounds", doc: Some("maximum bounds over all existing char") }, FieldDoc { name: "min_char_or_byte2", doc: Some("first character") }, FieldDoc { name: "max_char_or_byte2", doc: Some("last character") }, FieldDoc { name: "default_char", doc: Some("char to print for undefined character") }, FieldDoc { name: "properties_len", doc: Some("how many properties there are") }, FieldDoc { name: "all_chars_exist", doc: Some("flag if all characters have nonzero size") }, FieldDoc { name: "font_ascent", doc: Some("baseline to top edge of raster") }, FieldDoc { name: "font_descent", doc: Some("baseline to bottom edge of raster") }, FieldDoc { name: "replies_hint", doc: Some("An indication of how many more fonts will be returned. This is only a hint and may be larger or smaller than the number of fonts actually returned. A zero value does not guarantee that no more fonts will be returned.") }, FieldDoc { name: "draw_direction", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "QueryExtension",
+        brief: None,
+        description: None,
+        fields: &[FieldDoc { name: "present", doc: Some("Whether the extension is present on this X11 server.") }, FieldDoc { name: "major_opcode", doc: Some("The major opcode for requests.") }, FieldDoc { name: "first_event", doc: Some("The first event code, if any.") }, FieldDoc { name: "first_error", doc: Some("The first error code, if any.") }],
+        errors: &[],
+    },
+];
+/// The documentation of all events that are known to this build of x11rb.
+pub static EVENTS: &[Documentation] = &[
+    Documentation {
+        module: "xproto",
+        name: "KeyPress",
+        brief: Some("a key was pressed/released"),
+        description: None,
+        fields: &[FieldDoc { name: "detail", doc: Some("The keycode (a number representing a physical key on the keyboard) of the key which was pressed.") }, FieldDoc { name: "time", doc: Some("Time when the event was generated (in milliseconds).") }, FieldDoc { name: "root", doc: Some("The root window of `child`.") }, FieldDoc { name: "same_screen", doc: Some("Whether the `event` window is on the same screen as the `root` window.") }, FieldDoc { name: "event_x", doc: Some("If `same_screen` is true, this is the X coordinate relative to the `event` window's origin. Otherwise, `event_x` will be set to zero.") }, FieldDoc { name: "event_y", doc: Some("If `same_screen` is true, this is the Y coordinate relative to the `event` window's origin. Otherwise, `event_y` will be set to zero.") }, FieldDoc { name: "root_x", doc: Some("The X coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The Y coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "state", doc: Some("The logical state of the pointer buttons and modifier keys just prior to the event.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "KeyRelease",
+        brief: Some("a key was pressed/released"),
+        description: None,
+        fields: &[FieldDoc { name: "detail", doc: Some("The keycode (a number representing a physical key on the keyboard) of the key which was pressed.") }, FieldDoc { name: "time", doc: Some("Time when the event was generated (in milliseconds).") }, FieldDoc { name: "root", doc: Some("The root window of `child`.") }, FieldDoc { name: "same_screen", doc: Some("Whether the `event` window is on the same screen as the `root` window.") }, FieldDoc { name: "event_x", doc: Some("If `same_screen` is true, this is the X coordinate relative to the `event` window's origin. Otherwise, `event_x` will be set to zero.") }, FieldDoc { name: "event_y", doc: Some("If `same_screen` is true, this is the Y coordinate relative to the `event` window's origin. Otherwise, `event_y` will be set to zero.") }, FieldDoc { name: "root_x", doc: Some("The X coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The Y coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "state", doc: Some("The logical state of the pointer buttons and modifier keys just prior to the event.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ButtonPress",
+        brief: Some("a mouse button was pressed/released"),
+        description: None,
+        fields: &[FieldDoc { name: "detail", doc: Some("The keycode (a number representing a physical key on the keyboard) of the key which was pressed.") }, FieldDoc { name: "time", doc: Some("Time when the event was generated (in milliseconds).") }, FieldDoc { name: "root", doc: Some("The root window of `child`.") }, FieldDoc { name: "same_screen", doc: Some("Whether the `event` window is on the same screen as the `root` window.") }, FieldDoc { name: "event_x", doc: Some("If `same_screen` is true, this is the X coordinate relative to the `event` window's origin. Otherwise, `event_x` will be set to zero.") }, FieldDoc { name: "event_y", doc: Some("If `same_screen` is true, this is the Y coordinate relative to the `event` window's origin. Otherwise, `event_y` will be set to zero.") }, FieldDoc { name: "root_x", doc: Some("The X coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The Y coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "state", doc: Some("The logical state of the pointer buttons and modifier keys just prior to the event.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ButtonRelease",
+        brief: Some("a mouse button was pressed/released"),
+        description: None,
+        fields: &[FieldDoc { name: "detail", doc: Some("The keycode (a number representing a physical key on the keyboard) of the key which was pressed.") }, FieldDoc { name: "time", doc: Some("Time when the event was generated (in milliseconds).") }, FieldDoc { name: "root", doc: Some("The root window of `child`.") }, FieldDoc { name: "same_screen", doc: Some("Whether the `event` window is on the same screen as the `root` window.") }, FieldDoc { name: "event_x", doc: Some("If `same_screen` is true, this is the X coordinate relative to the `event` window's origin. Otherwise, `event_x` will be set to zero.") }, FieldDoc { name: "event_y", doc: Some("If `same_screen` is true, this is the Y coordinate relative to the `event` window's origin. Otherwise, `event_y` will be set to zero.") }, FieldDoc { name: "root_x", doc: Some("The X coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The Y coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "state", doc: Some("The logical state of the pointer buttons and modifier keys just prior to the event.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "MotionNotify",
+        brief: Some("a key was pressed"),
+        description: None,
+        fields: &[FieldDoc { name: "detail", doc: Some("The keycode (a number representing a physical key on the keyboard) of the key which was pressed.") }, FieldDoc { name: "time", doc: Some("Time when the event was generated (in milliseconds).") }, FieldDoc { name: "root", doc: Some("The root window of `child`.") }, FieldDoc { name: "same_screen", doc: Some("Whether the `event` window is on the same screen as the `root` window.") }, FieldDoc { name: "event_x", doc: Some("If `same_screen` is true, this is the X coordinate relative to the `event` window's origin. Otherwise, `event_x` will be set to zero.") }, FieldDoc { name: "event_y", doc: Some("If `same_screen` is true, this is the Y coordinate relative to the `event` window's origin. Otherwise, `event_y` will be set to zero.") }, FieldDoc { name: "root_x", doc: Some("The X coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The Y coordinate of the pointer relative to the `root` window at the time of the event.") }, FieldDoc { name: "state", doc: Some("The logical state of the pointer buttons and modifier keys just prior to the event.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "EnterNotify",
+        brief: Some("the pointer is in a different window"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The window on which the event was generated.") }, FieldDoc { name: "child", doc: Some("If the `event` window has subwindows and the final pointer position is in one of them, then `child` is set to that subwindow, `XCB_WINDOW_NONE` otherwise.") }, FieldDoc { name: "root", doc: Some("The root window for the final cursor position.") }, FieldDoc { name: "root_x", doc: Some("The pointer X coordinate relative to `root`'s origin at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The pointer Y coordinate relative to `root`'s origin at the time of the event.") }, FieldDoc { name: "event_x", doc: Some("If `event` is on the same screen as `root`, this is the pointer X coordinate relative to the event window's origin.") }, FieldDoc { name: "event_y", doc: Some("If `event` is on the same screen as `root`, this is the pointer Y coordinate relative to the event window's origin.") }, FieldDoc { name: "mode", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "LeaveNotify",
+        brief: Some("the pointer is in a different window"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The window on which the event was generated.") }, FieldDoc { name: "child", doc: Some("If the `event` window has subwindows and the final pointer position is in one of them, then `child` is set to that subwindow, `XCB_WINDOW_NONE` otherwise.") }, FieldDoc { name: "root", doc: Some("The root window for the final cursor position.") }, FieldDoc { name: "root_x", doc: Some("The pointer X coordinate relative to `root`'s origin at the time of the event.") }, FieldDoc { name: "root_y", doc: Some("The pointer Y coordinate relative to `root`'s origin at the time of the event.") }, FieldDoc { name: "event_x", doc: Some("If `event` is on the same screen as `root`, this is the pointer X coordinate relative to the event window's origin.") }, FieldDoc { name: "event_y", doc: Some("If `event` is on the same screen as `root`, this is the pointer Y coordinate relative to the event window's origin.") }, FieldDoc { name: "mode", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "FocusIn",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The window on which the focus event was generated. This is the window used by the X server to report the event.") }, FieldDoc { name: "detail", doc: None }, FieldDoc { name: "mode", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "FocusOut",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The window on which the focus event was generated. This is the window used by the X server to report the event.") }, FieldDoc { name: "detail", doc: None }, FieldDoc { name: "mode", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "Expose",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "window", doc: Some("The exposed (damaged) window.") }, FieldDoc { name: "x", doc: Some("The X coordinate of the left-upper corner of the exposed rectangle, relative to the `window`'s origin.") }, FieldDoc { name: "y", doc: Some("The Y coordinate of the left-upper corner of the exposed rectangle, relative to the `window`'s origin.") }, FieldDoc { name: "width", doc: Some("The width of the exposed rectangle.") }, FieldDoc { name: "height", doc: Some("The height of the exposed rectangle.") }, FieldDoc { name: "count", doc: Some("The amount of `Expose` events following this one. Simple applications that do not want to optimize redisplay by distinguishing between subareas of its window can just ignore all Expose events with nonzero counts and perform full redisplays on events with zero counts.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "DestroyNotify",
+        brief: Some("a window is destroyed"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The reconfigured window or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The window that is destroyed.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "UnmapNotify",
+        brief: Some("a window is unmapped"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The reconfigured window or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The window that was unmapped.") }, FieldDoc { name: "from_configure", doc: Some("Set to 1 if the event was generated as a result of a resizing of the window's parent when `window` had a win_gravity of `UnmapGravity`.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "MapNotify",
+        brief: Some("a window was mapped"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The window which was mapped or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The window that was mapped.") }, FieldDoc { name: "override_redirect", doc: Some("Window managers should ignore this window if `override_redirect` is 1.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "MapRequest",
+        brief: Some("window wants to be mapped"),
+        description: None,
+        fields: &[FieldDoc { name: "parent", doc: Some("The parent of `window`.") }, FieldDoc { name: "window", doc: Some("The window to be mapped.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ConfigureNotify",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("The reconfigured window or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The window whose size, position, border, and/or stacking order was changed.") }, FieldDoc { name: "above_sibling", doc: Some("If `XCB_NONE`, the `window` is on the bottom of the stack with respect to sibling windows. However, if set to a sibling window, the `window` is placed on top of this sibling window.") }, FieldDoc { name: "x", doc: Some("The X coordinate of the upper-left outside corner of `window`, relative to the parent window's origin.") }, FieldDoc { name: "y", doc: Some("The Y coordinate of the upper-left outside corner of `window`, relative to the parent window's origin.") }, FieldDoc { name: "width", doc: Some("The inside width of `window`, not including the border.") }, FieldDoc { name: "height", doc: Some("The inside height of `window`, not including the border.") }, FieldDoc { name: "border_width", doc: Some("The border width of `window`.") }, FieldDoc { name: "override_redirect", doc: Some("Window managers should ignore this window if `override_redirect` is 1.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CirculateNotify",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("Either the restacked window or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The restacked window.") }, FieldDoc { name: "place", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "CirculateRequest",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: None,
+        fields: &[FieldDoc { name: "event", doc: Some("Either the restacked window or its parent, depending on whether `StructureNotify` or `SubstructureNotify` was selected.") }, FieldDoc { name: "window", doc: Some("The restacked window.") }, FieldDoc { name: "place", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "PropertyNotify",
+        brief: Some("a window property changed"),
+        description: None,
+        fields: &[FieldDoc { name: "window", doc: Some("The window whose associated property was changed.") }, FieldDoc { name: "atom", doc: Some("The property's atom, to indicate which property was changed.") }, FieldDoc { name: "time", doc: Some("A timestamp of the server time when the property was changed.") }, FieldDoc { name: "state", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ColormapNotify",
+        brief: Some("the colormap for some window changed"),
+        description: None,
+        fields: &[FieldDoc { name: "window", doc: Some("The window whose associated colormap is changed, installed or uninstalled.") }, FieldDoc { name: "colormap", doc: Some("The colormap which is changed, installed or uninstalled. This is `XCB_NONE` when the colormap is changed by a call to `FreeColormap`.") }, FieldDoc { name: "_new", doc: Some("Indicates whether the colormap was changed (1) or installed/uninstalled (0).") }, FieldDoc { name: "state", doc: None }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "ClientMessage",
+        brief: Some("NOT YET DOCUMENTED"),
+        description: Some("This event represents a ClientMessage, sent by another X11 client. An example is a client sending the `_NET_WM_STATE` ClientMessage to the root window to indicate the fullscreen window state, effectively requesting that the window manager puts it into fullscreen mode."),
+        fields: &[FieldDoc { name: "format", doc: Some("Specifies how to interpret `data`. Can be either 8, 16 or 32.") }, FieldDoc { name: "type", doc: Some("An atom which indicates how the data should be interpreted by the receiving client.") }, FieldDoc { name: "data", doc: Some("The data itself (20 bytes max).") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "MappingNotify",
+        brief: Some("keyboard mapping changed"),
+        description: None,
+        fields: &[FieldDoc { name: "request", doc: None }, FieldDoc { name: "first_keycode", doc: Some("The first number in the range of the altered mapping.") }, FieldDoc { name: "count", doc: Some("The number of keycodes altered.") }],
+        errors: &[],
+    },
+    Documentation {
+        module: "xproto",
+        name: "GeGeneric",
+        brief: Some("generic event (with length)"),
+        description: None,
+        fields: &[FieldDoc { name: "extension", doc: Some("The major opcode of the extension creating this event") }, FieldDoc { name: "length", doc: Some("The amount (in 4-byte units) of data beyond 32 bytes") }, FieldDoc { name: "evtype", doc: Some("The extension-specific event type") }],
+        errors: &[],
+    },
+];
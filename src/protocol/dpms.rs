@@ -4,6 +4,8 @@
 //! Bindings to the `DPMS` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -33,11 +35,13 @@ pub const X11_XML_VERSION: (u32, u32) = (0, 0);
 
 /// Opcode for the GetVersion request
 pub const GET_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetVersionRequest {
     pub client_major_version: u16,
     pub client_minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 impl GetVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -65,12 +69,57 @@ impl GetVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        write(&[
+            major_opcode,
+            GET_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -87,9 +136,12 @@ impl GetVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 impl Request for GetVersionRequest {
     type Reply = GetVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
+#[inline]
 pub fn get_version<Conn>(conn: &Conn, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -101,6 +153,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetVersionReply {
     pub sequence: u16,
@@ -108,6 +161,7 @@ pub struct GetVersionReply {
     pub server_major_version: u16,
     pub server_minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 impl TryParse for GetVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -122,7 +176,8 @@ impl TryParse for GetVersionReply {
         }
         let result = GetVersionReply { sequence, length, server_major_version, server_minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -130,8 +185,10 @@ impl TryParse for GetVersionReply {
 
 /// Opcode for the Capable request
 pub const CAPABLE_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CapableRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 impl CapableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -153,12 +210,51 @@ impl CapableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            CAPABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -171,9 +267,12 @@ impl CapableRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 impl Request for CapableRequest {
     type Reply = CapableReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
+#[inline]
 pub fn capable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -182,12 +281,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CapableReply {
     pub sequence: u16,
     pub length: u32,
     pub capable: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 impl TryParse for CapableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -202,7 +303,8 @@ impl TryParse for CapableReply {
         }
         let result = CapableReply { sequence, length, capable };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -210,8 +312,10 @@ impl TryParse for CapableReply {
 
 /// Opcode for the GetTimeouts request
 pub const GET_TIMEOUTS_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTimeoutsRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 impl GetTimeoutsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -233,12 +337,51 @@ impl GetTimeoutsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            GET_TIMEOUTS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -251,9 +394,12 @@ impl GetTimeoutsRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 impl Request for GetTimeoutsRequest {
     type Reply = GetTimeoutsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
+#[inline]
 pub fn get_timeouts<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -262,6 +408,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTimeoutsReply {
     pub sequence: u16,
@@ -270,6 +417,7 @@ pub struct GetTimeoutsReply {
     pub suspend_timeout: u16,
     pub off_timeout: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 impl TryParse for GetTimeoutsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -286,7 +434,8 @@ impl TryParse for GetTimeoutsReply {
         }
         let result = GetTimeoutsReply { sequence, length, standby_timeout, suspend_timeout, off_timeout };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -294,12 +443,14 @@ impl TryParse for GetTimeoutsReply {
 
 /// Opcode for the SetTimeouts request
 pub const SET_TIMEOUTS_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetTimeoutsRequest {
     pub standby_timeout: u16,
     pub suspend_timeout: u16,
     pub off_timeout: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
 impl SetTimeoutsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -332,12 +483,62 @@ impl SetTimeoutsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let standby_timeout_bytes = self.standby_timeout.serialize();
+        let suspend_timeout_bytes = self.suspend_timeout.serialize();
+        let off_timeout_bytes = self.off_timeout.serialize();
+        write(&[
+            major_opcode,
+            SET_TIMEOUTS_REQUEST,
+            0,
+            0,
+            standby_timeout_bytes[0],
+            standby_timeout_bytes[1],
+            suspend_timeout_bytes[0],
+            suspend_timeout_bytes[1],
+            off_timeout_bytes[0],
+            off_timeout_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -356,9 +557,12 @@ impl SetTimeoutsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
 impl Request for SetTimeoutsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
+#[inline]
 pub fn set_timeouts<Conn>(conn: &Conn, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -373,8 +577,10 @@ where
 
 /// Opcode for the Enable request
 pub const ENABLE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EnableRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
 impl EnableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -396,12 +602,51 @@ impl EnableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -414,9 +659,12 @@ impl EnableRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
 impl Request for EnableRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
+#[inline]
 pub fn enable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -427,8 +675,10 @@ where
 
 /// Opcode for the Disable request
 pub const DISABLE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DisableRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
 impl DisableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -450,12 +700,51 @@ impl DisableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            DISABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -468,9 +757,12 @@ impl DisableRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
 impl Request for DisableRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
+#[inline]
 pub fn disable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -534,13 +826,26 @@ impl std::fmt::Debug for DPMSMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DPMSMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ForceLevel request
 pub const FORCE_LEVEL_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ForceLevelRequest {
     pub power_level: DPMSMode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
 impl ForceLevelRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -567,12 +872,56 @@ impl ForceLevelRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let power_level_bytes = u16::from(self.power_level).serialize();
+        write(&[
+            major_opcode,
+            FORCE_LEVEL_REQUEST,
+            0,
+            0,
+            power_level_bytes[0],
+            power_level_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -582,15 +931,20 @@ impl ForceLevelRequest {
         }
         let (power_level, remaining) = u16::try_parse(value)?;
         let power_level = power_level.into();
+        #[cfg(feature = "strict_parsing")]
+        DPMSMode::check_known(power_level)?;
         let _ = remaining;
         Ok(ForceLevelRequest {
             power_level,
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
 impl Request for ForceLevelRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
+#[inline]
 pub fn force_level<Conn>(conn: &Conn, power_level: DPMSMode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -603,8 +957,10 @@ where
 
 /// Opcode for the Info request
 pub const INFO_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InfoRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 impl InfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -626,12 +982,51 @@ impl InfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            INFO_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -644,9 +1039,12 @@ impl InfoRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 impl Request for InfoRequest {
     type Reply = InfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
+#[inline]
 pub fn info<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -655,6 +1053,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InfoReply {
     pub sequence: u16,
@@ -662,6 +1061,7 @@ pub struct InfoReply {
     pub power_level: DPMSMode,
     pub state: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 impl TryParse for InfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -676,9 +1076,12 @@ impl TryParse for InfoReply {
             return Err(ParseError::InvalidValue);
         }
         let power_level = power_level.into();
+        #[cfg(feature = "strict_parsing")]
+        DPMSMode::check_known(power_level)?;
         let result = InfoReply { sequence, length, power_level, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -686,34 +1089,50 @@ impl TryParse for InfoReply {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
+    #[inline]
     fn dpms_get_version(&self, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Self, GetVersionReply>, ConnectionError>
     {
         get_version(self, client_major_version, client_minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
+    #[inline]
     fn dpms_capable(&self) -> Result<Cookie<'_, Self, CapableReply>, ConnectionError>
     {
         capable(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
+    #[inline]
     fn dpms_get_timeouts(&self) -> Result<Cookie<'_, Self, GetTimeoutsReply>, ConnectionError>
     {
         get_timeouts(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
+    #[inline]
     fn dpms_set_timeouts(&self, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_timeouts(self, standby_timeout, suspend_timeout, off_timeout)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
+    #[inline]
     fn dpms_enable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         enable(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
+    #[inline]
     fn dpms_disable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         disable(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
+    #[inline]
     fn dpms_force_level(&self, power_level: DPMSMode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         force_level(self, power_level)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
+    #[inline]
     fn dpms_info(&self) -> Result<Cookie<'_, Self, InfoReply>, ConnectionError>
     {
         info(self)
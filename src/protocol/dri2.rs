@@ -4,6 +4,8 @@
 //! Bindings to the `DRI2` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -95,6 +97,17 @@ impl std::fmt::Debug for Attachment  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Attachment {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=10) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DriverType(u32);
@@ -141,6 +154,17 @@ impl std::fmt::Debug for DriverType  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DriverType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct EventType(u16);
@@ -195,6 +219,17 @@ impl std::fmt::Debug for EventType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl EventType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DRI2Buffer {
@@ -212,6 +247,8 @@ impl TryParse for DRI2Buffer {
         let (cpp, remaining) = u32::try_parse(remaining)?;
         let (flags, remaining) = u32::try_parse(remaining)?;
         let attachment = attachment.into();
+        #[cfg(feature = "strict_parsing")]
+        Attachment::check_known(attachment)?;
         let result = DRI2Buffer { attachment, name, pitch, cpp, flags };
         Ok((result, remaining))
     }
@@ -267,6 +304,8 @@ impl TryParse for AttachFormat {
         let (attachment, remaining) = u32::try_parse(remaining)?;
         let (format, remaining) = u32::try_parse(remaining)?;
         let attachment = attachment.into();
+        #[cfg(feature = "strict_parsing")]
+        Attachment::check_known(attachment)?;
         let result = AttachFormat { attachment, format };
         Ok((result, remaining))
     }
@@ -296,11 +335,13 @@ impl Serialize for AttachFormat {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -332,12 +373,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -354,9 +444,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -368,6 +461,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -375,6 +469,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -389,7 +484,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -397,11 +493,13 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the Connect request
 pub const CONNECT_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConnectRequest {
     pub window: xproto::Window,
     pub driver_type: DriverType,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 impl ConnectRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -433,12 +531,61 @@ impl ConnectRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let driver_type_bytes = u32::from(self.driver_type).serialize();
+        write(&[
+            major_opcode,
+            CONNECT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            driver_type_bytes[0],
+            driver_type_bytes[1],
+            driver_type_bytes[2],
+            driver_type_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ConnectReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -449,6 +596,8 @@ impl ConnectRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (driver_type, remaining) = u32::try_parse(remaining)?;
         let driver_type = driver_type.into();
+        #[cfg(feature = "strict_parsing")]
+        DriverType::check_known(driver_type)?;
         let _ = remaining;
         Ok(ConnectRequest {
             window,
@@ -456,9 +605,12 @@ impl ConnectRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 impl Request for ConnectRequest {
     type Reply = ConnectReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
+#[inline]
 pub fn connect<Conn>(conn: &Conn, window: xproto::Window, driver_type: DriverType) -> Result<Cookie<'_, Conn, ConnectReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -470,6 +622,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConnectReply {
     pub sequence: u16,
@@ -478,6 +631,7 @@ pub struct ConnectReply {
     pub alignment_pad: Vec<u8>,
     pub device_name: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 impl TryParse for ConnectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -499,11 +653,13 @@ impl TryParse for ConnectReply {
         }
         let result = ConnectReply { sequence, length, driver_name, alignment_pad, device_name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 impl ConnectReply {
     /// Get the value of the `driver_name_length` field.
     ///
@@ -535,11 +691,13 @@ impl ConnectReply {
 
 /// Opcode for the Authenticate request
 pub const AUTHENTICATE_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuthenticateRequest {
     pub window: xproto::Window,
     pub magic: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 impl AuthenticateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -571,12 +729,61 @@ impl AuthenticateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let magic_bytes = self.magic.serialize();
+        write(&[
+            major_opcode,
+            AUTHENTICATE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            magic_bytes[0],
+            magic_bytes[1],
+            magic_bytes[2],
+            magic_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AuthenticateReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -593,9 +800,12 @@ impl AuthenticateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 impl Request for AuthenticateRequest {
     type Reply = AuthenticateReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
+#[inline]
 pub fn authenticate<Conn>(conn: &Conn, window: xproto::Window, magic: u32) -> Result<Cookie<'_, Conn, AuthenticateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -607,12 +817,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuthenticateReply {
     pub sequence: u16,
     pub length: u32,
     pub authenticated: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 impl TryParse for AuthenticateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -626,7 +838,8 @@ impl TryParse for AuthenticateReply {
         }
         let result = AuthenticateReply { sequence, length, authenticated };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -634,10 +847,12 @@ impl TryParse for AuthenticateReply {
 
 /// Opcode for the CreateDrawable request
 pub const CREATE_DRAWABLE_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateDrawableRequest {
     pub drawable: xproto::Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
 impl CreateDrawableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -664,12 +879,56 @@ impl CreateDrawableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            CREATE_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -684,9 +943,12 @@ impl CreateDrawableRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
 impl Request for CreateDrawableRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
+#[inline]
 pub fn create_drawable<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -699,10 +961,12 @@ where
 
 /// Opcode for the DestroyDrawable request
 pub const DESTROY_DRAWABLE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyDrawableRequest {
     pub drawable: xproto::Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
 impl DestroyDrawableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -729,12 +993,56 @@ impl DestroyDrawableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -749,9 +1057,12 @@ impl DestroyDrawableRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
 impl Request for DestroyDrawableRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
+#[inline]
 pub fn destroy_drawable<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -764,12 +1075,14 @@ where
 
 /// Opcode for the GetBuffers request
 pub const GET_BUFFERS_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersRequest<'input> {
     pub drawable: xproto::Drawable,
     pub count: u32,
     pub attachments: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 impl<'input> GetBuffersRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -805,12 +1118,68 @@ impl<'input> GetBuffersRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attachments_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let count_bytes = self.count.serialize();
+        write(&[
+            major_opcode,
+            GET_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let attachments_bytes = self.attachments.serialize();
+        let length_so_far = length_so_far + attachments_bytes.len();
+        write(&attachments_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.attachments.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetBuffersReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -844,9 +1213,12 @@ impl<'input> GetBuffersRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 impl<'input> Request for GetBuffersRequest<'input> {
     type Reply = GetBuffersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
+#[inline]
 pub fn get_buffers<'c, 'input, Conn>(conn: &'c Conn, drawable: xproto::Drawable, count: u32, attachments: &'input [u32]) -> Result<Cookie<'c, Conn, GetBuffersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -859,6 +1231,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersReply {
     pub sequence: u16,
@@ -867,6 +1240,7 @@ pub struct GetBuffersReply {
     pub height: u32,
     pub buffers: Vec<DRI2Buffer>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 impl TryParse for GetBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -884,11 +1258,13 @@ impl TryParse for GetBuffersReply {
         }
         let result = GetBuffersReply { sequence, length, width, height, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 impl GetBuffersReply {
     /// Get the value of the `count` field.
     ///
@@ -907,6 +1283,7 @@ impl GetBuffersReply {
 
 /// Opcode for the CopyRegion request
 pub const COPY_REGION_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyRegionRequest {
     pub drawable: xproto::Drawable,
@@ -914,6 +1291,7 @@ pub struct CopyRegionRequest {
     pub dest: u32,
     pub src: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 impl CopyRegionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -955,12 +1333,71 @@ impl CopyRegionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let region_bytes = self.region.serialize();
+        let dest_bytes = self.dest.serialize();
+        let src_bytes = self.src.serialize();
+        write(&[
+            major_opcode,
+            COPY_REGION_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, CopyRegionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -981,9 +1418,12 @@ impl CopyRegionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 impl Request for CopyRegionRequest {
     type Reply = CopyRegionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
+#[inline]
 pub fn copy_region<Conn>(conn: &Conn, drawable: xproto::Drawable, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Conn, CopyRegionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -997,11 +1437,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyRegionReply {
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 impl TryParse for CopyRegionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1014,7 +1456,8 @@ impl TryParse for CopyRegionReply {
         }
         let result = CopyRegionReply { sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1022,12 +1465,14 @@ impl TryParse for CopyRegionReply {
 
 /// Opcode for the GetBuffersWithFormat request
 pub const GET_BUFFERS_WITH_FORMAT_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersWithFormatRequest<'input> {
     pub drawable: xproto::Drawable,
     pub count: u32,
     pub attachments: Cow<'input, [AttachFormat]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 impl<'input> GetBuffersWithFormatRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1063,12 +1508,68 @@ impl<'input> GetBuffersWithFormatRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attachments_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let count_bytes = self.count.serialize();
+        write(&[
+            major_opcode,
+            GET_BUFFERS_WITH_FORMAT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let attachments_bytes = self.attachments.serialize();
+        let length_so_far = length_so_far + attachments_bytes.len();
+        write(&attachments_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.attachments.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetBuffersWithFormatReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1102,9 +1603,12 @@ impl<'input> GetBuffersWithFormatRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 impl<'input> Request for GetBuffersWithFormatRequest<'input> {
     type Reply = GetBuffersWithFormatReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
+#[inline]
 pub fn get_buffers_with_format<'c, 'input, Conn>(conn: &'c Conn, drawable: xproto::Drawable, count: u32, attachments: &'input [AttachFormat]) -> Result<Cookie<'c, Conn, GetBuffersWithFormatReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1117,6 +1621,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBuffersWithFormatReply {
     pub sequence: u16,
@@ -1125,6 +1630,7 @@ pub struct GetBuffersWithFormatReply {
     pub height: u32,
     pub buffers: Vec<DRI2Buffer>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 impl TryParse for GetBuffersWithFormatReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1142,11 +1648,13 @@ impl TryParse for GetBuffersWithFormatReply {
         }
         let result = GetBuffersWithFormatReply { sequence, length, width, height, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 impl GetBuffersWithFormatReply {
     /// Get the value of the `count` field.
     ///
@@ -1165,6 +1673,7 @@ impl GetBuffersWithFormatReply {
 
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapBuffersRequest {
     pub drawable: xproto::Drawable,
@@ -1175,6 +1684,7 @@ pub struct SwapBuffersRequest {
     pub remainder_hi: u32,
     pub remainder_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 impl SwapBuffersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1231,12 +1741,86 @@ impl SwapBuffersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let target_msc_hi_bytes = self.target_msc_hi.serialize();
+        let target_msc_lo_bytes = self.target_msc_lo.serialize();
+        let divisor_hi_bytes = self.divisor_hi.serialize();
+        let divisor_lo_bytes = self.divisor_lo.serialize();
+        let remainder_hi_bytes = self.remainder_hi.serialize();
+        let remainder_lo_bytes = self.remainder_lo.serialize();
+        write(&[
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SwapBuffersReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1263,9 +1847,12 @@ impl SwapBuffersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 impl Request for SwapBuffersRequest {
     type Reply = SwapBuffersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
+#[inline]
 pub fn swap_buffers<Conn>(conn: &Conn, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, SwapBuffersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1282,6 +1869,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapBuffersReply {
     pub sequence: u16,
@@ -1289,6 +1877,7 @@ pub struct SwapBuffersReply {
     pub swap_hi: u32,
     pub swap_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 impl TryParse for SwapBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1303,7 +1892,8 @@ impl TryParse for SwapBuffersReply {
         }
         let result = SwapBuffersReply { sequence, length, swap_hi, swap_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1311,10 +1901,12 @@ impl TryParse for SwapBuffersReply {
 
 /// Opcode for the GetMSC request
 pub const GET_MSC_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMSCRequest {
     pub drawable: xproto::Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 impl GetMSCRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1341,12 +1933,56 @@ impl GetMSCRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            GET_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMSCReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1361,9 +1997,12 @@ impl GetMSCRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 impl Request for GetMSCRequest {
     type Reply = GetMSCReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
+#[inline]
 pub fn get_msc<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<Cookie<'_, Conn, GetMSCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1374,6 +2013,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMSCReply {
     pub sequence: u16,
@@ -1385,6 +2025,7 @@ pub struct GetMSCReply {
     pub sbc_hi: u32,
     pub sbc_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 impl TryParse for GetMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1403,7 +2044,8 @@ impl TryParse for GetMSCReply {
         }
         let result = GetMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1411,6 +2053,7 @@ impl TryParse for GetMSCReply {
 
 /// Opcode for the WaitMSC request
 pub const WAIT_MSC_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitMSCRequest {
     pub drawable: xproto::Drawable,
@@ -1421,6 +2064,7 @@ pub struct WaitMSCRequest {
     pub remainder_hi: u32,
     pub remainder_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 impl WaitMSCRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1477,12 +2121,86 @@ impl WaitMSCRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let target_msc_hi_bytes = self.target_msc_hi.serialize();
+        let target_msc_lo_bytes = self.target_msc_lo.serialize();
+        let divisor_hi_bytes = self.divisor_hi.serialize();
+        let divisor_lo_bytes = self.divisor_lo.serialize();
+        let remainder_hi_bytes = self.remainder_hi.serialize();
+        let remainder_lo_bytes = self.remainder_lo.serialize();
+        write(&[
+            major_opcode,
+            WAIT_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, WaitMSCReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1509,9 +2227,12 @@ impl WaitMSCRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 impl Request for WaitMSCRequest {
     type Reply = WaitMSCReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
+#[inline]
 pub fn wait_msc<Conn>(conn: &Conn, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Conn, WaitMSCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1528,6 +2249,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitMSCReply {
     pub sequence: u16,
@@ -1539,6 +2261,7 @@ pub struct WaitMSCReply {
     pub sbc_hi: u32,
     pub sbc_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 impl TryParse for WaitMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1557,7 +2280,8 @@ impl TryParse for WaitMSCReply {
         }
         let result = WaitMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1565,12 +2289,14 @@ impl TryParse for WaitMSCReply {
 
 /// Opcode for the WaitSBC request
 pub const WAIT_SBC_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitSBCRequest {
     pub drawable: xproto::Drawable,
     pub target_sbc_hi: u32,
     pub target_sbc_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 impl WaitSBCRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1607,12 +2333,66 @@ impl WaitSBCRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let target_sbc_hi_bytes = self.target_sbc_hi.serialize();
+        let target_sbc_lo_bytes = self.target_sbc_lo.serialize();
+        write(&[
+            major_opcode,
+            WAIT_SBC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_sbc_hi_bytes[0],
+            target_sbc_hi_bytes[1],
+            target_sbc_hi_bytes[2],
+            target_sbc_hi_bytes[3],
+            target_sbc_lo_bytes[0],
+            target_sbc_lo_bytes[1],
+            target_sbc_lo_bytes[2],
+            target_sbc_lo_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, WaitSBCReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1631,9 +2411,12 @@ impl WaitSBCRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 impl Request for WaitSBCRequest {
     type Reply = WaitSBCReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
+#[inline]
 pub fn wait_sbc<Conn>(conn: &Conn, drawable: xproto::Drawable, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Conn, WaitSBCReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1646,6 +2429,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitSBCReply {
     pub sequence: u16,
@@ -1657,6 +2441,7 @@ pub struct WaitSBCReply {
     pub sbc_hi: u32,
     pub sbc_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 impl TryParse for WaitSBCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1675,7 +2460,8 @@ impl TryParse for WaitSBCReply {
         }
         let result = WaitSBCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1683,11 +2469,13 @@ impl TryParse for WaitSBCReply {
 
 /// Opcode for the SwapInterval request
 pub const SWAP_INTERVAL_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapIntervalRequest {
     pub drawable: xproto::Drawable,
     pub interval: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
 impl SwapIntervalRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1719,12 +2507,61 @@ impl SwapIntervalRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let interval_bytes = self.interval.serialize();
+        write(&[
+            major_opcode,
+            SWAP_INTERVAL_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            interval_bytes[0],
+            interval_bytes[1],
+            interval_bytes[2],
+            interval_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1741,9 +2578,12 @@ impl SwapIntervalRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
 impl Request for SwapIntervalRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
+#[inline]
 pub fn swap_interval<Conn>(conn: &Conn, drawable: xproto::Drawable, interval: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1757,11 +2597,13 @@ where
 
 /// Opcode for the GetParam request
 pub const GET_PARAM_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetParamRequest {
     pub drawable: xproto::Drawable,
     pub param: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 impl GetParamRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1793,12 +2635,61 @@ impl GetParamRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let param_bytes = self.param.serialize();
+        write(&[
+            major_opcode,
+            GET_PARAM_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            param_bytes[0],
+            param_bytes[1],
+            param_bytes[2],
+            param_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetParamReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1815,9 +2706,12 @@ impl GetParamRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 impl Request for GetParamRequest {
     type Reply = GetParamReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
+#[inline]
 pub fn get_param<Conn>(conn: &Conn, drawable: xproto::Drawable, param: u32) -> Result<Cookie<'_, Conn, GetParamReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1829,6 +2723,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetParamReply {
     pub is_param_recognized: bool,
@@ -1837,6 +2732,7 @@ pub struct GetParamReply {
     pub value_hi: u32,
     pub value_lo: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 impl TryParse for GetParamReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1851,7 +2747,8 @@ impl TryParse for GetParamReply {
         }
         let result = GetParamReply { is_param_recognized, sequence, length, value_hi, value_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1886,6 +2783,8 @@ impl TryParse for BufferSwapCompleteEvent {
         let (msc_lo, remaining) = u32::try_parse(remaining)?;
         let (sbc, remaining) = u32::try_parse(remaining)?;
         let event_type = event_type.into();
+        #[cfg(feature = "strict_parsing")]
+        EventType::check_known(event_type)?;
         let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2018,58 +2917,86 @@ impl From<InvalidateBuffersEvent> for [u8; 32] {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
+    #[inline]
     fn dri2_query_version(&self, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, major_version, minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
+    #[inline]
     fn dri2_connect(&self, window: xproto::Window, driver_type: DriverType) -> Result<Cookie<'_, Self, ConnectReply>, ConnectionError>
     {
         connect(self, window, driver_type)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
+    #[inline]
     fn dri2_authenticate(&self, window: xproto::Window, magic: u32) -> Result<Cookie<'_, Self, AuthenticateReply>, ConnectionError>
     {
         authenticate(self, window, magic)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
+    #[inline]
     fn dri2_create_drawable(&self, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_drawable(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
+    #[inline]
     fn dri2_destroy_drawable(&self, drawable: xproto::Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_drawable(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
+    #[inline]
     fn dri2_get_buffers<'c, 'input>(&'c self, drawable: xproto::Drawable, count: u32, attachments: &'input [u32]) -> Result<Cookie<'c, Self, GetBuffersReply>, ConnectionError>
     {
         get_buffers(self, drawable, count, attachments)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
+    #[inline]
     fn dri2_copy_region(&self, drawable: xproto::Drawable, region: u32, dest: u32, src: u32) -> Result<Cookie<'_, Self, CopyRegionReply>, ConnectionError>
     {
         copy_region(self, drawable, region, dest, src)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
+    #[inline]
     fn dri2_get_buffers_with_format<'c, 'input>(&'c self, drawable: xproto::Drawable, count: u32, attachments: &'input [AttachFormat]) -> Result<Cookie<'c, Self, GetBuffersWithFormatReply>, ConnectionError>
     {
         get_buffers_with_format(self, drawable, count, attachments)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
+    #[inline]
     fn dri2_swap_buffers(&self, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, SwapBuffersReply>, ConnectionError>
     {
         swap_buffers(self, drawable, target_msc_hi, target_msc_lo, divisor_hi, divisor_lo, remainder_hi, remainder_lo)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
+    #[inline]
     fn dri2_get_msc(&self, drawable: xproto::Drawable) -> Result<Cookie<'_, Self, GetMSCReply>, ConnectionError>
     {
         get_msc(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
+    #[inline]
     fn dri2_wait_msc(&self, drawable: xproto::Drawable, target_msc_hi: u32, target_msc_lo: u32, divisor_hi: u32, divisor_lo: u32, remainder_hi: u32, remainder_lo: u32) -> Result<Cookie<'_, Self, WaitMSCReply>, ConnectionError>
     {
         wait_msc(self, drawable, target_msc_hi, target_msc_lo, divisor_hi, divisor_lo, remainder_hi, remainder_lo)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
+    #[inline]
     fn dri2_wait_sbc(&self, drawable: xproto::Drawable, target_sbc_hi: u32, target_sbc_lo: u32) -> Result<Cookie<'_, Self, WaitSBCReply>, ConnectionError>
     {
         wait_sbc(self, drawable, target_sbc_hi, target_sbc_lo)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
+    #[inline]
     fn dri2_swap_interval(&self, drawable: xproto::Drawable, interval: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         swap_interval(self, drawable, interval)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
+    #[inline]
     fn dri2_get_param(&self, drawable: xproto::Drawable, param: u32) -> Result<Cookie<'_, Self, GetParamReply>, ConnectionError>
     {
         get_param(self, drawable, param)
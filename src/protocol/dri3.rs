@@ -4,6 +4,8 @@
 //! Bindings to the `DRI3` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -34,11 +36,13 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 2);
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -70,12 +74,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -92,9 +145,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -106,6 +162,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -113,6 +170,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -127,7 +185,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -135,11 +194,13 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the Open request
 pub const OPEN_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OpenRequest {
     pub drawable: xproto::Drawable,
     pub provider: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
 impl OpenRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -171,12 +232,61 @@ impl OpenRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let provider_bytes = self.provider.serialize();
+        write(&[
+            major_opcode,
+            OPEN_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, OpenReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -193,9 +303,12 @@ impl OpenRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
 impl Request for OpenRequest {
     type Reply = OpenReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
+#[inline]
 pub fn open<Conn>(conn: &Conn, drawable: xproto::Drawable, provider: u32) -> Result<CookieWithFds<'_, Conn, OpenReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -207,15 +320,17 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
+#[derive(Debug)]
 pub struct OpenReply {
     pub nfd: u8,
     pub sequence: u16,
     pub length: u32,
-    pub device_fd: RawFdContainer,
+    pub device_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
 impl TryParseFd for OpenReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -229,7 +344,8 @@ impl TryParseFd for OpenReply {
         }
         let result = OpenReply { nfd, sequence, length, device_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -237,7 +353,8 @@ impl TryParseFd for OpenReply {
 
 /// Opcode for the PixmapFromBuffer request
 pub const PIXMAP_FROM_BUFFER_REQUEST: u8 = 2;
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
+#[derive(Debug)]
 pub struct PixmapFromBufferRequest {
     pub pixmap: xproto::Pixmap,
     pub drawable: xproto::Drawable,
@@ -247,8 +364,9 @@ pub struct PixmapFromBufferRequest {
     pub stride: u16,
     pub depth: u8,
     pub bpp: u8,
-    pub pixmap_fd: RawFdContainer,
+    pub pixmap_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
 impl PixmapFromBufferRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -298,16 +416,24 @@ impl PixmapFromBufferRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![self.pixmap_fd]))
     }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<OwnedFd>) -> Result<Self, ParseError> {
         if header.minor_opcode != PIXMAP_FROM_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -335,15 +461,18 @@ impl PixmapFromBufferRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
 impl Request for PixmapFromBufferRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
+#[inline]
 pub fn pixmap_from_buffer<Conn, A>(conn: &Conn, pixmap: xproto::Pixmap, drawable: xproto::Drawable, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<RawFdContainer>,
+    A: Into<OwnedFd>,
 {
-    let pixmap_fd: RawFdContainer = pixmap_fd.into();
+    let pixmap_fd: OwnedFd = pixmap_fd.into();
     let request0 = PixmapFromBufferRequest {
         pixmap,
         drawable,
@@ -360,10 +489,12 @@ where
 
 /// Opcode for the BufferFromPixmap request
 pub const BUFFER_FROM_PIXMAP_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BufferFromPixmapRequest {
     pub pixmap: xproto::Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
 impl BufferFromPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -390,12 +521,56 @@ impl BufferFromPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize();
+        write(&[
+            major_opcode,
+            BUFFER_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, BufferFromPixmapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -410,9 +585,12 @@ impl BufferFromPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
 impl Request for BufferFromPixmapRequest {
     type Reply = BufferFromPixmapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
+#[inline]
 pub fn buffer_from_pixmap<Conn>(conn: &Conn, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Conn, BufferFromPixmapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -423,7 +601,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
+#[derive(Debug)]
 pub struct BufferFromPixmapReply {
     pub nfd: u8,
     pub sequence: u16,
@@ -434,10 +613,11 @@ pub struct BufferFromPixmapReply {
     pub stride: u16,
     pub depth: u8,
     pub bpp: u8,
-    pub pixmap_fd: RawFdContainer,
+    pub pixmap_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
 impl TryParseFd for BufferFromPixmapReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -457,7 +637,8 @@ impl TryParseFd for BufferFromPixmapReply {
         }
         let result = BufferFromPixmapReply { nfd, sequence, length, size, width, height, stride, depth, bpp, pixmap_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -465,13 +646,15 @@ impl TryParseFd for BufferFromPixmapReply {
 
 /// Opcode for the FenceFromFD request
 pub const FENCE_FROM_FD_REQUEST: u8 = 4;
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
+#[derive(Debug)]
 pub struct FenceFromFDRequest {
     pub drawable: xproto::Drawable,
     pub fence: u32,
     pub initially_triggered: bool,
-    pub fence_fd: RawFdContainer,
+    pub fence_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
 impl FenceFromFDRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -508,16 +691,24 @@ impl FenceFromFDRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![self.fence_fd]))
     }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<OwnedFd>) -> Result<Self, ParseError> {
         if header.minor_opcode != FENCE_FROM_FD_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -536,15 +727,18 @@ impl FenceFromFDRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
 impl Request for FenceFromFDRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
+#[inline]
 pub fn fence_from_fd<Conn, A>(conn: &Conn, drawable: xproto::Drawable, fence: u32, initially_triggered: bool, fence_fd: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<RawFdContainer>,
+    A: Into<OwnedFd>,
 {
-    let fence_fd: RawFdContainer = fence_fd.into();
+    let fence_fd: OwnedFd = fence_fd.into();
     let request0 = FenceFromFDRequest {
         drawable,
         fence,
@@ -556,11 +750,13 @@ where
 
 /// Opcode for the FDFromFence request
 pub const FD_FROM_FENCE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FDFromFenceRequest {
     pub drawable: xproto::Drawable,
     pub fence: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
 impl FDFromFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -592,12 +788,61 @@ impl FDFromFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let fence_bytes = self.fence.serialize();
+        write(&[
+            major_opcode,
+            FD_FROM_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, FDFromFenceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -614,9 +859,12 @@ impl FDFromFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
 impl Request for FDFromFenceRequest {
     type Reply = FDFromFenceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
+#[inline]
 pub fn fd_from_fence<Conn>(conn: &Conn, drawable: xproto::Drawable, fence: u32) -> Result<CookieWithFds<'_, Conn, FDFromFenceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -628,15 +876,17 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
+#[derive(Debug)]
 pub struct FDFromFenceReply {
     pub nfd: u8,
     pub sequence: u16,
     pub length: u32,
-    pub fence_fd: RawFdContainer,
+    pub fence_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
 impl TryParseFd for FDFromFenceReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -650,7 +900,8 @@ impl TryParseFd for FDFromFenceReply {
         }
         let result = FDFromFenceReply { nfd, sequence, length, fence_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -658,12 +909,14 @@ impl TryParseFd for FDFromFenceReply {
 
 /// Opcode for the GetSupportedModifiers request
 pub const GET_SUPPORTED_MODIFIERS_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetSupportedModifiersRequest {
     pub window: u32,
     pub depth: u8,
     pub bpp: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 impl GetSupportedModifiersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -696,12 +949,62 @@ impl GetSupportedModifiersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let depth_bytes = self.depth.serialize();
+        let bpp_bytes = self.bpp.serialize();
+        write(&[
+            major_opcode,
+            GET_SUPPORTED_MODIFIERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            depth_bytes[0],
+            bpp_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetSupportedModifiersReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -721,9 +1024,12 @@ impl GetSupportedModifiersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 impl Request for GetSupportedModifiersRequest {
     type Reply = GetSupportedModifiersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
+#[inline]
 pub fn get_supported_modifiers<Conn>(conn: &Conn, window: u32, depth: u8, bpp: u8) -> Result<Cookie<'_, Conn, GetSupportedModifiersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -736,6 +1042,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSupportedModifiersReply {
     pub sequence: u16,
@@ -743,6 +1050,7 @@ pub struct GetSupportedModifiersReply {
     pub window_modifiers: Vec<u64>,
     pub screen_modifiers: Vec<u64>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 impl TryParse for GetSupportedModifiersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -760,11 +1068,13 @@ impl TryParse for GetSupportedModifiersReply {
         }
         let result = GetSupportedModifiersReply { sequence, length, window_modifiers, screen_modifiers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 impl GetSupportedModifiersReply {
     /// Get the value of the `num_window_modifiers` field.
     ///
@@ -796,7 +1106,8 @@ impl GetSupportedModifiersReply {
 
 /// Opcode for the PixmapFromBuffers request
 pub const PIXMAP_FROM_BUFFERS_REQUEST: u8 = 7;
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
+#[derive(Debug)]
 pub struct PixmapFromBuffersRequest {
     pub pixmap: xproto::Pixmap,
     pub window: xproto::Window,
@@ -813,8 +1124,9 @@ pub struct PixmapFromBuffersRequest {
     pub depth: u8,
     pub bpp: u8,
     pub modifier: u64,
-    pub buffers: Vec<RawFdContainer>,
+    pub buffers: Vec<OwnedFd>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
 impl PixmapFromBuffersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -913,16 +1225,24 @@ impl PixmapFromBuffersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], self.buffers))
     }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        64
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<OwnedFd>) -> Result<Self, ParseError> {
         if header.minor_opcode != PIXMAP_FROM_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -969,10 +1289,13 @@ impl PixmapFromBuffersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
 impl Request for PixmapFromBuffersRequest {
     type Reply = ();
 }
-pub fn pixmap_from_buffers<Conn>(conn: &Conn, pixmap: xproto::Pixmap, window: xproto::Window, width: u16, height: u16, stride0: u32, offset0: u32, stride1: u32, offset1: u32, stride2: u32, offset2: u32, stride3: u32, offset3: u32, depth: u8, bpp: u8, modifier: u64, buffers: Vec<RawFdContainer>) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
+#[inline]
+pub fn pixmap_from_buffers<Conn>(conn: &Conn, pixmap: xproto::Pixmap, window: xproto::Window, width: u16, height: u16, stride0: u32, offset0: u32, stride1: u32, offset1: u32, stride2: u32, offset2: u32, stride3: u32, offset3: u32, depth: u8, bpp: u8, modifier: u64, buffers: Vec<OwnedFd>) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
@@ -999,10 +1322,12 @@ where
 
 /// Opcode for the BuffersFromPixmap request
 pub const BUFFERS_FROM_PIXMAP_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BuffersFromPixmapRequest {
     pub pixmap: xproto::Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 impl BuffersFromPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1029,12 +1354,56 @@ impl BuffersFromPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize();
+        write(&[
+            major_opcode,
+            BUFFERS_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, BuffersFromPixmapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1049,9 +1418,12 @@ impl BuffersFromPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 impl Request for BuffersFromPixmapRequest {
     type Reply = BuffersFromPixmapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
+#[inline]
 pub fn buffers_from_pixmap<Conn>(conn: &Conn, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Conn, BuffersFromPixmapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1062,7 +1434,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
+#[derive(Debug)]
 pub struct BuffersFromPixmapReply {
     pub sequence: u16,
     pub length: u32,
@@ -1073,10 +1446,11 @@ pub struct BuffersFromPixmapReply {
     pub bpp: u8,
     pub strides: Vec<u32>,
     pub offsets: Vec<u32>,
-    pub buffers: Vec<RawFdContainer>,
+    pub buffers: Vec<OwnedFd>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 impl TryParseFd for BuffersFromPixmapReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -1100,11 +1474,13 @@ impl TryParseFd for BuffersFromPixmapReply {
         }
         let result = BuffersFromPixmapReply { sequence, length, width, height, modifier, depth, bpp, strides, offsets, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 impl BuffersFromPixmapReply {
     /// Get the value of the `nfd` field.
     ///
@@ -1123,42 +1499,60 @@ impl BuffersFromPixmapReply {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
+    #[inline]
     fn dri3_query_version(&self, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, major_version, minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
+    #[inline]
     fn dri3_open(&self, drawable: xproto::Drawable, provider: u32) -> Result<CookieWithFds<'_, Self, OpenReply>, ConnectionError>
     {
         open(self, drawable, provider)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
+    #[inline]
     fn dri3_pixmap_from_buffer<A>(&self, pixmap: xproto::Pixmap, drawable: xproto::Drawable, size: u32, width: u16, height: u16, stride: u16, depth: u8, bpp: u8, pixmap_fd: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<RawFdContainer>,
+        A: Into<OwnedFd>,
     {
         pixmap_from_buffer(self, pixmap, drawable, size, width, height, stride, depth, bpp, pixmap_fd)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
+    #[inline]
     fn dri3_buffer_from_pixmap(&self, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Self, BufferFromPixmapReply>, ConnectionError>
     {
         buffer_from_pixmap(self, pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
+    #[inline]
     fn dri3_fence_from_fd<A>(&self, drawable: xproto::Drawable, fence: u32, initially_triggered: bool, fence_fd: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<RawFdContainer>,
+        A: Into<OwnedFd>,
     {
         fence_from_fd(self, drawable, fence, initially_triggered, fence_fd)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
+    #[inline]
     fn dri3_fd_from_fence(&self, drawable: xproto::Drawable, fence: u32) -> Result<CookieWithFds<'_, Self, FDFromFenceReply>, ConnectionError>
     {
         fd_from_fence(self, drawable, fence)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
+    #[inline]
     fn dri3_get_supported_modifiers(&self, window: u32, depth: u8, bpp: u8) -> Result<Cookie<'_, Self, GetSupportedModifiersReply>, ConnectionError>
     {
         get_supported_modifiers(self, window, depth, bpp)
     }
-    fn dri3_pixmap_from_buffers(&self, pixmap: xproto::Pixmap, window: xproto::Window, width: u16, height: u16, stride0: u32, offset0: u32, stride1: u32, offset1: u32, stride2: u32, offset2: u32, stride3: u32, offset3: u32, depth: u8, bpp: u8, modifier: u64, buffers: Vec<RawFdContainer>) -> Result<VoidCookie<'_, Self>, ConnectionError>
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
+    #[inline]
+    fn dri3_pixmap_from_buffers(&self, pixmap: xproto::Pixmap, window: xproto::Window, width: u16, height: u16, stride0: u32, offset0: u32, stride1: u32, offset1: u32, stride2: u32, offset2: u32, stride3: u32, offset3: u32, depth: u8, bpp: u8, modifier: u64, buffers: Vec<OwnedFd>) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         pixmap_from_buffers(self, pixmap, window, width, height, stride0, offset0, stride1, offset1, stride2, offset2, stride3, offset3, depth, bpp, modifier, buffers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
+    #[inline]
     fn dri3_buffers_from_pixmap(&self, pixmap: xproto::Pixmap) -> Result<CookieWithFds<'_, Self, BuffersFromPixmapReply>, ConnectionError>
     {
         buffers_from_pixmap(self, pixmap)
@@ -4,6 +4,8 @@
 //! Bindings to the `Glx` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -386,11 +388,13 @@ impl std::fmt::Debug for PBCDT  {
 
 /// Opcode for the Render request
 pub const RENDER_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RenderRequest<'input> {
     pub context_tag: ContextTag,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
 impl<'input> RenderRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -420,12 +424,62 @@ impl<'input> RenderRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            RENDER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -449,9 +503,12 @@ impl<'input> RenderRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
 impl<'input> Request for RenderRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
+#[inline]
 pub fn render<'c, 'input, Conn>(conn: &'c Conn, context_tag: ContextTag, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -465,6 +522,7 @@ where
 
 /// Opcode for the RenderLarge request
 pub const RENDER_LARGE_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RenderLargeRequest<'input> {
     pub context_tag: ContextTag,
@@ -472,6 +530,7 @@ pub struct RenderLargeRequest<'input> {
     pub request_total: u16,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
 impl<'input> RenderLargeRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -513,12 +572,74 @@ impl<'input> RenderLargeRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let request_num_bytes = self.request_num.serialize();
+        let request_total_bytes = self.request_total.serialize();
+        let data_len = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        let data_len_bytes = data_len.serialize();
+        write(&[
+            major_opcode,
+            RENDER_LARGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            request_num_bytes[0],
+            request_num_bytes[1],
+            request_total_bytes[0],
+            request_total_bytes[1],
+            data_len_bytes[0],
+            data_len_bytes[1],
+            data_len_bytes[2],
+            data_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -549,9 +670,12 @@ impl<'input> RenderLargeRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
 impl<'input> Request for RenderLargeRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
+#[inline]
 pub fn render_large<'c, 'input, Conn>(conn: &'c Conn, context_tag: ContextTag, request_num: u16, request_total: u16, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -567,6 +691,7 @@ where
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateContextRequest {
     pub context: Context,
@@ -575,6 +700,7 @@ pub struct CreateContextRequest {
     pub share_list: Context,
     pub is_direct: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
 impl CreateContextRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -621,12 +747,76 @@ impl CreateContextRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        let visual_bytes = self.visual.serialize();
+        let screen_bytes = self.screen.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        write(&[
+            major_opcode,
+            CREATE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -650,9 +840,12 @@ impl CreateContextRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
 impl Request for CreateContextRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
+#[inline]
 pub fn create_context<Conn>(conn: &Conn, context: Context, visual: xproto::Visualid, screen: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -669,10 +862,12 @@ where
 
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyContextRequest {
     pub context: Context,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
 impl DestroyContextRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -699,12 +894,56 @@ impl DestroyContextRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -719,9 +958,12 @@ impl DestroyContextRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
 impl Request for DestroyContextRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
+#[inline]
 pub fn destroy_context<Conn>(conn: &Conn, context: Context) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -734,12 +976,14 @@ where
 
 /// Opcode for the MakeCurrent request
 pub const MAKE_CURRENT_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeCurrentRequest {
     pub drawable: Drawable,
     pub context: Context,
     pub old_context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 impl MakeCurrentRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -776,12 +1020,66 @@ impl MakeCurrentRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let context_bytes = self.context.serialize();
+        let old_context_tag_bytes = self.old_context_tag.serialize();
+        write(&[
+            major_opcode,
+            MAKE_CURRENT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, MakeCurrentReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -800,9 +1098,12 @@ impl MakeCurrentRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 impl Request for MakeCurrentRequest {
     type Reply = MakeCurrentReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
+#[inline]
 pub fn make_current<Conn>(conn: &Conn, drawable: Drawable, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Conn, MakeCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -815,12 +1116,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeCurrentReply {
     pub sequence: u16,
     pub length: u32,
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 impl TryParse for MakeCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -835,7 +1138,8 @@ impl TryParse for MakeCurrentReply {
         }
         let result = MakeCurrentReply { sequence, length, context_tag };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -843,10 +1147,12 @@ impl TryParse for MakeCurrentReply {
 
 /// Opcode for the IsDirect request
 pub const IS_DIRECT_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsDirectRequest {
     pub context: Context,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 impl IsDirectRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -873,12 +1179,56 @@ impl IsDirectRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        write(&[
+            major_opcode,
+            IS_DIRECT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, IsDirectReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -893,9 +1243,12 @@ impl IsDirectRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 impl Request for IsDirectRequest {
     type Reply = IsDirectReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
+#[inline]
 pub fn is_direct<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, IsDirectReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -906,12 +1259,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsDirectReply {
     pub sequence: u16,
     pub length: u32,
     pub is_direct: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 impl TryParse for IsDirectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -926,7 +1281,8 @@ impl TryParse for IsDirectReply {
         }
         let result = IsDirectReply { sequence, length, is_direct };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -934,11 +1290,13 @@ impl TryParse for IsDirectReply {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -970,12 +1328,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -992,9 +1399,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1006,6 +1416,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -1013,6 +1424,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1028,7 +1440,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1036,10 +1449,12 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the WaitGL request
 pub const WAIT_GL_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitGLRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
 impl WaitGLRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1066,12 +1481,56 @@ impl WaitGLRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            WAIT_GL_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1086,9 +1545,12 @@ impl WaitGLRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
 impl Request for WaitGLRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
+#[inline]
 pub fn wait_gl<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1101,10 +1563,12 @@ where
 
 /// Opcode for the WaitX request
 pub const WAIT_X_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WaitXRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
 impl WaitXRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1131,12 +1595,56 @@ impl WaitXRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            WAIT_X_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1151,9 +1659,12 @@ impl WaitXRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
 impl Request for WaitXRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
+#[inline]
 pub fn wait_x<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1166,6 +1677,7 @@ where
 
 /// Opcode for the CopyContext request
 pub const COPY_CONTEXT_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyContextRequest {
     pub src: Context,
@@ -1173,6 +1685,7 @@ pub struct CopyContextRequest {
     pub mask: u32,
     pub src_context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
 impl CopyContextRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1214,22 +1727,81 @@ impl CopyContextRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != COPY_CONTEXT_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (src, remaining) = Context::try_parse(value)?;
-        let (dest, remaining) = Context::try_parse(remaining)?;
-        let (mask, remaining) = u32::try_parse(remaining)?;
+        let length_so_far = 0;
+        let src_bytes = self.src.serialize();
+        let dest_bytes = self.dest.serialize();
+        let mask_bytes = self.mask.serialize();
+        let src_context_tag_bytes = self.src_context_tag.serialize();
+        write(&[
+            major_opcode,
+            COPY_CONTEXT_REQUEST,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            src_context_tag_bytes[0],
+            src_context_tag_bytes[1],
+            src_context_tag_bytes[2],
+            src_context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (src, remaining) = Context::try_parse(value)?;
+        let (dest, remaining) = Context::try_parse(remaining)?;
+        let (mask, remaining) = u32::try_parse(remaining)?;
         let (src_context_tag, remaining) = ContextTag::try_parse(remaining)?;
         let _ = remaining;
         Ok(CopyContextRequest {
@@ -1240,9 +1812,12 @@ impl CopyContextRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
 impl Request for CopyContextRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
+#[inline]
 pub fn copy_context<Conn>(conn: &Conn, src: Context, dest: Context, mask: u32, src_context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1342,11 +1917,13 @@ impl std::fmt::Debug for GC  {
 
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapBuffersRequest {
     pub context_tag: ContextTag,
     pub drawable: Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
 impl SwapBuffersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1378,12 +1955,61 @@ impl SwapBuffersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1400,9 +2026,12 @@ impl SwapBuffersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
 impl Request for SwapBuffersRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
+#[inline]
 pub fn swap_buffers<Conn>(conn: &Conn, context_tag: ContextTag, drawable: Drawable) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1416,6 +2045,7 @@ where
 
 /// Opcode for the UseXFont request
 pub const USE_X_FONT_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UseXFontRequest {
     pub context_tag: ContextTag,
@@ -1424,6 +2054,7 @@ pub struct UseXFontRequest {
     pub count: u32,
     pub list_base: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
 impl UseXFontRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1470,12 +2101,76 @@ impl UseXFontRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let font_bytes = self.font.serialize();
+        let first_bytes = self.first.serialize();
+        let count_bytes = self.count.serialize();
+        let list_base_bytes = self.list_base.serialize();
+        write(&[
+            major_opcode,
+            USE_X_FONT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+            first_bytes[0],
+            first_bytes[1],
+            first_bytes[2],
+            first_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+            list_base_bytes[0],
+            list_base_bytes[1],
+            list_base_bytes[2],
+            list_base_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1498,9 +2193,12 @@ impl UseXFontRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
 impl Request for UseXFontRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
+#[inline]
 pub fn use_x_font<Conn>(conn: &Conn, context_tag: ContextTag, font: xproto::Font, first: u32, count: u32, list_base: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1517,6 +2215,7 @@ where
 
 /// Opcode for the CreateGLXPixmap request
 pub const CREATE_GLX_PIXMAP_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateGLXPixmapRequest {
     pub screen: u32,
@@ -1524,6 +2223,7 @@ pub struct CreateGLXPixmapRequest {
     pub pixmap: xproto::Pixmap,
     pub glx_pixmap: Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
 impl CreateGLXPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1565,12 +2265,71 @@ impl CreateGLXPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let visual_bytes = self.visual.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        write(&[
+            major_opcode,
+            CREATE_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1591,9 +2350,12 @@ impl CreateGLXPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
 impl Request for CreateGLXPixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
+#[inline]
 pub fn create_glx_pixmap<Conn>(conn: &Conn, screen: u32, visual: xproto::Visualid, pixmap: xproto::Pixmap, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1609,10 +2371,12 @@ where
 
 /// Opcode for the GetVisualConfigs request
 pub const GET_VISUAL_CONFIGS_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetVisualConfigsRequest {
     pub screen: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 impl GetVisualConfigsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1639,12 +2403,56 @@ impl GetVisualConfigsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_VISUAL_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetVisualConfigsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1659,9 +2467,12 @@ impl GetVisualConfigsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 impl Request for GetVisualConfigsRequest {
     type Reply = GetVisualConfigsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
+#[inline]
 pub fn get_visual_configs<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetVisualConfigsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1672,6 +2483,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetVisualConfigsReply {
     pub sequence: u16,
@@ -1679,6 +2491,7 @@ pub struct GetVisualConfigsReply {
     pub num_properties: u32,
     pub property_list: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 impl TryParse for GetVisualConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1695,11 +2508,13 @@ impl TryParse for GetVisualConfigsReply {
         }
         let result = GetVisualConfigsReply { sequence, num_visuals, num_properties, property_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 impl GetVisualConfigsReply {
     /// Get the value of the `length` field.
     ///
@@ -1718,10 +2533,12 @@ impl GetVisualConfigsReply {
 
 /// Opcode for the DestroyGLXPixmap request
 pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyGLXPixmapRequest {
     pub glx_pixmap: Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
 impl DestroyGLXPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1748,12 +2565,56 @@ impl DestroyGLXPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1768,9 +2629,12 @@ impl DestroyGLXPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
 impl Request for DestroyGLXPixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
+#[inline]
 pub fn destroy_glx_pixmap<Conn>(conn: &Conn, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1783,12 +2647,14 @@ where
 
 /// Opcode for the VendorPrivate request
 pub const VENDOR_PRIVATE_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VendorPrivateRequest<'input> {
     pub vendor_code: u32,
     pub context_tag: ContextTag,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
 impl<'input> VendorPrivateRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1823,12 +2689,67 @@ impl<'input> VendorPrivateRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let vendor_code_bytes = self.vendor_code.serialize();
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            VENDOR_PRIVATE_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1855,9 +2776,12 @@ impl<'input> VendorPrivateRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
 impl<'input> Request for VendorPrivateRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
+#[inline]
 pub fn vendor_private<'c, 'input, Conn>(conn: &'c Conn, vendor_code: u32, context_tag: ContextTag, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1872,12 +2796,14 @@ where
 
 /// Opcode for the VendorPrivateWithReply request
 pub const VENDOR_PRIVATE_WITH_REPLY_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VendorPrivateWithReplyRequest<'input> {
     pub vendor_code: u32,
     pub context_tag: ContextTag,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 impl<'input> VendorPrivateWithReplyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1912,12 +2838,67 @@ impl<'input> VendorPrivateWithReplyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let vendor_code_bytes = self.vendor_code.serialize();
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            VENDOR_PRIVATE_WITH_REPLY_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, VendorPrivateWithReplyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1944,9 +2925,12 @@ impl<'input> VendorPrivateWithReplyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 impl<'input> Request for VendorPrivateWithReplyRequest<'input> {
     type Reply = VendorPrivateWithReplyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
+#[inline]
 pub fn vendor_private_with_reply<'c, 'input, Conn>(conn: &'c Conn, vendor_code: u32, context_tag: ContextTag, data: &'input [u8]) -> Result<Cookie<'c, Conn, VendorPrivateWithReplyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1959,6 +2943,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VendorPrivateWithReplyReply {
     pub sequence: u16,
@@ -1966,6 +2951,7 @@ pub struct VendorPrivateWithReplyReply {
     pub data1: [u8; 24],
     pub data2: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 impl TryParse for VendorPrivateWithReplyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1983,11 +2969,13 @@ impl TryParse for VendorPrivateWithReplyReply {
         }
         let result = VendorPrivateWithReplyReply { sequence, retval, data1, data2 };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 impl VendorPrivateWithReplyReply {
     /// Get the value of the `length` field.
     ///
@@ -2007,10 +2995,12 @@ impl VendorPrivateWithReplyReply {
 
 /// Opcode for the QueryExtensionsString request
 pub const QUERY_EXTENSIONS_STRING_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtensionsStringRequest {
     pub screen: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 impl QueryExtensionsStringRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2037,12 +3027,56 @@ impl QueryExtensionsStringRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryExtensionsStringReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            QUERY_EXTENSIONS_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryExtensionsStringReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2057,9 +3091,12 @@ impl QueryExtensionsStringRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 impl Request for QueryExtensionsStringRequest {
     type Reply = QueryExtensionsStringReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
+#[inline]
 pub fn query_extensions_string<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, QueryExtensionsStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2070,12 +3107,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtensionsStringReply {
     pub sequence: u16,
     pub length: u32,
     pub n: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 impl TryParse for QueryExtensionsStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2091,7 +3130,8 @@ impl TryParse for QueryExtensionsStringReply {
         }
         let result = QueryExtensionsStringReply { sequence, length, n };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2099,11 +3139,13 @@ impl TryParse for QueryExtensionsStringReply {
 
 /// Opcode for the QueryServerString request
 pub const QUERY_SERVER_STRING_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryServerStringRequest {
     pub screen: u32,
     pub name: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 impl QueryServerStringRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2135,12 +3177,61 @@ impl QueryServerStringRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let name_bytes = self.name.serialize();
+        write(&[
+            major_opcode,
+            QUERY_SERVER_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryServerStringReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2157,9 +3248,12 @@ impl QueryServerStringRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 impl Request for QueryServerStringRequest {
     type Reply = QueryServerStringReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
+#[inline]
 pub fn query_server_string<Conn>(conn: &Conn, screen: u32, name: u32) -> Result<Cookie<'_, Conn, QueryServerStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2171,12 +3265,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryServerStringReply {
     pub sequence: u16,
     pub length: u32,
     pub string: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 impl TryParse for QueryServerStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2194,11 +3290,13 @@ impl TryParse for QueryServerStringReply {
         }
         let result = QueryServerStringReply { sequence, length, string };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 impl QueryServerStringReply {
     /// Get the value of the `str_len` field.
     ///
@@ -2217,12 +3315,14 @@ impl QueryServerStringReply {
 
 /// Opcode for the ClientInfo request
 pub const CLIENT_INFO_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClientInfoRequest<'input> {
     pub major_version: u32,
     pub minor_version: u32,
     pub string: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
 impl<'input> ClientInfoRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2263,12 +3363,73 @@ impl<'input> ClientInfoRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.string, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        let str_len = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        let str_len_bytes = str_len.serialize();
+        write(&[
+            major_opcode,
+            CLIENT_INFO_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            str_len_bytes[0],
+            str_len_bytes[1],
+            str_len_bytes[2],
+            str_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.string.len();
+        write(&self.string[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.string.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2296,9 +3457,12 @@ impl<'input> ClientInfoRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
 impl<'input> Request for ClientInfoRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
+#[inline]
 pub fn client_info<'c, 'input, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, string: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2313,10 +3477,12 @@ where
 
 /// Opcode for the GetFBConfigs request
 pub const GET_FB_CONFIGS_REQUEST: u8 = 21;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetFBConfigsRequest {
     pub screen: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 impl GetFBConfigsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2343,12 +3509,56 @@ impl GetFBConfigsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_FB_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetFBConfigsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2363,9 +3573,12 @@ impl GetFBConfigsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 impl Request for GetFBConfigsRequest {
     type Reply = GetFBConfigsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
+#[inline]
 pub fn get_fb_configs<Conn>(conn: &Conn, screen: u32) -> Result<Cookie<'_, Conn, GetFBConfigsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2376,6 +3589,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetFBConfigsReply {
     pub sequence: u16,
@@ -2383,6 +3597,7 @@ pub struct GetFBConfigsReply {
     pub num_properties: u32,
     pub property_list: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 impl TryParse for GetFBConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2399,11 +3614,13 @@ impl TryParse for GetFBConfigsReply {
         }
         let result = GetFBConfigsReply { sequence, num_fb_configs, num_properties, property_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 impl GetFBConfigsReply {
     /// Get the value of the `length` field.
     ///
@@ -2422,6 +3639,7 @@ impl GetFBConfigsReply {
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreatePixmapRequest<'input> {
     pub screen: u32,
@@ -2430,6 +3648,7 @@ pub struct CreatePixmapRequest<'input> {
     pub glx_pixmap: Pixmap,
     pub attribs: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
 impl<'input> CreatePixmapRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2482,12 +3701,85 @@ impl<'input> CreatePixmapRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        write(&[
+            major_opcode,
+            CREATE_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let attribs_bytes = self.attribs.serialize();
+        let length_so_far = length_so_far + attribs_bytes.len();
+        write(&attribs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.attribs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2521,9 +3813,12 @@ impl<'input> CreatePixmapRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
 impl<'input> Request for CreatePixmapRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
+#[inline]
 pub fn create_pixmap<'c, 'input, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, pixmap: xproto::Pixmap, glx_pixmap: Pixmap, attribs: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2540,10 +3835,12 @@ where
 
 /// Opcode for the DestroyPixmap request
 pub const DESTROY_PIXMAP_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyPixmapRequest {
     pub glx_pixmap: Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
 impl DestroyPixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2570,12 +3867,56 @@ impl DestroyPixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2590,9 +3931,12 @@ impl DestroyPixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
 impl Request for DestroyPixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
+#[inline]
 pub fn destroy_pixmap<Conn>(conn: &Conn, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2605,6 +3949,7 @@ where
 
 /// Opcode for the CreateNewContext request
 pub const CREATE_NEW_CONTEXT_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateNewContextRequest {
     pub context: Context,
@@ -2614,6 +3959,7 @@ pub struct CreateNewContextRequest {
     pub share_list: Context,
     pub is_direct: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
 impl CreateNewContextRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2665,12 +4011,81 @@ impl CreateNewContextRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let screen_bytes = self.screen.serialize();
+        let render_type_bytes = self.render_type.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        write(&[
+            major_opcode,
+            CREATE_NEW_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            render_type_bytes[0],
+            render_type_bytes[1],
+            render_type_bytes[2],
+            render_type_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2696,9 +4111,12 @@ impl CreateNewContextRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
 impl Request for CreateNewContextRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
+#[inline]
 pub fn create_new_context<Conn>(conn: &Conn, context: Context, fbconfig: Fbconfig, screen: u32, render_type: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2716,10 +4134,12 @@ where
 
 /// Opcode for the QueryContext request
 pub const QUERY_CONTEXT_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryContextRequest {
     pub context: Context,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 impl QueryContextRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2746,12 +4166,56 @@ impl QueryContextRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        write(&[
+            major_opcode,
+            QUERY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryContextReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2766,9 +4230,12 @@ impl QueryContextRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 impl Request for QueryContextRequest {
     type Reply = QueryContextReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
+#[inline]
 pub fn query_context<Conn>(conn: &Conn, context: Context) -> Result<Cookie<'_, Conn, QueryContextReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2779,12 +4246,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryContextReply {
     pub sequence: u16,
     pub length: u32,
     pub attribs: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 impl TryParse for QueryContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2800,11 +4269,13 @@ impl TryParse for QueryContextReply {
         }
         let result = QueryContextReply { sequence, length, attribs };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 impl QueryContextReply {
     /// Get the value of the `num_attribs` field.
     ///
@@ -2824,6 +4295,7 @@ impl QueryContextReply {
 
 /// Opcode for the MakeContextCurrent request
 pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeContextCurrentRequest {
     pub old_context_tag: ContextTag,
@@ -2831,6 +4303,7 @@ pub struct MakeContextCurrentRequest {
     pub read_drawable: Drawable,
     pub context: Context,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 impl MakeContextCurrentRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2872,23 +4345,82 @@ impl MakeContextCurrentRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, MakeContextCurrentReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != MAKE_CONTEXT_CURRENT_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (old_context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (read_drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (context, remaining) = Context::try_parse(remaining)?;
+        let length_so_far = 0;
+        let old_context_tag_bytes = self.old_context_tag.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let read_drawable_bytes = self.read_drawable.serialize();
+        let context_bytes = self.context.serialize();
+        write(&[
+            major_opcode,
+            MAKE_CONTEXT_CURRENT_REQUEST,
+            0,
+            0,
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            read_drawable_bytes[0],
+            read_drawable_bytes[1],
+            read_drawable_bytes[2],
+            read_drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, MakeContextCurrentReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != MAKE_CONTEXT_CURRENT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (old_context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (drawable, remaining) = Drawable::try_parse(remaining)?;
+        let (read_drawable, remaining) = Drawable::try_parse(remaining)?;
+        let (context, remaining) = Context::try_parse(remaining)?;
         let _ = remaining;
         Ok(MakeContextCurrentRequest {
             old_context_tag,
@@ -2898,9 +4430,12 @@ impl MakeContextCurrentRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 impl Request for MakeContextCurrentRequest {
     type Reply = MakeContextCurrentReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
+#[inline]
 pub fn make_context_current<Conn>(conn: &Conn, old_context_tag: ContextTag, drawable: Drawable, read_drawable: Drawable, context: Context) -> Result<Cookie<'_, Conn, MakeContextCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2914,12 +4449,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MakeContextCurrentReply {
     pub sequence: u16,
     pub length: u32,
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 impl TryParse for MakeContextCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2934,7 +4471,8 @@ impl TryParse for MakeContextCurrentReply {
         }
         let result = MakeContextCurrentReply { sequence, length, context_tag };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2942,6 +4480,7 @@ impl TryParse for MakeContextCurrentReply {
 
 /// Opcode for the CreatePbuffer request
 pub const CREATE_PBUFFER_REQUEST: u8 = 27;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreatePbufferRequest<'input> {
     pub screen: u32,
@@ -2949,6 +4488,7 @@ pub struct CreatePbufferRequest<'input> {
     pub pbuffer: Pbuffer,
     pub attribs: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
 impl<'input> CreatePbufferRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2996,12 +4536,80 @@ impl<'input> CreatePbufferRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let pbuffer_bytes = self.pbuffer.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        write(&[
+            major_opcode,
+            CREATE_PBUFFER_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        let attribs_bytes = self.attribs.serialize();
+        let length_so_far = length_so_far + attribs_bytes.len();
+        write(&attribs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.attribs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3032,9 +4640,12 @@ impl<'input> CreatePbufferRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
 impl<'input> Request for CreatePbufferRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
+#[inline]
 pub fn create_pbuffer<'c, 'input, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, pbuffer: Pbuffer, attribs: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3050,10 +4661,12 @@ where
 
 /// Opcode for the DestroyPbuffer request
 pub const DESTROY_PBUFFER_REQUEST: u8 = 28;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyPbufferRequest {
     pub pbuffer: Pbuffer,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
 impl DestroyPbufferRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3080,12 +4693,56 @@ impl DestroyPbufferRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pbuffer_bytes = self.pbuffer.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_PBUFFER_REQUEST,
+            0,
+            0,
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3100,9 +4757,12 @@ impl DestroyPbufferRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
 impl Request for DestroyPbufferRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
+#[inline]
 pub fn destroy_pbuffer<Conn>(conn: &Conn, pbuffer: Pbuffer) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3115,10 +4775,12 @@ where
 
 /// Opcode for the GetDrawableAttributes request
 pub const GET_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 29;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDrawableAttributesRequest {
     pub drawable: Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 impl GetDrawableAttributesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3145,12 +4807,56 @@ impl GetDrawableAttributesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            GET_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDrawableAttributesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3165,9 +4871,12 @@ impl GetDrawableAttributesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 impl Request for GetDrawableAttributesRequest {
     type Reply = GetDrawableAttributesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
+#[inline]
 pub fn get_drawable_attributes<Conn>(conn: &Conn, drawable: Drawable) -> Result<Cookie<'_, Conn, GetDrawableAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3178,12 +4887,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDrawableAttributesReply {
     pub sequence: u16,
     pub length: u32,
     pub attribs: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 impl TryParse for GetDrawableAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3199,11 +4910,13 @@ impl TryParse for GetDrawableAttributesReply {
         }
         let result = GetDrawableAttributesReply { sequence, length, attribs };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 impl GetDrawableAttributesReply {
     /// Get the value of the `num_attribs` field.
     ///
@@ -3223,11 +4936,13 @@ impl GetDrawableAttributesReply {
 
 /// Opcode for the ChangeDrawableAttributes request
 pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeDrawableAttributesRequest<'input> {
     pub drawable: Drawable,
     pub attribs: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
 impl<'input> ChangeDrawableAttributesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3265,12 +4980,70 @@ impl<'input> ChangeDrawableAttributesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let attribs_bytes = self.attribs.serialize();
+        let length_so_far = length_so_far + attribs_bytes.len();
+        write(&attribs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.attribs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3295,9 +5068,12 @@ impl<'input> ChangeDrawableAttributesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
 impl<'input> Request for ChangeDrawableAttributesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
+#[inline]
 pub fn change_drawable_attributes<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, attribs: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3311,6 +5087,7 @@ where
 
 /// Opcode for the CreateWindow request
 pub const CREATE_WINDOW_REQUEST: u8 = 31;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateWindowRequest<'input> {
     pub screen: u32,
@@ -3319,6 +5096,7 @@ pub struct CreateWindowRequest<'input> {
     pub glx_window: Window,
     pub attribs: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
 impl<'input> CreateWindowRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3371,12 +5149,85 @@ impl<'input> CreateWindowRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let window_bytes = self.window.serialize();
+        let glx_window_bytes = self.glx_window.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        write(&[
+            major_opcode,
+            CREATE_WINDOW_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            glx_window_bytes[0],
+            glx_window_bytes[1],
+            glx_window_bytes[2],
+            glx_window_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let attribs_bytes = self.attribs.serialize();
+        let length_so_far = length_so_far + attribs_bytes.len();
+        write(&attribs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.attribs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3410,9 +5261,12 @@ impl<'input> CreateWindowRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
 impl<'input> Request for CreateWindowRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
+#[inline]
 pub fn create_window<'c, 'input, Conn>(conn: &'c Conn, screen: u32, fbconfig: Fbconfig, window: xproto::Window, glx_window: Window, attribs: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3429,10 +5283,12 @@ where
 
 /// Opcode for the DeleteWindow request
 pub const DELETE_WINDOW_REQUEST: u8 = 32;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteWindowRequest {
     pub glxwindow: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
 impl DeleteWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3459,12 +5315,56 @@ impl DeleteWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glxwindow_bytes = self.glxwindow.serialize();
+        write(&[
+            major_opcode,
+            DELETE_WINDOW_REQUEST,
+            0,
+            0,
+            glxwindow_bytes[0],
+            glxwindow_bytes[1],
+            glxwindow_bytes[2],
+            glxwindow_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3479,9 +5379,12 @@ impl DeleteWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
 impl Request for DeleteWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
+#[inline]
 pub fn delete_window<Conn>(conn: &Conn, glxwindow: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3494,6 +5397,7 @@ where
 
 /// Opcode for the SetClientInfoARB request
 pub const SET_CLIENT_INFO_ARB_REQUEST: u8 = 33;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetClientInfoARBRequest<'input> {
     pub major_version: u32,
@@ -3502,6 +5406,7 @@ pub struct SetClientInfoARBRequest<'input> {
     pub gl_extension_string: Cow<'input, [u8]>,
     pub glx_extension_string: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
 impl<'input> SetClientInfoARBRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3558,12 +5463,91 @@ impl<'input> SetClientInfoARBRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), gl_versions_bytes.into(), self.gl_extension_string, self.glx_extension_string, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        assert_eq!(self.gl_versions.len() % 2, 0, "`gl_versions` has an incorrect length, must be a multiple of 2");
+        let num_versions = u32::try_from(self.gl_versions.len() / 2).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize();
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize();
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize();
+        write(&[
+            major_opcode,
+            SET_CLIENT_INFO_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let gl_versions_bytes = self.gl_versions.serialize();
+        let length_so_far = length_so_far + gl_versions_bytes.len();
+        write(&gl_versions_bytes[..]);
+        let length_so_far = length_so_far + self.gl_extension_string.len();
+        write(&self.gl_extension_string[..]);
+        let length_so_far = length_so_far + self.glx_extension_string.len();
+        write(&self.glx_extension_string[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.gl_versions.len() * 4 + self.gl_extension_string.len() + self.glx_extension_string.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3599,9 +5583,12 @@ impl<'input> SetClientInfoARBRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
 impl<'input> Request for SetClientInfoARBRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
+#[inline]
 pub fn set_client_info_arb<'c, 'input, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, gl_versions: &'input [u32], gl_extension_string: &'input [u8], glx_extension_string: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3618,6 +5605,7 @@ where
 
 /// Opcode for the CreateContextAttribsARB request
 pub const CREATE_CONTEXT_ATTRIBS_ARB_REQUEST: u8 = 34;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateContextAttribsARBRequest<'input> {
     pub context: Context,
@@ -3627,14 +5615,67 @@ pub struct CreateContextAttribsARBRequest<'input> {
     pub is_direct: bool,
     pub attribs: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
 impl<'input> CreateContextAttribsARBRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let screen_bytes = self.screen.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            CREATE_CONTEXT_ATTRIBS_ARB_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize();
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let context_bytes = self.context.serialize();
         let fbconfig_bytes = self.fbconfig.serialize();
@@ -3644,8 +5685,8 @@ impl<'input> CreateContextAttribsARBRequest<'input> {
         assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
         let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
         let num_attribs_bytes = num_attribs.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             CREATE_CONTEXT_ATTRIBS_ARB_REQUEST,
             0,
             0,
@@ -3673,23 +5714,49 @@ impl<'input> CreateContextAttribsARBRequest<'input> {
             num_attribs_bytes[1],
             num_attribs_bytes[2],
             num_attribs_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 28;
         let attribs_bytes = self.attribs.serialize();
         let length_so_far = length_so_far + attribs_bytes.len();
+        write(&attribs_bytes[..]);
         let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
         let length_so_far = length_so_far + padding0.len();
+        write(padding0);
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.attribs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3727,9 +5794,12 @@ impl<'input> CreateContextAttribsARBRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
 impl<'input> Request for CreateContextAttribsARBRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
+#[inline]
 pub fn create_context_attribs_arb<'c, 'input, Conn>(conn: &'c Conn, context: Context, fbconfig: Fbconfig, screen: u32, share_list: Context, is_direct: bool, attribs: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3747,6 +5817,7 @@ where
 
 /// Opcode for the SetClientInfo2ARB request
 pub const SET_CLIENT_INFO2_ARB_REQUEST: u8 = 35;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetClientInfo2ARBRequest<'input> {
     pub major_version: u32,
@@ -3755,6 +5826,7 @@ pub struct SetClientInfo2ARBRequest<'input> {
     pub gl_extension_string: Cow<'input, [u8]>,
     pub glx_extension_string: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
 impl<'input> SetClientInfo2ARBRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3811,12 +5883,91 @@ impl<'input> SetClientInfo2ARBRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), gl_versions_bytes.into(), self.gl_extension_string, self.glx_extension_string, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        assert_eq!(self.gl_versions.len() % 3, 0, "`gl_versions` has an incorrect length, must be a multiple of 3");
+        let num_versions = u32::try_from(self.gl_versions.len() / 3).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize();
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize();
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize();
+        write(&[
+            major_opcode,
+            SET_CLIENT_INFO2_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let gl_versions_bytes = self.gl_versions.serialize();
+        let length_so_far = length_so_far + gl_versions_bytes.len();
+        write(&gl_versions_bytes[..]);
+        let length_so_far = length_so_far + self.gl_extension_string.len();
+        write(&self.gl_extension_string[..]);
+        let length_so_far = length_so_far + self.glx_extension_string.len();
+        write(&self.glx_extension_string[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.gl_versions.len() * 4 + self.gl_extension_string.len() + self.glx_extension_string.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3852,9 +6003,12 @@ impl<'input> SetClientInfo2ARBRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
 impl<'input> Request for SetClientInfo2ARBRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
+#[inline]
 pub fn set_client_info2_arb<'c, 'input, Conn>(conn: &'c Conn, major_version: u32, minor_version: u32, gl_versions: &'input [u32], gl_extension_string: &'input [u8], glx_extension_string: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3871,12 +6025,14 @@ where
 
 /// Opcode for the NewList request
 pub const NEW_LIST_REQUEST: u8 = 101;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NewListRequest {
     pub context_tag: ContextTag,
     pub list: u32,
     pub mode: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
 impl NewListRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3913,12 +6069,66 @@ impl NewListRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        let mode_bytes = self.mode.serialize();
+        write(&[
+            major_opcode,
+            NEW_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3937,9 +6147,12 @@ impl NewListRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
 impl Request for NewListRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
+#[inline]
 pub fn new_list<Conn>(conn: &Conn, context_tag: ContextTag, list: u32, mode: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3954,10 +6167,12 @@ where
 
 /// Opcode for the EndList request
 pub const END_LIST_REQUEST: u8 = 102;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EndListRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
 impl EndListRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3984,12 +6199,56 @@ impl EndListRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            END_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4004,9 +6263,12 @@ impl EndListRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
 impl Request for EndListRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
+#[inline]
 pub fn end_list<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4019,12 +6281,14 @@ where
 
 /// Opcode for the DeleteLists request
 pub const DELETE_LISTS_REQUEST: u8 = 103;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteListsRequest {
     pub context_tag: ContextTag,
     pub list: u32,
     pub range: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
 impl DeleteListsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4061,12 +6325,66 @@ impl DeleteListsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        let range_bytes = self.range.serialize();
+        write(&[
+            major_opcode,
+            DELETE_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4085,9 +6403,12 @@ impl DeleteListsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
 impl Request for DeleteListsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
+#[inline]
 pub fn delete_lists<Conn>(conn: &Conn, context_tag: ContextTag, list: u32, range: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4102,11 +6423,13 @@ where
 
 /// Opcode for the GenLists request
 pub const GEN_LISTS_REQUEST: u8 = 104;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenListsRequest {
     pub context_tag: ContextTag,
     pub range: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 impl GenListsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4138,12 +6461,61 @@ impl GenListsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let range_bytes = self.range.serialize();
+        write(&[
+            major_opcode,
+            GEN_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GenListsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4160,9 +6532,12 @@ impl GenListsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 impl Request for GenListsRequest {
     type Reply = GenListsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
+#[inline]
 pub fn gen_lists<Conn>(conn: &Conn, context_tag: ContextTag, range: i32) -> Result<Cookie<'_, Conn, GenListsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4174,12 +6549,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenListsReply {
     pub sequence: u16,
     pub length: u32,
     pub ret_val: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 impl TryParse for GenListsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4193,7 +6570,8 @@ impl TryParse for GenListsReply {
         }
         let result = GenListsReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -4201,12 +6579,14 @@ impl TryParse for GenListsReply {
 
 /// Opcode for the FeedbackBuffer request
 pub const FEEDBACK_BUFFER_REQUEST: u8 = 105;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FeedbackBufferRequest {
     pub context_tag: ContextTag,
     pub size: i32,
     pub type_: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
 impl FeedbackBufferRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4243,12 +6623,66 @@ impl FeedbackBufferRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let size_bytes = self.size.serialize();
+        let type_bytes = self.type_.serialize();
+        write(&[
+            major_opcode,
+            FEEDBACK_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4267,9 +6701,12 @@ impl FeedbackBufferRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
 impl Request for FeedbackBufferRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
+#[inline]
 pub fn feedback_buffer<Conn>(conn: &Conn, context_tag: ContextTag, size: i32, type_: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4284,11 +6721,13 @@ where
 
 /// Opcode for the SelectBuffer request
 pub const SELECT_BUFFER_REQUEST: u8 = 106;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectBufferRequest {
     pub context_tag: ContextTag,
     pub size: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
 impl SelectBufferRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4316,16 +6755,65 @@ impl SelectBufferRequest {
         ];
         let length_so_far = length_so_far + request0.len();
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let size_bytes = self.size.serialize();
+        write(&[
+            major_opcode,
+            SELECT_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
     }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4342,9 +6830,12 @@ impl SelectBufferRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
 impl Request for SelectBufferRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
+#[inline]
 pub fn select_buffer<Conn>(conn: &Conn, context_tag: ContextTag, size: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4358,11 +6849,13 @@ where
 
 /// Opcode for the RenderMode request
 pub const RENDER_MODE_REQUEST: u8 = 107;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RenderModeRequest {
     pub context_tag: ContextTag,
     pub mode: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 impl RenderModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4394,12 +6887,61 @@ impl RenderModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let mode_bytes = self.mode.serialize();
+        write(&[
+            major_opcode,
+            RENDER_MODE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, RenderModeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4416,9 +6958,12 @@ impl RenderModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 impl Request for RenderModeRequest {
     type Reply = RenderModeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
+#[inline]
 pub fn render_mode<Conn>(conn: &Conn, context_tag: ContextTag, mode: u32) -> Result<Cookie<'_, Conn, RenderModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4430,6 +6975,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RenderModeReply {
     pub sequence: u16,
@@ -4438,6 +6984,7 @@ pub struct RenderModeReply {
     pub new_mode: u32,
     pub data: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 impl TryParse for RenderModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4455,11 +7002,13 @@ impl TryParse for RenderModeReply {
         }
         let result = RenderModeReply { sequence, length, ret_val, new_mode, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 impl RenderModeReply {
     /// Get the value of the `n` field.
     ///
@@ -4532,10 +7081,12 @@ impl std::fmt::Debug for RM  {
 
 /// Opcode for the Finish request
 pub const FINISH_REQUEST: u8 = 108;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FinishRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 impl FinishRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4562,12 +7113,56 @@ impl FinishRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            FINISH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, FinishReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4582,9 +7177,12 @@ impl FinishRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 impl Request for FinishRequest {
     type Reply = FinishReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
+#[inline]
 pub fn finish<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<Cookie<'_, Conn, FinishReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4595,11 +7193,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FinishReply {
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 impl TryParse for FinishReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4612,7 +7212,8 @@ impl TryParse for FinishReply {
         }
         let result = FinishReply { sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -4620,12 +7221,14 @@ impl TryParse for FinishReply {
 
 /// Opcode for the PixelStoref request
 pub const PIXEL_STOREF_REQUEST: u8 = 109;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PixelStorefRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
     pub datum: Float32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
 impl PixelStorefRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4662,12 +7265,66 @@ impl PixelStorefRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        let datum_bytes = self.datum.serialize();
+        write(&[
+            major_opcode,
+            PIXEL_STOREF_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4686,9 +7343,12 @@ impl PixelStorefRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
 impl Request for PixelStorefRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
+#[inline]
 pub fn pixel_storef<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32, datum: Float32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4703,12 +7363,14 @@ where
 
 /// Opcode for the PixelStorei request
 pub const PIXEL_STOREI_REQUEST: u8 = 110;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PixelStoreiRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
     pub datum: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
 impl PixelStoreiRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4745,12 +7407,66 @@ impl PixelStoreiRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        let datum_bytes = self.datum.serialize();
+        write(&[
+            major_opcode,
+            PIXEL_STOREI_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4769,9 +7485,12 @@ impl PixelStoreiRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
 impl Request for PixelStoreiRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
+#[inline]
 pub fn pixel_storei<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32, datum: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4786,6 +7505,7 @@ where
 
 /// Opcode for the ReadPixels request
 pub const READ_PIXELS_REQUEST: u8 = 111;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReadPixelsRequest {
     pub context_tag: ContextTag,
@@ -4798,6 +7518,7 @@ pub struct ReadPixelsRequest {
     pub swap_bytes: bool,
     pub lsb_first: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 impl ReadPixelsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4860,12 +7581,92 @@ impl ReadPixelsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let lsb_first_bytes = self.lsb_first.serialize();
+        write(&[
+            major_opcode,
+            READ_PIXELS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            width_bytes[2],
+            width_bytes[3],
+            height_bytes[0],
+            height_bytes[1],
+            height_bytes[2],
+            height_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            lsb_first_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 36;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        36
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ReadPixelsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4896,9 +7697,12 @@ impl ReadPixelsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 impl Request for ReadPixelsRequest {
     type Reply = ReadPixelsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
+#[inline]
 pub fn read_pixels<Conn>(conn: &Conn, context_tag: ContextTag, x: i32, y: i32, width: i32, height: i32, format: u32, type_: u32, swap_bytes: bool, lsb_first: bool) -> Result<Cookie<'_, Conn, ReadPixelsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4917,11 +7721,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadPixelsReply {
     pub sequence: u16,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 impl TryParse for ReadPixelsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4937,11 +7743,13 @@ impl TryParse for ReadPixelsReply {
         }
         let result = ReadPixelsReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 impl ReadPixelsReply {
     /// Get the value of the `length` field.
     ///
@@ -4961,11 +7769,13 @@ impl ReadPixelsReply {
 
 /// Opcode for the GetBooleanv request
 pub const GET_BOOLEANV_REQUEST: u8 = 112;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetBooleanvRequest {
     pub context_tag: ContextTag,
     pub pname: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 impl GetBooleanvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4997,12 +7807,61 @@ impl GetBooleanvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_BOOLEANV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetBooleanvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5019,9 +7878,12 @@ impl GetBooleanvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 impl Request for GetBooleanvRequest {
     type Reply = GetBooleanvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
+#[inline]
 pub fn get_booleanv<Conn>(conn: &Conn, context_tag: ContextTag, pname: i32) -> Result<Cookie<'_, Conn, GetBooleanvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5033,6 +7895,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetBooleanvReply {
     pub sequence: u16,
@@ -5040,6 +7903,7 @@ pub struct GetBooleanvReply {
     pub datum: bool,
     pub data: Vec<bool>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 impl TryParse for GetBooleanvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5057,11 +7921,13 @@ impl TryParse for GetBooleanvReply {
         }
         let result = GetBooleanvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 impl GetBooleanvReply {
     /// Get the value of the `n` field.
     ///
@@ -5080,11 +7946,13 @@ impl GetBooleanvReply {
 
 /// Opcode for the GetClipPlane request
 pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetClipPlaneRequest {
     pub context_tag: ContextTag,
     pub plane: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 impl GetClipPlaneRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5116,12 +7984,61 @@ impl GetClipPlaneRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let plane_bytes = self.plane.serialize();
+        write(&[
+            major_opcode,
+            GET_CLIP_PLANE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            plane_bytes[0],
+            plane_bytes[1],
+            plane_bytes[2],
+            plane_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetClipPlaneReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5138,9 +8055,12 @@ impl GetClipPlaneRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 impl Request for GetClipPlaneRequest {
     type Reply = GetClipPlaneReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
+#[inline]
 pub fn get_clip_plane<Conn>(conn: &Conn, context_tag: ContextTag, plane: i32) -> Result<Cookie<'_, Conn, GetClipPlaneReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5152,11 +8072,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetClipPlaneReply {
     pub sequence: u16,
     pub data: Vec<Float64>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 impl TryParse for GetClipPlaneReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5171,11 +8093,13 @@ impl TryParse for GetClipPlaneReply {
         }
         let result = GetClipPlaneReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 impl GetClipPlaneReply {
     /// Get the value of the `length` field.
     ///
@@ -5195,11 +8119,13 @@ impl GetClipPlaneReply {
 
 /// Opcode for the GetDoublev request
 pub const GET_DOUBLEV_REQUEST: u8 = 114;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDoublevRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 impl GetDoublevRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5231,12 +8157,61 @@ impl GetDoublevRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_DOUBLEV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDoublevReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5253,9 +8228,12 @@ impl GetDoublevRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 impl Request for GetDoublevRequest {
     type Reply = GetDoublevReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
+#[inline]
 pub fn get_doublev<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetDoublevReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5267,6 +8245,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetDoublevReply {
     pub sequence: u16,
@@ -5274,6 +8253,7 @@ pub struct GetDoublevReply {
     pub datum: Float64,
     pub data: Vec<Float64>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 impl TryParse for GetDoublevReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5291,11 +8271,13 @@ impl TryParse for GetDoublevReply {
         }
         let result = GetDoublevReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 impl GetDoublevReply {
     /// Get the value of the `n` field.
     ///
@@ -5314,10 +8296,12 @@ impl GetDoublevReply {
 
 /// Opcode for the GetError request
 pub const GET_ERROR_REQUEST: u8 = 115;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetErrorRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 impl GetErrorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5344,12 +8328,56 @@ impl GetErrorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            GET_ERROR_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetErrorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5364,9 +8392,12 @@ impl GetErrorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 impl Request for GetErrorRequest {
     type Reply = GetErrorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
+#[inline]
 pub fn get_error<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<Cookie<'_, Conn, GetErrorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5377,12 +8408,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetErrorReply {
     pub sequence: u16,
     pub length: u32,
     pub error: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 impl TryParse for GetErrorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5396,7 +8429,8 @@ impl TryParse for GetErrorReply {
         }
         let result = GetErrorReply { sequence, length, error };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -5404,11 +8438,13 @@ impl TryParse for GetErrorReply {
 
 /// Opcode for the GetFloatv request
 pub const GET_FLOATV_REQUEST: u8 = 116;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetFloatvRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 impl GetFloatvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5440,12 +8476,61 @@ impl GetFloatvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_FLOATV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetFloatvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5462,9 +8547,12 @@ impl GetFloatvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 impl Request for GetFloatvRequest {
     type Reply = GetFloatvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
+#[inline]
 pub fn get_floatv<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetFloatvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5476,6 +8564,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetFloatvReply {
     pub sequence: u16,
@@ -5483,6 +8572,7 @@ pub struct GetFloatvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 impl TryParse for GetFloatvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5500,11 +8590,13 @@ impl TryParse for GetFloatvReply {
         }
         let result = GetFloatvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 impl GetFloatvReply {
     /// Get the value of the `n` field.
     ///
@@ -5523,11 +8615,13 @@ impl GetFloatvReply {
 
 /// Opcode for the GetIntegerv request
 pub const GET_INTEGERV_REQUEST: u8 = 117;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetIntegervRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 impl GetIntegervRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5559,12 +8653,61 @@ impl GetIntegervRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_INTEGERV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetIntegervReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5581,9 +8724,12 @@ impl GetIntegervRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 impl Request for GetIntegervRequest {
     type Reply = GetIntegervReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
+#[inline]
 pub fn get_integerv<Conn>(conn: &Conn, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Conn, GetIntegervReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5595,6 +8741,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetIntegervReply {
     pub sequence: u16,
@@ -5602,6 +8749,7 @@ pub struct GetIntegervReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 impl TryParse for GetIntegervReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5619,11 +8767,13 @@ impl TryParse for GetIntegervReply {
         }
         let result = GetIntegervReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 impl GetIntegervReply {
     /// Get the value of the `n` field.
     ///
@@ -5642,12 +8792,14 @@ impl GetIntegervReply {
 
 /// Opcode for the GetLightfv request
 pub const GET_LIGHTFV_REQUEST: u8 = 118;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetLightfvRequest {
     pub context_tag: ContextTag,
     pub light: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 impl GetLightfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5684,12 +8836,66 @@ impl GetLightfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let light_bytes = self.light.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_LIGHTFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetLightfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5708,9 +8914,12 @@ impl GetLightfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 impl Request for GetLightfvRequest {
     type Reply = GetLightfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
+#[inline]
 pub fn get_lightfv<Conn>(conn: &Conn, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Conn, GetLightfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5723,6 +8932,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetLightfvReply {
     pub sequence: u16,
@@ -5730,6 +8940,7 @@ pub struct GetLightfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 impl TryParse for GetLightfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5747,11 +8958,13 @@ impl TryParse for GetLightfvReply {
         }
         let result = GetLightfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 impl GetLightfvReply {
     /// Get the value of the `n` field.
     ///
@@ -5770,12 +8983,14 @@ impl GetLightfvReply {
 
 /// Opcode for the GetLightiv request
 pub const GET_LIGHTIV_REQUEST: u8 = 119;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetLightivRequest {
     pub context_tag: ContextTag,
     pub light: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 impl GetLightivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5812,12 +9027,66 @@ impl GetLightivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let light_bytes = self.light.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_LIGHTIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetLightivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5836,9 +9105,12 @@ impl GetLightivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 impl Request for GetLightivRequest {
     type Reply = GetLightivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
+#[inline]
 pub fn get_lightiv<Conn>(conn: &Conn, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Conn, GetLightivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5851,6 +9123,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetLightivReply {
     pub sequence: u16,
@@ -5858,6 +9131,7 @@ pub struct GetLightivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 impl TryParse for GetLightivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5875,11 +9149,13 @@ impl TryParse for GetLightivReply {
         }
         let result = GetLightivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 impl GetLightivReply {
     /// Get the value of the `n` field.
     ///
@@ -5898,12 +9174,14 @@ impl GetLightivReply {
 
 /// Opcode for the GetMapdv request
 pub const GET_MAPDV_REQUEST: u8 = 120;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMapdvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub query: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 impl GetMapdvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5940,12 +9218,66 @@ impl GetMapdvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        write(&[
+            major_opcode,
+            GET_MAPDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMapdvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5964,9 +9296,12 @@ impl GetMapdvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 impl Request for GetMapdvRequest {
     type Reply = GetMapdvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
+#[inline]
 pub fn get_mapdv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapdvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5979,6 +9314,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMapdvReply {
     pub sequence: u16,
@@ -5986,6 +9322,7 @@ pub struct GetMapdvReply {
     pub datum: Float64,
     pub data: Vec<Float64>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 impl TryParse for GetMapdvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6003,11 +9340,13 @@ impl TryParse for GetMapdvReply {
         }
         let result = GetMapdvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 impl GetMapdvReply {
     /// Get the value of the `n` field.
     ///
@@ -6026,12 +9365,14 @@ impl GetMapdvReply {
 
 /// Opcode for the GetMapfv request
 pub const GET_MAPFV_REQUEST: u8 = 121;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMapfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub query: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 impl GetMapfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6068,12 +9409,66 @@ impl GetMapfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        write(&[
+            major_opcode,
+            GET_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMapfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6092,9 +9487,12 @@ impl GetMapfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 impl Request for GetMapfvRequest {
     type Reply = GetMapfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
+#[inline]
 pub fn get_mapfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6107,6 +9505,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMapfvReply {
     pub sequence: u16,
@@ -6114,6 +9513,7 @@ pub struct GetMapfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 impl TryParse for GetMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6131,11 +9531,13 @@ impl TryParse for GetMapfvReply {
         }
         let result = GetMapfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 impl GetMapfvReply {
     /// Get the value of the `n` field.
     ///
@@ -6154,12 +9556,14 @@ impl GetMapfvReply {
 
 /// Opcode for the GetMapiv request
 pub const GET_MAPIV_REQUEST: u8 = 122;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMapivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub query: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 impl GetMapivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6196,12 +9600,66 @@ impl GetMapivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        write(&[
+            major_opcode,
+            GET_MAPIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMapivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6220,9 +9678,12 @@ impl GetMapivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 impl Request for GetMapivRequest {
     type Reply = GetMapivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
+#[inline]
 pub fn get_mapiv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Conn, GetMapivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6235,6 +9696,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMapivReply {
     pub sequence: u16,
@@ -6242,6 +9704,7 @@ pub struct GetMapivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 impl TryParse for GetMapivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6259,11 +9722,13 @@ impl TryParse for GetMapivReply {
         }
         let result = GetMapivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 impl GetMapivReply {
     /// Get the value of the `n` field.
     ///
@@ -6282,12 +9747,14 @@ impl GetMapivReply {
 
 /// Opcode for the GetMaterialfv request
 pub const GET_MATERIALFV_REQUEST: u8 = 123;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMaterialfvRequest {
     pub context_tag: ContextTag,
     pub face: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 impl GetMaterialfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6324,12 +9791,66 @@ impl GetMaterialfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let face_bytes = self.face.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_MATERIALFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMaterialfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6348,9 +9869,12 @@ impl GetMaterialfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 impl Request for GetMaterialfvRequest {
     type Reply = GetMaterialfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
+#[inline]
 pub fn get_materialfv<Conn>(conn: &Conn, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMaterialfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6363,6 +9887,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMaterialfvReply {
     pub sequence: u16,
@@ -6370,6 +9895,7 @@ pub struct GetMaterialfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 impl TryParse for GetMaterialfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6387,11 +9913,13 @@ impl TryParse for GetMaterialfvReply {
         }
         let result = GetMaterialfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 impl GetMaterialfvReply {
     /// Get the value of the `n` field.
     ///
@@ -6410,12 +9938,14 @@ impl GetMaterialfvReply {
 
 /// Opcode for the GetMaterialiv request
 pub const GET_MATERIALIV_REQUEST: u8 = 124;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMaterialivRequest {
     pub context_tag: ContextTag,
     pub face: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 impl GetMaterialivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6452,12 +9982,66 @@ impl GetMaterialivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let face_bytes = self.face.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_MATERIALIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMaterialivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6476,9 +10060,12 @@ impl GetMaterialivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 impl Request for GetMaterialivRequest {
     type Reply = GetMaterialivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
+#[inline]
 pub fn get_materialiv<Conn>(conn: &Conn, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMaterialivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6491,6 +10078,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMaterialivReply {
     pub sequence: u16,
@@ -6498,6 +10086,7 @@ pub struct GetMaterialivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 impl TryParse for GetMaterialivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6515,11 +10104,13 @@ impl TryParse for GetMaterialivReply {
         }
         let result = GetMaterialivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 impl GetMaterialivReply {
     /// Get the value of the `n` field.
     ///
@@ -6538,11 +10129,13 @@ impl GetMaterialivReply {
 
 /// Opcode for the GetPixelMapfv request
 pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPixelMapfvRequest {
     pub context_tag: ContextTag,
     pub map: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 impl GetPixelMapfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6574,12 +10167,61 @@ impl GetPixelMapfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        write(&[
+            major_opcode,
+            GET_PIXEL_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPixelMapfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6596,9 +10238,12 @@ impl GetPixelMapfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 impl Request for GetPixelMapfvRequest {
     type Reply = GetPixelMapfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
+#[inline]
 pub fn get_pixel_mapfv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6610,6 +10255,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetPixelMapfvReply {
     pub sequence: u16,
@@ -6617,6 +10263,7 @@ pub struct GetPixelMapfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 impl TryParse for GetPixelMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6634,11 +10281,13 @@ impl TryParse for GetPixelMapfvReply {
         }
         let result = GetPixelMapfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 impl GetPixelMapfvReply {
     /// Get the value of the `n` field.
     ///
@@ -6657,11 +10306,13 @@ impl GetPixelMapfvReply {
 
 /// Opcode for the GetPixelMapuiv request
 pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPixelMapuivRequest {
     pub context_tag: ContextTag,
     pub map: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 impl GetPixelMapuivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6693,12 +10344,61 @@ impl GetPixelMapuivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        write(&[
+            major_opcode,
+            GET_PIXEL_MAPUIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPixelMapuivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6715,9 +10415,12 @@ impl GetPixelMapuivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 impl Request for GetPixelMapuivRequest {
     type Reply = GetPixelMapuivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
+#[inline]
 pub fn get_pixel_mapuiv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapuivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6729,6 +10432,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPixelMapuivReply {
     pub sequence: u16,
@@ -6736,6 +10440,7 @@ pub struct GetPixelMapuivReply {
     pub datum: u32,
     pub data: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 impl TryParse for GetPixelMapuivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6753,11 +10458,13 @@ impl TryParse for GetPixelMapuivReply {
         }
         let result = GetPixelMapuivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 impl GetPixelMapuivReply {
     /// Get the value of the `n` field.
     ///
@@ -6776,11 +10483,13 @@ impl GetPixelMapuivReply {
 
 /// Opcode for the GetPixelMapusv request
 pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPixelMapusvRequest {
     pub context_tag: ContextTag,
     pub map: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 impl GetPixelMapusvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6812,12 +10521,61 @@ impl GetPixelMapusvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        write(&[
+            major_opcode,
+            GET_PIXEL_MAPUSV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPixelMapusvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6834,9 +10592,12 @@ impl GetPixelMapusvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 impl Request for GetPixelMapusvRequest {
     type Reply = GetPixelMapusvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
+#[inline]
 pub fn get_pixel_mapusv<Conn>(conn: &Conn, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Conn, GetPixelMapusvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6848,6 +10609,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPixelMapusvReply {
     pub sequence: u16,
@@ -6855,6 +10617,7 @@ pub struct GetPixelMapusvReply {
     pub datum: u16,
     pub data: Vec<u16>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 impl TryParse for GetPixelMapusvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6872,11 +10635,13 @@ impl TryParse for GetPixelMapusvReply {
         }
         let result = GetPixelMapusvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 impl GetPixelMapusvReply {
     /// Get the value of the `n` field.
     ///
@@ -6895,11 +10660,13 @@ impl GetPixelMapusvReply {
 
 /// Opcode for the GetPolygonStipple request
 pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPolygonStippleRequest {
     pub context_tag: ContextTag,
     pub lsb_first: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 impl GetPolygonStippleRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6931,12 +10698,61 @@ impl GetPolygonStippleRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let lsb_first_bytes = self.lsb_first.serialize();
+        write(&[
+            major_opcode,
+            GET_POLYGON_STIPPLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            lsb_first_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPolygonStippleReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6953,9 +10769,12 @@ impl GetPolygonStippleRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 impl Request for GetPolygonStippleRequest {
     type Reply = GetPolygonStippleReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
+#[inline]
 pub fn get_polygon_stipple<Conn>(conn: &Conn, context_tag: ContextTag, lsb_first: bool) -> Result<Cookie<'_, Conn, GetPolygonStippleReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6967,11 +10786,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPolygonStippleReply {
     pub sequence: u16,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 impl TryParse for GetPolygonStippleReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6987,11 +10808,13 @@ impl TryParse for GetPolygonStippleReply {
         }
         let result = GetPolygonStippleReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 impl GetPolygonStippleReply {
     /// Get the value of the `length` field.
     ///
@@ -7011,11 +10834,13 @@ impl GetPolygonStippleReply {
 
 /// Opcode for the GetString request
 pub const GET_STRING_REQUEST: u8 = 129;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetStringRequest {
     pub context_tag: ContextTag,
     pub name: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 impl GetStringRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7047,12 +10872,61 @@ impl GetStringRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let name_bytes = self.name.serialize();
+        write(&[
+            major_opcode,
+            GET_STRING_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetStringReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7069,9 +10943,12 @@ impl GetStringRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 impl Request for GetStringRequest {
     type Reply = GetStringReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
+#[inline]
 pub fn get_string<Conn>(conn: &Conn, context_tag: ContextTag, name: u32) -> Result<Cookie<'_, Conn, GetStringReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7083,12 +10960,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetStringReply {
     pub sequence: u16,
     pub length: u32,
     pub string: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 impl TryParse for GetStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7106,11 +10985,13 @@ impl TryParse for GetStringReply {
         }
         let result = GetStringReply { sequence, length, string };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 impl GetStringReply {
     /// Get the value of the `n` field.
     ///
@@ -7129,12 +11010,14 @@ impl GetStringReply {
 
 /// Opcode for the GetTexEnvfv request
 pub const GET_TEX_ENVFV_REQUEST: u8 = 130;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexEnvfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 impl GetTexEnvfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7171,12 +11054,66 @@ impl GetTexEnvfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_ENVFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexEnvfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7195,9 +11132,12 @@ impl GetTexEnvfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 impl Request for GetTexEnvfvRequest {
     type Reply = GetTexEnvfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
+#[inline]
 pub fn get_tex_envfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexEnvfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7210,6 +11150,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexEnvfvReply {
     pub sequence: u16,
@@ -7217,6 +11158,7 @@ pub struct GetTexEnvfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 impl TryParse for GetTexEnvfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7234,11 +11176,13 @@ impl TryParse for GetTexEnvfvReply {
         }
         let result = GetTexEnvfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 impl GetTexEnvfvReply {
     /// Get the value of the `n` field.
     ///
@@ -7257,12 +11201,14 @@ impl GetTexEnvfvReply {
 
 /// Opcode for the GetTexEnviv request
 pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexEnvivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 impl GetTexEnvivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7299,12 +11245,66 @@ impl GetTexEnvivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_ENVIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexEnvivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7323,9 +11323,12 @@ impl GetTexEnvivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 impl Request for GetTexEnvivRequest {
     type Reply = GetTexEnvivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
+#[inline]
 pub fn get_tex_enviv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexEnvivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7338,6 +11341,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexEnvivReply {
     pub sequence: u16,
@@ -7345,6 +11349,7 @@ pub struct GetTexEnvivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 impl TryParse for GetTexEnvivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7362,11 +11367,13 @@ impl TryParse for GetTexEnvivReply {
         }
         let result = GetTexEnvivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 impl GetTexEnvivReply {
     /// Get the value of the `n` field.
     ///
@@ -7385,12 +11392,14 @@ impl GetTexEnvivReply {
 
 /// Opcode for the GetTexGendv request
 pub const GET_TEX_GENDV_REQUEST: u8 = 132;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexGendvRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 impl GetTexGendvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7403,8 +11412,39 @@ impl GetTexGendvRequest {
         let context_tag_bytes = self.context_tag.serialize();
         let coord_bytes = self.coord.serialize();
         let pname_bytes = self.pname.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_TEX_GENDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
             GET_TEX_GENDV_REQUEST,
             0,
             0,
@@ -7420,19 +11460,42 @@ impl GetTexGendvRequest {
             pname_bytes[1],
             pname_bytes[2],
             pname_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 16;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexGendvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7451,9 +11514,12 @@ impl GetTexGendvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 impl Request for GetTexGendvRequest {
     type Reply = GetTexGendvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
+#[inline]
 pub fn get_tex_gendv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGendvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7466,6 +11532,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexGendvReply {
     pub sequence: u16,
@@ -7473,6 +11540,7 @@ pub struct GetTexGendvReply {
     pub datum: Float64,
     pub data: Vec<Float64>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 impl TryParse for GetTexGendvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7490,11 +11558,13 @@ impl TryParse for GetTexGendvReply {
         }
         let result = GetTexGendvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 impl GetTexGendvReply {
     /// Get the value of the `n` field.
     ///
@@ -7513,12 +11583,14 @@ impl GetTexGendvReply {
 
 /// Opcode for the GetTexGenfv request
 pub const GET_TEX_GENFV_REQUEST: u8 = 133;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexGenfvRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 impl GetTexGenfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7555,12 +11627,66 @@ impl GetTexGenfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_GENFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexGenfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7579,9 +11705,12 @@ impl GetTexGenfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 impl Request for GetTexGenfvRequest {
     type Reply = GetTexGenfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
+#[inline]
 pub fn get_tex_genfv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGenfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7594,6 +11723,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexGenfvReply {
     pub sequence: u16,
@@ -7601,6 +11731,7 @@ pub struct GetTexGenfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 impl TryParse for GetTexGenfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7618,11 +11749,13 @@ impl TryParse for GetTexGenfvReply {
         }
         let result = GetTexGenfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 impl GetTexGenfvReply {
     /// Get the value of the `n` field.
     ///
@@ -7641,12 +11774,14 @@ impl GetTexGenfvReply {
 
 /// Opcode for the GetTexGeniv request
 pub const GET_TEX_GENIV_REQUEST: u8 = 134;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexGenivRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 impl GetTexGenivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7683,12 +11818,66 @@ impl GetTexGenivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_GENIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexGenivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7707,9 +11896,12 @@ impl GetTexGenivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 impl Request for GetTexGenivRequest {
     type Reply = GetTexGenivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
+#[inline]
 pub fn get_tex_geniv<Conn>(conn: &Conn, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexGenivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7722,6 +11914,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexGenivReply {
     pub sequence: u16,
@@ -7729,6 +11922,7 @@ pub struct GetTexGenivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 impl TryParse for GetTexGenivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7746,11 +11940,13 @@ impl TryParse for GetTexGenivReply {
         }
         let result = GetTexGenivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 impl GetTexGenivReply {
     /// Get the value of the `n` field.
     ///
@@ -7769,6 +11965,7 @@ impl GetTexGenivReply {
 
 /// Opcode for the GetTexImage request
 pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexImageRequest {
     pub context_tag: ContextTag,
@@ -7778,6 +11975,7 @@ pub struct GetTexImageRequest {
     pub type_: u32,
     pub swap_bytes: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 impl GetTexImageRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7829,12 +12027,81 @@ impl GetTexImageRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_IMAGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexImageReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7859,9 +12126,12 @@ impl GetTexImageRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 impl Request for GetTexImageRequest {
     type Reply = GetTexImageReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
+#[inline]
 pub fn get_tex_image<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetTexImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7877,6 +12147,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexImageReply {
     pub sequence: u16,
@@ -7885,6 +12156,7 @@ pub struct GetTexImageReply {
     pub depth: i32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 impl TryParse for GetTexImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7904,11 +12176,13 @@ impl TryParse for GetTexImageReply {
         }
         let result = GetTexImageReply { sequence, width, height, depth, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 impl GetTexImageReply {
     /// Get the value of the `length` field.
     ///
@@ -7928,12 +12202,14 @@ impl GetTexImageReply {
 
 /// Opcode for the GetTexParameterfv request
 pub const GET_TEX_PARAMETERFV_REQUEST: u8 = 136;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 impl GetTexParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7970,12 +12246,66 @@ impl GetTexParameterfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7994,9 +12324,12 @@ impl GetTexParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 impl Request for GetTexParameterfvRequest {
     type Reply = GetTexParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
+#[inline]
 pub fn get_tex_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8009,6 +12342,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexParameterfvReply {
     pub sequence: u16,
@@ -8016,6 +12350,7 @@ pub struct GetTexParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 impl TryParse for GetTexParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8033,11 +12368,13 @@ impl TryParse for GetTexParameterfvReply {
         }
         let result = GetTexParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 impl GetTexParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -8056,12 +12393,14 @@ impl GetTexParameterfvReply {
 
 /// Opcode for the GetTexParameteriv request
 pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 impl GetTexParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8098,12 +12437,66 @@ impl GetTexParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8122,9 +12515,12 @@ impl GetTexParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 impl Request for GetTexParameterivRequest {
     type Reply = GetTexParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
+#[inline]
 pub fn get_tex_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetTexParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8137,6 +12533,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexParameterivReply {
     pub sequence: u16,
@@ -8144,6 +12541,7 @@ pub struct GetTexParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 impl TryParse for GetTexParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8161,11 +12559,13 @@ impl TryParse for GetTexParameterivReply {
         }
         let result = GetTexParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 impl GetTexParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -8184,6 +12584,7 @@ impl GetTexParameterivReply {
 
 /// Opcode for the GetTexLevelParameterfv request
 pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexLevelParameterfvRequest {
     pub context_tag: ContextTag,
@@ -8191,6 +12592,7 @@ pub struct GetTexLevelParameterfvRequest {
     pub level: i32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 impl GetTexLevelParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8232,12 +12634,71 @@ impl GetTexLevelParameterfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexLevelParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8258,9 +12719,12 @@ impl GetTexLevelParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 impl Request for GetTexLevelParameterfvRequest {
     type Reply = GetTexLevelParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
+#[inline]
 pub fn get_tex_level_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Conn, GetTexLevelParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8274,6 +12738,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetTexLevelParameterfvReply {
     pub sequence: u16,
@@ -8281,6 +12746,7 @@ pub struct GetTexLevelParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 impl TryParse for GetTexLevelParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8298,11 +12764,13 @@ impl TryParse for GetTexLevelParameterfvReply {
         }
         let result = GetTexLevelParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 impl GetTexLevelParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -8321,6 +12789,7 @@ impl GetTexLevelParameterfvReply {
 
 /// Opcode for the GetTexLevelParameteriv request
 pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetTexLevelParameterivRequest {
     pub context_tag: ContextTag,
@@ -8328,6 +12797,7 @@ pub struct GetTexLevelParameterivRequest {
     pub level: i32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 impl GetTexLevelParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8369,12 +12839,71 @@ impl GetTexLevelParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTexLevelParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8395,9 +12924,12 @@ impl GetTexLevelParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 impl Request for GetTexLevelParameterivRequest {
     type Reply = GetTexLevelParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
+#[inline]
 pub fn get_tex_level_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Conn, GetTexLevelParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8411,6 +12943,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetTexLevelParameterivReply {
     pub sequence: u16,
@@ -8418,6 +12951,7 @@ pub struct GetTexLevelParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 impl TryParse for GetTexLevelParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8435,11 +12969,13 @@ impl TryParse for GetTexLevelParameterivReply {
         }
         let result = GetTexLevelParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 impl GetTexLevelParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -8458,11 +12994,13 @@ impl GetTexLevelParameterivReply {
 
 /// Opcode for the IsEnabled request
 pub const IS_ENABLED_REQUEST: u8 = 140;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsEnabledRequest {
     pub context_tag: ContextTag,
     pub capability: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 impl IsEnabledRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8494,12 +13032,61 @@ impl IsEnabledRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let capability_bytes = self.capability.serialize();
+        write(&[
+            major_opcode,
+            IS_ENABLED_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            capability_bytes[0],
+            capability_bytes[1],
+            capability_bytes[2],
+            capability_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, IsEnabledReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8516,9 +13103,12 @@ impl IsEnabledRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 impl Request for IsEnabledRequest {
     type Reply = IsEnabledReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
+#[inline]
 pub fn is_enabled<Conn>(conn: &Conn, context_tag: ContextTag, capability: u32) -> Result<Cookie<'_, Conn, IsEnabledReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8530,12 +13120,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsEnabledReply {
     pub sequence: u16,
     pub length: u32,
     pub ret_val: Bool32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 impl TryParse for IsEnabledReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8549,7 +13141,8 @@ impl TryParse for IsEnabledReply {
         }
         let result = IsEnabledReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8557,11 +13150,13 @@ impl TryParse for IsEnabledReply {
 
 /// Opcode for the IsList request
 pub const IS_LIST_REQUEST: u8 = 141;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsListRequest {
     pub context_tag: ContextTag,
     pub list: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 impl IsListRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8593,12 +13188,61 @@ impl IsListRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        write(&[
+            major_opcode,
+            IS_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, IsListReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8615,9 +13259,12 @@ impl IsListRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 impl Request for IsListRequest {
     type Reply = IsListReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
+#[inline]
 pub fn is_list<Conn>(conn: &Conn, context_tag: ContextTag, list: u32) -> Result<Cookie<'_, Conn, IsListReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8629,12 +13276,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsListReply {
     pub sequence: u16,
     pub length: u32,
     pub ret_val: Bool32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 impl TryParse for IsListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8648,7 +13297,8 @@ impl TryParse for IsListReply {
         }
         let result = IsListReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8656,10 +13306,12 @@ impl TryParse for IsListReply {
 
 /// Opcode for the Flush request
 pub const FLUSH_REQUEST: u8 = 142;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FlushRequest {
     pub context_tag: ContextTag,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
 impl FlushRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8686,12 +13338,56 @@ impl FlushRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        write(&[
+            major_opcode,
+            FLUSH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8706,9 +13402,12 @@ impl FlushRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
 impl Request for FlushRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
+#[inline]
 pub fn flush<Conn>(conn: &Conn, context_tag: ContextTag) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8721,11 +13420,13 @@ where
 
 /// Opcode for the AreTexturesResident request
 pub const ARE_TEXTURES_RESIDENT_REQUEST: u8 = 143;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AreTexturesResidentRequest<'input> {
     pub context_tag: ContextTag,
     pub textures: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 impl<'input> AreTexturesResidentRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8762,12 +13463,69 @@ impl<'input> AreTexturesResidentRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), textures_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize();
+        write(&[
+            major_opcode,
+            ARE_TEXTURES_RESIDENT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let textures_bytes = self.textures.serialize();
+        let length_so_far = length_so_far + textures_bytes.len();
+        write(&textures_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.textures.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AreTexturesResidentReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8792,9 +13550,12 @@ impl<'input> AreTexturesResidentRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 impl<'input> Request for AreTexturesResidentRequest<'input> {
     type Reply = AreTexturesResidentReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
+#[inline]
 pub fn are_textures_resident<'c, 'input, Conn>(conn: &'c Conn, context_tag: ContextTag, textures: &'input [u32]) -> Result<Cookie<'c, Conn, AreTexturesResidentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8806,12 +13567,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AreTexturesResidentReply {
     pub sequence: u16,
     pub ret_val: Bool32,
     pub data: Vec<bool>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 impl TryParse for AreTexturesResidentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8827,11 +13590,13 @@ impl TryParse for AreTexturesResidentReply {
         }
         let result = AreTexturesResidentReply { sequence, ret_val, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 impl AreTexturesResidentReply {
     /// Get the value of the `length` field.
     ///
@@ -8851,11 +13616,13 @@ impl AreTexturesResidentReply {
 
 /// Opcode for the DeleteTextures request
 pub const DELETE_TEXTURES_REQUEST: u8 = 144;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeleteTexturesRequest<'input> {
     pub context_tag: ContextTag,
     pub textures: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
 impl<'input> DeleteTexturesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8892,12 +13659,69 @@ impl<'input> DeleteTexturesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), textures_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize();
+        write(&[
+            major_opcode,
+            DELETE_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let textures_bytes = self.textures.serialize();
+        let length_so_far = length_so_far + textures_bytes.len();
+        write(&textures_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.textures.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8922,9 +13746,12 @@ impl<'input> DeleteTexturesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
 impl<'input> Request for DeleteTexturesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
+#[inline]
 pub fn delete_textures<'c, 'input, Conn>(conn: &'c Conn, context_tag: ContextTag, textures: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8938,11 +13765,13 @@ where
 
 /// Opcode for the GenTextures request
 pub const GEN_TEXTURES_REQUEST: u8 = 145;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenTexturesRequest {
     pub context_tag: ContextTag,
     pub n: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 impl GenTexturesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8974,12 +13803,61 @@ impl GenTexturesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let n_bytes = self.n.serialize();
+        write(&[
+            major_opcode,
+            GEN_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GenTexturesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8996,9 +13874,12 @@ impl GenTexturesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 impl Request for GenTexturesRequest {
     type Reply = GenTexturesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
+#[inline]
 pub fn gen_textures<Conn>(conn: &Conn, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Conn, GenTexturesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9010,11 +13891,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenTexturesReply {
     pub sequence: u16,
     pub data: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 impl TryParse for GenTexturesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9029,11 +13912,13 @@ impl TryParse for GenTexturesReply {
         }
         let result = GenTexturesReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 impl GenTexturesReply {
     /// Get the value of the `length` field.
     ///
@@ -9052,11 +13937,13 @@ impl GenTexturesReply {
 
 /// Opcode for the IsTexture request
 pub const IS_TEXTURE_REQUEST: u8 = 146;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsTextureRequest {
     pub context_tag: ContextTag,
     pub texture: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 impl IsTextureRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9088,12 +13975,61 @@ impl IsTextureRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let texture_bytes = self.texture.serialize();
+        write(&[
+            major_opcode,
+            IS_TEXTURE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            texture_bytes[0],
+            texture_bytes[1],
+            texture_bytes[2],
+            texture_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, IsTextureReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9110,9 +14046,12 @@ impl IsTextureRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 impl Request for IsTextureRequest {
     type Reply = IsTextureReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
+#[inline]
 pub fn is_texture<Conn>(conn: &Conn, context_tag: ContextTag, texture: u32) -> Result<Cookie<'_, Conn, IsTextureReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9124,12 +14063,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsTextureReply {
     pub sequence: u16,
     pub length: u32,
     pub ret_val: Bool32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 impl TryParse for IsTextureReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9143,7 +14084,8 @@ impl TryParse for IsTextureReply {
         }
         let result = IsTextureReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -9151,6 +14093,7 @@ impl TryParse for IsTextureReply {
 
 /// Opcode for the GetColorTable request
 pub const GET_COLOR_TABLE_REQUEST: u8 = 147;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetColorTableRequest {
     pub context_tag: ContextTag,
@@ -9159,6 +14102,7 @@ pub struct GetColorTableRequest {
     pub type_: u32,
     pub swap_bytes: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 impl GetColorTableRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9205,12 +14149,76 @@ impl GetColorTableRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        write(&[
+            major_opcode,
+            GET_COLOR_TABLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetColorTableReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9233,9 +14241,12 @@ impl GetColorTableRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 impl Request for GetColorTableRequest {
     type Reply = GetColorTableReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
+#[inline]
 pub fn get_color_table<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetColorTableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9250,12 +14261,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetColorTableReply {
     pub sequence: u16,
     pub width: i32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 impl TryParse for GetColorTableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9273,11 +14286,13 @@ impl TryParse for GetColorTableReply {
         }
         let result = GetColorTableReply { sequence, width, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 impl GetColorTableReply {
     /// Get the value of the `length` field.
     ///
@@ -9297,26 +14312,59 @@ impl GetColorTableReply {
 
 /// Opcode for the GetColorTableParameterfv request
 pub const GET_COLOR_TABLE_PARAMETERFV_REQUEST: u8 = 148;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetColorTableParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 impl GetColorTableParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_COLOR_TABLE_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let context_tag_bytes = self.context_tag.serialize();
         let target_bytes = self.target.serialize();
         let pname_bytes = self.pname.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             GET_COLOR_TABLE_PARAMETERFV_REQUEST,
             0,
             0,
@@ -9332,19 +14380,42 @@ impl GetColorTableParameterfvRequest {
             pname_bytes[1],
             pname_bytes[2],
             pname_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 16;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetColorTableParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9363,9 +14434,12 @@ impl GetColorTableParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 impl Request for GetColorTableParameterfvRequest {
     type Reply = GetColorTableParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
+#[inline]
 pub fn get_color_table_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetColorTableParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9378,6 +14452,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetColorTableParameterfvReply {
     pub sequence: u16,
@@ -9385,6 +14460,7 @@ pub struct GetColorTableParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 impl TryParse for GetColorTableParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9402,11 +14478,13 @@ impl TryParse for GetColorTableParameterfvReply {
         }
         let result = GetColorTableParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 impl GetColorTableParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -9425,12 +14503,14 @@ impl GetColorTableParameterfvReply {
 
 /// Opcode for the GetColorTableParameteriv request
 pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetColorTableParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 impl GetColorTableParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9467,12 +14547,66 @@ impl GetColorTableParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_COLOR_TABLE_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetColorTableParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9491,9 +14625,12 @@ impl GetColorTableParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 impl Request for GetColorTableParameterivRequest {
     type Reply = GetColorTableParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
+#[inline]
 pub fn get_color_table_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetColorTableParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9506,6 +14643,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetColorTableParameterivReply {
     pub sequence: u16,
@@ -9513,6 +14651,7 @@ pub struct GetColorTableParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 impl TryParse for GetColorTableParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9530,11 +14669,13 @@ impl TryParse for GetColorTableParameterivReply {
         }
         let result = GetColorTableParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 impl GetColorTableParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -9553,6 +14694,7 @@ impl GetColorTableParameterivReply {
 
 /// Opcode for the GetConvolutionFilter request
 pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetConvolutionFilterRequest {
     pub context_tag: ContextTag,
@@ -9561,6 +14703,7 @@ pub struct GetConvolutionFilterRequest {
     pub type_: u32,
     pub swap_bytes: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 impl GetConvolutionFilterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9607,12 +14750,76 @@ impl GetConvolutionFilterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        write(&[
+            major_opcode,
+            GET_CONVOLUTION_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetConvolutionFilterReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9635,9 +14842,12 @@ impl GetConvolutionFilterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 impl Request for GetConvolutionFilterRequest {
     type Reply = GetConvolutionFilterReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
+#[inline]
 pub fn get_convolution_filter<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetConvolutionFilterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9652,6 +14862,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetConvolutionFilterReply {
     pub sequence: u16,
@@ -9659,6 +14870,7 @@ pub struct GetConvolutionFilterReply {
     pub height: i32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 impl TryParse for GetConvolutionFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9677,11 +14889,13 @@ impl TryParse for GetConvolutionFilterReply {
         }
         let result = GetConvolutionFilterReply { sequence, width, height, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 impl GetConvolutionFilterReply {
     /// Get the value of the `length` field.
     ///
@@ -9701,12 +14915,14 @@ impl GetConvolutionFilterReply {
 
 /// Opcode for the GetConvolutionParameterfv request
 pub const GET_CONVOLUTION_PARAMETERFV_REQUEST: u8 = 151;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetConvolutionParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 impl GetConvolutionParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9743,12 +14959,66 @@ impl GetConvolutionParameterfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetConvolutionParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9767,9 +15037,12 @@ impl GetConvolutionParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 impl Request for GetConvolutionParameterfvRequest {
     type Reply = GetConvolutionParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
+#[inline]
 pub fn get_convolution_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetConvolutionParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9782,6 +15055,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetConvolutionParameterfvReply {
     pub sequence: u16,
@@ -9789,6 +15063,7 @@ pub struct GetConvolutionParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 impl TryParse for GetConvolutionParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9806,11 +15081,13 @@ impl TryParse for GetConvolutionParameterfvReply {
         }
         let result = GetConvolutionParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 impl GetConvolutionParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -9829,12 +15106,14 @@ impl GetConvolutionParameterfvReply {
 
 /// Opcode for the GetConvolutionParameteriv request
 pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetConvolutionParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 impl GetConvolutionParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9871,12 +15150,66 @@ impl GetConvolutionParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetConvolutionParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9895,9 +15228,12 @@ impl GetConvolutionParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 impl Request for GetConvolutionParameterivRequest {
     type Reply = GetConvolutionParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
+#[inline]
 pub fn get_convolution_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetConvolutionParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9910,6 +15246,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetConvolutionParameterivReply {
     pub sequence: u16,
@@ -9917,6 +15254,7 @@ pub struct GetConvolutionParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 impl TryParse for GetConvolutionParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9934,11 +15272,13 @@ impl TryParse for GetConvolutionParameterivReply {
         }
         let result = GetConvolutionParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 impl GetConvolutionParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -9957,6 +15297,7 @@ impl GetConvolutionParameterivReply {
 
 /// Opcode for the GetSeparableFilter request
 pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetSeparableFilterRequest {
     pub context_tag: ContextTag,
@@ -9965,6 +15306,7 @@ pub struct GetSeparableFilterRequest {
     pub type_: u32,
     pub swap_bytes: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 impl GetSeparableFilterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10011,12 +15353,76 @@ impl GetSeparableFilterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        write(&[
+            major_opcode,
+            GET_SEPARABLE_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetSeparableFilterReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10039,9 +15445,12 @@ impl GetSeparableFilterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 impl Request for GetSeparableFilterRequest {
     type Reply = GetSeparableFilterReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
+#[inline]
 pub fn get_separable_filter<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Conn, GetSeparableFilterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10056,6 +15465,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSeparableFilterReply {
     pub sequence: u16,
@@ -10063,6 +15473,7 @@ pub struct GetSeparableFilterReply {
     pub col_h: i32,
     pub rows_and_cols: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 impl TryParse for GetSeparableFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10081,11 +15492,13 @@ impl TryParse for GetSeparableFilterReply {
         }
         let result = GetSeparableFilterReply { sequence, row_w, col_h, rows_and_cols };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 impl GetSeparableFilterReply {
     /// Get the value of the `length` field.
     ///
@@ -10101,27 +15514,71 @@ impl GetSeparableFilterReply {
             .checked_div(4).unwrap()
             .try_into().unwrap()
     }
-}
-
-/// Opcode for the GetHistogram request
-pub const GET_HISTOGRAM_REQUEST: u8 = 154;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct GetHistogramRequest {
-    pub context_tag: ContextTag,
-    pub target: u32,
-    pub format: u32,
-    pub type_: u32,
-    pub swap_bytes: bool,
-    pub reset: bool,
-}
-impl GetHistogramRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+}
+
+/// Opcode for the GetHistogram request
+pub const GET_HISTOGRAM_REQUEST: u8 = 154;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetHistogramRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub reset: bool,
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
+impl GetHistogramRequest {
+    /// Serialize this request into bytes for the provided connection
+    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let reset_bytes = self.reset.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_HISTOGRAM_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
         let length_so_far = 0;
         let context_tag_bytes = self.context_tag.serialize();
         let target_bytes = self.target.serialize();
@@ -10129,8 +15586,8 @@ impl GetHistogramRequest {
         let type_bytes = self.type_.serialize();
         let swap_bytes_bytes = self.swap_bytes.serialize();
         let reset_bytes = self.reset.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             GET_HISTOGRAM_REQUEST,
             0,
             0,
@@ -10154,19 +15611,42 @@ impl GetHistogramRequest {
             reset_bytes[0],
             0,
             0,
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 24;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetHistogramReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10191,9 +15671,12 @@ impl GetHistogramRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
 impl Request for GetHistogramRequest {
     type Reply = GetHistogramReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
+#[inline]
 pub fn get_histogram<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Conn, GetHistogramReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10209,12 +15692,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetHistogramReply {
     pub sequence: u16,
     pub width: i32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
 impl TryParse for GetHistogramReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10232,11 +15717,13 @@ impl TryParse for GetHistogramReply {
         }
         let result = GetHistogramReply { sequence, width, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
 impl GetHistogramReply {
     /// Get the value of the `length` field.
     ///
@@ -10256,12 +15743,14 @@ impl GetHistogramReply {
 
 /// Opcode for the GetHistogramParameterfv request
 pub const GET_HISTOGRAM_PARAMETERFV_REQUEST: u8 = 155;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetHistogramParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 impl GetHistogramParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10298,12 +15787,66 @@ impl GetHistogramParameterfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetHistogramParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10322,9 +15865,12 @@ impl GetHistogramParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 impl Request for GetHistogramParameterfvRequest {
     type Reply = GetHistogramParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
+#[inline]
 pub fn get_histogram_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetHistogramParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10337,6 +15883,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetHistogramParameterfvReply {
     pub sequence: u16,
@@ -10344,6 +15891,7 @@ pub struct GetHistogramParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 impl TryParse for GetHistogramParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10361,11 +15909,13 @@ impl TryParse for GetHistogramParameterfvReply {
         }
         let result = GetHistogramParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 impl GetHistogramParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -10384,12 +15934,14 @@ impl GetHistogramParameterfvReply {
 
 /// Opcode for the GetHistogramParameteriv request
 pub const GET_HISTOGRAM_PARAMETERIV_REQUEST: u8 = 156;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetHistogramParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 impl GetHistogramParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10426,12 +15978,66 @@ impl GetHistogramParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetHistogramParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10450,9 +16056,12 @@ impl GetHistogramParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 impl Request for GetHistogramParameterivRequest {
     type Reply = GetHistogramParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
+#[inline]
 pub fn get_histogram_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetHistogramParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10465,6 +16074,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetHistogramParameterivReply {
     pub sequence: u16,
@@ -10472,6 +16082,7 @@ pub struct GetHistogramParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 impl TryParse for GetHistogramParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10489,11 +16100,13 @@ impl TryParse for GetHistogramParameterivReply {
         }
         let result = GetHistogramParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 impl GetHistogramParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -10512,6 +16125,7 @@ impl GetHistogramParameterivReply {
 
 /// Opcode for the GetMinmax request
 pub const GET_MINMAX_REQUEST: u8 = 157;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMinmaxRequest {
     pub context_tag: ContextTag,
@@ -10521,6 +16135,7 @@ pub struct GetMinmaxRequest {
     pub swap_bytes: bool,
     pub reset: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 impl GetMinmaxRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10568,12 +16183,77 @@ impl GetMinmaxRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let reset_bytes = self.reset.serialize();
+        write(&[
+            major_opcode,
+            GET_MINMAX_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMinmaxReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10598,9 +16278,12 @@ impl GetMinmaxRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 impl Request for GetMinmaxRequest {
     type Reply = GetMinmaxReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
+#[inline]
 pub fn get_minmax<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Conn, GetMinmaxReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10616,11 +16299,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMinmaxReply {
     pub sequence: u16,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 impl TryParse for GetMinmaxReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10636,11 +16321,13 @@ impl TryParse for GetMinmaxReply {
         }
         let result = GetMinmaxReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 impl GetMinmaxReply {
     /// Get the value of the `length` field.
     ///
@@ -10660,12 +16347,14 @@ impl GetMinmaxReply {
 
 /// Opcode for the GetMinmaxParameterfv request
 pub const GET_MINMAX_PARAMETERFV_REQUEST: u8 = 158;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMinmaxParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 impl GetMinmaxParameterfvRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10702,12 +16391,66 @@ impl GetMinmaxParameterfvRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_MINMAX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMinmaxParameterfvReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10726,9 +16469,12 @@ impl GetMinmaxParameterfvRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 impl Request for GetMinmaxParameterfvRequest {
     type Reply = GetMinmaxParameterfvReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
+#[inline]
 pub fn get_minmax_parameterfv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMinmaxParameterfvReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10741,6 +16487,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetMinmaxParameterfvReply {
     pub sequence: u16,
@@ -10748,6 +16495,7 @@ pub struct GetMinmaxParameterfvReply {
     pub datum: Float32,
     pub data: Vec<Float32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 impl TryParse for GetMinmaxParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10765,11 +16513,13 @@ impl TryParse for GetMinmaxParameterfvReply {
         }
         let result = GetMinmaxParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 impl GetMinmaxParameterfvReply {
     /// Get the value of the `n` field.
     ///
@@ -10788,12 +16538,14 @@ impl GetMinmaxParameterfvReply {
 
 /// Opcode for the GetMinmaxParameteriv request
 pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMinmaxParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 impl GetMinmaxParameterivRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10830,12 +16582,66 @@ impl GetMinmaxParameterivRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_MINMAX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMinmaxParameterivReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10854,9 +16660,12 @@ impl GetMinmaxParameterivRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 impl Request for GetMinmaxParameterivRequest {
     type Reply = GetMinmaxParameterivReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
+#[inline]
 pub fn get_minmax_parameteriv<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetMinmaxParameterivReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10869,6 +16678,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMinmaxParameterivReply {
     pub sequence: u16,
@@ -10876,6 +16686,7 @@ pub struct GetMinmaxParameterivReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 impl TryParse for GetMinmaxParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10893,11 +16704,13 @@ impl TryParse for GetMinmaxParameterivReply {
         }
         let result = GetMinmaxParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 impl GetMinmaxParameterivReply {
     /// Get the value of the `n` field.
     ///
@@ -10916,12 +16729,14 @@ impl GetMinmaxParameterivReply {
 
 /// Opcode for the GetCompressedTexImageARB request
 pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCompressedTexImageARBRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub level: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 impl GetCompressedTexImageARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10958,12 +16773,66 @@ impl GetCompressedTexImageARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        write(&[
+            major_opcode,
+            GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCompressedTexImageARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10982,9 +16851,12 @@ impl GetCompressedTexImageARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 impl Request for GetCompressedTexImageARBRequest {
     type Reply = GetCompressedTexImageARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
+#[inline]
 pub fn get_compressed_tex_image_arb<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, level: i32) -> Result<Cookie<'_, Conn, GetCompressedTexImageARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10997,12 +16869,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCompressedTexImageARBReply {
     pub sequence: u16,
     pub size: i32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 impl TryParse for GetCompressedTexImageARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11020,11 +16894,13 @@ impl TryParse for GetCompressedTexImageARBReply {
         }
         let result = GetCompressedTexImageARBReply { sequence, size, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 impl GetCompressedTexImageARBReply {
     /// Get the value of the `length` field.
     ///
@@ -11044,11 +16920,13 @@ impl GetCompressedTexImageARBReply {
 
 /// Opcode for the DeleteQueriesARB request
 pub const DELETE_QUERIES_ARB_REQUEST: u8 = 161;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeleteQueriesARBRequest<'input> {
     pub context_tag: ContextTag,
     pub ids: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
 impl<'input> DeleteQueriesARBRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11085,12 +16963,69 @@ impl<'input> DeleteQueriesARBRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), ids_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.ids.len()).expect("`ids` has too many elements");
+        let n_bytes = n.serialize();
+        write(&[
+            major_opcode,
+            DELETE_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let ids_bytes = self.ids.serialize();
+        let length_so_far = length_so_far + ids_bytes.len();
+        write(&ids_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.ids.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11115,9 +17050,12 @@ impl<'input> DeleteQueriesARBRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
 impl<'input> Request for DeleteQueriesARBRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
+#[inline]
 pub fn delete_queries_arb<'c, 'input, Conn>(conn: &'c Conn, context_tag: ContextTag, ids: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11131,11 +17069,13 @@ where
 
 /// Opcode for the GenQueriesARB request
 pub const GEN_QUERIES_ARB_REQUEST: u8 = 162;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenQueriesARBRequest {
     pub context_tag: ContextTag,
     pub n: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 impl GenQueriesARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11167,12 +17107,61 @@ impl GenQueriesARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let n_bytes = self.n.serialize();
+        write(&[
+            major_opcode,
+            GEN_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GenQueriesARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11189,9 +17178,12 @@ impl GenQueriesARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 impl Request for GenQueriesARBRequest {
     type Reply = GenQueriesARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
+#[inline]
 pub fn gen_queries_arb<Conn>(conn: &Conn, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Conn, GenQueriesARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11203,11 +17195,13 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenQueriesARBReply {
     pub sequence: u16,
     pub data: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 impl TryParse for GenQueriesARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11222,11 +17216,13 @@ impl TryParse for GenQueriesARBReply {
         }
         let result = GenQueriesARBReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 impl GenQueriesARBReply {
     /// Get the value of the `length` field.
     ///
@@ -11245,11 +17241,13 @@ impl GenQueriesARBReply {
 
 /// Opcode for the IsQueryARB request
 pub const IS_QUERY_ARB_REQUEST: u8 = 163;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsQueryARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 impl IsQueryARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11281,12 +17279,61 @@ impl IsQueryARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        write(&[
+            major_opcode,
+            IS_QUERY_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, IsQueryARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11303,9 +17350,12 @@ impl IsQueryARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 impl Request for IsQueryARBRequest {
     type Reply = IsQueryARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
+#[inline]
 pub fn is_query_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32) -> Result<Cookie<'_, Conn, IsQueryARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11317,12 +17367,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsQueryARBReply {
     pub sequence: u16,
     pub length: u32,
     pub ret_val: Bool32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 impl TryParse for IsQueryARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11336,7 +17388,8 @@ impl TryParse for IsQueryARBReply {
         }
         let result = IsQueryARBReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -11344,12 +17397,14 @@ impl TryParse for IsQueryARBReply {
 
 /// Opcode for the GetQueryivARB request
 pub const GET_QUERYIV_ARB_REQUEST: u8 = 164;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetQueryivARBRequest {
     pub context_tag: ContextTag,
     pub target: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 impl GetQueryivARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11386,12 +17441,66 @@ impl GetQueryivARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_QUERYIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetQueryivARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11410,9 +17519,12 @@ impl GetQueryivARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 impl Request for GetQueryivARBRequest {
     type Reply = GetQueryivARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
+#[inline]
 pub fn get_queryiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11425,6 +17537,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryivARBReply {
     pub sequence: u16,
@@ -11432,6 +17545,7 @@ pub struct GetQueryivARBReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 impl TryParse for GetQueryivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11449,11 +17563,13 @@ impl TryParse for GetQueryivARBReply {
         }
         let result = GetQueryivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 impl GetQueryivARBReply {
     /// Get the value of the `n` field.
     ///
@@ -11472,12 +17588,14 @@ impl GetQueryivARBReply {
 
 /// Opcode for the GetQueryObjectivARB request
 pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetQueryObjectivARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 impl GetQueryObjectivARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11514,12 +17632,66 @@ impl GetQueryObjectivARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_QUERY_OBJECTIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetQueryObjectivARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11538,9 +17710,12 @@ impl GetQueryObjectivARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 impl Request for GetQueryObjectivARBRequest {
     type Reply = GetQueryObjectivARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
+#[inline]
 pub fn get_query_objectiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryObjectivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11553,6 +17728,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryObjectivARBReply {
     pub sequence: u16,
@@ -11560,6 +17736,7 @@ pub struct GetQueryObjectivARBReply {
     pub datum: i32,
     pub data: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 impl TryParse for GetQueryObjectivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11577,11 +17754,13 @@ impl TryParse for GetQueryObjectivARBReply {
         }
         let result = GetQueryObjectivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 impl GetQueryObjectivARBReply {
     /// Get the value of the `n` field.
     ///
@@ -11600,12 +17779,14 @@ impl GetQueryObjectivARBReply {
 
 /// Opcode for the GetQueryObjectuivARB request
 pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetQueryObjectuivARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
     pub pname: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 impl GetQueryObjectuivARBRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11642,12 +17823,66 @@ impl GetQueryObjectuivARBRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        let pname_bytes = self.pname.serialize();
+        write(&[
+            major_opcode,
+            GET_QUERY_OBJECTUIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetQueryObjectuivARBReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11666,9 +17901,12 @@ impl GetQueryObjectuivARBRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 impl Request for GetQueryObjectuivARBRequest {
     type Reply = GetQueryObjectuivARBReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
+#[inline]
 pub fn get_query_objectuiv_arb<Conn>(conn: &Conn, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Conn, GetQueryObjectuivARBReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11681,6 +17919,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetQueryObjectuivARBReply {
     pub sequence: u16,
@@ -11688,6 +17927,7 @@ pub struct GetQueryObjectuivARBReply {
     pub datum: u32,
     pub data: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 impl TryParse for GetQueryObjectuivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11705,11 +17945,13 @@ impl TryParse for GetQueryObjectuivARBReply {
         }
         let result = GetQueryObjectuivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 impl GetQueryObjectuivARBReply {
     /// Get the value of the `n` field.
     ///
@@ -11728,406 +17970,608 @@ impl GetQueryObjectuivARBReply {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
+    #[inline]
     fn glx_render<'c, 'input>(&'c self, context_tag: ContextTag, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         render(self, context_tag, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
+    #[inline]
     fn glx_render_large<'c, 'input>(&'c self, context_tag: ContextTag, request_num: u16, request_total: u16, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         render_large(self, context_tag, request_num, request_total, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
+    #[inline]
     fn glx_create_context(&self, context: Context, visual: xproto::Visualid, screen: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_context(self, context, visual, screen, share_list, is_direct)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
+    #[inline]
     fn glx_destroy_context(&self, context: Context) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_context(self, context)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
+    #[inline]
     fn glx_make_current(&self, drawable: Drawable, context: Context, old_context_tag: ContextTag) -> Result<Cookie<'_, Self, MakeCurrentReply>, ConnectionError>
     {
         make_current(self, drawable, context, old_context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
+    #[inline]
     fn glx_is_direct(&self, context: Context) -> Result<Cookie<'_, Self, IsDirectReply>, ConnectionError>
     {
         is_direct(self, context)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
+    #[inline]
     fn glx_query_version(&self, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, major_version, minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
+    #[inline]
     fn glx_wait_gl(&self, context_tag: ContextTag) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         wait_gl(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
+    #[inline]
     fn glx_wait_x(&self, context_tag: ContextTag) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         wait_x(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
+    #[inline]
     fn glx_copy_context(&self, src: Context, dest: Context, mask: u32, src_context_tag: ContextTag) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         copy_context(self, src, dest, mask, src_context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
+    #[inline]
     fn glx_swap_buffers(&self, context_tag: ContextTag, drawable: Drawable) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         swap_buffers(self, context_tag, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
+    #[inline]
     fn glx_use_x_font(&self, context_tag: ContextTag, font: xproto::Font, first: u32, count: u32, list_base: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         use_x_font(self, context_tag, font, first, count, list_base)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
+    #[inline]
     fn glx_create_glx_pixmap(&self, screen: u32, visual: xproto::Visualid, pixmap: xproto::Pixmap, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_glx_pixmap(self, screen, visual, pixmap, glx_pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
+    #[inline]
     fn glx_get_visual_configs(&self, screen: u32) -> Result<Cookie<'_, Self, GetVisualConfigsReply>, ConnectionError>
     {
         get_visual_configs(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
+    #[inline]
     fn glx_destroy_glx_pixmap(&self, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_glx_pixmap(self, glx_pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
+    #[inline]
     fn glx_vendor_private<'c, 'input>(&'c self, vendor_code: u32, context_tag: ContextTag, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         vendor_private(self, vendor_code, context_tag, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
+    #[inline]
     fn glx_vendor_private_with_reply<'c, 'input>(&'c self, vendor_code: u32, context_tag: ContextTag, data: &'input [u8]) -> Result<Cookie<'c, Self, VendorPrivateWithReplyReply>, ConnectionError>
     {
         vendor_private_with_reply(self, vendor_code, context_tag, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
+    #[inline]
     fn glx_query_extensions_string(&self, screen: u32) -> Result<Cookie<'_, Self, QueryExtensionsStringReply>, ConnectionError>
     {
         query_extensions_string(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
+    #[inline]
     fn glx_query_server_string(&self, screen: u32, name: u32) -> Result<Cookie<'_, Self, QueryServerStringReply>, ConnectionError>
     {
         query_server_string(self, screen, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
+    #[inline]
     fn glx_client_info<'c, 'input>(&'c self, major_version: u32, minor_version: u32, string: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         client_info(self, major_version, minor_version, string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
+    #[inline]
     fn glx_get_fb_configs(&self, screen: u32) -> Result<Cookie<'_, Self, GetFBConfigsReply>, ConnectionError>
     {
         get_fb_configs(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
+    #[inline]
     fn glx_create_pixmap<'c, 'input>(&'c self, screen: u32, fbconfig: Fbconfig, pixmap: xproto::Pixmap, glx_pixmap: Pixmap, attribs: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_pixmap(self, screen, fbconfig, pixmap, glx_pixmap, attribs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
+    #[inline]
     fn glx_destroy_pixmap(&self, glx_pixmap: Pixmap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_pixmap(self, glx_pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
+    #[inline]
     fn glx_create_new_context(&self, context: Context, fbconfig: Fbconfig, screen: u32, render_type: u32, share_list: Context, is_direct: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_new_context(self, context, fbconfig, screen, render_type, share_list, is_direct)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
+    #[inline]
     fn glx_query_context(&self, context: Context) -> Result<Cookie<'_, Self, QueryContextReply>, ConnectionError>
     {
         query_context(self, context)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
+    #[inline]
     fn glx_make_context_current(&self, old_context_tag: ContextTag, drawable: Drawable, read_drawable: Drawable, context: Context) -> Result<Cookie<'_, Self, MakeContextCurrentReply>, ConnectionError>
     {
         make_context_current(self, old_context_tag, drawable, read_drawable, context)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
+    #[inline]
     fn glx_create_pbuffer<'c, 'input>(&'c self, screen: u32, fbconfig: Fbconfig, pbuffer: Pbuffer, attribs: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_pbuffer(self, screen, fbconfig, pbuffer, attribs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
+    #[inline]
     fn glx_destroy_pbuffer(&self, pbuffer: Pbuffer) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_pbuffer(self, pbuffer)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
+    #[inline]
     fn glx_get_drawable_attributes(&self, drawable: Drawable) -> Result<Cookie<'_, Self, GetDrawableAttributesReply>, ConnectionError>
     {
         get_drawable_attributes(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
+    #[inline]
     fn glx_change_drawable_attributes<'c, 'input>(&'c self, drawable: Drawable, attribs: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_drawable_attributes(self, drawable, attribs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
+    #[inline]
     fn glx_create_window<'c, 'input>(&'c self, screen: u32, fbconfig: Fbconfig, window: xproto::Window, glx_window: Window, attribs: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_window(self, screen, fbconfig, window, glx_window, attribs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
+    #[inline]
     fn glx_delete_window(&self, glxwindow: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_window(self, glxwindow)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
+    #[inline]
     fn glx_set_client_info_arb<'c, 'input>(&'c self, major_version: u32, minor_version: u32, gl_versions: &'input [u32], gl_extension_string: &'input [u8], glx_extension_string: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_client_info_arb(self, major_version, minor_version, gl_versions, gl_extension_string, glx_extension_string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
+    #[inline]
     fn glx_create_context_attribs_arb<'c, 'input>(&'c self, context: Context, fbconfig: Fbconfig, screen: u32, share_list: Context, is_direct: bool, attribs: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_context_attribs_arb(self, context, fbconfig, screen, share_list, is_direct, attribs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
+    #[inline]
     fn glx_set_client_info2_arb<'c, 'input>(&'c self, major_version: u32, minor_version: u32, gl_versions: &'input [u32], gl_extension_string: &'input [u8], glx_extension_string: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_client_info2_arb(self, major_version, minor_version, gl_versions, gl_extension_string, glx_extension_string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
+    #[inline]
     fn glx_new_list(&self, context_tag: ContextTag, list: u32, mode: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         new_list(self, context_tag, list, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
+    #[inline]
     fn glx_end_list(&self, context_tag: ContextTag) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         end_list(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
+    #[inline]
     fn glx_delete_lists(&self, context_tag: ContextTag, list: u32, range: i32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_lists(self, context_tag, list, range)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
+    #[inline]
     fn glx_gen_lists(&self, context_tag: ContextTag, range: i32) -> Result<Cookie<'_, Self, GenListsReply>, ConnectionError>
     {
         gen_lists(self, context_tag, range)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
+    #[inline]
     fn glx_feedback_buffer(&self, context_tag: ContextTag, size: i32, type_: i32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         feedback_buffer(self, context_tag, size, type_)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
+    #[inline]
     fn glx_select_buffer(&self, context_tag: ContextTag, size: i32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         select_buffer(self, context_tag, size)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
+    #[inline]
     fn glx_render_mode(&self, context_tag: ContextTag, mode: u32) -> Result<Cookie<'_, Self, RenderModeReply>, ConnectionError>
     {
         render_mode(self, context_tag, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
+    #[inline]
     fn glx_finish(&self, context_tag: ContextTag) -> Result<Cookie<'_, Self, FinishReply>, ConnectionError>
     {
         finish(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
+    #[inline]
     fn glx_pixel_storef(&self, context_tag: ContextTag, pname: u32, datum: Float32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         pixel_storef(self, context_tag, pname, datum)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
+    #[inline]
     fn glx_pixel_storei(&self, context_tag: ContextTag, pname: u32, datum: i32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         pixel_storei(self, context_tag, pname, datum)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
+    #[inline]
     fn glx_read_pixels(&self, context_tag: ContextTag, x: i32, y: i32, width: i32, height: i32, format: u32, type_: u32, swap_bytes: bool, lsb_first: bool) -> Result<Cookie<'_, Self, ReadPixelsReply>, ConnectionError>
     {
         read_pixels(self, context_tag, x, y, width, height, format, type_, swap_bytes, lsb_first)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
+    #[inline]
     fn glx_get_booleanv(&self, context_tag: ContextTag, pname: i32) -> Result<Cookie<'_, Self, GetBooleanvReply>, ConnectionError>
     {
         get_booleanv(self, context_tag, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
+    #[inline]
     fn glx_get_clip_plane(&self, context_tag: ContextTag, plane: i32) -> Result<Cookie<'_, Self, GetClipPlaneReply>, ConnectionError>
     {
         get_clip_plane(self, context_tag, plane)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
+    #[inline]
     fn glx_get_doublev(&self, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Self, GetDoublevReply>, ConnectionError>
     {
         get_doublev(self, context_tag, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
+    #[inline]
     fn glx_get_error(&self, context_tag: ContextTag) -> Result<Cookie<'_, Self, GetErrorReply>, ConnectionError>
     {
         get_error(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
+    #[inline]
     fn glx_get_floatv(&self, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Self, GetFloatvReply>, ConnectionError>
     {
         get_floatv(self, context_tag, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
+    #[inline]
     fn glx_get_integerv(&self, context_tag: ContextTag, pname: u32) -> Result<Cookie<'_, Self, GetIntegervReply>, ConnectionError>
     {
         get_integerv(self, context_tag, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
+    #[inline]
     fn glx_get_lightfv(&self, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Self, GetLightfvReply>, ConnectionError>
     {
         get_lightfv(self, context_tag, light, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
+    #[inline]
     fn glx_get_lightiv(&self, context_tag: ContextTag, light: u32, pname: u32) -> Result<Cookie<'_, Self, GetLightivReply>, ConnectionError>
     {
         get_lightiv(self, context_tag, light, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
+    #[inline]
     fn glx_get_mapdv(&self, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Self, GetMapdvReply>, ConnectionError>
     {
         get_mapdv(self, context_tag, target, query)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
+    #[inline]
     fn glx_get_mapfv(&self, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Self, GetMapfvReply>, ConnectionError>
     {
         get_mapfv(self, context_tag, target, query)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
+    #[inline]
     fn glx_get_mapiv(&self, context_tag: ContextTag, target: u32, query: u32) -> Result<Cookie<'_, Self, GetMapivReply>, ConnectionError>
     {
         get_mapiv(self, context_tag, target, query)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
+    #[inline]
     fn glx_get_materialfv(&self, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Self, GetMaterialfvReply>, ConnectionError>
     {
         get_materialfv(self, context_tag, face, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
+    #[inline]
     fn glx_get_materialiv(&self, context_tag: ContextTag, face: u32, pname: u32) -> Result<Cookie<'_, Self, GetMaterialivReply>, ConnectionError>
     {
         get_materialiv(self, context_tag, face, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
+    #[inline]
     fn glx_get_pixel_mapfv(&self, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Self, GetPixelMapfvReply>, ConnectionError>
     {
         get_pixel_mapfv(self, context_tag, map)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
+    #[inline]
     fn glx_get_pixel_mapuiv(&self, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Self, GetPixelMapuivReply>, ConnectionError>
     {
         get_pixel_mapuiv(self, context_tag, map)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
+    #[inline]
     fn glx_get_pixel_mapusv(&self, context_tag: ContextTag, map: u32) -> Result<Cookie<'_, Self, GetPixelMapusvReply>, ConnectionError>
     {
         get_pixel_mapusv(self, context_tag, map)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
+    #[inline]
     fn glx_get_polygon_stipple(&self, context_tag: ContextTag, lsb_first: bool) -> Result<Cookie<'_, Self, GetPolygonStippleReply>, ConnectionError>
     {
         get_polygon_stipple(self, context_tag, lsb_first)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
+    #[inline]
     fn glx_get_string(&self, context_tag: ContextTag, name: u32) -> Result<Cookie<'_, Self, GetStringReply>, ConnectionError>
     {
         get_string(self, context_tag, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
+    #[inline]
     fn glx_get_tex_envfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexEnvfvReply>, ConnectionError>
     {
         get_tex_envfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
+    #[inline]
     fn glx_get_tex_enviv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexEnvivReply>, ConnectionError>
     {
         get_tex_enviv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
+    #[inline]
     fn glx_get_tex_gendv(&self, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexGendvReply>, ConnectionError>
     {
         get_tex_gendv(self, context_tag, coord, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
+    #[inline]
     fn glx_get_tex_genfv(&self, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexGenfvReply>, ConnectionError>
     {
         get_tex_genfv(self, context_tag, coord, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
+    #[inline]
     fn glx_get_tex_geniv(&self, context_tag: ContextTag, coord: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexGenivReply>, ConnectionError>
     {
         get_tex_geniv(self, context_tag, coord, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
+    #[inline]
     fn glx_get_tex_image(&self, context_tag: ContextTag, target: u32, level: i32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Self, GetTexImageReply>, ConnectionError>
     {
         get_tex_image(self, context_tag, target, level, format, type_, swap_bytes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
+    #[inline]
     fn glx_get_tex_parameterfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexParameterfvReply>, ConnectionError>
     {
         get_tex_parameterfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
+    #[inline]
     fn glx_get_tex_parameteriv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetTexParameterivReply>, ConnectionError>
     {
         get_tex_parameteriv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
+    #[inline]
     fn glx_get_tex_level_parameterfv(&self, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Self, GetTexLevelParameterfvReply>, ConnectionError>
     {
         get_tex_level_parameterfv(self, context_tag, target, level, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
+    #[inline]
     fn glx_get_tex_level_parameteriv(&self, context_tag: ContextTag, target: u32, level: i32, pname: u32) -> Result<Cookie<'_, Self, GetTexLevelParameterivReply>, ConnectionError>
     {
         get_tex_level_parameteriv(self, context_tag, target, level, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
+    #[inline]
     fn glx_is_enabled(&self, context_tag: ContextTag, capability: u32) -> Result<Cookie<'_, Self, IsEnabledReply>, ConnectionError>
     {
         is_enabled(self, context_tag, capability)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
+    #[inline]
     fn glx_is_list(&self, context_tag: ContextTag, list: u32) -> Result<Cookie<'_, Self, IsListReply>, ConnectionError>
     {
         is_list(self, context_tag, list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
+    #[inline]
     fn glx_flush(&self, context_tag: ContextTag) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         flush(self, context_tag)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
+    #[inline]
     fn glx_are_textures_resident<'c, 'input>(&'c self, context_tag: ContextTag, textures: &'input [u32]) -> Result<Cookie<'c, Self, AreTexturesResidentReply>, ConnectionError>
     {
         are_textures_resident(self, context_tag, textures)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
+    #[inline]
     fn glx_delete_textures<'c, 'input>(&'c self, context_tag: ContextTag, textures: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         delete_textures(self, context_tag, textures)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
+    #[inline]
     fn glx_gen_textures(&self, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Self, GenTexturesReply>, ConnectionError>
     {
         gen_textures(self, context_tag, n)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
+    #[inline]
     fn glx_is_texture(&self, context_tag: ContextTag, texture: u32) -> Result<Cookie<'_, Self, IsTextureReply>, ConnectionError>
     {
         is_texture(self, context_tag, texture)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
+    #[inline]
     fn glx_get_color_table(&self, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Self, GetColorTableReply>, ConnectionError>
     {
         get_color_table(self, context_tag, target, format, type_, swap_bytes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
+    #[inline]
     fn glx_get_color_table_parameterfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetColorTableParameterfvReply>, ConnectionError>
     {
         get_color_table_parameterfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
+    #[inline]
     fn glx_get_color_table_parameteriv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetColorTableParameterivReply>, ConnectionError>
     {
         get_color_table_parameteriv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
+    #[inline]
     fn glx_get_convolution_filter(&self, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Self, GetConvolutionFilterReply>, ConnectionError>
     {
         get_convolution_filter(self, context_tag, target, format, type_, swap_bytes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
+    #[inline]
     fn glx_get_convolution_parameterfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetConvolutionParameterfvReply>, ConnectionError>
     {
         get_convolution_parameterfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
+    #[inline]
     fn glx_get_convolution_parameteriv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetConvolutionParameterivReply>, ConnectionError>
     {
         get_convolution_parameteriv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
+    #[inline]
     fn glx_get_separable_filter(&self, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool) -> Result<Cookie<'_, Self, GetSeparableFilterReply>, ConnectionError>
     {
         get_separable_filter(self, context_tag, target, format, type_, swap_bytes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
+    #[inline]
     fn glx_get_histogram(&self, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Self, GetHistogramReply>, ConnectionError>
     {
         get_histogram(self, context_tag, target, format, type_, swap_bytes, reset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
+    #[inline]
     fn glx_get_histogram_parameterfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetHistogramParameterfvReply>, ConnectionError>
     {
         get_histogram_parameterfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
+    #[inline]
     fn glx_get_histogram_parameteriv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetHistogramParameterivReply>, ConnectionError>
     {
         get_histogram_parameteriv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
+    #[inline]
     fn glx_get_minmax(&self, context_tag: ContextTag, target: u32, format: u32, type_: u32, swap_bytes: bool, reset: bool) -> Result<Cookie<'_, Self, GetMinmaxReply>, ConnectionError>
     {
         get_minmax(self, context_tag, target, format, type_, swap_bytes, reset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
+    #[inline]
     fn glx_get_minmax_parameterfv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetMinmaxParameterfvReply>, ConnectionError>
     {
         get_minmax_parameterfv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
+    #[inline]
     fn glx_get_minmax_parameteriv(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetMinmaxParameterivReply>, ConnectionError>
     {
         get_minmax_parameteriv(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
+    #[inline]
     fn glx_get_compressed_tex_image_arb(&self, context_tag: ContextTag, target: u32, level: i32) -> Result<Cookie<'_, Self, GetCompressedTexImageARBReply>, ConnectionError>
     {
         get_compressed_tex_image_arb(self, context_tag, target, level)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
+    #[inline]
     fn glx_delete_queries_arb<'c, 'input>(&'c self, context_tag: ContextTag, ids: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         delete_queries_arb(self, context_tag, ids)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
+    #[inline]
     fn glx_gen_queries_arb(&self, context_tag: ContextTag, n: i32) -> Result<Cookie<'_, Self, GenQueriesARBReply>, ConnectionError>
     {
         gen_queries_arb(self, context_tag, n)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
+    #[inline]
     fn glx_is_query_arb(&self, context_tag: ContextTag, id: u32) -> Result<Cookie<'_, Self, IsQueryARBReply>, ConnectionError>
     {
         is_query_arb(self, context_tag, id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
+    #[inline]
     fn glx_get_queryiv_arb(&self, context_tag: ContextTag, target: u32, pname: u32) -> Result<Cookie<'_, Self, GetQueryivARBReply>, ConnectionError>
     {
         get_queryiv_arb(self, context_tag, target, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
+    #[inline]
     fn glx_get_query_objectiv_arb(&self, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Self, GetQueryObjectivARBReply>, ConnectionError>
     {
         get_query_objectiv_arb(self, context_tag, id, pname)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
+    #[inline]
     fn glx_get_query_objectuiv_arb(&self, context_tag: ContextTag, id: u32, pname: u32) -> Result<Cookie<'_, Self, GetQueryObjectuivARBReply>, ConnectionError>
     {
         get_query_objectuiv_arb(self, context_tag, id, pname)
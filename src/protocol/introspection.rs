@@ -0,0 +1,5812 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Descriptions of all requests and replies for `crate::dynamic`.
+
+// Descriptions of structs are unused if only disabled extensions use them
+#![allow(dead_code)]
+
+use crate::dynamic::{BitCase, FieldInfo, FieldKind, FieldType, ListLength, RequestInfo};
+
+const DRI2_ATTACHFORMAT: &[FieldInfo] = &[FieldInfo { name: "attachment", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }];
+const DRI2_DRI2BUFFER: &[FieldInfo] = &[FieldInfo { name: "attachment", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pitch", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cpp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }];
+const PRESENT_NOTIFY: &[FieldInfo] = &[FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "serial", kind: FieldKind::Value(FieldType::Card32) }];
+const RANDR_MODEINFO: &[FieldInfo] = &[FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dot_clock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hsync_start", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsync_end", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsync_start", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsync_end", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode_flags", kind: FieldKind::Value(FieldType::Card32) }];
+const RANDR_SCREENSIZE: &[FieldInfo] = &[FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mwidth", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mheight", kind: FieldKind::Value(FieldType::Card16) }];
+const RECORD_EXTRANGE: &[FieldInfo] = &[FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Struct { name: "Range16", fields: RECORD_RANGE16 }) }];
+const RECORD_RANGE: &[FieldInfo] = &[FieldInfo { name: "core_requests", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "core_replies", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "ext_requests", kind: FieldKind::Value(FieldType::Struct { name: "ExtRange", fields: RECORD_EXTRANGE }) }, FieldInfo { name: "ext_replies", kind: FieldKind::Value(FieldType::Struct { name: "ExtRange", fields: RECORD_EXTRANGE }) }, FieldInfo { name: "delivered_events", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "device_events", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "errors", kind: FieldKind::Value(FieldType::Struct { name: "Range8", fields: RECORD_RANGE8 }) }, FieldInfo { name: "client_started", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "client_died", kind: FieldKind::Value(FieldType::Bool) }];
+const RECORD_RANGE16: &[FieldInfo] = &[FieldInfo { name: "first", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "last", kind: FieldKind::Value(FieldType::Card16) }];
+const RECORD_RANGE8: &[FieldInfo] = &[FieldInfo { name: "first", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "last", kind: FieldKind::Value(FieldType::Card8) }];
+const RENDER_ANIMCURSORELT: &[FieldInfo] = &[FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "delay", kind: FieldKind::Value(FieldType::Card32) }];
+const RENDER_COLOR: &[FieldInfo] = &[FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alpha", kind: FieldKind::Value(FieldType::Card16) }];
+const RENDER_DIRECTFORMAT: &[FieldInfo] = &[FieldInfo { name: "red_shift", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "red_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green_shift", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue_shift", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alpha_shift", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alpha_mask", kind: FieldKind::Value(FieldType::Card16) }];
+const RENDER_GLYPHINFO: &[FieldInfo] = &[FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "x_off", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_off", kind: FieldKind::Value(FieldType::Int16) }];
+const RENDER_INDEXVALUE: &[FieldInfo] = &[FieldInfo { name: "pixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alpha", kind: FieldKind::Value(FieldType::Card16) }];
+const RENDER_LINEFIX: &[FieldInfo] = &[FieldInfo { name: "p1", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "p2", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }];
+const RENDER_PICTFORMINFO: &[FieldInfo] = &[FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "direct", kind: FieldKind::Value(FieldType::Struct { name: "DIRECTFORMAT", fields: RENDER_DIRECTFORMAT }) }, FieldInfo { name: "colormap", kind: FieldKind::Value(FieldType::Card32) }];
+const RENDER_POINTFIX: &[FieldInfo] = &[FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int32) }];
+const RENDER_SPANFIX: &[FieldInfo] = &[FieldInfo { name: "l", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "r", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int32) }];
+const RENDER_TRANSFORM: &[FieldInfo] = &[FieldInfo { name: "matrix11", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix12", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix13", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix21", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix22", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix23", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix31", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix32", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "matrix33", kind: FieldKind::Value(FieldType::Int32) }];
+const RENDER_TRAP: &[FieldInfo] = &[FieldInfo { name: "top", kind: FieldKind::Value(FieldType::Struct { name: "SPANFIX", fields: RENDER_SPANFIX }) }, FieldInfo { name: "bot", kind: FieldKind::Value(FieldType::Struct { name: "SPANFIX", fields: RENDER_SPANFIX }) }];
+const RENDER_TRAPEZOID: &[FieldInfo] = &[FieldInfo { name: "top", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "bottom", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "left", kind: FieldKind::Value(FieldType::Struct { name: "LINEFIX", fields: RENDER_LINEFIX }) }, FieldInfo { name: "right", kind: FieldKind::Value(FieldType::Struct { name: "LINEFIX", fields: RENDER_LINEFIX }) }];
+const RENDER_TRIANGLE: &[FieldInfo] = &[FieldInfo { name: "p1", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "p2", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "p3", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }];
+const RES_CLIENT: &[FieldInfo] = &[FieldInfo { name: "resource_base", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "resource_mask", kind: FieldKind::Value(FieldType::Card32) }];
+const RES_CLIENTIDSPEC: &[FieldInfo] = &[FieldInfo { name: "client", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card32) }];
+const RES_RESOURCEIDSPEC: &[FieldInfo] = &[FieldInfo { name: "resource", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }];
+const RES_TYPE: &[FieldInfo] = &[FieldInfo { name: "resource_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }];
+const SYNC_INT64: &[FieldInfo] = &[FieldInfo { name: "hi", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "lo", kind: FieldKind::Value(FieldType::Card32) }];
+const SYNC_TRIGGER: &[FieldInfo] = &[FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "wait_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "wait_value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }, FieldInfo { name: "test_type", kind: FieldKind::Value(FieldType::Card32) }];
+const SYNC_WAITCONDITION: &[FieldInfo] = &[FieldInfo { name: "trigger", kind: FieldKind::Value(FieldType::Struct { name: "TRIGGER", fields: SYNC_TRIGGER }) }, FieldInfo { name: "event_threshold", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }];
+const XEVIE_EVENT: &[FieldInfo] = &[FieldInfo { name: "", kind: FieldKind::Pad(32) }];
+const XF86DRI_DRMCLIPRECT: &[FieldInfo] = &[FieldInfo { name: "x1", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y1", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "x2", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "x3", kind: FieldKind::Value(FieldType::Int16) }];
+const XF86VIDMODE_MODEINFO: &[FieldInfo] = &[FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }];
+const XINERAMA_SCREENINFO: &[FieldInfo] = &[FieldInfo { name: "x_org", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_org", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }];
+const XINPUT_BARRIERRELEASEPOINTERINFO: &[FieldInfo] = &[FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "barrier", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "eventid", kind: FieldKind::Value(FieldType::Card32) }];
+const XINPUT_DEVICEINFO: &[FieldInfo] = &[FieldInfo { name: "device_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_class_info", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_use", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }];
+const XINPUT_GRABMODIFIERINFO: &[FieldInfo] = &[FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }];
+const XINPUT_GROUPINFO: &[FieldInfo] = &[FieldInfo { name: "base", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "latched", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "locked", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "effective", kind: FieldKind::Value(FieldType::Card8) }];
+const XINPUT_INPUTCLASSINFO: &[FieldInfo] = &[FieldInfo { name: "class_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "event_type_base", kind: FieldKind::Value(FieldType::Card8) }];
+const XINPUT_MODIFIERINFO: &[FieldInfo] = &[FieldInfo { name: "base", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "latched", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "locked", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "effective", kind: FieldKind::Value(FieldType::Card32) }];
+const XKB_INDICATORMAP: &[FieldInfo] = &[FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "whichGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "groups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "whichMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "realMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "vmods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ctrls", kind: FieldKind::Value(FieldType::Card32) }];
+const XKB_KEYMODMAP: &[FieldInfo] = &[FieldInfo { name: "keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mods", kind: FieldKind::Value(FieldType::Card8) }];
+const XKB_KEYVMODMAP: &[FieldInfo] = &[FieldInfo { name: "keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "vmods", kind: FieldKind::Value(FieldType::Card16) }];
+const XKB_MODDEF: &[FieldInfo] = &[FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "realMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "vmods", kind: FieldKind::Value(FieldType::Card16) }];
+const XKB_SETEXPLICIT: &[FieldInfo] = &[FieldInfo { name: "keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "explicit", kind: FieldKind::Value(FieldType::Card8) }];
+const XPROTO_ARC: &[FieldInfo] = &[FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "angle1", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "angle2", kind: FieldKind::Value(FieldType::Int16) }];
+const XPROTO_CHAR2B: &[FieldInfo] = &[FieldInfo { name: "byte1", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "byte2", kind: FieldKind::Value(FieldType::Card8) }];
+const XPROTO_CHARINFO: &[FieldInfo] = &[FieldInfo { name: "left_side_bearing", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "right_side_bearing", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "character_width", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "ascent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "descent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "attributes", kind: FieldKind::Value(FieldType::Card16) }];
+const XPROTO_COLORITEM: &[FieldInfo] = &[FieldInfo { name: "pixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }];
+const XPROTO_FONTPROP: &[FieldInfo] = &[FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Card32) }];
+const XPROTO_POINT: &[FieldInfo] = &[FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }];
+const XPROTO_RECTANGLE: &[FieldInfo] = &[FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }];
+const XPROTO_RGB: &[FieldInfo] = &[FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }];
+const XPROTO_SEGMENT: &[FieldInfo] = &[FieldInfo { name: "x1", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y1", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "x2", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y2", kind: FieldKind::Value(FieldType::Int16) }];
+const XPROTO_TIMECOORD: &[FieldInfo] = &[FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }];
+const XVMC_SURFACEINFO: &[FieldInfo] = &[FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "chroma_format", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pad0", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "subpicture_max_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "subpicture_max_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mc_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }];
+
+/// All requests that are known to this build of x11rb.
+pub static REQUESTS: &[RequestInfo] = &[
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreateWindow",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "wid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "parent", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "border_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "class", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "background_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "background_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "border_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "border_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "bit_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "win_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "backing_store", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "backing_planes", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "backing_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "override_redirect", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "save_under", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "do_not_propogate_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "colormap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16384, fields: &[FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeWindowAttributes",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "background_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "background_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "border_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "border_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "bit_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "win_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "backing_store", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "backing_planes", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "backing_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "override_redirect", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "save_under", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "do_not_propogate_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "colormap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16384, fields: &[FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetWindowAttributes",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "backing_store", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "class", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bit_gravity", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "win_gravity", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "backing_planes", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "backing_pixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "save_under", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "map_is_installed", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "map_state", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "override_redirect", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "colormap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "all_event_masks", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "your_event_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "do_not_propagate_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "DestroyWindow",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "DestroySubwindows",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeSaveSet",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ReparentWindow",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "parent", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "MapWindow",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "MapSubwindows",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UnmapWindow",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UnmapSubwindows",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ConfigureWindow",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "border_width", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "sibling", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "stack_mode", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CirculateWindow",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "direction", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetGeometry",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "border_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryTree",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "parent", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "children_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(14) }, FieldInfo { name: "children", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("children_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "InternAtom",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "only_if_exists", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "atom", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetAtomName",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "atom", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeProperty",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "data_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "DeleteProperty",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetProperty",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "delete", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_length", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_after", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "value", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListProperties",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "atoms_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "atoms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("atoms_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetSelectionOwner",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "owner", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetSelectionOwner",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "owner", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ConvertSelection",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "requestor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SendEvent",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "propagate", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Fixed(32) } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GrabPointer",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pointer_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "keyboard_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "confine_to", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UngrabPointer",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GrabButton",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pointer_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keyboard_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "confine_to", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "button", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UngrabButton",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "button", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeActivePointerGrab",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GrabKeyboard",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pointer_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "keyboard_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UngrabKeyboard",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GrabKey",
+        opcode: 33,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "key", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "pointer_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keyboard_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UngrabKey",
+        opcode: 34,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "key", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "AllowEvents",
+        opcode: 35,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GrabServer",
+        opcode: 36,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UngrabServer",
+        opcode: 37,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryPointer",
+        opcode: 38,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "same_screen", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "child", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "root_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "win_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "win_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetMotionEvents",
+        opcode: 39,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "start", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stop", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "events_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "events", kind: FieldKind::List { element: FieldType::Struct { name: "TIMECOORD", fields: XPROTO_TIMECOORD }, length: ListLength::Field("events_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "TranslateCoordinates",
+        opcode: 40,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "same_screen", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "child", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "WarpPointer",
+        opcode: 41,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetInputFocus",
+        opcode: 42,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "revert_to", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "focus", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetInputFocus",
+        opcode: 43,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "revert_to", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "focus", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryKeymap",
+        opcode: 44,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "keys", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Fixed(32) } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "OpenFont",
+        opcode: 45,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CloseFont",
+        opcode: 46,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryFont",
+        opcode: 47,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "min_bounds", kind: FieldKind::Value(FieldType::Struct { name: "CHARINFO", fields: XPROTO_CHARINFO }) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "max_bounds", kind: FieldKind::Value(FieldType::Struct { name: "CHARINFO", fields: XPROTO_CHARINFO }) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "min_char_or_byte2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_char_or_byte2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "default_char", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "properties_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "draw_direction", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "min_byte1", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "max_byte1", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "all_chars_exist", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "font_ascent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "font_descent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "char_infos_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "properties", kind: FieldKind::List { element: FieldType::Struct { name: "FONTPROP", fields: XPROTO_FONTPROP }, length: ListLength::Field("properties_len") } }, FieldInfo { name: "char_infos", kind: FieldKind::List { element: FieldType::Struct { name: "CHARINFO", fields: XPROTO_CHARINFO }, length: ListLength::Field("char_infos_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryTextExtents",
+        opcode: 48,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "odd_length", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Struct { name: "CHAR2B", fields: XPROTO_CHAR2B }, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "draw_direction", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "font_ascent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "font_descent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "overall_ascent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "overall_descent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "overall_width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "overall_left", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "overall_right", kind: FieldKind::Value(FieldType::Int32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListFonts",
+        opcode: 49,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_names", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pattern_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pattern", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("pattern_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "names_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "names", kind: FieldKind::Unsupported }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListFontsWithInfo",
+        opcode: 50,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_names", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pattern_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pattern", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("pattern_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "min_bounds", kind: FieldKind::Value(FieldType::Struct { name: "CHARINFO", fields: XPROTO_CHARINFO }) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "max_bounds", kind: FieldKind::Value(FieldType::Struct { name: "CHARINFO", fields: XPROTO_CHARINFO }) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "min_char_or_byte2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_char_or_byte2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "default_char", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "properties_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "draw_direction", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "min_byte1", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "max_byte1", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "all_chars_exist", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "font_ascent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "font_descent", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "replies_hint", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "properties", kind: FieldKind::List { element: FieldType::Struct { name: "FONTPROP", fields: XPROTO_FONTPROP }, length: ListLength::Field("properties_len") } }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetFontPath",
+        opcode: 51,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "font_qty", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "font", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetFontPath",
+        opcode: 52,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "path_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "path", kind: FieldKind::Unsupported }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreatePixmap",
+        opcode: 53,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FreePixmap",
+        opcode: 54,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreateGC",
+        opcode: 55,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "function", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "plane_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "foreground", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "background", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "line_width", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "line_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "cap_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "join_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "fill_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "fill_rule", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "tile", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "stipple", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "tile_stipple_x_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "tile_stipple_y_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 16384, fields: &[FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32768, fields: &[FieldInfo { name: "subwindow_mode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 65536, fields: &[FieldInfo { name: "graphics_exposures", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 131072, fields: &[FieldInfo { name: "clip_x_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 262144, fields: &[FieldInfo { name: "clip_y_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 524288, fields: &[FieldInfo { name: "clip_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1048576, fields: &[FieldInfo { name: "dash_offset", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2097152, fields: &[FieldInfo { name: "dashes", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4194304, fields: &[FieldInfo { name: "arc_mode", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeGC",
+        opcode: 56,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "function", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "plane_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "foreground", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "background", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "line_width", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "line_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "cap_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "join_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "fill_style", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "fill_rule", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "tile", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "stipple", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "tile_stipple_x_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "tile_stipple_y_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 16384, fields: &[FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32768, fields: &[FieldInfo { name: "subwindow_mode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 65536, fields: &[FieldInfo { name: "graphics_exposures", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 131072, fields: &[FieldInfo { name: "clip_x_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 262144, fields: &[FieldInfo { name: "clip_y_origin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 524288, fields: &[FieldInfo { name: "clip_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1048576, fields: &[FieldInfo { name: "dash_offset", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2097152, fields: &[FieldInfo { name: "dashes", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4194304, fields: &[FieldInfo { name: "arc_mode", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CopyGC",
+        opcode: 57,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetDashes",
+        opcode: 58,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dash_offset", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dashes_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dashes", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("dashes_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetClipRectangles",
+        opcode: 59,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ordering", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "clip_x_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "clip_y_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FreeGC",
+        opcode: 60,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ClearArea",
+        opcode: 61,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "exposures", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CopyArea",
+        opcode: 62,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CopyPlane",
+        opcode: 63,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bit_plane", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyPoint",
+        opcode: 64,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "coordinate_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "points", kind: FieldKind::List { element: FieldType::Struct { name: "POINT", fields: XPROTO_POINT }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyLine",
+        opcode: 65,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "coordinate_mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "points", kind: FieldKind::List { element: FieldType::Struct { name: "POINT", fields: XPROTO_POINT }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolySegment",
+        opcode: 66,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "segments", kind: FieldKind::List { element: FieldType::Struct { name: "SEGMENT", fields: XPROTO_SEGMENT }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyRectangle",
+        opcode: 67,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyArc",
+        opcode: 68,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "arcs", kind: FieldKind::List { element: FieldType::Struct { name: "ARC", fields: XPROTO_ARC }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FillPoly",
+        opcode: 69,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "shape", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "coordinate_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "points", kind: FieldKind::List { element: FieldType::Struct { name: "POINT", fields: XPROTO_POINT }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyFillRectangle",
+        opcode: 70,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyFillArc",
+        opcode: 71,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "arcs", kind: FieldKind::List { element: FieldType::Struct { name: "ARC", fields: XPROTO_ARC }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PutImage",
+        opcode: 72,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "left_pad", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetImage",
+        opcode: 73,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "plane_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyText8",
+        opcode: 74,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "items", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "PolyText16",
+        opcode: 75,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "items", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ImageText8",
+        opcode: 76,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "string_len", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("string_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ImageText16",
+        opcode: 77,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "string_len", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Struct { name: "CHAR2B", fields: XPROTO_CHAR2B }, length: ListLength::Field("string_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreateColormap",
+        opcode: 78,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "alloc", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FreeColormap",
+        opcode: 79,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CopyColormapAndFree",
+        opcode: 80,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_cmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "InstallColormap",
+        opcode: 81,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "UninstallColormap",
+        opcode: 82,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListInstalledColormaps",
+        opcode: 83,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cmaps_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "cmaps", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("cmaps_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "AllocColor",
+        opcode: 84,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "pixel", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "AllocNamedColor",
+        opcode: 85,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "exact_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "exact_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "exact_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_blue", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "AllocColorCells",
+        opcode: 86,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "contiguous", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "colors", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "planes", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixels_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "masks_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "pixels", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("pixels_len") } }, FieldInfo { name: "masks", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("masks_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "AllocColorPlanes",
+        opcode: 87,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "contiguous", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "colors", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "reds", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "greens", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "blues", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixels_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "red_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "green_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "blue_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "pixels", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("pixels_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FreeColors",
+        opcode: 88,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "plane_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixels", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "StoreColors",
+        opcode: 89,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "items", kind: FieldKind::List { element: FieldType::Struct { name: "COLORITEM", fields: XPROTO_COLORITEM }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "StoreNamedColor",
+        opcode: 90,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryColors",
+        opcode: 91,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixels", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "colors_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "colors", kind: FieldKind::List { element: FieldType::Struct { name: "RGB", fields: XPROTO_RGB }, length: ListLength::Field("colors_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "LookupColor",
+        opcode: 92,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "exact_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "exact_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "exact_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "visual_blue", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreateCursor",
+        opcode: 93,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fore_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "CreateGlyphCursor",
+        opcode: 94,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source_font", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_font", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source_char", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mask_char", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_blue", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "FreeCursor",
+        opcode: 95,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "RecolorCursor",
+        opcode: 96,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fore_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fore_blue", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_red", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_green", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "back_blue", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryBestSize",
+        opcode: 97,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "class", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "QueryExtension",
+        opcode: 98,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "first_event", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "first_error", kind: FieldKind::Value(FieldType::Card8) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListExtensions",
+        opcode: 99,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "names_len", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "names", kind: FieldKind::Unsupported }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeKeyboardMapping",
+        opcode: 100,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keycode_count", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "first_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keysyms_per_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "keysyms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetKeyboardMapping",
+        opcode: 101,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "first_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keysyms_per_keycode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "keysyms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeKeyboardControl",
+        opcode: 102,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "key_click_percent", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "bell_percent", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "bell_pitch", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "bell_duration", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "led", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "led_mode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "key", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "auto_repeat_mode", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetKeyboardControl",
+        opcode: 103,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "global_auto_repeat", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "led_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "key_click_percent", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bell_percent", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bell_pitch", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bell_duration", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "auto_repeats", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Fixed(32) } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "Bell",
+        opcode: 104,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "percent", kind: FieldKind::Value(FieldType::Int8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangePointerControl",
+        opcode: 105,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "acceleration_numerator", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "acceleration_denominator", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "threshold", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "do_acceleration", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "do_threshold", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetPointerControl",
+        opcode: 106,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "acceleration_numerator", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "acceleration_denominator", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "threshold", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetScreenSaver",
+        opcode: 107,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "timeout", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "interval", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "prefer_blanking", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "allow_exposures", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetScreenSaver",
+        opcode: 108,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "interval", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "prefer_blanking", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "allow_exposures", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ChangeHosts",
+        opcode: 109,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "family", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "address_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "address", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("address_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ListHosts",
+        opcode: 110,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hosts_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "hosts", kind: FieldKind::Unsupported }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetAccessControl",
+        opcode: 111,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetCloseDownMode",
+        opcode: 112,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "KillClient",
+        opcode: 113,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "resource", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "RotateProperties",
+        opcode: 114,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "atoms_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "delta", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "atoms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("atoms_len") } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "ForceScreenSaver",
+        opcode: 115,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetPointerMapping",
+        opcode: 116,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_len", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("map_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetPointerMapping",
+        opcode: 117,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_len", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "map", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("map_len") } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "SetModifierMapping",
+        opcode: 118,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keycodes_per_modifier", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "keycodes", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "GetModifierMapping",
+        opcode: 119,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keycodes_per_modifier", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "keycodes", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }]),
+    },
+    RequestInfo {
+        module: "xproto",
+        extension: None,
+        name: "NoOperation",
+        opcode: 127,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "bigreq",
+        extension: Some("BIG-REQUESTS"),
+        name: "Enable",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "maximum_request_length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "RedirectWindow",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "update", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "RedirectSubwindows",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "update", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "UnredirectWindow",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "update", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "UnredirectSubwindows",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "update", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "CreateRegionFromBorderClip",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "NameWindowPixmap",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "GetOverlayWindow",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "overlay_win", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "composite")]
+    RequestInfo {
+        module: "composite",
+        extension: Some("Composite"),
+        name: "ReleaseOverlayWindow",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "damage")]
+    RequestInfo {
+        module: "damage",
+        extension: Some("DAMAGE"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "damage")]
+    RequestInfo {
+        module: "damage",
+        extension: Some("DAMAGE"),
+        name: "Create",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "damage", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "level", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "damage")]
+    RequestInfo {
+        module: "damage",
+        extension: Some("DAMAGE"),
+        name: "Destroy",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "damage", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "damage")]
+    RequestInfo {
+        module: "damage",
+        extension: Some("DAMAGE"),
+        name: "Subtract",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "damage", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "repair", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "parts", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "damage")]
+    RequestInfo {
+        module: "damage",
+        extension: Some("DAMAGE"),
+        name: "Add",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "GetVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "Capable",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "capable", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "GetTimeouts",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "standby_timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "suspend_timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "off_timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }]),
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "SetTimeouts",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "standby_timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "suspend_timeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "off_timeout", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "Enable",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "Disable",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "ForceLevel",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "power_level", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "dpms")]
+    RequestInfo {
+        module: "dpms",
+        extension: Some("DPMS"),
+        name: "Info",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "power_level", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(21) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "Connect",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "driver_type", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "driver_name_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_name_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "driver_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("driver_name_length") } }, FieldInfo { name: "alignment_pad", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }, FieldInfo { name: "device_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("device_name_length") } }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "Authenticate",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "magic", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "authenticated", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "CreateDrawable",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "DestroyDrawable",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "GetBuffers",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attachments", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "buffers", kind: FieldKind::List { element: FieldType::Struct { name: "DRI2Buffer", fields: DRI2_DRI2BUFFER }, length: ListLength::Field("count") } }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "CopyRegion",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dest", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "GetBuffersWithFormat",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attachments", kind: FieldKind::List { element: FieldType::Struct { name: "AttachFormat", fields: DRI2_ATTACHFORMAT }, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "buffers", kind: FieldKind::List { element: FieldType::Struct { name: "DRI2Buffer", fields: DRI2_DRI2BUFFER }, length: ListLength::Field("count") } }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "SwapBuffers",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_msc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_msc_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "divisor_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "divisor_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "remainder_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "remainder_lo", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_lo", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "GetMSC",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_lo", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "WaitMSC",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_msc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_msc_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "divisor_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "divisor_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "remainder_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "remainder_lo", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_lo", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "WaitSBC",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_sbc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target_sbc_lo", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ust_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "msc_lo", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sbc_lo", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "SwapInterval",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "interval", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "dri2")]
+    RequestInfo {
+        module: "dri2",
+        extension: Some("DRI2"),
+        name: "GetParam",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "param", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "is_param_recognized", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_hi", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_lo", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "Open",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "PixmapFromBuffer",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "stride", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bpp", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "pixmap_fd", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "BufferFromPixmap",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "stride", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bpp", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "pixmap_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Pad(12) }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "FenceFromFD",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "initially_triggered", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "fence_fd", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "FDFromFence",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fence_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "GetSupportedModifiers",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bpp", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_window_modifiers", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_screen_modifiers", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "window_modifiers", kind: FieldKind::List { element: FieldType::Card64, length: ListLength::Field("num_window_modifiers") } }, FieldInfo { name: "screen_modifiers", kind: FieldKind::List { element: FieldType::Card64, length: ListLength::Field("num_screen_modifiers") } }]),
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "PixmapFromBuffers",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_buffers", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "stride0", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset0", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stride1", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset1", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stride2", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset2", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stride3", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset3", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bpp", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "modifier", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "buffers", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "dri3")]
+    RequestInfo {
+        module: "dri3",
+        extension: Some("DRI3"),
+        name: "BuffersFromPixmap",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "modifier", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "bpp", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(6) }, FieldInfo { name: "strides", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nfd") } }, FieldInfo { name: "offsets", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nfd") } }, FieldInfo { name: "buffers", kind: FieldKind::Unsupported }]),
+    },
+    RequestInfo {
+        module: "ge",
+        extension: Some("Generic Event Extension"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "Render",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "RenderLarge",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "request_num", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "request_total", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "data_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("data_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreateContext",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "share_list", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "is_direct", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DestroyContext",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "MakeCurrent",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "old_context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "IsDirect",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "is_direct", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "QueryVersion",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "WaitGL",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "WaitX",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CopyContext",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dest", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "SwapBuffers",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "UseXFont",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "font", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "first", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "list_base", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreateGLXPixmap",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glx_pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetVisualConfigs",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_visuals", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_properties", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "property_list", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DestroyGLXPixmap",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glx_pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "VendorPrivate",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vendor_code", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "VendorPrivateWithReply",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vendor_code", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "retval", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data1", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Fixed(24) } }, FieldInfo { name: "data2", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "QueryExtensionsString",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "QueryServerString",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("str_len") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "ClientInfo",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("str_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetFBConfigs",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_FB_configs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_properties", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "property_list", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreatePixmap",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fbconfig", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glx_pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DestroyPixmap",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glx_pixmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreateNewContext",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fbconfig", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "render_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "share_list", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "is_direct", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "QueryContext",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "MakeContextCurrent",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "old_context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "read_drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreatePbuffer",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fbconfig", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pbuffer", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DestroyPbuffer",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pbuffer", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetDrawableAttributes",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "ChangeDrawableAttributes",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreateWindow",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fbconfig", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glx_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DeleteWindow",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glxwindow", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "SetClientInfoARB",
+        opcode: 33,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_versions", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gl_str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glx_str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gl_versions", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }, FieldInfo { name: "gl_extension_string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("gl_str_len") } }, FieldInfo { name: "glx_extension_string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("glx_str_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "CreateContextAttribsARB",
+        opcode: 34,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fbconfig", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "share_list", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "is_direct", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "num_attribs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "SetClientInfo2ARB",
+        opcode: 35,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_versions", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gl_str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glx_str_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gl_versions", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }, FieldInfo { name: "gl_extension_string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("gl_str_len") } }, FieldInfo { name: "glx_extension_string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("glx_str_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "NewList",
+        opcode: 101,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "list", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "EndList",
+        opcode: 102,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DeleteLists",
+        opcode: 103,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "list", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GenLists",
+        opcode: 104,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "FeedbackBuffer",
+        opcode: 105,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "SelectBuffer",
+        opcode: 106,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "RenderMode",
+        opcode: 107,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "new_mode", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "Finish",
+        opcode: 108,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "PixelStoref",
+        opcode: 109,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "PixelStorei",
+        opcode: 110,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "ReadPixels",
+        opcode: 111,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "lsb_first", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetBooleanv",
+        opcode: 112,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(15) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Bool, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetClipPlane",
+        opcode: 113,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "plane", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Double, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetDoublev",
+        opcode: 114,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Double) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Double, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetError",
+        opcode: 115,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "error", kind: FieldKind::Value(FieldType::Int32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetFloatv",
+        opcode: 116,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetIntegerv",
+        opcode: 117,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetLightfv",
+        opcode: 118,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "light", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetLightiv",
+        opcode: 119,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "light", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMapdv",
+        opcode: 120,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "query", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Double) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Double, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMapfv",
+        opcode: 121,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "query", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMapiv",
+        opcode: 122,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "query", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMaterialfv",
+        opcode: 123,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "face", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMaterialiv",
+        opcode: 124,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "face", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetPixelMapfv",
+        opcode: 125,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "map", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetPixelMapuiv",
+        opcode: 126,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "map", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetPixelMapusv",
+        opcode: 127,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "map", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetPolygonStipple",
+        opcode: 128,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "lsb_first", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetString",
+        opcode: 129,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "string", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexEnvfv",
+        opcode: 130,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexEnviv",
+        opcode: 131,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexGendv",
+        opcode: 132,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "coord", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Double) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Double, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexGenfv",
+        opcode: 133,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "coord", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexGeniv",
+        opcode: 134,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "coord", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexImage",
+        opcode: 135,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "level", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexParameterfv",
+        opcode: 136,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexParameteriv",
+        opcode: 137,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexLevelParameterfv",
+        opcode: 138,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "level", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetTexLevelParameteriv",
+        opcode: 139,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "level", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "IsEnabled",
+        opcode: 140,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "capability", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "IsList",
+        opcode: 141,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "list", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "Flush",
+        opcode: 142,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "AreTexturesResident",
+        opcode: 143,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "textures", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Bool, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DeleteTextures",
+        opcode: 144,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "textures", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GenTextures",
+        opcode: 145,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "IsTexture",
+        opcode: 146,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "texture", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetColorTable",
+        opcode: 147,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetColorTableParameterfv",
+        opcode: 148,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetColorTableParameteriv",
+        opcode: 149,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetConvolutionFilter",
+        opcode: 150,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetConvolutionParameterfv",
+        opcode: 151,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetConvolutionParameteriv",
+        opcode: 152,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetSeparableFilter",
+        opcode: 153,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "row_w", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "col_h", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "rows_and_cols", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetHistogram",
+        opcode: 154,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "reset", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetHistogramParameterfv",
+        opcode: 155,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetHistogramParameteriv",
+        opcode: 156,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMinmax",
+        opcode: 157,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "swap_bytes", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "reset", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMinmaxParameterfv",
+        opcode: 158,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Float) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Float, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetMinmaxParameteriv",
+        opcode: 159,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetCompressedTexImageARB",
+        opcode: 160,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "level", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "DeleteQueriesARB",
+        opcode: 161,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "ids", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }],
+        reply: None,
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GenQueriesARB",
+        opcode: 162,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "IsQueryARB",
+        opcode: 163,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ret_val", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetQueryivARB",
+        opcode: 164,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetQueryObjectivARB",
+        opcode: 165,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "glx")]
+    RequestInfo {
+        module: "glx",
+        extension: Some("GLX"),
+        name: "GetQueryObjectuivARB",
+        opcode: 166,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_tag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pname", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "n", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "datum", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("n") } }]),
+    },
+    #[cfg(feature = "present")]
+    RequestInfo {
+        module: "present",
+        extension: Some("Present"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "present")]
+    RequestInfo {
+        module: "present",
+        extension: Some("Present"),
+        name: "Pixmap",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pixmap", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "serial", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "valid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "update", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_off", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_off", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "target_crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "wait_fence", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "idle_fence", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "options", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "target_msc", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "divisor", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "remainder", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "notifies", kind: FieldKind::List { element: FieldType::Struct { name: "Notify", fields: PRESENT_NOTIFY }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "present")]
+    RequestInfo {
+        module: "present",
+        extension: Some("Present"),
+        name: "NotifyMSC",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "serial", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "target_msc", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "divisor", kind: FieldKind::Value(FieldType::Card64) }, FieldInfo { name: "remainder", kind: FieldKind::Value(FieldType::Card64) }],
+        reply: None,
+    },
+    #[cfg(feature = "present")]
+    RequestInfo {
+        module: "present",
+        extension: Some("Present"),
+        name: "SelectInput",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "eid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "present")]
+    RequestInfo {
+        module: "present",
+        extension: Some("Present"),
+        name: "QueryCapabilities",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "capabilities", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetScreenConfig",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sizeID", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "rotation", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "rate", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "new_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "subpixel_order", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(10) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SelectInput",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "enable", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetScreenInfo",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "rotations", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nSizes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "sizeID", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "rotation", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "rate", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nInfo", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "sizes", kind: FieldKind::List { element: FieldType::Struct { name: "ScreenSize", fields: RANDR_SCREENSIZE }, length: ListLength::Field("nSizes") } }, FieldInfo { name: "rates", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetScreenSizeRange",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "min_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "min_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "max_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetScreenSize",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mm_width", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mm_height", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetScreenResources",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_crtcs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_modes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "names_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "crtcs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_crtcs") } }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_outputs") } }, FieldInfo { name: "modes", kind: FieldKind::List { element: FieldType::Struct { name: "ModeInfo", fields: RANDR_MODEINFO }, length: ListLength::Field("num_modes") } }, FieldInfo { name: "names", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("names_len") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetOutputInfo",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mm_width", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mm_height", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "connection", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "subpixel_order", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_crtcs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_modes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_preferred", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_clones", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtcs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_crtcs") } }, FieldInfo { name: "modes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_modes") } }, FieldInfo { name: "clones", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_clones") } }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("name_len") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ListOutputProperties",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_atoms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "atoms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_atoms") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "QueryOutputProperty",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "immutable", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(21) }, FieldInfo { name: "validValues", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ConfigureOutputProperty",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "values", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ChangeOutputProperty",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "num_units", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "DeleteOutputProperty",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetOutputProperty",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "delete", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_after", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "CreateMode",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode_info", kind: FieldKind::Value(FieldType::Struct { name: "ModeInfo", fields: RANDR_MODEINFO }) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "DestroyMode",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "AddOutputMode",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "DeleteOutputMode",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetCrtcInfo",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rotation", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "rotations", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_possible_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_outputs") } }, FieldInfo { name: "possible", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_possible_outputs") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetCrtcConfig",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rotation", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetCrtcGammaSize",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetCrtcGamma",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "red", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }, FieldInfo { name: "green", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }, FieldInfo { name: "blue", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetCrtcGamma",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "red", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }, FieldInfo { name: "green", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }, FieldInfo { name: "blue", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("size") } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetScreenResourcesCurrent",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_crtcs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_modes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "names_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "crtcs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_crtcs") } }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_outputs") } }, FieldInfo { name: "modes", kind: FieldKind::List { element: FieldType::Struct { name: "ModeInfo", fields: RANDR_MODEINFO }, length: ListLength::Field("num_modes") } }, FieldInfo { name: "names", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("names_len") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetCrtcTransform",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "transform", kind: FieldKind::Value(FieldType::Struct { name: "TRANSFORM", fields: RENDER_TRANSFORM }) }, FieldInfo { name: "filter_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "filter_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("filter_len") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "filter_params", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetCrtcTransform",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pending_transform", kind: FieldKind::Value(FieldType::Struct { name: "TRANSFORM", fields: RENDER_TRANSFORM }) }, FieldInfo { name: "has_transforms", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "current_transform", kind: FieldKind::Value(FieldType::Struct { name: "TRANSFORM", fields: RENDER_TRANSFORM }) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "pending_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pending_nparams", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "current_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "current_nparams", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pending_filter_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("pending_len") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "pending_params", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("pending_nparams") } }, FieldInfo { name: "current_filter_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("current_len") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "current_params", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("current_nparams") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetPanning",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "left", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "top", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_left", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_top", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "border_left", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_top", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_right", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_bottom", kind: FieldKind::Value(FieldType::Int16) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetPanning",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "left", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "top", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_left", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_top", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "track_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "border_left", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_top", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_right", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "border_bottom", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetOutputPrimary",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetOutputPrimary",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "output", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetProviders",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_providers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }, FieldInfo { name: "providers", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_providers") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetProviderInfo",
+        opcode: 33,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "capabilities", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_crtcs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_associated_providers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "crtcs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_crtcs") } }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_outputs") } }, FieldInfo { name: "associated_providers", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_associated_providers") } }, FieldInfo { name: "associated_capability", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_associated_providers") } }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetProviderOffloadSink",
+        opcode: 34,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sink_provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetProviderOutputSource",
+        opcode: 35,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source_provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "config_timestamp", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ListProviderProperties",
+        opcode: 36,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_atoms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "atoms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_atoms") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "QueryProviderProperty",
+        opcode: 37,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "immutable", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(21) }, FieldInfo { name: "valid_values", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ConfigureProviderProperty",
+        opcode: 38,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "range", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "values", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "ChangeProviderProperty",
+        opcode: 39,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "DeleteProviderProperty",
+        opcode: 40,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetProviderProperty",
+        opcode: 41,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "provider", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "long_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "delete", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "pending", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_after", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "GetMonitors",
+        opcode: 42,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "get_active", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "timestamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nMonitors", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nOutputs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "monitors", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "SetMonitor",
+        opcode: 43,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "monitorinfo", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "DeleteMonitor",
+        opcode: 44,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "CreateLease",
+        opcode: 45,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "lid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_crtcs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_outputs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "crtcs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_crtcs") } }, FieldInfo { name: "outputs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_outputs") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "master_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "randr")]
+    RequestInfo {
+        module: "randr",
+        extension: Some("RANDR"),
+        name: "FreeLease",
+        opcode: 46,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "lid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "terminate", kind: FieldKind::Value(FieldType::Byte) }],
+        reply: None,
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "CreateContext",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "element_header", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "num_client_specs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_ranges", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_specs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_client_specs") } }, FieldInfo { name: "ranges", kind: FieldKind::List { element: FieldType::Struct { name: "Range", fields: RECORD_RANGE }, length: ListLength::Field("num_ranges") } }],
+        reply: None,
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "RegisterClients",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "element_header", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "num_client_specs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_ranges", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_specs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_client_specs") } }, FieldInfo { name: "ranges", kind: FieldKind::List { element: FieldType::Struct { name: "Range", fields: RECORD_RANGE }, length: ListLength::Field("num_ranges") } }],
+        reply: None,
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "UnregisterClients",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_client_specs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_specs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_client_specs") } }],
+        reply: None,
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "GetContext",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "enabled", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "element_header", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "num_intercepted_clients", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "intercepted_clients", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "EnableContext",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "category", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "element_header", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "client_swapped", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "xid_base", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rec_sequence_num", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "DisableContext",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "record")]
+    RequestInfo {
+        module: "record",
+        extension: Some("RECORD"),
+        name: "FreeContext",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "QueryPictFormats",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_formats", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_screens", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_depths", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_visuals", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_subpixel", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "formats", kind: FieldKind::List { element: FieldType::Struct { name: "PICTFORMINFO", fields: RENDER_PICTFORMINFO }, length: ListLength::Field("num_formats") } }, FieldInfo { name: "screens", kind: FieldKind::Unsupported }, FieldInfo { name: "subpixels", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_subpixel") } }]),
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "QueryPictIndexValues",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_values", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "values", kind: FieldKind::List { element: FieldType::Struct { name: "INDEXVALUE", fields: RENDER_INDEXVALUE }, length: ListLength::Field("num_values") } }]),
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreatePicture",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "repeat", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "alphamap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "alphaxorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "alphayorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "clipxorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "clipyorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "clipmask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "graphicsexposure", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "subwindowmode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "polyedge", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "polymode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "dither", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "componentalpha", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "ChangePicture",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "repeat", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "alphamap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "alphaxorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "alphayorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "clipxorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "clipyorigin", kind: FieldKind::Value(FieldType::Int32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "clipmask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "graphicsexposure", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "subwindowmode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "polyedge", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "polymode", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "dither", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "componentalpha", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "SetPictureClipRectangles",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "clip_x_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "clip_y_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "FreePicture",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "Composite",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "mask_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "mask_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "Trapezoids",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "traps", kind: FieldKind::List { element: FieldType::Struct { name: "TRAPEZOID", fields: RENDER_TRAPEZOID }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "Triangles",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "triangles", kind: FieldKind::List { element: FieldType::Struct { name: "TRIANGLE", fields: RENDER_TRIANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "TriStrip",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "points", kind: FieldKind::List { element: FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "TriFan",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "points", kind: FieldKind::List { element: FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateGlyphSet",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gsid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "ReferenceGlyphSet",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gsid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "existing", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "FreeGlyphSet",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "AddGlyphs",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphs_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphids", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("glyphs_len") } }, FieldInfo { name: "glyphs", kind: FieldKind::List { element: FieldType::Struct { name: "GLYPHINFO", fields: RENDER_GLYPHINFO }, length: ListLength::Field("glyphs_len") } }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "FreeGlyphs",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphs", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CompositeGlyphs8",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "glyphcmds", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CompositeGlyphs16",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "glyphcmds", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CompositeGlyphs32",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mask_format", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "glyphset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "glyphcmds", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "FillRectangles",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "op", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "dst", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "color", kind: FieldKind::Value(FieldType::Struct { name: "COLOR", fields: RENDER_COLOR }) }, FieldInfo { name: "rects", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateCursor",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "SetPictureTransform",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "transform", kind: FieldKind::Value(FieldType::Struct { name: "TRANSFORM", fields: RENDER_TRANSFORM }) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "QueryFilters",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_aliases", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_filters", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "aliases", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("num_aliases") } }, FieldInfo { name: "filters", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "SetPictureFilter",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "filter_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "filter", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("filter_len") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "values", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateAnimCursor",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursors", kind: FieldKind::List { element: FieldType::Struct { name: "ANIMCURSORELT", fields: RENDER_ANIMCURSORELT }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "AddTraps",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_off", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_off", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "traps", kind: FieldKind::List { element: FieldType::Struct { name: "TRAP", fields: RENDER_TRAP }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateSolidFill",
+        opcode: 33,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "color", kind: FieldKind::Value(FieldType::Struct { name: "COLOR", fields: RENDER_COLOR }) }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateLinearGradient",
+        opcode: 34,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "p1", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "p2", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "num_stops", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stops", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("num_stops") } }, FieldInfo { name: "colors", kind: FieldKind::List { element: FieldType::Struct { name: "COLOR", fields: RENDER_COLOR }, length: ListLength::Field("num_stops") } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateRadialGradient",
+        opcode: 35,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "inner", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "outer", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "inner_radius", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "outer_radius", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "num_stops", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stops", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("num_stops") } }, FieldInfo { name: "colors", kind: FieldKind::List { element: FieldType::Struct { name: "COLOR", fields: RENDER_COLOR }, length: ListLength::Field("num_stops") } }],
+        reply: None,
+    },
+    #[cfg(feature = "render")]
+    RequestInfo {
+        module: "render",
+        extension: Some("RENDER"),
+        name: "CreateConicalGradient",
+        opcode: 36,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "center", kind: FieldKind::Value(FieldType::Struct { name: "POINTFIX", fields: RENDER_POINTFIX }) }, FieldInfo { name: "angle", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "num_stops", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stops", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("num_stops") } }, FieldInfo { name: "colors", kind: FieldKind::List { element: FieldType::Struct { name: "COLOR", fields: RENDER_COLOR }, length: ListLength::Field("num_stops") } }],
+        reply: None,
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "client_minor", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryClients",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_clients", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "clients", kind: FieldKind::List { element: FieldType::Struct { name: "Client", fields: RES_CLIENT }, length: ListLength::Field("num_clients") } }]),
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryClientResources",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "xid", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_types", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "types", kind: FieldKind::List { element: FieldType::Struct { name: "Type", fields: RES_TYPE }, length: ListLength::Field("num_types") } }]),
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryClientPixmapBytes",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "xid", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_overflow", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryClientIds",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_specs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "specs", kind: FieldKind::List { element: FieldType::Struct { name: "ClientIdSpec", fields: RES_CLIENTIDSPEC }, length: ListLength::Field("num_specs") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_ids", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "ids", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "res")]
+    RequestInfo {
+        module: "res",
+        extension: Some("X-Resource"),
+        name: "QueryResourceBytes",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_specs", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "specs", kind: FieldKind::List { element: FieldType::Struct { name: "ResourceIdSpec", fields: RES_RESOURCEIDSPEC }, length: ListLength::Field("num_specs") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_sizes", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "sizes", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "QueryInfo",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "saver_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ms_until_server", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ms_since_user_input", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "kind", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(7) }]),
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "SelectInput",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "SetAttributes",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "border_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "class", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "background_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "background_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "border_pixmap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "border_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "bit_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "win_gravity", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "backing_store", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "backing_planes", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "backing_pixel", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "override_redirect", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "save_under", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "do_not_propogate_mask", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "colormap", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16384, fields: &[FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "UnsetAttributes",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "screensaver")]
+    RequestInfo {
+        module: "screensaver",
+        extension: Some("MIT-SCREEN-SAVER"),
+        name: "Suspend",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "suspend", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "Rectangles",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "operation", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "destination_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ordering", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "Mask",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "operation", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "destination_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "source_bitmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "Combine",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "operation", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "destination_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "source_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "source_window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "Offset",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_offset", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "QueryExtents",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bounding_shaped", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "clip_shaped", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "bounding_shape_extents_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "bounding_shape_extents_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "bounding_shape_extents_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bounding_shape_extents_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "clip_shape_extents_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "clip_shape_extents_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "clip_shape_extents_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "clip_shape_extents_height", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "SelectInput",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "enable", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "InputSelected",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination_window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "enabled", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "shape")]
+    RequestInfo {
+        module: "shape",
+        extension: Some("SHAPE"),
+        name: "GetRectangles",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ordering", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rectangles_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Field("rectangles_len") } }]),
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "shared_pixmaps", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "uid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pixmap_format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(15) }]),
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "Attach",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "shmid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "read_only", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "Detach",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "PutImage",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "total_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "total_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "send_event", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "GetImage",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "plane_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "CreatePixmap",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "pid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "depth", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "AttachFd",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "shm_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "read_only", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "shm")]
+    RequestInfo {
+        module: "shm",
+        extension: Some("MIT-SHM"),
+        name: "CreateSegment",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "read_only", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nfd", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "shm_fd", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "Initialize",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "desired_major_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "desired_minor_version", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "ListSystemCounters",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "counters_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "counters", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "CreateCounter",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "initial_value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "DestroyCounter",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "QueryCounter",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "counter_value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "Await",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "wait_list", kind: FieldKind::List { element: FieldType::Struct { name: "WAITCONDITION", fields: SYNC_WAITCONDITION }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "ChangeCounter",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "amount", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "SetCounter",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "CreateAlarm",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "valueType", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "testType", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "delta", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "events", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "ChangeAlarm",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value_list", kind: FieldKind::Switch { mask: "value_mask", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "counter", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "valueType", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "testType", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "delta", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "events", kind: FieldKind::Value(FieldType::Card32) }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "DestroyAlarm",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alarm", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "QueryAlarm",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "alarm", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "trigger", kind: FieldKind::Value(FieldType::Struct { name: "TRIGGER", fields: SYNC_TRIGGER }) }, FieldInfo { name: "delta", kind: FieldKind::Value(FieldType::Struct { name: "INT64", fields: SYNC_INT64 }) }, FieldInfo { name: "events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "SetPriority",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "priority", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "GetPriority",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "priority", kind: FieldKind::Value(FieldType::Int32) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "CreateFence",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "initially_triggered", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "TriggerFence",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "ResetFence",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "DestroyFence",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "QueryFence",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fence", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "triggered", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "sync")]
+    RequestInfo {
+        module: "sync",
+        extension: Some("SYNC"),
+        name: "AwaitFence",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "fence_list", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    RequestInfo {
+        module: "xc_misc",
+        extension: Some("XC-MISC"),
+        name: "GetVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    RequestInfo {
+        module: "xc_misc",
+        extension: Some("XC-MISC"),
+        name: "GetXIDRange",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "start_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    RequestInfo {
+        module: "xc_misc",
+        extension: Some("XC-MISC"),
+        name: "GetXIDList",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ids_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "ids", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("ids_len") } }]),
+    },
+    #[cfg(feature = "xevie")]
+    RequestInfo {
+        module: "xevie",
+        extension: Some("XEVIE"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xevie")]
+    RequestInfo {
+        module: "xevie",
+        extension: Some("XEVIE"),
+        name: "Start",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "xevie")]
+    RequestInfo {
+        module: "xevie",
+        extension: Some("XEVIE"),
+        name: "End",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "xevie")]
+    RequestInfo {
+        module: "xevie",
+        extension: Some("XEVIE"),
+        name: "Send",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "event", kind: FieldKind::Value(FieldType::Struct { name: "Event", fields: XEVIE_EVENT }) }, FieldInfo { name: "data_type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(64) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "xevie")]
+    RequestInfo {
+        module: "xevie",
+        extension: Some("XEVIE"),
+        name: "SelectInput",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dri_major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dri_minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dri_minor_patch", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "QueryDirectRenderingCapable",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "is_capable", kind: FieldKind::Value(FieldType::Bool) }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "OpenConnection",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sarea_handle_low", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "sarea_handle_high", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bus_id_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "bus_id", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("bus_id_len") } }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "CloseConnection",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "GetClientDriverName",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_driver_major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_driver_minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_driver_patch_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_driver_name_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "client_driver_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("client_driver_name_len") } }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "CreateContext",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "visual", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hw_context", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "DestroyContext",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "CreateDrawable",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hw_drawable_handle", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "DestroyDrawable",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "GetDrawableInfo",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable_table_index", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable_table_stamp", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable_origin_X", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drawable_origin_Y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drawable_size_W", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drawable_size_H", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "num_clip_rects", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "back_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "back_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "num_back_clip_rects", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "clip_rects", kind: FieldKind::List { element: FieldType::Struct { name: "DrmClipRect", fields: XF86DRI_DRMCLIPRECT }, length: ListLength::Field("num_clip_rects") } }, FieldInfo { name: "back_clip_rects", kind: FieldKind::List { element: FieldType::Struct { name: "DrmClipRect", fields: XF86DRI_DRMCLIPRECT }, length: ListLength::Field("num_back_clip_rects") } }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "GetDeviceInfo",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "framebuffer_handle_low", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "framebuffer_handle_high", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "framebuffer_origin_offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "framebuffer_size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "framebuffer_stride", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_private_size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_private", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("device_private_size") } }]),
+    },
+    #[cfg(feature = "xf86dri")]
+    RequestInfo {
+        module: "xf86dri",
+        extension: Some("XFree86-DRI"),
+        name: "AuthConnection",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "magic", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "authenticated", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetModeLine",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "ModModeLine",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SwitchMode",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "zoom", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetMonitor",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vendor_length", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "model_length", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_hsync", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_vsync", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "hsync", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_hsync") } }, FieldInfo { name: "vsync", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_vsync") } }, FieldInfo { name: "vendor", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("vendor_length") } }, FieldInfo { name: "alignment_pad", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Expression } }, FieldInfo { name: "model", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("model_length") } }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "LockModeSwitch",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "lock", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetAllModeLines",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modecount", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "modeinfo", kind: FieldKind::List { element: FieldType::Struct { name: "ModeInfo", fields: XF86VIDMODE_MODEINFO }, length: ListLength::Field("modecount") } }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "AddModeLine",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "after_dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "after_hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "after_vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "after_flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "DeleteModeLine",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "ValidateModeLine",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SwitchToMode",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dotclock", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "hdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "htotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "hskew", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vdisplay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncstart", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vsyncend", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vtotal", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "privsize", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "private", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("privsize") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetViewPort",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SetViewPort",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetDotClocks",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "clocks", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "maxclocks", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "clock", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SetClientVersion",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SetGamma",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetGamma",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(26) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "red", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "green", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "blue", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetGammaRamp",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "red", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }, FieldInfo { name: "green", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }, FieldInfo { name: "blue", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "SetGammaRamp",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "red", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }, FieldInfo { name: "green", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }, FieldInfo { name: "blue", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetGammaRampSize",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "size", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }]),
+    },
+    #[cfg(feature = "xf86vidmode")]
+    RequestInfo {
+        module: "xf86vidmode",
+        extension: Some("XFree86-VidModeExtension"),
+        name: "GetPermissions",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "permissions", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "client_minor_version", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "ChangeSaveSet",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "target", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "map", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SelectSelectionInput",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SelectCursorInput",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "GetCursorImage",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "xhot", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "yhot", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor_serial", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "cursor_image", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreateRegion",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreateRegionFromBitmap",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bitmap", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreateRegionFromWindow",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreateRegionFromGC",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreateRegionFromPicture",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "DestroyRegion",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SetRegion",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CopyRegion",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "UnionRegion",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source1", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source2", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "IntersectRegion",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source1", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source2", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SubtractRegion",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source1", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "source2", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "InvertRegion",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bounds", kind: FieldKind::Value(FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "TranslateRegion",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dx", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "dy", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "RegionExtents",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "FetchRegion",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "extents", kind: FieldKind::Value(FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "rectangles", kind: FieldKind::List { element: FieldType::Struct { name: "RECTANGLE", fields: XPROTO_RECTANGLE }, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SetGCClipRegion",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_origin", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SetWindowShapeRegion",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dest", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dest_kind", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "x_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_offset", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SetPictureClipRegion",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "picture", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "region", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x_origin", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y_origin", kind: FieldKind::Value(FieldType::Int16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "SetCursorName",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nbytes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nbytes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "GetCursorName",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "atom", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nbytes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nbytes") } }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "GetCursorImageAndName",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "xhot", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "yhot", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cursor_serial", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor_atom", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nbytes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "cursor_image", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nbytes") } }]),
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "ChangeCursor",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "ChangeCursorByName",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nbytes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nbytes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "ExpandRegion",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "source", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "left", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "right", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "top", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bottom", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "HideCursor",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "ShowCursor",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "CreatePointerBarrier",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "barrier", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "x1", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "y1", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "x2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "y2", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "directions", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "num_devices", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "devices", kind: FieldKind::List { element: FieldType::Card16, length: ListLength::Field("num_devices") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xfixes")]
+    RequestInfo {
+        module: "xfixes",
+        extension: Some("XFIXES"),
+        name: "DeletePointerBarrier",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "barrier", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "GetState",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "GetScreenCount",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "screen_count", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "GetScreenSize",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "screen", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "IsActive",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xinerama")]
+    RequestInfo {
+        module: "xinerama",
+        extension: Some("XINERAMA"),
+        name: "QueryScreens",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "number", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "screen_info", kind: FieldKind::List { element: FieldType::Struct { name: "ScreenInfo", fields: XINERAMA_SCREENINFO }, length: ListLength::Field("number") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetExtensionVersion",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("name_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(19) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ListInputDevices",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "devices_len", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "devices", kind: FieldKind::List { element: FieldType::Struct { name: "DeviceInfo", fields: XINPUT_DEVICEINFO }, length: ListLength::Field("devices_len") } }, FieldInfo { name: "infos", kind: FieldKind::Unsupported }, FieldInfo { name: "names", kind: FieldKind::Unsupported }, FieldInfo { name: "", kind: FieldKind::Align(4) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "OpenDevice",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "class_info", kind: FieldKind::List { element: FieldType::Struct { name: "InputClassInfo", fields: XINPUT_INPUTCLASSINFO }, length: ListLength::Field("num_classes") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "CloseDevice",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SetDeviceMode",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SelectExtensionEvent",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetSelectedExtensionEvents",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_this_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_all_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "this_classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_this_classes") } }, FieldInfo { name: "all_classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_all_classes") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeDeviceDontPropagateList",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceDontPropagateList",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceMotionEvents",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "start", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stop", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_events", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_axes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(18) }, FieldInfo { name: "events", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeKeyboardDevice",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangePointerDevice",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "x_axis", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "y_axis", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GrabDevice",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "this_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "other_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "UngrabDevice",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GrabDeviceKey",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "modifier_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "grabbed_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "key", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "this_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "other_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "UngrabDeviceKey",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grabWindow", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "modifier_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "key", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "grabbed_device", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GrabDeviceButton",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "grabbed_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "modifier_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "this_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "other_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "button", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "UngrabDeviceButton",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "modifier_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "button", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "grabbed_device", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "AllowDeviceEvents",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceFocus",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "focus", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "revert_to", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(15) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SetDeviceFocus",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "focus", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "revert_to", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetFeedbackControl",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_feedbacks", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "feedbacks", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeFeedbackControl",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "feedback_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "feedback", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceKeyMapping",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "first_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "count", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "keysyms_per_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "keysyms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeDeviceKeyMapping",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "first_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keysyms_per_keycode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keycode_count", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keysyms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceModifierMapping",
+        opcode: 26,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "keycodes_per_modifier", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "keymaps", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SetDeviceModifierMapping",
+        opcode: 27,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keycodes_per_modifier", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "keymaps", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceButtonMapping",
+        opcode: 28,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "map_size", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "map", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("map_size") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SetDeviceButtonMapping",
+        opcode: 29,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_size", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "map", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("map_size") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "QueryDeviceState",
+        opcode: 30,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "classes", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "DeviceBell",
+        opcode: 32,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "feedback_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "feedback_class", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "percent", kind: FieldKind::Value(FieldType::Int8) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SetDeviceValuators",
+        opcode: 33,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "first_valuator", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "num_valuators", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "valuators", kind: FieldKind::List { element: FieldType::Int32, length: ListLength::Field("num_valuators") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceControl",
+        opcode: 34,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "control_id", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }, FieldInfo { name: "control", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeDeviceControl",
+        opcode: 35,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "control_id", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "control", kind: FieldKind::Unsupported }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ListDeviceProperties",
+        opcode: 36,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_atoms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "atoms", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_atoms") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "ChangeDeviceProperty",
+        opcode: 37,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "items", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "DeleteDeviceProperty",
+        opcode: 38,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "GetDeviceProperty",
+        opcode: 39,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "delete", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "xi_reply_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_after", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(10) }, FieldInfo { name: "items", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIQueryPointer",
+        opcode: 40,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "child", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root_x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "root_y", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "win_x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "win_y", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "same_screen", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "buttons_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mods", kind: FieldKind::Value(FieldType::Struct { name: "ModifierInfo", fields: XINPUT_MODIFIERINFO }) }, FieldInfo { name: "group", kind: FieldKind::Value(FieldType::Struct { name: "GroupInfo", fields: XINPUT_GROUPINFO }) }, FieldInfo { name: "buttons", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("buttons_len") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIWarpPointer",
+        opcode: 41,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_win", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dst_win", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "src_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dst_x", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "dst_y", kind: FieldKind::Value(FieldType::Int32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIChangeCursor",
+        opcode: 42,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIChangeHierarchy",
+        opcode: 43,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_changes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "changes", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XISetClientPointer",
+        opcode: 44,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIGetClientPointer",
+        opcode: 45,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "set", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XISelectEvents",
+        opcode: 46,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_mask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "masks", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIQueryVersion",
+        opcode: 47,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIQueryDevice",
+        opcode: 48,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_infos", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "infos", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XISetFocus",
+        opcode: 49,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIGetFocus",
+        opcode: 50,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "focus", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIGrabDevice",
+        opcode: 51,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "paired_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "mask_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mask", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("mask_len") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(23) }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIUngrabDevice",
+        opcode: 52,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIAllowEvents",
+        opcode: 53,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "event_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "touchid", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIPassiveGrabDevice",
+        opcode: 54,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "detail", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mask_len", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "grab_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "paired_device_mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "owner_events", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "mask", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("mask_len") } }, FieldInfo { name: "modifiers", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_modifiers") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "modifiers", kind: FieldKind::List { element: FieldType::Struct { name: "GrabModifierInfo", fields: XINPUT_GRABMODIFIERINFO }, length: ListLength::Field("num_modifiers") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIPassiveUngrabDevice",
+        opcode: 55,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "detail", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_modifiers", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "grab_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "modifiers", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_modifiers") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIListProperties",
+        opcode: 56,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_properties", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "properties", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_properties") } }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIChangeProperty",
+        opcode: 57,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "items", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIDeleteProperty",
+        opcode: 58,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIGetProperty",
+        opcode: 59,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "delete", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "len", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "bytes_after", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_items", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "format", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(11) }, FieldInfo { name: "items", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIGetSelectedEvents",
+        opcode: 60,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_masks", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "masks", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "XIBarrierReleasePointer",
+        opcode: 61,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_barriers", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "barriers", kind: FieldKind::List { element: FieldType::Struct { name: "BarrierReleasePointerInfo", fields: XINPUT_BARRIERRELEASEPOINTERINFO }, length: ListLength::Field("num_barriers") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xinput")]
+    RequestInfo {
+        module: "xinput",
+        extension: Some("XInputExtension"),
+        name: "SendExtensionEvent",
+        opcode: 31,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "destination", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "device_id", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "propagate", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "num_classes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_events", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "events", kind: FieldKind::Unsupported }, FieldInfo { name: "classes", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_classes") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "UseExtension",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "wantedMajor", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "wantedMinor", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "supported", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "serverMajor", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "serverMinor", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SelectEvents",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "affectWhich", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "clear", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "selectAll", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "affectMap", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "details", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "Bell",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bellClass", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "bellID", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "percent", kind: FieldKind::Value(FieldType::Int8) }, FieldInfo { name: "forceSound", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "eventOnly", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "pitch", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "duration", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetState",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "baseMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "latchedMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "lockedMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "group", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "lockedGroup", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "baseGroup", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "latchedGroup", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "compatState", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "grabMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "compatGrabMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "lookupMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "compatLookupMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "ptrBtnState", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(6) }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "LatchLockState",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "affectModLocks", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "modLocks", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "lockGroup", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "groupLock", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "affectModLatches", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "latchGroup", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "groupLatch", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetControls",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "mouseKeysDfltBtn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "numGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "groupsWrap", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "internalModsMask", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ignoreLockModsMask", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "internalModsRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ignoreLockModsRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "internalModsVmods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ignoreLockModsVmods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "repeatDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "repeatInterval", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "slowKeysDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "debounceDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysInterval", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysTimeToMax", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysMaxSpeed", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysCurve", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "accessXOption", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "accessXTimeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "accessXTimeoutOptionsMask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "accessXTimeoutOptionsValues", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "accessXTimeoutMask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "accessXTimeoutValues", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "enabledControls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "perKeyRepeat", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Fixed(32) } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetControls",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "affectInternalRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "internalRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "affectIgnoreLockRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "ignoreLockRealMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "affectInternalVirtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "internalVirtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "affectIgnoreLockVirtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ignoreLockVirtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysDfltBtn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "groupsWrap", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "accessXOptions", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "affectEnabledControls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "enabledControls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "changeControls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "repeatDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "repeatInterval", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "slowKeysDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "debounceDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysDelay", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysInterval", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysTimeToMax", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysMaxSpeed", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "mouseKeysCurve", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "accessXTimeout", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "accessXTimeoutMask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "accessXTimeoutValues", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "accessXTimeoutOptionsMask", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "accessXTimeoutOptionsValues", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "perKeyRepeat", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Fixed(32) } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetMap",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "full", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "partial", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstType", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeySym", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeySyms", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyAction", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyActions", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyBehavior", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstVModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "minKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "maxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstType", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeySym", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalSyms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeySyms", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyAction", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalActions", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeyActions", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyBehavior", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstVModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map", kind: FieldKind::Switch { mask: "present", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "types_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "syms_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "acts_rtrn_count", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nKeyActions") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "acts_rtrn_acts", kind: FieldKind::Unsupported }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "behaviors_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "vmods_rtrn", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "explicit_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "SetExplicit", fields: XKB_SETEXPLICIT }, length: ListLength::Field("totalKeyExplicit") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "modmap_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "KeyModMap", fields: XKB_KEYMODMAP }, length: ListLength::Field("totalModMapKeys") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "vmodmap_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "KeyVModMap", fields: XKB_KEYVMODMAP }, length: ListLength::Field("totalVModMapKeys") } }] }] } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetMap",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "maxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstType", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeySym", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeySyms", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalSyms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstKeyAction", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyActions", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalActions", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstKeyBehavior", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstVModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "values", kind: FieldKind::Switch { mask: "present", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "types", kind: FieldKind::Unsupported }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "syms", kind: FieldKind::Unsupported }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "actionsCount", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nKeyActions") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "actions", kind: FieldKind::Unsupported }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "behaviors", kind: FieldKind::Unsupported }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "vmods", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "explicit", kind: FieldKind::List { element: FieldType::Struct { name: "SetExplicit", fields: XKB_SETEXPLICIT }, length: ListLength::Field("totalKeyExplicit") } }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "modmap", kind: FieldKind::List { element: FieldType::Struct { name: "KeyModMap", fields: XKB_KEYMODMAP }, length: ListLength::Field("totalModMapKeys") } }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "vmodmap", kind: FieldKind::List { element: FieldType::Struct { name: "KeyVModMap", fields: XKB_KEYVMODMAP }, length: ListLength::Field("totalVModMapKeys") } }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetCompatMap",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "groups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "getAllSI", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "firstSI", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSI", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "groupsRtrn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "firstSIRtrn", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSIRtrn", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nTotalSI", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "si_rtrn", kind: FieldKind::Unsupported }, FieldInfo { name: "group_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "ModDef", fields: XKB_MODDEF }, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetCompatMap",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "recomputeActions", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "truncateSI", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "groups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstSI", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSI", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "si", kind: FieldKind::Unsupported }, FieldInfo { name: "groupMaps", kind: FieldKind::List { element: FieldType::Struct { name: "ModDef", fields: XKB_MODDEF }, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetIndicatorState",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "state", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetIndicatorMap",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "realIndicators", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nIndicators", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(15) }, FieldInfo { name: "maps", kind: FieldKind::List { element: FieldType::Struct { name: "IndicatorMap", fields: XKB_INDICATORMAP }, length: ListLength::Expression } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetIndicatorMap",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "maps", kind: FieldKind::List { element: FieldType::Struct { name: "IndicatorMap", fields: XKB_INDICATORMAP }, length: ListLength::Expression } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetNamedIndicator",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ledClass", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ledID", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "indicator", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "indicator", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "found", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "on", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "realIndicator", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "ndx", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_flags", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_whichGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_groups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_whichMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_mods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_realMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_vmod", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map_ctrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "supported", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetNamedIndicator",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ledClass", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ledID", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "indicator", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "setState", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "on", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "setMap", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "createMap", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "map_flags", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_whichGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_groups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_whichMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_realMods", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "map_vmods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map_ctrls", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetNames",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "maxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "groupNames", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "indicators", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nRadioGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyAliases", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKTLevels", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "valueList", kind: FieldKind::Switch { mask: "which", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "keycodesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "geometryName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "symbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "physSymbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "typesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "compatName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "typeNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nTypes") } }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "nLevelsPerType", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nTypes") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "ktLevelNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "indicatorNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "virtualModNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "groups", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "keyNames", kind: FieldKind::Unsupported }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "keyAliases", kind: FieldKind::Unsupported }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "radioGroupNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nRadioGroups") } }] }] } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetNames",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "firstType", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKTLevelt", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKTLevels", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "indicators", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "groupNames", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nRadioGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyAliases", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "totalKTLevelNames", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "values", kind: FieldKind::Switch { mask: "which", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "keycodesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "geometryName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "symbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "physSymbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "typesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "compatName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "typeNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nTypes") } }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "nLevelsPerType", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nTypes") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "ktLevelNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "indicatorNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "virtualModNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "groups", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "keyNames", kind: FieldKind::Unsupported }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "keyAliases", kind: FieldKind::Unsupported }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "radioGroupNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nRadioGroups") } }] }] } }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "PerClientFlags",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "change", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ctrlsToChange", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "autoCtrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "autoCtrlsValues", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "supported", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "autoCtrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "autoCtrlsValues", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "ListComponents",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "maxNames", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nKeymaps", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeycodes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nCompatMaps", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSymbols", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nGeometries", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "extra", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(10) }, FieldInfo { name: "keymaps", kind: FieldKind::Unsupported }, FieldInfo { name: "keycodes", kind: FieldKind::Unsupported }, FieldInfo { name: "types", kind: FieldKind::Unsupported }, FieldInfo { name: "compatMaps", kind: FieldKind::Unsupported }, FieldInfo { name: "symbols", kind: FieldKind::Unsupported }, FieldInfo { name: "geometries", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetKbdByName",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "need", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "want", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "load", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "maxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "loaded", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "newKeyboard", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "found", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "reported", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "replies", kind: FieldKind::Switch { mask: "reported", cases: &[BitCase { mask: 13, fields: &[FieldInfo { name: "getmap_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "typeDeviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "getmap_sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "getmap_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "typeMinKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "typeMaxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstType", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeySym", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalSyms", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeySyms", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyAction", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalActions", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeyActions", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyBehavior", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyBehaviors", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalKeyExplicit", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstVModMapKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalVModMapKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "map", kind: FieldKind::Switch { mask: "present", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "types_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "syms_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "acts_rtrn_count", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nKeyActions") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "acts_rtrn_acts", kind: FieldKind::Unsupported }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "behaviors_rtrn", kind: FieldKind::Unsupported }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "vmods_rtrn", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Expression } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "explicit_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "SetExplicit", fields: XKB_SETEXPLICIT }, length: ListLength::Field("totalKeyExplicit") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "modmap_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "KeyModMap", fields: XKB_KEYMODMAP }, length: ListLength::Field("totalModMapKeys") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "vmodmap_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "KeyVModMap", fields: XKB_KEYVMODMAP }, length: ListLength::Field("totalVModMapKeys") } }] }] } }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "compatmap_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "compatDeviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "compatmap_sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "compatmap_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "groupsRtrn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "firstSIRtrn", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSIRtrn", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nTotalSI", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "si_rtrn", kind: FieldKind::Unsupported }, FieldInfo { name: "group_rtrn", kind: FieldKind::List { element: FieldType::Struct { name: "ModDef", fields: XKB_MODDEF }, length: ListLength::Expression } }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "indicatormap_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "indicatorDeviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "indicatormap_sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "indicatormap_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "realIndicators", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nIndicators", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(15) }, FieldInfo { name: "maps", kind: FieldKind::List { element: FieldType::Struct { name: "IndicatorMap", fields: XKB_INDICATORMAP }, length: ListLength::Field("nIndicators") } }] }, BitCase { mask: 160, fields: &[FieldInfo { name: "keyname_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keyDeviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keyname_sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "keyname_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "which", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "keyMinKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "keyMaxKeyCode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nTypes", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "groupNames", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "virtualMods", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstKey", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeys", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "indicators", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nRadioGroups", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKeyAliases", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nKTLevels", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(4) }, FieldInfo { name: "valueList", kind: FieldKind::Switch { mask: "which", cases: &[BitCase { mask: 1, fields: &[FieldInfo { name: "keycodesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 2, fields: &[FieldInfo { name: "geometryName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 4, fields: &[FieldInfo { name: "symbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 8, fields: &[FieldInfo { name: "physSymbolsName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 16, fields: &[FieldInfo { name: "typesName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 32, fields: &[FieldInfo { name: "compatName", kind: FieldKind::Value(FieldType::Card32) }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "typeNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nTypes") } }] }, BitCase { mask: 128, fields: &[FieldInfo { name: "nLevelsPerType", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Field("nTypes") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "ktLevelNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 256, fields: &[FieldInfo { name: "indicatorNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 2048, fields: &[FieldInfo { name: "virtualModNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 4096, fields: &[FieldInfo { name: "groups", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Expression } }] }, BitCase { mask: 512, fields: &[FieldInfo { name: "keyNames", kind: FieldKind::Unsupported }] }, BitCase { mask: 1024, fields: &[FieldInfo { name: "keyAliases", kind: FieldKind::Unsupported }] }, BitCase { mask: 8192, fields: &[FieldInfo { name: "radioGroupNames", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("nRadioGroups") } }] }] } }] }, BitCase { mask: 64, fields: &[FieldInfo { name: "geometry_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "geometryDeviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "geometry_sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "geometry_length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "name", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "geometryFound", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "widthMM", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "heightMM", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nProperties", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nColors", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nShapes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nSections", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nDoodads", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nKeyAliases", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "baseColorNdx", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "labelColorNdx", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "labelFont", kind: FieldKind::Unsupported }] }] } }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "GetDeviceInfo",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "wanted", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "allButtons", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "firstButton", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nButtons", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "ledClass", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "ledID", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "deviceID", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "present", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "supported", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "unsupported", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nDeviceLedFBs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstBtnWanted", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nBtnsWanted", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "firstBtnRtrn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nBtnsRtrn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "totalBtns", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "hasOwnState", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "dfltKbdFB", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "dfltLedFB", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "devType", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nameLen", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nameLen") } }, FieldInfo { name: "", kind: FieldKind::Align(4) }, FieldInfo { name: "btnActions", kind: FieldKind::Unsupported }, FieldInfo { name: "leds", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetDeviceInfo",
+        opcode: 25,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "deviceSpec", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "firstBtn", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "nBtns", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "change", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "nDeviceLedFBs", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "btnActions", kind: FieldKind::Unsupported }, FieldInfo { name: "leds", kind: FieldKind::Unsupported }],
+        reply: None,
+    },
+    #[cfg(feature = "xkb")]
+    RequestInfo {
+        module: "xkb",
+        extension: Some("XKEYBOARD"),
+        name: "SetDebuggingFlags",
+        opcode: 101,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "msgLength", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "affectFlags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "affectCtrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "ctrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "message", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("msgLength") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "currentFlags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "currentCtrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "supportedFlags", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "supportedCtrls", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintQueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetPrinterList",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "printerNameLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "localeLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "printer_name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("printerNameLen") } }, FieldInfo { name: "locale", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("localeLen") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "listCount", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "printers", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintRehashPrinterList",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "CreateContext",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "printerNameLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "localeLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "printerName", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("printerNameLen") } }, FieldInfo { name: "locale", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("localeLen") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintSetContext",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetContext",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintDestroyContext",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetScreenOfContext",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintStartJob",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "output_mode", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintEndJob",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cancel", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintStartDoc",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "driver_mode", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintEndDoc",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cancel", kind: FieldKind::Value(FieldType::Bool) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintPutDocumentData",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "len_data", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "len_fmt", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "len_options", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("len_data") } }, FieldInfo { name: "doc_format", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("len_fmt") } }, FieldInfo { name: "options", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("len_options") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetDocumentData",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "max_bytes", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "status_code", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "finished_flag", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "dataLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Byte, length: ListLength::Field("dataLen") } }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintStartPage",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintEndPage",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "cancel", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintSelectInput",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintInputSelected",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "event_mask", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "all_events_mask", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetAttributes",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pool", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stringLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "attributes", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("stringLen") } }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetOneAttributes",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "nameLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pool", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }, FieldInfo { name: "name", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("nameLen") } }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "valueLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "value", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("valueLen") } }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintSetAttributes",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "stringLen", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "pool", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "rule", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "attributes", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetPageDimensions",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "offset_x", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "offset_y", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "reproducible_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "reproducible_height", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintQueryScreens",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "listCount", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "roots", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("listCount") } }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintSetImageResolution",
+        opcode: 23,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "image_resolution", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "status", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "previous_resolutions", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xprint")]
+    RequestInfo {
+        module: "xprint",
+        extension: Some("XpExtension"),
+        name: "PrintGetImageResolution",
+        opcode: 24,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "image_resolution", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "client_major", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "client_minor", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "server_major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "server_minor", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetDeviceCreateContext",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetDeviceCreateContext",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetDeviceContext",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetDeviceContext",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "device", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetWindowCreateContext",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetWindowCreateContext",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetWindowContext",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetPropertyCreateContext",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetPropertyCreateContext",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetPropertyUseContext",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetPropertyUseContext",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetPropertyContext",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetPropertyDataContext",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "property", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "ListProperties",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "properties_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "properties", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetSelectionCreateContext",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetSelectionCreateContext",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "SetSelectionUseContext",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }],
+        reply: None,
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetSelectionUseContext",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetSelectionContext",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetSelectionDataContext",
+        opcode: 20,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "selection", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "ListSelections",
+        opcode: 21,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "selections_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "selections", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xselinux")]
+    RequestInfo {
+        module: "xselinux",
+        extension: Some("SELinux"),
+        name: "GetClientContext",
+        opcode: 22,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "resource", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_len", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "context", kind: FieldKind::List { element: FieldType::Char, length: ListLength::Field("context_len") } }]),
+    },
+    #[cfg(feature = "xtest")]
+    RequestInfo {
+        module: "xtest",
+        extension: Some("XTEST"),
+        name: "GetVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "major_version", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor_version", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xtest")]
+    RequestInfo {
+        module: "xtest",
+        extension: Some("XTEST"),
+        name: "CompareCursor",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "cursor", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "same", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xtest")]
+    RequestInfo {
+        module: "xtest",
+        extension: Some("XTEST"),
+        name: "FakeInput",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "type", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "detail", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "", kind: FieldKind::Pad(2) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "root", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(8) }, FieldInfo { name: "rootX", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "rootY", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "", kind: FieldKind::Pad(7) }, FieldInfo { name: "deviceid", kind: FieldKind::Value(FieldType::Card8) }],
+        reply: None,
+    },
+    #[cfg(feature = "xtest")]
+    RequestInfo {
+        module: "xtest",
+        extension: Some("XTEST"),
+        name: "GrabControl",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "impervious", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryExtension",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryAdaptors",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "window", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_adaptors", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "info", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryEncodings",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_encodings", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(22) }, FieldInfo { name: "info", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "GrabPort",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "result", kind: FieldKind::Value(FieldType::Byte) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "UngrabPort",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "time", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "PutVideo",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vid_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vid_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "PutStill",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vid_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vid_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "GetVideo",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vid_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vid_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "GetStill",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vid_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "vid_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vid_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "StopVideo",
+        opcode: 9,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "SelectVideoNotify",
+        opcode: 10,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "onoff", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "SelectPortNotify",
+        opcode: 11,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "onoff", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryBestSize",
+        opcode: 12,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "vid_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "vid_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "motion", kind: FieldKind::Value(FieldType::Bool) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "actual_width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "actual_height", kind: FieldKind::Value(FieldType::Card16) }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "SetPortAttribute",
+        opcode: 13,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribute", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Int32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "GetPortAttribute",
+        opcode: 14,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "attribute", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "value", kind: FieldKind::Value(FieldType::Int32) }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryPortAttributes",
+        opcode: 15,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_attributes", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "text_size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(16) }, FieldInfo { name: "attributes", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "ListImageFormats",
+        opcode: 16,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_formats", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "format", kind: FieldKind::Unsupported }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "QueryImageAttributes",
+        opcode: 17,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num_planes", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "data_size", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "pitches", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_planes") } }, FieldInfo { name: "offsets", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("num_planes") } }]),
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "PutImage",
+        opcode: 18,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "data", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Remaining } }],
+        reply: None,
+    },
+    #[cfg(feature = "xv")]
+    RequestInfo {
+        module: "xv",
+        extension: Some("XVideo"),
+        name: "ShmPutImage",
+        opcode: 19,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "drawable", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "gc", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "shmseg", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "offset", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "src_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "src_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "src_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_x", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_y", kind: FieldKind::Value(FieldType::Int16) }, FieldInfo { name: "drw_w", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "drw_h", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "send_event", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(3) }],
+        reply: None,
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "major", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "minor", kind: FieldKind::Value(FieldType::Card32) }]),
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "ListSurfaceTypes",
+        opcode: 1,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "surfaces", kind: FieldKind::List { element: FieldType::Struct { name: "SurfaceInfo", fields: XVMC_SURFACEINFO }, length: ListLength::Field("num") } }]),
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "CreateContext",
+        opcode: 2,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "port_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "surface_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "flags", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width_actual", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height_actual", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "flags_return", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "priv_data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "DestroyContext",
+        opcode: 3,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "context_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "CreateSurface",
+        opcode: 4,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "surface_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(24) }, FieldInfo { name: "priv_data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "DestroySurface",
+        opcode: 5,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "surface_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "CreateSubpicture",
+        opcode: 6,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "subpicture_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "context", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "xvimage_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height", kind: FieldKind::Value(FieldType::Card16) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "width_actual", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "height_actual", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "num_palette_entries", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "entry_bytes", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "component_order", kind: FieldKind::List { element: FieldType::Card8, length: ListLength::Fixed(4) } }, FieldInfo { name: "", kind: FieldKind::Pad(12) }, FieldInfo { name: "priv_data", kind: FieldKind::List { element: FieldType::Card32, length: ListLength::Field("length") } }]),
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "DestroySubpicture",
+        opcode: 7,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "subpicture_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: None,
+    },
+    #[cfg(feature = "xvmc")]
+    RequestInfo {
+        module: "xvmc",
+        extension: Some("XVideo-MotionCompensation"),
+        name: "ListSubpictureTypes",
+        opcode: 8,
+        fields: &[FieldInfo { name: "major_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "minor_opcode", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "port_id", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "surface_id", kind: FieldKind::Value(FieldType::Card32) }],
+        reply: Some(&[FieldInfo { name: "response_type", kind: FieldKind::Value(FieldType::Card8) }, FieldInfo { name: "", kind: FieldKind::Pad(1) }, FieldInfo { name: "sequence", kind: FieldKind::Value(FieldType::Card16) }, FieldInfo { name: "length", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "num", kind: FieldKind::Value(FieldType::Card32) }, FieldInfo { name: "", kind: FieldKind::Pad(20) }, FieldInfo { name: "types", kind: FieldKind::Unsupported }]),
+    },
+];
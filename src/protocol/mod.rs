@@ -9,7 +9,7 @@
 use std::borrow::Cow;
 use std::convert::TryInto;
 use crate::errors::ParseError;
-use crate::utils::RawFdContainer;
+use crate::utils::OwnedFd;
 use crate::x11_utils::{TryParse, X11Error};
 use crate::x11_utils::{ExtInfoProvider, ReplyParsingFunction, Request as RequestTrait, RequestHeader};
 
@@ -71,6 +71,16 @@ pub mod xtest;
 pub mod xv;
 #[cfg(feature = "xvmc")]
 pub mod xvmc;
+#[cfg(feature = "protocol-docs")]
+pub mod documentation;
+#[cfg(feature = "dynamic")]
+pub mod introspection;
+
+/// The version of xcb-proto that this code was generated from.
+///
+/// Each extension module has an `X11_XML_VERSION` constant with the version of the extension
+/// that it implements. Servers might only support older versions.
+pub const XCB_PROTO_VERSION: &str = "1.14";
 
 /// Enumeration of all possible X11 requests.
 #[derive(Debug)]
@@ -78,1184 +88,1828 @@ pub mod xvmc;
 #[non_exhaustive]
 pub enum Request<'input> {
     Unknown(RequestHeader, Cow<'input, [u8]>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
     CreateWindow(xproto::CreateWindowRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
     ChangeWindowAttributes(xproto::ChangeWindowAttributesRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
     GetWindowAttributes(xproto::GetWindowAttributesRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
     DestroyWindow(xproto::DestroyWindowRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
     DestroySubwindows(xproto::DestroySubwindowsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
     ChangeSaveSet(xproto::ChangeSaveSetRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
     ReparentWindow(xproto::ReparentWindowRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
     MapWindow(xproto::MapWindowRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
     MapSubwindows(xproto::MapSubwindowsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
     UnmapWindow(xproto::UnmapWindowRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
     UnmapSubwindows(xproto::UnmapSubwindowsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
     ConfigureWindow(xproto::ConfigureWindowRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
     CirculateWindow(xproto::CirculateWindowRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
     GetGeometry(xproto::GetGeometryRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
     QueryTree(xproto::QueryTreeRequest),
     InternAtom(xproto::InternAtomRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
     GetAtomName(xproto::GetAtomNameRequest),
     ChangeProperty(xproto::ChangePropertyRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
     DeleteProperty(xproto::DeletePropertyRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
     GetProperty(xproto::GetPropertyRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
     ListProperties(xproto::ListPropertiesRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
     SetSelectionOwner(xproto::SetSelectionOwnerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
     GetSelectionOwner(xproto::GetSelectionOwnerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
     ConvertSelection(xproto::ConvertSelectionRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
     SendEvent(xproto::SendEventRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
     GrabPointer(xproto::GrabPointerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
     UngrabPointer(xproto::UngrabPointerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
     GrabButton(xproto::GrabButtonRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
     UngrabButton(xproto::UngrabButtonRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
     ChangeActivePointerGrab(xproto::ChangeActivePointerGrabRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
     GrabKeyboard(xproto::GrabKeyboardRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
     UngrabKeyboard(xproto::UngrabKeyboardRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
     GrabKey(xproto::GrabKeyRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
     UngrabKey(xproto::UngrabKeyRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
     AllowEvents(xproto::AllowEventsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
     GrabServer(xproto::GrabServerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
     UngrabServer(xproto::UngrabServerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
     QueryPointer(xproto::QueryPointerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
     GetMotionEvents(xproto::GetMotionEventsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
     TranslateCoordinates(xproto::TranslateCoordinatesRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
     WarpPointer(xproto::WarpPointerRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
     SetInputFocus(xproto::SetInputFocusRequest),
     GetInputFocus(xproto::GetInputFocusRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
     QueryKeymap(xproto::QueryKeymapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
     OpenFont(xproto::OpenFontRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
     CloseFont(xproto::CloseFontRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
     QueryFont(xproto::QueryFontRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
     QueryTextExtents(xproto::QueryTextExtentsRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
     ListFonts(xproto::ListFontsRequest<'input>),
     ListFontsWithInfo(xproto::ListFontsWithInfoRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
     SetFontPath(xproto::SetFontPathRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
     GetFontPath(xproto::GetFontPathRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
     CreatePixmap(xproto::CreatePixmapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
     FreePixmap(xproto::FreePixmapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
     CreateGC(xproto::CreateGCRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
     ChangeGC(xproto::ChangeGCRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
     CopyGC(xproto::CopyGCRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
     SetDashes(xproto::SetDashesRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
     SetClipRectangles(xproto::SetClipRectanglesRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
     FreeGC(xproto::FreeGCRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
     ClearArea(xproto::ClearAreaRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
     CopyArea(xproto::CopyAreaRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
     CopyPlane(xproto::CopyPlaneRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
     PolyPoint(xproto::PolyPointRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
     PolyLine(xproto::PolyLineRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
     PolySegment(xproto::PolySegmentRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
     PolyRectangle(xproto::PolyRectangleRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
     PolyArc(xproto::PolyArcRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
     FillPoly(xproto::FillPolyRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
     PolyFillRectangle(xproto::PolyFillRectangleRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
     PolyFillArc(xproto::PolyFillArcRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
     PutImage(xproto::PutImageRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
     GetImage(xproto::GetImageRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
     PolyText8(xproto::PolyText8Request<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
     PolyText16(xproto::PolyText16Request<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
     ImageText8(xproto::ImageText8Request<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
     ImageText16(xproto::ImageText16Request<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
     CreateColormap(xproto::CreateColormapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
     FreeColormap(xproto::FreeColormapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
     CopyColormapAndFree(xproto::CopyColormapAndFreeRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
     InstallColormap(xproto::InstallColormapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
     UninstallColormap(xproto::UninstallColormapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
     ListInstalledColormaps(xproto::ListInstalledColormapsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
     AllocColor(xproto::AllocColorRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
     AllocNamedColor(xproto::AllocNamedColorRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
     AllocColorCells(xproto::AllocColorCellsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
     AllocColorPlanes(xproto::AllocColorPlanesRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
     FreeColors(xproto::FreeColorsRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
     StoreColors(xproto::StoreColorsRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
     StoreNamedColor(xproto::StoreNamedColorRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
     QueryColors(xproto::QueryColorsRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
     LookupColor(xproto::LookupColorRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
     CreateCursor(xproto::CreateCursorRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
     CreateGlyphCursor(xproto::CreateGlyphCursorRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
     FreeCursor(xproto::FreeCursorRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
     RecolorCursor(xproto::RecolorCursorRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
     QueryBestSize(xproto::QueryBestSizeRequest),
     QueryExtension(xproto::QueryExtensionRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
     ListExtensions(xproto::ListExtensionsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
     ChangeKeyboardMapping(xproto::ChangeKeyboardMappingRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
     GetKeyboardMapping(xproto::GetKeyboardMappingRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
     ChangeKeyboardControl(xproto::ChangeKeyboardControlRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
     GetKeyboardControl(xproto::GetKeyboardControlRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
     Bell(xproto::BellRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
     ChangePointerControl(xproto::ChangePointerControlRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
     GetPointerControl(xproto::GetPointerControlRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
     SetScreenSaver(xproto::SetScreenSaverRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
     GetScreenSaver(xproto::GetScreenSaverRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
     ChangeHosts(xproto::ChangeHostsRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
     ListHosts(xproto::ListHostsRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
     SetAccessControl(xproto::SetAccessControlRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
     SetCloseDownMode(xproto::SetCloseDownModeRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
     KillClient(xproto::KillClientRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
     RotateProperties(xproto::RotatePropertiesRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
     ForceScreenSaver(xproto::ForceScreenSaverRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
     SetPointerMapping(xproto::SetPointerMappingRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
     GetPointerMapping(xproto::GetPointerMappingRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
     SetModifierMapping(xproto::SetModifierMappingRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
     GetModifierMapping(xproto::GetModifierMappingRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
     NoOperation(xproto::NoOperationRequest),
     BigreqEnable(bigreq::EnableRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
     CompositeQueryVersion(composite::QueryVersionRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
     CompositeRedirectWindow(composite::RedirectWindowRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
     CompositeRedirectSubwindows(composite::RedirectSubwindowsRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
     CompositeUnredirectWindow(composite::UnredirectWindowRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
     CompositeUnredirectSubwindows(composite::UnredirectSubwindowsRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
     CompositeCreateRegionFromBorderClip(composite::CreateRegionFromBorderClipRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
     CompositeNameWindowPixmap(composite::NameWindowPixmapRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
     CompositeGetOverlayWindow(composite::GetOverlayWindowRequest),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
     CompositeReleaseOverlayWindow(composite::ReleaseOverlayWindowRequest),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
     DamageQueryVersion(damage::QueryVersionRequest),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
     DamageCreate(damage::CreateRequest),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
     DamageDestroy(damage::DestroyRequest),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
     DamageSubtract(damage::SubtractRequest),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
     DamageAdd(damage::AddRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
     DpmsGetVersion(dpms::GetVersionRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
     DpmsCapable(dpms::CapableRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
     DpmsGetTimeouts(dpms::GetTimeoutsRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
     DpmsSetTimeouts(dpms::SetTimeoutsRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
     DpmsEnable(dpms::EnableRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
     DpmsDisable(dpms::DisableRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
     DpmsForceLevel(dpms::ForceLevelRequest),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
     DpmsInfo(dpms::InfoRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
     Dri2QueryVersion(dri2::QueryVersionRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
     Dri2Connect(dri2::ConnectRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
     Dri2Authenticate(dri2::AuthenticateRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
     Dri2CreateDrawable(dri2::CreateDrawableRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
     Dri2DestroyDrawable(dri2::DestroyDrawableRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
     Dri2GetBuffers(dri2::GetBuffersRequest<'input>),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
     Dri2CopyRegion(dri2::CopyRegionRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
     Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatRequest<'input>),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
     Dri2SwapBuffers(dri2::SwapBuffersRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
     Dri2GetMSC(dri2::GetMSCRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
     Dri2WaitMSC(dri2::WaitMSCRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
     Dri2WaitSBC(dri2::WaitSBCRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
     Dri2SwapInterval(dri2::SwapIntervalRequest),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
     Dri2GetParam(dri2::GetParamRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
     Dri3QueryVersion(dri3::QueryVersionRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
     Dri3Open(dri3::OpenRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
     Dri3PixmapFromBuffer(dri3::PixmapFromBufferRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
     Dri3BufferFromPixmap(dri3::BufferFromPixmapRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
     Dri3FenceFromFD(dri3::FenceFromFDRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
     Dri3FDFromFence(dri3::FDFromFenceRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
     Dri3GetSupportedModifiers(dri3::GetSupportedModifiersRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
     Dri3PixmapFromBuffers(dri3::PixmapFromBuffersRequest),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
     Dri3BuffersFromPixmap(dri3::BuffersFromPixmapRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
     GeQueryVersion(ge::QueryVersionRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
     GlxRender(glx::RenderRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
     GlxRenderLarge(glx::RenderLargeRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
     GlxCreateContext(glx::CreateContextRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
     GlxDestroyContext(glx::DestroyContextRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
     GlxMakeCurrent(glx::MakeCurrentRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
     GlxIsDirect(glx::IsDirectRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
     GlxQueryVersion(glx::QueryVersionRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
     GlxWaitGL(glx::WaitGLRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
     GlxWaitX(glx::WaitXRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
     GlxCopyContext(glx::CopyContextRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
     GlxSwapBuffers(glx::SwapBuffersRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
     GlxUseXFont(glx::UseXFontRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
     GlxCreateGLXPixmap(glx::CreateGLXPixmapRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
     GlxGetVisualConfigs(glx::GetVisualConfigsRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
     GlxDestroyGLXPixmap(glx::DestroyGLXPixmapRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
     GlxVendorPrivate(glx::VendorPrivateRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
     GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
     GlxQueryExtensionsString(glx::QueryExtensionsStringRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
     GlxQueryServerString(glx::QueryServerStringRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
     GlxClientInfo(glx::ClientInfoRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
     GlxGetFBConfigs(glx::GetFBConfigsRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
     GlxCreatePixmap(glx::CreatePixmapRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
     GlxDestroyPixmap(glx::DestroyPixmapRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
     GlxCreateNewContext(glx::CreateNewContextRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
     GlxQueryContext(glx::QueryContextRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
     GlxMakeContextCurrent(glx::MakeContextCurrentRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
     GlxCreatePbuffer(glx::CreatePbufferRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
     GlxDestroyPbuffer(glx::DestroyPbufferRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
     GlxGetDrawableAttributes(glx::GetDrawableAttributesRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
     GlxChangeDrawableAttributes(glx::ChangeDrawableAttributesRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
     GlxCreateWindow(glx::CreateWindowRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
     GlxDeleteWindow(glx::DeleteWindowRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
     GlxSetClientInfoARB(glx::SetClientInfoARBRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
     GlxCreateContextAttribsARB(glx::CreateContextAttribsARBRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
     GlxSetClientInfo2ARB(glx::SetClientInfo2ARBRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
     GlxNewList(glx::NewListRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
     GlxEndList(glx::EndListRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
     GlxDeleteLists(glx::DeleteListsRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
     GlxGenLists(glx::GenListsRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
     GlxFeedbackBuffer(glx::FeedbackBufferRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
     GlxSelectBuffer(glx::SelectBufferRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
     GlxRenderMode(glx::RenderModeRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
     GlxFinish(glx::FinishRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
     GlxPixelStoref(glx::PixelStorefRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
     GlxPixelStorei(glx::PixelStoreiRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
     GlxReadPixels(glx::ReadPixelsRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
     GlxGetBooleanv(glx::GetBooleanvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
     GlxGetClipPlane(glx::GetClipPlaneRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
     GlxGetDoublev(glx::GetDoublevRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
     GlxGetError(glx::GetErrorRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
     GlxGetFloatv(glx::GetFloatvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
     GlxGetIntegerv(glx::GetIntegervRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
     GlxGetLightfv(glx::GetLightfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
     GlxGetLightiv(glx::GetLightivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
     GlxGetMapdv(glx::GetMapdvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
     GlxGetMapfv(glx::GetMapfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
     GlxGetMapiv(glx::GetMapivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
     GlxGetMaterialfv(glx::GetMaterialfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
     GlxGetMaterialiv(glx::GetMaterialivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
     GlxGetPixelMapfv(glx::GetPixelMapfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
     GlxGetPixelMapuiv(glx::GetPixelMapuivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
     GlxGetPixelMapusv(glx::GetPixelMapusvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
     GlxGetPolygonStipple(glx::GetPolygonStippleRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
     GlxGetString(glx::GetStringRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
     GlxGetTexEnvfv(glx::GetTexEnvfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
     GlxGetTexEnviv(glx::GetTexEnvivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
     GlxGetTexGendv(glx::GetTexGendvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
     GlxGetTexGenfv(glx::GetTexGenfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
     GlxGetTexGeniv(glx::GetTexGenivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
     GlxGetTexImage(glx::GetTexImageRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
     GlxGetTexParameterfv(glx::GetTexParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
     GlxGetTexParameteriv(glx::GetTexParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
     GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
     GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
     GlxIsEnabled(glx::IsEnabledRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
     GlxIsList(glx::IsListRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
     GlxFlush(glx::FlushRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
     GlxAreTexturesResident(glx::AreTexturesResidentRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
     GlxDeleteTextures(glx::DeleteTexturesRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
     GlxGenTextures(glx::GenTexturesRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
     GlxIsTexture(glx::IsTextureRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
     GlxGetColorTable(glx::GetColorTableRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
     GlxGetColorTableParameterfv(glx::GetColorTableParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
     GlxGetColorTableParameteriv(glx::GetColorTableParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
     GlxGetConvolutionFilter(glx::GetConvolutionFilterRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
     GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
     GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
     GlxGetSeparableFilter(glx::GetSeparableFilterRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
     GlxGetHistogram(glx::GetHistogramRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
     GlxGetHistogramParameterfv(glx::GetHistogramParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
     GlxGetHistogramParameteriv(glx::GetHistogramParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
     GlxGetMinmax(glx::GetMinmaxRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
     GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
     GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
     GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
     GlxDeleteQueriesARB(glx::DeleteQueriesARBRequest<'input>),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
     GlxGenQueriesARB(glx::GenQueriesARBRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
     GlxIsQueryARB(glx::IsQueryARBRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
     GlxGetQueryivARB(glx::GetQueryivARBRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
     GlxGetQueryObjectivARB(glx::GetQueryObjectivARBRequest),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
     GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBRequest),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
     PresentQueryVersion(present::QueryVersionRequest),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::Pixmap"))]
     PresentPixmap(present::PixmapRequest<'input>),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::NotifyMSC"))]
     PresentNotifyMSC(present::NotifyMSCRequest),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::SelectInput"))]
     PresentSelectInput(present::SelectInputRequest),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
     PresentQueryCapabilities(present::QueryCapabilitiesRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
     RandrQueryVersion(randr::QueryVersionRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
     RandrSetScreenConfig(randr::SetScreenConfigRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
     RandrSelectInput(randr::SelectInputRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
     RandrGetScreenInfo(randr::GetScreenInfoRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
     RandrGetScreenSizeRange(randr::GetScreenSizeRangeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
     RandrSetScreenSize(randr::SetScreenSizeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
     RandrGetScreenResources(randr::GetScreenResourcesRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
     RandrGetOutputInfo(randr::GetOutputInfoRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
     RandrListOutputProperties(randr::ListOutputPropertiesRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
     RandrQueryOutputProperty(randr::QueryOutputPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
     RandrConfigureOutputProperty(randr::ConfigureOutputPropertyRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
     RandrChangeOutputProperty(randr::ChangeOutputPropertyRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
     RandrDeleteOutputProperty(randr::DeleteOutputPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
     RandrGetOutputProperty(randr::GetOutputPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
     RandrCreateMode(randr::CreateModeRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
     RandrDestroyMode(randr::DestroyModeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
     RandrAddOutputMode(randr::AddOutputModeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
     RandrDeleteOutputMode(randr::DeleteOutputModeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
     RandrGetCrtcInfo(randr::GetCrtcInfoRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
     RandrSetCrtcConfig(randr::SetCrtcConfigRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
     RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
     RandrGetCrtcGamma(randr::GetCrtcGammaRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
     RandrSetCrtcGamma(randr::SetCrtcGammaRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
     RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
     RandrSetCrtcTransform(randr::SetCrtcTransformRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
     RandrGetCrtcTransform(randr::GetCrtcTransformRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
     RandrGetPanning(randr::GetPanningRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
     RandrSetPanning(randr::SetPanningRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
     RandrSetOutputPrimary(randr::SetOutputPrimaryRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
     RandrGetOutputPrimary(randr::GetOutputPrimaryRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
     RandrGetProviders(randr::GetProvidersRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
     RandrGetProviderInfo(randr::GetProviderInfoRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
     RandrSetProviderOffloadSink(randr::SetProviderOffloadSinkRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
     RandrSetProviderOutputSource(randr::SetProviderOutputSourceRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
     RandrListProviderProperties(randr::ListProviderPropertiesRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
     RandrQueryProviderProperty(randr::QueryProviderPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
     RandrConfigureProviderProperty(randr::ConfigureProviderPropertyRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
     RandrChangeProviderProperty(randr::ChangeProviderPropertyRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
     RandrDeleteProviderProperty(randr::DeleteProviderPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
     RandrGetProviderProperty(randr::GetProviderPropertyRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
     RandrGetMonitors(randr::GetMonitorsRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
     RandrSetMonitor(randr::SetMonitorRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
     RandrDeleteMonitor(randr::DeleteMonitorRequest),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
     RandrCreateLease(randr::CreateLeaseRequest<'input>),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
     RandrFreeLease(randr::FreeLeaseRequest),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
     RecordQueryVersion(record::QueryVersionRequest),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::CreateContext"))]
     RecordCreateContext(record::CreateContextRequest<'input>),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::RegisterClients"))]
     RecordRegisterClients(record::RegisterClientsRequest<'input>),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::UnregisterClients"))]
     RecordUnregisterClients(record::UnregisterClientsRequest<'input>),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
     RecordGetContext(record::GetContextRequest),
     #[cfg(feature = "record")]
     RecordEnableContext(record::EnableContextRequest),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::DisableContext"))]
     RecordDisableContext(record::DisableContextRequest),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::FreeContext"))]
     RecordFreeContext(record::FreeContextRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
     RenderQueryVersion(render::QueryVersionRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
     RenderQueryPictFormats(render::QueryPictFormatsRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
     RenderQueryPictIndexValues(render::QueryPictIndexValuesRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
     RenderCreatePicture(render::CreatePictureRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
     RenderChangePicture(render::ChangePictureRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
     RenderSetPictureClipRectangles(render::SetPictureClipRectanglesRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
     RenderFreePicture(render::FreePictureRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
     RenderComposite(render::CompositeRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
     RenderTrapezoids(render::TrapezoidsRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
     RenderTriangles(render::TrianglesRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
     RenderTriStrip(render::TriStripRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
     RenderTriFan(render::TriFanRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
     RenderCreateGlyphSet(render::CreateGlyphSetRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
     RenderReferenceGlyphSet(render::ReferenceGlyphSetRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
     RenderFreeGlyphSet(render::FreeGlyphSetRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
     RenderAddGlyphs(render::AddGlyphsRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
     RenderFreeGlyphs(render::FreeGlyphsRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
     RenderCompositeGlyphs8(render::CompositeGlyphs8Request<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
     RenderCompositeGlyphs16(render::CompositeGlyphs16Request<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
     RenderCompositeGlyphs32(render::CompositeGlyphs32Request<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
     RenderFillRectangles(render::FillRectanglesRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
     RenderCreateCursor(render::CreateCursorRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
     RenderSetPictureTransform(render::SetPictureTransformRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
     RenderQueryFilters(render::QueryFiltersRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
     RenderSetPictureFilter(render::SetPictureFilterRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
     RenderCreateAnimCursor(render::CreateAnimCursorRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
     RenderAddTraps(render::AddTrapsRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
     RenderCreateSolidFill(render::CreateSolidFillRequest),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
     RenderCreateLinearGradient(render::CreateLinearGradientRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
     RenderCreateRadialGradient(render::CreateRadialGradientRequest<'input>),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
     RenderCreateConicalGradient(render::CreateConicalGradientRequest<'input>),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
     ResQueryVersion(res::QueryVersionRequest),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
     ResQueryClients(res::QueryClientsRequest),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
     ResQueryClientResources(res::QueryClientResourcesRequest),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
     ResQueryClientPixmapBytes(res::QueryClientPixmapBytesRequest),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
     ResQueryClientIds(res::QueryClientIdsRequest<'input>),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
     ResQueryResourceBytes(res::QueryResourceBytesRequest<'input>),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
     ScreensaverQueryVersion(screensaver::QueryVersionRequest),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
     ScreensaverQueryInfo(screensaver::QueryInfoRequest),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SelectInput"))]
     ScreensaverSelectInput(screensaver::SelectInputRequest),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SetAttributes"))]
     ScreensaverSetAttributes(screensaver::SetAttributesRequest<'input>),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::UnsetAttributes"))]
     ScreensaverUnsetAttributes(screensaver::UnsetAttributesRequest),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::Suspend"))]
     ScreensaverSuspend(screensaver::SuspendRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
     ShapeQueryVersion(shape::QueryVersionRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
     ShapeRectangles(shape::RectanglesRequest<'input>),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
     ShapeMask(shape::MaskRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
     ShapeCombine(shape::CombineRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
     ShapeOffset(shape::OffsetRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
     ShapeQueryExtents(shape::QueryExtentsRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
     ShapeSelectInput(shape::SelectInputRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
     ShapeInputSelected(shape::InputSelectedRequest),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
     ShapeGetRectangles(shape::GetRectanglesRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
     ShmQueryVersion(shm::QueryVersionRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
     ShmAttach(shm::AttachRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
     ShmDetach(shm::DetachRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
     ShmPutImage(shm::PutImageRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
     ShmGetImage(shm::GetImageRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
     ShmCreatePixmap(shm::CreatePixmapRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
     ShmAttachFd(shm::AttachFdRequest),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
     ShmCreateSegment(shm::CreateSegmentRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
     SyncInitialize(sync::InitializeRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
     SyncListSystemCounters(sync::ListSystemCountersRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
     SyncCreateCounter(sync::CreateCounterRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
     SyncDestroyCounter(sync::DestroyCounterRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
     SyncQueryCounter(sync::QueryCounterRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
     SyncAwait(sync::AwaitRequest<'input>),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
     SyncChangeCounter(sync::ChangeCounterRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
     SyncSetCounter(sync::SetCounterRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
     SyncCreateAlarm(sync::CreateAlarmRequest<'input>),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
     SyncChangeAlarm(sync::ChangeAlarmRequest<'input>),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
     SyncDestroyAlarm(sync::DestroyAlarmRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
     SyncQueryAlarm(sync::QueryAlarmRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
     SyncSetPriority(sync::SetPriorityRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
     SyncGetPriority(sync::GetPriorityRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
     SyncCreateFence(sync::CreateFenceRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
     SyncTriggerFence(sync::TriggerFenceRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
     SyncResetFence(sync::ResetFenceRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
     SyncDestroyFence(sync::DestroyFenceRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
     SyncQueryFence(sync::QueryFenceRequest),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
     SyncAwaitFence(sync::AwaitFenceRequest<'input>),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
     XcMiscGetVersion(xc_misc::GetVersionRequest),
     XcMiscGetXIDRange(xc_misc::GetXIDRangeRequest),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
     XcMiscGetXIDList(xc_misc::GetXIDListRequest),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
     XevieQueryVersion(xevie::QueryVersionRequest),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
     XevieStart(xevie::StartRequest),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
     XevieEnd(xevie::EndRequest),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
     XevieSend(xevie::SendRequest),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
     XevieSelectInput(xevie::SelectInputRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
     Xf86driQueryVersion(xf86dri::QueryVersionRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
     Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
     Xf86driOpenConnection(xf86dri::OpenConnectionRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CloseConnection"))]
     Xf86driCloseConnection(xf86dri::CloseConnectionRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
     Xf86driGetClientDriverName(xf86dri::GetClientDriverNameRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
     Xf86driCreateContext(xf86dri::CreateContextRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyContext"))]
     Xf86driDestroyContext(xf86dri::DestroyContextRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
     Xf86driCreateDrawable(xf86dri::CreateDrawableRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyDrawable"))]
     Xf86driDestroyDrawable(xf86dri::DestroyDrawableRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
     Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
     Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoRequest),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
     Xf86driAuthConnection(xf86dri::AuthConnectionRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
     Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
     Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
     Xf86vidmodeModModeLine(xf86vidmode::ModModeLineRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
     Xf86vidmodeSwitchMode(xf86vidmode::SwitchModeRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
     Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
     Xf86vidmodeLockModeSwitch(xf86vidmode::LockModeSwitchRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
     Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
     Xf86vidmodeAddModeLine(xf86vidmode::AddModeLineRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
     Xf86vidmodeDeleteModeLine(xf86vidmode::DeleteModeLineRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
     Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
     Xf86vidmodeSwitchToMode(xf86vidmode::SwitchToModeRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
     Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
     Xf86vidmodeSetViewPort(xf86vidmode::SetViewPortRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
     Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
     Xf86vidmodeSetClientVersion(xf86vidmode::SetClientVersionRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
     Xf86vidmodeSetGamma(xf86vidmode::SetGammaRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
     Xf86vidmodeGetGamma(xf86vidmode::GetGammaRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
     Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
     Xf86vidmodeSetGammaRamp(xf86vidmode::SetGammaRampRequest<'input>),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
     Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeRequest),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
     Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
     XfixesQueryVersion(xfixes::QueryVersionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeSaveSet"))]
     XfixesChangeSaveSet(xfixes::ChangeSaveSetRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectSelectionInput"))]
     XfixesSelectSelectionInput(xfixes::SelectSelectionInputRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectCursorInput"))]
     XfixesSelectCursorInput(xfixes::SelectCursorInputRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
     XfixesGetCursorImage(xfixes::GetCursorImageRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegion"))]
     XfixesCreateRegion(xfixes::CreateRegionRequest<'input>),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromBitmap"))]
     XfixesCreateRegionFromBitmap(xfixes::CreateRegionFromBitmapRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromWindow"))]
     XfixesCreateRegionFromWindow(xfixes::CreateRegionFromWindowRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromGC"))]
     XfixesCreateRegionFromGC(xfixes::CreateRegionFromGCRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromPicture"))]
     XfixesCreateRegionFromPicture(xfixes::CreateRegionFromPictureRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DestroyRegion"))]
     XfixesDestroyRegion(xfixes::DestroyRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetRegion"))]
     XfixesSetRegion(xfixes::SetRegionRequest<'input>),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CopyRegion"))]
     XfixesCopyRegion(xfixes::CopyRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::UnionRegion"))]
     XfixesUnionRegion(xfixes::UnionRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::IntersectRegion"))]
     XfixesIntersectRegion(xfixes::IntersectRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SubtractRegion"))]
     XfixesSubtractRegion(xfixes::SubtractRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::InvertRegion"))]
     XfixesInvertRegion(xfixes::InvertRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::TranslateRegion"))]
     XfixesTranslateRegion(xfixes::TranslateRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::RegionExtents"))]
     XfixesRegionExtents(xfixes::RegionExtentsRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
     XfixesFetchRegion(xfixes::FetchRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetGCClipRegion"))]
     XfixesSetGCClipRegion(xfixes::SetGCClipRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetWindowShapeRegion"))]
     XfixesSetWindowShapeRegion(xfixes::SetWindowShapeRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetPictureClipRegion"))]
     XfixesSetPictureClipRegion(xfixes::SetPictureClipRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetCursorName"))]
     XfixesSetCursorName(xfixes::SetCursorNameRequest<'input>),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
     XfixesGetCursorName(xfixes::GetCursorNameRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
     XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursor"))]
     XfixesChangeCursor(xfixes::ChangeCursorRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursorByName"))]
     XfixesChangeCursorByName(xfixes::ChangeCursorByNameRequest<'input>),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ExpandRegion"))]
     XfixesExpandRegion(xfixes::ExpandRegionRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::HideCursor"))]
     XfixesHideCursor(xfixes::HideCursorRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ShowCursor"))]
     XfixesShowCursor(xfixes::ShowCursorRequest),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreatePointerBarrier"))]
     XfixesCreatePointerBarrier(xfixes::CreatePointerBarrierRequest<'input>),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DeletePointerBarrier"))]
     XfixesDeletePointerBarrier(xfixes::DeletePointerBarrierRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
     XineramaQueryVersion(xinerama::QueryVersionRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
     XineramaGetState(xinerama::GetStateRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
     XineramaGetScreenCount(xinerama::GetScreenCountRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
     XineramaGetScreenSize(xinerama::GetScreenSizeRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
     XineramaIsActive(xinerama::IsActiveRequest),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
     XineramaQueryScreens(xinerama::QueryScreensRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
     XinputGetExtensionVersion(xinput::GetExtensionVersionRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
     XinputListInputDevices(xinput::ListInputDevicesRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
     XinputOpenDevice(xinput::OpenDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
     XinputCloseDevice(xinput::CloseDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
     XinputSetDeviceMode(xinput::SetDeviceModeRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
     XinputSelectExtensionEvent(xinput::SelectExtensionEventRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
     XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
     XinputChangeDeviceDontPropagateList(xinput::ChangeDeviceDontPropagateListRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
     XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
     XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
     XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
     XinputChangePointerDevice(xinput::ChangePointerDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
     XinputGrabDevice(xinput::GrabDeviceRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
     XinputUngrabDevice(xinput::UngrabDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
     XinputGrabDeviceKey(xinput::GrabDeviceKeyRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
     XinputUngrabDeviceKey(xinput::UngrabDeviceKeyRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
     XinputGrabDeviceButton(xinput::GrabDeviceButtonRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
     XinputUngrabDeviceButton(xinput::UngrabDeviceButtonRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
     XinputAllowDeviceEvents(xinput::AllowDeviceEventsRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
     XinputGetDeviceFocus(xinput::GetDeviceFocusRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
     XinputSetDeviceFocus(xinput::SetDeviceFocusRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
     XinputGetFeedbackControl(xinput::GetFeedbackControlRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
     XinputChangeFeedbackControl(xinput::ChangeFeedbackControlRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
     XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
     XinputChangeDeviceKeyMapping(xinput::ChangeDeviceKeyMappingRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
     XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
     XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
     XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
     XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
     XinputQueryDeviceState(xinput::QueryDeviceStateRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
     XinputDeviceBell(xinput::DeviceBellRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
     XinputSetDeviceValuators(xinput::SetDeviceValuatorsRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
     XinputGetDeviceControl(xinput::GetDeviceControlRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
     XinputChangeDeviceControl(xinput::ChangeDeviceControlRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
     XinputListDeviceProperties(xinput::ListDevicePropertiesRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
     XinputChangeDeviceProperty(xinput::ChangeDevicePropertyRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
     XinputDeleteDeviceProperty(xinput::DeleteDevicePropertyRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
     XinputGetDeviceProperty(xinput::GetDevicePropertyRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
     XinputXIQueryPointer(xinput::XIQueryPointerRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
     XinputXIWarpPointer(xinput::XIWarpPointerRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
     XinputXIChangeCursor(xinput::XIChangeCursorRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
     XinputXIChangeHierarchy(xinput::XIChangeHierarchyRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
     XinputXISetClientPointer(xinput::XISetClientPointerRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
     XinputXIGetClientPointer(xinput::XIGetClientPointerRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
     XinputXISelectEvents(xinput::XISelectEventsRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
     XinputXIQueryVersion(xinput::XIQueryVersionRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
     XinputXIQueryDevice(xinput::XIQueryDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
     XinputXISetFocus(xinput::XISetFocusRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
     XinputXIGetFocus(xinput::XIGetFocusRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
     XinputXIGrabDevice(xinput::XIGrabDeviceRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
     XinputXIUngrabDevice(xinput::XIUngrabDeviceRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
     XinputXIAllowEvents(xinput::XIAllowEventsRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
     XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
     XinputXIPassiveUngrabDevice(xinput::XIPassiveUngrabDeviceRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
     XinputXIListProperties(xinput::XIListPropertiesRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
     XinputXIChangeProperty(xinput::XIChangePropertyRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
     XinputXIDeleteProperty(xinput::XIDeletePropertyRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
     XinputXIGetProperty(xinput::XIGetPropertyRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
     XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsRequest),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
     XinputXIBarrierReleasePointer(xinput::XIBarrierReleasePointerRequest<'input>),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
     XinputSendExtensionEvent(xinput::SendExtensionEventRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
     XkbUseExtension(xkb::UseExtensionRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
     XkbSelectEvents(xkb::SelectEventsRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
     XkbBell(xkb::BellRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
     XkbGetState(xkb::GetStateRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
     XkbLatchLockState(xkb::LatchLockStateRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
     XkbGetControls(xkb::GetControlsRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
     XkbSetControls(xkb::SetControlsRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
     XkbGetMap(xkb::GetMapRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
     XkbSetMap(xkb::SetMapRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
     XkbGetCompatMap(xkb::GetCompatMapRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
     XkbSetCompatMap(xkb::SetCompatMapRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
     XkbGetIndicatorState(xkb::GetIndicatorStateRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
     XkbGetIndicatorMap(xkb::GetIndicatorMapRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
     XkbSetIndicatorMap(xkb::SetIndicatorMapRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
     XkbGetNamedIndicator(xkb::GetNamedIndicatorRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
     XkbSetNamedIndicator(xkb::SetNamedIndicatorRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
     XkbGetNames(xkb::GetNamesRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
     XkbSetNames(xkb::SetNamesRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
     XkbPerClientFlags(xkb::PerClientFlagsRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
     XkbListComponents(xkb::ListComponentsRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
     XkbGetKbdByName(xkb::GetKbdByNameRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
     XkbGetDeviceInfo(xkb::GetDeviceInfoRequest),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
     XkbSetDeviceInfo(xkb::SetDeviceInfoRequest<'input>),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
     XkbSetDebuggingFlags(xkb::SetDebuggingFlagsRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
     XprintPrintQueryVersion(xprint::PrintQueryVersionRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
     XprintPrintGetPrinterList(xprint::PrintGetPrinterListRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintRehashPrinterList"))]
     XprintPrintRehashPrinterList(xprint::PrintRehashPrinterListRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::CreateContext"))]
     XprintCreateContext(xprint::CreateContextRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetContext"))]
     XprintPrintSetContext(xprint::PrintSetContextRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
     XprintPrintGetContext(xprint::PrintGetContextRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintDestroyContext"))]
     XprintPrintDestroyContext(xprint::PrintDestroyContextRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
     XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartJob"))]
     XprintPrintStartJob(xprint::PrintStartJobRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndJob"))]
     XprintPrintEndJob(xprint::PrintEndJobRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartDoc"))]
     XprintPrintStartDoc(xprint::PrintStartDocRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndDoc"))]
     XprintPrintEndDoc(xprint::PrintEndDocRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintPutDocumentData"))]
     XprintPrintPutDocumentData(xprint::PrintPutDocumentDataRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
     XprintPrintGetDocumentData(xprint::PrintGetDocumentDataRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartPage"))]
     XprintPrintStartPage(xprint::PrintStartPageRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndPage"))]
     XprintPrintEndPage(xprint::PrintEndPageRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSelectInput"))]
     XprintPrintSelectInput(xprint::PrintSelectInputRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
     XprintPrintInputSelected(xprint::PrintInputSelectedRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
     XprintPrintGetAttributes(xprint::PrintGetAttributesRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
     XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetAttributes"))]
     XprintPrintSetAttributes(xprint::PrintSetAttributesRequest<'input>),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
     XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
     XprintPrintQueryScreens(xprint::PrintQueryScreensRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
     XprintPrintSetImageResolution(xprint::PrintSetImageResolutionRequest),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
     XprintPrintGetImageResolution(xprint::PrintGetImageResolutionRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
     XselinuxQueryVersion(xselinux::QueryVersionRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceCreateContext"))]
     XselinuxSetDeviceCreateContext(xselinux::SetDeviceCreateContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
     XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceContext"))]
     XselinuxSetDeviceContext(xselinux::SetDeviceContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
     XselinuxGetDeviceContext(xselinux::GetDeviceContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetWindowCreateContext"))]
     XselinuxSetWindowCreateContext(xselinux::SetWindowCreateContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
     XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
     XselinuxGetWindowContext(xselinux::GetWindowContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyCreateContext"))]
     XselinuxSetPropertyCreateContext(xselinux::SetPropertyCreateContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
     XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyUseContext"))]
     XselinuxSetPropertyUseContext(xselinux::SetPropertyUseContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
     XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
     XselinuxGetPropertyContext(xselinux::GetPropertyContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
     XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
     XselinuxListProperties(xselinux::ListPropertiesRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionCreateContext"))]
     XselinuxSetSelectionCreateContext(xselinux::SetSelectionCreateContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
     XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionUseContext"))]
     XselinuxSetSelectionUseContext(xselinux::SetSelectionUseContextRequest<'input>),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
     XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
     XselinuxGetSelectionContext(xselinux::GetSelectionContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
     XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
     XselinuxListSelections(xselinux::ListSelectionsRequest),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
     XselinuxGetClientContext(xselinux::GetClientContextRequest),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
     XtestGetVersion(xtest::GetVersionRequest),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
     XtestCompareCursor(xtest::CompareCursorRequest),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::FakeInput"))]
     XtestFakeInput(xtest::FakeInputRequest),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GrabControl"))]
     XtestGrabControl(xtest::GrabControlRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
     XvQueryExtension(xv::QueryExtensionRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
     XvQueryAdaptors(xv::QueryAdaptorsRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
     XvQueryEncodings(xv::QueryEncodingsRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
     XvGrabPort(xv::GrabPortRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::UngrabPort"))]
     XvUngrabPort(xv::UngrabPortRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutVideo"))]
     XvPutVideo(xv::PutVideoRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutStill"))]
     XvPutStill(xv::PutStillRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetVideo"))]
     XvGetVideo(xv::GetVideoRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetStill"))]
     XvGetStill(xv::GetStillRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::StopVideo"))]
     XvStopVideo(xv::StopVideoRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectVideoNotify"))]
     XvSelectVideoNotify(xv::SelectVideoNotifyRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectPortNotify"))]
     XvSelectPortNotify(xv::SelectPortNotifyRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
     XvQueryBestSize(xv::QueryBestSizeRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SetPortAttribute"))]
     XvSetPortAttribute(xv::SetPortAttributeRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
     XvGetPortAttribute(xv::GetPortAttributeRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
     XvQueryPortAttributes(xv::QueryPortAttributesRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
     XvListImageFormats(xv::ListImageFormatsRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
     XvQueryImageAttributes(xv::QueryImageAttributesRequest),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutImage"))]
     XvPutImage(xv::PutImageRequest<'input>),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ShmPutImage"))]
     XvShmPutImage(xv::ShmPutImageRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
     XvmcQueryVersion(xvmc::QueryVersionRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
     XvmcListSurfaceTypes(xvmc::ListSurfaceTypesRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
     XvmcCreateContext(xvmc::CreateContextRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroyContext"))]
     XvmcDestroyContext(xvmc::DestroyContextRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
     XvmcCreateSurface(xvmc::CreateSurfaceRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySurface"))]
     XvmcDestroySurface(xvmc::DestroySurfaceRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
     XvmcCreateSubpicture(xvmc::CreateSubpictureRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySubpicture"))]
     XvmcDestroySubpicture(xvmc::DestroySubpictureRequest),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
     XvmcListSubpictureTypes(xvmc::ListSubpictureTypesRequest),
 }
 
@@ -1267,131 +1921,246 @@ impl<'input> Request<'input> {
         body: &'input [u8],
         // Might not be used if none of the extensions that use FD passing is enabled
         #[allow(unused_variables)]
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
         ext_info_provider: &dyn ExtInfoProvider,
     ) -> Result<Self, ParseError> {
         let remaining = body;
         // Check if this is a core protocol request.
         match header.major_opcode {
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
             xproto::CREATE_WINDOW_REQUEST => return Ok(Request::CreateWindow(xproto::CreateWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
             xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST => return Ok(Request::ChangeWindowAttributes(xproto::ChangeWindowAttributesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
             xproto::GET_WINDOW_ATTRIBUTES_REQUEST => return Ok(Request::GetWindowAttributes(xproto::GetWindowAttributesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
             xproto::DESTROY_WINDOW_REQUEST => return Ok(Request::DestroyWindow(xproto::DestroyWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
             xproto::DESTROY_SUBWINDOWS_REQUEST => return Ok(Request::DestroySubwindows(xproto::DestroySubwindowsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
             xproto::CHANGE_SAVE_SET_REQUEST => return Ok(Request::ChangeSaveSet(xproto::ChangeSaveSetRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
             xproto::REPARENT_WINDOW_REQUEST => return Ok(Request::ReparentWindow(xproto::ReparentWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
             xproto::MAP_WINDOW_REQUEST => return Ok(Request::MapWindow(xproto::MapWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
             xproto::MAP_SUBWINDOWS_REQUEST => return Ok(Request::MapSubwindows(xproto::MapSubwindowsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
             xproto::UNMAP_WINDOW_REQUEST => return Ok(Request::UnmapWindow(xproto::UnmapWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
             xproto::UNMAP_SUBWINDOWS_REQUEST => return Ok(Request::UnmapSubwindows(xproto::UnmapSubwindowsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
             xproto::CONFIGURE_WINDOW_REQUEST => return Ok(Request::ConfigureWindow(xproto::ConfigureWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
             xproto::CIRCULATE_WINDOW_REQUEST => return Ok(Request::CirculateWindow(xproto::CirculateWindowRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
             xproto::GET_GEOMETRY_REQUEST => return Ok(Request::GetGeometry(xproto::GetGeometryRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
             xproto::QUERY_TREE_REQUEST => return Ok(Request::QueryTree(xproto::QueryTreeRequest::try_parse_request(header, remaining)?)),
             xproto::INTERN_ATOM_REQUEST => return Ok(Request::InternAtom(xproto::InternAtomRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
             xproto::GET_ATOM_NAME_REQUEST => return Ok(Request::GetAtomName(xproto::GetAtomNameRequest::try_parse_request(header, remaining)?)),
             xproto::CHANGE_PROPERTY_REQUEST => return Ok(Request::ChangeProperty(xproto::ChangePropertyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
             xproto::DELETE_PROPERTY_REQUEST => return Ok(Request::DeleteProperty(xproto::DeletePropertyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
             xproto::GET_PROPERTY_REQUEST => return Ok(Request::GetProperty(xproto::GetPropertyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
             xproto::LIST_PROPERTIES_REQUEST => return Ok(Request::ListProperties(xproto::ListPropertiesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
             xproto::SET_SELECTION_OWNER_REQUEST => return Ok(Request::SetSelectionOwner(xproto::SetSelectionOwnerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
             xproto::GET_SELECTION_OWNER_REQUEST => return Ok(Request::GetSelectionOwner(xproto::GetSelectionOwnerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
             xproto::CONVERT_SELECTION_REQUEST => return Ok(Request::ConvertSelection(xproto::ConvertSelectionRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
             xproto::SEND_EVENT_REQUEST => return Ok(Request::SendEvent(xproto::SendEventRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
             xproto::GRAB_POINTER_REQUEST => return Ok(Request::GrabPointer(xproto::GrabPointerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
             xproto::UNGRAB_POINTER_REQUEST => return Ok(Request::UngrabPointer(xproto::UngrabPointerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
             xproto::GRAB_BUTTON_REQUEST => return Ok(Request::GrabButton(xproto::GrabButtonRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
             xproto::UNGRAB_BUTTON_REQUEST => return Ok(Request::UngrabButton(xproto::UngrabButtonRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
             xproto::CHANGE_ACTIVE_POINTER_GRAB_REQUEST => return Ok(Request::ChangeActivePointerGrab(xproto::ChangeActivePointerGrabRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
             xproto::GRAB_KEYBOARD_REQUEST => return Ok(Request::GrabKeyboard(xproto::GrabKeyboardRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
             xproto::UNGRAB_KEYBOARD_REQUEST => return Ok(Request::UngrabKeyboard(xproto::UngrabKeyboardRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
             xproto::GRAB_KEY_REQUEST => return Ok(Request::GrabKey(xproto::GrabKeyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
             xproto::UNGRAB_KEY_REQUEST => return Ok(Request::UngrabKey(xproto::UngrabKeyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
             xproto::ALLOW_EVENTS_REQUEST => return Ok(Request::AllowEvents(xproto::AllowEventsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
             xproto::GRAB_SERVER_REQUEST => return Ok(Request::GrabServer(xproto::GrabServerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
             xproto::UNGRAB_SERVER_REQUEST => return Ok(Request::UngrabServer(xproto::UngrabServerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
             xproto::QUERY_POINTER_REQUEST => return Ok(Request::QueryPointer(xproto::QueryPointerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
             xproto::GET_MOTION_EVENTS_REQUEST => return Ok(Request::GetMotionEvents(xproto::GetMotionEventsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
             xproto::TRANSLATE_COORDINATES_REQUEST => return Ok(Request::TranslateCoordinates(xproto::TranslateCoordinatesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
             xproto::WARP_POINTER_REQUEST => return Ok(Request::WarpPointer(xproto::WarpPointerRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
             xproto::SET_INPUT_FOCUS_REQUEST => return Ok(Request::SetInputFocus(xproto::SetInputFocusRequest::try_parse_request(header, remaining)?)),
             xproto::GET_INPUT_FOCUS_REQUEST => return Ok(Request::GetInputFocus(xproto::GetInputFocusRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
             xproto::QUERY_KEYMAP_REQUEST => return Ok(Request::QueryKeymap(xproto::QueryKeymapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
             xproto::OPEN_FONT_REQUEST => return Ok(Request::OpenFont(xproto::OpenFontRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
             xproto::CLOSE_FONT_REQUEST => return Ok(Request::CloseFont(xproto::CloseFontRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
             xproto::QUERY_FONT_REQUEST => return Ok(Request::QueryFont(xproto::QueryFontRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
             xproto::QUERY_TEXT_EXTENTS_REQUEST => return Ok(Request::QueryTextExtents(xproto::QueryTextExtentsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
             xproto::LIST_FONTS_REQUEST => return Ok(Request::ListFonts(xproto::ListFontsRequest::try_parse_request(header, remaining)?)),
             xproto::LIST_FONTS_WITH_INFO_REQUEST => return Ok(Request::ListFontsWithInfo(xproto::ListFontsWithInfoRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
             xproto::SET_FONT_PATH_REQUEST => return Ok(Request::SetFontPath(xproto::SetFontPathRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
             xproto::GET_FONT_PATH_REQUEST => return Ok(Request::GetFontPath(xproto::GetFontPathRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
             xproto::CREATE_PIXMAP_REQUEST => return Ok(Request::CreatePixmap(xproto::CreatePixmapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
             xproto::FREE_PIXMAP_REQUEST => return Ok(Request::FreePixmap(xproto::FreePixmapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
             xproto::CREATE_GC_REQUEST => return Ok(Request::CreateGC(xproto::CreateGCRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
             xproto::CHANGE_GC_REQUEST => return Ok(Request::ChangeGC(xproto::ChangeGCRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
             xproto::COPY_GC_REQUEST => return Ok(Request::CopyGC(xproto::CopyGCRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
             xproto::SET_DASHES_REQUEST => return Ok(Request::SetDashes(xproto::SetDashesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
             xproto::SET_CLIP_RECTANGLES_REQUEST => return Ok(Request::SetClipRectangles(xproto::SetClipRectanglesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
             xproto::FREE_GC_REQUEST => return Ok(Request::FreeGC(xproto::FreeGCRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
             xproto::CLEAR_AREA_REQUEST => return Ok(Request::ClearArea(xproto::ClearAreaRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
             xproto::COPY_AREA_REQUEST => return Ok(Request::CopyArea(xproto::CopyAreaRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
             xproto::COPY_PLANE_REQUEST => return Ok(Request::CopyPlane(xproto::CopyPlaneRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
             xproto::POLY_POINT_REQUEST => return Ok(Request::PolyPoint(xproto::PolyPointRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
             xproto::POLY_LINE_REQUEST => return Ok(Request::PolyLine(xproto::PolyLineRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
             xproto::POLY_SEGMENT_REQUEST => return Ok(Request::PolySegment(xproto::PolySegmentRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
             xproto::POLY_RECTANGLE_REQUEST => return Ok(Request::PolyRectangle(xproto::PolyRectangleRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
             xproto::POLY_ARC_REQUEST => return Ok(Request::PolyArc(xproto::PolyArcRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
             xproto::FILL_POLY_REQUEST => return Ok(Request::FillPoly(xproto::FillPolyRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
             xproto::POLY_FILL_RECTANGLE_REQUEST => return Ok(Request::PolyFillRectangle(xproto::PolyFillRectangleRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
             xproto::POLY_FILL_ARC_REQUEST => return Ok(Request::PolyFillArc(xproto::PolyFillArcRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
             xproto::PUT_IMAGE_REQUEST => return Ok(Request::PutImage(xproto::PutImageRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
             xproto::GET_IMAGE_REQUEST => return Ok(Request::GetImage(xproto::GetImageRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
             xproto::POLY_TEXT8_REQUEST => return Ok(Request::PolyText8(xproto::PolyText8Request::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
             xproto::POLY_TEXT16_REQUEST => return Ok(Request::PolyText16(xproto::PolyText16Request::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
             xproto::IMAGE_TEXT8_REQUEST => return Ok(Request::ImageText8(xproto::ImageText8Request::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
             xproto::IMAGE_TEXT16_REQUEST => return Ok(Request::ImageText16(xproto::ImageText16Request::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
             xproto::CREATE_COLORMAP_REQUEST => return Ok(Request::CreateColormap(xproto::CreateColormapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
             xproto::FREE_COLORMAP_REQUEST => return Ok(Request::FreeColormap(xproto::FreeColormapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
             xproto::COPY_COLORMAP_AND_FREE_REQUEST => return Ok(Request::CopyColormapAndFree(xproto::CopyColormapAndFreeRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
             xproto::INSTALL_COLORMAP_REQUEST => return Ok(Request::InstallColormap(xproto::InstallColormapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
             xproto::UNINSTALL_COLORMAP_REQUEST => return Ok(Request::UninstallColormap(xproto::UninstallColormapRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
             xproto::LIST_INSTALLED_COLORMAPS_REQUEST => return Ok(Request::ListInstalledColormaps(xproto::ListInstalledColormapsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
             xproto::ALLOC_COLOR_REQUEST => return Ok(Request::AllocColor(xproto::AllocColorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
             xproto::ALLOC_NAMED_COLOR_REQUEST => return Ok(Request::AllocNamedColor(xproto::AllocNamedColorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
             xproto::ALLOC_COLOR_CELLS_REQUEST => return Ok(Request::AllocColorCells(xproto::AllocColorCellsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
             xproto::ALLOC_COLOR_PLANES_REQUEST => return Ok(Request::AllocColorPlanes(xproto::AllocColorPlanesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
             xproto::FREE_COLORS_REQUEST => return Ok(Request::FreeColors(xproto::FreeColorsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
             xproto::STORE_COLORS_REQUEST => return Ok(Request::StoreColors(xproto::StoreColorsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
             xproto::STORE_NAMED_COLOR_REQUEST => return Ok(Request::StoreNamedColor(xproto::StoreNamedColorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
             xproto::QUERY_COLORS_REQUEST => return Ok(Request::QueryColors(xproto::QueryColorsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
             xproto::LOOKUP_COLOR_REQUEST => return Ok(Request::LookupColor(xproto::LookupColorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
             xproto::CREATE_CURSOR_REQUEST => return Ok(Request::CreateCursor(xproto::CreateCursorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
             xproto::CREATE_GLYPH_CURSOR_REQUEST => return Ok(Request::CreateGlyphCursor(xproto::CreateGlyphCursorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
             xproto::FREE_CURSOR_REQUEST => return Ok(Request::FreeCursor(xproto::FreeCursorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
             xproto::RECOLOR_CURSOR_REQUEST => return Ok(Request::RecolorCursor(xproto::RecolorCursorRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
             xproto::QUERY_BEST_SIZE_REQUEST => return Ok(Request::QueryBestSize(xproto::QueryBestSizeRequest::try_parse_request(header, remaining)?)),
             xproto::QUERY_EXTENSION_REQUEST => return Ok(Request::QueryExtension(xproto::QueryExtensionRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
             xproto::LIST_EXTENSIONS_REQUEST => return Ok(Request::ListExtensions(xproto::ListExtensionsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
             xproto::CHANGE_KEYBOARD_MAPPING_REQUEST => return Ok(Request::ChangeKeyboardMapping(xproto::ChangeKeyboardMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
             xproto::GET_KEYBOARD_MAPPING_REQUEST => return Ok(Request::GetKeyboardMapping(xproto::GetKeyboardMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
             xproto::CHANGE_KEYBOARD_CONTROL_REQUEST => return Ok(Request::ChangeKeyboardControl(xproto::ChangeKeyboardControlRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
             xproto::GET_KEYBOARD_CONTROL_REQUEST => return Ok(Request::GetKeyboardControl(xproto::GetKeyboardControlRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
             xproto::BELL_REQUEST => return Ok(Request::Bell(xproto::BellRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
             xproto::CHANGE_POINTER_CONTROL_REQUEST => return Ok(Request::ChangePointerControl(xproto::ChangePointerControlRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
             xproto::GET_POINTER_CONTROL_REQUEST => return Ok(Request::GetPointerControl(xproto::GetPointerControlRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
             xproto::SET_SCREEN_SAVER_REQUEST => return Ok(Request::SetScreenSaver(xproto::SetScreenSaverRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
             xproto::GET_SCREEN_SAVER_REQUEST => return Ok(Request::GetScreenSaver(xproto::GetScreenSaverRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
             xproto::CHANGE_HOSTS_REQUEST => return Ok(Request::ChangeHosts(xproto::ChangeHostsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
             xproto::LIST_HOSTS_REQUEST => return Ok(Request::ListHosts(xproto::ListHostsRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
             xproto::SET_ACCESS_CONTROL_REQUEST => return Ok(Request::SetAccessControl(xproto::SetAccessControlRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
             xproto::SET_CLOSE_DOWN_MODE_REQUEST => return Ok(Request::SetCloseDownMode(xproto::SetCloseDownModeRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
             xproto::KILL_CLIENT_REQUEST => return Ok(Request::KillClient(xproto::KillClientRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
             xproto::ROTATE_PROPERTIES_REQUEST => return Ok(Request::RotateProperties(xproto::RotatePropertiesRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
             xproto::FORCE_SCREEN_SAVER_REQUEST => return Ok(Request::ForceScreenSaver(xproto::ForceScreenSaverRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
             xproto::SET_POINTER_MAPPING_REQUEST => return Ok(Request::SetPointerMapping(xproto::SetPointerMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
             xproto::GET_POINTER_MAPPING_REQUEST => return Ok(Request::GetPointerMapping(xproto::GetPointerMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
             xproto::SET_MODIFIER_MAPPING_REQUEST => return Ok(Request::SetModifierMapping(xproto::SetModifierMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
             xproto::GET_MODIFIER_MAPPING_REQUEST => return Ok(Request::GetModifierMapping(xproto::GetModifierMappingRequest::try_parse_request(header, remaining)?)),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
             xproto::NO_OPERATION_REQUEST => return Ok(Request::NoOperation(xproto::NoOperationRequest::try_parse_request(header, remaining)?)),
             _ => (),
         }
@@ -1407,14 +2176,23 @@ impl<'input> Request<'input> {
             #[cfg(feature = "composite")]
             Some((composite::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
                     composite::QUERY_VERSION_REQUEST => return Ok(Request::CompositeQueryVersion(composite::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
                     composite::REDIRECT_WINDOW_REQUEST => return Ok(Request::CompositeRedirectWindow(composite::RedirectWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
                     composite::REDIRECT_SUBWINDOWS_REQUEST => return Ok(Request::CompositeRedirectSubwindows(composite::RedirectSubwindowsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
                     composite::UNREDIRECT_WINDOW_REQUEST => return Ok(Request::CompositeUnredirectWindow(composite::UnredirectWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
                     composite::UNREDIRECT_SUBWINDOWS_REQUEST => return Ok(Request::CompositeUnredirectSubwindows(composite::UnredirectSubwindowsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
                     composite::CREATE_REGION_FROM_BORDER_CLIP_REQUEST => return Ok(Request::CompositeCreateRegionFromBorderClip(composite::CreateRegionFromBorderClipRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
                     composite::NAME_WINDOW_PIXMAP_REQUEST => return Ok(Request::CompositeNameWindowPixmap(composite::NameWindowPixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
                     composite::GET_OVERLAY_WINDOW_REQUEST => return Ok(Request::CompositeGetOverlayWindow(composite::GetOverlayWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
                     composite::RELEASE_OVERLAY_WINDOW_REQUEST => return Ok(Request::CompositeReleaseOverlayWindow(composite::ReleaseOverlayWindowRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1422,10 +2200,15 @@ impl<'input> Request<'input> {
             #[cfg(feature = "damage")]
             Some((damage::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
                     damage::QUERY_VERSION_REQUEST => return Ok(Request::DamageQueryVersion(damage::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
                     damage::CREATE_REQUEST => return Ok(Request::DamageCreate(damage::CreateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
                     damage::DESTROY_REQUEST => return Ok(Request::DamageDestroy(damage::DestroyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
                     damage::SUBTRACT_REQUEST => return Ok(Request::DamageSubtract(damage::SubtractRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
                     damage::ADD_REQUEST => return Ok(Request::DamageAdd(damage::AddRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1433,13 +2216,21 @@ impl<'input> Request<'input> {
             #[cfg(feature = "dpms")]
             Some((dpms::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
                     dpms::GET_VERSION_REQUEST => return Ok(Request::DpmsGetVersion(dpms::GetVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
                     dpms::CAPABLE_REQUEST => return Ok(Request::DpmsCapable(dpms::CapableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
                     dpms::GET_TIMEOUTS_REQUEST => return Ok(Request::DpmsGetTimeouts(dpms::GetTimeoutsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
                     dpms::SET_TIMEOUTS_REQUEST => return Ok(Request::DpmsSetTimeouts(dpms::SetTimeoutsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
                     dpms::ENABLE_REQUEST => return Ok(Request::DpmsEnable(dpms::EnableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
                     dpms::DISABLE_REQUEST => return Ok(Request::DpmsDisable(dpms::DisableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
                     dpms::FORCE_LEVEL_REQUEST => return Ok(Request::DpmsForceLevel(dpms::ForceLevelRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
                     dpms::INFO_REQUEST => return Ok(Request::DpmsInfo(dpms::InfoRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1447,19 +2238,33 @@ impl<'input> Request<'input> {
             #[cfg(feature = "dri2")]
             Some((dri2::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
                     dri2::QUERY_VERSION_REQUEST => return Ok(Request::Dri2QueryVersion(dri2::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
                     dri2::CONNECT_REQUEST => return Ok(Request::Dri2Connect(dri2::ConnectRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
                     dri2::AUTHENTICATE_REQUEST => return Ok(Request::Dri2Authenticate(dri2::AuthenticateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
                     dri2::CREATE_DRAWABLE_REQUEST => return Ok(Request::Dri2CreateDrawable(dri2::CreateDrawableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
                     dri2::DESTROY_DRAWABLE_REQUEST => return Ok(Request::Dri2DestroyDrawable(dri2::DestroyDrawableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
                     dri2::GET_BUFFERS_REQUEST => return Ok(Request::Dri2GetBuffers(dri2::GetBuffersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
                     dri2::COPY_REGION_REQUEST => return Ok(Request::Dri2CopyRegion(dri2::CopyRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
                     dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => return Ok(Request::Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
                     dri2::SWAP_BUFFERS_REQUEST => return Ok(Request::Dri2SwapBuffers(dri2::SwapBuffersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
                     dri2::GET_MSC_REQUEST => return Ok(Request::Dri2GetMSC(dri2::GetMSCRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
                     dri2::WAIT_MSC_REQUEST => return Ok(Request::Dri2WaitMSC(dri2::WaitMSCRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
                     dri2::WAIT_SBC_REQUEST => return Ok(Request::Dri2WaitSBC(dri2::WaitSBCRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
                     dri2::SWAP_INTERVAL_REQUEST => return Ok(Request::Dri2SwapInterval(dri2::SwapIntervalRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
                     dri2::GET_PARAM_REQUEST => return Ok(Request::Dri2GetParam(dri2::GetParamRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1467,20 +2272,30 @@ impl<'input> Request<'input> {
             #[cfg(feature = "dri3")]
             Some((dri3::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
                     dri3::QUERY_VERSION_REQUEST => return Ok(Request::Dri3QueryVersion(dri3::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
                     dri3::OPEN_REQUEST => return Ok(Request::Dri3Open(dri3::OpenRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
                     dri3::PIXMAP_FROM_BUFFER_REQUEST => return Ok(Request::Dri3PixmapFromBuffer(dri3::PixmapFromBufferRequest::try_parse_request_fd(header, remaining, fds)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
                     dri3::BUFFER_FROM_PIXMAP_REQUEST => return Ok(Request::Dri3BufferFromPixmap(dri3::BufferFromPixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
                     dri3::FENCE_FROM_FD_REQUEST => return Ok(Request::Dri3FenceFromFD(dri3::FenceFromFDRequest::try_parse_request_fd(header, remaining, fds)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
                     dri3::FD_FROM_FENCE_REQUEST => return Ok(Request::Dri3FDFromFence(dri3::FDFromFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
                     dri3::GET_SUPPORTED_MODIFIERS_REQUEST => return Ok(Request::Dri3GetSupportedModifiers(dri3::GetSupportedModifiersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
                     dri3::PIXMAP_FROM_BUFFERS_REQUEST => return Ok(Request::Dri3PixmapFromBuffers(dri3::PixmapFromBuffersRequest::try_parse_request_fd(header, remaining, fds)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
                     dri3::BUFFERS_FROM_PIXMAP_REQUEST => return Ok(Request::Dri3BuffersFromPixmap(dri3::BuffersFromPixmapRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
             }
             Some((ge::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
                     ge::QUERY_VERSION_REQUEST => return Ok(Request::GeQueryVersion(ge::QueryVersionRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1488,106 +2303,207 @@ impl<'input> Request<'input> {
             #[cfg(feature = "glx")]
             Some((glx::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
                     glx::RENDER_REQUEST => return Ok(Request::GlxRender(glx::RenderRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
                     glx::RENDER_LARGE_REQUEST => return Ok(Request::GlxRenderLarge(glx::RenderLargeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
                     glx::CREATE_CONTEXT_REQUEST => return Ok(Request::GlxCreateContext(glx::CreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
                     glx::DESTROY_CONTEXT_REQUEST => return Ok(Request::GlxDestroyContext(glx::DestroyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
                     glx::MAKE_CURRENT_REQUEST => return Ok(Request::GlxMakeCurrent(glx::MakeCurrentRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
                     glx::IS_DIRECT_REQUEST => return Ok(Request::GlxIsDirect(glx::IsDirectRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
                     glx::QUERY_VERSION_REQUEST => return Ok(Request::GlxQueryVersion(glx::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
                     glx::WAIT_GL_REQUEST => return Ok(Request::GlxWaitGL(glx::WaitGLRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
                     glx::WAIT_X_REQUEST => return Ok(Request::GlxWaitX(glx::WaitXRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
                     glx::COPY_CONTEXT_REQUEST => return Ok(Request::GlxCopyContext(glx::CopyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
                     glx::SWAP_BUFFERS_REQUEST => return Ok(Request::GlxSwapBuffers(glx::SwapBuffersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
                     glx::USE_X_FONT_REQUEST => return Ok(Request::GlxUseXFont(glx::UseXFontRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
                     glx::CREATE_GLX_PIXMAP_REQUEST => return Ok(Request::GlxCreateGLXPixmap(glx::CreateGLXPixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
                     glx::GET_VISUAL_CONFIGS_REQUEST => return Ok(Request::GlxGetVisualConfigs(glx::GetVisualConfigsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
                     glx::DESTROY_GLX_PIXMAP_REQUEST => return Ok(Request::GlxDestroyGLXPixmap(glx::DestroyGLXPixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
                     glx::VENDOR_PRIVATE_REQUEST => return Ok(Request::GlxVendorPrivate(glx::VendorPrivateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
                     glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => return Ok(Request::GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
                     glx::QUERY_EXTENSIONS_STRING_REQUEST => return Ok(Request::GlxQueryExtensionsString(glx::QueryExtensionsStringRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
                     glx::QUERY_SERVER_STRING_REQUEST => return Ok(Request::GlxQueryServerString(glx::QueryServerStringRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
                     glx::CLIENT_INFO_REQUEST => return Ok(Request::GlxClientInfo(glx::ClientInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
                     glx::GET_FB_CONFIGS_REQUEST => return Ok(Request::GlxGetFBConfigs(glx::GetFBConfigsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
                     glx::CREATE_PIXMAP_REQUEST => return Ok(Request::GlxCreatePixmap(glx::CreatePixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
                     glx::DESTROY_PIXMAP_REQUEST => return Ok(Request::GlxDestroyPixmap(glx::DestroyPixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
                     glx::CREATE_NEW_CONTEXT_REQUEST => return Ok(Request::GlxCreateNewContext(glx::CreateNewContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
                     glx::QUERY_CONTEXT_REQUEST => return Ok(Request::GlxQueryContext(glx::QueryContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
                     glx::MAKE_CONTEXT_CURRENT_REQUEST => return Ok(Request::GlxMakeContextCurrent(glx::MakeContextCurrentRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
                     glx::CREATE_PBUFFER_REQUEST => return Ok(Request::GlxCreatePbuffer(glx::CreatePbufferRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
                     glx::DESTROY_PBUFFER_REQUEST => return Ok(Request::GlxDestroyPbuffer(glx::DestroyPbufferRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
                     glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => return Ok(Request::GlxGetDrawableAttributes(glx::GetDrawableAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
                     glx::CHANGE_DRAWABLE_ATTRIBUTES_REQUEST => return Ok(Request::GlxChangeDrawableAttributes(glx::ChangeDrawableAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
                     glx::CREATE_WINDOW_REQUEST => return Ok(Request::GlxCreateWindow(glx::CreateWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
                     glx::DELETE_WINDOW_REQUEST => return Ok(Request::GlxDeleteWindow(glx::DeleteWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
                     glx::SET_CLIENT_INFO_ARB_REQUEST => return Ok(Request::GlxSetClientInfoARB(glx::SetClientInfoARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
                     glx::CREATE_CONTEXT_ATTRIBS_ARB_REQUEST => return Ok(Request::GlxCreateContextAttribsARB(glx::CreateContextAttribsARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
                     glx::SET_CLIENT_INFO2_ARB_REQUEST => return Ok(Request::GlxSetClientInfo2ARB(glx::SetClientInfo2ARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
                     glx::NEW_LIST_REQUEST => return Ok(Request::GlxNewList(glx::NewListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
                     glx::END_LIST_REQUEST => return Ok(Request::GlxEndList(glx::EndListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
                     glx::DELETE_LISTS_REQUEST => return Ok(Request::GlxDeleteLists(glx::DeleteListsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
                     glx::GEN_LISTS_REQUEST => return Ok(Request::GlxGenLists(glx::GenListsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
                     glx::FEEDBACK_BUFFER_REQUEST => return Ok(Request::GlxFeedbackBuffer(glx::FeedbackBufferRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
                     glx::SELECT_BUFFER_REQUEST => return Ok(Request::GlxSelectBuffer(glx::SelectBufferRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
                     glx::RENDER_MODE_REQUEST => return Ok(Request::GlxRenderMode(glx::RenderModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
                     glx::FINISH_REQUEST => return Ok(Request::GlxFinish(glx::FinishRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
                     glx::PIXEL_STOREF_REQUEST => return Ok(Request::GlxPixelStoref(glx::PixelStorefRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
                     glx::PIXEL_STOREI_REQUEST => return Ok(Request::GlxPixelStorei(glx::PixelStoreiRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
                     glx::READ_PIXELS_REQUEST => return Ok(Request::GlxReadPixels(glx::ReadPixelsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
                     glx::GET_BOOLEANV_REQUEST => return Ok(Request::GlxGetBooleanv(glx::GetBooleanvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
                     glx::GET_CLIP_PLANE_REQUEST => return Ok(Request::GlxGetClipPlane(glx::GetClipPlaneRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
                     glx::GET_DOUBLEV_REQUEST => return Ok(Request::GlxGetDoublev(glx::GetDoublevRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
                     glx::GET_ERROR_REQUEST => return Ok(Request::GlxGetError(glx::GetErrorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
                     glx::GET_FLOATV_REQUEST => return Ok(Request::GlxGetFloatv(glx::GetFloatvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
                     glx::GET_INTEGERV_REQUEST => return Ok(Request::GlxGetIntegerv(glx::GetIntegervRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
                     glx::GET_LIGHTFV_REQUEST => return Ok(Request::GlxGetLightfv(glx::GetLightfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
                     glx::GET_LIGHTIV_REQUEST => return Ok(Request::GlxGetLightiv(glx::GetLightivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
                     glx::GET_MAPDV_REQUEST => return Ok(Request::GlxGetMapdv(glx::GetMapdvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
                     glx::GET_MAPFV_REQUEST => return Ok(Request::GlxGetMapfv(glx::GetMapfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
                     glx::GET_MAPIV_REQUEST => return Ok(Request::GlxGetMapiv(glx::GetMapivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
                     glx::GET_MATERIALFV_REQUEST => return Ok(Request::GlxGetMaterialfv(glx::GetMaterialfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
                     glx::GET_MATERIALIV_REQUEST => return Ok(Request::GlxGetMaterialiv(glx::GetMaterialivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
                     glx::GET_PIXEL_MAPFV_REQUEST => return Ok(Request::GlxGetPixelMapfv(glx::GetPixelMapfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
                     glx::GET_PIXEL_MAPUIV_REQUEST => return Ok(Request::GlxGetPixelMapuiv(glx::GetPixelMapuivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
                     glx::GET_PIXEL_MAPUSV_REQUEST => return Ok(Request::GlxGetPixelMapusv(glx::GetPixelMapusvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
                     glx::GET_POLYGON_STIPPLE_REQUEST => return Ok(Request::GlxGetPolygonStipple(glx::GetPolygonStippleRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
                     glx::GET_STRING_REQUEST => return Ok(Request::GlxGetString(glx::GetStringRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
                     glx::GET_TEX_ENVFV_REQUEST => return Ok(Request::GlxGetTexEnvfv(glx::GetTexEnvfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
                     glx::GET_TEX_ENVIV_REQUEST => return Ok(Request::GlxGetTexEnviv(glx::GetTexEnvivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
                     glx::GET_TEX_GENDV_REQUEST => return Ok(Request::GlxGetTexGendv(glx::GetTexGendvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
                     glx::GET_TEX_GENFV_REQUEST => return Ok(Request::GlxGetTexGenfv(glx::GetTexGenfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
                     glx::GET_TEX_GENIV_REQUEST => return Ok(Request::GlxGetTexGeniv(glx::GetTexGenivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
                     glx::GET_TEX_IMAGE_REQUEST => return Ok(Request::GlxGetTexImage(glx::GetTexImageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
                     glx::GET_TEX_PARAMETERFV_REQUEST => return Ok(Request::GlxGetTexParameterfv(glx::GetTexParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
                     glx::GET_TEX_PARAMETERIV_REQUEST => return Ok(Request::GlxGetTexParameteriv(glx::GetTexParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
                     glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => return Ok(Request::GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
                     glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => return Ok(Request::GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
                     glx::IS_ENABLED_REQUEST => return Ok(Request::GlxIsEnabled(glx::IsEnabledRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
                     glx::IS_LIST_REQUEST => return Ok(Request::GlxIsList(glx::IsListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
                     glx::FLUSH_REQUEST => return Ok(Request::GlxFlush(glx::FlushRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
                     glx::ARE_TEXTURES_RESIDENT_REQUEST => return Ok(Request::GlxAreTexturesResident(glx::AreTexturesResidentRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
                     glx::DELETE_TEXTURES_REQUEST => return Ok(Request::GlxDeleteTextures(glx::DeleteTexturesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
                     glx::GEN_TEXTURES_REQUEST => return Ok(Request::GlxGenTextures(glx::GenTexturesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
                     glx::IS_TEXTURE_REQUEST => return Ok(Request::GlxIsTexture(glx::IsTextureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
                     glx::GET_COLOR_TABLE_REQUEST => return Ok(Request::GlxGetColorTable(glx::GetColorTableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
                     glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => return Ok(Request::GlxGetColorTableParameterfv(glx::GetColorTableParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
                     glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => return Ok(Request::GlxGetColorTableParameteriv(glx::GetColorTableParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
                     glx::GET_CONVOLUTION_FILTER_REQUEST => return Ok(Request::GlxGetConvolutionFilter(glx::GetConvolutionFilterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
                     glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => return Ok(Request::GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
                     glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => return Ok(Request::GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
                     glx::GET_SEPARABLE_FILTER_REQUEST => return Ok(Request::GlxGetSeparableFilter(glx::GetSeparableFilterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
                     glx::GET_HISTOGRAM_REQUEST => return Ok(Request::GlxGetHistogram(glx::GetHistogramRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
                     glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => return Ok(Request::GlxGetHistogramParameterfv(glx::GetHistogramParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
                     glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => return Ok(Request::GlxGetHistogramParameteriv(glx::GetHistogramParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
                     glx::GET_MINMAX_REQUEST => return Ok(Request::GlxGetMinmax(glx::GetMinmaxRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
                     glx::GET_MINMAX_PARAMETERFV_REQUEST => return Ok(Request::GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
                     glx::GET_MINMAX_PARAMETERIV_REQUEST => return Ok(Request::GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
                     glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => return Ok(Request::GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
                     glx::DELETE_QUERIES_ARB_REQUEST => return Ok(Request::GlxDeleteQueriesARB(glx::DeleteQueriesARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
                     glx::GEN_QUERIES_ARB_REQUEST => return Ok(Request::GlxGenQueriesARB(glx::GenQueriesARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
                     glx::IS_QUERY_ARB_REQUEST => return Ok(Request::GlxIsQueryARB(glx::IsQueryARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
                     glx::GET_QUERYIV_ARB_REQUEST => return Ok(Request::GlxGetQueryivARB(glx::GetQueryivARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
                     glx::GET_QUERY_OBJECTIV_ARB_REQUEST => return Ok(Request::GlxGetQueryObjectivARB(glx::GetQueryObjectivARBRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
                     glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => return Ok(Request::GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1595,10 +2511,15 @@ impl<'input> Request<'input> {
             #[cfg(feature = "present")]
             Some((present::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
                     present::QUERY_VERSION_REQUEST => return Ok(Request::PresentQueryVersion(present::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::Pixmap"))]
                     present::PIXMAP_REQUEST => return Ok(Request::PresentPixmap(present::PixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::NotifyMSC"))]
                     present::NOTIFY_MSC_REQUEST => return Ok(Request::PresentNotifyMSC(present::NotifyMSCRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::SelectInput"))]
                     present::SELECT_INPUT_REQUEST => return Ok(Request::PresentSelectInput(present::SelectInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
                     present::QUERY_CAPABILITIES_REQUEST => return Ok(Request::PresentQueryCapabilities(present::QueryCapabilitiesRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1606,50 +2527,95 @@ impl<'input> Request<'input> {
             #[cfg(feature = "randr")]
             Some((randr::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
                     randr::QUERY_VERSION_REQUEST => return Ok(Request::RandrQueryVersion(randr::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
                     randr::SET_SCREEN_CONFIG_REQUEST => return Ok(Request::RandrSetScreenConfig(randr::SetScreenConfigRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
                     randr::SELECT_INPUT_REQUEST => return Ok(Request::RandrSelectInput(randr::SelectInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
                     randr::GET_SCREEN_INFO_REQUEST => return Ok(Request::RandrGetScreenInfo(randr::GetScreenInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
                     randr::GET_SCREEN_SIZE_RANGE_REQUEST => return Ok(Request::RandrGetScreenSizeRange(randr::GetScreenSizeRangeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
                     randr::SET_SCREEN_SIZE_REQUEST => return Ok(Request::RandrSetScreenSize(randr::SetScreenSizeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
                     randr::GET_SCREEN_RESOURCES_REQUEST => return Ok(Request::RandrGetScreenResources(randr::GetScreenResourcesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
                     randr::GET_OUTPUT_INFO_REQUEST => return Ok(Request::RandrGetOutputInfo(randr::GetOutputInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
                     randr::LIST_OUTPUT_PROPERTIES_REQUEST => return Ok(Request::RandrListOutputProperties(randr::ListOutputPropertiesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
                     randr::QUERY_OUTPUT_PROPERTY_REQUEST => return Ok(Request::RandrQueryOutputProperty(randr::QueryOutputPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
                     randr::CONFIGURE_OUTPUT_PROPERTY_REQUEST => return Ok(Request::RandrConfigureOutputProperty(randr::ConfigureOutputPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
                     randr::CHANGE_OUTPUT_PROPERTY_REQUEST => return Ok(Request::RandrChangeOutputProperty(randr::ChangeOutputPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
                     randr::DELETE_OUTPUT_PROPERTY_REQUEST => return Ok(Request::RandrDeleteOutputProperty(randr::DeleteOutputPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
                     randr::GET_OUTPUT_PROPERTY_REQUEST => return Ok(Request::RandrGetOutputProperty(randr::GetOutputPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
                     randr::CREATE_MODE_REQUEST => return Ok(Request::RandrCreateMode(randr::CreateModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
                     randr::DESTROY_MODE_REQUEST => return Ok(Request::RandrDestroyMode(randr::DestroyModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
                     randr::ADD_OUTPUT_MODE_REQUEST => return Ok(Request::RandrAddOutputMode(randr::AddOutputModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
                     randr::DELETE_OUTPUT_MODE_REQUEST => return Ok(Request::RandrDeleteOutputMode(randr::DeleteOutputModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
                     randr::GET_CRTC_INFO_REQUEST => return Ok(Request::RandrGetCrtcInfo(randr::GetCrtcInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
                     randr::SET_CRTC_CONFIG_REQUEST => return Ok(Request::RandrSetCrtcConfig(randr::SetCrtcConfigRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
                     randr::GET_CRTC_GAMMA_SIZE_REQUEST => return Ok(Request::RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
                     randr::GET_CRTC_GAMMA_REQUEST => return Ok(Request::RandrGetCrtcGamma(randr::GetCrtcGammaRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
                     randr::SET_CRTC_GAMMA_REQUEST => return Ok(Request::RandrSetCrtcGamma(randr::SetCrtcGammaRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
                     randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => return Ok(Request::RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
                     randr::SET_CRTC_TRANSFORM_REQUEST => return Ok(Request::RandrSetCrtcTransform(randr::SetCrtcTransformRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
                     randr::GET_CRTC_TRANSFORM_REQUEST => return Ok(Request::RandrGetCrtcTransform(randr::GetCrtcTransformRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
                     randr::GET_PANNING_REQUEST => return Ok(Request::RandrGetPanning(randr::GetPanningRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
                     randr::SET_PANNING_REQUEST => return Ok(Request::RandrSetPanning(randr::SetPanningRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
                     randr::SET_OUTPUT_PRIMARY_REQUEST => return Ok(Request::RandrSetOutputPrimary(randr::SetOutputPrimaryRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
                     randr::GET_OUTPUT_PRIMARY_REQUEST => return Ok(Request::RandrGetOutputPrimary(randr::GetOutputPrimaryRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
                     randr::GET_PROVIDERS_REQUEST => return Ok(Request::RandrGetProviders(randr::GetProvidersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
                     randr::GET_PROVIDER_INFO_REQUEST => return Ok(Request::RandrGetProviderInfo(randr::GetProviderInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
                     randr::SET_PROVIDER_OFFLOAD_SINK_REQUEST => return Ok(Request::RandrSetProviderOffloadSink(randr::SetProviderOffloadSinkRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
                     randr::SET_PROVIDER_OUTPUT_SOURCE_REQUEST => return Ok(Request::RandrSetProviderOutputSource(randr::SetProviderOutputSourceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
                     randr::LIST_PROVIDER_PROPERTIES_REQUEST => return Ok(Request::RandrListProviderProperties(randr::ListProviderPropertiesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
                     randr::QUERY_PROVIDER_PROPERTY_REQUEST => return Ok(Request::RandrQueryProviderProperty(randr::QueryProviderPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
                     randr::CONFIGURE_PROVIDER_PROPERTY_REQUEST => return Ok(Request::RandrConfigureProviderProperty(randr::ConfigureProviderPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
                     randr::CHANGE_PROVIDER_PROPERTY_REQUEST => return Ok(Request::RandrChangeProviderProperty(randr::ChangeProviderPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
                     randr::DELETE_PROVIDER_PROPERTY_REQUEST => return Ok(Request::RandrDeleteProviderProperty(randr::DeleteProviderPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
                     randr::GET_PROVIDER_PROPERTY_REQUEST => return Ok(Request::RandrGetProviderProperty(randr::GetProviderPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
                     randr::GET_MONITORS_REQUEST => return Ok(Request::RandrGetMonitors(randr::GetMonitorsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
                     randr::SET_MONITOR_REQUEST => return Ok(Request::RandrSetMonitor(randr::SetMonitorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
                     randr::DELETE_MONITOR_REQUEST => return Ok(Request::RandrDeleteMonitor(randr::DeleteMonitorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
                     randr::CREATE_LEASE_REQUEST => return Ok(Request::RandrCreateLease(randr::CreateLeaseRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
                     randr::FREE_LEASE_REQUEST => return Ok(Request::RandrFreeLease(randr::FreeLeaseRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1657,13 +2623,20 @@ impl<'input> Request<'input> {
             #[cfg(feature = "record")]
             Some((record::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
                     record::QUERY_VERSION_REQUEST => return Ok(Request::RecordQueryVersion(record::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::CreateContext"))]
                     record::CREATE_CONTEXT_REQUEST => return Ok(Request::RecordCreateContext(record::CreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::RegisterClients"))]
                     record::REGISTER_CLIENTS_REQUEST => return Ok(Request::RecordRegisterClients(record::RegisterClientsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::UnregisterClients"))]
                     record::UNREGISTER_CLIENTS_REQUEST => return Ok(Request::RecordUnregisterClients(record::UnregisterClientsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
                     record::GET_CONTEXT_REQUEST => return Ok(Request::RecordGetContext(record::GetContextRequest::try_parse_request(header, remaining)?)),
                     record::ENABLE_CONTEXT_REQUEST => return Ok(Request::RecordEnableContext(record::EnableContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::DisableContext"))]
                     record::DISABLE_CONTEXT_REQUEST => return Ok(Request::RecordDisableContext(record::DisableContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::FreeContext"))]
                     record::FREE_CONTEXT_REQUEST => return Ok(Request::RecordFreeContext(record::FreeContextRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1671,36 +2644,67 @@ impl<'input> Request<'input> {
             #[cfg(feature = "render")]
             Some((render::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
                     render::QUERY_VERSION_REQUEST => return Ok(Request::RenderQueryVersion(render::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
                     render::QUERY_PICT_FORMATS_REQUEST => return Ok(Request::RenderQueryPictFormats(render::QueryPictFormatsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
                     render::QUERY_PICT_INDEX_VALUES_REQUEST => return Ok(Request::RenderQueryPictIndexValues(render::QueryPictIndexValuesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
                     render::CREATE_PICTURE_REQUEST => return Ok(Request::RenderCreatePicture(render::CreatePictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
                     render::CHANGE_PICTURE_REQUEST => return Ok(Request::RenderChangePicture(render::ChangePictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
                     render::SET_PICTURE_CLIP_RECTANGLES_REQUEST => return Ok(Request::RenderSetPictureClipRectangles(render::SetPictureClipRectanglesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
                     render::FREE_PICTURE_REQUEST => return Ok(Request::RenderFreePicture(render::FreePictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
                     render::COMPOSITE_REQUEST => return Ok(Request::RenderComposite(render::CompositeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
                     render::TRAPEZOIDS_REQUEST => return Ok(Request::RenderTrapezoids(render::TrapezoidsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
                     render::TRIANGLES_REQUEST => return Ok(Request::RenderTriangles(render::TrianglesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
                     render::TRI_STRIP_REQUEST => return Ok(Request::RenderTriStrip(render::TriStripRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
                     render::TRI_FAN_REQUEST => return Ok(Request::RenderTriFan(render::TriFanRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
                     render::CREATE_GLYPH_SET_REQUEST => return Ok(Request::RenderCreateGlyphSet(render::CreateGlyphSetRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
                     render::REFERENCE_GLYPH_SET_REQUEST => return Ok(Request::RenderReferenceGlyphSet(render::ReferenceGlyphSetRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
                     render::FREE_GLYPH_SET_REQUEST => return Ok(Request::RenderFreeGlyphSet(render::FreeGlyphSetRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
                     render::ADD_GLYPHS_REQUEST => return Ok(Request::RenderAddGlyphs(render::AddGlyphsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
                     render::FREE_GLYPHS_REQUEST => return Ok(Request::RenderFreeGlyphs(render::FreeGlyphsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
                     render::COMPOSITE_GLYPHS8_REQUEST => return Ok(Request::RenderCompositeGlyphs8(render::CompositeGlyphs8Request::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
                     render::COMPOSITE_GLYPHS16_REQUEST => return Ok(Request::RenderCompositeGlyphs16(render::CompositeGlyphs16Request::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
                     render::COMPOSITE_GLYPHS32_REQUEST => return Ok(Request::RenderCompositeGlyphs32(render::CompositeGlyphs32Request::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
                     render::FILL_RECTANGLES_REQUEST => return Ok(Request::RenderFillRectangles(render::FillRectanglesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
                     render::CREATE_CURSOR_REQUEST => return Ok(Request::RenderCreateCursor(render::CreateCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
                     render::SET_PICTURE_TRANSFORM_REQUEST => return Ok(Request::RenderSetPictureTransform(render::SetPictureTransformRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
                     render::QUERY_FILTERS_REQUEST => return Ok(Request::RenderQueryFilters(render::QueryFiltersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
                     render::SET_PICTURE_FILTER_REQUEST => return Ok(Request::RenderSetPictureFilter(render::SetPictureFilterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
                     render::CREATE_ANIM_CURSOR_REQUEST => return Ok(Request::RenderCreateAnimCursor(render::CreateAnimCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
                     render::ADD_TRAPS_REQUEST => return Ok(Request::RenderAddTraps(render::AddTrapsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
                     render::CREATE_SOLID_FILL_REQUEST => return Ok(Request::RenderCreateSolidFill(render::CreateSolidFillRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
                     render::CREATE_LINEAR_GRADIENT_REQUEST => return Ok(Request::RenderCreateLinearGradient(render::CreateLinearGradientRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
                     render::CREATE_RADIAL_GRADIENT_REQUEST => return Ok(Request::RenderCreateRadialGradient(render::CreateRadialGradientRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
                     render::CREATE_CONICAL_GRADIENT_REQUEST => return Ok(Request::RenderCreateConicalGradient(render::CreateConicalGradientRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1708,11 +2712,17 @@ impl<'input> Request<'input> {
             #[cfg(feature = "res")]
             Some((res::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
                     res::QUERY_VERSION_REQUEST => return Ok(Request::ResQueryVersion(res::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
                     res::QUERY_CLIENTS_REQUEST => return Ok(Request::ResQueryClients(res::QueryClientsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
                     res::QUERY_CLIENT_RESOURCES_REQUEST => return Ok(Request::ResQueryClientResources(res::QueryClientResourcesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
                     res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => return Ok(Request::ResQueryClientPixmapBytes(res::QueryClientPixmapBytesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
                     res::QUERY_CLIENT_IDS_REQUEST => return Ok(Request::ResQueryClientIds(res::QueryClientIdsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
                     res::QUERY_RESOURCE_BYTES_REQUEST => return Ok(Request::ResQueryResourceBytes(res::QueryResourceBytesRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1720,11 +2730,17 @@ impl<'input> Request<'input> {
             #[cfg(feature = "screensaver")]
             Some((screensaver::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
                     screensaver::QUERY_VERSION_REQUEST => return Ok(Request::ScreensaverQueryVersion(screensaver::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
                     screensaver::QUERY_INFO_REQUEST => return Ok(Request::ScreensaverQueryInfo(screensaver::QueryInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SelectInput"))]
                     screensaver::SELECT_INPUT_REQUEST => return Ok(Request::ScreensaverSelectInput(screensaver::SelectInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SetAttributes"))]
                     screensaver::SET_ATTRIBUTES_REQUEST => return Ok(Request::ScreensaverSetAttributes(screensaver::SetAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::UnsetAttributes"))]
                     screensaver::UNSET_ATTRIBUTES_REQUEST => return Ok(Request::ScreensaverUnsetAttributes(screensaver::UnsetAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::Suspend"))]
                     screensaver::SUSPEND_REQUEST => return Ok(Request::ScreensaverSuspend(screensaver::SuspendRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1732,14 +2748,23 @@ impl<'input> Request<'input> {
             #[cfg(feature = "shape")]
             Some((shape::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
                     shape::QUERY_VERSION_REQUEST => return Ok(Request::ShapeQueryVersion(shape::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
                     shape::RECTANGLES_REQUEST => return Ok(Request::ShapeRectangles(shape::RectanglesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
                     shape::MASK_REQUEST => return Ok(Request::ShapeMask(shape::MaskRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
                     shape::COMBINE_REQUEST => return Ok(Request::ShapeCombine(shape::CombineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
                     shape::OFFSET_REQUEST => return Ok(Request::ShapeOffset(shape::OffsetRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
                     shape::QUERY_EXTENTS_REQUEST => return Ok(Request::ShapeQueryExtents(shape::QueryExtentsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
                     shape::SELECT_INPUT_REQUEST => return Ok(Request::ShapeSelectInput(shape::SelectInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
                     shape::INPUT_SELECTED_REQUEST => return Ok(Request::ShapeInputSelected(shape::InputSelectedRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
                     shape::GET_RECTANGLES_REQUEST => return Ok(Request::ShapeGetRectangles(shape::GetRectanglesRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1747,13 +2772,21 @@ impl<'input> Request<'input> {
             #[cfg(feature = "shm")]
             Some((shm::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
                     shm::QUERY_VERSION_REQUEST => return Ok(Request::ShmQueryVersion(shm::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
                     shm::ATTACH_REQUEST => return Ok(Request::ShmAttach(shm::AttachRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
                     shm::DETACH_REQUEST => return Ok(Request::ShmDetach(shm::DetachRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
                     shm::PUT_IMAGE_REQUEST => return Ok(Request::ShmPutImage(shm::PutImageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
                     shm::GET_IMAGE_REQUEST => return Ok(Request::ShmGetImage(shm::GetImageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
                     shm::CREATE_PIXMAP_REQUEST => return Ok(Request::ShmCreatePixmap(shm::CreatePixmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
                     shm::ATTACH_FD_REQUEST => return Ok(Request::ShmAttachFd(shm::AttachFdRequest::try_parse_request_fd(header, remaining, fds)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
                     shm::CREATE_SEGMENT_REQUEST => return Ok(Request::ShmCreateSegment(shm::CreateSegmentRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1761,33 +2794,55 @@ impl<'input> Request<'input> {
             #[cfg(feature = "sync")]
             Some((sync::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
                     sync::INITIALIZE_REQUEST => return Ok(Request::SyncInitialize(sync::InitializeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
                     sync::LIST_SYSTEM_COUNTERS_REQUEST => return Ok(Request::SyncListSystemCounters(sync::ListSystemCountersRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
                     sync::CREATE_COUNTER_REQUEST => return Ok(Request::SyncCreateCounter(sync::CreateCounterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
                     sync::DESTROY_COUNTER_REQUEST => return Ok(Request::SyncDestroyCounter(sync::DestroyCounterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
                     sync::QUERY_COUNTER_REQUEST => return Ok(Request::SyncQueryCounter(sync::QueryCounterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
                     sync::AWAIT_REQUEST => return Ok(Request::SyncAwait(sync::AwaitRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
                     sync::CHANGE_COUNTER_REQUEST => return Ok(Request::SyncChangeCounter(sync::ChangeCounterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
                     sync::SET_COUNTER_REQUEST => return Ok(Request::SyncSetCounter(sync::SetCounterRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
                     sync::CREATE_ALARM_REQUEST => return Ok(Request::SyncCreateAlarm(sync::CreateAlarmRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
                     sync::CHANGE_ALARM_REQUEST => return Ok(Request::SyncChangeAlarm(sync::ChangeAlarmRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
                     sync::DESTROY_ALARM_REQUEST => return Ok(Request::SyncDestroyAlarm(sync::DestroyAlarmRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
                     sync::QUERY_ALARM_REQUEST => return Ok(Request::SyncQueryAlarm(sync::QueryAlarmRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
                     sync::SET_PRIORITY_REQUEST => return Ok(Request::SyncSetPriority(sync::SetPriorityRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
                     sync::GET_PRIORITY_REQUEST => return Ok(Request::SyncGetPriority(sync::GetPriorityRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
                     sync::CREATE_FENCE_REQUEST => return Ok(Request::SyncCreateFence(sync::CreateFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
                     sync::TRIGGER_FENCE_REQUEST => return Ok(Request::SyncTriggerFence(sync::TriggerFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
                     sync::RESET_FENCE_REQUEST => return Ok(Request::SyncResetFence(sync::ResetFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
                     sync::DESTROY_FENCE_REQUEST => return Ok(Request::SyncDestroyFence(sync::DestroyFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
                     sync::QUERY_FENCE_REQUEST => return Ok(Request::SyncQueryFence(sync::QueryFenceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
                     sync::AWAIT_FENCE_REQUEST => return Ok(Request::SyncAwaitFence(sync::AwaitFenceRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
             }
             Some((xc_misc::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
                     xc_misc::GET_VERSION_REQUEST => return Ok(Request::XcMiscGetVersion(xc_misc::GetVersionRequest::try_parse_request(header, remaining)?)),
                     xc_misc::GET_XID_RANGE_REQUEST => return Ok(Request::XcMiscGetXIDRange(xc_misc::GetXIDRangeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
                     xc_misc::GET_XID_LIST_REQUEST => return Ok(Request::XcMiscGetXIDList(xc_misc::GetXIDListRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1795,10 +2850,15 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xevie")]
             Some((xevie::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
                     xevie::QUERY_VERSION_REQUEST => return Ok(Request::XevieQueryVersion(xevie::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
                     xevie::START_REQUEST => return Ok(Request::XevieStart(xevie::StartRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
                     xevie::END_REQUEST => return Ok(Request::XevieEnd(xevie::EndRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
                     xevie::SEND_REQUEST => return Ok(Request::XevieSend(xevie::SendRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
                     xevie::SELECT_INPUT_REQUEST => return Ok(Request::XevieSelectInput(xevie::SelectInputRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1806,17 +2866,29 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xf86dri")]
             Some((xf86dri::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
                     xf86dri::QUERY_VERSION_REQUEST => return Ok(Request::Xf86driQueryVersion(xf86dri::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
                     xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => return Ok(Request::Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
                     xf86dri::OPEN_CONNECTION_REQUEST => return Ok(Request::Xf86driOpenConnection(xf86dri::OpenConnectionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CloseConnection"))]
                     xf86dri::CLOSE_CONNECTION_REQUEST => return Ok(Request::Xf86driCloseConnection(xf86dri::CloseConnectionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
                     xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => return Ok(Request::Xf86driGetClientDriverName(xf86dri::GetClientDriverNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
                     xf86dri::CREATE_CONTEXT_REQUEST => return Ok(Request::Xf86driCreateContext(xf86dri::CreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyContext"))]
                     xf86dri::DESTROY_CONTEXT_REQUEST => return Ok(Request::Xf86driDestroyContext(xf86dri::DestroyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
                     xf86dri::CREATE_DRAWABLE_REQUEST => return Ok(Request::Xf86driCreateDrawable(xf86dri::CreateDrawableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyDrawable"))]
                     xf86dri::DESTROY_DRAWABLE_REQUEST => return Ok(Request::Xf86driDestroyDrawable(xf86dri::DestroyDrawableRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
                     xf86dri::GET_DRAWABLE_INFO_REQUEST => return Ok(Request::Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
                     xf86dri::GET_DEVICE_INFO_REQUEST => return Ok(Request::Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
                     xf86dri::AUTH_CONNECTION_REQUEST => return Ok(Request::Xf86driAuthConnection(xf86dri::AuthConnectionRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1824,26 +2896,47 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xf86vidmode")]
             Some((xf86vidmode::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
                     xf86vidmode::QUERY_VERSION_REQUEST => return Ok(Request::Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
                     xf86vidmode::GET_MODE_LINE_REQUEST => return Ok(Request::Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
                     xf86vidmode::MOD_MODE_LINE_REQUEST => return Ok(Request::Xf86vidmodeModModeLine(xf86vidmode::ModModeLineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
                     xf86vidmode::SWITCH_MODE_REQUEST => return Ok(Request::Xf86vidmodeSwitchMode(xf86vidmode::SwitchModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
                     xf86vidmode::GET_MONITOR_REQUEST => return Ok(Request::Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
                     xf86vidmode::LOCK_MODE_SWITCH_REQUEST => return Ok(Request::Xf86vidmodeLockModeSwitch(xf86vidmode::LockModeSwitchRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
                     xf86vidmode::GET_ALL_MODE_LINES_REQUEST => return Ok(Request::Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
                     xf86vidmode::ADD_MODE_LINE_REQUEST => return Ok(Request::Xf86vidmodeAddModeLine(xf86vidmode::AddModeLineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
                     xf86vidmode::DELETE_MODE_LINE_REQUEST => return Ok(Request::Xf86vidmodeDeleteModeLine(xf86vidmode::DeleteModeLineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
                     xf86vidmode::VALIDATE_MODE_LINE_REQUEST => return Ok(Request::Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
                     xf86vidmode::SWITCH_TO_MODE_REQUEST => return Ok(Request::Xf86vidmodeSwitchToMode(xf86vidmode::SwitchToModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
                     xf86vidmode::GET_VIEW_PORT_REQUEST => return Ok(Request::Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
                     xf86vidmode::SET_VIEW_PORT_REQUEST => return Ok(Request::Xf86vidmodeSetViewPort(xf86vidmode::SetViewPortRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
                     xf86vidmode::GET_DOT_CLOCKS_REQUEST => return Ok(Request::Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
                     xf86vidmode::SET_CLIENT_VERSION_REQUEST => return Ok(Request::Xf86vidmodeSetClientVersion(xf86vidmode::SetClientVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
                     xf86vidmode::SET_GAMMA_REQUEST => return Ok(Request::Xf86vidmodeSetGamma(xf86vidmode::SetGammaRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
                     xf86vidmode::GET_GAMMA_REQUEST => return Ok(Request::Xf86vidmodeGetGamma(xf86vidmode::GetGammaRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
                     xf86vidmode::GET_GAMMA_RAMP_REQUEST => return Ok(Request::Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
                     xf86vidmode::SET_GAMMA_RAMP_REQUEST => return Ok(Request::Xf86vidmodeSetGammaRamp(xf86vidmode::SetGammaRampRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
                     xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => return Ok(Request::Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
                     xf86vidmode::GET_PERMISSIONS_REQUEST => return Ok(Request::Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1851,38 +2944,71 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xfixes")]
             Some((xfixes::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
                     xfixes::QUERY_VERSION_REQUEST => return Ok(Request::XfixesQueryVersion(xfixes::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeSaveSet"))]
                     xfixes::CHANGE_SAVE_SET_REQUEST => return Ok(Request::XfixesChangeSaveSet(xfixes::ChangeSaveSetRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectSelectionInput"))]
                     xfixes::SELECT_SELECTION_INPUT_REQUEST => return Ok(Request::XfixesSelectSelectionInput(xfixes::SelectSelectionInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectCursorInput"))]
                     xfixes::SELECT_CURSOR_INPUT_REQUEST => return Ok(Request::XfixesSelectCursorInput(xfixes::SelectCursorInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
                     xfixes::GET_CURSOR_IMAGE_REQUEST => return Ok(Request::XfixesGetCursorImage(xfixes::GetCursorImageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegion"))]
                     xfixes::CREATE_REGION_REQUEST => return Ok(Request::XfixesCreateRegion(xfixes::CreateRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromBitmap"))]
                     xfixes::CREATE_REGION_FROM_BITMAP_REQUEST => return Ok(Request::XfixesCreateRegionFromBitmap(xfixes::CreateRegionFromBitmapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromWindow"))]
                     xfixes::CREATE_REGION_FROM_WINDOW_REQUEST => return Ok(Request::XfixesCreateRegionFromWindow(xfixes::CreateRegionFromWindowRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromGC"))]
                     xfixes::CREATE_REGION_FROM_GC_REQUEST => return Ok(Request::XfixesCreateRegionFromGC(xfixes::CreateRegionFromGCRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromPicture"))]
                     xfixes::CREATE_REGION_FROM_PICTURE_REQUEST => return Ok(Request::XfixesCreateRegionFromPicture(xfixes::CreateRegionFromPictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DestroyRegion"))]
                     xfixes::DESTROY_REGION_REQUEST => return Ok(Request::XfixesDestroyRegion(xfixes::DestroyRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetRegion"))]
                     xfixes::SET_REGION_REQUEST => return Ok(Request::XfixesSetRegion(xfixes::SetRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CopyRegion"))]
                     xfixes::COPY_REGION_REQUEST => return Ok(Request::XfixesCopyRegion(xfixes::CopyRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::UnionRegion"))]
                     xfixes::UNION_REGION_REQUEST => return Ok(Request::XfixesUnionRegion(xfixes::UnionRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::IntersectRegion"))]
                     xfixes::INTERSECT_REGION_REQUEST => return Ok(Request::XfixesIntersectRegion(xfixes::IntersectRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SubtractRegion"))]
                     xfixes::SUBTRACT_REGION_REQUEST => return Ok(Request::XfixesSubtractRegion(xfixes::SubtractRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::InvertRegion"))]
                     xfixes::INVERT_REGION_REQUEST => return Ok(Request::XfixesInvertRegion(xfixes::InvertRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::TranslateRegion"))]
                     xfixes::TRANSLATE_REGION_REQUEST => return Ok(Request::XfixesTranslateRegion(xfixes::TranslateRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::RegionExtents"))]
                     xfixes::REGION_EXTENTS_REQUEST => return Ok(Request::XfixesRegionExtents(xfixes::RegionExtentsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
                     xfixes::FETCH_REGION_REQUEST => return Ok(Request::XfixesFetchRegion(xfixes::FetchRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetGCClipRegion"))]
                     xfixes::SET_GC_CLIP_REGION_REQUEST => return Ok(Request::XfixesSetGCClipRegion(xfixes::SetGCClipRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetWindowShapeRegion"))]
                     xfixes::SET_WINDOW_SHAPE_REGION_REQUEST => return Ok(Request::XfixesSetWindowShapeRegion(xfixes::SetWindowShapeRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetPictureClipRegion"))]
                     xfixes::SET_PICTURE_CLIP_REGION_REQUEST => return Ok(Request::XfixesSetPictureClipRegion(xfixes::SetPictureClipRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetCursorName"))]
                     xfixes::SET_CURSOR_NAME_REQUEST => return Ok(Request::XfixesSetCursorName(xfixes::SetCursorNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
                     xfixes::GET_CURSOR_NAME_REQUEST => return Ok(Request::XfixesGetCursorName(xfixes::GetCursorNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
                     xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => return Ok(Request::XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursor"))]
                     xfixes::CHANGE_CURSOR_REQUEST => return Ok(Request::XfixesChangeCursor(xfixes::ChangeCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursorByName"))]
                     xfixes::CHANGE_CURSOR_BY_NAME_REQUEST => return Ok(Request::XfixesChangeCursorByName(xfixes::ChangeCursorByNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ExpandRegion"))]
                     xfixes::EXPAND_REGION_REQUEST => return Ok(Request::XfixesExpandRegion(xfixes::ExpandRegionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::HideCursor"))]
                     xfixes::HIDE_CURSOR_REQUEST => return Ok(Request::XfixesHideCursor(xfixes::HideCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ShowCursor"))]
                     xfixes::SHOW_CURSOR_REQUEST => return Ok(Request::XfixesShowCursor(xfixes::ShowCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreatePointerBarrier"))]
                     xfixes::CREATE_POINTER_BARRIER_REQUEST => return Ok(Request::XfixesCreatePointerBarrier(xfixes::CreatePointerBarrierRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DeletePointerBarrier"))]
                     xfixes::DELETE_POINTER_BARRIER_REQUEST => return Ok(Request::XfixesDeletePointerBarrier(xfixes::DeletePointerBarrierRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1890,11 +3016,17 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xinerama")]
             Some((xinerama::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
                     xinerama::QUERY_VERSION_REQUEST => return Ok(Request::XineramaQueryVersion(xinerama::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
                     xinerama::GET_STATE_REQUEST => return Ok(Request::XineramaGetState(xinerama::GetStateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
                     xinerama::GET_SCREEN_COUNT_REQUEST => return Ok(Request::XineramaGetScreenCount(xinerama::GetScreenCountRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
                     xinerama::GET_SCREEN_SIZE_REQUEST => return Ok(Request::XineramaGetScreenSize(xinerama::GetScreenSizeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
                     xinerama::IS_ACTIVE_REQUEST => return Ok(Request::XineramaIsActive(xinerama::IsActiveRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
                     xinerama::QUERY_SCREENS_REQUEST => return Ok(Request::XineramaQueryScreens(xinerama::QueryScreensRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1902,66 +3034,127 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xinput")]
             Some((xinput::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
                     xinput::GET_EXTENSION_VERSION_REQUEST => return Ok(Request::XinputGetExtensionVersion(xinput::GetExtensionVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
                     xinput::LIST_INPUT_DEVICES_REQUEST => return Ok(Request::XinputListInputDevices(xinput::ListInputDevicesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
                     xinput::OPEN_DEVICE_REQUEST => return Ok(Request::XinputOpenDevice(xinput::OpenDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
                     xinput::CLOSE_DEVICE_REQUEST => return Ok(Request::XinputCloseDevice(xinput::CloseDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
                     xinput::SET_DEVICE_MODE_REQUEST => return Ok(Request::XinputSetDeviceMode(xinput::SetDeviceModeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
                     xinput::SELECT_EXTENSION_EVENT_REQUEST => return Ok(Request::XinputSelectExtensionEvent(xinput::SelectExtensionEventRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
                     xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => return Ok(Request::XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
                     xinput::CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST => return Ok(Request::XinputChangeDeviceDontPropagateList(xinput::ChangeDeviceDontPropagateListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
                     xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => return Ok(Request::XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
                     xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => return Ok(Request::XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
                     xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => return Ok(Request::XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
                     xinput::CHANGE_POINTER_DEVICE_REQUEST => return Ok(Request::XinputChangePointerDevice(xinput::ChangePointerDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
                     xinput::GRAB_DEVICE_REQUEST => return Ok(Request::XinputGrabDevice(xinput::GrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
                     xinput::UNGRAB_DEVICE_REQUEST => return Ok(Request::XinputUngrabDevice(xinput::UngrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
                     xinput::GRAB_DEVICE_KEY_REQUEST => return Ok(Request::XinputGrabDeviceKey(xinput::GrabDeviceKeyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
                     xinput::UNGRAB_DEVICE_KEY_REQUEST => return Ok(Request::XinputUngrabDeviceKey(xinput::UngrabDeviceKeyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
                     xinput::GRAB_DEVICE_BUTTON_REQUEST => return Ok(Request::XinputGrabDeviceButton(xinput::GrabDeviceButtonRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
                     xinput::UNGRAB_DEVICE_BUTTON_REQUEST => return Ok(Request::XinputUngrabDeviceButton(xinput::UngrabDeviceButtonRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
                     xinput::ALLOW_DEVICE_EVENTS_REQUEST => return Ok(Request::XinputAllowDeviceEvents(xinput::AllowDeviceEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
                     xinput::GET_DEVICE_FOCUS_REQUEST => return Ok(Request::XinputGetDeviceFocus(xinput::GetDeviceFocusRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
                     xinput::SET_DEVICE_FOCUS_REQUEST => return Ok(Request::XinputSetDeviceFocus(xinput::SetDeviceFocusRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
                     xinput::GET_FEEDBACK_CONTROL_REQUEST => return Ok(Request::XinputGetFeedbackControl(xinput::GetFeedbackControlRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
                     xinput::CHANGE_FEEDBACK_CONTROL_REQUEST => return Ok(Request::XinputChangeFeedbackControl(xinput::ChangeFeedbackControlRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
                     xinput::GET_DEVICE_KEY_MAPPING_REQUEST => return Ok(Request::XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
                     xinput::CHANGE_DEVICE_KEY_MAPPING_REQUEST => return Ok(Request::XinputChangeDeviceKeyMapping(xinput::ChangeDeviceKeyMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
                     xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => return Ok(Request::XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
                     xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => return Ok(Request::XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
                     xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => return Ok(Request::XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
                     xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => return Ok(Request::XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
                     xinput::QUERY_DEVICE_STATE_REQUEST => return Ok(Request::XinputQueryDeviceState(xinput::QueryDeviceStateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
                     xinput::DEVICE_BELL_REQUEST => return Ok(Request::XinputDeviceBell(xinput::DeviceBellRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
                     xinput::SET_DEVICE_VALUATORS_REQUEST => return Ok(Request::XinputSetDeviceValuators(xinput::SetDeviceValuatorsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
                     xinput::GET_DEVICE_CONTROL_REQUEST => return Ok(Request::XinputGetDeviceControl(xinput::GetDeviceControlRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
                     xinput::CHANGE_DEVICE_CONTROL_REQUEST => return Ok(Request::XinputChangeDeviceControl(xinput::ChangeDeviceControlRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
                     xinput::LIST_DEVICE_PROPERTIES_REQUEST => return Ok(Request::XinputListDeviceProperties(xinput::ListDevicePropertiesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
                     xinput::CHANGE_DEVICE_PROPERTY_REQUEST => return Ok(Request::XinputChangeDeviceProperty(xinput::ChangeDevicePropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
                     xinput::DELETE_DEVICE_PROPERTY_REQUEST => return Ok(Request::XinputDeleteDeviceProperty(xinput::DeleteDevicePropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
                     xinput::GET_DEVICE_PROPERTY_REQUEST => return Ok(Request::XinputGetDeviceProperty(xinput::GetDevicePropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
                     xinput::XI_QUERY_POINTER_REQUEST => return Ok(Request::XinputXIQueryPointer(xinput::XIQueryPointerRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
                     xinput::XI_WARP_POINTER_REQUEST => return Ok(Request::XinputXIWarpPointer(xinput::XIWarpPointerRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
                     xinput::XI_CHANGE_CURSOR_REQUEST => return Ok(Request::XinputXIChangeCursor(xinput::XIChangeCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
                     xinput::XI_CHANGE_HIERARCHY_REQUEST => return Ok(Request::XinputXIChangeHierarchy(xinput::XIChangeHierarchyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
                     xinput::XI_SET_CLIENT_POINTER_REQUEST => return Ok(Request::XinputXISetClientPointer(xinput::XISetClientPointerRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
                     xinput::XI_GET_CLIENT_POINTER_REQUEST => return Ok(Request::XinputXIGetClientPointer(xinput::XIGetClientPointerRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
                     xinput::XI_SELECT_EVENTS_REQUEST => return Ok(Request::XinputXISelectEvents(xinput::XISelectEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
                     xinput::XI_QUERY_VERSION_REQUEST => return Ok(Request::XinputXIQueryVersion(xinput::XIQueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
                     xinput::XI_QUERY_DEVICE_REQUEST => return Ok(Request::XinputXIQueryDevice(xinput::XIQueryDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
                     xinput::XI_SET_FOCUS_REQUEST => return Ok(Request::XinputXISetFocus(xinput::XISetFocusRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
                     xinput::XI_GET_FOCUS_REQUEST => return Ok(Request::XinputXIGetFocus(xinput::XIGetFocusRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
                     xinput::XI_GRAB_DEVICE_REQUEST => return Ok(Request::XinputXIGrabDevice(xinput::XIGrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
                     xinput::XI_UNGRAB_DEVICE_REQUEST => return Ok(Request::XinputXIUngrabDevice(xinput::XIUngrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
                     xinput::XI_ALLOW_EVENTS_REQUEST => return Ok(Request::XinputXIAllowEvents(xinput::XIAllowEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
                     xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => return Ok(Request::XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
                     xinput::XI_PASSIVE_UNGRAB_DEVICE_REQUEST => return Ok(Request::XinputXIPassiveUngrabDevice(xinput::XIPassiveUngrabDeviceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
                     xinput::XI_LIST_PROPERTIES_REQUEST => return Ok(Request::XinputXIListProperties(xinput::XIListPropertiesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
                     xinput::XI_CHANGE_PROPERTY_REQUEST => return Ok(Request::XinputXIChangeProperty(xinput::XIChangePropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
                     xinput::XI_DELETE_PROPERTY_REQUEST => return Ok(Request::XinputXIDeleteProperty(xinput::XIDeletePropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
                     xinput::XI_GET_PROPERTY_REQUEST => return Ok(Request::XinputXIGetProperty(xinput::XIGetPropertyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
                     xinput::XI_GET_SELECTED_EVENTS_REQUEST => return Ok(Request::XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
                     xinput::XI_BARRIER_RELEASE_POINTER_REQUEST => return Ok(Request::XinputXIBarrierReleasePointer(xinput::XIBarrierReleasePointerRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
                     xinput::SEND_EXTENSION_EVENT_REQUEST => return Ok(Request::XinputSendExtensionEvent(xinput::SendExtensionEventRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1969,29 +3162,53 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xkb")]
             Some((xkb::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
                     xkb::USE_EXTENSION_REQUEST => return Ok(Request::XkbUseExtension(xkb::UseExtensionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
                     xkb::SELECT_EVENTS_REQUEST => return Ok(Request::XkbSelectEvents(xkb::SelectEventsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
                     xkb::BELL_REQUEST => return Ok(Request::XkbBell(xkb::BellRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
                     xkb::GET_STATE_REQUEST => return Ok(Request::XkbGetState(xkb::GetStateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
                     xkb::LATCH_LOCK_STATE_REQUEST => return Ok(Request::XkbLatchLockState(xkb::LatchLockStateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
                     xkb::GET_CONTROLS_REQUEST => return Ok(Request::XkbGetControls(xkb::GetControlsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
                     xkb::SET_CONTROLS_REQUEST => return Ok(Request::XkbSetControls(xkb::SetControlsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
                     xkb::GET_MAP_REQUEST => return Ok(Request::XkbGetMap(xkb::GetMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
                     xkb::SET_MAP_REQUEST => return Ok(Request::XkbSetMap(xkb::SetMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
                     xkb::GET_COMPAT_MAP_REQUEST => return Ok(Request::XkbGetCompatMap(xkb::GetCompatMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
                     xkb::SET_COMPAT_MAP_REQUEST => return Ok(Request::XkbSetCompatMap(xkb::SetCompatMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
                     xkb::GET_INDICATOR_STATE_REQUEST => return Ok(Request::XkbGetIndicatorState(xkb::GetIndicatorStateRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
                     xkb::GET_INDICATOR_MAP_REQUEST => return Ok(Request::XkbGetIndicatorMap(xkb::GetIndicatorMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
                     xkb::SET_INDICATOR_MAP_REQUEST => return Ok(Request::XkbSetIndicatorMap(xkb::SetIndicatorMapRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
                     xkb::GET_NAMED_INDICATOR_REQUEST => return Ok(Request::XkbGetNamedIndicator(xkb::GetNamedIndicatorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
                     xkb::SET_NAMED_INDICATOR_REQUEST => return Ok(Request::XkbSetNamedIndicator(xkb::SetNamedIndicatorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
                     xkb::GET_NAMES_REQUEST => return Ok(Request::XkbGetNames(xkb::GetNamesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
                     xkb::SET_NAMES_REQUEST => return Ok(Request::XkbSetNames(xkb::SetNamesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
                     xkb::PER_CLIENT_FLAGS_REQUEST => return Ok(Request::XkbPerClientFlags(xkb::PerClientFlagsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
                     xkb::LIST_COMPONENTS_REQUEST => return Ok(Request::XkbListComponents(xkb::ListComponentsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
                     xkb::GET_KBD_BY_NAME_REQUEST => return Ok(Request::XkbGetKbdByName(xkb::GetKbdByNameRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
                     xkb::GET_DEVICE_INFO_REQUEST => return Ok(Request::XkbGetDeviceInfo(xkb::GetDeviceInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
                     xkb::SET_DEVICE_INFO_REQUEST => return Ok(Request::XkbSetDeviceInfo(xkb::SetDeviceInfoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
                     xkb::SET_DEBUGGING_FLAGS_REQUEST => return Ok(Request::XkbSetDebuggingFlags(xkb::SetDebuggingFlagsRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -1999,30 +3216,55 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xprint")]
             Some((xprint::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
                     xprint::PRINT_QUERY_VERSION_REQUEST => return Ok(Request::XprintPrintQueryVersion(xprint::PrintQueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
                     xprint::PRINT_GET_PRINTER_LIST_REQUEST => return Ok(Request::XprintPrintGetPrinterList(xprint::PrintGetPrinterListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintRehashPrinterList"))]
                     xprint::PRINT_REHASH_PRINTER_LIST_REQUEST => return Ok(Request::XprintPrintRehashPrinterList(xprint::PrintRehashPrinterListRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::CreateContext"))]
                     xprint::CREATE_CONTEXT_REQUEST => return Ok(Request::XprintCreateContext(xprint::CreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetContext"))]
                     xprint::PRINT_SET_CONTEXT_REQUEST => return Ok(Request::XprintPrintSetContext(xprint::PrintSetContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
                     xprint::PRINT_GET_CONTEXT_REQUEST => return Ok(Request::XprintPrintGetContext(xprint::PrintGetContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintDestroyContext"))]
                     xprint::PRINT_DESTROY_CONTEXT_REQUEST => return Ok(Request::XprintPrintDestroyContext(xprint::PrintDestroyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
                     xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => return Ok(Request::XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartJob"))]
                     xprint::PRINT_START_JOB_REQUEST => return Ok(Request::XprintPrintStartJob(xprint::PrintStartJobRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndJob"))]
                     xprint::PRINT_END_JOB_REQUEST => return Ok(Request::XprintPrintEndJob(xprint::PrintEndJobRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartDoc"))]
                     xprint::PRINT_START_DOC_REQUEST => return Ok(Request::XprintPrintStartDoc(xprint::PrintStartDocRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndDoc"))]
                     xprint::PRINT_END_DOC_REQUEST => return Ok(Request::XprintPrintEndDoc(xprint::PrintEndDocRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintPutDocumentData"))]
                     xprint::PRINT_PUT_DOCUMENT_DATA_REQUEST => return Ok(Request::XprintPrintPutDocumentData(xprint::PrintPutDocumentDataRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
                     xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => return Ok(Request::XprintPrintGetDocumentData(xprint::PrintGetDocumentDataRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartPage"))]
                     xprint::PRINT_START_PAGE_REQUEST => return Ok(Request::XprintPrintStartPage(xprint::PrintStartPageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndPage"))]
                     xprint::PRINT_END_PAGE_REQUEST => return Ok(Request::XprintPrintEndPage(xprint::PrintEndPageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSelectInput"))]
                     xprint::PRINT_SELECT_INPUT_REQUEST => return Ok(Request::XprintPrintSelectInput(xprint::PrintSelectInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
                     xprint::PRINT_INPUT_SELECTED_REQUEST => return Ok(Request::XprintPrintInputSelected(xprint::PrintInputSelectedRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
                     xprint::PRINT_GET_ATTRIBUTES_REQUEST => return Ok(Request::XprintPrintGetAttributes(xprint::PrintGetAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
                     xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => return Ok(Request::XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetAttributes"))]
                     xprint::PRINT_SET_ATTRIBUTES_REQUEST => return Ok(Request::XprintPrintSetAttributes(xprint::PrintSetAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
                     xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => return Ok(Request::XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
                     xprint::PRINT_QUERY_SCREENS_REQUEST => return Ok(Request::XprintPrintQueryScreens(xprint::PrintQueryScreensRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
                     xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => return Ok(Request::XprintPrintSetImageResolution(xprint::PrintSetImageResolutionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
                     xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => return Ok(Request::XprintPrintGetImageResolution(xprint::PrintGetImageResolutionRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -2030,28 +3272,51 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xselinux")]
             Some((xselinux::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
                     xselinux::QUERY_VERSION_REQUEST => return Ok(Request::XselinuxQueryVersion(xselinux::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceCreateContext"))]
                     xselinux::SET_DEVICE_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetDeviceCreateContext(xselinux::SetDeviceCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
                     xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceContext"))]
                     xselinux::SET_DEVICE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetDeviceContext(xselinux::SetDeviceContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
                     xselinux::GET_DEVICE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetDeviceContext(xselinux::GetDeviceContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetWindowCreateContext"))]
                     xselinux::SET_WINDOW_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetWindowCreateContext(xselinux::SetWindowCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
                     xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
                     xselinux::GET_WINDOW_CONTEXT_REQUEST => return Ok(Request::XselinuxGetWindowContext(xselinux::GetWindowContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyCreateContext"))]
                     xselinux::SET_PROPERTY_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetPropertyCreateContext(xselinux::SetPropertyCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
                     xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyUseContext"))]
                     xselinux::SET_PROPERTY_USE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetPropertyUseContext(xselinux::SetPropertyUseContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
                     xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
                     xselinux::GET_PROPERTY_CONTEXT_REQUEST => return Ok(Request::XselinuxGetPropertyContext(xselinux::GetPropertyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
                     xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => return Ok(Request::XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
                     xselinux::LIST_PROPERTIES_REQUEST => return Ok(Request::XselinuxListProperties(xselinux::ListPropertiesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionCreateContext"))]
                     xselinux::SET_SELECTION_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetSelectionCreateContext(xselinux::SetSelectionCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
                     xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionUseContext"))]
                     xselinux::SET_SELECTION_USE_CONTEXT_REQUEST => return Ok(Request::XselinuxSetSelectionUseContext(xselinux::SetSelectionUseContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
                     xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => return Ok(Request::XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
                     xselinux::GET_SELECTION_CONTEXT_REQUEST => return Ok(Request::XselinuxGetSelectionContext(xselinux::GetSelectionContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
                     xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => return Ok(Request::XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
                     xselinux::LIST_SELECTIONS_REQUEST => return Ok(Request::XselinuxListSelections(xselinux::ListSelectionsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
                     xselinux::GET_CLIENT_CONTEXT_REQUEST => return Ok(Request::XselinuxGetClientContext(xselinux::GetClientContextRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -2059,9 +3324,13 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xtest")]
             Some((xtest::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
                     xtest::GET_VERSION_REQUEST => return Ok(Request::XtestGetVersion(xtest::GetVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
                     xtest::COMPARE_CURSOR_REQUEST => return Ok(Request::XtestCompareCursor(xtest::CompareCursorRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::FakeInput"))]
                     xtest::FAKE_INPUT_REQUEST => return Ok(Request::XtestFakeInput(xtest::FakeInputRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GrabControl"))]
                     xtest::GRAB_CONTROL_REQUEST => return Ok(Request::XtestGrabControl(xtest::GrabControlRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -2069,25 +3338,45 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xv")]
             Some((xv::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
                     xv::QUERY_EXTENSION_REQUEST => return Ok(Request::XvQueryExtension(xv::QueryExtensionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
                     xv::QUERY_ADAPTORS_REQUEST => return Ok(Request::XvQueryAdaptors(xv::QueryAdaptorsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
                     xv::QUERY_ENCODINGS_REQUEST => return Ok(Request::XvQueryEncodings(xv::QueryEncodingsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
                     xv::GRAB_PORT_REQUEST => return Ok(Request::XvGrabPort(xv::GrabPortRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::UngrabPort"))]
                     xv::UNGRAB_PORT_REQUEST => return Ok(Request::XvUngrabPort(xv::UngrabPortRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutVideo"))]
                     xv::PUT_VIDEO_REQUEST => return Ok(Request::XvPutVideo(xv::PutVideoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutStill"))]
                     xv::PUT_STILL_REQUEST => return Ok(Request::XvPutStill(xv::PutStillRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetVideo"))]
                     xv::GET_VIDEO_REQUEST => return Ok(Request::XvGetVideo(xv::GetVideoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetStill"))]
                     xv::GET_STILL_REQUEST => return Ok(Request::XvGetStill(xv::GetStillRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::StopVideo"))]
                     xv::STOP_VIDEO_REQUEST => return Ok(Request::XvStopVideo(xv::StopVideoRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectVideoNotify"))]
                     xv::SELECT_VIDEO_NOTIFY_REQUEST => return Ok(Request::XvSelectVideoNotify(xv::SelectVideoNotifyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectPortNotify"))]
                     xv::SELECT_PORT_NOTIFY_REQUEST => return Ok(Request::XvSelectPortNotify(xv::SelectPortNotifyRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
                     xv::QUERY_BEST_SIZE_REQUEST => return Ok(Request::XvQueryBestSize(xv::QueryBestSizeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SetPortAttribute"))]
                     xv::SET_PORT_ATTRIBUTE_REQUEST => return Ok(Request::XvSetPortAttribute(xv::SetPortAttributeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
                     xv::GET_PORT_ATTRIBUTE_REQUEST => return Ok(Request::XvGetPortAttribute(xv::GetPortAttributeRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
                     xv::QUERY_PORT_ATTRIBUTES_REQUEST => return Ok(Request::XvQueryPortAttributes(xv::QueryPortAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
                     xv::LIST_IMAGE_FORMATS_REQUEST => return Ok(Request::XvListImageFormats(xv::ListImageFormatsRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
                     xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => return Ok(Request::XvQueryImageAttributes(xv::QueryImageAttributesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutImage"))]
                     xv::PUT_IMAGE_REQUEST => return Ok(Request::XvPutImage(xv::PutImageRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ShmPutImage"))]
                     xv::SHM_PUT_IMAGE_REQUEST => return Ok(Request::XvShmPutImage(xv::ShmPutImageRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -2095,14 +3384,23 @@ impl<'input> Request<'input> {
             #[cfg(feature = "xvmc")]
             Some((xvmc::X11_EXTENSION_NAME, _)) => {
                 match header.minor_opcode {
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
                     xvmc::QUERY_VERSION_REQUEST => return Ok(Request::XvmcQueryVersion(xvmc::QueryVersionRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
                     xvmc::LIST_SURFACE_TYPES_REQUEST => return Ok(Request::XvmcListSurfaceTypes(xvmc::ListSurfaceTypesRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
                     xvmc::CREATE_CONTEXT_REQUEST => return Ok(Request::XvmcCreateContext(xvmc::CreateContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroyContext"))]
                     xvmc::DESTROY_CONTEXT_REQUEST => return Ok(Request::XvmcDestroyContext(xvmc::DestroyContextRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
                     xvmc::CREATE_SURFACE_REQUEST => return Ok(Request::XvmcCreateSurface(xvmc::CreateSurfaceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySurface"))]
                     xvmc::DESTROY_SURFACE_REQUEST => return Ok(Request::XvmcDestroySurface(xvmc::DestroySurfaceRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
                     xvmc::CREATE_SUBPICTURE_REQUEST => return Ok(Request::XvmcCreateSubpicture(xvmc::CreateSubpictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySubpicture"))]
                     xvmc::DESTROY_SUBPICTURE_REQUEST => return Ok(Request::XvmcDestroySubpicture(xvmc::DestroySubpictureRequest::try_parse_request(header, remaining)?)),
+                    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
                     xvmc::LIST_SUBPICTURE_TYPES_REQUEST => return Ok(Request::XvmcListSubpictureTypes(xvmc::ListSubpictureTypesRequest::try_parse_request(header, remaining)?)),
                     _ => (),
                 }
@@ -2116,1184 +3414,1828 @@ impl<'input> Request<'input> {
     pub fn reply_parser(&self) -> Option<ReplyParsingFunction> {
         match self {
             Request::Unknown(_, _) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
             Request::CreateWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
             Request::ChangeWindowAttributes(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
             Request::GetWindowAttributes(_) => Some(xproto::GetWindowAttributesRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
             Request::DestroyWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
             Request::DestroySubwindows(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
             Request::ChangeSaveSet(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
             Request::ReparentWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
             Request::MapWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
             Request::MapSubwindows(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
             Request::UnmapWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
             Request::UnmapSubwindows(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
             Request::ConfigureWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
             Request::CirculateWindow(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
             Request::GetGeometry(_) => Some(xproto::GetGeometryRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
             Request::QueryTree(_) => Some(xproto::QueryTreeRequest::parse_reply),
             Request::InternAtom(_) => Some(xproto::InternAtomRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
             Request::GetAtomName(_) => Some(xproto::GetAtomNameRequest::parse_reply),
             Request::ChangeProperty(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
             Request::DeleteProperty(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
             Request::GetProperty(_) => Some(xproto::GetPropertyRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
             Request::ListProperties(_) => Some(xproto::ListPropertiesRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
             Request::SetSelectionOwner(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
             Request::GetSelectionOwner(_) => Some(xproto::GetSelectionOwnerRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
             Request::ConvertSelection(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
             Request::SendEvent(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
             Request::GrabPointer(_) => Some(xproto::GrabPointerRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
             Request::UngrabPointer(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
             Request::GrabButton(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
             Request::UngrabButton(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
             Request::ChangeActivePointerGrab(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
             Request::GrabKeyboard(_) => Some(xproto::GrabKeyboardRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
             Request::UngrabKeyboard(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
             Request::GrabKey(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
             Request::UngrabKey(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
             Request::AllowEvents(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
             Request::GrabServer(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
             Request::UngrabServer(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
             Request::QueryPointer(_) => Some(xproto::QueryPointerRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
             Request::GetMotionEvents(_) => Some(xproto::GetMotionEventsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
             Request::TranslateCoordinates(_) => Some(xproto::TranslateCoordinatesRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
             Request::WarpPointer(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
             Request::SetInputFocus(_) => None,
             Request::GetInputFocus(_) => Some(xproto::GetInputFocusRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
             Request::QueryKeymap(_) => Some(xproto::QueryKeymapRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
             Request::OpenFont(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
             Request::CloseFont(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
             Request::QueryFont(_) => Some(xproto::QueryFontRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
             Request::QueryTextExtents(_) => Some(xproto::QueryTextExtentsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
             Request::ListFonts(_) => Some(xproto::ListFontsRequest::parse_reply),
             Request::ListFontsWithInfo(_) => Some(xproto::ListFontsWithInfoRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
             Request::SetFontPath(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
             Request::GetFontPath(_) => Some(xproto::GetFontPathRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
             Request::CreatePixmap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
             Request::FreePixmap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
             Request::CreateGC(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
             Request::ChangeGC(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
             Request::CopyGC(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
             Request::SetDashes(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
             Request::SetClipRectangles(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
             Request::FreeGC(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
             Request::ClearArea(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
             Request::CopyArea(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
             Request::CopyPlane(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
             Request::PolyPoint(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
             Request::PolyLine(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
             Request::PolySegment(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
             Request::PolyRectangle(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
             Request::PolyArc(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
             Request::FillPoly(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
             Request::PolyFillRectangle(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
             Request::PolyFillArc(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
             Request::PutImage(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
             Request::GetImage(_) => Some(xproto::GetImageRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
             Request::PolyText8(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
             Request::PolyText16(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
             Request::ImageText8(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
             Request::ImageText16(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
             Request::CreateColormap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
             Request::FreeColormap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
             Request::CopyColormapAndFree(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
             Request::InstallColormap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
             Request::UninstallColormap(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
             Request::ListInstalledColormaps(_) => Some(xproto::ListInstalledColormapsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
             Request::AllocColor(_) => Some(xproto::AllocColorRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
             Request::AllocNamedColor(_) => Some(xproto::AllocNamedColorRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
             Request::AllocColorCells(_) => Some(xproto::AllocColorCellsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
             Request::AllocColorPlanes(_) => Some(xproto::AllocColorPlanesRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
             Request::FreeColors(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
             Request::StoreColors(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
             Request::StoreNamedColor(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
             Request::QueryColors(_) => Some(xproto::QueryColorsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
             Request::LookupColor(_) => Some(xproto::LookupColorRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
             Request::CreateCursor(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
             Request::CreateGlyphCursor(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
             Request::FreeCursor(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
             Request::RecolorCursor(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
             Request::QueryBestSize(_) => Some(xproto::QueryBestSizeRequest::parse_reply),
             Request::QueryExtension(_) => Some(xproto::QueryExtensionRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
             Request::ListExtensions(_) => Some(xproto::ListExtensionsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
             Request::ChangeKeyboardMapping(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
             Request::GetKeyboardMapping(_) => Some(xproto::GetKeyboardMappingRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
             Request::ChangeKeyboardControl(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
             Request::GetKeyboardControl(_) => Some(xproto::GetKeyboardControlRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
             Request::Bell(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
             Request::ChangePointerControl(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
             Request::GetPointerControl(_) => Some(xproto::GetPointerControlRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
             Request::SetScreenSaver(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
             Request::GetScreenSaver(_) => Some(xproto::GetScreenSaverRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
             Request::ChangeHosts(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
             Request::ListHosts(_) => Some(xproto::ListHostsRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
             Request::SetAccessControl(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
             Request::SetCloseDownMode(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
             Request::KillClient(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
             Request::RotateProperties(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
             Request::ForceScreenSaver(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
             Request::SetPointerMapping(_) => Some(xproto::SetPointerMappingRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
             Request::GetPointerMapping(_) => Some(xproto::GetPointerMappingRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
             Request::SetModifierMapping(_) => Some(xproto::SetModifierMappingRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
             Request::GetModifierMapping(_) => Some(xproto::GetModifierMappingRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
             Request::NoOperation(_) => None,
             Request::BigreqEnable(_) => Some(bigreq::EnableRequest::parse_reply),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
             Request::CompositeQueryVersion(_) => Some(composite::QueryVersionRequest::parse_reply),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
             Request::CompositeRedirectWindow(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
             Request::CompositeRedirectSubwindows(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
             Request::CompositeUnredirectWindow(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
             Request::CompositeUnredirectSubwindows(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
             Request::CompositeCreateRegionFromBorderClip(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
             Request::CompositeNameWindowPixmap(_) => None,
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
             Request::CompositeGetOverlayWindow(_) => Some(composite::GetOverlayWindowRequest::parse_reply),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
             Request::CompositeReleaseOverlayWindow(_) => None,
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
             Request::DamageQueryVersion(_) => Some(damage::QueryVersionRequest::parse_reply),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
             Request::DamageCreate(_) => None,
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
             Request::DamageDestroy(_) => None,
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
             Request::DamageSubtract(_) => None,
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
             Request::DamageAdd(_) => None,
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
             Request::DpmsGetVersion(_) => Some(dpms::GetVersionRequest::parse_reply),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
             Request::DpmsCapable(_) => Some(dpms::CapableRequest::parse_reply),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
             Request::DpmsGetTimeouts(_) => Some(dpms::GetTimeoutsRequest::parse_reply),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
             Request::DpmsSetTimeouts(_) => None,
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
             Request::DpmsEnable(_) => None,
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
             Request::DpmsDisable(_) => None,
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
             Request::DpmsForceLevel(_) => None,
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
             Request::DpmsInfo(_) => Some(dpms::InfoRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
             Request::Dri2QueryVersion(_) => Some(dri2::QueryVersionRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
             Request::Dri2Connect(_) => Some(dri2::ConnectRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
             Request::Dri2Authenticate(_) => Some(dri2::AuthenticateRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
             Request::Dri2CreateDrawable(_) => None,
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
             Request::Dri2DestroyDrawable(_) => None,
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
             Request::Dri2GetBuffers(_) => Some(dri2::GetBuffersRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
             Request::Dri2CopyRegion(_) => Some(dri2::CopyRegionRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
             Request::Dri2GetBuffersWithFormat(_) => Some(dri2::GetBuffersWithFormatRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
             Request::Dri2SwapBuffers(_) => Some(dri2::SwapBuffersRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
             Request::Dri2GetMSC(_) => Some(dri2::GetMSCRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
             Request::Dri2WaitMSC(_) => Some(dri2::WaitMSCRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
             Request::Dri2WaitSBC(_) => Some(dri2::WaitSBCRequest::parse_reply),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
             Request::Dri2SwapInterval(_) => None,
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
             Request::Dri2GetParam(_) => Some(dri2::GetParamRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
             Request::Dri3QueryVersion(_) => Some(dri3::QueryVersionRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
             Request::Dri3Open(_) => Some(dri3::OpenRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
             Request::Dri3PixmapFromBuffer(_) => None,
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
             Request::Dri3BufferFromPixmap(_) => Some(dri3::BufferFromPixmapRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
             Request::Dri3FenceFromFD(_) => None,
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
             Request::Dri3FDFromFence(_) => Some(dri3::FDFromFenceRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
             Request::Dri3GetSupportedModifiers(_) => Some(dri3::GetSupportedModifiersRequest::parse_reply),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
             Request::Dri3PixmapFromBuffers(_) => None,
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
             Request::Dri3BuffersFromPixmap(_) => Some(dri3::BuffersFromPixmapRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
             Request::GeQueryVersion(_) => Some(ge::QueryVersionRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
             Request::GlxRender(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
             Request::GlxRenderLarge(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
             Request::GlxCreateContext(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
             Request::GlxDestroyContext(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
             Request::GlxMakeCurrent(_) => Some(glx::MakeCurrentRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
             Request::GlxIsDirect(_) => Some(glx::IsDirectRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
             Request::GlxQueryVersion(_) => Some(glx::QueryVersionRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
             Request::GlxWaitGL(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
             Request::GlxWaitX(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
             Request::GlxCopyContext(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
             Request::GlxSwapBuffers(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
             Request::GlxUseXFont(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
             Request::GlxCreateGLXPixmap(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
             Request::GlxGetVisualConfigs(_) => Some(glx::GetVisualConfigsRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
             Request::GlxDestroyGLXPixmap(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
             Request::GlxVendorPrivate(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
             Request::GlxVendorPrivateWithReply(_) => Some(glx::VendorPrivateWithReplyRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
             Request::GlxQueryExtensionsString(_) => Some(glx::QueryExtensionsStringRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
             Request::GlxQueryServerString(_) => Some(glx::QueryServerStringRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
             Request::GlxClientInfo(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
             Request::GlxGetFBConfigs(_) => Some(glx::GetFBConfigsRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
             Request::GlxCreatePixmap(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
             Request::GlxDestroyPixmap(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
             Request::GlxCreateNewContext(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
             Request::GlxQueryContext(_) => Some(glx::QueryContextRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
             Request::GlxMakeContextCurrent(_) => Some(glx::MakeContextCurrentRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
             Request::GlxCreatePbuffer(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
             Request::GlxDestroyPbuffer(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
             Request::GlxGetDrawableAttributes(_) => Some(glx::GetDrawableAttributesRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
             Request::GlxChangeDrawableAttributes(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
             Request::GlxCreateWindow(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
             Request::GlxDeleteWindow(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
             Request::GlxSetClientInfoARB(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
             Request::GlxCreateContextAttribsARB(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
             Request::GlxSetClientInfo2ARB(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
             Request::GlxNewList(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
             Request::GlxEndList(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
             Request::GlxDeleteLists(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
             Request::GlxGenLists(_) => Some(glx::GenListsRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
             Request::GlxFeedbackBuffer(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
             Request::GlxSelectBuffer(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
             Request::GlxRenderMode(_) => Some(glx::RenderModeRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
             Request::GlxFinish(_) => Some(glx::FinishRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
             Request::GlxPixelStoref(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
             Request::GlxPixelStorei(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
             Request::GlxReadPixels(_) => Some(glx::ReadPixelsRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
             Request::GlxGetBooleanv(_) => Some(glx::GetBooleanvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
             Request::GlxGetClipPlane(_) => Some(glx::GetClipPlaneRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
             Request::GlxGetDoublev(_) => Some(glx::GetDoublevRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
             Request::GlxGetError(_) => Some(glx::GetErrorRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
             Request::GlxGetFloatv(_) => Some(glx::GetFloatvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
             Request::GlxGetIntegerv(_) => Some(glx::GetIntegervRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
             Request::GlxGetLightfv(_) => Some(glx::GetLightfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
             Request::GlxGetLightiv(_) => Some(glx::GetLightivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
             Request::GlxGetMapdv(_) => Some(glx::GetMapdvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
             Request::GlxGetMapfv(_) => Some(glx::GetMapfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
             Request::GlxGetMapiv(_) => Some(glx::GetMapivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
             Request::GlxGetMaterialfv(_) => Some(glx::GetMaterialfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
             Request::GlxGetMaterialiv(_) => Some(glx::GetMaterialivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
             Request::GlxGetPixelMapfv(_) => Some(glx::GetPixelMapfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
             Request::GlxGetPixelMapuiv(_) => Some(glx::GetPixelMapuivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
             Request::GlxGetPixelMapusv(_) => Some(glx::GetPixelMapusvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
             Request::GlxGetPolygonStipple(_) => Some(glx::GetPolygonStippleRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
             Request::GlxGetString(_) => Some(glx::GetStringRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
             Request::GlxGetTexEnvfv(_) => Some(glx::GetTexEnvfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
             Request::GlxGetTexEnviv(_) => Some(glx::GetTexEnvivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
             Request::GlxGetTexGendv(_) => Some(glx::GetTexGendvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
             Request::GlxGetTexGenfv(_) => Some(glx::GetTexGenfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
             Request::GlxGetTexGeniv(_) => Some(glx::GetTexGenivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
             Request::GlxGetTexImage(_) => Some(glx::GetTexImageRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
             Request::GlxGetTexParameterfv(_) => Some(glx::GetTexParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
             Request::GlxGetTexParameteriv(_) => Some(glx::GetTexParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
             Request::GlxGetTexLevelParameterfv(_) => Some(glx::GetTexLevelParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
             Request::GlxGetTexLevelParameteriv(_) => Some(glx::GetTexLevelParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
             Request::GlxIsEnabled(_) => Some(glx::IsEnabledRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
             Request::GlxIsList(_) => Some(glx::IsListRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
             Request::GlxFlush(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
             Request::GlxAreTexturesResident(_) => Some(glx::AreTexturesResidentRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
             Request::GlxDeleteTextures(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
             Request::GlxGenTextures(_) => Some(glx::GenTexturesRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
             Request::GlxIsTexture(_) => Some(glx::IsTextureRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
             Request::GlxGetColorTable(_) => Some(glx::GetColorTableRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
             Request::GlxGetColorTableParameterfv(_) => Some(glx::GetColorTableParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
             Request::GlxGetColorTableParameteriv(_) => Some(glx::GetColorTableParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
             Request::GlxGetConvolutionFilter(_) => Some(glx::GetConvolutionFilterRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
             Request::GlxGetConvolutionParameterfv(_) => Some(glx::GetConvolutionParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
             Request::GlxGetConvolutionParameteriv(_) => Some(glx::GetConvolutionParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
             Request::GlxGetSeparableFilter(_) => Some(glx::GetSeparableFilterRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
             Request::GlxGetHistogram(_) => Some(glx::GetHistogramRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
             Request::GlxGetHistogramParameterfv(_) => Some(glx::GetHistogramParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
             Request::GlxGetHistogramParameteriv(_) => Some(glx::GetHistogramParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
             Request::GlxGetMinmax(_) => Some(glx::GetMinmaxRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
             Request::GlxGetMinmaxParameterfv(_) => Some(glx::GetMinmaxParameterfvRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
             Request::GlxGetMinmaxParameteriv(_) => Some(glx::GetMinmaxParameterivRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
             Request::GlxGetCompressedTexImageARB(_) => Some(glx::GetCompressedTexImageARBRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
             Request::GlxDeleteQueriesARB(_) => None,
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
             Request::GlxGenQueriesARB(_) => Some(glx::GenQueriesARBRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
             Request::GlxIsQueryARB(_) => Some(glx::IsQueryARBRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
             Request::GlxGetQueryivARB(_) => Some(glx::GetQueryivARBRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
             Request::GlxGetQueryObjectivARB(_) => Some(glx::GetQueryObjectivARBRequest::parse_reply),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
             Request::GlxGetQueryObjectuivARB(_) => Some(glx::GetQueryObjectuivARBRequest::parse_reply),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
             Request::PresentQueryVersion(_) => Some(present::QueryVersionRequest::parse_reply),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::Pixmap"))]
             Request::PresentPixmap(_) => None,
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::NotifyMSC"))]
             Request::PresentNotifyMSC(_) => None,
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::SelectInput"))]
             Request::PresentSelectInput(_) => None,
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
             Request::PresentQueryCapabilities(_) => Some(present::QueryCapabilitiesRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
             Request::RandrQueryVersion(_) => Some(randr::QueryVersionRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
             Request::RandrSetScreenConfig(_) => Some(randr::SetScreenConfigRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
             Request::RandrSelectInput(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
             Request::RandrGetScreenInfo(_) => Some(randr::GetScreenInfoRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
             Request::RandrGetScreenSizeRange(_) => Some(randr::GetScreenSizeRangeRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
             Request::RandrSetScreenSize(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
             Request::RandrGetScreenResources(_) => Some(randr::GetScreenResourcesRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
             Request::RandrGetOutputInfo(_) => Some(randr::GetOutputInfoRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
             Request::RandrListOutputProperties(_) => Some(randr::ListOutputPropertiesRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
             Request::RandrQueryOutputProperty(_) => Some(randr::QueryOutputPropertyRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
             Request::RandrConfigureOutputProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
             Request::RandrChangeOutputProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
             Request::RandrDeleteOutputProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
             Request::RandrGetOutputProperty(_) => Some(randr::GetOutputPropertyRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
             Request::RandrCreateMode(_) => Some(randr::CreateModeRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
             Request::RandrDestroyMode(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
             Request::RandrAddOutputMode(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
             Request::RandrDeleteOutputMode(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
             Request::RandrGetCrtcInfo(_) => Some(randr::GetCrtcInfoRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
             Request::RandrSetCrtcConfig(_) => Some(randr::SetCrtcConfigRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
             Request::RandrGetCrtcGammaSize(_) => Some(randr::GetCrtcGammaSizeRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
             Request::RandrGetCrtcGamma(_) => Some(randr::GetCrtcGammaRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
             Request::RandrSetCrtcGamma(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
             Request::RandrGetScreenResourcesCurrent(_) => Some(randr::GetScreenResourcesCurrentRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
             Request::RandrSetCrtcTransform(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
             Request::RandrGetCrtcTransform(_) => Some(randr::GetCrtcTransformRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
             Request::RandrGetPanning(_) => Some(randr::GetPanningRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
             Request::RandrSetPanning(_) => Some(randr::SetPanningRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
             Request::RandrSetOutputPrimary(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
             Request::RandrGetOutputPrimary(_) => Some(randr::GetOutputPrimaryRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
             Request::RandrGetProviders(_) => Some(randr::GetProvidersRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
             Request::RandrGetProviderInfo(_) => Some(randr::GetProviderInfoRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
             Request::RandrSetProviderOffloadSink(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
             Request::RandrSetProviderOutputSource(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
             Request::RandrListProviderProperties(_) => Some(randr::ListProviderPropertiesRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
             Request::RandrQueryProviderProperty(_) => Some(randr::QueryProviderPropertyRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
             Request::RandrConfigureProviderProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
             Request::RandrChangeProviderProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
             Request::RandrDeleteProviderProperty(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
             Request::RandrGetProviderProperty(_) => Some(randr::GetProviderPropertyRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
             Request::RandrGetMonitors(_) => Some(randr::GetMonitorsRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
             Request::RandrSetMonitor(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
             Request::RandrDeleteMonitor(_) => None,
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
             Request::RandrCreateLease(_) => Some(randr::CreateLeaseRequest::parse_reply),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
             Request::RandrFreeLease(_) => None,
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
             Request::RecordQueryVersion(_) => Some(record::QueryVersionRequest::parse_reply),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::CreateContext"))]
             Request::RecordCreateContext(_) => None,
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::RegisterClients"))]
             Request::RecordRegisterClients(_) => None,
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::UnregisterClients"))]
             Request::RecordUnregisterClients(_) => None,
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
             Request::RecordGetContext(_) => Some(record::GetContextRequest::parse_reply),
             #[cfg(feature = "record")]
             Request::RecordEnableContext(_) => Some(record::EnableContextRequest::parse_reply),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::DisableContext"))]
             Request::RecordDisableContext(_) => None,
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::FreeContext"))]
             Request::RecordFreeContext(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
             Request::RenderQueryVersion(_) => Some(render::QueryVersionRequest::parse_reply),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
             Request::RenderQueryPictFormats(_) => Some(render::QueryPictFormatsRequest::parse_reply),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
             Request::RenderQueryPictIndexValues(_) => Some(render::QueryPictIndexValuesRequest::parse_reply),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
             Request::RenderCreatePicture(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
             Request::RenderChangePicture(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
             Request::RenderSetPictureClipRectangles(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
             Request::RenderFreePicture(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
             Request::RenderComposite(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
             Request::RenderTrapezoids(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
             Request::RenderTriangles(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
             Request::RenderTriStrip(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
             Request::RenderTriFan(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
             Request::RenderCreateGlyphSet(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
             Request::RenderReferenceGlyphSet(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
             Request::RenderFreeGlyphSet(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
             Request::RenderAddGlyphs(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
             Request::RenderFreeGlyphs(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
             Request::RenderCompositeGlyphs8(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
             Request::RenderCompositeGlyphs16(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
             Request::RenderCompositeGlyphs32(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
             Request::RenderFillRectangles(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
             Request::RenderCreateCursor(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
             Request::RenderSetPictureTransform(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
             Request::RenderQueryFilters(_) => Some(render::QueryFiltersRequest::parse_reply),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
             Request::RenderSetPictureFilter(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
             Request::RenderCreateAnimCursor(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
             Request::RenderAddTraps(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
             Request::RenderCreateSolidFill(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
             Request::RenderCreateLinearGradient(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
             Request::RenderCreateRadialGradient(_) => None,
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
             Request::RenderCreateConicalGradient(_) => None,
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
             Request::ResQueryVersion(_) => Some(res::QueryVersionRequest::parse_reply),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
             Request::ResQueryClients(_) => Some(res::QueryClientsRequest::parse_reply),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
             Request::ResQueryClientResources(_) => Some(res::QueryClientResourcesRequest::parse_reply),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
             Request::ResQueryClientPixmapBytes(_) => Some(res::QueryClientPixmapBytesRequest::parse_reply),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
             Request::ResQueryClientIds(_) => Some(res::QueryClientIdsRequest::parse_reply),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
             Request::ResQueryResourceBytes(_) => Some(res::QueryResourceBytesRequest::parse_reply),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
             Request::ScreensaverQueryVersion(_) => Some(screensaver::QueryVersionRequest::parse_reply),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
             Request::ScreensaverQueryInfo(_) => Some(screensaver::QueryInfoRequest::parse_reply),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SelectInput"))]
             Request::ScreensaverSelectInput(_) => None,
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SetAttributes"))]
             Request::ScreensaverSetAttributes(_) => None,
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::UnsetAttributes"))]
             Request::ScreensaverUnsetAttributes(_) => None,
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::Suspend"))]
             Request::ScreensaverSuspend(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
             Request::ShapeQueryVersion(_) => Some(shape::QueryVersionRequest::parse_reply),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
             Request::ShapeRectangles(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
             Request::ShapeMask(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
             Request::ShapeCombine(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
             Request::ShapeOffset(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
             Request::ShapeQueryExtents(_) => Some(shape::QueryExtentsRequest::parse_reply),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
             Request::ShapeSelectInput(_) => None,
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
             Request::ShapeInputSelected(_) => Some(shape::InputSelectedRequest::parse_reply),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
             Request::ShapeGetRectangles(_) => Some(shape::GetRectanglesRequest::parse_reply),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
             Request::ShmQueryVersion(_) => Some(shm::QueryVersionRequest::parse_reply),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
             Request::ShmAttach(_) => None,
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
             Request::ShmDetach(_) => None,
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
             Request::ShmPutImage(_) => None,
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
             Request::ShmGetImage(_) => Some(shm::GetImageRequest::parse_reply),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
             Request::ShmCreatePixmap(_) => None,
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
             Request::ShmAttachFd(_) => None,
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
             Request::ShmCreateSegment(_) => Some(shm::CreateSegmentRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
             Request::SyncInitialize(_) => Some(sync::InitializeRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
             Request::SyncListSystemCounters(_) => Some(sync::ListSystemCountersRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
             Request::SyncCreateCounter(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
             Request::SyncDestroyCounter(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
             Request::SyncQueryCounter(_) => Some(sync::QueryCounterRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
             Request::SyncAwait(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
             Request::SyncChangeCounter(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
             Request::SyncSetCounter(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
             Request::SyncCreateAlarm(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
             Request::SyncChangeAlarm(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
             Request::SyncDestroyAlarm(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
             Request::SyncQueryAlarm(_) => Some(sync::QueryAlarmRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
             Request::SyncSetPriority(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
             Request::SyncGetPriority(_) => Some(sync::GetPriorityRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
             Request::SyncCreateFence(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
             Request::SyncTriggerFence(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
             Request::SyncResetFence(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
             Request::SyncDestroyFence(_) => None,
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
             Request::SyncQueryFence(_) => Some(sync::QueryFenceRequest::parse_reply),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
             Request::SyncAwaitFence(_) => None,
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
             Request::XcMiscGetVersion(_) => Some(xc_misc::GetVersionRequest::parse_reply),
             Request::XcMiscGetXIDRange(_) => Some(xc_misc::GetXIDRangeRequest::parse_reply),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
             Request::XcMiscGetXIDList(_) => Some(xc_misc::GetXIDListRequest::parse_reply),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
             Request::XevieQueryVersion(_) => Some(xevie::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
             Request::XevieStart(_) => Some(xevie::StartRequest::parse_reply),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
             Request::XevieEnd(_) => Some(xevie::EndRequest::parse_reply),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
             Request::XevieSend(_) => Some(xevie::SendRequest::parse_reply),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
             Request::XevieSelectInput(_) => Some(xevie::SelectInputRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
             Request::Xf86driQueryVersion(_) => Some(xf86dri::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
             Request::Xf86driQueryDirectRenderingCapable(_) => Some(xf86dri::QueryDirectRenderingCapableRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
             Request::Xf86driOpenConnection(_) => Some(xf86dri::OpenConnectionRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CloseConnection"))]
             Request::Xf86driCloseConnection(_) => None,
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
             Request::Xf86driGetClientDriverName(_) => Some(xf86dri::GetClientDriverNameRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
             Request::Xf86driCreateContext(_) => Some(xf86dri::CreateContextRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyContext"))]
             Request::Xf86driDestroyContext(_) => None,
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
             Request::Xf86driCreateDrawable(_) => Some(xf86dri::CreateDrawableRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyDrawable"))]
             Request::Xf86driDestroyDrawable(_) => None,
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
             Request::Xf86driGetDrawableInfo(_) => Some(xf86dri::GetDrawableInfoRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
             Request::Xf86driGetDeviceInfo(_) => Some(xf86dri::GetDeviceInfoRequest::parse_reply),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
             Request::Xf86driAuthConnection(_) => Some(xf86dri::AuthConnectionRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
             Request::Xf86vidmodeQueryVersion(_) => Some(xf86vidmode::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
             Request::Xf86vidmodeGetModeLine(_) => Some(xf86vidmode::GetModeLineRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
             Request::Xf86vidmodeModModeLine(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
             Request::Xf86vidmodeSwitchMode(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
             Request::Xf86vidmodeGetMonitor(_) => Some(xf86vidmode::GetMonitorRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
             Request::Xf86vidmodeLockModeSwitch(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
             Request::Xf86vidmodeGetAllModeLines(_) => Some(xf86vidmode::GetAllModeLinesRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
             Request::Xf86vidmodeAddModeLine(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
             Request::Xf86vidmodeDeleteModeLine(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
             Request::Xf86vidmodeValidateModeLine(_) => Some(xf86vidmode::ValidateModeLineRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
             Request::Xf86vidmodeSwitchToMode(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
             Request::Xf86vidmodeGetViewPort(_) => Some(xf86vidmode::GetViewPortRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
             Request::Xf86vidmodeSetViewPort(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
             Request::Xf86vidmodeGetDotClocks(_) => Some(xf86vidmode::GetDotClocksRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
             Request::Xf86vidmodeSetClientVersion(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
             Request::Xf86vidmodeSetGamma(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
             Request::Xf86vidmodeGetGamma(_) => Some(xf86vidmode::GetGammaRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
             Request::Xf86vidmodeGetGammaRamp(_) => Some(xf86vidmode::GetGammaRampRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
             Request::Xf86vidmodeSetGammaRamp(_) => None,
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
             Request::Xf86vidmodeGetGammaRampSize(_) => Some(xf86vidmode::GetGammaRampSizeRequest::parse_reply),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
             Request::Xf86vidmodeGetPermissions(_) => Some(xf86vidmode::GetPermissionsRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
             Request::XfixesQueryVersion(_) => Some(xfixes::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeSaveSet"))]
             Request::XfixesChangeSaveSet(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectSelectionInput"))]
             Request::XfixesSelectSelectionInput(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectCursorInput"))]
             Request::XfixesSelectCursorInput(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
             Request::XfixesGetCursorImage(_) => Some(xfixes::GetCursorImageRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegion"))]
             Request::XfixesCreateRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromBitmap"))]
             Request::XfixesCreateRegionFromBitmap(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromWindow"))]
             Request::XfixesCreateRegionFromWindow(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromGC"))]
             Request::XfixesCreateRegionFromGC(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromPicture"))]
             Request::XfixesCreateRegionFromPicture(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DestroyRegion"))]
             Request::XfixesDestroyRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetRegion"))]
             Request::XfixesSetRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CopyRegion"))]
             Request::XfixesCopyRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::UnionRegion"))]
             Request::XfixesUnionRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::IntersectRegion"))]
             Request::XfixesIntersectRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SubtractRegion"))]
             Request::XfixesSubtractRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::InvertRegion"))]
             Request::XfixesInvertRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::TranslateRegion"))]
             Request::XfixesTranslateRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::RegionExtents"))]
             Request::XfixesRegionExtents(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
             Request::XfixesFetchRegion(_) => Some(xfixes::FetchRegionRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetGCClipRegion"))]
             Request::XfixesSetGCClipRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetWindowShapeRegion"))]
             Request::XfixesSetWindowShapeRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetPictureClipRegion"))]
             Request::XfixesSetPictureClipRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetCursorName"))]
             Request::XfixesSetCursorName(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
             Request::XfixesGetCursorName(_) => Some(xfixes::GetCursorNameRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
             Request::XfixesGetCursorImageAndName(_) => Some(xfixes::GetCursorImageAndNameRequest::parse_reply),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursor"))]
             Request::XfixesChangeCursor(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursorByName"))]
             Request::XfixesChangeCursorByName(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ExpandRegion"))]
             Request::XfixesExpandRegion(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::HideCursor"))]
             Request::XfixesHideCursor(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ShowCursor"))]
             Request::XfixesShowCursor(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreatePointerBarrier"))]
             Request::XfixesCreatePointerBarrier(_) => None,
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DeletePointerBarrier"))]
             Request::XfixesDeletePointerBarrier(_) => None,
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
             Request::XineramaQueryVersion(_) => Some(xinerama::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
             Request::XineramaGetState(_) => Some(xinerama::GetStateRequest::parse_reply),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
             Request::XineramaGetScreenCount(_) => Some(xinerama::GetScreenCountRequest::parse_reply),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
             Request::XineramaGetScreenSize(_) => Some(xinerama::GetScreenSizeRequest::parse_reply),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
             Request::XineramaIsActive(_) => Some(xinerama::IsActiveRequest::parse_reply),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
             Request::XineramaQueryScreens(_) => Some(xinerama::QueryScreensRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
             Request::XinputGetExtensionVersion(_) => Some(xinput::GetExtensionVersionRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
             Request::XinputListInputDevices(_) => Some(xinput::ListInputDevicesRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
             Request::XinputOpenDevice(_) => Some(xinput::OpenDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
             Request::XinputCloseDevice(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
             Request::XinputSetDeviceMode(_) => Some(xinput::SetDeviceModeRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
             Request::XinputSelectExtensionEvent(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
             Request::XinputGetSelectedExtensionEvents(_) => Some(xinput::GetSelectedExtensionEventsRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
             Request::XinputChangeDeviceDontPropagateList(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
             Request::XinputGetDeviceDontPropagateList(_) => Some(xinput::GetDeviceDontPropagateListRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
             Request::XinputGetDeviceMotionEvents(_) => Some(xinput::GetDeviceMotionEventsRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
             Request::XinputChangeKeyboardDevice(_) => Some(xinput::ChangeKeyboardDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
             Request::XinputChangePointerDevice(_) => Some(xinput::ChangePointerDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
             Request::XinputGrabDevice(_) => Some(xinput::GrabDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
             Request::XinputUngrabDevice(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
             Request::XinputGrabDeviceKey(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
             Request::XinputUngrabDeviceKey(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
             Request::XinputGrabDeviceButton(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
             Request::XinputUngrabDeviceButton(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
             Request::XinputAllowDeviceEvents(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
             Request::XinputGetDeviceFocus(_) => Some(xinput::GetDeviceFocusRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
             Request::XinputSetDeviceFocus(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
             Request::XinputGetFeedbackControl(_) => Some(xinput::GetFeedbackControlRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
             Request::XinputChangeFeedbackControl(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
             Request::XinputGetDeviceKeyMapping(_) => Some(xinput::GetDeviceKeyMappingRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
             Request::XinputChangeDeviceKeyMapping(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
             Request::XinputGetDeviceModifierMapping(_) => Some(xinput::GetDeviceModifierMappingRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
             Request::XinputSetDeviceModifierMapping(_) => Some(xinput::SetDeviceModifierMappingRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
             Request::XinputGetDeviceButtonMapping(_) => Some(xinput::GetDeviceButtonMappingRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
             Request::XinputSetDeviceButtonMapping(_) => Some(xinput::SetDeviceButtonMappingRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
             Request::XinputQueryDeviceState(_) => Some(xinput::QueryDeviceStateRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
             Request::XinputDeviceBell(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
             Request::XinputSetDeviceValuators(_) => Some(xinput::SetDeviceValuatorsRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
             Request::XinputGetDeviceControl(_) => Some(xinput::GetDeviceControlRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
             Request::XinputChangeDeviceControl(_) => Some(xinput::ChangeDeviceControlRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
             Request::XinputListDeviceProperties(_) => Some(xinput::ListDevicePropertiesRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
             Request::XinputChangeDeviceProperty(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
             Request::XinputDeleteDeviceProperty(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
             Request::XinputGetDeviceProperty(_) => Some(xinput::GetDevicePropertyRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
             Request::XinputXIQueryPointer(_) => Some(xinput::XIQueryPointerRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
             Request::XinputXIWarpPointer(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
             Request::XinputXIChangeCursor(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
             Request::XinputXIChangeHierarchy(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
             Request::XinputXISetClientPointer(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
             Request::XinputXIGetClientPointer(_) => Some(xinput::XIGetClientPointerRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
             Request::XinputXISelectEvents(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
             Request::XinputXIQueryVersion(_) => Some(xinput::XIQueryVersionRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
             Request::XinputXIQueryDevice(_) => Some(xinput::XIQueryDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
             Request::XinputXISetFocus(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
             Request::XinputXIGetFocus(_) => Some(xinput::XIGetFocusRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
             Request::XinputXIGrabDevice(_) => Some(xinput::XIGrabDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
             Request::XinputXIUngrabDevice(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
             Request::XinputXIAllowEvents(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
             Request::XinputXIPassiveGrabDevice(_) => Some(xinput::XIPassiveGrabDeviceRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
             Request::XinputXIPassiveUngrabDevice(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
             Request::XinputXIListProperties(_) => Some(xinput::XIListPropertiesRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
             Request::XinputXIChangeProperty(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
             Request::XinputXIDeleteProperty(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
             Request::XinputXIGetProperty(_) => Some(xinput::XIGetPropertyRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
             Request::XinputXIGetSelectedEvents(_) => Some(xinput::XIGetSelectedEventsRequest::parse_reply),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
             Request::XinputXIBarrierReleasePointer(_) => None,
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
             Request::XinputSendExtensionEvent(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
             Request::XkbUseExtension(_) => Some(xkb::UseExtensionRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
             Request::XkbSelectEvents(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
             Request::XkbBell(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
             Request::XkbGetState(_) => Some(xkb::GetStateRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
             Request::XkbLatchLockState(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
             Request::XkbGetControls(_) => Some(xkb::GetControlsRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
             Request::XkbSetControls(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
             Request::XkbGetMap(_) => Some(xkb::GetMapRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
             Request::XkbSetMap(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
             Request::XkbGetCompatMap(_) => Some(xkb::GetCompatMapRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
             Request::XkbSetCompatMap(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
             Request::XkbGetIndicatorState(_) => Some(xkb::GetIndicatorStateRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
             Request::XkbGetIndicatorMap(_) => Some(xkb::GetIndicatorMapRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
             Request::XkbSetIndicatorMap(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
             Request::XkbGetNamedIndicator(_) => Some(xkb::GetNamedIndicatorRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
             Request::XkbSetNamedIndicator(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
             Request::XkbGetNames(_) => Some(xkb::GetNamesRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
             Request::XkbSetNames(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
             Request::XkbPerClientFlags(_) => Some(xkb::PerClientFlagsRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
             Request::XkbListComponents(_) => Some(xkb::ListComponentsRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
             Request::XkbGetKbdByName(_) => Some(xkb::GetKbdByNameRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
             Request::XkbGetDeviceInfo(_) => Some(xkb::GetDeviceInfoRequest::parse_reply),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
             Request::XkbSetDeviceInfo(_) => None,
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
             Request::XkbSetDebuggingFlags(_) => Some(xkb::SetDebuggingFlagsRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
             Request::XprintPrintQueryVersion(_) => Some(xprint::PrintQueryVersionRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
             Request::XprintPrintGetPrinterList(_) => Some(xprint::PrintGetPrinterListRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintRehashPrinterList"))]
             Request::XprintPrintRehashPrinterList(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::CreateContext"))]
             Request::XprintCreateContext(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetContext"))]
             Request::XprintPrintSetContext(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
             Request::XprintPrintGetContext(_) => Some(xprint::PrintGetContextRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintDestroyContext"))]
             Request::XprintPrintDestroyContext(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
             Request::XprintPrintGetScreenOfContext(_) => Some(xprint::PrintGetScreenOfContextRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartJob"))]
             Request::XprintPrintStartJob(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndJob"))]
             Request::XprintPrintEndJob(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartDoc"))]
             Request::XprintPrintStartDoc(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndDoc"))]
             Request::XprintPrintEndDoc(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintPutDocumentData"))]
             Request::XprintPrintPutDocumentData(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
             Request::XprintPrintGetDocumentData(_) => Some(xprint::PrintGetDocumentDataRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartPage"))]
             Request::XprintPrintStartPage(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndPage"))]
             Request::XprintPrintEndPage(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSelectInput"))]
             Request::XprintPrintSelectInput(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
             Request::XprintPrintInputSelected(_) => Some(xprint::PrintInputSelectedRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
             Request::XprintPrintGetAttributes(_) => Some(xprint::PrintGetAttributesRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
             Request::XprintPrintGetOneAttributes(_) => Some(xprint::PrintGetOneAttributesRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetAttributes"))]
             Request::XprintPrintSetAttributes(_) => None,
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
             Request::XprintPrintGetPageDimensions(_) => Some(xprint::PrintGetPageDimensionsRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
             Request::XprintPrintQueryScreens(_) => Some(xprint::PrintQueryScreensRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
             Request::XprintPrintSetImageResolution(_) => Some(xprint::PrintSetImageResolutionRequest::parse_reply),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
             Request::XprintPrintGetImageResolution(_) => Some(xprint::PrintGetImageResolutionRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
             Request::XselinuxQueryVersion(_) => Some(xselinux::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceCreateContext"))]
             Request::XselinuxSetDeviceCreateContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
             Request::XselinuxGetDeviceCreateContext(_) => Some(xselinux::GetDeviceCreateContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceContext"))]
             Request::XselinuxSetDeviceContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
             Request::XselinuxGetDeviceContext(_) => Some(xselinux::GetDeviceContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetWindowCreateContext"))]
             Request::XselinuxSetWindowCreateContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
             Request::XselinuxGetWindowCreateContext(_) => Some(xselinux::GetWindowCreateContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
             Request::XselinuxGetWindowContext(_) => Some(xselinux::GetWindowContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyCreateContext"))]
             Request::XselinuxSetPropertyCreateContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
             Request::XselinuxGetPropertyCreateContext(_) => Some(xselinux::GetPropertyCreateContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyUseContext"))]
             Request::XselinuxSetPropertyUseContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
             Request::XselinuxGetPropertyUseContext(_) => Some(xselinux::GetPropertyUseContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
             Request::XselinuxGetPropertyContext(_) => Some(xselinux::GetPropertyContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
             Request::XselinuxGetPropertyDataContext(_) => Some(xselinux::GetPropertyDataContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
             Request::XselinuxListProperties(_) => Some(xselinux::ListPropertiesRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionCreateContext"))]
             Request::XselinuxSetSelectionCreateContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
             Request::XselinuxGetSelectionCreateContext(_) => Some(xselinux::GetSelectionCreateContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionUseContext"))]
             Request::XselinuxSetSelectionUseContext(_) => None,
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
             Request::XselinuxGetSelectionUseContext(_) => Some(xselinux::GetSelectionUseContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
             Request::XselinuxGetSelectionContext(_) => Some(xselinux::GetSelectionContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
             Request::XselinuxGetSelectionDataContext(_) => Some(xselinux::GetSelectionDataContextRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
             Request::XselinuxListSelections(_) => Some(xselinux::ListSelectionsRequest::parse_reply),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
             Request::XselinuxGetClientContext(_) => Some(xselinux::GetClientContextRequest::parse_reply),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
             Request::XtestGetVersion(_) => Some(xtest::GetVersionRequest::parse_reply),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
             Request::XtestCompareCursor(_) => Some(xtest::CompareCursorRequest::parse_reply),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::FakeInput"))]
             Request::XtestFakeInput(_) => None,
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GrabControl"))]
             Request::XtestGrabControl(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
             Request::XvQueryExtension(_) => Some(xv::QueryExtensionRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
             Request::XvQueryAdaptors(_) => Some(xv::QueryAdaptorsRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
             Request::XvQueryEncodings(_) => Some(xv::QueryEncodingsRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
             Request::XvGrabPort(_) => Some(xv::GrabPortRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::UngrabPort"))]
             Request::XvUngrabPort(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutVideo"))]
             Request::XvPutVideo(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutStill"))]
             Request::XvPutStill(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetVideo"))]
             Request::XvGetVideo(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetStill"))]
             Request::XvGetStill(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::StopVideo"))]
             Request::XvStopVideo(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectVideoNotify"))]
             Request::XvSelectVideoNotify(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectPortNotify"))]
             Request::XvSelectPortNotify(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
             Request::XvQueryBestSize(_) => Some(xv::QueryBestSizeRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SetPortAttribute"))]
             Request::XvSetPortAttribute(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
             Request::XvGetPortAttribute(_) => Some(xv::GetPortAttributeRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
             Request::XvQueryPortAttributes(_) => Some(xv::QueryPortAttributesRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
             Request::XvListImageFormats(_) => Some(xv::ListImageFormatsRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
             Request::XvQueryImageAttributes(_) => Some(xv::QueryImageAttributesRequest::parse_reply),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutImage"))]
             Request::XvPutImage(_) => None,
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ShmPutImage"))]
             Request::XvShmPutImage(_) => None,
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
             Request::XvmcQueryVersion(_) => Some(xvmc::QueryVersionRequest::parse_reply),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
             Request::XvmcListSurfaceTypes(_) => Some(xvmc::ListSurfaceTypesRequest::parse_reply),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
             Request::XvmcCreateContext(_) => Some(xvmc::CreateContextRequest::parse_reply),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroyContext"))]
             Request::XvmcDestroyContext(_) => None,
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
             Request::XvmcCreateSurface(_) => Some(xvmc::CreateSurfaceRequest::parse_reply),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySurface"))]
             Request::XvmcDestroySurface(_) => None,
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
             Request::XvmcCreateSubpicture(_) => Some(xvmc::CreateSubpictureRequest::parse_reply),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySubpicture"))]
             Request::XvmcDestroySubpicture(_) => None,
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
             Request::XvmcListSubpictureTypes(_) => Some(xvmc::ListSubpictureTypesRequest::parse_reply),
         }
     }
@@ -3301,1789 +5243,3537 @@ impl<'input> Request<'input> {
     pub fn into_owned(self) -> Request<'static> {
         match self {
             Request::Unknown(header, body) => Request::Unknown(header, Cow::Owned(body.into_owned())),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
             Request::CreateWindow(req) => Request::CreateWindow(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
             Request::ChangeWindowAttributes(req) => Request::ChangeWindowAttributes(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
             Request::GetWindowAttributes(req) => Request::GetWindowAttributes(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
             Request::DestroyWindow(req) => Request::DestroyWindow(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
             Request::DestroySubwindows(req) => Request::DestroySubwindows(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
             Request::ChangeSaveSet(req) => Request::ChangeSaveSet(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
             Request::ReparentWindow(req) => Request::ReparentWindow(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
             Request::MapWindow(req) => Request::MapWindow(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
             Request::MapSubwindows(req) => Request::MapSubwindows(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
             Request::UnmapWindow(req) => Request::UnmapWindow(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
             Request::UnmapSubwindows(req) => Request::UnmapSubwindows(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
             Request::ConfigureWindow(req) => Request::ConfigureWindow(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
             Request::CirculateWindow(req) => Request::CirculateWindow(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
             Request::GetGeometry(req) => Request::GetGeometry(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
             Request::QueryTree(req) => Request::QueryTree(req),
             Request::InternAtom(req) => Request::InternAtom(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
             Request::GetAtomName(req) => Request::GetAtomName(req),
             Request::ChangeProperty(req) => Request::ChangeProperty(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
             Request::DeleteProperty(req) => Request::DeleteProperty(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
             Request::GetProperty(req) => Request::GetProperty(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
             Request::ListProperties(req) => Request::ListProperties(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
             Request::SetSelectionOwner(req) => Request::SetSelectionOwner(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
             Request::GetSelectionOwner(req) => Request::GetSelectionOwner(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
             Request::ConvertSelection(req) => Request::ConvertSelection(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
             Request::SendEvent(req) => Request::SendEvent(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
             Request::GrabPointer(req) => Request::GrabPointer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
             Request::UngrabPointer(req) => Request::UngrabPointer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
             Request::GrabButton(req) => Request::GrabButton(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
             Request::UngrabButton(req) => Request::UngrabButton(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
             Request::ChangeActivePointerGrab(req) => Request::ChangeActivePointerGrab(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
             Request::GrabKeyboard(req) => Request::GrabKeyboard(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
             Request::UngrabKeyboard(req) => Request::UngrabKeyboard(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
             Request::GrabKey(req) => Request::GrabKey(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
             Request::UngrabKey(req) => Request::UngrabKey(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
             Request::AllowEvents(req) => Request::AllowEvents(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
             Request::GrabServer(req) => Request::GrabServer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
             Request::UngrabServer(req) => Request::UngrabServer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
             Request::QueryPointer(req) => Request::QueryPointer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
             Request::GetMotionEvents(req) => Request::GetMotionEvents(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
             Request::TranslateCoordinates(req) => Request::TranslateCoordinates(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
             Request::WarpPointer(req) => Request::WarpPointer(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
             Request::SetInputFocus(req) => Request::SetInputFocus(req),
             Request::GetInputFocus(req) => Request::GetInputFocus(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
             Request::QueryKeymap(req) => Request::QueryKeymap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
             Request::OpenFont(req) => Request::OpenFont(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
             Request::CloseFont(req) => Request::CloseFont(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
             Request::QueryFont(req) => Request::QueryFont(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
             Request::QueryTextExtents(req) => Request::QueryTextExtents(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
             Request::ListFonts(req) => Request::ListFonts(req.into_owned()),
             Request::ListFontsWithInfo(req) => Request::ListFontsWithInfo(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
             Request::SetFontPath(req) => Request::SetFontPath(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
             Request::GetFontPath(req) => Request::GetFontPath(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
             Request::CreatePixmap(req) => Request::CreatePixmap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
             Request::FreePixmap(req) => Request::FreePixmap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
             Request::CreateGC(req) => Request::CreateGC(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
             Request::ChangeGC(req) => Request::ChangeGC(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
             Request::CopyGC(req) => Request::CopyGC(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
             Request::SetDashes(req) => Request::SetDashes(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
             Request::SetClipRectangles(req) => Request::SetClipRectangles(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
             Request::FreeGC(req) => Request::FreeGC(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
             Request::ClearArea(req) => Request::ClearArea(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
             Request::CopyArea(req) => Request::CopyArea(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
             Request::CopyPlane(req) => Request::CopyPlane(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
             Request::PolyPoint(req) => Request::PolyPoint(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
             Request::PolyLine(req) => Request::PolyLine(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
             Request::PolySegment(req) => Request::PolySegment(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
             Request::PolyRectangle(req) => Request::PolyRectangle(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
             Request::PolyArc(req) => Request::PolyArc(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
             Request::FillPoly(req) => Request::FillPoly(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
             Request::PolyFillRectangle(req) => Request::PolyFillRectangle(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
             Request::PolyFillArc(req) => Request::PolyFillArc(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
             Request::PutImage(req) => Request::PutImage(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
             Request::GetImage(req) => Request::GetImage(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
             Request::PolyText8(req) => Request::PolyText8(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
             Request::PolyText16(req) => Request::PolyText16(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
             Request::ImageText8(req) => Request::ImageText8(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
             Request::ImageText16(req) => Request::ImageText16(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
             Request::CreateColormap(req) => Request::CreateColormap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
             Request::FreeColormap(req) => Request::FreeColormap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
             Request::CopyColormapAndFree(req) => Request::CopyColormapAndFree(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
             Request::InstallColormap(req) => Request::InstallColormap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
             Request::UninstallColormap(req) => Request::UninstallColormap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
             Request::ListInstalledColormaps(req) => Request::ListInstalledColormaps(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
             Request::AllocColor(req) => Request::AllocColor(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
             Request::AllocNamedColor(req) => Request::AllocNamedColor(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
             Request::AllocColorCells(req) => Request::AllocColorCells(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
             Request::AllocColorPlanes(req) => Request::AllocColorPlanes(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
             Request::FreeColors(req) => Request::FreeColors(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
             Request::StoreColors(req) => Request::StoreColors(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
             Request::StoreNamedColor(req) => Request::StoreNamedColor(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
             Request::QueryColors(req) => Request::QueryColors(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
             Request::LookupColor(req) => Request::LookupColor(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
             Request::CreateCursor(req) => Request::CreateCursor(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
             Request::CreateGlyphCursor(req) => Request::CreateGlyphCursor(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
             Request::FreeCursor(req) => Request::FreeCursor(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
             Request::RecolorCursor(req) => Request::RecolorCursor(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
             Request::QueryBestSize(req) => Request::QueryBestSize(req),
             Request::QueryExtension(req) => Request::QueryExtension(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
             Request::ListExtensions(req) => Request::ListExtensions(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
             Request::ChangeKeyboardMapping(req) => Request::ChangeKeyboardMapping(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
             Request::GetKeyboardMapping(req) => Request::GetKeyboardMapping(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
             Request::ChangeKeyboardControl(req) => Request::ChangeKeyboardControl(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
             Request::GetKeyboardControl(req) => Request::GetKeyboardControl(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
             Request::Bell(req) => Request::Bell(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
             Request::ChangePointerControl(req) => Request::ChangePointerControl(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
             Request::GetPointerControl(req) => Request::GetPointerControl(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
             Request::SetScreenSaver(req) => Request::SetScreenSaver(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
             Request::GetScreenSaver(req) => Request::GetScreenSaver(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
             Request::ChangeHosts(req) => Request::ChangeHosts(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
             Request::ListHosts(req) => Request::ListHosts(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
             Request::SetAccessControl(req) => Request::SetAccessControl(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
             Request::SetCloseDownMode(req) => Request::SetCloseDownMode(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
             Request::KillClient(req) => Request::KillClient(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
             Request::RotateProperties(req) => Request::RotateProperties(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
             Request::ForceScreenSaver(req) => Request::ForceScreenSaver(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
             Request::SetPointerMapping(req) => Request::SetPointerMapping(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
             Request::GetPointerMapping(req) => Request::GetPointerMapping(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
             Request::SetModifierMapping(req) => Request::SetModifierMapping(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
             Request::GetModifierMapping(req) => Request::GetModifierMapping(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
             Request::NoOperation(req) => Request::NoOperation(req),
             Request::BigreqEnable(req) => Request::BigreqEnable(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
             Request::CompositeQueryVersion(req) => Request::CompositeQueryVersion(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectWindow"))]
             Request::CompositeRedirectWindow(req) => Request::CompositeRedirectWindow(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::RedirectSubwindows"))]
             Request::CompositeRedirectSubwindows(req) => Request::CompositeRedirectSubwindows(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectWindow"))]
             Request::CompositeUnredirectWindow(req) => Request::CompositeUnredirectWindow(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::UnredirectSubwindows"))]
             Request::CompositeUnredirectSubwindows(req) => Request::CompositeUnredirectSubwindows(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::CreateRegionFromBorderClip"))]
             Request::CompositeCreateRegionFromBorderClip(req) => Request::CompositeCreateRegionFromBorderClip(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::NameWindowPixmap"))]
             Request::CompositeNameWindowPixmap(req) => Request::CompositeNameWindowPixmap(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
             Request::CompositeGetOverlayWindow(req) => Request::CompositeGetOverlayWindow(req),
             #[cfg(feature = "composite")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::ReleaseOverlayWindow"))]
             Request::CompositeReleaseOverlayWindow(req) => Request::CompositeReleaseOverlayWindow(req),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
             Request::DamageQueryVersion(req) => Request::DamageQueryVersion(req),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Create"))]
             Request::DamageCreate(req) => Request::DamageCreate(req),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Destroy"))]
             Request::DamageDestroy(req) => Request::DamageDestroy(req),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Subtract"))]
             Request::DamageSubtract(req) => Request::DamageSubtract(req),
             #[cfg(feature = "damage")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::Add"))]
             Request::DamageAdd(req) => Request::DamageAdd(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
             Request::DpmsGetVersion(req) => Request::DpmsGetVersion(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
             Request::DpmsCapable(req) => Request::DpmsCapable(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
             Request::DpmsGetTimeouts(req) => Request::DpmsGetTimeouts(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::SetTimeouts"))]
             Request::DpmsSetTimeouts(req) => Request::DpmsSetTimeouts(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Enable"))]
             Request::DpmsEnable(req) => Request::DpmsEnable(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Disable"))]
             Request::DpmsDisable(req) => Request::DpmsDisable(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::ForceLevel"))]
             Request::DpmsForceLevel(req) => Request::DpmsForceLevel(req),
             #[cfg(feature = "dpms")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
             Request::DpmsInfo(req) => Request::DpmsInfo(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
             Request::Dri2QueryVersion(req) => Request::Dri2QueryVersion(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
             Request::Dri2Connect(req) => Request::Dri2Connect(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
             Request::Dri2Authenticate(req) => Request::Dri2Authenticate(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CreateDrawable"))]
             Request::Dri2CreateDrawable(req) => Request::Dri2CreateDrawable(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::DestroyDrawable"))]
             Request::Dri2DestroyDrawable(req) => Request::Dri2DestroyDrawable(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
             Request::Dri2GetBuffers(req) => Request::Dri2GetBuffers(req.into_owned()),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
             Request::Dri2CopyRegion(req) => Request::Dri2CopyRegion(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
             Request::Dri2GetBuffersWithFormat(req) => Request::Dri2GetBuffersWithFormat(req.into_owned()),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
             Request::Dri2SwapBuffers(req) => Request::Dri2SwapBuffers(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
             Request::Dri2GetMSC(req) => Request::Dri2GetMSC(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
             Request::Dri2WaitMSC(req) => Request::Dri2WaitMSC(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
             Request::Dri2WaitSBC(req) => Request::Dri2WaitSBC(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapInterval"))]
             Request::Dri2SwapInterval(req) => Request::Dri2SwapInterval(req),
             #[cfg(feature = "dri2")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
             Request::Dri2GetParam(req) => Request::Dri2GetParam(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
             Request::Dri3QueryVersion(req) => Request::Dri3QueryVersion(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
             Request::Dri3Open(req) => Request::Dri3Open(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffer"))]
             Request::Dri3PixmapFromBuffer(req) => Request::Dri3PixmapFromBuffer(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
             Request::Dri3BufferFromPixmap(req) => Request::Dri3BufferFromPixmap(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FenceFromFD"))]
             Request::Dri3FenceFromFD(req) => Request::Dri3FenceFromFD(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
             Request::Dri3FDFromFence(req) => Request::Dri3FDFromFence(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
             Request::Dri3GetSupportedModifiers(req) => Request::Dri3GetSupportedModifiers(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::PixmapFromBuffers"))]
             Request::Dri3PixmapFromBuffers(req) => Request::Dri3PixmapFromBuffers(req),
             #[cfg(feature = "dri3")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
             Request::Dri3BuffersFromPixmap(req) => Request::Dri3BuffersFromPixmap(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
             Request::GeQueryVersion(req) => Request::GeQueryVersion(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Render"))]
             Request::GlxRender(req) => Request::GlxRender(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderLarge"))]
             Request::GlxRenderLarge(req) => Request::GlxRenderLarge(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContext"))]
             Request::GlxCreateContext(req) => Request::GlxCreateContext(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyContext"))]
             Request::GlxDestroyContext(req) => Request::GlxDestroyContext(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
             Request::GlxMakeCurrent(req) => Request::GlxMakeCurrent(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
             Request::GlxIsDirect(req) => Request::GlxIsDirect(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
             Request::GlxQueryVersion(req) => Request::GlxQueryVersion(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitGL"))]
             Request::GlxWaitGL(req) => Request::GlxWaitGL(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::WaitX"))]
             Request::GlxWaitX(req) => Request::GlxWaitX(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CopyContext"))]
             Request::GlxCopyContext(req) => Request::GlxCopyContext(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SwapBuffers"))]
             Request::GlxSwapBuffers(req) => Request::GlxSwapBuffers(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::UseXFont"))]
             Request::GlxUseXFont(req) => Request::GlxUseXFont(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateGLXPixmap"))]
             Request::GlxCreateGLXPixmap(req) => Request::GlxCreateGLXPixmap(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
             Request::GlxGetVisualConfigs(req) => Request::GlxGetVisualConfigs(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyGLXPixmap"))]
             Request::GlxDestroyGLXPixmap(req) => Request::GlxDestroyGLXPixmap(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivate"))]
             Request::GlxVendorPrivate(req) => Request::GlxVendorPrivate(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
             Request::GlxVendorPrivateWithReply(req) => Request::GlxVendorPrivateWithReply(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
             Request::GlxQueryExtensionsString(req) => Request::GlxQueryExtensionsString(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
             Request::GlxQueryServerString(req) => Request::GlxQueryServerString(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ClientInfo"))]
             Request::GlxClientInfo(req) => Request::GlxClientInfo(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
             Request::GlxGetFBConfigs(req) => Request::GlxGetFBConfigs(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePixmap"))]
             Request::GlxCreatePixmap(req) => Request::GlxCreatePixmap(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPixmap"))]
             Request::GlxDestroyPixmap(req) => Request::GlxDestroyPixmap(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateNewContext"))]
             Request::GlxCreateNewContext(req) => Request::GlxCreateNewContext(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
             Request::GlxQueryContext(req) => Request::GlxQueryContext(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
             Request::GlxMakeContextCurrent(req) => Request::GlxMakeContextCurrent(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreatePbuffer"))]
             Request::GlxCreatePbuffer(req) => Request::GlxCreatePbuffer(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DestroyPbuffer"))]
             Request::GlxDestroyPbuffer(req) => Request::GlxDestroyPbuffer(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
             Request::GlxGetDrawableAttributes(req) => Request::GlxGetDrawableAttributes(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ChangeDrawableAttributes"))]
             Request::GlxChangeDrawableAttributes(req) => Request::GlxChangeDrawableAttributes(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateWindow"))]
             Request::GlxCreateWindow(req) => Request::GlxCreateWindow(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteWindow"))]
             Request::GlxDeleteWindow(req) => Request::GlxDeleteWindow(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfoARB"))]
             Request::GlxSetClientInfoARB(req) => Request::GlxSetClientInfoARB(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::CreateContextAttribsARB"))]
             Request::GlxCreateContextAttribsARB(req) => Request::GlxCreateContextAttribsARB(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SetClientInfo2ARB"))]
             Request::GlxSetClientInfo2ARB(req) => Request::GlxSetClientInfo2ARB(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::NewList"))]
             Request::GlxNewList(req) => Request::GlxNewList(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::EndList"))]
             Request::GlxEndList(req) => Request::GlxEndList(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteLists"))]
             Request::GlxDeleteLists(req) => Request::GlxDeleteLists(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
             Request::GlxGenLists(req) => Request::GlxGenLists(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::FeedbackBuffer"))]
             Request::GlxFeedbackBuffer(req) => Request::GlxFeedbackBuffer(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::SelectBuffer"))]
             Request::GlxSelectBuffer(req) => Request::GlxSelectBuffer(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
             Request::GlxRenderMode(req) => Request::GlxRenderMode(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
             Request::GlxFinish(req) => Request::GlxFinish(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStoref"))]
             Request::GlxPixelStoref(req) => Request::GlxPixelStoref(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::PixelStorei"))]
             Request::GlxPixelStorei(req) => Request::GlxPixelStorei(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
             Request::GlxReadPixels(req) => Request::GlxReadPixels(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
             Request::GlxGetBooleanv(req) => Request::GlxGetBooleanv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
             Request::GlxGetClipPlane(req) => Request::GlxGetClipPlane(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
             Request::GlxGetDoublev(req) => Request::GlxGetDoublev(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
             Request::GlxGetError(req) => Request::GlxGetError(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
             Request::GlxGetFloatv(req) => Request::GlxGetFloatv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
             Request::GlxGetIntegerv(req) => Request::GlxGetIntegerv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
             Request::GlxGetLightfv(req) => Request::GlxGetLightfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
             Request::GlxGetLightiv(req) => Request::GlxGetLightiv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
             Request::GlxGetMapdv(req) => Request::GlxGetMapdv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
             Request::GlxGetMapfv(req) => Request::GlxGetMapfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
             Request::GlxGetMapiv(req) => Request::GlxGetMapiv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
             Request::GlxGetMaterialfv(req) => Request::GlxGetMaterialfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
             Request::GlxGetMaterialiv(req) => Request::GlxGetMaterialiv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
             Request::GlxGetPixelMapfv(req) => Request::GlxGetPixelMapfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
             Request::GlxGetPixelMapuiv(req) => Request::GlxGetPixelMapuiv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
             Request::GlxGetPixelMapusv(req) => Request::GlxGetPixelMapusv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
             Request::GlxGetPolygonStipple(req) => Request::GlxGetPolygonStipple(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
             Request::GlxGetString(req) => Request::GlxGetString(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
             Request::GlxGetTexEnvfv(req) => Request::GlxGetTexEnvfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
             Request::GlxGetTexEnviv(req) => Request::GlxGetTexEnviv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
             Request::GlxGetTexGendv(req) => Request::GlxGetTexGendv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
             Request::GlxGetTexGenfv(req) => Request::GlxGetTexGenfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
             Request::GlxGetTexGeniv(req) => Request::GlxGetTexGeniv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
             Request::GlxGetTexImage(req) => Request::GlxGetTexImage(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
             Request::GlxGetTexParameterfv(req) => Request::GlxGetTexParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
             Request::GlxGetTexParameteriv(req) => Request::GlxGetTexParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
             Request::GlxGetTexLevelParameterfv(req) => Request::GlxGetTexLevelParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
             Request::GlxGetTexLevelParameteriv(req) => Request::GlxGetTexLevelParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
             Request::GlxIsEnabled(req) => Request::GlxIsEnabled(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
             Request::GlxIsList(req) => Request::GlxIsList(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Flush"))]
             Request::GlxFlush(req) => Request::GlxFlush(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
             Request::GlxAreTexturesResident(req) => Request::GlxAreTexturesResident(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteTextures"))]
             Request::GlxDeleteTextures(req) => Request::GlxDeleteTextures(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
             Request::GlxGenTextures(req) => Request::GlxGenTextures(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
             Request::GlxIsTexture(req) => Request::GlxIsTexture(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
             Request::GlxGetColorTable(req) => Request::GlxGetColorTable(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
             Request::GlxGetColorTableParameterfv(req) => Request::GlxGetColorTableParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
             Request::GlxGetColorTableParameteriv(req) => Request::GlxGetColorTableParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
             Request::GlxGetConvolutionFilter(req) => Request::GlxGetConvolutionFilter(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
             Request::GlxGetConvolutionParameterfv(req) => Request::GlxGetConvolutionParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
             Request::GlxGetConvolutionParameteriv(req) => Request::GlxGetConvolutionParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
             Request::GlxGetSeparableFilter(req) => Request::GlxGetSeparableFilter(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
             Request::GlxGetHistogram(req) => Request::GlxGetHistogram(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
             Request::GlxGetHistogramParameterfv(req) => Request::GlxGetHistogramParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
             Request::GlxGetHistogramParameteriv(req) => Request::GlxGetHistogramParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
             Request::GlxGetMinmax(req) => Request::GlxGetMinmax(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
             Request::GlxGetMinmaxParameterfv(req) => Request::GlxGetMinmaxParameterfv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
             Request::GlxGetMinmaxParameteriv(req) => Request::GlxGetMinmaxParameteriv(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
             Request::GlxGetCompressedTexImageARB(req) => Request::GlxGetCompressedTexImageARB(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::DeleteQueriesARB"))]
             Request::GlxDeleteQueriesARB(req) => Request::GlxDeleteQueriesARB(req.into_owned()),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
             Request::GlxGenQueriesARB(req) => Request::GlxGenQueriesARB(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
             Request::GlxIsQueryARB(req) => Request::GlxIsQueryARB(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
             Request::GlxGetQueryivARB(req) => Request::GlxGetQueryivARB(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
             Request::GlxGetQueryObjectivARB(req) => Request::GlxGetQueryObjectivARB(req),
             #[cfg(feature = "glx")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
             Request::GlxGetQueryObjectuivARB(req) => Request::GlxGetQueryObjectuivARB(req),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
             Request::PresentQueryVersion(req) => Request::PresentQueryVersion(req),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::Pixmap"))]
             Request::PresentPixmap(req) => Request::PresentPixmap(req.into_owned()),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::NotifyMSC"))]
             Request::PresentNotifyMSC(req) => Request::PresentNotifyMSC(req),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::SelectInput"))]
             Request::PresentSelectInput(req) => Request::PresentSelectInput(req),
             #[cfg(feature = "present")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
             Request::PresentQueryCapabilities(req) => Request::PresentQueryCapabilities(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
             Request::RandrQueryVersion(req) => Request::RandrQueryVersion(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
             Request::RandrSetScreenConfig(req) => Request::RandrSetScreenConfig(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
             Request::RandrSelectInput(req) => Request::RandrSelectInput(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
             Request::RandrGetScreenInfo(req) => Request::RandrGetScreenInfo(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
             Request::RandrGetScreenSizeRange(req) => Request::RandrGetScreenSizeRange(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
             Request::RandrSetScreenSize(req) => Request::RandrSetScreenSize(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
             Request::RandrGetScreenResources(req) => Request::RandrGetScreenResources(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
             Request::RandrGetOutputInfo(req) => Request::RandrGetOutputInfo(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
             Request::RandrListOutputProperties(req) => Request::RandrListOutputProperties(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
             Request::RandrQueryOutputProperty(req) => Request::RandrQueryOutputProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
             Request::RandrConfigureOutputProperty(req) => Request::RandrConfigureOutputProperty(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
             Request::RandrChangeOutputProperty(req) => Request::RandrChangeOutputProperty(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
             Request::RandrDeleteOutputProperty(req) => Request::RandrDeleteOutputProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
             Request::RandrGetOutputProperty(req) => Request::RandrGetOutputProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
             Request::RandrCreateMode(req) => Request::RandrCreateMode(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
             Request::RandrDestroyMode(req) => Request::RandrDestroyMode(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
             Request::RandrAddOutputMode(req) => Request::RandrAddOutputMode(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
             Request::RandrDeleteOutputMode(req) => Request::RandrDeleteOutputMode(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
             Request::RandrGetCrtcInfo(req) => Request::RandrGetCrtcInfo(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
             Request::RandrSetCrtcConfig(req) => Request::RandrSetCrtcConfig(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
             Request::RandrGetCrtcGammaSize(req) => Request::RandrGetCrtcGammaSize(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
             Request::RandrGetCrtcGamma(req) => Request::RandrGetCrtcGamma(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
             Request::RandrSetCrtcGamma(req) => Request::RandrSetCrtcGamma(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
             Request::RandrGetScreenResourcesCurrent(req) => Request::RandrGetScreenResourcesCurrent(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
             Request::RandrSetCrtcTransform(req) => Request::RandrSetCrtcTransform(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
             Request::RandrGetCrtcTransform(req) => Request::RandrGetCrtcTransform(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
             Request::RandrGetPanning(req) => Request::RandrGetPanning(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
             Request::RandrSetPanning(req) => Request::RandrSetPanning(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
             Request::RandrSetOutputPrimary(req) => Request::RandrSetOutputPrimary(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
             Request::RandrGetOutputPrimary(req) => Request::RandrGetOutputPrimary(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
             Request::RandrGetProviders(req) => Request::RandrGetProviders(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
             Request::RandrGetProviderInfo(req) => Request::RandrGetProviderInfo(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
             Request::RandrSetProviderOffloadSink(req) => Request::RandrSetProviderOffloadSink(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
             Request::RandrSetProviderOutputSource(req) => Request::RandrSetProviderOutputSource(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
             Request::RandrListProviderProperties(req) => Request::RandrListProviderProperties(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
             Request::RandrQueryProviderProperty(req) => Request::RandrQueryProviderProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
             Request::RandrConfigureProviderProperty(req) => Request::RandrConfigureProviderProperty(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
             Request::RandrChangeProviderProperty(req) => Request::RandrChangeProviderProperty(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
             Request::RandrDeleteProviderProperty(req) => Request::RandrDeleteProviderProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
             Request::RandrGetProviderProperty(req) => Request::RandrGetProviderProperty(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
             Request::RandrGetMonitors(req) => Request::RandrGetMonitors(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
             Request::RandrSetMonitor(req) => Request::RandrSetMonitor(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
             Request::RandrDeleteMonitor(req) => Request::RandrDeleteMonitor(req),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
             Request::RandrCreateLease(req) => Request::RandrCreateLease(req.into_owned()),
             #[cfg(feature = "randr")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
             Request::RandrFreeLease(req) => Request::RandrFreeLease(req),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
             Request::RecordQueryVersion(req) => Request::RecordQueryVersion(req),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::CreateContext"))]
             Request::RecordCreateContext(req) => Request::RecordCreateContext(req.into_owned()),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::RegisterClients"))]
             Request::RecordRegisterClients(req) => Request::RecordRegisterClients(req.into_owned()),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::UnregisterClients"))]
             Request::RecordUnregisterClients(req) => Request::RecordUnregisterClients(req.into_owned()),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
             Request::RecordGetContext(req) => Request::RecordGetContext(req),
             #[cfg(feature = "record")]
             Request::RecordEnableContext(req) => Request::RecordEnableContext(req),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::DisableContext"))]
             Request::RecordDisableContext(req) => Request::RecordDisableContext(req),
             #[cfg(feature = "record")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::FreeContext"))]
             Request::RecordFreeContext(req) => Request::RecordFreeContext(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
             Request::RenderQueryVersion(req) => Request::RenderQueryVersion(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
             Request::RenderQueryPictFormats(req) => Request::RenderQueryPictFormats(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
             Request::RenderQueryPictIndexValues(req) => Request::RenderQueryPictIndexValues(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
             Request::RenderCreatePicture(req) => Request::RenderCreatePicture(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
             Request::RenderChangePicture(req) => Request::RenderChangePicture(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
             Request::RenderSetPictureClipRectangles(req) => Request::RenderSetPictureClipRectangles(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
             Request::RenderFreePicture(req) => Request::RenderFreePicture(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
             Request::RenderComposite(req) => Request::RenderComposite(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
             Request::RenderTrapezoids(req) => Request::RenderTrapezoids(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
             Request::RenderTriangles(req) => Request::RenderTriangles(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
             Request::RenderTriStrip(req) => Request::RenderTriStrip(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
             Request::RenderTriFan(req) => Request::RenderTriFan(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
             Request::RenderCreateGlyphSet(req) => Request::RenderCreateGlyphSet(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
             Request::RenderReferenceGlyphSet(req) => Request::RenderReferenceGlyphSet(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
             Request::RenderFreeGlyphSet(req) => Request::RenderFreeGlyphSet(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
             Request::RenderAddGlyphs(req) => Request::RenderAddGlyphs(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
             Request::RenderFreeGlyphs(req) => Request::RenderFreeGlyphs(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
             Request::RenderCompositeGlyphs8(req) => Request::RenderCompositeGlyphs8(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
             Request::RenderCompositeGlyphs16(req) => Request::RenderCompositeGlyphs16(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
             Request::RenderCompositeGlyphs32(req) => Request::RenderCompositeGlyphs32(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
             Request::RenderFillRectangles(req) => Request::RenderFillRectangles(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
             Request::RenderCreateCursor(req) => Request::RenderCreateCursor(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
             Request::RenderSetPictureTransform(req) => Request::RenderSetPictureTransform(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
             Request::RenderQueryFilters(req) => Request::RenderQueryFilters(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
             Request::RenderSetPictureFilter(req) => Request::RenderSetPictureFilter(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
             Request::RenderCreateAnimCursor(req) => Request::RenderCreateAnimCursor(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
             Request::RenderAddTraps(req) => Request::RenderAddTraps(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
             Request::RenderCreateSolidFill(req) => Request::RenderCreateSolidFill(req),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
             Request::RenderCreateLinearGradient(req) => Request::RenderCreateLinearGradient(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
             Request::RenderCreateRadialGradient(req) => Request::RenderCreateRadialGradient(req.into_owned()),
             #[cfg(feature = "render")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
             Request::RenderCreateConicalGradient(req) => Request::RenderCreateConicalGradient(req.into_owned()),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
             Request::ResQueryVersion(req) => Request::ResQueryVersion(req),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
             Request::ResQueryClients(req) => Request::ResQueryClients(req),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
             Request::ResQueryClientResources(req) => Request::ResQueryClientResources(req),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
             Request::ResQueryClientPixmapBytes(req) => Request::ResQueryClientPixmapBytes(req),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
             Request::ResQueryClientIds(req) => Request::ResQueryClientIds(req.into_owned()),
             #[cfg(feature = "res")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
             Request::ResQueryResourceBytes(req) => Request::ResQueryResourceBytes(req.into_owned()),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
             Request::ScreensaverQueryVersion(req) => Request::ScreensaverQueryVersion(req),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
             Request::ScreensaverQueryInfo(req) => Request::ScreensaverQueryInfo(req),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SelectInput"))]
             Request::ScreensaverSelectInput(req) => Request::ScreensaverSelectInput(req),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::SetAttributes"))]
             Request::ScreensaverSetAttributes(req) => Request::ScreensaverSetAttributes(req.into_owned()),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::UnsetAttributes"))]
             Request::ScreensaverUnsetAttributes(req) => Request::ScreensaverUnsetAttributes(req),
             #[cfg(feature = "screensaver")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::Suspend"))]
             Request::ScreensaverSuspend(req) => Request::ScreensaverSuspend(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
             Request::ShapeQueryVersion(req) => Request::ShapeQueryVersion(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
             Request::ShapeRectangles(req) => Request::ShapeRectangles(req.into_owned()),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
             Request::ShapeMask(req) => Request::ShapeMask(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
             Request::ShapeCombine(req) => Request::ShapeCombine(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
             Request::ShapeOffset(req) => Request::ShapeOffset(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
             Request::ShapeQueryExtents(req) => Request::ShapeQueryExtents(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
             Request::ShapeSelectInput(req) => Request::ShapeSelectInput(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
             Request::ShapeInputSelected(req) => Request::ShapeInputSelected(req),
             #[cfg(feature = "shape")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
             Request::ShapeGetRectangles(req) => Request::ShapeGetRectangles(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
             Request::ShmQueryVersion(req) => Request::ShmQueryVersion(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
             Request::ShmAttach(req) => Request::ShmAttach(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
             Request::ShmDetach(req) => Request::ShmDetach(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
             Request::ShmPutImage(req) => Request::ShmPutImage(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
             Request::ShmGetImage(req) => Request::ShmGetImage(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
             Request::ShmCreatePixmap(req) => Request::ShmCreatePixmap(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
             Request::ShmAttachFd(req) => Request::ShmAttachFd(req),
             #[cfg(feature = "shm")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
             Request::ShmCreateSegment(req) => Request::ShmCreateSegment(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
             Request::SyncInitialize(req) => Request::SyncInitialize(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
             Request::SyncListSystemCounters(req) => Request::SyncListSystemCounters(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
             Request::SyncCreateCounter(req) => Request::SyncCreateCounter(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
             Request::SyncDestroyCounter(req) => Request::SyncDestroyCounter(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
             Request::SyncQueryCounter(req) => Request::SyncQueryCounter(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
             Request::SyncAwait(req) => Request::SyncAwait(req.into_owned()),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
             Request::SyncChangeCounter(req) => Request::SyncChangeCounter(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
             Request::SyncSetCounter(req) => Request::SyncSetCounter(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
             Request::SyncCreateAlarm(req) => Request::SyncCreateAlarm(req.into_owned()),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
             Request::SyncChangeAlarm(req) => Request::SyncChangeAlarm(req.into_owned()),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
             Request::SyncDestroyAlarm(req) => Request::SyncDestroyAlarm(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
             Request::SyncQueryAlarm(req) => Request::SyncQueryAlarm(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
             Request::SyncSetPriority(req) => Request::SyncSetPriority(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
             Request::SyncGetPriority(req) => Request::SyncGetPriority(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
             Request::SyncCreateFence(req) => Request::SyncCreateFence(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
             Request::SyncTriggerFence(req) => Request::SyncTriggerFence(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
             Request::SyncResetFence(req) => Request::SyncResetFence(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
             Request::SyncDestroyFence(req) => Request::SyncDestroyFence(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
             Request::SyncQueryFence(req) => Request::SyncQueryFence(req),
             #[cfg(feature = "sync")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
             Request::SyncAwaitFence(req) => Request::SyncAwaitFence(req.into_owned()),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
             Request::XcMiscGetVersion(req) => Request::XcMiscGetVersion(req),
             Request::XcMiscGetXIDRange(req) => Request::XcMiscGetXIDRange(req),
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
             Request::XcMiscGetXIDList(req) => Request::XcMiscGetXIDList(req),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
             Request::XevieQueryVersion(req) => Request::XevieQueryVersion(req),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
             Request::XevieStart(req) => Request::XevieStart(req),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
             Request::XevieEnd(req) => Request::XevieEnd(req),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
             Request::XevieSend(req) => Request::XevieSend(req),
             #[cfg(feature = "xevie")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
             Request::XevieSelectInput(req) => Request::XevieSelectInput(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
             Request::Xf86driQueryVersion(req) => Request::Xf86driQueryVersion(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
             Request::Xf86driQueryDirectRenderingCapable(req) => Request::Xf86driQueryDirectRenderingCapable(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
             Request::Xf86driOpenConnection(req) => Request::Xf86driOpenConnection(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CloseConnection"))]
             Request::Xf86driCloseConnection(req) => Request::Xf86driCloseConnection(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
             Request::Xf86driGetClientDriverName(req) => Request::Xf86driGetClientDriverName(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
             Request::Xf86driCreateContext(req) => Request::Xf86driCreateContext(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyContext"))]
             Request::Xf86driDestroyContext(req) => Request::Xf86driDestroyContext(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
             Request::Xf86driCreateDrawable(req) => Request::Xf86driCreateDrawable(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::DestroyDrawable"))]
             Request::Xf86driDestroyDrawable(req) => Request::Xf86driDestroyDrawable(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
             Request::Xf86driGetDrawableInfo(req) => Request::Xf86driGetDrawableInfo(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
             Request::Xf86driGetDeviceInfo(req) => Request::Xf86driGetDeviceInfo(req),
             #[cfg(feature = "xf86dri")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
             Request::Xf86driAuthConnection(req) => Request::Xf86driAuthConnection(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
             Request::Xf86vidmodeQueryVersion(req) => Request::Xf86vidmodeQueryVersion(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
             Request::Xf86vidmodeGetModeLine(req) => Request::Xf86vidmodeGetModeLine(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
             Request::Xf86vidmodeModModeLine(req) => Request::Xf86vidmodeModModeLine(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
             Request::Xf86vidmodeSwitchMode(req) => Request::Xf86vidmodeSwitchMode(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
             Request::Xf86vidmodeGetMonitor(req) => Request::Xf86vidmodeGetMonitor(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
             Request::Xf86vidmodeLockModeSwitch(req) => Request::Xf86vidmodeLockModeSwitch(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
             Request::Xf86vidmodeGetAllModeLines(req) => Request::Xf86vidmodeGetAllModeLines(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
             Request::Xf86vidmodeAddModeLine(req) => Request::Xf86vidmodeAddModeLine(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
             Request::Xf86vidmodeDeleteModeLine(req) => Request::Xf86vidmodeDeleteModeLine(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
             Request::Xf86vidmodeValidateModeLine(req) => Request::Xf86vidmodeValidateModeLine(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
             Request::Xf86vidmodeSwitchToMode(req) => Request::Xf86vidmodeSwitchToMode(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
             Request::Xf86vidmodeGetViewPort(req) => Request::Xf86vidmodeGetViewPort(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
             Request::Xf86vidmodeSetViewPort(req) => Request::Xf86vidmodeSetViewPort(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
             Request::Xf86vidmodeGetDotClocks(req) => Request::Xf86vidmodeGetDotClocks(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
             Request::Xf86vidmodeSetClientVersion(req) => Request::Xf86vidmodeSetClientVersion(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
             Request::Xf86vidmodeSetGamma(req) => Request::Xf86vidmodeSetGamma(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
             Request::Xf86vidmodeGetGamma(req) => Request::Xf86vidmodeGetGamma(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
             Request::Xf86vidmodeGetGammaRamp(req) => Request::Xf86vidmodeGetGammaRamp(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
             Request::Xf86vidmodeSetGammaRamp(req) => Request::Xf86vidmodeSetGammaRamp(req.into_owned()),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
             Request::Xf86vidmodeGetGammaRampSize(req) => Request::Xf86vidmodeGetGammaRampSize(req),
             #[cfg(feature = "xf86vidmode")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
             Request::Xf86vidmodeGetPermissions(req) => Request::Xf86vidmodeGetPermissions(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
             Request::XfixesQueryVersion(req) => Request::XfixesQueryVersion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeSaveSet"))]
             Request::XfixesChangeSaveSet(req) => Request::XfixesChangeSaveSet(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectSelectionInput"))]
             Request::XfixesSelectSelectionInput(req) => Request::XfixesSelectSelectionInput(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SelectCursorInput"))]
             Request::XfixesSelectCursorInput(req) => Request::XfixesSelectCursorInput(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
             Request::XfixesGetCursorImage(req) => Request::XfixesGetCursorImage(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegion"))]
             Request::XfixesCreateRegion(req) => Request::XfixesCreateRegion(req.into_owned()),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromBitmap"))]
             Request::XfixesCreateRegionFromBitmap(req) => Request::XfixesCreateRegionFromBitmap(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromWindow"))]
             Request::XfixesCreateRegionFromWindow(req) => Request::XfixesCreateRegionFromWindow(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromGC"))]
             Request::XfixesCreateRegionFromGC(req) => Request::XfixesCreateRegionFromGC(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreateRegionFromPicture"))]
             Request::XfixesCreateRegionFromPicture(req) => Request::XfixesCreateRegionFromPicture(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DestroyRegion"))]
             Request::XfixesDestroyRegion(req) => Request::XfixesDestroyRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetRegion"))]
             Request::XfixesSetRegion(req) => Request::XfixesSetRegion(req.into_owned()),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CopyRegion"))]
             Request::XfixesCopyRegion(req) => Request::XfixesCopyRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::UnionRegion"))]
             Request::XfixesUnionRegion(req) => Request::XfixesUnionRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::IntersectRegion"))]
             Request::XfixesIntersectRegion(req) => Request::XfixesIntersectRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SubtractRegion"))]
             Request::XfixesSubtractRegion(req) => Request::XfixesSubtractRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::InvertRegion"))]
             Request::XfixesInvertRegion(req) => Request::XfixesInvertRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::TranslateRegion"))]
             Request::XfixesTranslateRegion(req) => Request::XfixesTranslateRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::RegionExtents"))]
             Request::XfixesRegionExtents(req) => Request::XfixesRegionExtents(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
             Request::XfixesFetchRegion(req) => Request::XfixesFetchRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetGCClipRegion"))]
             Request::XfixesSetGCClipRegion(req) => Request::XfixesSetGCClipRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetWindowShapeRegion"))]
             Request::XfixesSetWindowShapeRegion(req) => Request::XfixesSetWindowShapeRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetPictureClipRegion"))]
             Request::XfixesSetPictureClipRegion(req) => Request::XfixesSetPictureClipRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::SetCursorName"))]
             Request::XfixesSetCursorName(req) => Request::XfixesSetCursorName(req.into_owned()),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
             Request::XfixesGetCursorName(req) => Request::XfixesGetCursorName(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
             Request::XfixesGetCursorImageAndName(req) => Request::XfixesGetCursorImageAndName(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursor"))]
             Request::XfixesChangeCursor(req) => Request::XfixesChangeCursor(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ChangeCursorByName"))]
             Request::XfixesChangeCursorByName(req) => Request::XfixesChangeCursorByName(req.into_owned()),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ExpandRegion"))]
             Request::XfixesExpandRegion(req) => Request::XfixesExpandRegion(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::HideCursor"))]
             Request::XfixesHideCursor(req) => Request::XfixesHideCursor(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::ShowCursor"))]
             Request::XfixesShowCursor(req) => Request::XfixesShowCursor(req),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::CreatePointerBarrier"))]
             Request::XfixesCreatePointerBarrier(req) => Request::XfixesCreatePointerBarrier(req.into_owned()),
             #[cfg(feature = "xfixes")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::DeletePointerBarrier"))]
             Request::XfixesDeletePointerBarrier(req) => Request::XfixesDeletePointerBarrier(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
             Request::XineramaQueryVersion(req) => Request::XineramaQueryVersion(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
             Request::XineramaGetState(req) => Request::XineramaGetState(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
             Request::XineramaGetScreenCount(req) => Request::XineramaGetScreenCount(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
             Request::XineramaGetScreenSize(req) => Request::XineramaGetScreenSize(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
             Request::XineramaIsActive(req) => Request::XineramaIsActive(req),
             #[cfg(feature = "xinerama")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
             Request::XineramaQueryScreens(req) => Request::XineramaQueryScreens(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
             Request::XinputGetExtensionVersion(req) => Request::XinputGetExtensionVersion(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
             Request::XinputListInputDevices(req) => Request::XinputListInputDevices(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
             Request::XinputOpenDevice(req) => Request::XinputOpenDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
             Request::XinputCloseDevice(req) => Request::XinputCloseDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
             Request::XinputSetDeviceMode(req) => Request::XinputSetDeviceMode(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
             Request::XinputSelectExtensionEvent(req) => Request::XinputSelectExtensionEvent(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
             Request::XinputGetSelectedExtensionEvents(req) => Request::XinputGetSelectedExtensionEvents(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
             Request::XinputChangeDeviceDontPropagateList(req) => Request::XinputChangeDeviceDontPropagateList(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
             Request::XinputGetDeviceDontPropagateList(req) => Request::XinputGetDeviceDontPropagateList(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
             Request::XinputGetDeviceMotionEvents(req) => Request::XinputGetDeviceMotionEvents(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
             Request::XinputChangeKeyboardDevice(req) => Request::XinputChangeKeyboardDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
             Request::XinputChangePointerDevice(req) => Request::XinputChangePointerDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
             Request::XinputGrabDevice(req) => Request::XinputGrabDevice(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
             Request::XinputUngrabDevice(req) => Request::XinputUngrabDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
             Request::XinputGrabDeviceKey(req) => Request::XinputGrabDeviceKey(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
             Request::XinputUngrabDeviceKey(req) => Request::XinputUngrabDeviceKey(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
             Request::XinputGrabDeviceButton(req) => Request::XinputGrabDeviceButton(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
             Request::XinputUngrabDeviceButton(req) => Request::XinputUngrabDeviceButton(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
             Request::XinputAllowDeviceEvents(req) => Request::XinputAllowDeviceEvents(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
             Request::XinputGetDeviceFocus(req) => Request::XinputGetDeviceFocus(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
             Request::XinputSetDeviceFocus(req) => Request::XinputSetDeviceFocus(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
             Request::XinputGetFeedbackControl(req) => Request::XinputGetFeedbackControl(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
             Request::XinputChangeFeedbackControl(req) => Request::XinputChangeFeedbackControl(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
             Request::XinputGetDeviceKeyMapping(req) => Request::XinputGetDeviceKeyMapping(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
             Request::XinputChangeDeviceKeyMapping(req) => Request::XinputChangeDeviceKeyMapping(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
             Request::XinputGetDeviceModifierMapping(req) => Request::XinputGetDeviceModifierMapping(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
             Request::XinputSetDeviceModifierMapping(req) => Request::XinputSetDeviceModifierMapping(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
             Request::XinputGetDeviceButtonMapping(req) => Request::XinputGetDeviceButtonMapping(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
             Request::XinputSetDeviceButtonMapping(req) => Request::XinputSetDeviceButtonMapping(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
             Request::XinputQueryDeviceState(req) => Request::XinputQueryDeviceState(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
             Request::XinputDeviceBell(req) => Request::XinputDeviceBell(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
             Request::XinputSetDeviceValuators(req) => Request::XinputSetDeviceValuators(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
             Request::XinputGetDeviceControl(req) => Request::XinputGetDeviceControl(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
             Request::XinputChangeDeviceControl(req) => Request::XinputChangeDeviceControl(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
             Request::XinputListDeviceProperties(req) => Request::XinputListDeviceProperties(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
             Request::XinputChangeDeviceProperty(req) => Request::XinputChangeDeviceProperty(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
             Request::XinputDeleteDeviceProperty(req) => Request::XinputDeleteDeviceProperty(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
             Request::XinputGetDeviceProperty(req) => Request::XinputGetDeviceProperty(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
             Request::XinputXIQueryPointer(req) => Request::XinputXIQueryPointer(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
             Request::XinputXIWarpPointer(req) => Request::XinputXIWarpPointer(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
             Request::XinputXIChangeCursor(req) => Request::XinputXIChangeCursor(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
             Request::XinputXIChangeHierarchy(req) => Request::XinputXIChangeHierarchy(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
             Request::XinputXISetClientPointer(req) => Request::XinputXISetClientPointer(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
             Request::XinputXIGetClientPointer(req) => Request::XinputXIGetClientPointer(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
             Request::XinputXISelectEvents(req) => Request::XinputXISelectEvents(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
             Request::XinputXIQueryVersion(req) => Request::XinputXIQueryVersion(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
             Request::XinputXIQueryDevice(req) => Request::XinputXIQueryDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
             Request::XinputXISetFocus(req) => Request::XinputXISetFocus(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
             Request::XinputXIGetFocus(req) => Request::XinputXIGetFocus(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
             Request::XinputXIGrabDevice(req) => Request::XinputXIGrabDevice(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
             Request::XinputXIUngrabDevice(req) => Request::XinputXIUngrabDevice(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
             Request::XinputXIAllowEvents(req) => Request::XinputXIAllowEvents(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
             Request::XinputXIPassiveGrabDevice(req) => Request::XinputXIPassiveGrabDevice(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
             Request::XinputXIPassiveUngrabDevice(req) => Request::XinputXIPassiveUngrabDevice(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
             Request::XinputXIListProperties(req) => Request::XinputXIListProperties(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
             Request::XinputXIChangeProperty(req) => Request::XinputXIChangeProperty(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
             Request::XinputXIDeleteProperty(req) => Request::XinputXIDeleteProperty(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
             Request::XinputXIGetProperty(req) => Request::XinputXIGetProperty(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
             Request::XinputXIGetSelectedEvents(req) => Request::XinputXIGetSelectedEvents(req),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
             Request::XinputXIBarrierReleasePointer(req) => Request::XinputXIBarrierReleasePointer(req.into_owned()),
             #[cfg(feature = "xinput")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
             Request::XinputSendExtensionEvent(req) => Request::XinputSendExtensionEvent(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
             Request::XkbUseExtension(req) => Request::XkbUseExtension(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
             Request::XkbSelectEvents(req) => Request::XkbSelectEvents(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
             Request::XkbBell(req) => Request::XkbBell(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
             Request::XkbGetState(req) => Request::XkbGetState(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
             Request::XkbLatchLockState(req) => Request::XkbLatchLockState(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
             Request::XkbGetControls(req) => Request::XkbGetControls(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
             Request::XkbSetControls(req) => Request::XkbSetControls(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
             Request::XkbGetMap(req) => Request::XkbGetMap(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
             Request::XkbSetMap(req) => Request::XkbSetMap(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
             Request::XkbGetCompatMap(req) => Request::XkbGetCompatMap(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
             Request::XkbSetCompatMap(req) => Request::XkbSetCompatMap(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
             Request::XkbGetIndicatorState(req) => Request::XkbGetIndicatorState(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
             Request::XkbGetIndicatorMap(req) => Request::XkbGetIndicatorMap(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
             Request::XkbSetIndicatorMap(req) => Request::XkbSetIndicatorMap(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
             Request::XkbGetNamedIndicator(req) => Request::XkbGetNamedIndicator(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
             Request::XkbSetNamedIndicator(req) => Request::XkbSetNamedIndicator(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
             Request::XkbGetNames(req) => Request::XkbGetNames(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
             Request::XkbSetNames(req) => Request::XkbSetNames(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
             Request::XkbPerClientFlags(req) => Request::XkbPerClientFlags(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
             Request::XkbListComponents(req) => Request::XkbListComponents(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
             Request::XkbGetKbdByName(req) => Request::XkbGetKbdByName(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
             Request::XkbGetDeviceInfo(req) => Request::XkbGetDeviceInfo(req),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
             Request::XkbSetDeviceInfo(req) => Request::XkbSetDeviceInfo(req.into_owned()),
             #[cfg(feature = "xkb")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
             Request::XkbSetDebuggingFlags(req) => Request::XkbSetDebuggingFlags(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
             Request::XprintPrintQueryVersion(req) => Request::XprintPrintQueryVersion(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
             Request::XprintPrintGetPrinterList(req) => Request::XprintPrintGetPrinterList(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintRehashPrinterList"))]
             Request::XprintPrintRehashPrinterList(req) => Request::XprintPrintRehashPrinterList(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::CreateContext"))]
             Request::XprintCreateContext(req) => Request::XprintCreateContext(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetContext"))]
             Request::XprintPrintSetContext(req) => Request::XprintPrintSetContext(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
             Request::XprintPrintGetContext(req) => Request::XprintPrintGetContext(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintDestroyContext"))]
             Request::XprintPrintDestroyContext(req) => Request::XprintPrintDestroyContext(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
             Request::XprintPrintGetScreenOfContext(req) => Request::XprintPrintGetScreenOfContext(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartJob"))]
             Request::XprintPrintStartJob(req) => Request::XprintPrintStartJob(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndJob"))]
             Request::XprintPrintEndJob(req) => Request::XprintPrintEndJob(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartDoc"))]
             Request::XprintPrintStartDoc(req) => Request::XprintPrintStartDoc(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndDoc"))]
             Request::XprintPrintEndDoc(req) => Request::XprintPrintEndDoc(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintPutDocumentData"))]
             Request::XprintPrintPutDocumentData(req) => Request::XprintPrintPutDocumentData(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
             Request::XprintPrintGetDocumentData(req) => Request::XprintPrintGetDocumentData(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintStartPage"))]
             Request::XprintPrintStartPage(req) => Request::XprintPrintStartPage(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintEndPage"))]
             Request::XprintPrintEndPage(req) => Request::XprintPrintEndPage(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSelectInput"))]
             Request::XprintPrintSelectInput(req) => Request::XprintPrintSelectInput(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
             Request::XprintPrintInputSelected(req) => Request::XprintPrintInputSelected(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
             Request::XprintPrintGetAttributes(req) => Request::XprintPrintGetAttributes(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
             Request::XprintPrintGetOneAttributes(req) => Request::XprintPrintGetOneAttributes(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetAttributes"))]
             Request::XprintPrintSetAttributes(req) => Request::XprintPrintSetAttributes(req.into_owned()),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
             Request::XprintPrintGetPageDimensions(req) => Request::XprintPrintGetPageDimensions(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
             Request::XprintPrintQueryScreens(req) => Request::XprintPrintQueryScreens(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
             Request::XprintPrintSetImageResolution(req) => Request::XprintPrintSetImageResolution(req),
             #[cfg(feature = "xprint")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
             Request::XprintPrintGetImageResolution(req) => Request::XprintPrintGetImageResolution(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
             Request::XselinuxQueryVersion(req) => Request::XselinuxQueryVersion(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceCreateContext"))]
             Request::XselinuxSetDeviceCreateContext(req) => Request::XselinuxSetDeviceCreateContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
             Request::XselinuxGetDeviceCreateContext(req) => Request::XselinuxGetDeviceCreateContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetDeviceContext"))]
             Request::XselinuxSetDeviceContext(req) => Request::XselinuxSetDeviceContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
             Request::XselinuxGetDeviceContext(req) => Request::XselinuxGetDeviceContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetWindowCreateContext"))]
             Request::XselinuxSetWindowCreateContext(req) => Request::XselinuxSetWindowCreateContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
             Request::XselinuxGetWindowCreateContext(req) => Request::XselinuxGetWindowCreateContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
             Request::XselinuxGetWindowContext(req) => Request::XselinuxGetWindowContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyCreateContext"))]
             Request::XselinuxSetPropertyCreateContext(req) => Request::XselinuxSetPropertyCreateContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
             Request::XselinuxGetPropertyCreateContext(req) => Request::XselinuxGetPropertyCreateContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetPropertyUseContext"))]
             Request::XselinuxSetPropertyUseContext(req) => Request::XselinuxSetPropertyUseContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
             Request::XselinuxGetPropertyUseContext(req) => Request::XselinuxGetPropertyUseContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
             Request::XselinuxGetPropertyContext(req) => Request::XselinuxGetPropertyContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
             Request::XselinuxGetPropertyDataContext(req) => Request::XselinuxGetPropertyDataContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
             Request::XselinuxListProperties(req) => Request::XselinuxListProperties(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionCreateContext"))]
             Request::XselinuxSetSelectionCreateContext(req) => Request::XselinuxSetSelectionCreateContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
             Request::XselinuxGetSelectionCreateContext(req) => Request::XselinuxGetSelectionCreateContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::SetSelectionUseContext"))]
             Request::XselinuxSetSelectionUseContext(req) => Request::XselinuxSetSelectionUseContext(req.into_owned()),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
             Request::XselinuxGetSelectionUseContext(req) => Request::XselinuxGetSelectionUseContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
             Request::XselinuxGetSelectionContext(req) => Request::XselinuxGetSelectionContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
             Request::XselinuxGetSelectionDataContext(req) => Request::XselinuxGetSelectionDataContext(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
             Request::XselinuxListSelections(req) => Request::XselinuxListSelections(req),
             #[cfg(feature = "xselinux")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
             Request::XselinuxGetClientContext(req) => Request::XselinuxGetClientContext(req),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
             Request::XtestGetVersion(req) => Request::XtestGetVersion(req),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
             Request::XtestCompareCursor(req) => Request::XtestCompareCursor(req),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::FakeInput"))]
             Request::XtestFakeInput(req) => Request::XtestFakeInput(req),
             #[cfg(feature = "xtest")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GrabControl"))]
             Request::XtestGrabControl(req) => Request::XtestGrabControl(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
             Request::XvQueryExtension(req) => Request::XvQueryExtension(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
             Request::XvQueryAdaptors(req) => Request::XvQueryAdaptors(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
             Request::XvQueryEncodings(req) => Request::XvQueryEncodings(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
             Request::XvGrabPort(req) => Request::XvGrabPort(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::UngrabPort"))]
             Request::XvUngrabPort(req) => Request::XvUngrabPort(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutVideo"))]
             Request::XvPutVideo(req) => Request::XvPutVideo(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutStill"))]
             Request::XvPutStill(req) => Request::XvPutStill(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetVideo"))]
             Request::XvGetVideo(req) => Request::XvGetVideo(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetStill"))]
             Request::XvGetStill(req) => Request::XvGetStill(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::StopVideo"))]
             Request::XvStopVideo(req) => Request::XvStopVideo(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectVideoNotify"))]
             Request::XvSelectVideoNotify(req) => Request::XvSelectVideoNotify(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SelectPortNotify"))]
             Request::XvSelectPortNotify(req) => Request::XvSelectPortNotify(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
             Request::XvQueryBestSize(req) => Request::XvQueryBestSize(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::SetPortAttribute"))]
             Request::XvSetPortAttribute(req) => Request::XvSetPortAttribute(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
             Request::XvGetPortAttribute(req) => Request::XvGetPortAttribute(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
             Request::XvQueryPortAttributes(req) => Request::XvQueryPortAttributes(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
             Request::XvListImageFormats(req) => Request::XvListImageFormats(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
             Request::XvQueryImageAttributes(req) => Request::XvQueryImageAttributes(req),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::PutImage"))]
             Request::XvPutImage(req) => Request::XvPutImage(req.into_owned()),
             #[cfg(feature = "xv")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ShmPutImage"))]
             Request::XvShmPutImage(req) => Request::XvShmPutImage(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
             Request::XvmcQueryVersion(req) => Request::XvmcQueryVersion(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
             Request::XvmcListSurfaceTypes(req) => Request::XvmcListSurfaceTypes(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
             Request::XvmcCreateContext(req) => Request::XvmcCreateContext(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroyContext"))]
             Request::XvmcDestroyContext(req) => Request::XvmcDestroyContext(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
             Request::XvmcCreateSurface(req) => Request::XvmcCreateSurface(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySurface"))]
             Request::XvmcDestroySurface(req) => Request::XvmcDestroySurface(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
             Request::XvmcCreateSubpicture(req) => Request::XvmcCreateSubpicture(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::DestroySubpicture"))]
             Request::XvmcDestroySubpicture(req) => Request::XvmcDestroySubpicture(req),
             #[cfg(feature = "xvmc")]
+            #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
             Request::XvmcListSubpictureTypes(req) => Request::XvmcListSubpictureTypes(req),
         }
     }
 }
 
+/// Get the name of the request with the given opcodes, e.g. `"xproto::CreateWindow"`.
+///
+/// The name of an extension request can only be found if the extension is known to the
+/// `ext_info_provider`. `None` is returned for unknown requests.
+pub fn get_request_name(
+    ext_info_provider: &dyn ExtInfoProvider,
+    major_opcode: u8,
+    minor_opcode: u16,
+) -> Option<&'static str> {
+    // Core protocol requests have major opcodes below 128
+    if major_opcode < 128 {
+        return match major_opcode {
+            xproto::CREATE_WINDOW_REQUEST => Some("xproto::CreateWindow"),
+            xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST => Some("xproto::ChangeWindowAttributes"),
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST => Some("xproto::GetWindowAttributes"),
+            xproto::DESTROY_WINDOW_REQUEST => Some("xproto::DestroyWindow"),
+            xproto::DESTROY_SUBWINDOWS_REQUEST => Some("xproto::DestroySubwindows"),
+            xproto::CHANGE_SAVE_SET_REQUEST => Some("xproto::ChangeSaveSet"),
+            xproto::REPARENT_WINDOW_REQUEST => Some("xproto::ReparentWindow"),
+            xproto::MAP_WINDOW_REQUEST => Some("xproto::MapWindow"),
+            xproto::MAP_SUBWINDOWS_REQUEST => Some("xproto::MapSubwindows"),
+            xproto::UNMAP_WINDOW_REQUEST => Some("xproto::UnmapWindow"),
+            xproto::UNMAP_SUBWINDOWS_REQUEST => Some("xproto::UnmapSubwindows"),
+            xproto::CONFIGURE_WINDOW_REQUEST => Some("xproto::ConfigureWindow"),
+            xproto::CIRCULATE_WINDOW_REQUEST => Some("xproto::CirculateWindow"),
+            xproto::GET_GEOMETRY_REQUEST => Some("xproto::GetGeometry"),
+            xproto::QUERY_TREE_REQUEST => Some("xproto::QueryTree"),
+            xproto::INTERN_ATOM_REQUEST => Some("xproto::InternAtom"),
+            xproto::GET_ATOM_NAME_REQUEST => Some("xproto::GetAtomName"),
+            xproto::CHANGE_PROPERTY_REQUEST => Some("xproto::ChangeProperty"),
+            xproto::DELETE_PROPERTY_REQUEST => Some("xproto::DeleteProperty"),
+            xproto::GET_PROPERTY_REQUEST => Some("xproto::GetProperty"),
+            xproto::LIST_PROPERTIES_REQUEST => Some("xproto::ListProperties"),
+            xproto::SET_SELECTION_OWNER_REQUEST => Some("xproto::SetSelectionOwner"),
+            xproto::GET_SELECTION_OWNER_REQUEST => Some("xproto::GetSelectionOwner"),
+            xproto::CONVERT_SELECTION_REQUEST => Some("xproto::ConvertSelection"),
+            xproto::SEND_EVENT_REQUEST => Some("xproto::SendEvent"),
+            xproto::GRAB_POINTER_REQUEST => Some("xproto::GrabPointer"),
+            xproto::UNGRAB_POINTER_REQUEST => Some("xproto::UngrabPointer"),
+            xproto::GRAB_BUTTON_REQUEST => Some("xproto::GrabButton"),
+            xproto::UNGRAB_BUTTON_REQUEST => Some("xproto::UngrabButton"),
+            xproto::CHANGE_ACTIVE_POINTER_GRAB_REQUEST => Some("xproto::ChangeActivePointerGrab"),
+            xproto::GRAB_KEYBOARD_REQUEST => Some("xproto::GrabKeyboard"),
+            xproto::UNGRAB_KEYBOARD_REQUEST => Some("xproto::UngrabKeyboard"),
+            xproto::GRAB_KEY_REQUEST => Some("xproto::GrabKey"),
+            xproto::UNGRAB_KEY_REQUEST => Some("xproto::UngrabKey"),
+            xproto::ALLOW_EVENTS_REQUEST => Some("xproto::AllowEvents"),
+            xproto::GRAB_SERVER_REQUEST => Some("xproto::GrabServer"),
+            xproto::UNGRAB_SERVER_REQUEST => Some("xproto::UngrabServer"),
+            xproto::QUERY_POINTER_REQUEST => Some("xproto::QueryPointer"),
+            xproto::GET_MOTION_EVENTS_REQUEST => Some("xproto::GetMotionEvents"),
+            xproto::TRANSLATE_COORDINATES_REQUEST => Some("xproto::TranslateCoordinates"),
+            xproto::WARP_POINTER_REQUEST => Some("xproto::WarpPointer"),
+            xproto::SET_INPUT_FOCUS_REQUEST => Some("xproto::SetInputFocus"),
+            xproto::GET_INPUT_FOCUS_REQUEST => Some("xproto::GetInputFocus"),
+            xproto::QUERY_KEYMAP_REQUEST => Some("xproto::QueryKeymap"),
+            xproto::OPEN_FONT_REQUEST => Some("xproto::OpenFont"),
+            xproto::CLOSE_FONT_REQUEST => Some("xproto::CloseFont"),
+            xproto::QUERY_FONT_REQUEST => Some("xproto::QueryFont"),
+            xproto::QUERY_TEXT_EXTENTS_REQUEST => Some("xproto::QueryTextExtents"),
+            xproto::LIST_FONTS_REQUEST => Some("xproto::ListFonts"),
+            xproto::LIST_FONTS_WITH_INFO_REQUEST => Some("xproto::ListFontsWithInfo"),
+            xproto::SET_FONT_PATH_REQUEST => Some("xproto::SetFontPath"),
+            xproto::GET_FONT_PATH_REQUEST => Some("xproto::GetFontPath"),
+            xproto::CREATE_PIXMAP_REQUEST => Some("xproto::CreatePixmap"),
+            xproto::FREE_PIXMAP_REQUEST => Some("xproto::FreePixmap"),
+            xproto::CREATE_GC_REQUEST => Some("xproto::CreateGC"),
+            xproto::CHANGE_GC_REQUEST => Some("xproto::ChangeGC"),
+            xproto::COPY_GC_REQUEST => Some("xproto::CopyGC"),
+            xproto::SET_DASHES_REQUEST => Some("xproto::SetDashes"),
+            xproto::SET_CLIP_RECTANGLES_REQUEST => Some("xproto::SetClipRectangles"),
+            xproto::FREE_GC_REQUEST => Some("xproto::FreeGC"),
+            xproto::CLEAR_AREA_REQUEST => Some("xproto::ClearArea"),
+            xproto::COPY_AREA_REQUEST => Some("xproto::CopyArea"),
+            xproto::COPY_PLANE_REQUEST => Some("xproto::CopyPlane"),
+            xproto::POLY_POINT_REQUEST => Some("xproto::PolyPoint"),
+            xproto::POLY_LINE_REQUEST => Some("xproto::PolyLine"),
+            xproto::POLY_SEGMENT_REQUEST => Some("xproto::PolySegment"),
+            xproto::POLY_RECTANGLE_REQUEST => Some("xproto::PolyRectangle"),
+            xproto::POLY_ARC_REQUEST => Some("xproto::PolyArc"),
+            xproto::FILL_POLY_REQUEST => Some("xproto::FillPoly"),
+            xproto::POLY_FILL_RECTANGLE_REQUEST => Some("xproto::PolyFillRectangle"),
+            xproto::POLY_FILL_ARC_REQUEST => Some("xproto::PolyFillArc"),
+            xproto::PUT_IMAGE_REQUEST => Some("xproto::PutImage"),
+            xproto::GET_IMAGE_REQUEST => Some("xproto::GetImage"),
+            xproto::POLY_TEXT8_REQUEST => Some("xproto::PolyText8"),
+            xproto::POLY_TEXT16_REQUEST => Some("xproto::PolyText16"),
+            xproto::IMAGE_TEXT8_REQUEST => Some("xproto::ImageText8"),
+            xproto::IMAGE_TEXT16_REQUEST => Some("xproto::ImageText16"),
+            xproto::CREATE_COLORMAP_REQUEST => Some("xproto::CreateColormap"),
+            xproto::FREE_COLORMAP_REQUEST => Some("xproto::FreeColormap"),
+            xproto::COPY_COLORMAP_AND_FREE_REQUEST => Some("xproto::CopyColormapAndFree"),
+            xproto::INSTALL_COLORMAP_REQUEST => Some("xproto::InstallColormap"),
+            xproto::UNINSTALL_COLORMAP_REQUEST => Some("xproto::UninstallColormap"),
+            xproto::LIST_INSTALLED_COLORMAPS_REQUEST => Some("xproto::ListInstalledColormaps"),
+            xproto::ALLOC_COLOR_REQUEST => Some("xproto::AllocColor"),
+            xproto::ALLOC_NAMED_COLOR_REQUEST => Some("xproto::AllocNamedColor"),
+            xproto::ALLOC_COLOR_CELLS_REQUEST => Some("xproto::AllocColorCells"),
+            xproto::ALLOC_COLOR_PLANES_REQUEST => Some("xproto::AllocColorPlanes"),
+            xproto::FREE_COLORS_REQUEST => Some("xproto::FreeColors"),
+            xproto::STORE_COLORS_REQUEST => Some("xproto::StoreColors"),
+            xproto::STORE_NAMED_COLOR_REQUEST => Some("xproto::StoreNamedColor"),
+            xproto::QUERY_COLORS_REQUEST => Some("xproto::QueryColors"),
+            xproto::LOOKUP_COLOR_REQUEST => Some("xproto::LookupColor"),
+            xproto::CREATE_CURSOR_REQUEST => Some("xproto::CreateCursor"),
+            xproto::CREATE_GLYPH_CURSOR_REQUEST => Some("xproto::CreateGlyphCursor"),
+            xproto::FREE_CURSOR_REQUEST => Some("xproto::FreeCursor"),
+            xproto::RECOLOR_CURSOR_REQUEST => Some("xproto::RecolorCursor"),
+            xproto::QUERY_BEST_SIZE_REQUEST => Some("xproto::QueryBestSize"),
+            xproto::QUERY_EXTENSION_REQUEST => Some("xproto::QueryExtension"),
+            xproto::LIST_EXTENSIONS_REQUEST => Some("xproto::ListExtensions"),
+            xproto::CHANGE_KEYBOARD_MAPPING_REQUEST => Some("xproto::ChangeKeyboardMapping"),
+            xproto::GET_KEYBOARD_MAPPING_REQUEST => Some("xproto::GetKeyboardMapping"),
+            xproto::CHANGE_KEYBOARD_CONTROL_REQUEST => Some("xproto::ChangeKeyboardControl"),
+            xproto::GET_KEYBOARD_CONTROL_REQUEST => Some("xproto::GetKeyboardControl"),
+            xproto::BELL_REQUEST => Some("xproto::Bell"),
+            xproto::CHANGE_POINTER_CONTROL_REQUEST => Some("xproto::ChangePointerControl"),
+            xproto::GET_POINTER_CONTROL_REQUEST => Some("xproto::GetPointerControl"),
+            xproto::SET_SCREEN_SAVER_REQUEST => Some("xproto::SetScreenSaver"),
+            xproto::GET_SCREEN_SAVER_REQUEST => Some("xproto::GetScreenSaver"),
+            xproto::CHANGE_HOSTS_REQUEST => Some("xproto::ChangeHosts"),
+            xproto::LIST_HOSTS_REQUEST => Some("xproto::ListHosts"),
+            xproto::SET_ACCESS_CONTROL_REQUEST => Some("xproto::SetAccessControl"),
+            xproto::SET_CLOSE_DOWN_MODE_REQUEST => Some("xproto::SetCloseDownMode"),
+            xproto::KILL_CLIENT_REQUEST => Some("xproto::KillClient"),
+            xproto::ROTATE_PROPERTIES_REQUEST => Some("xproto::RotateProperties"),
+            xproto::FORCE_SCREEN_SAVER_REQUEST => Some("xproto::ForceScreenSaver"),
+            xproto::SET_POINTER_MAPPING_REQUEST => Some("xproto::SetPointerMapping"),
+            xproto::GET_POINTER_MAPPING_REQUEST => Some("xproto::GetPointerMapping"),
+            xproto::SET_MODIFIER_MAPPING_REQUEST => Some("xproto::SetModifierMapping"),
+            xproto::GET_MODIFIER_MAPPING_REQUEST => Some("xproto::GetModifierMapping"),
+            xproto::NO_OPERATION_REQUEST => Some("xproto::NoOperation"),
+            _ => None,
+        };
+    }
+    // Extension requests have 8 bit minor opcodes
+    let minor_opcode: u8 = minor_opcode.try_into().ok()?;
+    match ext_info_provider.get_from_major_opcode(major_opcode) {
+        Some((bigreq::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            bigreq::ENABLE_REQUEST => Some("bigreq::Enable"),
+            _ => None,
+        },
+        #[cfg(feature = "composite")]
+        Some((composite::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            composite::QUERY_VERSION_REQUEST => Some("composite::QueryVersion"),
+            composite::REDIRECT_WINDOW_REQUEST => Some("composite::RedirectWindow"),
+            composite::REDIRECT_SUBWINDOWS_REQUEST => Some("composite::RedirectSubwindows"),
+            composite::UNREDIRECT_WINDOW_REQUEST => Some("composite::UnredirectWindow"),
+            composite::UNREDIRECT_SUBWINDOWS_REQUEST => Some("composite::UnredirectSubwindows"),
+            composite::CREATE_REGION_FROM_BORDER_CLIP_REQUEST => Some("composite::CreateRegionFromBorderClip"),
+            composite::NAME_WINDOW_PIXMAP_REQUEST => Some("composite::NameWindowPixmap"),
+            composite::GET_OVERLAY_WINDOW_REQUEST => Some("composite::GetOverlayWindow"),
+            composite::RELEASE_OVERLAY_WINDOW_REQUEST => Some("composite::ReleaseOverlayWindow"),
+            _ => None,
+        },
+        #[cfg(feature = "damage")]
+        Some((damage::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            damage::QUERY_VERSION_REQUEST => Some("damage::QueryVersion"),
+            damage::CREATE_REQUEST => Some("damage::Create"),
+            damage::DESTROY_REQUEST => Some("damage::Destroy"),
+            damage::SUBTRACT_REQUEST => Some("damage::Subtract"),
+            damage::ADD_REQUEST => Some("damage::Add"),
+            _ => None,
+        },
+        #[cfg(feature = "dpms")]
+        Some((dpms::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            dpms::GET_VERSION_REQUEST => Some("dpms::GetVersion"),
+            dpms::CAPABLE_REQUEST => Some("dpms::Capable"),
+            dpms::GET_TIMEOUTS_REQUEST => Some("dpms::GetTimeouts"),
+            dpms::SET_TIMEOUTS_REQUEST => Some("dpms::SetTimeouts"),
+            dpms::ENABLE_REQUEST => Some("dpms::Enable"),
+            dpms::DISABLE_REQUEST => Some("dpms::Disable"),
+            dpms::FORCE_LEVEL_REQUEST => Some("dpms::ForceLevel"),
+            dpms::INFO_REQUEST => Some("dpms::Info"),
+            _ => None,
+        },
+        #[cfg(feature = "dri2")]
+        Some((dri2::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            dri2::QUERY_VERSION_REQUEST => Some("dri2::QueryVersion"),
+            dri2::CONNECT_REQUEST => Some("dri2::Connect"),
+            dri2::AUTHENTICATE_REQUEST => Some("dri2::Authenticate"),
+            dri2::CREATE_DRAWABLE_REQUEST => Some("dri2::CreateDrawable"),
+            dri2::DESTROY_DRAWABLE_REQUEST => Some("dri2::DestroyDrawable"),
+            dri2::GET_BUFFERS_REQUEST => Some("dri2::GetBuffers"),
+            dri2::COPY_REGION_REQUEST => Some("dri2::CopyRegion"),
+            dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => Some("dri2::GetBuffersWithFormat"),
+            dri2::SWAP_BUFFERS_REQUEST => Some("dri2::SwapBuffers"),
+            dri2::GET_MSC_REQUEST => Some("dri2::GetMSC"),
+            dri2::WAIT_MSC_REQUEST => Some("dri2::WaitMSC"),
+            dri2::WAIT_SBC_REQUEST => Some("dri2::WaitSBC"),
+            dri2::SWAP_INTERVAL_REQUEST => Some("dri2::SwapInterval"),
+            dri2::GET_PARAM_REQUEST => Some("dri2::GetParam"),
+            _ => None,
+        },
+        #[cfg(feature = "dri3")]
+        Some((dri3::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            dri3::QUERY_VERSION_REQUEST => Some("dri3::QueryVersion"),
+            dri3::OPEN_REQUEST => Some("dri3::Open"),
+            dri3::PIXMAP_FROM_BUFFER_REQUEST => Some("dri3::PixmapFromBuffer"),
+            dri3::BUFFER_FROM_PIXMAP_REQUEST => Some("dri3::BufferFromPixmap"),
+            dri3::FENCE_FROM_FD_REQUEST => Some("dri3::FenceFromFD"),
+            dri3::FD_FROM_FENCE_REQUEST => Some("dri3::FDFromFence"),
+            dri3::GET_SUPPORTED_MODIFIERS_REQUEST => Some("dri3::GetSupportedModifiers"),
+            dri3::PIXMAP_FROM_BUFFERS_REQUEST => Some("dri3::PixmapFromBuffers"),
+            dri3::BUFFERS_FROM_PIXMAP_REQUEST => Some("dri3::BuffersFromPixmap"),
+            _ => None,
+        },
+        Some((ge::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            ge::QUERY_VERSION_REQUEST => Some("ge::QueryVersion"),
+            _ => None,
+        },
+        #[cfg(feature = "glx")]
+        Some((glx::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            glx::RENDER_REQUEST => Some("glx::Render"),
+            glx::RENDER_LARGE_REQUEST => Some("glx::RenderLarge"),
+            glx::CREATE_CONTEXT_REQUEST => Some("glx::CreateContext"),
+            glx::DESTROY_CONTEXT_REQUEST => Some("glx::DestroyContext"),
+            glx::MAKE_CURRENT_REQUEST => Some("glx::MakeCurrent"),
+            glx::IS_DIRECT_REQUEST => Some("glx::IsDirect"),
+            glx::QUERY_VERSION_REQUEST => Some("glx::QueryVersion"),
+            glx::WAIT_GL_REQUEST => Some("glx::WaitGL"),
+            glx::WAIT_X_REQUEST => Some("glx::WaitX"),
+            glx::COPY_CONTEXT_REQUEST => Some("glx::CopyContext"),
+            glx::SWAP_BUFFERS_REQUEST => Some("glx::SwapBuffers"),
+            glx::USE_X_FONT_REQUEST => Some("glx::UseXFont"),
+            glx::CREATE_GLX_PIXMAP_REQUEST => Some("glx::CreateGLXPixmap"),
+            glx::GET_VISUAL_CONFIGS_REQUEST => Some("glx::GetVisualConfigs"),
+            glx::DESTROY_GLX_PIXMAP_REQUEST => Some("glx::DestroyGLXPixmap"),
+            glx::VENDOR_PRIVATE_REQUEST => Some("glx::VendorPrivate"),
+            glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => Some("glx::VendorPrivateWithReply"),
+            glx::QUERY_EXTENSIONS_STRING_REQUEST => Some("glx::QueryExtensionsString"),
+            glx::QUERY_SERVER_STRING_REQUEST => Some("glx::QueryServerString"),
+            glx::CLIENT_INFO_REQUEST => Some("glx::ClientInfo"),
+            glx::GET_FB_CONFIGS_REQUEST => Some("glx::GetFBConfigs"),
+            glx::CREATE_PIXMAP_REQUEST => Some("glx::CreatePixmap"),
+            glx::DESTROY_PIXMAP_REQUEST => Some("glx::DestroyPixmap"),
+            glx::CREATE_NEW_CONTEXT_REQUEST => Some("glx::CreateNewContext"),
+            glx::QUERY_CONTEXT_REQUEST => Some("glx::QueryContext"),
+            glx::MAKE_CONTEXT_CURRENT_REQUEST => Some("glx::MakeContextCurrent"),
+            glx::CREATE_PBUFFER_REQUEST => Some("glx::CreatePbuffer"),
+            glx::DESTROY_PBUFFER_REQUEST => Some("glx::DestroyPbuffer"),
+            glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => Some("glx::GetDrawableAttributes"),
+            glx::CHANGE_DRAWABLE_ATTRIBUTES_REQUEST => Some("glx::ChangeDrawableAttributes"),
+            glx::CREATE_WINDOW_REQUEST => Some("glx::CreateWindow"),
+            glx::DELETE_WINDOW_REQUEST => Some("glx::DeleteWindow"),
+            glx::SET_CLIENT_INFO_ARB_REQUEST => Some("glx::SetClientInfoARB"),
+            glx::CREATE_CONTEXT_ATTRIBS_ARB_REQUEST => Some("glx::CreateContextAttribsARB"),
+            glx::SET_CLIENT_INFO2_ARB_REQUEST => Some("glx::SetClientInfo2ARB"),
+            glx::NEW_LIST_REQUEST => Some("glx::NewList"),
+            glx::END_LIST_REQUEST => Some("glx::EndList"),
+            glx::DELETE_LISTS_REQUEST => Some("glx::DeleteLists"),
+            glx::GEN_LISTS_REQUEST => Some("glx::GenLists"),
+            glx::FEEDBACK_BUFFER_REQUEST => Some("glx::FeedbackBuffer"),
+            glx::SELECT_BUFFER_REQUEST => Some("glx::SelectBuffer"),
+            glx::RENDER_MODE_REQUEST => Some("glx::RenderMode"),
+            glx::FINISH_REQUEST => Some("glx::Finish"),
+            glx::PIXEL_STOREF_REQUEST => Some("glx::PixelStoref"),
+            glx::PIXEL_STOREI_REQUEST => Some("glx::PixelStorei"),
+            glx::READ_PIXELS_REQUEST => Some("glx::ReadPixels"),
+            glx::GET_BOOLEANV_REQUEST => Some("glx::GetBooleanv"),
+            glx::GET_CLIP_PLANE_REQUEST => Some("glx::GetClipPlane"),
+            glx::GET_DOUBLEV_REQUEST => Some("glx::GetDoublev"),
+            glx::GET_ERROR_REQUEST => Some("glx::GetError"),
+            glx::GET_FLOATV_REQUEST => Some("glx::GetFloatv"),
+            glx::GET_INTEGERV_REQUEST => Some("glx::GetIntegerv"),
+            glx::GET_LIGHTFV_REQUEST => Some("glx::GetLightfv"),
+            glx::GET_LIGHTIV_REQUEST => Some("glx::GetLightiv"),
+            glx::GET_MAPDV_REQUEST => Some("glx::GetMapdv"),
+            glx::GET_MAPFV_REQUEST => Some("glx::GetMapfv"),
+            glx::GET_MAPIV_REQUEST => Some("glx::GetMapiv"),
+            glx::GET_MATERIALFV_REQUEST => Some("glx::GetMaterialfv"),
+            glx::GET_MATERIALIV_REQUEST => Some("glx::GetMaterialiv"),
+            glx::GET_PIXEL_MAPFV_REQUEST => Some("glx::GetPixelMapfv"),
+            glx::GET_PIXEL_MAPUIV_REQUEST => Some("glx::GetPixelMapuiv"),
+            glx::GET_PIXEL_MAPUSV_REQUEST => Some("glx::GetPixelMapusv"),
+            glx::GET_POLYGON_STIPPLE_REQUEST => Some("glx::GetPolygonStipple"),
+            glx::GET_STRING_REQUEST => Some("glx::GetString"),
+            glx::GET_TEX_ENVFV_REQUEST => Some("glx::GetTexEnvfv"),
+            glx::GET_TEX_ENVIV_REQUEST => Some("glx::GetTexEnviv"),
+            glx::GET_TEX_GENDV_REQUEST => Some("glx::GetTexGendv"),
+            glx::GET_TEX_GENFV_REQUEST => Some("glx::GetTexGenfv"),
+            glx::GET_TEX_GENIV_REQUEST => Some("glx::GetTexGeniv"),
+            glx::GET_TEX_IMAGE_REQUEST => Some("glx::GetTexImage"),
+            glx::GET_TEX_PARAMETERFV_REQUEST => Some("glx::GetTexParameterfv"),
+            glx::GET_TEX_PARAMETERIV_REQUEST => Some("glx::GetTexParameteriv"),
+            glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => Some("glx::GetTexLevelParameterfv"),
+            glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => Some("glx::GetTexLevelParameteriv"),
+            glx::IS_ENABLED_REQUEST => Some("glx::IsEnabled"),
+            glx::IS_LIST_REQUEST => Some("glx::IsList"),
+            glx::FLUSH_REQUEST => Some("glx::Flush"),
+            glx::ARE_TEXTURES_RESIDENT_REQUEST => Some("glx::AreTexturesResident"),
+            glx::DELETE_TEXTURES_REQUEST => Some("glx::DeleteTextures"),
+            glx::GEN_TEXTURES_REQUEST => Some("glx::GenTextures"),
+            glx::IS_TEXTURE_REQUEST => Some("glx::IsTexture"),
+            glx::GET_COLOR_TABLE_REQUEST => Some("glx::GetColorTable"),
+            glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => Some("glx::GetColorTableParameterfv"),
+            glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => Some("glx::GetColorTableParameteriv"),
+            glx::GET_CONVOLUTION_FILTER_REQUEST => Some("glx::GetConvolutionFilter"),
+            glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => Some("glx::GetConvolutionParameterfv"),
+            glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => Some("glx::GetConvolutionParameteriv"),
+            glx::GET_SEPARABLE_FILTER_REQUEST => Some("glx::GetSeparableFilter"),
+            glx::GET_HISTOGRAM_REQUEST => Some("glx::GetHistogram"),
+            glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => Some("glx::GetHistogramParameterfv"),
+            glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => Some("glx::GetHistogramParameteriv"),
+            glx::GET_MINMAX_REQUEST => Some("glx::GetMinmax"),
+            glx::GET_MINMAX_PARAMETERFV_REQUEST => Some("glx::GetMinmaxParameterfv"),
+            glx::GET_MINMAX_PARAMETERIV_REQUEST => Some("glx::GetMinmaxParameteriv"),
+            glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => Some("glx::GetCompressedTexImageARB"),
+            glx::DELETE_QUERIES_ARB_REQUEST => Some("glx::DeleteQueriesARB"),
+            glx::GEN_QUERIES_ARB_REQUEST => Some("glx::GenQueriesARB"),
+            glx::IS_QUERY_ARB_REQUEST => Some("glx::IsQueryARB"),
+            glx::GET_QUERYIV_ARB_REQUEST => Some("glx::GetQueryivARB"),
+            glx::GET_QUERY_OBJECTIV_ARB_REQUEST => Some("glx::GetQueryObjectivARB"),
+            glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => Some("glx::GetQueryObjectuivARB"),
+            _ => None,
+        },
+        #[cfg(feature = "present")]
+        Some((present::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            present::QUERY_VERSION_REQUEST => Some("present::QueryVersion"),
+            present::PIXMAP_REQUEST => Some("present::Pixmap"),
+            present::NOTIFY_MSC_REQUEST => Some("present::NotifyMSC"),
+            present::SELECT_INPUT_REQUEST => Some("present::SelectInput"),
+            present::QUERY_CAPABILITIES_REQUEST => Some("present::QueryCapabilities"),
+            _ => None,
+        },
+        #[cfg(feature = "randr")]
+        Some((randr::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            randr::QUERY_VERSION_REQUEST => Some("randr::QueryVersion"),
+            randr::SET_SCREEN_CONFIG_REQUEST => Some("randr::SetScreenConfig"),
+            randr::SELECT_INPUT_REQUEST => Some("randr::SelectInput"),
+            randr::GET_SCREEN_INFO_REQUEST => Some("randr::GetScreenInfo"),
+            randr::GET_SCREEN_SIZE_RANGE_REQUEST => Some("randr::GetScreenSizeRange"),
+            randr::SET_SCREEN_SIZE_REQUEST => Some("randr::SetScreenSize"),
+            randr::GET_SCREEN_RESOURCES_REQUEST => Some("randr::GetScreenResources"),
+            randr::GET_OUTPUT_INFO_REQUEST => Some("randr::GetOutputInfo"),
+            randr::LIST_OUTPUT_PROPERTIES_REQUEST => Some("randr::ListOutputProperties"),
+            randr::QUERY_OUTPUT_PROPERTY_REQUEST => Some("randr::QueryOutputProperty"),
+            randr::CONFIGURE_OUTPUT_PROPERTY_REQUEST => Some("randr::ConfigureOutputProperty"),
+            randr::CHANGE_OUTPUT_PROPERTY_REQUEST => Some("randr::ChangeOutputProperty"),
+            randr::DELETE_OUTPUT_PROPERTY_REQUEST => Some("randr::DeleteOutputProperty"),
+            randr::GET_OUTPUT_PROPERTY_REQUEST => Some("randr::GetOutputProperty"),
+            randr::CREATE_MODE_REQUEST => Some("randr::CreateMode"),
+            randr::DESTROY_MODE_REQUEST => Some("randr::DestroyMode"),
+            randr::ADD_OUTPUT_MODE_REQUEST => Some("randr::AddOutputMode"),
+            randr::DELETE_OUTPUT_MODE_REQUEST => Some("randr::DeleteOutputMode"),
+            randr::GET_CRTC_INFO_REQUEST => Some("randr::GetCrtcInfo"),
+            randr::SET_CRTC_CONFIG_REQUEST => Some("randr::SetCrtcConfig"),
+            randr::GET_CRTC_GAMMA_SIZE_REQUEST => Some("randr::GetCrtcGammaSize"),
+            randr::GET_CRTC_GAMMA_REQUEST => Some("randr::GetCrtcGamma"),
+            randr::SET_CRTC_GAMMA_REQUEST => Some("randr::SetCrtcGamma"),
+            randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => Some("randr::GetScreenResourcesCurrent"),
+            randr::SET_CRTC_TRANSFORM_REQUEST => Some("randr::SetCrtcTransform"),
+            randr::GET_CRTC_TRANSFORM_REQUEST => Some("randr::GetCrtcTransform"),
+            randr::GET_PANNING_REQUEST => Some("randr::GetPanning"),
+            randr::SET_PANNING_REQUEST => Some("randr::SetPanning"),
+            randr::SET_OUTPUT_PRIMARY_REQUEST => Some("randr::SetOutputPrimary"),
+            randr::GET_OUTPUT_PRIMARY_REQUEST => Some("randr::GetOutputPrimary"),
+            randr::GET_PROVIDERS_REQUEST => Some("randr::GetProviders"),
+            randr::GET_PROVIDER_INFO_REQUEST => Some("randr::GetProviderInfo"),
+            randr::SET_PROVIDER_OFFLOAD_SINK_REQUEST => Some("randr::SetProviderOffloadSink"),
+            randr::SET_PROVIDER_OUTPUT_SOURCE_REQUEST => Some("randr::SetProviderOutputSource"),
+            randr::LIST_PROVIDER_PROPERTIES_REQUEST => Some("randr::ListProviderProperties"),
+            randr::QUERY_PROVIDER_PROPERTY_REQUEST => Some("randr::QueryProviderProperty"),
+            randr::CONFIGURE_PROVIDER_PROPERTY_REQUEST => Some("randr::ConfigureProviderProperty"),
+            randr::CHANGE_PROVIDER_PROPERTY_REQUEST => Some("randr::ChangeProviderProperty"),
+            randr::DELETE_PROVIDER_PROPERTY_REQUEST => Some("randr::DeleteProviderProperty"),
+            randr::GET_PROVIDER_PROPERTY_REQUEST => Some("randr::GetProviderProperty"),
+            randr::GET_MONITORS_REQUEST => Some("randr::GetMonitors"),
+            randr::SET_MONITOR_REQUEST => Some("randr::SetMonitor"),
+            randr::DELETE_MONITOR_REQUEST => Some("randr::DeleteMonitor"),
+            randr::CREATE_LEASE_REQUEST => Some("randr::CreateLease"),
+            randr::FREE_LEASE_REQUEST => Some("randr::FreeLease"),
+            _ => None,
+        },
+        #[cfg(feature = "record")]
+        Some((record::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            record::QUERY_VERSION_REQUEST => Some("record::QueryVersion"),
+            record::CREATE_CONTEXT_REQUEST => Some("record::CreateContext"),
+            record::REGISTER_CLIENTS_REQUEST => Some("record::RegisterClients"),
+            record::UNREGISTER_CLIENTS_REQUEST => Some("record::UnregisterClients"),
+            record::GET_CONTEXT_REQUEST => Some("record::GetContext"),
+            record::ENABLE_CONTEXT_REQUEST => Some("record::EnableContext"),
+            record::DISABLE_CONTEXT_REQUEST => Some("record::DisableContext"),
+            record::FREE_CONTEXT_REQUEST => Some("record::FreeContext"),
+            _ => None,
+        },
+        #[cfg(feature = "render")]
+        Some((render::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            render::QUERY_VERSION_REQUEST => Some("render::QueryVersion"),
+            render::QUERY_PICT_FORMATS_REQUEST => Some("render::QueryPictFormats"),
+            render::QUERY_PICT_INDEX_VALUES_REQUEST => Some("render::QueryPictIndexValues"),
+            render::CREATE_PICTURE_REQUEST => Some("render::CreatePicture"),
+            render::CHANGE_PICTURE_REQUEST => Some("render::ChangePicture"),
+            render::SET_PICTURE_CLIP_RECTANGLES_REQUEST => Some("render::SetPictureClipRectangles"),
+            render::FREE_PICTURE_REQUEST => Some("render::FreePicture"),
+            render::COMPOSITE_REQUEST => Some("render::Composite"),
+            render::TRAPEZOIDS_REQUEST => Some("render::Trapezoids"),
+            render::TRIANGLES_REQUEST => Some("render::Triangles"),
+            render::TRI_STRIP_REQUEST => Some("render::TriStrip"),
+            render::TRI_FAN_REQUEST => Some("render::TriFan"),
+            render::CREATE_GLYPH_SET_REQUEST => Some("render::CreateGlyphSet"),
+            render::REFERENCE_GLYPH_SET_REQUEST => Some("render::ReferenceGlyphSet"),
+            render::FREE_GLYPH_SET_REQUEST => Some("render::FreeGlyphSet"),
+            render::ADD_GLYPHS_REQUEST => Some("render::AddGlyphs"),
+            render::FREE_GLYPHS_REQUEST => Some("render::FreeGlyphs"),
+            render::COMPOSITE_GLYPHS8_REQUEST => Some("render::CompositeGlyphs8"),
+            render::COMPOSITE_GLYPHS16_REQUEST => Some("render::CompositeGlyphs16"),
+            render::COMPOSITE_GLYPHS32_REQUEST => Some("render::CompositeGlyphs32"),
+            render::FILL_RECTANGLES_REQUEST => Some("render::FillRectangles"),
+            render::CREATE_CURSOR_REQUEST => Some("render::CreateCursor"),
+            render::SET_PICTURE_TRANSFORM_REQUEST => Some("render::SetPictureTransform"),
+            render::QUERY_FILTERS_REQUEST => Some("render::QueryFilters"),
+            render::SET_PICTURE_FILTER_REQUEST => Some("render::SetPictureFilter"),
+            render::CREATE_ANIM_CURSOR_REQUEST => Some("render::CreateAnimCursor"),
+            render::ADD_TRAPS_REQUEST => Some("render::AddTraps"),
+            render::CREATE_SOLID_FILL_REQUEST => Some("render::CreateSolidFill"),
+            render::CREATE_LINEAR_GRADIENT_REQUEST => Some("render::CreateLinearGradient"),
+            render::CREATE_RADIAL_GRADIENT_REQUEST => Some("render::CreateRadialGradient"),
+            render::CREATE_CONICAL_GRADIENT_REQUEST => Some("render::CreateConicalGradient"),
+            _ => None,
+        },
+        #[cfg(feature = "res")]
+        Some((res::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            res::QUERY_VERSION_REQUEST => Some("res::QueryVersion"),
+            res::QUERY_CLIENTS_REQUEST => Some("res::QueryClients"),
+            res::QUERY_CLIENT_RESOURCES_REQUEST => Some("res::QueryClientResources"),
+            res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => Some("res::QueryClientPixmapBytes"),
+            res::QUERY_CLIENT_IDS_REQUEST => Some("res::QueryClientIds"),
+            res::QUERY_RESOURCE_BYTES_REQUEST => Some("res::QueryResourceBytes"),
+            _ => None,
+        },
+        #[cfg(feature = "screensaver")]
+        Some((screensaver::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            screensaver::QUERY_VERSION_REQUEST => Some("screensaver::QueryVersion"),
+            screensaver::QUERY_INFO_REQUEST => Some("screensaver::QueryInfo"),
+            screensaver::SELECT_INPUT_REQUEST => Some("screensaver::SelectInput"),
+            screensaver::SET_ATTRIBUTES_REQUEST => Some("screensaver::SetAttributes"),
+            screensaver::UNSET_ATTRIBUTES_REQUEST => Some("screensaver::UnsetAttributes"),
+            screensaver::SUSPEND_REQUEST => Some("screensaver::Suspend"),
+            _ => None,
+        },
+        #[cfg(feature = "shape")]
+        Some((shape::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            shape::QUERY_VERSION_REQUEST => Some("shape::QueryVersion"),
+            shape::RECTANGLES_REQUEST => Some("shape::Rectangles"),
+            shape::MASK_REQUEST => Some("shape::Mask"),
+            shape::COMBINE_REQUEST => Some("shape::Combine"),
+            shape::OFFSET_REQUEST => Some("shape::Offset"),
+            shape::QUERY_EXTENTS_REQUEST => Some("shape::QueryExtents"),
+            shape::SELECT_INPUT_REQUEST => Some("shape::SelectInput"),
+            shape::INPUT_SELECTED_REQUEST => Some("shape::InputSelected"),
+            shape::GET_RECTANGLES_REQUEST => Some("shape::GetRectangles"),
+            _ => None,
+        },
+        #[cfg(feature = "shm")]
+        Some((shm::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            shm::QUERY_VERSION_REQUEST => Some("shm::QueryVersion"),
+            shm::ATTACH_REQUEST => Some("shm::Attach"),
+            shm::DETACH_REQUEST => Some("shm::Detach"),
+            shm::PUT_IMAGE_REQUEST => Some("shm::PutImage"),
+            shm::GET_IMAGE_REQUEST => Some("shm::GetImage"),
+            shm::CREATE_PIXMAP_REQUEST => Some("shm::CreatePixmap"),
+            shm::ATTACH_FD_REQUEST => Some("shm::AttachFd"),
+            shm::CREATE_SEGMENT_REQUEST => Some("shm::CreateSegment"),
+            _ => None,
+        },
+        #[cfg(feature = "sync")]
+        Some((sync::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            sync::INITIALIZE_REQUEST => Some("sync::Initialize"),
+            sync::LIST_SYSTEM_COUNTERS_REQUEST => Some("sync::ListSystemCounters"),
+            sync::CREATE_COUNTER_REQUEST => Some("sync::CreateCounter"),
+            sync::DESTROY_COUNTER_REQUEST => Some("sync::DestroyCounter"),
+            sync::QUERY_COUNTER_REQUEST => Some("sync::QueryCounter"),
+            sync::AWAIT_REQUEST => Some("sync::Await"),
+            sync::CHANGE_COUNTER_REQUEST => Some("sync::ChangeCounter"),
+            sync::SET_COUNTER_REQUEST => Some("sync::SetCounter"),
+            sync::CREATE_ALARM_REQUEST => Some("sync::CreateAlarm"),
+            sync::CHANGE_ALARM_REQUEST => Some("sync::ChangeAlarm"),
+            sync::DESTROY_ALARM_REQUEST => Some("sync::DestroyAlarm"),
+            sync::QUERY_ALARM_REQUEST => Some("sync::QueryAlarm"),
+            sync::SET_PRIORITY_REQUEST => Some("sync::SetPriority"),
+            sync::GET_PRIORITY_REQUEST => Some("sync::GetPriority"),
+            sync::CREATE_FENCE_REQUEST => Some("sync::CreateFence"),
+            sync::TRIGGER_FENCE_REQUEST => Some("sync::TriggerFence"),
+            sync::RESET_FENCE_REQUEST => Some("sync::ResetFence"),
+            sync::DESTROY_FENCE_REQUEST => Some("sync::DestroyFence"),
+            sync::QUERY_FENCE_REQUEST => Some("sync::QueryFence"),
+            sync::AWAIT_FENCE_REQUEST => Some("sync::AwaitFence"),
+            _ => None,
+        },
+        Some((xc_misc::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xc_misc::GET_VERSION_REQUEST => Some("xc_misc::GetVersion"),
+            xc_misc::GET_XID_RANGE_REQUEST => Some("xc_misc::GetXIDRange"),
+            xc_misc::GET_XID_LIST_REQUEST => Some("xc_misc::GetXIDList"),
+            _ => None,
+        },
+        #[cfg(feature = "xevie")]
+        Some((xevie::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xevie::QUERY_VERSION_REQUEST => Some("xevie::QueryVersion"),
+            xevie::START_REQUEST => Some("xevie::Start"),
+            xevie::END_REQUEST => Some("xevie::End"),
+            xevie::SEND_REQUEST => Some("xevie::Send"),
+            xevie::SELECT_INPUT_REQUEST => Some("xevie::SelectInput"),
+            _ => None,
+        },
+        #[cfg(feature = "xf86dri")]
+        Some((xf86dri::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xf86dri::QUERY_VERSION_REQUEST => Some("xf86dri::QueryVersion"),
+            xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => Some("xf86dri::QueryDirectRenderingCapable"),
+            xf86dri::OPEN_CONNECTION_REQUEST => Some("xf86dri::OpenConnection"),
+            xf86dri::CLOSE_CONNECTION_REQUEST => Some("xf86dri::CloseConnection"),
+            xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => Some("xf86dri::GetClientDriverName"),
+            xf86dri::CREATE_CONTEXT_REQUEST => Some("xf86dri::CreateContext"),
+            xf86dri::DESTROY_CONTEXT_REQUEST => Some("xf86dri::DestroyContext"),
+            xf86dri::CREATE_DRAWABLE_REQUEST => Some("xf86dri::CreateDrawable"),
+            xf86dri::DESTROY_DRAWABLE_REQUEST => Some("xf86dri::DestroyDrawable"),
+            xf86dri::GET_DRAWABLE_INFO_REQUEST => Some("xf86dri::GetDrawableInfo"),
+            xf86dri::GET_DEVICE_INFO_REQUEST => Some("xf86dri::GetDeviceInfo"),
+            xf86dri::AUTH_CONNECTION_REQUEST => Some("xf86dri::AuthConnection"),
+            _ => None,
+        },
+        #[cfg(feature = "xf86vidmode")]
+        Some((xf86vidmode::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xf86vidmode::QUERY_VERSION_REQUEST => Some("xf86vidmode::QueryVersion"),
+            xf86vidmode::GET_MODE_LINE_REQUEST => Some("xf86vidmode::GetModeLine"),
+            xf86vidmode::MOD_MODE_LINE_REQUEST => Some("xf86vidmode::ModModeLine"),
+            xf86vidmode::SWITCH_MODE_REQUEST => Some("xf86vidmode::SwitchMode"),
+            xf86vidmode::GET_MONITOR_REQUEST => Some("xf86vidmode::GetMonitor"),
+            xf86vidmode::LOCK_MODE_SWITCH_REQUEST => Some("xf86vidmode::LockModeSwitch"),
+            xf86vidmode::GET_ALL_MODE_LINES_REQUEST => Some("xf86vidmode::GetAllModeLines"),
+            xf86vidmode::ADD_MODE_LINE_REQUEST => Some("xf86vidmode::AddModeLine"),
+            xf86vidmode::DELETE_MODE_LINE_REQUEST => Some("xf86vidmode::DeleteModeLine"),
+            xf86vidmode::VALIDATE_MODE_LINE_REQUEST => Some("xf86vidmode::ValidateModeLine"),
+            xf86vidmode::SWITCH_TO_MODE_REQUEST => Some("xf86vidmode::SwitchToMode"),
+            xf86vidmode::GET_VIEW_PORT_REQUEST => Some("xf86vidmode::GetViewPort"),
+            xf86vidmode::SET_VIEW_PORT_REQUEST => Some("xf86vidmode::SetViewPort"),
+            xf86vidmode::GET_DOT_CLOCKS_REQUEST => Some("xf86vidmode::GetDotClocks"),
+            xf86vidmode::SET_CLIENT_VERSION_REQUEST => Some("xf86vidmode::SetClientVersion"),
+            xf86vidmode::SET_GAMMA_REQUEST => Some("xf86vidmode::SetGamma"),
+            xf86vidmode::GET_GAMMA_REQUEST => Some("xf86vidmode::GetGamma"),
+            xf86vidmode::GET_GAMMA_RAMP_REQUEST => Some("xf86vidmode::GetGammaRamp"),
+            xf86vidmode::SET_GAMMA_RAMP_REQUEST => Some("xf86vidmode::SetGammaRamp"),
+            xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => Some("xf86vidmode::GetGammaRampSize"),
+            xf86vidmode::GET_PERMISSIONS_REQUEST => Some("xf86vidmode::GetPermissions"),
+            _ => None,
+        },
+        #[cfg(feature = "xfixes")]
+        Some((xfixes::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xfixes::QUERY_VERSION_REQUEST => Some("xfixes::QueryVersion"),
+            xfixes::CHANGE_SAVE_SET_REQUEST => Some("xfixes::ChangeSaveSet"),
+            xfixes::SELECT_SELECTION_INPUT_REQUEST => Some("xfixes::SelectSelectionInput"),
+            xfixes::SELECT_CURSOR_INPUT_REQUEST => Some("xfixes::SelectCursorInput"),
+            xfixes::GET_CURSOR_IMAGE_REQUEST => Some("xfixes::GetCursorImage"),
+            xfixes::CREATE_REGION_REQUEST => Some("xfixes::CreateRegion"),
+            xfixes::CREATE_REGION_FROM_BITMAP_REQUEST => Some("xfixes::CreateRegionFromBitmap"),
+            xfixes::CREATE_REGION_FROM_WINDOW_REQUEST => Some("xfixes::CreateRegionFromWindow"),
+            xfixes::CREATE_REGION_FROM_GC_REQUEST => Some("xfixes::CreateRegionFromGC"),
+            xfixes::CREATE_REGION_FROM_PICTURE_REQUEST => Some("xfixes::CreateRegionFromPicture"),
+            xfixes::DESTROY_REGION_REQUEST => Some("xfixes::DestroyRegion"),
+            xfixes::SET_REGION_REQUEST => Some("xfixes::SetRegion"),
+            xfixes::COPY_REGION_REQUEST => Some("xfixes::CopyRegion"),
+            xfixes::UNION_REGION_REQUEST => Some("xfixes::UnionRegion"),
+            xfixes::INTERSECT_REGION_REQUEST => Some("xfixes::IntersectRegion"),
+            xfixes::SUBTRACT_REGION_REQUEST => Some("xfixes::SubtractRegion"),
+            xfixes::INVERT_REGION_REQUEST => Some("xfixes::InvertRegion"),
+            xfixes::TRANSLATE_REGION_REQUEST => Some("xfixes::TranslateRegion"),
+            xfixes::REGION_EXTENTS_REQUEST => Some("xfixes::RegionExtents"),
+            xfixes::FETCH_REGION_REQUEST => Some("xfixes::FetchRegion"),
+            xfixes::SET_GC_CLIP_REGION_REQUEST => Some("xfixes::SetGCClipRegion"),
+            xfixes::SET_WINDOW_SHAPE_REGION_REQUEST => Some("xfixes::SetWindowShapeRegion"),
+            xfixes::SET_PICTURE_CLIP_REGION_REQUEST => Some("xfixes::SetPictureClipRegion"),
+            xfixes::SET_CURSOR_NAME_REQUEST => Some("xfixes::SetCursorName"),
+            xfixes::GET_CURSOR_NAME_REQUEST => Some("xfixes::GetCursorName"),
+            xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => Some("xfixes::GetCursorImageAndName"),
+            xfixes::CHANGE_CURSOR_REQUEST => Some("xfixes::ChangeCursor"),
+            xfixes::CHANGE_CURSOR_BY_NAME_REQUEST => Some("xfixes::ChangeCursorByName"),
+            xfixes::EXPAND_REGION_REQUEST => Some("xfixes::ExpandRegion"),
+            xfixes::HIDE_CURSOR_REQUEST => Some("xfixes::HideCursor"),
+            xfixes::SHOW_CURSOR_REQUEST => Some("xfixes::ShowCursor"),
+            xfixes::CREATE_POINTER_BARRIER_REQUEST => Some("xfixes::CreatePointerBarrier"),
+            xfixes::DELETE_POINTER_BARRIER_REQUEST => Some("xfixes::DeletePointerBarrier"),
+            _ => None,
+        },
+        #[cfg(feature = "xinerama")]
+        Some((xinerama::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xinerama::QUERY_VERSION_REQUEST => Some("xinerama::QueryVersion"),
+            xinerama::GET_STATE_REQUEST => Some("xinerama::GetState"),
+            xinerama::GET_SCREEN_COUNT_REQUEST => Some("xinerama::GetScreenCount"),
+            xinerama::GET_SCREEN_SIZE_REQUEST => Some("xinerama::GetScreenSize"),
+            xinerama::IS_ACTIVE_REQUEST => Some("xinerama::IsActive"),
+            xinerama::QUERY_SCREENS_REQUEST => Some("xinerama::QueryScreens"),
+            _ => None,
+        },
+        #[cfg(feature = "xinput")]
+        Some((xinput::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xinput::GET_EXTENSION_VERSION_REQUEST => Some("xinput::GetExtensionVersion"),
+            xinput::LIST_INPUT_DEVICES_REQUEST => Some("xinput::ListInputDevices"),
+            xinput::OPEN_DEVICE_REQUEST => Some("xinput::OpenDevice"),
+            xinput::CLOSE_DEVICE_REQUEST => Some("xinput::CloseDevice"),
+            xinput::SET_DEVICE_MODE_REQUEST => Some("xinput::SetDeviceMode"),
+            xinput::SELECT_EXTENSION_EVENT_REQUEST => Some("xinput::SelectExtensionEvent"),
+            xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => Some("xinput::GetSelectedExtensionEvents"),
+            xinput::CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Some("xinput::ChangeDeviceDontPropagateList"),
+            xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Some("xinput::GetDeviceDontPropagateList"),
+            xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => Some("xinput::GetDeviceMotionEvents"),
+            xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => Some("xinput::ChangeKeyboardDevice"),
+            xinput::CHANGE_POINTER_DEVICE_REQUEST => Some("xinput::ChangePointerDevice"),
+            xinput::GRAB_DEVICE_REQUEST => Some("xinput::GrabDevice"),
+            xinput::UNGRAB_DEVICE_REQUEST => Some("xinput::UngrabDevice"),
+            xinput::GRAB_DEVICE_KEY_REQUEST => Some("xinput::GrabDeviceKey"),
+            xinput::UNGRAB_DEVICE_KEY_REQUEST => Some("xinput::UngrabDeviceKey"),
+            xinput::GRAB_DEVICE_BUTTON_REQUEST => Some("xinput::GrabDeviceButton"),
+            xinput::UNGRAB_DEVICE_BUTTON_REQUEST => Some("xinput::UngrabDeviceButton"),
+            xinput::ALLOW_DEVICE_EVENTS_REQUEST => Some("xinput::AllowDeviceEvents"),
+            xinput::GET_DEVICE_FOCUS_REQUEST => Some("xinput::GetDeviceFocus"),
+            xinput::SET_DEVICE_FOCUS_REQUEST => Some("xinput::SetDeviceFocus"),
+            xinput::GET_FEEDBACK_CONTROL_REQUEST => Some("xinput::GetFeedbackControl"),
+            xinput::CHANGE_FEEDBACK_CONTROL_REQUEST => Some("xinput::ChangeFeedbackControl"),
+            xinput::GET_DEVICE_KEY_MAPPING_REQUEST => Some("xinput::GetDeviceKeyMapping"),
+            xinput::CHANGE_DEVICE_KEY_MAPPING_REQUEST => Some("xinput::ChangeDeviceKeyMapping"),
+            xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => Some("xinput::GetDeviceModifierMapping"),
+            xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => Some("xinput::SetDeviceModifierMapping"),
+            xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => Some("xinput::GetDeviceButtonMapping"),
+            xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => Some("xinput::SetDeviceButtonMapping"),
+            xinput::QUERY_DEVICE_STATE_REQUEST => Some("xinput::QueryDeviceState"),
+            xinput::DEVICE_BELL_REQUEST => Some("xinput::DeviceBell"),
+            xinput::SET_DEVICE_VALUATORS_REQUEST => Some("xinput::SetDeviceValuators"),
+            xinput::GET_DEVICE_CONTROL_REQUEST => Some("xinput::GetDeviceControl"),
+            xinput::CHANGE_DEVICE_CONTROL_REQUEST => Some("xinput::ChangeDeviceControl"),
+            xinput::LIST_DEVICE_PROPERTIES_REQUEST => Some("xinput::ListDeviceProperties"),
+            xinput::CHANGE_DEVICE_PROPERTY_REQUEST => Some("xinput::ChangeDeviceProperty"),
+            xinput::DELETE_DEVICE_PROPERTY_REQUEST => Some("xinput::DeleteDeviceProperty"),
+            xinput::GET_DEVICE_PROPERTY_REQUEST => Some("xinput::GetDeviceProperty"),
+            xinput::XI_QUERY_POINTER_REQUEST => Some("xinput::XIQueryPointer"),
+            xinput::XI_WARP_POINTER_REQUEST => Some("xinput::XIWarpPointer"),
+            xinput::XI_CHANGE_CURSOR_REQUEST => Some("xinput::XIChangeCursor"),
+            xinput::XI_CHANGE_HIERARCHY_REQUEST => Some("xinput::XIChangeHierarchy"),
+            xinput::XI_SET_CLIENT_POINTER_REQUEST => Some("xinput::XISetClientPointer"),
+            xinput::XI_GET_CLIENT_POINTER_REQUEST => Some("xinput::XIGetClientPointer"),
+            xinput::XI_SELECT_EVENTS_REQUEST => Some("xinput::XISelectEvents"),
+            xinput::XI_QUERY_VERSION_REQUEST => Some("xinput::XIQueryVersion"),
+            xinput::XI_QUERY_DEVICE_REQUEST => Some("xinput::XIQueryDevice"),
+            xinput::XI_SET_FOCUS_REQUEST => Some("xinput::XISetFocus"),
+            xinput::XI_GET_FOCUS_REQUEST => Some("xinput::XIGetFocus"),
+            xinput::XI_GRAB_DEVICE_REQUEST => Some("xinput::XIGrabDevice"),
+            xinput::XI_UNGRAB_DEVICE_REQUEST => Some("xinput::XIUngrabDevice"),
+            xinput::XI_ALLOW_EVENTS_REQUEST => Some("xinput::XIAllowEvents"),
+            xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => Some("xinput::XIPassiveGrabDevice"),
+            xinput::XI_PASSIVE_UNGRAB_DEVICE_REQUEST => Some("xinput::XIPassiveUngrabDevice"),
+            xinput::XI_LIST_PROPERTIES_REQUEST => Some("xinput::XIListProperties"),
+            xinput::XI_CHANGE_PROPERTY_REQUEST => Some("xinput::XIChangeProperty"),
+            xinput::XI_DELETE_PROPERTY_REQUEST => Some("xinput::XIDeleteProperty"),
+            xinput::XI_GET_PROPERTY_REQUEST => Some("xinput::XIGetProperty"),
+            xinput::XI_GET_SELECTED_EVENTS_REQUEST => Some("xinput::XIGetSelectedEvents"),
+            xinput::XI_BARRIER_RELEASE_POINTER_REQUEST => Some("xinput::XIBarrierReleasePointer"),
+            xinput::SEND_EXTENSION_EVENT_REQUEST => Some("xinput::SendExtensionEvent"),
+            _ => None,
+        },
+        #[cfg(feature = "xkb")]
+        Some((xkb::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xkb::USE_EXTENSION_REQUEST => Some("xkb::UseExtension"),
+            xkb::SELECT_EVENTS_REQUEST => Some("xkb::SelectEvents"),
+            xkb::BELL_REQUEST => Some("xkb::Bell"),
+            xkb::GET_STATE_REQUEST => Some("xkb::GetState"),
+            xkb::LATCH_LOCK_STATE_REQUEST => Some("xkb::LatchLockState"),
+            xkb::GET_CONTROLS_REQUEST => Some("xkb::GetControls"),
+            xkb::SET_CONTROLS_REQUEST => Some("xkb::SetControls"),
+            xkb::GET_MAP_REQUEST => Some("xkb::GetMap"),
+            xkb::SET_MAP_REQUEST => Some("xkb::SetMap"),
+            xkb::GET_COMPAT_MAP_REQUEST => Some("xkb::GetCompatMap"),
+            xkb::SET_COMPAT_MAP_REQUEST => Some("xkb::SetCompatMap"),
+            xkb::GET_INDICATOR_STATE_REQUEST => Some("xkb::GetIndicatorState"),
+            xkb::GET_INDICATOR_MAP_REQUEST => Some("xkb::GetIndicatorMap"),
+            xkb::SET_INDICATOR_MAP_REQUEST => Some("xkb::SetIndicatorMap"),
+            xkb::GET_NAMED_INDICATOR_REQUEST => Some("xkb::GetNamedIndicator"),
+            xkb::SET_NAMED_INDICATOR_REQUEST => Some("xkb::SetNamedIndicator"),
+            xkb::GET_NAMES_REQUEST => Some("xkb::GetNames"),
+            xkb::SET_NAMES_REQUEST => Some("xkb::SetNames"),
+            xkb::PER_CLIENT_FLAGS_REQUEST => Some("xkb::PerClientFlags"),
+            xkb::LIST_COMPONENTS_REQUEST => Some("xkb::ListComponents"),
+            xkb::GET_KBD_BY_NAME_REQUEST => Some("xkb::GetKbdByName"),
+            xkb::GET_DEVICE_INFO_REQUEST => Some("xkb::GetDeviceInfo"),
+            xkb::SET_DEVICE_INFO_REQUEST => Some("xkb::SetDeviceInfo"),
+            xkb::SET_DEBUGGING_FLAGS_REQUEST => Some("xkb::SetDebuggingFlags"),
+            _ => None,
+        },
+        #[cfg(feature = "xprint")]
+        Some((xprint::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xprint::PRINT_QUERY_VERSION_REQUEST => Some("xprint::PrintQueryVersion"),
+            xprint::PRINT_GET_PRINTER_LIST_REQUEST => Some("xprint::PrintGetPrinterList"),
+            xprint::PRINT_REHASH_PRINTER_LIST_REQUEST => Some("xprint::PrintRehashPrinterList"),
+            xprint::CREATE_CONTEXT_REQUEST => Some("xprint::CreateContext"),
+            xprint::PRINT_SET_CONTEXT_REQUEST => Some("xprint::PrintSetContext"),
+            xprint::PRINT_GET_CONTEXT_REQUEST => Some("xprint::PrintGetContext"),
+            xprint::PRINT_DESTROY_CONTEXT_REQUEST => Some("xprint::PrintDestroyContext"),
+            xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => Some("xprint::PrintGetScreenOfContext"),
+            xprint::PRINT_START_JOB_REQUEST => Some("xprint::PrintStartJob"),
+            xprint::PRINT_END_JOB_REQUEST => Some("xprint::PrintEndJob"),
+            xprint::PRINT_START_DOC_REQUEST => Some("xprint::PrintStartDoc"),
+            xprint::PRINT_END_DOC_REQUEST => Some("xprint::PrintEndDoc"),
+            xprint::PRINT_PUT_DOCUMENT_DATA_REQUEST => Some("xprint::PrintPutDocumentData"),
+            xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => Some("xprint::PrintGetDocumentData"),
+            xprint::PRINT_START_PAGE_REQUEST => Some("xprint::PrintStartPage"),
+            xprint::PRINT_END_PAGE_REQUEST => Some("xprint::PrintEndPage"),
+            xprint::PRINT_SELECT_INPUT_REQUEST => Some("xprint::PrintSelectInput"),
+            xprint::PRINT_INPUT_SELECTED_REQUEST => Some("xprint::PrintInputSelected"),
+            xprint::PRINT_GET_ATTRIBUTES_REQUEST => Some("xprint::PrintGetAttributes"),
+            xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => Some("xprint::PrintGetOneAttributes"),
+            xprint::PRINT_SET_ATTRIBUTES_REQUEST => Some("xprint::PrintSetAttributes"),
+            xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => Some("xprint::PrintGetPageDimensions"),
+            xprint::PRINT_QUERY_SCREENS_REQUEST => Some("xprint::PrintQueryScreens"),
+            xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => Some("xprint::PrintSetImageResolution"),
+            xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => Some("xprint::PrintGetImageResolution"),
+            _ => None,
+        },
+        #[cfg(feature = "xselinux")]
+        Some((xselinux::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xselinux::QUERY_VERSION_REQUEST => Some("xselinux::QueryVersion"),
+            xselinux::SET_DEVICE_CREATE_CONTEXT_REQUEST => Some("xselinux::SetDeviceCreateContext"),
+            xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => Some("xselinux::GetDeviceCreateContext"),
+            xselinux::SET_DEVICE_CONTEXT_REQUEST => Some("xselinux::SetDeviceContext"),
+            xselinux::GET_DEVICE_CONTEXT_REQUEST => Some("xselinux::GetDeviceContext"),
+            xselinux::SET_WINDOW_CREATE_CONTEXT_REQUEST => Some("xselinux::SetWindowCreateContext"),
+            xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => Some("xselinux::GetWindowCreateContext"),
+            xselinux::GET_WINDOW_CONTEXT_REQUEST => Some("xselinux::GetWindowContext"),
+            xselinux::SET_PROPERTY_CREATE_CONTEXT_REQUEST => Some("xselinux::SetPropertyCreateContext"),
+            xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => Some("xselinux::GetPropertyCreateContext"),
+            xselinux::SET_PROPERTY_USE_CONTEXT_REQUEST => Some("xselinux::SetPropertyUseContext"),
+            xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => Some("xselinux::GetPropertyUseContext"),
+            xselinux::GET_PROPERTY_CONTEXT_REQUEST => Some("xselinux::GetPropertyContext"),
+            xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => Some("xselinux::GetPropertyDataContext"),
+            xselinux::LIST_PROPERTIES_REQUEST => Some("xselinux::ListProperties"),
+            xselinux::SET_SELECTION_CREATE_CONTEXT_REQUEST => Some("xselinux::SetSelectionCreateContext"),
+            xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => Some("xselinux::GetSelectionCreateContext"),
+            xselinux::SET_SELECTION_USE_CONTEXT_REQUEST => Some("xselinux::SetSelectionUseContext"),
+            xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => Some("xselinux::GetSelectionUseContext"),
+            xselinux::GET_SELECTION_CONTEXT_REQUEST => Some("xselinux::GetSelectionContext"),
+            xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => Some("xselinux::GetSelectionDataContext"),
+            xselinux::LIST_SELECTIONS_REQUEST => Some("xselinux::ListSelections"),
+            xselinux::GET_CLIENT_CONTEXT_REQUEST => Some("xselinux::GetClientContext"),
+            _ => None,
+        },
+        #[cfg(feature = "xtest")]
+        Some((xtest::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xtest::GET_VERSION_REQUEST => Some("xtest::GetVersion"),
+            xtest::COMPARE_CURSOR_REQUEST => Some("xtest::CompareCursor"),
+            xtest::FAKE_INPUT_REQUEST => Some("xtest::FakeInput"),
+            xtest::GRAB_CONTROL_REQUEST => Some("xtest::GrabControl"),
+            _ => None,
+        },
+        #[cfg(feature = "xv")]
+        Some((xv::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xv::QUERY_EXTENSION_REQUEST => Some("xv::QueryExtension"),
+            xv::QUERY_ADAPTORS_REQUEST => Some("xv::QueryAdaptors"),
+            xv::QUERY_ENCODINGS_REQUEST => Some("xv::QueryEncodings"),
+            xv::GRAB_PORT_REQUEST => Some("xv::GrabPort"),
+            xv::UNGRAB_PORT_REQUEST => Some("xv::UngrabPort"),
+            xv::PUT_VIDEO_REQUEST => Some("xv::PutVideo"),
+            xv::PUT_STILL_REQUEST => Some("xv::PutStill"),
+            xv::GET_VIDEO_REQUEST => Some("xv::GetVideo"),
+            xv::GET_STILL_REQUEST => Some("xv::GetStill"),
+            xv::STOP_VIDEO_REQUEST => Some("xv::StopVideo"),
+            xv::SELECT_VIDEO_NOTIFY_REQUEST => Some("xv::SelectVideoNotify"),
+            xv::SELECT_PORT_NOTIFY_REQUEST => Some("xv::SelectPortNotify"),
+            xv::QUERY_BEST_SIZE_REQUEST => Some("xv::QueryBestSize"),
+            xv::SET_PORT_ATTRIBUTE_REQUEST => Some("xv::SetPortAttribute"),
+            xv::GET_PORT_ATTRIBUTE_REQUEST => Some("xv::GetPortAttribute"),
+            xv::QUERY_PORT_ATTRIBUTES_REQUEST => Some("xv::QueryPortAttributes"),
+            xv::LIST_IMAGE_FORMATS_REQUEST => Some("xv::ListImageFormats"),
+            xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => Some("xv::QueryImageAttributes"),
+            xv::PUT_IMAGE_REQUEST => Some("xv::PutImage"),
+            xv::SHM_PUT_IMAGE_REQUEST => Some("xv::ShmPutImage"),
+            _ => None,
+        },
+        #[cfg(feature = "xvmc")]
+        Some((xvmc::X11_EXTENSION_NAME, _)) => match minor_opcode {
+            xvmc::QUERY_VERSION_REQUEST => Some("xvmc::QueryVersion"),
+            xvmc::LIST_SURFACE_TYPES_REQUEST => Some("xvmc::ListSurfaceTypes"),
+            xvmc::CREATE_CONTEXT_REQUEST => Some("xvmc::CreateContext"),
+            xvmc::DESTROY_CONTEXT_REQUEST => Some("xvmc::DestroyContext"),
+            xvmc::CREATE_SURFACE_REQUEST => Some("xvmc::CreateSurface"),
+            xvmc::DESTROY_SURFACE_REQUEST => Some("xvmc::DestroySurface"),
+            xvmc::CREATE_SUBPICTURE_REQUEST => Some("xvmc::CreateSubpicture"),
+            xvmc::DESTROY_SUBPICTURE_REQUEST => Some("xvmc::DestroySubpicture"),
+            xvmc::LIST_SUBPICTURE_TYPES_REQUEST => Some("xvmc::ListSubpictureTypes"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Enumeration of all possible X11 replies.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
 pub enum Reply {
     Void,
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
     GetWindowAttributes(xproto::GetWindowAttributesReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
     GetGeometry(xproto::GetGeometryReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
     QueryTree(xproto::QueryTreeReply),
     InternAtom(xproto::InternAtomReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
     GetAtomName(xproto::GetAtomNameReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
     GetProperty(xproto::GetPropertyReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
     ListProperties(xproto::ListPropertiesReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
     GetSelectionOwner(xproto::GetSelectionOwnerReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
     GrabPointer(xproto::GrabPointerReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
     GrabKeyboard(xproto::GrabKeyboardReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
     QueryPointer(xproto::QueryPointerReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
     GetMotionEvents(xproto::GetMotionEventsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
     TranslateCoordinates(xproto::TranslateCoordinatesReply),
     GetInputFocus(xproto::GetInputFocusReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
     QueryKeymap(xproto::QueryKeymapReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
     QueryFont(xproto::QueryFontReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
     QueryTextExtents(xproto::QueryTextExtentsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
     ListFonts(xproto::ListFontsReply),
     ListFontsWithInfo(xproto::ListFontsWithInfoReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
     GetFontPath(xproto::GetFontPathReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
     GetImage(xproto::GetImageReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
     ListInstalledColormaps(xproto::ListInstalledColormapsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
     AllocColor(xproto::AllocColorReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
     AllocNamedColor(xproto::AllocNamedColorReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
     AllocColorCells(xproto::AllocColorCellsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
     AllocColorPlanes(xproto::AllocColorPlanesReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
     QueryColors(xproto::QueryColorsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
     LookupColor(xproto::LookupColorReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
     QueryBestSize(xproto::QueryBestSizeReply),
     QueryExtension(xproto::QueryExtensionReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
     ListExtensions(xproto::ListExtensionsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
     GetKeyboardMapping(xproto::GetKeyboardMappingReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
     GetKeyboardControl(xproto::GetKeyboardControlReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
     GetPointerControl(xproto::GetPointerControlReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
     GetScreenSaver(xproto::GetScreenSaverReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
     ListHosts(xproto::ListHostsReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
     SetPointerMapping(xproto::SetPointerMappingReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
     GetPointerMapping(xproto::GetPointerMappingReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
     SetModifierMapping(xproto::SetModifierMappingReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
     GetModifierMapping(xproto::GetModifierMappingReply),
     BigreqEnable(bigreq::EnableReply),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
     CompositeQueryVersion(composite::QueryVersionReply),
     #[cfg(feature = "composite")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
     CompositeGetOverlayWindow(composite::GetOverlayWindowReply),
     #[cfg(feature = "damage")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
     DamageQueryVersion(damage::QueryVersionReply),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
     DpmsGetVersion(dpms::GetVersionReply),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
     DpmsCapable(dpms::CapableReply),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
     DpmsGetTimeouts(dpms::GetTimeoutsReply),
     #[cfg(feature = "dpms")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
     DpmsInfo(dpms::InfoReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
     Dri2QueryVersion(dri2::QueryVersionReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
     Dri2Connect(dri2::ConnectReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
     Dri2Authenticate(dri2::AuthenticateReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
     Dri2GetBuffers(dri2::GetBuffersReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
     Dri2CopyRegion(dri2::CopyRegionReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
     Dri2GetBuffersWithFormat(dri2::GetBuffersWithFormatReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
     Dri2SwapBuffers(dri2::SwapBuffersReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
     Dri2GetMSC(dri2::GetMSCReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
     Dri2WaitMSC(dri2::WaitMSCReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
     Dri2WaitSBC(dri2::WaitSBCReply),
     #[cfg(feature = "dri2")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
     Dri2GetParam(dri2::GetParamReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
     Dri3QueryVersion(dri3::QueryVersionReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
     Dri3Open(dri3::OpenReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
     Dri3BufferFromPixmap(dri3::BufferFromPixmapReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
     Dri3FDFromFence(dri3::FDFromFenceReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
     Dri3GetSupportedModifiers(dri3::GetSupportedModifiersReply),
     #[cfg(feature = "dri3")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
     Dri3BuffersFromPixmap(dri3::BuffersFromPixmapReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
     GeQueryVersion(ge::QueryVersionReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
     GlxMakeCurrent(glx::MakeCurrentReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
     GlxIsDirect(glx::IsDirectReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
     GlxQueryVersion(glx::QueryVersionReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
     GlxGetVisualConfigs(glx::GetVisualConfigsReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
     GlxVendorPrivateWithReply(glx::VendorPrivateWithReplyReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
     GlxQueryExtensionsString(glx::QueryExtensionsStringReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
     GlxQueryServerString(glx::QueryServerStringReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
     GlxGetFBConfigs(glx::GetFBConfigsReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
     GlxQueryContext(glx::QueryContextReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
     GlxMakeContextCurrent(glx::MakeContextCurrentReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
     GlxGetDrawableAttributes(glx::GetDrawableAttributesReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
     GlxGenLists(glx::GenListsReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
     GlxRenderMode(glx::RenderModeReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
     GlxFinish(glx::FinishReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
     GlxReadPixels(glx::ReadPixelsReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
     GlxGetBooleanv(glx::GetBooleanvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
     GlxGetClipPlane(glx::GetClipPlaneReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
     GlxGetDoublev(glx::GetDoublevReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
     GlxGetError(glx::GetErrorReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
     GlxGetFloatv(glx::GetFloatvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
     GlxGetIntegerv(glx::GetIntegervReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
     GlxGetLightfv(glx::GetLightfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
     GlxGetLightiv(glx::GetLightivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
     GlxGetMapdv(glx::GetMapdvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
     GlxGetMapfv(glx::GetMapfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
     GlxGetMapiv(glx::GetMapivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
     GlxGetMaterialfv(glx::GetMaterialfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
     GlxGetMaterialiv(glx::GetMaterialivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
     GlxGetPixelMapfv(glx::GetPixelMapfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
     GlxGetPixelMapuiv(glx::GetPixelMapuivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
     GlxGetPixelMapusv(glx::GetPixelMapusvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
     GlxGetPolygonStipple(glx::GetPolygonStippleReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
     GlxGetString(glx::GetStringReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
     GlxGetTexEnvfv(glx::GetTexEnvfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
     GlxGetTexEnviv(glx::GetTexEnvivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
     GlxGetTexGendv(glx::GetTexGendvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
     GlxGetTexGenfv(glx::GetTexGenfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
     GlxGetTexGeniv(glx::GetTexGenivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
     GlxGetTexImage(glx::GetTexImageReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
     GlxGetTexParameterfv(glx::GetTexParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
     GlxGetTexParameteriv(glx::GetTexParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
     GlxGetTexLevelParameterfv(glx::GetTexLevelParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
     GlxGetTexLevelParameteriv(glx::GetTexLevelParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
     GlxIsEnabled(glx::IsEnabledReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
     GlxIsList(glx::IsListReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
     GlxAreTexturesResident(glx::AreTexturesResidentReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
     GlxGenTextures(glx::GenTexturesReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
     GlxIsTexture(glx::IsTextureReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
     GlxGetColorTable(glx::GetColorTableReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
     GlxGetColorTableParameterfv(glx::GetColorTableParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
     GlxGetColorTableParameteriv(glx::GetColorTableParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
     GlxGetConvolutionFilter(glx::GetConvolutionFilterReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
     GlxGetConvolutionParameterfv(glx::GetConvolutionParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
     GlxGetConvolutionParameteriv(glx::GetConvolutionParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
     GlxGetSeparableFilter(glx::GetSeparableFilterReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
     GlxGetHistogram(glx::GetHistogramReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
     GlxGetHistogramParameterfv(glx::GetHistogramParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
     GlxGetHistogramParameteriv(glx::GetHistogramParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
     GlxGetMinmax(glx::GetMinmaxReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
     GlxGetMinmaxParameterfv(glx::GetMinmaxParameterfvReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
     GlxGetMinmaxParameteriv(glx::GetMinmaxParameterivReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
     GlxGetCompressedTexImageARB(glx::GetCompressedTexImageARBReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
     GlxGenQueriesARB(glx::GenQueriesARBReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
     GlxIsQueryARB(glx::IsQueryARBReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
     GlxGetQueryivARB(glx::GetQueryivARBReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
     GlxGetQueryObjectivARB(glx::GetQueryObjectivARBReply),
     #[cfg(feature = "glx")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
     GlxGetQueryObjectuivARB(glx::GetQueryObjectuivARBReply),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
     PresentQueryVersion(present::QueryVersionReply),
     #[cfg(feature = "present")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
     PresentQueryCapabilities(present::QueryCapabilitiesReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
     RandrQueryVersion(randr::QueryVersionReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
     RandrSetScreenConfig(randr::SetScreenConfigReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
     RandrGetScreenInfo(randr::GetScreenInfoReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
     RandrGetScreenSizeRange(randr::GetScreenSizeRangeReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
     RandrGetScreenResources(randr::GetScreenResourcesReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
     RandrGetOutputInfo(randr::GetOutputInfoReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
     RandrListOutputProperties(randr::ListOutputPropertiesReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
     RandrQueryOutputProperty(randr::QueryOutputPropertyReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
     RandrGetOutputProperty(randr::GetOutputPropertyReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
     RandrCreateMode(randr::CreateModeReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
     RandrGetCrtcInfo(randr::GetCrtcInfoReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
     RandrSetCrtcConfig(randr::SetCrtcConfigReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
     RandrGetCrtcGammaSize(randr::GetCrtcGammaSizeReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
     RandrGetCrtcGamma(randr::GetCrtcGammaReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
     RandrGetScreenResourcesCurrent(randr::GetScreenResourcesCurrentReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
     RandrGetCrtcTransform(randr::GetCrtcTransformReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
     RandrGetPanning(randr::GetPanningReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
     RandrSetPanning(randr::SetPanningReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
     RandrGetOutputPrimary(randr::GetOutputPrimaryReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
     RandrGetProviders(randr::GetProvidersReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
     RandrGetProviderInfo(randr::GetProviderInfoReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
     RandrListProviderProperties(randr::ListProviderPropertiesReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
     RandrQueryProviderProperty(randr::QueryProviderPropertyReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
     RandrGetProviderProperty(randr::GetProviderPropertyReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
     RandrGetMonitors(randr::GetMonitorsReply),
     #[cfg(feature = "randr")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
     RandrCreateLease(randr::CreateLeaseReply),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
     RecordQueryVersion(record::QueryVersionReply),
     #[cfg(feature = "record")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
     RecordGetContext(record::GetContextReply),
     #[cfg(feature = "record")]
     RecordEnableContext(record::EnableContextReply),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
     RenderQueryVersion(render::QueryVersionReply),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
     RenderQueryPictFormats(render::QueryPictFormatsReply),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
     RenderQueryPictIndexValues(render::QueryPictIndexValuesReply),
     #[cfg(feature = "render")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
     RenderQueryFilters(render::QueryFiltersReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
     ResQueryVersion(res::QueryVersionReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
     ResQueryClients(res::QueryClientsReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
     ResQueryClientResources(res::QueryClientResourcesReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
     ResQueryClientPixmapBytes(res::QueryClientPixmapBytesReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
     ResQueryClientIds(res::QueryClientIdsReply),
     #[cfg(feature = "res")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
     ResQueryResourceBytes(res::QueryResourceBytesReply),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
     ScreensaverQueryVersion(screensaver::QueryVersionReply),
     #[cfg(feature = "screensaver")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
     ScreensaverQueryInfo(screensaver::QueryInfoReply),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
     ShapeQueryVersion(shape::QueryVersionReply),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
     ShapeQueryExtents(shape::QueryExtentsReply),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
     ShapeInputSelected(shape::InputSelectedReply),
     #[cfg(feature = "shape")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
     ShapeGetRectangles(shape::GetRectanglesReply),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
     ShmQueryVersion(shm::QueryVersionReply),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
     ShmGetImage(shm::GetImageReply),
     #[cfg(feature = "shm")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
     ShmCreateSegment(shm::CreateSegmentReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
     SyncInitialize(sync::InitializeReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
     SyncListSystemCounters(sync::ListSystemCountersReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
     SyncQueryCounter(sync::QueryCounterReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
     SyncQueryAlarm(sync::QueryAlarmReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
     SyncGetPriority(sync::GetPriorityReply),
     #[cfg(feature = "sync")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
     SyncQueryFence(sync::QueryFenceReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
     XcMiscGetVersion(xc_misc::GetVersionReply),
     XcMiscGetXIDRange(xc_misc::GetXIDRangeReply),
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
     XcMiscGetXIDList(xc_misc::GetXIDListReply),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
     XevieQueryVersion(xevie::QueryVersionReply),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
     XevieStart(xevie::StartReply),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
     XevieEnd(xevie::EndReply),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
     XevieSend(xevie::SendReply),
     #[cfg(feature = "xevie")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
     XevieSelectInput(xevie::SelectInputReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
     Xf86driQueryVersion(xf86dri::QueryVersionReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
     Xf86driQueryDirectRenderingCapable(xf86dri::QueryDirectRenderingCapableReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
     Xf86driOpenConnection(xf86dri::OpenConnectionReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
     Xf86driGetClientDriverName(xf86dri::GetClientDriverNameReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
     Xf86driCreateContext(xf86dri::CreateContextReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
     Xf86driCreateDrawable(xf86dri::CreateDrawableReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
     Xf86driGetDrawableInfo(xf86dri::GetDrawableInfoReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
     Xf86driGetDeviceInfo(xf86dri::GetDeviceInfoReply),
     #[cfg(feature = "xf86dri")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
     Xf86driAuthConnection(xf86dri::AuthConnectionReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
     Xf86vidmodeQueryVersion(xf86vidmode::QueryVersionReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
     Xf86vidmodeGetModeLine(xf86vidmode::GetModeLineReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
     Xf86vidmodeGetMonitor(xf86vidmode::GetMonitorReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
     Xf86vidmodeGetAllModeLines(xf86vidmode::GetAllModeLinesReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
     Xf86vidmodeValidateModeLine(xf86vidmode::ValidateModeLineReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
     Xf86vidmodeGetViewPort(xf86vidmode::GetViewPortReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
     Xf86vidmodeGetDotClocks(xf86vidmode::GetDotClocksReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
     Xf86vidmodeGetGamma(xf86vidmode::GetGammaReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
     Xf86vidmodeGetGammaRamp(xf86vidmode::GetGammaRampReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
     Xf86vidmodeGetGammaRampSize(xf86vidmode::GetGammaRampSizeReply),
     #[cfg(feature = "xf86vidmode")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
     Xf86vidmodeGetPermissions(xf86vidmode::GetPermissionsReply),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
     XfixesQueryVersion(xfixes::QueryVersionReply),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
     XfixesGetCursorImage(xfixes::GetCursorImageReply),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
     XfixesFetchRegion(xfixes::FetchRegionReply),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
     XfixesGetCursorName(xfixes::GetCursorNameReply),
     #[cfg(feature = "xfixes")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
     XfixesGetCursorImageAndName(xfixes::GetCursorImageAndNameReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
     XineramaQueryVersion(xinerama::QueryVersionReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
     XineramaGetState(xinerama::GetStateReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
     XineramaGetScreenCount(xinerama::GetScreenCountReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
     XineramaGetScreenSize(xinerama::GetScreenSizeReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
     XineramaIsActive(xinerama::IsActiveReply),
     #[cfg(feature = "xinerama")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
     XineramaQueryScreens(xinerama::QueryScreensReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
     XinputGetExtensionVersion(xinput::GetExtensionVersionReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
     XinputListInputDevices(xinput::ListInputDevicesReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
     XinputOpenDevice(xinput::OpenDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
     XinputSetDeviceMode(xinput::SetDeviceModeReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
     XinputGetSelectedExtensionEvents(xinput::GetSelectedExtensionEventsReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
     XinputGetDeviceDontPropagateList(xinput::GetDeviceDontPropagateListReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
     XinputGetDeviceMotionEvents(xinput::GetDeviceMotionEventsReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
     XinputChangeKeyboardDevice(xinput::ChangeKeyboardDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
     XinputChangePointerDevice(xinput::ChangePointerDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
     XinputGrabDevice(xinput::GrabDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
     XinputGetDeviceFocus(xinput::GetDeviceFocusReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
     XinputGetFeedbackControl(xinput::GetFeedbackControlReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
     XinputGetDeviceKeyMapping(xinput::GetDeviceKeyMappingReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
     XinputGetDeviceModifierMapping(xinput::GetDeviceModifierMappingReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
     XinputSetDeviceModifierMapping(xinput::SetDeviceModifierMappingReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
     XinputGetDeviceButtonMapping(xinput::GetDeviceButtonMappingReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
     XinputSetDeviceButtonMapping(xinput::SetDeviceButtonMappingReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
     XinputQueryDeviceState(xinput::QueryDeviceStateReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
     XinputSetDeviceValuators(xinput::SetDeviceValuatorsReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
     XinputGetDeviceControl(xinput::GetDeviceControlReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
     XinputChangeDeviceControl(xinput::ChangeDeviceControlReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
     XinputListDeviceProperties(xinput::ListDevicePropertiesReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
     XinputGetDeviceProperty(xinput::GetDevicePropertyReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
     XinputXIQueryPointer(xinput::XIQueryPointerReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
     XinputXIGetClientPointer(xinput::XIGetClientPointerReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
     XinputXIQueryVersion(xinput::XIQueryVersionReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
     XinputXIQueryDevice(xinput::XIQueryDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
     XinputXIGetFocus(xinput::XIGetFocusReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
     XinputXIGrabDevice(xinput::XIGrabDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
     XinputXIPassiveGrabDevice(xinput::XIPassiveGrabDeviceReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
     XinputXIListProperties(xinput::XIListPropertiesReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
     XinputXIGetProperty(xinput::XIGetPropertyReply),
     #[cfg(feature = "xinput")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
     XinputXIGetSelectedEvents(xinput::XIGetSelectedEventsReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
     XkbUseExtension(xkb::UseExtensionReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
     XkbGetState(xkb::GetStateReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
     XkbGetControls(xkb::GetControlsReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
     XkbGetMap(xkb::GetMapReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
     XkbGetCompatMap(xkb::GetCompatMapReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
     XkbGetIndicatorState(xkb::GetIndicatorStateReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
     XkbGetIndicatorMap(xkb::GetIndicatorMapReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
     XkbGetNamedIndicator(xkb::GetNamedIndicatorReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
     XkbGetNames(xkb::GetNamesReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
     XkbPerClientFlags(xkb::PerClientFlagsReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
     XkbListComponents(xkb::ListComponentsReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
     XkbGetKbdByName(xkb::GetKbdByNameReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
     XkbGetDeviceInfo(xkb::GetDeviceInfoReply),
     #[cfg(feature = "xkb")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
     XkbSetDebuggingFlags(xkb::SetDebuggingFlagsReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
     XprintPrintQueryVersion(xprint::PrintQueryVersionReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
     XprintPrintGetPrinterList(xprint::PrintGetPrinterListReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
     XprintPrintGetContext(xprint::PrintGetContextReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
     XprintPrintGetScreenOfContext(xprint::PrintGetScreenOfContextReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
     XprintPrintGetDocumentData(xprint::PrintGetDocumentDataReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
     XprintPrintInputSelected(xprint::PrintInputSelectedReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
     XprintPrintGetAttributes(xprint::PrintGetAttributesReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
     XprintPrintGetOneAttributes(xprint::PrintGetOneAttributesReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
     XprintPrintGetPageDimensions(xprint::PrintGetPageDimensionsReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
     XprintPrintQueryScreens(xprint::PrintQueryScreensReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
     XprintPrintSetImageResolution(xprint::PrintSetImageResolutionReply),
     #[cfg(feature = "xprint")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
     XprintPrintGetImageResolution(xprint::PrintGetImageResolutionReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
     XselinuxQueryVersion(xselinux::QueryVersionReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
     XselinuxGetDeviceCreateContext(xselinux::GetDeviceCreateContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
     XselinuxGetDeviceContext(xselinux::GetDeviceContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
     XselinuxGetWindowCreateContext(xselinux::GetWindowCreateContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
     XselinuxGetWindowContext(xselinux::GetWindowContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
     XselinuxGetPropertyCreateContext(xselinux::GetPropertyCreateContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
     XselinuxGetPropertyUseContext(xselinux::GetPropertyUseContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
     XselinuxGetPropertyContext(xselinux::GetPropertyContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
     XselinuxGetPropertyDataContext(xselinux::GetPropertyDataContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
     XselinuxListProperties(xselinux::ListPropertiesReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
     XselinuxGetSelectionCreateContext(xselinux::GetSelectionCreateContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
     XselinuxGetSelectionUseContext(xselinux::GetSelectionUseContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
     XselinuxGetSelectionContext(xselinux::GetSelectionContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
     XselinuxGetSelectionDataContext(xselinux::GetSelectionDataContextReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
     XselinuxListSelections(xselinux::ListSelectionsReply),
     #[cfg(feature = "xselinux")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
     XselinuxGetClientContext(xselinux::GetClientContextReply),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
     XtestGetVersion(xtest::GetVersionReply),
     #[cfg(feature = "xtest")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
     XtestCompareCursor(xtest::CompareCursorReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
     XvQueryExtension(xv::QueryExtensionReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
     XvQueryAdaptors(xv::QueryAdaptorsReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
     XvQueryEncodings(xv::QueryEncodingsReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
     XvGrabPort(xv::GrabPortReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
     XvQueryBestSize(xv::QueryBestSizeReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
     XvGetPortAttribute(xv::GetPortAttributeReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
     XvQueryPortAttributes(xv::QueryPortAttributesReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
     XvListImageFormats(xv::ListImageFormatsReply),
     #[cfg(feature = "xv")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
     XvQueryImageAttributes(xv::QueryImageAttributesReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
     XvmcQueryVersion(xvmc::QueryVersionReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
     XvmcListSurfaceTypes(xvmc::ListSurfaceTypesReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
     XvmcCreateContext(xvmc::CreateContextReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
     XvmcCreateSurface(xvmc::CreateSurfaceReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
     XvmcCreateSubpicture(xvmc::CreateSubpictureReply),
     #[cfg(feature = "xvmc")]
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
     XvmcListSubpictureTypes(xvmc::ListSubpictureTypesReply),
 }
 impl From<()> for Reply {
@@ -5091,16 +8781,19 @@ impl From<()> for Reply {
         Reply::Void
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 impl From<xproto::GetWindowAttributesReply> for Reply {
   fn from(reply: xproto::GetWindowAttributesReply) -> Reply {
     Reply::GetWindowAttributes(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 impl From<xproto::GetGeometryReply> for Reply {
   fn from(reply: xproto::GetGeometryReply) -> Reply {
     Reply::GetGeometry(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 impl From<xproto::QueryTreeReply> for Reply {
   fn from(reply: xproto::QueryTreeReply) -> Reply {
     Reply::QueryTree(reply)
@@ -5111,46 +8804,55 @@ impl From<xproto::InternAtomReply> for Reply {
     Reply::InternAtom(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 impl From<xproto::GetAtomNameReply> for Reply {
   fn from(reply: xproto::GetAtomNameReply) -> Reply {
     Reply::GetAtomName(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 impl From<xproto::GetPropertyReply> for Reply {
   fn from(reply: xproto::GetPropertyReply) -> Reply {
     Reply::GetProperty(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 impl From<xproto::ListPropertiesReply> for Reply {
   fn from(reply: xproto::ListPropertiesReply) -> Reply {
     Reply::ListProperties(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 impl From<xproto::GetSelectionOwnerReply> for Reply {
   fn from(reply: xproto::GetSelectionOwnerReply) -> Reply {
     Reply::GetSelectionOwner(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 impl From<xproto::GrabPointerReply> for Reply {
   fn from(reply: xproto::GrabPointerReply) -> Reply {
     Reply::GrabPointer(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 impl From<xproto::GrabKeyboardReply> for Reply {
   fn from(reply: xproto::GrabKeyboardReply) -> Reply {
     Reply::GrabKeyboard(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 impl From<xproto::QueryPointerReply> for Reply {
   fn from(reply: xproto::QueryPointerReply) -> Reply {
     Reply::QueryPointer(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 impl From<xproto::GetMotionEventsReply> for Reply {
   fn from(reply: xproto::GetMotionEventsReply) -> Reply {
     Reply::GetMotionEvents(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 impl From<xproto::TranslateCoordinatesReply> for Reply {
   fn from(reply: xproto::TranslateCoordinatesReply) -> Reply {
     Reply::TranslateCoordinates(reply)
@@ -5161,21 +8863,25 @@ impl From<xproto::GetInputFocusReply> for Reply {
     Reply::GetInputFocus(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 impl From<xproto::QueryKeymapReply> for Reply {
   fn from(reply: xproto::QueryKeymapReply) -> Reply {
     Reply::QueryKeymap(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 impl From<xproto::QueryFontReply> for Reply {
   fn from(reply: xproto::QueryFontReply) -> Reply {
     Reply::QueryFont(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 impl From<xproto::QueryTextExtentsReply> for Reply {
   fn from(reply: xproto::QueryTextExtentsReply) -> Reply {
     Reply::QueryTextExtents(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 impl From<xproto::ListFontsReply> for Reply {
   fn from(reply: xproto::ListFontsReply) -> Reply {
     Reply::ListFonts(reply)
@@ -5186,51 +8892,61 @@ impl From<xproto::ListFontsWithInfoReply> for Reply {
     Reply::ListFontsWithInfo(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 impl From<xproto::GetFontPathReply> for Reply {
   fn from(reply: xproto::GetFontPathReply) -> Reply {
     Reply::GetFontPath(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 impl From<xproto::GetImageReply> for Reply {
   fn from(reply: xproto::GetImageReply) -> Reply {
     Reply::GetImage(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 impl From<xproto::ListInstalledColormapsReply> for Reply {
   fn from(reply: xproto::ListInstalledColormapsReply) -> Reply {
     Reply::ListInstalledColormaps(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 impl From<xproto::AllocColorReply> for Reply {
   fn from(reply: xproto::AllocColorReply) -> Reply {
     Reply::AllocColor(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 impl From<xproto::AllocNamedColorReply> for Reply {
   fn from(reply: xproto::AllocNamedColorReply) -> Reply {
     Reply::AllocNamedColor(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 impl From<xproto::AllocColorCellsReply> for Reply {
   fn from(reply: xproto::AllocColorCellsReply) -> Reply {
     Reply::AllocColorCells(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 impl From<xproto::AllocColorPlanesReply> for Reply {
   fn from(reply: xproto::AllocColorPlanesReply) -> Reply {
     Reply::AllocColorPlanes(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 impl From<xproto::QueryColorsReply> for Reply {
   fn from(reply: xproto::QueryColorsReply) -> Reply {
     Reply::QueryColors(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 impl From<xproto::LookupColorReply> for Reply {
   fn from(reply: xproto::LookupColorReply) -> Reply {
     Reply::LookupColor(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 impl From<xproto::QueryBestSizeReply> for Reply {
   fn from(reply: xproto::QueryBestSizeReply) -> Reply {
     Reply::QueryBestSize(reply)
@@ -5241,51 +8957,61 @@ impl From<xproto::QueryExtensionReply> for Reply {
     Reply::QueryExtension(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 impl From<xproto::ListExtensionsReply> for Reply {
   fn from(reply: xproto::ListExtensionsReply) -> Reply {
     Reply::ListExtensions(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 impl From<xproto::GetKeyboardMappingReply> for Reply {
   fn from(reply: xproto::GetKeyboardMappingReply) -> Reply {
     Reply::GetKeyboardMapping(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 impl From<xproto::GetKeyboardControlReply> for Reply {
   fn from(reply: xproto::GetKeyboardControlReply) -> Reply {
     Reply::GetKeyboardControl(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 impl From<xproto::GetPointerControlReply> for Reply {
   fn from(reply: xproto::GetPointerControlReply) -> Reply {
     Reply::GetPointerControl(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 impl From<xproto::GetScreenSaverReply> for Reply {
   fn from(reply: xproto::GetScreenSaverReply) -> Reply {
     Reply::GetScreenSaver(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 impl From<xproto::ListHostsReply> for Reply {
   fn from(reply: xproto::ListHostsReply) -> Reply {
     Reply::ListHosts(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 impl From<xproto::SetPointerMappingReply> for Reply {
   fn from(reply: xproto::SetPointerMappingReply) -> Reply {
     Reply::SetPointerMapping(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 impl From<xproto::GetPointerMappingReply> for Reply {
   fn from(reply: xproto::GetPointerMappingReply) -> Reply {
     Reply::GetPointerMapping(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 impl From<xproto::SetModifierMappingReply> for Reply {
   fn from(reply: xproto::SetModifierMappingReply) -> Reply {
     Reply::SetModifierMapping(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
 impl From<xproto::GetModifierMappingReply> for Reply {
   fn from(reply: xproto::GetModifierMappingReply) -> Reply {
     Reply::GetModifierMapping(reply)
@@ -5297,731 +9023,853 @@ impl From<bigreq::EnableReply> for Reply {
   }
 }
 #[cfg(feature = "composite")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::QueryVersion"))]
 impl From<composite::QueryVersionReply> for Reply {
   fn from(reply: composite::QueryVersionReply) -> Reply {
     Reply::CompositeQueryVersion(reply)
   }
 }
 #[cfg(feature = "composite")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "composite::GetOverlayWindow"))]
 impl From<composite::GetOverlayWindowReply> for Reply {
   fn from(reply: composite::GetOverlayWindowReply) -> Reply {
     Reply::CompositeGetOverlayWindow(reply)
   }
 }
 #[cfg(feature = "damage")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "damage::QueryVersion"))]
 impl From<damage::QueryVersionReply> for Reply {
   fn from(reply: damage::QueryVersionReply) -> Reply {
     Reply::DamageQueryVersion(reply)
   }
 }
 #[cfg(feature = "dpms")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetVersion"))]
 impl From<dpms::GetVersionReply> for Reply {
   fn from(reply: dpms::GetVersionReply) -> Reply {
     Reply::DpmsGetVersion(reply)
   }
 }
 #[cfg(feature = "dpms")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Capable"))]
 impl From<dpms::CapableReply> for Reply {
   fn from(reply: dpms::CapableReply) -> Reply {
     Reply::DpmsCapable(reply)
   }
 }
 #[cfg(feature = "dpms")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::GetTimeouts"))]
 impl From<dpms::GetTimeoutsReply> for Reply {
   fn from(reply: dpms::GetTimeoutsReply) -> Reply {
     Reply::DpmsGetTimeouts(reply)
   }
 }
 #[cfg(feature = "dpms")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dpms::Info"))]
 impl From<dpms::InfoReply> for Reply {
   fn from(reply: dpms::InfoReply) -> Reply {
     Reply::DpmsInfo(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::QueryVersion"))]
 impl From<dri2::QueryVersionReply> for Reply {
   fn from(reply: dri2::QueryVersionReply) -> Reply {
     Reply::Dri2QueryVersion(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Connect"))]
 impl From<dri2::ConnectReply> for Reply {
   fn from(reply: dri2::ConnectReply) -> Reply {
     Reply::Dri2Connect(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::Authenticate"))]
 impl From<dri2::AuthenticateReply> for Reply {
   fn from(reply: dri2::AuthenticateReply) -> Reply {
     Reply::Dri2Authenticate(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffers"))]
 impl From<dri2::GetBuffersReply> for Reply {
   fn from(reply: dri2::GetBuffersReply) -> Reply {
     Reply::Dri2GetBuffers(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::CopyRegion"))]
 impl From<dri2::CopyRegionReply> for Reply {
   fn from(reply: dri2::CopyRegionReply) -> Reply {
     Reply::Dri2CopyRegion(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetBuffersWithFormat"))]
 impl From<dri2::GetBuffersWithFormatReply> for Reply {
   fn from(reply: dri2::GetBuffersWithFormatReply) -> Reply {
     Reply::Dri2GetBuffersWithFormat(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::SwapBuffers"))]
 impl From<dri2::SwapBuffersReply> for Reply {
   fn from(reply: dri2::SwapBuffersReply) -> Reply {
     Reply::Dri2SwapBuffers(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetMSC"))]
 impl From<dri2::GetMSCReply> for Reply {
   fn from(reply: dri2::GetMSCReply) -> Reply {
     Reply::Dri2GetMSC(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitMSC"))]
 impl From<dri2::WaitMSCReply> for Reply {
   fn from(reply: dri2::WaitMSCReply) -> Reply {
     Reply::Dri2WaitMSC(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::WaitSBC"))]
 impl From<dri2::WaitSBCReply> for Reply {
   fn from(reply: dri2::WaitSBCReply) -> Reply {
     Reply::Dri2WaitSBC(reply)
   }
 }
 #[cfg(feature = "dri2")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri2::GetParam"))]
 impl From<dri2::GetParamReply> for Reply {
   fn from(reply: dri2::GetParamReply) -> Reply {
     Reply::Dri2GetParam(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::QueryVersion"))]
 impl From<dri3::QueryVersionReply> for Reply {
   fn from(reply: dri3::QueryVersionReply) -> Reply {
     Reply::Dri3QueryVersion(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::Open"))]
 impl From<dri3::OpenReply> for Reply {
   fn from(reply: dri3::OpenReply) -> Reply {
     Reply::Dri3Open(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BufferFromPixmap"))]
 impl From<dri3::BufferFromPixmapReply> for Reply {
   fn from(reply: dri3::BufferFromPixmapReply) -> Reply {
     Reply::Dri3BufferFromPixmap(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::FDFromFence"))]
 impl From<dri3::FDFromFenceReply> for Reply {
   fn from(reply: dri3::FDFromFenceReply) -> Reply {
     Reply::Dri3FDFromFence(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::GetSupportedModifiers"))]
 impl From<dri3::GetSupportedModifiersReply> for Reply {
   fn from(reply: dri3::GetSupportedModifiersReply) -> Reply {
     Reply::Dri3GetSupportedModifiers(reply)
   }
 }
 #[cfg(feature = "dri3")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "dri3::BuffersFromPixmap"))]
 impl From<dri3::BuffersFromPixmapReply> for Reply {
   fn from(reply: dri3::BuffersFromPixmapReply) -> Reply {
     Reply::Dri3BuffersFromPixmap(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "ge::QueryVersion"))]
 impl From<ge::QueryVersionReply> for Reply {
   fn from(reply: ge::QueryVersionReply) -> Reply {
     Reply::GeQueryVersion(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeCurrent"))]
 impl From<glx::MakeCurrentReply> for Reply {
   fn from(reply: glx::MakeCurrentReply) -> Reply {
     Reply::GlxMakeCurrent(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsDirect"))]
 impl From<glx::IsDirectReply> for Reply {
   fn from(reply: glx::IsDirectReply) -> Reply {
     Reply::GlxIsDirect(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryVersion"))]
 impl From<glx::QueryVersionReply> for Reply {
   fn from(reply: glx::QueryVersionReply) -> Reply {
     Reply::GlxQueryVersion(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetVisualConfigs"))]
 impl From<glx::GetVisualConfigsReply> for Reply {
   fn from(reply: glx::GetVisualConfigsReply) -> Reply {
     Reply::GlxGetVisualConfigs(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::VendorPrivateWithReply"))]
 impl From<glx::VendorPrivateWithReplyReply> for Reply {
   fn from(reply: glx::VendorPrivateWithReplyReply) -> Reply {
     Reply::GlxVendorPrivateWithReply(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryExtensionsString"))]
 impl From<glx::QueryExtensionsStringReply> for Reply {
   fn from(reply: glx::QueryExtensionsStringReply) -> Reply {
     Reply::GlxQueryExtensionsString(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryServerString"))]
 impl From<glx::QueryServerStringReply> for Reply {
   fn from(reply: glx::QueryServerStringReply) -> Reply {
     Reply::GlxQueryServerString(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFBConfigs"))]
 impl From<glx::GetFBConfigsReply> for Reply {
   fn from(reply: glx::GetFBConfigsReply) -> Reply {
     Reply::GlxGetFBConfigs(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::QueryContext"))]
 impl From<glx::QueryContextReply> for Reply {
   fn from(reply: glx::QueryContextReply) -> Reply {
     Reply::GlxQueryContext(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::MakeContextCurrent"))]
 impl From<glx::MakeContextCurrentReply> for Reply {
   fn from(reply: glx::MakeContextCurrentReply) -> Reply {
     Reply::GlxMakeContextCurrent(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDrawableAttributes"))]
 impl From<glx::GetDrawableAttributesReply> for Reply {
   fn from(reply: glx::GetDrawableAttributesReply) -> Reply {
     Reply::GlxGetDrawableAttributes(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenLists"))]
 impl From<glx::GenListsReply> for Reply {
   fn from(reply: glx::GenListsReply) -> Reply {
     Reply::GlxGenLists(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::RenderMode"))]
 impl From<glx::RenderModeReply> for Reply {
   fn from(reply: glx::RenderModeReply) -> Reply {
     Reply::GlxRenderMode(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::Finish"))]
 impl From<glx::FinishReply> for Reply {
   fn from(reply: glx::FinishReply) -> Reply {
     Reply::GlxFinish(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::ReadPixels"))]
 impl From<glx::ReadPixelsReply> for Reply {
   fn from(reply: glx::ReadPixelsReply) -> Reply {
     Reply::GlxReadPixels(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetBooleanv"))]
 impl From<glx::GetBooleanvReply> for Reply {
   fn from(reply: glx::GetBooleanvReply) -> Reply {
     Reply::GlxGetBooleanv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetClipPlane"))]
 impl From<glx::GetClipPlaneReply> for Reply {
   fn from(reply: glx::GetClipPlaneReply) -> Reply {
     Reply::GlxGetClipPlane(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetDoublev"))]
 impl From<glx::GetDoublevReply> for Reply {
   fn from(reply: glx::GetDoublevReply) -> Reply {
     Reply::GlxGetDoublev(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetError"))]
 impl From<glx::GetErrorReply> for Reply {
   fn from(reply: glx::GetErrorReply) -> Reply {
     Reply::GlxGetError(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetFloatv"))]
 impl From<glx::GetFloatvReply> for Reply {
   fn from(reply: glx::GetFloatvReply) -> Reply {
     Reply::GlxGetFloatv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetIntegerv"))]
 impl From<glx::GetIntegervReply> for Reply {
   fn from(reply: glx::GetIntegervReply) -> Reply {
     Reply::GlxGetIntegerv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightfv"))]
 impl From<glx::GetLightfvReply> for Reply {
   fn from(reply: glx::GetLightfvReply) -> Reply {
     Reply::GlxGetLightfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetLightiv"))]
 impl From<glx::GetLightivReply> for Reply {
   fn from(reply: glx::GetLightivReply) -> Reply {
     Reply::GlxGetLightiv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapdv"))]
 impl From<glx::GetMapdvReply> for Reply {
   fn from(reply: glx::GetMapdvReply) -> Reply {
     Reply::GlxGetMapdv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapfv"))]
 impl From<glx::GetMapfvReply> for Reply {
   fn from(reply: glx::GetMapfvReply) -> Reply {
     Reply::GlxGetMapfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMapiv"))]
 impl From<glx::GetMapivReply> for Reply {
   fn from(reply: glx::GetMapivReply) -> Reply {
     Reply::GlxGetMapiv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialfv"))]
 impl From<glx::GetMaterialfvReply> for Reply {
   fn from(reply: glx::GetMaterialfvReply) -> Reply {
     Reply::GlxGetMaterialfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMaterialiv"))]
 impl From<glx::GetMaterialivReply> for Reply {
   fn from(reply: glx::GetMaterialivReply) -> Reply {
     Reply::GlxGetMaterialiv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapfv"))]
 impl From<glx::GetPixelMapfvReply> for Reply {
   fn from(reply: glx::GetPixelMapfvReply) -> Reply {
     Reply::GlxGetPixelMapfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapuiv"))]
 impl From<glx::GetPixelMapuivReply> for Reply {
   fn from(reply: glx::GetPixelMapuivReply) -> Reply {
     Reply::GlxGetPixelMapuiv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPixelMapusv"))]
 impl From<glx::GetPixelMapusvReply> for Reply {
   fn from(reply: glx::GetPixelMapusvReply) -> Reply {
     Reply::GlxGetPixelMapusv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetPolygonStipple"))]
 impl From<glx::GetPolygonStippleReply> for Reply {
   fn from(reply: glx::GetPolygonStippleReply) -> Reply {
     Reply::GlxGetPolygonStipple(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetString"))]
 impl From<glx::GetStringReply> for Reply {
   fn from(reply: glx::GetStringReply) -> Reply {
     Reply::GlxGetString(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnvfv"))]
 impl From<glx::GetTexEnvfvReply> for Reply {
   fn from(reply: glx::GetTexEnvfvReply) -> Reply {
     Reply::GlxGetTexEnvfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexEnviv"))]
 impl From<glx::GetTexEnvivReply> for Reply {
   fn from(reply: glx::GetTexEnvivReply) -> Reply {
     Reply::GlxGetTexEnviv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGendv"))]
 impl From<glx::GetTexGendvReply> for Reply {
   fn from(reply: glx::GetTexGendvReply) -> Reply {
     Reply::GlxGetTexGendv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGenfv"))]
 impl From<glx::GetTexGenfvReply> for Reply {
   fn from(reply: glx::GetTexGenfvReply) -> Reply {
     Reply::GlxGetTexGenfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexGeniv"))]
 impl From<glx::GetTexGenivReply> for Reply {
   fn from(reply: glx::GetTexGenivReply) -> Reply {
     Reply::GlxGetTexGeniv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexImage"))]
 impl From<glx::GetTexImageReply> for Reply {
   fn from(reply: glx::GetTexImageReply) -> Reply {
     Reply::GlxGetTexImage(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameterfv"))]
 impl From<glx::GetTexParameterfvReply> for Reply {
   fn from(reply: glx::GetTexParameterfvReply) -> Reply {
     Reply::GlxGetTexParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexParameteriv"))]
 impl From<glx::GetTexParameterivReply> for Reply {
   fn from(reply: glx::GetTexParameterivReply) -> Reply {
     Reply::GlxGetTexParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameterfv"))]
 impl From<glx::GetTexLevelParameterfvReply> for Reply {
   fn from(reply: glx::GetTexLevelParameterfvReply) -> Reply {
     Reply::GlxGetTexLevelParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetTexLevelParameteriv"))]
 impl From<glx::GetTexLevelParameterivReply> for Reply {
   fn from(reply: glx::GetTexLevelParameterivReply) -> Reply {
     Reply::GlxGetTexLevelParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsEnabled"))]
 impl From<glx::IsEnabledReply> for Reply {
   fn from(reply: glx::IsEnabledReply) -> Reply {
     Reply::GlxIsEnabled(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsList"))]
 impl From<glx::IsListReply> for Reply {
   fn from(reply: glx::IsListReply) -> Reply {
     Reply::GlxIsList(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::AreTexturesResident"))]
 impl From<glx::AreTexturesResidentReply> for Reply {
   fn from(reply: glx::AreTexturesResidentReply) -> Reply {
     Reply::GlxAreTexturesResident(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenTextures"))]
 impl From<glx::GenTexturesReply> for Reply {
   fn from(reply: glx::GenTexturesReply) -> Reply {
     Reply::GlxGenTextures(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsTexture"))]
 impl From<glx::IsTextureReply> for Reply {
   fn from(reply: glx::IsTextureReply) -> Reply {
     Reply::GlxIsTexture(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTable"))]
 impl From<glx::GetColorTableReply> for Reply {
   fn from(reply: glx::GetColorTableReply) -> Reply {
     Reply::GlxGetColorTable(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameterfv"))]
 impl From<glx::GetColorTableParameterfvReply> for Reply {
   fn from(reply: glx::GetColorTableParameterfvReply) -> Reply {
     Reply::GlxGetColorTableParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetColorTableParameteriv"))]
 impl From<glx::GetColorTableParameterivReply> for Reply {
   fn from(reply: glx::GetColorTableParameterivReply) -> Reply {
     Reply::GlxGetColorTableParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionFilter"))]
 impl From<glx::GetConvolutionFilterReply> for Reply {
   fn from(reply: glx::GetConvolutionFilterReply) -> Reply {
     Reply::GlxGetConvolutionFilter(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameterfv"))]
 impl From<glx::GetConvolutionParameterfvReply> for Reply {
   fn from(reply: glx::GetConvolutionParameterfvReply) -> Reply {
     Reply::GlxGetConvolutionParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetConvolutionParameteriv"))]
 impl From<glx::GetConvolutionParameterivReply> for Reply {
   fn from(reply: glx::GetConvolutionParameterivReply) -> Reply {
     Reply::GlxGetConvolutionParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetSeparableFilter"))]
 impl From<glx::GetSeparableFilterReply> for Reply {
   fn from(reply: glx::GetSeparableFilterReply) -> Reply {
     Reply::GlxGetSeparableFilter(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogram"))]
 impl From<glx::GetHistogramReply> for Reply {
   fn from(reply: glx::GetHistogramReply) -> Reply {
     Reply::GlxGetHistogram(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameterfv"))]
 impl From<glx::GetHistogramParameterfvReply> for Reply {
   fn from(reply: glx::GetHistogramParameterfvReply) -> Reply {
     Reply::GlxGetHistogramParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetHistogramParameteriv"))]
 impl From<glx::GetHistogramParameterivReply> for Reply {
   fn from(reply: glx::GetHistogramParameterivReply) -> Reply {
     Reply::GlxGetHistogramParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmax"))]
 impl From<glx::GetMinmaxReply> for Reply {
   fn from(reply: glx::GetMinmaxReply) -> Reply {
     Reply::GlxGetMinmax(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameterfv"))]
 impl From<glx::GetMinmaxParameterfvReply> for Reply {
   fn from(reply: glx::GetMinmaxParameterfvReply) -> Reply {
     Reply::GlxGetMinmaxParameterfv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetMinmaxParameteriv"))]
 impl From<glx::GetMinmaxParameterivReply> for Reply {
   fn from(reply: glx::GetMinmaxParameterivReply) -> Reply {
     Reply::GlxGetMinmaxParameteriv(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetCompressedTexImageARB"))]
 impl From<glx::GetCompressedTexImageARBReply> for Reply {
   fn from(reply: glx::GetCompressedTexImageARBReply) -> Reply {
     Reply::GlxGetCompressedTexImageARB(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GenQueriesARB"))]
 impl From<glx::GenQueriesARBReply> for Reply {
   fn from(reply: glx::GenQueriesARBReply) -> Reply {
     Reply::GlxGenQueriesARB(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::IsQueryARB"))]
 impl From<glx::IsQueryARBReply> for Reply {
   fn from(reply: glx::IsQueryARBReply) -> Reply {
     Reply::GlxIsQueryARB(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryivARB"))]
 impl From<glx::GetQueryivARBReply> for Reply {
   fn from(reply: glx::GetQueryivARBReply) -> Reply {
     Reply::GlxGetQueryivARB(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectivARB"))]
 impl From<glx::GetQueryObjectivARBReply> for Reply {
   fn from(reply: glx::GetQueryObjectivARBReply) -> Reply {
     Reply::GlxGetQueryObjectivARB(reply)
   }
 }
 #[cfg(feature = "glx")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "glx::GetQueryObjectuivARB"))]
 impl From<glx::GetQueryObjectuivARBReply> for Reply {
   fn from(reply: glx::GetQueryObjectuivARBReply) -> Reply {
     Reply::GlxGetQueryObjectuivARB(reply)
   }
 }
 #[cfg(feature = "present")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryVersion"))]
 impl From<present::QueryVersionReply> for Reply {
   fn from(reply: present::QueryVersionReply) -> Reply {
     Reply::PresentQueryVersion(reply)
   }
 }
 #[cfg(feature = "present")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "present::QueryCapabilities"))]
 impl From<present::QueryCapabilitiesReply> for Reply {
   fn from(reply: present::QueryCapabilitiesReply) -> Reply {
     Reply::PresentQueryCapabilities(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 impl From<randr::QueryVersionReply> for Reply {
   fn from(reply: randr::QueryVersionReply) -> Reply {
     Reply::RandrQueryVersion(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 impl From<randr::SetScreenConfigReply> for Reply {
   fn from(reply: randr::SetScreenConfigReply) -> Reply {
     Reply::RandrSetScreenConfig(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 impl From<randr::GetScreenInfoReply> for Reply {
   fn from(reply: randr::GetScreenInfoReply) -> Reply {
     Reply::RandrGetScreenInfo(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 impl From<randr::GetScreenSizeRangeReply> for Reply {
   fn from(reply: randr::GetScreenSizeRangeReply) -> Reply {
     Reply::RandrGetScreenSizeRange(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 impl From<randr::GetScreenResourcesReply> for Reply {
   fn from(reply: randr::GetScreenResourcesReply) -> Reply {
     Reply::RandrGetScreenResources(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 impl From<randr::GetOutputInfoReply> for Reply {
   fn from(reply: randr::GetOutputInfoReply) -> Reply {
     Reply::RandrGetOutputInfo(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 impl From<randr::ListOutputPropertiesReply> for Reply {
   fn from(reply: randr::ListOutputPropertiesReply) -> Reply {
     Reply::RandrListOutputProperties(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 impl From<randr::QueryOutputPropertyReply> for Reply {
   fn from(reply: randr::QueryOutputPropertyReply) -> Reply {
     Reply::RandrQueryOutputProperty(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 impl From<randr::GetOutputPropertyReply> for Reply {
   fn from(reply: randr::GetOutputPropertyReply) -> Reply {
     Reply::RandrGetOutputProperty(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 impl From<randr::CreateModeReply> for Reply {
   fn from(reply: randr::CreateModeReply) -> Reply {
     Reply::RandrCreateMode(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 impl From<randr::GetCrtcInfoReply> for Reply {
   fn from(reply: randr::GetCrtcInfoReply) -> Reply {
     Reply::RandrGetCrtcInfo(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 impl From<randr::SetCrtcConfigReply> for Reply {
   fn from(reply: randr::SetCrtcConfigReply) -> Reply {
     Reply::RandrSetCrtcConfig(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 impl From<randr::GetCrtcGammaSizeReply> for Reply {
   fn from(reply: randr::GetCrtcGammaSizeReply) -> Reply {
     Reply::RandrGetCrtcGammaSize(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 impl From<randr::GetCrtcGammaReply> for Reply {
   fn from(reply: randr::GetCrtcGammaReply) -> Reply {
     Reply::RandrGetCrtcGamma(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 impl From<randr::GetScreenResourcesCurrentReply> for Reply {
   fn from(reply: randr::GetScreenResourcesCurrentReply) -> Reply {
     Reply::RandrGetScreenResourcesCurrent(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 impl From<randr::GetCrtcTransformReply> for Reply {
   fn from(reply: randr::GetCrtcTransformReply) -> Reply {
     Reply::RandrGetCrtcTransform(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 impl From<randr::GetPanningReply> for Reply {
   fn from(reply: randr::GetPanningReply) -> Reply {
     Reply::RandrGetPanning(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 impl From<randr::SetPanningReply> for Reply {
   fn from(reply: randr::SetPanningReply) -> Reply {
     Reply::RandrSetPanning(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 impl From<randr::GetOutputPrimaryReply> for Reply {
   fn from(reply: randr::GetOutputPrimaryReply) -> Reply {
     Reply::RandrGetOutputPrimary(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 impl From<randr::GetProvidersReply> for Reply {
   fn from(reply: randr::GetProvidersReply) -> Reply {
     Reply::RandrGetProviders(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 impl From<randr::GetProviderInfoReply> for Reply {
   fn from(reply: randr::GetProviderInfoReply) -> Reply {
     Reply::RandrGetProviderInfo(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 impl From<randr::ListProviderPropertiesReply> for Reply {
   fn from(reply: randr::ListProviderPropertiesReply) -> Reply {
     Reply::RandrListProviderProperties(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 impl From<randr::QueryProviderPropertyReply> for Reply {
   fn from(reply: randr::QueryProviderPropertyReply) -> Reply {
     Reply::RandrQueryProviderProperty(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 impl From<randr::GetProviderPropertyReply> for Reply {
   fn from(reply: randr::GetProviderPropertyReply) -> Reply {
     Reply::RandrGetProviderProperty(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 impl From<randr::GetMonitorsReply> for Reply {
   fn from(reply: randr::GetMonitorsReply) -> Reply {
     Reply::RandrGetMonitors(reply)
   }
 }
 #[cfg(feature = "randr")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
 impl From<randr::CreateLeaseReply> for Reply {
   fn from(reply: randr::CreateLeaseReply) -> Reply {
     Reply::RandrCreateLease(reply)
   }
 }
 #[cfg(feature = "record")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::QueryVersion"))]
 impl From<record::QueryVersionReply> for Reply {
   fn from(reply: record::QueryVersionReply) -> Reply {
     Reply::RecordQueryVersion(reply)
   }
 }
 #[cfg(feature = "record")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "record::GetContext"))]
 impl From<record::GetContextReply> for Reply {
   fn from(reply: record::GetContextReply) -> Reply {
     Reply::RecordGetContext(reply)
@@ -6034,155 +9882,181 @@ impl From<record::EnableContextReply> for Reply {
   }
 }
 #[cfg(feature = "render")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 impl From<render::QueryVersionReply> for Reply {
   fn from(reply: render::QueryVersionReply) -> Reply {
     Reply::RenderQueryVersion(reply)
   }
 }
 #[cfg(feature = "render")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 impl From<render::QueryPictFormatsReply> for Reply {
   fn from(reply: render::QueryPictFormatsReply) -> Reply {
     Reply::RenderQueryPictFormats(reply)
   }
 }
 #[cfg(feature = "render")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 impl From<render::QueryPictIndexValuesReply> for Reply {
   fn from(reply: render::QueryPictIndexValuesReply) -> Reply {
     Reply::RenderQueryPictIndexValues(reply)
   }
 }
 #[cfg(feature = "render")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 impl From<render::QueryFiltersReply> for Reply {
   fn from(reply: render::QueryFiltersReply) -> Reply {
     Reply::RenderQueryFilters(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryVersion"))]
 impl From<res::QueryVersionReply> for Reply {
   fn from(reply: res::QueryVersionReply) -> Reply {
     Reply::ResQueryVersion(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClients"))]
 impl From<res::QueryClientsReply> for Reply {
   fn from(reply: res::QueryClientsReply) -> Reply {
     Reply::ResQueryClients(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientResources"))]
 impl From<res::QueryClientResourcesReply> for Reply {
   fn from(reply: res::QueryClientResourcesReply) -> Reply {
     Reply::ResQueryClientResources(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientPixmapBytes"))]
 impl From<res::QueryClientPixmapBytesReply> for Reply {
   fn from(reply: res::QueryClientPixmapBytesReply) -> Reply {
     Reply::ResQueryClientPixmapBytes(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryClientIds"))]
 impl From<res::QueryClientIdsReply> for Reply {
   fn from(reply: res::QueryClientIdsReply) -> Reply {
     Reply::ResQueryClientIds(reply)
   }
 }
 #[cfg(feature = "res")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "res::QueryResourceBytes"))]
 impl From<res::QueryResourceBytesReply> for Reply {
   fn from(reply: res::QueryResourceBytesReply) -> Reply {
     Reply::ResQueryResourceBytes(reply)
   }
 }
 #[cfg(feature = "screensaver")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryVersion"))]
 impl From<screensaver::QueryVersionReply> for Reply {
   fn from(reply: screensaver::QueryVersionReply) -> Reply {
     Reply::ScreensaverQueryVersion(reply)
   }
 }
 #[cfg(feature = "screensaver")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "screensaver::QueryInfo"))]
 impl From<screensaver::QueryInfoReply> for Reply {
   fn from(reply: screensaver::QueryInfoReply) -> Reply {
     Reply::ScreensaverQueryInfo(reply)
   }
 }
 #[cfg(feature = "shape")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 impl From<shape::QueryVersionReply> for Reply {
   fn from(reply: shape::QueryVersionReply) -> Reply {
     Reply::ShapeQueryVersion(reply)
   }
 }
 #[cfg(feature = "shape")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 impl From<shape::QueryExtentsReply> for Reply {
   fn from(reply: shape::QueryExtentsReply) -> Reply {
     Reply::ShapeQueryExtents(reply)
   }
 }
 #[cfg(feature = "shape")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 impl From<shape::InputSelectedReply> for Reply {
   fn from(reply: shape::InputSelectedReply) -> Reply {
     Reply::ShapeInputSelected(reply)
   }
 }
 #[cfg(feature = "shape")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 impl From<shape::GetRectanglesReply> for Reply {
   fn from(reply: shape::GetRectanglesReply) -> Reply {
     Reply::ShapeGetRectangles(reply)
   }
 }
 #[cfg(feature = "shm")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 impl From<shm::QueryVersionReply> for Reply {
   fn from(reply: shm::QueryVersionReply) -> Reply {
     Reply::ShmQueryVersion(reply)
   }
 }
 #[cfg(feature = "shm")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 impl From<shm::GetImageReply> for Reply {
   fn from(reply: shm::GetImageReply) -> Reply {
     Reply::ShmGetImage(reply)
   }
 }
 #[cfg(feature = "shm")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
 impl From<shm::CreateSegmentReply> for Reply {
   fn from(reply: shm::CreateSegmentReply) -> Reply {
     Reply::ShmCreateSegment(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 impl From<sync::InitializeReply> for Reply {
   fn from(reply: sync::InitializeReply) -> Reply {
     Reply::SyncInitialize(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 impl From<sync::ListSystemCountersReply> for Reply {
   fn from(reply: sync::ListSystemCountersReply) -> Reply {
     Reply::SyncListSystemCounters(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 impl From<sync::QueryCounterReply> for Reply {
   fn from(reply: sync::QueryCounterReply) -> Reply {
     Reply::SyncQueryCounter(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 impl From<sync::QueryAlarmReply> for Reply {
   fn from(reply: sync::QueryAlarmReply) -> Reply {
     Reply::SyncQueryAlarm(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 impl From<sync::GetPriorityReply> for Reply {
   fn from(reply: sync::GetPriorityReply) -> Reply {
     Reply::SyncGetPriority(reply)
   }
 }
 #[cfg(feature = "sync")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 impl From<sync::QueryFenceReply> for Reply {
   fn from(reply: sync::QueryFenceReply) -> Reply {
     Reply::SyncQueryFence(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetVersion"))]
 impl From<xc_misc::GetVersionReply> for Reply {
   fn from(reply: xc_misc::GetVersionReply) -> Reply {
     Reply::XcMiscGetVersion(reply)
@@ -6193,774 +10067,903 @@ impl From<xc_misc::GetXIDRangeReply> for Reply {
     Reply::XcMiscGetXIDRange(reply)
   }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xc_misc::GetXIDList"))]
 impl From<xc_misc::GetXIDListReply> for Reply {
   fn from(reply: xc_misc::GetXIDListReply) -> Reply {
     Reply::XcMiscGetXIDList(reply)
   }
 }
 #[cfg(feature = "xevie")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::QueryVersion"))]
 impl From<xevie::QueryVersionReply> for Reply {
   fn from(reply: xevie::QueryVersionReply) -> Reply {
     Reply::XevieQueryVersion(reply)
   }
 }
 #[cfg(feature = "xevie")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Start"))]
 impl From<xevie::StartReply> for Reply {
   fn from(reply: xevie::StartReply) -> Reply {
     Reply::XevieStart(reply)
   }
 }
 #[cfg(feature = "xevie")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::End"))]
 impl From<xevie::EndReply> for Reply {
   fn from(reply: xevie::EndReply) -> Reply {
     Reply::XevieEnd(reply)
   }
 }
 #[cfg(feature = "xevie")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::Send"))]
 impl From<xevie::SendReply> for Reply {
   fn from(reply: xevie::SendReply) -> Reply {
     Reply::XevieSend(reply)
   }
 }
 #[cfg(feature = "xevie")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xevie::SelectInput"))]
 impl From<xevie::SelectInputReply> for Reply {
   fn from(reply: xevie::SelectInputReply) -> Reply {
     Reply::XevieSelectInput(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryVersion"))]
 impl From<xf86dri::QueryVersionReply> for Reply {
   fn from(reply: xf86dri::QueryVersionReply) -> Reply {
     Reply::Xf86driQueryVersion(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::QueryDirectRenderingCapable"))]
 impl From<xf86dri::QueryDirectRenderingCapableReply> for Reply {
   fn from(reply: xf86dri::QueryDirectRenderingCapableReply) -> Reply {
     Reply::Xf86driQueryDirectRenderingCapable(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::OpenConnection"))]
 impl From<xf86dri::OpenConnectionReply> for Reply {
   fn from(reply: xf86dri::OpenConnectionReply) -> Reply {
     Reply::Xf86driOpenConnection(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetClientDriverName"))]
 impl From<xf86dri::GetClientDriverNameReply> for Reply {
   fn from(reply: xf86dri::GetClientDriverNameReply) -> Reply {
     Reply::Xf86driGetClientDriverName(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateContext"))]
 impl From<xf86dri::CreateContextReply> for Reply {
   fn from(reply: xf86dri::CreateContextReply) -> Reply {
     Reply::Xf86driCreateContext(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::CreateDrawable"))]
 impl From<xf86dri::CreateDrawableReply> for Reply {
   fn from(reply: xf86dri::CreateDrawableReply) -> Reply {
     Reply::Xf86driCreateDrawable(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDrawableInfo"))]
 impl From<xf86dri::GetDrawableInfoReply> for Reply {
   fn from(reply: xf86dri::GetDrawableInfoReply) -> Reply {
     Reply::Xf86driGetDrawableInfo(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::GetDeviceInfo"))]
 impl From<xf86dri::GetDeviceInfoReply> for Reply {
   fn from(reply: xf86dri::GetDeviceInfoReply) -> Reply {
     Reply::Xf86driGetDeviceInfo(reply)
   }
 }
 #[cfg(feature = "xf86dri")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86dri::AuthConnection"))]
 impl From<xf86dri::AuthConnectionReply> for Reply {
   fn from(reply: xf86dri::AuthConnectionReply) -> Reply {
     Reply::Xf86driAuthConnection(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 impl From<xf86vidmode::QueryVersionReply> for Reply {
   fn from(reply: xf86vidmode::QueryVersionReply) -> Reply {
     Reply::Xf86vidmodeQueryVersion(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 impl From<xf86vidmode::GetModeLineReply> for Reply {
   fn from(reply: xf86vidmode::GetModeLineReply) -> Reply {
     Reply::Xf86vidmodeGetModeLine(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 impl From<xf86vidmode::GetMonitorReply> for Reply {
   fn from(reply: xf86vidmode::GetMonitorReply) -> Reply {
     Reply::Xf86vidmodeGetMonitor(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 impl From<xf86vidmode::GetAllModeLinesReply> for Reply {
   fn from(reply: xf86vidmode::GetAllModeLinesReply) -> Reply {
     Reply::Xf86vidmodeGetAllModeLines(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 impl From<xf86vidmode::ValidateModeLineReply> for Reply {
   fn from(reply: xf86vidmode::ValidateModeLineReply) -> Reply {
     Reply::Xf86vidmodeValidateModeLine(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 impl From<xf86vidmode::GetViewPortReply> for Reply {
   fn from(reply: xf86vidmode::GetViewPortReply) -> Reply {
     Reply::Xf86vidmodeGetViewPort(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 impl From<xf86vidmode::GetDotClocksReply> for Reply {
   fn from(reply: xf86vidmode::GetDotClocksReply) -> Reply {
     Reply::Xf86vidmodeGetDotClocks(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 impl From<xf86vidmode::GetGammaReply> for Reply {
   fn from(reply: xf86vidmode::GetGammaReply) -> Reply {
     Reply::Xf86vidmodeGetGamma(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 impl From<xf86vidmode::GetGammaRampReply> for Reply {
   fn from(reply: xf86vidmode::GetGammaRampReply) -> Reply {
     Reply::Xf86vidmodeGetGammaRamp(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 impl From<xf86vidmode::GetGammaRampSizeReply> for Reply {
   fn from(reply: xf86vidmode::GetGammaRampSizeReply) -> Reply {
     Reply::Xf86vidmodeGetGammaRampSize(reply)
   }
 }
 #[cfg(feature = "xf86vidmode")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 impl From<xf86vidmode::GetPermissionsReply> for Reply {
   fn from(reply: xf86vidmode::GetPermissionsReply) -> Reply {
     Reply::Xf86vidmodeGetPermissions(reply)
   }
 }
 #[cfg(feature = "xfixes")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::QueryVersion"))]
 impl From<xfixes::QueryVersionReply> for Reply {
   fn from(reply: xfixes::QueryVersionReply) -> Reply {
     Reply::XfixesQueryVersion(reply)
   }
 }
 #[cfg(feature = "xfixes")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImage"))]
 impl From<xfixes::GetCursorImageReply> for Reply {
   fn from(reply: xfixes::GetCursorImageReply) -> Reply {
     Reply::XfixesGetCursorImage(reply)
   }
 }
 #[cfg(feature = "xfixes")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::FetchRegion"))]
 impl From<xfixes::FetchRegionReply> for Reply {
   fn from(reply: xfixes::FetchRegionReply) -> Reply {
     Reply::XfixesFetchRegion(reply)
   }
 }
 #[cfg(feature = "xfixes")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorName"))]
 impl From<xfixes::GetCursorNameReply> for Reply {
   fn from(reply: xfixes::GetCursorNameReply) -> Reply {
     Reply::XfixesGetCursorName(reply)
   }
 }
 #[cfg(feature = "xfixes")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xfixes::GetCursorImageAndName"))]
 impl From<xfixes::GetCursorImageAndNameReply> for Reply {
   fn from(reply: xfixes::GetCursorImageAndNameReply) -> Reply {
     Reply::XfixesGetCursorImageAndName(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryVersion"))]
 impl From<xinerama::QueryVersionReply> for Reply {
   fn from(reply: xinerama::QueryVersionReply) -> Reply {
     Reply::XineramaQueryVersion(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetState"))]
 impl From<xinerama::GetStateReply> for Reply {
   fn from(reply: xinerama::GetStateReply) -> Reply {
     Reply::XineramaGetState(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenCount"))]
 impl From<xinerama::GetScreenCountReply> for Reply {
   fn from(reply: xinerama::GetScreenCountReply) -> Reply {
     Reply::XineramaGetScreenCount(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::GetScreenSize"))]
 impl From<xinerama::GetScreenSizeReply> for Reply {
   fn from(reply: xinerama::GetScreenSizeReply) -> Reply {
     Reply::XineramaGetScreenSize(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::IsActive"))]
 impl From<xinerama::IsActiveReply> for Reply {
   fn from(reply: xinerama::IsActiveReply) -> Reply {
     Reply::XineramaIsActive(reply)
   }
 }
 #[cfg(feature = "xinerama")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinerama::QueryScreens"))]
 impl From<xinerama::QueryScreensReply> for Reply {
   fn from(reply: xinerama::QueryScreensReply) -> Reply {
     Reply::XineramaQueryScreens(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 impl From<xinput::GetExtensionVersionReply> for Reply {
   fn from(reply: xinput::GetExtensionVersionReply) -> Reply {
     Reply::XinputGetExtensionVersion(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 impl From<xinput::ListInputDevicesReply> for Reply {
   fn from(reply: xinput::ListInputDevicesReply) -> Reply {
     Reply::XinputListInputDevices(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 impl From<xinput::OpenDeviceReply> for Reply {
   fn from(reply: xinput::OpenDeviceReply) -> Reply {
     Reply::XinputOpenDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 impl From<xinput::SetDeviceModeReply> for Reply {
   fn from(reply: xinput::SetDeviceModeReply) -> Reply {
     Reply::XinputSetDeviceMode(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 impl From<xinput::GetSelectedExtensionEventsReply> for Reply {
   fn from(reply: xinput::GetSelectedExtensionEventsReply) -> Reply {
     Reply::XinputGetSelectedExtensionEvents(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 impl From<xinput::GetDeviceDontPropagateListReply> for Reply {
   fn from(reply: xinput::GetDeviceDontPropagateListReply) -> Reply {
     Reply::XinputGetDeviceDontPropagateList(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 impl From<xinput::GetDeviceMotionEventsReply> for Reply {
   fn from(reply: xinput::GetDeviceMotionEventsReply) -> Reply {
     Reply::XinputGetDeviceMotionEvents(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 impl From<xinput::ChangeKeyboardDeviceReply> for Reply {
   fn from(reply: xinput::ChangeKeyboardDeviceReply) -> Reply {
     Reply::XinputChangeKeyboardDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 impl From<xinput::ChangePointerDeviceReply> for Reply {
   fn from(reply: xinput::ChangePointerDeviceReply) -> Reply {
     Reply::XinputChangePointerDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 impl From<xinput::GrabDeviceReply> for Reply {
   fn from(reply: xinput::GrabDeviceReply) -> Reply {
     Reply::XinputGrabDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 impl From<xinput::GetDeviceFocusReply> for Reply {
   fn from(reply: xinput::GetDeviceFocusReply) -> Reply {
     Reply::XinputGetDeviceFocus(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 impl From<xinput::GetFeedbackControlReply> for Reply {
   fn from(reply: xinput::GetFeedbackControlReply) -> Reply {
     Reply::XinputGetFeedbackControl(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 impl From<xinput::GetDeviceKeyMappingReply> for Reply {
   fn from(reply: xinput::GetDeviceKeyMappingReply) -> Reply {
     Reply::XinputGetDeviceKeyMapping(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 impl From<xinput::GetDeviceModifierMappingReply> for Reply {
   fn from(reply: xinput::GetDeviceModifierMappingReply) -> Reply {
     Reply::XinputGetDeviceModifierMapping(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 impl From<xinput::SetDeviceModifierMappingReply> for Reply {
   fn from(reply: xinput::SetDeviceModifierMappingReply) -> Reply {
     Reply::XinputSetDeviceModifierMapping(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 impl From<xinput::GetDeviceButtonMappingReply> for Reply {
   fn from(reply: xinput::GetDeviceButtonMappingReply) -> Reply {
     Reply::XinputGetDeviceButtonMapping(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 impl From<xinput::SetDeviceButtonMappingReply> for Reply {
   fn from(reply: xinput::SetDeviceButtonMappingReply) -> Reply {
     Reply::XinputSetDeviceButtonMapping(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 impl From<xinput::QueryDeviceStateReply> for Reply {
   fn from(reply: xinput::QueryDeviceStateReply) -> Reply {
     Reply::XinputQueryDeviceState(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 impl From<xinput::SetDeviceValuatorsReply> for Reply {
   fn from(reply: xinput::SetDeviceValuatorsReply) -> Reply {
     Reply::XinputSetDeviceValuators(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
 impl From<xinput::GetDeviceControlReply> for Reply {
   fn from(reply: xinput::GetDeviceControlReply) -> Reply {
     Reply::XinputGetDeviceControl(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
 impl From<xinput::ChangeDeviceControlReply> for Reply {
   fn from(reply: xinput::ChangeDeviceControlReply) -> Reply {
     Reply::XinputChangeDeviceControl(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 impl From<xinput::ListDevicePropertiesReply> for Reply {
   fn from(reply: xinput::ListDevicePropertiesReply) -> Reply {
     Reply::XinputListDeviceProperties(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl From<xinput::GetDevicePropertyReply> for Reply {
   fn from(reply: xinput::GetDevicePropertyReply) -> Reply {
     Reply::XinputGetDeviceProperty(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 impl From<xinput::XIQueryPointerReply> for Reply {
   fn from(reply: xinput::XIQueryPointerReply) -> Reply {
     Reply::XinputXIQueryPointer(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 impl From<xinput::XIGetClientPointerReply> for Reply {
   fn from(reply: xinput::XIGetClientPointerReply) -> Reply {
     Reply::XinputXIGetClientPointer(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 impl From<xinput::XIQueryVersionReply> for Reply {
   fn from(reply: xinput::XIQueryVersionReply) -> Reply {
     Reply::XinputXIQueryVersion(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 impl From<xinput::XIQueryDeviceReply> for Reply {
   fn from(reply: xinput::XIQueryDeviceReply) -> Reply {
     Reply::XinputXIQueryDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 impl From<xinput::XIGetFocusReply> for Reply {
   fn from(reply: xinput::XIGetFocusReply) -> Reply {
     Reply::XinputXIGetFocus(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 impl From<xinput::XIGrabDeviceReply> for Reply {
   fn from(reply: xinput::XIGrabDeviceReply) -> Reply {
     Reply::XinputXIGrabDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 impl From<xinput::XIPassiveGrabDeviceReply> for Reply {
   fn from(reply: xinput::XIPassiveGrabDeviceReply) -> Reply {
     Reply::XinputXIPassiveGrabDevice(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 impl From<xinput::XIListPropertiesReply> for Reply {
   fn from(reply: xinput::XIListPropertiesReply) -> Reply {
     Reply::XinputXIListProperties(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl From<xinput::XIGetPropertyReply> for Reply {
   fn from(reply: xinput::XIGetPropertyReply) -> Reply {
     Reply::XinputXIGetProperty(reply)
   }
 }
 #[cfg(feature = "xinput")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 impl From<xinput::XIGetSelectedEventsReply> for Reply {
   fn from(reply: xinput::XIGetSelectedEventsReply) -> Reply {
     Reply::XinputXIGetSelectedEvents(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 impl From<xkb::UseExtensionReply> for Reply {
   fn from(reply: xkb::UseExtensionReply) -> Reply {
     Reply::XkbUseExtension(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
 impl From<xkb::GetStateReply> for Reply {
   fn from(reply: xkb::GetStateReply) -> Reply {
     Reply::XkbGetState(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
 impl From<xkb::GetControlsReply> for Reply {
   fn from(reply: xkb::GetControlsReply) -> Reply {
     Reply::XkbGetControls(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl From<xkb::GetMapReply> for Reply {
   fn from(reply: xkb::GetMapReply) -> Reply {
     Reply::XkbGetMap(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 impl From<xkb::GetCompatMapReply> for Reply {
   fn from(reply: xkb::GetCompatMapReply) -> Reply {
     Reply::XkbGetCompatMap(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 impl From<xkb::GetIndicatorStateReply> for Reply {
   fn from(reply: xkb::GetIndicatorStateReply) -> Reply {
     Reply::XkbGetIndicatorState(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 impl From<xkb::GetIndicatorMapReply> for Reply {
   fn from(reply: xkb::GetIndicatorMapReply) -> Reply {
     Reply::XkbGetIndicatorMap(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
 impl From<xkb::GetNamedIndicatorReply> for Reply {
   fn from(reply: xkb::GetNamedIndicatorReply) -> Reply {
     Reply::XkbGetNamedIndicator(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl From<xkb::GetNamesReply> for Reply {
   fn from(reply: xkb::GetNamesReply) -> Reply {
     Reply::XkbGetNames(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 impl From<xkb::PerClientFlagsReply> for Reply {
   fn from(reply: xkb::PerClientFlagsReply) -> Reply {
     Reply::XkbPerClientFlags(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 impl From<xkb::ListComponentsReply> for Reply {
   fn from(reply: xkb::ListComponentsReply) -> Reply {
     Reply::XkbListComponents(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl From<xkb::GetKbdByNameReply> for Reply {
   fn from(reply: xkb::GetKbdByNameReply) -> Reply {
     Reply::XkbGetKbdByName(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 impl From<xkb::GetDeviceInfoReply> for Reply {
   fn from(reply: xkb::GetDeviceInfoReply) -> Reply {
     Reply::XkbGetDeviceInfo(reply)
   }
 }
 #[cfg(feature = "xkb")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 impl From<xkb::SetDebuggingFlagsReply> for Reply {
   fn from(reply: xkb::SetDebuggingFlagsReply) -> Reply {
     Reply::XkbSetDebuggingFlags(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryVersion"))]
 impl From<xprint::PrintQueryVersionReply> for Reply {
   fn from(reply: xprint::PrintQueryVersionReply) -> Reply {
     Reply::XprintPrintQueryVersion(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPrinterList"))]
 impl From<xprint::PrintGetPrinterListReply> for Reply {
   fn from(reply: xprint::PrintGetPrinterListReply) -> Reply {
     Reply::XprintPrintGetPrinterList(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetContext"))]
 impl From<xprint::PrintGetContextReply> for Reply {
   fn from(reply: xprint::PrintGetContextReply) -> Reply {
     Reply::XprintPrintGetContext(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetScreenOfContext"))]
 impl From<xprint::PrintGetScreenOfContextReply> for Reply {
   fn from(reply: xprint::PrintGetScreenOfContextReply) -> Reply {
     Reply::XprintPrintGetScreenOfContext(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetDocumentData"))]
 impl From<xprint::PrintGetDocumentDataReply> for Reply {
   fn from(reply: xprint::PrintGetDocumentDataReply) -> Reply {
     Reply::XprintPrintGetDocumentData(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintInputSelected"))]
 impl From<xprint::PrintInputSelectedReply> for Reply {
   fn from(reply: xprint::PrintInputSelectedReply) -> Reply {
     Reply::XprintPrintInputSelected(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetAttributes"))]
 impl From<xprint::PrintGetAttributesReply> for Reply {
   fn from(reply: xprint::PrintGetAttributesReply) -> Reply {
     Reply::XprintPrintGetAttributes(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetOneAttributes"))]
 impl From<xprint::PrintGetOneAttributesReply> for Reply {
   fn from(reply: xprint::PrintGetOneAttributesReply) -> Reply {
     Reply::XprintPrintGetOneAttributes(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetPageDimensions"))]
 impl From<xprint::PrintGetPageDimensionsReply> for Reply {
   fn from(reply: xprint::PrintGetPageDimensionsReply) -> Reply {
     Reply::XprintPrintGetPageDimensions(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintQueryScreens"))]
 impl From<xprint::PrintQueryScreensReply> for Reply {
   fn from(reply: xprint::PrintQueryScreensReply) -> Reply {
     Reply::XprintPrintQueryScreens(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintSetImageResolution"))]
 impl From<xprint::PrintSetImageResolutionReply> for Reply {
   fn from(reply: xprint::PrintSetImageResolutionReply) -> Reply {
     Reply::XprintPrintSetImageResolution(reply)
   }
 }
 #[cfg(feature = "xprint")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xprint::PrintGetImageResolution"))]
 impl From<xprint::PrintGetImageResolutionReply> for Reply {
   fn from(reply: xprint::PrintGetImageResolutionReply) -> Reply {
     Reply::XprintPrintGetImageResolution(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::QueryVersion"))]
 impl From<xselinux::QueryVersionReply> for Reply {
   fn from(reply: xselinux::QueryVersionReply) -> Reply {
     Reply::XselinuxQueryVersion(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceCreateContext"))]
 impl From<xselinux::GetDeviceCreateContextReply> for Reply {
   fn from(reply: xselinux::GetDeviceCreateContextReply) -> Reply {
     Reply::XselinuxGetDeviceCreateContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetDeviceContext"))]
 impl From<xselinux::GetDeviceContextReply> for Reply {
   fn from(reply: xselinux::GetDeviceContextReply) -> Reply {
     Reply::XselinuxGetDeviceContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowCreateContext"))]
 impl From<xselinux::GetWindowCreateContextReply> for Reply {
   fn from(reply: xselinux::GetWindowCreateContextReply) -> Reply {
     Reply::XselinuxGetWindowCreateContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetWindowContext"))]
 impl From<xselinux::GetWindowContextReply> for Reply {
   fn from(reply: xselinux::GetWindowContextReply) -> Reply {
     Reply::XselinuxGetWindowContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyCreateContext"))]
 impl From<xselinux::GetPropertyCreateContextReply> for Reply {
   fn from(reply: xselinux::GetPropertyCreateContextReply) -> Reply {
     Reply::XselinuxGetPropertyCreateContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyUseContext"))]
 impl From<xselinux::GetPropertyUseContextReply> for Reply {
   fn from(reply: xselinux::GetPropertyUseContextReply) -> Reply {
     Reply::XselinuxGetPropertyUseContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyContext"))]
 impl From<xselinux::GetPropertyContextReply> for Reply {
   fn from(reply: xselinux::GetPropertyContextReply) -> Reply {
     Reply::XselinuxGetPropertyContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetPropertyDataContext"))]
 impl From<xselinux::GetPropertyDataContextReply> for Reply {
   fn from(reply: xselinux::GetPropertyDataContextReply) -> Reply {
     Reply::XselinuxGetPropertyDataContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListProperties"))]
 impl From<xselinux::ListPropertiesReply> for Reply {
   fn from(reply: xselinux::ListPropertiesReply) -> Reply {
     Reply::XselinuxListProperties(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionCreateContext"))]
 impl From<xselinux::GetSelectionCreateContextReply> for Reply {
   fn from(reply: xselinux::GetSelectionCreateContextReply) -> Reply {
     Reply::XselinuxGetSelectionCreateContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionUseContext"))]
 impl From<xselinux::GetSelectionUseContextReply> for Reply {
   fn from(reply: xselinux::GetSelectionUseContextReply) -> Reply {
     Reply::XselinuxGetSelectionUseContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionContext"))]
 impl From<xselinux::GetSelectionContextReply> for Reply {
   fn from(reply: xselinux::GetSelectionContextReply) -> Reply {
     Reply::XselinuxGetSelectionContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetSelectionDataContext"))]
 impl From<xselinux::GetSelectionDataContextReply> for Reply {
   fn from(reply: xselinux::GetSelectionDataContextReply) -> Reply {
     Reply::XselinuxGetSelectionDataContext(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::ListSelections"))]
 impl From<xselinux::ListSelectionsReply> for Reply {
   fn from(reply: xselinux::ListSelectionsReply) -> Reply {
     Reply::XselinuxListSelections(reply)
   }
 }
 #[cfg(feature = "xselinux")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xselinux::GetClientContext"))]
 impl From<xselinux::GetClientContextReply> for Reply {
   fn from(reply: xselinux::GetClientContextReply) -> Reply {
     Reply::XselinuxGetClientContext(reply)
   }
 }
 #[cfg(feature = "xtest")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::GetVersion"))]
 impl From<xtest::GetVersionReply> for Reply {
   fn from(reply: xtest::GetVersionReply) -> Reply {
     Reply::XtestGetVersion(reply)
   }
 }
 #[cfg(feature = "xtest")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xtest::CompareCursor"))]
 impl From<xtest::CompareCursorReply> for Reply {
   fn from(reply: xtest::CompareCursorReply) -> Reply {
     Reply::XtestCompareCursor(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryExtension"))]
 impl From<xv::QueryExtensionReply> for Reply {
   fn from(reply: xv::QueryExtensionReply) -> Reply {
     Reply::XvQueryExtension(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryAdaptors"))]
 impl From<xv::QueryAdaptorsReply> for Reply {
   fn from(reply: xv::QueryAdaptorsReply) -> Reply {
     Reply::XvQueryAdaptors(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryEncodings"))]
 impl From<xv::QueryEncodingsReply> for Reply {
   fn from(reply: xv::QueryEncodingsReply) -> Reply {
     Reply::XvQueryEncodings(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GrabPort"))]
 impl From<xv::GrabPortReply> for Reply {
   fn from(reply: xv::GrabPortReply) -> Reply {
     Reply::XvGrabPort(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryBestSize"))]
 impl From<xv::QueryBestSizeReply> for Reply {
   fn from(reply: xv::QueryBestSizeReply) -> Reply {
     Reply::XvQueryBestSize(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::GetPortAttribute"))]
 impl From<xv::GetPortAttributeReply> for Reply {
   fn from(reply: xv::GetPortAttributeReply) -> Reply {
     Reply::XvGetPortAttribute(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryPortAttributes"))]
 impl From<xv::QueryPortAttributesReply> for Reply {
   fn from(reply: xv::QueryPortAttributesReply) -> Reply {
     Reply::XvQueryPortAttributes(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::ListImageFormats"))]
 impl From<xv::ListImageFormatsReply> for Reply {
   fn from(reply: xv::ListImageFormatsReply) -> Reply {
     Reply::XvListImageFormats(reply)
   }
 }
 #[cfg(feature = "xv")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xv::QueryImageAttributes"))]
 impl From<xv::QueryImageAttributesReply> for Reply {
   fn from(reply: xv::QueryImageAttributesReply) -> Reply {
     Reply::XvQueryImageAttributes(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::QueryVersion"))]
 impl From<xvmc::QueryVersionReply> for Reply {
   fn from(reply: xvmc::QueryVersionReply) -> Reply {
     Reply::XvmcQueryVersion(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSurfaceTypes"))]
 impl From<xvmc::ListSurfaceTypesReply> for Reply {
   fn from(reply: xvmc::ListSurfaceTypesReply) -> Reply {
     Reply::XvmcListSurfaceTypes(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateContext"))]
 impl From<xvmc::CreateContextReply> for Reply {
   fn from(reply: xvmc::CreateContextReply) -> Reply {
     Reply::XvmcCreateContext(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSurface"))]
 impl From<xvmc::CreateSurfaceReply> for Reply {
   fn from(reply: xvmc::CreateSurfaceReply) -> Reply {
     Reply::XvmcCreateSurface(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::CreateSubpicture"))]
 impl From<xvmc::CreateSubpictureReply> for Reply {
   fn from(reply: xvmc::CreateSubpictureReply) -> Reply {
     Reply::XvmcCreateSubpicture(reply)
   }
 }
 #[cfg(feature = "xvmc")]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xvmc::ListSubpictureTypes"))]
 impl From<xvmc::ListSubpictureTypesReply> for Reply {
   fn from(reply: xvmc::ListSubpictureTypesReply) -> Reply {
     Reply::XvmcListSubpictureTypes(reply)
@@ -7118,14 +11121,14 @@ impl ErrorKind {
         match ext_info {
             #[cfg(feature = "damage")]
             Some((damage::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     damage::BAD_DAMAGE_ERROR => Self::DamageBadDamage,
                     _ => Self::Unknown(error_code),
                 }
             }
             #[cfg(feature = "glx")]
             Some((glx::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     glx::BAD_CONTEXT_ERROR => Self::GlxBadContext,
                     glx::BAD_CONTEXT_STATE_ERROR => Self::GlxBadContextState,
                     glx::BAD_CONTEXT_TAG_ERROR => Self::GlxBadContextTag,
@@ -7145,7 +11148,7 @@ impl ErrorKind {
             }
             #[cfg(feature = "randr")]
             Some((randr::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     randr::BAD_CRTC_ERROR => Self::RandrBadCrtc,
                     randr::BAD_MODE_ERROR => Self::RandrBadMode,
                     randr::BAD_OUTPUT_ERROR => Self::RandrBadOutput,
@@ -7155,14 +11158,14 @@ impl ErrorKind {
             }
             #[cfg(feature = "record")]
             Some((record::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     record::BAD_CONTEXT_ERROR => Self::RecordBadContext,
                     _ => Self::Unknown(error_code),
                 }
             }
             #[cfg(feature = "render")]
             Some((render::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     render::GLYPH_ERROR => Self::RenderGlyph,
                     render::GLYPH_SET_ERROR => Self::RenderGlyphSet,
                     render::PICT_FORMAT_ERROR => Self::RenderPictFormat,
@@ -7173,14 +11176,14 @@ impl ErrorKind {
             }
             #[cfg(feature = "shm")]
             Some((shm::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     shm::BAD_SEG_ERROR => Self::ShmBadSeg,
                     _ => Self::Unknown(error_code),
                 }
             }
             #[cfg(feature = "sync")]
             Some((sync::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     sync::ALARM_ERROR => Self::SyncAlarm,
                     sync::COUNTER_ERROR => Self::SyncCounter,
                     _ => Self::Unknown(error_code),
@@ -7188,7 +11191,7 @@ impl ErrorKind {
             }
             #[cfg(feature = "xf86vidmode")]
             Some((xf86vidmode::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xf86vidmode::BAD_CLOCK_ERROR => Self::Xf86vidmodeBadClock,
                     xf86vidmode::BAD_H_TIMINGS_ERROR => Self::Xf86vidmodeBadHTimings,
                     xf86vidmode::BAD_V_TIMINGS_ERROR => Self::Xf86vidmodeBadVTimings,
@@ -7201,14 +11204,14 @@ impl ErrorKind {
             }
             #[cfg(feature = "xfixes")]
             Some((xfixes::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xfixes::BAD_REGION_ERROR => Self::XfixesBadRegion,
                     _ => Self::Unknown(error_code),
                 }
             }
             #[cfg(feature = "xinput")]
             Some((xinput::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xinput::CLASS_ERROR => Self::XinputClass,
                     xinput::DEVICE_ERROR => Self::XinputDevice,
                     xinput::DEVICE_BUSY_ERROR => Self::XinputDeviceBusy,
@@ -7219,14 +11222,14 @@ impl ErrorKind {
             }
             #[cfg(feature = "xkb")]
             Some((xkb::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xkb::KEYBOARD_ERROR => Self::XkbKeyboard,
                     _ => Self::Unknown(error_code),
                 }
             }
             #[cfg(feature = "xprint")]
             Some((xprint::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xprint::BAD_CONTEXT_ERROR => Self::XprintBadContext,
                     xprint::BAD_SEQUENCE_ERROR => Self::XprintBadSequence,
                     _ => Self::Unknown(error_code),
@@ -7234,7 +11237,7 @@ impl ErrorKind {
             }
             #[cfg(feature = "xv")]
             Some((xv::X11_EXTENSION_NAME, ext_info)) => {
-                match error_code - ext_info.first_error {
+                match error_code.wrapping_sub(ext_info.first_error) {
                     xv::BAD_CONTROL_ERROR => Self::XvBadControl,
                     xv::BAD_ENCODING_ERROR => Self::XvBadEncoding,
                     xv::BAD_PORT_ERROR => Self::XvBadPort,
@@ -7498,14 +11501,14 @@ impl Event {
         match ext_info {
             #[cfg(feature = "damage")]
             Some((damage::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     damage::NOTIFY_EVENT => Ok(Self::DamageNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
                 }
             }
             #[cfg(feature = "dri2")]
             Some((dri2::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     dri2::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::Dri2BufferSwapComplete(TryParse::try_parse(event)?.0)),
                     dri2::INVALIDATE_BUFFERS_EVENT => Ok(Self::Dri2InvalidateBuffers(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7513,7 +11516,7 @@ impl Event {
             }
             #[cfg(feature = "glx")]
             Some((glx::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     glx::BUFFER_SWAP_COMPLETE_EVENT => Ok(Self::GlxBufferSwapComplete(TryParse::try_parse(event)?.0)),
                     glx::PBUFFER_CLOBBER_EVENT => Ok(Self::GlxPbufferClobber(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7521,14 +11524,14 @@ impl Event {
             }
             #[cfg(feature = "present")]
             Some((present::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     present::GENERIC_EVENT => Ok(Self::PresentGeneric(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
                 }
             }
             #[cfg(feature = "randr")]
             Some((randr::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     randr::NOTIFY_EVENT => Ok(Self::RandrNotify(TryParse::try_parse(event)?.0)),
                     randr::SCREEN_CHANGE_NOTIFY_EVENT => Ok(Self::RandrScreenChangeNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7536,28 +11539,28 @@ impl Event {
             }
             #[cfg(feature = "screensaver")]
             Some((screensaver::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     screensaver::NOTIFY_EVENT => Ok(Self::ScreensaverNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
                 }
             }
             #[cfg(feature = "shape")]
             Some((shape::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     shape::NOTIFY_EVENT => Ok(Self::ShapeNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
                 }
             }
             #[cfg(feature = "shm")]
             Some((shm::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     shm::COMPLETION_EVENT => Ok(Self::ShmCompletion(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
                 }
             }
             #[cfg(feature = "sync")]
             Some((sync::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     sync::ALARM_NOTIFY_EVENT => Ok(Self::SyncAlarmNotify(TryParse::try_parse(event)?.0)),
                     sync::COUNTER_NOTIFY_EVENT => Ok(Self::SyncCounterNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7565,7 +11568,7 @@ impl Event {
             }
             #[cfg(feature = "xfixes")]
             Some((xfixes::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     xfixes::CURSOR_NOTIFY_EVENT => Ok(Self::XfixesCursorNotify(TryParse::try_parse(event)?.0)),
                     xfixes::SELECTION_NOTIFY_EVENT => Ok(Self::XfixesSelectionNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7573,7 +11576,7 @@ impl Event {
             }
             #[cfg(feature = "xinput")]
             Some((xinput::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     xinput::CHANGE_DEVICE_NOTIFY_EVENT => Ok(Self::XinputChangeDeviceNotify(TryParse::try_parse(event)?.0)),
                     xinput::DEVICE_BUTTON_PRESS_EVENT => Ok(Self::XinputDeviceButtonPress(TryParse::try_parse(event)?.0)),
                     xinput::DEVICE_BUTTON_RELEASE_EVENT => Ok(Self::XinputDeviceButtonRelease(TryParse::try_parse(event)?.0)),
@@ -7617,7 +11620,7 @@ impl Event {
             }
             #[cfg(feature = "xprint")]
             Some((xprint::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     xprint::ATTRIBUT_NOTIFY_EVENT => Ok(Self::XprintAttributNotify(TryParse::try_parse(event)?.0)),
                     xprint::NOTIFY_EVENT => Ok(Self::XprintNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7625,7 +11628,7 @@ impl Event {
             }
             #[cfg(feature = "xv")]
             Some((xv::X11_EXTENSION_NAME, ext_info)) => {
-                match event_code - ext_info.first_event {
+                match event_code.wrapping_sub(ext_info.first_event) {
                     xv::PORT_NOTIFY_EVENT => Ok(Self::XvPortNotify(TryParse::try_parse(event)?.0)),
                     xv::VIDEO_NOTIFY_EVENT => Ok(Self::XvVideoNotify(TryParse::try_parse(event)?.0)),
                     _ => Ok(Self::Unknown(event.to_vec())),
@@ -7692,6 +11695,7 @@ impl Event {
     }
 
     /// Get the sequence number contained in this X11 event
+    #[doc(alias = "sequence_number")]
     pub fn wire_sequence_number(&self) -> Option<u16> {
         match self {
             Event::Unknown(value) => sequence_number(value).ok(),
@@ -8110,9 +12114,617 @@ impl Event {
     ///
     /// If this function returns true, then this event comes from another client via
     /// the `SendEvent` request. Otherwise, it was generated by the X11 server.
+    #[doc(alias = "is_from_send_event")]
     pub fn sent_event(&self) -> bool {
         self.raw_response_type() & 0x80 != 0
     }
+
+    /// Get the name of the extension that this X11 event belongs to
+    ///
+    /// This is the name that is used with `QueryExtension`, e.g. `"XInputExtension"`.
+    /// Events of the core protocol, errors, and unknown events return `None`.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Event::Unknown(_) => None,
+            Event::Error(_) => None,
+            Event::ButtonPress(_) => None,
+            Event::ButtonRelease(_) => None,
+            Event::CirculateNotify(_) => None,
+            Event::CirculateRequest(_) => None,
+            Event::ClientMessage(_) => None,
+            Event::ColormapNotify(_) => None,
+            Event::ConfigureNotify(_) => None,
+            Event::ConfigureRequest(_) => None,
+            Event::CreateNotify(_) => None,
+            Event::DestroyNotify(_) => None,
+            Event::EnterNotify(_) => None,
+            Event::Expose(_) => None,
+            Event::FocusIn(_) => None,
+            Event::FocusOut(_) => None,
+            Event::GeGeneric(_) => None,
+            Event::GraphicsExposure(_) => None,
+            Event::GravityNotify(_) => None,
+            Event::KeyPress(_) => None,
+            Event::KeyRelease(_) => None,
+            Event::KeymapNotify(_) => None,
+            Event::LeaveNotify(_) => None,
+            Event::MapNotify(_) => None,
+            Event::MapRequest(_) => None,
+            Event::MappingNotify(_) => None,
+            Event::MotionNotify(_) => None,
+            Event::NoExposure(_) => None,
+            Event::PropertyNotify(_) => None,
+            Event::ReparentNotify(_) => None,
+            Event::ResizeRequest(_) => None,
+            Event::SelectionClear(_) => None,
+            Event::SelectionNotify(_) => None,
+            Event::SelectionRequest(_) => None,
+            Event::UnmapNotify(_) => None,
+            Event::VisibilityNotify(_) => None,
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => Some(damage::X11_EXTENSION_NAME),
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => Some(dri2::X11_EXTENSION_NAME),
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => Some(dri2::X11_EXTENSION_NAME),
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => Some(glx::X11_EXTENSION_NAME),
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => Some(glx::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => Some(randr::X11_EXTENSION_NAME),
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(_) => Some(randr::X11_EXTENSION_NAME),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(_) => Some(screensaver::X11_EXTENSION_NAME),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(_) => Some(shape::X11_EXTENSION_NAME),
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => Some(shm::X11_EXTENSION_NAME),
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => Some(sync::X11_EXTENSION_NAME),
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => Some(sync::X11_EXTENSION_NAME),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(_) => Some(xfixes::X11_EXTENSION_NAME),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(_) => Some(xfixes::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => Some(xprint::X11_EXTENSION_NAME),
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => Some(xprint::X11_EXTENSION_NAME),
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => Some(xv::X11_EXTENSION_NAME),
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => Some(xv::X11_EXTENSION_NAME),
+        }
+    }
+
+    /// Get the window that this X11 event is about
+    ///
+    /// This is a best-effort guess based on the fields of the event: A field called
+    /// `window` is preferred, then a field called `event`, and otherwise the first field
+    /// of type `WINDOW` is used. For example, this returns the `event` window of a
+    /// `ButtonPress` event and the `owner` of a `SelectionRequest` event. Events without
+    /// a window, errors, and unknown events return `None`.
+    pub fn window(&self) -> Option<xproto::Window> {
+        match self {
+            Event::Unknown(_) => None,
+            Event::Error(_) => None,
+            Event::ButtonPress(value) => Some(value.event),
+            Event::ButtonRelease(value) => Some(value.event),
+            Event::CirculateNotify(value) => Some(value.window),
+            Event::CirculateRequest(value) => Some(value.window),
+            Event::ClientMessage(value) => Some(value.window),
+            Event::ColormapNotify(value) => Some(value.window),
+            Event::ConfigureNotify(value) => Some(value.window),
+            Event::ConfigureRequest(value) => Some(value.window),
+            Event::CreateNotify(value) => Some(value.window),
+            Event::DestroyNotify(value) => Some(value.window),
+            Event::EnterNotify(value) => Some(value.event),
+            Event::Expose(value) => Some(value.window),
+            Event::FocusIn(value) => Some(value.event),
+            Event::FocusOut(value) => Some(value.event),
+            Event::GeGeneric(_) => None,
+            Event::GraphicsExposure(_) => None,
+            Event::GravityNotify(value) => Some(value.window),
+            Event::KeyPress(value) => Some(value.event),
+            Event::KeyRelease(value) => Some(value.event),
+            Event::KeymapNotify(_) => None,
+            Event::LeaveNotify(value) => Some(value.event),
+            Event::MapNotify(value) => Some(value.window),
+            Event::MapRequest(value) => Some(value.window),
+            Event::MappingNotify(_) => None,
+            Event::MotionNotify(value) => Some(value.event),
+            Event::NoExposure(_) => None,
+            Event::PropertyNotify(value) => Some(value.window),
+            Event::ReparentNotify(value) => Some(value.window),
+            Event::ResizeRequest(value) => Some(value.window),
+            Event::SelectionClear(value) => Some(value.owner),
+            Event::SelectionNotify(value) => Some(value.requestor),
+            Event::SelectionRequest(value) => Some(value.owner),
+            Event::UnmapNotify(value) => Some(value.window),
+            Event::VisibilityNotify(value) => Some(value.window),
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => None,
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => None,
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => None,
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => None,
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => Some(value.window),
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => None,
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => Some(value.root),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => Some(value.window),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => Some(value.affected_window),
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => None,
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => None,
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => None,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => Some(value.event),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => Some(value.window),
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => None,
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => None,
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => None,
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => None,
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => None,
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => None,
+        }
+    }
+
+    /// Get the server time contained in this X11 event
+    ///
+    /// This is the value of a field called `time`, a field called `timestamp`, or
+    /// otherwise the first field of type `TIMESTAMP`. Events without a timestamp,
+    /// errors, and unknown events return `None`.
+    pub fn server_time(&self) -> Option<xproto::Timestamp> {
+        match self {
+            Event::Unknown(_) => None,
+            Event::Error(_) => None,
+            Event::ButtonPress(value) => Some(value.time),
+            Event::ButtonRelease(value) => Some(value.time),
+            Event::CirculateNotify(_) => None,
+            Event::CirculateRequest(_) => None,
+            Event::ClientMessage(_) => None,
+            Event::ColormapNotify(_) => None,
+            Event::ConfigureNotify(_) => None,
+            Event::ConfigureRequest(_) => None,
+            Event::CreateNotify(_) => None,
+            Event::DestroyNotify(_) => None,
+            Event::EnterNotify(value) => Some(value.time),
+            Event::Expose(_) => None,
+            Event::FocusIn(_) => None,
+            Event::FocusOut(_) => None,
+            Event::GeGeneric(_) => None,
+            Event::GraphicsExposure(_) => None,
+            Event::GravityNotify(_) => None,
+            Event::KeyPress(value) => Some(value.time),
+            Event::KeyRelease(value) => Some(value.time),
+            Event::KeymapNotify(_) => None,
+            Event::LeaveNotify(value) => Some(value.time),
+            Event::MapNotify(_) => None,
+            Event::MapRequest(_) => None,
+            Event::MappingNotify(_) => None,
+            Event::MotionNotify(value) => Some(value.time),
+            Event::NoExposure(_) => None,
+            Event::PropertyNotify(value) => Some(value.time),
+            Event::ReparentNotify(_) => None,
+            Event::ResizeRequest(_) => None,
+            Event::SelectionClear(value) => Some(value.time),
+            Event::SelectionNotify(value) => Some(value.time),
+            Event::SelectionRequest(value) => Some(value.time),
+            Event::UnmapNotify(_) => None,
+            Event::VisibilityNotify(_) => None,
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => None,
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => None,
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => None,
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => None,
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => None,
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => None,
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => Some(value.time),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => Some(value.server_time),
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => None,
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => Some(value.timestamp),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => None,
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => Some(value.time),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(value) => Some(value.time),
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(value) => Some(value.time),
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => None,
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => None,
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(value) => Some(value.time),
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(value) => Some(value.time),
+        }
+    }
 }
 
 /// Get the response type out of the raw bytes of an X11 error or event.
@@ -4,6 +4,8 @@
 //! Bindings to the `RandR` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -209,11 +211,13 @@ impl RefreshRates {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -245,12 +249,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -267,9 +320,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -281,6 +337,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -288,6 +345,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -303,7 +361,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -370,9 +429,21 @@ impl std::fmt::Debug for SetConfig  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SetConfig {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetScreenConfig request
 pub const SET_SCREEN_CONFIG_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetScreenConfigRequest {
     pub window: xproto::Window,
@@ -382,6 +453,7 @@ pub struct SetScreenConfigRequest {
     pub rotation: u16,
     pub rate: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 impl SetScreenConfigRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -429,12 +501,77 @@ impl SetScreenConfigRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        let size_id_bytes = self.size_id.serialize();
+        let rotation_bytes = self.rotation.serialize();
+        let rate_bytes = self.rate.serialize();
+        write(&[
+            major_opcode,
+            SET_SCREEN_CONFIG_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            size_id_bytes[0],
+            size_id_bytes[1],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            rate_bytes[0],
+            rate_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetScreenConfigReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -460,9 +597,12 @@ impl SetScreenConfigRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 impl Request for SetScreenConfigRequest {
     type Reply = SetScreenConfigReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
+#[inline]
 pub fn set_screen_config<Conn, A>(conn: &Conn, window: xproto::Window, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, size_id: u16, rotation: A, rate: u16) -> Result<Cookie<'_, Conn, SetScreenConfigReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -480,6 +620,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetScreenConfigReply {
     pub status: SetConfig,
@@ -490,6 +631,7 @@ pub struct SetScreenConfigReply {
     pub root: xproto::Window,
     pub subpixel_order: render::SubPixel,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
 impl TryParse for SetScreenConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -506,10 +648,15 @@ impl TryParse for SetScreenConfigReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let subpixel_order = subpixel_order.into();
+        #[cfg(feature = "strict_parsing")]
+        render::SubPixel::check_known(subpixel_order)?;
         let result = SetScreenConfigReply { status, sequence, length, new_timestamp, config_timestamp, root, subpixel_order };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -588,11 +735,13 @@ bitmask_binop!(NotifyMask, u8);
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectInputRequest {
     pub window: xproto::Window,
     pub enable: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
 impl SelectInputRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -624,12 +773,61 @@ impl SelectInputRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let enable_bytes = self.enable.serialize();
+        write(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            enable_bytes[0],
+            enable_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -647,9 +845,12 @@ impl SelectInputRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
 impl Request for SelectInputRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
+#[inline]
 pub fn select_input<Conn, A>(conn: &Conn, window: xproto::Window, enable: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -665,10 +866,12 @@ where
 
 /// Opcode for the GetScreenInfo request
 pub const GET_SCREEN_INFO_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenInfoRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 impl GetScreenInfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -695,12 +898,56 @@ impl GetScreenInfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_SCREEN_INFO_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -715,9 +962,12 @@ impl GetScreenInfoRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 impl Request for GetScreenInfoRequest {
     type Reply = GetScreenInfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
+#[inline]
 pub fn get_screen_info<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -728,7 +978,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetScreenInfoReply {
     pub rotations: u8,
     pub sequence: u16,
@@ -743,6 +994,26 @@ pub struct GetScreenInfoReply {
     pub sizes: Vec<ScreenSize>,
     pub rates: Vec<RefreshRates>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
+impl std::fmt::Debug for GetScreenInfoReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetScreenInfoReply")
+            .field("rotations", &Rotation::from(self.rotations))
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("root", &self.root)
+            .field("timestamp", &self.timestamp)
+            .field("config_timestamp", &self.config_timestamp)
+            .field("size_id", &self.size_id)
+            .field("rotation", &self.rotation)
+            .field("rate", &self.rate)
+            .field("n_info", &self.n_info)
+            .field("sizes", &self.sizes)
+            .field("rates", &self.rates)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 impl TryParse for GetScreenInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -766,11 +1037,13 @@ impl TryParse for GetScreenInfoReply {
         }
         let result = GetScreenInfoReply { rotations, sequence, length, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
 impl GetScreenInfoReply {
     /// Get the value of the `nSizes` field.
     ///
@@ -789,10 +1062,12 @@ impl GetScreenInfoReply {
 
 /// Opcode for the GetScreenSizeRange request
 pub const GET_SCREEN_SIZE_RANGE_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenSizeRangeRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 impl GetScreenSizeRangeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -819,12 +1094,56 @@ impl GetScreenSizeRangeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_SCREEN_SIZE_RANGE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenSizeRangeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -839,9 +1158,12 @@ impl GetScreenSizeRangeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 impl Request for GetScreenSizeRangeRequest {
     type Reply = GetScreenSizeRangeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
+#[inline]
 pub fn get_screen_size_range<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenSizeRangeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -852,6 +1174,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenSizeRangeReply {
     pub sequence: u16,
@@ -861,6 +1184,7 @@ pub struct GetScreenSizeRangeReply {
     pub max_width: u16,
     pub max_height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
 impl TryParse for GetScreenSizeRangeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -878,7 +1202,8 @@ impl TryParse for GetScreenSizeRangeReply {
         }
         let result = GetScreenSizeRangeReply { sequence, length, min_width, min_height, max_width, max_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -886,6 +1211,7 @@ impl TryParse for GetScreenSizeRangeReply {
 
 /// Opcode for the SetScreenSize request
 pub const SET_SCREEN_SIZE_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetScreenSizeRequest {
     pub window: xproto::Window,
@@ -894,6 +1220,7 @@ pub struct SetScreenSizeRequest {
     pub mm_width: u32,
     pub mm_height: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
 impl SetScreenSizeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -936,12 +1263,72 @@ impl SetScreenSizeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let mm_width_bytes = self.mm_width.serialize();
+        let mm_height_bytes = self.mm_height.serialize();
+        write(&[
+            major_opcode,
+            SET_SCREEN_SIZE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            mm_width_bytes[0],
+            mm_width_bytes[1],
+            mm_width_bytes[2],
+            mm_width_bytes[3],
+            mm_height_bytes[0],
+            mm_height_bytes[1],
+            mm_height_bytes[2],
+            mm_height_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -964,9 +1351,12 @@ impl SetScreenSizeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
 impl Request for SetScreenSizeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
+#[inline]
 pub fn set_screen_size<Conn>(conn: &Conn, window: xproto::Window, width: u16, height: u16, mm_width: u32, mm_height: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1164,10 +1554,12 @@ impl Serialize for ModeInfo {
 
 /// Opcode for the GetScreenResources request
 pub const GET_SCREEN_RESOURCES_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenResourcesRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 impl GetScreenResourcesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1194,12 +1586,56 @@ impl GetScreenResourcesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_SCREEN_RESOURCES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenResourcesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1214,9 +1650,12 @@ impl GetScreenResourcesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 impl Request for GetScreenResourcesRequest {
     type Reply = GetScreenResourcesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
+#[inline]
 pub fn get_screen_resources<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenResourcesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1227,6 +1666,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetScreenResourcesReply {
     pub sequence: u16,
@@ -1238,6 +1678,7 @@ pub struct GetScreenResourcesReply {
     pub modes: Vec<ModeInfo>,
     pub names: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 impl TryParse for GetScreenResourcesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1262,11 +1703,13 @@ impl TryParse for GetScreenResourcesReply {
         }
         let result = GetScreenResourcesReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
 impl GetScreenResourcesReply {
     /// Get the value of the `num_crtcs` field.
     ///
@@ -1381,14 +1824,27 @@ impl std::fmt::Debug for Connection  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Connection {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the GetOutputInfo request
 pub const GET_OUTPUT_INFO_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOutputInfoRequest {
     pub output: Output,
     pub config_timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 impl GetOutputInfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1420,12 +1876,61 @@ impl GetOutputInfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        write(&[
+            major_opcode,
+            GET_OUTPUT_INFO_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetOutputInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1442,9 +1947,12 @@ impl GetOutputInfoRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 impl Request for GetOutputInfoRequest {
     type Reply = GetOutputInfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
+#[inline]
 pub fn get_output_info<Conn>(conn: &Conn, output: Output, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetOutputInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1456,6 +1964,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetOutputInfoReply {
     pub status: SetConfig,
@@ -1473,6 +1982,7 @@ pub struct GetOutputInfoReply {
     pub clones: Vec<Output>,
     pub name: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 impl TryParse for GetOutputInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1500,15 +2010,23 @@ impl TryParse for GetOutputInfoReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let connection = connection.into();
+        #[cfg(feature = "strict_parsing")]
+        Connection::check_known(connection)?;
         let subpixel_order = subpixel_order.into();
+        #[cfg(feature = "strict_parsing")]
+        render::SubPixel::check_known(subpixel_order)?;
         let result = GetOutputInfoReply { status, sequence, length, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
 impl GetOutputInfoReply {
     /// Get the value of the `num_crtcs` field.
     ///
@@ -1566,10 +2084,12 @@ impl GetOutputInfoReply {
 
 /// Opcode for the ListOutputProperties request
 pub const LIST_OUTPUT_PROPERTIES_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListOutputPropertiesRequest {
     pub output: Output,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 impl ListOutputPropertiesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1596,12 +2116,56 @@ impl ListOutputPropertiesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        write(&[
+            major_opcode,
+            LIST_OUTPUT_PROPERTIES_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListOutputPropertiesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1616,9 +2180,12 @@ impl ListOutputPropertiesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 impl Request for ListOutputPropertiesRequest {
     type Reply = ListOutputPropertiesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
+#[inline]
 pub fn list_output_properties<Conn>(conn: &Conn, output: Output) -> Result<Cookie<'_, Conn, ListOutputPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1629,12 +2196,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListOutputPropertiesReply {
     pub sequence: u16,
     pub length: u32,
     pub atoms: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 impl TryParse for ListOutputPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1650,11 +2219,13 @@ impl TryParse for ListOutputPropertiesReply {
         }
         let result = ListOutputPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
 impl ListOutputPropertiesReply {
     /// Get the value of the `num_atoms` field.
     ///
@@ -1673,11 +2244,13 @@ impl ListOutputPropertiesReply {
 
 /// Opcode for the QueryOutputProperty request
 pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryOutputPropertyRequest {
     pub output: Output,
     pub property: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 impl QueryOutputPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1709,13 +2282,62 @@ impl QueryOutputPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryOutputPropertyReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            major_opcode,
+            QUERY_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryOutputPropertyReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
@@ -1731,9 +2353,12 @@ impl QueryOutputPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 impl Request for QueryOutputPropertyRequest {
     type Reply = QueryOutputPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
+#[inline]
 pub fn query_output_property<Conn>(conn: &Conn, output: Output, property: xproto::Atom) -> Result<Cookie<'_, Conn, QueryOutputPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1745,6 +2370,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryOutputPropertyReply {
     pub sequence: u16,
@@ -1753,6 +2379,7 @@ pub struct QueryOutputPropertyReply {
     pub immutable: bool,
     pub valid_values: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 impl TryParse for QueryOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1770,11 +2397,13 @@ impl TryParse for QueryOutputPropertyReply {
         }
         let result = QueryOutputPropertyReply { sequence, pending, range, immutable, valid_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
 impl QueryOutputPropertyReply {
     /// Get the value of the `length` field.
     ///
@@ -1793,6 +2422,7 @@ impl QueryOutputPropertyReply {
 
 /// Opcode for the ConfigureOutputProperty request
 pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConfigureOutputPropertyRequest<'input> {
     pub output: Output,
@@ -1801,6 +2431,7 @@ pub struct ConfigureOutputPropertyRequest<'input> {
     pub range: bool,
     pub values: Cow<'input, [i32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
 impl<'input> ConfigureOutputPropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1842,12 +2473,74 @@ impl<'input> ConfigureOutputPropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), values_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let pending_bytes = self.pending.serialize();
+        let range_bytes = self.range.serialize();
+        write(&[
+            major_opcode,
+            CONFIGURE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let values_bytes = self.values.serialize();
+        let length_so_far = length_so_far + values_bytes.len();
+        write(&values_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.values.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1888,9 +2581,12 @@ impl<'input> ConfigureOutputPropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
 impl<'input> Request for ConfigureOutputPropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
+#[inline]
 pub fn configure_output_property<'c, 'input, Conn>(conn: &'c Conn, output: Output, property: xproto::Atom, pending: bool, range: bool, values: &'input [i32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1907,6 +2603,7 @@ where
 
 /// Opcode for the ChangeOutputProperty request
 pub const CHANGE_OUTPUT_PROPERTY_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeOutputPropertyRequest<'input> {
     pub output: Output,
@@ -1917,6 +2614,7 @@ pub struct ChangeOutputPropertyRequest<'input> {
     pub num_units: u32,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
 impl<'input> ChangeOutputPropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1968,12 +2666,84 @@ impl<'input> ChangeOutputPropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let num_units_bytes = self.num_units.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_units_bytes[0],
+            num_units_bytes[1],
+            num_units_bytes[2],
+            num_units_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(self.data.len(), usize::try_from(self.num_units.checked_mul(u32::from(self.format)).unwrap().checked_div(8u32).unwrap()).unwrap(), "`data` has an incorrect length");
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1987,6 +2757,8 @@ impl<'input> ChangeOutputPropertyRequest<'input> {
         let (format, remaining) = u8::try_parse(remaining)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::PropMode::check_known(mode)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (num_units, remaining) = u32::try_parse(remaining)?;
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_units.checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
@@ -2014,9 +2786,12 @@ impl<'input> ChangeOutputPropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
 impl<'input> Request for ChangeOutputPropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
+#[inline]
 pub fn change_output_property<'c, 'input, Conn>(conn: &'c Conn, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: xproto::PropMode, num_units: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2035,11 +2810,13 @@ where
 
 /// Opcode for the DeleteOutputProperty request
 pub const DELETE_OUTPUT_PROPERTY_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteOutputPropertyRequest {
     pub output: Output,
     pub property: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
 impl DeleteOutputPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2071,12 +2848,61 @@ impl DeleteOutputPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            major_opcode,
+            DELETE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2093,9 +2919,12 @@ impl DeleteOutputPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
 impl Request for DeleteOutputPropertyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
+#[inline]
 pub fn delete_output_property<Conn>(conn: &Conn, output: Output, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2109,6 +2938,7 @@ where
 
 /// Opcode for the GetOutputProperty request
 pub const GET_OUTPUT_PROPERTY_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOutputPropertyRequest {
     pub output: Output,
@@ -2119,6 +2949,7 @@ pub struct GetOutputPropertyRequest {
     pub delete: bool,
     pub pending: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 impl GetOutputPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2171,12 +3002,82 @@ impl GetOutputPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        let delete_bytes = self.delete.serialize();
+        let pending_bytes = self.pending.serialize();
+        write(&[
+            major_opcode,
+            GET_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetOutputPropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2204,9 +3105,12 @@ impl GetOutputPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 impl Request for GetOutputPropertyRequest {
     type Reply = GetOutputPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
+#[inline]
 pub fn get_output_property<Conn, A>(conn: &Conn, output: Output, property: xproto::Atom, type_: A, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Conn, GetOutputPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2225,6 +3129,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetOutputPropertyReply {
     pub format: u8,
@@ -2235,6 +3140,7 @@ pub struct GetOutputPropertyReply {
     pub num_items: u32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
 impl TryParse for GetOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2253,7 +3159,8 @@ impl TryParse for GetOutputPropertyReply {
         }
         let result = GetOutputPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2261,12 +3168,14 @@ impl TryParse for GetOutputPropertyReply {
 
 /// Opcode for the CreateMode request
 pub const CREATE_MODE_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateModeRequest<'input> {
     pub window: xproto::Window,
     pub mode_info: ModeInfo,
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 impl<'input> CreateModeRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2329,12 +3238,95 @@ impl<'input> CreateModeRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.name, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let mode_info_bytes = self.mode_info.serialize();
+        write(&[
+            major_opcode,
+            CREATE_MODE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            mode_info_bytes[0],
+            mode_info_bytes[1],
+            mode_info_bytes[2],
+            mode_info_bytes[3],
+            mode_info_bytes[4],
+            mode_info_bytes[5],
+            mode_info_bytes[6],
+            mode_info_bytes[7],
+            mode_info_bytes[8],
+            mode_info_bytes[9],
+            mode_info_bytes[10],
+            mode_info_bytes[11],
+            mode_info_bytes[12],
+            mode_info_bytes[13],
+            mode_info_bytes[14],
+            mode_info_bytes[15],
+            mode_info_bytes[16],
+            mode_info_bytes[17],
+            mode_info_bytes[18],
+            mode_info_bytes[19],
+            mode_info_bytes[20],
+            mode_info_bytes[21],
+            mode_info_bytes[22],
+            mode_info_bytes[23],
+            mode_info_bytes[24],
+            mode_info_bytes[25],
+            mode_info_bytes[26],
+            mode_info_bytes[27],
+            mode_info_bytes[28],
+            mode_info_bytes[29],
+            mode_info_bytes[30],
+            mode_info_bytes[31],
+        ]);
+        let length_so_far = length_so_far + 40;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 40 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, CreateModeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2361,9 +3353,12 @@ impl<'input> CreateModeRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 impl<'input> Request for CreateModeRequest<'input> {
     type Reply = CreateModeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
+#[inline]
 pub fn create_mode<'c, 'input, Conn>(conn: &'c Conn, window: xproto::Window, mode_info: ModeInfo, name: &'input [u8]) -> Result<Cookie<'c, Conn, CreateModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2376,12 +3371,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateModeReply {
     pub sequence: u16,
     pub length: u32,
     pub mode: Mode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
 impl TryParse for CreateModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2396,7 +3393,8 @@ impl TryParse for CreateModeReply {
         }
         let result = CreateModeReply { sequence, length, mode };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2404,10 +3402,12 @@ impl TryParse for CreateModeReply {
 
 /// Opcode for the DestroyMode request
 pub const DESTROY_MODE_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyModeRequest {
     pub mode: Mode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
 impl DestroyModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2434,12 +3434,56 @@ impl DestroyModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = self.mode.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_MODE_REQUEST,
+            0,
+            0,
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2454,9 +3498,12 @@ impl DestroyModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
 impl Request for DestroyModeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
+#[inline]
 pub fn destroy_mode<Conn>(conn: &Conn, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2469,11 +3516,13 @@ where
 
 /// Opcode for the AddOutputMode request
 pub const ADD_OUTPUT_MODE_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AddOutputModeRequest {
     pub output: Output,
     pub mode: Mode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
 impl AddOutputModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2505,12 +3554,61 @@ impl AddOutputModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let mode_bytes = self.mode.serialize();
+        write(&[
+            major_opcode,
+            ADD_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2527,9 +3625,12 @@ impl AddOutputModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
 impl Request for AddOutputModeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
+#[inline]
 pub fn add_output_mode<Conn>(conn: &Conn, output: Output, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2543,11 +3644,13 @@ where
 
 /// Opcode for the DeleteOutputMode request
 pub const DELETE_OUTPUT_MODE_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteOutputModeRequest {
     pub output: Output,
     pub mode: Mode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
 impl DeleteOutputModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2579,12 +3682,61 @@ impl DeleteOutputModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize();
+        let mode_bytes = self.mode.serialize();
+        write(&[
+            major_opcode,
+            DELETE_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2601,9 +3753,12 @@ impl DeleteOutputModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
 impl Request for DeleteOutputModeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
+#[inline]
 pub fn delete_output_mode<Conn>(conn: &Conn, output: Output, mode: Mode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2617,11 +3772,13 @@ where
 
 /// Opcode for the GetCrtcInfo request
 pub const GET_CRTC_INFO_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcInfoRequest {
     pub crtc: Crtc,
     pub config_timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 impl GetCrtcInfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2653,12 +3810,61 @@ impl GetCrtcInfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        write(&[
+            major_opcode,
+            GET_CRTC_INFO_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCrtcInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2675,9 +3881,12 @@ impl GetCrtcInfoRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 impl Request for GetCrtcInfoRequest {
     type Reply = GetCrtcInfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
+#[inline]
 pub fn get_crtc_info<Conn>(conn: &Conn, crtc: Crtc, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetCrtcInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2689,6 +3898,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcInfoReply {
     pub status: SetConfig,
@@ -2705,6 +3915,7 @@ pub struct GetCrtcInfoReply {
     pub outputs: Vec<Output>,
     pub possible: Vec<Output>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 impl TryParse for GetCrtcInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2728,13 +3939,17 @@ impl TryParse for GetCrtcInfoReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let result = GetCrtcInfoReply { status, sequence, length, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
 impl GetCrtcInfoReply {
     /// Get the value of the `num_outputs` field.
     ///
@@ -2766,6 +3981,7 @@ impl GetCrtcInfoReply {
 
 /// Opcode for the SetCrtcConfig request
 pub const SET_CRTC_CONFIG_REQUEST: u8 = 21;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetCrtcConfigRequest<'input> {
     pub crtc: Crtc,
@@ -2777,6 +3993,7 @@ pub struct SetCrtcConfigRequest<'input> {
     pub rotation: u16,
     pub outputs: Cow<'input, [Output]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 impl<'input> SetCrtcConfigRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2833,12 +4050,89 @@ impl<'input> SetCrtcConfigRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), outputs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let mode_bytes = self.mode.serialize();
+        let rotation_bytes = self.rotation.serialize();
+        write(&[
+            major_opcode,
+            SET_CRTC_CONFIG_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 28;
+        let outputs_bytes = self.outputs.serialize();
+        let length_so_far = length_so_far + outputs_bytes.len();
+        write(&outputs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.outputs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetCrtcConfigReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2888,9 +4182,12 @@ impl<'input> SetCrtcConfigRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 impl<'input> Request for SetCrtcConfigRequest<'input> {
     type Reply = SetCrtcConfigReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
+#[inline]
 pub fn set_crtc_config<'c, 'input, Conn, A>(conn: &'c Conn, crtc: Crtc, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, x: i16, y: i16, mode: Mode, rotation: A, outputs: &'input [Output]) -> Result<Cookie<'c, Conn, SetCrtcConfigReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2910,6 +4207,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetCrtcConfigReply {
     pub status: SetConfig,
@@ -2917,6 +4215,7 @@ pub struct SetCrtcConfigReply {
     pub length: u32,
     pub timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
 impl TryParse for SetCrtcConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2930,9 +4229,12 @@ impl TryParse for SetCrtcConfigReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let result = SetCrtcConfigReply { status, sequence, length, timestamp };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2940,10 +4242,12 @@ impl TryParse for SetCrtcConfigReply {
 
 /// Opcode for the GetCrtcGammaSize request
 pub const GET_CRTC_GAMMA_SIZE_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcGammaSizeRequest {
     pub crtc: Crtc,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 impl GetCrtcGammaSizeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2970,12 +4274,56 @@ impl GetCrtcGammaSizeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        write(&[
+            major_opcode,
+            GET_CRTC_GAMMA_SIZE_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCrtcGammaSizeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2990,9 +4338,12 @@ impl GetCrtcGammaSizeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 impl Request for GetCrtcGammaSizeRequest {
     type Reply = GetCrtcGammaSizeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
+#[inline]
 pub fn get_crtc_gamma_size<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcGammaSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3003,12 +4354,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcGammaSizeReply {
     pub sequence: u16,
     pub length: u32,
     pub size: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
 impl TryParse for GetCrtcGammaSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3023,7 +4376,8 @@ impl TryParse for GetCrtcGammaSizeReply {
         }
         let result = GetCrtcGammaSizeReply { sequence, length, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -3031,10 +4385,12 @@ impl TryParse for GetCrtcGammaSizeReply {
 
 /// Opcode for the GetCrtcGamma request
 pub const GET_CRTC_GAMMA_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcGammaRequest {
     pub crtc: Crtc,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 impl GetCrtcGammaRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3061,12 +4417,56 @@ impl GetCrtcGammaRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        write(&[
+            major_opcode,
+            GET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCrtcGammaReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3081,9 +4481,12 @@ impl GetCrtcGammaRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 impl Request for GetCrtcGammaRequest {
     type Reply = GetCrtcGammaReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
+#[inline]
 pub fn get_crtc_gamma<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcGammaReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3094,6 +4497,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcGammaReply {
     pub sequence: u16,
@@ -3102,6 +4506,7 @@ pub struct GetCrtcGammaReply {
     pub green: Vec<u16>,
     pub blue: Vec<u16>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 impl TryParse for GetCrtcGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3119,11 +4524,13 @@ impl TryParse for GetCrtcGammaReply {
         }
         let result = GetCrtcGammaReply { sequence, length, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
 impl GetCrtcGammaReply {
     /// Get the value of the `size` field.
     ///
@@ -3142,6 +4549,7 @@ impl GetCrtcGammaReply {
 
 /// Opcode for the SetCrtcGamma request
 pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetCrtcGammaRequest<'input> {
     pub crtc: Crtc,
@@ -3149,6 +4557,7 @@ pub struct SetCrtcGammaRequest<'input> {
     pub green: Cow<'input, [u16]>,
     pub blue: Cow<'input, [u16]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
 impl<'input> SetCrtcGammaRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3191,12 +4600,77 @@ impl<'input> SetCrtcGammaRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), red_bytes.into(), green_bytes.into(), blue_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        let size = u16::try_from(self.red.len()).expect("`red` has too many elements");
+        let size_bytes = size.serialize();
+        write(&[
+            major_opcode,
+            SET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let red_bytes = self.red.serialize();
+        let length_so_far = length_so_far + red_bytes.len();
+        write(&red_bytes[..]);
+        assert_eq!(self.green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
+        let green_bytes = self.green.serialize();
+        let length_so_far = length_so_far + green_bytes.len();
+        write(&green_bytes[..]);
+        assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
+        let blue_bytes = self.blue.serialize();
+        let length_so_far = length_so_far + blue_bytes.len();
+        write(&blue_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.red.len() * 2 + self.green.len() * 2 + self.blue.len() * 2;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3228,9 +4702,12 @@ impl<'input> SetCrtcGammaRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
 impl<'input> Request for SetCrtcGammaRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
+#[inline]
 pub fn set_crtc_gamma<'c, 'input, Conn>(conn: &'c Conn, crtc: Crtc, red: &'input [u16], green: &'input [u16], blue: &'input [u16]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3246,10 +4723,12 @@ where
 
 /// Opcode for the GetScreenResourcesCurrent request
 pub const GET_SCREEN_RESOURCES_CURRENT_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenResourcesCurrentRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 impl GetScreenResourcesCurrentRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3269,19 +4748,63 @@ impl GetScreenResourcesCurrentRequest {
             window_bytes[1],
             window_bytes[2],
             window_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_SCREEN_RESOURCES_CURRENT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenResourcesCurrentReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3296,9 +4819,12 @@ impl GetScreenResourcesCurrentRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 impl Request for GetScreenResourcesCurrentRequest {
     type Reply = GetScreenResourcesCurrentReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
+#[inline]
 pub fn get_screen_resources_current<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetScreenResourcesCurrentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3309,6 +4835,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetScreenResourcesCurrentReply {
     pub sequence: u16,
@@ -3320,6 +4847,7 @@ pub struct GetScreenResourcesCurrentReply {
     pub modes: Vec<ModeInfo>,
     pub names: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 impl TryParse for GetScreenResourcesCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3344,11 +4872,13 @@ impl TryParse for GetScreenResourcesCurrentReply {
         }
         let result = GetScreenResourcesCurrentReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
 impl GetScreenResourcesCurrentReply {
     /// Get the value of the `num_crtcs` field.
     ///
@@ -3469,6 +4999,7 @@ bitmask_binop!(Transform, u8);
 
 /// Opcode for the SetCrtcTransform request
 pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetCrtcTransformRequest<'input> {
     pub crtc: Crtc,
@@ -3476,6 +5007,7 @@ pub struct SetCrtcTransformRequest<'input> {
     pub filter_name: Cow<'input, [u8]>,
     pub filter_params: Cow<'input, [render::Fixed]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
 impl<'input> SetCrtcTransformRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3552,12 +5084,112 @@ impl<'input> SetCrtcTransformRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.filter_name, padding0.into(), filter_params_bytes.into(), padding1.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        let transform_bytes = self.transform.serialize();
+        let filter_len = u16::try_from(self.filter_name.len()).expect("`filter_name` has too many elements");
+        let filter_len_bytes = filter_len.serialize();
+        write(&[
+            major_opcode,
+            SET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            transform_bytes[0],
+            transform_bytes[1],
+            transform_bytes[2],
+            transform_bytes[3],
+            transform_bytes[4],
+            transform_bytes[5],
+            transform_bytes[6],
+            transform_bytes[7],
+            transform_bytes[8],
+            transform_bytes[9],
+            transform_bytes[10],
+            transform_bytes[11],
+            transform_bytes[12],
+            transform_bytes[13],
+            transform_bytes[14],
+            transform_bytes[15],
+            transform_bytes[16],
+            transform_bytes[17],
+            transform_bytes[18],
+            transform_bytes[19],
+            transform_bytes[20],
+            transform_bytes[21],
+            transform_bytes[22],
+            transform_bytes[23],
+            transform_bytes[24],
+            transform_bytes[25],
+            transform_bytes[26],
+            transform_bytes[27],
+            transform_bytes[28],
+            transform_bytes[29],
+            transform_bytes[30],
+            transform_bytes[31],
+            transform_bytes[32],
+            transform_bytes[33],
+            transform_bytes[34],
+            transform_bytes[35],
+            filter_len_bytes[0],
+            filter_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 48;
+        let length_so_far = length_so_far + self.filter_name.len();
+        write(&self.filter_name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(&padding0[..]);
+        let filter_params_bytes = self.filter_params.serialize();
+        let length_so_far = length_so_far + filter_params_bytes.len();
+        write(&filter_params_bytes[..]);
+        let padding1 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding1.len();
+        write(padding1);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 48 + self.filter_name.len();
+        let length_so_far = length_so_far + (4 - (length_so_far % 4)) % 4 + self.filter_params.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3570,10 +5202,7 @@ impl<'input> SetCrtcTransformRequest<'input> {
         let (filter_len, remaining) = u16::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?)?;
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut filter_params = Vec::new();
@@ -3600,9 +5229,12 @@ impl<'input> SetCrtcTransformRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
 impl<'input> Request for SetCrtcTransformRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
+#[inline]
 pub fn set_crtc_transform<'c, 'input, Conn>(conn: &'c Conn, crtc: Crtc, transform: render::Transform, filter_name: &'input [u8], filter_params: &'input [render::Fixed]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3618,10 +5250,12 @@ where
 
 /// Opcode for the GetCrtcTransform request
 pub const GET_CRTC_TRANSFORM_REQUEST: u8 = 27;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCrtcTransformRequest {
     pub crtc: Crtc,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 impl GetCrtcTransformRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3648,12 +5282,56 @@ impl GetCrtcTransformRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        write(&[
+            major_opcode,
+            GET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCrtcTransformReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3668,9 +5346,12 @@ impl GetCrtcTransformRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 impl Request for GetCrtcTransformRequest {
     type Reply = GetCrtcTransformReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
+#[inline]
 pub fn get_crtc_transform<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetCrtcTransformReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3681,6 +5362,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetCrtcTransformReply {
     pub sequence: u16,
@@ -3693,6 +5375,7 @@ pub struct GetCrtcTransformReply {
     pub current_filter_name: Vec<u8>,
     pub current_params: Vec<render::Fixed>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 impl TryParse for GetCrtcTransformReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3712,28 +5395,24 @@ impl TryParse for GetCrtcTransformReply {
         let (current_nparams, remaining) = u16::try_parse(remaining)?;
         let (pending_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, pending_len.try_to_usize()?)?;
         let pending_filter_name = pending_filter_name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (pending_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, pending_nparams.try_to_usize()?)?;
         let (current_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, current_len.try_to_usize()?)?;
         let current_filter_name = current_filter_name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (current_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, current_nparams.try_to_usize()?)?;
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
         let result = GetCrtcTransformReply { sequence, length, pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
 impl GetCrtcTransformReply {
     /// Get the value of the `pending_len` field.
     ///
@@ -3791,10 +5470,12 @@ impl GetCrtcTransformReply {
 
 /// Opcode for the GetPanning request
 pub const GET_PANNING_REQUEST: u8 = 28;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPanningRequest {
     pub crtc: Crtc,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 impl GetPanningRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3821,12 +5502,56 @@ impl GetPanningRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        write(&[
+            major_opcode,
+            GET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPanningReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3841,9 +5566,12 @@ impl GetPanningRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 impl Request for GetPanningRequest {
     type Reply = GetPanningReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
+#[inline]
 pub fn get_panning<Conn>(conn: &Conn, crtc: Crtc) -> Result<Cookie<'_, Conn, GetPanningReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3854,6 +5582,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPanningReply {
     pub status: SetConfig,
@@ -3873,6 +5602,7 @@ pub struct GetPanningReply {
     pub border_right: i16,
     pub border_bottom: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
 impl TryParse for GetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3897,9 +5627,12 @@ impl TryParse for GetPanningReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let result = GetPanningReply { status, sequence, length, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -3907,6 +5640,7 @@ impl TryParse for GetPanningReply {
 
 /// Opcode for the SetPanning request
 pub const SET_PANNING_REQUEST: u8 = 29;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPanningRequest {
     pub crtc: Crtc,
@@ -3924,6 +5658,7 @@ pub struct SetPanningRequest {
     pub border_right: i16,
     pub border_bottom: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 impl SetPanningRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3991,12 +5726,97 @@ impl SetPanningRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let left_bytes = self.left.serialize();
+        let top_bytes = self.top.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let track_left_bytes = self.track_left.serialize();
+        let track_top_bytes = self.track_top.serialize();
+        let track_width_bytes = self.track_width.serialize();
+        let track_height_bytes = self.track_height.serialize();
+        let border_left_bytes = self.border_left.serialize();
+        let border_top_bytes = self.border_top.serialize();
+        let border_right_bytes = self.border_right.serialize();
+        let border_bottom_bytes = self.border_bottom.serialize();
+        write(&[
+            major_opcode,
+            SET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            left_bytes[0],
+            left_bytes[1],
+            top_bytes[0],
+            top_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            track_left_bytes[0],
+            track_left_bytes[1],
+            track_top_bytes[0],
+            track_top_bytes[1],
+            track_width_bytes[0],
+            track_width_bytes[1],
+            track_height_bytes[0],
+            track_height_bytes[1],
+            border_left_bytes[0],
+            border_left_bytes[1],
+            border_top_bytes[0],
+            border_top_bytes[1],
+            border_right_bytes[0],
+            border_right_bytes[1],
+            border_bottom_bytes[0],
+            border_bottom_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 36;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        36
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetPanningReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4037,9 +5857,12 @@ impl SetPanningRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 impl Request for SetPanningRequest {
     type Reply = SetPanningReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
+#[inline]
 pub fn set_panning<Conn>(conn: &Conn, crtc: Crtc, timestamp: xproto::Timestamp, left: u16, top: u16, width: u16, height: u16, track_left: u16, track_top: u16, track_width: u16, track_height: u16, border_left: i16, border_top: i16, border_right: i16, border_bottom: i16) -> Result<Cookie<'_, Conn, SetPanningReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4063,6 +5886,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPanningReply {
     pub status: SetConfig,
@@ -4070,6 +5894,7 @@ pub struct SetPanningReply {
     pub length: u32,
     pub timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
 impl TryParse for SetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4082,9 +5907,12 @@ impl TryParse for SetPanningReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        SetConfig::check_known(status)?;
         let result = SetPanningReply { status, sequence, length, timestamp };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -4092,11 +5920,13 @@ impl TryParse for SetPanningReply {
 
 /// Opcode for the SetOutputPrimary request
 pub const SET_OUTPUT_PRIMARY_REQUEST: u8 = 30;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetOutputPrimaryRequest {
     pub window: xproto::Window,
     pub output: Output,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
 impl SetOutputPrimaryRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4128,12 +5958,61 @@ impl SetOutputPrimaryRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let output_bytes = self.output.serialize();
+        write(&[
+            major_opcode,
+            SET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4150,9 +6029,12 @@ impl SetOutputPrimaryRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
 impl Request for SetOutputPrimaryRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
+#[inline]
 pub fn set_output_primary<Conn>(conn: &Conn, window: xproto::Window, output: Output) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4166,10 +6048,12 @@ where
 
 /// Opcode for the GetOutputPrimary request
 pub const GET_OUTPUT_PRIMARY_REQUEST: u8 = 31;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOutputPrimaryRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 impl GetOutputPrimaryRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4196,12 +6080,56 @@ impl GetOutputPrimaryRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetOutputPrimaryReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4216,9 +6144,12 @@ impl GetOutputPrimaryRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 impl Request for GetOutputPrimaryRequest {
     type Reply = GetOutputPrimaryReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
+#[inline]
 pub fn get_output_primary<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetOutputPrimaryReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4229,12 +6160,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetOutputPrimaryReply {
     pub sequence: u16,
     pub length: u32,
     pub output: Output,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
 impl TryParse for GetOutputPrimaryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4248,7 +6181,8 @@ impl TryParse for GetOutputPrimaryReply {
         }
         let result = GetOutputPrimaryReply { sequence, length, output };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -4256,22 +6190,45 @@ impl TryParse for GetOutputPrimaryReply {
 
 /// Opcode for the GetProviders request
 pub const GET_PROVIDERS_REQUEST: u8 = 32;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetProvidersRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 impl GetProvidersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_PROVIDERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let window_bytes = self.window.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             GET_PROVIDERS_REQUEST,
             0,
             0,
@@ -4279,19 +6236,42 @@ impl GetProvidersRequest {
             window_bytes[1],
             window_bytes[2],
             window_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 8;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetProvidersReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4306,9 +6286,12 @@ impl GetProvidersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 impl Request for GetProvidersRequest {
     type Reply = GetProvidersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
+#[inline]
 pub fn get_providers<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetProvidersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4319,6 +6302,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProvidersReply {
     pub sequence: u16,
@@ -4326,6 +6310,7 @@ pub struct GetProvidersReply {
     pub timestamp: xproto::Timestamp,
     pub providers: Vec<Provider>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 impl TryParse for GetProvidersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4342,11 +6327,13 @@ impl TryParse for GetProvidersReply {
         }
         let result = GetProvidersReply { sequence, length, timestamp, providers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
 impl GetProvidersReply {
     /// Get the value of the `num_providers` field.
     ///
@@ -4428,11 +6415,13 @@ bitmask_binop!(ProviderCapability, u8);
 
 /// Opcode for the GetProviderInfo request
 pub const GET_PROVIDER_INFO_REQUEST: u8 = 33;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetProviderInfoRequest {
     pub provider: Provider,
     pub config_timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 impl GetProviderInfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4464,12 +6453,61 @@ impl GetProviderInfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        write(&[
+            major_opcode,
+            GET_PROVIDER_INFO_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetProviderInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4486,9 +6524,12 @@ impl GetProviderInfoRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 impl Request for GetProviderInfoRequest {
     type Reply = GetProviderInfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
+#[inline]
 pub fn get_provider_info<Conn>(conn: &Conn, provider: Provider, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Conn, GetProviderInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4500,6 +6541,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProviderInfoReply {
     pub status: u8,
@@ -4513,6 +6555,7 @@ pub struct GetProviderInfoReply {
     pub associated_capability: Vec<u32>,
     pub name: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 impl TryParse for GetProviderInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4538,11 +6581,13 @@ impl TryParse for GetProviderInfoReply {
         }
         let result = GetProviderInfoReply { status, sequence, length, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
 impl GetProviderInfoReply {
     /// Get the value of the `num_crtcs` field.
     ///
@@ -4600,12 +6645,14 @@ impl GetProviderInfoReply {
 
 /// Opcode for the SetProviderOffloadSink request
 pub const SET_PROVIDER_OFFLOAD_SINK_REQUEST: u8 = 34;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetProviderOffloadSinkRequest {
     pub provider: Provider,
     pub sink_provider: Provider,
     pub config_timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
 impl SetProviderOffloadSinkRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4642,12 +6689,66 @@ impl SetProviderOffloadSinkRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let sink_provider_bytes = self.sink_provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        write(&[
+            major_opcode,
+            SET_PROVIDER_OFFLOAD_SINK_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            sink_provider_bytes[0],
+            sink_provider_bytes[1],
+            sink_provider_bytes[2],
+            sink_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4666,9 +6767,12 @@ impl SetProviderOffloadSinkRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
 impl Request for SetProviderOffloadSinkRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
+#[inline]
 pub fn set_provider_offload_sink<Conn>(conn: &Conn, provider: Provider, sink_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4683,12 +6787,14 @@ where
 
 /// Opcode for the SetProviderOutputSource request
 pub const SET_PROVIDER_OUTPUT_SOURCE_REQUEST: u8 = 35;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetProviderOutputSourceRequest {
     pub provider: Provider,
     pub source_provider: Provider,
     pub config_timestamp: xproto::Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
 impl SetProviderOutputSourceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4725,12 +6831,66 @@ impl SetProviderOutputSourceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let source_provider_bytes = self.source_provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        write(&[
+            major_opcode,
+            SET_PROVIDER_OUTPUT_SOURCE_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            source_provider_bytes[0],
+            source_provider_bytes[1],
+            source_provider_bytes[2],
+            source_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4749,9 +6909,12 @@ impl SetProviderOutputSourceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
 impl Request for SetProviderOutputSourceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
+#[inline]
 pub fn set_provider_output_source<Conn>(conn: &Conn, provider: Provider, source_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4766,10 +6929,12 @@ where
 
 /// Opcode for the ListProviderProperties request
 pub const LIST_PROVIDER_PROPERTIES_REQUEST: u8 = 36;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListProviderPropertiesRequest {
     pub provider: Provider,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 impl ListProviderPropertiesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4796,12 +6961,56 @@ impl ListProviderPropertiesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        write(&[
+            major_opcode,
+            LIST_PROVIDER_PROPERTIES_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListProviderPropertiesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4816,9 +7025,12 @@ impl ListProviderPropertiesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 impl Request for ListProviderPropertiesRequest {
     type Reply = ListProviderPropertiesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
+#[inline]
 pub fn list_provider_properties<Conn>(conn: &Conn, provider: Provider) -> Result<Cookie<'_, Conn, ListProviderPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4829,12 +7041,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListProviderPropertiesReply {
     pub sequence: u16,
     pub length: u32,
     pub atoms: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 impl TryParse for ListProviderPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4850,11 +7064,13 @@ impl TryParse for ListProviderPropertiesReply {
         }
         let result = ListProviderPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
 impl ListProviderPropertiesReply {
     /// Get the value of the `num_atoms` field.
     ///
@@ -4873,11 +7089,13 @@ impl ListProviderPropertiesReply {
 
 /// Opcode for the QueryProviderProperty request
 pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryProviderPropertyRequest {
     pub provider: Provider,
     pub property: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 impl QueryProviderPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4909,12 +7127,61 @@ impl QueryProviderPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            major_opcode,
+            QUERY_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryProviderPropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4931,9 +7198,12 @@ impl QueryProviderPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 impl Request for QueryProviderPropertyRequest {
     type Reply = QueryProviderPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
+#[inline]
 pub fn query_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom) -> Result<Cookie<'_, Conn, QueryProviderPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4945,6 +7215,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryProviderPropertyReply {
     pub sequence: u16,
@@ -4953,6 +7224,7 @@ pub struct QueryProviderPropertyReply {
     pub immutable: bool,
     pub valid_values: Vec<i32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 impl TryParse for QueryProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4970,11 +7242,13 @@ impl TryParse for QueryProviderPropertyReply {
         }
         let result = QueryProviderPropertyReply { sequence, pending, range, immutable, valid_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
 impl QueryProviderPropertyReply {
     /// Get the value of the `length` field.
     ///
@@ -4993,6 +7267,7 @@ impl QueryProviderPropertyReply {
 
 /// Opcode for the ConfigureProviderProperty request
 pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConfigureProviderPropertyRequest<'input> {
     pub provider: Provider,
@@ -5001,6 +7276,7 @@ pub struct ConfigureProviderPropertyRequest<'input> {
     pub range: bool,
     pub values: Cow<'input, [i32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
 impl<'input> ConfigureProviderPropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5042,12 +7318,74 @@ impl<'input> ConfigureProviderPropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), values_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let pending_bytes = self.pending.serialize();
+        let range_bytes = self.range.serialize();
+        write(&[
+            major_opcode,
+            CONFIGURE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let values_bytes = self.values.serialize();
+        let length_so_far = length_so_far + values_bytes.len();
+        write(&values_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.values.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5088,9 +7426,12 @@ impl<'input> ConfigureProviderPropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
 impl<'input> Request for ConfigureProviderPropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
+#[inline]
 pub fn configure_provider_property<'c, 'input, Conn>(conn: &'c Conn, provider: Provider, property: xproto::Atom, pending: bool, range: bool, values: &'input [i32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5107,6 +7448,7 @@ where
 
 /// Opcode for the ChangeProviderProperty request
 pub const CHANGE_PROVIDER_PROPERTY_REQUEST: u8 = 39;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeProviderPropertyRequest<'input> {
     pub provider: Provider,
@@ -5117,6 +7459,7 @@ pub struct ChangeProviderPropertyRequest<'input> {
     pub num_items: u32,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
 impl<'input> ChangeProviderPropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5168,12 +7511,84 @@ impl<'input> ChangeProviderPropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let mode_bytes = self.mode.serialize();
+        let num_items_bytes = self.num_items.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5213,9 +7628,12 @@ impl<'input> ChangeProviderPropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
 impl<'input> Request for ChangeProviderPropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
+#[inline]
 pub fn change_provider_property<'c, 'input, Conn>(conn: &'c Conn, provider: Provider, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: u8, num_items: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5234,11 +7652,13 @@ where
 
 /// Opcode for the DeleteProviderProperty request
 pub const DELETE_PROVIDER_PROPERTY_REQUEST: u8 = 40;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteProviderPropertyRequest {
     pub provider: Provider,
     pub property: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
 impl DeleteProviderPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5266,16 +7686,65 @@ impl DeleteProviderPropertyRequest {
         ];
         let length_so_far = length_so_far + request0.len();
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            major_opcode,
+            DELETE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
     }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5292,9 +7761,12 @@ impl DeleteProviderPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
 impl Request for DeleteProviderPropertyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
+#[inline]
 pub fn delete_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5308,6 +7780,7 @@ where
 
 /// Opcode for the GetProviderProperty request
 pub const GET_PROVIDER_PROPERTY_REQUEST: u8 = 41;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetProviderPropertyRequest {
     pub provider: Provider,
@@ -5318,6 +7791,7 @@ pub struct GetProviderPropertyRequest {
     pub delete: bool,
     pub pending: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 impl GetProviderPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5370,12 +7844,82 @@ impl GetProviderPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        let delete_bytes = self.delete.serialize();
+        let pending_bytes = self.pending.serialize();
+        write(&[
+            major_opcode,
+            GET_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetProviderPropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5403,9 +7947,12 @@ impl GetProviderPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 impl Request for GetProviderPropertyRequest {
     type Reply = GetProviderPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
+#[inline]
 pub fn get_provider_property<Conn>(conn: &Conn, provider: Provider, property: xproto::Atom, type_: xproto::Atom, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Conn, GetProviderPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5422,6 +7969,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetProviderPropertyReply {
     pub format: u8,
@@ -5432,6 +7980,7 @@ pub struct GetProviderPropertyReply {
     pub num_items: u32,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
 impl TryParse for GetProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5450,7 +7999,8 @@ impl TryParse for GetProviderPropertyReply {
         }
         let result = GetProviderPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -5458,7 +8008,7 @@ impl TryParse for GetProviderPropertyReply {
 
 /// Opcode for the ScreenChangeNotify event
 pub const SCREEN_CHANGE_NOTIFY_EVENT: u8 = 0;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ScreenChangeNotifyEvent {
     pub response_type: u8,
     pub rotation: u8,
@@ -5474,6 +8024,25 @@ pub struct ScreenChangeNotifyEvent {
     pub mwidth: u16,
     pub mheight: u16,
 }
+impl std::fmt::Debug for ScreenChangeNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ScreenChangeNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("rotation", &Rotation::from(self.rotation))
+            .field("sequence", &self.sequence)
+            .field("timestamp", &self.timestamp)
+            .field("config_timestamp", &self.config_timestamp)
+            .field("root", &self.root)
+            .field("request_window", &self.request_window)
+            .field("size_id", &self.size_id)
+            .field("subpixel_order", &self.subpixel_order)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("mwidth", &self.mwidth)
+            .field("mheight", &self.mheight)
+            .finish()
+    }
+}
 impl TryParse for ScreenChangeNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5491,6 +8060,8 @@ impl TryParse for ScreenChangeNotifyEvent {
         let (mwidth, remaining) = u16::try_parse(remaining)?;
         let (mheight, remaining) = u16::try_parse(remaining)?;
         let subpixel_order = subpixel_order.into();
+        #[cfg(feature = "strict_parsing")]
+        render::SubPixel::check_known(subpixel_order)?;
         let result = ScreenChangeNotifyEvent { response_type, rotation, sequence, timestamp, config_timestamp, root, request_window, size_id, subpixel_order, width, height, mwidth, mheight };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -5622,6 +8193,17 @@ impl std::fmt::Debug for Notify  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Notify {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=6) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CrtcChange {
@@ -5733,7 +8315,11 @@ impl TryParse for OutputChange {
         let (connection, remaining) = u8::try_parse(remaining)?;
         let (subpixel_order, remaining) = u8::try_parse(remaining)?;
         let connection = connection.into();
+        #[cfg(feature = "strict_parsing")]
+        Connection::check_known(connection)?;
         let subpixel_order = subpixel_order.into();
+        #[cfg(feature = "strict_parsing")]
+        render::SubPixel::check_known(subpixel_order)?;
         let result = OutputChange { timestamp, config_timestamp, window, output, crtc, mode, rotation, connection, subpixel_order };
         Ok((result, remaining))
     }
@@ -5812,6 +8398,8 @@ impl TryParse for OutputProperty {
         let (status, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::Property::check_known(status)?;
         let result = OutputProperty { window, output, atom, timestamp, status };
         Ok((result, remaining))
     }
@@ -6127,11 +8715,13 @@ impl MonitorInfo {
 
 /// Opcode for the GetMonitors request
 pub const GET_MONITORS_REQUEST: u8 = 42;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMonitorsRequest {
     pub window: xproto::Window,
     pub get_active: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 impl GetMonitorsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6163,12 +8753,61 @@ impl GetMonitorsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let get_active_bytes = self.get_active.serialize();
+        write(&[
+            major_opcode,
+            GET_MONITORS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            get_active_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMonitorsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6185,9 +8824,12 @@ impl GetMonitorsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 impl Request for GetMonitorsRequest {
     type Reply = GetMonitorsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
+#[inline]
 pub fn get_monitors<Conn>(conn: &Conn, window: xproto::Window, get_active: bool) -> Result<Cookie<'_, Conn, GetMonitorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6199,6 +8841,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMonitorsReply {
     pub sequence: u16,
@@ -6207,6 +8850,7 @@ pub struct GetMonitorsReply {
     pub n_outputs: u32,
     pub monitors: Vec<MonitorInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 impl TryParse for GetMonitorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6224,11 +8868,13 @@ impl TryParse for GetMonitorsReply {
         }
         let result = GetMonitorsReply { sequence, length, timestamp, n_outputs, monitors };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
 impl GetMonitorsReply {
     /// Get the value of the `nMonitors` field.
     ///
@@ -6247,11 +8893,13 @@ impl GetMonitorsReply {
 
 /// Opcode for the SetMonitor request
 pub const SET_MONITOR_REQUEST: u8 = 43;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetMonitorRequest {
     pub window: xproto::Window,
     pub monitorinfo: MonitorInfo,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
 impl SetMonitorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6282,12 +8930,63 @@ impl SetMonitorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), monitorinfo_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            SET_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let monitorinfo_bytes = self.monitorinfo.serialize();
+        let length_so_far = length_so_far + monitorinfo_bytes.len();
+        write(&monitorinfo_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.monitorinfo.serialize().len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6304,9 +9003,12 @@ impl SetMonitorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
 impl Request for SetMonitorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
+#[inline]
 pub fn set_monitor<Conn>(conn: &Conn, window: xproto::Window, monitorinfo: MonitorInfo) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6320,11 +9022,13 @@ where
 
 /// Opcode for the DeleteMonitor request
 pub const DELETE_MONITOR_REQUEST: u8 = 44;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteMonitorRequest {
     pub window: xproto::Window,
     pub name: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
 impl DeleteMonitorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6356,12 +9060,61 @@ impl DeleteMonitorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let name_bytes = self.name.serialize();
+        write(&[
+            major_opcode,
+            DELETE_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6378,9 +9131,12 @@ impl DeleteMonitorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
 impl Request for DeleteMonitorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
+#[inline]
 pub fn delete_monitor<Conn>(conn: &Conn, window: xproto::Window, name: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6394,6 +9150,7 @@ where
 
 /// Opcode for the CreateLease request
 pub const CREATE_LEASE_REQUEST: u8 = 45;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateLeaseRequest<'input> {
     pub window: xproto::Window,
@@ -6401,6 +9158,7 @@ pub struct CreateLeaseRequest<'input> {
     pub crtcs: Cow<'input, [Crtc]>,
     pub outputs: Cow<'input, [Output]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
 impl<'input> CreateLeaseRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6446,12 +9204,79 @@ impl<'input> CreateLeaseRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), crtcs_bytes.into(), outputs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let lid_bytes = self.lid.serialize();
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        let num_crtcs_bytes = num_crtcs.serialize();
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        let num_outputs_bytes = num_outputs.serialize();
+        write(&[
+            major_opcode,
+            CREATE_LEASE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            num_crtcs_bytes[0],
+            num_crtcs_bytes[1],
+            num_outputs_bytes[0],
+            num_outputs_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let crtcs_bytes = self.crtcs.serialize();
+        let length_so_far = length_so_far + crtcs_bytes.len();
+        write(&crtcs_bytes[..]);
+        let outputs_bytes = self.outputs.serialize();
+        let length_so_far = length_so_far + outputs_bytes.len();
+        write(&outputs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.crtcs.len() * 4 + self.outputs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, CreateLeaseReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6483,9 +9308,12 @@ impl<'input> CreateLeaseRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
 impl<'input> Request for CreateLeaseRequest<'input> {
     type Reply = CreateLeaseReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
+#[inline]
 pub fn create_lease<'c, 'input, Conn>(conn: &'c Conn, window: xproto::Window, lid: Lease, crtcs: &'input [Crtc], outputs: &'input [Output]) -> Result<CookieWithFds<'c, Conn, CreateLeaseReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6499,15 +9327,17 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
+#[derive(Debug)]
 pub struct CreateLeaseReply {
     pub nfd: u8,
     pub sequence: u16,
     pub length: u32,
-    pub master_fd: RawFdContainer,
+    pub master_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
 impl TryParseFd for CreateLeaseReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -6521,7 +9351,8 @@ impl TryParseFd for CreateLeaseReply {
         }
         let result = CreateLeaseReply { nfd, sequence, length, master_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -6529,11 +9360,13 @@ impl TryParseFd for CreateLeaseReply {
 
 /// Opcode for the FreeLease request
 pub const FREE_LEASE_REQUEST: u8 = 46;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FreeLeaseRequest {
     pub lid: Lease,
     pub terminate: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
 impl FreeLeaseRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6565,12 +9398,61 @@ impl FreeLeaseRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let lid_bytes = self.lid.serialize();
+        let terminate_bytes = self.terminate.serialize();
+        write(&[
+            major_opcode,
+            FREE_LEASE_REQUEST,
+            0,
+            0,
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            terminate_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6587,9 +9469,12 @@ impl FreeLeaseRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
 impl Request for FreeLeaseRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
+#[inline]
 pub fn free_lease<Conn>(conn: &Conn, lid: Lease, terminate: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6806,6 +9691,8 @@ impl TryParse for NotifyEvent {
         let (sequence, remaining) = u16::try_parse(remaining)?;
         let (u, remaining) = NotifyData::try_parse(remaining)?;
         let sub_code = sub_code.into();
+        #[cfg(feature = "strict_parsing")]
+        Notify::check_known(sub_code)?;
         let result = NotifyEvent { response_type, sub_code, sequence, u };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -6863,190 +9750,280 @@ impl From<NotifyEvent> for [u8; 32] {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryVersion"))]
+    #[inline]
     fn randr_query_version(&self, major_version: u32, minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, major_version, minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenConfig"))]
+    #[inline]
     fn randr_set_screen_config<A>(&self, window: xproto::Window, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, size_id: u16, rotation: A, rate: u16) -> Result<Cookie<'_, Self, SetScreenConfigReply>, ConnectionError>
     where
         A: Into<u16>,
     {
         set_screen_config(self, window, timestamp, config_timestamp, size_id, rotation, rate)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SelectInput"))]
+    #[inline]
     fn randr_select_input<A>(&self, window: xproto::Window, enable: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
     {
         select_input(self, window, enable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenInfo"))]
+    #[inline]
     fn randr_get_screen_info(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetScreenInfoReply>, ConnectionError>
     {
         get_screen_info(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenSizeRange"))]
+    #[inline]
     fn randr_get_screen_size_range(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetScreenSizeRangeReply>, ConnectionError>
     {
         get_screen_size_range(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetScreenSize"))]
+    #[inline]
     fn randr_set_screen_size(&self, window: xproto::Window, width: u16, height: u16, mm_width: u32, mm_height: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_screen_size(self, window, width, height, mm_width, mm_height)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResources"))]
+    #[inline]
     fn randr_get_screen_resources(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetScreenResourcesReply>, ConnectionError>
     {
         get_screen_resources(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputInfo"))]
+    #[inline]
     fn randr_get_output_info(&self, output: Output, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Self, GetOutputInfoReply>, ConnectionError>
     {
         get_output_info(self, output, config_timestamp)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListOutputProperties"))]
+    #[inline]
     fn randr_list_output_properties(&self, output: Output) -> Result<Cookie<'_, Self, ListOutputPropertiesReply>, ConnectionError>
     {
         list_output_properties(self, output)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryOutputProperty"))]
+    #[inline]
     fn randr_query_output_property(&self, output: Output, property: xproto::Atom) -> Result<Cookie<'_, Self, QueryOutputPropertyReply>, ConnectionError>
     {
         query_output_property(self, output, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureOutputProperty"))]
+    #[inline]
     fn randr_configure_output_property<'c, 'input>(&'c self, output: Output, property: xproto::Atom, pending: bool, range: bool, values: &'input [i32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         configure_output_property(self, output, property, pending, range, values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeOutputProperty"))]
+    #[inline]
     fn randr_change_output_property<'c, 'input>(&'c self, output: Output, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: xproto::PropMode, num_units: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_output_property(self, output, property, type_, format, mode, num_units, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputProperty"))]
+    #[inline]
     fn randr_delete_output_property(&self, output: Output, property: xproto::Atom) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_output_property(self, output, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputProperty"))]
+    #[inline]
     fn randr_get_output_property<A>(&self, output: Output, property: xproto::Atom, type_: A, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Self, GetOutputPropertyReply>, ConnectionError>
     where
         A: Into<xproto::Atom>,
     {
         get_output_property(self, output, property, type_, long_offset, long_length, delete, pending)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateMode"))]
+    #[inline]
     fn randr_create_mode<'c, 'input>(&'c self, window: xproto::Window, mode_info: ModeInfo, name: &'input [u8]) -> Result<Cookie<'c, Self, CreateModeReply>, ConnectionError>
     {
         create_mode(self, window, mode_info, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DestroyMode"))]
+    #[inline]
     fn randr_destroy_mode(&self, mode: Mode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_mode(self, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::AddOutputMode"))]
+    #[inline]
     fn randr_add_output_mode(&self, output: Output, mode: Mode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         add_output_mode(self, output, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteOutputMode"))]
+    #[inline]
     fn randr_delete_output_mode(&self, output: Output, mode: Mode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_output_mode(self, output, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcInfo"))]
+    #[inline]
     fn randr_get_crtc_info(&self, crtc: Crtc, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Self, GetCrtcInfoReply>, ConnectionError>
     {
         get_crtc_info(self, crtc, config_timestamp)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcConfig"))]
+    #[inline]
     fn randr_set_crtc_config<'c, 'input, A>(&'c self, crtc: Crtc, timestamp: xproto::Timestamp, config_timestamp: xproto::Timestamp, x: i16, y: i16, mode: Mode, rotation: A, outputs: &'input [Output]) -> Result<Cookie<'c, Self, SetCrtcConfigReply>, ConnectionError>
     where
         A: Into<u16>,
     {
         set_crtc_config(self, crtc, timestamp, config_timestamp, x, y, mode, rotation, outputs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGammaSize"))]
+    #[inline]
     fn randr_get_crtc_gamma_size(&self, crtc: Crtc) -> Result<Cookie<'_, Self, GetCrtcGammaSizeReply>, ConnectionError>
     {
         get_crtc_gamma_size(self, crtc)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcGamma"))]
+    #[inline]
     fn randr_get_crtc_gamma(&self, crtc: Crtc) -> Result<Cookie<'_, Self, GetCrtcGammaReply>, ConnectionError>
     {
         get_crtc_gamma(self, crtc)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcGamma"))]
+    #[inline]
     fn randr_set_crtc_gamma<'c, 'input>(&'c self, crtc: Crtc, red: &'input [u16], green: &'input [u16], blue: &'input [u16]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_crtc_gamma(self, crtc, red, green, blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetScreenResourcesCurrent"))]
+    #[inline]
     fn randr_get_screen_resources_current(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetScreenResourcesCurrentReply>, ConnectionError>
     {
         get_screen_resources_current(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetCrtcTransform"))]
+    #[inline]
     fn randr_set_crtc_transform<'c, 'input>(&'c self, crtc: Crtc, transform: render::Transform, filter_name: &'input [u8], filter_params: &'input [render::Fixed]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_crtc_transform(self, crtc, transform, filter_name, filter_params)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetCrtcTransform"))]
+    #[inline]
     fn randr_get_crtc_transform(&self, crtc: Crtc) -> Result<Cookie<'_, Self, GetCrtcTransformReply>, ConnectionError>
     {
         get_crtc_transform(self, crtc)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetPanning"))]
+    #[inline]
     fn randr_get_panning(&self, crtc: Crtc) -> Result<Cookie<'_, Self, GetPanningReply>, ConnectionError>
     {
         get_panning(self, crtc)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetPanning"))]
+    #[inline]
     fn randr_set_panning(&self, crtc: Crtc, timestamp: xproto::Timestamp, left: u16, top: u16, width: u16, height: u16, track_left: u16, track_top: u16, track_width: u16, track_height: u16, border_left: i16, border_top: i16, border_right: i16, border_bottom: i16) -> Result<Cookie<'_, Self, SetPanningReply>, ConnectionError>
     {
         set_panning(self, crtc, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetOutputPrimary"))]
+    #[inline]
     fn randr_set_output_primary(&self, window: xproto::Window, output: Output) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_output_primary(self, window, output)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetOutputPrimary"))]
+    #[inline]
     fn randr_get_output_primary(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetOutputPrimaryReply>, ConnectionError>
     {
         get_output_primary(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviders"))]
+    #[inline]
     fn randr_get_providers(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetProvidersReply>, ConnectionError>
     {
         get_providers(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderInfo"))]
+    #[inline]
     fn randr_get_provider_info(&self, provider: Provider, config_timestamp: xproto::Timestamp) -> Result<Cookie<'_, Self, GetProviderInfoReply>, ConnectionError>
     {
         get_provider_info(self, provider, config_timestamp)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOffloadSink"))]
+    #[inline]
     fn randr_set_provider_offload_sink(&self, provider: Provider, sink_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_provider_offload_sink(self, provider, sink_provider, config_timestamp)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetProviderOutputSource"))]
+    #[inline]
     fn randr_set_provider_output_source(&self, provider: Provider, source_provider: Provider, config_timestamp: xproto::Timestamp) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_provider_output_source(self, provider, source_provider, config_timestamp)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ListProviderProperties"))]
+    #[inline]
     fn randr_list_provider_properties(&self, provider: Provider) -> Result<Cookie<'_, Self, ListProviderPropertiesReply>, ConnectionError>
     {
         list_provider_properties(self, provider)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::QueryProviderProperty"))]
+    #[inline]
     fn randr_query_provider_property(&self, provider: Provider, property: xproto::Atom) -> Result<Cookie<'_, Self, QueryProviderPropertyReply>, ConnectionError>
     {
         query_provider_property(self, provider, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ConfigureProviderProperty"))]
+    #[inline]
     fn randr_configure_provider_property<'c, 'input>(&'c self, provider: Provider, property: xproto::Atom, pending: bool, range: bool, values: &'input [i32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         configure_provider_property(self, provider, property, pending, range, values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::ChangeProviderProperty"))]
+    #[inline]
     fn randr_change_provider_property<'c, 'input>(&'c self, provider: Provider, property: xproto::Atom, type_: xproto::Atom, format: u8, mode: u8, num_items: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_provider_property(self, provider, property, type_, format, mode, num_items, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteProviderProperty"))]
+    #[inline]
     fn randr_delete_provider_property(&self, provider: Provider, property: xproto::Atom) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_provider_property(self, provider, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetProviderProperty"))]
+    #[inline]
     fn randr_get_provider_property(&self, provider: Provider, property: xproto::Atom, type_: xproto::Atom, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Result<Cookie<'_, Self, GetProviderPropertyReply>, ConnectionError>
     {
         get_provider_property(self, provider, property, type_, long_offset, long_length, delete, pending)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::GetMonitors"))]
+    #[inline]
     fn randr_get_monitors(&self, window: xproto::Window, get_active: bool) -> Result<Cookie<'_, Self, GetMonitorsReply>, ConnectionError>
     {
         get_monitors(self, window, get_active)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::SetMonitor"))]
+    #[inline]
     fn randr_set_monitor(&self, window: xproto::Window, monitorinfo: MonitorInfo) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_monitor(self, window, monitorinfo)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::DeleteMonitor"))]
+    #[inline]
     fn randr_delete_monitor(&self, window: xproto::Window, name: xproto::Atom) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_monitor(self, window, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::CreateLease"))]
+    #[inline]
     fn randr_create_lease<'c, 'input>(&'c self, window: xproto::Window, lid: Lease, crtcs: &'input [Crtc], outputs: &'input [Output]) -> Result<CookieWithFds<'c, Self, CreateLeaseReply>, ConnectionError>
     {
         create_lease(self, window, lid, crtcs, outputs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "randr::FreeLease"))]
+    #[inline]
     fn randr_free_lease(&self, lid: Lease, terminate: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_lease(self, lid, terminate)
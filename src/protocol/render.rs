@@ -4,6 +4,8 @@
 //! Bindings to the `Render` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -89,6 +91,17 @@ impl std::fmt::Debug for PictType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PictType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PictureEnum(u8);
@@ -305,6 +318,17 @@ impl std::fmt::Debug for PictOp  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PictOp {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=13 | 16..=27 | 32..=43 | 48..=62) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PolyEdge(u32);
@@ -351,6 +375,17 @@ impl std::fmt::Debug for PolyEdge  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PolyEdge {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PolyMode(u32);
@@ -397,6 +432,17 @@ impl std::fmt::Debug for PolyMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PolyMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CP(u16);
@@ -526,6 +572,17 @@ impl std::fmt::Debug for SubPixel  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SubPixel {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Repeat(u32);
@@ -576,6 +633,17 @@ impl std::fmt::Debug for Repeat  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Repeat {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 pub type Glyph = u32;
 
@@ -687,6 +755,8 @@ impl TryParse for Pictforminfo {
         let (direct, remaining) = Directformat::try_parse(remaining)?;
         let (colormap, remaining) = xproto::Colormap::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        PictType::check_known(type_)?;
         let result = Pictforminfo { id, type_, depth, direct, colormap };
         Ok((result, remaining))
     }
@@ -1237,11 +1307,13 @@ impl Serialize for Glyphinfo {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest {
     pub client_major_version: u32,
     pub client_minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1273,12 +1345,61 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1295,9 +1416,12 @@ impl QueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1309,6 +1433,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -1316,6 +1441,7 @@ pub struct QueryVersionReply {
     pub major_version: u32,
     pub minor_version: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1331,7 +1457,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1339,8 +1466,10 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the QueryPictFormats request
 pub const QUERY_PICT_FORMATS_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryPictFormatsRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 impl QueryPictFormatsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1362,12 +1491,51 @@ impl QueryPictFormatsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            QUERY_PICT_FORMATS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryPictFormatsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1380,9 +1548,12 @@ impl QueryPictFormatsRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 impl Request for QueryPictFormatsRequest {
     type Reply = QueryPictFormatsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
+#[inline]
 pub fn query_pict_formats<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryPictFormatsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1391,6 +1562,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryPictFormatsReply {
     pub sequence: u16,
@@ -1401,6 +1573,7 @@ pub struct QueryPictFormatsReply {
     pub screens: Vec<Pictscreen>,
     pub subpixels: Vec<SubPixel>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 impl TryParse for QueryPictFormatsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1418,10 +1591,12 @@ impl TryParse for QueryPictFormatsReply {
         let (screens, remaining) = crate::x11_utils::parse_list::<Pictscreen>(remaining, num_screens.try_to_usize()?)?;
         let mut remaining = remaining;
         let list_length = num_subpixel.try_to_usize()?;
-        let mut subpixels = Vec::with_capacity(list_length);
+        let mut subpixels = Vec::with_capacity(crate::x11_utils::list_capacity(remaining, list_length)?);
         for _ in 0..list_length {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             let v = v.into();
+            #[cfg(feature = "strict_parsing")]
+            SubPixel::check_known(v)?;
             remaining = new_remaining;
             subpixels.push(v);
         }
@@ -1430,11 +1605,13 @@ impl TryParse for QueryPictFormatsReply {
         }
         let result = QueryPictFormatsReply { sequence, length, num_depths, num_visuals, formats, screens, subpixels };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
 impl QueryPictFormatsReply {
     /// Get the value of the `num_formats` field.
     ///
@@ -1479,10 +1656,12 @@ impl QueryPictFormatsReply {
 
 /// Opcode for the QueryPictIndexValues request
 pub const QUERY_PICT_INDEX_VALUES_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryPictIndexValuesRequest {
     pub format: Pictformat,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 impl QueryPictIndexValuesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1509,12 +1688,56 @@ impl QueryPictIndexValuesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let format_bytes = self.format.serialize();
+        write(&[
+            major_opcode,
+            QUERY_PICT_INDEX_VALUES_REQUEST,
+            0,
+            0,
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryPictIndexValuesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1529,9 +1752,12 @@ impl QueryPictIndexValuesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 impl Request for QueryPictIndexValuesRequest {
     type Reply = QueryPictIndexValuesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
+#[inline]
 pub fn query_pict_index_values<Conn>(conn: &Conn, format: Pictformat) -> Result<Cookie<'_, Conn, QueryPictIndexValuesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1542,12 +1768,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryPictIndexValuesReply {
     pub sequence: u16,
     pub length: u32,
     pub values: Vec<Indexvalue>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 impl TryParse for QueryPictIndexValuesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1563,11 +1791,13 @@ impl TryParse for QueryPictIndexValuesReply {
         }
         let result = QueryPictIndexValuesReply { sequence, length, values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
 impl QueryPictIndexValuesReply {
     /// Get the value of the `num_values` field.
     ///
@@ -1584,6 +1814,9 @@ impl QueryPictIndexValuesReply {
     }
 }
 
+/// Opcode for the CreatePicture request
+pub const CREATE_PICTURE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 /// Auxiliary and optional information for the `create_picture` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CreatePictureAux {
@@ -1601,6 +1834,7 @@ pub struct CreatePictureAux {
     pub dither: Option<xproto::Atom>,
     pub componentalpha: Option<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl CreatePictureAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -1609,6 +1843,8 @@ impl CreatePictureAux {
             let remaining = outer_remaining;
             let (repeat, remaining) = u32::try_parse(remaining)?;
             let repeat = repeat.into();
+            #[cfg(feature = "strict_parsing")]
+            Repeat::check_known(repeat)?;
             outer_remaining = remaining;
             Some(repeat)
         } else {
@@ -1674,6 +1910,8 @@ impl CreatePictureAux {
             let remaining = outer_remaining;
             let (subwindowmode, remaining) = u32::try_parse(remaining)?;
             let subwindowmode = subwindowmode.into();
+            #[cfg(feature = "strict_parsing")]
+            xproto::SubwindowMode::check_known(subwindowmode)?;
             outer_remaining = remaining;
             Some(subwindowmode)
         } else {
@@ -1683,6 +1921,8 @@ impl CreatePictureAux {
             let remaining = outer_remaining;
             let (polyedge, remaining) = u32::try_parse(remaining)?;
             let polyedge = polyedge.into();
+            #[cfg(feature = "strict_parsing")]
+            PolyEdge::check_known(polyedge)?;
             outer_remaining = remaining;
             Some(polyedge)
         } else {
@@ -1692,6 +1932,8 @@ impl CreatePictureAux {
             let remaining = outer_remaining;
             let (polymode, remaining) = u32::try_parse(remaining)?;
             let polymode = polymode.into();
+            #[cfg(feature = "strict_parsing")]
+            PolyMode::check_known(polymode)?;
             outer_remaining = remaining;
             Some(polymode)
         } else {
@@ -1717,6 +1959,7 @@ impl CreatePictureAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl CreatePictureAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -1767,6 +2010,7 @@ impl CreatePictureAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl CreatePictureAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -1812,6 +2056,7 @@ impl CreatePictureAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl CreatePictureAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -1884,8 +2129,7 @@ impl CreatePictureAux {
     }
 }
 
-/// Opcode for the CreatePicture request
-pub const CREATE_PICTURE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreatePictureRequest<'input> {
     pub pid: Picture,
@@ -1893,6 +2137,7 @@ pub struct CreatePictureRequest<'input> {
     pub format: Pictformat,
     pub value_list: Cow<'input, CreatePictureAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl<'input> CreatePictureRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1939,12 +2184,80 @@ impl<'input> CreatePictureRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let format_bytes = self.format.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            major_opcode,
+            CREATE_PICTURE_REQUEST,
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 20 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1975,9 +2288,12 @@ impl<'input> CreatePictureRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
 impl<'input> Request for CreatePictureRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
+#[inline]
 pub fn create_picture<'c, 'input, Conn>(conn: &'c Conn, pid: Picture, drawable: xproto::Drawable, format: Pictformat, value_list: &'input CreatePictureAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1991,6 +2307,9 @@ where
     request0.send(conn)
 }
 
+/// Opcode for the ChangePicture request
+pub const CHANGE_PICTURE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 /// Auxiliary and optional information for the `change_picture` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ChangePictureAux {
@@ -2008,6 +2327,7 @@ pub struct ChangePictureAux {
     pub dither: Option<xproto::Atom>,
     pub componentalpha: Option<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl ChangePictureAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -2016,6 +2336,8 @@ impl ChangePictureAux {
             let remaining = outer_remaining;
             let (repeat, remaining) = u32::try_parse(remaining)?;
             let repeat = repeat.into();
+            #[cfg(feature = "strict_parsing")]
+            Repeat::check_known(repeat)?;
             outer_remaining = remaining;
             Some(repeat)
         } else {
@@ -2081,6 +2403,8 @@ impl ChangePictureAux {
             let remaining = outer_remaining;
             let (subwindowmode, remaining) = u32::try_parse(remaining)?;
             let subwindowmode = subwindowmode.into();
+            #[cfg(feature = "strict_parsing")]
+            xproto::SubwindowMode::check_known(subwindowmode)?;
             outer_remaining = remaining;
             Some(subwindowmode)
         } else {
@@ -2090,6 +2414,8 @@ impl ChangePictureAux {
             let remaining = outer_remaining;
             let (polyedge, remaining) = u32::try_parse(remaining)?;
             let polyedge = polyedge.into();
+            #[cfg(feature = "strict_parsing")]
+            PolyEdge::check_known(polyedge)?;
             outer_remaining = remaining;
             Some(polyedge)
         } else {
@@ -2099,6 +2425,8 @@ impl ChangePictureAux {
             let remaining = outer_remaining;
             let (polymode, remaining) = u32::try_parse(remaining)?;
             let polymode = polymode.into();
+            #[cfg(feature = "strict_parsing")]
+            PolyMode::check_known(polymode)?;
             outer_remaining = remaining;
             Some(polymode)
         } else {
@@ -2124,6 +2452,7 @@ impl ChangePictureAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl ChangePictureAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -2174,6 +2503,7 @@ impl ChangePictureAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl ChangePictureAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -2219,6 +2549,7 @@ impl ChangePictureAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl ChangePictureAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -2291,13 +2622,13 @@ impl ChangePictureAux {
     }
 }
 
-/// Opcode for the ChangePicture request
-pub const CHANGE_PICTURE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangePictureRequest<'input> {
     pub picture: Picture,
     pub value_list: Cow<'input, ChangePictureAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl<'input> ChangePictureRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2334,12 +2665,70 @@ impl<'input> ChangePictureRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_PICTURE_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2364,9 +2753,12 @@ impl<'input> ChangePictureRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
 impl<'input> Request for ChangePictureRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
+#[inline]
 pub fn change_picture<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, value_list: &'input ChangePictureAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2380,6 +2772,7 @@ where
 
 /// Opcode for the SetPictureClipRectangles request
 pub const SET_PICTURE_CLIP_RECTANGLES_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetPictureClipRectanglesRequest<'input> {
     pub picture: Picture,
@@ -2387,6 +2780,7 @@ pub struct SetPictureClipRectanglesRequest<'input> {
     pub clip_y_origin: i16,
     pub rectangles: Cow<'input, [xproto::Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
 impl<'input> SetPictureClipRectanglesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2423,12 +2817,69 @@ impl<'input> SetPictureClipRectanglesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let clip_x_origin_bytes = self.clip_x_origin.serialize();
+        let clip_y_origin_bytes = self.clip_y_origin.serialize();
+        write(&[
+            major_opcode,
+            SET_PICTURE_CLIP_RECTANGLES_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            clip_x_origin_bytes[0],
+            clip_x_origin_bytes[1],
+            clip_y_origin_bytes[0],
+            clip_y_origin_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let rectangles_bytes = self.rectangles.serialize();
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        write(&rectangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.rectangles.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2465,9 +2916,12 @@ impl<'input> SetPictureClipRectanglesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
 impl<'input> Request for SetPictureClipRectanglesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
+#[inline]
 pub fn set_picture_clip_rectangles<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, clip_x_origin: i16, clip_y_origin: i16, rectangles: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2483,10 +2937,12 @@ where
 
 /// Opcode for the FreePicture request
 pub const FREE_PICTURE_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FreePictureRequest {
     pub picture: Picture,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
 impl FreePictureRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2513,12 +2969,56 @@ impl FreePictureRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        write(&[
+            major_opcode,
+            FREE_PICTURE_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2533,9 +3033,12 @@ impl FreePictureRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
 impl Request for FreePictureRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
+#[inline]
 pub fn free_picture<Conn>(conn: &Conn, picture: Picture) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2548,6 +3051,7 @@ where
 
 /// Opcode for the Composite request
 pub const COMPOSITE_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CompositeRequest {
     pub op: PictOp,
@@ -2563,6 +3067,7 @@ pub struct CompositeRequest {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
 impl CompositeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2628,37 +3133,122 @@ impl CompositeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != COMPOSITE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (op, remaining) = u8::try_parse(value)?;
-        let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (mask, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (mask_x, remaining) = i16::try_parse(remaining)?;
-        let (mask_y, remaining) = i16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let _ = remaining;
-        Ok(CompositeRequest {
-            op,
-            src,
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let mask_bytes = self.mask.serialize();
+        let dst_bytes = self.dst.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let mask_x_bytes = self.mask_x.serialize();
+        let mask_y_bytes = self.mask_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        write(&[
+            major_opcode,
+            COMPOSITE_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            mask_x_bytes[0],
+            mask_x_bytes[1],
+            mask_y_bytes[0],
+            mask_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 36;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        36
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (op, remaining) = u8::try_parse(value)?;
+        let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (src, remaining) = Picture::try_parse(remaining)?;
+        let (mask, remaining) = Picture::try_parse(remaining)?;
+        let (dst, remaining) = Picture::try_parse(remaining)?;
+        let (src_x, remaining) = i16::try_parse(remaining)?;
+        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let (mask_x, remaining) = i16::try_parse(remaining)?;
+        let (mask_y, remaining) = i16::try_parse(remaining)?;
+        let (dst_x, remaining) = i16::try_parse(remaining)?;
+        let (dst_y, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
+        let (height, remaining) = u16::try_parse(remaining)?;
+        let _ = remaining;
+        Ok(CompositeRequest {
+            op,
+            src,
             mask,
             dst,
             src_x,
@@ -2672,9 +3262,12 @@ impl CompositeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
 impl Request for CompositeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
+#[inline]
 pub fn composite<Conn, A>(conn: &Conn, op: PictOp, src: Picture, mask: A, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2700,6 +3293,7 @@ where
 
 /// Opcode for the Trapezoids request
 pub const TRAPEZOIDS_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrapezoidsRequest<'input> {
     pub op: PictOp,
@@ -2710,6 +3304,7 @@ pub struct TrapezoidsRequest<'input> {
     pub src_y: i16,
     pub traps: Cow<'input, [Trapezoid]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
 impl<'input> TrapezoidsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2761,12 +3356,84 @@ impl<'input> TrapezoidsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), traps_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            TRAPEZOIDS_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let traps_bytes = self.traps.serialize();
+        let length_so_far = length_so_far + traps_bytes.len();
+        write(&traps_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.traps.len() * 40;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2776,6 +3443,8 @@ impl<'input> TrapezoidsRequest<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -2814,9 +3483,12 @@ impl<'input> TrapezoidsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
 impl<'input> Request for TrapezoidsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
+#[inline]
 pub fn trapezoids<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &'input [Trapezoid]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2835,6 +3507,7 @@ where
 
 /// Opcode for the Triangles request
 pub const TRIANGLES_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrianglesRequest<'input> {
     pub op: PictOp,
@@ -2845,6 +3518,7 @@ pub struct TrianglesRequest<'input> {
     pub src_y: i16,
     pub triangles: Cow<'input, [Triangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
 impl<'input> TrianglesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2896,12 +3570,84 @@ impl<'input> TrianglesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), triangles_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            TRIANGLES_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let triangles_bytes = self.triangles.serialize();
+        let length_so_far = length_so_far + triangles_bytes.len();
+        write(&triangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.triangles.len() * 24;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2911,6 +3657,8 @@ impl<'input> TrianglesRequest<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -2949,9 +3697,12 @@ impl<'input> TrianglesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
 impl<'input> Request for TrianglesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
+#[inline]
 pub fn triangles<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &'input [Triangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2970,6 +3721,7 @@ where
 
 /// Opcode for the TriStrip request
 pub const TRI_STRIP_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TriStripRequest<'input> {
     pub op: PictOp,
@@ -2980,6 +3732,7 @@ pub struct TriStripRequest<'input> {
     pub src_y: i16,
     pub points: Cow<'input, [Pointfix]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
 impl<'input> TriStripRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3031,12 +3784,84 @@ impl<'input> TriStripRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), points_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            TRI_STRIP_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let points_bytes = self.points.serialize();
+        let length_so_far = length_so_far + points_bytes.len();
+        write(&points_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.points.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3046,6 +3871,8 @@ impl<'input> TriStripRequest<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -3084,9 +3911,12 @@ impl<'input> TriStripRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
 impl<'input> Request for TriStripRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
+#[inline]
 pub fn tri_strip<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &'input [Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3105,6 +3935,7 @@ where
 
 /// Opcode for the TriFan request
 pub const TRI_FAN_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TriFanRequest<'input> {
     pub op: PictOp,
@@ -3115,6 +3946,7 @@ pub struct TriFanRequest<'input> {
     pub src_y: i16,
     pub points: Cow<'input, [Pointfix]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
 impl<'input> TriFanRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3166,37 +3998,111 @@ impl<'input> TriFanRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), points_bytes.into(), padding0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != TRI_FAN_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (op, remaining) = u8::try_parse(value)?;
-        let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let mut remaining = remaining;
-        // Length is 'everything left in the input'
-        let mut points = Vec::new();
-        while !remaining.is_empty() {
-            let (v, new_remaining) = Pointfix::try_parse(remaining)?;
-            remaining = new_remaining;
-            points.push(v);
-        }
-        let _ = remaining;
-        Ok(TriFanRequest {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            TRI_FAN_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let points_bytes = self.points.serialize();
+        let length_so_far = length_so_far + points_bytes.len();
+        write(&points_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.points.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRI_FAN_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (op, remaining) = u8::try_parse(value)?;
+        let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (src, remaining) = Picture::try_parse(remaining)?;
+        let (dst, remaining) = Picture::try_parse(remaining)?;
+        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
+        let (src_x, remaining) = i16::try_parse(remaining)?;
+        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut points = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Pointfix::try_parse(remaining)?;
+            remaining = new_remaining;
+            points.push(v);
+        }
+        let _ = remaining;
+        Ok(TriFanRequest {
             op,
             src,
             dst,
@@ -3219,9 +4125,12 @@ impl<'input> TriFanRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
 impl<'input> Request for TriFanRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
+#[inline]
 pub fn tri_fan<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &'input [Pointfix]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3240,11 +4149,13 @@ where
 
 /// Opcode for the CreateGlyphSet request
 pub const CREATE_GLYPH_SET_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateGlyphSetRequest {
     pub gsid: Glyphset,
     pub format: Pictformat,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
 impl CreateGlyphSetRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3276,12 +4187,61 @@ impl CreateGlyphSetRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let gsid_bytes = self.gsid.serialize();
+        let format_bytes = self.format.serialize();
+        write(&[
+            major_opcode,
+            CREATE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            gsid_bytes[0],
+            gsid_bytes[1],
+            gsid_bytes[2],
+            gsid_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3298,9 +4258,12 @@ impl CreateGlyphSetRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
 impl Request for CreateGlyphSetRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
+#[inline]
 pub fn create_glyph_set<Conn>(conn: &Conn, gsid: Glyphset, format: Pictformat) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3314,11 +4277,13 @@ where
 
 /// Opcode for the ReferenceGlyphSet request
 pub const REFERENCE_GLYPH_SET_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReferenceGlyphSetRequest {
     pub gsid: Glyphset,
     pub existing: Glyphset,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
 impl ReferenceGlyphSetRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3350,12 +4315,61 @@ impl ReferenceGlyphSetRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let gsid_bytes = self.gsid.serialize();
+        let existing_bytes = self.existing.serialize();
+        write(&[
+            major_opcode,
+            REFERENCE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            gsid_bytes[0],
+            gsid_bytes[1],
+            gsid_bytes[2],
+            gsid_bytes[3],
+            existing_bytes[0],
+            existing_bytes[1],
+            existing_bytes[2],
+            existing_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3372,9 +4386,12 @@ impl ReferenceGlyphSetRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
 impl Request for ReferenceGlyphSetRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
+#[inline]
 pub fn reference_glyph_set<Conn>(conn: &Conn, gsid: Glyphset, existing: Glyphset) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3388,10 +4405,12 @@ where
 
 /// Opcode for the FreeGlyphSet request
 pub const FREE_GLYPH_SET_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FreeGlyphSetRequest {
     pub glyphset: Glyphset,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
 impl FreeGlyphSetRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3418,12 +4437,56 @@ impl FreeGlyphSetRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glyphset_bytes = self.glyphset.serialize();
+        write(&[
+            major_opcode,
+            FREE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3438,9 +4501,12 @@ impl FreeGlyphSetRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
 impl Request for FreeGlyphSetRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
+#[inline]
 pub fn free_glyph_set<Conn>(conn: &Conn, glyphset: Glyphset) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3453,6 +4519,7 @@ where
 
 /// Opcode for the AddGlyphs request
 pub const ADD_GLYPHS_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddGlyphsRequest<'input> {
     pub glyphset: Glyphset,
@@ -3460,6 +4527,7 @@ pub struct AddGlyphsRequest<'input> {
     pub glyphs: Cow<'input, [Glyphinfo]>,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
 impl<'input> AddGlyphsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3500,12 +4568,75 @@ impl<'input> AddGlyphsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), glyphids_bytes.into(), glyphs_bytes.into(), self.data, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glyphset_bytes = self.glyphset.serialize();
+        let glyphs_len = u32::try_from(self.glyphids.len()).expect("`glyphids` has too many elements");
+        let glyphs_len_bytes = glyphs_len.serialize();
+        write(&[
+            major_opcode,
+            ADD_GLYPHS_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            glyphs_len_bytes[0],
+            glyphs_len_bytes[1],
+            glyphs_len_bytes[2],
+            glyphs_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let glyphids_bytes = self.glyphids.serialize();
+        let length_so_far = length_so_far + glyphids_bytes.len();
+        write(&glyphids_bytes[..]);
+        assert_eq!(self.glyphs.len(), usize::try_from(glyphs_len).unwrap(), "`glyphs` has an incorrect length");
+        let glyphs_bytes = self.glyphs.serialize();
+        let length_so_far = length_so_far + glyphs_bytes.len();
+        write(&glyphs_bytes[..]);
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.glyphids.len() * 4 + self.glyphs.len() * 12 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3536,9 +4667,12 @@ impl<'input> AddGlyphsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
 impl<'input> Request for AddGlyphsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
+#[inline]
 pub fn add_glyphs<'c, 'input, Conn>(conn: &'c Conn, glyphset: Glyphset, glyphids: &'input [u32], glyphs: &'input [Glyphinfo], data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3554,11 +4688,13 @@ where
 
 /// Opcode for the FreeGlyphs request
 pub const FREE_GLYPHS_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FreeGlyphsRequest<'input> {
     pub glyphset: Glyphset,
     pub glyphs: Cow<'input, [Glyph]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
 impl<'input> FreeGlyphsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3589,12 +4725,63 @@ impl<'input> FreeGlyphsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), glyphs_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let glyphset_bytes = self.glyphset.serialize();
+        write(&[
+            major_opcode,
+            FREE_GLYPHS_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let glyphs_bytes = self.glyphs.serialize();
+        let length_so_far = length_so_far + glyphs_bytes.len();
+        write(&glyphs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.glyphs.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3625,9 +4812,12 @@ impl<'input> FreeGlyphsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
 impl<'input> Request for FreeGlyphsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
+#[inline]
 pub fn free_glyphs<'c, 'input, Conn>(conn: &'c Conn, glyphset: Glyphset, glyphs: &'input [Glyph]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3641,6 +4831,7 @@ where
 
 /// Opcode for the CompositeGlyphs8 request
 pub const COMPOSITE_GLYPHS8_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompositeGlyphs8Request<'input> {
     pub op: PictOp,
@@ -3652,6 +4843,7 @@ pub struct CompositeGlyphs8Request<'input> {
     pub src_y: i16,
     pub glyphcmds: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
 impl<'input> CompositeGlyphs8Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3707,21 +4899,99 @@ impl<'input> CompositeGlyphs8Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.glyphcmds, padding0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            COMPOSITE_GLYPHS8_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 28;
+        let length_so_far = length_so_far + self.glyphcmds.len();
+        write(&self.glyphcmds[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
     }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != COMPOSITE_GLYPHS8_REQUEST {
-            return Err(ParseError::InvalidValue);
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.glyphcmds.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMPOSITE_GLYPHS8_REQUEST {
+            return Err(ParseError::InvalidValue);
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -3756,9 +5026,12 @@ impl<'input> CompositeGlyphs8Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
 impl<'input> Request for CompositeGlyphs8Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
+#[inline]
 pub fn composite_glyphs8<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3778,6 +5051,7 @@ where
 
 /// Opcode for the CompositeGlyphs16 request
 pub const COMPOSITE_GLYPHS16_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompositeGlyphs16Request<'input> {
     pub op: PictOp,
@@ -3789,6 +5063,7 @@ pub struct CompositeGlyphs16Request<'input> {
     pub src_y: i16,
     pub glyphcmds: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
 impl<'input> CompositeGlyphs16Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3844,12 +5119,88 @@ impl<'input> CompositeGlyphs16Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.glyphcmds, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            COMPOSITE_GLYPHS16_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 28;
+        let length_so_far = length_so_far + self.glyphcmds.len();
+        write(&self.glyphcmds[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.glyphcmds.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3859,6 +5210,8 @@ impl<'input> CompositeGlyphs16Request<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -3893,9 +5246,12 @@ impl<'input> CompositeGlyphs16Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
 impl<'input> Request for CompositeGlyphs16Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
+#[inline]
 pub fn composite_glyphs16<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3915,6 +5271,7 @@ where
 
 /// Opcode for the CompositeGlyphs32 request
 pub const COMPOSITE_GLYPHS32_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompositeGlyphs32Request<'input> {
     pub op: PictOp,
@@ -3926,6 +5283,7 @@ pub struct CompositeGlyphs32Request<'input> {
     pub src_y: i16,
     pub glyphcmds: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
 impl<'input> CompositeGlyphs32Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3981,12 +5339,88 @@ impl<'input> CompositeGlyphs32Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.glyphcmds, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            major_opcode,
+            COMPOSITE_GLYPHS32_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 28;
+        let length_so_far = length_so_far + self.glyphcmds.len();
+        write(&self.glyphcmds[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.glyphcmds.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3996,6 +5430,8 @@ impl<'input> CompositeGlyphs32Request<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (src, remaining) = Picture::try_parse(remaining)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
@@ -4030,9 +5466,12 @@ impl<'input> CompositeGlyphs32Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
 impl<'input> Request for CompositeGlyphs32Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
+#[inline]
 pub fn composite_glyphs32<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4052,6 +5491,7 @@ where
 
 /// Opcode for the FillRectangles request
 pub const FILL_RECTANGLES_REQUEST: u8 = 26;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FillRectanglesRequest<'input> {
     pub op: PictOp,
@@ -4059,6 +5499,7 @@ pub struct FillRectanglesRequest<'input> {
     pub color: Color,
     pub rects: Cow<'input, [xproto::Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
 impl<'input> FillRectanglesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4103,12 +5544,77 @@ impl<'input> FillRectanglesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), rects_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let op_bytes = u8::from(self.op).serialize();
+        let dst_bytes = self.dst.serialize();
+        let color_bytes = self.color.serialize();
+        write(&[
+            major_opcode,
+            FILL_RECTANGLES_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            color_bytes[0],
+            color_bytes[1],
+            color_bytes[2],
+            color_bytes[3],
+            color_bytes[4],
+            color_bytes[5],
+            color_bytes[6],
+            color_bytes[7],
+        ]);
+        let length_so_far = length_so_far + 20;
+        let rects_bytes = self.rects.serialize();
+        let length_so_far = length_so_far + rects_bytes.len();
+        write(&rects_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.rects.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4118,6 +5624,8 @@ impl<'input> FillRectanglesRequest<'input> {
         }
         let (op, remaining) = u8::try_parse(value)?;
         let op = op.into();
+        #[cfg(feature = "strict_parsing")]
+        PictOp::check_known(op)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (dst, remaining) = Picture::try_parse(remaining)?;
         let (color, remaining) = Color::try_parse(remaining)?;
@@ -4147,9 +5655,12 @@ impl<'input> FillRectanglesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
 impl<'input> Request for FillRectanglesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
+#[inline]
 pub fn fill_rectangles<'c, 'input, Conn>(conn: &'c Conn, op: PictOp, dst: Picture, color: Color, rects: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4165,6 +5676,7 @@ where
 
 /// Opcode for the CreateCursor request
 pub const CREATE_CURSOR_REQUEST: u8 = 27;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateCursorRequest {
     pub cid: xproto::Cursor,
@@ -4172,6 +5684,7 @@ pub struct CreateCursorRequest {
     pub x: u16,
     pub y: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
 impl CreateCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4209,12 +5722,67 @@ impl CreateCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cid_bytes = self.cid.serialize();
+        let source_bytes = self.source.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            major_opcode,
+            CREATE_CURSOR_REQUEST,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4235,9 +5803,12 @@ impl CreateCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
 impl Request for CreateCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
+#[inline]
 pub fn create_cursor<Conn>(conn: &Conn, cid: xproto::Cursor, source: Picture, x: u16, y: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4345,11 +5916,13 @@ impl Serialize for Transform {
 
 /// Opcode for the SetPictureTransform request
 pub const SET_PICTURE_TRANSFORM_REQUEST: u8 = 28;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPictureTransformRequest {
     pub picture: Picture,
     pub transform: Transform,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
 impl SetPictureTransformRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4413,12 +5986,93 @@ impl SetPictureTransformRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let transform_bytes = self.transform.serialize();
+        write(&[
+            major_opcode,
+            SET_PICTURE_TRANSFORM_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            transform_bytes[0],
+            transform_bytes[1],
+            transform_bytes[2],
+            transform_bytes[3],
+            transform_bytes[4],
+            transform_bytes[5],
+            transform_bytes[6],
+            transform_bytes[7],
+            transform_bytes[8],
+            transform_bytes[9],
+            transform_bytes[10],
+            transform_bytes[11],
+            transform_bytes[12],
+            transform_bytes[13],
+            transform_bytes[14],
+            transform_bytes[15],
+            transform_bytes[16],
+            transform_bytes[17],
+            transform_bytes[18],
+            transform_bytes[19],
+            transform_bytes[20],
+            transform_bytes[21],
+            transform_bytes[22],
+            transform_bytes[23],
+            transform_bytes[24],
+            transform_bytes[25],
+            transform_bytes[26],
+            transform_bytes[27],
+            transform_bytes[28],
+            transform_bytes[29],
+            transform_bytes[30],
+            transform_bytes[31],
+            transform_bytes[32],
+            transform_bytes[33],
+            transform_bytes[34],
+            transform_bytes[35],
+        ]);
+        let length_so_far = length_so_far + 44;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        44
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4435,9 +6089,12 @@ impl SetPictureTransformRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
 impl Request for SetPictureTransformRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
+#[inline]
 pub fn set_picture_transform<Conn>(conn: &Conn, picture: Picture, transform: Transform) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4451,10 +6108,12 @@ where
 
 /// Opcode for the QueryFilters request
 pub const QUERY_FILTERS_REQUEST: u8 = 29;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryFiltersRequest {
     pub drawable: xproto::Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 impl QueryFiltersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4481,15 +6140,59 @@ impl QueryFiltersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryFiltersReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            major_opcode,
+            QUERY_FILTERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryFiltersReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
         if header.minor_opcode != QUERY_FILTERS_REQUEST {
             return Err(ParseError::InvalidValue);
@@ -4501,9 +6204,12 @@ impl QueryFiltersRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 impl Request for QueryFiltersRequest {
     type Reply = QueryFiltersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
+#[inline]
 pub fn query_filters<Conn>(conn: &Conn, drawable: xproto::Drawable) -> Result<Cookie<'_, Conn, QueryFiltersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4514,6 +6220,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryFiltersReply {
     pub sequence: u16,
@@ -4521,6 +6228,7 @@ pub struct QueryFiltersReply {
     pub aliases: Vec<u16>,
     pub filters: Vec<xproto::Str>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 impl TryParse for QueryFiltersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4538,11 +6246,13 @@ impl TryParse for QueryFiltersReply {
         }
         let result = QueryFiltersReply { sequence, length, aliases, filters };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
 impl QueryFiltersReply {
     /// Get the value of the `num_aliases` field.
     ///
@@ -4574,12 +6284,14 @@ impl QueryFiltersReply {
 
 /// Opcode for the SetPictureFilter request
 pub const SET_PICTURE_FILTER_REQUEST: u8 = 30;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetPictureFilterRequest<'input> {
     pub picture: Picture,
     pub filter: Cow<'input, [u8]>,
     pub values: Cow<'input, [Fixed]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
 impl<'input> SetPictureFilterRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4619,12 +6331,75 @@ impl<'input> SetPictureFilterRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.filter, padding0.into(), values_bytes.into(), padding1.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let filter_len = u16::try_from(self.filter.len()).expect("`filter` has too many elements");
+        let filter_len_bytes = filter_len.serialize();
+        write(&[
+            major_opcode,
+            SET_PICTURE_FILTER_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            filter_len_bytes[0],
+            filter_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.filter.len();
+        write(&self.filter[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(&padding0[..]);
+        let values_bytes = self.values.serialize();
+        let length_so_far = length_so_far + values_bytes.len();
+        write(&values_bytes[..]);
+        let padding1 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding1.len();
+        write(padding1);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.filter.len();
+        let length_so_far = length_so_far + (4 - (length_so_far % 4)) % 4 + self.values.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4636,10 +6411,7 @@ impl<'input> SetPictureFilterRequest<'input> {
         let (filter_len, remaining) = u16::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (filter, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?)?;
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut values = Vec::new();
@@ -4664,9 +6436,12 @@ impl<'input> SetPictureFilterRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
 impl<'input> Request for SetPictureFilterRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
+#[inline]
 pub fn set_picture_filter<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, filter: &'input [u8], values: &'input [Fixed]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4717,11 +6492,13 @@ impl Serialize for Animcursorelt {
 
 /// Opcode for the CreateAnimCursor request
 pub const CREATE_ANIM_CURSOR_REQUEST: u8 = 31;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateAnimCursorRequest<'input> {
     pub cid: xproto::Cursor,
     pub cursors: Cow<'input, [Animcursorelt]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
 impl<'input> CreateAnimCursorRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4752,12 +6529,63 @@ impl<'input> CreateAnimCursorRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), cursors_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cid_bytes = self.cid.serialize();
+        write(&[
+            major_opcode,
+            CREATE_ANIM_CURSOR_REQUEST,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let cursors_bytes = self.cursors.serialize();
+        let length_so_far = length_so_far + cursors_bytes.len();
+        write(&cursors_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.cursors.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4788,9 +6616,12 @@ impl<'input> CreateAnimCursorRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
 impl<'input> Request for CreateAnimCursorRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
+#[inline]
 pub fn create_anim_cursor<'c, 'input, Conn>(conn: &'c Conn, cid: xproto::Cursor, cursors: &'input [Animcursorelt]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4900,6 +6731,7 @@ impl Serialize for Trap {
 
 /// Opcode for the AddTraps request
 pub const ADD_TRAPS_REQUEST: u8 = 32;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddTrapsRequest<'input> {
     pub picture: Picture,
@@ -4907,6 +6739,7 @@ pub struct AddTrapsRequest<'input> {
     pub y_off: i16,
     pub traps: Cow<'input, [Trap]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
 impl<'input> AddTrapsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4943,12 +6776,69 @@ impl<'input> AddTrapsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), traps_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let x_off_bytes = self.x_off.serialize();
+        let y_off_bytes = self.y_off.serialize();
+        write(&[
+            major_opcode,
+            ADD_TRAPS_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            x_off_bytes[0],
+            x_off_bytes[1],
+            y_off_bytes[0],
+            y_off_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let traps_bytes = self.traps.serialize();
+        let length_so_far = length_so_far + traps_bytes.len();
+        write(&traps_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.traps.len() * 24;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4985,9 +6875,12 @@ impl<'input> AddTrapsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
 impl<'input> Request for AddTrapsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
+#[inline]
 pub fn add_traps<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, x_off: i16, y_off: i16, traps: &'input [Trap]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5003,11 +6896,13 @@ where
 
 /// Opcode for the CreateSolidFill request
 pub const CREATE_SOLID_FILL_REQUEST: u8 = 33;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateSolidFillRequest {
     pub picture: Picture,
     pub color: Color,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
 impl CreateSolidFillRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5043,12 +6938,65 @@ impl CreateSolidFillRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let color_bytes = self.color.serialize();
+        write(&[
+            major_opcode,
+            CREATE_SOLID_FILL_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            color_bytes[0],
+            color_bytes[1],
+            color_bytes[2],
+            color_bytes[3],
+            color_bytes[4],
+            color_bytes[5],
+            color_bytes[6],
+            color_bytes[7],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5065,9 +7013,12 @@ impl CreateSolidFillRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
 impl Request for CreateSolidFillRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
+#[inline]
 pub fn create_solid_fill<Conn>(conn: &Conn, picture: Picture, color: Color) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5081,6 +7032,7 @@ where
 
 /// Opcode for the CreateLinearGradient request
 pub const CREATE_LINEAR_GRADIENT_REQUEST: u8 = 34;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateLinearGradientRequest<'input> {
     pub picture: Picture,
@@ -5089,6 +7041,7 @@ pub struct CreateLinearGradientRequest<'input> {
     pub stops: Cow<'input, [Fixed]>,
     pub colors: Cow<'input, [Color]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
 impl<'input> CreateLinearGradientRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5146,12 +7099,91 @@ impl<'input> CreateLinearGradientRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), stops_bytes.into(), colors_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let p1_bytes = self.p1.serialize();
+        let p2_bytes = self.p2.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        write(&[
+            major_opcode,
+            CREATE_LINEAR_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            p1_bytes[0],
+            p1_bytes[1],
+            p1_bytes[2],
+            p1_bytes[3],
+            p1_bytes[4],
+            p1_bytes[5],
+            p1_bytes[6],
+            p1_bytes[7],
+            p2_bytes[0],
+            p2_bytes[1],
+            p2_bytes[2],
+            p2_bytes[3],
+            p2_bytes[4],
+            p2_bytes[5],
+            p2_bytes[6],
+            p2_bytes[7],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 28;
+        let stops_bytes = self.stops.serialize();
+        let length_so_far = length_so_far + stops_bytes.len();
+        write(&stops_bytes[..]);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        let colors_bytes = self.colors.serialize();
+        let length_so_far = length_so_far + colors_bytes.len();
+        write(&colors_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 28 + self.stops.len() * 4 + self.colors.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5185,9 +7217,12 @@ impl<'input> CreateLinearGradientRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
 impl<'input> Request for CreateLinearGradientRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
+#[inline]
 pub fn create_linear_gradient<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, p1: Pointfix, p2: Pointfix, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5204,6 +7239,7 @@ where
 
 /// Opcode for the CreateRadialGradient request
 pub const CREATE_RADIAL_GRADIENT_REQUEST: u8 = 35;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateRadialGradientRequest<'input> {
     pub picture: Picture,
@@ -5214,6 +7250,7 @@ pub struct CreateRadialGradientRequest<'input> {
     pub stops: Cow<'input, [Fixed]>,
     pub colors: Cow<'input, [Color]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
 impl<'input> CreateRadialGradientRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5281,12 +7318,101 @@ impl<'input> CreateRadialGradientRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), stops_bytes.into(), colors_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let inner_bytes = self.inner.serialize();
+        let outer_bytes = self.outer.serialize();
+        let inner_radius_bytes = self.inner_radius.serialize();
+        let outer_radius_bytes = self.outer_radius.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        write(&[
+            major_opcode,
+            CREATE_RADIAL_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            inner_bytes[0],
+            inner_bytes[1],
+            inner_bytes[2],
+            inner_bytes[3],
+            inner_bytes[4],
+            inner_bytes[5],
+            inner_bytes[6],
+            inner_bytes[7],
+            outer_bytes[0],
+            outer_bytes[1],
+            outer_bytes[2],
+            outer_bytes[3],
+            outer_bytes[4],
+            outer_bytes[5],
+            outer_bytes[6],
+            outer_bytes[7],
+            inner_radius_bytes[0],
+            inner_radius_bytes[1],
+            inner_radius_bytes[2],
+            inner_radius_bytes[3],
+            outer_radius_bytes[0],
+            outer_radius_bytes[1],
+            outer_radius_bytes[2],
+            outer_radius_bytes[3],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 36;
+        let stops_bytes = self.stops.serialize();
+        let length_so_far = length_so_far + stops_bytes.len();
+        write(&stops_bytes[..]);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        let colors_bytes = self.colors.serialize();
+        let length_so_far = length_so_far + colors_bytes.len();
+        write(&colors_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 36 + self.stops.len() * 4 + self.colors.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5326,9 +7452,12 @@ impl<'input> CreateRadialGradientRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
 impl<'input> Request for CreateRadialGradientRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
+#[inline]
 pub fn create_radial_gradient<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5347,6 +7476,7 @@ where
 
 /// Opcode for the CreateConicalGradient request
 pub const CREATE_CONICAL_GRADIENT_REQUEST: u8 = 36;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateConicalGradientRequest<'input> {
     pub picture: Picture,
@@ -5355,6 +7485,7 @@ pub struct CreateConicalGradientRequest<'input> {
     pub stops: Cow<'input, [Fixed]>,
     pub colors: Cow<'input, [Color]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
 impl<'input> CreateConicalGradientRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5408,12 +7539,87 @@ impl<'input> CreateConicalGradientRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), stops_bytes.into(), colors_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize();
+        let center_bytes = self.center.serialize();
+        let angle_bytes = self.angle.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        write(&[
+            major_opcode,
+            CREATE_CONICAL_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            center_bytes[0],
+            center_bytes[1],
+            center_bytes[2],
+            center_bytes[3],
+            center_bytes[4],
+            center_bytes[5],
+            center_bytes[6],
+            center_bytes[7],
+            angle_bytes[0],
+            angle_bytes[1],
+            angle_bytes[2],
+            angle_bytes[3],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let stops_bytes = self.stops.serialize();
+        let length_so_far = length_so_far + stops_bytes.len();
+        write(&stops_bytes[..]);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        let colors_bytes = self.colors.serialize();
+        let length_so_far = length_so_far + colors_bytes.len();
+        write(&colors_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.stops.len() * 4 + self.colors.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5447,9 +7653,12 @@ impl<'input> CreateConicalGradientRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
 impl<'input> Request for CreateConicalGradientRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
+#[inline]
 pub fn create_conical_gradient<'c, 'input, Conn>(conn: &'c Conn, picture: Picture, center: Pointfix, angle: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5464,130 +7673,412 @@ where
     request0.send(conn)
 }
 
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Picture`].
+///
+/// Instances of this struct represent a `Picture` that is freed in `Drop` with [`free_picture`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct PictureWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Picture);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> PictureWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_picture(conn: &'c C, id: Picture) -> Self {
+        PictureWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn picture(&self) -> Picture {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_picture(self) -> Picture {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> PictureWrapper<'c, C> {
+    /// Create a new `Picture` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_picture`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_picture_and_get_cookie<'input>(conn: &'c C, drawable: xproto::Drawable, format: Pictformat, value_list: &'input CreatePictureAux) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let pid = conn.generate_id()?;
+        let cookie = create_picture(conn, pid, drawable, format, value_list)?;
+        Ok((Self::for_picture(conn, pid), cookie))
+    }
+
+    /// Create a new `Picture` and return a wrapper.
+    ///
+    /// Like [`Self::create_picture_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_picture<'input>(conn: &'c C, drawable: xproto::Drawable, format: Pictformat, value_list: &'input CreatePictureAux) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_picture_and_get_cookie(conn, drawable, format, value_list)?.0)
+    }
+
+    /// Create a new `Picture` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_solid_fill`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_solid_fill_and_get_cookie(conn: &'c C, color: Color) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let picture = conn.generate_id()?;
+        let cookie = create_solid_fill(conn, picture, color)?;
+        Ok((Self::for_picture(conn, picture), cookie))
+    }
+
+    /// Create a new `Picture` and return a wrapper.
+    ///
+    /// Like [`Self::create_solid_fill_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_solid_fill(conn: &'c C, color: Color) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_solid_fill_and_get_cookie(conn, color)?.0)
+    }
+
+    /// Create a new `Picture` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_linear_gradient`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_linear_gradient_and_get_cookie<'input>(conn: &'c C, p1: Pointfix, p2: Pointfix, stops: &'input [Fixed], colors: &'input [Color]) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let picture = conn.generate_id()?;
+        let cookie = create_linear_gradient(conn, picture, p1, p2, stops, colors)?;
+        Ok((Self::for_picture(conn, picture), cookie))
+    }
+
+    /// Create a new `Picture` and return a wrapper.
+    ///
+    /// Like [`Self::create_linear_gradient_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_linear_gradient<'input>(conn: &'c C, p1: Pointfix, p2: Pointfix, stops: &'input [Fixed], colors: &'input [Color]) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_linear_gradient_and_get_cookie(conn, p1, p2, stops, colors)?.0)
+    }
+
+    /// Create a new `Picture` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_radial_gradient`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_radial_gradient_and_get_cookie<'input>(conn: &'c C, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let picture = conn.generate_id()?;
+        let cookie = create_radial_gradient(conn, picture, inner, outer, inner_radius, outer_radius, stops, colors)?;
+        Ok((Self::for_picture(conn, picture), cookie))
+    }
+
+    /// Create a new `Picture` and return a wrapper.
+    ///
+    /// Like [`Self::create_radial_gradient_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_radial_gradient<'input>(conn: &'c C, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_radial_gradient_and_get_cookie(conn, inner, outer, inner_radius, outer_radius, stops, colors)?.0)
+    }
+
+    /// Create a new `Picture` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_conical_gradient`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_conical_gradient_and_get_cookie<'input>(conn: &'c C, center: Pointfix, angle: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let picture = conn.generate_id()?;
+        let cookie = create_conical_gradient(conn, picture, center, angle, stops, colors)?;
+        Ok((Self::for_picture(conn, picture), cookie))
+    }
+
+    /// Create a new `Picture` and return a wrapper.
+    ///
+    /// Like [`Self::create_conical_gradient_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_conical_gradient<'input>(conn: &'c C, center: Pointfix, angle: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_conical_gradient_and_get_cookie(conn, center, angle, stops, colors)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&PictureWrapper<'_, C>> for Picture {
+    fn from(from: &PictureWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for PictureWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_picture(self.0, self.1);
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Glyphset`].
+///
+/// Instances of this struct represent a `Glyphset` that is freed in `Drop` with [`free_glyph_set`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct GlyphsetWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Glyphset);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> GlyphsetWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_glyphset(conn: &'c C, id: Glyphset) -> Self {
+        GlyphsetWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn glyphset(&self) -> Glyphset {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_glyphset(self) -> Glyphset {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> GlyphsetWrapper<'c, C> {
+    /// Create a new `Glyphset` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_glyph_set`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_glyph_set_and_get_cookie(conn: &'c C, format: Pictformat) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let gsid = conn.generate_id()?;
+        let cookie = create_glyph_set(conn, gsid, format)?;
+        Ok((Self::for_glyphset(conn, gsid), cookie))
+    }
+
+    /// Create a new `Glyphset` and return a wrapper.
+    ///
+    /// Like [`Self::create_glyph_set_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_glyph_set(conn: &'c C, format: Pictformat) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_glyph_set_and_get_cookie(conn, format)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&GlyphsetWrapper<'_, C>> for Glyphset {
+    fn from(from: &GlyphsetWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for GlyphsetWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_glyph_set(self.0, self.1);
+    }
+}
+
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryVersion"))]
+    #[inline]
     fn render_query_version(&self, client_major_version: u32, client_minor_version: u32) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self, client_major_version, client_minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictFormats"))]
+    #[inline]
     fn render_query_pict_formats(&self) -> Result<Cookie<'_, Self, QueryPictFormatsReply>, ConnectionError>
     {
         query_pict_formats(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryPictIndexValues"))]
+    #[inline]
     fn render_query_pict_index_values(&self, format: Pictformat) -> Result<Cookie<'_, Self, QueryPictIndexValuesReply>, ConnectionError>
     {
         query_pict_index_values(self, format)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreatePicture"))]
+    #[inline]
     fn render_create_picture<'c, 'input>(&'c self, pid: Picture, drawable: xproto::Drawable, format: Pictformat, value_list: &'input CreatePictureAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_picture(self, pid, drawable, format, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ChangePicture"))]
+    #[inline]
     fn render_change_picture<'c, 'input>(&'c self, picture: Picture, value_list: &'input ChangePictureAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_picture(self, picture, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureClipRectangles"))]
+    #[inline]
     fn render_set_picture_clip_rectangles<'c, 'input>(&'c self, picture: Picture, clip_x_origin: i16, clip_y_origin: i16, rectangles: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_picture_clip_rectangles(self, picture, clip_x_origin, clip_y_origin, rectangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreePicture"))]
+    #[inline]
     fn render_free_picture(&self, picture: Picture) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_picture(self, picture)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Composite"))]
+    #[inline]
     fn render_composite<A>(&self, op: PictOp, src: Picture, mask: A, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Picture>,
     {
         composite(self, op, src, mask, dst, src_x, src_y, mask_x, mask_y, dst_x, dst_y, width, height)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Trapezoids"))]
+    #[inline]
     fn render_trapezoids<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &'input [Trapezoid]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         trapezoids(self, op, src, dst, mask_format, src_x, src_y, traps)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::Triangles"))]
+    #[inline]
     fn render_triangles<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &'input [Triangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         self::triangles(self, op, src, dst, mask_format, src_x, src_y, triangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriStrip"))]
+    #[inline]
     fn render_tri_strip<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &'input [Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         tri_strip(self, op, src, dst, mask_format, src_x, src_y, points)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::TriFan"))]
+    #[inline]
     fn render_tri_fan<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &'input [Pointfix]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         tri_fan(self, op, src, dst, mask_format, src_x, src_y, points)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateGlyphSet"))]
+    #[inline]
     fn render_create_glyph_set(&self, gsid: Glyphset, format: Pictformat) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_glyph_set(self, gsid, format)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::ReferenceGlyphSet"))]
+    #[inline]
     fn render_reference_glyph_set(&self, gsid: Glyphset, existing: Glyphset) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         reference_glyph_set(self, gsid, existing)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphSet"))]
+    #[inline]
     fn render_free_glyph_set(&self, glyphset: Glyphset) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_glyph_set(self, glyphset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddGlyphs"))]
+    #[inline]
     fn render_add_glyphs<'c, 'input>(&'c self, glyphset: Glyphset, glyphids: &'input [u32], glyphs: &'input [Glyphinfo], data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         add_glyphs(self, glyphset, glyphids, glyphs, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FreeGlyphs"))]
+    #[inline]
     fn render_free_glyphs<'c, 'input>(&'c self, glyphset: Glyphset, glyphs: &'input [Glyph]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         free_glyphs(self, glyphset, glyphs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs8"))]
+    #[inline]
     fn render_composite_glyphs8<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         composite_glyphs8(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs16"))]
+    #[inline]
     fn render_composite_glyphs16<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         composite_glyphs16(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CompositeGlyphs32"))]
+    #[inline]
     fn render_composite_glyphs32<'c, 'input>(&'c self, op: PictOp, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         composite_glyphs32(self, op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::FillRectangles"))]
+    #[inline]
     fn render_fill_rectangles<'c, 'input>(&'c self, op: PictOp, dst: Picture, color: Color, rects: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         fill_rectangles(self, op, dst, color, rects)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateCursor"))]
+    #[inline]
     fn render_create_cursor(&self, cid: xproto::Cursor, source: Picture, x: u16, y: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_cursor(self, cid, source, x, y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureTransform"))]
+    #[inline]
     fn render_set_picture_transform(&self, picture: Picture, transform: Transform) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_picture_transform(self, picture, transform)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::QueryFilters"))]
+    #[inline]
     fn render_query_filters(&self, drawable: xproto::Drawable) -> Result<Cookie<'_, Self, QueryFiltersReply>, ConnectionError>
     {
         query_filters(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::SetPictureFilter"))]
+    #[inline]
     fn render_set_picture_filter<'c, 'input>(&'c self, picture: Picture, filter: &'input [u8], values: &'input [Fixed]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_picture_filter(self, picture, filter, values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateAnimCursor"))]
+    #[inline]
     fn render_create_anim_cursor<'c, 'input>(&'c self, cid: xproto::Cursor, cursors: &'input [Animcursorelt]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_anim_cursor(self, cid, cursors)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::AddTraps"))]
+    #[inline]
     fn render_add_traps<'c, 'input>(&'c self, picture: Picture, x_off: i16, y_off: i16, traps: &'input [Trap]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         add_traps(self, picture, x_off, y_off, traps)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateSolidFill"))]
+    #[inline]
     fn render_create_solid_fill(&self, picture: Picture, color: Color) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_solid_fill(self, picture, color)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateLinearGradient"))]
+    #[inline]
     fn render_create_linear_gradient<'c, 'input>(&'c self, picture: Picture, p1: Pointfix, p2: Pointfix, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_linear_gradient(self, picture, p1, p2, stops, colors)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateRadialGradient"))]
+    #[inline]
     fn render_create_radial_gradient<'c, 'input>(&'c self, picture: Picture, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_radial_gradient(self, picture, inner, outer, inner_radius, outer_radius, stops, colors)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "render::CreateConicalGradient"))]
+    #[inline]
     fn render_create_conical_gradient<'c, 'input>(&'c self, picture: Picture, center: Pointfix, angle: Fixed, stops: &'input [Fixed], colors: &'input [Color]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_conical_gradient(self, picture, center, angle, stops, colors)
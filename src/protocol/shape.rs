@@ -4,6 +4,8 @@
 //! Bindings to the `Shape` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -99,6 +101,17 @@ impl std::fmt::Debug for SO  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SO {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SK(u8);
@@ -159,6 +172,17 @@ impl std::fmt::Debug for SK  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SK {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the Notify event
 pub const NOTIFY_EVENT: u8 = 0;
@@ -190,6 +214,8 @@ impl TryParse for NotifyEvent {
         let (shaped, remaining) = bool::try_parse(remaining)?;
         let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
         let shape_kind = shape_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(shape_kind)?;
         let result = NotifyEvent { response_type, shape_kind, sequence, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -253,8 +279,10 @@ impl From<NotifyEvent> for [u8; 32] {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -276,12 +304,51 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -294,9 +361,12 @@ impl QueryVersionRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -305,6 +375,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -312,6 +383,7 @@ pub struct QueryVersionReply {
     pub major_version: u16,
     pub minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -326,7 +398,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -334,6 +407,7 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the Rectangles request
 pub const RECTANGLES_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RectanglesRequest<'input> {
     pub operation: SO,
@@ -344,6 +418,7 @@ pub struct RectanglesRequest<'input> {
     pub y_offset: i16,
     pub rectangles: Cow<'input, [xproto::Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
 impl<'input> RectanglesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -387,12 +462,76 @@ impl<'input> RectanglesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let ordering_bytes = u8::from(self.ordering).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        write(&[
+            major_opcode,
+            RECTANGLES_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            ordering_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let rectangles_bytes = self.rectangles.serialize();
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        write(&rectangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.rectangles.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -402,10 +541,16 @@ impl<'input> RectanglesRequest<'input> {
         }
         let (operation, remaining) = Op::try_parse(value)?;
         let operation = operation.into();
+        #[cfg(feature = "strict_parsing")]
+        SO::check_known(operation)?;
         let (destination_kind, remaining) = Kind::try_parse(remaining)?;
         let destination_kind = destination_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(destination_kind)?;
         let (ordering, remaining) = u8::try_parse(remaining)?;
         let ordering = ordering.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::ClipOrdering::check_known(ordering)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
         let (x_offset, remaining) = i16::try_parse(remaining)?;
@@ -442,9 +587,12 @@ impl<'input> RectanglesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
 impl<'input> Request for RectanglesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
+#[inline]
 pub fn rectangles<'c, 'input, Conn>(conn: &'c Conn, operation: SO, destination_kind: SK, ordering: xproto::ClipOrdering, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -463,6 +611,7 @@ where
 
 /// Opcode for the Mask request
 pub const MASK_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MaskRequest {
     pub operation: SO,
@@ -472,6 +621,7 @@ pub struct MaskRequest {
     pub y_offset: i16,
     pub source_bitmap: xproto::Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
 impl MaskRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -515,12 +665,73 @@ impl MaskRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        let source_bitmap_bytes = self.source_bitmap.serialize();
+        write(&[
+            major_opcode,
+            MASK_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_bitmap_bytes[0],
+            source_bitmap_bytes[1],
+            source_bitmap_bytes[2],
+            source_bitmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -530,8 +741,12 @@ impl MaskRequest {
         }
         let (operation, remaining) = Op::try_parse(value)?;
         let operation = operation.into();
+        #[cfg(feature = "strict_parsing")]
+        SO::check_known(operation)?;
         let (destination_kind, remaining) = Kind::try_parse(remaining)?;
         let destination_kind = destination_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(destination_kind)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
         let (x_offset, remaining) = i16::try_parse(remaining)?;
@@ -548,9 +763,12 @@ impl MaskRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
 impl Request for MaskRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
+#[inline]
 pub fn mask<Conn, A>(conn: &Conn, operation: SO, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -570,6 +788,7 @@ where
 
 /// Opcode for the Combine request
 pub const COMBINE_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CombineRequest {
     pub operation: SO,
@@ -580,6 +799,7 @@ pub struct CombineRequest {
     pub y_offset: i16,
     pub source_window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
 impl CombineRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -624,12 +844,74 @@ impl CombineRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let source_kind_bytes = Kind::from(self.source_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        let source_window_bytes = self.source_window.serialize();
+        write(&[
+            major_opcode,
+            COMBINE_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            source_kind_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_window_bytes[0],
+            source_window_bytes[1],
+            source_window_bytes[2],
+            source_window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -639,10 +921,16 @@ impl CombineRequest {
         }
         let (operation, remaining) = Op::try_parse(value)?;
         let operation = operation.into();
+        #[cfg(feature = "strict_parsing")]
+        SO::check_known(operation)?;
         let (destination_kind, remaining) = Kind::try_parse(remaining)?;
         let destination_kind = destination_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(destination_kind)?;
         let (source_kind, remaining) = Kind::try_parse(remaining)?;
         let source_kind = source_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(source_kind)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
         let (x_offset, remaining) = i16::try_parse(remaining)?;
@@ -660,9 +948,12 @@ impl CombineRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
 impl Request for CombineRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
+#[inline]
 pub fn combine<Conn>(conn: &Conn, operation: SO, destination_kind: SK, source_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -681,6 +972,7 @@ where
 
 /// Opcode for the Offset request
 pub const OFFSET_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OffsetRequest {
     pub destination_kind: SK,
@@ -688,6 +980,7 @@ pub struct OffsetRequest {
     pub x_offset: i16,
     pub y_offset: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
 impl OffsetRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -725,12 +1018,67 @@ impl OffsetRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        write(&[
+            major_opcode,
+            OFFSET_REQUEST,
+            0,
+            0,
+            destination_kind_bytes[0],
+            0,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -740,6 +1088,8 @@ impl OffsetRequest {
         }
         let (destination_kind, remaining) = Kind::try_parse(value)?;
         let destination_kind = destination_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(destination_kind)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
         let (x_offset, remaining) = i16::try_parse(remaining)?;
@@ -753,9 +1103,12 @@ impl OffsetRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
 impl Request for OffsetRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
+#[inline]
 pub fn offset<Conn>(conn: &Conn, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -771,10 +1124,12 @@ where
 
 /// Opcode for the QueryExtents request
 pub const QUERY_EXTENTS_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtentsRequest {
     pub destination_window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 impl QueryExtentsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -801,12 +1156,56 @@ impl QueryExtentsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize();
+        write(&[
+            major_opcode,
+            QUERY_EXTENTS_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryExtentsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -821,9 +1220,12 @@ impl QueryExtentsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 impl Request for QueryExtentsRequest {
     type Reply = QueryExtentsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
+#[inline]
 pub fn query_extents<Conn>(conn: &Conn, destination_window: xproto::Window) -> Result<Cookie<'_, Conn, QueryExtentsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -834,6 +1236,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryExtentsReply {
     pub sequence: u16,
@@ -849,6 +1252,7 @@ pub struct QueryExtentsReply {
     pub clip_shape_extents_width: u16,
     pub clip_shape_extents_height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
 impl TryParse for QueryExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -872,7 +1276,8 @@ impl TryParse for QueryExtentsReply {
         }
         let result = QueryExtentsReply { sequence, length, bounding_shaped, clip_shaped, bounding_shape_extents_x, bounding_shape_extents_y, bounding_shape_extents_width, bounding_shape_extents_height, clip_shape_extents_x, clip_shape_extents_y, clip_shape_extents_width, clip_shape_extents_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -880,11 +1285,13 @@ impl TryParse for QueryExtentsReply {
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectInputRequest {
     pub destination_window: xproto::Window,
     pub enable: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
 impl SelectInputRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -916,12 +1323,61 @@ impl SelectInputRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize();
+        let enable_bytes = self.enable.serialize();
+        write(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            enable_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -939,9 +1395,12 @@ impl SelectInputRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
 impl Request for SelectInputRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
+#[inline]
 pub fn select_input<Conn>(conn: &Conn, destination_window: xproto::Window, enable: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -955,10 +1414,12 @@ where
 
 /// Opcode for the InputSelected request
 pub const INPUT_SELECTED_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InputSelectedRequest {
     pub destination_window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 impl InputSelectedRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -985,12 +1446,56 @@ impl InputSelectedRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize();
+        write(&[
+            major_opcode,
+            INPUT_SELECTED_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, InputSelectedReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1005,9 +1510,12 @@ impl InputSelectedRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 impl Request for InputSelectedRequest {
     type Reply = InputSelectedReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
+#[inline]
 pub fn input_selected<Conn>(conn: &Conn, destination_window: xproto::Window) -> Result<Cookie<'_, Conn, InputSelectedReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1018,12 +1526,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InputSelectedReply {
     pub enabled: bool,
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
 impl TryParse for InputSelectedReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1036,7 +1546,8 @@ impl TryParse for InputSelectedReply {
         }
         let result = InputSelectedReply { enabled, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1044,11 +1555,13 @@ impl TryParse for InputSelectedReply {
 
 /// Opcode for the GetRectangles request
 pub const GET_RECTANGLES_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetRectanglesRequest {
     pub window: xproto::Window,
     pub source_kind: SK,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 impl GetRectanglesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1080,12 +1593,61 @@ impl GetRectanglesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let source_kind_bytes = Kind::from(self.source_kind).serialize();
+        write(&[
+            major_opcode,
+            GET_RECTANGLES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            source_kind_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetRectanglesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1096,6 +1658,8 @@ impl GetRectanglesRequest {
         let (window, remaining) = xproto::Window::try_parse(value)?;
         let (source_kind, remaining) = Kind::try_parse(remaining)?;
         let source_kind = source_kind.into();
+        #[cfg(feature = "strict_parsing")]
+        SK::check_known(source_kind)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(GetRectanglesRequest {
@@ -1104,9 +1668,12 @@ impl GetRectanglesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 impl Request for GetRectanglesRequest {
     type Reply = GetRectanglesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
+#[inline]
 pub fn get_rectangles<Conn>(conn: &Conn, window: xproto::Window, source_kind: SK) -> Result<Cookie<'_, Conn, GetRectanglesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1118,6 +1685,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetRectanglesReply {
     pub ordering: xproto::ClipOrdering,
@@ -1125,6 +1693,7 @@ pub struct GetRectanglesReply {
     pub length: u32,
     pub rectangles: Vec<xproto::Rectangle>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 impl TryParse for GetRectanglesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1139,13 +1708,17 @@ impl TryParse for GetRectanglesReply {
             return Err(ParseError::InvalidValue);
         }
         let ordering = ordering.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::ClipOrdering::check_known(ordering)?;
         let result = GetRectanglesReply { ordering, sequence, length, rectangles };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
 impl GetRectanglesReply {
     /// Get the value of the `rectangles_len` field.
     ///
@@ -1164,40 +1737,58 @@ impl GetRectanglesReply {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryVersion"))]
+    #[inline]
     fn shape_query_version(&self) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Rectangles"))]
+    #[inline]
     fn shape_rectangles<'c, 'input>(&'c self, operation: SO, destination_kind: SK, ordering: xproto::ClipOrdering, destination_window: xproto::Window, x_offset: i16, y_offset: i16, rectangles: &'input [xproto::Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         self::rectangles(self, operation, destination_kind, ordering, destination_window, x_offset, y_offset, rectangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Mask"))]
+    #[inline]
     fn shape_mask<A>(&self, operation: SO, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Pixmap>,
     {
         mask(self, operation, destination_kind, destination_window, x_offset, y_offset, source_bitmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Combine"))]
+    #[inline]
     fn shape_combine(&self, operation: SO, destination_kind: SK, source_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16, source_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         combine(self, operation, destination_kind, source_kind, destination_window, x_offset, y_offset, source_window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::Offset"))]
+    #[inline]
     fn shape_offset(&self, destination_kind: SK, destination_window: xproto::Window, x_offset: i16, y_offset: i16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         offset(self, destination_kind, destination_window, x_offset, y_offset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::QueryExtents"))]
+    #[inline]
     fn shape_query_extents(&self, destination_window: xproto::Window) -> Result<Cookie<'_, Self, QueryExtentsReply>, ConnectionError>
     {
         query_extents(self, destination_window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::SelectInput"))]
+    #[inline]
     fn shape_select_input(&self, destination_window: xproto::Window, enable: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         select_input(self, destination_window, enable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::InputSelected"))]
+    #[inline]
     fn shape_input_selected(&self, destination_window: xproto::Window) -> Result<Cookie<'_, Self, InputSelectedReply>, ConnectionError>
     {
         input_selected(self, destination_window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shape::GetRectangles"))]
+    #[inline]
     fn shape_get_rectangles(&self, window: xproto::Window, source_kind: SK) -> Result<Cookie<'_, Self, GetRectanglesReply>, ConnectionError>
     {
         get_rectangles(self, window, source_kind)
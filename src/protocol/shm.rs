@@ -4,6 +4,8 @@
 //! Bindings to the `Shm` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -122,8 +124,10 @@ pub const BAD_SEG_ERROR: u8 = 0;
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -145,12 +149,51 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -163,9 +206,12 @@ impl QueryVersionRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -174,6 +220,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub shared_pixmaps: bool,
@@ -185,6 +232,7 @@ pub struct QueryVersionReply {
     pub gid: u16,
     pub pixmap_format: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -203,7 +251,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { shared_pixmaps, sequence, length, major_version, minor_version, uid, gid, pixmap_format };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -211,12 +260,14 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the Attach request
 pub const ATTACH_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AttachRequest {
     pub shmseg: Seg,
     pub shmid: u32,
     pub read_only: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
 impl AttachRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -253,12 +304,66 @@ impl AttachRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let shmseg_bytes = self.shmseg.serialize();
+        let shmid_bytes = self.shmid.serialize();
+        let read_only_bytes = self.read_only.serialize();
+        write(&[
+            major_opcode,
+            ATTACH_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            shmid_bytes[0],
+            shmid_bytes[1],
+            shmid_bytes[2],
+            shmid_bytes[3],
+            read_only_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -278,9 +383,12 @@ impl AttachRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
 impl Request for AttachRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
+#[inline]
 pub fn attach<Conn>(conn: &Conn, shmseg: Seg, shmid: u32, read_only: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -295,10 +403,12 @@ where
 
 /// Opcode for the Detach request
 pub const DETACH_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DetachRequest {
     pub shmseg: Seg,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
 impl DetachRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -325,12 +435,56 @@ impl DetachRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let shmseg_bytes = self.shmseg.serialize();
+        write(&[
+            major_opcode,
+            DETACH_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -345,9 +499,12 @@ impl DetachRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
 impl Request for DetachRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
+#[inline]
 pub fn detach<Conn>(conn: &Conn, shmseg: Seg) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -360,6 +517,7 @@ where
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PutImageRequest {
     pub drawable: xproto::Drawable,
@@ -378,6 +536,7 @@ pub struct PutImageRequest {
     pub shmseg: Seg,
     pub offset: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
 impl PutImageRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -450,12 +609,102 @@ impl PutImageRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let total_width_bytes = self.total_width.serialize();
+        let total_height_bytes = self.total_height.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let depth_bytes = self.depth.serialize();
+        let format_bytes = self.format.serialize();
+        let send_event_bytes = self.send_event.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        write(&[
+            major_opcode,
+            PUT_IMAGE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            total_width_bytes[0],
+            total_width_bytes[1],
+            total_height_bytes[0],
+            total_height_bytes[1],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            depth_bytes[0],
+            format_bytes[0],
+            send_event_bytes[0],
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 40;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        40
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -499,9 +748,12 @@ impl PutImageRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
 impl Request for PutImageRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
+#[inline]
 pub fn put_image<Conn>(conn: &Conn, drawable: xproto::Drawable, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -528,6 +780,7 @@ where
 
 /// Opcode for the GetImage request
 pub const GET_IMAGE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetImageRequest {
     pub drawable: xproto::Drawable,
@@ -540,6 +793,7 @@ pub struct GetImageRequest {
     pub shmseg: Seg,
     pub offset: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 impl GetImageRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -598,12 +852,88 @@ impl GetImageRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        let format_bytes = self.format.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        write(&[
+            major_opcode,
+            GET_IMAGE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+            format_bytes[0],
+            0,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -635,9 +965,12 @@ impl GetImageRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 impl Request for GetImageRequest {
     type Reply = GetImageReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
+#[inline]
 pub fn get_image<Conn>(conn: &Conn, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -656,6 +989,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetImageReply {
     pub depth: u8,
@@ -664,6 +998,7 @@ pub struct GetImageReply {
     pub visual: xproto::Visualid,
     pub size: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -678,7 +1013,8 @@ impl TryParse for GetImageReply {
         }
         let result = GetImageReply { depth, sequence, length, visual, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -686,6 +1022,7 @@ impl TryParse for GetImageReply {
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreatePixmapRequest {
     pub pid: xproto::Pixmap,
@@ -696,6 +1033,7 @@ pub struct CreatePixmapRequest {
     pub shmseg: Seg,
     pub offset: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
 impl CreatePixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -748,12 +1086,82 @@ impl CreatePixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let depth_bytes = self.depth.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        write(&[
+            major_opcode,
+            CREATE_PIXMAP_REQUEST,
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            depth_bytes[0],
+            0,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -781,9 +1189,12 @@ impl CreatePixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
 impl Request for CreatePixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
+#[inline]
 pub fn create_pixmap<Conn>(conn: &Conn, pid: xproto::Pixmap, drawable: xproto::Drawable, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -802,12 +1213,14 @@ where
 
 /// Opcode for the AttachFd request
 pub const ATTACH_FD_REQUEST: u8 = 6;
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
+#[derive(Debug)]
 pub struct AttachFdRequest {
     pub shmseg: Seg,
-    pub shm_fd: RawFdContainer,
+    pub shm_fd: OwnedFd,
     pub read_only: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
 impl AttachFdRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -839,16 +1252,24 @@ impl AttachFdRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![self.shm_fd]))
     }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<OwnedFd>) -> Result<Self, ParseError> {
         if header.minor_opcode != ATTACH_FD_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -865,15 +1286,18 @@ impl AttachFdRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
 impl Request for AttachFdRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
+#[inline]
 pub fn attach_fd<Conn, A>(conn: &Conn, shmseg: Seg, shm_fd: A, read_only: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
-    A: Into<RawFdContainer>,
+    A: Into<OwnedFd>,
 {
-    let shm_fd: RawFdContainer = shm_fd.into();
+    let shm_fd: OwnedFd = shm_fd.into();
     let request0 = AttachFdRequest {
         shmseg,
         shm_fd,
@@ -884,12 +1308,14 @@ where
 
 /// Opcode for the CreateSegment request
 pub const CREATE_SEGMENT_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateSegmentRequest {
     pub shmseg: Seg,
     pub size: u32,
     pub read_only: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
 impl CreateSegmentRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -926,12 +1352,66 @@ impl CreateSegmentRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let shmseg_bytes = self.shmseg.serialize();
+        let size_bytes = self.size.serialize();
+        let read_only_bytes = self.read_only.serialize();
+        write(&[
+            major_opcode,
+            CREATE_SEGMENT_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            read_only_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<CookieWithFds<'_, Conn, CreateSegmentReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply_with_fds(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -951,9 +1431,12 @@ impl CreateSegmentRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
 impl Request for CreateSegmentRequest {
     type Reply = CreateSegmentReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
+#[inline]
 pub fn create_segment<Conn>(conn: &Conn, shmseg: Seg, size: u32, read_only: bool) -> Result<CookieWithFds<'_, Conn, CreateSegmentReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -966,15 +1449,17 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
+#[derive(Debug)]
 pub struct CreateSegmentReply {
     pub nfd: u8,
     pub sequence: u16,
     pub length: u32,
-    pub shm_fd: RawFdContainer,
+    pub shm_fd: OwnedFd,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
 impl TryParseFd for CreateSegmentReply {
-    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+    fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<OwnedFd>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
         let (response_type, remaining) = u8::try_parse(remaining)?;
         let (nfd, remaining) = u8::try_parse(remaining)?;
@@ -988,44 +1473,154 @@ impl TryParseFd for CreateSegmentReply {
         }
         let result = CreateSegmentReply { nfd, sequence, length, shm_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
 
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Seg`].
+///
+/// Instances of this struct represent a `Seg` that is freed in `Drop` with [`detach`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct SegWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Seg);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> SegWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_seg(conn: &'c C, id: Seg) -> Self {
+        SegWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn seg(&self) -> Seg {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_seg(self) -> Seg {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> SegWrapper<'c, C> {
+    /// Create a new `Seg` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`attach`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn attach_and_get_cookie(conn: &'c C, shmid: u32, read_only: bool) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let shmseg = conn.generate_id()?;
+        let cookie = attach(conn, shmseg, shmid, read_only)?;
+        Ok((Self::for_seg(conn, shmseg), cookie))
+    }
+
+    /// Create a new `Seg` and return a wrapper.
+    ///
+    /// Like [`Self::attach_and_get_cookie`], but errors from the request are handled as events.
+    pub fn attach(conn: &'c C, shmid: u32, read_only: bool) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::attach_and_get_cookie(conn, shmid, read_only)?.0)
+    }
+
+    /// Create a new `Seg` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`attach_fd`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn attach_fd_and_get_cookie<A>(conn: &'c C, shm_fd: A, read_only: bool) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    where
+        A: Into<OwnedFd>,
+    {
+        let shmseg = conn.generate_id()?;
+        let cookie = attach_fd(conn, shmseg, shm_fd, read_only)?;
+        Ok((Self::for_seg(conn, shmseg), cookie))
+    }
+
+    /// Create a new `Seg` and return a wrapper.
+    ///
+    /// Like [`Self::attach_fd_and_get_cookie`], but errors from the request are handled as events.
+    pub fn attach_fd<A>(conn: &'c C, shm_fd: A, read_only: bool) -> Result<Self, crate::errors::ReplyOrIdError>
+    where
+        A: Into<OwnedFd>,
+    {
+        Ok(Self::attach_fd_and_get_cookie(conn, shm_fd, read_only)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&SegWrapper<'_, C>> for Seg {
+    fn from(from: &SegWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for SegWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = detach(self.0, self.1);
+    }
+}
+
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::QueryVersion"))]
+    #[inline]
     fn shm_query_version(&self) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Attach"))]
+    #[inline]
     fn shm_attach(&self, shmseg: Seg, shmid: u32, read_only: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         attach(self, shmseg, shmid, read_only)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::Detach"))]
+    #[inline]
     fn shm_detach(&self, shmseg: Seg) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         detach(self, shmseg)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::PutImage"))]
+    #[inline]
     fn shm_put_image(&self, drawable: xproto::Drawable, gc: xproto::Gcontext, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         put_image(self, drawable, gc, total_width, total_height, src_x, src_y, src_width, src_height, dst_x, dst_y, depth, format, send_event, shmseg, offset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::GetImage"))]
+    #[inline]
     fn shm_get_image(&self, drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: Seg, offset: u32) -> Result<Cookie<'_, Self, GetImageReply>, ConnectionError>
     {
         get_image(self, drawable, x, y, width, height, plane_mask, format, shmseg, offset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreatePixmap"))]
+    #[inline]
     fn shm_create_pixmap(&self, pid: xproto::Pixmap, drawable: xproto::Drawable, width: u16, height: u16, depth: u8, shmseg: Seg, offset: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_pixmap(self, pid, drawable, width, height, depth, shmseg, offset)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::AttachFd"))]
+    #[inline]
     fn shm_attach_fd<A>(&self, shmseg: Seg, shm_fd: A, read_only: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
-        A: Into<RawFdContainer>,
+        A: Into<OwnedFd>,
     {
         attach_fd(self, shmseg, shm_fd, read_only)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "shm::CreateSegment"))]
+    #[inline]
     fn shm_create_segment(&self, shmseg: Seg, size: u32, read_only: bool) -> Result<CookieWithFds<'_, Self, CreateSegmentReply>, ConnectionError>
     {
         create_segment(self, shmseg, size, read_only)
@@ -4,6 +4,8 @@
 //! Bindings to the `Sync` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -93,6 +95,17 @@ impl std::fmt::Debug for ALARMSTATE  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ALARMSTATE {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 pub type Counter = u32;
 
@@ -147,6 +160,17 @@ impl std::fmt::Debug for TESTTYPE  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl TESTTYPE {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct VALUETYPE(u32);
@@ -193,6 +217,17 @@ impl std::fmt::Debug for VALUETYPE  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl VALUETYPE {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CA(u8);
@@ -311,10 +346,7 @@ impl TryParse for Systemcounter {
         let (name_len, remaining) = u16::try_parse(remaining)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
         let name = name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let result = Systemcounter { counter, resolution, name };
         Ok((result, remaining))
     }
@@ -366,7 +398,11 @@ impl TryParse for Trigger {
         let (wait_value, remaining) = Int64::try_parse(remaining)?;
         let (test_type, remaining) = u32::try_parse(remaining)?;
         let wait_type = wait_type.into();
+        #[cfg(feature = "strict_parsing")]
+        VALUETYPE::check_known(wait_type)?;
         let test_type = test_type.into();
+        #[cfg(feature = "strict_parsing")]
+        TESTTYPE::check_known(test_type)?;
         let result = Trigger { counter, wait_type, wait_value, test_type };
         Ok((result, remaining))
     }
@@ -474,11 +510,13 @@ pub const ALARM_ERROR: u8 = 1;
 
 /// Opcode for the Initialize request
 pub const INITIALIZE_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InitializeRequest {
     pub desired_major_version: u8,
     pub desired_minor_version: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 impl InitializeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -506,12 +544,57 @@ impl InitializeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let desired_major_version_bytes = self.desired_major_version.serialize();
+        let desired_minor_version_bytes = self.desired_minor_version.serialize();
+        write(&[
+            major_opcode,
+            INITIALIZE_REQUEST,
+            0,
+            0,
+            desired_major_version_bytes[0],
+            desired_minor_version_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, InitializeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -528,9 +611,12 @@ impl InitializeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 impl Request for InitializeRequest {
     type Reply = InitializeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
+#[inline]
 pub fn initialize<Conn>(conn: &Conn, desired_major_version: u8, desired_minor_version: u8) -> Result<Cookie<'_, Conn, InitializeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -542,6 +628,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InitializeReply {
     pub sequence: u16,
@@ -549,6 +636,7 @@ pub struct InitializeReply {
     pub major_version: u8,
     pub minor_version: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
 impl TryParse for InitializeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -564,7 +652,8 @@ impl TryParse for InitializeReply {
         }
         let result = InitializeReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -572,8 +661,10 @@ impl TryParse for InitializeReply {
 
 /// Opcode for the ListSystemCounters request
 pub const LIST_SYSTEM_COUNTERS_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListSystemCountersRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 impl ListSystemCountersRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -595,12 +686,51 @@ impl ListSystemCountersRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            LIST_SYSTEM_COUNTERS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListSystemCountersReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -613,9 +743,12 @@ impl ListSystemCountersRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 impl Request for ListSystemCountersRequest {
     type Reply = ListSystemCountersReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
+#[inline]
 pub fn list_system_counters<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListSystemCountersReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -624,12 +757,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListSystemCountersReply {
     pub sequence: u16,
     pub length: u32,
     pub counters: Vec<Systemcounter>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 impl TryParse for ListSystemCountersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -645,11 +780,13 @@ impl TryParse for ListSystemCountersReply {
         }
         let result = ListSystemCountersReply { sequence, length, counters };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
 impl ListSystemCountersReply {
     /// Get the value of the `counters_len` field.
     ///
@@ -668,11 +805,13 @@ impl ListSystemCountersReply {
 
 /// Opcode for the CreateCounter request
 pub const CREATE_COUNTER_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateCounterRequest {
     pub id: Counter,
     pub initial_value: Int64,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
 impl CreateCounterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -708,12 +847,65 @@ impl CreateCounterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize();
+        let initial_value_bytes = self.initial_value.serialize();
+        write(&[
+            major_opcode,
+            CREATE_COUNTER_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            initial_value_bytes[0],
+            initial_value_bytes[1],
+            initial_value_bytes[2],
+            initial_value_bytes[3],
+            initial_value_bytes[4],
+            initial_value_bytes[5],
+            initial_value_bytes[6],
+            initial_value_bytes[7],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -730,9 +922,12 @@ impl CreateCounterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
 impl Request for CreateCounterRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
+#[inline]
 pub fn create_counter<Conn>(conn: &Conn, id: Counter, initial_value: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -746,10 +941,12 @@ where
 
 /// Opcode for the DestroyCounter request
 pub const DESTROY_COUNTER_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyCounterRequest {
     pub counter: Counter,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
 impl DestroyCounterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -776,12 +973,56 @@ impl DestroyCounterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -796,9 +1037,12 @@ impl DestroyCounterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
 impl Request for DestroyCounterRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
+#[inline]
 pub fn destroy_counter<Conn>(conn: &Conn, counter: Counter) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -811,10 +1055,12 @@ where
 
 /// Opcode for the QueryCounter request
 pub const QUERY_COUNTER_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryCounterRequest {
     pub counter: Counter,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 impl QueryCounterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -841,12 +1087,56 @@ impl QueryCounterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize();
+        write(&[
+            major_opcode,
+            QUERY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryCounterReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -861,9 +1151,12 @@ impl QueryCounterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 impl Request for QueryCounterRequest {
     type Reply = QueryCounterReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
+#[inline]
 pub fn query_counter<Conn>(conn: &Conn, counter: Counter) -> Result<Cookie<'_, Conn, QueryCounterReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -874,12 +1167,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryCounterReply {
     pub sequence: u16,
     pub length: u32,
     pub counter_value: Int64,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
 impl TryParse for QueryCounterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -893,7 +1188,8 @@ impl TryParse for QueryCounterReply {
         }
         let result = QueryCounterReply { sequence, length, counter_value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -901,10 +1197,12 @@ impl TryParse for QueryCounterReply {
 
 /// Opcode for the Await request
 pub const AWAIT_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AwaitRequest<'input> {
     pub wait_list: Cow<'input, [Waitcondition]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
 impl<'input> AwaitRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -930,12 +1228,58 @@ impl<'input> AwaitRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), wait_list_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            AWAIT_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        let wait_list_bytes = self.wait_list.serialize();
+        let length_so_far = length_so_far + wait_list_bytes.len();
+        write(&wait_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 4 + self.wait_list.len() * 28;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -947,7 +1291,7 @@ impl<'input> AwaitRequest<'input> {
         // Length is 'everything left in the input'
         let mut wait_list = Vec::new();
         while !remaining.is_empty() {
-            let (v, new_remaining) = Waitcondition::try_parse(value)?;
+            let (v, new_remaining) = Waitcondition::try_parse(remaining)?;
             remaining = new_remaining;
             wait_list.push(v);
         }
@@ -963,9 +1307,12 @@ impl<'input> AwaitRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
 impl<'input> Request for AwaitRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
+#[inline]
 pub fn await_<'c, 'input, Conn>(conn: &'c Conn, wait_list: &'input [Waitcondition]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -978,11 +1325,13 @@ where
 
 /// Opcode for the ChangeCounter request
 pub const CHANGE_COUNTER_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeCounterRequest {
     pub counter: Counter,
     pub amount: Int64,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
 impl ChangeCounterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1018,12 +1367,65 @@ impl ChangeCounterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize();
+        let amount_bytes = self.amount.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            amount_bytes[0],
+            amount_bytes[1],
+            amount_bytes[2],
+            amount_bytes[3],
+            amount_bytes[4],
+            amount_bytes[5],
+            amount_bytes[6],
+            amount_bytes[7],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1040,9 +1442,12 @@ impl ChangeCounterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
 impl Request for ChangeCounterRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
+#[inline]
 pub fn change_counter<Conn>(conn: &Conn, counter: Counter, amount: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1056,11 +1461,13 @@ where
 
 /// Opcode for the SetCounter request
 pub const SET_COUNTER_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetCounterRequest {
     pub counter: Counter,
     pub value: Int64,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
 impl SetCounterRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1096,12 +1503,65 @@ impl SetCounterRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize();
+        let value_bytes = self.value.serialize();
+        write(&[
+            major_opcode,
+            SET_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            value_bytes[4],
+            value_bytes[5],
+            value_bytes[6],
+            value_bytes[7],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1118,9 +1578,12 @@ impl SetCounterRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
 impl Request for SetCounterRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
+#[inline]
 pub fn set_counter<Conn>(conn: &Conn, counter: Counter, value: Int64) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1132,6 +1595,9 @@ where
     request0.send(conn)
 }
 
+/// Opcode for the CreateAlarm request
+pub const CREATE_ALARM_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 /// Auxiliary and optional information for the `create_alarm` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CreateAlarmAux {
@@ -1142,6 +1608,7 @@ pub struct CreateAlarmAux {
     pub delta: Option<Int64>,
     pub events: Option<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl CreateAlarmAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -1158,6 +1625,8 @@ impl CreateAlarmAux {
             let remaining = outer_remaining;
             let (value_type, remaining) = u32::try_parse(remaining)?;
             let value_type = value_type.into();
+            #[cfg(feature = "strict_parsing")]
+            VALUETYPE::check_known(value_type)?;
             outer_remaining = remaining;
             Some(value_type)
         } else {
@@ -1175,6 +1644,8 @@ impl CreateAlarmAux {
             let remaining = outer_remaining;
             let (test_type, remaining) = u32::try_parse(remaining)?;
             let test_type = test_type.into();
+            #[cfg(feature = "strict_parsing")]
+            TESTTYPE::check_known(test_type)?;
             outer_remaining = remaining;
             Some(test_type)
         } else {
@@ -1200,6 +1671,7 @@ impl CreateAlarmAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl CreateAlarmAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -1229,6 +1701,7 @@ impl CreateAlarmAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl CreateAlarmAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -1253,6 +1726,7 @@ impl CreateAlarmAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl CreateAlarmAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -1290,13 +1764,13 @@ impl CreateAlarmAux {
     }
 }
 
-/// Opcode for the CreateAlarm request
-pub const CREATE_ALARM_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateAlarmRequest<'input> {
     pub id: Alarm,
     pub value_list: Cow<'input, CreateAlarmAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl<'input> CreateAlarmRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1333,12 +1807,70 @@ impl<'input> CreateAlarmRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            major_opcode,
+            CREATE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1363,9 +1895,12 @@ impl<'input> CreateAlarmRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
 impl<'input> Request for CreateAlarmRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
+#[inline]
 pub fn create_alarm<'c, 'input, Conn>(conn: &'c Conn, id: Alarm, value_list: &'input CreateAlarmAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1377,6 +1912,9 @@ where
     request0.send(conn)
 }
 
+/// Opcode for the ChangeAlarm request
+pub const CHANGE_ALARM_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 /// Auxiliary and optional information for the `change_alarm` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ChangeAlarmAux {
@@ -1387,6 +1925,7 @@ pub struct ChangeAlarmAux {
     pub delta: Option<Int64>,
     pub events: Option<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl ChangeAlarmAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -1403,6 +1942,8 @@ impl ChangeAlarmAux {
             let remaining = outer_remaining;
             let (value_type, remaining) = u32::try_parse(remaining)?;
             let value_type = value_type.into();
+            #[cfg(feature = "strict_parsing")]
+            VALUETYPE::check_known(value_type)?;
             outer_remaining = remaining;
             Some(value_type)
         } else {
@@ -1420,6 +1961,8 @@ impl ChangeAlarmAux {
             let remaining = outer_remaining;
             let (test_type, remaining) = u32::try_parse(remaining)?;
             let test_type = test_type.into();
+            #[cfg(feature = "strict_parsing")]
+            TESTTYPE::check_known(test_type)?;
             outer_remaining = remaining;
             Some(test_type)
         } else {
@@ -1445,6 +1988,7 @@ impl ChangeAlarmAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl ChangeAlarmAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -1474,6 +2018,7 @@ impl ChangeAlarmAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl ChangeAlarmAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -1498,6 +2043,7 @@ impl ChangeAlarmAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl ChangeAlarmAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -1535,13 +2081,13 @@ impl ChangeAlarmAux {
     }
 }
 
-/// Opcode for the ChangeAlarm request
-pub const CHANGE_ALARM_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeAlarmRequest<'input> {
     pub id: Alarm,
     pub value_list: Cow<'input, ChangeAlarmAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl<'input> ChangeAlarmRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1578,19 +2124,77 @@ impl<'input> ChangeAlarmRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != CHANGE_ALARM_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_ALARM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
         let (id, remaining) = Alarm::try_parse(value)?;
         let (value_mask, remaining) = u32::try_parse(remaining)?;
         let (value_list, remaining) = ChangeAlarmAux::try_parse(remaining, value_mask)?;
@@ -1608,9 +2212,12 @@ impl<'input> ChangeAlarmRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
 impl<'input> Request for ChangeAlarmRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
+#[inline]
 pub fn change_alarm<'c, 'input, Conn>(conn: &'c Conn, id: Alarm, value_list: &'input ChangeAlarmAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1624,10 +2231,12 @@ where
 
 /// Opcode for the DestroyAlarm request
 pub const DESTROY_ALARM_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyAlarmRequest {
     pub alarm: Alarm,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
 impl DestroyAlarmRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1654,12 +2263,56 @@ impl DestroyAlarmRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let alarm_bytes = self.alarm.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1674,9 +2327,12 @@ impl DestroyAlarmRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
 impl Request for DestroyAlarmRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
+#[inline]
 pub fn destroy_alarm<Conn>(conn: &Conn, alarm: Alarm) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1689,10 +2345,12 @@ where
 
 /// Opcode for the QueryAlarm request
 pub const QUERY_ALARM_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryAlarmRequest {
     pub alarm: Alarm,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 impl QueryAlarmRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1719,12 +2377,56 @@ impl QueryAlarmRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let alarm_bytes = self.alarm.serialize();
+        write(&[
+            major_opcode,
+            QUERY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryAlarmReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1739,9 +2441,12 @@ impl QueryAlarmRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 impl Request for QueryAlarmRequest {
     type Reply = QueryAlarmReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
+#[inline]
 pub fn query_alarm<Conn>(conn: &Conn, alarm: Alarm) -> Result<Cookie<'_, Conn, QueryAlarmReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1752,6 +2457,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryAlarmReply {
     pub sequence: u16,
@@ -1761,6 +2467,7 @@ pub struct QueryAlarmReply {
     pub events: bool,
     pub state: ALARMSTATE,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
 impl TryParse for QueryAlarmReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1777,9 +2484,12 @@ impl TryParse for QueryAlarmReply {
             return Err(ParseError::InvalidValue);
         }
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        ALARMSTATE::check_known(state)?;
         let result = QueryAlarmReply { sequence, length, trigger, delta, events, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1787,11 +2497,13 @@ impl TryParse for QueryAlarmReply {
 
 /// Opcode for the SetPriority request
 pub const SET_PRIORITY_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPriorityRequest {
     pub id: u32,
     pub priority: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
 impl SetPriorityRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1823,12 +2535,61 @@ impl SetPriorityRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize();
+        let priority_bytes = self.priority.serialize();
+        write(&[
+            major_opcode,
+            SET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            priority_bytes[0],
+            priority_bytes[1],
+            priority_bytes[2],
+            priority_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1845,9 +2606,12 @@ impl SetPriorityRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
 impl Request for SetPriorityRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
+#[inline]
 pub fn set_priority<Conn>(conn: &Conn, id: u32, priority: i32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1861,10 +2625,12 @@ where
 
 /// Opcode for the GetPriority request
 pub const GET_PRIORITY_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPriorityRequest {
     pub id: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 impl GetPriorityRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1891,12 +2657,56 @@ impl GetPriorityRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize();
+        write(&[
+            major_opcode,
+            GET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPriorityReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1911,9 +2721,12 @@ impl GetPriorityRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 impl Request for GetPriorityRequest {
     type Reply = GetPriorityReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
+#[inline]
 pub fn get_priority<Conn>(conn: &Conn, id: u32) -> Result<Cookie<'_, Conn, GetPriorityReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1924,12 +2737,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPriorityReply {
     pub sequence: u16,
     pub length: u32,
     pub priority: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
 impl TryParse for GetPriorityReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1943,7 +2758,8 @@ impl TryParse for GetPriorityReply {
         }
         let result = GetPriorityReply { sequence, length, priority };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1951,12 +2767,14 @@ impl TryParse for GetPriorityReply {
 
 /// Opcode for the CreateFence request
 pub const CREATE_FENCE_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateFenceRequest {
     pub drawable: xproto::Drawable,
     pub fence: Fence,
     pub initially_triggered: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
 impl CreateFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1993,12 +2811,66 @@ impl CreateFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let fence_bytes = self.fence.serialize();
+        let initially_triggered_bytes = self.initially_triggered.serialize();
+        write(&[
+            major_opcode,
+            CREATE_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+            initially_triggered_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2017,9 +2889,12 @@ impl CreateFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
 impl Request for CreateFenceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
+#[inline]
 pub fn create_fence<Conn>(conn: &Conn, drawable: xproto::Drawable, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2034,10 +2909,12 @@ where
 
 /// Opcode for the TriggerFence request
 pub const TRIGGER_FENCE_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TriggerFenceRequest {
     pub fence: Fence,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
 impl TriggerFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2064,12 +2941,56 @@ impl TriggerFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize();
+        write(&[
+            major_opcode,
+            TRIGGER_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2084,9 +3005,12 @@ impl TriggerFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
 impl Request for TriggerFenceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
+#[inline]
 pub fn trigger_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2099,10 +3023,12 @@ where
 
 /// Opcode for the ResetFence request
 pub const RESET_FENCE_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ResetFenceRequest {
     pub fence: Fence,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
 impl ResetFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2129,12 +3055,56 @@ impl ResetFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize();
+        write(&[
+            major_opcode,
+            RESET_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2149,9 +3119,12 @@ impl ResetFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
 impl Request for ResetFenceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
+#[inline]
 pub fn reset_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2164,10 +3137,12 @@ where
 
 /// Opcode for the DestroyFence request
 pub const DESTROY_FENCE_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroyFenceRequest {
     pub fence: Fence,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
 impl DestroyFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2194,12 +3169,56 @@ impl DestroyFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize();
+        write(&[
+            major_opcode,
+            DESTROY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2214,9 +3233,12 @@ impl DestroyFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
 impl Request for DestroyFenceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
+#[inline]
 pub fn destroy_fence<Conn>(conn: &Conn, fence: Fence) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2229,10 +3251,12 @@ where
 
 /// Opcode for the QueryFence request
 pub const QUERY_FENCE_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryFenceRequest {
     pub fence: Fence,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 impl QueryFenceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2259,12 +3283,56 @@ impl QueryFenceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize();
+        write(&[
+            major_opcode,
+            QUERY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryFenceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2279,9 +3347,12 @@ impl QueryFenceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 impl Request for QueryFenceRequest {
     type Reply = QueryFenceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
+#[inline]
 pub fn query_fence<Conn>(conn: &Conn, fence: Fence) -> Result<Cookie<'_, Conn, QueryFenceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2292,12 +3363,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryFenceReply {
     pub sequence: u16,
     pub length: u32,
     pub triggered: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
 impl TryParse for QueryFenceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2312,7 +3385,8 @@ impl TryParse for QueryFenceReply {
         }
         let result = QueryFenceReply { sequence, length, triggered };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2320,10 +3394,12 @@ impl TryParse for QueryFenceReply {
 
 /// Opcode for the AwaitFence request
 pub const AWAIT_FENCE_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AwaitFenceRequest<'input> {
     pub fence_list: Cow<'input, [Fence]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
 impl<'input> AwaitFenceRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2349,12 +3425,58 @@ impl<'input> AwaitFenceRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), fence_list_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            AWAIT_FENCE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        let fence_list_bytes = self.fence_list.serialize();
+        let length_so_far = length_so_far + fence_list_bytes.len();
+        write(&fence_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 4 + self.fence_list.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2366,7 +3488,7 @@ impl<'input> AwaitFenceRequest<'input> {
         // Length is 'everything left in the input'
         let mut fence_list = Vec::new();
         while !remaining.is_empty() {
-            let (v, new_remaining) = Fence::try_parse(value)?;
+            let (v, new_remaining) = Fence::try_parse(remaining)?;
             remaining = new_remaining;
             fence_list.push(v);
         }
@@ -2382,9 +3504,12 @@ impl<'input> AwaitFenceRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
 impl<'input> Request for AwaitFenceRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
+#[inline]
 pub fn await_fence<'c, 'input, Conn>(conn: &'c Conn, fence_list: &'input [Fence]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2508,6 +3633,8 @@ impl TryParse for AlarmNotifyEvent {
         let (state, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        ALARMSTATE::check_known(state)?;
         let result = AlarmNotifyEvent { response_type, kind, sequence, alarm, counter_value, alarm_value, timestamp, state };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2569,82 +3696,122 @@ impl From<AlarmNotifyEvent> for [u8; 32] {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Initialize"))]
+    #[inline]
     fn sync_initialize(&self, desired_major_version: u8, desired_minor_version: u8) -> Result<Cookie<'_, Self, InitializeReply>, ConnectionError>
     {
         initialize(self, desired_major_version, desired_minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ListSystemCounters"))]
+    #[inline]
     fn sync_list_system_counters(&self) -> Result<Cookie<'_, Self, ListSystemCountersReply>, ConnectionError>
     {
         list_system_counters(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateCounter"))]
+    #[inline]
     fn sync_create_counter(&self, id: Counter, initial_value: Int64) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_counter(self, id, initial_value)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyCounter"))]
+    #[inline]
     fn sync_destroy_counter(&self, counter: Counter) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_counter(self, counter)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryCounter"))]
+    #[inline]
     fn sync_query_counter(&self, counter: Counter) -> Result<Cookie<'_, Self, QueryCounterReply>, ConnectionError>
     {
         query_counter(self, counter)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::Await"))]
+    #[inline]
     fn sync_await_<'c, 'input>(&'c self, wait_list: &'input [Waitcondition]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         await_(self, wait_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeCounter"))]
+    #[inline]
     fn sync_change_counter(&self, counter: Counter, amount: Int64) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         change_counter(self, counter, amount)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetCounter"))]
+    #[inline]
     fn sync_set_counter(&self, counter: Counter, value: Int64) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_counter(self, counter, value)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateAlarm"))]
+    #[inline]
     fn sync_create_alarm<'c, 'input>(&'c self, id: Alarm, value_list: &'input CreateAlarmAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_alarm(self, id, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ChangeAlarm"))]
+    #[inline]
     fn sync_change_alarm<'c, 'input>(&'c self, id: Alarm, value_list: &'input ChangeAlarmAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_alarm(self, id, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyAlarm"))]
+    #[inline]
     fn sync_destroy_alarm(&self, alarm: Alarm) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_alarm(self, alarm)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryAlarm"))]
+    #[inline]
     fn sync_query_alarm(&self, alarm: Alarm) -> Result<Cookie<'_, Self, QueryAlarmReply>, ConnectionError>
     {
         query_alarm(self, alarm)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::SetPriority"))]
+    #[inline]
     fn sync_set_priority(&self, id: u32, priority: i32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_priority(self, id, priority)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::GetPriority"))]
+    #[inline]
     fn sync_get_priority(&self, id: u32) -> Result<Cookie<'_, Self, GetPriorityReply>, ConnectionError>
     {
         get_priority(self, id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::CreateFence"))]
+    #[inline]
     fn sync_create_fence(&self, drawable: xproto::Drawable, fence: Fence, initially_triggered: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_fence(self, drawable, fence, initially_triggered)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::TriggerFence"))]
+    #[inline]
     fn sync_trigger_fence(&self, fence: Fence) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         trigger_fence(self, fence)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::ResetFence"))]
+    #[inline]
     fn sync_reset_fence(&self, fence: Fence) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         reset_fence(self, fence)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::DestroyFence"))]
+    #[inline]
     fn sync_destroy_fence(&self, fence: Fence) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_fence(self, fence)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::QueryFence"))]
+    #[inline]
     fn sync_query_fence(&self, fence: Fence) -> Result<Cookie<'_, Self, QueryFenceReply>, ConnectionError>
     {
         query_fence(self, fence)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "sync::AwaitFence"))]
+    #[inline]
     fn sync_await_fence<'c, 'input>(&'c self, fence_list: &'input [Fence]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         await_fence(self, fence_list)
@@ -4,6 +4,8 @@
 //! Bindings to the `XF86VidMode` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -348,8 +350,10 @@ impl Serialize for ModeInfo {
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 impl QueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -371,12 +375,51 @@ impl QueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -389,9 +432,12 @@ impl QueryVersionRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 impl Request for QueryVersionRequest {
     type Reply = QueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
+#[inline]
 pub fn query_version<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -400,6 +446,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryVersionReply {
     pub sequence: u16,
@@ -407,6 +454,7 @@ pub struct QueryVersionReply {
     pub major_version: u16,
     pub minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -421,7 +469,8 @@ impl TryParse for QueryVersionReply {
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -429,10 +478,12 @@ impl TryParse for QueryVersionReply {
 
 /// Opcode for the GetModeLine request
 pub const GET_MODE_LINE_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetModeLineRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 impl GetModeLineRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -459,12 +510,56 @@ impl GetModeLineRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetModeLineReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -480,9 +575,12 @@ impl GetModeLineRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 impl Request for GetModeLineRequest {
     type Reply = GetModeLineReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
+#[inline]
 pub fn get_mode_line<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetModeLineReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -493,6 +591,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetModeLineReply {
     pub sequence: u16,
@@ -510,6 +609,7 @@ pub struct GetModeLineReply {
     pub flags: u32,
     pub private: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 impl TryParse for GetModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -538,11 +638,13 @@ impl TryParse for GetModeLineReply {
         }
         let result = GetModeLineReply { sequence, length, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
 impl GetModeLineReply {
     /// Get the value of the `privsize` field.
     ///
@@ -561,6 +663,7 @@ impl GetModeLineReply {
 
 /// Opcode for the ModModeLine request
 pub const MOD_MODE_LINE_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModModeLineRequest<'input> {
     pub screen: u32,
@@ -576,6 +679,7 @@ pub struct ModModeLineRequest<'input> {
     pub flags: u32,
     pub private: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
 impl<'input> ModModeLineRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -657,12 +761,114 @@ impl<'input> ModModeLineRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.private, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = self.flags.serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        write(&[
+            major_opcode,
+            MOD_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 48;
+        let length_so_far = length_so_far + self.private.len();
+        write(&self.private[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 48 + self.private.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -719,9 +925,12 @@ impl<'input> ModModeLineRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
 impl<'input> Request for ModModeLineRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
+#[inline]
 pub fn mod_mode_line<'c, 'input, Conn, A>(conn: &'c Conn, screen: u32, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -747,11 +956,13 @@ where
 
 /// Opcode for the SwitchMode request
 pub const SWITCH_MODE_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwitchModeRequest {
     pub screen: u16,
     pub zoom: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
 impl SwitchModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -779,12 +990,57 @@ impl SwitchModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let zoom_bytes = self.zoom.serialize();
+        write(&[
+            major_opcode,
+            SWITCH_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            zoom_bytes[0],
+            zoom_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -801,9 +1057,12 @@ impl SwitchModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
 impl Request for SwitchModeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
+#[inline]
 pub fn switch_mode<Conn>(conn: &Conn, screen: u16, zoom: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -817,10 +1076,12 @@ where
 
 /// Opcode for the GetMonitor request
 pub const GET_MONITOR_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMonitorRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 impl GetMonitorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -847,12 +1108,56 @@ impl GetMonitorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_MONITOR_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMonitorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -868,9 +1173,12 @@ impl GetMonitorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 impl Request for GetMonitorRequest {
     type Reply = GetMonitorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
+#[inline]
 pub fn get_monitor<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetMonitorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -881,6 +1189,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMonitorReply {
     pub sequence: u16,
@@ -891,6 +1200,7 @@ pub struct GetMonitorReply {
     pub alignment_pad: Vec<u8>,
     pub model: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 impl TryParse for GetMonitorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -916,11 +1226,13 @@ impl TryParse for GetMonitorReply {
         }
         let result = GetMonitorReply { sequence, length, hsync, vsync, vendor, alignment_pad, model };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
 impl GetMonitorReply {
     /// Get the value of the `vendor_length` field.
     ///
@@ -978,11 +1290,13 @@ impl GetMonitorReply {
 
 /// Opcode for the LockModeSwitch request
 pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LockModeSwitchRequest {
     pub screen: u16,
     pub lock: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
 impl LockModeSwitchRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1010,12 +1324,57 @@ impl LockModeSwitchRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let lock_bytes = self.lock.serialize();
+        write(&[
+            major_opcode,
+            LOCK_MODE_SWITCH_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            lock_bytes[0],
+            lock_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1032,9 +1391,12 @@ impl LockModeSwitchRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
 impl Request for LockModeSwitchRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
+#[inline]
 pub fn lock_mode_switch<Conn>(conn: &Conn, screen: u16, lock: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1048,10 +1410,12 @@ where
 
 /// Opcode for the GetAllModeLines request
 pub const GET_ALL_MODE_LINES_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetAllModeLinesRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 impl GetAllModeLinesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1078,12 +1442,56 @@ impl GetAllModeLinesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_ALL_MODE_LINES_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetAllModeLinesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1099,9 +1507,12 @@ impl GetAllModeLinesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 impl Request for GetAllModeLinesRequest {
     type Reply = GetAllModeLinesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
+#[inline]
 pub fn get_all_mode_lines<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetAllModeLinesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1112,12 +1523,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetAllModeLinesReply {
     pub sequence: u16,
     pub length: u32,
     pub modeinfo: Vec<ModeInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 impl TryParse for GetAllModeLinesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1133,11 +1546,13 @@ impl TryParse for GetAllModeLinesReply {
         }
         let result = GetAllModeLinesReply { sequence, length, modeinfo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
 impl GetAllModeLinesReply {
     /// Get the value of the `modecount` field.
     ///
@@ -1156,6 +1571,7 @@ impl GetAllModeLinesReply {
 
 /// Opcode for the AddModeLine request
 pub const ADD_MODE_LINE_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddModeLineRequest<'input> {
     pub screen: u32,
@@ -1183,6 +1599,7 @@ pub struct AddModeLineRequest<'input> {
     pub after_flags: u32,
     pub private: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
 impl<'input> AddModeLineRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1320,65 +1737,223 @@ impl<'input> AddModeLineRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.private, padding0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != ADD_MODE_LINE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (after_dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (after_hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (after_hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (after_hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (after_htotal, remaining) = u16::try_parse(remaining)?;
-        let (after_hskew, remaining) = u16::try_parse(remaining)?;
-        let (after_vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (after_vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (after_vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (after_vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (after_flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
-        let _ = remaining;
-        Ok(AddModeLineRequest {
-            screen,
-            dotclock,
-            hdisplay,
-            hsyncstart,
-            hsyncend,
-            htotal,
-            hskew,
-            vdisplay,
-            vsyncstart,
-            vsyncend,
-            vtotal,
-            flags,
-            after_dotclock,
-            after_hdisplay,
-            after_hsyncstart,
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = self.flags.serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        let after_dotclock_bytes = self.after_dotclock.serialize();
+        let after_hdisplay_bytes = self.after_hdisplay.serialize();
+        let after_hsyncstart_bytes = self.after_hsyncstart.serialize();
+        let after_hsyncend_bytes = self.after_hsyncend.serialize();
+        let after_htotal_bytes = self.after_htotal.serialize();
+        let after_hskew_bytes = self.after_hskew.serialize();
+        let after_vdisplay_bytes = self.after_vdisplay.serialize();
+        let after_vsyncstart_bytes = self.after_vsyncstart.serialize();
+        let after_vsyncend_bytes = self.after_vsyncend.serialize();
+        let after_vtotal_bytes = self.after_vtotal.serialize();
+        let after_flags_bytes = self.after_flags.serialize();
+        write(&[
+            major_opcode,
+            ADD_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+            after_dotclock_bytes[0],
+            after_dotclock_bytes[1],
+            after_dotclock_bytes[2],
+            after_dotclock_bytes[3],
+            after_hdisplay_bytes[0],
+            after_hdisplay_bytes[1],
+            after_hsyncstart_bytes[0],
+            after_hsyncstart_bytes[1],
+            after_hsyncend_bytes[0],
+            after_hsyncend_bytes[1],
+            after_htotal_bytes[0],
+            after_htotal_bytes[1],
+            after_hskew_bytes[0],
+            after_hskew_bytes[1],
+            after_vdisplay_bytes[0],
+            after_vdisplay_bytes[1],
+            after_vsyncstart_bytes[0],
+            after_vsyncstart_bytes[1],
+            after_vsyncend_bytes[0],
+            after_vsyncend_bytes[1],
+            after_vtotal_bytes[0],
+            after_vtotal_bytes[1],
+            0,
+            0,
+            after_flags_bytes[0],
+            after_flags_bytes[1],
+            after_flags_bytes[2],
+            after_flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 92;
+        let length_so_far = length_so_far + self.private.len();
+        write(&self.private[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 92 + self.private.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse(value)?;
+        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
+        let (hdisplay, remaining) = u16::try_parse(remaining)?;
+        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (hsyncend, remaining) = u16::try_parse(remaining)?;
+        let (htotal, remaining) = u16::try_parse(remaining)?;
+        let (hskew, remaining) = u16::try_parse(remaining)?;
+        let (vdisplay, remaining) = u16::try_parse(remaining)?;
+        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (vsyncend, remaining) = u16::try_parse(remaining)?;
+        let (vtotal, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (after_dotclock, remaining) = Dotclock::try_parse(remaining)?;
+        let (after_hdisplay, remaining) = u16::try_parse(remaining)?;
+        let (after_hsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (after_hsyncend, remaining) = u16::try_parse(remaining)?;
+        let (after_htotal, remaining) = u16::try_parse(remaining)?;
+        let (after_hskew, remaining) = u16::try_parse(remaining)?;
+        let (after_vdisplay, remaining) = u16::try_parse(remaining)?;
+        let (after_vsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (after_vsyncend, remaining) = u16::try_parse(remaining)?;
+        let (after_vtotal, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (after_flags, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let _ = remaining;
+        Ok(AddModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            after_dotclock,
+            after_hdisplay,
+            after_hsyncstart,
             after_hsyncend,
             after_htotal,
             after_hskew,
@@ -1420,9 +1995,12 @@ impl<'input> AddModeLineRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
 impl<'input> Request for AddModeLineRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
+#[inline]
 pub fn add_mode_line<'c, 'input, Conn, A, B>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, after_dotclock: Dotclock, after_hdisplay: u16, after_hsyncstart: u16, after_hsyncend: u16, after_htotal: u16, after_hskew: u16, after_vdisplay: u16, after_vsyncstart: u16, after_vsyncend: u16, after_vtotal: u16, after_flags: B, private: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1462,6 +2040,7 @@ where
 
 /// Opcode for the DeleteModeLine request
 pub const DELETE_MODE_LINE_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeleteModeLineRequest<'input> {
     pub screen: u32,
@@ -1478,6 +2057,7 @@ pub struct DeleteModeLineRequest<'input> {
     pub flags: u32,
     pub private: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
 impl<'input> DeleteModeLineRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1564,12 +2144,119 @@ impl<'input> DeleteModeLineRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.private, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = self.flags.serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        write(&[
+            major_opcode,
+            DELETE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 52;
+        let length_so_far = length_so_far + self.private.len();
+        write(&self.private[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 52 + self.private.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1629,9 +2316,12 @@ impl<'input> DeleteModeLineRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
 impl<'input> Request for DeleteModeLineRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
+#[inline]
 pub fn delete_mode_line<'c, 'input, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1658,6 +2348,7 @@ where
 
 /// Opcode for the ValidateModeLine request
 pub const VALIDATE_MODE_LINE_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ValidateModeLineRequest<'input> {
     pub screen: u32,
@@ -1674,6 +2365,7 @@ pub struct ValidateModeLineRequest<'input> {
     pub flags: u32,
     pub private: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 impl<'input> ValidateModeLineRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1760,12 +2452,119 @@ impl<'input> ValidateModeLineRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.private, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = self.flags.serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        write(&[
+            major_opcode,
+            VALIDATE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 52;
+        let length_so_far = length_so_far + self.private.len();
+        write(&self.private[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 52 + self.private.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ValidateModeLineReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1825,9 +2624,12 @@ impl<'input> ValidateModeLineRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 impl<'input> Request for ValidateModeLineRequest<'input> {
     type Reply = ValidateModeLineReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
+#[inline]
 pub fn validate_mode_line<'c, 'input, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<Cookie<'c, Conn, ValidateModeLineReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1852,12 +2654,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidateModeLineReply {
     pub sequence: u16,
     pub length: u32,
     pub status: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
 impl TryParse for ValidateModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1872,7 +2676,8 @@ impl TryParse for ValidateModeLineReply {
         }
         let result = ValidateModeLineReply { sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1880,6 +2685,7 @@ impl TryParse for ValidateModeLineReply {
 
 /// Opcode for the SwitchToMode request
 pub const SWITCH_TO_MODE_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SwitchToModeRequest<'input> {
     pub screen: u32,
@@ -1896,6 +2702,7 @@ pub struct SwitchToModeRequest<'input> {
     pub flags: u32,
     pub private: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
 impl<'input> SwitchToModeRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1982,12 +2789,119 @@ impl<'input> SwitchToModeRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.private, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = self.flags.serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        write(&[
+            major_opcode,
+            SWITCH_TO_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 52;
+        let length_so_far = length_so_far + self.private.len();
+        write(&self.private[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 52 + self.private.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2047,9 +2961,12 @@ impl<'input> SwitchToModeRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
 impl<'input> Request for SwitchToModeRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
+#[inline]
 pub fn switch_to_mode<'c, 'input, Conn, A>(conn: &'c Conn, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2076,10 +2993,12 @@ where
 
 /// Opcode for the GetViewPort request
 pub const GET_VIEW_PORT_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetViewPortRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 impl GetViewPortRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2106,12 +3025,56 @@ impl GetViewPortRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetViewPortReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2127,9 +3090,12 @@ impl GetViewPortRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 impl Request for GetViewPortRequest {
     type Reply = GetViewPortReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
+#[inline]
 pub fn get_view_port<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetViewPortReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2140,6 +3106,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetViewPortReply {
     pub sequence: u16,
@@ -2147,6 +3114,7 @@ pub struct GetViewPortReply {
     pub x: u32,
     pub y: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
 impl TryParse for GetViewPortReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2162,7 +3130,8 @@ impl TryParse for GetViewPortReply {
         }
         let result = GetViewPortReply { sequence, length, x, y };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2170,12 +3139,14 @@ impl TryParse for GetViewPortReply {
 
 /// Opcode for the SetViewPort request
 pub const SET_VIEW_PORT_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetViewPortRequest {
     pub screen: u16,
     pub x: u32,
     pub y: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
 impl SetViewPortRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2212,12 +3183,66 @@ impl SetViewPortRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            major_opcode,
+            SET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2237,9 +3262,12 @@ impl SetViewPortRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
 impl Request for SetViewPortRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
+#[inline]
 pub fn set_view_port<Conn>(conn: &Conn, screen: u16, x: u32, y: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2254,10 +3282,12 @@ where
 
 /// Opcode for the GetDotClocks request
 pub const GET_DOT_CLOCKS_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDotClocksRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 impl GetDotClocksRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2284,12 +3314,56 @@ impl GetDotClocksRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_DOT_CLOCKS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDotClocksReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2305,9 +3379,12 @@ impl GetDotClocksRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 impl Request for GetDotClocksRequest {
     type Reply = GetDotClocksReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
+#[inline]
 pub fn get_dot_clocks<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetDotClocksReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2318,6 +3395,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDotClocksReply {
     pub sequence: u16,
@@ -2327,6 +3405,7 @@ pub struct GetDotClocksReply {
     pub maxclocks: u32,
     pub clock: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
 impl TryParse for GetDotClocksReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2344,7 +3423,8 @@ impl TryParse for GetDotClocksReply {
         }
         let result = GetDotClocksReply { sequence, length, flags, clocks, maxclocks, clock };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2352,11 +3432,13 @@ impl TryParse for GetDotClocksReply {
 
 /// Opcode for the SetClientVersion request
 pub const SET_CLIENT_VERSION_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetClientVersionRequest {
     pub major: u16,
     pub minor: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
 impl SetClientVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2384,12 +3466,57 @@ impl SetClientVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_bytes = self.major.serialize();
+        let minor_bytes = self.minor.serialize();
+        write(&[
+            major_opcode,
+            SET_CLIENT_VERSION_REQUEST,
+            0,
+            0,
+            major_bytes[0],
+            major_bytes[1],
+            minor_bytes[0],
+            minor_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2406,9 +3533,12 @@ impl SetClientVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
 impl Request for SetClientVersionRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
+#[inline]
 pub fn set_client_version<Conn>(conn: &Conn, major: u16, minor: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2422,6 +3552,7 @@ where
 
 /// Opcode for the SetGamma request
 pub const SET_GAMMA_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetGammaRequest {
     pub screen: u16,
@@ -2429,6 +3560,7 @@ pub struct SetGammaRequest {
     pub green: u32,
     pub blue: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
 impl SetGammaRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2482,12 +3614,83 @@ impl SetGammaRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let red_bytes = self.red.serialize();
+        let green_bytes = self.green.serialize();
+        let blue_bytes = self.blue.serialize();
+        write(&[
+            major_opcode,
+            SET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            red_bytes[0],
+            red_bytes[1],
+            red_bytes[2],
+            red_bytes[3],
+            green_bytes[0],
+            green_bytes[1],
+            green_bytes[2],
+            green_bytes[3],
+            blue_bytes[0],
+            blue_bytes[1],
+            blue_bytes[2],
+            blue_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2510,9 +3713,12 @@ impl SetGammaRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
 impl Request for SetGammaRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
+#[inline]
 pub fn set_gamma<Conn>(conn: &Conn, screen: u16, red: u32, green: u32, blue: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2528,10 +3734,12 @@ where
 
 /// Opcode for the GetGamma request
 pub const GET_GAMMA_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 impl GetGammaRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2582,12 +3790,80 @@ impl GetGammaRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetGammaReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2603,9 +3879,12 @@ impl GetGammaRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 impl Request for GetGammaRequest {
     type Reply = GetGammaReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
+#[inline]
 pub fn get_gamma<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetGammaReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2616,6 +3895,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaReply {
     pub sequence: u16,
@@ -2624,6 +3904,7 @@ pub struct GetGammaReply {
     pub green: u32,
     pub blue: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
 impl TryParse for GetGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2640,7 +3921,8 @@ impl TryParse for GetGammaReply {
         }
         let result = GetGammaReply { sequence, length, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2648,11 +3930,13 @@ impl TryParse for GetGammaReply {
 
 /// Opcode for the GetGammaRamp request
 pub const GET_GAMMA_RAMP_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaRampRequest {
     pub screen: u16,
     pub size: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 impl GetGammaRampRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2680,12 +3964,57 @@ impl GetGammaRampRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let size_bytes = self.size.serialize();
+        write(&[
+            major_opcode,
+            GET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetGammaRampReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2702,9 +4031,12 @@ impl GetGammaRampRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 impl Request for GetGammaRampRequest {
     type Reply = GetGammaRampReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
+#[inline]
 pub fn get_gamma_ramp<Conn>(conn: &Conn, screen: u16, size: u16) -> Result<Cookie<'_, Conn, GetGammaRampReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2716,6 +4048,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetGammaRampReply {
     pub sequence: u16,
@@ -2725,6 +4058,7 @@ pub struct GetGammaRampReply {
     pub green: Vec<u16>,
     pub blue: Vec<u16>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
 impl TryParse for GetGammaRampReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2742,7 +4076,8 @@ impl TryParse for GetGammaRampReply {
         }
         let result = GetGammaRampReply { sequence, length, size, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2750,6 +4085,7 @@ impl TryParse for GetGammaRampReply {
 
 /// Opcode for the SetGammaRamp request
 pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetGammaRampRequest<'input> {
     pub screen: u16,
@@ -2758,6 +4094,7 @@ pub struct SetGammaRampRequest<'input> {
     pub green: Cow<'input, [u16]>,
     pub blue: Cow<'input, [u16]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
 impl<'input> SetGammaRampRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2796,12 +4133,73 @@ impl<'input> SetGammaRampRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), red_bytes.into(), green_bytes.into(), blue_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let size_bytes = self.size.serialize();
+        write(&[
+            major_opcode,
+            SET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(self.red.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
+        let red_bytes = self.red.serialize();
+        let length_so_far = length_so_far + red_bytes.len();
+        write(&red_bytes[..]);
+        assert_eq!(self.green.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
+        let green_bytes = self.green.serialize();
+        let length_so_far = length_so_far + green_bytes.len();
+        write(&green_bytes[..]);
+        assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
+        let blue_bytes = self.blue.serialize();
+        let length_so_far = length_so_far + blue_bytes.len();
+        write(&blue_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.red.len() * 2 + self.green.len() * 2 + self.blue.len() * 2;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3]), IoSlice::new(&bytes[4])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2834,9 +4232,12 @@ impl<'input> SetGammaRampRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
 impl<'input> Request for SetGammaRampRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
+#[inline]
 pub fn set_gamma_ramp<'c, 'input, Conn>(conn: &'c Conn, screen: u16, size: u16, red: &'input [u16], green: &'input [u16], blue: &'input [u16]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2853,10 +4254,12 @@ where
 
 /// Opcode for the GetGammaRampSize request
 pub const GET_GAMMA_RAMP_SIZE_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaRampSizeRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 impl GetGammaRampSizeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2883,12 +4286,56 @@ impl GetGammaRampSizeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_GAMMA_RAMP_SIZE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetGammaRampSizeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2904,9 +4351,12 @@ impl GetGammaRampSizeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 impl Request for GetGammaRampSizeRequest {
     type Reply = GetGammaRampSizeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
+#[inline]
 pub fn get_gamma_ramp_size<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetGammaRampSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2917,12 +4367,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetGammaRampSizeReply {
     pub sequence: u16,
     pub length: u32,
     pub size: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
 impl TryParse for GetGammaRampSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2937,7 +4389,8 @@ impl TryParse for GetGammaRampSizeReply {
         }
         let result = GetGammaRampSizeReply { sequence, length, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2945,10 +4398,12 @@ impl TryParse for GetGammaRampSizeReply {
 
 /// Opcode for the GetPermissions request
 pub const GET_PERMISSIONS_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPermissionsRequest {
     pub screen: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 impl GetPermissionsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2975,12 +4430,56 @@ impl GetPermissionsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        write(&[
+            major_opcode,
+            GET_PERMISSIONS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPermissionsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2996,9 +4495,12 @@ impl GetPermissionsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 impl Request for GetPermissionsRequest {
     type Reply = GetPermissionsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
+#[inline]
 pub fn get_permissions<Conn>(conn: &Conn, screen: u16) -> Result<Cookie<'_, Conn, GetPermissionsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3009,12 +4511,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPermissionsReply {
     pub sequence: u16,
     pub length: u32,
     pub permissions: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
 impl TryParse for GetPermissionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3029,7 +4533,8 @@ impl TryParse for GetPermissionsReply {
         }
         let result = GetPermissionsReply { sequence, length, permissions };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -3058,36 +4563,52 @@ pub const ZOOM_LOCKED_ERROR: u8 = 6;
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::QueryVersion"))]
+    #[inline]
     fn xf86vidmode_query_version(&self) -> Result<Cookie<'_, Self, QueryVersionReply>, ConnectionError>
     {
         query_version(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetModeLine"))]
+    #[inline]
     fn xf86vidmode_get_mode_line(&self, screen: u16) -> Result<Cookie<'_, Self, GetModeLineReply>, ConnectionError>
     {
         get_mode_line(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ModModeLine"))]
+    #[inline]
     fn xf86vidmode_mod_mode_line<'c, 'input, A>(&'c self, screen: u32, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         mod_mode_line(self, screen, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchMode"))]
+    #[inline]
     fn xf86vidmode_switch_mode(&self, screen: u16, zoom: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         switch_mode(self, screen, zoom)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetMonitor"))]
+    #[inline]
     fn xf86vidmode_get_monitor(&self, screen: u16) -> Result<Cookie<'_, Self, GetMonitorReply>, ConnectionError>
     {
         get_monitor(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::LockModeSwitch"))]
+    #[inline]
     fn xf86vidmode_lock_mode_switch(&self, screen: u16, lock: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         lock_mode_switch(self, screen, lock)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetAllModeLines"))]
+    #[inline]
     fn xf86vidmode_get_all_mode_lines(&self, screen: u16) -> Result<Cookie<'_, Self, GetAllModeLinesReply>, ConnectionError>
     {
         get_all_mode_lines(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::AddModeLine"))]
+    #[inline]
     fn xf86vidmode_add_mode_line<'c, 'input, A, B>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, after_dotclock: Dotclock, after_hdisplay: u16, after_hsyncstart: u16, after_hsyncend: u16, after_htotal: u16, after_hskew: u16, after_vdisplay: u16, after_vsyncstart: u16, after_vsyncend: u16, after_vtotal: u16, after_flags: B, private: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u32>,
@@ -3095,60 +4616,86 @@ pub trait ConnectionExt: RequestConnection {
     {
         add_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, after_dotclock, after_hdisplay, after_hsyncstart, after_hsyncend, after_htotal, after_hskew, after_vdisplay, after_vsyncstart, after_vsyncend, after_vtotal, after_flags, private)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::DeleteModeLine"))]
+    #[inline]
     fn xf86vidmode_delete_mode_line<'c, 'input, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         delete_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::ValidateModeLine"))]
+    #[inline]
     fn xf86vidmode_validate_mode_line<'c, 'input, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<Cookie<'c, Self, ValidateModeLineReply>, ConnectionError>
     where
         A: Into<u32>,
     {
         validate_mode_line(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SwitchToMode"))]
+    #[inline]
     fn xf86vidmode_switch_to_mode<'c, 'input, A>(&'c self, screen: u32, dotclock: Dotclock, hdisplay: u16, hsyncstart: u16, hsyncend: u16, htotal: u16, hskew: u16, vdisplay: u16, vsyncstart: u16, vsyncend: u16, vtotal: u16, flags: A, private: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         switch_to_mode(self, screen, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetViewPort"))]
+    #[inline]
     fn xf86vidmode_get_view_port(&self, screen: u16) -> Result<Cookie<'_, Self, GetViewPortReply>, ConnectionError>
     {
         get_view_port(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetViewPort"))]
+    #[inline]
     fn xf86vidmode_set_view_port(&self, screen: u16, x: u32, y: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_view_port(self, screen, x, y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetDotClocks"))]
+    #[inline]
     fn xf86vidmode_get_dot_clocks(&self, screen: u16) -> Result<Cookie<'_, Self, GetDotClocksReply>, ConnectionError>
     {
         get_dot_clocks(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetClientVersion"))]
+    #[inline]
     fn xf86vidmode_set_client_version(&self, major: u16, minor: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_client_version(self, major, minor)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGamma"))]
+    #[inline]
     fn xf86vidmode_set_gamma(&self, screen: u16, red: u32, green: u32, blue: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_gamma(self, screen, red, green, blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGamma"))]
+    #[inline]
     fn xf86vidmode_get_gamma(&self, screen: u16) -> Result<Cookie<'_, Self, GetGammaReply>, ConnectionError>
     {
         get_gamma(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRamp"))]
+    #[inline]
     fn xf86vidmode_get_gamma_ramp(&self, screen: u16, size: u16) -> Result<Cookie<'_, Self, GetGammaRampReply>, ConnectionError>
     {
         get_gamma_ramp(self, screen, size)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::SetGammaRamp"))]
+    #[inline]
     fn xf86vidmode_set_gamma_ramp<'c, 'input>(&'c self, screen: u16, size: u16, red: &'input [u16], green: &'input [u16], blue: &'input [u16]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_gamma_ramp(self, screen, size, red, green, blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetGammaRampSize"))]
+    #[inline]
     fn xf86vidmode_get_gamma_ramp_size(&self, screen: u16) -> Result<Cookie<'_, Self, GetGammaRampSizeReply>, ConnectionError>
     {
         get_gamma_ramp_size(self, screen)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xf86vidmode::GetPermissions"))]
+    #[inline]
     fn xf86vidmode_get_permissions(&self, screen: u16) -> Result<Cookie<'_, Self, GetPermissionsReply>, ConnectionError>
     {
         get_permissions(self, screen)
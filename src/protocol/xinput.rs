@@ -4,6 +4,8 @@
 //! Bindings to the `Input` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -79,10 +81,12 @@ impl Serialize for Fp3232 {
 
 /// Opcode for the GetExtensionVersion request
 pub const GET_EXTENSION_VERSION_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetExtensionVersionRequest<'input> {
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 impl<'input> GetExtensionVersionRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -113,12 +117,63 @@ impl<'input> GetExtensionVersionRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.name, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            major_opcode,
+            GET_EXTENSION_VERSION_REQUEST,
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetExtensionVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -141,9 +196,12 @@ impl<'input> GetExtensionVersionRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 impl<'input> Request for GetExtensionVersionRequest<'input> {
     type Reply = GetExtensionVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
+#[inline]
 pub fn get_extension_version<'c, 'input, Conn>(conn: &'c Conn, name: &'input [u8]) -> Result<Cookie<'c, Conn, GetExtensionVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -154,6 +212,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetExtensionVersionReply {
     pub xi_reply_type: u8,
@@ -163,6 +222,7 @@ pub struct GetExtensionVersionReply {
     pub server_minor: u16,
     pub present: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
 impl TryParse for GetExtensionVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -179,7 +239,8 @@ impl TryParse for GetExtensionVersionReply {
         }
         let result = GetExtensionVersionReply { xi_reply_type, sequence, length, server_major, server_minor, present };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -248,6 +309,17 @@ impl std::fmt::Debug for DeviceUse  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceUse {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct InputClass(u8);
@@ -316,6 +388,17 @@ impl std::fmt::Debug for InputClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl InputClass {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=6) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ValuatorMode(u8);
@@ -374,6 +457,17 @@ impl std::fmt::Debug for ValuatorMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ValuatorMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeviceInfo {
@@ -390,6 +484,8 @@ impl TryParse for DeviceInfo {
         let (device_use, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let device_use = device_use.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceUse::check_known(device_use)?;
         let result = DeviceInfo { device_type, device_id, num_class_info, device_use };
         Ok((result, remaining))
     }
@@ -439,6 +535,8 @@ impl TryParse for KeyInfo {
         let (num_keys, remaining) = u16::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = KeyInfo { class_id, len, min_keycode, max_keycode, num_keys };
         Ok((result, remaining))
     }
@@ -485,6 +583,8 @@ impl TryParse for ButtonInfo {
         let (len, remaining) = u8::try_parse(remaining)?;
         let (num_buttons, remaining) = u16::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = ButtonInfo { class_id, len, num_buttons };
         Ok((result, remaining))
     }
@@ -571,7 +671,11 @@ impl TryParse for ValuatorInfo {
         let (motion_size, remaining) = u32::try_parse(remaining)?;
         let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(mode)?;
         let result = ValuatorInfo { class_id, len, mode, motion_size, axes };
         Ok((result, remaining))
     }
@@ -687,6 +791,8 @@ impl TryParse for InputInfoInfoValuator {
         let (motion_size, remaining) = u32::try_parse(remaining)?;
         let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(mode)?;
         let result = InputInfoInfoValuator { mode, motion_size, axes };
         Ok((result, remaining))
     }
@@ -761,7 +867,10 @@ impl InputInfoInfo {
             parse_result = Some(InputInfoInfo::Valuator(valuator));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((InputInfoInfo::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -824,6 +933,8 @@ impl TryParse for InputInfo {
         let (class_id, remaining) = u8::try_parse(remaining)?;
         let (len, remaining) = u8::try_parse(remaining)?;
         let (info, remaining) = InputInfoInfo::try_parse(remaining, class_id)?;
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id.into())?;
         let result = InputInfo { len, info };
         Ok((result, remaining))
     }
@@ -889,8 +1000,10 @@ impl DeviceName {
 
 /// Opcode for the ListInputDevices request
 pub const LIST_INPUT_DEVICES_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListInputDevicesRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 impl ListInputDevicesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -912,12 +1025,51 @@ impl ListInputDevicesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            major_opcode,
+            LIST_INPUT_DEVICES_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListInputDevicesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -930,9 +1082,12 @@ impl ListInputDevicesRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 impl Request for ListInputDevicesRequest {
     type Reply = ListInputDevicesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
+#[inline]
 pub fn list_input_devices<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListInputDevicesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -941,6 +1096,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListInputDevicesReply {
     pub xi_reply_type: u8,
@@ -950,6 +1106,7 @@ pub struct ListInputDevicesReply {
     pub infos: Vec<InputInfo>,
     pub names: Vec<xproto::Str>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 impl TryParse for ListInputDevicesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -963,20 +1120,19 @@ impl TryParse for ListInputDevicesReply {
         let (devices, remaining) = crate::x11_utils::parse_list::<DeviceInfo>(remaining, devices_len.try_to_usize()?)?;
         let (infos, remaining) = crate::x11_utils::parse_list::<InputInfo>(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let (names, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, devices_len.try_to_usize()?)?;
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
         let result = ListInputDevicesReply { xi_reply_type, sequence, length, devices, infos, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
 impl ListInputDevicesReply {
     /// Get the value of the `devices_len` field.
     ///
@@ -1005,6 +1161,8 @@ impl TryParse for InputClassInfo {
         let (class_id, remaining) = u8::try_parse(remaining)?;
         let (event_type_base, remaining) = EventTypeBase::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = InputClassInfo { class_id, event_type_base };
         Ok((result, remaining))
     }
@@ -1028,10 +1186,12 @@ impl Serialize for InputClassInfo {
 
 /// Opcode for the OpenDevice request
 pub const OPEN_DEVICE_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OpenDeviceRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 impl OpenDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1058,12 +1218,56 @@ impl OpenDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            OPEN_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, OpenDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1079,9 +1283,12 @@ impl OpenDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 impl Request for OpenDeviceRequest {
     type Reply = OpenDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
+#[inline]
 pub fn open_device<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, OpenDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1092,6 +1299,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OpenDeviceReply {
     pub xi_reply_type: u8,
@@ -1099,6 +1307,7 @@ pub struct OpenDeviceReply {
     pub length: u32,
     pub class_info: Vec<InputClassInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 impl TryParse for OpenDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1110,20 +1319,19 @@ impl TryParse for OpenDeviceReply {
         let (num_classes, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
         let (class_info, remaining) = crate::x11_utils::parse_list::<InputClassInfo>(remaining, num_classes.try_to_usize()?)?;
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
         let result = OpenDeviceReply { xi_reply_type, sequence, length, class_info };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
 impl OpenDeviceReply {
     /// Get the value of the `num_classes` field.
     ///
@@ -1142,10 +1350,12 @@ impl OpenDeviceReply {
 
 /// Opcode for the CloseDevice request
 pub const CLOSE_DEVICE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CloseDeviceRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
 impl CloseDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1172,12 +1382,56 @@ impl CloseDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            CLOSE_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1193,9 +1447,12 @@ impl CloseDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
 impl Request for CloseDeviceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
+#[inline]
 pub fn close_device<Conn>(conn: &Conn, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1208,11 +1465,13 @@ where
 
 /// Opcode for the SetDeviceMode request
 pub const SET_DEVICE_MODE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceModeRequest {
     pub device_id: u8,
     pub mode: ValuatorMode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 impl SetDeviceModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1240,12 +1499,57 @@ impl SetDeviceModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_MODE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetDeviceModeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1256,6 +1560,8 @@ impl SetDeviceModeRequest {
         let (device_id, remaining) = u8::try_parse(value)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(mode)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(SetDeviceModeRequest {
@@ -1264,9 +1570,12 @@ impl SetDeviceModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 impl Request for SetDeviceModeRequest {
     type Reply = SetDeviceModeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
+#[inline]
 pub fn set_device_mode<Conn>(conn: &Conn, device_id: u8, mode: ValuatorMode) -> Result<Cookie<'_, Conn, SetDeviceModeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1278,6 +1587,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceModeReply {
     pub xi_reply_type: u8,
@@ -1285,6 +1595,7 @@ pub struct SetDeviceModeReply {
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
 impl TryParse for SetDeviceModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1298,9 +1609,12 @@ impl TryParse for SetDeviceModeReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = SetDeviceModeReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -1308,11 +1622,13 @@ impl TryParse for SetDeviceModeReply {
 
 /// Opcode for the SelectExtensionEvent request
 pub const SELECT_EXTENSION_EVENT_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SelectExtensionEventRequest<'input> {
     pub window: xproto::Window,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
 impl<'input> SelectExtensionEventRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1349,12 +1665,69 @@ impl<'input> SelectExtensionEventRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        write(&[
+            major_opcode,
+            SELECT_EXTENSION_EVENT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1380,9 +1753,12 @@ impl<'input> SelectExtensionEventRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
 impl<'input> Request for SelectExtensionEventRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
+#[inline]
 pub fn select_extension_event<'c, 'input, Conn>(conn: &'c Conn, window: xproto::Window, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1396,10 +1772,12 @@ where
 
 /// Opcode for the GetSelectedExtensionEvents request
 pub const GET_SELECTED_EXTENSION_EVENTS_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetSelectedExtensionEventsRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 impl GetSelectedExtensionEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1426,12 +1804,56 @@ impl GetSelectedExtensionEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_SELECTED_EXTENSION_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetSelectedExtensionEventsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1446,9 +1868,12 @@ impl GetSelectedExtensionEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 impl Request for GetSelectedExtensionEventsRequest {
     type Reply = GetSelectedExtensionEventsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
+#[inline]
 pub fn get_selected_extension_events<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetSelectedExtensionEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1459,6 +1884,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetSelectedExtensionEventsReply {
     pub xi_reply_type: u8,
@@ -1467,6 +1893,7 @@ pub struct GetSelectedExtensionEventsReply {
     pub this_classes: Vec<EventClass>,
     pub all_classes: Vec<EventClass>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 impl TryParse for GetSelectedExtensionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1484,11 +1911,13 @@ impl TryParse for GetSelectedExtensionEventsReply {
         }
         let result = GetSelectedExtensionEventsReply { xi_reply_type, sequence, length, this_classes, all_classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
 impl GetSelectedExtensionEventsReply {
     /// Get the value of the `num_this_classes` field.
     ///
@@ -1575,15 +2004,28 @@ impl std::fmt::Debug for PropagateMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PropagateMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ChangeDeviceDontPropagateList request
 pub const CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeDeviceDontPropagateListRequest<'input> {
     pub window: xproto::Window,
     pub mode: PropagateMode,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
 impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1621,12 +2063,70 @@ impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        write(&[
+            major_opcode,
+            CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            mode_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1638,6 +2138,8 @@ impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
         let (num_classes, remaining) = u16::try_parse(remaining)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        PropagateMode::check_known(mode)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
         let _ = remaining;
@@ -1656,9 +2158,12 @@ impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
 impl<'input> Request for ChangeDeviceDontPropagateListRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
+#[inline]
 pub fn change_device_dont_propagate_list<'c, 'input, Conn>(conn: &'c Conn, window: xproto::Window, mode: PropagateMode, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1673,10 +2178,12 @@ where
 
 /// Opcode for the GetDeviceDontPropagateList request
 pub const GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceDontPropagateListRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 impl GetDeviceDontPropagateListRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1703,12 +2210,56 @@ impl GetDeviceDontPropagateListRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceDontPropagateListReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -1723,9 +2274,12 @@ impl GetDeviceDontPropagateListRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 impl Request for GetDeviceDontPropagateListRequest {
     type Reply = GetDeviceDontPropagateListReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
+#[inline]
 pub fn get_device_dont_propagate_list<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, GetDeviceDontPropagateListReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1736,6 +2290,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceDontPropagateListReply {
     pub xi_reply_type: u8,
@@ -1743,6 +2298,7 @@ pub struct GetDeviceDontPropagateListReply {
     pub length: u32,
     pub classes: Vec<EventClass>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 impl TryParse for GetDeviceDontPropagateListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1758,11 +2314,13 @@ impl TryParse for GetDeviceDontPropagateListReply {
         }
         let result = GetDeviceDontPropagateListReply { xi_reply_type, sequence, length, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
 impl GetDeviceDontPropagateListReply {
     /// Get the value of the `num_classes` field.
     ///
@@ -1808,12 +2366,14 @@ impl DeviceTimeCoord {
 
 /// Opcode for the GetDeviceMotionEvents request
 pub const GET_DEVICE_MOTION_EVENTS_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceMotionEventsRequest {
     pub start: xproto::Timestamp,
     pub stop: xproto::Timestamp,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 impl GetDeviceMotionEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1850,19 +2410,73 @@ impl GetDeviceMotionEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceMotionEventsReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
+        let length_so_far = 0;
+        let start_bytes = self.start.serialize();
+        let stop_bytes = self.stop.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_MOTION_EVENTS_REQUEST,
+            0,
+            0,
+            start_bytes[0],
+            start_bytes[1],
+            start_bytes[2],
+            start_bytes[3],
+            stop_bytes[0],
+            stop_bytes[1],
+            stop_bytes[2],
+            stop_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
     }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != GET_DEVICE_MOTION_EVENTS_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceMotionEventsReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_MOTION_EVENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
         let (start, remaining) = xproto::Timestamp::try_parse(value)?;
         let (stop, remaining) = xproto::Timestamp::try_parse(remaining)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
@@ -1875,9 +2489,12 @@ impl GetDeviceMotionEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 impl Request for GetDeviceMotionEventsRequest {
     type Reply = GetDeviceMotionEventsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
+#[inline]
 pub fn get_device_motion_events<Conn, A>(conn: &Conn, start: xproto::Timestamp, stop: A, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceMotionEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -1892,6 +2509,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceMotionEventsReply {
     pub xi_reply_type: u8,
@@ -1901,6 +2519,7 @@ pub struct GetDeviceMotionEventsReply {
     pub device_mode: ValuatorMode,
     pub events: Vec<DeviceTimeCoord>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 impl TryParse for GetDeviceMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1914,7 +2533,7 @@ impl TryParse for GetDeviceMotionEventsReply {
         let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         let list_length = num_events.try_to_usize()?;
-        let mut events = Vec::with_capacity(list_length);
+        let mut events = Vec::with_capacity(crate::x11_utils::list_capacity(remaining, list_length)?);
         for _ in 0..list_length {
             let (v, new_remaining) = DeviceTimeCoord::try_parse(remaining, num_axes)?;
             remaining = new_remaining;
@@ -1924,13 +2543,17 @@ impl TryParse for GetDeviceMotionEventsReply {
             return Err(ParseError::InvalidValue);
         }
         let device_mode = device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(device_mode)?;
         let result = GetDeviceMotionEventsReply { xi_reply_type, sequence, length, num_axes, device_mode, events };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
 impl GetDeviceMotionEventsReply {
     /// Get the value of the `num_events` field.
     ///
@@ -1949,10 +2572,12 @@ impl GetDeviceMotionEventsReply {
 
 /// Opcode for the ChangeKeyboardDevice request
 pub const CHANGE_KEYBOARD_DEVICE_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeKeyboardDeviceRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 impl ChangeKeyboardDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -1979,12 +2604,56 @@ impl ChangeKeyboardDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_KEYBOARD_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ChangeKeyboardDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2000,9 +2669,12 @@ impl ChangeKeyboardDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 impl Request for ChangeKeyboardDeviceRequest {
     type Reply = ChangeKeyboardDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
+#[inline]
 pub fn change_keyboard_device<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, ChangeKeyboardDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2013,6 +2685,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeKeyboardDeviceReply {
     pub xi_reply_type: u8,
@@ -2020,6 +2693,7 @@ pub struct ChangeKeyboardDeviceReply {
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
 impl TryParse for ChangeKeyboardDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2033,9 +2707,12 @@ impl TryParse for ChangeKeyboardDeviceReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = ChangeKeyboardDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2043,12 +2720,14 @@ impl TryParse for ChangeKeyboardDeviceReply {
 
 /// Opcode for the ChangePointerDevice request
 pub const CHANGE_POINTER_DEVICE_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangePointerDeviceRequest {
     pub x_axis: u8,
     pub y_axis: u8,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 impl ChangePointerDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2077,12 +2756,58 @@ impl ChangePointerDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let x_axis_bytes = self.x_axis.serialize();
+        let y_axis_bytes = self.y_axis.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_POINTER_DEVICE_REQUEST,
+            0,
+            0,
+            x_axis_bytes[0],
+            y_axis_bytes[0],
+            device_id_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ChangePointerDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2102,9 +2827,12 @@ impl ChangePointerDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 impl Request for ChangePointerDeviceRequest {
     type Reply = ChangePointerDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
+#[inline]
 pub fn change_pointer_device<Conn>(conn: &Conn, x_axis: u8, y_axis: u8, device_id: u8) -> Result<Cookie<'_, Conn, ChangePointerDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2117,6 +2845,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangePointerDeviceReply {
     pub xi_reply_type: u8,
@@ -2124,6 +2853,7 @@ pub struct ChangePointerDeviceReply {
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
 impl TryParse for ChangePointerDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2137,9 +2867,12 @@ impl TryParse for ChangePointerDeviceReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = ChangePointerDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2147,6 +2880,7 @@ impl TryParse for ChangePointerDeviceReply {
 
 /// Opcode for the GrabDevice request
 pub const GRAB_DEVICE_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GrabDeviceRequest<'input> {
     pub grab_window: xproto::Window,
@@ -2157,6 +2891,7 @@ pub struct GrabDeviceRequest<'input> {
     pub device_id: u8,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 impl<'input> GrabDeviceRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2206,12 +2941,82 @@ impl<'input> GrabDeviceRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let time_bytes = self.time.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            owner_events_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 20;
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GrabDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2224,8 +3029,12 @@ impl<'input> GrabDeviceRequest<'input> {
         let (num_classes, remaining) = u16::try_parse(remaining)?;
         let (this_device_mode, remaining) = u8::try_parse(remaining)?;
         let this_device_mode = this_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(this_device_mode)?;
         let (other_device_mode, remaining) = u8::try_parse(remaining)?;
         let other_device_mode = other_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(other_device_mode)?;
         let (owner_events, remaining) = bool::try_parse(remaining)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -2254,9 +3063,12 @@ impl<'input> GrabDeviceRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 impl<'input> Request for GrabDeviceRequest<'input> {
     type Reply = GrabDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
+#[inline]
 pub fn grab_device<'c, 'input, Conn, A>(conn: &'c Conn, grab_window: xproto::Window, time: A, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, device_id: u8, classes: &'input [EventClass]) -> Result<Cookie<'c, Conn, GrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2275,6 +3087,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabDeviceReply {
     pub xi_reply_type: u8,
@@ -2282,6 +3095,7 @@ pub struct GrabDeviceReply {
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
 impl TryParse for GrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2295,9 +3109,12 @@ impl TryParse for GrabDeviceReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = GrabDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -2305,11 +3122,13 @@ impl TryParse for GrabDeviceReply {
 
 /// Opcode for the UngrabDevice request
 pub const UNGRAB_DEVICE_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabDeviceRequest {
     pub time: xproto::Timestamp,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
 impl UngrabDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2341,12 +3160,61 @@ impl UngrabDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2364,9 +3232,12 @@ impl UngrabDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
 impl Request for UngrabDeviceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
+#[inline]
 pub fn ungrab_device<Conn, A>(conn: &Conn, time: A, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2438,6 +3309,7 @@ impl std::fmt::Debug for ModifierDevice  {
 
 /// Opcode for the GrabDeviceKey request
 pub const GRAB_DEVICE_KEY_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GrabDeviceKeyRequest<'input> {
     pub grab_window: xproto::Window,
@@ -2450,6 +3322,7 @@ pub struct GrabDeviceKeyRequest<'input> {
     pub owner_events: bool,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
 impl<'input> GrabDeviceKeyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2501,12 +3374,84 @@ impl<'input> GrabDeviceKeyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        let key_bytes = self.key.serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        write(&[
+            major_opcode,
+            GRAB_DEVICE_KEY_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            grabbed_device_bytes[0],
+            key_bytes[0],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 20;
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2522,8 +3467,12 @@ impl<'input> GrabDeviceKeyRequest<'input> {
         let (key, remaining) = u8::try_parse(remaining)?;
         let (this_device_mode, remaining) = u8::try_parse(remaining)?;
         let this_device_mode = this_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(this_device_mode)?;
         let (other_device_mode, remaining) = u8::try_parse(remaining)?;
         let other_device_mode = other_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(other_device_mode)?;
         let (owner_events, remaining) = bool::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
@@ -2555,9 +3504,12 @@ impl<'input> GrabDeviceKeyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
 impl<'input> Request for GrabDeviceKeyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
+#[inline]
 pub fn grab_device_key<'c, 'input, Conn, A, B, C>(conn: &'c Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2584,6 +3536,7 @@ where
 
 /// Opcode for the UngrabDeviceKey request
 pub const UNGRAB_DEVICE_KEY_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabDeviceKeyRequest {
     pub grab_window: xproto::Window,
@@ -2592,6 +3545,7 @@ pub struct UngrabDeviceKeyRequest {
     pub key: u8,
     pub grabbed_device: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
 impl UngrabDeviceKeyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2630,12 +3584,68 @@ impl UngrabDeviceKeyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let key_bytes = self.key.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        write(&[
+            major_opcode,
+            UNGRAB_DEVICE_KEY_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            key_bytes[0],
+            grabbed_device_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2658,9 +3668,12 @@ impl UngrabDeviceKeyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
 impl Request for UngrabDeviceKeyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
+#[inline]
 pub fn ungrab_device_key<Conn, A, B, C>(conn: &Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, key: C, grabbed_device: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2683,6 +3696,7 @@ where
 
 /// Opcode for the GrabDeviceButton request
 pub const GRAB_DEVICE_BUTTON_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GrabDeviceButtonRequest<'input> {
     pub grab_window: xproto::Window,
@@ -2695,6 +3709,7 @@ pub struct GrabDeviceButtonRequest<'input> {
     pub owner_events: bool,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
 impl<'input> GrabDeviceButtonRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2746,12 +3761,84 @@ impl<'input> GrabDeviceButtonRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let button_bytes = self.button.serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        write(&[
+            major_opcode,
+            GRAB_DEVICE_BUTTON_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            grabbed_device_bytes[0],
+            modifier_device_bytes[0],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            button_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 20;
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2766,8 +3853,12 @@ impl<'input> GrabDeviceButtonRequest<'input> {
         let (modifiers, remaining) = u16::try_parse(remaining)?;
         let (this_device_mode, remaining) = u8::try_parse(remaining)?;
         let this_device_mode = this_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(this_device_mode)?;
         let (other_device_mode, remaining) = u8::try_parse(remaining)?;
         let other_device_mode = other_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(other_device_mode)?;
         let (button, remaining) = u8::try_parse(remaining)?;
         let (owner_events, remaining) = bool::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -2800,9 +3891,12 @@ impl<'input> GrabDeviceButtonRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
 impl<'input> Request for GrabDeviceButtonRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
+#[inline]
 pub fn grab_device_button<'c, 'input, Conn, A, B, C>(conn: &'c Conn, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, button: C, owner_events: bool, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2829,6 +3923,7 @@ where
 
 /// Opcode for the UngrabDeviceButton request
 pub const UNGRAB_DEVICE_BUTTON_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabDeviceButtonRequest {
     pub grab_window: xproto::Window,
@@ -2837,6 +3932,7 @@ pub struct UngrabDeviceButtonRequest {
     pub button: u8,
     pub grabbed_device: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
 impl UngrabDeviceButtonRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -2875,12 +3971,68 @@ impl UngrabDeviceButtonRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let button_bytes = self.button.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        write(&[
+            major_opcode,
+            UNGRAB_DEVICE_BUTTON_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            button_bytes[0],
+            grabbed_device_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -2904,9 +4056,12 @@ impl UngrabDeviceButtonRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
 impl Request for UngrabDeviceButtonRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
+#[inline]
 pub fn ungrab_device_button<Conn, A, B, C>(conn: &Conn, grab_window: xproto::Window, modifiers: A, modifier_device: B, button: C, grabbed_device: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -2992,15 +4147,28 @@ impl std::fmt::Debug for DeviceInputMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceInputMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the AllowDeviceEvents request
 pub const ALLOW_DEVICE_EVENTS_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllowDeviceEventsRequest {
     pub time: xproto::Timestamp,
     pub mode: DeviceInputMode,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
 impl AllowDeviceEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3033,12 +4201,62 @@ impl AllowDeviceEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            ALLOW_DEVICE_EVENTS_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            mode_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3049,6 +4267,8 @@ impl AllowDeviceEventsRequest {
         let (time, remaining) = xproto::Timestamp::try_parse(value)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceInputMode::check_known(mode)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
@@ -3059,9 +4279,12 @@ impl AllowDeviceEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
 impl Request for AllowDeviceEventsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
+#[inline]
 pub fn allow_device_events<Conn, A>(conn: &Conn, time: A, mode: DeviceInputMode, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3078,10 +4301,12 @@ where
 
 /// Opcode for the GetDeviceFocus request
 pub const GET_DEVICE_FOCUS_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceFocusRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 impl GetDeviceFocusRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3092,8 +4317,29 @@ impl GetDeviceFocusRequest {
             .ok_or(ConnectionError::UnsupportedExtension)?;
         let length_so_far = 0;
         let device_id_bytes = self.device_id.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_DEVICE_FOCUS_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
             GET_DEVICE_FOCUS_REQUEST,
             0,
             0,
@@ -3101,19 +4347,42 @@ impl GetDeviceFocusRequest {
             0,
             0,
             0,
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 8;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceFocusReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3129,9 +4398,12 @@ impl GetDeviceFocusRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 impl Request for GetDeviceFocusRequest {
     type Reply = GetDeviceFocusReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
+#[inline]
 pub fn get_device_focus<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceFocusReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3142,6 +4414,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceFocusReply {
     pub xi_reply_type: u8,
@@ -3151,6 +4424,7 @@ pub struct GetDeviceFocusReply {
     pub time: xproto::Timestamp,
     pub revert_to: xproto::InputFocus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
 impl TryParse for GetDeviceFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3166,9 +4440,12 @@ impl TryParse for GetDeviceFocusReply {
             return Err(ParseError::InvalidValue);
         }
         let revert_to = revert_to.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::InputFocus::check_known(revert_to)?;
         let result = GetDeviceFocusReply { xi_reply_type, sequence, length, focus, time, revert_to };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -3176,6 +4453,7 @@ impl TryParse for GetDeviceFocusReply {
 
 /// Opcode for the SetDeviceFocus request
 pub const SET_DEVICE_FOCUS_REQUEST: u8 = 21;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceFocusRequest {
     pub focus: xproto::Window,
@@ -3183,6 +4461,7 @@ pub struct SetDeviceFocusRequest {
     pub revert_to: xproto::InputFocus,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
 impl SetDeviceFocusRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -3220,12 +4499,67 @@ impl SetDeviceFocusRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let focus_bytes = self.focus.serialize();
+        let time_bytes = self.time.serialize();
+        let revert_to_bytes = u8::from(self.revert_to).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_FOCUS_REQUEST,
+            0,
+            0,
+            focus_bytes[0],
+            focus_bytes[1],
+            focus_bytes[2],
+            focus_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            revert_to_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -3237,6 +4571,8 @@ impl SetDeviceFocusRequest {
         let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
         let (revert_to, remaining) = u8::try_parse(remaining)?;
         let revert_to = revert_to.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::InputFocus::check_known(revert_to)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
@@ -3248,9 +4584,12 @@ impl SetDeviceFocusRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
 impl Request for SetDeviceFocusRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
+#[inline]
 pub fn set_device_focus<Conn, A, B>(conn: &Conn, focus: A, time: B, revert_to: xproto::InputFocus, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -3333,6 +4672,17 @@ impl std::fmt::Debug for FeedbackClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl FeedbackClass {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KbdFeedbackState {
@@ -3364,6 +4714,8 @@ impl TryParse for KbdFeedbackState {
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let auto_repeats = <[u8; 32]>::try_from(auto_repeats).unwrap();
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = KbdFeedbackState { class_id, feedback_id, len, pitch, duration, led_mask, led_values, global_auto_repeat, click, percent, auto_repeats };
         Ok((result, remaining))
     }
@@ -3472,6 +4824,8 @@ impl TryParse for PtrFeedbackState {
         let (accel_denom, remaining) = u16::try_parse(remaining)?;
         let (threshold, remaining) = u16::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = PtrFeedbackState { class_id, feedback_id, len, accel_num, accel_denom, threshold };
         Ok((result, remaining))
     }
@@ -3530,6 +4884,8 @@ impl TryParse for IntegerFeedbackState {
         let (min_value, remaining) = i32::try_parse(remaining)?;
         let (max_value, remaining) = i32::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = IntegerFeedbackState { class_id, feedback_id, len, resolution, min_value, max_value };
         Ok((result, remaining))
     }
@@ -3590,6 +4946,8 @@ impl TryParse for StringFeedbackState {
         let (num_keysyms, remaining) = u16::try_parse(remaining)?;
         let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = StringFeedbackState { class_id, feedback_id, len, max_symbols, keysyms };
         Ok((result, remaining))
     }
@@ -3647,6 +5005,8 @@ impl TryParse for BellFeedbackState {
         let (pitch, remaining) = u16::try_parse(remaining)?;
         let (duration, remaining) = u16::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = BellFeedbackState { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -3703,6 +5063,8 @@ impl TryParse for LedFeedbackState {
         let (led_mask, remaining) = u32::try_parse(remaining)?;
         let (led_values, remaining) = u32::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = LedFeedbackState { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -4105,7 +5467,10 @@ impl FeedbackStateData {
             parse_result = Some(FeedbackStateData::Bell(bell));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((FeedbackStateData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -4194,6 +5559,8 @@ impl TryParse for FeedbackState {
         let (feedback_id, remaining) = u8::try_parse(remaining)?;
         let (len, remaining) = u16::try_parse(remaining)?;
         let (data, remaining) = FeedbackStateData::try_parse(remaining, class_id)?;
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id.into())?;
         let result = FeedbackState { feedback_id, len, data };
         Ok((result, remaining))
     }
@@ -4217,10 +5584,12 @@ impl Serialize for FeedbackState {
 
 /// Opcode for the GetFeedbackControl request
 pub const GET_FEEDBACK_CONTROL_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetFeedbackControlRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 impl GetFeedbackControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -4247,12 +5616,56 @@ impl GetFeedbackControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GET_FEEDBACK_CONTROL_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetFeedbackControlReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -4268,9 +5681,12 @@ impl GetFeedbackControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 impl Request for GetFeedbackControlRequest {
     type Reply = GetFeedbackControlReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
+#[inline]
 pub fn get_feedback_control<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetFeedbackControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -4281,6 +5697,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetFeedbackControlReply {
     pub xi_reply_type: u8,
@@ -4288,6 +5705,7 @@ pub struct GetFeedbackControlReply {
     pub length: u32,
     pub feedbacks: Vec<FeedbackState>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 impl TryParse for GetFeedbackControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4303,11 +5721,13 @@ impl TryParse for GetFeedbackControlReply {
         }
         let result = GetFeedbackControlReply { xi_reply_type, sequence, length, feedbacks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
 impl GetFeedbackControlReply {
     /// Get the value of the `num_feedbacks` field.
     ///
@@ -4352,6 +5772,8 @@ impl TryParse for KbdFeedbackCtl {
         let (led_mask, remaining) = u32::try_parse(remaining)?;
         let (led_values, remaining) = u32::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = KbdFeedbackCtl { class_id, feedback_id, len, key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -4428,6 +5850,8 @@ impl TryParse for PtrFeedbackCtl {
         let (denom, remaining) = i16::try_parse(remaining)?;
         let (threshold, remaining) = i16::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = PtrFeedbackCtl { class_id, feedback_id, len, num, denom, threshold };
         Ok((result, remaining))
     }
@@ -4482,6 +5906,8 @@ impl TryParse for IntegerFeedbackCtl {
         let (len, remaining) = u16::try_parse(remaining)?;
         let (int_to_display, remaining) = i32::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = IntegerFeedbackCtl { class_id, feedback_id, len, int_to_display };
         Ok((result, remaining))
     }
@@ -4529,6 +5955,8 @@ impl TryParse for StringFeedbackCtl {
         let (num_keysyms, remaining) = u16::try_parse(remaining)?;
         let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = StringFeedbackCtl { class_id, feedback_id, len, keysyms };
         Ok((result, remaining))
     }
@@ -4586,6 +6014,8 @@ impl TryParse for BellFeedbackCtl {
         let (pitch, remaining) = i16::try_parse(remaining)?;
         let (duration, remaining) = i16::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = BellFeedbackCtl { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -4642,6 +6072,8 @@ impl TryParse for LedFeedbackCtl {
         let (led_mask, remaining) = u32::try_parse(remaining)?;
         let (led_values, remaining) = u32::try_parse(remaining)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id)?;
         let result = LedFeedbackCtl { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -4993,7 +6425,10 @@ impl FeedbackCtlData {
             parse_result = Some(FeedbackCtlData::Bell(bell));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((FeedbackCtlData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -5082,6 +6517,8 @@ impl TryParse for FeedbackCtl {
         let (feedback_id, remaining) = u8::try_parse(remaining)?;
         let (len, remaining) = u16::try_parse(remaining)?;
         let (data, remaining) = FeedbackCtlData::try_parse(remaining, class_id)?;
+        #[cfg(feature = "strict_parsing")]
+        FeedbackClass::check_known(class_id.into())?;
         let result = FeedbackCtl { feedback_id, len, data };
         Ok((result, remaining))
     }
@@ -5186,6 +6623,7 @@ bitmask_binop!(ChangeFeedbackControlMask, u8);
 
 /// Opcode for the ChangeFeedbackControl request
 pub const CHANGE_FEEDBACK_CONTROL_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeFeedbackControlRequest {
     pub mask: u32,
@@ -5193,6 +6631,7 @@ pub struct ChangeFeedbackControlRequest {
     pub feedback_id: u8,
     pub feedback: FeedbackCtl,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
 impl ChangeFeedbackControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5229,12 +6668,69 @@ impl ChangeFeedbackControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), feedback_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mask_bytes = self.mask.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let feedback_id_bytes = self.feedback_id.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_FEEDBACK_CONTROL_REQUEST,
+            0,
+            0,
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            device_id_bytes[0],
+            feedback_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let feedback_bytes = self.feedback.serialize();
+        let length_so_far = length_so_far + feedback_bytes.len();
+        write(&feedback_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.feedback.serialize().len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5256,9 +6752,12 @@ impl ChangeFeedbackControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
 impl Request for ChangeFeedbackControlRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
+#[inline]
 pub fn change_feedback_control<Conn, A>(conn: &Conn, mask: A, device_id: u8, feedback_id: u8, feedback: FeedbackCtl) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5276,12 +6775,14 @@ where
 
 /// Opcode for the GetDeviceKeyMapping request
 pub const GET_DEVICE_KEY_MAPPING_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceKeyMappingRequest {
     pub device_id: u8,
     pub first_keycode: KeyCode,
     pub count: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 impl GetDeviceKeyMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5310,12 +6811,58 @@ impl GetDeviceKeyMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let count_bytes = self.count.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_KEY_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_keycode_bytes[0],
+            count_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceKeyMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5335,9 +6882,12 @@ impl GetDeviceKeyMappingRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 impl Request for GetDeviceKeyMappingRequest {
     type Reply = GetDeviceKeyMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
+#[inline]
 pub fn get_device_key_mapping<Conn>(conn: &Conn, device_id: u8, first_keycode: KeyCode, count: u8) -> Result<Cookie<'_, Conn, GetDeviceKeyMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5350,6 +6900,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceKeyMappingReply {
     pub xi_reply_type: u8,
@@ -5357,6 +6908,7 @@ pub struct GetDeviceKeyMappingReply {
     pub keysyms_per_keycode: u8,
     pub keysyms: Vec<xproto::Keysym>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 impl TryParse for GetDeviceKeyMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5372,11 +6924,13 @@ impl TryParse for GetDeviceKeyMappingReply {
         }
         let result = GetDeviceKeyMappingReply { xi_reply_type, sequence, keysyms_per_keycode, keysyms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
 impl GetDeviceKeyMappingReply {
     /// Get the value of the `length` field.
     ///
@@ -5395,6 +6949,7 @@ impl GetDeviceKeyMappingReply {
 
 /// Opcode for the ChangeDeviceKeyMapping request
 pub const CHANGE_DEVICE_KEY_MAPPING_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeDeviceKeyMappingRequest<'input> {
     pub device_id: u8,
@@ -5403,6 +6958,7 @@ pub struct ChangeDeviceKeyMappingRequest<'input> {
     pub keycode_count: u8,
     pub keysyms: Cow<'input, [xproto::Keysym]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
 impl<'input> ChangeDeviceKeyMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5437,12 +6993,67 @@ impl<'input> ChangeDeviceKeyMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), keysyms_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let keysyms_per_keycode_bytes = self.keysyms_per_keycode.serialize();
+        let keycode_count_bytes = self.keycode_count.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_DEVICE_KEY_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_keycode_bytes[0],
+            keysyms_per_keycode_bytes[0],
+            keycode_count_bytes[0],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(self.keysyms.len(), usize::try_from(u32::from(self.keycode_count).checked_mul(u32::from(self.keysyms_per_keycode)).unwrap()).unwrap(), "`keysyms` has an incorrect length");
+        let keysyms_bytes = self.keysyms.serialize();
+        let length_so_far = length_so_far + keysyms_bytes.len();
+        write(&keysyms_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.keysyms.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5475,9 +7086,12 @@ impl<'input> ChangeDeviceKeyMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
 impl<'input> Request for ChangeDeviceKeyMappingRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
+#[inline]
 pub fn change_device_key_mapping<'c, 'input, Conn>(conn: &'c Conn, device_id: u8, first_keycode: KeyCode, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &'input [xproto::Keysym]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5494,10 +7108,12 @@ where
 
 /// Opcode for the GetDeviceModifierMapping request
 pub const GET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 26;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceModifierMappingRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 impl GetDeviceModifierMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5524,12 +7140,56 @@ impl GetDeviceModifierMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceModifierMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5545,9 +7205,12 @@ impl GetDeviceModifierMappingRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 impl Request for GetDeviceModifierMappingRequest {
     type Reply = GetDeviceModifierMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
+#[inline]
 pub fn get_device_modifier_mapping<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceModifierMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5558,6 +7221,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceModifierMappingReply {
     pub xi_reply_type: u8,
@@ -5565,6 +7229,7 @@ pub struct GetDeviceModifierMappingReply {
     pub length: u32,
     pub keymaps: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 impl TryParse for GetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5581,11 +7246,13 @@ impl TryParse for GetDeviceModifierMappingReply {
         }
         let result = GetDeviceModifierMappingReply { xi_reply_type, sequence, length, keymaps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
 impl GetDeviceModifierMappingReply {
     /// Get the value of the `keycodes_per_modifier` field.
     ///
@@ -5605,11 +7272,13 @@ impl GetDeviceModifierMappingReply {
 
 /// Opcode for the SetDeviceModifierMapping request
 pub const SET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 27;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetDeviceModifierMappingRequest<'input> {
     pub device_id: u8,
     pub keymaps: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 impl<'input> SetDeviceModifierMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5642,12 +7311,65 @@ impl<'input> SetDeviceModifierMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.keymaps, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        assert_eq!(self.keymaps.len() % 8, 0, "`keymaps` has an incorrect length, must be a multiple of 8");
+        let keycodes_per_modifier = u8::try_from(self.keymaps.len() / 8).expect("`keymaps` has too many elements");
+        let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            keycodes_per_modifier_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.keymaps.len();
+        write(&self.keymaps[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.keymaps.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetDeviceModifierMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5673,9 +7395,12 @@ impl<'input> SetDeviceModifierMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 impl<'input> Request for SetDeviceModifierMappingRequest<'input> {
     type Reply = SetDeviceModifierMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
+#[inline]
 pub fn set_device_modifier_mapping<'c, 'input, Conn>(conn: &'c Conn, device_id: u8, keymaps: &'input [u8]) -> Result<Cookie<'c, Conn, SetDeviceModifierMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5687,6 +7412,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceModifierMappingReply {
     pub xi_reply_type: u8,
@@ -5694,6 +7420,7 @@ pub struct SetDeviceModifierMappingReply {
     pub length: u32,
     pub status: xproto::MappingStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
 impl TryParse for SetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5707,9 +7434,12 @@ impl TryParse for SetDeviceModifierMappingReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::MappingStatus::check_known(status)?;
         let result = SetDeviceModifierMappingReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -5717,10 +7447,12 @@ impl TryParse for SetDeviceModifierMappingReply {
 
 /// Opcode for the GetDeviceButtonMapping request
 pub const GET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 28;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceButtonMappingRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 impl GetDeviceButtonMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5747,12 +7479,56 @@ impl GetDeviceButtonMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_BUTTON_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceButtonMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5768,9 +7544,12 @@ impl GetDeviceButtonMappingRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 impl Request for GetDeviceButtonMappingRequest {
     type Reply = GetDeviceButtonMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
+#[inline]
 pub fn get_device_button_mapping<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceButtonMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5781,6 +7560,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDeviceButtonMappingReply {
     pub xi_reply_type: u8,
@@ -5788,6 +7568,7 @@ pub struct GetDeviceButtonMappingReply {
     pub length: u32,
     pub map: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 impl TryParse for GetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5800,20 +7581,19 @@ impl TryParse for GetDeviceButtonMappingReply {
         let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
         let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?)?;
         let map = map.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
         let result = GetDeviceButtonMappingReply { xi_reply_type, sequence, length, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
 impl GetDeviceButtonMappingReply {
     /// Get the value of the `map_size` field.
     ///
@@ -5832,11 +7612,13 @@ impl GetDeviceButtonMappingReply {
 
 /// Opcode for the SetDeviceButtonMapping request
 pub const SET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 29;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetDeviceButtonMappingRequest<'input> {
     pub device_id: u8,
     pub map: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 impl<'input> SetDeviceButtonMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5868,12 +7650,64 @@ impl<'input> SetDeviceButtonMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.map, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let map_size = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        let map_size_bytes = map_size.serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_BUTTON_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            map_size_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.map.len();
+        write(&self.map[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.map.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetDeviceButtonMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5899,9 +7733,12 @@ impl<'input> SetDeviceButtonMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 impl<'input> Request for SetDeviceButtonMappingRequest<'input> {
     type Reply = SetDeviceButtonMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
+#[inline]
 pub fn set_device_button_mapping<'c, 'input, Conn>(conn: &'c Conn, device_id: u8, map: &'input [u8]) -> Result<Cookie<'c, Conn, SetDeviceButtonMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5913,6 +7750,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceButtonMappingReply {
     pub xi_reply_type: u8,
@@ -5920,6 +7758,7 @@ pub struct SetDeviceButtonMappingReply {
     pub length: u32,
     pub status: xproto::MappingStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
 impl TryParse for SetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5933,9 +7772,12 @@ impl TryParse for SetDeviceButtonMappingReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::MappingStatus::check_known(status)?;
         let result = SetDeviceButtonMappingReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -5957,6 +7799,8 @@ impl TryParse for KeyState {
         let (keys, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let keys = <[u8; 32]>::try_from(keys).unwrap();
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = KeyState { class_id, len, num_keys, keys };
         Ok((result, remaining))
     }
@@ -6032,6 +7876,8 @@ impl TryParse for ButtonState {
         let (buttons, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
         let buttons = <[u8; 32]>::try_from(buttons).unwrap();
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = ButtonState { class_id, len, num_buttons, buttons };
         Ok((result, remaining))
     }
@@ -6150,13 +7996,23 @@ impl std::fmt::Debug for ValuatorStateModeMask  {
 }
 bitmask_binop!(ValuatorStateModeMask, u8);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct ValuatorState {
     pub class_id: InputClass,
     pub len: u8,
     pub mode: u8,
     pub valuators: Vec<i32>,
 }
+impl std::fmt::Debug for ValuatorState {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ValuatorState")
+            .field("class_id", &self.class_id)
+            .field("len", &self.len)
+            .field("mode", &ValuatorStateModeMask::from(self.mode))
+            .field("valuators", &self.valuators)
+            .finish()
+    }
+}
 impl TryParse for ValuatorState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -6165,6 +8021,8 @@ impl TryParse for ValuatorState {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?)?;
         let class_id = class_id.into();
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id)?;
         let result = ValuatorState { class_id, len, mode, valuators };
         Ok((result, remaining))
     }
@@ -6328,11 +8186,19 @@ impl Serialize for InputStateDataButton {
         bytes.extend_from_slice(&self.buttons);
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct InputStateDataValuator {
     pub mode: u8,
     pub valuators: Vec<i32>,
 }
+impl std::fmt::Debug for InputStateDataValuator {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("InputStateDataValuator")
+            .field("mode", &ValuatorStateModeMask::from(self.mode))
+            .field("valuators", &self.valuators)
+            .finish()
+    }
+}
 impl TryParse for InputStateDataValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_valuators, remaining) = u8::try_parse(remaining)?;
@@ -6411,7 +8277,10 @@ impl InputStateData {
             parse_result = Some(InputStateData::Valuator(valuator));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((InputStateData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -6474,6 +8343,8 @@ impl TryParse for InputState {
         let (class_id, remaining) = u8::try_parse(remaining)?;
         let (len, remaining) = u8::try_parse(remaining)?;
         let (data, remaining) = InputStateData::try_parse(remaining, class_id)?;
+        #[cfg(feature = "strict_parsing")]
+        InputClass::check_known(class_id.into())?;
         let result = InputState { len, data };
         Ok((result, remaining))
     }
@@ -6496,10 +8367,12 @@ impl Serialize for InputState {
 
 /// Opcode for the QueryDeviceState request
 pub const QUERY_DEVICE_STATE_REQUEST: u8 = 30;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryDeviceStateRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 impl QueryDeviceStateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6526,12 +8399,56 @@ impl QueryDeviceStateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            QUERY_DEVICE_STATE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryDeviceStateReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6547,9 +8464,12 @@ impl QueryDeviceStateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 impl Request for QueryDeviceStateRequest {
     type Reply = QueryDeviceStateReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
+#[inline]
 pub fn query_device_state<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, QueryDeviceStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6560,6 +8480,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryDeviceStateReply {
     pub xi_reply_type: u8,
@@ -6567,6 +8488,7 @@ pub struct QueryDeviceStateReply {
     pub length: u32,
     pub classes: Vec<InputState>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 impl TryParse for QueryDeviceStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6582,11 +8504,13 @@ impl TryParse for QueryDeviceStateReply {
         }
         let result = QueryDeviceStateReply { xi_reply_type, sequence, length, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
 impl QueryDeviceStateReply {
     /// Get the value of the `num_classes` field.
     ///
@@ -6605,6 +8529,7 @@ impl QueryDeviceStateReply {
 
 /// Opcode for the DeviceBell request
 pub const DEVICE_BELL_REQUEST: u8 = 32;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeviceBellRequest {
     pub device_id: u8,
@@ -6612,6 +8537,7 @@ pub struct DeviceBellRequest {
     pub feedback_class: u8,
     pub percent: i8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
 impl DeviceBellRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6641,12 +8567,59 @@ impl DeviceBellRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let feedback_id_bytes = self.feedback_id.serialize();
+        let feedback_class_bytes = self.feedback_class.serialize();
+        let percent_bytes = self.percent.serialize();
+        write(&[
+            major_opcode,
+            DEVICE_BELL_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            feedback_id_bytes[0],
+            feedback_class_bytes[0],
+            percent_bytes[0],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6667,9 +8640,12 @@ impl DeviceBellRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
 impl Request for DeviceBellRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
+#[inline]
 pub fn device_bell<Conn>(conn: &Conn, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6685,12 +8661,14 @@ where
 
 /// Opcode for the SetDeviceValuators request
 pub const SET_DEVICE_VALUATORS_REQUEST: u8 = 33;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetDeviceValuatorsRequest<'input> {
     pub device_id: u8,
     pub first_valuator: u8,
     pub valuators: Cow<'input, [i32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 impl<'input> SetDeviceValuatorsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6724,12 +8702,66 @@ impl<'input> SetDeviceValuatorsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), valuators_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        let first_valuator_bytes = self.first_valuator.serialize();
+        let num_valuators = u8::try_from(self.valuators.len()).expect("`valuators` has too many elements");
+        let num_valuators_bytes = num_valuators.serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_VALUATORS_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_valuator_bytes[0],
+            num_valuators_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let valuators_bytes = self.valuators.serialize();
+        let length_so_far = length_so_far + valuators_bytes.len();
+        write(&valuators_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.valuators.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetDeviceValuatorsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6758,9 +8790,12 @@ impl<'input> SetDeviceValuatorsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 impl<'input> Request for SetDeviceValuatorsRequest<'input> {
     type Reply = SetDeviceValuatorsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
+#[inline]
 pub fn set_device_valuators<'c, 'input, Conn>(conn: &'c Conn, device_id: u8, first_valuator: u8, valuators: &'input [i32]) -> Result<Cookie<'c, Conn, SetDeviceValuatorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6773,6 +8808,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDeviceValuatorsReply {
     pub xi_reply_type: u8,
@@ -6780,6 +8816,7 @@ pub struct SetDeviceValuatorsReply {
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
 impl TryParse for SetDeviceValuatorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6793,9 +8830,12 @@ impl TryParse for SetDeviceValuatorsReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = SetDeviceValuatorsReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -6858,6 +8898,17 @@ impl std::fmt::Debug for DeviceControl  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceControl {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeviceResolutionState {
@@ -6876,6 +8927,8 @@ impl TryParse for DeviceResolutionState {
         let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
         let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceResolutionState { control_id, len, resolution_values, resolution_min, resolution_max };
         Ok((result, remaining))
     }
@@ -6942,6 +8995,8 @@ impl TryParse for DeviceAbsCalibState {
         let (rotation, remaining) = u32::try_parse(remaining)?;
         let (button_threshold, remaining) = u32::try_parse(remaining)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceAbsCalibState { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -7035,6 +9090,8 @@ impl TryParse for DeviceAbsAreaState {
         let (screen, remaining) = u32::try_parse(remaining)?;
         let (following, remaining) = u32::try_parse(remaining)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceAbsAreaState { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -7109,6 +9166,8 @@ impl TryParse for DeviceCoreState {
         let (iscore, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceCoreState { control_id, len, status, iscore };
         Ok((result, remaining))
     }
@@ -7154,6 +9213,8 @@ impl TryParse for DeviceEnableState {
         let (enable, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceEnableState { control_id, len, enable };
         Ok((result, remaining))
     }
@@ -7470,7 +9531,10 @@ impl DeviceStateData {
             parse_result = Some(DeviceStateData::AbsArea(abs_area));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((DeviceStateData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -7553,6 +9617,8 @@ impl TryParse for DeviceState {
         let (control_id, remaining) = u16::try_parse(remaining)?;
         let (len, remaining) = u16::try_parse(remaining)?;
         let (data, remaining) = DeviceStateData::try_parse(remaining, control_id)?;
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id.into())?;
         let result = DeviceState { len, data };
         Ok((result, remaining))
     }
@@ -7575,11 +9641,13 @@ impl Serialize for DeviceState {
 
 /// Opcode for the GetDeviceControl request
 pub const GET_DEVICE_CONTROL_REQUEST: u8 = 34;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceControlRequest {
     pub control_id: DeviceControl,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
 impl GetDeviceControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7607,12 +9675,57 @@ impl GetDeviceControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let control_id_bytes = u16::from(self.control_id).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_CONTROL_REQUEST,
+            0,
+            0,
+            control_id_bytes[0],
+            control_id_bytes[1],
+            device_id_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceControlReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7622,6 +9735,8 @@ impl GetDeviceControlRequest {
         }
         let (control_id, remaining) = u16::try_parse(value)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
@@ -7631,9 +9746,12 @@ impl GetDeviceControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
 impl Request for GetDeviceControlRequest {
     type Reply = GetDeviceControlReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
+#[inline]
 pub fn get_device_control<Conn>(conn: &Conn, control_id: DeviceControl, device_id: u8) -> Result<Cookie<'_, Conn, GetDeviceControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7645,7 +9763,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetDeviceControlReply {
     pub xi_reply_type: u8,
     pub sequence: u16,
@@ -7653,6 +9772,19 @@ pub struct GetDeviceControlReply {
     pub status: u8,
     pub control: DeviceState,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
+impl std::fmt::Debug for GetDeviceControlReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetDeviceControlReply")
+            .field("xi_reply_type", &self.xi_reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("status", &xproto::GrabStatus::from(self.status))
+            .field("control", &self.control)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
 impl TryParse for GetDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7668,7 +9800,8 @@ impl TryParse for GetDeviceControlReply {
         }
         let result = GetDeviceControlReply { xi_reply_type, sequence, length, status, control };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -7690,6 +9823,8 @@ impl TryParse for DeviceResolutionCtl {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceResolutionCtl { control_id, len, first_valuator, resolution_values };
         Ok((result, remaining))
     }
@@ -7754,6 +9889,8 @@ impl TryParse for DeviceAbsCalibCtl {
         let (rotation, remaining) = u32::try_parse(remaining)?;
         let (button_threshold, remaining) = u32::try_parse(remaining)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceAbsCalibCtl { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -7847,6 +9984,8 @@ impl TryParse for DeviceAbsAreaCtrl {
         let (screen, remaining) = i32::try_parse(remaining)?;
         let (following, remaining) = u32::try_parse(remaining)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceAbsAreaCtrl { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -7919,6 +10058,8 @@ impl TryParse for DeviceCoreCtrl {
         let (status, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceCoreCtrl { control_id, len, status };
         Ok((result, remaining))
     }
@@ -7962,6 +10103,8 @@ impl TryParse for DeviceEnableCtrl {
         let (enable, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let result = DeviceEnableCtrl { control_id, len, enable };
         Ok((result, remaining))
     }
@@ -8272,7 +10415,10 @@ impl DeviceCtlData {
             parse_result = Some(DeviceCtlData::AbsArea(abs_area));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((DeviceCtlData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -8355,6 +10501,8 @@ impl TryParse for DeviceCtl {
         let (control_id, remaining) = u16::try_parse(remaining)?;
         let (len, remaining) = u16::try_parse(remaining)?;
         let (data, remaining) = DeviceCtlData::try_parse(remaining, control_id)?;
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id.into())?;
         let result = DeviceCtl { len, data };
         Ok((result, remaining))
     }
@@ -8377,12 +10525,14 @@ impl Serialize for DeviceCtl {
 
 /// Opcode for the ChangeDeviceControl request
 pub const CHANGE_DEVICE_CONTROL_REQUEST: u8 = 35;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeDeviceControlRequest {
     pub control_id: DeviceControl,
     pub device_id: u8,
     pub control: DeviceCtl,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
 impl ChangeDeviceControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8414,12 +10564,64 @@ impl ChangeDeviceControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), control_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let control_id_bytes = u16::from(self.control_id).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_DEVICE_CONTROL_REQUEST,
+            0,
+            0,
+            control_id_bytes[0],
+            control_id_bytes[1],
+            device_id_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let control_bytes = self.control.serialize();
+        let length_so_far = length_so_far + control_bytes.len();
+        write(&control_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.control.serialize().len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ChangeDeviceControlReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8429,6 +10631,8 @@ impl ChangeDeviceControlRequest {
         }
         let (control_id, remaining) = u16::try_parse(value)?;
         let control_id = control_id.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceControl::check_known(control_id)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (control, remaining) = DeviceCtl::try_parse(remaining)?;
@@ -8440,9 +10644,12 @@ impl ChangeDeviceControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
 impl Request for ChangeDeviceControlRequest {
     type Reply = ChangeDeviceControlReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
+#[inline]
 pub fn change_device_control<Conn>(conn: &Conn, control_id: DeviceControl, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Conn, ChangeDeviceControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8455,13 +10662,26 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ChangeDeviceControlReply {
     pub xi_reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub status: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
+impl std::fmt::Debug for ChangeDeviceControlReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ChangeDeviceControlReply")
+            .field("xi_reply_type", &self.xi_reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("status", &xproto::GrabStatus::from(self.status))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
 impl TryParse for ChangeDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8476,7 +10696,8 @@ impl TryParse for ChangeDeviceControlReply {
         }
         let result = ChangeDeviceControlReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8484,10 +10705,12 @@ impl TryParse for ChangeDeviceControlReply {
 
 /// Opcode for the ListDeviceProperties request
 pub const LIST_DEVICE_PROPERTIES_REQUEST: u8 = 36;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListDevicePropertiesRequest {
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 impl ListDevicePropertiesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8514,12 +10737,56 @@ impl ListDevicePropertiesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            LIST_DEVICE_PROPERTIES_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListDevicePropertiesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8535,9 +10802,12 @@ impl ListDevicePropertiesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 impl Request for ListDevicePropertiesRequest {
     type Reply = ListDevicePropertiesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
+#[inline]
 pub fn list_device_properties<Conn>(conn: &Conn, device_id: u8) -> Result<Cookie<'_, Conn, ListDevicePropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8548,6 +10818,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListDevicePropertiesReply {
     pub xi_reply_type: u8,
@@ -8555,6 +10826,7 @@ pub struct ListDevicePropertiesReply {
     pub length: u32,
     pub atoms: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 impl TryParse for ListDevicePropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8570,11 +10842,13 @@ impl TryParse for ListDevicePropertiesReply {
         }
         let result = ListDevicePropertiesReply { xi_reply_type, sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
 impl ListDevicePropertiesReply {
     /// Get the value of the `num_atoms` field.
     ///
@@ -8650,7 +10924,21 @@ impl std::fmt::Debug for PropertyFormat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PropertyFormat {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 8 | 16 | 32) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
+/// Opcode for the ChangeDeviceProperty request
+pub const CHANGE_DEVICE_PROPERTY_REQUEST: u8 = 37;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChangeDevicePropertyAux {
     Data8(Vec<u8>),
@@ -8666,6 +10954,7 @@ pub enum ChangeDevicePropertyAux {
     /// will raise a panic.
     InvalidValue(u32),
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl ChangeDevicePropertyAux {
     fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
@@ -8676,10 +10965,7 @@ impl ChangeDevicePropertyAux {
             let value = remaining;
             let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
             let data8 = data8.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(ChangeDevicePropertyAux::Data8(data8));
@@ -8688,10 +10974,7 @@ impl ChangeDevicePropertyAux {
             let remaining = outer_remaining;
             let value = remaining;
             let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(ChangeDevicePropertyAux::Data16(data16));
@@ -8704,11 +10987,15 @@ impl ChangeDevicePropertyAux {
             parse_result = Some(ChangeDevicePropertyAux::Data32(data32));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((ChangeDevicePropertyAux::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl ChangeDevicePropertyAux {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -8729,6 +11016,7 @@ impl ChangeDevicePropertyAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl ChangeDevicePropertyAux {
     #[allow(dead_code)]
     fn serialize(&self, format: u8, num_items: u32) -> Vec<u8> {
@@ -8757,6 +11045,7 @@ impl ChangeDevicePropertyAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl ChangeDevicePropertyAux {
     fn switch_expr(&self) -> u32 {
         match self {
@@ -8768,8 +11057,7 @@ impl ChangeDevicePropertyAux {
     }
 }
 
-/// Opcode for the ChangeDeviceProperty request
-pub const CHANGE_DEVICE_PROPERTY_REQUEST: u8 = 37;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeDevicePropertyRequest<'input> {
     pub property: xproto::Atom,
@@ -8779,6 +11067,7 @@ pub struct ChangeDevicePropertyRequest<'input> {
     pub num_items: u32,
     pub items: Cow<'input, ChangeDevicePropertyAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl<'input> ChangeDevicePropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8827,12 +11116,82 @@ impl<'input> ChangeDevicePropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), items_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        let format_bytes = format.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let num_items_bytes = self.num_items.serialize();
+        write(&[
+            major_opcode,
+            CHANGE_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            device_id_bytes[0],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        let items_bytes = self.items.serialize(format, self.num_items);
+        let length_so_far = length_so_far + items_bytes.len();
+        write(&items_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        let length_so_far = 20 + self.items.serialize(format, self.num_items).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8844,8 +11203,12 @@ impl<'input> ChangeDevicePropertyRequest<'input> {
         let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let (format, remaining) = u8::try_parse(remaining)?;
+        #[cfg(feature = "strict_parsing")]
+        PropertyFormat::check_known(format.into())?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::PropMode::check_known(mode)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (num_items, remaining) = u32::try_parse(remaining)?;
         let (items, remaining) = ChangeDevicePropertyAux::try_parse(remaining, format, num_items)?;
@@ -8871,9 +11234,12 @@ impl<'input> ChangeDevicePropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
 impl<'input> Request for ChangeDevicePropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
+#[inline]
 pub fn change_device_property<'c, 'input, Conn>(conn: &'c Conn, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: xproto::PropMode, num_items: u32, items: &'input ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8891,11 +11257,13 @@ where
 
 /// Opcode for the DeleteDeviceProperty request
 pub const DELETE_DEVICE_PROPERTY_REQUEST: u8 = 38;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeleteDevicePropertyRequest {
     pub property: xproto::Atom,
     pub device_id: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
 impl DeleteDevicePropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8927,12 +11295,61 @@ impl DeleteDevicePropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let property_bytes = self.property.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        write(&[
+            major_opcode,
+            DELETE_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8950,9 +11367,12 @@ impl DeleteDevicePropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
 impl Request for DeleteDevicePropertyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
+#[inline]
 pub fn delete_device_property<Conn>(conn: &Conn, property: xproto::Atom, device_id: u8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8966,6 +11386,7 @@ where
 
 /// Opcode for the GetDeviceProperty request
 pub const GET_DEVICE_PROPERTY_REQUEST: u8 = 39;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDevicePropertyRequest {
     pub property: xproto::Atom,
@@ -8975,14 +11396,57 @@ pub struct GetDevicePropertyRequest {
     pub device_id: u8,
     pub delete: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl GetDevicePropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let offset_bytes = self.offset.serialize();
+        let len_bytes = self.len.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let delete_bytes = self.delete.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            len_bytes[0],
+            len_bytes[1],
+            len_bytes[2],
+            len_bytes[3],
+            device_id_bytes[0],
+            delete_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let property_bytes = self.property.serialize();
         let type_bytes = self.type_.serialize();
@@ -8990,8 +11454,8 @@ impl GetDevicePropertyRequest {
         let len_bytes = self.len.serialize();
         let device_id_bytes = self.device_id.serialize();
         let delete_bytes = self.delete.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             GET_DEVICE_PROPERTY_REQUEST,
             0,
             0,
@@ -9015,19 +11479,42 @@ impl GetDevicePropertyRequest {
             delete_bytes[0],
             0,
             0,
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 24;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDevicePropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9053,9 +11540,12 @@ impl GetDevicePropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl Request for GetDevicePropertyRequest {
     type Reply = GetDevicePropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
+#[inline]
 pub fn get_device_property<Conn>(conn: &Conn, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32, device_id: u8, delete: bool) -> Result<Cookie<'_, Conn, GetDevicePropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9071,6 +11561,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GetDevicePropertyItems {
     Data8(Vec<u8>),
@@ -9086,6 +11577,7 @@ pub enum GetDevicePropertyItems {
     /// will raise a panic.
     InvalidValue(u32),
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl GetDevicePropertyItems {
     fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
@@ -9096,10 +11588,7 @@ impl GetDevicePropertyItems {
             let value = remaining;
             let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
             let data8 = data8.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data8(data8));
@@ -9108,10 +11597,7 @@ impl GetDevicePropertyItems {
             let remaining = outer_remaining;
             let value = remaining;
             let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data16(data16));
@@ -9124,11 +11610,15 @@ impl GetDevicePropertyItems {
             parse_result = Some(GetDevicePropertyItems::Data32(data32));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((GetDevicePropertyItems::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl GetDevicePropertyItems {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -9150,6 +11640,7 @@ impl GetDevicePropertyItems {
     }
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetDevicePropertyReply {
     pub xi_reply_type: u8,
@@ -9161,6 +11652,7 @@ pub struct GetDevicePropertyReply {
     pub device_id: u8,
     pub items: GetDevicePropertyItems,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
 impl TryParse for GetDevicePropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9178,9 +11670,12 @@ impl TryParse for GetDevicePropertyReply {
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
+        #[cfg(feature = "strict_parsing")]
+        PropertyFormat::check_known(format.into())?;
         let result = GetDevicePropertyReply { xi_reply_type, sequence, length, type_, bytes_after, num_items, device_id, items };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -9350,11 +11845,13 @@ impl Serialize for ModifierInfo {
 
 /// Opcode for the XIQueryPointer request
 pub const XI_QUERY_POINTER_REQUEST: u8 = 40;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIQueryPointerRequest {
     pub window: xproto::Window,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 impl XIQueryPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9386,12 +11883,61 @@ impl XIQueryPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_QUERY_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIQueryPointerReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9409,9 +11955,12 @@ impl XIQueryPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 impl Request for XIQueryPointerRequest {
     type Reply = XIQueryPointerReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
+#[inline]
 pub fn xi_query_pointer<Conn, A>(conn: &Conn, window: xproto::Window, deviceid: A) -> Result<Cookie<'_, Conn, XIQueryPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9425,6 +11974,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIQueryPointerReply {
     pub sequence: u16,
@@ -9440,6 +11990,7 @@ pub struct XIQueryPointerReply {
     pub group: GroupInfo,
     pub buttons: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 impl TryParse for XIQueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9464,11 +12015,13 @@ impl TryParse for XIQueryPointerReply {
         }
         let result = XIQueryPointerReply { sequence, length, root, child, root_x, root_y, win_x, win_y, same_screen, mods, group, buttons };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
 impl XIQueryPointerReply {
     /// Get the value of the `buttons_len` field.
     ///
@@ -9487,6 +12040,7 @@ impl XIQueryPointerReply {
 
 /// Opcode for the XIWarpPointer request
 pub const XI_WARP_POINTER_REQUEST: u8 = 41;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIWarpPointerRequest {
     pub src_win: xproto::Window,
@@ -9499,6 +12053,7 @@ pub struct XIWarpPointerRequest {
     pub dst_y: Fp1616,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
 impl XIWarpPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9561,12 +12116,92 @@ impl XIWarpPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let src_win_bytes = self.src_win.serialize();
+        let dst_win_bytes = self.dst_win.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_WARP_POINTER_REQUEST,
+            0,
+            0,
+            src_win_bytes[0],
+            src_win_bytes[1],
+            src_win_bytes[2],
+            src_win_bytes[3],
+            dst_win_bytes[0],
+            dst_win_bytes[1],
+            dst_win_bytes[2],
+            dst_win_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_x_bytes[2],
+            src_x_bytes[3],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_y_bytes[2],
+            src_y_bytes[3],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_x_bytes[2],
+            dst_x_bytes[3],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            dst_y_bytes[2],
+            dst_y_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 36;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        36
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9598,9 +12233,12 @@ impl XIWarpPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
 impl Request for XIWarpPointerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
+#[inline]
 pub fn xi_warp_pointer<Conn, A>(conn: &Conn, src_win: xproto::Window, dst_win: xproto::Window, src_x: Fp1616, src_y: Fp1616, src_width: u16, src_height: u16, dst_x: Fp1616, dst_y: Fp1616, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9623,12 +12261,14 @@ where
 
 /// Opcode for the XIChangeCursor request
 pub const XI_CHANGE_CURSOR_REQUEST: u8 = 42;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIChangeCursorRequest {
     pub window: xproto::Window,
     pub cursor: xproto::Cursor,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
 impl XIChangeCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9665,12 +12305,66 @@ impl XIChangeCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_CHANGE_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9690,9 +12384,12 @@ impl XIChangeCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
 impl Request for XIChangeCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
+#[inline]
 pub fn xi_change_cursor<Conn, A>(conn: &Conn, window: xproto::Window, cursor: xproto::Cursor, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9762,6 +12459,17 @@ impl std::fmt::Debug for HierarchyChangeType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl HierarchyChangeType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ChangeMode(u8);
@@ -9820,6 +12528,17 @@ impl std::fmt::Debug for ChangeMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ChangeMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1 | 2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddMaster {
@@ -9839,11 +12558,10 @@ impl TryParse for AddMaster {
         let (enable, remaining) = bool::try_parse(remaining)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
         let name = name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        HierarchyChangeType::check_known(type_)?;
         let result = AddMaster { type_, len, send_core, enable, name };
         Ok((result, remaining))
     }
@@ -9902,7 +12620,11 @@ impl TryParse for RemoveMaster {
         let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
         let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        HierarchyChangeType::check_known(type_)?;
         let return_mode = return_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ChangeMode::check_known(return_mode)?;
         let result = RemoveMaster { type_, len, deviceid, return_mode, return_pointer, return_keyboard };
         Ok((result, remaining))
     }
@@ -9957,6 +12679,8 @@ impl TryParse for AttachSlave {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
         let (master, remaining) = DeviceId::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        HierarchyChangeType::check_known(type_)?;
         let result = AttachSlave { type_, len, deviceid, master };
         Ok((result, remaining))
     }
@@ -10001,6 +12725,8 @@ impl TryParse for DetachSlave {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        HierarchyChangeType::check_known(type_)?;
         let result = DetachSlave { type_, len, deviceid };
         Ok((result, remaining))
     }
@@ -10045,10 +12771,7 @@ impl TryParse for HierarchyChangeDataAddMaster {
         let (enable, remaining) = bool::try_parse(remaining)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
         let name = name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let result = HierarchyChangeDataAddMaster { send_core, enable, name };
         Ok((result, remaining))
     }
@@ -10100,6 +12823,8 @@ impl TryParse for HierarchyChangeDataRemoveMaster {
         let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
         let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
         let return_mode = return_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ChangeMode::check_known(return_mode)?;
         let result = HierarchyChangeDataRemoveMaster { deviceid, return_mode, return_pointer, return_keyboard };
         Ok((result, remaining))
     }
@@ -10237,7 +12962,10 @@ impl HierarchyChangeData {
             parse_result = Some(HierarchyChangeData::DetachSlave(detach_slave));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((HierarchyChangeData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -10308,6 +13036,8 @@ impl TryParse for HierarchyChange {
         let (type_, remaining) = u16::try_parse(remaining)?;
         let (len, remaining) = u16::try_parse(remaining)?;
         let (data, remaining) = HierarchyChangeData::try_parse(remaining, type_)?;
+        #[cfg(feature = "strict_parsing")]
+        HierarchyChangeType::check_known(type_.into())?;
         let result = HierarchyChange { len, data };
         Ok((result, remaining))
     }
@@ -10330,10 +13060,12 @@ impl Serialize for HierarchyChange {
 
 /// Opcode for the XIChangeHierarchy request
 pub const XI_CHANGE_HIERARCHY_REQUEST: u8 = 43;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIChangeHierarchyRequest<'input> {
     pub changes: Cow<'input, [HierarchyChange]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
 impl<'input> XIChangeHierarchyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10365,12 +13097,64 @@ impl<'input> XIChangeHierarchyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), changes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let num_changes = u8::try_from(self.changes.len()).expect("`changes` has too many elements");
+        let num_changes_bytes = num_changes.serialize();
+        write(&[
+            major_opcode,
+            XI_CHANGE_HIERARCHY_REQUEST,
+            0,
+            0,
+            num_changes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let changes_bytes = self.changes.serialize();
+        let length_so_far = length_so_far + changes_bytes.len();
+        write(&changes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.changes.iter().map(|element| element.serialize().len()).sum::<usize>();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10393,9 +13177,12 @@ impl<'input> XIChangeHierarchyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
 impl<'input> Request for XIChangeHierarchyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
+#[inline]
 pub fn xi_change_hierarchy<'c, 'input, Conn>(conn: &'c Conn, changes: &'input [HierarchyChange]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10408,11 +13195,13 @@ where
 
 /// Opcode for the XISetClientPointer request
 pub const XI_SET_CLIENT_POINTER_REQUEST: u8 = 44;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XISetClientPointerRequest {
     pub window: xproto::Window,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
 impl XISetClientPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10444,12 +13233,61 @@ impl XISetClientPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_SET_CLIENT_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10467,9 +13305,12 @@ impl XISetClientPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
 impl Request for XISetClientPointerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
+#[inline]
 pub fn xi_set_client_pointer<Conn, A>(conn: &Conn, window: xproto::Window, deviceid: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10485,10 +13326,12 @@ where
 
 /// Opcode for the XIGetClientPointer request
 pub const XI_GET_CLIENT_POINTER_REQUEST: u8 = 45;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetClientPointerRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 impl XIGetClientPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10515,12 +13358,56 @@ impl XIGetClientPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            XI_GET_CLIENT_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIGetClientPointerReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10535,9 +13422,12 @@ impl XIGetClientPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 impl Request for XIGetClientPointerRequest {
     type Reply = XIGetClientPointerReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
+#[inline]
 pub fn xi_get_client_pointer<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetClientPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10548,6 +13438,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetClientPointerReply {
     pub sequence: u16,
@@ -10555,6 +13446,7 @@ pub struct XIGetClientPointerReply {
     pub set: bool,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
 impl TryParse for XIGetClientPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10571,7 +13463,8 @@ impl TryParse for XIGetClientPointerReply {
         }
         let result = XIGetClientPointerReply { sequence, length, set, deviceid };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -10719,25 +13612,58 @@ impl EventMask {
 
 /// Opcode for the XISelectEvents request
 pub const XI_SELECT_EVENTS_REQUEST: u8 = 46;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XISelectEventsRequest<'input> {
     pub window: xproto::Window,
     pub masks: Cow<'input, [EventMask]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
 impl<'input> XISelectEventsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let num_mask = u16::try_from(self.masks.len()).expect("`masks` has too many elements");
+        let num_mask_bytes = num_mask.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            XI_SELECT_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_mask_bytes[0],
+            num_mask_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let masks_bytes = self.masks.serialize();
+        let length_so_far = length_so_far + masks_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into(), masks_bytes.into(), padding0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let window_bytes = self.window.serialize();
         let num_mask = u16::try_from(self.masks.len()).expect("`masks` has too many elements");
         let num_mask_bytes = num_mask.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             XI_SELECT_EVENTS_REQUEST,
             0,
             0,
@@ -10749,23 +13675,49 @@ impl<'input> XISelectEventsRequest<'input> {
             num_mask_bytes[1],
             0,
             0,
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 12;
         let masks_bytes = self.masks.serialize();
         let length_so_far = length_so_far + masks_bytes.len();
+        write(&masks_bytes[..]);
         let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
         let length_so_far = length_so_far + padding0.len();
+        write(padding0);
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into(), masks_bytes.into(), padding0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.masks.iter().map(|element| element.serialize().len()).sum::<usize>();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10791,9 +13743,12 @@ impl<'input> XISelectEventsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
 impl<'input> Request for XISelectEventsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
+#[inline]
 pub fn xi_select_events<'c, 'input, Conn>(conn: &'c Conn, window: xproto::Window, masks: &'input [EventMask]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10807,11 +13762,13 @@ where
 
 /// Opcode for the XIQueryVersion request
 pub const XI_QUERY_VERSION_REQUEST: u8 = 47;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIQueryVersionRequest {
     pub major_version: u16,
     pub minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 impl XIQueryVersionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10839,12 +13796,57 @@ impl XIQueryVersionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        write(&[
+            major_opcode,
+            XI_QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIQueryVersionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10861,9 +13863,12 @@ impl XIQueryVersionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 impl Request for XIQueryVersionRequest {
     type Reply = XIQueryVersionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
+#[inline]
 pub fn xi_query_version<Conn>(conn: &Conn, major_version: u16, minor_version: u16) -> Result<Cookie<'_, Conn, XIQueryVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10875,6 +13880,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIQueryVersionReply {
     pub sequence: u16,
@@ -10882,6 +13888,7 @@ pub struct XIQueryVersionReply {
     pub major_version: u16,
     pub minor_version: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
 impl TryParse for XIQueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10897,7 +13904,8 @@ impl TryParse for XIQueryVersionReply {
         }
         let result = XIQueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -10960,6 +13968,17 @@ impl std::fmt::Debug for DeviceClassType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceClassType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3 | 8) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DeviceType(u16);
@@ -11018,6 +14037,17 @@ impl std::fmt::Debug for DeviceType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ScrollFlags(u8);
@@ -11129,6 +14159,17 @@ impl std::fmt::Debug for ScrollType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ScrollType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1 | 2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct TouchMode(u8);
@@ -11187,6 +14228,17 @@ impl std::fmt::Debug for TouchMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl TouchMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1 | 2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ButtonClass {
@@ -11205,6 +14257,8 @@ impl TryParse for ButtonClass {
         let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::InvalidExpression)?.checked_div(32u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
         let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_to_usize()?)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_)?;
         let result = ButtonClass { type_, len, sourceid, state, labels };
         Ok((result, remaining))
     }
@@ -11259,6 +14313,8 @@ impl TryParse for KeyClass {
         let (num_keys, remaining) = u16::try_parse(remaining)?;
         let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_to_usize()?)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_)?;
         let result = KeyClass { type_, len, sourceid, keys };
         Ok((result, remaining))
     }
@@ -11317,7 +14373,11 @@ impl TryParse for ScrollClass {
         let (flags, remaining) = u32::try_parse(remaining)?;
         let (increment, remaining) = Fp3232::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_)?;
         let scroll_type = scroll_type.into();
+        #[cfg(feature = "strict_parsing")]
+        ScrollType::check_known(scroll_type)?;
         let result = ScrollClass { type_, len, sourceid, number, scroll_type, flags, increment };
         Ok((result, remaining))
     }
@@ -11388,7 +14448,11 @@ impl TryParse for TouchClass {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let (num_touches, remaining) = u8::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        TouchMode::check_known(mode)?;
         let result = TouchClass { type_, len, sourceid, mode, num_touches };
         Ok((result, remaining))
     }
@@ -11449,7 +14513,11 @@ impl TryParse for ValuatorClass {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(mode)?;
         let result = ValuatorClass { type_, len, sourceid, number, label, min, max, value, resolution, mode };
         Ok((result, remaining))
     }
@@ -11635,6 +14703,8 @@ impl TryParse for DeviceClassDataValuator {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ValuatorMode::check_known(mode)?;
         let result = DeviceClassDataValuator { number, label, min, max, value, resolution, mode };
         Ok((result, remaining))
     }
@@ -11717,6 +14787,8 @@ impl TryParse for DeviceClassDataScroll {
         let (flags, remaining) = u32::try_parse(remaining)?;
         let (increment, remaining) = Fp3232::try_parse(remaining)?;
         let scroll_type = scroll_type.into();
+        #[cfg(feature = "strict_parsing")]
+        ScrollType::check_known(scroll_type)?;
         let result = DeviceClassDataScroll { number, scroll_type, flags, increment };
         Ok((result, remaining))
     }
@@ -11768,6 +14840,8 @@ impl TryParse for DeviceClassDataTouch {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let (num_touches, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        TouchMode::check_known(mode)?;
         let result = DeviceClassDataTouch { mode, num_touches };
         Ok((result, remaining))
     }
@@ -11841,7 +14915,10 @@ impl DeviceClassData {
             parse_result = Some(DeviceClassData::Touch(touch));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((DeviceClassData::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
@@ -11922,6 +14999,8 @@ impl TryParse for DeviceClass {
         let (len, remaining) = u16::try_parse(remaining)?;
         let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
         let (data, remaining) = DeviceClassData::try_parse(remaining, type_)?;
+        #[cfg(feature = "strict_parsing")]
+        DeviceClassType::check_known(type_.into())?;
         let result = DeviceClass { len, sourceid, data };
         Ok((result, remaining))
     }
@@ -11964,12 +15043,11 @@ impl TryParse for XIDeviceInfo {
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
         let name = name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceType::check_known(type_)?;
         let result = XIDeviceInfo { deviceid, type_, attachment, enabled, name, classes };
         Ok((result, remaining))
     }
@@ -12028,10 +15106,12 @@ impl XIDeviceInfo {
 
 /// Opcode for the XIQueryDevice request
 pub const XI_QUERY_DEVICE_REQUEST: u8 = 48;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIQueryDeviceRequest {
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 impl XIQueryDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -12058,12 +15138,56 @@ impl XIQueryDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_QUERY_DEVICE_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIQueryDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12079,9 +15203,12 @@ impl XIQueryDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 impl Request for XIQueryDeviceRequest {
     type Reply = XIQueryDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
+#[inline]
 pub fn xi_query_device<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIQueryDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12094,12 +15221,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIQueryDeviceReply {
     pub sequence: u16,
     pub length: u32,
     pub infos: Vec<XIDeviceInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 impl TryParse for XIQueryDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12115,11 +15244,13 @@ impl TryParse for XIQueryDeviceReply {
         }
         let result = XIQueryDeviceReply { sequence, length, infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
 impl XIQueryDeviceReply {
     /// Get the value of the `num_infos` field.
     ///
@@ -12138,12 +15269,14 @@ impl XIQueryDeviceReply {
 
 /// Opcode for the XISetFocus request
 pub const XI_SET_FOCUS_REQUEST: u8 = 49;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XISetFocusRequest {
     pub window: xproto::Window,
     pub time: xproto::Timestamp,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
 impl XISetFocusRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -12180,12 +15313,66 @@ impl XISetFocusRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_SET_FOCUS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12205,9 +15392,12 @@ impl XISetFocusRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
 impl Request for XISetFocusRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
+#[inline]
 pub fn xi_set_focus<Conn, A, B>(conn: &Conn, window: xproto::Window, time: A, deviceid: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12226,10 +15416,12 @@ where
 
 /// Opcode for the XIGetFocus request
 pub const XI_GET_FOCUS_REQUEST: u8 = 50;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetFocusRequest {
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 impl XIGetFocusRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -12256,12 +15448,56 @@ impl XIGetFocusRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_GET_FOCUS_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIGetFocusReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12277,9 +15513,12 @@ impl XIGetFocusRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 impl Request for XIGetFocusRequest {
     type Reply = XIGetFocusReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
+#[inline]
 pub fn xi_get_focus<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIGetFocusReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12292,12 +15531,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetFocusReply {
     pub sequence: u16,
     pub length: u32,
     pub focus: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
 impl TryParse for XIGetFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12312,7 +15553,8 @@ impl TryParse for XIGetFocusReply {
         }
         let result = XIGetFocusReply { sequence, length, focus };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -12390,6 +15632,7 @@ impl std::fmt::Debug for GrabOwner  {
 
 /// Opcode for the XIGrabDevice request
 pub const XI_GRAB_DEVICE_REQUEST: u8 = 51;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIGrabDeviceRequest<'input> {
     pub window: xproto::Window,
@@ -12401,6 +15644,7 @@ pub struct XIGrabDeviceRequest<'input> {
     pub owner_events: GrabOwner,
     pub mask: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 impl<'input> XIGrabDeviceRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -12455,12 +15699,87 @@ impl<'input> XIGrabDeviceRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), mask_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let time_bytes = self.time.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let paired_device_mode_bytes = u8::from(self.paired_device_mode).serialize();
+        let owner_events_bytes = bool::from(self.owner_events).serialize();
+        let mask_len = u16::try_from(self.mask.len()).expect("`mask` has too many elements");
+        let mask_len_bytes = mask_len.serialize();
+        write(&[
+            major_opcode,
+            XI_GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            mode_bytes[0],
+            paired_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            mask_len_bytes[0],
+            mask_len_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let mask_bytes = self.mask.serialize();
+        let length_so_far = length_so_far + mask_bytes.len();
+        write(&mask_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.mask.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIGrabDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12474,8 +15793,12 @@ impl<'input> XIGrabDeviceRequest<'input> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(mode)?;
         let (paired_device_mode, remaining) = u8::try_parse(remaining)?;
         let paired_device_mode = paired_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(paired_device_mode)?;
         let (owner_events, remaining) = bool::try_parse(remaining)?;
         let owner_events = owner_events.into();
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
@@ -12507,9 +15830,12 @@ impl<'input> XIGrabDeviceRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 impl<'input> Request for XIGrabDeviceRequest<'input> {
     type Reply = XIGrabDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
+#[inline]
 pub fn xi_grab_device<'c, 'input, Conn, A, B>(conn: &'c Conn, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: xproto::GrabMode, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &'input [u32]) -> Result<Cookie<'c, Conn, XIGrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12531,12 +15857,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGrabDeviceReply {
     pub sequence: u16,
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
 impl TryParse for XIGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12550,9 +15878,12 @@ impl TryParse for XIGrabDeviceReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = XIGrabDeviceReply { sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -12560,11 +15891,13 @@ impl TryParse for XIGrabDeviceReply {
 
 /// Opcode for the XIUngrabDevice request
 pub const XI_UNGRAB_DEVICE_REQUEST: u8 = 52;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIUngrabDeviceRequest {
     pub time: xproto::Timestamp,
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
 impl XIUngrabDeviceRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -12596,12 +15929,61 @@ impl XIUngrabDeviceRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12619,9 +16001,12 @@ impl XIUngrabDeviceRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
 impl Request for XIUngrabDeviceRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
+#[inline]
 pub fn xi_ungrab_device<Conn, A, B>(conn: &Conn, time: A, deviceid: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12706,9 +16091,21 @@ impl std::fmt::Debug for EventMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl EventMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=7) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the XIAllowEvents request
 pub const XI_ALLOW_EVENTS_REQUEST: u8 = 53;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIAllowEventsRequest {
     pub time: xproto::Timestamp,
@@ -12717,22 +16114,60 @@ pub struct XIAllowEventsRequest {
     pub touchid: u32,
     pub grab_window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
 impl XIAllowEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let event_mode_bytes = u8::from(self.event_mode).serialize();
+        let touchid_bytes = self.touchid.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            XI_ALLOW_EVENTS_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            event_mode_bytes[0],
+            0,
+            touchid_bytes[0],
+            touchid_bytes[1],
+            touchid_bytes[2],
+            touchid_bytes[3],
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let time_bytes = self.time.serialize();
         let deviceid_bytes = self.deviceid.serialize();
         let event_mode_bytes = u8::from(self.event_mode).serialize();
         let touchid_bytes = self.touchid.serialize();
         let grab_window_bytes = self.grab_window.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             XI_ALLOW_EVENTS_REQUEST,
             0,
             0,
@@ -12752,19 +16187,42 @@ impl XIAllowEventsRequest {
             grab_window_bytes[1],
             grab_window_bytes[2],
             grab_window_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 20;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12776,6 +16234,8 @@ impl XIAllowEventsRequest {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
         let (event_mode, remaining) = u8::try_parse(remaining)?;
         let event_mode = event_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        EventMode::check_known(event_mode)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (touchid, remaining) = u32::try_parse(remaining)?;
         let (grab_window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -12789,9 +16249,12 @@ impl XIAllowEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
 impl Request for XIAllowEventsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
+#[inline]
 pub fn xi_allow_events<Conn, A, B>(conn: &Conn, time: A, deviceid: B, event_mode: EventMode, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12869,6 +16332,17 @@ impl std::fmt::Debug for GrabMode22  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl GrabMode22 {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GrabType(u8);
@@ -12933,6 +16407,17 @@ impl std::fmt::Debug for GrabType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl GrabType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ModifierMask(u32);
@@ -12979,17 +16464,27 @@ impl std::fmt::Debug for ModifierMask  {
 }
 bitmask_binop!(ModifierMask, u32);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GrabModifierInfo {
     pub modifiers: u32,
     pub status: xproto::GrabStatus,
 }
+impl std::fmt::Debug for GrabModifierInfo {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GrabModifierInfo")
+            .field("modifiers", &ModifierMask::from(self.modifiers))
+            .field("status", &self.status)
+            .finish()
+    }
+}
 impl TryParse for GrabModifierInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (modifiers, remaining) = u32::try_parse(remaining)?;
         let (status, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabStatus::check_known(status)?;
         let result = GrabModifierInfo { modifiers, status };
         Ok((result, remaining))
     }
@@ -13020,6 +16515,7 @@ impl Serialize for GrabModifierInfo {
 
 /// Opcode for the XIPassiveGrabDevice request
 pub const XI_PASSIVE_GRAB_DEVICE_REQUEST: u8 = 54;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIPassiveGrabDeviceRequest<'input> {
     pub time: xproto::Timestamp,
@@ -13034,6 +16530,7 @@ pub struct XIPassiveGrabDeviceRequest<'input> {
     pub mask: Cow<'input, [u32]>,
     pub modifiers: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 impl<'input> XIPassiveGrabDeviceRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -13102,12 +16599,102 @@ impl<'input> XIPassiveGrabDeviceRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), mask_bytes.into(), modifiers_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let detail_bytes = self.detail.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let num_modifiers = u16::try_from(self.modifiers.len()).expect("`modifiers` has too many elements");
+        let num_modifiers_bytes = num_modifiers.serialize();
+        let mask_len = u16::try_from(self.mask.len()).expect("`mask` has too many elements");
+        let mask_len_bytes = mask_len.serialize();
+        let grab_type_bytes = u8::from(self.grab_type).serialize();
+        let grab_mode_bytes = u8::from(self.grab_mode).serialize();
+        let paired_device_mode_bytes = u8::from(self.paired_device_mode).serialize();
+        let owner_events_bytes = bool::from(self.owner_events).serialize();
+        write(&[
+            major_opcode,
+            XI_PASSIVE_GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            detail_bytes[0],
+            detail_bytes[1],
+            detail_bytes[2],
+            detail_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            num_modifiers_bytes[0],
+            num_modifiers_bytes[1],
+            mask_len_bytes[0],
+            mask_len_bytes[1],
+            grab_type_bytes[0],
+            grab_mode_bytes[0],
+            paired_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 32;
+        let mask_bytes = self.mask.serialize();
+        let length_so_far = length_so_far + mask_bytes.len();
+        write(&mask_bytes[..]);
+        let modifiers_bytes = self.modifiers.serialize();
+        let length_so_far = length_so_far + modifiers_bytes.len();
+        write(&modifiers_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 32 + self.mask.len() * 4 + self.modifiers.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIPassiveGrabDeviceReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13124,10 +16711,16 @@ impl<'input> XIPassiveGrabDeviceRequest<'input> {
         let (mask_len, remaining) = u16::try_parse(remaining)?;
         let (grab_type, remaining) = u8::try_parse(remaining)?;
         let grab_type = grab_type.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabType::check_known(grab_type)?;
         let (grab_mode, remaining) = u8::try_parse(remaining)?;
         let grab_mode = grab_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode22::check_known(grab_mode)?;
         let (paired_device_mode, remaining) = u8::try_parse(remaining)?;
         let paired_device_mode = paired_device_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::GrabMode::check_known(paired_device_mode)?;
         let (owner_events, remaining) = bool::try_parse(remaining)?;
         let owner_events = owner_events.into();
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -13165,9 +16758,12 @@ impl<'input> XIPassiveGrabDeviceRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 impl<'input> Request for XIPassiveGrabDeviceRequest<'input> {
     type Reply = XIPassiveGrabDeviceReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
+#[inline]
 pub fn xi_passive_grab_device<'c, 'input, Conn, A, B>(conn: &'c Conn, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: GrabType, grab_mode: GrabMode22, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &'input [u32], modifiers: &'input [u32]) -> Result<Cookie<'c, Conn, XIPassiveGrabDeviceReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13192,12 +16788,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIPassiveGrabDeviceReply {
     pub sequence: u16,
     pub length: u32,
     pub modifiers: Vec<GrabModifierInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 impl TryParse for XIPassiveGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13213,11 +16811,13 @@ impl TryParse for XIPassiveGrabDeviceReply {
         }
         let result = XIPassiveGrabDeviceReply { sequence, length, modifiers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
 impl XIPassiveGrabDeviceReply {
     /// Get the value of the `num_modifiers` field.
     ///
@@ -13236,6 +16836,7 @@ impl XIPassiveGrabDeviceReply {
 
 /// Opcode for the XIPassiveUngrabDevice request
 pub const XI_PASSIVE_UNGRAB_DEVICE_REQUEST: u8 = 55;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIPassiveUngrabDeviceRequest<'input> {
     pub grab_window: xproto::Window,
@@ -13244,6 +16845,7 @@ pub struct XIPassiveUngrabDeviceRequest<'input> {
     pub grab_type: GrabType,
     pub modifiers: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
 impl<'input> XIPassiveUngrabDeviceRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -13291,12 +16893,80 @@ impl<'input> XIPassiveUngrabDeviceRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), modifiers_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let grab_window_bytes = self.grab_window.serialize();
+        let detail_bytes = self.detail.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let num_modifiers = u16::try_from(self.modifiers.len()).expect("`modifiers` has too many elements");
+        let num_modifiers_bytes = num_modifiers.serialize();
+        let grab_type_bytes = u8::from(self.grab_type).serialize();
+        write(&[
+            major_opcode,
+            XI_PASSIVE_UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            detail_bytes[0],
+            detail_bytes[1],
+            detail_bytes[2],
+            detail_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            num_modifiers_bytes[0],
+            num_modifiers_bytes[1],
+            grab_type_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 20;
+        let modifiers_bytes = self.modifiers.serialize();
+        let length_so_far = length_so_far + modifiers_bytes.len();
+        write(&modifiers_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 20 + self.modifiers.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13310,6 +16980,8 @@ impl<'input> XIPassiveUngrabDeviceRequest<'input> {
         let (num_modifiers, remaining) = u16::try_parse(remaining)?;
         let (grab_type, remaining) = u8::try_parse(remaining)?;
         let grab_type = grab_type.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabType::check_known(grab_type)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let (modifiers, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_modifiers.try_to_usize()?)?;
         let _ = remaining;
@@ -13332,9 +17004,12 @@ impl<'input> XIPassiveUngrabDeviceRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
 impl<'input> Request for XIPassiveUngrabDeviceRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
+#[inline]
 pub fn xi_passive_ungrab_device<'c, 'input, Conn, A>(conn: &'c Conn, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: GrabType, modifiers: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13353,10 +17028,12 @@ where
 
 /// Opcode for the XIListProperties request
 pub const XI_LIST_PROPERTIES_REQUEST: u8 = 56;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIListPropertiesRequest {
     pub deviceid: DeviceId,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 impl XIListPropertiesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -13383,12 +17060,56 @@ impl XIListPropertiesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        write(&[
+            major_opcode,
+            XI_LIST_PROPERTIES_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIListPropertiesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13404,9 +17125,12 @@ impl XIListPropertiesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 impl Request for XIListPropertiesRequest {
     type Reply = XIListPropertiesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
+#[inline]
 pub fn xi_list_properties<Conn, A>(conn: &Conn, deviceid: A) -> Result<Cookie<'_, Conn, XIListPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13419,12 +17143,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIListPropertiesReply {
     pub sequence: u16,
     pub length: u32,
     pub properties: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 impl TryParse for XIListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13440,11 +17166,13 @@ impl TryParse for XIListPropertiesReply {
         }
         let result = XIListPropertiesReply { sequence, length, properties };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
 impl XIListPropertiesReply {
     /// Get the value of the `num_properties` field.
     ///
@@ -13461,6 +17189,9 @@ impl XIListPropertiesReply {
     }
 }
 
+/// Opcode for the XIChangeProperty request
+pub const XI_CHANGE_PROPERTY_REQUEST: u8 = 57;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum XIChangePropertyAux {
     Data8(Vec<u8>),
@@ -13476,6 +17207,7 @@ pub enum XIChangePropertyAux {
     /// will raise a panic.
     InvalidValue(u32),
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl XIChangePropertyAux {
     fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
@@ -13486,10 +17218,7 @@ impl XIChangePropertyAux {
             let value = remaining;
             let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
             let data8 = data8.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIChangePropertyAux::Data8(data8));
@@ -13498,10 +17227,7 @@ impl XIChangePropertyAux {
             let remaining = outer_remaining;
             let value = remaining;
             let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIChangePropertyAux::Data16(data16));
@@ -13514,11 +17240,15 @@ impl XIChangePropertyAux {
             parse_result = Some(XIChangePropertyAux::Data32(data32));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((XIChangePropertyAux::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl XIChangePropertyAux {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -13539,6 +17269,7 @@ impl XIChangePropertyAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl XIChangePropertyAux {
     #[allow(dead_code)]
     fn serialize(&self, format: u8, num_items: u32) -> Vec<u8> {
@@ -13567,6 +17298,7 @@ impl XIChangePropertyAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl XIChangePropertyAux {
     fn switch_expr(&self) -> u32 {
         match self {
@@ -13578,8 +17310,7 @@ impl XIChangePropertyAux {
     }
 }
 
-/// Opcode for the XIChangeProperty request
-pub const XI_CHANGE_PROPERTY_REQUEST: u8 = 57;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIChangePropertyRequest<'input> {
     pub deviceid: DeviceId,
@@ -13589,6 +17320,7 @@ pub struct XIChangePropertyRequest<'input> {
     pub num_items: u32,
     pub items: Cow<'input, XIChangePropertyAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl<'input> XIChangePropertyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -13637,12 +17369,82 @@ impl<'input> XIChangePropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), items_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        let format_bytes = format.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let num_items_bytes = self.num_items.serialize();
+        write(&[
+            major_opcode,
+            XI_CHANGE_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            mode_bytes[0],
+            format_bytes[0],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        let items_bytes = self.items.serialize(format, self.num_items);
+        let length_so_far = length_so_far + items_bytes.len();
+        write(&items_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let format = u8::try_from(self.items.switch_expr()).unwrap();
+        let length_so_far = 20 + self.items.serialize(format, self.num_items).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13653,7 +17455,11 @@ impl<'input> XIChangePropertyRequest<'input> {
         let (deviceid, remaining) = DeviceId::try_parse(value)?;
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::PropMode::check_known(mode)?;
         let (format, remaining) = u8::try_parse(remaining)?;
+        #[cfg(feature = "strict_parsing")]
+        PropertyFormat::check_known(format.into())?;
         let (property, remaining) = xproto::Atom::try_parse(remaining)?;
         let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
         let (num_items, remaining) = u32::try_parse(remaining)?;
@@ -13680,9 +17486,12 @@ impl<'input> XIChangePropertyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
 impl<'input> Request for XIChangePropertyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
+#[inline]
 pub fn xi_change_property<'c, 'input, Conn, A>(conn: &'c Conn, deviceid: A, mode: xproto::PropMode, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &'input XIChangePropertyAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13702,11 +17511,13 @@ where
 
 /// Opcode for the XIDeleteProperty request
 pub const XI_DELETE_PROPERTY_REQUEST: u8 = 58;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIDeletePropertyRequest {
     pub deviceid: DeviceId,
     pub property: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
 impl XIDeletePropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -13738,12 +17549,61 @@ impl XIDeletePropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            major_opcode,
+            XI_DELETE_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13761,9 +17621,12 @@ impl XIDeletePropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
 impl Request for XIDeletePropertyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
+#[inline]
 pub fn xi_delete_property<Conn, A>(conn: &Conn, deviceid: A, property: xproto::Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13779,6 +17642,7 @@ where
 
 /// Opcode for the XIGetProperty request
 pub const XI_GET_PROPERTY_REQUEST: u8 = 59;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetPropertyRequest {
     pub deviceid: DeviceId,
@@ -13788,14 +17652,57 @@ pub struct XIGetPropertyRequest {
     pub offset: u32,
     pub len: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl XIGetPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let deviceid_bytes = self.deviceid.serialize();
+        let delete_bytes = self.delete.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let offset_bytes = self.offset.serialize();
+        let len_bytes = self.len.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            XI_GET_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            delete_bytes[0],
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            len_bytes[0],
+            len_bytes[1],
+            len_bytes[2],
+            len_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let deviceid_bytes = self.deviceid.serialize();
         let delete_bytes = self.delete.serialize();
@@ -13803,8 +17710,8 @@ impl XIGetPropertyRequest {
         let type_bytes = self.type_.serialize();
         let offset_bytes = self.offset.serialize();
         let len_bytes = self.len.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             XI_GET_PROPERTY_REQUEST,
             0,
             0,
@@ -13828,19 +17735,42 @@ impl XIGetPropertyRequest {
             len_bytes[1],
             len_bytes[2],
             len_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 24;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIGetPropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13866,9 +17796,12 @@ impl XIGetPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl Request for XIGetPropertyRequest {
     type Reply = XIGetPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
+#[inline]
 pub fn xi_get_property<Conn, A>(conn: &Conn, deviceid: A, delete: bool, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32) -> Result<Cookie<'_, Conn, XIGetPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13886,6 +17819,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum XIGetPropertyItems {
     Data8(Vec<u8>),
@@ -13901,6 +17835,7 @@ pub enum XIGetPropertyItems {
     /// will raise a panic.
     InvalidValue(u32),
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl XIGetPropertyItems {
     fn try_parse(value: &[u8], format: u8, num_items: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(format);
@@ -13911,10 +17846,7 @@ impl XIGetPropertyItems {
             let value = remaining;
             let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
             let data8 = data8.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data8(data8));
@@ -13923,10 +17855,7 @@ impl XIGetPropertyItems {
             let remaining = outer_remaining;
             let value = remaining;
             let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data16(data16));
@@ -13939,11 +17868,15 @@ impl XIGetPropertyItems {
             parse_result = Some(XIGetPropertyItems::Data32(data32));
         }
         match parse_result {
+            #[cfg(not(feature = "strict_parsing"))]
             None => Ok((XIGetPropertyItems::InvalidValue(switch_expr), &[])),
+            #[cfg(feature = "strict_parsing")]
+            None => Err(ParseError::InvalidValue),
             Some(result) => Ok((result, outer_remaining)),
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl XIGetPropertyItems {
     pub fn as_data8(&self) -> Option<&Vec<u8>> {
         match self {
@@ -13965,6 +17898,7 @@ impl XIGetPropertyItems {
     }
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIGetPropertyReply {
     pub sequence: u16,
@@ -13974,6 +17908,7 @@ pub struct XIGetPropertyReply {
     pub num_items: u32,
     pub items: XIGetPropertyItems,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
 impl TryParse for XIGetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13990,9 +17925,12 @@ impl TryParse for XIGetPropertyReply {
         if response_type != 1 {
             return Err(ParseError::InvalidValue);
         }
+        #[cfg(feature = "strict_parsing")]
+        PropertyFormat::check_known(format.into())?;
         let result = XIGetPropertyReply { sequence, length, type_, bytes_after, num_items, items };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -14000,10 +17938,12 @@ impl TryParse for XIGetPropertyReply {
 
 /// Opcode for the XIGetSelectedEvents request
 pub const XI_GET_SELECTED_EVENTS_REQUEST: u8 = 60;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct XIGetSelectedEventsRequest {
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 impl XIGetSelectedEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -14030,12 +17970,56 @@ impl XIGetSelectedEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            XI_GET_SELECTED_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, XIGetSelectedEventsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -14050,9 +18034,12 @@ impl XIGetSelectedEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 impl Request for XIGetSelectedEventsRequest {
     type Reply = XIGetSelectedEventsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
+#[inline]
 pub fn xi_get_selected_events<Conn>(conn: &Conn, window: xproto::Window) -> Result<Cookie<'_, Conn, XIGetSelectedEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14063,12 +18050,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIGetSelectedEventsReply {
     pub sequence: u16,
     pub length: u32,
     pub masks: Vec<EventMask>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 impl TryParse for XIGetSelectedEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14084,11 +18073,13 @@ impl TryParse for XIGetSelectedEventsReply {
         }
         let result = XIGetSelectedEventsReply { sequence, length, masks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
 impl XIGetSelectedEventsReply {
     /// Get the value of the `num_masks` field.
     ///
@@ -14153,10 +18144,12 @@ impl Serialize for BarrierReleasePointerInfo {
 
 /// Opcode for the XIBarrierReleasePointer request
 pub const XI_BARRIER_RELEASE_POINTER_REQUEST: u8 = 61;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct XIBarrierReleasePointerRequest<'input> {
     pub barriers: Cow<'input, [BarrierReleasePointerInfo]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
 impl<'input> XIBarrierReleasePointerRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -14188,12 +18181,64 @@ impl<'input> XIBarrierReleasePointerRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), barriers_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let num_barriers = u32::try_from(self.barriers.len()).expect("`barriers` has too many elements");
+        let num_barriers_bytes = num_barriers.serialize();
+        write(&[
+            major_opcode,
+            XI_BARRIER_RELEASE_POINTER_REQUEST,
+            0,
+            0,
+            num_barriers_bytes[0],
+            num_barriers_bytes[1],
+            num_barriers_bytes[2],
+            num_barriers_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let barriers_bytes = self.barriers.serialize();
+        let length_so_far = length_so_far + barriers_bytes.len();
+        write(&barriers_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.barriers.len() * 12;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -14215,9 +18260,12 @@ impl<'input> XIBarrierReleasePointerRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
 impl<'input> Request for XIBarrierReleasePointerRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
+#[inline]
 pub fn xi_barrier_release_pointer<'c, 'input, Conn>(conn: &'c Conn, barriers: &'input [BarrierReleasePointerInfo]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14385,7 +18433,7 @@ bitmask_binop!(MoreEventsMask, u8);
 
 /// Opcode for the DeviceKeyPress event
 pub const DEVICE_KEY_PRESS_EVENT: u8 = 1;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DeviceKeyPressEvent {
     pub response_type: u8,
     pub detail: u8,
@@ -14402,6 +18450,26 @@ pub struct DeviceKeyPressEvent {
     pub same_screen: bool,
     pub device_id: u8,
 }
+impl std::fmt::Debug for DeviceKeyPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DeviceKeyPressEvent")
+            .field("response_type", &self.response_type)
+            .field("detail", &self.detail)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("state", &xproto::KeyButMask::from(self.state))
+            .field("same_screen", &self.same_screen)
+            .field("device_id", &MoreEventsMask::from(self.device_id))
+            .finish()
+    }
+}
 impl TryParse for DeviceKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14524,7 +18592,11 @@ impl TryParse for DeviceFocusInEvent {
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
         let detail = detail.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::NotifyDetail::check_known(detail)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::NotifyMode::check_known(mode)?;
         let result = DeviceFocusInEvent { response_type, detail, sequence, time, window, mode, device_id };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -14662,7 +18734,7 @@ bitmask_binop!(ClassesReportedMask, u8);
 
 /// Opcode for the DeviceStateNotify event
 pub const DEVICE_STATE_NOTIFY_EVENT: u8 = 10;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DeviceStateNotifyEvent {
     pub response_type: u8,
     pub device_id: u8,
@@ -14676,6 +18748,23 @@ pub struct DeviceStateNotifyEvent {
     pub keys: [u8; 4],
     pub valuators: [u32; 3],
 }
+impl std::fmt::Debug for DeviceStateNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DeviceStateNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("device_id", &MoreEventsMask::from(self.device_id))
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("num_keys", &self.num_keys)
+            .field("num_buttons", &self.num_buttons)
+            .field("num_valuators", &self.num_valuators)
+            .field("classes_reported", &ClassesReportedMask::from(self.classes_reported))
+            .field("buttons", &self.buttons)
+            .field("keys", &self.keys)
+            .field("valuators", &self.valuators)
+            .finish()
+    }
+}
 impl TryParse for DeviceStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14786,6 +18875,8 @@ impl TryParse for DeviceMappingNotifyEvent {
         let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
         let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
         let request = request.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::Mapping::check_known(request)?;
         let result = DeviceMappingNotifyEvent { response_type, device_id, sequence, request, first_keycode, count, time };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -14901,6 +18992,17 @@ impl std::fmt::Debug for ChangeDevice  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ChangeDevice {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ChangeDeviceNotify event
 pub const CHANGE_DEVICE_NOTIFY_EVENT: u8 = 12;
@@ -14922,6 +19024,8 @@ impl TryParse for ChangeDeviceNotifyEvent {
         let (request, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
         let request = request.into();
+        #[cfg(feature = "strict_parsing")]
+        ChangeDevice::check_known(request)?;
         let result = ChangeDeviceNotifyEvent { response_type, device_id, sequence, time, request };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -14980,13 +19084,23 @@ impl From<ChangeDeviceNotifyEvent> for [u8; 32] {
 
 /// Opcode for the DeviceKeyStateNotify event
 pub const DEVICE_KEY_STATE_NOTIFY_EVENT: u8 = 13;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DeviceKeyStateNotifyEvent {
     pub response_type: u8,
     pub device_id: u8,
     pub sequence: u16,
     pub keys: [u8; 28],
 }
+impl std::fmt::Debug for DeviceKeyStateNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DeviceKeyStateNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("device_id", &MoreEventsMask::from(self.device_id))
+            .field("sequence", &self.sequence)
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
 impl TryParse for DeviceKeyStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15051,13 +19165,23 @@ impl From<DeviceKeyStateNotifyEvent> for [u8; 32] {
 
 /// Opcode for the DeviceButtonStateNotify event
 pub const DEVICE_BUTTON_STATE_NOTIFY_EVENT: u8 = 14;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct DeviceButtonStateNotifyEvent {
     pub response_type: u8,
     pub device_id: u8,
     pub sequence: u16,
     pub buttons: [u8; 28],
 }
+impl std::fmt::Debug for DeviceButtonStateNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DeviceButtonStateNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("device_id", &MoreEventsMask::from(self.device_id))
+            .field("sequence", &self.sequence)
+            .field("buttons", &self.buttons)
+            .finish()
+    }
+}
 impl TryParse for DeviceButtonStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15185,6 +19309,17 @@ impl std::fmt::Debug for DeviceChange  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl DeviceChange {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the DevicePresenceNotify event
 pub const DEVICE_PRESENCE_NOTIFY_EVENT: u8 = 15;
@@ -15209,6 +19344,8 @@ impl TryParse for DevicePresenceNotifyEvent {
         let (control, remaining) = u16::try_parse(remaining)?;
         let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
         let devchange = devchange.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceChange::check_known(devchange)?;
         let result = DevicePresenceNotifyEvent { response_type, sequence, time, devchange, device_id, control };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -15288,6 +19425,8 @@ impl TryParse for DevicePropertyNotifyEvent {
         let remaining = remaining.get(19..).ok_or(ParseError::InsufficientData)?;
         let (device_id, remaining) = u8::try_parse(remaining)?;
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        xproto::Property::check_known(state)?;
         let result = DevicePropertyNotifyEvent { response_type, state, sequence, time, property, device_id };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -15402,6 +19541,17 @@ impl std::fmt::Debug for ChangeReason  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ChangeReason {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 1 | 2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the DeviceChanged event
 pub const DEVICE_CHANGED_EVENT: u16 = 1;
@@ -15434,9 +19584,12 @@ impl TryParse for DeviceChangedEvent {
         let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
         let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?)?;
         let reason = reason.into();
+        #[cfg(feature = "strict_parsing")]
+        ChangeReason::check_known(reason)?;
         let result = DeviceChangedEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, reason, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -15504,7 +19657,7 @@ bitmask_binop!(KeyEventFlags, u32);
 
 /// Opcode for the KeyPress event
 pub const KEY_PRESS_EVENT: u16 = 2;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct KeyPressEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -15529,6 +19682,34 @@ pub struct KeyPressEvent {
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
 }
+impl std::fmt::Debug for KeyPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KeyPressEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &KeyEventFlags::from(self.flags))
+            .field("mods", &self.mods)
+            .field("group", &self.group)
+            .field("button_mask", &self.button_mask)
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .finish()
+    }
+}
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15559,7 +19740,8 @@ impl TryParse for KeyPressEvent {
         let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = KeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -15644,7 +19826,7 @@ bitmask_binop!(PointerEventFlags, u32);
 
 /// Opcode for the ButtonPress event
 pub const BUTTON_PRESS_EVENT: u16 = 4;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct ButtonPressEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -15669,6 +19851,34 @@ pub struct ButtonPressEvent {
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
 }
+impl std::fmt::Debug for ButtonPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ButtonPressEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &PointerEventFlags::from(self.flags))
+            .field("mods", &self.mods)
+            .field("group", &self.group)
+            .field("button_mask", &self.button_mask)
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .finish()
+    }
+}
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15699,7 +19909,8 @@ impl TryParse for ButtonPressEvent {
         let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = ButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -15806,6 +20017,17 @@ impl std::fmt::Debug for NotifyMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl NotifyMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct NotifyDetail(u8);
@@ -15876,6 +20098,17 @@ impl std::fmt::Debug for NotifyDetail  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl NotifyDetail {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=7) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the Enter event
 pub const ENTER_EVENT: u16 = 7;
@@ -15931,10 +20164,15 @@ impl TryParse for EnterEvent {
         let (group, remaining) = GroupInfo::try_parse(remaining)?;
         let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyMode::check_known(mode)?;
         let detail = detail.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyDetail::check_known(detail)?;
         let result = EnterEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16055,6 +20293,8 @@ impl TryParse for HierarchyInfo {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (flags, remaining) = u32::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        DeviceType::check_known(type_)?;
         let result = HierarchyInfo { deviceid, attachment, type_, enabled, flags };
         Ok((result, remaining))
     }
@@ -16123,7 +20363,8 @@ impl TryParse for HierarchyEvent {
         let (infos, remaining) = crate::x11_utils::parse_list::<HierarchyInfo>(remaining, num_infos.try_to_usize()?)?;
         let result = HierarchyEvent { response_type, extension, sequence, length, event_type, deviceid, time, flags, infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16203,6 +20444,17 @@ impl std::fmt::Debug for PropertyFlag  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PropertyFlag {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the Property event
 pub const PROPERTY_EVENT: u16 = 12;
@@ -16232,9 +20484,12 @@ impl TryParse for PropertyEvent {
         let (what, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
         let what = what.into();
+        #[cfg(feature = "strict_parsing")]
+        PropertyFlag::check_known(what)?;
         let result = PropertyEvent { response_type, extension, sequence, length, event_type, deviceid, time, property, what };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16242,7 +20497,7 @@ impl TryParse for PropertyEvent {
 
 /// Opcode for the RawKeyPress event
 pub const RAW_KEY_PRESS_EVENT: u16 = 13;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct RawKeyPressEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -16258,6 +20513,25 @@ pub struct RawKeyPressEvent {
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
+impl std::fmt::Debug for RawKeyPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RawKeyPressEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &KeyEventFlags::from(self.flags))
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .field("axisvalues_raw", &self.axisvalues_raw)
+            .finish()
+    }
+}
 impl TryParse for RawKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16278,7 +20552,8 @@ impl TryParse for RawKeyPressEvent {
         let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = RawKeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16305,7 +20580,7 @@ pub type RawKeyReleaseEvent = RawKeyPressEvent;
 
 /// Opcode for the RawButtonPress event
 pub const RAW_BUTTON_PRESS_EVENT: u16 = 15;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct RawButtonPressEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -16321,6 +20596,25 @@ pub struct RawButtonPressEvent {
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
+impl std::fmt::Debug for RawButtonPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RawButtonPressEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &PointerEventFlags::from(self.flags))
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .field("axisvalues_raw", &self.axisvalues_raw)
+            .finish()
+    }
+}
 impl TryParse for RawButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16341,7 +20635,8 @@ impl TryParse for RawButtonPressEvent {
         let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = RawButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16419,7 +20714,7 @@ bitmask_binop!(TouchEventFlags, u32);
 
 /// Opcode for the TouchBegin event
 pub const TOUCH_BEGIN_EVENT: u16 = 18;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct TouchBeginEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -16444,6 +20739,34 @@ pub struct TouchBeginEvent {
     pub valuator_mask: Vec<u32>,
     pub axisvalues: Vec<Fp3232>,
 }
+impl std::fmt::Debug for TouchBeginEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("TouchBeginEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &TouchEventFlags::from(self.flags))
+            .field("mods", &self.mods)
+            .field("group", &self.group)
+            .field("button_mask", &self.button_mask)
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .finish()
+    }
+}
 impl TryParse for TouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16474,7 +20797,8 @@ impl TryParse for TouchBeginEvent {
         let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = TouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16559,6 +20883,17 @@ impl std::fmt::Debug for TouchOwnershipFlags  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl TouchOwnershipFlags {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the TouchOwnership event
 pub const TOUCH_OWNERSHIP_EVENT: u16 = 21;
@@ -16597,9 +20932,12 @@ impl TryParse for TouchOwnershipEvent {
         let (flags, remaining) = u32::try_parse(remaining)?;
         let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
         let flags = flags.into();
+        #[cfg(feature = "strict_parsing")]
+        TouchOwnershipFlags::check_known(flags)?;
         let result = TouchOwnershipEvent { response_type, extension, sequence, length, event_type, deviceid, time, touchid, root, event, child, sourceid, flags };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16607,7 +20945,7 @@ impl TryParse for TouchOwnershipEvent {
 
 /// Opcode for the RawTouchBegin event
 pub const RAW_TOUCH_BEGIN_EVENT: u16 = 22;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct RawTouchBeginEvent {
     pub response_type: u8,
     pub extension: u8,
@@ -16623,6 +20961,25 @@ pub struct RawTouchBeginEvent {
     pub axisvalues: Vec<Fp3232>,
     pub axisvalues_raw: Vec<Fp3232>,
 }
+impl std::fmt::Debug for RawTouchBeginEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RawTouchBeginEvent")
+            .field("response_type", &self.response_type)
+            .field("extension", &self.extension)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("event_type", &self.event_type)
+            .field("deviceid", &self.deviceid)
+            .field("time", &self.time)
+            .field("detail", &self.detail)
+            .field("sourceid", &self.sourceid)
+            .field("flags", &TouchEventFlags::from(self.flags))
+            .field("valuator_mask", &self.valuator_mask)
+            .field("axisvalues", &self.axisvalues)
+            .field("axisvalues_raw", &self.axisvalues_raw)
+            .finish()
+    }
+}
 impl TryParse for RawTouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16643,7 +21000,8 @@ impl TryParse for RawTouchBeginEvent {
         let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add((*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = RawTouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16778,7 +21136,8 @@ impl TryParse for BarrierHitEvent {
         let (dy, remaining) = Fp3232::try_parse(remaining)?;
         let result = BarrierHitEvent { response_type, extension, sequence, length, event_type, deviceid, time, eventid, root, event, barrier, dtime, flags, sourceid, root_x, root_y, dx, dy };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -16999,6 +21358,7 @@ impl From<&DevicePropertyNotifyEvent> for EventForSend {
 
 /// Opcode for the SendExtensionEvent request
 pub const SEND_EXTENSION_EVENT_REQUEST: u8 = 31;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
 #[derive(Debug, Clone)]
 pub struct SendExtensionEventRequest<'input> {
     pub destination: xproto::Window,
@@ -17007,6 +21367,7 @@ pub struct SendExtensionEventRequest<'input> {
     pub events: Cow<'input, [EventForSend]>,
     pub classes: Cow<'input, [EventClass]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
 impl<'input> SendExtensionEventRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -17053,12 +21414,80 @@ impl<'input> SendExtensionEventRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), events_bytes.into(), classes_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let destination_bytes = self.destination.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let propagate_bytes = self.propagate.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let num_events = u8::try_from(self.events.len()).expect("`events` has too many elements");
+        let num_events_bytes = num_events.serialize();
+        write(&[
+            major_opcode,
+            SEND_EXTENSION_EVENT_REQUEST,
+            0,
+            0,
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            device_id_bytes[0],
+            propagate_bytes[0],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            num_events_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let events_bytes = self.events.serialize();
+        let length_so_far = length_so_far + events_bytes.len();
+        write(&events_bytes[..]);
+        let classes_bytes = self.classes.serialize();
+        let length_so_far = length_so_far + classes_bytes.len();
+        write(&classes_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.events.len() * 32 + self.classes.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17094,9 +21523,12 @@ impl<'input> SendExtensionEventRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
 impl<'input> Request for SendExtensionEventRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
+#[inline]
 pub fn send_extension_event<'c, 'input, Conn>(conn: &'c Conn, destination: xproto::Window, device_id: u8, propagate: bool, events: &'input [EventForSend], classes: &'input [EventClass]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17128,68 +21560,98 @@ pub const CLASS_ERROR: u8 = 4;
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetExtensionVersion"))]
+    #[inline]
     fn xinput_get_extension_version<'c, 'input>(&'c self, name: &'input [u8]) -> Result<Cookie<'c, Self, GetExtensionVersionReply>, ConnectionError>
     {
         get_extension_version(self, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListInputDevices"))]
+    #[inline]
     fn xinput_list_input_devices(&self) -> Result<Cookie<'_, Self, ListInputDevicesReply>, ConnectionError>
     {
         list_input_devices(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::OpenDevice"))]
+    #[inline]
     fn xinput_open_device(&self, device_id: u8) -> Result<Cookie<'_, Self, OpenDeviceReply>, ConnectionError>
     {
         open_device(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::CloseDevice"))]
+    #[inline]
     fn xinput_close_device(&self, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         close_device(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceMode"))]
+    #[inline]
     fn xinput_set_device_mode(&self, device_id: u8, mode: ValuatorMode) -> Result<Cookie<'_, Self, SetDeviceModeReply>, ConnectionError>
     {
         set_device_mode(self, device_id, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SelectExtensionEvent"))]
+    #[inline]
     fn xinput_select_extension_event<'c, 'input>(&'c self, window: xproto::Window, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         select_extension_event(self, window, classes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetSelectedExtensionEvents"))]
+    #[inline]
     fn xinput_get_selected_extension_events(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetSelectedExtensionEventsReply>, ConnectionError>
     {
         get_selected_extension_events(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceDontPropagateList"))]
+    #[inline]
     fn xinput_change_device_dont_propagate_list<'c, 'input>(&'c self, window: xproto::Window, mode: PropagateMode, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_device_dont_propagate_list(self, window, mode, classes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceDontPropagateList"))]
+    #[inline]
     fn xinput_get_device_dont_propagate_list(&self, window: xproto::Window) -> Result<Cookie<'_, Self, GetDeviceDontPropagateListReply>, ConnectionError>
     {
         get_device_dont_propagate_list(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceMotionEvents"))]
+    #[inline]
     fn xinput_get_device_motion_events<A>(&self, start: xproto::Timestamp, stop: A, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceMotionEventsReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
     {
         get_device_motion_events(self, start, stop, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeKeyboardDevice"))]
+    #[inline]
     fn xinput_change_keyboard_device(&self, device_id: u8) -> Result<Cookie<'_, Self, ChangeKeyboardDeviceReply>, ConnectionError>
     {
         change_keyboard_device(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangePointerDevice"))]
+    #[inline]
     fn xinput_change_pointer_device(&self, x_axis: u8, y_axis: u8, device_id: u8) -> Result<Cookie<'_, Self, ChangePointerDeviceReply>, ConnectionError>
     {
         change_pointer_device(self, x_axis, y_axis, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDevice"))]
+    #[inline]
     fn xinput_grab_device<'c, 'input, A>(&'c self, grab_window: xproto::Window, time: A, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, device_id: u8, classes: &'input [EventClass]) -> Result<Cookie<'c, Self, GrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
     {
         grab_device(self, grab_window, time, this_device_mode, other_device_mode, owner_events, device_id, classes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDevice"))]
+    #[inline]
     fn xinput_ungrab_device<A>(&self, time: A, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
     {
         ungrab_device(self, time, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceKey"))]
+    #[inline]
     fn xinput_grab_device_key<'c, 'input, A, B, C>(&'c self, grab_window: xproto::Window, modifiers: A, modifier_device: B, grabbed_device: u8, key: C, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, owner_events: bool, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -17198,6 +21660,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         grab_device_key(self, grab_window, modifiers, modifier_device, grabbed_device, key, this_device_mode, other_device_mode, owner_events, classes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceKey"))]
+    #[inline]
     fn xinput_ungrab_device_key<A, B, C>(&self, grab_window: xproto::Window, modifiers: A, modifier_device: B, key: C, grabbed_device: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -17206,6 +21670,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         ungrab_device_key(self, grab_window, modifiers, modifier_device, key, grabbed_device)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GrabDeviceButton"))]
+    #[inline]
     fn xinput_grab_device_button<'c, 'input, A, B, C>(&'c self, grab_window: xproto::Window, grabbed_device: u8, modifier_device: A, modifiers: B, this_device_mode: xproto::GrabMode, other_device_mode: xproto::GrabMode, button: C, owner_events: bool, classes: &'input [EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u8>,
@@ -17214,6 +21680,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         grab_device_button(self, grab_window, grabbed_device, modifier_device, modifiers, this_device_mode, other_device_mode, button, owner_events, classes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::UngrabDeviceButton"))]
+    #[inline]
     fn xinput_ungrab_device_button<A, B, C>(&self, grab_window: xproto::Window, modifiers: A, modifier_device: B, button: C, grabbed_device: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -17222,16 +21690,22 @@ pub trait ConnectionExt: RequestConnection {
     {
         ungrab_device_button(self, grab_window, modifiers, modifier_device, button, grabbed_device)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::AllowDeviceEvents"))]
+    #[inline]
     fn xinput_allow_device_events<A>(&self, time: A, mode: DeviceInputMode, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
     {
         allow_device_events(self, time, mode, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceFocus"))]
+    #[inline]
     fn xinput_get_device_focus(&self, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceFocusReply>, ConnectionError>
     {
         get_device_focus(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceFocus"))]
+    #[inline]
     fn xinput_set_device_focus<A, B>(&self, focus: A, time: B, revert_to: xproto::InputFocus, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Window>,
@@ -17239,122 +21713,176 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_device_focus(self, focus, time, revert_to, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetFeedbackControl"))]
+    #[inline]
     fn xinput_get_feedback_control(&self, device_id: u8) -> Result<Cookie<'_, Self, GetFeedbackControlReply>, ConnectionError>
     {
         get_feedback_control(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeFeedbackControl"))]
+    #[inline]
     fn xinput_change_feedback_control<A>(&self, mask: A, device_id: u8, feedback_id: u8, feedback: FeedbackCtl) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         change_feedback_control(self, mask, device_id, feedback_id, feedback)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceKeyMapping"))]
+    #[inline]
     fn xinput_get_device_key_mapping(&self, device_id: u8, first_keycode: KeyCode, count: u8) -> Result<Cookie<'_, Self, GetDeviceKeyMappingReply>, ConnectionError>
     {
         get_device_key_mapping(self, device_id, first_keycode, count)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceKeyMapping"))]
+    #[inline]
     fn xinput_change_device_key_mapping<'c, 'input>(&'c self, device_id: u8, first_keycode: KeyCode, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &'input [xproto::Keysym]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_device_key_mapping(self, device_id, first_keycode, keysyms_per_keycode, keycode_count, keysyms)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceModifierMapping"))]
+    #[inline]
     fn xinput_get_device_modifier_mapping(&self, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceModifierMappingReply>, ConnectionError>
     {
         get_device_modifier_mapping(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceModifierMapping"))]
+    #[inline]
     fn xinput_set_device_modifier_mapping<'c, 'input>(&'c self, device_id: u8, keymaps: &'input [u8]) -> Result<Cookie<'c, Self, SetDeviceModifierMappingReply>, ConnectionError>
     {
         set_device_modifier_mapping(self, device_id, keymaps)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceButtonMapping"))]
+    #[inline]
     fn xinput_get_device_button_mapping(&self, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceButtonMappingReply>, ConnectionError>
     {
         get_device_button_mapping(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceButtonMapping"))]
+    #[inline]
     fn xinput_set_device_button_mapping<'c, 'input>(&'c self, device_id: u8, map: &'input [u8]) -> Result<Cookie<'c, Self, SetDeviceButtonMappingReply>, ConnectionError>
     {
         set_device_button_mapping(self, device_id, map)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::QueryDeviceState"))]
+    #[inline]
     fn xinput_query_device_state(&self, device_id: u8) -> Result<Cookie<'_, Self, QueryDeviceStateReply>, ConnectionError>
     {
         query_device_state(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeviceBell"))]
+    #[inline]
     fn xinput_device_bell(&self, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         device_bell(self, device_id, feedback_id, feedback_class, percent)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SetDeviceValuators"))]
+    #[inline]
     fn xinput_set_device_valuators<'c, 'input>(&'c self, device_id: u8, first_valuator: u8, valuators: &'input [i32]) -> Result<Cookie<'c, Self, SetDeviceValuatorsReply>, ConnectionError>
     {
         set_device_valuators(self, device_id, first_valuator, valuators)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceControl"))]
+    #[inline]
     fn xinput_get_device_control(&self, control_id: DeviceControl, device_id: u8) -> Result<Cookie<'_, Self, GetDeviceControlReply>, ConnectionError>
     {
         get_device_control(self, control_id, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceControl"))]
+    #[inline]
     fn xinput_change_device_control(&self, control_id: DeviceControl, device_id: u8, control: DeviceCtl) -> Result<Cookie<'_, Self, ChangeDeviceControlReply>, ConnectionError>
     {
         change_device_control(self, control_id, device_id, control)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ListDeviceProperties"))]
+    #[inline]
     fn xinput_list_device_properties(&self, device_id: u8) -> Result<Cookie<'_, Self, ListDevicePropertiesReply>, ConnectionError>
     {
         list_device_properties(self, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::ChangeDeviceProperty"))]
+    #[inline]
     fn xinput_change_device_property<'c, 'input>(&'c self, property: xproto::Atom, type_: xproto::Atom, device_id: u8, mode: xproto::PropMode, num_items: u32, items: &'input ChangeDevicePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_device_property(self, property, type_, device_id, mode, num_items, items)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::DeleteDeviceProperty"))]
+    #[inline]
     fn xinput_delete_device_property(&self, property: xproto::Atom, device_id: u8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_device_property(self, property, device_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::GetDeviceProperty"))]
+    #[inline]
     fn xinput_get_device_property(&self, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32, device_id: u8, delete: bool) -> Result<Cookie<'_, Self, GetDevicePropertyReply>, ConnectionError>
     {
         get_device_property(self, property, type_, offset, len, device_id, delete)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryPointer"))]
+    #[inline]
     fn xinput_xi_query_pointer<A>(&self, window: xproto::Window, deviceid: A) -> Result<Cookie<'_, Self, XIQueryPointerReply>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_query_pointer(self, window, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIWarpPointer"))]
+    #[inline]
     fn xinput_xi_warp_pointer<A>(&self, src_win: xproto::Window, dst_win: xproto::Window, src_x: Fp1616, src_y: Fp1616, src_width: u16, src_height: u16, dst_x: Fp1616, dst_y: Fp1616, deviceid: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_warp_pointer(self, src_win, dst_win, src_x, src_y, src_width, src_height, dst_x, dst_y, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeCursor"))]
+    #[inline]
     fn xinput_xi_change_cursor<A>(&self, window: xproto::Window, cursor: xproto::Cursor, deviceid: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_change_cursor(self, window, cursor, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeHierarchy"))]
+    #[inline]
     fn xinput_xi_change_hierarchy<'c, 'input>(&'c self, changes: &'input [HierarchyChange]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         xi_change_hierarchy(self, changes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetClientPointer"))]
+    #[inline]
     fn xinput_xi_set_client_pointer<A>(&self, window: xproto::Window, deviceid: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_set_client_pointer(self, window, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetClientPointer"))]
+    #[inline]
     fn xinput_xi_get_client_pointer(&self, window: xproto::Window) -> Result<Cookie<'_, Self, XIGetClientPointerReply>, ConnectionError>
     {
         xi_get_client_pointer(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISelectEvents"))]
+    #[inline]
     fn xinput_xi_select_events<'c, 'input>(&'c self, window: xproto::Window, masks: &'input [EventMask]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         xi_select_events(self, window, masks)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryVersion"))]
+    #[inline]
     fn xinput_xi_query_version(&self, major_version: u16, minor_version: u16) -> Result<Cookie<'_, Self, XIQueryVersionReply>, ConnectionError>
     {
         xi_query_version(self, major_version, minor_version)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIQueryDevice"))]
+    #[inline]
     fn xinput_xi_query_device<A>(&self, deviceid: A) -> Result<Cookie<'_, Self, XIQueryDeviceReply>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_query_device(self, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XISetFocus"))]
+    #[inline]
     fn xinput_xi_set_focus<A, B>(&self, window: xproto::Window, time: A, deviceid: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
@@ -17362,12 +21890,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_set_focus(self, window, time, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetFocus"))]
+    #[inline]
     fn xinput_xi_get_focus<A>(&self, deviceid: A) -> Result<Cookie<'_, Self, XIGetFocusReply>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_get_focus(self, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGrabDevice"))]
+    #[inline]
     fn xinput_xi_grab_device<'c, 'input, A, B>(&'c self, window: xproto::Window, time: A, cursor: xproto::Cursor, deviceid: B, mode: xproto::GrabMode, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &'input [u32]) -> Result<Cookie<'c, Self, XIGrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
@@ -17375,6 +21907,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_grab_device(self, window, time, cursor, deviceid, mode, paired_device_mode, owner_events, mask)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIUngrabDevice"))]
+    #[inline]
     fn xinput_xi_ungrab_device<A, B>(&self, time: A, deviceid: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
@@ -17382,6 +21916,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_ungrab_device(self, time, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIAllowEvents"))]
+    #[inline]
     fn xinput_xi_allow_events<A, B>(&self, time: A, deviceid: B, event_mode: EventMode, touchid: u32, grab_window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
@@ -17389,6 +21925,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_allow_events(self, time, deviceid, event_mode, touchid, grab_window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveGrabDevice"))]
+    #[inline]
     fn xinput_xi_passive_grab_device<'c, 'input, A, B>(&'c self, time: A, grab_window: xproto::Window, cursor: xproto::Cursor, detail: u32, deviceid: B, grab_type: GrabType, grab_mode: GrabMode22, paired_device_mode: xproto::GrabMode, owner_events: GrabOwner, mask: &'input [u32], modifiers: &'input [u32]) -> Result<Cookie<'c, Self, XIPassiveGrabDeviceReply>, ConnectionError>
     where
         A: Into<xproto::Timestamp>,
@@ -17396,44 +21934,60 @@ pub trait ConnectionExt: RequestConnection {
     {
         xi_passive_grab_device(self, time, grab_window, cursor, detail, deviceid, grab_type, grab_mode, paired_device_mode, owner_events, mask, modifiers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIPassiveUngrabDevice"))]
+    #[inline]
     fn xinput_xi_passive_ungrab_device<'c, 'input, A>(&'c self, grab_window: xproto::Window, detail: u32, deviceid: A, grab_type: GrabType, modifiers: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_passive_ungrab_device(self, grab_window, detail, deviceid, grab_type, modifiers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIListProperties"))]
+    #[inline]
     fn xinput_xi_list_properties<A>(&self, deviceid: A) -> Result<Cookie<'_, Self, XIListPropertiesReply>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_list_properties(self, deviceid)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIChangeProperty"))]
+    #[inline]
     fn xinput_xi_change_property<'c, 'input, A>(&'c self, deviceid: A, mode: xproto::PropMode, property: xproto::Atom, type_: xproto::Atom, num_items: u32, items: &'input XIChangePropertyAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_change_property(self, deviceid, mode, property, type_, num_items, items)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIDeleteProperty"))]
+    #[inline]
     fn xinput_xi_delete_property<A>(&self, deviceid: A, property: xproto::Atom) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_delete_property(self, deviceid, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetProperty"))]
+    #[inline]
     fn xinput_xi_get_property<A>(&self, deviceid: A, delete: bool, property: xproto::Atom, type_: xproto::Atom, offset: u32, len: u32) -> Result<Cookie<'_, Self, XIGetPropertyReply>, ConnectionError>
     where
         A: Into<DeviceId>,
     {
         xi_get_property(self, deviceid, delete, property, type_, offset, len)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIGetSelectedEvents"))]
+    #[inline]
     fn xinput_xi_get_selected_events(&self, window: xproto::Window) -> Result<Cookie<'_, Self, XIGetSelectedEventsReply>, ConnectionError>
     {
         xi_get_selected_events(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::XIBarrierReleasePointer"))]
+    #[inline]
     fn xinput_xi_barrier_release_pointer<'c, 'input>(&'c self, barriers: &'input [BarrierReleasePointerInfo]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         xi_barrier_release_pointer(self, barriers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xinput::SendExtensionEvent"))]
+    #[inline]
     fn xinput_send_extension_event<'c, 'input>(&'c self, destination: xproto::Window, device_id: u8, propagate: bool, events: &'input [EventForSend], classes: &'input [EventClass]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         send_extension_event(self, destination, device_id, propagate, events, classes)
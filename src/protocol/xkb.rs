@@ -4,6 +4,8 @@
 //! Bindings to the `xkb` X11 extension.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -12,7 +14,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
@@ -756,6 +758,17 @@ impl std::fmt::Debug for LedClassResult  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl LedClassResult {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct LedClass(u16);
@@ -812,6 +825,17 @@ impl std::fmt::Debug for LedClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl LedClass {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 4 | 768 | 1280) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 pub type LedClassSpec = u16;
 
@@ -872,6 +896,17 @@ impl std::fmt::Debug for BellClassResult  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl BellClassResult {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct BellClass(u16);
@@ -1054,6 +1089,17 @@ impl std::fmt::Debug for Group  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Group {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Groups(u8);
@@ -1174,6 +1220,17 @@ impl std::fmt::Debug for SetOfGroup  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SetOfGroup {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if self.0 & !0xf == 0 {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 bitmask_binop!(SetOfGroup, u8);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -1769,6 +1826,17 @@ impl std::fmt::Debug for IMFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl IMFlag {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if self.0 & !0xe0 == 0 {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 bitmask_binop!(IMFlag, u8);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -1834,6 +1902,17 @@ impl std::fmt::Debug for IMModsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl IMModsWhich {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if self.0 & !0x1f == 0 {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 bitmask_binop!(IMModsWhich, u8);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -1899,9 +1978,20 @@ impl std::fmt::Debug for IMGroupsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl IMGroupsWhich {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if self.0 & !0x1f == 0 {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 bitmask_binop!(IMGroupsWhich, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct IndicatorMap {
     pub flags: IMFlag,
     pub which_groups: IMGroupsWhich,
@@ -1912,6 +2002,20 @@ pub struct IndicatorMap {
     pub vmods: u16,
     pub ctrls: u32,
 }
+impl std::fmt::Debug for IndicatorMap {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("IndicatorMap")
+            .field("flags", &self.flags)
+            .field("which_groups", &self.which_groups)
+            .field("groups", &self.groups)
+            .field("which_mods", &self.which_mods)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("vmods", &VMod::from(self.vmods))
+            .field("ctrls", &self.ctrls)
+            .finish()
+    }
+}
 impl TryParse for IndicatorMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (flags, remaining) = u8::try_parse(remaining)?;
@@ -1923,9 +2027,17 @@ impl TryParse for IndicatorMap {
         let (vmods, remaining) = u16::try_parse(remaining)?;
         let (ctrls, remaining) = u32::try_parse(remaining)?;
         let flags = flags.into();
+        #[cfg(feature = "strict_parsing")]
+        IMFlag::check_known(flags)?;
         let which_groups = which_groups.into();
+        #[cfg(feature = "strict_parsing")]
+        IMGroupsWhich::check_known(which_groups)?;
         let groups = groups.into();
+        #[cfg(feature = "strict_parsing")]
+        SetOfGroup::check_known(groups)?;
         let which_mods = which_mods.into();
+        #[cfg(feature = "strict_parsing")]
+        IMModsWhich::check_known(which_mods)?;
         let result = IndicatorMap { flags, which_groups, groups, which_mods, mods, real_mods, vmods, ctrls };
         Ok((result, remaining))
     }
@@ -2306,12 +2418,21 @@ impl std::fmt::Debug for PerClientFlag  {
 }
 bitmask_binop!(PerClientFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ModDef {
     pub mask: u8,
     pub real_mods: u8,
     pub vmods: u16,
 }
+impl std::fmt::Debug for ModDef {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ModDef")
+            .field("mask", &xproto::ModMask::from(self.mask))
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("vmods", &VMod::from(self.vmods))
+            .finish()
+    }
+}
 impl TryParse for ModDef {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mask, remaining) = u8::try_parse(remaining)?;
@@ -2453,7 +2574,7 @@ impl CountedString16 {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct KTMapEntry {
     pub active: bool,
     pub mods_mask: u8,
@@ -2461,6 +2582,17 @@ pub struct KTMapEntry {
     pub mods_mods: u8,
     pub mods_vmods: u16,
 }
+impl std::fmt::Debug for KTMapEntry {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KTMapEntry")
+            .field("active", &self.active)
+            .field("mods_mask", &xproto::ModMask::from(self.mods_mask))
+            .field("level", &self.level)
+            .field("mods_mods", &xproto::ModMask::from(self.mods_mods))
+            .field("mods_vmods", &VMod::from(self.mods_vmods))
+            .finish()
+    }
+}
 impl TryParse for KTMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (active, remaining) = bool::try_parse(remaining)?;
@@ -2503,7 +2635,7 @@ impl Serialize for KTMapEntry {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct KeyType {
     pub mods_mask: u8,
     pub mods_mods: u8,
@@ -2513,6 +2645,19 @@ pub struct KeyType {
     pub map: Vec<KTMapEntry>,
     pub preserve: Vec<ModDef>,
 }
+impl std::fmt::Debug for KeyType {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KeyType")
+            .field("mods_mask", &xproto::ModMask::from(self.mods_mask))
+            .field("mods_mods", &xproto::ModMask::from(self.mods_mods))
+            .field("mods_vmods", &VMod::from(self.mods_vmods))
+            .field("num_levels", &self.num_levels)
+            .field("has_preserve", &self.has_preserve)
+            .field("map", &self.map)
+            .field("preserve", &self.preserve)
+            .finish()
+    }
+}
 impl TryParse for KeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mods_mask, remaining) = u8::try_parse(remaining)?;
@@ -2996,11 +3141,19 @@ impl Serialize for SetBehavior {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SetExplicit {
     pub keycode: xproto::Keycode,
     pub explicit: u8,
 }
+impl std::fmt::Debug for SetExplicit {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SetExplicit")
+            .field("keycode", &self.keycode)
+            .field("explicit", &Explicit::from(self.explicit))
+            .finish()
+    }
+}
 impl TryParse for SetExplicit {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3026,11 +3179,19 @@ impl Serialize for SetExplicit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct KeyModMap {
     pub keycode: xproto::Keycode,
     pub mods: u8,
 }
+impl std::fmt::Debug for KeyModMap {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KeyModMap")
+            .field("keycode", &self.keycode)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .finish()
+    }
+}
 impl TryParse for KeyModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3056,11 +3217,19 @@ impl Serialize for KeyModMap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct KeyVModMap {
     pub keycode: xproto::Keycode,
     pub vmods: u16,
 }
+impl std::fmt::Debug for KeyVModMap {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KeyVModMap")
+            .field("keycode", &self.keycode)
+            .field("vmods", &VMod::from(self.vmods))
+            .finish()
+    }
+}
 impl TryParse for KeyVModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3090,12 +3259,21 @@ impl Serialize for KeyVModMap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct KTSetMapEntry {
     pub level: u8,
     pub real_mods: u8,
     pub virtual_mods: u16,
 }
+impl std::fmt::Debug for KTSetMapEntry {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KTSetMapEntry")
+            .field("level", &self.level)
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .finish()
+    }
+}
 impl TryParse for KTSetMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (level, remaining) = u8::try_parse(remaining)?;
@@ -3126,7 +3304,7 @@ impl Serialize for KTSetMapEntry {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct SetKeyType {
     pub mask: u8,
     pub real_mods: u8,
@@ -3136,6 +3314,19 @@ pub struct SetKeyType {
     pub entries: Vec<KTSetMapEntry>,
     pub preserve_entries: Vec<KTSetMapEntry>,
 }
+impl std::fmt::Debug for SetKeyType {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SetKeyType")
+            .field("mask", &xproto::ModMask::from(self.mask))
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .field("num_levels", &self.num_levels)
+            .field("preserve", &self.preserve)
+            .field("entries", &self.entries)
+            .field("preserve_entries", &self.preserve_entries)
+            .finish()
+    }
+}
 impl TryParse for SetKeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mask, remaining) = u8::try_parse(remaining)?;
@@ -3594,10 +3785,7 @@ impl TryParse for Listing {
         let (length, remaining) = u16::try_parse(remaining)?;
         let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_to_usize()?)?;
         let string = string.to_vec();
-        // Align offset to multiple of 2
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (2 - (offset % 2)) % 2;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 2)?;
         let result = Listing { flags, string };
         Ok((result, remaining))
     }
@@ -3634,7 +3822,7 @@ impl Listing {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct DeviceLedInfo {
     pub led_class: LedClass,
     pub led_id: IDSpec,
@@ -3645,6 +3833,20 @@ pub struct DeviceLedInfo {
     pub names: Vec<xproto::Atom>,
     pub maps: Vec<IndicatorMap>,
 }
+impl std::fmt::Debug for DeviceLedInfo {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DeviceLedInfo")
+            .field("led_class", &self.led_class)
+            .field("led_id", &ID::from(self.led_id))
+            .field("names_present", &self.names_present)
+            .field("maps_present", &self.maps_present)
+            .field("phys_indicators", &self.phys_indicators)
+            .field("state", &self.state)
+            .field("names", &self.names)
+            .field("maps", &self.maps)
+            .finish()
+    }
+}
 impl TryParse for DeviceLedInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
@@ -3656,6 +3858,8 @@ impl TryParse for DeviceLedInfo {
         let (names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, names_present.count_ones().try_to_usize()?)?;
         let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, maps_present.count_ones().try_to_usize()?)?;
         let led_class = led_class.into();
+        #[cfg(feature = "strict_parsing")]
+        LedClass::check_known(led_class)?;
         let result = DeviceLedInfo { led_class, led_id, names_present, maps_present, phys_indicators, state, names, maps };
         Ok((result, remaining))
     }
@@ -3903,6 +4107,17 @@ impl std::fmt::Debug for SAType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SAType {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=20) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SANoAction {
@@ -3913,6 +4128,8 @@ impl TryParse for SANoAction {
         let (type_, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SANoAction { type_ };
         Ok((result, remaining))
     }
@@ -3939,7 +4156,7 @@ impl Serialize for SANoAction {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SASetMods {
     pub type_: SAType,
     pub flags: u8,
@@ -3948,6 +4165,18 @@ pub struct SASetMods {
     pub vmods_high: u8,
     pub vmods_low: u8,
 }
+impl std::fmt::Debug for SASetMods {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SASetMods")
+            .field("type_", &self.type_)
+            .field("flags", &SA::from(self.flags))
+            .field("mask", &xproto::ModMask::from(self.mask))
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("vmods_high", &VModsHigh::from(self.vmods_high))
+            .field("vmods_low", &VModsLow::from(self.vmods_low))
+            .finish()
+    }
+}
 impl TryParse for SASetMods {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -3958,6 +4187,8 @@ impl TryParse for SASetMods {
         let (vmods_low, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SASetMods { type_, flags, mask, real_mods, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -3998,12 +4229,21 @@ pub type SALatchMods = SASetMods;
 
 pub type SALockMods = SASetMods;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SASetGroup {
     pub type_: SAType,
     pub flags: u8,
     pub group: i8,
 }
+impl std::fmt::Debug for SASetGroup {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SASetGroup")
+            .field("type_", &self.type_)
+            .field("flags", &SA::from(self.flags))
+            .field("group", &self.group)
+            .finish()
+    }
+}
 impl TryParse for SASetGroup {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4011,6 +4251,8 @@ impl TryParse for SASetGroup {
         let (group, remaining) = i8::try_parse(remaining)?;
         let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SASetGroup { type_, flags, group };
         Ok((result, remaining))
     }
@@ -4106,7 +4348,7 @@ impl std::fmt::Debug for SAMovePtrFlag  {
 }
 bitmask_binop!(SAMovePtrFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SAMovePtr {
     pub type_: SAType,
     pub flags: u8,
@@ -4115,6 +4357,18 @@ pub struct SAMovePtr {
     pub y_high: i8,
     pub y_low: u8,
 }
+impl std::fmt::Debug for SAMovePtr {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SAMovePtr")
+            .field("type_", &self.type_)
+            .field("flags", &SAMovePtrFlag::from(self.flags))
+            .field("x_high", &self.x_high)
+            .field("x_low", &self.x_low)
+            .field("y_high", &self.y_high)
+            .field("y_low", &self.y_low)
+            .finish()
+    }
+}
 impl TryParse for SAMovePtr {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4125,6 +4379,8 @@ impl TryParse for SAMovePtr {
         let (y_low, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SAMovePtr { type_, flags, x_high, x_low, y_high, y_low };
         Ok((result, remaining))
     }
@@ -4176,6 +4432,8 @@ impl TryParse for SAPtrBtn {
         let (button, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SAPtrBtn { type_, flags, count, button };
         Ok((result, remaining))
     }
@@ -4222,6 +4480,8 @@ impl TryParse for SALockPtrBtn {
         let (button, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SALockPtrBtn { type_, flags, button };
         Ok((result, remaining))
     }
@@ -4312,13 +4572,23 @@ impl std::fmt::Debug for SASetPtrDfltFlag  {
 }
 bitmask_binop!(SASetPtrDfltFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SASetPtrDflt {
     pub type_: SAType,
     pub flags: u8,
     pub affect: u8,
     pub value: i8,
 }
+impl std::fmt::Debug for SASetPtrDflt {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SASetPtrDflt")
+            .field("type_", &self.type_)
+            .field("flags", &SASetPtrDfltFlag::from(self.flags))
+            .field("affect", &SASetPtrDfltFlag::from(self.affect))
+            .field("value", &self.value)
+            .finish()
+    }
+}
 impl TryParse for SASetPtrDflt {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4327,6 +4597,8 @@ impl TryParse for SASetPtrDflt {
         let (value, remaining) = i8::try_parse(remaining)?;
         let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SASetPtrDflt { type_, flags, affect, value };
         Ok((result, remaining))
     }
@@ -4487,7 +4759,7 @@ impl std::fmt::Debug for SAIsoLockNoAffect  {
 }
 bitmask_binop!(SAIsoLockNoAffect, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SAIsoLock {
     pub type_: SAType,
     pub flags: u8,
@@ -4498,6 +4770,20 @@ pub struct SAIsoLock {
     pub vmods_high: u8,
     pub vmods_low: u8,
 }
+impl std::fmt::Debug for SAIsoLock {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SAIsoLock")
+            .field("type_", &self.type_)
+            .field("flags", &SAIsoLockFlag::from(self.flags))
+            .field("mask", &xproto::ModMask::from(self.mask))
+            .field("real_mods", &xproto::ModMask::from(self.real_mods))
+            .field("group", &self.group)
+            .field("affect", &SAIsoLockNoAffect::from(self.affect))
+            .field("vmods_high", &VModsHigh::from(self.vmods_high))
+            .field("vmods_low", &VModsLow::from(self.vmods_low))
+            .finish()
+    }
+}
 impl TryParse for SAIsoLock {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4509,6 +4795,8 @@ impl TryParse for SAIsoLock {
         let (vmods_high, remaining) = u8::try_parse(remaining)?;
         let (vmods_low, remaining) = u8::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SAIsoLock { type_, flags, mask, real_mods, group, affect, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -4557,6 +4845,8 @@ impl TryParse for SATerminate {
         let (type_, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SATerminate { type_ };
         Ok((result, remaining))
     }
@@ -4655,6 +4945,8 @@ impl TryParse for SASwitchScreen {
         let (new_screen, remaining) = i8::try_parse(remaining)?;
         let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SASwitchScreen { type_, flags, new_screen };
         Ok((result, remaining))
     }
@@ -4821,12 +5113,21 @@ impl std::fmt::Debug for BoolCtrlsLow  {
 }
 bitmask_binop!(BoolCtrlsLow, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SASetControls {
     pub type_: SAType,
     pub bool_ctrls_high: u8,
     pub bool_ctrls_low: u8,
 }
+impl std::fmt::Debug for SASetControls {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SASetControls")
+            .field("type_", &self.type_)
+            .field("bool_ctrls_high", &BoolCtrlsHigh::from(self.bool_ctrls_high))
+            .field("bool_ctrls_low", &BoolCtrlsLow::from(self.bool_ctrls_low))
+            .finish()
+    }
+}
 impl TryParse for SASetControls {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4835,6 +5136,8 @@ impl TryParse for SASetControls {
         let (bool_ctrls_low, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SASetControls { type_, bool_ctrls_high, bool_ctrls_low };
         Ok((result, remaining))
     }
@@ -4929,12 +5232,21 @@ impl std::fmt::Debug for ActionMessageFlag  {
 }
 bitmask_binop!(ActionMessageFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SAActionMessage {
     pub type_: SAType,
     pub flags: u8,
     pub message: [u8; 6],
 }
+impl std::fmt::Debug for SAActionMessage {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SAActionMessage")
+            .field("type_", &self.type_)
+            .field("flags", &ActionMessageFlag::from(self.flags))
+            .field("message", &self.message)
+            .finish()
+    }
+}
 impl TryParse for SAActionMessage {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4942,6 +5254,8 @@ impl TryParse for SAActionMessage {
         let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, 6)?;
         let message = <[u8; 6]>::try_from(message).unwrap();
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SAActionMessage { type_, flags, message };
         Ok((result, remaining))
     }
@@ -4970,7 +5284,7 @@ impl Serialize for SAActionMessage {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SARedirectKey {
     pub type_: SAType,
     pub newkey: xproto::Keycode,
@@ -4981,6 +5295,20 @@ pub struct SARedirectKey {
     pub vmods_high: u8,
     pub vmods_low: u8,
 }
+impl std::fmt::Debug for SARedirectKey {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SARedirectKey")
+            .field("type_", &self.type_)
+            .field("newkey", &self.newkey)
+            .field("mask", &xproto::ModMask::from(self.mask))
+            .field("real_modifiers", &xproto::ModMask::from(self.real_modifiers))
+            .field("vmods_mask_high", &VModsHigh::from(self.vmods_mask_high))
+            .field("vmods_mask_low", &VModsLow::from(self.vmods_mask_low))
+            .field("vmods_high", &VModsHigh::from(self.vmods_high))
+            .field("vmods_low", &VModsLow::from(self.vmods_low))
+            .finish()
+    }
+}
 impl TryParse for SARedirectKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4992,6 +5320,8 @@ impl TryParse for SARedirectKey {
         let (vmods_high, remaining) = u8::try_parse(remaining)?;
         let (vmods_low, remaining) = u8::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SARedirectKey { type_, newkey, mask, real_modifiers, vmods_mask_high, vmods_mask_low, vmods_high, vmods_low };
         Ok((result, remaining))
     }
@@ -5048,6 +5378,8 @@ impl TryParse for SADeviceBtn {
         let (device, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SADeviceBtn { type_, flags, count, button, device };
         Ok((result, remaining))
     }
@@ -5141,13 +5473,23 @@ impl std::fmt::Debug for LockDeviceFlags  {
 }
 bitmask_binop!(LockDeviceFlags, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SALockDeviceBtn {
     pub type_: SAType,
     pub flags: u8,
     pub button: u8,
     pub device: u8,
 }
+impl std::fmt::Debug for SALockDeviceBtn {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SALockDeviceBtn")
+            .field("type_", &self.type_)
+            .field("flags", &LockDeviceFlags::from(self.flags))
+            .field("button", &self.button)
+            .field("device", &self.device)
+            .finish()
+    }
+}
 impl TryParse for SALockDeviceBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5157,6 +5499,8 @@ impl TryParse for SALockDeviceBtn {
         let (device, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SALockDeviceBtn { type_, flags, button, device };
         Ok((result, remaining))
     }
@@ -5255,6 +5599,17 @@ impl std::fmt::Debug for SAValWhat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SAValWhat {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SADeviceValuator {
@@ -5278,8 +5633,14 @@ impl TryParse for SADeviceValuator {
         let (val2index, remaining) = u8::try_parse(remaining)?;
         let (val2value, remaining) = u8::try_parse(remaining)?;
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let val1what = val1what.into();
+        #[cfg(feature = "strict_parsing")]
+        SAValWhat::check_known(val1what)?;
         let val2what = val2what.into();
+        #[cfg(feature = "strict_parsing")]
+        SAValWhat::check_known(val2what)?;
         let result = SADeviceValuator { type_, device, val1what, val1index, val1value, val2what, val2index, val2value };
         Ok((result, remaining))
     }
@@ -5330,6 +5691,8 @@ impl TryParse for SIAction {
         let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, 7)?;
         let data = <[u8; 7]>::try_from(data).unwrap();
         let type_ = type_.into();
+        #[cfg(feature = "strict_parsing")]
+        SAType::check_known(type_)?;
         let result = SIAction { type_, data };
         Ok((result, remaining))
     }
@@ -5356,7 +5719,7 @@ impl Serialize for SIAction {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SymInterpret {
     pub sym: xproto::Keysym,
     pub mods: u8,
@@ -5365,6 +5728,18 @@ pub struct SymInterpret {
     pub flags: u8,
     pub action: SIAction,
 }
+impl std::fmt::Debug for SymInterpret {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SymInterpret")
+            .field("sym", &self.sym)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .field("match_", &SymInterpretMatch::from(self.match_))
+            .field("virtual_mod", &VModsLow::from(self.virtual_mod))
+            .field("flags", &self.flags)
+            .field("action", &self.action)
+            .finish()
+    }
+}
 impl TryParse for SymInterpret {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (sym, remaining) = xproto::Keysym::try_parse(remaining)?;
@@ -5591,6 +5966,8 @@ impl Action {
         fn do_the_parse(remaining: &[u8]) -> Result<SAType, ParseError> {
             let (type_, remaining) = u8::try_parse(remaining)?;
             let type_ = type_.into();
+            #[cfg(feature = "strict_parsing")]
+            SAType::check_known(type_)?;
             let _ = remaining;
             Ok(type_)
         }
@@ -5731,11 +6108,13 @@ impl From<SAType> for Action {
 
 /// Opcode for the UseExtension request
 pub const USE_EXTENSION_REQUEST: u8 = 0;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UseExtensionRequest {
     pub wanted_major: u16,
     pub wanted_minor: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 impl UseExtensionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -5763,12 +6142,57 @@ impl UseExtensionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let wanted_major_bytes = self.wanted_major.serialize();
+        let wanted_minor_bytes = self.wanted_minor.serialize();
+        write(&[
+            major_opcode,
+            USE_EXTENSION_REQUEST,
+            0,
+            0,
+            wanted_major_bytes[0],
+            wanted_major_bytes[1],
+            wanted_minor_bytes[0],
+            wanted_minor_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, UseExtensionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5785,9 +6209,12 @@ impl UseExtensionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 impl Request for UseExtensionRequest {
     type Reply = UseExtensionReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
+#[inline]
 pub fn use_extension<Conn>(conn: &Conn, wanted_major: u16, wanted_minor: u16) -> Result<Cookie<'_, Conn, UseExtensionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5799,6 +6226,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UseExtensionReply {
     pub supported: bool,
@@ -5807,6 +6235,7 @@ pub struct UseExtensionReply {
     pub server_major: u16,
     pub server_minor: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
 impl TryParse for UseExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5822,17 +6251,22 @@ impl TryParse for UseExtensionReply {
         }
         let result = UseExtensionReply { supported, sequence, length, server_major, server_minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
 
+/// Opcode for the SelectEvents request
+pub const SELECT_EVENTS_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase1 {
     pub affect_new_keyboard: u16,
     pub new_keyboard_details: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase1 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_new_keyboard, remaining) = u16::try_parse(remaining)?;
@@ -5841,6 +6275,7 @@ impl TryParse for SelectEventsAuxBitcase1 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase1 {
     type Bytes = [u8; 4];
     fn serialize(&self) -> [u8; 4] {
@@ -5859,11 +6294,22 @@ impl Serialize for SelectEventsAuxBitcase1 {
         self.new_keyboard_details.serialize_into(bytes);
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase2 {
     pub affect_state: u16,
     pub state_details: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+impl std::fmt::Debug for SelectEventsAuxBitcase2 {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SelectEventsAuxBitcase2")
+            .field("affect_state", &StatePart::from(self.affect_state))
+            .field("state_details", &StatePart::from(self.state_details))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase2 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_state, remaining) = u16::try_parse(remaining)?;
@@ -5872,6 +6318,7 @@ impl TryParse for SelectEventsAuxBitcase2 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase2 {
     type Bytes = [u8; 4];
     fn serialize(&self) -> [u8; 4] {
@@ -5890,11 +6337,22 @@ impl Serialize for SelectEventsAuxBitcase2 {
         self.state_details.serialize_into(bytes);
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase3 {
     pub affect_ctrls: u32,
     pub ctrl_details: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+impl std::fmt::Debug for SelectEventsAuxBitcase3 {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SelectEventsAuxBitcase3")
+            .field("affect_ctrls", &Control::from(self.affect_ctrls))
+            .field("ctrl_details", &Control::from(self.ctrl_details))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase3 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_ctrls, remaining) = u32::try_parse(remaining)?;
@@ -5903,6 +6361,7 @@ impl TryParse for SelectEventsAuxBitcase3 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase3 {
     type Bytes = [u8; 8];
     fn serialize(&self) -> [u8; 8] {
@@ -5925,11 +6384,13 @@ impl Serialize for SelectEventsAuxBitcase3 {
         self.ctrl_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase4 {
     pub affect_indicator_state: u32,
     pub indicator_state_details: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase4 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_indicator_state, remaining) = u32::try_parse(remaining)?;
@@ -5938,6 +6399,7 @@ impl TryParse for SelectEventsAuxBitcase4 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase4 {
     type Bytes = [u8; 8];
     fn serialize(&self) -> [u8; 8] {
@@ -5960,11 +6422,13 @@ impl Serialize for SelectEventsAuxBitcase4 {
         self.indicator_state_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase5 {
     pub affect_indicator_map: u32,
     pub indicator_map_details: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase5 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_indicator_map, remaining) = u32::try_parse(remaining)?;
@@ -5973,6 +6437,7 @@ impl TryParse for SelectEventsAuxBitcase5 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase5 {
     type Bytes = [u8; 8];
     fn serialize(&self) -> [u8; 8] {
@@ -5995,11 +6460,22 @@ impl Serialize for SelectEventsAuxBitcase5 {
         self.indicator_map_details.serialize_into(bytes);
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase6 {
     pub affect_names: u16,
     pub names_details: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+impl std::fmt::Debug for SelectEventsAuxBitcase6 {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SelectEventsAuxBitcase6")
+            .field("affect_names", &NameDetail::from(self.affect_names))
+            .field("names_details", &NameDetail::from(self.names_details))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase6 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_names, remaining) = u16::try_parse(remaining)?;
@@ -6008,6 +6484,7 @@ impl TryParse for SelectEventsAuxBitcase6 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase6 {
     type Bytes = [u8; 4];
     fn serialize(&self) -> [u8; 4] {
@@ -6026,11 +6503,22 @@ impl Serialize for SelectEventsAuxBitcase6 {
         self.names_details.serialize_into(bytes);
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase7 {
     pub affect_compat: u8,
     pub compat_details: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+impl std::fmt::Debug for SelectEventsAuxBitcase7 {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SelectEventsAuxBitcase7")
+            .field("affect_compat", &CMDetail::from(self.affect_compat))
+            .field("compat_details", &CMDetail::from(self.compat_details))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase7 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_compat, remaining) = u8::try_parse(remaining)?;
@@ -6039,6 +6527,7 @@ impl TryParse for SelectEventsAuxBitcase7 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase7 {
     type Bytes = [u8; 2];
     fn serialize(&self) -> [u8; 2] {
@@ -6055,11 +6544,13 @@ impl Serialize for SelectEventsAuxBitcase7 {
         self.compat_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase8 {
     pub affect_bell: u8,
     pub bell_details: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase8 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_bell, remaining) = u8::try_parse(remaining)?;
@@ -6068,6 +6559,7 @@ impl TryParse for SelectEventsAuxBitcase8 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase8 {
     type Bytes = [u8; 2];
     fn serialize(&self) -> [u8; 2] {
@@ -6084,11 +6576,13 @@ impl Serialize for SelectEventsAuxBitcase8 {
         self.bell_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase9 {
     pub affect_msg_details: u8,
     pub msg_details: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase9 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_msg_details, remaining) = u8::try_parse(remaining)?;
@@ -6097,6 +6591,7 @@ impl TryParse for SelectEventsAuxBitcase9 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase9 {
     type Bytes = [u8; 2];
     fn serialize(&self) -> [u8; 2] {
@@ -6113,11 +6608,13 @@ impl Serialize for SelectEventsAuxBitcase9 {
         self.msg_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase10 {
     pub affect_access_x: u16,
     pub access_x_details: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase10 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_access_x, remaining) = u16::try_parse(remaining)?;
@@ -6126,6 +6623,7 @@ impl TryParse for SelectEventsAuxBitcase10 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase10 {
     type Bytes = [u8; 4];
     fn serialize(&self) -> [u8; 4] {
@@ -6144,11 +6642,13 @@ impl Serialize for SelectEventsAuxBitcase10 {
         self.access_x_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SelectEventsAuxBitcase11 {
     pub affect_ext_dev: u16,
     pub extdev_details: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl TryParse for SelectEventsAuxBitcase11 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_ext_dev, remaining) = u16::try_parse(remaining)?;
@@ -6157,6 +6657,7 @@ impl TryParse for SelectEventsAuxBitcase11 {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl Serialize for SelectEventsAuxBitcase11 {
     type Bytes = [u8; 4];
     fn serialize(&self) -> [u8; 4] {
@@ -6175,6 +6676,7 @@ impl Serialize for SelectEventsAuxBitcase11 {
         self.extdev_details.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 /// Auxiliary and optional information for the `select_events` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct SelectEventsAux {
@@ -6190,6 +6692,7 @@ pub struct SelectEventsAux {
     pub bitcase10: Option<SelectEventsAuxBitcase10>,
     pub bitcase11: Option<SelectEventsAuxBitcase11>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl SelectEventsAux {
     fn try_parse(value: &[u8], affect_which: u16, clear: u16, select_all: u16) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(affect_which) & ((!u32::from(clear)) & (!u32::from(select_all)));
@@ -6275,6 +6778,7 @@ impl SelectEventsAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl SelectEventsAux {
     #[allow(dead_code)]
     fn serialize(&self, affect_which: u16, clear: u16, select_all: u16) -> Vec<u8> {
@@ -6319,6 +6823,7 @@ impl SelectEventsAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl SelectEventsAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -6358,6 +6863,7 @@ impl SelectEventsAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl SelectEventsAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -6420,8 +6926,7 @@ impl SelectEventsAux {
     }
 }
 
-/// Opcode for the SelectEvents request
-pub const SELECT_EVENTS_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SelectEventsRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -6431,6 +6936,7 @@ pub struct SelectEventsRequest<'input> {
     pub map: u16,
     pub details: Cow<'input, SelectEventsAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl<'input> SelectEventsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6475,12 +6981,78 @@ impl<'input> SelectEventsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), details_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_which = u16::try_from(self.details.switch_expr() | (u32::from(self.clear) | u32::from(self.select_all))).unwrap();
+        let affect_which_bytes = affect_which.serialize();
+        let clear_bytes = self.clear.serialize();
+        let select_all_bytes = self.select_all.serialize();
+        let affect_map_bytes = self.affect_map.serialize();
+        let map_bytes = self.map.serialize();
+        write(&[
+            major_opcode,
+            SELECT_EVENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_which_bytes[0],
+            affect_which_bytes[1],
+            clear_bytes[0],
+            clear_bytes[1],
+            select_all_bytes[0],
+            select_all_bytes[1],
+            affect_map_bytes[0],
+            affect_map_bytes[1],
+            map_bytes[0],
+            map_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let details_bytes = self.details.serialize(affect_which, self.clear, self.select_all);
+        let length_so_far = length_so_far + details_bytes.len();
+        write(&details_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let affect_which = u16::try_from(self.details.switch_expr() | (u32::from(self.clear) | u32::from(self.select_all))).unwrap();
+        let length_so_far = 16 + self.details.serialize(affect_which, self.clear, self.select_all).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6517,9 +7089,12 @@ impl<'input> SelectEventsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
 impl<'input> Request for SelectEventsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+#[inline]
 pub fn select_events<'c, 'input, Conn, A, B, C, D>(conn: &'c Conn, device_spec: DeviceSpec, clear: A, select_all: B, affect_map: C, map: D, details: &'input SelectEventsAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6545,6 +7120,7 @@ where
 
 /// Opcode for the Bell request
 pub const BELL_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BellRequest {
     pub device_spec: DeviceSpec,
@@ -6558,6 +7134,7 @@ pub struct BellRequest {
     pub name: xproto::Atom,
     pub window: xproto::Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
 impl BellRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6613,31 +7190,104 @@ impl BellRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != BELL_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (bell_class, remaining) = BellClassSpec::try_parse(remaining)?;
-        let (bell_id, remaining) = IDSpec::try_parse(remaining)?;
-        let (percent, remaining) = i8::try_parse(remaining)?;
-        let (force_sound, remaining) = bool::try_parse(remaining)?;
-        let (event_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let bell_class_bytes = self.bell_class.serialize();
+        let bell_id_bytes = self.bell_id.serialize();
+        let percent_bytes = self.percent.serialize();
+        let force_sound_bytes = self.force_sound.serialize();
+        let event_only_bytes = self.event_only.serialize();
+        let pitch_bytes = self.pitch.serialize();
+        let duration_bytes = self.duration.serialize();
+        let name_bytes = self.name.serialize();
+        let window_bytes = self.window.serialize();
+        write(&[
+            major_opcode,
+            BELL_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            bell_class_bytes[0],
+            bell_class_bytes[1],
+            bell_id_bytes[0],
+            bell_id_bytes[1],
+            percent_bytes[0],
+            force_sound_bytes[0],
+            event_only_bytes[0],
+            0,
+            pitch_bytes[0],
+            pitch_bytes[1],
+            duration_bytes[0],
+            duration_bytes[1],
+            0,
+            0,
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn)?;
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != BELL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
+        let (bell_class, remaining) = BellClassSpec::try_parse(remaining)?;
+        let (bell_id, remaining) = IDSpec::try_parse(remaining)?;
+        let (percent, remaining) = i8::try_parse(remaining)?;
+        let (force_sound, remaining) = bool::try_parse(remaining)?;
+        let (event_only, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (pitch, remaining) = i16::try_parse(remaining)?;
+        let (duration, remaining) = i16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (window, remaining) = xproto::Window::try_parse(remaining)?;
         let _ = remaining;
         Ok(BellRequest {
             device_spec,
@@ -6653,9 +7303,12 @@ impl BellRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
 impl Request for BellRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
+#[inline]
 pub fn bell<Conn>(conn: &Conn, device_spec: DeviceSpec, bell_class: BellClassSpec, bell_id: IDSpec, percent: i8, force_sound: bool, event_only: bool, pitch: i16, duration: i16, name: xproto::Atom, window: xproto::Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6677,10 +7330,12 @@ where
 
 /// Opcode for the GetState request
 pub const GET_STATE_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetStateRequest {
     pub device_spec: DeviceSpec,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
 impl GetStateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6707,12 +7362,56 @@ impl GetStateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        write(&[
+            major_opcode,
+            GET_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetStateReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6728,9 +7427,12 @@ impl GetStateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
 impl Request for GetStateRequest {
     type Reply = GetStateReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
+#[inline]
 pub fn get_state<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6741,7 +7443,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GetStateReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -6761,6 +7464,31 @@ pub struct GetStateReply {
     pub compat_lookup_mods: u8,
     pub ptr_btn_state: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
+impl std::fmt::Debug for GetStateReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetStateReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .field("base_mods", &xproto::ModMask::from(self.base_mods))
+            .field("latched_mods", &xproto::ModMask::from(self.latched_mods))
+            .field("locked_mods", &xproto::ModMask::from(self.locked_mods))
+            .field("group", &self.group)
+            .field("locked_group", &self.locked_group)
+            .field("base_group", &self.base_group)
+            .field("latched_group", &self.latched_group)
+            .field("compat_state", &xproto::ModMask::from(self.compat_state))
+            .field("grab_mods", &xproto::ModMask::from(self.grab_mods))
+            .field("compat_grab_mods", &xproto::ModMask::from(self.compat_grab_mods))
+            .field("lookup_mods", &xproto::ModMask::from(self.lookup_mods))
+            .field("compat_lookup_mods", &xproto::ModMask::from(self.compat_lookup_mods))
+            .field("ptr_btn_state", &xproto::KeyButMask::from(self.ptr_btn_state))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
 impl TryParse for GetStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6788,10 +7516,15 @@ impl TryParse for GetStateReply {
             return Err(ParseError::InvalidValue);
         }
         let group = group.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(group)?;
         let locked_group = locked_group.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(locked_group)?;
         let result = GetStateReply { device_id, sequence, length, mods, base_mods, latched_mods, locked_mods, group, locked_group, base_group, latched_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_lookup_mods, ptr_btn_state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -6799,6 +7532,7 @@ impl TryParse for GetStateReply {
 
 /// Opcode for the LatchLockState request
 pub const LATCH_LOCK_STATE_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LatchLockStateRequest {
     pub device_spec: DeviceSpec,
@@ -6810,6 +7544,7 @@ pub struct LatchLockStateRequest {
     pub latch_group: bool,
     pub group_latch: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
 impl LatchLockStateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6851,12 +7586,71 @@ impl LatchLockStateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_mod_locks_bytes = self.affect_mod_locks.serialize();
+        let mod_locks_bytes = self.mod_locks.serialize();
+        let lock_group_bytes = self.lock_group.serialize();
+        let group_lock_bytes = u8::from(self.group_lock).serialize();
+        let affect_mod_latches_bytes = self.affect_mod_latches.serialize();
+        let latch_group_bytes = self.latch_group.serialize();
+        let group_latch_bytes = self.group_latch.serialize();
+        write(&[
+            major_opcode,
+            LATCH_LOCK_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_mod_locks_bytes[0],
+            mod_locks_bytes[0],
+            lock_group_bytes[0],
+            group_lock_bytes[0],
+            affect_mod_latches_bytes[0],
+            0,
+            0,
+            latch_group_bytes[0],
+            group_latch_bytes[0],
+            group_latch_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6870,6 +7664,8 @@ impl LatchLockStateRequest {
         let (lock_group, remaining) = bool::try_parse(remaining)?;
         let (group_lock, remaining) = u8::try_parse(remaining)?;
         let group_lock = group_lock.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(group_lock)?;
         let (affect_mod_latches, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
@@ -6888,9 +7684,12 @@ impl LatchLockStateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
 impl Request for LatchLockStateRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
+#[inline]
 pub fn latch_lock_state<Conn, A, B, C>(conn: &Conn, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: Group, affect_mod_latches: C, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6916,10 +7715,12 @@ where
 
 /// Opcode for the GetControls request
 pub const GET_CONTROLS_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetControlsRequest {
     pub device_spec: DeviceSpec,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
 impl GetControlsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -6946,12 +7747,56 @@ impl GetControlsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        write(&[
+            major_opcode,
+            GET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetControlsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6967,9 +7812,12 @@ impl GetControlsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
 impl Request for GetControlsRequest {
     type Reply = GetControlsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
+#[inline]
 pub fn get_controls<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetControlsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6980,7 +7828,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GetControlsReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -7012,6 +7861,43 @@ pub struct GetControlsReply {
     pub enabled_controls: u32,
     pub per_key_repeat: [u8; 32],
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
+impl std::fmt::Debug for GetControlsReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetControlsReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("mouse_keys_dflt_btn", &self.mouse_keys_dflt_btn)
+            .field("num_groups", &self.num_groups)
+            .field("groups_wrap", &self.groups_wrap)
+            .field("internal_mods_mask", &xproto::ModMask::from(self.internal_mods_mask))
+            .field("ignore_lock_mods_mask", &xproto::ModMask::from(self.ignore_lock_mods_mask))
+            .field("internal_mods_real_mods", &xproto::ModMask::from(self.internal_mods_real_mods))
+            .field("ignore_lock_mods_real_mods", &xproto::ModMask::from(self.ignore_lock_mods_real_mods))
+            .field("internal_mods_vmods", &VMod::from(self.internal_mods_vmods))
+            .field("ignore_lock_mods_vmods", &VMod::from(self.ignore_lock_mods_vmods))
+            .field("repeat_delay", &self.repeat_delay)
+            .field("repeat_interval", &self.repeat_interval)
+            .field("slow_keys_delay", &self.slow_keys_delay)
+            .field("debounce_delay", &self.debounce_delay)
+            .field("mouse_keys_delay", &self.mouse_keys_delay)
+            .field("mouse_keys_interval", &self.mouse_keys_interval)
+            .field("mouse_keys_time_to_max", &self.mouse_keys_time_to_max)
+            .field("mouse_keys_max_speed", &self.mouse_keys_max_speed)
+            .field("mouse_keys_curve", &self.mouse_keys_curve)
+            .field("access_x_option", &AXOption::from(self.access_x_option))
+            .field("access_x_timeout", &self.access_x_timeout)
+            .field("access_x_timeout_options_mask", &AXOption::from(self.access_x_timeout_options_mask))
+            .field("access_x_timeout_options_values", &AXOption::from(self.access_x_timeout_options_values))
+            .field("access_x_timeout_mask", &self.access_x_timeout_mask)
+            .field("access_x_timeout_values", &self.access_x_timeout_values)
+            .field("enabled_controls", &self.enabled_controls)
+            .field("per_key_repeat", &self.per_key_repeat)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
 impl TryParse for GetControlsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7053,7 +7939,8 @@ impl TryParse for GetControlsReply {
         }
         let result = GetControlsReply { device_id, sequence, length, mouse_keys_dflt_btn, num_groups, groups_wrap, internal_mods_mask, ignore_lock_mods_mask, internal_mods_real_mods, ignore_lock_mods_real_mods, internal_mods_vmods, ignore_lock_mods_vmods, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_option, access_x_timeout, access_x_timeout_options_mask, access_x_timeout_options_values, access_x_timeout_mask, access_x_timeout_values, enabled_controls, per_key_repeat };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -7061,6 +7948,7 @@ impl TryParse for GetControlsReply {
 
 /// Opcode for the SetControls request
 pub const SET_CONTROLS_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetControlsRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -7094,6 +7982,7 @@ pub struct SetControlsRequest<'input> {
     pub access_x_timeout_options_values: u16,
     pub per_key_repeat: Cow<'input, [u8; 32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
 impl<'input> SetControlsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -7209,12 +8098,146 @@ impl<'input> SetControlsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), Cow::Owned(self.per_key_repeat.to_vec())], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_internal_real_mods_bytes = self.affect_internal_real_mods.serialize();
+        let internal_real_mods_bytes = self.internal_real_mods.serialize();
+        let affect_ignore_lock_real_mods_bytes = self.affect_ignore_lock_real_mods.serialize();
+        let ignore_lock_real_mods_bytes = self.ignore_lock_real_mods.serialize();
+        let affect_internal_virtual_mods_bytes = self.affect_internal_virtual_mods.serialize();
+        let internal_virtual_mods_bytes = self.internal_virtual_mods.serialize();
+        let affect_ignore_lock_virtual_mods_bytes = self.affect_ignore_lock_virtual_mods.serialize();
+        let ignore_lock_virtual_mods_bytes = self.ignore_lock_virtual_mods.serialize();
+        let mouse_keys_dflt_btn_bytes = self.mouse_keys_dflt_btn.serialize();
+        let groups_wrap_bytes = self.groups_wrap.serialize();
+        let access_x_options_bytes = self.access_x_options.serialize();
+        let affect_enabled_controls_bytes = self.affect_enabled_controls.serialize();
+        let enabled_controls_bytes = self.enabled_controls.serialize();
+        let change_controls_bytes = self.change_controls.serialize();
+        let repeat_delay_bytes = self.repeat_delay.serialize();
+        let repeat_interval_bytes = self.repeat_interval.serialize();
+        let slow_keys_delay_bytes = self.slow_keys_delay.serialize();
+        let debounce_delay_bytes = self.debounce_delay.serialize();
+        let mouse_keys_delay_bytes = self.mouse_keys_delay.serialize();
+        let mouse_keys_interval_bytes = self.mouse_keys_interval.serialize();
+        let mouse_keys_time_to_max_bytes = self.mouse_keys_time_to_max.serialize();
+        let mouse_keys_max_speed_bytes = self.mouse_keys_max_speed.serialize();
+        let mouse_keys_curve_bytes = self.mouse_keys_curve.serialize();
+        let access_x_timeout_bytes = self.access_x_timeout.serialize();
+        let access_x_timeout_mask_bytes = self.access_x_timeout_mask.serialize();
+        let access_x_timeout_values_bytes = self.access_x_timeout_values.serialize();
+        let access_x_timeout_options_mask_bytes = self.access_x_timeout_options_mask.serialize();
+        let access_x_timeout_options_values_bytes = self.access_x_timeout_options_values.serialize();
+        write(&[
+            major_opcode,
+            SET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_internal_real_mods_bytes[0],
+            internal_real_mods_bytes[0],
+            affect_ignore_lock_real_mods_bytes[0],
+            ignore_lock_real_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[1],
+            internal_virtual_mods_bytes[0],
+            internal_virtual_mods_bytes[1],
+            affect_ignore_lock_virtual_mods_bytes[0],
+            affect_ignore_lock_virtual_mods_bytes[1],
+            ignore_lock_virtual_mods_bytes[0],
+            ignore_lock_virtual_mods_bytes[1],
+            mouse_keys_dflt_btn_bytes[0],
+            groups_wrap_bytes[0],
+            access_x_options_bytes[0],
+            access_x_options_bytes[1],
+            0,
+            0,
+            affect_enabled_controls_bytes[0],
+            affect_enabled_controls_bytes[1],
+            affect_enabled_controls_bytes[2],
+            affect_enabled_controls_bytes[3],
+            enabled_controls_bytes[0],
+            enabled_controls_bytes[1],
+            enabled_controls_bytes[2],
+            enabled_controls_bytes[3],
+            change_controls_bytes[0],
+            change_controls_bytes[1],
+            change_controls_bytes[2],
+            change_controls_bytes[3],
+            repeat_delay_bytes[0],
+            repeat_delay_bytes[1],
+            repeat_interval_bytes[0],
+            repeat_interval_bytes[1],
+            slow_keys_delay_bytes[0],
+            slow_keys_delay_bytes[1],
+            debounce_delay_bytes[0],
+            debounce_delay_bytes[1],
+            mouse_keys_delay_bytes[0],
+            mouse_keys_delay_bytes[1],
+            mouse_keys_interval_bytes[0],
+            mouse_keys_interval_bytes[1],
+            mouse_keys_time_to_max_bytes[0],
+            mouse_keys_time_to_max_bytes[1],
+            mouse_keys_max_speed_bytes[0],
+            mouse_keys_max_speed_bytes[1],
+            mouse_keys_curve_bytes[0],
+            mouse_keys_curve_bytes[1],
+            access_x_timeout_bytes[0],
+            access_x_timeout_bytes[1],
+            access_x_timeout_mask_bytes[0],
+            access_x_timeout_mask_bytes[1],
+            access_x_timeout_mask_bytes[2],
+            access_x_timeout_mask_bytes[3],
+            access_x_timeout_values_bytes[0],
+            access_x_timeout_values_bytes[1],
+            access_x_timeout_values_bytes[2],
+            access_x_timeout_values_bytes[3],
+            access_x_timeout_options_mask_bytes[0],
+            access_x_timeout_options_mask_bytes[1],
+            access_x_timeout_options_values_bytes[0],
+            access_x_timeout_options_values_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 68;
+        let length_so_far = length_so_far + self.per_key_repeat.len();
+        write(&self.per_key_repeat[..]);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        100
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7324,9 +8347,12 @@ impl<'input> SetControlsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
 impl<'input> Request for SetControlsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
+#[inline]
 pub fn set_controls<'c, 'input, Conn, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P>(conn: &'c Conn, device_spec: DeviceSpec, affect_internal_real_mods: A, internal_real_mods: B, affect_ignore_lock_real_mods: C, ignore_lock_real_mods: D, affect_internal_virtual_mods: E, internal_virtual_mods: F, affect_ignore_lock_virtual_mods: G, ignore_lock_virtual_mods: H, mouse_keys_dflt_btn: u8, groups_wrap: u8, access_x_options: I, affect_enabled_controls: J, enabled_controls: K, change_controls: L, repeat_delay: u16, repeat_interval: u16, slow_keys_delay: u16, debounce_delay: u16, mouse_keys_delay: u16, mouse_keys_interval: u16, mouse_keys_time_to_max: u16, mouse_keys_max_speed: u16, mouse_keys_curve: i16, access_x_timeout: u16, access_x_timeout_mask: M, access_x_timeout_values: N, access_x_timeout_options_mask: O, access_x_timeout_options_values: P, per_key_repeat: &'input [u8; 32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7400,6 +8426,7 @@ where
 
 /// Opcode for the GetMap request
 pub const GET_MAP_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMapRequest {
     pub device_spec: DeviceSpec,
@@ -7421,14 +8448,73 @@ pub struct GetMapRequest {
     pub first_v_mod_map_key: xproto::Keycode,
     pub n_v_mod_map_keys: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl GetMapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let full_bytes = self.full.serialize();
+        let partial_bytes = self.partial.serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_key_sym_bytes = self.first_key_sym.serialize();
+        let n_key_syms_bytes = self.n_key_syms.serialize();
+        let first_key_action_bytes = self.first_key_action.serialize();
+        let n_key_actions_bytes = self.n_key_actions.serialize();
+        let first_key_behavior_bytes = self.first_key_behavior.serialize();
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize();
+        let virtual_mods_bytes = self.virtual_mods.serialize();
+        let first_key_explicit_bytes = self.first_key_explicit.serialize();
+        let n_key_explicit_bytes = self.n_key_explicit.serialize();
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize();
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize();
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize();
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            GET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            full_bytes[0],
+            full_bytes[1],
+            partial_bytes[0],
+            partial_bytes[1],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let device_spec_bytes = self.device_spec.serialize();
         let full_bytes = self.full.serialize();
@@ -7448,8 +8534,8 @@ impl GetMapRequest {
         let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize();
         let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize();
         let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             GET_MAP_REQUEST,
             0,
             0,
@@ -7477,19 +8563,42 @@ impl GetMapRequest {
             n_v_mod_map_keys_bytes[0],
             0,
             0,
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 28;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7539,9 +8648,12 @@ impl GetMapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl Request for GetMapRequest {
     type Reply = GetMapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
+#[inline]
 pub fn get_map<Conn, A, B, C>(conn: &Conn, device_spec: DeviceSpec, full: A, partial: B, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, first_key_action: xproto::Keycode, n_key_actions: u8, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, virtual_mods: C, first_key_explicit: xproto::Keycode, n_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8) -> Result<Cookie<'_, Conn, GetMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7575,25 +8687,25 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 #[derive(Debug, Clone)]
 pub struct GetMapMapBitcase3 {
     pub acts_rtrn_count: Vec<u8>,
     pub acts_rtrn_acts: Vec<Action>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl GetMapMapBitcase3 {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
         let result = GetMapMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 #[derive(Debug, Clone, Default)]
 pub struct GetMapMap {
     pub types_rtrn: Option<Vec<KeyType>>,
@@ -7605,6 +8717,7 @@ pub struct GetMapMap {
     pub modmap_rtrn: Option<Vec<KeyModMap>>,
     pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl GetMapMap {
     fn try_parse(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(present);
@@ -7645,10 +8758,7 @@ impl GetMapMap {
             let value = remaining;
             let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_to_usize()?)?;
             let vmods_rtrn = vmods_rtrn.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(vmods_rtrn)
         } else {
@@ -7658,10 +8768,7 @@ impl GetMapMap {
             let remaining = outer_remaining;
             let value = remaining;
             let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(explicit_rtrn)
         } else {
@@ -7671,10 +8778,7 @@ impl GetMapMap {
             let remaining = outer_remaining;
             let value = remaining;
             let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(modmap_rtrn)
         } else {
@@ -7693,7 +8797,8 @@ impl GetMapMap {
     }
 }
 
-#[derive(Debug, Clone)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
+#[derive(Clone)]
 pub struct GetMapReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -7724,6 +8829,42 @@ pub struct GetMapReply {
     pub virtual_mods: u16,
     pub map: GetMapMap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
+impl std::fmt::Debug for GetMapReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetMapReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("total_types", &self.total_types)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("total_syms", &self.total_syms)
+            .field("n_key_syms", &self.n_key_syms)
+            .field("first_key_action", &self.first_key_action)
+            .field("total_actions", &self.total_actions)
+            .field("n_key_actions", &self.n_key_actions)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behaviors", &self.n_key_behaviors)
+            .field("total_key_behaviors", &self.total_key_behaviors)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("total_key_explicit", &self.total_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("total_mod_map_keys", &self.total_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("total_v_mod_map_keys", &self.total_v_mod_map_keys)
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .field("map", &self.map)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
 impl TryParse for GetMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7764,31 +8905,34 @@ impl TryParse for GetMapReply {
         }
         let result = GetMapReply { device_id, sequence, length, min_key_code, max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
 
+/// Opcode for the SetMap request
+pub const SET_MAP_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 #[derive(Debug, Clone)]
 pub struct SetMapAuxBitcase3 {
     pub actions_count: Vec<u8>,
     pub actions: Vec<Action>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAuxBitcase3 {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (actions_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
         let actions_count = actions_count.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
         let result = SetMapAuxBitcase3 { actions_count, actions };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAuxBitcase3 {
     #[allow(dead_code)]
     fn serialize(&self, n_key_actions: u8, total_actions: u16) -> Vec<u8> {
@@ -7804,6 +8948,7 @@ impl SetMapAuxBitcase3 {
         self.actions.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 /// Auxiliary and optional information for the `set_map` function
 #[derive(Debug, Clone, Default)]
 pub struct SetMapAux {
@@ -7816,6 +8961,7 @@ pub struct SetMapAux {
     pub modmap: Option<Vec<KeyModMap>>,
     pub vmodmap: Option<Vec<KeyVModMap>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAux {
     fn try_parse(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(present);
@@ -7856,10 +9002,7 @@ impl SetMapAux {
             let value = remaining;
             let (vmods, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_to_usize()?)?;
             let vmods = vmods.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(vmods)
         } else {
@@ -7893,6 +9036,7 @@ impl SetMapAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAux {
     #[allow(dead_code)]
     fn serialize(&self, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Vec<u8> {
@@ -7936,6 +9080,7 @@ impl SetMapAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -7966,6 +9111,7 @@ impl SetMapAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl SetMapAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -8013,8 +9159,7 @@ impl SetMapAux {
     }
 }
 
-/// Opcode for the SetMap request
-pub const SET_MAP_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 #[derive(Debug, Clone)]
 pub struct SetMapRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -8044,6 +9189,7 @@ pub struct SetMapRequest<'input> {
     pub virtual_mods: u16,
     pub values: Cow<'input, SetMapAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl<'input> SetMapRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8128,12 +9274,118 @@ impl<'input> SetMapRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), values_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let present = u16::try_from(self.values.switch_expr()).unwrap();
+        let present_bytes = present.serialize();
+        let flags_bytes = self.flags.serialize();
+        let min_key_code_bytes = self.min_key_code.serialize();
+        let max_key_code_bytes = self.max_key_code.serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_key_sym_bytes = self.first_key_sym.serialize();
+        let n_key_syms_bytes = self.n_key_syms.serialize();
+        let total_syms_bytes = self.total_syms.serialize();
+        let first_key_action_bytes = self.first_key_action.serialize();
+        let n_key_actions_bytes = self.n_key_actions.serialize();
+        let total_actions_bytes = self.total_actions.serialize();
+        let first_key_behavior_bytes = self.first_key_behavior.serialize();
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize();
+        let total_key_behaviors_bytes = self.total_key_behaviors.serialize();
+        let first_key_explicit_bytes = self.first_key_explicit.serialize();
+        let n_key_explicit_bytes = self.n_key_explicit.serialize();
+        let total_key_explicit_bytes = self.total_key_explicit.serialize();
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize();
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize();
+        let total_mod_map_keys_bytes = self.total_mod_map_keys.serialize();
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize();
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize();
+        let total_v_mod_map_keys_bytes = self.total_v_mod_map_keys.serialize();
+        let virtual_mods_bytes = self.virtual_mods.serialize();
+        write(&[
+            major_opcode,
+            SET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            present_bytes[0],
+            present_bytes[1],
+            flags_bytes[0],
+            flags_bytes[1],
+            min_key_code_bytes[0],
+            max_key_code_bytes[0],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            total_syms_bytes[0],
+            total_syms_bytes[1],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            total_actions_bytes[0],
+            total_actions_bytes[1],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            total_key_behaviors_bytes[0],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            total_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            total_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            total_v_mod_map_keys_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 36;
+        let values_bytes = self.values.serialize(present, self.n_types, self.n_key_syms, self.n_key_actions, self.total_actions, self.total_key_behaviors, self.virtual_mods, self.total_key_explicit, self.total_mod_map_keys, self.total_v_mod_map_keys);
+        let length_so_far = length_so_far + values_bytes.len();
+        write(&values_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let present = u16::try_from(self.values.switch_expr()).unwrap();
+        let length_so_far = 36 + self.values.serialize(present, self.n_types, self.n_key_syms, self.n_key_actions, self.total_actions, self.total_key_behaviors, self.virtual_mods, self.total_key_explicit, self.total_mod_map_keys, self.total_v_mod_map_keys).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8230,9 +9482,12 @@ impl<'input> SetMapRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
 impl<'input> Request for SetMapRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
+#[inline]
 pub fn set_map<'c, 'input, Conn, A, B>(conn: &'c Conn, device_spec: DeviceSpec, flags: A, min_key_code: xproto::Keycode, max_key_code: xproto::Keycode, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, total_syms: u16, first_key_action: xproto::Keycode, n_key_actions: u8, total_actions: u16, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, total_key_behaviors: u8, first_key_explicit: xproto::Keycode, n_key_explicit: u8, total_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, total_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8, total_v_mod_map_keys: u8, virtual_mods: B, values: &'input SetMapAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8274,6 +9529,7 @@ where
 
 /// Opcode for the GetCompatMap request
 pub const GET_COMPAT_MAP_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetCompatMapRequest {
     pub device_spec: DeviceSpec,
@@ -8282,6 +9538,7 @@ pub struct GetCompatMapRequest {
     pub first_si: u16,
     pub n_si: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 impl GetCompatMapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8316,12 +9573,64 @@ impl GetCompatMapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let groups_bytes = self.groups.serialize();
+        let get_all_si_bytes = self.get_all_si.serialize();
+        let first_si_bytes = self.first_si.serialize();
+        let n_si_bytes = self.n_si.serialize();
+        write(&[
+            major_opcode,
+            GET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            groups_bytes[0],
+            get_all_si_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetCompatMapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8344,9 +9653,12 @@ impl GetCompatMapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 impl Request for GetCompatMapRequest {
     type Reply = GetCompatMapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
+#[inline]
 pub fn get_compat_map<Conn, A>(conn: &Conn, device_spec: DeviceSpec, groups: A, get_all_si: bool, first_si: u16, n_si: u16) -> Result<Cookie<'_, Conn, GetCompatMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8363,7 +9675,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetCompatMapReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -8374,6 +9687,22 @@ pub struct GetCompatMapReply {
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
+impl std::fmt::Debug for GetCompatMapReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetCompatMapReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("groups_rtrn", &SetOfGroup::from(self.groups_rtrn))
+            .field("first_si_rtrn", &self.first_si_rtrn)
+            .field("n_total_si", &self.n_total_si)
+            .field("si_rtrn", &self.si_rtrn)
+            .field("group_rtrn", &self.group_rtrn)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 impl TryParse for GetCompatMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8394,11 +9723,13 @@ impl TryParse for GetCompatMapReply {
         }
         let result = GetCompatMapReply { device_id, sequence, length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
 impl GetCompatMapReply {
     /// Get the value of the `nSIRtrn` field.
     ///
@@ -8417,6 +9748,7 @@ impl GetCompatMapReply {
 
 /// Opcode for the SetCompatMap request
 pub const SET_COMPAT_MAP_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetCompatMapRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -8427,6 +9759,7 @@ pub struct SetCompatMapRequest<'input> {
     pub si: Cow<'input, [SymInterpret]>,
     pub group_maps: Cow<'input, [ModDef]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
 impl<'input> SetCompatMapRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8474,12 +9807,81 @@ impl<'input> SetCompatMapRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), si_bytes.into(), group_maps_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let recompute_actions_bytes = self.recompute_actions.serialize();
+        let truncate_si_bytes = self.truncate_si.serialize();
+        let groups_bytes = self.groups.serialize();
+        let first_si_bytes = self.first_si.serialize();
+        let n_si = u16::try_from(self.si.len()).expect("`si` has too many elements");
+        let n_si_bytes = n_si.serialize();
+        write(&[
+            major_opcode,
+            SET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            recompute_actions_bytes[0],
+            truncate_si_bytes[0],
+            groups_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let si_bytes = self.si.serialize();
+        let length_so_far = length_so_far + si_bytes.len();
+        write(&si_bytes[..]);
+        assert_eq!(self.group_maps.len(), usize::try_from(self.groups.count_ones()).unwrap(), "`group_maps` has an incorrect length");
+        let group_maps_bytes = self.group_maps.serialize();
+        let length_so_far = length_so_far + group_maps_bytes.len();
+        write(&group_maps_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.si.len() * 16 + self.group_maps.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8521,9 +9923,12 @@ impl<'input> SetCompatMapRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
 impl<'input> Request for SetCompatMapRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
+#[inline]
 pub fn set_compat_map<'c, 'input, Conn, A>(conn: &'c Conn, device_spec: DeviceSpec, recompute_actions: bool, truncate_si: bool, groups: A, first_si: u16, si: &'input [SymInterpret], group_maps: &'input [ModDef]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8544,10 +9949,12 @@ where
 
 /// Opcode for the GetIndicatorState request
 pub const GET_INDICATOR_STATE_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetIndicatorStateRequest {
     pub device_spec: DeviceSpec,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 impl GetIndicatorStateRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8574,12 +9981,56 @@ impl GetIndicatorStateRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        write(&[
+            major_opcode,
+            GET_INDICATOR_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetIndicatorStateReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8595,9 +10046,12 @@ impl GetIndicatorStateRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 impl Request for GetIndicatorStateRequest {
     type Reply = GetIndicatorStateReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
+#[inline]
 pub fn get_indicator_state<Conn>(conn: &Conn, device_spec: DeviceSpec) -> Result<Cookie<'_, Conn, GetIndicatorStateReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8608,6 +10062,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetIndicatorStateReply {
     pub device_id: u8,
@@ -8615,6 +10070,7 @@ pub struct GetIndicatorStateReply {
     pub length: u32,
     pub state: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
 impl TryParse for GetIndicatorStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8629,7 +10085,8 @@ impl TryParse for GetIndicatorStateReply {
         }
         let result = GetIndicatorStateReply { device_id, sequence, length, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8637,11 +10094,13 @@ impl TryParse for GetIndicatorStateReply {
 
 /// Opcode for the GetIndicatorMap request
 pub const GET_INDICATOR_MAP_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetIndicatorMapRequest {
     pub device_spec: DeviceSpec,
     pub which: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 impl GetIndicatorMapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8673,12 +10132,61 @@ impl GetIndicatorMapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = self.which.serialize();
+        write(&[
+            major_opcode,
+            GET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetIndicatorMapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8696,9 +10204,12 @@ impl GetIndicatorMapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 impl Request for GetIndicatorMapRequest {
     type Reply = GetIndicatorMapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
+#[inline]
 pub fn get_indicator_map<Conn>(conn: &Conn, device_spec: DeviceSpec, which: u32) -> Result<Cookie<'_, Conn, GetIndicatorMapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8710,6 +10221,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetIndicatorMapReply {
     pub device_id: u8,
@@ -8720,6 +10232,7 @@ pub struct GetIndicatorMapReply {
     pub n_indicators: u8,
     pub maps: Vec<IndicatorMap>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
 impl TryParse for GetIndicatorMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8737,7 +10250,8 @@ impl TryParse for GetIndicatorMapReply {
         }
         let result = GetIndicatorMapReply { device_id, sequence, length, which, real_indicators, n_indicators, maps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8745,12 +10259,14 @@ impl TryParse for GetIndicatorMapReply {
 
 /// Opcode for the SetIndicatorMap request
 pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetIndicatorMapRequest<'input> {
     pub device_spec: DeviceSpec,
     pub which: u32,
     pub maps: Cow<'input, [IndicatorMap]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
 impl<'input> SetIndicatorMapRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8787,12 +10303,69 @@ impl<'input> SetIndicatorMapRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), maps_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = self.which.serialize();
+        write(&[
+            major_opcode,
+            SET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(self.maps.len(), usize::try_from(self.which.count_ones()).unwrap(), "`maps` has an incorrect length");
+        let maps_bytes = self.maps.serialize();
+        let length_so_far = length_so_far + maps_bytes.len();
+        write(&maps_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.maps.len() * 12;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8820,9 +10393,12 @@ impl<'input> SetIndicatorMapRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
 impl<'input> Request for SetIndicatorMapRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
+#[inline]
 pub fn set_indicator_map<'c, 'input, Conn>(conn: &'c Conn, device_spec: DeviceSpec, which: u32, maps: &'input [IndicatorMap]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8837,6 +10413,7 @@ where
 
 /// Opcode for the GetNamedIndicator request
 pub const GET_NAMED_INDICATOR_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetNamedIndicatorRequest {
     pub device_spec: DeviceSpec,
@@ -8844,6 +10421,7 @@ pub struct GetNamedIndicatorRequest {
     pub led_id: IDSpec,
     pub indicator: xproto::Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
 impl GetNamedIndicatorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -8881,12 +10459,67 @@ impl GetNamedIndicatorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        let indicator_bytes = self.indicator.serialize();
+        write(&[
+            major_opcode,
+            GET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetNamedIndicatorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8897,6 +10530,8 @@ impl GetNamedIndicatorRequest {
         let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
         let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
         let led_class = led_class.into();
+        #[cfg(feature = "strict_parsing")]
+        LedClass::check_known(led_class)?;
         let (led_id, remaining) = IDSpec::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -8909,9 +10544,12 @@ impl GetNamedIndicatorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
 impl Request for GetNamedIndicatorRequest {
     type Reply = GetNamedIndicatorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
+#[inline]
 pub fn get_named_indicator<Conn, A>(conn: &Conn, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom) -> Result<Cookie<'_, Conn, GetNamedIndicatorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8927,7 +10565,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GetNamedIndicatorReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -8947,6 +10586,31 @@ pub struct GetNamedIndicatorReply {
     pub map_ctrls: u32,
     pub supported: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
+impl std::fmt::Debug for GetNamedIndicatorReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetNamedIndicatorReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("indicator", &self.indicator)
+            .field("found", &self.found)
+            .field("on", &self.on)
+            .field("real_indicator", &self.real_indicator)
+            .field("ndx", &self.ndx)
+            .field("map_flags", &IMFlag::from(self.map_flags))
+            .field("map_which_groups", &IMGroupsWhich::from(self.map_which_groups))
+            .field("map_groups", &SetOfGroups::from(self.map_groups))
+            .field("map_which_mods", &IMModsWhich::from(self.map_which_mods))
+            .field("map_mods", &xproto::ModMask::from(self.map_mods))
+            .field("map_real_mods", &xproto::ModMask::from(self.map_real_mods))
+            .field("map_vmod", &VMod::from(self.map_vmod))
+            .field("map_ctrls", &self.map_ctrls)
+            .field("supported", &self.supported)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
 impl TryParse for GetNamedIndicatorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8974,7 +10638,8 @@ impl TryParse for GetNamedIndicatorReply {
         }
         let result = GetNamedIndicatorReply { device_id, sequence, length, indicator, found, on, real_indicator, ndx, map_flags, map_which_groups, map_groups, map_which_mods, map_mods, map_real_mods, map_vmod, map_ctrls, supported };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8982,6 +10647,7 @@ impl TryParse for GetNamedIndicatorReply {
 
 /// Opcode for the SetNamedIndicator request
 pub const SET_NAMED_INDICATOR_REQUEST: u8 = 16;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetNamedIndicatorRequest {
     pub device_spec: DeviceSpec,
@@ -9000,6 +10666,7 @@ pub struct SetNamedIndicatorRequest {
     pub map_vmods: u16,
     pub map_ctrls: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
 impl SetNamedIndicatorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9064,12 +10731,94 @@ impl SetNamedIndicatorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        let indicator_bytes = self.indicator.serialize();
+        let set_state_bytes = self.set_state.serialize();
+        let on_bytes = self.on.serialize();
+        let set_map_bytes = self.set_map.serialize();
+        let create_map_bytes = self.create_map.serialize();
+        let map_flags_bytes = self.map_flags.serialize();
+        let map_which_groups_bytes = self.map_which_groups.serialize();
+        let map_groups_bytes = self.map_groups.serialize();
+        let map_which_mods_bytes = self.map_which_mods.serialize();
+        let map_real_mods_bytes = self.map_real_mods.serialize();
+        let map_vmods_bytes = self.map_vmods.serialize();
+        let map_ctrls_bytes = self.map_ctrls.serialize();
+        write(&[
+            major_opcode,
+            SET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+            set_state_bytes[0],
+            on_bytes[0],
+            set_map_bytes[0],
+            create_map_bytes[0],
+            0,
+            map_flags_bytes[0],
+            map_which_groups_bytes[0],
+            map_groups_bytes[0],
+            map_which_mods_bytes[0],
+            map_real_mods_bytes[0],
+            map_vmods_bytes[0],
+            map_vmods_bytes[1],
+            map_ctrls_bytes[0],
+            map_ctrls_bytes[1],
+            map_ctrls_bytes[2],
+            map_ctrls_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9080,6 +10829,8 @@ impl SetNamedIndicatorRequest {
         let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
         let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
         let led_class = led_class.into();
+        #[cfg(feature = "strict_parsing")]
+        LedClass::check_known(led_class)?;
         let (led_id, remaining) = IDSpec::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -9115,9 +10866,12 @@ impl SetNamedIndicatorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
 impl Request for SetNamedIndicatorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
+#[inline]
 pub fn set_named_indicator<Conn, A, B, C, D, E, F, G, H>(conn: &Conn, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: B, map_which_groups: C, map_groups: D, map_which_mods: E, map_real_mods: F, map_vmods: G, map_ctrls: H) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9160,11 +10914,13 @@ where
 
 /// Opcode for the GetNames request
 pub const GET_NAMES_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetNamesRequest {
     pub device_spec: DeviceSpec,
     pub which: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl GetNamesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9196,12 +10952,61 @@ impl GetNamesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = self.which.serialize();
+        write(&[
+            major_opcode,
+            GET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetNamesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9219,9 +11024,12 @@ impl GetNamesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl Request for GetNamesRequest {
     type Reply = GetNamesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
+#[inline]
 pub fn get_names<Conn, A>(conn: &Conn, device_spec: DeviceSpec, which: A) -> Result<Cookie<'_, Conn, GetNamesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9235,25 +11043,25 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetNamesValueListBitcase8 {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl GetNamesValueListBitcase8 {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
         let n_levels_per_type = n_levels_per_type.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = GetNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct GetNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
@@ -9271,6 +11079,7 @@ pub struct GetNamesValueList {
     pub key_aliases: Option<Vec<KeyAlias>>,
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl GetNamesValueList {
     fn try_parse(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = which;
@@ -9391,7 +11200,8 @@ impl GetNamesValueList {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetNamesReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -9409,6 +11219,29 @@ pub struct GetNamesReply {
     pub n_kt_levels: u16,
     pub value_list: GetNamesValueList,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
+impl std::fmt::Debug for GetNamesReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetNamesReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("n_types", &self.n_types)
+            .field("group_names", &SetOfGroup::from(self.group_names))
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .field("first_key", &self.first_key)
+            .field("n_keys", &self.n_keys)
+            .field("indicators", &self.indicators)
+            .field("n_radio_groups", &self.n_radio_groups)
+            .field("n_key_aliases", &self.n_key_aliases)
+            .field("n_kt_levels", &self.n_kt_levels)
+            .field("value_list", &self.value_list)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
 impl TryParse for GetNamesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9435,31 +11268,34 @@ impl TryParse for GetNamesReply {
         }
         let result = GetNamesReply { device_id, sequence, length, min_key_code, max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
 
+/// Opcode for the SetNames request
+pub const SET_NAMES_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetNamesAuxBitcase8 {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAuxBitcase8 {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
         let n_levels_per_type = n_levels_per_type.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = SetNamesAuxBitcase8 { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAuxBitcase8 {
     #[allow(dead_code)]
     fn serialize(&self, n_types: u8) -> Vec<u8> {
@@ -9475,6 +11311,7 @@ impl SetNamesAuxBitcase8 {
         self.kt_level_names.serialize_into(bytes);
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 /// Auxiliary and optional information for the `set_names` function
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct SetNamesAux {
@@ -9493,6 +11330,7 @@ pub struct SetNamesAux {
     pub key_aliases: Option<Vec<KeyAlias>>,
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAux {
     fn try_parse(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = which;
@@ -9612,6 +11450,7 @@ impl SetNamesAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAux {
     #[allow(dead_code)]
     fn serialize(&self, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Vec<u8> {
@@ -9672,6 +11511,7 @@ impl SetNamesAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -9720,6 +11560,7 @@ impl SetNamesAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl SetNamesAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -9797,8 +11638,7 @@ impl SetNamesAux {
     }
 }
 
-/// Opcode for the SetNames request
-pub const SET_NAMES_REQUEST: u8 = 18;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetNamesRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -9816,6 +11656,7 @@ pub struct SetNamesRequest<'input> {
     pub total_kt_level_names: u16,
     pub values: Cow<'input, SetNamesAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl<'input> SetNamesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -9880,12 +11721,98 @@ impl<'input> SetNamesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), values_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let virtual_mods_bytes = self.virtual_mods.serialize();
+        let which = self.values.switch_expr();
+        let which_bytes = which.serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_kt_levelt_bytes = self.first_kt_levelt.serialize();
+        let n_kt_levels_bytes = self.n_kt_levels.serialize();
+        let indicators_bytes = self.indicators.serialize();
+        let group_names_bytes = self.group_names.serialize();
+        let n_radio_groups_bytes = self.n_radio_groups.serialize();
+        let first_key_bytes = self.first_key.serialize();
+        let n_keys_bytes = self.n_keys.serialize();
+        let n_key_aliases_bytes = self.n_key_aliases.serialize();
+        let total_kt_level_names_bytes = self.total_kt_level_names.serialize();
+        write(&[
+            major_opcode,
+            SET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_kt_levelt_bytes[0],
+            n_kt_levels_bytes[0],
+            indicators_bytes[0],
+            indicators_bytes[1],
+            indicators_bytes[2],
+            indicators_bytes[3],
+            group_names_bytes[0],
+            n_radio_groups_bytes[0],
+            first_key_bytes[0],
+            n_keys_bytes[0],
+            n_key_aliases_bytes[0],
+            0,
+            total_kt_level_names_bytes[0],
+            total_kt_level_names_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 28;
+        let values_bytes = self.values.serialize(which, self.n_types, self.indicators, self.virtual_mods, self.group_names, self.n_keys, self.n_key_aliases, self.n_radio_groups);
+        let length_so_far = length_so_far + values_bytes.len();
+        write(&values_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let which = self.values.switch_expr();
+        let length_so_far = 28 + self.values.serialize(which, self.n_types, self.indicators, self.virtual_mods, self.group_names, self.n_keys, self.n_key_aliases, self.n_radio_groups).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9947,9 +11874,12 @@ impl<'input> SetNamesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
 impl<'input> Request for SetNamesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
+#[inline]
 pub fn set_names<'c, 'input, Conn, A, B>(conn: &'c Conn, device_spec: DeviceSpec, virtual_mods: A, first_type: u8, n_types: u8, first_kt_levelt: u8, n_kt_levels: u8, indicators: u32, group_names: B, n_radio_groups: u8, first_key: xproto::Keycode, n_keys: u8, n_key_aliases: u8, total_kt_level_names: u16, values: &'input SetNamesAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9979,6 +11909,7 @@ where
 
 /// Opcode for the PerClientFlags request
 pub const PER_CLIENT_FLAGS_REQUEST: u8 = 21;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PerClientFlagsRequest {
     pub device_spec: DeviceSpec,
@@ -9988,14 +11919,61 @@ pub struct PerClientFlagsRequest {
     pub auto_ctrls: u32,
     pub auto_ctrls_values: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 impl PerClientFlagsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let change_bytes = self.change.serialize();
+        let value_bytes = self.value.serialize();
+        let ctrls_to_change_bytes = self.ctrls_to_change.serialize();
+        let auto_ctrls_bytes = self.auto_ctrls.serialize();
+        let auto_ctrls_values_bytes = self.auto_ctrls_values.serialize();
+        let mut request0 = vec![
+            extension_information.major_opcode,
+            PER_CLIENT_FLAGS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            change_bytes[0],
+            change_bytes[1],
+            change_bytes[2],
+            change_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            ctrls_to_change_bytes[0],
+            ctrls_to_change_bytes[1],
+            ctrls_to_change_bytes[2],
+            ctrls_to_change_bytes[3],
+            auto_ctrls_bytes[0],
+            auto_ctrls_bytes[1],
+            auto_ctrls_bytes[2],
+            auto_ctrls_bytes[3],
+            auto_ctrls_values_bytes[0],
+            auto_ctrls_values_bytes[1],
+            auto_ctrls_values_bytes[2],
+            auto_ctrls_values_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        Ok((vec![request0.into()], vec![]))
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
         let length_so_far = 0;
         let device_spec_bytes = self.device_spec.serialize();
         let change_bytes = self.change.serialize();
@@ -10003,8 +11981,8 @@ impl PerClientFlagsRequest {
         let ctrls_to_change_bytes = self.ctrls_to_change.serialize();
         let auto_ctrls_bytes = self.auto_ctrls.serialize();
         let auto_ctrls_values_bytes = self.auto_ctrls_values.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
+        write(&[
+            major_opcode,
             PER_CLIENT_FLAGS_REQUEST,
             0,
             0,
@@ -10032,19 +12010,42 @@ impl PerClientFlagsRequest {
             auto_ctrls_values_bytes[1],
             auto_ctrls_values_bytes[2],
             auto_ctrls_values_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = length_so_far + 28;
         assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
     }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, PerClientFlagsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10070,9 +12071,12 @@ impl PerClientFlagsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 impl Request for PerClientFlagsRequest {
     type Reply = PerClientFlagsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
+#[inline]
 pub fn per_client_flags<Conn, A, B, C, D, E>(conn: &Conn, device_spec: DeviceSpec, change: A, value: B, ctrls_to_change: C, auto_ctrls: D, auto_ctrls_values: E) -> Result<Cookie<'_, Conn, PerClientFlagsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10098,6 +12102,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PerClientFlagsReply {
     pub device_id: u8,
@@ -10108,6 +12113,7 @@ pub struct PerClientFlagsReply {
     pub auto_ctrls: u32,
     pub auto_ctrls_values: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
 impl TryParse for PerClientFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10125,7 +12131,8 @@ impl TryParse for PerClientFlagsReply {
         }
         let result = PerClientFlagsReply { device_id, sequence, length, supported, value, auto_ctrls, auto_ctrls_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -10133,11 +12140,13 @@ impl TryParse for PerClientFlagsReply {
 
 /// Opcode for the ListComponents request
 pub const LIST_COMPONENTS_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListComponentsRequest {
     pub device_spec: DeviceSpec,
     pub max_names: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 impl ListComponentsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10165,12 +12174,57 @@ impl ListComponentsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let max_names_bytes = self.max_names.serialize();
+        write(&[
+            major_opcode,
+            LIST_COMPONENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            max_names_bytes[0],
+            max_names_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListComponentsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10187,9 +12241,12 @@ impl ListComponentsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 impl Request for ListComponentsRequest {
     type Reply = ListComponentsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
+#[inline]
 pub fn list_components<Conn>(conn: &Conn, device_spec: DeviceSpec, max_names: u16) -> Result<Cookie<'_, Conn, ListComponentsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10201,6 +12258,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListComponentsReply {
     pub device_id: u8,
@@ -10214,6 +12272,7 @@ pub struct ListComponentsReply {
     pub symbols: Vec<Listing>,
     pub geometries: Vec<Listing>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 impl TryParse for ListComponentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10240,11 +12299,13 @@ impl TryParse for ListComponentsReply {
         }
         let result = ListComponentsReply { device_id, sequence, length, extra, keymaps, keycodes, types, compat_maps, symbols, geometries };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
 impl ListComponentsReply {
     /// Get the value of the `nKeymaps` field.
     ///
@@ -10328,6 +12389,7 @@ impl ListComponentsReply {
 
 /// Opcode for the GetKbdByName request
 pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetKbdByNameRequest {
     pub device_spec: DeviceSpec,
@@ -10335,6 +12397,7 @@ pub struct GetKbdByNameRequest {
     pub want: u16,
     pub load: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -10368,12 +12431,63 @@ impl GetKbdByNameRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let need_bytes = self.need.serialize();
+        let want_bytes = self.want.serialize();
+        let load_bytes = self.load.serialize();
+        write(&[
+            major_opcode,
+            GET_KBD_BY_NAME_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            need_bytes[0],
+            need_bytes[1],
+            want_bytes[0],
+            want_bytes[1],
+            load_bytes[0],
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetKbdByNameReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10395,9 +12509,12 @@ impl GetKbdByNameRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl Request for GetKbdByNameRequest {
     type Reply = GetKbdByNameReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+#[inline]
 pub fn get_kbd_by_name<Conn, A, B>(conn: &Conn, device_spec: DeviceSpec, need: A, want: B, load: bool) -> Result<Cookie<'_, Conn, GetKbdByNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10415,25 +12532,25 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone)]
 pub struct GetKbdByNameRepliesTypesMapBitcase3 {
     pub acts_rtrn_count: Vec<u8>,
     pub acts_rtrn_acts: Vec<Action>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesTypesMapBitcase3 {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
         let result = GetKbdByNameRepliesTypesMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, Default)]
 pub struct GetKbdByNameRepliesTypesMap {
     pub types_rtrn: Option<Vec<KeyType>>,
@@ -10445,6 +12562,7 @@ pub struct GetKbdByNameRepliesTypesMap {
     pub modmap_rtrn: Option<Vec<KeyModMap>>,
     pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesTypesMap {
     fn try_parse(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(present);
@@ -10485,10 +12603,7 @@ impl GetKbdByNameRepliesTypesMap {
             let value = remaining;
             let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_to_usize()?)?;
             let vmods_rtrn = vmods_rtrn.to_vec();
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(vmods_rtrn)
         } else {
@@ -10498,10 +12613,7 @@ impl GetKbdByNameRepliesTypesMap {
             let remaining = outer_remaining;
             let value = remaining;
             let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(explicit_rtrn)
         } else {
@@ -10511,10 +12623,7 @@ impl GetKbdByNameRepliesTypesMap {
             let remaining = outer_remaining;
             let value = remaining;
             let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?)?;
-            // Align offset to multiple of 4
-            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-            let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::wire::align(value, remaining, 4)?;
             outer_remaining = remaining;
             Some(modmap_rtrn)
         } else {
@@ -10533,7 +12642,8 @@ impl GetKbdByNameRepliesTypesMap {
     }
 }
 
-#[derive(Debug, Clone)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+#[derive(Clone)]
 pub struct GetKbdByNameRepliesTypes {
     pub getmap_type: u8,
     pub type_device_id: u8,
@@ -10565,6 +12675,43 @@ pub struct GetKbdByNameRepliesTypes {
     pub virtual_mods: u16,
     pub map: GetKbdByNameRepliesTypesMap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+impl std::fmt::Debug for GetKbdByNameRepliesTypes {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetKbdByNameRepliesTypes")
+            .field("getmap_type", &self.getmap_type)
+            .field("type_device_id", &self.type_device_id)
+            .field("getmap_sequence", &self.getmap_sequence)
+            .field("getmap_length", &self.getmap_length)
+            .field("type_min_key_code", &self.type_min_key_code)
+            .field("type_max_key_code", &self.type_max_key_code)
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("total_types", &self.total_types)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("total_syms", &self.total_syms)
+            .field("n_key_syms", &self.n_key_syms)
+            .field("first_key_action", &self.first_key_action)
+            .field("total_actions", &self.total_actions)
+            .field("n_key_actions", &self.n_key_actions)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behaviors", &self.n_key_behaviors)
+            .field("total_key_behaviors", &self.total_key_behaviors)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("total_key_explicit", &self.total_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("total_mod_map_keys", &self.total_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("total_v_mod_map_keys", &self.total_v_mod_map_keys)
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .field("map", &self.map)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameRepliesTypes {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (getmap_type, remaining) = u8::try_parse(remaining)?;
@@ -10603,7 +12750,8 @@ impl TryParse for GetKbdByNameRepliesTypes {
         Ok((result, remaining))
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesCompatMap {
     pub compatmap_type: u8,
     pub compat_device_id: u8,
@@ -10615,6 +12763,23 @@ pub struct GetKbdByNameRepliesCompatMap {
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+impl std::fmt::Debug for GetKbdByNameRepliesCompatMap {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetKbdByNameRepliesCompatMap")
+            .field("compatmap_type", &self.compatmap_type)
+            .field("compat_device_id", &self.compat_device_id)
+            .field("compatmap_sequence", &self.compatmap_sequence)
+            .field("compatmap_length", &self.compatmap_length)
+            .field("groups_rtrn", &SetOfGroup::from(self.groups_rtrn))
+            .field("first_si_rtrn", &self.first_si_rtrn)
+            .field("n_total_si", &self.n_total_si)
+            .field("si_rtrn", &self.si_rtrn)
+            .field("group_rtrn", &self.group_rtrn)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameRepliesCompatMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (compatmap_type, remaining) = u8::try_parse(remaining)?;
@@ -10633,6 +12798,7 @@ impl TryParse for GetKbdByNameRepliesCompatMap {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesCompatMap {
     /// Get the value of the `nSIRtrn` field.
     ///
@@ -10648,6 +12814,7 @@ impl GetKbdByNameRepliesCompatMap {
             .try_into().unwrap()
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesIndicatorMaps {
     pub indicatormap_type: u8,
@@ -10658,6 +12825,7 @@ pub struct GetKbdByNameRepliesIndicatorMaps {
     pub real_indicators: u32,
     pub maps: Vec<IndicatorMap>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameRepliesIndicatorMaps {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (indicatormap_type, remaining) = u8::try_parse(remaining)?;
@@ -10673,6 +12841,7 @@ impl TryParse for GetKbdByNameRepliesIndicatorMaps {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesIndicatorMaps {
     /// Get the value of the `nIndicators` field.
     ///
@@ -10688,25 +12857,25 @@ impl GetKbdByNameRepliesIndicatorMaps {
             .try_into().unwrap()
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesKeyNamesValueListBitcase8 {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
         let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
         let n_levels_per_type = n_levels_per_type.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
         let result = GetKbdByNameRepliesKeyNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct GetKbdByNameRepliesKeyNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
@@ -10724,6 +12893,7 @@ pub struct GetKbdByNameRepliesKeyNamesValueList {
     pub key_aliases: Option<Vec<KeyAlias>>,
     pub radio_group_names: Option<Vec<xproto::Atom>>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameRepliesKeyNamesValueList {
     fn try_parse(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = which;
@@ -10844,7 +13014,8 @@ impl GetKbdByNameRepliesKeyNamesValueList {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+#[derive(Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesKeyNames {
     pub keyname_type: u8,
     pub key_device_id: u8,
@@ -10863,6 +13034,30 @@ pub struct GetKbdByNameRepliesKeyNames {
     pub n_kt_levels: u16,
     pub value_list: GetKbdByNameRepliesKeyNamesValueList,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+impl std::fmt::Debug for GetKbdByNameRepliesKeyNames {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetKbdByNameRepliesKeyNames")
+            .field("keyname_type", &self.keyname_type)
+            .field("key_device_id", &self.key_device_id)
+            .field("keyname_sequence", &self.keyname_sequence)
+            .field("keyname_length", &self.keyname_length)
+            .field("key_min_key_code", &self.key_min_key_code)
+            .field("key_max_key_code", &self.key_max_key_code)
+            .field("n_types", &self.n_types)
+            .field("group_names", &SetOfGroup::from(self.group_names))
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .field("first_key", &self.first_key)
+            .field("n_keys", &self.n_keys)
+            .field("indicators", &self.indicators)
+            .field("n_radio_groups", &self.n_radio_groups)
+            .field("n_key_aliases", &self.n_key_aliases)
+            .field("n_kt_levels", &self.n_kt_levels)
+            .field("value_list", &self.value_list)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameRepliesKeyNames {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keyname_type, remaining) = u8::try_parse(remaining)?;
@@ -10887,6 +13082,7 @@ impl TryParse for GetKbdByNameRepliesKeyNames {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKbdByNameRepliesGeometry {
     pub geometry_type: u8,
@@ -10907,6 +13103,7 @@ pub struct GetKbdByNameRepliesGeometry {
     pub label_color_ndx: u8,
     pub label_font: CountedString16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameRepliesGeometry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (geometry_type, remaining) = u8::try_parse(remaining)?;
@@ -10931,6 +13128,7 @@ impl TryParse for GetKbdByNameRepliesGeometry {
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone, Default)]
 pub struct GetKbdByNameReplies {
     pub types: Option<GetKbdByNameRepliesTypes>,
@@ -10939,6 +13137,7 @@ pub struct GetKbdByNameReplies {
     pub key_names: Option<GetKbdByNameRepliesKeyNames>,
     pub geometry: Option<GetKbdByNameRepliesGeometry>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl GetKbdByNameReplies {
     fn try_parse(value: &[u8], reported: u16) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(reported);
@@ -10983,6 +13182,7 @@ impl GetKbdByNameReplies {
     }
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 #[derive(Debug, Clone)]
 pub struct GetKbdByNameReply {
     pub device_id: u8,
@@ -10996,6 +13196,7 @@ pub struct GetKbdByNameReply {
     pub reported: u16,
     pub replies: GetKbdByNameReplies,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
 impl TryParse for GetKbdByNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11016,7 +13217,8 @@ impl TryParse for GetKbdByNameReply {
         }
         let result = GetKbdByNameReply { device_id, sequence, length, min_key_code, max_key_code, loaded, new_keyboard, found, reported, replies };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -11024,6 +13226,7 @@ impl TryParse for GetKbdByNameReply {
 
 /// Opcode for the GetDeviceInfo request
 pub const GET_DEVICE_INFO_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetDeviceInfoRequest {
     pub device_spec: DeviceSpec,
@@ -11034,6 +13237,7 @@ pub struct GetDeviceInfoRequest {
     pub led_class: LedClass,
     pub led_id: IDSpec,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 impl GetDeviceInfoRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11074,12 +13278,70 @@ impl GetDeviceInfoRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let wanted_bytes = self.wanted.serialize();
+        let all_buttons_bytes = self.all_buttons.serialize();
+        let first_button_bytes = self.first_button.serialize();
+        let n_buttons_bytes = self.n_buttons.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        write(&[
+            major_opcode,
+            GET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            wanted_bytes[0],
+            wanted_bytes[1],
+            all_buttons_bytes[0],
+            first_button_bytes[0],
+            n_buttons_bytes[0],
+            0,
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetDeviceInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11095,6 +13357,8 @@ impl GetDeviceInfoRequest {
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
         let led_class = led_class.into();
+        #[cfg(feature = "strict_parsing")]
+        LedClass::check_known(led_class)?;
         let (led_id, remaining) = IDSpec::try_parse(remaining)?;
         let _ = remaining;
         Ok(GetDeviceInfoRequest {
@@ -11108,9 +13372,12 @@ impl GetDeviceInfoRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 impl Request for GetDeviceInfoRequest {
     type Reply = GetDeviceInfoReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
+#[inline]
 pub fn get_device_info<Conn, A, B>(conn: &Conn, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: LedClass, led_id: B) -> Result<Cookie<'_, Conn, GetDeviceInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11131,7 +13398,8 @@ where
     request0.send(conn)
 }
 
-#[derive(Debug, Clone)]
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
+#[derive(Clone)]
 pub struct GetDeviceInfoReply {
     pub device_id: u8,
     pub sequence: u16,
@@ -11151,6 +13419,31 @@ pub struct GetDeviceInfoReply {
     pub btn_actions: Vec<Action>,
     pub leds: Vec<DeviceLedInfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
+impl std::fmt::Debug for GetDeviceInfoReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetDeviceInfoReply")
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("present", &self.present)
+            .field("supported", &self.supported)
+            .field("unsupported", &self.unsupported)
+            .field("first_btn_wanted", &self.first_btn_wanted)
+            .field("n_btns_wanted", &self.n_btns_wanted)
+            .field("first_btn_rtrn", &self.first_btn_rtrn)
+            .field("total_btns", &self.total_btns)
+            .field("has_own_state", &self.has_own_state)
+            .field("dflt_kbd_fb", &ID::from(self.dflt_kbd_fb))
+            .field("dflt_led_fb", &ID::from(self.dflt_led_fb))
+            .field("dev_type", &self.dev_type)
+            .field("name", &self.name)
+            .field("btn_actions", &self.btn_actions)
+            .field("leds", &self.leds)
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 impl TryParse for GetDeviceInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11176,10 +13469,7 @@ impl TryParse for GetDeviceInfoReply {
         let (name_len, remaining) = u16::try_parse(remaining)?;
         let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
         let name = name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns_rtrn.try_to_usize()?)?;
         let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_to_usize()?)?;
         if response_type != 1 {
@@ -11187,11 +13477,13 @@ impl TryParse for GetDeviceInfoReply {
         }
         let result = GetDeviceInfoReply { device_id, sequence, length, present, supported, unsupported, first_btn_wanted, n_btns_wanted, first_btn_rtrn, total_btns, has_own_state, dflt_kbd_fb, dflt_led_fb, dev_type, name, btn_actions, leds };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
 impl GetDeviceInfoReply {
     /// Get the value of the `nDeviceLedFBs` field.
     ///
@@ -11236,6 +13528,7 @@ impl GetDeviceInfoReply {
 
 /// Opcode for the SetDeviceInfo request
 pub const SET_DEVICE_INFO_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
 #[derive(Debug, Clone)]
 pub struct SetDeviceInfoRequest<'input> {
     pub device_spec: DeviceSpec,
@@ -11244,6 +13537,7 @@ pub struct SetDeviceInfoRequest<'input> {
     pub btn_actions: Cow<'input, [Action]>,
     pub leds: Cow<'input, [DeviceLedInfo]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
 impl<'input> SetDeviceInfoRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11286,12 +13580,76 @@ impl<'input> SetDeviceInfoRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), btn_actions_bytes.into(), leds_bytes.into(), padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize();
+        let first_btn_bytes = self.first_btn.serialize();
+        let n_btns = u8::try_from(self.btn_actions.len()).expect("`btn_actions` has too many elements");
+        let n_btns_bytes = n_btns.serialize();
+        let change_bytes = self.change.serialize();
+        let n_device_led_f_bs = u16::try_from(self.leds.len()).expect("`leds` has too many elements");
+        let n_device_led_f_bs_bytes = n_device_led_f_bs.serialize();
+        write(&[
+            major_opcode,
+            SET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            first_btn_bytes[0],
+            n_btns_bytes[0],
+            change_bytes[0],
+            change_bytes[1],
+            n_device_led_f_bs_bytes[0],
+            n_device_led_f_bs_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let btn_actions_bytes = self.btn_actions.serialize();
+        let length_so_far = length_so_far + btn_actions_bytes.len();
+        write(&btn_actions_bytes[..]);
+        let leds_bytes = self.leds.serialize();
+        let length_so_far = length_so_far + leds_bytes.len();
+        write(&leds_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.btn_actions.len() * 8 + self.leds.iter().map(|element| element.serialize().len()).sum::<usize>();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2]), IoSlice::new(&bytes[3])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11326,9 +13684,12 @@ impl<'input> SetDeviceInfoRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
 impl<'input> Request for SetDeviceInfoRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
+#[inline]
 pub fn set_device_info<'c, 'input, Conn, A>(conn: &'c Conn, device_spec: DeviceSpec, first_btn: u8, change: A, btn_actions: &'input [Action], leds: &'input [DeviceLedInfo]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11347,6 +13708,7 @@ where
 
 /// Opcode for the SetDebuggingFlags request
 pub const SET_DEBUGGING_FLAGS_REQUEST: u8 = 101;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetDebuggingFlagsRequest<'input> {
     pub affect_flags: u32,
@@ -11355,6 +13717,7 @@ pub struct SetDebuggingFlagsRequest<'input> {
     pub ctrls: u32,
     pub message: Cow<'input, [String8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 impl<'input> SetDebuggingFlagsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
@@ -11405,12 +13768,83 @@ impl<'input> SetDebuggingFlagsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         Ok((vec![request0.into(), self.message, padding0.into()], vec![]))
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, major_opcode: u8, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let msg_length = u16::try_from(self.message.len()).expect("`message` has too many elements");
+        let msg_length_bytes = msg_length.serialize();
+        let affect_flags_bytes = self.affect_flags.serialize();
+        let flags_bytes = self.flags.serialize();
+        let affect_ctrls_bytes = self.affect_ctrls.serialize();
+        let ctrls_bytes = self.ctrls.serialize();
+        write(&[
+            major_opcode,
+            SET_DEBUGGING_FLAGS_REQUEST,
+            0,
+            0,
+            msg_length_bytes[0],
+            msg_length_bytes[1],
+            0,
+            0,
+            affect_flags_bytes[0],
+            affect_flags_bytes[1],
+            affect_flags_bytes[2],
+            affect_flags_bytes[3],
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            affect_ctrls_bytes[0],
+            affect_ctrls_bytes[1],
+            affect_ctrls_bytes[2],
+            affect_ctrls_bytes[3],
+            ctrls_bytes[0],
+            ctrls_bytes[1],
+            ctrls_bytes[2],
+            ctrls_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        let length_so_far = length_so_far + self.message.len();
+        write(&self.message[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    ///
+    /// `major_opcode` is the major opcode of the extension on the X11 server.
+    pub fn serialize_into(self, major_opcode: u8, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(major_opcode, write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, major_opcode: u8, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(major_opcode, write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.message.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetDebuggingFlagsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11445,9 +13879,12 @@ impl<'input> SetDebuggingFlagsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 impl<'input> Request for SetDebuggingFlagsRequest<'input> {
     type Reply = SetDebuggingFlagsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
+#[inline]
 pub fn set_debugging_flags<'c, 'input, Conn>(conn: &'c Conn, affect_flags: u32, flags: u32, affect_ctrls: u32, ctrls: u32, message: &'input [String8]) -> Result<Cookie<'c, Conn, SetDebuggingFlagsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11462,6 +13899,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetDebuggingFlagsReply {
     pub sequence: u16,
@@ -11471,6 +13909,7 @@ pub struct SetDebuggingFlagsReply {
     pub supported_flags: u32,
     pub supported_ctrls: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
 impl TryParse for SetDebuggingFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11488,7 +13927,8 @@ impl TryParse for SetDebuggingFlagsReply {
         }
         let result = SetDebuggingFlagsReply { sequence, length, current_flags, current_ctrls, supported_flags, supported_ctrls };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -11595,7 +14035,7 @@ impl From<NewKeyboardNotifyEvent> for [u8; 32] {
 
 /// Opcode for the MapNotify event
 pub const MAP_NOTIFY_EVENT: u8 = 1;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct MapNotifyEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -11622,6 +14062,36 @@ pub struct MapNotifyEvent {
     pub n_v_mod_map_keys: u8,
     pub virtual_mods: u16,
 }
+impl std::fmt::Debug for MapNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("MapNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("ptr_btn_actions", &self.ptr_btn_actions)
+            .field("changed", &self.changed)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("n_key_syms", &self.n_key_syms)
+            .field("first_key_act", &self.first_key_act)
+            .field("n_key_acts", &self.n_key_acts)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behavior", &self.n_key_behavior)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("virtual_mods", &VMod::from(self.virtual_mods))
+            .finish()
+    }
+}
 impl TryParse for MapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11727,7 +14197,7 @@ impl From<MapNotifyEvent> for [u8; 32] {
 
 /// Opcode for the StateNotify event
 pub const STATE_NOTIFY_EVENT: u8 = 2;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct StateNotifyEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -11754,6 +14224,36 @@ pub struct StateNotifyEvent {
     pub request_major: u8,
     pub request_minor: u8,
 }
+impl std::fmt::Debug for StateNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("StateNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .field("base_mods", &xproto::ModMask::from(self.base_mods))
+            .field("latched_mods", &xproto::ModMask::from(self.latched_mods))
+            .field("locked_mods", &xproto::ModMask::from(self.locked_mods))
+            .field("group", &self.group)
+            .field("base_group", &self.base_group)
+            .field("latched_group", &self.latched_group)
+            .field("locked_group", &self.locked_group)
+            .field("compat_state", &xproto::ModMask::from(self.compat_state))
+            .field("grab_mods", &xproto::ModMask::from(self.grab_mods))
+            .field("compat_grab_mods", &xproto::ModMask::from(self.compat_grab_mods))
+            .field("lookup_mods", &xproto::ModMask::from(self.lookup_mods))
+            .field("compat_loockup_mods", &xproto::ModMask::from(self.compat_loockup_mods))
+            .field("ptr_btn_state", &xproto::KeyButMask::from(self.ptr_btn_state))
+            .field("changed", &StatePart::from(self.changed))
+            .field("keycode", &self.keycode)
+            .field("event_type", &self.event_type)
+            .field("request_major", &self.request_major)
+            .field("request_minor", &self.request_minor)
+            .finish()
+    }
+}
 impl TryParse for StateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11782,7 +14282,11 @@ impl TryParse for StateNotifyEvent {
         let (request_major, remaining) = u8::try_parse(remaining)?;
         let (request_minor, remaining) = u8::try_parse(remaining)?;
         let group = group.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(group)?;
         let locked_group = locked_group.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(locked_group)?;
         let result = StateNotifyEvent { response_type, xkb_type, sequence, time, device_id, mods, base_mods, latched_mods, locked_mods, group, base_group, latched_group, locked_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_loockup_mods, ptr_btn_state, changed, keycode, event_type, request_major, request_minor };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -11860,7 +14364,7 @@ impl From<StateNotifyEvent> for [u8; 32] {
 
 /// Opcode for the ControlsNotify event
 pub const CONTROLS_NOTIFY_EVENT: u8 = 3;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ControlsNotifyEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -11876,6 +14380,25 @@ pub struct ControlsNotifyEvent {
     pub request_major: u8,
     pub request_minor: u8,
 }
+impl std::fmt::Debug for ControlsNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ControlsNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("num_groups", &self.num_groups)
+            .field("changed_controls", &Control::from(self.changed_controls))
+            .field("enabled_controls", &self.enabled_controls)
+            .field("enabled_control_changes", &self.enabled_control_changes)
+            .field("keycode", &self.keycode)
+            .field("event_type", &self.event_type)
+            .field("request_major", &self.request_major)
+            .field("request_minor", &self.request_minor)
+            .finish()
+    }
+}
 impl TryParse for ControlsNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12124,7 +14647,7 @@ impl From<IndicatorMapNotifyEvent> for [u8; 32] {
 
 /// Opcode for the NamesNotify event
 pub const NAMES_NOTIFY_EVENT: u8 = 6;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct NamesNotifyEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -12144,6 +14667,29 @@ pub struct NamesNotifyEvent {
     pub n_keys: u8,
     pub changed_indicators: u32,
 }
+impl std::fmt::Debug for NamesNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("NamesNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("changed", &NameDetail::from(self.changed))
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("first_level_name", &self.first_level_name)
+            .field("n_level_names", &self.n_level_names)
+            .field("n_radio_groups", &self.n_radio_groups)
+            .field("n_key_aliases", &self.n_key_aliases)
+            .field("changed_group_names", &SetOfGroup::from(self.changed_group_names))
+            .field("changed_virtual_mods", &VMod::from(self.changed_virtual_mods))
+            .field("first_key", &self.first_key)
+            .field("n_keys", &self.n_keys)
+            .field("changed_indicators", &self.changed_indicators)
+            .finish()
+    }
+}
 impl TryParse for NamesNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12237,7 +14783,7 @@ impl From<NamesNotifyEvent> for [u8; 32] {
 
 /// Opcode for the CompatMapNotify event
 pub const COMPAT_MAP_NOTIFY_EVENT: u8 = 7;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CompatMapNotifyEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -12249,6 +14795,21 @@ pub struct CompatMapNotifyEvent {
     pub n_si: u16,
     pub n_total_si: u16,
 }
+impl std::fmt::Debug for CompatMapNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("CompatMapNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("changed_groups", &SetOfGroup::from(self.changed_groups))
+            .field("first_si", &self.first_si)
+            .field("n_si", &self.n_si)
+            .field("n_total_si", &self.n_total_si)
+            .finish()
+    }
+}
 impl TryParse for CompatMapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12358,6 +14919,8 @@ impl TryParse for BellNotifyEvent {
         let (event_only, remaining) = bool::try_parse(remaining)?;
         let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
         let bell_class = bell_class.into();
+        #[cfg(feature = "strict_parsing")]
+        BellClassResult::check_known(bell_class)?;
         let result = BellNotifyEvent { response_type, xkb_type, sequence, time, device_id, bell_class, bell_id, percent, pitch, duration, name, window, event_only };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -12424,7 +14987,7 @@ impl From<BellNotifyEvent> for [u8; 32] {
 
 /// Opcode for the ActionMessage event
 pub const ACTION_MESSAGE_EVENT: u8 = 9;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ActionMessageEvent {
     pub response_type: u8,
     pub xkb_type: u8,
@@ -12438,6 +15001,23 @@ pub struct ActionMessageEvent {
     pub group: Group,
     pub message: [String8; 8],
 }
+impl std::fmt::Debug for ActionMessageEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ActionMessageEvent")
+            .field("response_type", &self.response_type)
+            .field("xkb_type", &self.xkb_type)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("device_id", &self.device_id)
+            .field("keycode", &self.keycode)
+            .field("press", &self.press)
+            .field("key_event_follows", &self.key_event_follows)
+            .field("mods", &xproto::ModMask::from(self.mods))
+            .field("group", &self.group)
+            .field("message", &self.message)
+            .finish()
+    }
+}
 impl TryParse for ActionMessageEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12455,6 +15035,8 @@ impl TryParse for ActionMessageEvent {
         let message = <[u8; 8]>::try_from(message).unwrap();
         let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
         let group = group.into();
+        #[cfg(feature = "strict_parsing")]
+        Group::check_known(group)?;
         let result = ActionMessageEvent { response_type, xkb_type, sequence, time, device_id, keycode, press, key_event_follows, mods, group, message };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -12642,6 +15224,8 @@ impl TryParse for ExtensionDeviceNotifyEvent {
         let (unsupported, remaining) = u16::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let led_class = led_class.into();
+        #[cfg(feature = "strict_parsing")]
+        LedClassResult::check_known(led_class)?;
         let result = ExtensionDeviceNotifyEvent { response_type, xkb_type, sequence, time, device_id, reason, led_class, led_id, leds_defined, led_state, first_button, n_buttons, supported, unsupported };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -12709,10 +15293,14 @@ impl From<ExtensionDeviceNotifyEvent> for [u8; 32] {
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::UseExtension"))]
+    #[inline]
     fn xkb_use_extension(&self, wanted_major: u16, wanted_minor: u16) -> Result<Cookie<'_, Self, UseExtensionReply>, ConnectionError>
     {
         use_extension(self, wanted_major, wanted_minor)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SelectEvents"))]
+    #[inline]
     fn xkb_select_events<'c, 'input, A, B, C, D>(&'c self, device_spec: DeviceSpec, clear: A, select_all: B, affect_map: C, map: D, details: &'input SelectEventsAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -12722,14 +15310,20 @@ pub trait ConnectionExt: RequestConnection {
     {
         select_events(self, device_spec, clear, select_all, affect_map, map, details)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::Bell"))]
+    #[inline]
     fn xkb_bell(&self, device_spec: DeviceSpec, bell_class: BellClassSpec, bell_id: IDSpec, percent: i8, force_sound: bool, event_only: bool, pitch: i16, duration: i16, name: xproto::Atom, window: xproto::Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         bell(self, device_spec, bell_class, bell_id, percent, force_sound, event_only, pitch, duration, name, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetState"))]
+    #[inline]
     fn xkb_get_state(&self, device_spec: DeviceSpec) -> Result<Cookie<'_, Self, GetStateReply>, ConnectionError>
     {
         get_state(self, device_spec)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::LatchLockState"))]
+    #[inline]
     fn xkb_latch_lock_state<A, B, C>(&self, device_spec: DeviceSpec, affect_mod_locks: A, mod_locks: B, lock_group: bool, group_lock: Group, affect_mod_latches: C, latch_group: bool, group_latch: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u8>,
@@ -12738,10 +15332,14 @@ pub trait ConnectionExt: RequestConnection {
     {
         latch_lock_state(self, device_spec, affect_mod_locks, mod_locks, lock_group, group_lock, affect_mod_latches, latch_group, group_latch)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetControls"))]
+    #[inline]
     fn xkb_get_controls(&self, device_spec: DeviceSpec) -> Result<Cookie<'_, Self, GetControlsReply>, ConnectionError>
     {
         get_controls(self, device_spec)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetControls"))]
+    #[inline]
     fn xkb_set_controls<'c, 'input, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P>(&'c self, device_spec: DeviceSpec, affect_internal_real_mods: A, internal_real_mods: B, affect_ignore_lock_real_mods: C, ignore_lock_real_mods: D, affect_internal_virtual_mods: E, internal_virtual_mods: F, affect_ignore_lock_virtual_mods: G, ignore_lock_virtual_mods: H, mouse_keys_dflt_btn: u8, groups_wrap: u8, access_x_options: I, affect_enabled_controls: J, enabled_controls: K, change_controls: L, repeat_delay: u16, repeat_interval: u16, slow_keys_delay: u16, debounce_delay: u16, mouse_keys_delay: u16, mouse_keys_interval: u16, mouse_keys_time_to_max: u16, mouse_keys_max_speed: u16, mouse_keys_curve: i16, access_x_timeout: u16, access_x_timeout_mask: M, access_x_timeout_values: N, access_x_timeout_options_mask: O, access_x_timeout_options_values: P, per_key_repeat: &'input [u8; 32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u8>,
@@ -12763,6 +15361,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_controls(self, device_spec, affect_internal_real_mods, internal_real_mods, affect_ignore_lock_real_mods, ignore_lock_real_mods, affect_internal_virtual_mods, internal_virtual_mods, affect_ignore_lock_virtual_mods, ignore_lock_virtual_mods, mouse_keys_dflt_btn, groups_wrap, access_x_options, affect_enabled_controls, enabled_controls, change_controls, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_timeout, access_x_timeout_mask, access_x_timeout_values, access_x_timeout_options_mask, access_x_timeout_options_values, per_key_repeat)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetMap"))]
+    #[inline]
     fn xkb_get_map<A, B, C>(&self, device_spec: DeviceSpec, full: A, partial: B, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, first_key_action: xproto::Keycode, n_key_actions: u8, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, virtual_mods: C, first_key_explicit: xproto::Keycode, n_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8) -> Result<Cookie<'_, Self, GetMapReply>, ConnectionError>
     where
         A: Into<u16>,
@@ -12771,6 +15371,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_map(self, device_spec, full, partial, first_type, n_types, first_key_sym, n_key_syms, first_key_action, n_key_actions, first_key_behavior, n_key_behaviors, virtual_mods, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetMap"))]
+    #[inline]
     fn xkb_set_map<'c, 'input, A, B>(&'c self, device_spec: DeviceSpec, flags: A, min_key_code: xproto::Keycode, max_key_code: xproto::Keycode, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, total_syms: u16, first_key_action: xproto::Keycode, n_key_actions: u8, total_actions: u16, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, total_key_behaviors: u8, first_key_explicit: xproto::Keycode, n_key_explicit: u8, total_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, total_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8, total_v_mod_map_keys: u8, virtual_mods: B, values: &'input SetMapAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -12778,36 +15380,50 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_map(self, device_spec, flags, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, total_syms, first_key_action, n_key_actions, total_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetCompatMap"))]
+    #[inline]
     fn xkb_get_compat_map<A>(&self, device_spec: DeviceSpec, groups: A, get_all_si: bool, first_si: u16, n_si: u16) -> Result<Cookie<'_, Self, GetCompatMapReply>, ConnectionError>
     where
         A: Into<u8>,
     {
         get_compat_map(self, device_spec, groups, get_all_si, first_si, n_si)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetCompatMap"))]
+    #[inline]
     fn xkb_set_compat_map<'c, 'input, A>(&'c self, device_spec: DeviceSpec, recompute_actions: bool, truncate_si: bool, groups: A, first_si: u16, si: &'input [SymInterpret], group_maps: &'input [ModDef]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u8>,
     {
         set_compat_map(self, device_spec, recompute_actions, truncate_si, groups, first_si, si, group_maps)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorState"))]
+    #[inline]
     fn xkb_get_indicator_state(&self, device_spec: DeviceSpec) -> Result<Cookie<'_, Self, GetIndicatorStateReply>, ConnectionError>
     {
         get_indicator_state(self, device_spec)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetIndicatorMap"))]
+    #[inline]
     fn xkb_get_indicator_map(&self, device_spec: DeviceSpec, which: u32) -> Result<Cookie<'_, Self, GetIndicatorMapReply>, ConnectionError>
     {
         get_indicator_map(self, device_spec, which)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetIndicatorMap"))]
+    #[inline]
     fn xkb_set_indicator_map<'c, 'input>(&'c self, device_spec: DeviceSpec, which: u32, maps: &'input [IndicatorMap]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_indicator_map(self, device_spec, which, maps)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNamedIndicator"))]
+    #[inline]
     fn xkb_get_named_indicator<A>(&self, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom) -> Result<Cookie<'_, Self, GetNamedIndicatorReply>, ConnectionError>
     where
         A: Into<IDSpec>,
     {
         get_named_indicator(self, device_spec, led_class, led_id, indicator)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNamedIndicator"))]
+    #[inline]
     fn xkb_set_named_indicator<A, B, C, D, E, F, G, H>(&self, device_spec: DeviceSpec, led_class: LedClass, led_id: A, indicator: xproto::Atom, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: B, map_which_groups: C, map_groups: D, map_which_mods: E, map_real_mods: F, map_vmods: G, map_ctrls: H) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<IDSpec>,
@@ -12821,12 +15437,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_named_indicator(self, device_spec, led_class, led_id, indicator, set_state, on, set_map, create_map, map_flags, map_which_groups, map_groups, map_which_mods, map_real_mods, map_vmods, map_ctrls)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetNames"))]
+    #[inline]
     fn xkb_get_names<A>(&self, device_spec: DeviceSpec, which: A) -> Result<Cookie<'_, Self, GetNamesReply>, ConnectionError>
     where
         A: Into<u32>,
     {
         get_names(self, device_spec, which)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetNames"))]
+    #[inline]
     fn xkb_set_names<'c, 'input, A, B>(&'c self, device_spec: DeviceSpec, virtual_mods: A, first_type: u8, n_types: u8, first_kt_levelt: u8, n_kt_levels: u8, indicators: u32, group_names: B, n_radio_groups: u8, first_key: xproto::Keycode, n_keys: u8, n_key_aliases: u8, total_kt_level_names: u16, values: &'input SetNamesAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -12834,6 +15454,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_names(self, device_spec, virtual_mods, first_type, n_types, first_kt_levelt, n_kt_levels, indicators, group_names, n_radio_groups, first_key, n_keys, n_key_aliases, total_kt_level_names, values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::PerClientFlags"))]
+    #[inline]
     fn xkb_per_client_flags<A, B, C, D, E>(&self, device_spec: DeviceSpec, change: A, value: B, ctrls_to_change: C, auto_ctrls: D, auto_ctrls_values: E) -> Result<Cookie<'_, Self, PerClientFlagsReply>, ConnectionError>
     where
         A: Into<u32>,
@@ -12844,10 +15466,14 @@ pub trait ConnectionExt: RequestConnection {
     {
         per_client_flags(self, device_spec, change, value, ctrls_to_change, auto_ctrls, auto_ctrls_values)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::ListComponents"))]
+    #[inline]
     fn xkb_list_components(&self, device_spec: DeviceSpec, max_names: u16) -> Result<Cookie<'_, Self, ListComponentsReply>, ConnectionError>
     {
         list_components(self, device_spec, max_names)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetKbdByName"))]
+    #[inline]
     fn xkb_get_kbd_by_name<A, B>(&self, device_spec: DeviceSpec, need: A, want: B, load: bool) -> Result<Cookie<'_, Self, GetKbdByNameReply>, ConnectionError>
     where
         A: Into<u16>,
@@ -12855,6 +15481,8 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_kbd_by_name(self, device_spec, need, want, load)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::GetDeviceInfo"))]
+    #[inline]
     fn xkb_get_device_info<A, B>(&self, device_spec: DeviceSpec, wanted: A, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: LedClass, led_id: B) -> Result<Cookie<'_, Self, GetDeviceInfoReply>, ConnectionError>
     where
         A: Into<u16>,
@@ -12862,12 +15490,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_device_info(self, device_spec, wanted, all_buttons, first_button, n_buttons, led_class, led_id)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDeviceInfo"))]
+    #[inline]
     fn xkb_set_device_info<'c, 'input, A>(&'c self, device_spec: DeviceSpec, first_btn: u8, change: A, btn_actions: &'input [Action], leds: &'input [DeviceLedInfo]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u16>,
     {
         set_device_info(self, device_spec, first_btn, change, btn_actions, leds)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xkb::SetDebuggingFlags"))]
+    #[inline]
     fn xkb_set_debugging_flags<'c, 'input>(&'c self, affect_flags: u32, flags: u32, affect_ctrls: u32, ctrls: u32, message: &'input [String8]) -> Result<Cookie<'c, Self, SetDebuggingFlagsReply>, ConnectionError>
     {
         set_debugging_flags(self, affect_flags, flags, affect_ctrls, ctrls, message)
@@ -9,6 +9,8 @@
 //! specific errors, events, or requests.
 
 #![allow(clippy::too_many_arguments)]
+// Pruned requests leave behind imports and helpers that are only used by them
+#![cfg_attr(feature = "request-pruning", allow(unused_imports, dead_code))]
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
@@ -17,13 +19,13 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+use crate::utils::{OwnedFd, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd, TryIntoUSize};
 use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
-use crate::cookie::ListFontsWithInfoCookie;
+use crate::cookie::CookieStream;
 use crate::errors::{ConnectionError, ParseError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -327,6 +329,17 @@ impl std::fmt::Debug for VisualClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl VisualClass {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Visualtype {
@@ -349,6 +362,8 @@ impl TryParse for Visualtype {
         let (blue_mask, remaining) = u32::try_parse(remaining)?;
         let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
         let class = class.into();
+        #[cfg(feature = "strict_parsing")]
+        VisualClass::check_known(class)?;
         let result = Visualtype { visual_id, class, bits_per_rgb_value, colormap_entries, red_mask, green_mask, blue_mask };
         Ok((result, remaining))
     }
@@ -594,8 +609,19 @@ impl std::fmt::Debug for BackingStore  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl BackingStore {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Screen {
     pub root: Window,
     pub default_colormap: Colormap,
@@ -614,6 +640,28 @@ pub struct Screen {
     pub root_depth: u8,
     pub allowed_depths: Vec<Depth>,
 }
+impl std::fmt::Debug for Screen {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Screen")
+            .field("root", &self.root)
+            .field("default_colormap", &self.default_colormap)
+            .field("white_pixel", &self.white_pixel)
+            .field("black_pixel", &self.black_pixel)
+            .field("current_input_masks", &EventMask::from(self.current_input_masks))
+            .field("width_in_pixels", &self.width_in_pixels)
+            .field("height_in_pixels", &self.height_in_pixels)
+            .field("width_in_millimeters", &self.width_in_millimeters)
+            .field("height_in_millimeters", &self.height_in_millimeters)
+            .field("min_installed_maps", &self.min_installed_maps)
+            .field("max_installed_maps", &self.max_installed_maps)
+            .field("root_visual", &self.root_visual)
+            .field("backing_stores", &self.backing_stores)
+            .field("save_unders", &self.save_unders)
+            .field("root_depth", &self.root_depth)
+            .field("allowed_depths", &self.allowed_depths)
+            .finish()
+    }
+}
 impl TryParse for Screen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (root, remaining) = Window::try_parse(remaining)?;
@@ -634,6 +682,8 @@ impl TryParse for Screen {
         let (allowed_depths_len, remaining) = u8::try_parse(remaining)?;
         let (allowed_depths, remaining) = crate::x11_utils::parse_list::<Depth>(remaining, allowed_depths_len.try_to_usize()?)?;
         let backing_stores = backing_stores.into();
+        #[cfg(feature = "strict_parsing")]
+        BackingStore::check_known(backing_stores)?;
         let result = Screen { root, default_colormap, white_pixel, black_pixel, current_input_masks, width_in_pixels, height_in_pixels, width_in_millimeters, height_in_millimeters, min_installed_maps, max_installed_maps, root_visual, backing_stores, save_unders, root_depth, allowed_depths };
         Ok((result, remaining))
     }
@@ -703,16 +753,10 @@ impl TryParse for SetupRequest {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let (authorization_protocol_name, remaining) = crate::x11_utils::parse_u8_list(remaining, authorization_protocol_name_len.try_to_usize()?)?;
         let authorization_protocol_name = authorization_protocol_name.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (authorization_protocol_data, remaining) = crate::x11_utils::parse_u8_list(remaining, authorization_protocol_data_len.try_to_usize()?)?;
         let authorization_protocol_data = authorization_protocol_data.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let result = SetupRequest { byte_order, protocol_major_version, protocol_minor_version, authorization_protocol_name, authorization_protocol_data };
         Ok((result, remaining))
     }
@@ -932,6 +976,17 @@ impl std::fmt::Debug for ImageOrder  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ImageOrder {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Setup {
@@ -979,14 +1034,15 @@ impl TryParse for Setup {
         let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
         let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_len.try_to_usize()?)?;
         let vendor = vendor.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let (pixmap_formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, pixmap_formats_len.try_to_usize()?)?;
         let (roots, remaining) = crate::x11_utils::parse_list::<Screen>(remaining, roots_len.try_to_usize()?)?;
         let image_byte_order = image_byte_order.into();
+        #[cfg(feature = "strict_parsing")]
+        ImageOrder::check_known(image_byte_order)?;
         let bitmap_format_bit_order = bitmap_format_bit_order.into();
+        #[cfg(feature = "strict_parsing")]
+        ImageOrder::check_known(bitmap_format_bit_order)?;
         let result = Setup { status, protocol_major_version, protocol_minor_version, length, release_number, resource_id_base, resource_id_mask, motion_buffer_size, maximum_request_length, image_byte_order, bitmap_format_bit_order, bitmap_format_scanline_unit, bitmap_format_scanline_pad, min_keycode, max_keycode, vendor, pixmap_formats, roots };
         Ok((result, remaining))
     }
@@ -1295,7 +1351,7 @@ pub const KEY_PRESS_EVENT: u8 = 2;
 ///
 /// * `GrabKey`: request
 /// * `GrabKeyboard`: request
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct KeyPressEvent {
     pub response_type: u8,
     pub detail: Keycode,
@@ -1311,6 +1367,25 @@ pub struct KeyPressEvent {
     pub state: u16,
     pub same_screen: bool,
 }
+impl std::fmt::Debug for KeyPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("KeyPressEvent")
+            .field("response_type", &self.response_type)
+            .field("detail", &self.detail)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("state", &KeyButMask::from(self.state))
+            .field("same_screen", &self.same_screen)
+            .finish()
+    }
+}
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1483,7 +1558,7 @@ pub const BUTTON_PRESS_EVENT: u8 = 4;
 ///
 /// * `GrabButton`: request
 /// * `GrabPointer`: request
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ButtonPressEvent {
     pub response_type: u8,
     pub detail: Button,
@@ -1499,6 +1574,25 @@ pub struct ButtonPressEvent {
     pub state: u16,
     pub same_screen: bool,
 }
+impl std::fmt::Debug for ButtonPressEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ButtonPressEvent")
+            .field("response_type", &self.response_type)
+            .field("detail", &self.detail)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("state", &KeyButMask::from(self.state))
+            .field("same_screen", &self.same_screen)
+            .finish()
+    }
+}
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1641,6 +1735,17 @@ impl std::fmt::Debug for Motion  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Motion {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the MotionNotify event
 pub const MOTION_NOTIFY_EVENT: u8 = 6;
@@ -1668,7 +1773,7 @@ pub const MOTION_NOTIFY_EVENT: u8 = 6;
 ///
 /// * `GrabKey`: request
 /// * `GrabKeyboard`: request
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct MotionNotifyEvent {
     pub response_type: u8,
     pub detail: Motion,
@@ -1684,6 +1789,25 @@ pub struct MotionNotifyEvent {
     pub state: u16,
     pub same_screen: bool,
 }
+impl std::fmt::Debug for MotionNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("MotionNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("detail", &self.detail)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("state", &KeyButMask::from(self.state))
+            .field("same_screen", &self.same_screen)
+            .finish()
+    }
+}
 impl TryParse for MotionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1702,6 +1826,8 @@ impl TryParse for MotionNotifyEvent {
         let (same_screen, remaining) = bool::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let detail = detail.into();
+        #[cfg(feature = "strict_parsing")]
+        Motion::check_known(detail)?;
         let result = MotionNotifyEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -1835,6 +1961,17 @@ impl std::fmt::Debug for NotifyDetail  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl NotifyDetail {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=7) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct NotifyMode(u8);
@@ -1897,6 +2034,17 @@ impl std::fmt::Debug for NotifyMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl NotifyMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the EnterNotify event
 pub const ENTER_NOTIFY_EVENT: u8 = 7;
@@ -1915,7 +2063,7 @@ pub const ENTER_NOTIFY_EVENT: u8 = 7;
 /// * `event_y` - If `event` is on the same screen as `root`, this is the pointer Y coordinate
 /// relative to the event window's origin.
 /// * `mode` -
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct EnterNotifyEvent {
     pub response_type: u8,
     pub detail: NotifyDetail,
@@ -1932,6 +2080,26 @@ pub struct EnterNotifyEvent {
     pub mode: NotifyMode,
     pub same_screen_focus: u8,
 }
+impl std::fmt::Debug for EnterNotifyEvent {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("EnterNotifyEvent")
+            .field("response_type", &self.response_type)
+            .field("detail", &self.detail)
+            .field("sequence", &self.sequence)
+            .field("time", &self.time)
+            .field("root", &self.root)
+            .field("event", &self.event)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("event_x", &self.event_x)
+            .field("event_y", &self.event_y)
+            .field("state", &KeyButMask::from(self.state))
+            .field("mode", &self.mode)
+            .field("same_screen_focus", &self.same_screen_focus)
+            .finish()
+    }
+}
 impl TryParse for EnterNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1950,7 +2118,11 @@ impl TryParse for EnterNotifyEvent {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let (same_screen_focus, remaining) = u8::try_parse(remaining)?;
         let detail = detail.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyDetail::check_known(detail)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyMode::check_known(mode)?;
         let result = EnterNotifyEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, mode, same_screen_focus };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2048,7 +2220,11 @@ impl TryParse for FocusInEvent {
         let (mode, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let detail = detail.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyDetail::check_known(detail)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        NotifyMode::check_known(mode)?;
         let result = FocusInEvent { response_type, detail, sequence, event, mode };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2504,6 +2680,17 @@ impl std::fmt::Debug for Visibility  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Visibility {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the VisibilityNotify event
 pub const VISIBILITY_NOTIFY_EVENT: u8 = 15;
@@ -2524,6 +2711,8 @@ impl TryParse for VisibilityNotifyEvent {
         let (state, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        Visibility::check_known(state)?;
         let result = VisibilityNotifyEvent { response_type, sequence, window, state };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3254,6 +3443,8 @@ impl TryParse for ConfigureRequestEvent {
         let (border_width, remaining) = u16::try_parse(remaining)?;
         let (value_mask, remaining) = u16::try_parse(remaining)?;
         let stack_mode = stack_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        StackMode::check_known(stack_mode)?;
         let result = ConfigureRequestEvent { response_type, stack_mode, sequence, parent, window, sibling, x, y, width, height, border_width, value_mask };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3534,6 +3725,17 @@ impl std::fmt::Debug for Place  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Place {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the CirculateNotify event
 pub const CIRCULATE_NOTIFY_EVENT: u8 = 26;
@@ -3569,6 +3771,8 @@ impl TryParse for CirculateNotifyEvent {
         let (place, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let place = place.into();
+        #[cfg(feature = "strict_parsing")]
+        Place::check_known(place)?;
         let result = CirculateNotifyEvent { response_type, sequence, event, window, place };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3687,6 +3891,17 @@ impl std::fmt::Debug for Property  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Property {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the PropertyNotify event
 pub const PROPERTY_NOTIFY_EVENT: u8 = 28;
@@ -3723,6 +3938,8 @@ impl TryParse for PropertyNotifyEvent {
         let (state, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        Property::check_known(state)?;
         let result = PropertyNotifyEvent { response_type, sequence, window, atom, time, state };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4335,6 +4552,17 @@ impl std::fmt::Debug for ColormapState  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ColormapState {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ColormapEnum(u8);
@@ -4428,6 +4656,8 @@ impl TryParse for ColormapNotifyEvent {
         let (state, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let state = state.into();
+        #[cfg(feature = "strict_parsing")]
+        ColormapState::check_known(state)?;
         let result = ColormapNotifyEvent { response_type, sequence, window, colormap, new, state };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4794,6 +5024,17 @@ impl std::fmt::Debug for Mapping  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Mapping {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the MappingNotify event
 pub const MAPPING_NOTIFY_EVENT: u8 = 34;
@@ -4823,6 +5064,8 @@ impl TryParse for MappingNotifyEvent {
         let (count, remaining) = u8::try_parse(remaining)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let request = request.into();
+        #[cfg(feature = "strict_parsing")]
+        Mapping::check_known(request)?;
         let result = MappingNotifyEvent { response_type, sequence, request, first_keycode, count };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4908,7 +5151,8 @@ impl TryParse for GeGenericEvent {
         let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
         let result = GeGenericEvent { response_type, extension, sequence, length, event_type };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -5018,6 +5262,17 @@ impl std::fmt::Debug for WindowClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl WindowClass {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// # Fields
 ///
@@ -5303,7 +5558,21 @@ impl std::fmt::Debug for Gravity  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Gravity {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=10) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
+/// Opcode for the CreateWindow request
+pub const CREATE_WINDOW_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 /// Auxiliary and optional information for the `create_window` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CreateWindowAux {
@@ -5323,6 +5592,7 @@ pub struct CreateWindowAux {
     pub colormap: Option<Colormap>,
     pub cursor: Option<Cursor>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl CreateWindowAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -5363,6 +5633,8 @@ impl CreateWindowAux {
             let remaining = outer_remaining;
             let (bit_gravity, remaining) = u32::try_parse(remaining)?;
             let bit_gravity = bit_gravity.into();
+            #[cfg(feature = "strict_parsing")]
+            Gravity::check_known(bit_gravity)?;
             outer_remaining = remaining;
             Some(bit_gravity)
         } else {
@@ -5372,6 +5644,8 @@ impl CreateWindowAux {
             let remaining = outer_remaining;
             let (win_gravity, remaining) = u32::try_parse(remaining)?;
             let win_gravity = win_gravity.into();
+            #[cfg(feature = "strict_parsing")]
+            Gravity::check_known(win_gravity)?;
             outer_remaining = remaining;
             Some(win_gravity)
         } else {
@@ -5381,6 +5655,8 @@ impl CreateWindowAux {
             let remaining = outer_remaining;
             let (backing_store, remaining) = u32::try_parse(remaining)?;
             let backing_store = backing_store.into();
+            #[cfg(feature = "strict_parsing")]
+            BackingStore::check_known(backing_store)?;
             outer_remaining = remaining;
             Some(backing_store)
         } else {
@@ -5454,6 +5730,7 @@ impl CreateWindowAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl CreateWindowAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -5510,6 +5787,7 @@ impl CreateWindowAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl CreateWindowAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -5561,6 +5839,7 @@ impl CreateWindowAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl CreateWindowAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -5643,8 +5922,7 @@ impl CreateWindowAux {
     }
 }
 
-/// Opcode for the CreateWindow request
-pub const CREATE_WINDOW_REQUEST: u8 = 1;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 /// Creates a window.
 ///
 /// Creates an unmapped window as child of the specified `parent` window. A
@@ -5713,6 +5991,7 @@ pub struct CreateWindowRequest<'input> {
     pub visual: Visualid,
     pub value_list: Cow<'input, CreateWindowAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl<'input> CreateWindowRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -5777,12 +6056,97 @@ impl<'input> CreateWindowRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let depth_bytes = self.depth.serialize();
+        let wid_bytes = self.wid.serialize();
+        let parent_bytes = self.parent.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let border_width_bytes = self.border_width.serialize();
+        let class_bytes = u16::from(self.class).serialize();
+        let visual_bytes = self.visual.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CREATE_WINDOW_REQUEST,
+            depth_bytes[0],
+            0,
+            0,
+            wid_bytes[0],
+            wid_bytes[1],
+            wid_bytes[2],
+            wid_bytes[3],
+            parent_bytes[0],
+            parent_bytes[1],
+            parent_bytes[2],
+            parent_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            border_width_bytes[0],
+            border_width_bytes[1],
+            class_bytes[0],
+            class_bytes[1],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 32 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -5802,6 +6166,8 @@ impl<'input> CreateWindowRequest<'input> {
         let (border_width, remaining) = u16::try_parse(remaining)?;
         let (class, remaining) = u16::try_parse(remaining)?;
         let class = class.into();
+        #[cfg(feature = "strict_parsing")]
+        WindowClass::check_known(class)?;
         let (visual, remaining) = Visualid::try_parse(remaining)?;
         let (value_mask, remaining) = u32::try_parse(remaining)?;
         let (value_list, remaining) = CreateWindowAux::try_parse(remaining, value_mask)?;
@@ -5837,9 +6203,11 @@ impl<'input> CreateWindowRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 impl<'input> Request for CreateWindowRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
 /// Creates a window.
 ///
 /// Creates an unmapped window as child of the specified `parent` window. A
@@ -5894,6 +6262,7 @@ impl<'input> Request for CreateWindowRequest<'input> {
 /// * `xcb_generate_id`: function
 /// * `MapWindow`: request
 /// * `CreateNotify`: event
+#[inline]
 pub fn create_window<'c, 'input, Conn>(conn: &'c Conn, depth: u8, wid: Window, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: WindowClass, visual: Visualid, value_list: &'input CreateWindowAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -5914,6 +6283,9 @@ where
     request0.send(conn)
 }
 
+/// Opcode for the ChangeWindowAttributes request
+pub const CHANGE_WINDOW_ATTRIBUTES_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 /// Auxiliary and optional information for the `change_window_attributes` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ChangeWindowAttributesAux {
@@ -5933,6 +6305,7 @@ pub struct ChangeWindowAttributesAux {
     pub colormap: Option<Colormap>,
     pub cursor: Option<Cursor>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl ChangeWindowAttributesAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -5973,6 +6346,8 @@ impl ChangeWindowAttributesAux {
             let remaining = outer_remaining;
             let (bit_gravity, remaining) = u32::try_parse(remaining)?;
             let bit_gravity = bit_gravity.into();
+            #[cfg(feature = "strict_parsing")]
+            Gravity::check_known(bit_gravity)?;
             outer_remaining = remaining;
             Some(bit_gravity)
         } else {
@@ -5982,6 +6357,8 @@ impl ChangeWindowAttributesAux {
             let remaining = outer_remaining;
             let (win_gravity, remaining) = u32::try_parse(remaining)?;
             let win_gravity = win_gravity.into();
+            #[cfg(feature = "strict_parsing")]
+            Gravity::check_known(win_gravity)?;
             outer_remaining = remaining;
             Some(win_gravity)
         } else {
@@ -5991,6 +6368,8 @@ impl ChangeWindowAttributesAux {
             let remaining = outer_remaining;
             let (backing_store, remaining) = u32::try_parse(remaining)?;
             let backing_store = backing_store.into();
+            #[cfg(feature = "strict_parsing")]
+            BackingStore::check_known(backing_store)?;
             outer_remaining = remaining;
             Some(backing_store)
         } else {
@@ -6064,6 +6443,7 @@ impl ChangeWindowAttributesAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl ChangeWindowAttributesAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -6120,6 +6500,7 @@ impl ChangeWindowAttributesAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl ChangeWindowAttributesAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -6171,6 +6552,7 @@ impl ChangeWindowAttributesAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl ChangeWindowAttributesAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -6253,8 +6635,7 @@ impl ChangeWindowAttributesAux {
     }
 }
 
-/// Opcode for the ChangeWindowAttributes request
-pub const CHANGE_WINDOW_ATTRIBUTES_REQUEST: u8 = 2;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 /// change window attributes.
 ///
 /// Changes the attributes specified by `value_mask` for the specified `window`.
@@ -6281,6 +6662,7 @@ pub struct ChangeWindowAttributesRequest<'input> {
     pub window: Window,
     pub value_list: Cow<'input, ChangeWindowAttributesAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl<'input> ChangeWindowAttributesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -6316,12 +6698,68 @@ impl<'input> ChangeWindowAttributesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CHANGE_WINDOW_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6349,9 +6787,11 @@ impl<'input> ChangeWindowAttributesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 impl<'input> Request for ChangeWindowAttributesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
 /// change window attributes.
 ///
 /// Changes the attributes specified by `value_mask` for the specified `window`.
@@ -6373,6 +6813,7 @@ impl<'input> Request for ChangeWindowAttributesRequest<'input> {
 /// * `Pixmap` - TODO: reasons?
 /// * `Value` - TODO: reasons?
 /// * `Window` - The specified `window` does not exist.
+#[inline]
 pub fn change_window_attributes<'c, 'input, Conn>(conn: &'c Conn, window: Window, value_list: &'input ChangeWindowAttributesAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6443,9 +6884,21 @@ impl std::fmt::Debug for MapState  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl MapState {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the GetWindowAttributes request
 pub const GET_WINDOW_ATTRIBUTES_REQUEST: u8 = 3;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 /// Gets window attributes.
 ///
 /// Gets the current attributes for the specified `window`.
@@ -6462,6 +6915,7 @@ pub const GET_WINDOW_ATTRIBUTES_REQUEST: u8 = 3;
 pub struct GetWindowAttributesRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 impl GetWindowAttributesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -6487,12 +6941,54 @@ impl GetWindowAttributesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            GET_WINDOW_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetWindowAttributesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6510,9 +7006,11 @@ impl GetWindowAttributesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 impl Request for GetWindowAttributesRequest {
     type Reply = GetWindowAttributesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 /// Gets window attributes.
 ///
 /// Gets the current attributes for the specified `window`.
@@ -6525,6 +7023,7 @@ impl Request for GetWindowAttributesRequest {
 ///
 /// * `Window` - The specified `window` does not exist.
 /// * `Drawable` - TODO: reasons?
+#[inline]
 pub fn get_window_attributes<Conn>(conn: &Conn, window: Window) -> Result<Cookie<'_, Conn, GetWindowAttributesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6535,6 +7034,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 /// # Fields
 ///
 /// * `override_redirect` - Window managers should ignore this window if `override_redirect` is 1.
@@ -6551,7 +7051,7 @@ where
 /// * `bit_gravity` -
 /// * `win_gravity` -
 /// * `map_state` -
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GetWindowAttributesReply {
     pub backing_store: BackingStore,
     pub sequence: u16,
@@ -6571,6 +7071,31 @@ pub struct GetWindowAttributesReply {
     pub your_event_mask: u32,
     pub do_not_propagate_mask: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
+impl std::fmt::Debug for GetWindowAttributesReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("GetWindowAttributesReply")
+            .field("backing_store", &self.backing_store)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("visual", &self.visual)
+            .field("class", &self.class)
+            .field("bit_gravity", &self.bit_gravity)
+            .field("win_gravity", &self.win_gravity)
+            .field("backing_planes", &self.backing_planes)
+            .field("backing_pixel", &self.backing_pixel)
+            .field("save_under", &self.save_under)
+            .field("map_is_installed", &self.map_is_installed)
+            .field("map_state", &self.map_state)
+            .field("override_redirect", &self.override_redirect)
+            .field("colormap", &self.colormap)
+            .field("all_event_masks", &EventMask::from(self.all_event_masks))
+            .field("your_event_mask", &EventMask::from(self.your_event_mask))
+            .field("do_not_propagate_mask", &EventMask::from(self.do_not_propagate_mask))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
 impl TryParse for GetWindowAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6597,13 +7122,24 @@ impl TryParse for GetWindowAttributesReply {
             return Err(ParseError::InvalidValue);
         }
         let backing_store = backing_store.into();
+        #[cfg(feature = "strict_parsing")]
+        BackingStore::check_known(backing_store)?;
         let class = class.into();
+        #[cfg(feature = "strict_parsing")]
+        WindowClass::check_known(class)?;
         let bit_gravity = bit_gravity.into();
+        #[cfg(feature = "strict_parsing")]
+        Gravity::check_known(bit_gravity)?;
         let win_gravity = win_gravity.into();
+        #[cfg(feature = "strict_parsing")]
+        Gravity::check_known(win_gravity)?;
         let map_state = map_state.into();
+        #[cfg(feature = "strict_parsing")]
+        MapState::check_known(map_state)?;
         let result = GetWindowAttributesReply { backing_store, sequence, length, visual, class, bit_gravity, win_gravity, backing_planes, backing_pixel, save_under, map_is_installed, map_state, override_redirect, colormap, all_event_masks, your_event_mask, do_not_propagate_mask };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -6611,6 +7147,7 @@ impl TryParse for GetWindowAttributesReply {
 
 /// Opcode for the DestroyWindow request
 pub const DESTROY_WINDOW_REQUEST: u8 = 4;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
 /// Destroys a window.
 ///
 /// Destroys the specified window and all of its subwindows. A DestroyNotify event
@@ -6637,6 +7174,7 @@ pub const DESTROY_WINDOW_REQUEST: u8 = 4;
 pub struct DestroyWindowRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
 impl DestroyWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -6662,12 +7200,54 @@ impl DestroyWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            DESTROY_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6685,9 +7265,11 @@ impl DestroyWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
 impl Request for DestroyWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
 /// Destroys a window.
 ///
 /// Destroys the specified window and all of its subwindows. A DestroyNotify event
@@ -6710,6 +7292,7 @@ impl Request for DestroyWindowRequest {
 /// * `DestroyNotify`: event
 /// * `MapWindow`: request
 /// * `UnmapWindow`: request
+#[inline]
 pub fn destroy_window<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6722,10 +7305,12 @@ where
 
 /// Opcode for the DestroySubwindows request
 pub const DESTROY_SUBWINDOWS_REQUEST: u8 = 5;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DestroySubwindowsRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
 impl DestroySubwindowsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -6751,13 +7336,55 @@ impl DestroySubwindowsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            DESTROY_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
@@ -6774,9 +7401,12 @@ impl DestroySubwindowsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
 impl Request for DestroySubwindowsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
+#[inline]
 pub fn destroy_subwindows<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6844,9 +7474,21 @@ impl std::fmt::Debug for SetMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SetMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ChangeSaveSet request
 pub const CHANGE_SAVE_SET_REQUEST: u8 = 6;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
 /// Changes a client's save set.
 ///
 /// TODO: explain what the save set is for.
@@ -6874,6 +7516,7 @@ pub struct ChangeSaveSetRequest {
     pub mode: SetMode,
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
 impl ChangeSaveSetRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -6900,12 +7543,55 @@ impl ChangeSaveSetRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        let window_bytes = self.window.serialize();
+        write(&[
+            CHANGE_SAVE_SET_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -6916,6 +7602,8 @@ impl ChangeSaveSetRequest {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        SetMode::check_known(mode)?;
         let _ = remaining;
         let (window, remaining) = Window::try_parse(value)?;
         let _ = remaining;
@@ -6925,9 +7613,11 @@ impl ChangeSaveSetRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
 impl Request for ChangeSaveSetRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
 /// Changes a client's save set.
 ///
 /// TODO: explain what the save set is for.
@@ -6950,6 +7640,7 @@ impl Request for ChangeSaveSetRequest {
 /// # See
 ///
 /// * `ReparentWindow`: request
+#[inline]
 pub fn change_save_set<Conn>(conn: &Conn, mode: SetMode, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -6963,6 +7654,7 @@ where
 
 /// Opcode for the ReparentWindow request
 pub const REPARENT_WINDOW_REQUEST: u8 = 7;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
 /// Reparents a window.
 ///
 /// Makes the specified window a child of the specified parent window. If the
@@ -7002,6 +7694,7 @@ pub struct ReparentWindowRequest {
     pub x: i16,
     pub y: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
 impl ReparentWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7038,12 +7731,65 @@ impl ReparentWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let parent_bytes = self.parent.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            REPARENT_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            parent_bytes[0],
+            parent_bytes[1],
+            parent_bytes[2],
+            parent_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7067,9 +7813,11 @@ impl ReparentWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
 impl Request for ReparentWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
 /// Reparents a window.
 ///
 /// Makes the specified window a child of the specified parent window. If the
@@ -7102,6 +7850,7 @@ impl Request for ReparentWindowRequest {
 /// * `ReparentNotify`: event
 /// * `MapWindow`: request
 /// * `UnmapWindow`: request
+#[inline]
 pub fn reparent_window<Conn>(conn: &Conn, window: Window, parent: Window, x: i16, y: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7117,6 +7866,7 @@ where
 
 /// Opcode for the MapWindow request
 pub const MAP_WINDOW_REQUEST: u8 = 8;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
 /// Makes a window visible.
 ///
 /// Maps the specified window. This means making the window visible (as long as its
@@ -7156,6 +7906,7 @@ pub const MAP_WINDOW_REQUEST: u8 = 8;
 pub struct MapWindowRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
 impl MapWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7181,12 +7932,54 @@ impl MapWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            MAP_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7204,9 +7997,11 @@ impl MapWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
 impl Request for MapWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
 /// Makes a window visible.
 ///
 /// Maps the specified window. This means making the window visible (as long as its
@@ -7242,6 +8037,7 @@ impl Request for MapWindowRequest {
 /// * `MapNotify`: event
 /// * `Expose`: event
 /// * `UnmapWindow`: request
+#[inline]
 pub fn map_window<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7254,10 +8050,12 @@ where
 
 /// Opcode for the MapSubwindows request
 pub const MAP_SUBWINDOWS_REQUEST: u8 = 9;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MapSubwindowsRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
 impl MapSubwindowsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7283,12 +8081,54 @@ impl MapSubwindowsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            MAP_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7306,9 +8146,12 @@ impl MapSubwindowsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
 impl Request for MapSubwindowsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
+#[inline]
 pub fn map_subwindows<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7321,6 +8164,7 @@ where
 
 /// Opcode for the UnmapWindow request
 pub const UNMAP_WINDOW_REQUEST: u8 = 10;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
 /// Makes a window invisible.
 ///
 /// Unmaps the specified window. This means making the window invisible (and all
@@ -7346,6 +8190,7 @@ pub const UNMAP_WINDOW_REQUEST: u8 = 10;
 pub struct UnmapWindowRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
 impl UnmapWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7371,12 +8216,54 @@ impl UnmapWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            UNMAP_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7394,9 +8281,11 @@ impl UnmapWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
 impl Request for UnmapWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
 /// Makes a window invisible.
 ///
 /// Unmaps the specified window. This means making the window invisible (and all
@@ -7418,6 +8307,7 @@ impl Request for UnmapWindowRequest {
 /// * `UnmapNotify`: event
 /// * `Expose`: event
 /// * `MapWindow`: request
+#[inline]
 pub fn unmap_window<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7430,10 +8320,12 @@ where
 
 /// Opcode for the UnmapSubwindows request
 pub const UNMAP_SUBWINDOWS_REQUEST: u8 = 11;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnmapSubwindowsRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
 impl UnmapSubwindowsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7459,12 +8351,54 @@ impl UnmapSubwindowsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            UNMAP_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7482,9 +8416,12 @@ impl UnmapSubwindowsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
 impl Request for UnmapSubwindowsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
+#[inline]
 pub fn unmap_subwindows<Conn>(conn: &Conn, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -7615,7 +8552,21 @@ impl std::fmt::Debug for StackMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl StackMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
+/// Opcode for the ConfigureWindow request
+pub const CONFIGURE_WINDOW_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 /// Auxiliary and optional information for the `configure_window` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ConfigureWindowAux {
@@ -7627,6 +8578,7 @@ pub struct ConfigureWindowAux {
     pub sibling: Option<Window>,
     pub stack_mode: Option<StackMode>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl ConfigureWindowAux {
     fn try_parse(value: &[u8], value_mask: u16) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = u32::from(value_mask);
@@ -7683,6 +8635,8 @@ impl ConfigureWindowAux {
             let remaining = outer_remaining;
             let (stack_mode, remaining) = u32::try_parse(remaining)?;
             let stack_mode = stack_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            StackMode::check_known(stack_mode)?;
             outer_remaining = remaining;
             Some(stack_mode)
         } else {
@@ -7692,6 +8646,7 @@ impl ConfigureWindowAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl ConfigureWindowAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u16) -> Vec<u8> {
@@ -7724,6 +8679,7 @@ impl ConfigureWindowAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl ConfigureWindowAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -7751,6 +8707,7 @@ impl ConfigureWindowAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl ConfigureWindowAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -7792,6 +8749,7 @@ impl ConfigureWindowAux {
         self
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl ConfigureWindowAux {
     /// Construct from a [`ConfigureRequestEvent`].
     ///
@@ -7825,8 +8783,7 @@ impl ConfigureWindowAux {
     }
 }
 
-/// Opcode for the ConfigureWindow request
-pub const CONFIGURE_WINDOW_REQUEST: u8 = 12;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 /// Configures window attributes.
 ///
 /// Configures a window's size, position, border width and stacking order.
@@ -7882,6 +8839,7 @@ pub struct ConfigureWindowRequest<'input> {
     pub window: Window,
     pub value_list: Cow<'input, ConfigureWindowAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl<'input> ConfigureWindowRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -7917,12 +8875,68 @@ impl<'input> ConfigureWindowRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let value_mask = u16::try_from(self.value_list.switch_expr()).unwrap();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CONFIGURE_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = u16::try_from(self.value_list.switch_expr()).unwrap();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -7951,9 +8965,11 @@ impl<'input> ConfigureWindowRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 impl<'input> Request for ConfigureWindowRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
 /// Configures window attributes.
 ///
 /// Configures a window's size, position, border width and stacking order.
@@ -8004,6 +9020,7 @@ impl<'input> Request for ConfigureWindowRequest<'input> {
 ///     xcb_flush(c);
 /// }
 /// ```
+#[inline]
 pub fn configure_window<'c, 'input, Conn>(conn: &'c Conn, window: Window, value_list: &'input ConfigureWindowAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8072,9 +9089,21 @@ impl std::fmt::Debug for Circulate  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Circulate {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the CirculateWindow request
 pub const CIRCULATE_WINDOW_REQUEST: u8 = 13;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
 /// Change window stacking order.
 ///
 /// If `direction` is `XCB_CIRCULATE_RAISE_LOWEST`, the lowest mapped child (if
@@ -8097,6 +9126,7 @@ pub struct CirculateWindowRequest {
     pub direction: Circulate,
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
 impl CirculateWindowRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -8123,12 +9153,55 @@ impl CirculateWindowRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let direction_bytes = u8::from(self.direction).serialize();
+        let window_bytes = self.window.serialize();
+        write(&[
+            CIRCULATE_WINDOW_REQUEST,
+            direction_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8139,6 +9212,8 @@ impl CirculateWindowRequest {
         let remaining = &[header.minor_opcode];
         let (direction, remaining) = u8::try_parse(remaining)?;
         let direction = direction.into();
+        #[cfg(feature = "strict_parsing")]
+        Circulate::check_known(direction)?;
         let _ = remaining;
         let (window, remaining) = Window::try_parse(value)?;
         let _ = remaining;
@@ -8148,9 +9223,11 @@ impl CirculateWindowRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
 impl Request for CirculateWindowRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
 /// Change window stacking order.
 ///
 /// If `direction` is `XCB_CIRCULATE_RAISE_LOWEST`, the lowest mapped child (if
@@ -8168,6 +9245,7 @@ impl Request for CirculateWindowRequest {
 ///
 /// * `Window` - The specified `window` does not exist.
 /// * `Value` - The specified `direction` is invalid.
+#[inline]
 pub fn circulate_window<Conn>(conn: &Conn, direction: Circulate, window: Window) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8181,6 +9259,7 @@ where
 
 /// Opcode for the GetGeometry request
 pub const GET_GEOMETRY_REQUEST: u8 = 14;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 /// Get current window geometry.
 ///
 /// Gets the current geometry of the specified drawable (either `Window` or `Pixmap`).
@@ -8221,6 +9300,7 @@ pub const GET_GEOMETRY_REQUEST: u8 = 14;
 pub struct GetGeometryRequest {
     pub drawable: Drawable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 impl GetGeometryRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -8246,12 +9326,54 @@ impl GetGeometryRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        write(&[
+            GET_GEOMETRY_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetGeometryReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8269,9 +9391,11 @@ impl GetGeometryRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 impl Request for GetGeometryRequest {
     type Reply = GetGeometryReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 /// Get current window geometry.
 ///
 /// Gets the current geometry of the specified drawable (either `Window` or `Pixmap`).
@@ -8308,6 +9432,7 @@ impl Request for GetGeometryRequest {
 ///     free(reply);
 /// }
 /// ```
+#[inline]
 pub fn get_geometry<Conn>(conn: &Conn, drawable: Drawable) -> Result<Cookie<'_, Conn, GetGeometryReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8318,6 +9443,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 /// # Fields
 ///
 /// * `root` - Root window of the screen containing `drawable`.
@@ -8343,6 +9469,7 @@ pub struct GetGeometryReply {
     pub height: u16,
     pub border_width: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
 impl TryParse for GetGeometryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8362,7 +9489,8 @@ impl TryParse for GetGeometryReply {
         }
         let result = GetGeometryReply { depth, sequence, length, root, x, y, width, height, border_width };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8370,6 +9498,7 @@ impl TryParse for GetGeometryReply {
 
 /// Opcode for the QueryTree request
 pub const QUERY_TREE_REQUEST: u8 = 15;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 /// query the window tree.
 ///
 /// Gets the root window ID, parent window ID and list of children windows for the
@@ -8411,6 +9540,7 @@ pub const QUERY_TREE_REQUEST: u8 = 15;
 pub struct QueryTreeRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 impl QueryTreeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -8436,17 +9566,59 @@ impl QueryTreeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryTreeReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != QUERY_TREE_REQUEST {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            QUERY_TREE_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryTreeReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != QUERY_TREE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
@@ -8459,9 +9631,11 @@ impl QueryTreeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 impl Request for QueryTreeRequest {
     type Reply = QueryTreeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 /// query the window tree.
 ///
 /// Gets the root window ID, parent window ID and list of children windows for the
@@ -8499,6 +9673,7 @@ impl Request for QueryTreeRequest {
 ///     }
 /// }
 /// ```
+#[inline]
 pub fn query_tree<Conn>(conn: &Conn, window: Window) -> Result<Cookie<'_, Conn, QueryTreeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8509,6 +9684,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 /// # Fields
 ///
 /// * `root` - The root window of `window`.
@@ -8522,6 +9698,7 @@ pub struct QueryTreeReply {
     pub parent: Window,
     pub children: Vec<Window>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 impl TryParse for QueryTreeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8539,11 +9716,13 @@ impl TryParse for QueryTreeReply {
         }
         let result = QueryTreeReply { sequence, length, root, parent, children };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
 impl QueryTreeReply {
     /// Get the value of the `children_len` field.
     ///
@@ -8642,12 +9821,62 @@ impl<'input> InternAtomRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let only_if_exists_bytes = self.only_if_exists.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            INTERN_ATOM_REQUEST,
+            only_if_exists_bytes[0],
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, InternAtomReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8723,6 +9952,7 @@ impl<'input> Request for InternAtomRequest<'input> {
 ///     }
 /// }
 /// ```
+#[inline]
 pub fn intern_atom<'c, 'input, Conn>(conn: &'c Conn, only_if_exists: bool, name: &'input [u8]) -> Result<Cookie<'c, Conn, InternAtomReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8753,7 +9983,8 @@ impl TryParse for InternAtomReply {
         }
         let result = InternAtomReply { sequence, length, atom };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -8761,10 +9992,12 @@ impl TryParse for InternAtomReply {
 
 /// Opcode for the GetAtomName request
 pub const GET_ATOM_NAME_REQUEST: u8 = 17;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetAtomNameRequest {
     pub atom: Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 impl GetAtomNameRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -8790,12 +10023,54 @@ impl GetAtomNameRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let atom_bytes = self.atom.serialize();
+        write(&[
+            GET_ATOM_NAME_REQUEST,
+            0,
+            0,
+            0,
+            atom_bytes[0],
+            atom_bytes[1],
+            atom_bytes[2],
+            atom_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetAtomNameReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -8813,9 +10088,12 @@ impl GetAtomNameRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 impl Request for GetAtomNameRequest {
     type Reply = GetAtomNameReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
+#[inline]
 pub fn get_atom_name<Conn>(conn: &Conn, atom: Atom) -> Result<Cookie<'_, Conn, GetAtomNameReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -8826,12 +10104,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetAtomNameReply {
     pub sequence: u16,
     pub length: u32,
     pub name: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 impl TryParse for GetAtomNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8848,11 +10128,13 @@ impl TryParse for GetAtomNameReply {
         }
         let result = GetAtomNameReply { sequence, length, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
 impl GetAtomNameReply {
     /// Get the value of the `name_len` field.
     ///
@@ -8937,6 +10219,17 @@ impl std::fmt::Debug for PropMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PropMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ChangeProperty request
 pub const CHANGE_PROPERTY_REQUEST: u8 = 18;
@@ -9051,12 +10344,82 @@ impl<'input> ChangePropertyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.data, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let data_len_bytes = self.data_len.serialize();
+        write(&[
+            CHANGE_PROPERTY_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            0,
+            0,
+            0,
+            data_len_bytes[0],
+            data_len_bytes[1],
+            data_len_bytes[2],
+            data_len_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(self.data.len(), usize::try_from(self.data_len.checked_mul(u32::from(self.format)).unwrap().checked_div(8u32).unwrap()).unwrap(), "`data` has an incorrect length");
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9067,6 +10430,8 @@ impl<'input> ChangePropertyRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        PropMode::check_known(mode)?;
         let _ = remaining;
         let (window, remaining) = Window::try_parse(value)?;
         let (property, remaining) = Atom::try_parse(remaining)?;
@@ -9153,6 +10518,7 @@ impl<'input> Request for ChangePropertyRequest<'input> {
 ///     xcb_flush(conn);
 /// }
 /// ```
+#[inline]
 pub fn change_property<'c, 'input, Conn, A, B>(conn: &'c Conn, mode: PropMode, window: Window, property: A, type_: B, format: u8, data_len: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9175,11 +10541,13 @@ where
 
 /// Opcode for the DeleteProperty request
 pub const DELETE_PROPERTY_REQUEST: u8 = 19;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeletePropertyRequest {
     pub window: Window,
     pub property: Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
 impl DeletePropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -9210,12 +10578,59 @@ impl DeletePropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        write(&[
+            DELETE_PROPERTY_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9235,9 +10650,12 @@ impl DeletePropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
 impl Request for DeletePropertyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
+#[inline]
 pub fn delete_property<Conn>(conn: &Conn, window: Window, property: Atom) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9307,6 +10725,7 @@ impl std::fmt::Debug for GetPropertyType  {
 
 /// Opcode for the GetProperty request
 pub const GET_PROPERTY_REQUEST: u8 = 20;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 /// Gets a window property.
 ///
 /// Gets the specified `property` from the specified `window`. Properties are for
@@ -9384,6 +10803,7 @@ pub struct GetPropertyRequest {
     pub long_offset: u32,
     pub long_length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 impl GetPropertyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -9430,12 +10850,75 @@ impl GetPropertyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let delete_bytes = self.delete.serialize();
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        write(&[
+            GET_PROPERTY_REQUEST,
+            delete_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPropertyReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9462,9 +10945,11 @@ impl GetPropertyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 impl Request for GetPropertyRequest {
     type Reply = GetPropertyReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 /// Gets a window property.
 ///
 /// Gets the specified `property` from the specified `window`. Properties are for
@@ -9533,6 +11018,7 @@ impl Request for GetPropertyRequest {
 ///     free(reply);
 /// }
 /// ```
+#[inline]
 pub fn get_property<Conn, A, B>(conn: &Conn, delete: bool, window: Window, property: A, type_: B, long_offset: u32, long_length: u32) -> Result<Cookie<'_, Conn, GetPropertyReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9551,6 +11037,7 @@ where
     };
     request0.send(conn)
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 impl GetPropertyReply {
     /// Iterate over the contained value if its format is 8.
     ///
@@ -9709,6 +11196,7 @@ impl GetPropertyReply {
 }
 
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 /// # Fields
 ///
 /// * `format` - Specifies whether the data should be viewed as a list of 8-bit, 16-bit, or
@@ -9729,6 +11217,7 @@ pub struct GetPropertyReply {
     pub value_len: u32,
     pub value: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
 impl TryParse for GetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9747,7 +11236,8 @@ impl TryParse for GetPropertyReply {
         }
         let result = GetPropertyReply { format, sequence, length, type_, bytes_after, value_len, value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -9755,10 +11245,12 @@ impl TryParse for GetPropertyReply {
 
 /// Opcode for the ListProperties request
 pub const LIST_PROPERTIES_REQUEST: u8 = 21;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListPropertiesRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 impl ListPropertiesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -9784,12 +11276,54 @@ impl ListPropertiesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            LIST_PROPERTIES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListPropertiesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9807,9 +11341,12 @@ impl ListPropertiesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 impl Request for ListPropertiesRequest {
     type Reply = ListPropertiesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
+#[inline]
 pub fn list_properties<Conn>(conn: &Conn, window: Window) -> Result<Cookie<'_, Conn, ListPropertiesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -9820,12 +11357,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListPropertiesReply {
     pub sequence: u16,
     pub length: u32,
     pub atoms: Vec<Atom>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 impl TryParse for ListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9841,11 +11380,13 @@ impl TryParse for ListPropertiesReply {
         }
         let result = ListPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
 impl ListPropertiesReply {
     /// Get the value of the `atoms_len` field.
     ///
@@ -9864,6 +11405,7 @@ impl ListPropertiesReply {
 
 /// Opcode for the SetSelectionOwner request
 pub const SET_SELECTION_OWNER_REQUEST: u8 = 22;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
 /// Sets the owner of a selection.
 ///
 /// Makes `window` the owner of the selection `selection` and updates the
@@ -9899,6 +11441,7 @@ pub struct SetSelectionOwnerRequest {
     pub selection: Atom,
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
 impl SetSelectionOwnerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -9934,12 +11477,64 @@ impl SetSelectionOwnerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let owner_bytes = self.owner.serialize();
+        let selection_bytes = self.selection.serialize();
+        let time_bytes = self.time.serialize();
+        write(&[
+            SET_SELECTION_OWNER_REQUEST,
+            0,
+            0,
+            0,
+            owner_bytes[0],
+            owner_bytes[1],
+            owner_bytes[2],
+            owner_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -9961,9 +11556,11 @@ impl SetSelectionOwnerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
 impl Request for SetSelectionOwnerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
 /// Sets the owner of a selection.
 ///
 /// Makes `window` the owner of the selection `selection` and updates the
@@ -9993,6 +11590,7 @@ impl Request for SetSelectionOwnerRequest {
 /// # See
 ///
 /// * `SetSelectionOwner`: request
+#[inline]
 pub fn set_selection_owner<Conn, A, B>(conn: &Conn, owner: A, selection: Atom, time: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10011,6 +11609,7 @@ where
 
 /// Opcode for the GetSelectionOwner request
 pub const GET_SELECTION_OWNER_REQUEST: u8 = 23;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 /// Gets the owner of a selection.
 ///
 /// Gets the owner of the specified selection.
@@ -10032,6 +11631,7 @@ pub const GET_SELECTION_OWNER_REQUEST: u8 = 23;
 pub struct GetSelectionOwnerRequest {
     pub selection: Atom,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 impl GetSelectionOwnerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -10057,16 +11657,58 @@ impl GetSelectionOwnerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetSelectionOwnerReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        let length_so_far = 0;
+        let selection_bytes = self.selection.serialize();
+        write(&[
+            GET_SELECTION_OWNER_REQUEST,
+            0,
+            0,
+            0,
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetSelectionOwnerReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
         if header.major_opcode != GET_SELECTION_OWNER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -10080,9 +11722,11 @@ impl GetSelectionOwnerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 impl Request for GetSelectionOwnerRequest {
     type Reply = GetSelectionOwnerReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 /// Gets the owner of a selection.
 ///
 /// Gets the owner of the specified selection.
@@ -10100,6 +11744,7 @@ impl Request for GetSelectionOwnerRequest {
 /// # See
 ///
 /// * `SetSelectionOwner`: request
+#[inline]
 pub fn get_selection_owner<Conn>(conn: &Conn, selection: Atom) -> Result<Cookie<'_, Conn, GetSelectionOwnerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10110,6 +11755,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 /// # Fields
 ///
 /// * `owner` - The current selection owner window.
@@ -10119,6 +11765,7 @@ pub struct GetSelectionOwnerReply {
     pub length: u32,
     pub owner: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
 impl TryParse for GetSelectionOwnerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10132,7 +11779,8 @@ impl TryParse for GetSelectionOwnerReply {
         }
         let result = GetSelectionOwnerReply { sequence, length, owner };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -10140,6 +11788,7 @@ impl TryParse for GetSelectionOwnerReply {
 
 /// Opcode for the ConvertSelection request
 pub const CONVERT_SELECTION_REQUEST: u8 = 24;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConvertSelectionRequest {
     pub requestor: Window,
@@ -10148,6 +11797,7 @@ pub struct ConvertSelectionRequest {
     pub property: Atom,
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
 impl ConvertSelectionRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -10193,12 +11843,74 @@ impl ConvertSelectionRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let requestor_bytes = self.requestor.serialize();
+        let selection_bytes = self.selection.serialize();
+        let target_bytes = self.target.serialize();
+        let property_bytes = self.property.serialize();
+        let time_bytes = self.time.serialize();
+        write(&[
+            CONVERT_SELECTION_REQUEST,
+            0,
+            0,
+            0,
+            requestor_bytes[0],
+            requestor_bytes[1],
+            requestor_bytes[2],
+            requestor_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10224,9 +11936,12 @@ impl ConvertSelectionRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
 impl Request for ConvertSelectionRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
+#[inline]
 pub fn convert_selection<Conn, A, B>(conn: &Conn, requestor: Window, selection: Atom, target: Atom, property: A, time: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10317,6 +12032,7 @@ impl std::fmt::Debug for SendEventDest  {
 
 /// Opcode for the SendEvent request
 pub const SEND_EVENT_REQUEST: u8 = 25;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
 /// send an event.
 ///
 /// Identifies the `destination` window, determines which clients should receive
@@ -10397,6 +12113,7 @@ pub struct SendEventRequest<'input> {
     pub event_mask: u32,
     pub event: Cow<'input, [u8; 32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
 impl<'input> SendEventRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -10429,12 +12146,62 @@ impl<'input> SendEventRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), Cow::Owned(self.event.to_vec())], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let propagate_bytes = self.propagate.serialize();
+        let destination_bytes = self.destination.serialize();
+        let event_mask_bytes = self.event_mask.serialize();
+        write(&[
+            SEND_EVENT_REQUEST,
+            propagate_bytes[0],
+            0,
+            0,
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.event.len();
+        write(&self.event[..]);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        44
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10467,9 +12234,11 @@ impl<'input> SendEventRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
 impl<'input> Request for SendEventRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
 /// send an event.
 ///
 /// Identifies the `destination` window, determines which clients should receive
@@ -10543,6 +12312,7 @@ impl<'input> Request for SendEventRequest<'input> {
 ///     free(event);
 /// }
 /// ```
+#[inline]
 pub fn send_event<Conn, A, B, C>(conn: &Conn, propagate: bool, destination: A, event_mask: B, event: C) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -10625,6 +12395,17 @@ impl std::fmt::Debug for GrabMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl GrabMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GrabStatus(u8);
@@ -10689,6 +12470,17 @@ impl std::fmt::Debug for GrabStatus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl GrabStatus {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=4) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CursorEnum(u8);
@@ -10748,6 +12540,7 @@ impl std::fmt::Debug for CursorEnum  {
 
 /// Opcode for the GrabPointer request
 pub const GRAB_POINTER_REQUEST: u8 = 26;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 /// Grab the pointer.
 ///
 /// Actively grabs control of the pointer. Further pointer events are reported only to the grabbing client. Overrides any active pointer grab by this client.
@@ -10830,6 +12623,7 @@ pub struct GrabPointerRequest {
     pub cursor: Cursor,
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 impl GrabPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -10878,12 +12672,77 @@ impl GrabPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let event_mask_bytes = self.event_mask.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        let confine_to_bytes = self.confine_to.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let time_bytes = self.time.serialize();
+        write(&[
+            GRAB_POINTER_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            confine_to_bytes[0],
+            confine_to_bytes[1],
+            confine_to_bytes[2],
+            confine_to_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GrabPointerReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -10898,8 +12757,12 @@ impl GrabPointerRequest {
         let (event_mask, remaining) = u16::try_parse(remaining)?;
         let (pointer_mode, remaining) = u8::try_parse(remaining)?;
         let pointer_mode = pointer_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(pointer_mode)?;
         let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
         let keyboard_mode = keyboard_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(keyboard_mode)?;
         let (confine_to, remaining) = Window::try_parse(remaining)?;
         let (cursor, remaining) = Cursor::try_parse(remaining)?;
         let (time, remaining) = Timestamp::try_parse(remaining)?;
@@ -10916,9 +12779,11 @@ impl GrabPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 impl Request for GrabPointerRequest {
     type Reply = GrabPointerReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 /// Grab the pointer.
 ///
 /// Actively grabs control of the pointer. Further pointer events are reported only to the grabbing client. Overrides any active pointer grab by this client.
@@ -10990,6 +12855,7 @@ impl Request for GrabPointerRequest {
 ///     }
 /// }
 /// ```
+#[inline]
 pub fn grab_pointer<Conn, A, B, C, D>(conn: &Conn, owner_events: bool, grab_window: Window, event_mask: A, pointer_mode: GrabMode, keyboard_mode: GrabMode, confine_to: B, cursor: C, time: D) -> Result<Cookie<'_, Conn, GrabPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11015,12 +12881,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabPointerReply {
     pub status: GrabStatus,
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
 impl TryParse for GrabPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11032,9 +12900,12 @@ impl TryParse for GrabPointerReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabStatus::check_known(status)?;
         let result = GrabPointerReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -11042,6 +12913,7 @@ impl TryParse for GrabPointerReply {
 
 /// Opcode for the UngrabPointer request
 pub const UNGRAB_POINTER_REQUEST: u8 = 27;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
 /// release the pointer.
 ///
 /// Releases the pointer and any queued events if you actively grabbed the pointer
@@ -11069,6 +12941,7 @@ pub const UNGRAB_POINTER_REQUEST: u8 = 27;
 pub struct UngrabPointerRequest {
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
 impl UngrabPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11094,12 +12967,54 @@ impl UngrabPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        write(&[
+            UNGRAB_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11117,9 +13032,11 @@ impl UngrabPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
 impl Request for UngrabPointerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
 /// release the pointer.
 ///
 /// Releases the pointer and any queued events if you actively grabbed the pointer
@@ -11143,6 +13060,7 @@ impl Request for UngrabPointerRequest {
 /// * `GrabButton`: request
 /// * `EnterNotify`: event
 /// * `LeaveNotify`: event
+#[inline]
 pub fn ungrab_pointer<Conn, A>(conn: &Conn, time: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11228,9 +13146,21 @@ impl std::fmt::Debug for ButtonIndex  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ButtonIndex {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=5) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the GrabButton request
 pub const GRAB_BUTTON_REQUEST: u8 = 28;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
 /// Grab pointer button(s).
 ///
 /// This request establishes a passive grab. The pointer is actively grabbed as
@@ -11310,6 +13240,7 @@ pub struct GrabButtonRequest {
     pub button: ButtonIndex,
     pub modifiers: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
 impl GrabButtonRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11359,12 +13290,78 @@ impl GrabButtonRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let event_mask_bytes = self.event_mask.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        let confine_to_bytes = self.confine_to.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let button_bytes = u8::from(self.button).serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        write(&[
+            GRAB_BUTTON_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            confine_to_bytes[0],
+            confine_to_bytes[1],
+            confine_to_bytes[2],
+            confine_to_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            button_bytes[0],
+            0,
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11379,12 +13376,18 @@ impl GrabButtonRequest {
         let (event_mask, remaining) = u16::try_parse(remaining)?;
         let (pointer_mode, remaining) = u8::try_parse(remaining)?;
         let pointer_mode = pointer_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(pointer_mode)?;
         let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
         let keyboard_mode = keyboard_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(keyboard_mode)?;
         let (confine_to, remaining) = Window::try_parse(remaining)?;
         let (cursor, remaining) = Cursor::try_parse(remaining)?;
         let (button, remaining) = u8::try_parse(remaining)?;
         let button = button.into();
+        #[cfg(feature = "strict_parsing")]
+        ButtonIndex::check_known(button)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (modifiers, remaining) = u16::try_parse(remaining)?;
         let _ = remaining;
@@ -11401,9 +13404,11 @@ impl GrabButtonRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
 impl Request for GrabButtonRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
 /// Grab pointer button(s).
 ///
 /// This request establishes a passive grab. The pointer is actively grabbed as
@@ -11471,6 +13476,7 @@ impl Request for GrabButtonRequest {
 /// * `Value` - TODO: reasons?
 /// * `Cursor` - The specified `cursor` does not exist.
 /// * `Window` - The specified `window` does not exist.
+#[inline]
 pub fn grab_button<Conn, A, B, C, D>(conn: &Conn, owner_events: bool, grab_window: Window, event_mask: A, pointer_mode: GrabMode, keyboard_mode: GrabMode, confine_to: B, cursor: C, button: ButtonIndex, modifiers: D) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11499,12 +13505,14 @@ where
 
 /// Opcode for the UngrabButton request
 pub const UNGRAB_BUTTON_REQUEST: u8 = 29;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabButtonRequest {
     pub button: ButtonIndex,
     pub grab_window: Window,
     pub modifiers: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
 impl UngrabButtonRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11536,12 +13544,60 @@ impl UngrabButtonRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let button_bytes = u8::from(self.button).serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        write(&[
+            UNGRAB_BUTTON_REQUEST,
+            button_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11552,6 +13608,8 @@ impl UngrabButtonRequest {
         let remaining = &[header.minor_opcode];
         let (button, remaining) = u8::try_parse(remaining)?;
         let button = button.into();
+        #[cfg(feature = "strict_parsing")]
+        ButtonIndex::check_known(button)?;
         let _ = remaining;
         let (grab_window, remaining) = Window::try_parse(value)?;
         let (modifiers, remaining) = u16::try_parse(remaining)?;
@@ -11564,9 +13622,12 @@ impl UngrabButtonRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
 impl Request for UngrabButtonRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
+#[inline]
 pub fn ungrab_button<Conn, A>(conn: &Conn, button: ButtonIndex, grab_window: Window, modifiers: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11583,12 +13644,14 @@ where
 
 /// Opcode for the ChangeActivePointerGrab request
 pub const CHANGE_ACTIVE_POINTER_GRAB_REQUEST: u8 = 30;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangeActivePointerGrabRequest {
     pub cursor: Cursor,
     pub time: Timestamp,
     pub event_mask: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
 impl ChangeActivePointerGrabRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11624,14 +13687,66 @@ impl ChangeActivePointerGrabRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
+        let length_so_far = 0;
+        let cursor_bytes = self.cursor.serialize();
+        let time_bytes = self.time.serialize();
+        let event_mask_bytes = self.event_mask.serialize();
+        write(&[
+            CHANGE_ACTIVE_POINTER_GRAB_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
     /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
         if header.major_opcode != CHANGE_ACTIVE_POINTER_GRAB_REQUEST {
@@ -11652,9 +13767,12 @@ impl ChangeActivePointerGrabRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
 impl Request for ChangeActivePointerGrabRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
+#[inline]
 pub fn change_active_pointer_grab<Conn, A, B, C>(conn: &Conn, cursor: A, time: B, event_mask: C) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11675,6 +13793,7 @@ where
 
 /// Opcode for the GrabKeyboard request
 pub const GRAB_KEYBOARD_REQUEST: u8 = 31;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 /// Grab the keyboard.
 ///
 /// Actively grabs control of the keyboard and generates FocusIn and FocusOut
@@ -11745,6 +13864,7 @@ pub struct GrabKeyboardRequest {
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 impl GrabKeyboardRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11782,12 +13902,66 @@ impl GrabKeyboardRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let time_bytes = self.time.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        write(&[
+            GRAB_KEYBOARD_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GrabKeyboardReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11802,8 +13976,12 @@ impl GrabKeyboardRequest {
         let (time, remaining) = Timestamp::try_parse(remaining)?;
         let (pointer_mode, remaining) = u8::try_parse(remaining)?;
         let pointer_mode = pointer_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(pointer_mode)?;
         let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
         let keyboard_mode = keyboard_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(keyboard_mode)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(GrabKeyboardRequest {
@@ -11815,9 +13993,11 @@ impl GrabKeyboardRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 impl Request for GrabKeyboardRequest {
     type Reply = GrabKeyboardReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 /// Grab the keyboard.
 ///
 /// Actively grabs control of the keyboard and generates FocusIn and FocusOut
@@ -11880,6 +14060,7 @@ impl Request for GrabKeyboardRequest {
 ///     }
 /// }
 /// ```
+#[inline]
 pub fn grab_keyboard<Conn, A>(conn: &Conn, owner_events: bool, grab_window: Window, time: A, pointer_mode: GrabMode, keyboard_mode: GrabMode) -> Result<Cookie<'_, Conn, GrabKeyboardReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -11896,12 +14077,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabKeyboardReply {
     pub status: GrabStatus,
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
 impl TryParse for GrabKeyboardReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11913,9 +14096,12 @@ impl TryParse for GrabKeyboardReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabStatus::check_known(status)?;
         let result = GrabKeyboardReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -11923,10 +14109,12 @@ impl TryParse for GrabKeyboardReply {
 
 /// Opcode for the UngrabKeyboard request
 pub const UNGRAB_KEYBOARD_REQUEST: u8 = 32;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabKeyboardRequest {
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
 impl UngrabKeyboardRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -11952,12 +14140,54 @@ impl UngrabKeyboardRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let time_bytes = self.time.serialize();
+        write(&[
+            UNGRAB_KEYBOARD_REQUEST,
+            0,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -11975,9 +14205,12 @@ impl UngrabKeyboardRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
 impl Request for UngrabKeyboardRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
+#[inline]
 pub fn ungrab_keyboard<Conn, A>(conn: &Conn, time: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12048,6 +14281,7 @@ impl std::fmt::Debug for Grab  {
 
 /// Opcode for the GrabKey request
 pub const GRAB_KEY_REQUEST: u8 = 33;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
 /// Grab keyboard key(s).
 ///
 /// Establishes a passive grab on the keyboard. In the future, the keyboard is
@@ -12117,6 +14351,7 @@ pub struct GrabKeyRequest {
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
 impl GrabKeyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12155,12 +14390,67 @@ impl GrabKeyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        let key_bytes = self.key.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        write(&[
+            GRAB_KEY_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            key_bytes[0],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12176,8 +14466,12 @@ impl GrabKeyRequest {
         let (key, remaining) = Keycode::try_parse(remaining)?;
         let (pointer_mode, remaining) = u8::try_parse(remaining)?;
         let pointer_mode = pointer_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(pointer_mode)?;
         let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
         let keyboard_mode = keyboard_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        GrabMode::check_known(keyboard_mode)?;
         let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
         let _ = remaining;
         Ok(GrabKeyRequest {
@@ -12190,9 +14484,11 @@ impl GrabKeyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
 impl Request for GrabKeyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
 /// Grab keyboard key(s).
 ///
 /// Establishes a passive grab on the keyboard. In the future, the keyboard is
@@ -12253,6 +14549,7 @@ impl Request for GrabKeyRequest {
 /// # See
 ///
 /// * `GrabKeyboard`: request
+#[inline]
 pub fn grab_key<Conn, A, B>(conn: &Conn, owner_events: bool, grab_window: Window, modifiers: A, key: B, pointer_mode: GrabMode, keyboard_mode: GrabMode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12274,6 +14571,7 @@ where
 
 /// Opcode for the UngrabKey request
 pub const UNGRAB_KEY_REQUEST: u8 = 34;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
 /// release a key combination.
 ///
 /// Releases the key combination on `grab_window` if you grabbed it using
@@ -12305,6 +14603,7 @@ pub struct UngrabKeyRequest {
     pub grab_window: Window,
     pub modifiers: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
 impl UngrabKeyRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12336,12 +14635,60 @@ impl UngrabKeyRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let key_bytes = self.key.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = self.modifiers.serialize();
+        write(&[
+            UNGRAB_KEY_REQUEST,
+            key_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12363,9 +14710,11 @@ impl UngrabKeyRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
 impl Request for UngrabKeyRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
 /// release a key combination.
 ///
 /// Releases the key combination on `grab_window` if you grabbed it using
@@ -12391,6 +14740,7 @@ impl Request for UngrabKeyRequest {
 ///
 /// * `GrabKey`: request
 /// * `xev`: program
+#[inline]
 pub fn ungrab_key<Conn, A, B>(conn: &Conn, key: A, grab_window: Window, modifiers: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12535,9 +14885,21 @@ impl std::fmt::Debug for Allow  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Allow {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=7) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the AllowEvents request
 pub const ALLOW_EVENTS_REQUEST: u8 = 35;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
 /// release queued events.
 ///
 /// Releases queued events if the client has caused a device (pointer/keyboard) to
@@ -12561,6 +14923,7 @@ pub struct AllowEventsRequest {
     pub mode: Allow,
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
 impl AllowEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12587,12 +14950,55 @@ impl AllowEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        let time_bytes = self.time.serialize();
+        write(&[
+            ALLOW_EVENTS_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12603,6 +15009,8 @@ impl AllowEventsRequest {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        Allow::check_known(mode)?;
         let _ = remaining;
         let (time, remaining) = Timestamp::try_parse(value)?;
         let _ = remaining;
@@ -12612,9 +15020,11 @@ impl AllowEventsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
 impl Request for AllowEventsRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
 /// release queued events.
 ///
 /// Releases queued events if the client has caused a device (pointer/keyboard) to
@@ -12633,6 +15043,7 @@ impl Request for AllowEventsRequest {
 /// # Errors
 ///
 /// * `Value` - You specified an invalid `mode`.
+#[inline]
 pub fn allow_events<Conn, A>(conn: &Conn, mode: Allow, time: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12648,8 +15059,10 @@ where
 
 /// Opcode for the GrabServer request
 pub const GRAB_SERVER_REQUEST: u8 = 36;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GrabServerRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
 impl GrabServerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12670,12 +15083,49 @@ impl GrabServerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GRAB_SERVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12691,9 +15141,12 @@ impl GrabServerRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
 impl Request for GrabServerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
+#[inline]
 pub fn grab_server<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12704,8 +15157,10 @@ where
 
 /// Opcode for the UngrabServer request
 pub const UNGRAB_SERVER_REQUEST: u8 = 37;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UngrabServerRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
 impl UngrabServerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12726,12 +15181,49 @@ impl UngrabServerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            UNGRAB_SERVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12747,9 +15239,12 @@ impl UngrabServerRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
 impl Request for UngrabServerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
+#[inline]
 pub fn ungrab_server<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12760,6 +15255,7 @@ where
 
 /// Opcode for the QueryPointer request
 pub const QUERY_POINTER_REQUEST: u8 = 38;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 /// get pointer coordinates.
 ///
 /// Gets the root window the pointer is logically on and the pointer coordinates
@@ -12777,6 +15273,7 @@ pub const QUERY_POINTER_REQUEST: u8 = 38;
 pub struct QueryPointerRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 impl QueryPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12802,12 +15299,54 @@ impl QueryPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            QUERY_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryPointerReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -12825,9 +15364,11 @@ impl QueryPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 impl Request for QueryPointerRequest {
     type Reply = QueryPointerReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 /// get pointer coordinates.
 ///
 /// Gets the root window the pointer is logically on and the pointer coordinates
@@ -12841,6 +15382,7 @@ impl Request for QueryPointerRequest {
 /// # Errors
 ///
 /// * `Window` - The specified `window` does not exist.
+#[inline]
 pub fn query_pointer<Conn>(conn: &Conn, window: Window) -> Result<Cookie<'_, Conn, QueryPointerReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -12851,6 +15393,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 /// # Fields
 ///
 /// * `same_screen` - If `same_screen` is False, then the pointer is not on the same screen as the
@@ -12870,7 +15413,7 @@ where
 /// * `mask` - The current logical state of the modifier keys and the buttons. Note that the
 /// logical state of a device (as seen by means of the protocol) may lag the
 /// physical state if device event processing is frozen.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct QueryPointerReply {
     pub same_screen: bool,
     pub sequence: u16,
@@ -12883,6 +15426,24 @@ pub struct QueryPointerReply {
     pub win_y: i16,
     pub mask: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
+impl std::fmt::Debug for QueryPointerReply {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("QueryPointerReply")
+            .field("same_screen", &self.same_screen)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("root", &self.root)
+            .field("child", &self.child)
+            .field("root_x", &self.root_x)
+            .field("root_y", &self.root_y)
+            .field("win_x", &self.win_x)
+            .field("win_y", &self.win_y)
+            .field("mask", &KeyButMask::from(self.mask))
+            .finish()
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
 impl TryParse for QueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12903,7 +15464,8 @@ impl TryParse for QueryPointerReply {
         }
         let result = QueryPointerReply { same_screen, sequence, length, root, child, root_x, root_y, win_x, win_y, mask };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -12951,12 +15513,14 @@ impl Serialize for Timecoord {
 
 /// Opcode for the GetMotionEvents request
 pub const GET_MOTION_EVENTS_REQUEST: u8 = 39;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetMotionEventsRequest {
     pub window: Window,
     pub start: Timestamp,
     pub stop: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 impl GetMotionEventsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -12992,36 +15556,91 @@ impl GetMotionEventsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMotionEventsReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != GET_MOTION_EVENTS_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (start, remaining) = Timestamp::try_parse(remaining)?;
-        let (stop, remaining) = Timestamp::try_parse(remaining)?;
-        let _ = remaining;
-        Ok(GetMotionEventsRequest {
-            window,
-            start,
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let start_bytes = self.start.serialize();
+        let stop_bytes = self.stop.serialize();
+        write(&[
+            GET_MOTION_EVENTS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            start_bytes[0],
+            start_bytes[1],
+            start_bytes[2],
+            start_bytes[3],
+            stop_bytes[0],
+            stop_bytes[1],
+            stop_bytes[2],
+            stop_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetMotionEventsReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != GET_MOTION_EVENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let (window, remaining) = Window::try_parse(value)?;
+        let (start, remaining) = Timestamp::try_parse(remaining)?;
+        let (stop, remaining) = Timestamp::try_parse(remaining)?;
+        let _ = remaining;
+        Ok(GetMotionEventsRequest {
+            window,
+            start,
             stop,
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 impl Request for GetMotionEventsRequest {
     type Reply = GetMotionEventsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
+#[inline]
 pub fn get_motion_events<Conn, A, B>(conn: &Conn, window: Window, start: A, stop: B) -> Result<Cookie<'_, Conn, GetMotionEventsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13038,12 +15657,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetMotionEventsReply {
     pub sequence: u16,
     pub length: u32,
     pub events: Vec<Timecoord>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 impl TryParse for GetMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13059,11 +15680,13 @@ impl TryParse for GetMotionEventsReply {
         }
         let result = GetMotionEventsReply { sequence, length, events };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
 impl GetMotionEventsReply {
     /// Get the value of the `events_len` field.
     ///
@@ -13082,6 +15705,7 @@ impl GetMotionEventsReply {
 
 /// Opcode for the TranslateCoordinates request
 pub const TRANSLATE_COORDINATES_REQUEST: u8 = 40;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TranslateCoordinatesRequest {
     pub src_window: Window,
@@ -13089,6 +15713,7 @@ pub struct TranslateCoordinatesRequest {
     pub src_x: i16,
     pub src_y: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 impl TranslateCoordinatesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13125,12 +15750,65 @@ impl TranslateCoordinatesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let src_window_bytes = self.src_window.serialize();
+        let dst_window_bytes = self.dst_window.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        write(&[
+            TRANSLATE_COORDINATES_REQUEST,
+            0,
+            0,
+            0,
+            src_window_bytes[0],
+            src_window_bytes[1],
+            src_window_bytes[2],
+            src_window_bytes[3],
+            dst_window_bytes[0],
+            dst_window_bytes[1],
+            dst_window_bytes[2],
+            dst_window_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, TranslateCoordinatesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13154,9 +15832,12 @@ impl TranslateCoordinatesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 impl Request for TranslateCoordinatesRequest {
     type Reply = TranslateCoordinatesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
+#[inline]
 pub fn translate_coordinates<Conn>(conn: &Conn, src_window: Window, dst_window: Window, src_x: i16, src_y: i16) -> Result<Cookie<'_, Conn, TranslateCoordinatesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13170,6 +15851,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TranslateCoordinatesReply {
     pub same_screen: bool,
@@ -13179,6 +15861,7 @@ pub struct TranslateCoordinatesReply {
     pub dst_x: i16,
     pub dst_y: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
 impl TryParse for TranslateCoordinatesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13194,7 +15877,8 @@ impl TryParse for TranslateCoordinatesReply {
         }
         let result = TranslateCoordinatesReply { same_screen, sequence, length, child, dst_x, dst_y };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -13202,6 +15886,7 @@ impl TryParse for TranslateCoordinatesReply {
 
 /// Opcode for the WarpPointer request
 pub const WARP_POINTER_REQUEST: u8 = 41;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
 /// move mouse pointer.
 ///
 /// Moves the mouse pointer to the specified position.
@@ -13245,6 +15930,7 @@ pub struct WarpPointerRequest {
     pub dst_x: i16,
     pub dst_y: i16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
 impl WarpPointerRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13293,12 +15979,77 @@ impl WarpPointerRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let src_window_bytes = self.src_window.serialize();
+        let dst_window_bytes = self.dst_window.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        write(&[
+            WARP_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            src_window_bytes[0],
+            src_window_bytes[1],
+            src_window_bytes[2],
+            src_window_bytes[3],
+            dst_window_bytes[0],
+            dst_window_bytes[1],
+            dst_window_bytes[2],
+            dst_window_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 24;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        24
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13330,9 +16081,11 @@ impl WarpPointerRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
 impl Request for WarpPointerRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
 /// move mouse pointer.
 ///
 /// Moves the mouse pointer to the specified position.
@@ -13365,6 +16118,7 @@ impl Request for WarpPointerRequest {
 /// # See
 ///
 /// * `SetInputFocus`: request
+#[inline]
 pub fn warp_pointer<Conn, A, B>(conn: &Conn, src_window: A, dst_window: B, src_x: i16, src_y: i16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13456,9 +16210,21 @@ impl std::fmt::Debug for InputFocus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl InputFocus {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetInputFocus request
 pub const SET_INPUT_FOCUS_REQUEST: u8 = 42;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
 /// Sets input focus.
 ///
 /// Changes the input focus and the last-focus-change time. If the specified `time`
@@ -13500,6 +16266,7 @@ pub struct SetInputFocusRequest {
     pub focus: Window,
     pub time: Timestamp,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
 impl SetInputFocusRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13531,12 +16298,60 @@ impl SetInputFocusRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let revert_to_bytes = u8::from(self.revert_to).serialize();
+        let focus_bytes = self.focus.serialize();
+        let time_bytes = self.time.serialize();
+        write(&[
+            SET_INPUT_FOCUS_REQUEST,
+            revert_to_bytes[0],
+            0,
+            0,
+            focus_bytes[0],
+            focus_bytes[1],
+            focus_bytes[2],
+            focus_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13547,6 +16362,8 @@ impl SetInputFocusRequest {
         let remaining = &[header.minor_opcode];
         let (revert_to, remaining) = u8::try_parse(remaining)?;
         let revert_to = revert_to.into();
+        #[cfg(feature = "strict_parsing")]
+        InputFocus::check_known(revert_to)?;
         let _ = remaining;
         let (focus, remaining) = Window::try_parse(value)?;
         let (time, remaining) = Timestamp::try_parse(remaining)?;
@@ -13558,9 +16375,11 @@ impl SetInputFocusRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
 impl Request for SetInputFocusRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
 /// Sets input focus.
 ///
 /// Changes the input focus and the last-focus-change time. If the specified `time`
@@ -13596,6 +16415,7 @@ impl Request for SetInputFocusRequest {
 ///
 /// * `FocusIn`: event
 /// * `FocusOut`: event
+#[inline]
 pub fn set_input_focus<Conn, A, B>(conn: &Conn, revert_to: InputFocus, focus: A, time: B) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13636,12 +16456,49 @@ impl GetInputFocusRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_INPUT_FOCUS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetInputFocusReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13660,6 +16517,7 @@ impl GetInputFocusRequest {
 impl Request for GetInputFocusRequest {
     type Reply = GetInputFocusReply;
 }
+#[inline]
 pub fn get_input_focus<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetInputFocusReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13687,9 +16545,12 @@ impl TryParse for GetInputFocusReply {
             return Err(ParseError::InvalidValue);
         }
         let revert_to = revert_to.into();
+        #[cfg(feature = "strict_parsing")]
+        InputFocus::check_known(revert_to)?;
         let result = GetInputFocusReply { revert_to, sequence, length, focus };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -13697,8 +16558,10 @@ impl TryParse for GetInputFocusReply {
 
 /// Opcode for the QueryKeymap request
 pub const QUERY_KEYMAP_REQUEST: u8 = 44;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryKeymapRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 impl QueryKeymapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13719,12 +16582,49 @@ impl QueryKeymapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            QUERY_KEYMAP_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryKeymapReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13740,9 +16640,12 @@ impl QueryKeymapRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 impl Request for QueryKeymapRequest {
     type Reply = QueryKeymapReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
+#[inline]
 pub fn query_keymap<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, QueryKeymapReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13751,12 +16654,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryKeymapReply {
     pub sequence: u16,
     pub length: u32,
     pub keys: [u8; 32],
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
 impl TryParse for QueryKeymapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13771,7 +16676,8 @@ impl TryParse for QueryKeymapReply {
         }
         let result = QueryKeymapReply { sequence, length, keys };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -13779,6 +16685,7 @@ impl TryParse for QueryKeymapReply {
 
 /// Opcode for the OpenFont request
 pub const OPEN_FONT_REQUEST: u8 = 45;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
 /// opens a font.
 ///
 /// Opens any X core font matching the given `name` (for example "-misc-fixed-*").
@@ -13804,6 +16711,7 @@ pub struct OpenFontRequest<'input> {
     pub fid: Font,
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
 impl<'input> OpenFontRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13838,12 +16746,66 @@ impl<'input> OpenFontRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let fid_bytes = self.fid.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            OPEN_FONT_REQUEST,
+            0,
+            0,
+            0,
+            fid_bytes[0],
+            fid_bytes[1],
+            fid_bytes[2],
+            fid_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13872,9 +16834,11 @@ impl<'input> OpenFontRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
 impl<'input> Request for OpenFontRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
 /// opens a font.
 ///
 /// Opens any X core font matching the given `name` (for example "-misc-fixed-*").
@@ -13895,6 +16859,7 @@ impl<'input> Request for OpenFontRequest<'input> {
 /// # See
 ///
 /// * `xcb_generate_id`: function
+#[inline]
 pub fn open_font<'c, 'input, Conn>(conn: &'c Conn, fid: Font, name: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -13908,10 +16873,12 @@ where
 
 /// Opcode for the CloseFont request
 pub const CLOSE_FONT_REQUEST: u8 = 46;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CloseFontRequest {
     pub font: Font,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
 impl CloseFontRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -13937,12 +16904,54 @@ impl CloseFontRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let font_bytes = self.font.serialize();
+        write(&[
+            CLOSE_FONT_REQUEST,
+            0,
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -13960,9 +16969,12 @@ impl CloseFontRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
 impl Request for CloseFontRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
+#[inline]
 pub fn close_font<Conn>(conn: &Conn, font: Font) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14030,6 +17042,17 @@ impl std::fmt::Debug for FontDraw  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl FontDraw {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Fontprop {
@@ -14125,6 +17148,7 @@ impl Serialize for Charinfo {
 
 /// Opcode for the QueryFont request
 pub const QUERY_FONT_REQUEST: u8 = 47;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 /// query font metrics.
 ///
 /// Queries information associated with the font.
@@ -14136,6 +17160,7 @@ pub const QUERY_FONT_REQUEST: u8 = 47;
 pub struct QueryFontRequest {
     pub font: Fontable,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 impl QueryFontRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -14161,16 +17186,58 @@ impl QueryFontRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryFontReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        let length_so_far = 0;
+        let font_bytes = self.font.serialize();
+        write(&[
+            QUERY_FONT_REQUEST,
+            0,
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryFontReply>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
         if header.major_opcode != QUERY_FONT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
@@ -14184,9 +17251,11 @@ impl QueryFontRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 impl Request for QueryFontRequest {
     type Reply = QueryFontReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 /// query font metrics.
 ///
 /// Queries information associated with the font.
@@ -14194,6 +17263,7 @@ impl Request for QueryFontRequest {
 /// # Fields
 ///
 /// * `font` - The fontable (Font or Graphics Context) to query.
+#[inline]
 pub fn query_font<Conn>(conn: &Conn, font: Fontable) -> Result<Cookie<'_, Conn, QueryFontReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14204,6 +17274,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 /// # Fields
 ///
 /// * `min_bounds` - minimum bounds over all existing char
@@ -14234,6 +17305,7 @@ pub struct QueryFontReply {
     pub properties: Vec<Fontprop>,
     pub char_infos: Vec<Charinfo>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 impl TryParse for QueryFontReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14262,13 +17334,17 @@ impl TryParse for QueryFontReply {
             return Err(ParseError::InvalidValue);
         }
         let draw_direction = draw_direction.into();
+        #[cfg(feature = "strict_parsing")]
+        FontDraw::check_known(draw_direction)?;
         let result = QueryFontReply { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, properties, char_infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
 impl QueryFontReply {
     /// Get the value of the `properties_len` field.
     ///
@@ -14300,6 +17376,7 @@ impl QueryFontReply {
 
 /// Opcode for the QueryTextExtents request
 pub const QUERY_TEXT_EXTENTS_REQUEST: u8 = 48;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 /// get text extents.
 ///
 /// Query text extents from the X11 server. This request returns the bounding box
@@ -14339,6 +17416,7 @@ pub struct QueryTextExtentsRequest<'input> {
     pub font: Fontable,
     pub string: Cow<'input, [Char2b]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 impl<'input> QueryTextExtentsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -14371,12 +17449,64 @@ impl<'input> QueryTextExtentsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), string_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let string_len = u32::try_from(self.string.len()).unwrap();
+        let length_so_far = 0;
+        let odd_length = (string_len & 1u32) != 0;
+        let odd_length_bytes = odd_length.serialize();
+        let font_bytes = self.font.serialize();
+        write(&[
+            QUERY_TEXT_EXTENTS_REQUEST,
+            odd_length_bytes[0],
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let string_bytes = self.string.serialize();
+        let length_so_far = length_so_far + string_bytes.len();
+        write(&string_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.string.len() * 2;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryTextExtentsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -14416,9 +17546,11 @@ impl<'input> QueryTextExtentsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 impl<'input> Request for QueryTextExtentsRequest<'input> {
     type Reply = QueryTextExtentsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 /// get text extents.
 ///
 /// Query text extents from the X11 server. This request returns the bounding box
@@ -14453,6 +17585,7 @@ impl<'input> Request for QueryTextExtentsRequest<'input> {
 ///
 /// * `GContext` - The specified graphics context does not exist.
 /// * `Font` - The specified `font` does not exist.
+#[inline]
 pub fn query_text_extents<'c, 'input, Conn>(conn: &'c Conn, font: Fontable, string: &'input [Char2b]) -> Result<Cookie<'c, Conn, QueryTextExtentsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14464,6 +17597,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryTextExtentsReply {
     pub draw_direction: FontDraw,
@@ -14477,6 +17611,7 @@ pub struct QueryTextExtentsReply {
     pub overall_left: i32,
     pub overall_right: i32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
 impl TryParse for QueryTextExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14495,9 +17630,12 @@ impl TryParse for QueryTextExtentsReply {
             return Err(ParseError::InvalidValue);
         }
         let draw_direction = draw_direction.into();
+        #[cfg(feature = "strict_parsing")]
+        FontDraw::check_known(draw_direction)?;
         let result = QueryTextExtentsReply { draw_direction, sequence, length, font_ascent, font_descent, overall_ascent, overall_descent, overall_width, overall_left, overall_right };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -14547,6 +17685,7 @@ impl Str {
 
 /// Opcode for the ListFonts request
 pub const LIST_FONTS_REQUEST: u8 = 49;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 /// get matching font names.
 ///
 /// Gets a list of available font names which match the given `pattern`.
@@ -14565,6 +17704,7 @@ pub struct ListFontsRequest<'input> {
     pub max_names: u16,
     pub pattern: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 impl<'input> ListFontsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -14595,12 +17735,62 @@ impl<'input> ListFontsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.pattern, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let max_names_bytes = self.max_names.serialize();
+        let pattern_len = u16::try_from(self.pattern.len()).expect("`pattern` has too many elements");
+        let pattern_len_bytes = pattern_len.serialize();
+        write(&[
+            LIST_FONTS_REQUEST,
+            0,
+            0,
+            0,
+            max_names_bytes[0],
+            max_names_bytes[1],
+            pattern_len_bytes[0],
+            pattern_len_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.pattern.len();
+        write(&self.pattern[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.pattern.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListFontsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -14628,9 +17818,11 @@ impl<'input> ListFontsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 impl<'input> Request for ListFontsRequest<'input> {
     type Reply = ListFontsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 /// get matching font names.
 ///
 /// Gets a list of available font names which match the given `pattern`.
@@ -14644,6 +17836,7 @@ impl<'input> Request for ListFontsRequest<'input> {
 /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
 /// not matter.
 /// * `max_names` - The maximum number of fonts to be returned.
+#[inline]
 pub fn list_fonts<'c, 'input, Conn>(conn: &'c Conn, max_names: u16, pattern: &'input [u8]) -> Result<Cookie<'c, Conn, ListFontsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14655,6 +17848,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 /// # Fields
 ///
 /// * `names_len` - The number of font names.
@@ -14664,6 +17858,7 @@ pub struct ListFontsReply {
     pub length: u32,
     pub names: Vec<Str>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 impl TryParse for ListFontsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14679,11 +17874,13 @@ impl TryParse for ListFontsReply {
         }
         let result = ListFontsReply { sequence, length, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
 impl ListFontsReply {
     /// Get the value of the `names_len` field.
     ///
@@ -14750,13 +17947,63 @@ impl<'input> ListFontsWithInfoRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.pattern, padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<ListFontsWithInfoCookie<'_, Conn>, ConnectionError>
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let max_names_bytes = self.max_names.serialize();
+        let pattern_len = u16::try_from(self.pattern.len()).expect("`pattern` has too many elements");
+        let pattern_len_bytes = pattern_len.serialize();
+        write(&[
+            LIST_FONTS_WITH_INFO_REQUEST,
+            0,
+            0,
+            0,
+            max_names_bytes[0],
+            max_names_bytes[1],
+            pattern_len_bytes[0],
+            pattern_len_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.pattern.len();
+        write(&self.pattern[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.pattern.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<CookieStream<'_, Conn, ListFontsWithInfoReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(ListFontsWithInfoCookie::new(conn.send_request_with_reply(&slices, fds)?))
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        Ok(CookieStream::new(conn.send_request_with_reply(&slices, fds)?))
     }
     /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
@@ -14799,7 +18046,8 @@ impl<'input> Request for ListFontsWithInfoRequest<'input> {
 /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
 /// not matter.
 /// * `max_names` - The maximum number of fonts to be returned.
-pub fn list_fonts_with_info<'c, 'input, Conn>(conn: &'c Conn, max_names: u16, pattern: &'input [u8]) -> Result<ListFontsWithInfoCookie<'c, Conn>, ConnectionError>
+#[inline]
+pub fn list_fonts_with_info<'c, 'input, Conn>(conn: &'c Conn, max_names: u16, pattern: &'input [u8]) -> Result<CookieStream<'c, Conn, ListFontsWithInfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
@@ -14874,9 +18122,12 @@ impl TryParse for ListFontsWithInfoReply {
             return Err(ParseError::InvalidValue);
         }
         let draw_direction = draw_direction.into();
+        #[cfg(feature = "strict_parsing")]
+        FontDraw::check_known(draw_direction)?;
         let result = ListFontsWithInfoReply { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, replies_hint, properties, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -14912,10 +18163,12 @@ impl ListFontsWithInfoReply {
 
 /// Opcode for the SetFontPath request
 pub const SET_FONT_PATH_REQUEST: u8 = 51;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetFontPathRequest<'input> {
     pub font: Cow<'input, [Str]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
 impl<'input> SetFontPathRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -14946,12 +18199,62 @@ impl<'input> SetFontPathRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), font_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let font_qty = u16::try_from(self.font.len()).expect("`font` has too many elements");
+        let font_qty_bytes = font_qty.serialize();
+        write(&[
+            SET_FONT_PATH_REQUEST,
+            0,
+            0,
+            0,
+            font_qty_bytes[0],
+            font_qty_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let font_bytes = self.font.serialize();
+        let length_so_far = length_so_far + font_bytes.len();
+        write(&font_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.font.iter().map(|element| element.serialize().len()).sum::<usize>();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -14977,9 +18280,12 @@ impl<'input> SetFontPathRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
 impl<'input> Request for SetFontPathRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
+#[inline]
 pub fn set_font_path<'c, 'input, Conn>(conn: &'c Conn, font: &'input [Str]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -14992,8 +18298,10 @@ where
 
 /// Opcode for the GetFontPath request
 pub const GET_FONT_PATH_REQUEST: u8 = 52;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetFontPathRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 impl GetFontPathRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -15014,12 +18322,49 @@ impl GetFontPathRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_FONT_PATH_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetFontPathReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -15035,9 +18380,12 @@ impl GetFontPathRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 impl Request for GetFontPathRequest {
     type Reply = GetFontPathReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
+#[inline]
 pub fn get_font_path<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetFontPathReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -15046,12 +18394,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetFontPathReply {
     pub sequence: u16,
     pub length: u32,
     pub path: Vec<Str>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 impl TryParse for GetFontPathReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15067,11 +18417,13 @@ impl TryParse for GetFontPathReply {
         }
         let result = GetFontPathReply { sequence, length, path };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
 impl GetFontPathReply {
     /// Get the value of the `path_len` field.
     ///
@@ -15090,6 +18442,7 @@ impl GetFontPathReply {
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 53;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
 /// Creates a pixmap.
 ///
 /// Creates a pixmap. The pixmap can only be used on the same screen as `drawable`
@@ -15121,6 +18474,7 @@ pub struct CreatePixmapRequest {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
 impl CreatePixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -15158,12 +18512,66 @@ impl CreatePixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let depth_bytes = self.depth.serialize();
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        write(&[
+            CREATE_PIXMAP_REQUEST,
+            depth_bytes[0],
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -15188,9 +18596,11 @@ impl CreatePixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
 impl Request for CreatePixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
 /// Creates a pixmap.
 ///
 /// Creates a pixmap. The pixmap can only be used on the same screen as `drawable`
@@ -15214,6 +18624,7 @@ impl Request for CreatePixmapRequest {
 /// # See
 ///
 /// * `xcb_generate_id`: function
+#[inline]
 pub fn create_pixmap<Conn>(conn: &Conn, depth: u8, pid: Pixmap, drawable: Drawable, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -15230,6 +18641,7 @@ where
 
 /// Opcode for the FreePixmap request
 pub const FREE_PIXMAP_REQUEST: u8 = 54;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
 /// Destroys a pixmap.
 ///
 /// Deletes the association between the pixmap ID and the pixmap. The pixmap
@@ -15246,6 +18658,7 @@ pub const FREE_PIXMAP_REQUEST: u8 = 54;
 pub struct FreePixmapRequest {
     pub pixmap: Pixmap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
 impl FreePixmapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -15271,12 +18684,54 @@ impl FreePixmapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize();
+        write(&[
+            FREE_PIXMAP_REQUEST,
+            0,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -15294,9 +18749,11 @@ impl FreePixmapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
 impl Request for FreePixmapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
 /// Destroys a pixmap.
 ///
 /// Deletes the association between the pixmap ID and the pixmap. The pixmap
@@ -15309,6 +18766,7 @@ impl Request for FreePixmapRequest {
 /// # Errors
 ///
 /// * `Pixmap` - The specified pixmap does not exist.
+#[inline]
 pub fn free_pixmap<Conn>(conn: &Conn, pixmap: Pixmap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -15583,6 +19041,17 @@ impl std::fmt::Debug for GX  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl GX {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=15) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct LineStyle(u32);
@@ -15631,6 +19100,17 @@ impl std::fmt::Debug for LineStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl LineStyle {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CapStyle(u32);
@@ -15681,6 +19161,17 @@ impl std::fmt::Debug for CapStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl CapStyle {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct JoinStyle(u32);
@@ -15729,6 +19220,17 @@ impl std::fmt::Debug for JoinStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl JoinStyle {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct FillStyle(u32);
@@ -15779,6 +19281,17 @@ impl std::fmt::Debug for FillStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl FillStyle {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct FillRule(u32);
@@ -15825,6 +19338,17 @@ impl std::fmt::Debug for FillRule  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl FillRule {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SubwindowMode(u32);
@@ -15871,6 +19395,17 @@ impl std::fmt::Debug for SubwindowMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl SubwindowMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ArcMode(u32);
@@ -15917,7 +19452,21 @@ impl std::fmt::Debug for ArcMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ArcMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
+/// Opcode for the CreateGC request
+pub const CREATE_GC_REQUEST: u8 = 55;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 /// Auxiliary and optional information for the `create_gc` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CreateGCAux {
@@ -15945,6 +19494,7 @@ pub struct CreateGCAux {
     pub dashes: Option<u32>,
     pub arc_mode: Option<ArcMode>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl CreateGCAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -15953,6 +19503,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (function, remaining) = u32::try_parse(remaining)?;
             let function = function.into();
+            #[cfg(feature = "strict_parsing")]
+            GX::check_known(function)?;
             outer_remaining = remaining;
             Some(function)
         } else {
@@ -15994,6 +19546,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (line_style, remaining) = u32::try_parse(remaining)?;
             let line_style = line_style.into();
+            #[cfg(feature = "strict_parsing")]
+            LineStyle::check_known(line_style)?;
             outer_remaining = remaining;
             Some(line_style)
         } else {
@@ -16003,6 +19557,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (cap_style, remaining) = u32::try_parse(remaining)?;
             let cap_style = cap_style.into();
+            #[cfg(feature = "strict_parsing")]
+            CapStyle::check_known(cap_style)?;
             outer_remaining = remaining;
             Some(cap_style)
         } else {
@@ -16012,6 +19568,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (join_style, remaining) = u32::try_parse(remaining)?;
             let join_style = join_style.into();
+            #[cfg(feature = "strict_parsing")]
+            JoinStyle::check_known(join_style)?;
             outer_remaining = remaining;
             Some(join_style)
         } else {
@@ -16021,6 +19579,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (fill_style, remaining) = u32::try_parse(remaining)?;
             let fill_style = fill_style.into();
+            #[cfg(feature = "strict_parsing")]
+            FillStyle::check_known(fill_style)?;
             outer_remaining = remaining;
             Some(fill_style)
         } else {
@@ -16030,6 +19590,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (fill_rule, remaining) = u32::try_parse(remaining)?;
             let fill_rule = fill_rule.into();
+            #[cfg(feature = "strict_parsing")]
+            FillRule::check_known(fill_rule)?;
             outer_remaining = remaining;
             Some(fill_rule)
         } else {
@@ -16079,6 +19641,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (subwindow_mode, remaining) = u32::try_parse(remaining)?;
             let subwindow_mode = subwindow_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            SubwindowMode::check_known(subwindow_mode)?;
             outer_remaining = remaining;
             Some(subwindow_mode)
         } else {
@@ -16136,6 +19700,8 @@ impl CreateGCAux {
             let remaining = outer_remaining;
             let (arc_mode, remaining) = u32::try_parse(remaining)?;
             let arc_mode = arc_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            ArcMode::check_known(arc_mode)?;
             outer_remaining = remaining;
             Some(arc_mode)
         } else {
@@ -16145,6 +19711,7 @@ impl CreateGCAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl CreateGCAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -16225,6 +19792,7 @@ impl CreateGCAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl CreateGCAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -16300,6 +19868,7 @@ impl CreateGCAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl CreateGCAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -16422,8 +19991,7 @@ impl CreateGCAux {
     }
 }
 
-/// Opcode for the CreateGC request
-pub const CREATE_GC_REQUEST: u8 = 55;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 /// Creates a graphics context.
 ///
 /// Creates a graphics context. The graphics context can be used with any drawable
@@ -16453,6 +20021,7 @@ pub struct CreateGCRequest<'input> {
     pub drawable: Drawable,
     pub value_list: Cow<'input, CreateGCAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl<'input> CreateGCRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -16493,15 +20062,76 @@ impl<'input> CreateGCRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
+        let length_so_far = 0;
+        let cid_bytes = self.cid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CREATE_GC_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 16 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
     pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
         if header.major_opcode != CREATE_GC_REQUEST {
             return Err(ParseError::InvalidValue);
@@ -16529,9 +20159,11 @@ impl<'input> CreateGCRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 impl<'input> Request for CreateGCRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
 /// Creates a graphics context.
 ///
 /// Creates a graphics context. The graphics context can be used with any drawable
@@ -16555,6 +20187,7 @@ impl<'input> Request for CreateGCRequest<'input> {
 /// # See
 ///
 /// * `xcb_generate_id`: function
+#[inline]
 pub fn create_gc<'c, 'input, Conn>(conn: &'c Conn, cid: Gcontext, drawable: Drawable, value_list: &'input CreateGCAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -16567,6 +20200,9 @@ where
     request0.send(conn)
 }
 
+/// Opcode for the ChangeGC request
+pub const CHANGE_GC_REQUEST: u8 = 56;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 /// Auxiliary and optional information for the `change_gc` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ChangeGCAux {
@@ -16594,6 +20230,7 @@ pub struct ChangeGCAux {
     pub dashes: Option<u32>,
     pub arc_mode: Option<ArcMode>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl ChangeGCAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -16602,6 +20239,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (function, remaining) = u32::try_parse(remaining)?;
             let function = function.into();
+            #[cfg(feature = "strict_parsing")]
+            GX::check_known(function)?;
             outer_remaining = remaining;
             Some(function)
         } else {
@@ -16643,6 +20282,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (line_style, remaining) = u32::try_parse(remaining)?;
             let line_style = line_style.into();
+            #[cfg(feature = "strict_parsing")]
+            LineStyle::check_known(line_style)?;
             outer_remaining = remaining;
             Some(line_style)
         } else {
@@ -16652,6 +20293,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (cap_style, remaining) = u32::try_parse(remaining)?;
             let cap_style = cap_style.into();
+            #[cfg(feature = "strict_parsing")]
+            CapStyle::check_known(cap_style)?;
             outer_remaining = remaining;
             Some(cap_style)
         } else {
@@ -16661,6 +20304,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (join_style, remaining) = u32::try_parse(remaining)?;
             let join_style = join_style.into();
+            #[cfg(feature = "strict_parsing")]
+            JoinStyle::check_known(join_style)?;
             outer_remaining = remaining;
             Some(join_style)
         } else {
@@ -16670,6 +20315,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (fill_style, remaining) = u32::try_parse(remaining)?;
             let fill_style = fill_style.into();
+            #[cfg(feature = "strict_parsing")]
+            FillStyle::check_known(fill_style)?;
             outer_remaining = remaining;
             Some(fill_style)
         } else {
@@ -16679,6 +20326,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (fill_rule, remaining) = u32::try_parse(remaining)?;
             let fill_rule = fill_rule.into();
+            #[cfg(feature = "strict_parsing")]
+            FillRule::check_known(fill_rule)?;
             outer_remaining = remaining;
             Some(fill_rule)
         } else {
@@ -16728,6 +20377,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (subwindow_mode, remaining) = u32::try_parse(remaining)?;
             let subwindow_mode = subwindow_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            SubwindowMode::check_known(subwindow_mode)?;
             outer_remaining = remaining;
             Some(subwindow_mode)
         } else {
@@ -16785,6 +20436,8 @@ impl ChangeGCAux {
             let remaining = outer_remaining;
             let (arc_mode, remaining) = u32::try_parse(remaining)?;
             let arc_mode = arc_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            ArcMode::check_known(arc_mode)?;
             outer_remaining = remaining;
             Some(arc_mode)
         } else {
@@ -16794,6 +20447,7 @@ impl ChangeGCAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl ChangeGCAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -16874,6 +20528,7 @@ impl ChangeGCAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl ChangeGCAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -16949,6 +20604,7 @@ impl ChangeGCAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl ChangeGCAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -17071,8 +20727,7 @@ impl ChangeGCAux {
     }
 }
 
-/// Opcode for the ChangeGC request
-pub const CHANGE_GC_REQUEST: u8 = 56;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 /// change graphics context components.
 ///
 /// Changes the components specified by `value_mask` for the specified graphics context.
@@ -17123,6 +20778,7 @@ pub struct ChangeGCRequest<'input> {
     pub gc: Gcontext,
     pub value_list: Cow<'input, ChangeGCAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl<'input> ChangeGCRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17158,12 +20814,68 @@ impl<'input> ChangeGCRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let gc_bytes = self.gc.serialize();
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CHANGE_GC_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 12 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17191,9 +20903,11 @@ impl<'input> ChangeGCRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 impl<'input> Request for ChangeGCRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
 /// change graphics context components.
 ///
 /// Changes the components specified by `value_mask` for the specified graphics context.
@@ -17239,6 +20953,7 @@ impl<'input> Request for ChangeGCRequest<'input> {
 ///     xcb_flush(conn);
 /// }
 /// ```
+#[inline]
 pub fn change_gc<'c, 'input, Conn>(conn: &'c Conn, gc: Gcontext, value_list: &'input ChangeGCAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17252,12 +20967,14 @@ where
 
 /// Opcode for the CopyGC request
 pub const COPY_GC_REQUEST: u8 = 57;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyGCRequest {
     pub src_gc: Gcontext,
     pub dst_gc: Gcontext,
     pub value_mask: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
 impl CopyGCRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17293,12 +21010,64 @@ impl CopyGCRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let src_gc_bytes = self.src_gc.serialize();
+        let dst_gc_bytes = self.dst_gc.serialize();
+        let value_mask_bytes = self.value_mask.serialize();
+        write(&[
+            COPY_GC_REQUEST,
+            0,
+            0,
+            0,
+            src_gc_bytes[0],
+            src_gc_bytes[1],
+            src_gc_bytes[2],
+            src_gc_bytes[3],
+            dst_gc_bytes[0],
+            dst_gc_bytes[1],
+            dst_gc_bytes[2],
+            dst_gc_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17320,9 +21089,12 @@ impl CopyGCRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
 impl Request for CopyGCRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
+#[inline]
 pub fn copy_gc<Conn, A>(conn: &Conn, src_gc: Gcontext, dst_gc: Gcontext, value_mask: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17339,12 +21111,14 @@ where
 
 /// Opcode for the SetDashes request
 pub const SET_DASHES_REQUEST: u8 = 58;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetDashesRequest<'input> {
     pub gc: Gcontext,
     pub dash_offset: u16,
     pub dashes: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
 impl<'input> SetDashesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17380,12 +21154,67 @@ impl<'input> SetDashesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.dashes, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let gc_bytes = self.gc.serialize();
+        let dash_offset_bytes = self.dash_offset.serialize();
+        let dashes_len = u16::try_from(self.dashes.len()).expect("`dashes` has too many elements");
+        let dashes_len_bytes = dashes_len.serialize();
+        write(&[
+            SET_DASHES_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            dash_offset_bytes[0],
+            dash_offset_bytes[1],
+            dashes_len_bytes[0],
+            dashes_len_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.dashes.len();
+        write(&self.dashes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.dashes.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17416,9 +21245,12 @@ impl<'input> SetDashesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
 impl<'input> Request for SetDashesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
+#[inline]
 pub fn set_dashes<'c, 'input, Conn>(conn: &'c Conn, gc: Gcontext, dash_offset: u16, dashes: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17492,9 +21324,21 @@ impl std::fmt::Debug for ClipOrdering  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ClipOrdering {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=3) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetClipRectangles request
 pub const SET_CLIP_RECTANGLES_REQUEST: u8 = 59;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetClipRectanglesRequest<'input> {
     pub ordering: ClipOrdering,
@@ -17503,6 +21347,7 @@ pub struct SetClipRectanglesRequest<'input> {
     pub clip_y_origin: i16,
     pub rectangles: Cow<'input, [Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
 impl<'input> SetClipRectanglesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17539,12 +21384,68 @@ impl<'input> SetClipRectanglesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let ordering_bytes = u8::from(self.ordering).serialize();
+        let gc_bytes = self.gc.serialize();
+        let clip_x_origin_bytes = self.clip_x_origin.serialize();
+        let clip_y_origin_bytes = self.clip_y_origin.serialize();
+        write(&[
+            SET_CLIP_RECTANGLES_REQUEST,
+            ordering_bytes[0],
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            clip_x_origin_bytes[0],
+            clip_x_origin_bytes[1],
+            clip_y_origin_bytes[0],
+            clip_y_origin_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let rectangles_bytes = self.rectangles.serialize();
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        write(&rectangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.rectangles.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17555,6 +21456,8 @@ impl<'input> SetClipRectanglesRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (ordering, remaining) = u8::try_parse(remaining)?;
         let ordering = ordering.into();
+        #[cfg(feature = "strict_parsing")]
+        ClipOrdering::check_known(ordering)?;
         let _ = remaining;
         let (gc, remaining) = Gcontext::try_parse(value)?;
         let (clip_x_origin, remaining) = i16::try_parse(remaining)?;
@@ -17587,9 +21490,12 @@ impl<'input> SetClipRectanglesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
 impl<'input> Request for SetClipRectanglesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
+#[inline]
 pub fn set_clip_rectangles<'c, 'input, Conn>(conn: &'c Conn, ordering: ClipOrdering, gc: Gcontext, clip_x_origin: i16, clip_y_origin: i16, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17606,6 +21512,7 @@ where
 
 /// Opcode for the FreeGC request
 pub const FREE_GC_REQUEST: u8 = 60;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
 /// Destroys a graphics context.
 ///
 /// Destroys the specified `gc` and all associated storage.
@@ -17621,6 +21528,7 @@ pub const FREE_GC_REQUEST: u8 = 60;
 pub struct FreeGCRequest {
     pub gc: Gcontext,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
 impl FreeGCRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17646,12 +21554,54 @@ impl FreeGCRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            FREE_GC_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17669,9 +21619,11 @@ impl FreeGCRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
 impl Request for FreeGCRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
 /// Destroys a graphics context.
 ///
 /// Destroys the specified `gc` and all associated storage.
@@ -17683,6 +21635,7 @@ impl Request for FreeGCRequest {
 /// # Errors
 ///
 /// * `GContext` - The specified graphics context does not exist.
+#[inline]
 pub fn free_gc<Conn>(conn: &Conn, gc: Gcontext) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17695,6 +21648,7 @@ where
 
 /// Opcode for the ClearArea request
 pub const CLEAR_AREA_REQUEST: u8 = 61;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ClearAreaRequest {
     pub exposures: bool,
@@ -17704,6 +21658,7 @@ pub struct ClearAreaRequest {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
 impl ClearAreaRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17742,12 +21697,67 @@ impl ClearAreaRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let exposures_bytes = self.exposures.serialize();
+        let window_bytes = self.window.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        write(&[
+            CLEAR_AREA_REQUEST,
+            exposures_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -17774,9 +21784,12 @@ impl ClearAreaRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
 impl Request for ClearAreaRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
+#[inline]
 pub fn clear_area<Conn>(conn: &Conn, exposures: bool, window: Window, x: i16, y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17794,6 +21807,7 @@ where
 
 /// Opcode for the CopyArea request
 pub const COPY_AREA_REQUEST: u8 = 62;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
 /// copy areas.
 ///
 /// Copies the specified rectangle from `src_drawable` to `dst_drawable`.
@@ -17827,6 +21841,7 @@ pub struct CopyAreaRequest {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
 impl CopyAreaRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -17880,30 +21895,100 @@ impl CopyAreaRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != COPY_AREA_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (src_drawable, remaining) = Drawable::try_parse(value)?;
-        let (dst_drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
+        let length_so_far = 0;
+        let src_drawable_bytes = self.src_drawable.serialize();
+        let dst_drawable_bytes = self.dst_drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        write(&[
+            COPY_AREA_REQUEST,
+            0,
+            0,
+            0,
+            src_drawable_bytes[0],
+            src_drawable_bytes[1],
+            src_drawable_bytes[2],
+            src_drawable_bytes[3],
+            dst_drawable_bytes[0],
+            dst_drawable_bytes[1],
+            dst_drawable_bytes[2],
+            dst_drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 28;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        28
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != COPY_AREA_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let (src_drawable, remaining) = Drawable::try_parse(value)?;
+        let (dst_drawable, remaining) = Drawable::try_parse(remaining)?;
+        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (src_x, remaining) = i16::try_parse(remaining)?;
+        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let (dst_x, remaining) = i16::try_parse(remaining)?;
+        let (dst_y, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
         let (height, remaining) = u16::try_parse(remaining)?;
         let _ = remaining;
         Ok(CopyAreaRequest {
@@ -17919,9 +22004,11 @@ impl CopyAreaRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
 impl Request for CopyAreaRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
 /// copy areas.
 ///
 /// Copies the specified rectangle from `src_drawable` to `dst_drawable`.
@@ -17943,6 +22030,7 @@ impl Request for CopyAreaRequest {
 /// * `Drawable` - The specified `drawable` (Window or Pixmap) does not exist.
 /// * `GContext` - The specified graphics context does not exist.
 /// * `Match` - `src_drawable` has a different root or depth than `dst_drawable`.
+#[inline]
 pub fn copy_area<Conn>(conn: &Conn, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -17963,6 +22051,7 @@ where
 
 /// Opcode for the CopyPlane request
 pub const COPY_PLANE_REQUEST: u8 = 63;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyPlaneRequest {
     pub src_drawable: Drawable,
@@ -17976,6 +22065,7 @@ pub struct CopyPlaneRequest {
     pub height: u16,
     pub bit_plane: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
 impl CopyPlaneRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18034,12 +22124,87 @@ impl CopyPlaneRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let src_drawable_bytes = self.src_drawable.serialize();
+        let dst_drawable_bytes = self.dst_drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let bit_plane_bytes = self.bit_plane.serialize();
+        write(&[
+            COPY_PLANE_REQUEST,
+            0,
+            0,
+            0,
+            src_drawable_bytes[0],
+            src_drawable_bytes[1],
+            src_drawable_bytes[2],
+            src_drawable_bytes[3],
+            dst_drawable_bytes[0],
+            dst_drawable_bytes[1],
+            dst_drawable_bytes[2],
+            dst_drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            bit_plane_bytes[0],
+            bit_plane_bytes[1],
+            bit_plane_bytes[2],
+            bit_plane_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18075,9 +22240,12 @@ impl CopyPlaneRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
 impl Request for CopyPlaneRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
+#[inline]
 pub fn copy_plane<Conn>(conn: &Conn, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16, bit_plane: u32) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18158,9 +22326,21 @@ impl std::fmt::Debug for CoordMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl CoordMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the PolyPoint request
 pub const POLY_POINT_REQUEST: u8 = 64;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyPointRequest<'input> {
     pub coordinate_mode: CoordMode,
@@ -18168,6 +22348,7 @@ pub struct PolyPointRequest<'input> {
     pub gc: Gcontext,
     pub points: Cow<'input, [Point]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
 impl<'input> PolyPointRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18203,12 +22384,67 @@ impl<'input> PolyPointRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), points_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_POINT_REQUEST,
+            coordinate_mode_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let points_bytes = self.points.serialize();
+        let length_so_far = length_so_far + points_bytes.len();
+        write(&points_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.points.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18219,6 +22455,8 @@ impl<'input> PolyPointRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
         let coordinate_mode = coordinate_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        CoordMode::check_known(coordinate_mode)?;
         let _ = remaining;
         let (drawable, remaining) = Drawable::try_parse(value)?;
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
@@ -18248,9 +22486,12 @@ impl<'input> PolyPointRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
 impl<'input> Request for PolyPointRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
+#[inline]
 pub fn poly_point<'c, 'input, Conn>(conn: &'c Conn, coordinate_mode: CoordMode, drawable: Drawable, gc: Gcontext, points: &'input [Point]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18266,6 +22507,7 @@ where
 
 /// Opcode for the PolyLine request
 pub const POLY_LINE_REQUEST: u8 = 65;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
 /// draw lines.
 ///
 /// Draws `points_len`-1 lines between each pair of points (point[i], point[i+1])
@@ -18312,6 +22554,7 @@ pub struct PolyLineRequest<'input> {
     pub gc: Gcontext,
     pub points: Cow<'input, [Point]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
 impl<'input> PolyLineRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18347,12 +22590,67 @@ impl<'input> PolyLineRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), points_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_LINE_REQUEST,
+            coordinate_mode_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let points_bytes = self.points.serialize();
+        let length_so_far = length_so_far + points_bytes.len();
+        write(&points_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.points.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18363,6 +22661,8 @@ impl<'input> PolyLineRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
         let coordinate_mode = coordinate_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        CoordMode::check_known(coordinate_mode)?;
         let _ = remaining;
         let (drawable, remaining) = Drawable::try_parse(value)?;
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
@@ -18392,9 +22692,11 @@ impl<'input> PolyLineRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
 impl<'input> Request for PolyLineRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
 /// draw lines.
 ///
 /// Draws `points_len`-1 lines between each pair of points (point[i], point[i+1])
@@ -18434,6 +22736,7 @@ impl<'input> Request for PolyLineRequest<'input> {
 ///     xcb_flush(conn);
 /// }
 /// ```
+#[inline]
 pub fn poly_line<'c, 'input, Conn>(conn: &'c Conn, coordinate_mode: CoordMode, drawable: Drawable, gc: Gcontext, points: &'input [Point]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18493,6 +22796,7 @@ impl Serialize for Segment {
 
 /// Opcode for the PolySegment request
 pub const POLY_SEGMENT_REQUEST: u8 = 66;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
 /// draw lines.
 ///
 /// Draws multiple, unconnected lines. For each segment, a line is drawn between
@@ -18525,6 +22829,7 @@ pub struct PolySegmentRequest<'input> {
     pub gc: Gcontext,
     pub segments: Cow<'input, [Segment]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
 impl<'input> PolySegmentRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18559,12 +22864,66 @@ impl<'input> PolySegmentRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), segments_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_SEGMENT_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let segments_bytes = self.segments.serialize();
+        let length_so_far = length_so_far + segments_bytes.len();
+        write(&segments_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.segments.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18601,9 +22960,11 @@ impl<'input> PolySegmentRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
 impl<'input> Request for PolySegmentRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
 /// draw lines.
 ///
 /// Draws multiple, unconnected lines. For each segment, a line is drawn between
@@ -18630,6 +22991,7 @@ impl<'input> Request for PolySegmentRequest<'input> {
 /// * `Drawable` - The specified `drawable` does not exist.
 /// * `GContext` - The specified `gc` does not exist.
 /// * `Match` - TODO: reasons?
+#[inline]
 pub fn poly_segment<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, segments: &'input [Segment]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18644,12 +23006,14 @@ where
 
 /// Opcode for the PolyRectangle request
 pub const POLY_RECTANGLE_REQUEST: u8 = 67;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyRectangleRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
     pub rectangles: Cow<'input, [Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
 impl<'input> PolyRectangleRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18684,12 +23048,66 @@ impl<'input> PolyRectangleRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_RECTANGLE_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let rectangles_bytes = self.rectangles.serialize();
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        write(&rectangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.rectangles.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18726,9 +23144,12 @@ impl<'input> PolyRectangleRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
 impl<'input> Request for PolyRectangleRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
+#[inline]
 pub fn poly_rectangle<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18743,12 +23164,14 @@ where
 
 /// Opcode for the PolyArc request
 pub const POLY_ARC_REQUEST: u8 = 68;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyArcRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
     pub arcs: Cow<'input, [Arc]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
 impl<'input> PolyArcRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18783,12 +23206,66 @@ impl<'input> PolyArcRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), arcs_bytes.into(), padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_ARC_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let arcs_bytes = self.arcs.serialize();
+        let length_so_far = length_so_far + arcs_bytes.len();
+        write(&arcs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.arcs.len() * 12;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18825,9 +23302,12 @@ impl<'input> PolyArcRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
 impl<'input> Request for PolyArcRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
+#[inline]
 pub fn poly_arc<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, arcs: &'input [Arc]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -18899,9 +23379,21 @@ impl std::fmt::Debug for PolyShape  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl PolyShape {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the FillPoly request
 pub const FILL_POLY_REQUEST: u8 = 69;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FillPolyRequest<'input> {
     pub drawable: Drawable,
@@ -18910,6 +23402,7 @@ pub struct FillPolyRequest<'input> {
     pub coordinate_mode: CoordMode,
     pub points: Cow<'input, [Point]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
 impl<'input> FillPolyRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -18950,12 +23443,72 @@ impl<'input> FillPolyRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), points_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let shape_bytes = u8::from(self.shape).serialize();
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        write(&[
+            FILL_POLY_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            shape_bytes[0],
+            coordinate_mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let points_bytes = self.points.serialize();
+        let length_so_far = length_so_far + points_bytes.len();
+        write(&points_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.points.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -18970,8 +23523,12 @@ impl<'input> FillPolyRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let (shape, remaining) = u8::try_parse(remaining)?;
         let shape = shape.into();
+        #[cfg(feature = "strict_parsing")]
+        PolyShape::check_known(shape)?;
         let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
         let coordinate_mode = coordinate_mode.into();
+        #[cfg(feature = "strict_parsing")]
+        CoordMode::check_known(coordinate_mode)?;
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
@@ -19001,9 +23558,12 @@ impl<'input> FillPolyRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
 impl<'input> Request for FillPolyRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
+#[inline]
 pub fn fill_poly<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, shape: PolyShape, coordinate_mode: CoordMode, points: &'input [Point]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19020,6 +23580,7 @@ where
 
 /// Opcode for the PolyFillRectangle request
 pub const POLY_FILL_RECTANGLE_REQUEST: u8 = 70;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
 /// Fills rectangles.
 ///
 /// Fills the specified rectangle(s) in the order listed in the array. For any
@@ -19051,6 +23612,7 @@ pub struct PolyFillRectangleRequest<'input> {
     pub gc: Gcontext,
     pub rectangles: Cow<'input, [Rectangle]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
 impl<'input> PolyFillRectangleRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19085,12 +23647,66 @@ impl<'input> PolyFillRectangleRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_FILL_RECTANGLE_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let rectangles_bytes = self.rectangles.serialize();
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        write(&rectangles_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.rectangles.len() * 8;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19127,9 +23743,11 @@ impl<'input> PolyFillRectangleRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
 impl<'input> Request for PolyFillRectangleRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
 /// Fills rectangles.
 ///
 /// Fills the specified rectangle(s) in the order listed in the array. For any
@@ -19155,6 +23773,7 @@ impl<'input> Request for PolyFillRectangleRequest<'input> {
 /// * `Drawable` - The specified `drawable` (Window or Pixmap) does not exist.
 /// * `GContext` - The specified graphics context does not exist.
 /// * `Match` - TODO: reasons?
+#[inline]
 pub fn poly_fill_rectangle<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19169,12 +23788,14 @@ where
 
 /// Opcode for the PolyFillArc request
 pub const POLY_FILL_ARC_REQUEST: u8 = 71;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyFillArcRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
     pub arcs: Cow<'input, [Arc]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
 impl<'input> PolyFillArcRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19209,12 +23830,66 @@ impl<'input> PolyFillArcRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), arcs_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        write(&[
+            POLY_FILL_ARC_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let arcs_bytes = self.arcs.serialize();
+        let length_so_far = length_so_far + arcs_bytes.len();
+        write(&arcs_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.arcs.len() * 12;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19251,9 +23926,12 @@ impl<'input> PolyFillArcRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
 impl<'input> Request for PolyFillArcRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
+#[inline]
 pub fn poly_fill_arc<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, arcs: &'input [Arc]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19325,9 +24003,21 @@ impl std::fmt::Debug for ImageFormat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ImageFormat {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 72;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PutImageRequest<'input> {
     pub format: ImageFormat,
@@ -19341,6 +24031,7 @@ pub struct PutImageRequest<'input> {
     pub depth: u8,
     pub data: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
 impl<'input> PutImageRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19393,12 +24084,84 @@ impl<'input> PutImageRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.data, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let format_bytes = u8::from(self.format).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let left_pad_bytes = self.left_pad.serialize();
+        let depth_bytes = self.depth.serialize();
+        write(&[
+            PUT_IMAGE_REQUEST,
+            format_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            left_pad_bytes[0],
+            depth_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 24;
+        let length_so_far = length_so_far + self.data.len();
+        write(&self.data[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 24 + self.data.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19409,6 +24172,8 @@ impl<'input> PutImageRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (format, remaining) = u8::try_parse(remaining)?;
         let format = format.into();
+        #[cfg(feature = "strict_parsing")]
+        ImageFormat::check_known(format)?;
         let _ = remaining;
         let (drawable, remaining) = Drawable::try_parse(value)?;
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
@@ -19450,9 +24215,12 @@ impl<'input> PutImageRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
 impl<'input> Request for PutImageRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
+#[inline]
 pub fn put_image<'c, 'input, Conn>(conn: &'c Conn, format: ImageFormat, drawable: Drawable, gc: Gcontext, width: u16, height: u16, dst_x: i16, dst_y: i16, left_pad: u8, depth: u8, data: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19474,6 +24242,7 @@ where
 
 /// Opcode for the GetImage request
 pub const GET_IMAGE_REQUEST: u8 = 73;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetImageRequest {
     pub format: ImageFormat,
@@ -19484,6 +24253,7 @@ pub struct GetImageRequest {
     pub height: u16,
     pub plane_mask: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 impl GetImageRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19527,12 +24297,72 @@ impl GetImageRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let format_bytes = u8::from(self.format).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        write(&[
+            GET_IMAGE_REQUEST,
+            format_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19543,6 +24373,8 @@ impl GetImageRequest {
         let remaining = &[header.minor_opcode];
         let (format, remaining) = u8::try_parse(remaining)?;
         let format = format.into();
+        #[cfg(feature = "strict_parsing")]
+        ImageFormat::check_known(format)?;
         let _ = remaining;
         let (drawable, remaining) = Drawable::try_parse(value)?;
         let (x, remaining) = i16::try_parse(remaining)?;
@@ -19562,9 +24394,12 @@ impl GetImageRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 impl Request for GetImageRequest {
     type Reply = GetImageReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
+#[inline]
 pub fn get_image<Conn>(conn: &Conn, format: ImageFormat, drawable: Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32) -> Result<Cookie<'_, Conn, GetImageReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19581,6 +24416,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetImageReply {
     pub depth: u8,
@@ -19588,6 +24424,7 @@ pub struct GetImageReply {
     pub visual: Visualid,
     pub data: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -19604,11 +24441,13 @@ impl TryParse for GetImageReply {
         }
         let result = GetImageReply { depth, sequence, visual, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
 impl GetImageReply {
     /// Get the value of the `length` field.
     ///
@@ -19628,6 +24467,7 @@ impl GetImageReply {
 
 /// Opcode for the PolyText8 request
 pub const POLY_TEXT8_REQUEST: u8 = 74;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyText8Request<'input> {
     pub drawable: Drawable,
@@ -19636,6 +24476,7 @@ pub struct PolyText8Request<'input> {
     pub y: i16,
     pub items: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
 impl<'input> PolyText8Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19675,37 +24516,96 @@ impl<'input> PolyText8Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.items, padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != POLY_TEXT8_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (items, remaining) = remaining.split_at(remaining.len());
-        let _ = remaining;
-        Ok(PolyText8Request {
-            drawable,
-            gc,
-            x,
-            y,
-            items: Cow::Borrowed(items),
-        })
-    }
-    /// Clone all borrowed data in this PolyText8Request.
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            POLY_TEXT8_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.items.len();
+        write(&self.items[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.items.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != POLY_TEXT8_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let (drawable, remaining) = Drawable::try_parse(value)?;
+        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (x, remaining) = i16::try_parse(remaining)?;
+        let (y, remaining) = i16::try_parse(remaining)?;
+        let (items, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(PolyText8Request {
+            drawable,
+            gc,
+            x,
+            y,
+            items: Cow::Borrowed(items),
+        })
+    }
+    /// Clone all borrowed data in this PolyText8Request.
     pub fn into_owned(self) -> PolyText8Request<'static> {
         PolyText8Request {
             drawable: self.drawable,
@@ -19716,9 +24616,12 @@ impl<'input> PolyText8Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
 impl<'input> Request for PolyText8Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
+#[inline]
 pub fn poly_text8<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19735,6 +24638,7 @@ where
 
 /// Opcode for the PolyText16 request
 pub const POLY_TEXT16_REQUEST: u8 = 75;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyText16Request<'input> {
     pub drawable: Drawable,
@@ -19743,6 +24647,7 @@ pub struct PolyText16Request<'input> {
     pub y: i16,
     pub items: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
 impl<'input> PolyText16Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19782,12 +24687,71 @@ impl<'input> PolyText16Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.items, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            POLY_TEXT16_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.items.len();
+        write(&self.items[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.items.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19823,9 +24787,12 @@ impl<'input> PolyText16Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
 impl<'input> Request for PolyText16Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
+#[inline]
 pub fn poly_text16<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -19842,6 +24809,7 @@ where
 
 /// Opcode for the ImageText8 request
 pub const IMAGE_TEXT8_REQUEST: u8 = 76;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
 /// Draws text.
 ///
 /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -19884,6 +24852,7 @@ pub struct ImageText8Request<'input> {
     pub y: i16,
     pub string: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
 impl<'input> ImageText8Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -19925,12 +24894,73 @@ impl<'input> ImageText8Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.string, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let string_len = u8::try_from(self.string.len()).expect("`string` has too many elements");
+        let string_len_bytes = string_len.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            IMAGE_TEXT8_REQUEST,
+            string_len_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.string.len();
+        write(&self.string[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.string.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -19966,9 +24996,11 @@ impl<'input> ImageText8Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
 impl<'input> Request for ImageText8Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
 /// Draws text.
 ///
 /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -20003,6 +25035,7 @@ impl<'input> Request for ImageText8Request<'input> {
 /// # See
 ///
 /// * `ImageText16`: request
+#[inline]
 pub fn image_text8<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20019,6 +25052,7 @@ where
 
 /// Opcode for the ImageText16 request
 pub const IMAGE_TEXT16_REQUEST: u8 = 77;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
 /// Draws text.
 ///
 /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -20062,6 +25096,7 @@ pub struct ImageText16Request<'input> {
     pub y: i16,
     pub string: Cow<'input, [Char2b]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
 impl<'input> ImageText16Request<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20104,12 +25139,74 @@ impl<'input> ImageText16Request<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), string_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let string_len = u8::try_from(self.string.len()).expect("`string` has too many elements");
+        let string_len_bytes = string_len.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            IMAGE_TEXT16_REQUEST,
+            string_len_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        let string_bytes = self.string.serialize();
+        let length_so_far = length_so_far + string_bytes.len();
+        write(&string_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.string.len() * 2;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20145,9 +25242,11 @@ impl<'input> ImageText16Request<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
 impl<'input> Request for ImageText16Request<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
 /// Draws text.
 ///
 /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -20183,6 +25282,7 @@ impl<'input> Request for ImageText16Request<'input> {
 /// # See
 ///
 /// * `ImageText8`: request
+#[inline]
 pub fn image_text16<'c, 'input, Conn>(conn: &'c Conn, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &'input [Char2b]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20254,9 +25354,21 @@ impl std::fmt::Debug for ColormapAlloc  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ColormapAlloc {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the CreateColormap request
 pub const CREATE_COLORMAP_REQUEST: u8 = 78;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateColormapRequest {
     pub alloc: ColormapAlloc,
@@ -20264,6 +25376,7 @@ pub struct CreateColormapRequest {
     pub window: Window,
     pub visual: Visualid,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
 impl CreateColormapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20300,12 +25413,65 @@ impl CreateColormapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let alloc_bytes = u8::from(self.alloc).serialize();
+        let mid_bytes = self.mid.serialize();
+        let window_bytes = self.window.serialize();
+        let visual_bytes = self.visual.serialize();
+        write(&[
+            CREATE_COLORMAP_REQUEST,
+            alloc_bytes[0],
+            0,
+            0,
+            mid_bytes[0],
+            mid_bytes[1],
+            mid_bytes[2],
+            mid_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20316,6 +25482,8 @@ impl CreateColormapRequest {
         let remaining = &[header.minor_opcode];
         let (alloc, remaining) = u8::try_parse(remaining)?;
         let alloc = alloc.into();
+        #[cfg(feature = "strict_parsing")]
+        ColormapAlloc::check_known(alloc)?;
         let _ = remaining;
         let (mid, remaining) = Colormap::try_parse(value)?;
         let (window, remaining) = Window::try_parse(remaining)?;
@@ -20329,9 +25497,12 @@ impl CreateColormapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
 impl Request for CreateColormapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
+#[inline]
 pub fn create_colormap<Conn>(conn: &Conn, alloc: ColormapAlloc, mid: Colormap, window: Window, visual: Visualid) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20347,10 +25518,12 @@ where
 
 /// Opcode for the FreeColormap request
 pub const FREE_COLORMAP_REQUEST: u8 = 79;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FreeColormapRequest {
     pub cmap: Colormap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
 impl FreeColormapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20376,12 +25549,54 @@ impl FreeColormapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        write(&[
+            FREE_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20399,9 +25614,12 @@ impl FreeColormapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
 impl Request for FreeColormapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
+#[inline]
 pub fn free_colormap<Conn>(conn: &Conn, cmap: Colormap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20414,11 +25632,13 @@ where
 
 /// Opcode for the CopyColormapAndFree request
 pub const COPY_COLORMAP_AND_FREE_REQUEST: u8 = 80;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CopyColormapAndFreeRequest {
     pub mid: Colormap,
     pub src_cmap: Colormap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
 impl CopyColormapAndFreeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20449,17 +25669,64 @@ impl CopyColormapAndFreeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
+        let length_so_far = 0;
+        let mid_bytes = self.mid.serialize();
+        let src_cmap_bytes = self.src_cmap.serialize();
+        write(&[
+            COPY_COLORMAP_AND_FREE_REQUEST,
+            0,
+            0,
+            0,
+            mid_bytes[0],
+            mid_bytes[1],
+            mid_bytes[2],
+            mid_bytes[3],
+            src_cmap_bytes[0],
+            src_cmap_bytes[1],
+            src_cmap_bytes[2],
+            src_cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
     }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != COPY_COLORMAP_AND_FREE_REQUEST {
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != COPY_COLORMAP_AND_FREE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
@@ -20474,9 +25741,12 @@ impl CopyColormapAndFreeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
 impl Request for CopyColormapAndFreeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
+#[inline]
 pub fn copy_colormap_and_free<Conn>(conn: &Conn, mid: Colormap, src_cmap: Colormap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20490,10 +25760,12 @@ where
 
 /// Opcode for the InstallColormap request
 pub const INSTALL_COLORMAP_REQUEST: u8 = 81;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InstallColormapRequest {
     pub cmap: Colormap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
 impl InstallColormapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20519,12 +25791,54 @@ impl InstallColormapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        write(&[
+            INSTALL_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20542,9 +25856,12 @@ impl InstallColormapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
 impl Request for InstallColormapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
+#[inline]
 pub fn install_colormap<Conn>(conn: &Conn, cmap: Colormap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20557,10 +25874,12 @@ where
 
 /// Opcode for the UninstallColormap request
 pub const UNINSTALL_COLORMAP_REQUEST: u8 = 82;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UninstallColormapRequest {
     pub cmap: Colormap,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
 impl UninstallColormapRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20586,12 +25905,54 @@ impl UninstallColormapRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        write(&[
+            UNINSTALL_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20609,9 +25970,12 @@ impl UninstallColormapRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
 impl Request for UninstallColormapRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
+#[inline]
 pub fn uninstall_colormap<Conn>(conn: &Conn, cmap: Colormap) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20624,10 +25988,12 @@ where
 
 /// Opcode for the ListInstalledColormaps request
 pub const LIST_INSTALLED_COLORMAPS_REQUEST: u8 = 83;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListInstalledColormapsRequest {
     pub window: Window,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 impl ListInstalledColormapsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20653,12 +26019,54 @@ impl ListInstalledColormapsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        write(&[
+            LIST_INSTALLED_COLORMAPS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListInstalledColormapsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20676,9 +26084,12 @@ impl ListInstalledColormapsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 impl Request for ListInstalledColormapsRequest {
     type Reply = ListInstalledColormapsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
+#[inline]
 pub fn list_installed_colormaps<Conn>(conn: &Conn, window: Window) -> Result<Cookie<'_, Conn, ListInstalledColormapsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20689,12 +26100,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListInstalledColormapsReply {
     pub sequence: u16,
     pub length: u32,
     pub cmaps: Vec<Colormap>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 impl TryParse for ListInstalledColormapsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -20710,11 +26123,13 @@ impl TryParse for ListInstalledColormapsReply {
         }
         let result = ListInstalledColormapsReply { sequence, length, cmaps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
 impl ListInstalledColormapsReply {
     /// Get the value of the `cmaps_len` field.
     ///
@@ -20733,6 +26148,7 @@ impl ListInstalledColormapsReply {
 
 /// Opcode for the AllocColor request
 pub const ALLOC_COLOR_REQUEST: u8 = 84;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 /// Allocate a color.
 ///
 /// Allocates a read-only colormap entry corresponding to the closest RGB value
@@ -20758,6 +26174,7 @@ pub struct AllocColorRequest {
     pub green: u16,
     pub blue: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 impl AllocColorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20794,12 +26211,65 @@ impl AllocColorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        let red_bytes = self.red.serialize();
+        let green_bytes = self.green.serialize();
+        let blue_bytes = self.blue.serialize();
+        write(&[
+            ALLOC_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            red_bytes[0],
+            red_bytes[1],
+            green_bytes[0],
+            green_bytes[1],
+            blue_bytes[0],
+            blue_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AllocColorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20824,9 +26294,11 @@ impl AllocColorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 impl Request for AllocColorRequest {
     type Reply = AllocColorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 /// Allocate a color.
 ///
 /// Allocates a read-only colormap entry corresponding to the closest RGB value
@@ -20845,6 +26317,7 @@ impl Request for AllocColorRequest {
 /// # Errors
 ///
 /// * `Colormap` - The specified colormap `cmap` does not exist.
+#[inline]
 pub fn alloc_color<Conn>(conn: &Conn, cmap: Colormap, red: u16, green: u16, blue: u16) -> Result<Cookie<'_, Conn, AllocColorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20858,6 +26331,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllocColorReply {
     pub sequence: u16,
@@ -20867,6 +26341,7 @@ pub struct AllocColorReply {
     pub blue: u16,
     pub pixel: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
 impl TryParse for AllocColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -20884,7 +26359,8 @@ impl TryParse for AllocColorReply {
         }
         let result = AllocColorReply { sequence, length, red, green, blue, pixel };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -20892,11 +26368,13 @@ impl TryParse for AllocColorReply {
 
 /// Opcode for the AllocNamedColor request
 pub const ALLOC_NAMED_COLOR_REQUEST: u8 = 85;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AllocNamedColorRequest<'input> {
     pub cmap: Colormap,
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 impl<'input> AllocNamedColorRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -20931,12 +26409,66 @@ impl<'input> AllocNamedColorRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            ALLOC_NAMED_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AllocNamedColorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -20965,9 +26497,12 @@ impl<'input> AllocNamedColorRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 impl<'input> Request for AllocNamedColorRequest<'input> {
     type Reply = AllocNamedColorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
+#[inline]
 pub fn alloc_named_color<'c, 'input, Conn>(conn: &'c Conn, cmap: Colormap, name: &'input [u8]) -> Result<Cookie<'c, Conn, AllocNamedColorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -20979,6 +26514,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllocNamedColorReply {
     pub sequence: u16,
@@ -20991,6 +26527,7 @@ pub struct AllocNamedColorReply {
     pub visual_green: u16,
     pub visual_blue: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
 impl TryParse for AllocNamedColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21010,7 +26547,8 @@ impl TryParse for AllocNamedColorReply {
         }
         let result = AllocNamedColorReply { sequence, length, pixel, exact_red, exact_green, exact_blue, visual_red, visual_green, visual_blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -21018,6 +26556,7 @@ impl TryParse for AllocNamedColorReply {
 
 /// Opcode for the AllocColorCells request
 pub const ALLOC_COLOR_CELLS_REQUEST: u8 = 86;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllocColorCellsRequest {
     pub contiguous: bool,
@@ -21025,6 +26564,7 @@ pub struct AllocColorCellsRequest {
     pub colors: u16,
     pub planes: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 impl AllocColorCellsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21057,12 +26597,61 @@ impl AllocColorCellsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let contiguous_bytes = self.contiguous.serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let colors_bytes = self.colors.serialize();
+        let planes_bytes = self.planes.serialize();
+        write(&[
+            ALLOC_COLOR_CELLS_REQUEST,
+            contiguous_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            colors_bytes[0],
+            colors_bytes[1],
+            planes_bytes[0],
+            planes_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AllocColorCellsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21085,9 +26674,12 @@ impl AllocColorCellsRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 impl Request for AllocColorCellsRequest {
     type Reply = AllocColorCellsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
+#[inline]
 pub fn alloc_color_cells<Conn>(conn: &Conn, contiguous: bool, cmap: Colormap, colors: u16, planes: u16) -> Result<Cookie<'_, Conn, AllocColorCellsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21101,6 +26693,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AllocColorCellsReply {
     pub sequence: u16,
@@ -21108,6 +26701,7 @@ pub struct AllocColorCellsReply {
     pub pixels: Vec<u32>,
     pub masks: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 impl TryParse for AllocColorCellsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21125,11 +26719,13 @@ impl TryParse for AllocColorCellsReply {
         }
         let result = AllocColorCellsReply { sequence, length, pixels, masks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
 impl AllocColorCellsReply {
     /// Get the value of the `pixels_len` field.
     ///
@@ -21161,6 +26757,7 @@ impl AllocColorCellsReply {
 
 /// Opcode for the AllocColorPlanes request
 pub const ALLOC_COLOR_PLANES_REQUEST: u8 = 87;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AllocColorPlanesRequest {
     pub contiguous: bool,
@@ -21170,6 +26767,7 @@ pub struct AllocColorPlanesRequest {
     pub greens: u16,
     pub blues: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 impl AllocColorPlanesRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21208,12 +26806,67 @@ impl AllocColorPlanesRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let contiguous_bytes = self.contiguous.serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let colors_bytes = self.colors.serialize();
+        let reds_bytes = self.reds.serialize();
+        let greens_bytes = self.greens.serialize();
+        let blues_bytes = self.blues.serialize();
+        write(&[
+            ALLOC_COLOR_PLANES_REQUEST,
+            contiguous_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            colors_bytes[0],
+            colors_bytes[1],
+            reds_bytes[0],
+            reds_bytes[1],
+            greens_bytes[0],
+            greens_bytes[1],
+            blues_bytes[0],
+            blues_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 16;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        16
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, AllocColorPlanesReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21240,9 +26893,12 @@ impl AllocColorPlanesRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 impl Request for AllocColorPlanesRequest {
     type Reply = AllocColorPlanesReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
+#[inline]
 pub fn alloc_color_planes<Conn>(conn: &Conn, contiguous: bool, cmap: Colormap, colors: u16, reds: u16, greens: u16, blues: u16) -> Result<Cookie<'_, Conn, AllocColorPlanesReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21258,6 +26914,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AllocColorPlanesReply {
     pub sequence: u16,
@@ -21267,6 +26924,7 @@ pub struct AllocColorPlanesReply {
     pub blue_mask: u32,
     pub pixels: Vec<u32>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 impl TryParse for AllocColorPlanesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21286,11 +26944,13 @@ impl TryParse for AllocColorPlanesReply {
         }
         let result = AllocColorPlanesReply { sequence, length, red_mask, green_mask, blue_mask, pixels };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
 impl AllocColorPlanesReply {
     /// Get the value of the `pixels_len` field.
     ///
@@ -21309,12 +26969,14 @@ impl AllocColorPlanesReply {
 
 /// Opcode for the FreeColors request
 pub const FREE_COLORS_REQUEST: u8 = 88;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FreeColorsRequest<'input> {
     pub cmap: Colormap,
     pub plane_mask: u32,
     pub pixels: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
 impl<'input> FreeColorsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21349,12 +27011,66 @@ impl<'input> FreeColorsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), pixels_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        write(&[
+            FREE_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let pixels_bytes = self.pixels.serialize();
+        let length_so_far = length_so_far + pixels_bytes.len();
+        write(&pixels_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.pixels.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21391,9 +27107,12 @@ impl<'input> FreeColorsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
 impl<'input> Request for FreeColorsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
+#[inline]
 pub fn free_colors<'c, 'input, Conn>(conn: &'c Conn, cmap: Colormap, plane_mask: u32, pixels: &'input [u32]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21467,7 +27186,7 @@ impl std::fmt::Debug for ColorFlag  {
 }
 bitmask_binop!(ColorFlag, u8);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Coloritem {
     pub pixel: u32,
     pub red: u16,
@@ -21475,6 +27194,17 @@ pub struct Coloritem {
     pub blue: u16,
     pub flags: u8,
 }
+impl std::fmt::Debug for Coloritem {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Coloritem")
+            .field("pixel", &self.pixel)
+            .field("red", &self.red)
+            .field("green", &self.green)
+            .field("blue", &self.blue)
+            .field("flags", &ColorFlag::from(self.flags))
+            .finish()
+    }
+}
 impl TryParse for Coloritem {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (pixel, remaining) = u32::try_parse(remaining)?;
@@ -21523,11 +27253,13 @@ impl Serialize for Coloritem {
 
 /// Opcode for the StoreColors request
 pub const STORE_COLORS_REQUEST: u8 = 89;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StoreColorsRequest<'input> {
     pub cmap: Colormap,
     pub items: Cow<'input, [Coloritem]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
 impl<'input> StoreColorsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21557,24 +27289,73 @@ impl<'input> StoreColorsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), items_bytes.into(), padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != STORE_COLORS_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let mut remaining = remaining;
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        write(&[
+            STORE_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let items_bytes = self.items.serialize();
+        let length_so_far = length_so_far + items_bytes.len();
+        write(&items_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.items.len() * 12;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != STORE_COLORS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut items = Vec::new();
         while !remaining.is_empty() {
@@ -21596,9 +27377,12 @@ impl<'input> StoreColorsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
 impl<'input> Request for StoreColorsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
+#[inline]
 pub fn store_colors<'c, 'input, Conn>(conn: &'c Conn, cmap: Colormap, items: &'input [Coloritem]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21612,6 +27396,7 @@ where
 
 /// Opcode for the StoreNamedColor request
 pub const STORE_NAMED_COLOR_REQUEST: u8 = 90;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StoreNamedColorRequest<'input> {
     pub flags: u8,
@@ -21619,6 +27404,7 @@ pub struct StoreNamedColorRequest<'input> {
     pub pixel: u32,
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
 impl<'input> StoreNamedColorRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21659,12 +27445,72 @@ impl<'input> StoreNamedColorRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let flags_bytes = self.flags.serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let pixel_bytes = self.pixel.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            STORE_NAMED_COLOR_REQUEST,
+            flags_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            pixel_bytes[0],
+            pixel_bytes[1],
+            pixel_bytes[2],
+            pixel_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 16;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 16 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21698,9 +27544,12 @@ impl<'input> StoreNamedColorRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
 impl<'input> Request for StoreNamedColorRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
+#[inline]
 pub fn store_named_color<'c, 'input, Conn, A>(conn: &'c Conn, flags: A, cmap: Colormap, pixel: u32, name: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21760,11 +27609,13 @@ impl Serialize for Rgb {
 
 /// Opcode for the QueryColors request
 pub const QUERY_COLORS_REQUEST: u8 = 91;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryColorsRequest<'input> {
     pub cmap: Colormap,
     pub pixels: Cow<'input, [u32]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 impl<'input> QueryColorsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21794,12 +27645,61 @@ impl<'input> QueryColorsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), pixels_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        write(&[
+            QUERY_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let pixels_bytes = self.pixels.serialize();
+        let length_so_far = length_so_far + pixels_bytes.len();
+        write(&pixels_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.pixels.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryColorsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21833,9 +27733,12 @@ impl<'input> QueryColorsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 impl<'input> Request for QueryColorsRequest<'input> {
     type Reply = QueryColorsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
+#[inline]
 pub fn query_colors<'c, 'input, Conn>(conn: &'c Conn, cmap: Colormap, pixels: &'input [u32]) -> Result<Cookie<'c, Conn, QueryColorsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21847,12 +27750,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryColorsReply {
     pub sequence: u16,
     pub length: u32,
     pub colors: Vec<Rgb>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 impl TryParse for QueryColorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21868,11 +27773,13 @@ impl TryParse for QueryColorsReply {
         }
         let result = QueryColorsReply { sequence, length, colors };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
 impl QueryColorsReply {
     /// Get the value of the `colors_len` field.
     ///
@@ -21891,11 +27798,13 @@ impl QueryColorsReply {
 
 /// Opcode for the LookupColor request
 pub const LOOKUP_COLOR_REQUEST: u8 = 92;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LookupColorRequest<'input> {
     pub cmap: Colormap,
     pub name: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 impl<'input> LookupColorRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -21930,12 +27839,66 @@ impl<'input> LookupColorRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cmap_bytes = self.cmap.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            LOOKUP_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, LookupColorReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -21964,9 +27927,12 @@ impl<'input> LookupColorRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 impl<'input> Request for LookupColorRequest<'input> {
     type Reply = LookupColorReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
+#[inline]
 pub fn lookup_color<'c, 'input, Conn>(conn: &'c Conn, cmap: Colormap, name: &'input [u8]) -> Result<Cookie<'c, Conn, LookupColorReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -21978,6 +27944,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LookupColorReply {
     pub sequence: u16,
@@ -21989,6 +27956,7 @@ pub struct LookupColorReply {
     pub visual_green: u16,
     pub visual_blue: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
 impl TryParse for LookupColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -22007,7 +27975,8 @@ impl TryParse for LookupColorReply {
         }
         let result = LookupColorReply { sequence, length, exact_red, exact_green, exact_blue, visual_red, visual_green, visual_blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -22071,6 +28040,7 @@ impl std::fmt::Debug for PixmapEnum  {
 
 /// Opcode for the CreateCursor request
 pub const CREATE_CURSOR_REQUEST: u8 = 93;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CreateCursorRequest {
     pub cid: Cursor,
@@ -22085,6 +28055,7 @@ pub struct CreateCursorRequest {
     pub x: u16,
     pub y: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
 impl CreateCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -22144,12 +28115,88 @@ impl CreateCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cid_bytes = self.cid.serialize();
+        let source_bytes = self.source.serialize();
+        let mask_bytes = self.mask.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        write(&[
+            CREATE_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -22187,9 +28234,12 @@ impl CreateCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
 impl Request for CreateCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
+#[inline]
 pub fn create_cursor<Conn, A>(conn: &Conn, cid: Cursor, source: Pixmap, mask: A, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -22270,6 +28320,7 @@ impl std::fmt::Debug for FontEnum  {
 
 /// Opcode for the CreateGlyphCursor request
 pub const CREATE_GLYPH_CURSOR_REQUEST: u8 = 94;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
 /// create cursor.
 ///
 /// Creates a cursor from a font glyph. X provides a set of standard cursor shapes
@@ -22317,6 +28368,7 @@ pub struct CreateGlyphCursorRequest {
     pub back_green: u16,
     pub back_blue: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
 impl CreateGlyphCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -22376,12 +28428,88 @@ impl CreateGlyphCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cid_bytes = self.cid.serialize();
+        let source_font_bytes = self.source_font.serialize();
+        let mask_font_bytes = self.mask_font.serialize();
+        let source_char_bytes = self.source_char.serialize();
+        let mask_char_bytes = self.mask_char.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        write(&[
+            CREATE_GLYPH_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_font_bytes[0],
+            source_font_bytes[1],
+            source_font_bytes[2],
+            source_font_bytes[3],
+            mask_font_bytes[0],
+            mask_font_bytes[1],
+            mask_font_bytes[2],
+            mask_font_bytes[3],
+            source_char_bytes[0],
+            source_char_bytes[1],
+            mask_char_bytes[0],
+            mask_char_bytes[1],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 32;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        32
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -22419,9 +28547,11 @@ impl CreateGlyphCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
 impl Request for CreateGlyphCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
 /// create cursor.
 ///
 /// Creates a cursor from a font glyph. X provides a set of standard cursor shapes
@@ -22455,6 +28585,7 @@ impl Request for CreateGlyphCursorRequest {
 /// * `Alloc` - The X server could not allocate the requested resources (no memory?).
 /// * `Font` - The specified `source_font` or `mask_font` does not exist.
 /// * `Value` - Either `source_char` or `mask_char` are not defined in `source_font` or `mask_font`, respectively.
+#[inline]
 pub fn create_glyph_cursor<Conn, A>(conn: &Conn, cid: Cursor, source_font: Font, mask_font: A, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -22479,6 +28610,7 @@ where
 
 /// Opcode for the FreeCursor request
 pub const FREE_CURSOR_REQUEST: u8 = 95;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
 /// Deletes a cursor.
 ///
 /// Deletes the association between the cursor resource ID and the specified
@@ -22495,6 +28627,7 @@ pub const FREE_CURSOR_REQUEST: u8 = 95;
 pub struct FreeCursorRequest {
     pub cursor: Cursor,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
 impl FreeCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -22520,22 +28653,64 @@ impl FreeCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != FREE_CURSOR_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
+        let length_so_far = 0;
+        let cursor_bytes = self.cursor.serialize();
+        write(&[
+            FREE_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != FREE_CURSOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
         let (cursor, remaining) = Cursor::try_parse(value)?;
         let _ = remaining;
         Ok(FreeCursorRequest {
@@ -22543,9 +28718,11 @@ impl FreeCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
 impl Request for FreeCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
 /// Deletes a cursor.
 ///
 /// Deletes the association between the cursor resource ID and the specified
@@ -22558,6 +28735,7 @@ impl Request for FreeCursorRequest {
 /// # Errors
 ///
 /// * `Cursor` - The specified cursor does not exist.
+#[inline]
 pub fn free_cursor<Conn>(conn: &Conn, cursor: Cursor) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -22570,6 +28748,7 @@ where
 
 /// Opcode for the RecolorCursor request
 pub const RECOLOR_CURSOR_REQUEST: u8 = 96;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RecolorCursorRequest {
     pub cursor: Cursor,
@@ -22580,6 +28759,7 @@ pub struct RecolorCursorRequest {
     pub back_green: u16,
     pub back_blue: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
 impl RecolorCursorRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -22623,12 +28803,72 @@ impl RecolorCursorRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let cursor_bytes = self.cursor.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        write(&[
+            RECOLOR_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 20;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        20
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -22658,9 +28898,12 @@ impl RecolorCursorRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
 impl Request for RecolorCursorRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
+#[inline]
 pub fn recolor_cursor<Conn>(conn: &Conn, cursor: Cursor, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -22736,9 +28979,21 @@ impl std::fmt::Debug for QueryShapeOf  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl QueryShapeOf {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the QueryBestSize request
 pub const QUERY_BEST_SIZE_REQUEST: u8 = 97;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryBestSizeRequest {
     pub class: QueryShapeOf,
@@ -22746,6 +29001,7 @@ pub struct QueryBestSizeRequest {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 impl QueryBestSizeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -22778,12 +29034,61 @@ impl QueryBestSizeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let class_bytes = u8::from(self.class).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        write(&[
+            QUERY_BEST_SIZE_REQUEST,
+            class_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryBestSizeReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -22794,6 +29099,8 @@ impl QueryBestSizeRequest {
         let remaining = &[header.minor_opcode];
         let (class, remaining) = u8::try_parse(remaining)?;
         let class = class.into();
+        #[cfg(feature = "strict_parsing")]
+        QueryShapeOf::check_known(class)?;
         let _ = remaining;
         let (drawable, remaining) = Drawable::try_parse(value)?;
         let (width, remaining) = u16::try_parse(remaining)?;
@@ -22807,9 +29114,12 @@ impl QueryBestSizeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 impl Request for QueryBestSizeRequest {
     type Reply = QueryBestSizeReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
+#[inline]
 pub fn query_best_size<Conn>(conn: &Conn, class: QueryShapeOf, drawable: Drawable, width: u16, height: u16) -> Result<Cookie<'_, Conn, QueryBestSizeReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -22823,6 +29133,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QueryBestSizeReply {
     pub sequence: u16,
@@ -22830,6 +29141,7 @@ pub struct QueryBestSizeReply {
     pub width: u16,
     pub height: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
 impl TryParse for QueryBestSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -22844,7 +29156,8 @@ impl TryParse for QueryBestSizeReply {
         }
         let result = QueryBestSizeReply { sequence, length, width, height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -22907,12 +29220,61 @@ impl<'input> QueryExtensionRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.name, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        write(&[
+            QUERY_EXTENSION_REQUEST,
+            0,
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.name.len();
+        write(&self.name[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.name.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, QueryExtensionReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -22963,6 +29325,7 @@ impl<'input> Request for QueryExtensionRequest<'input> {
 ///
 /// * `xdpyinfo`: program
 /// * `xcb_get_extension_data`: function
+#[inline]
 pub fn query_extension<'c, 'input, Conn>(conn: &'c Conn, name: &'input [u8]) -> Result<Cookie<'c, Conn, QueryExtensionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23004,7 +29367,8 @@ impl TryParse for QueryExtensionReply {
         }
         let result = QueryExtensionReply { sequence, length, present, major_opcode, first_event, first_error };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -23012,8 +29376,10 @@ impl TryParse for QueryExtensionReply {
 
 /// Opcode for the ListExtensions request
 pub const LIST_EXTENSIONS_REQUEST: u8 = 99;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListExtensionsRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 impl ListExtensionsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23034,12 +29400,49 @@ impl ListExtensionsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            LIST_EXTENSIONS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListExtensionsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23055,9 +29458,12 @@ impl ListExtensionsRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 impl Request for ListExtensionsRequest {
     type Reply = ListExtensionsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
+#[inline]
 pub fn list_extensions<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListExtensionsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23066,12 +29472,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListExtensionsReply {
     pub sequence: u16,
     pub length: u32,
     pub names: Vec<Str>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 impl TryParse for ListExtensionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23086,11 +29494,13 @@ impl TryParse for ListExtensionsReply {
         }
         let result = ListExtensionsReply { sequence, length, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
 impl ListExtensionsReply {
     /// Get the value of the `names_len` field.
     ///
@@ -23109,6 +29519,7 @@ impl ListExtensionsReply {
 
 /// Opcode for the ChangeKeyboardMapping request
 pub const CHANGE_KEYBOARD_MAPPING_REQUEST: u8 = 100;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeKeyboardMappingRequest<'input> {
     pub keycode_count: u8,
@@ -23116,6 +29527,7 @@ pub struct ChangeKeyboardMappingRequest<'input> {
     pub keysyms_per_keycode: u8,
     pub keysyms: Cow<'input, [Keysym]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
 impl<'input> ChangeKeyboardMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23148,12 +29560,64 @@ impl<'input> ChangeKeyboardMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), keysyms_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let keycode_count_bytes = self.keycode_count.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let keysyms_per_keycode_bytes = self.keysyms_per_keycode.serialize();
+        write(&[
+            CHANGE_KEYBOARD_MAPPING_REQUEST,
+            keycode_count_bytes[0],
+            0,
+            0,
+            first_keycode_bytes[0],
+            keysyms_per_keycode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(self.keysyms.len(), usize::try_from(u32::from(self.keycode_count).checked_mul(u32::from(self.keysyms_per_keycode)).unwrap()).unwrap(), "`keysyms` has an incorrect length");
+        let keysyms_bytes = self.keysyms.serialize();
+        let length_so_far = length_so_far + keysyms_bytes.len();
+        write(&keysyms_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.keysyms.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23186,9 +29650,12 @@ impl<'input> ChangeKeyboardMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
 impl<'input> Request for ChangeKeyboardMappingRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
+#[inline]
 pub fn change_keyboard_mapping<'c, 'input, Conn>(conn: &'c Conn, keycode_count: u8, first_keycode: Keycode, keysyms_per_keycode: u8, keysyms: &'input [Keysym]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23204,11 +29671,13 @@ where
 
 /// Opcode for the GetKeyboardMapping request
 pub const GET_KEYBOARD_MAPPING_REQUEST: u8 = 101;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetKeyboardMappingRequest {
     pub first_keycode: Keycode,
     pub count: u8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 impl GetKeyboardMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23235,12 +29704,55 @@ impl GetKeyboardMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let count_bytes = self.count.serialize();
+        write(&[
+            GET_KEYBOARD_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+            first_keycode_bytes[0],
+            count_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetKeyboardMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23260,9 +29772,12 @@ impl GetKeyboardMappingRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 impl Request for GetKeyboardMappingRequest {
     type Reply = GetKeyboardMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
+#[inline]
 pub fn get_keyboard_mapping<Conn>(conn: &Conn, first_keycode: Keycode, count: u8) -> Result<Cookie<'_, Conn, GetKeyboardMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23274,12 +29789,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetKeyboardMappingReply {
     pub keysyms_per_keycode: u8,
     pub sequence: u16,
     pub keysyms: Vec<Keysym>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 impl TryParse for GetKeyboardMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23294,11 +29811,13 @@ impl TryParse for GetKeyboardMappingReply {
         }
         let result = GetKeyboardMappingReply { keysyms_per_keycode, sequence, keysyms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
 impl GetKeyboardMappingReply {
     /// Get the value of the `length` field.
     ///
@@ -23431,6 +29950,17 @@ impl std::fmt::Debug for LedMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl LedMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct AutoRepeatMode(u32);
@@ -23479,7 +30009,21 @@ impl std::fmt::Debug for AutoRepeatMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl AutoRepeatMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
+/// Opcode for the ChangeKeyboardControl request
+pub const CHANGE_KEYBOARD_CONTROL_REQUEST: u8 = 102;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 /// Auxiliary and optional information for the `change_keyboard_control` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ChangeKeyboardControlAux {
@@ -23492,6 +30036,7 @@ pub struct ChangeKeyboardControlAux {
     pub key: Option<Keycode32>,
     pub auto_repeat_mode: Option<AutoRepeatMode>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl ChangeKeyboardControlAux {
     fn try_parse(value: &[u8], value_mask: u32) -> Result<(Self, &[u8]), ParseError> {
         let switch_expr = value_mask;
@@ -23540,6 +30085,8 @@ impl ChangeKeyboardControlAux {
             let remaining = outer_remaining;
             let (led_mode, remaining) = u32::try_parse(remaining)?;
             let led_mode = led_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            LedMode::check_known(led_mode)?;
             outer_remaining = remaining;
             Some(led_mode)
         } else {
@@ -23557,6 +30104,8 @@ impl ChangeKeyboardControlAux {
             let remaining = outer_remaining;
             let (auto_repeat_mode, remaining) = u32::try_parse(remaining)?;
             let auto_repeat_mode = auto_repeat_mode.into();
+            #[cfg(feature = "strict_parsing")]
+            AutoRepeatMode::check_known(auto_repeat_mode)?;
             outer_remaining = remaining;
             Some(auto_repeat_mode)
         } else {
@@ -23566,6 +30115,7 @@ impl ChangeKeyboardControlAux {
         Ok((result, outer_remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl ChangeKeyboardControlAux {
     #[allow(dead_code)]
     fn serialize(&self, value_mask: u32) -> Vec<u8> {
@@ -23601,6 +30151,7 @@ impl ChangeKeyboardControlAux {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl ChangeKeyboardControlAux {
     fn switch_expr(&self) -> u32 {
         let mut expr_value = 0;
@@ -23631,6 +30182,7 @@ impl ChangeKeyboardControlAux {
         expr_value
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl ChangeKeyboardControlAux {
     /// Create a new instance with all fields unset / not present.
     pub fn new() -> Self {
@@ -23678,12 +30230,12 @@ impl ChangeKeyboardControlAux {
     }
 }
 
-/// Opcode for the ChangeKeyboardControl request
-pub const CHANGE_KEYBOARD_CONTROL_REQUEST: u8 = 102;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeKeyboardControlRequest<'input> {
     pub value_list: Cow<'input, ChangeKeyboardControlAux>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl<'input> ChangeKeyboardControlRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23714,12 +30266,63 @@ impl<'input> ChangeKeyboardControlRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        write(&[
+            CHANGE_KEYBOARD_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let value_list_bytes = self.value_list.serialize(value_mask);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        write(&value_list_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let value_mask = self.value_list.switch_expr();
+        let length_so_far = 8 + self.value_list.serialize(value_mask).len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23744,9 +30347,12 @@ impl<'input> ChangeKeyboardControlRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
 impl<'input> Request for ChangeKeyboardControlRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
+#[inline]
 pub fn change_keyboard_control<'c, 'input, Conn>(conn: &'c Conn, value_list: &'input ChangeKeyboardControlAux) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23759,8 +30365,10 @@ where
 
 /// Opcode for the GetKeyboardControl request
 pub const GET_KEYBOARD_CONTROL_REQUEST: u8 = 103;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetKeyboardControlRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 impl GetKeyboardControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23781,12 +30389,49 @@ impl GetKeyboardControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_KEYBOARD_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetKeyboardControlReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23802,9 +30447,12 @@ impl GetKeyboardControlRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 impl Request for GetKeyboardControlRequest {
     type Reply = GetKeyboardControlReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
+#[inline]
 pub fn get_keyboard_control<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetKeyboardControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23813,6 +30461,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetKeyboardControlReply {
     pub global_auto_repeat: AutoRepeatMode,
@@ -23825,6 +30474,7 @@ pub struct GetKeyboardControlReply {
     pub bell_duration: u16,
     pub auto_repeats: [u8; 32],
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
 impl TryParse for GetKeyboardControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23844,9 +30494,12 @@ impl TryParse for GetKeyboardControlReply {
             return Err(ParseError::InvalidValue);
         }
         let global_auto_repeat = global_auto_repeat.into();
+        #[cfg(feature = "strict_parsing")]
+        AutoRepeatMode::check_known(global_auto_repeat)?;
         let result = GetKeyboardControlReply { global_auto_repeat, sequence, length, led_mask, key_click_percent, bell_percent, bell_pitch, bell_duration, auto_repeats };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -23854,10 +30507,12 @@ impl TryParse for GetKeyboardControlReply {
 
 /// Opcode for the Bell request
 pub const BELL_REQUEST: u8 = 104;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BellRequest {
     pub percent: i8,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
 impl BellRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23879,12 +30534,50 @@ impl BellRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let percent_bytes = self.percent.serialize();
+        write(&[
+            BELL_REQUEST,
+            percent_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23901,9 +30594,12 @@ impl BellRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
 impl Request for BellRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
+#[inline]
 pub fn bell<Conn>(conn: &Conn, percent: i8) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -23916,6 +30612,7 @@ where
 
 /// Opcode for the ChangePointerControl request
 pub const CHANGE_POINTER_CONTROL_REQUEST: u8 = 105;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChangePointerControlRequest {
     pub acceleration_numerator: i16,
@@ -23924,6 +30621,7 @@ pub struct ChangePointerControlRequest {
     pub do_acceleration: bool,
     pub do_threshold: bool,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
 impl ChangePointerControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -23957,12 +30655,62 @@ impl ChangePointerControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let acceleration_numerator_bytes = self.acceleration_numerator.serialize();
+        let acceleration_denominator_bytes = self.acceleration_denominator.serialize();
+        let threshold_bytes = self.threshold.serialize();
+        let do_acceleration_bytes = self.do_acceleration.serialize();
+        let do_threshold_bytes = self.do_threshold.serialize();
+        write(&[
+            CHANGE_POINTER_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+            acceleration_numerator_bytes[0],
+            acceleration_numerator_bytes[1],
+            acceleration_denominator_bytes[0],
+            acceleration_denominator_bytes[1],
+            threshold_bytes[0],
+            threshold_bytes[1],
+            do_acceleration_bytes[0],
+            do_threshold_bytes[0],
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -23988,9 +30736,12 @@ impl ChangePointerControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
 impl Request for ChangePointerControlRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
+#[inline]
 pub fn change_pointer_control<Conn>(conn: &Conn, acceleration_numerator: i16, acceleration_denominator: i16, threshold: i16, do_acceleration: bool, do_threshold: bool) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24007,8 +30758,10 @@ where
 
 /// Opcode for the GetPointerControl request
 pub const GET_POINTER_CONTROL_REQUEST: u8 = 106;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPointerControlRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 impl GetPointerControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24029,12 +30782,49 @@ impl GetPointerControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_POINTER_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPointerControlReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24050,9 +30840,12 @@ impl GetPointerControlRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 impl Request for GetPointerControlRequest {
     type Reply = GetPointerControlReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
+#[inline]
 pub fn get_pointer_control<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetPointerControlReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24061,6 +30854,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPointerControlReply {
     pub sequence: u16,
@@ -24069,6 +30863,7 @@ pub struct GetPointerControlReply {
     pub acceleration_denominator: u16,
     pub threshold: u16,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
 impl TryParse for GetPointerControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24085,7 +30880,8 @@ impl TryParse for GetPointerControlReply {
         }
         let result = GetPointerControlReply { sequence, length, acceleration_numerator, acceleration_denominator, threshold };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -24150,6 +30946,17 @@ impl std::fmt::Debug for Blanking  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Blanking {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Exposures(u8);
@@ -24210,9 +31017,21 @@ impl std::fmt::Debug for Exposures  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Exposures {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetScreenSaver request
 pub const SET_SCREEN_SAVER_REQUEST: u8 = 107;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetScreenSaverRequest {
     pub timeout: i16,
@@ -24220,6 +31039,7 @@ pub struct SetScreenSaverRequest {
     pub prefer_blanking: Blanking,
     pub allow_exposures: Exposures,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
 impl SetScreenSaverRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24252,12 +31072,61 @@ impl SetScreenSaverRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let timeout_bytes = self.timeout.serialize();
+        let interval_bytes = self.interval.serialize();
+        let prefer_blanking_bytes = u8::from(self.prefer_blanking).serialize();
+        let allow_exposures_bytes = u8::from(self.allow_exposures).serialize();
+        write(&[
+            SET_SCREEN_SAVER_REQUEST,
+            0,
+            0,
+            0,
+            timeout_bytes[0],
+            timeout_bytes[1],
+            interval_bytes[0],
+            interval_bytes[1],
+            prefer_blanking_bytes[0],
+            allow_exposures_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 12;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        12
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24272,8 +31141,12 @@ impl SetScreenSaverRequest {
         let (interval, remaining) = i16::try_parse(remaining)?;
         let (prefer_blanking, remaining) = u8::try_parse(remaining)?;
         let prefer_blanking = prefer_blanking.into();
+        #[cfg(feature = "strict_parsing")]
+        Blanking::check_known(prefer_blanking)?;
         let (allow_exposures, remaining) = u8::try_parse(remaining)?;
         let allow_exposures = allow_exposures.into();
+        #[cfg(feature = "strict_parsing")]
+        Exposures::check_known(allow_exposures)?;
         let _ = remaining;
         Ok(SetScreenSaverRequest {
             timeout,
@@ -24283,9 +31156,12 @@ impl SetScreenSaverRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
 impl Request for SetScreenSaverRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
+#[inline]
 pub fn set_screen_saver<Conn>(conn: &Conn, timeout: i16, interval: i16, prefer_blanking: Blanking, allow_exposures: Exposures) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24301,8 +31177,10 @@ where
 
 /// Opcode for the GetScreenSaver request
 pub const GET_SCREEN_SAVER_REQUEST: u8 = 108;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenSaverRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 impl GetScreenSaverRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24323,12 +31201,49 @@ impl GetScreenSaverRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_SCREEN_SAVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenSaverReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24344,9 +31259,12 @@ impl GetScreenSaverRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 impl Request for GetScreenSaverRequest {
     type Reply = GetScreenSaverReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
+#[inline]
 pub fn get_screen_saver<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetScreenSaverReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24355,6 +31273,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetScreenSaverReply {
     pub sequence: u16,
@@ -24364,6 +31283,7 @@ pub struct GetScreenSaverReply {
     pub prefer_blanking: Blanking,
     pub allow_exposures: Exposures,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
 impl TryParse for GetScreenSaverReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24380,10 +31300,15 @@ impl TryParse for GetScreenSaverReply {
             return Err(ParseError::InvalidValue);
         }
         let prefer_blanking = prefer_blanking.into();
+        #[cfg(feature = "strict_parsing")]
+        Blanking::check_known(prefer_blanking)?;
         let allow_exposures = allow_exposures.into();
+        #[cfg(feature = "strict_parsing")]
+        Exposures::check_known(allow_exposures)?;
         let result = GetScreenSaverReply { sequence, length, timeout, interval, prefer_blanking, allow_exposures };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -24446,6 +31371,17 @@ impl std::fmt::Debug for HostMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl HostMode {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Family(u8);
@@ -24510,15 +31446,28 @@ impl std::fmt::Debug for Family  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl Family {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2 | 5 | 6) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ChangeHosts request
 pub const CHANGE_HOSTS_REQUEST: u8 = 109;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangeHostsRequest<'input> {
     pub mode: HostMode,
     pub family: Family,
     pub address: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
 impl<'input> ChangeHostsRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24550,12 +31499,63 @@ impl<'input> ChangeHostsRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.address, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        let family_bytes = u8::from(self.family).serialize();
+        let address_len = u16::try_from(self.address.len()).expect("`address` has too many elements");
+        let address_len_bytes = address_len.serialize();
+        write(&[
+            CHANGE_HOSTS_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            family_bytes[0],
+            0,
+            address_len_bytes[0],
+            address_len_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 8;
+        let length_so_far = length_so_far + self.address.len();
+        write(&self.address[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 8 + self.address.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24566,9 +31566,13 @@ impl<'input> ChangeHostsRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        HostMode::check_known(mode)?;
         let _ = remaining;
         let (family, remaining) = u8::try_parse(value)?;
         let family = family.into();
+        #[cfg(feature = "strict_parsing")]
+        Family::check_known(family)?;
         let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
         let (address_len, remaining) = u16::try_parse(remaining)?;
         let (address, remaining) = crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?)?;
@@ -24588,9 +31592,12 @@ impl<'input> ChangeHostsRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
 impl<'input> Request for ChangeHostsRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
+#[inline]
 pub fn change_hosts<'c, 'input, Conn>(conn: &'c Conn, mode: HostMode, family: Family, address: &'input [u8]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24616,11 +31623,10 @@ impl TryParse for Host {
         let (address_len, remaining) = u16::try_parse(remaining)?;
         let (address, remaining) = crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?)?;
         let address = address.to_vec();
-        // Align offset to multiple of 4
-        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
-        let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::wire::align(value, remaining, 4)?;
         let family = family.into();
+        #[cfg(feature = "strict_parsing")]
+        Family::check_known(family)?;
         let result = Host { family, address };
         Ok((result, remaining))
     }
@@ -24660,8 +31666,10 @@ impl Host {
 
 /// Opcode for the ListHosts request
 pub const LIST_HOSTS_REQUEST: u8 = 110;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ListHostsRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 impl ListHostsRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24682,12 +31690,49 @@ impl ListHostsRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            LIST_HOSTS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, ListHostsReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24703,9 +31748,12 @@ impl ListHostsRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 impl Request for ListHostsRequest {
     type Reply = ListHostsReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
+#[inline]
 pub fn list_hosts<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, ListHostsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24714,6 +31762,7 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListHostsReply {
     pub mode: AccessControl,
@@ -24721,6 +31770,7 @@ pub struct ListHostsReply {
     pub length: u32,
     pub hosts: Vec<Host>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 impl TryParse for ListHostsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24735,13 +31785,17 @@ impl TryParse for ListHostsReply {
             return Err(ParseError::InvalidValue);
         }
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        AccessControl::check_known(mode)?;
         let result = ListHostsReply { mode, sequence, length, hosts };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
 impl ListHostsReply {
     /// Get the value of the `hosts_len` field.
     ///
@@ -24815,13 +31869,26 @@ impl std::fmt::Debug for AccessControl  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl AccessControl {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetAccessControl request
 pub const SET_ACCESS_CONTROL_REQUEST: u8 = 111;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetAccessControlRequest {
     pub mode: AccessControl,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
 impl SetAccessControlRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24843,12 +31910,50 @@ impl SetAccessControlRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        write(&[
+            SET_ACCESS_CONTROL_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24859,6 +31964,8 @@ impl SetAccessControlRequest {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        AccessControl::check_known(mode)?;
         let _ = remaining;
         let _ = value;
         Ok(SetAccessControlRequest {
@@ -24866,9 +31973,12 @@ impl SetAccessControlRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
 impl Request for SetAccessControlRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
+#[inline]
 pub fn set_access_control<Conn>(conn: &Conn, mode: AccessControl) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -24938,13 +32048,26 @@ impl std::fmt::Debug for CloseDown  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl CloseDown {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetCloseDownMode request
 pub const SET_CLOSE_DOWN_MODE_REQUEST: u8 = 112;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetCloseDownModeRequest {
     pub mode: CloseDown,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
 impl SetCloseDownModeRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -24966,12 +32089,50 @@ impl SetCloseDownModeRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        write(&[
+            SET_CLOSE_DOWN_MODE_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -24982,6 +32143,8 @@ impl SetCloseDownModeRequest {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        CloseDown::check_known(mode)?;
         let _ = remaining;
         let _ = value;
         Ok(SetCloseDownModeRequest {
@@ -24989,9 +32152,12 @@ impl SetCloseDownModeRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
 impl Request for SetCloseDownModeRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
+#[inline]
 pub fn set_close_down_mode<Conn>(conn: &Conn, mode: CloseDown) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25060,6 +32226,7 @@ impl std::fmt::Debug for Kill  {
 
 /// Opcode for the KillClient request
 pub const KILL_CLIENT_REQUEST: u8 = 113;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
 /// kills a client.
 ///
 /// Forces a close down of the client that created the specified `resource`.
@@ -25083,6 +32250,7 @@ pub const KILL_CLIENT_REQUEST: u8 = 113;
 pub struct KillClientRequest {
     pub resource: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
 impl KillClientRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25108,12 +32276,54 @@ impl KillClientRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let resource_bytes = self.resource.serialize();
+        write(&[
+            KILL_CLIENT_REQUEST,
+            0,
+            0,
+            0,
+            resource_bytes[0],
+            resource_bytes[1],
+            resource_bytes[2],
+            resource_bytes[3],
+        ]);
+        let length_so_far = length_so_far + 8;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        8
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -25131,9 +32341,11 @@ impl KillClientRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
 impl Request for KillClientRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
 /// kills a client.
 ///
 /// Forces a close down of the client that created the specified `resource`.
@@ -25153,6 +32365,7 @@ impl Request for KillClientRequest {
 /// # See
 ///
 /// * `xkill`: program
+#[inline]
 pub fn kill_client<Conn, A>(conn: &Conn, resource: A) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25167,12 +32380,14 @@ where
 
 /// Opcode for the RotateProperties request
 pub const ROTATE_PROPERTIES_REQUEST: u8 = 114;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RotatePropertiesRequest<'input> {
     pub window: Window,
     pub delta: i16,
     pub atoms: Cow<'input, [Atom]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
 impl<'input> RotatePropertiesRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25209,29 +32424,85 @@ impl<'input> RotatePropertiesRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), atoms_bytes.into(), padding0.into()], vec![])
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != ROTATE_PROPERTIES_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (atoms_len, remaining) = u16::try_parse(remaining)?;
-        let (delta, remaining) = i16::try_parse(remaining)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?)?;
-        let _ = remaining;
-        Ok(RotatePropertiesRequest {
-            window,
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize();
+        let atoms_len = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        let atoms_len_bytes = atoms_len.serialize();
+        let delta_bytes = self.delta.serialize();
+        write(&[
+            ROTATE_PROPERTIES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            atoms_len_bytes[0],
+            atoms_len_bytes[1],
+            delta_bytes[0],
+            delta_bytes[1],
+        ]);
+        let length_so_far = length_so_far + 12;
+        let atoms_bytes = self.atoms.serialize();
+        let length_so_far = length_so_far + atoms_bytes.len();
+        write(&atoms_bytes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 12 + self.atoms.len() * 4;
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != ROTATE_PROPERTIES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let (window, remaining) = Window::try_parse(value)?;
+        let (atoms_len, remaining) = u16::try_parse(remaining)?;
+        let (delta, remaining) = i16::try_parse(remaining)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?)?;
+        let _ = remaining;
+        Ok(RotatePropertiesRequest {
+            window,
             delta,
             atoms: Cow::Owned(atoms),
         })
@@ -25245,9 +32516,12 @@ impl<'input> RotatePropertiesRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
 impl<'input> Request for RotatePropertiesRequest<'input> {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
+#[inline]
 pub fn rotate_properties<'c, 'input, Conn>(conn: &'c Conn, window: Window, delta: i16, atoms: &'input [Atom]) -> Result<VoidCookie<'c, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25317,13 +32591,26 @@ impl std::fmt::Debug for ScreenSaver  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl ScreenSaver {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0 | 1) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the ForceScreenSaver request
 pub const FORCE_SCREEN_SAVER_REQUEST: u8 = 115;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ForceScreenSaverRequest {
     pub mode: ScreenSaver,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
 impl ForceScreenSaverRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25345,12 +32632,50 @@ impl ForceScreenSaverRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize();
+        write(&[
+            FORCE_SCREEN_SAVER_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_without_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -25361,6 +32686,8 @@ impl ForceScreenSaverRequest {
         let remaining = &[header.minor_opcode];
         let (mode, remaining) = u8::try_parse(remaining)?;
         let mode = mode.into();
+        #[cfg(feature = "strict_parsing")]
+        ScreenSaver::check_known(mode)?;
         let _ = remaining;
         let _ = value;
         Ok(ForceScreenSaverRequest {
@@ -25368,9 +32695,12 @@ impl ForceScreenSaverRequest {
         })
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
 impl Request for ForceScreenSaverRequest {
     type Reply = ();
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
+#[inline]
 pub fn force_screen_saver<Conn>(conn: &Conn, mode: ScreenSaver) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25440,13 +32770,26 @@ impl std::fmt::Debug for MappingStatus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+#[cfg(feature = "strict_parsing")]
+impl MappingStatus {
+    /// Fail with `ParseError::InvalidValue` if this is not a known value.
+    pub(crate) fn check_known(self) -> Result<(), ParseError> {
+        if matches!(self.0, 0..=2) {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidValue)
+        }
+    }
+}
 
 /// Opcode for the SetPointerMapping request
 pub const SET_POINTER_MAPPING_REQUEST: u8 = 116;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetPointerMappingRequest<'input> {
     pub map: Cow<'input, [u8]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 impl<'input> SetPointerMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25472,12 +32815,57 @@ impl<'input> SetPointerMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.map, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        let map_len = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        let map_len_bytes = map_len.serialize();
+        write(&[
+            SET_POINTER_MAPPING_REQUEST,
+            map_len_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        let length_so_far = length_so_far + self.map.len();
+        write(&self.map[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 4 + self.map.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetPointerMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -25501,9 +32889,12 @@ impl<'input> SetPointerMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 impl<'input> Request for SetPointerMappingRequest<'input> {
     type Reply = SetPointerMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
+#[inline]
 pub fn set_pointer_mapping<'c, 'input, Conn>(conn: &'c Conn, map: &'input [u8]) -> Result<Cookie<'c, Conn, SetPointerMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25514,12 +32905,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetPointerMappingReply {
     pub status: MappingStatus,
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
 impl TryParse for SetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -25531,9 +32924,12 @@ impl TryParse for SetPointerMappingReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        MappingStatus::check_known(status)?;
         let result = SetPointerMappingReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -25541,8 +32937,10 @@ impl TryParse for SetPointerMappingReply {
 
 /// Opcode for the GetPointerMapping request
 pub const GET_POINTER_MAPPING_REQUEST: u8 = 117;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetPointerMappingRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 impl GetPointerMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25563,12 +32961,49 @@ impl GetPointerMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_POINTER_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetPointerMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -25584,9 +33019,12 @@ impl GetPointerMappingRequest {
         )
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 impl Request for GetPointerMappingRequest {
     type Reply = GetPointerMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
+#[inline]
 pub fn get_pointer_mapping<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetPointerMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25595,12 +33033,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetPointerMappingReply {
     pub sequence: u16,
     pub length: u32,
     pub map: Vec<u8>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 impl TryParse for GetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -25616,11 +33056,13 @@ impl TryParse for GetPointerMappingReply {
         }
         let result = GetPointerMappingReply { sequence, length, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
 impl GetPointerMappingReply {
     /// Get the value of the `map_len` field.
     ///
@@ -25709,10 +33151,12 @@ impl std::fmt::Debug for MapIndex  {
 
 /// Opcode for the SetModifierMapping request
 pub const SET_MODIFIER_MAPPING_REQUEST: u8 = 118;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetModifierMappingRequest<'input> {
     pub keycodes: Cow<'input, [Keycode]>,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 impl<'input> SetModifierMappingRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     fn serialize<Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25739,12 +33183,58 @@ impl<'input> SetModifierMappingRequest<'input> {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into(), self.keycodes, padding0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        assert_eq!(self.keycodes.len() % 8, 0, "`keycodes` has an incorrect length, must be a multiple of 8");
+        let keycodes_per_modifier = u8::try_from(self.keycodes.len() / 8).expect("`keycodes` has too many elements");
+        let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize();
+        write(&[
+            SET_MODIFIER_MAPPING_REQUEST,
+            keycodes_per_modifier_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        let length_so_far = length_so_far + self.keycodes.len();
+        write(&self.keycodes[..]);
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        write(padding0);
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        let length_so_far = 4 + self.keycodes.len();
+        length_so_far + (4 - (length_so_far % 4)) % 4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, SetModifierMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
         let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+        let slices = [IoSlice::new(&bytes[0]), IoSlice::new(&bytes[1]), IoSlice::new(&bytes[2])];
         conn.send_request_with_reply(&slices, fds)
     }
     /// Parse this request given its header, its body, and any fds that go along with it
@@ -25768,9 +33258,12 @@ impl<'input> SetModifierMappingRequest<'input> {
         }
     }
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 impl<'input> Request for SetModifierMappingRequest<'input> {
     type Reply = SetModifierMappingReply;
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
+#[inline]
 pub fn set_modifier_mapping<'c, 'input, Conn>(conn: &'c Conn, keycodes: &'input [Keycode]) -> Result<Cookie<'c, Conn, SetModifierMappingReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -25781,12 +33274,14 @@ where
     request0.send(conn)
 }
 
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SetModifierMappingReply {
     pub status: MappingStatus,
     pub sequence: u16,
     pub length: u32,
 }
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
 impl TryParse for SetModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -25798,9 +33293,12 @@ impl TryParse for SetModifierMappingReply {
             return Err(ParseError::InvalidValue);
         }
         let status = status.into();
+        #[cfg(feature = "strict_parsing")]
+        MappingStatus::check_known(status)?;
         let result = SetModifierMappingReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
             .ok_or(ParseError::InsufficientData)?;
         Ok((result, remaining))
     }
@@ -25808,8 +33306,10 @@ impl TryParse for SetModifierMappingReply {
 
 /// Opcode for the GetModifierMapping request
 pub const GET_MODIFIER_MAPPING_REQUEST: u8 = 119;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GetModifierMappingRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
 impl GetModifierMappingRequest {
     /// Serialize this request into bytes for the provided connection
     fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
@@ -25830,139 +33330,663 @@ impl GetModifierMappingRequest {
         request0[2..4].copy_from_slice(&length.to_ne_bytes());
         (vec![request0.into()], vec![])
     }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            GET_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
     pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetModifierMappingReply>, ConnectionError>
     where
         Conn: RequestConnection + ?Sized,
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_with_reply(&slices, fds)
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_with_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != GET_MODIFIER_MAPPING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let _ = value;
+        Ok(GetModifierMappingRequest
+        )
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+impl Request for GetModifierMappingRequest {
+    type Reply = GetModifierMappingReply;
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+#[inline]
+pub fn get_modifier_mapping<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetModifierMappingReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let request0 = GetModifierMappingRequest;
+    request0.send(conn)
+}
+
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetModifierMappingReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub keycodes: Vec<Keycode>,
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+impl TryParse for GetModifierMappingReply {
+    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (keycodes, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let keycodes = keycodes.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetModifierMappingReply { sequence, length, keycodes };
+        let _ = remaining;
+        let end = crate::wire::length_field_end(32, length)?;
+        let remaining = initial_value.get(end..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+impl GetModifierMappingReply {
+    /// Get the value of the `keycodes_per_modifier` field.
+    ///
+    /// The `keycodes_per_modifier` field is used as the length field of the `keycodes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn keycodes_per_modifier(&self) -> u8 {
+        self.keycodes.len()
+            .checked_div(8).unwrap()
+            .try_into().unwrap()
+    }
+}
+
+/// Opcode for the NoOperation request
+pub const NO_OPERATION_REQUEST: u8 = 127;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoOperationRequest;
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
+impl NoOperationRequest {
+    /// Serialize this request into bytes for the provided connection
+    fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let _ = conn;
+        let length_so_far = 0;
+        let mut request0 = vec![
+            NO_OPERATION_REQUEST,
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        (vec![request0.into()], vec![])
+    }
+    /// Pass the bytes of this request to `write`, without the length field
+    fn write_to(self, write: &mut dyn FnMut(&[u8]))
+    {
+        let length_so_far = 0;
+        write(&[
+            NO_OPERATION_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = length_so_far + 4;
+        assert_eq!(length_so_far % 4, 0);
+    }
+    /// Serialize this request and append it to `bytes`.
+    ///
+    /// Requests that are too long for the normal length field use the BIG-REQUESTS encoding.
+    pub fn serialize_into(self, bytes: &mut Vec<u8>) {
+        crate::x11_utils::serialize_request_into(bytes, |write| self.write_to(write));
+    }
+    /// Serialize this request into the beginning of `buffer`.
+    ///
+    /// Returns the number of bytes that were written. Nothing is written if the request does
+    /// not fit into `buffer`.
+    pub fn serialize_into_slice(self, buffer: &mut [u8]) -> Result<usize, crate::errors::BufferTooSmall> {
+        let wire_length = self.wire_length();
+        crate::x11_utils::serialize_request_into_slice(buffer, wire_length, |write| {
+            self.write_to(write)
+        })
+    }
+    /// Get the length in bytes of this request when it is sent to the X11 server.
+    ///
+    /// A request can only be sent if this is at most [`RequestConnection::maximum_request_bytes()`].
+    /// The four bytes that the BIG-REQUESTS extension adds to long requests are not included.
+    pub fn wire_length(&self) -> usize {
+        4
+    }
+    #[inline]
+    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+    where
+        Conn: RequestConnection + ?Sized,
+    {
+        let (bytes, fds) = self.serialize(conn);
+        let slices = [IoSlice::new(&bytes[0])];
+        conn.send_request_without_reply(&slices, fds)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.major_opcode != NO_OPERATION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let remaining = &[header.minor_opcode];
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        let _ = value;
+        Ok(NoOperationRequest
+        )
+    }
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
+impl Request for NoOperationRequest {
+    type Reply = ();
+}
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
+#[inline]
+pub fn no_operation<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let request0 = NoOperationRequest;
+    request0.send(conn)
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Window`].
+///
+/// Instances of this struct represent a `Window` that is freed in `Drop` with [`destroy_window`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct WindowWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Window);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> WindowWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_window(conn: &'c C, id: Window) -> Self {
+        WindowWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn window(&self) -> Window {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_window(self) -> Window {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> WindowWrapper<'c, C> {
+    /// Create a new `Window` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_window`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_window_and_get_cookie<'input>(conn: &'c C, depth: u8, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: WindowClass, visual: Visualid, value_list: &'input CreateWindowAux) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let wid = conn.generate_id()?;
+        let cookie = create_window(conn, depth, wid, parent, x, y, width, height, border_width, class, visual, value_list)?;
+        Ok((Self::for_window(conn, wid), cookie))
+    }
+
+    /// Create a new `Window` and return a wrapper.
+    ///
+    /// Like [`Self::create_window_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_window<'input>(conn: &'c C, depth: u8, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: WindowClass, visual: Visualid, value_list: &'input CreateWindowAux) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_window_and_get_cookie(conn, depth, parent, x, y, width, height, border_width, class, visual, value_list)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&WindowWrapper<'_, C>> for Window {
+    fn from(from: &WindowWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for WindowWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = destroy_window(self.0, self.1);
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Pixmap`].
+///
+/// Instances of this struct represent a `Pixmap` that is freed in `Drop` with [`free_pixmap`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct PixmapWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Pixmap);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> PixmapWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_pixmap(conn: &'c C, id: Pixmap) -> Self {
+        PixmapWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn pixmap(&self) -> Pixmap {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_pixmap(self) -> Pixmap {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> PixmapWrapper<'c, C> {
+    /// Create a new `Pixmap` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_pixmap`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_pixmap_and_get_cookie(conn: &'c C, depth: u8, drawable: Drawable, width: u16, height: u16) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let pid = conn.generate_id()?;
+        let cookie = create_pixmap(conn, depth, pid, drawable, width, height)?;
+        Ok((Self::for_pixmap(conn, pid), cookie))
+    }
+
+    /// Create a new `Pixmap` and return a wrapper.
+    ///
+    /// Like [`Self::create_pixmap_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_pixmap(conn: &'c C, depth: u8, drawable: Drawable, width: u16, height: u16) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_pixmap_and_get_cookie(conn, depth, drawable, width, height)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&PixmapWrapper<'_, C>> for Pixmap {
+    fn from(from: &PixmapWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for PixmapWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_pixmap(self.0, self.1);
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Font`].
+///
+/// Instances of this struct represent a `Font` that is freed in `Drop` with [`close_font`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct FontWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Font);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> FontWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_font(conn: &'c C, id: Font) -> Self {
+        FontWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn font(&self) -> Font {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_font(self) -> Font {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> FontWrapper<'c, C> {
+    /// Create a new `Font` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`open_font`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn open_font_and_get_cookie<'input>(conn: &'c C, name: &'input [u8]) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let fid = conn.generate_id()?;
+        let cookie = open_font(conn, fid, name)?;
+        Ok((Self::for_font(conn, fid), cookie))
+    }
+
+    /// Create a new `Font` and return a wrapper.
+    ///
+    /// Like [`Self::open_font_and_get_cookie`], but errors from the request are handled as events.
+    pub fn open_font<'input>(conn: &'c C, name: &'input [u8]) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::open_font_and_get_cookie(conn, name)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&FontWrapper<'_, C>> for Font {
+    fn from(from: &FontWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for FontWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = close_font(self.0, self.1);
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Gcontext`].
+///
+/// Instances of this struct represent a `Gcontext` that is freed in `Drop` with [`free_gc`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct GcontextWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Gcontext);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> GcontextWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_gcontext(conn: &'c C, id: Gcontext) -> Self {
+        GcontextWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn gcontext(&self) -> Gcontext {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_gcontext(self) -> Gcontext {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> GcontextWrapper<'c, C> {
+    /// Create a new `Gcontext` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_gc`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_gc_and_get_cookie<'input>(conn: &'c C, drawable: Drawable, value_list: &'input CreateGCAux) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let cid = conn.generate_id()?;
+        let cookie = create_gc(conn, cid, drawable, value_list)?;
+        Ok((Self::for_gcontext(conn, cid), cookie))
+    }
+
+    /// Create a new `Gcontext` and return a wrapper.
+    ///
+    /// Like [`Self::create_gc_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_gc<'input>(conn: &'c C, drawable: Drawable, value_list: &'input CreateGCAux) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_gc_and_get_cookie(conn, drawable, value_list)?.0)
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&GcontextWrapper<'_, C>> for Gcontext {
+    fn from(from: &GcontextWrapper<'_, C>) -> Self {
+        from.1
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for GcontextWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_gc(self.0, self.1);
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Colormap`].
+///
+/// Instances of this struct represent a `Colormap` that is freed in `Drop` with [`free_colormap`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct ColormapWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Colormap);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> ColormapWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_colormap(conn: &'c C, id: Colormap) -> Self {
+        ColormapWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn colormap(&self) -> Colormap {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_colormap(self) -> Colormap {
+        let id = self.1;
+        std::mem::forget(self);
+        id
+    }
+}
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> ColormapWrapper<'c, C> {
+    /// Create a new `Colormap` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_colormap`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_colormap_and_get_cookie(conn: &'c C, alloc: ColormapAlloc, window: Window, visual: Visualid) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    {
+        let mid = conn.generate_id()?;
+        let cookie = create_colormap(conn, alloc, mid, window, visual)?;
+        Ok((Self::for_colormap(conn, mid), cookie))
     }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != GET_MODIFIER_MAPPING_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let _ = value;
-        Ok(GetModifierMappingRequest
-        )
+
+    /// Create a new `Colormap` and return a wrapper.
+    ///
+    /// Like [`Self::create_colormap_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_colormap(conn: &'c C, alloc: ColormapAlloc, window: Window, visual: Visualid) -> Result<Self, crate::errors::ReplyOrIdError>
+    {
+        Ok(Self::create_colormap_and_get_cookie(conn, alloc, window, visual)?.0)
     }
 }
-impl Request for GetModifierMappingRequest {
-    type Reply = GetModifierMappingReply;
-}
-pub fn get_modifier_mapping<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetModifierMappingReply>, ConnectionError>
-where
-    Conn: RequestConnection + ?Sized,
-{
-    let request0 = GetModifierMappingRequest;
-    request0.send(conn)
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&ColormapWrapper<'_, C>> for Colormap {
+    fn from(from: &ColormapWrapper<'_, C>) -> Self {
+        from.1
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GetModifierMappingReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub keycodes: Vec<Keycode>,
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for ColormapWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_colormap(self.0, self.1);
+    }
 }
-impl TryParse for GetModifierMappingReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (keycodes, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let keycodes = keycodes.to_vec();
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let result = GetModifierMappingReply { sequence, length, keycodes };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
+
+#[cfg(not(feature = "request-pruning"))]
+/// A RAII-like wrapper around a [`Cursor`].
+///
+/// Instances of this struct represent a `Cursor` that is freed in `Drop` with [`free_cursor`].
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that the X11
+/// connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct CursorWrapper<'c, C: RequestConnection + ?Sized>(&'c C, Cursor);
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: RequestConnection + ?Sized> CursorWrapper<'c, C> {
+    /// Assume ownership of the given resource and free it in `Drop`.
+    pub fn for_cursor(conn: &'c C, id: Cursor) -> Self {
+        CursorWrapper(conn, id)
+    }
+
+    /// Get the XID of the wrapped resource.
+    pub fn cursor(&self) -> Cursor {
+        self.1
+    }
+
+    /// Take the XID of the wrapped resource without freeing the resource.
+    pub fn into_cursor(self) -> Cursor {
+        let id = self.1;
+        std::mem::forget(self);
+        id
     }
 }
-impl GetModifierMappingReply {
-    /// Get the value of the `keycodes_per_modifier` field.
-    ///
-    /// The `keycodes_per_modifier` field is used as the length field of the `keycodes` field.
-    /// This function computes the field's value again based on the length of the list.
+
+#[cfg(not(feature = "request-pruning"))]
+impl<'c, C: crate::connection::Connection + ?Sized> CursorWrapper<'c, C> {
+    /// Create a new `Cursor` and return a wrapper and a cookie.
     ///
-    /// # Panics
+    /// This is a thin wrapper around [`create_cursor`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
     ///
-    /// Panics if the value cannot be represented in the target type. This
-    /// cannot happen with values of the struct received from the X11 server.
-    pub fn keycodes_per_modifier(&self) -> u8 {
-        self.keycodes.len()
-            .checked_div(8).unwrap()
-            .try_into().unwrap()
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_cursor_and_get_cookie<A>(conn: &'c C, source: Pixmap, mask: A, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
+    where
+        A: Into<Pixmap>,
+    {
+        let cid = conn.generate_id()?;
+        let cookie = create_cursor(conn, cid, source, mask, fore_red, fore_green, fore_blue, back_red, back_green, back_blue, x, y)?;
+        Ok((Self::for_cursor(conn, cid), cookie))
     }
-}
 
-/// Opcode for the NoOperation request
-pub const NO_OPERATION_REQUEST: u8 = 127;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct NoOperationRequest;
-impl NoOperationRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> BufWithFds<PiecewiseBuf<'input>>
+    /// Create a new `Cursor` and return a wrapper.
+    ///
+    /// Like [`Self::create_cursor_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_cursor<A>(conn: &'c C, source: Pixmap, mask: A, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> Result<Self, crate::errors::ReplyOrIdError>
     where
-        Conn: RequestConnection + ?Sized,
+        A: Into<Pixmap>,
     {
-        let _ = conn;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            NO_OPERATION_REQUEST,
-            0,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        (vec![request0.into()], vec![])
+        Ok(Self::create_cursor_and_get_cookie(conn, source, mask, fore_red, fore_green, fore_blue, back_red, back_green, back_blue, x, y)?.0)
     }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
+
+    /// Create a new `Cursor` and return a wrapper and a cookie.
+    ///
+    /// This is a thin wrapper around [`create_glyph_cursor`] that allocates an XID for the new resource.
+    /// The returned wrapper frees the resource in `Drop`. The cookie comes from the request.
+    ///
+    /// Errors can come from allocating the XID or from sending the request.
+    pub fn create_glyph_cursor_and_get_cookie<A>(conn: &'c C, source_font: Font, mask_font: A, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<(Self, VoidCookie<'c, C>), crate::errors::ReplyOrIdError>
     where
-        Conn: RequestConnection + ?Sized,
+        A: Into<Font>,
     {
-        let (bytes, fds) = self.serialize(conn);
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        conn.send_request_without_reply(&slices, fds)
+        let cid = conn.generate_id()?;
+        let cookie = create_glyph_cursor(conn, cid, source_font, mask_font, source_char, mask_char, fore_red, fore_green, fore_blue, back_red, back_green, back_blue)?;
+        Ok((Self::for_cursor(conn, cid), cookie))
     }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.major_opcode != NO_OPERATION_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let _ = value;
-        Ok(NoOperationRequest
-        )
+
+    /// Create a new `Cursor` and return a wrapper.
+    ///
+    /// Like [`Self::create_glyph_cursor_and_get_cookie`], but errors from the request are handled as events.
+    pub fn create_glyph_cursor<A>(conn: &'c C, source_font: Font, mask_font: A, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<Self, crate::errors::ReplyOrIdError>
+    where
+        A: Into<Font>,
+    {
+        Ok(Self::create_glyph_cursor_and_get_cookie(conn, source_font, mask_font, source_char, mask_char, fore_red, fore_green, fore_blue, back_red, back_green, back_blue)?.0)
     }
 }
-impl Request for NoOperationRequest {
-    type Reply = ();
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> From<&CursorWrapper<'_, C>> for Cursor {
+    fn from(from: &CursorWrapper<'_, C>) -> Self {
+        from.1
+    }
 }
-pub fn no_operation<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-where
-    Conn: RequestConnection + ?Sized,
-{
-    let request0 = NoOperationRequest;
-    request0.send(conn)
+
+#[cfg(not(feature = "request-pruning"))]
+impl<C: RequestConnection + ?Sized> Drop for CursorWrapper<'_, C> {
+    fn drop(&mut self) {
+        let _ = free_cursor(self.0, self.1);
+    }
 }
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateWindow"))]
     /// Creates a window.
     ///
     /// Creates an unmapped window as child of the specified `parent` window. A
@@ -26017,10 +34041,12 @@ pub trait ConnectionExt: RequestConnection {
     /// * `xcb_generate_id`: function
     /// * `MapWindow`: request
     /// * `CreateNotify`: event
+    #[inline]
     fn create_window<'c, 'input>(&'c self, depth: u8, wid: Window, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: WindowClass, visual: Visualid, value_list: &'input CreateWindowAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_window(self, depth, wid, parent, x, y, width, height, border_width, class, visual, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeWindowAttributes"))]
     /// change window attributes.
     ///
     /// Changes the attributes specified by `value_mask` for the specified `window`.
@@ -26042,10 +34068,12 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Pixmap` - TODO: reasons?
     /// * `Value` - TODO: reasons?
     /// * `Window` - The specified `window` does not exist.
+    #[inline]
     fn change_window_attributes<'c, 'input>(&'c self, window: Window, value_list: &'input ChangeWindowAttributesAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_window_attributes(self, window, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetWindowAttributes"))]
     /// Gets window attributes.
     ///
     /// Gets the current attributes for the specified `window`.
@@ -26058,10 +34086,12 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `Window` - The specified `window` does not exist.
     /// * `Drawable` - TODO: reasons?
+    #[inline]
     fn get_window_attributes(&self, window: Window) -> Result<Cookie<'_, Self, GetWindowAttributesReply>, ConnectionError>
     {
         get_window_attributes(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroyWindow"))]
     /// Destroys a window.
     ///
     /// Destroys the specified window and all of its subwindows. A DestroyNotify event
@@ -26084,14 +34114,18 @@ pub trait ConnectionExt: RequestConnection {
     /// * `DestroyNotify`: event
     /// * `MapWindow`: request
     /// * `UnmapWindow`: request
+    #[inline]
     fn destroy_window(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_window(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DestroySubwindows"))]
+    #[inline]
     fn destroy_subwindows(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         destroy_subwindows(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeSaveSet"))]
     /// Changes a client's save set.
     ///
     /// TODO: explain what the save set is for.
@@ -26114,10 +34148,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `ReparentWindow`: request
+    #[inline]
     fn change_save_set(&self, mode: SetMode, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         change_save_set(self, mode, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ReparentWindow"))]
     /// Reparents a window.
     ///
     /// Makes the specified window a child of the specified parent window. If the
@@ -26150,10 +34186,12 @@ pub trait ConnectionExt: RequestConnection {
     /// * `ReparentNotify`: event
     /// * `MapWindow`: request
     /// * `UnmapWindow`: request
+    #[inline]
     fn reparent_window(&self, window: Window, parent: Window, x: i16, y: i16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         reparent_window(self, window, parent, x, y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapWindow"))]
     /// Makes a window visible.
     ///
     /// Maps the specified window. This means making the window visible (as long as its
@@ -26189,14 +34227,18 @@ pub trait ConnectionExt: RequestConnection {
     /// * `MapNotify`: event
     /// * `Expose`: event
     /// * `UnmapWindow`: request
+    #[inline]
     fn map_window(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         map_window(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::MapSubwindows"))]
+    #[inline]
     fn map_subwindows(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         map_subwindows(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapWindow"))]
     /// Makes a window invisible.
     ///
     /// Unmaps the specified window. This means making the window invisible (and all
@@ -26218,14 +34260,18 @@ pub trait ConnectionExt: RequestConnection {
     /// * `UnmapNotify`: event
     /// * `Expose`: event
     /// * `MapWindow`: request
+    #[inline]
     fn unmap_window(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         unmap_window(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UnmapSubwindows"))]
+    #[inline]
     fn unmap_subwindows(&self, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         unmap_subwindows(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConfigureWindow"))]
     /// Configures window attributes.
     ///
     /// Configures a window's size, position, border width and stacking order.
@@ -26276,10 +34322,12 @@ pub trait ConnectionExt: RequestConnection {
     ///     xcb_flush(c);
     /// }
     /// ```
+    #[inline]
     fn configure_window<'c, 'input>(&'c self, window: Window, value_list: &'input ConfigureWindowAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         configure_window(self, window, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CirculateWindow"))]
     /// Change window stacking order.
     ///
     /// If `direction` is `XCB_CIRCULATE_RAISE_LOWEST`, the lowest mapped child (if
@@ -26297,10 +34345,12 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `Window` - The specified `window` does not exist.
     /// * `Value` - The specified `direction` is invalid.
+    #[inline]
     fn circulate_window(&self, direction: Circulate, window: Window) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         circulate_window(self, direction, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetGeometry"))]
     /// Get current window geometry.
     ///
     /// Gets the current geometry of the specified drawable (either `Window` or `Pixmap`).
@@ -26337,10 +34387,12 @@ pub trait ConnectionExt: RequestConnection {
     ///     free(reply);
     /// }
     /// ```
+    #[inline]
     fn get_geometry(&self, drawable: Drawable) -> Result<Cookie<'_, Self, GetGeometryReply>, ConnectionError>
     {
         get_geometry(self, drawable)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTree"))]
     /// query the window tree.
     ///
     /// Gets the root window ID, parent window ID and list of children windows for the
@@ -26378,6 +34430,7 @@ pub trait ConnectionExt: RequestConnection {
     ///     }
     /// }
     /// ```
+    #[inline]
     fn query_tree(&self, window: Window) -> Result<Cookie<'_, Self, QueryTreeReply>, ConnectionError>
     {
         query_tree(self, window)
@@ -26427,10 +34480,13 @@ pub trait ConnectionExt: RequestConnection {
     ///     }
     /// }
     /// ```
+    #[inline]
     fn intern_atom<'c, 'input>(&'c self, only_if_exists: bool, name: &'input [u8]) -> Result<Cookie<'c, Self, InternAtomReply>, ConnectionError>
     {
         intern_atom(self, only_if_exists, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetAtomName"))]
+    #[inline]
     fn get_atom_name(&self, atom: Atom) -> Result<Cookie<'_, Self, GetAtomNameReply>, ConnectionError>
     {
         get_atom_name(self, atom)
@@ -26486,6 +34542,7 @@ pub trait ConnectionExt: RequestConnection {
     ///     xcb_flush(conn);
     /// }
     /// ```
+    #[inline]
     fn change_property<'c, 'input, A, B>(&'c self, mode: PropMode, window: Window, property: A, type_: B, format: u8, data_len: u32, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<Atom>,
@@ -26493,10 +34550,13 @@ pub trait ConnectionExt: RequestConnection {
     {
         change_property(self, mode, window, property, type_, format, data_len, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::DeleteProperty"))]
+    #[inline]
     fn delete_property(&self, window: Window, property: Atom) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         delete_property(self, window, property)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetProperty"))]
     /// Gets a window property.
     ///
     /// Gets the specified `property` from the specified `window`. Properties are for
@@ -26565,6 +34625,7 @@ pub trait ConnectionExt: RequestConnection {
     ///     free(reply);
     /// }
     /// ```
+    #[inline]
     fn get_property<A, B>(&self, delete: bool, window: Window, property: A, type_: B, long_offset: u32, long_length: u32) -> Result<Cookie<'_, Self, GetPropertyReply>, ConnectionError>
     where
         A: Into<Atom>,
@@ -26572,10 +34633,13 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_property(self, delete, window, property, type_, long_offset, long_length)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListProperties"))]
+    #[inline]
     fn list_properties(&self, window: Window) -> Result<Cookie<'_, Self, ListPropertiesReply>, ConnectionError>
     {
         list_properties(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetSelectionOwner"))]
     /// Sets the owner of a selection.
     ///
     /// Makes `window` the owner of the selection `selection` and updates the
@@ -26605,6 +34669,7 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `SetSelectionOwner`: request
+    #[inline]
     fn set_selection_owner<A, B>(&self, owner: A, selection: Atom, time: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Window>,
@@ -26612,6 +34677,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_selection_owner(self, owner, selection, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetSelectionOwner"))]
     /// Gets the owner of a selection.
     ///
     /// Gets the owner of the specified selection.
@@ -26629,10 +34695,13 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `SetSelectionOwner`: request
+    #[inline]
     fn get_selection_owner(&self, selection: Atom) -> Result<Cookie<'_, Self, GetSelectionOwnerReply>, ConnectionError>
     {
         get_selection_owner(self, selection)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ConvertSelection"))]
+    #[inline]
     fn convert_selection<A, B>(&self, requestor: Window, selection: Atom, target: Atom, property: A, time: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Atom>,
@@ -26640,6 +34709,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         convert_selection(self, requestor, selection, target, property, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SendEvent"))]
     /// send an event.
     ///
     /// Identifies the `destination` window, determines which clients should receive
@@ -26713,6 +34783,7 @@ pub trait ConnectionExt: RequestConnection {
     ///     free(event);
     /// }
     /// ```
+    #[inline]
     fn send_event<A, B, C>(&self, propagate: bool, destination: A, event_mask: B, event: C) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Window>,
@@ -26721,6 +34792,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         send_event(self, propagate, destination, event_mask, event)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabPointer"))]
     /// Grab the pointer.
     ///
     /// Actively grabs control of the pointer. Further pointer events are reported only to the grabbing client. Overrides any active pointer grab by this client.
@@ -26792,6 +34864,7 @@ pub trait ConnectionExt: RequestConnection {
     ///     }
     /// }
     /// ```
+    #[inline]
     fn grab_pointer<A, B, C, D>(&self, owner_events: bool, grab_window: Window, event_mask: A, pointer_mode: GrabMode, keyboard_mode: GrabMode, confine_to: B, cursor: C, time: D) -> Result<Cookie<'_, Self, GrabPointerReply>, ConnectionError>
     where
         A: Into<u16>,
@@ -26801,6 +34874,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         grab_pointer(self, owner_events, grab_window, event_mask, pointer_mode, keyboard_mode, confine_to, cursor, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabPointer"))]
     /// release the pointer.
     ///
     /// Releases the pointer and any queued events if you actively grabbed the pointer
@@ -26824,12 +34898,14 @@ pub trait ConnectionExt: RequestConnection {
     /// * `GrabButton`: request
     /// * `EnterNotify`: event
     /// * `LeaveNotify`: event
+    #[inline]
     fn ungrab_pointer<A>(&self, time: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Timestamp>,
     {
         ungrab_pointer(self, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabButton"))]
     /// Grab pointer button(s).
     ///
     /// This request establishes a passive grab. The pointer is actively grabbed as
@@ -26897,6 +34973,7 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Value` - TODO: reasons?
     /// * `Cursor` - The specified `cursor` does not exist.
     /// * `Window` - The specified `window` does not exist.
+    #[inline]
     fn grab_button<A, B, C, D>(&self, owner_events: bool, grab_window: Window, event_mask: A, pointer_mode: GrabMode, keyboard_mode: GrabMode, confine_to: B, cursor: C, button: ButtonIndex, modifiers: D) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -26906,12 +34983,16 @@ pub trait ConnectionExt: RequestConnection {
     {
         grab_button(self, owner_events, grab_window, event_mask, pointer_mode, keyboard_mode, confine_to, cursor, button, modifiers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabButton"))]
+    #[inline]
     fn ungrab_button<A>(&self, button: ButtonIndex, grab_window: Window, modifiers: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
     {
         ungrab_button(self, button, grab_window, modifiers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeActivePointerGrab"))]
+    #[inline]
     fn change_active_pointer_grab<A, B, C>(&self, cursor: A, time: B, event_mask: C) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Cursor>,
@@ -26920,6 +35001,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         change_active_pointer_grab(self, cursor, time, event_mask)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKeyboard"))]
     /// Grab the keyboard.
     ///
     /// Actively grabs control of the keyboard and generates FocusIn and FocusOut
@@ -26982,18 +35064,22 @@ pub trait ConnectionExt: RequestConnection {
     ///     }
     /// }
     /// ```
+    #[inline]
     fn grab_keyboard<A>(&self, owner_events: bool, grab_window: Window, time: A, pointer_mode: GrabMode, keyboard_mode: GrabMode) -> Result<Cookie<'_, Self, GrabKeyboardReply>, ConnectionError>
     where
         A: Into<Timestamp>,
     {
         grab_keyboard(self, owner_events, grab_window, time, pointer_mode, keyboard_mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKeyboard"))]
+    #[inline]
     fn ungrab_keyboard<A>(&self, time: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Timestamp>,
     {
         ungrab_keyboard(self, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabKey"))]
     /// Grab keyboard key(s).
     ///
     /// Establishes a passive grab on the keyboard. In the future, the keyboard is
@@ -27054,6 +35140,7 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `GrabKeyboard`: request
+    #[inline]
     fn grab_key<A, B>(&self, owner_events: bool, grab_window: Window, modifiers: A, key: B, pointer_mode: GrabMode, keyboard_mode: GrabMode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u16>,
@@ -27061,6 +35148,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         grab_key(self, owner_events, grab_window, modifiers, key, pointer_mode, keyboard_mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabKey"))]
     /// release a key combination.
     ///
     /// Releases the key combination on `grab_window` if you grabbed it using
@@ -27086,6 +35174,7 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `GrabKey`: request
     /// * `xev`: program
+    #[inline]
     fn ungrab_key<A, B>(&self, key: A, grab_window: Window, modifiers: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Keycode>,
@@ -27093,6 +35182,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         ungrab_key(self, key, grab_window, modifiers)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllowEvents"))]
     /// release queued events.
     ///
     /// Releases queued events if the client has caused a device (pointer/keyboard) to
@@ -27111,20 +35201,26 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `Value` - You specified an invalid `mode`.
+    #[inline]
     fn allow_events<A>(&self, mode: Allow, time: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Timestamp>,
     {
         allow_events(self, mode, time)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GrabServer"))]
+    #[inline]
     fn grab_server(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         grab_server(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UngrabServer"))]
+    #[inline]
     fn ungrab_server(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         ungrab_server(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryPointer"))]
     /// get pointer coordinates.
     ///
     /// Gets the root window the pointer is logically on and the pointer coordinates
@@ -27138,10 +35234,13 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `Window` - The specified `window` does not exist.
+    #[inline]
     fn query_pointer(&self, window: Window) -> Result<Cookie<'_, Self, QueryPointerReply>, ConnectionError>
     {
         query_pointer(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetMotionEvents"))]
+    #[inline]
     fn get_motion_events<A, B>(&self, window: Window, start: A, stop: B) -> Result<Cookie<'_, Self, GetMotionEventsReply>, ConnectionError>
     where
         A: Into<Timestamp>,
@@ -27149,10 +35248,13 @@ pub trait ConnectionExt: RequestConnection {
     {
         get_motion_events(self, window, start, stop)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::TranslateCoordinates"))]
+    #[inline]
     fn translate_coordinates(&self, src_window: Window, dst_window: Window, src_x: i16, src_y: i16) -> Result<Cookie<'_, Self, TranslateCoordinatesReply>, ConnectionError>
     {
         translate_coordinates(self, src_window, dst_window, src_x, src_y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::WarpPointer"))]
     /// move mouse pointer.
     ///
     /// Moves the mouse pointer to the specified position.
@@ -27185,6 +35287,7 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `SetInputFocus`: request
+    #[inline]
     fn warp_pointer<A, B>(&self, src_window: A, dst_window: B, src_x: i16, src_y: i16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Window>,
@@ -27192,6 +35295,7 @@ pub trait ConnectionExt: RequestConnection {
     {
         warp_pointer(self, src_window, dst_window, src_x, src_y, src_width, src_height, dst_x, dst_y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetInputFocus"))]
     /// Sets input focus.
     ///
     /// Changes the input focus and the last-focus-change time. If the specified `time`
@@ -27227,6 +35331,7 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `FocusIn`: event
     /// * `FocusOut`: event
+    #[inline]
     fn set_input_focus<A, B>(&self, revert_to: InputFocus, focus: A, time: B) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Window>,
@@ -27234,14 +35339,18 @@ pub trait ConnectionExt: RequestConnection {
     {
         set_input_focus(self, revert_to, focus, time)
     }
+    #[inline]
     fn get_input_focus(&self) -> Result<Cookie<'_, Self, GetInputFocusReply>, ConnectionError>
     {
         get_input_focus(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryKeymap"))]
+    #[inline]
     fn query_keymap(&self) -> Result<Cookie<'_, Self, QueryKeymapReply>, ConnectionError>
     {
         query_keymap(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::OpenFont"))]
     /// opens a font.
     ///
     /// Opens any X core font matching the given `name` (for example "-misc-fixed-*").
@@ -27262,14 +35371,18 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `xcb_generate_id`: function
+    #[inline]
     fn open_font<'c, 'input>(&'c self, fid: Font, name: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         open_font(self, fid, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CloseFont"))]
+    #[inline]
     fn close_font(&self, font: Font) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         close_font(self, font)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryFont"))]
     /// query font metrics.
     ///
     /// Queries information associated with the font.
@@ -27277,10 +35390,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # Fields
     ///
     /// * `font` - The fontable (Font or Graphics Context) to query.
+    #[inline]
     fn query_font(&self, font: Fontable) -> Result<Cookie<'_, Self, QueryFontReply>, ConnectionError>
     {
         query_font(self, font)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryTextExtents"))]
     /// get text extents.
     ///
     /// Query text extents from the X11 server. This request returns the bounding box
@@ -27315,10 +35430,12 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `GContext` - The specified graphics context does not exist.
     /// * `Font` - The specified `font` does not exist.
+    #[inline]
     fn query_text_extents<'c, 'input>(&'c self, font: Fontable, string: &'input [Char2b]) -> Result<Cookie<'c, Self, QueryTextExtentsReply>, ConnectionError>
     {
         query_text_extents(self, font, string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListFonts"))]
     /// get matching font names.
     ///
     /// Gets a list of available font names which match the given `pattern`.
@@ -27332,6 +35449,7 @@ pub trait ConnectionExt: RequestConnection {
     /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
     /// not matter.
     /// * `max_names` - The maximum number of fonts to be returned.
+    #[inline]
     fn list_fonts<'c, 'input>(&'c self, max_names: u16, pattern: &'input [u8]) -> Result<Cookie<'c, Self, ListFontsReply>, ConnectionError>
     {
         list_fonts(self, max_names, pattern)
@@ -27349,18 +35467,24 @@ pub trait ConnectionExt: RequestConnection {
     /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
     /// not matter.
     /// * `max_names` - The maximum number of fonts to be returned.
-    fn list_fonts_with_info<'c, 'input>(&'c self, max_names: u16, pattern: &'input [u8]) -> Result<ListFontsWithInfoCookie<'c, Self>, ConnectionError>
+    #[inline]
+    fn list_fonts_with_info<'c, 'input>(&'c self, max_names: u16, pattern: &'input [u8]) -> Result<CookieStream<'c, Self, ListFontsWithInfoReply>, ConnectionError>
     {
         list_fonts_with_info(self, max_names, pattern)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetFontPath"))]
+    #[inline]
     fn set_font_path<'c, 'input>(&'c self, font: &'input [Str]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_font_path(self, font)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetFontPath"))]
+    #[inline]
     fn get_font_path(&self) -> Result<Cookie<'_, Self, GetFontPathReply>, ConnectionError>
     {
         get_font_path(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreatePixmap"))]
     /// Creates a pixmap.
     ///
     /// Creates a pixmap. The pixmap can only be used on the same screen as `drawable`
@@ -27384,10 +35508,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `xcb_generate_id`: function
+    #[inline]
     fn create_pixmap(&self, depth: u8, pid: Pixmap, drawable: Drawable, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_pixmap(self, depth, pid, drawable, width, height)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreePixmap"))]
     /// Destroys a pixmap.
     ///
     /// Deletes the association between the pixmap ID and the pixmap. The pixmap
@@ -27400,10 +35526,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `Pixmap` - The specified pixmap does not exist.
+    #[inline]
     fn free_pixmap(&self, pixmap: Pixmap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_pixmap(self, pixmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGC"))]
     /// Creates a graphics context.
     ///
     /// Creates a graphics context. The graphics context can be used with any drawable
@@ -27427,10 +35555,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `xcb_generate_id`: function
+    #[inline]
     fn create_gc<'c, 'input>(&'c self, cid: Gcontext, drawable: Drawable, value_list: &'input CreateGCAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         create_gc(self, cid, drawable, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeGC"))]
     /// change graphics context components.
     ///
     /// Changes the components specified by `value_mask` for the specified graphics context.
@@ -27476,24 +35606,32 @@ pub trait ConnectionExt: RequestConnection {
     ///     xcb_flush(conn);
     /// }
     /// ```
+    #[inline]
     fn change_gc<'c, 'input>(&'c self, gc: Gcontext, value_list: &'input ChangeGCAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_gc(self, gc, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyGC"))]
+    #[inline]
     fn copy_gc<A>(&self, src_gc: Gcontext, dst_gc: Gcontext, value_mask: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         copy_gc(self, src_gc, dst_gc, value_mask)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetDashes"))]
+    #[inline]
     fn set_dashes<'c, 'input>(&'c self, gc: Gcontext, dash_offset: u16, dashes: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_dashes(self, gc, dash_offset, dashes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetClipRectangles"))]
+    #[inline]
     fn set_clip_rectangles<'c, 'input>(&'c self, ordering: ClipOrdering, gc: Gcontext, clip_x_origin: i16, clip_y_origin: i16, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         set_clip_rectangles(self, ordering, gc, clip_x_origin, clip_y_origin, rectangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeGC"))]
     /// Destroys a graphics context.
     ///
     /// Destroys the specified `gc` and all associated storage.
@@ -27505,14 +35643,18 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `GContext` - The specified graphics context does not exist.
+    #[inline]
     fn free_gc(&self, gc: Gcontext) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_gc(self, gc)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ClearArea"))]
+    #[inline]
     fn clear_area(&self, exposures: bool, window: Window, x: i16, y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         clear_area(self, exposures, window, x, y, width, height)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyArea"))]
     /// copy areas.
     ///
     /// Copies the specified rectangle from `src_drawable` to `dst_drawable`.
@@ -27534,18 +35676,24 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Drawable` - The specified `drawable` (Window or Pixmap) does not exist.
     /// * `GContext` - The specified graphics context does not exist.
     /// * `Match` - `src_drawable` has a different root or depth than `dst_drawable`.
+    #[inline]
     fn copy_area(&self, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         copy_area(self, src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyPlane"))]
+    #[inline]
     fn copy_plane(&self, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16, bit_plane: u32) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         copy_plane(self, src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height, bit_plane)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyPoint"))]
+    #[inline]
     fn poly_point<'c, 'input>(&'c self, coordinate_mode: CoordMode, drawable: Drawable, gc: Gcontext, points: &'input [Point]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_point(self, coordinate_mode, drawable, gc, points)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyLine"))]
     /// draw lines.
     ///
     /// Draws `points_len`-1 lines between each pair of points (point[i], point[i+1])
@@ -27585,10 +35733,12 @@ pub trait ConnectionExt: RequestConnection {
     ///     xcb_flush(conn);
     /// }
     /// ```
+    #[inline]
     fn poly_line<'c, 'input>(&'c self, coordinate_mode: CoordMode, drawable: Drawable, gc: Gcontext, points: &'input [Point]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_line(self, coordinate_mode, drawable, gc, points)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolySegment"))]
     /// draw lines.
     ///
     /// Draws multiple, unconnected lines. For each segment, a line is drawn between
@@ -27615,22 +35765,30 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Drawable` - The specified `drawable` does not exist.
     /// * `GContext` - The specified `gc` does not exist.
     /// * `Match` - TODO: reasons?
+    #[inline]
     fn poly_segment<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, segments: &'input [Segment]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_segment(self, drawable, gc, segments)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyRectangle"))]
+    #[inline]
     fn poly_rectangle<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_rectangle(self, drawable, gc, rectangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyArc"))]
+    #[inline]
     fn poly_arc<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, arcs: &'input [Arc]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_arc(self, drawable, gc, arcs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FillPoly"))]
+    #[inline]
     fn fill_poly<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, shape: PolyShape, coordinate_mode: CoordMode, points: &'input [Point]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         fill_poly(self, drawable, gc, shape, coordinate_mode, points)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillRectangle"))]
     /// Fills rectangles.
     ///
     /// Fills the specified rectangle(s) in the order listed in the array. For any
@@ -27656,30 +35814,42 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Drawable` - The specified `drawable` (Window or Pixmap) does not exist.
     /// * `GContext` - The specified graphics context does not exist.
     /// * `Match` - TODO: reasons?
+    #[inline]
     fn poly_fill_rectangle<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, rectangles: &'input [Rectangle]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_fill_rectangle(self, drawable, gc, rectangles)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyFillArc"))]
+    #[inline]
     fn poly_fill_arc<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, arcs: &'input [Arc]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_fill_arc(self, drawable, gc, arcs)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
+    #[inline]
     fn put_image<'c, 'input>(&'c self, format: ImageFormat, drawable: Drawable, gc: Gcontext, width: u16, height: u16, dst_x: i16, dst_y: i16, left_pad: u8, depth: u8, data: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         put_image(self, format, drawable, gc, width, height, dst_x, dst_y, left_pad, depth, data)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetImage"))]
+    #[inline]
     fn get_image(&self, format: ImageFormat, drawable: Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32) -> Result<Cookie<'_, Self, GetImageReply>, ConnectionError>
     {
         get_image(self, format, drawable, x, y, width, height, plane_mask)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText8"))]
+    #[inline]
     fn poly_text8<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_text8(self, drawable, gc, x, y, items)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PolyText16"))]
+    #[inline]
     fn poly_text16<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         poly_text16(self, drawable, gc, x, y, items)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText8"))]
     /// Draws text.
     ///
     /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -27714,10 +35884,12 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `ImageText16`: request
+    #[inline]
     fn image_text8<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         image_text8(self, drawable, gc, x, y, string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ImageText16"))]
     /// Draws text.
     ///
     /// Fills the destination rectangle with the background pixel from `gc`, then
@@ -27753,34 +35925,48 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `ImageText8`: request
+    #[inline]
     fn image_text16<'c, 'input>(&'c self, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &'input [Char2b]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         image_text16(self, drawable, gc, x, y, string)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateColormap"))]
+    #[inline]
     fn create_colormap(&self, alloc: ColormapAlloc, mid: Colormap, window: Window, visual: Visualid) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         create_colormap(self, alloc, mid, window, visual)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColormap"))]
+    #[inline]
     fn free_colormap(&self, cmap: Colormap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_colormap(self, cmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CopyColormapAndFree"))]
+    #[inline]
     fn copy_colormap_and_free(&self, mid: Colormap, src_cmap: Colormap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         copy_colormap_and_free(self, mid, src_cmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::InstallColormap"))]
+    #[inline]
     fn install_colormap(&self, cmap: Colormap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         install_colormap(self, cmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::UninstallColormap"))]
+    #[inline]
     fn uninstall_colormap(&self, cmap: Colormap) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         uninstall_colormap(self, cmap)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListInstalledColormaps"))]
+    #[inline]
     fn list_installed_colormaps(&self, window: Window) -> Result<Cookie<'_, Self, ListInstalledColormapsReply>, ConnectionError>
     {
         list_installed_colormaps(self, window)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColor"))]
     /// Allocate a color.
     ///
     /// Allocates a read-only colormap entry corresponding to the closest RGB value
@@ -27799,50 +35985,70 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `Colormap` - The specified colormap `cmap` does not exist.
+    #[inline]
     fn alloc_color(&self, cmap: Colormap, red: u16, green: u16, blue: u16) -> Result<Cookie<'_, Self, AllocColorReply>, ConnectionError>
     {
         alloc_color(self, cmap, red, green, blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocNamedColor"))]
+    #[inline]
     fn alloc_named_color<'c, 'input>(&'c self, cmap: Colormap, name: &'input [u8]) -> Result<Cookie<'c, Self, AllocNamedColorReply>, ConnectionError>
     {
         alloc_named_color(self, cmap, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorCells"))]
+    #[inline]
     fn alloc_color_cells(&self, contiguous: bool, cmap: Colormap, colors: u16, planes: u16) -> Result<Cookie<'_, Self, AllocColorCellsReply>, ConnectionError>
     {
         alloc_color_cells(self, contiguous, cmap, colors, planes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::AllocColorPlanes"))]
+    #[inline]
     fn alloc_color_planes(&self, contiguous: bool, cmap: Colormap, colors: u16, reds: u16, greens: u16, blues: u16) -> Result<Cookie<'_, Self, AllocColorPlanesReply>, ConnectionError>
     {
         alloc_color_planes(self, contiguous, cmap, colors, reds, greens, blues)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeColors"))]
+    #[inline]
     fn free_colors<'c, 'input>(&'c self, cmap: Colormap, plane_mask: u32, pixels: &'input [u32]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         free_colors(self, cmap, plane_mask, pixels)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreColors"))]
+    #[inline]
     fn store_colors<'c, 'input>(&'c self, cmap: Colormap, items: &'input [Coloritem]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         store_colors(self, cmap, items)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::StoreNamedColor"))]
+    #[inline]
     fn store_named_color<'c, 'input, A>(&'c self, flags: A, cmap: Colormap, pixel: u32, name: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     where
         A: Into<u8>,
     {
         store_named_color(self, flags, cmap, pixel, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryColors"))]
+    #[inline]
     fn query_colors<'c, 'input>(&'c self, cmap: Colormap, pixels: &'input [u32]) -> Result<Cookie<'c, Self, QueryColorsReply>, ConnectionError>
     {
         query_colors(self, cmap, pixels)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::LookupColor"))]
+    #[inline]
     fn lookup_color<'c, 'input>(&'c self, cmap: Colormap, name: &'input [u8]) -> Result<Cookie<'c, Self, LookupColorReply>, ConnectionError>
     {
         lookup_color(self, cmap, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateCursor"))]
+    #[inline]
     fn create_cursor<A>(&self, cid: Cursor, source: Pixmap, mask: A, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Pixmap>,
     {
         create_cursor(self, cid, source, mask, fore_red, fore_green, fore_blue, back_red, back_green, back_blue, x, y)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::CreateGlyphCursor"))]
     /// create cursor.
     ///
     /// Creates a cursor from a font glyph. X provides a set of standard cursor shapes
@@ -27876,12 +36082,14 @@ pub trait ConnectionExt: RequestConnection {
     /// * `Alloc` - The X server could not allocate the requested resources (no memory?).
     /// * `Font` - The specified `source_font` or `mask_font` does not exist.
     /// * `Value` - Either `source_char` or `mask_char` are not defined in `source_font` or `mask_font`, respectively.
+    #[inline]
     fn create_glyph_cursor<A>(&self, cid: Cursor, source_font: Font, mask_font: A, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<Font>,
     {
         create_glyph_cursor(self, cid, source_font, mask_font, source_char, mask_char, fore_red, fore_green, fore_blue, back_red, back_green, back_blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::FreeCursor"))]
     /// Deletes a cursor.
     ///
     /// Deletes the association between the cursor resource ID and the specified
@@ -27894,14 +36102,19 @@ pub trait ConnectionExt: RequestConnection {
     /// # Errors
     ///
     /// * `Cursor` - The specified cursor does not exist.
+    #[inline]
     fn free_cursor(&self, cursor: Cursor) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         free_cursor(self, cursor)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RecolorCursor"))]
+    #[inline]
     fn recolor_cursor(&self, cursor: Cursor, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         recolor_cursor(self, cursor, fore_red, fore_green, fore_blue, back_red, back_green, back_blue)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::QueryBestSize"))]
+    #[inline]
     fn query_best_size(&self, class: QueryShapeOf, drawable: Drawable, width: u16, height: u16) -> Result<Cookie<'_, Self, QueryBestSizeReply>, ConnectionError>
     {
         query_best_size(self, class, drawable, width, height)
@@ -27928,66 +36141,96 @@ pub trait ConnectionExt: RequestConnection {
     ///
     /// * `xdpyinfo`: program
     /// * `xcb_get_extension_data`: function
+    #[inline]
     fn query_extension<'c, 'input>(&'c self, name: &'input [u8]) -> Result<Cookie<'c, Self, QueryExtensionReply>, ConnectionError>
     {
         query_extension(self, name)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListExtensions"))]
+    #[inline]
     fn list_extensions(&self) -> Result<Cookie<'_, Self, ListExtensionsReply>, ConnectionError>
     {
         list_extensions(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardMapping"))]
+    #[inline]
     fn change_keyboard_mapping<'c, 'input>(&'c self, keycode_count: u8, first_keycode: Keycode, keysyms_per_keycode: u8, keysyms: &'input [Keysym]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_keyboard_mapping(self, keycode_count, first_keycode, keysyms_per_keycode, keysyms)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardMapping"))]
+    #[inline]
     fn get_keyboard_mapping(&self, first_keycode: Keycode, count: u8) -> Result<Cookie<'_, Self, GetKeyboardMappingReply>, ConnectionError>
     {
         get_keyboard_mapping(self, first_keycode, count)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeKeyboardControl"))]
+    #[inline]
     fn change_keyboard_control<'c, 'input>(&'c self, value_list: &'input ChangeKeyboardControlAux) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_keyboard_control(self, value_list)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetKeyboardControl"))]
+    #[inline]
     fn get_keyboard_control(&self) -> Result<Cookie<'_, Self, GetKeyboardControlReply>, ConnectionError>
     {
         get_keyboard_control(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::Bell"))]
+    #[inline]
     fn bell(&self, percent: i8) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         bell(self, percent)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangePointerControl"))]
+    #[inline]
     fn change_pointer_control(&self, acceleration_numerator: i16, acceleration_denominator: i16, threshold: i16, do_acceleration: bool, do_threshold: bool) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         change_pointer_control(self, acceleration_numerator, acceleration_denominator, threshold, do_acceleration, do_threshold)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerControl"))]
+    #[inline]
     fn get_pointer_control(&self) -> Result<Cookie<'_, Self, GetPointerControlReply>, ConnectionError>
     {
         get_pointer_control(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetScreenSaver"))]
+    #[inline]
     fn set_screen_saver(&self, timeout: i16, interval: i16, prefer_blanking: Blanking, allow_exposures: Exposures) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_screen_saver(self, timeout, interval, prefer_blanking, allow_exposures)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetScreenSaver"))]
+    #[inline]
     fn get_screen_saver(&self) -> Result<Cookie<'_, Self, GetScreenSaverReply>, ConnectionError>
     {
         get_screen_saver(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ChangeHosts"))]
+    #[inline]
     fn change_hosts<'c, 'input>(&'c self, mode: HostMode, family: Family, address: &'input [u8]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         change_hosts(self, mode, family, address)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ListHosts"))]
+    #[inline]
     fn list_hosts(&self) -> Result<Cookie<'_, Self, ListHostsReply>, ConnectionError>
     {
         list_hosts(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetAccessControl"))]
+    #[inline]
     fn set_access_control(&self, mode: AccessControl) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_access_control(self, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetCloseDownMode"))]
+    #[inline]
     fn set_close_down_mode(&self, mode: CloseDown) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_close_down_mode(self, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::KillClient"))]
     /// kills a client.
     ///
     /// Forces a close down of the client that created the specified `resource`.
@@ -28007,36 +36250,51 @@ pub trait ConnectionExt: RequestConnection {
     /// # See
     ///
     /// * `xkill`: program
+    #[inline]
     fn kill_client<A>(&self, resource: A) -> Result<VoidCookie<'_, Self>, ConnectionError>
     where
         A: Into<u32>,
     {
         kill_client(self, resource)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::RotateProperties"))]
+    #[inline]
     fn rotate_properties<'c, 'input>(&'c self, window: Window, delta: i16, atoms: &'input [Atom]) -> Result<VoidCookie<'c, Self>, ConnectionError>
     {
         rotate_properties(self, window, delta, atoms)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::ForceScreenSaver"))]
+    #[inline]
     fn force_screen_saver(&self, mode: ScreenSaver) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         force_screen_saver(self, mode)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetPointerMapping"))]
+    #[inline]
     fn set_pointer_mapping<'c, 'input>(&'c self, map: &'input [u8]) -> Result<Cookie<'c, Self, SetPointerMappingReply>, ConnectionError>
     {
         set_pointer_mapping(self, map)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetPointerMapping"))]
+    #[inline]
     fn get_pointer_mapping(&self) -> Result<Cookie<'_, Self, GetPointerMappingReply>, ConnectionError>
     {
         get_pointer_mapping(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::SetModifierMapping"))]
+    #[inline]
     fn set_modifier_mapping<'c, 'input>(&'c self, keycodes: &'input [Keycode]) -> Result<Cookie<'c, Self, SetModifierMappingReply>, ConnectionError>
     {
         set_modifier_mapping(self, keycodes)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::GetModifierMapping"))]
+    #[inline]
     fn get_modifier_mapping(&self) -> Result<Cookie<'_, Self, GetModifierMappingReply>, ConnectionError>
     {
         get_modifier_mapping(self)
     }
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::NoOperation"))]
+    #[inline]
     fn no_operation(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         no_operation(self)
@@ -0,0 +1,153 @@
+//! Documentation of requests, replies and events at runtime.
+//!
+//! The XML description of the X11 protocol contains documentation for many requests, replies
+//! and events. This documentation also ends up in the doc comments of [`crate::protocol`], but
+//! tools that decode X11 traffic at runtime, e.g. a proxy or an interactive REPL, might want to
+//! show it to their users. This module provides the documentation as plain text.
+//!
+//! Items are looked up by their name in the protocol description, e.g. `CreateWindow` for the
+//! request and its reply and `KeyPress` for the event. Items of extensions are prefixed with the
+//! module name, e.g. `randr::ScreenChangeNotify`. This is the same form that is used by
+//! [`X11Error::request_name`](crate::x11_utils::X11Error::request_name).
+//!
+//! ```
+//! use x11rb::protocol_docs::request_doc;
+//!
+//! let doc = request_doc("xproto::CreateWindow").unwrap();
+//! assert_eq!(doc.brief, Some("Creates a window"));
+//! println!("{}", doc.field("parent").unwrap());
+//! ```
+//!
+//! Only items that are documented in the protocol description can be found. Most extensions
+//! have no documentation at all.
+//!
+//! The code in this module is only available when the `protocol-docs` feature of the library is
+//! enabled.
+
+use crate::protocol::documentation::{EVENTS, REPLIES, REQUESTS};
+
+/// The documentation of a request, reply, or event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Documentation {
+    /// The module in [`crate::protocol`] that the item belongs to, e.g. `"xproto"`.
+    pub module: &'static str,
+    /// The name of the item, e.g. `"CreateWindow"`.
+    pub name: &'static str,
+    /// A brief, one-line description.
+    pub brief: Option<&'static str>,
+    /// A verbose description. Paragraphs are separated by empty lines.
+    pub description: Option<&'static str>,
+    /// The documentation of the individual fields.
+    pub fields: &'static [FieldDoc],
+    /// The X11 errors that can occur.
+    pub errors: &'static [ErrorDoc],
+}
+
+impl Documentation {
+    /// Get the documentation of the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&'static str> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .and_then(|field| field.doc)
+    }
+}
+
+/// The documentation of a single field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDoc {
+    /// The name of the field.
+    pub name: &'static str,
+    /// A description of the field.
+    pub doc: Option<&'static str>,
+}
+
+/// The documentation of an X11 error that can occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorDoc {
+    /// The name of the error, e.g. `"Window"`.
+    pub error: &'static str,
+    /// A description of when this error occurs.
+    pub doc: Option<&'static str>,
+}
+
+/// Get the documentation of all requests that are known to this build of x11rb.
+///
+/// This only contains the requests of extensions whose feature is enabled.
+pub fn requests() -> &'static [Documentation] {
+    REQUESTS
+}
+
+/// Get the documentation of all replies that are known to this build of x11rb.
+///
+/// Replies have the name of their request.
+pub fn replies() -> &'static [Documentation] {
+    REPLIES
+}
+
+/// Get the documentation of all events that are known to this build of x11rb.
+pub fn events() -> &'static [Documentation] {
+    EVENTS
+}
+
+/// Find the documentation of a request by its name.
+///
+/// The name has the form `module::Request`. For the core protocol, the module can be left out,
+/// e.g. `CreateWindow` instead of `xproto::CreateWindow`.
+pub fn request_doc(name: &str) -> Option<&'static Documentation> {
+    find(REQUESTS, name)
+}
+
+/// Find the documentation of the reply to a request by the name of the request.
+///
+/// See [`request_doc`] for the form of the name.
+pub fn reply_doc(name: &str) -> Option<&'static Documentation> {
+    find(REPLIES, name)
+}
+
+/// Find the documentation of an event by its name.
+///
+/// The name is the name in the protocol description, e.g. `KeyPress` and not `KeyPressEvent`.
+/// See [`request_doc`] for the form of the name.
+pub fn event_doc(name: &str) -> Option<&'static Documentation> {
+    find(EVENTS, name)
+}
+
+fn find(docs: &'static [Documentation], name: &str) -> Option<&'static Documentation> {
+    let (module, name) = match name.find("::") {
+        Some(pos) => (&name[..pos], &name[pos + 2..]),
+        None => ("xproto", name),
+    };
+    docs.iter()
+        .find(|doc| doc.module == module && doc.name == name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{event_doc, reply_doc, request_doc};
+
+    #[test]
+    fn find_core_docs() {
+        let request = request_doc("GetGeometry").unwrap();
+        assert_eq!(request, request_doc("xproto::GetGeometry").unwrap());
+        assert_eq!(request.brief, Some("Get current window geometry"));
+        assert!(request.errors.iter().any(|error| error.error == "Drawable"));
+
+        let reply = reply_doc("GetGeometry").unwrap();
+        assert!(reply.field("root").is_some());
+        assert_eq!(reply.field("does_not_exist"), None);
+
+        // Copies of events share the documentation of the original
+        let press = event_doc("KeyPress").unwrap();
+        let release = event_doc("KeyRelease").unwrap();
+        assert_eq!(press.description, release.description);
+        assert_eq!(release.name, "KeyRelease");
+    }
+
+    #[test]
+    fn find_unknown_docs() {
+        assert_eq!(request_doc("DoesNotExist"), None);
+        assert_eq!(request_doc("nosuchext::GetGeometry"), None);
+        assert_eq!(event_doc("GetGeometry"), None);
+    }
+}
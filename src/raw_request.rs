@@ -0,0 +1,271 @@
+//! Sending hand-serialized requests.
+//!
+//! The generated code in [`crate::protocol`] only knows the requests that are described in
+//! xcb-proto. Requests of newer extension versions or of extensions that are not described there
+//! at all can still be sent with [`send_request_raw`]: The caller serializes the body of the
+//! request and this module adds the header, the padding, and the length field and sends it. The
+//! reply is returned as raw bytes that the caller has to parse.
+//!
+//! ```
+//! use x11rb::connection::RequestConnection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::raw_request::{send_request_raw, RawRequest, ReplyKind};
+//!
+//! /// Send `QueryVersion` (minor opcode 0) of a hypothetical extension.
+//! fn query_version(conn: &impl RequestConnection) -> Result<(u16, u16), ReplyError> {
+//!     let mut body = Vec::new();
+//!     body.extend_from_slice(&1u16.to_ne_bytes());
+//!     body.extend_from_slice(&0u16.to_ne_bytes());
+//!     let request = RawRequest::extension("EXAMPLE", 0, &body, ReplyKind::Reply);
+//!     let (reply, _fds) = send_request_raw(conn, request)?
+//!         .reply()?
+//!         .expect("QueryVersion has a reply");
+//!     let reply = reply.as_ref();
+//!     let major = u16::from_ne_bytes([reply[8], reply[9]]);
+//!     let minor = u16::from_ne_bytes([reply[10], reply[11]]);
+//!     Ok((major, minor))
+//! }
+//! ```
+//!
+//! Errors are parsed like all other errors, so [`crate::x11_utils::X11Error`] contains the
+//! opcodes of the request even if x11rb does not know its name.
+
+use std::convert::TryFrom;
+use std::io::IoSlice;
+
+use crate::connection::{BufWithFds, RequestConnection, SequenceNumber};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyError};
+use crate::utils::OwnedFd;
+use crate::x11_utils::TryParse;
+
+/// What the X11 server sends in response to a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplyKind {
+    /// The request does not have a reply. Only an error can be sent.
+    NoReply,
+    /// The request has a reply without file descriptors.
+    Reply,
+    /// The request has a reply that contains file descriptors.
+    ReplyWithFds,
+}
+
+/// A request that was serialized by hand.
+#[derive(Debug)]
+pub struct RawRequest<'a> {
+    extension: Option<&'static str>,
+    opcode: u8,
+    data: u8,
+    body: &'a [u8],
+    fds: Vec<OwnedFd>,
+    reply: ReplyKind,
+}
+
+impl<'a> RawRequest<'a> {
+    /// Describe a request of the core protocol.
+    ///
+    /// `data` is the second byte of the request, which some core requests use for a parameter.
+    /// `body` is everything after the four byte header.
+    pub fn core(opcode: u8, data: u8, body: &'a [u8], reply: ReplyKind) -> Self {
+        Self {
+            extension: None,
+            opcode,
+            data,
+            body,
+            fds: Vec::new(),
+            reply,
+        }
+    }
+
+    /// Describe a request of the extension with the given name, e.g. `"RANDR"`.
+    ///
+    /// The major opcode of the extension is looked up when the request is sent. `body` is
+    /// everything after the four byte header.
+    pub fn extension(
+        extension_name: &'static str,
+        minor_opcode: u8,
+        body: &'a [u8],
+        reply: ReplyKind,
+    ) -> Self {
+        Self {
+            extension: Some(extension_name),
+            opcode: minor_opcode,
+            data: 0,
+            body,
+            fds: Vec::new(),
+            reply,
+        }
+    }
+
+    /// Send the given file descriptors together with the request.
+    pub fn fds(mut self, fds: Vec<OwnedFd>) -> Self {
+        self.fds = fds;
+        self
+    }
+}
+
+/// Placeholder reply type, since replies are only handled as raw bytes.
+#[derive(Debug)]
+struct Unparsed;
+
+impl TryParse for Unparsed {
+    fn try_parse(_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        unreachable!("Raw replies are never parsed")
+    }
+}
+
+#[derive(Debug)]
+enum CookieKind<'a, C: RequestConnection + ?Sized> {
+    Void(VoidCookie<'a, C>),
+    Reply(Cookie<'a, C, Unparsed>),
+    ReplyWithFds(CookieWithFds<'a, C, Unparsed>),
+}
+
+/// A handle to the response to a request that was sent with [`send_request_raw`].
+///
+/// Like for other cookies, an error becomes an event if this is dropped without getting the
+/// reply.
+#[derive(Debug)]
+pub struct RawRequestCookie<'a, C: RequestConnection + ?Sized>(CookieKind<'a, C>);
+
+impl<C: RequestConnection + ?Sized> RawRequestCookie<'_, C> {
+    /// Get the sequence number of the request.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        match self.0 {
+            CookieKind::Void(ref cookie) => cookie.sequence_number(),
+            CookieKind::Reply(ref cookie) => cookie.sequence_number(),
+            CookieKind::ReplyWithFds(ref cookie) => cookie.sequence_number(),
+        }
+    }
+
+    /// Wait for the response to the request.
+    ///
+    /// Returns the raw reply, including the header with the sequence number and the length. For
+    /// requests without a reply, this checks for an error and returns `None`.
+    pub fn reply(self) -> Result<Option<BufWithFds<C::Buf>>, ReplyError> {
+        match self.0 {
+            CookieKind::Void(cookie) => {
+                cookie.check()?;
+                Ok(None)
+            }
+            CookieKind::Reply(cookie) => Ok(Some((cookie.raw_reply()?, Vec::new()))),
+            CookieKind::ReplyWithFds(cookie) => Ok(Some(cookie.raw_reply()?)),
+        }
+    }
+}
+
+/// Send a hand-serialized request.
+///
+/// This adds the header and padding to the body of the request and sends it. Requests that are
+/// too long for the length field of the header use BIG-REQUESTS automatically. For extension
+/// requests, the major opcode is looked up on the connection. If the X11 server does not support
+/// the extension, [`ConnectionError::UnsupportedExtension`] is returned.
+///
+/// The connection relies on the [`ReplyKind`] to match replies to requests, so it has to be
+/// correct. Otherwise, waiting for a reply might block forever.
+pub fn send_request_raw<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    request: RawRequest<'_>,
+) -> Result<RawRequestCookie<'c, C>, ConnectionError> {
+    let header = header(conn, &request)?;
+    let padding = [0; 3];
+    let bufs = [
+        IoSlice::new(&header),
+        IoSlice::new(request.body),
+        IoSlice::new(&padding[..padding_len(request.body)]),
+    ];
+    let fds = request.fds;
+    let cookie = match request.reply {
+        ReplyKind::NoReply => CookieKind::Void(conn.send_request_without_reply(&bufs, fds)?),
+        ReplyKind::Reply => CookieKind::Reply(conn.send_request_with_reply(&bufs, fds)?),
+        ReplyKind::ReplyWithFds => {
+            CookieKind::ReplyWithFds(conn.send_request_with_reply_with_fds(&bufs, fds)?)
+        }
+    };
+    Ok(RawRequestCookie(cookie))
+}
+
+/// Build the four byte header of a request.
+fn header<C: RequestConnection + ?Sized>(
+    conn: &C,
+    request: &RawRequest<'_>,
+) -> Result<[u8; 4], ConnectionError> {
+    let (major_opcode, data) = match request.extension {
+        None => (request.opcode, request.data),
+        Some(extension_name) => {
+            let extension = conn
+                .extension_information(extension_name)?
+                .ok_or(ConnectionError::UnsupportedExtension)?;
+            (extension.major_opcode, request.opcode)
+        }
+    };
+    // A length of zero means that the connection has to use the BIG-REQUESTS extension
+    let length = (4 + request.body.len() + padding_len(request.body)) / 4;
+    let length = u16::try_from(length).unwrap_or(0).to_ne_bytes();
+    Ok([major_opcode, data, length[0], length[1]])
+}
+
+/// Get the number of bytes that are needed to pad the body to a multiple of four bytes.
+fn padding_len(body: &[u8]) -> usize {
+    (4 - body.len() % 4) % 4
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{send_request_raw, RawRequest, ReplyKind};
+    use crate::errors::ConnectionError;
+    use crate::protocol::xproto;
+    use crate::testing::{FakeConnection, Response};
+    use crate::x11_utils::ExtensionInformation;
+
+    #[test]
+    fn core_request() {
+        let conn = FakeConnection::default();
+        let mut reply = vec![0; 32];
+        reply[8] = 42;
+        conn.respond(
+            xproto::GET_INPUT_FOCUS_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+        let request = RawRequest::core(xproto::GET_INPUT_FOCUS_REQUEST, 0, &[], ReplyKind::Reply);
+        let (reply, fds) = send_request_raw(&conn, request)
+            .unwrap()
+            .reply()
+            .unwrap()
+            .unwrap();
+        assert_eq!(reply[8], 42);
+        assert!(fds.is_empty());
+
+        let sent = conn.take_sent_requests();
+        assert_eq!(sent[0].bytes, [xproto::GET_INPUT_FOCUS_REQUEST, 0, 1, 0]);
+    }
+
+    #[test]
+    fn extension_request_is_padded() {
+        let conn = FakeConnection::default();
+        let request = RawRequest::extension("EXAMPLE", 3, &[1, 2, 3, 4, 5], ReplyKind::NoReply);
+        match send_request_raw(&conn, request) {
+            Err(ConnectionError::UnsupportedExtension) => {}
+            result => panic!("Unexpected result {:?}", result.map(|_| ())),
+        }
+
+        conn.add_extension(
+            "EXAMPLE",
+            ExtensionInformation {
+                major_opcode: 140,
+                first_event: 0,
+                first_error: 0,
+            },
+        );
+        let request = RawRequest::extension("EXAMPLE", 3, &[1, 2, 3, 4, 5], ReplyKind::NoReply);
+        let cookie = send_request_raw(&conn, request).unwrap();
+        assert!(cookie.reply().unwrap().is_none());
+        let sent = conn.take_sent_requests();
+        let length = 3u16.to_ne_bytes();
+        assert_eq!(
+            sent[0].bytes,
+            [140, 3, length[0], length[1], 1, 2, 3, 4, 5, 0, 0, 0]
+        );
+    }
+}
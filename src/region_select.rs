@@ -0,0 +1,358 @@
+//! Letting the user select a region of the screen or a window with the mouse.
+//!
+//! Screenshot and screen recording tools usually start by asking the user for the region that
+//! should be captured. [`select_region`] implements this: It grabs the pointer and the keyboard,
+//! shows a crosshair cursor, and draws a rubber-band rectangle while the user drags with the
+//! first mouse button. Pressing `Escape` or another mouse button cancels the selection.
+//!
+//! Inspection tools like `xwininfo` instead let the user click on a window. [`pick_window`]
+//! implements this and finds the client window below the window manager's frame.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::region_select::{select_region, SelectError};
+//!
+//! fn capture(conn: &impl Connection, screen_num: usize) -> Result<(), SelectError> {
+//!     match select_region(conn, screen_num)? {
+//!         Some(rect) => println!("Capturing {}x{}+{}+{}", rect.width, rect.height, rect.x, rect.y),
+//!         None => println!("Cancelled"),
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The rectangle is drawn with an XOR graphics context directly on the root window, so that no
+//! window has to be created and drawing it twice removes it again. With a compositing manager,
+//! drawing on the root window might not be visible.
+
+use std::fmt;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::grab::{GrabError, GrabKeyboardGuard, GrabPointerGuard};
+use crate::keysyms::KeyboardMapping;
+use crate::protocol::xproto::{
+    self, AtomEnum, CreateGCAux, CursorWrapper, EventMask, FontWrapper, GcontextWrapper,
+    GrabKeyboardRequest, GrabMode, GrabPointerRequest, Keysym, Rectangle, SubwindowMode, Window,
+    GX,
+};
+use crate::protocol::Event;
+use crate::{CURRENT_TIME, NONE};
+
+/// The crosshair glyph in the `cursor` font.
+const XC_CROSSHAIR: u16 = 34;
+
+const KEYSYM_ESCAPE: Keysym = 0xff1b;
+
+/// An error that occurred while selecting a region.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SelectError {
+    /// Grabbing the pointer or the keyboard failed.
+    Grab(GrabError),
+    /// Another request failed.
+    Reply(ReplyOrIdError),
+}
+
+impl std::error::Error for SelectError {}
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectError::Grab(err) => err.fmt(f),
+            SelectError::Reply(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<GrabError> for SelectError {
+    fn from(err: GrabError) -> Self {
+        SelectError::Grab(err)
+    }
+}
+
+impl From<ReplyOrIdError> for SelectError {
+    fn from(err: ReplyOrIdError) -> Self {
+        SelectError::Reply(err)
+    }
+}
+
+impl From<ReplyError> for SelectError {
+    fn from(err: ReplyError) -> Self {
+        SelectError::Reply(err.into())
+    }
+}
+
+impl From<ConnectionError> for SelectError {
+    fn from(err: ConnectionError) -> Self {
+        SelectError::Reply(err.into())
+    }
+}
+
+/// The rectangle between the point where the button was pressed and the current pointer position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RubberBand {
+    start: (i16, i16),
+    end: (i16, i16),
+}
+
+impl RubberBand {
+    fn new(x: i16, y: i16) -> Self {
+        Self {
+            start: (x, y),
+            end: (x, y),
+        }
+    }
+
+    /// Get the selected rectangle. Both corners are part of it.
+    fn rectangle(&self) -> Rectangle {
+        let (x1, x2) = (self.start.0.min(self.end.0), self.start.0.max(self.end.0));
+        let (y1, y2) = (self.start.1.min(self.end.1), self.start.1.max(self.end.1));
+        Rectangle {
+            x: x1,
+            y: y1,
+            width: (i32::from(x2) - i32::from(x1) + 1) as u16,
+            height: (i32::from(y2) - i32::from(y1) + 1) as u16,
+        }
+    }
+
+    /// Get the rectangle that has to be drawn to outline the selection.
+    fn outline(&self) -> Rectangle {
+        // PolyRectangle draws one pixel more than its width and height
+        let rect = self.rectangle();
+        Rectangle {
+            width: rect.width - 1,
+            height: rect.height - 1,
+            ..rect
+        }
+    }
+}
+
+/// Grab the pointer with a crosshair cursor and the keyboard.
+fn grab_input<'c, C: Connection + ?Sized>(
+    conn: &'c C,
+    root: Window,
+    event_mask: EventMask,
+) -> Result<(GrabPointerGuard<'c, C>, GrabKeyboardGuard<'c, C>), SelectError> {
+    // The cursor stays alive while the grab uses it, so the wrappers can free it afterwards
+    let font = FontWrapper::open_font(conn, b"cursor")?;
+    let cursor = CursorWrapper::create_glyph_cursor(
+        conn,
+        font.font(),
+        font.font(),
+        XC_CROSSHAIR,
+        XC_CROSSHAIR + 1,
+        0,
+        0,
+        0,
+        0xffff,
+        0xffff,
+        0xffff,
+    )?;
+    let pointer = GrabPointerGuard::new(
+        conn,
+        GrabPointerRequest {
+            owner_events: false,
+            grab_window: root,
+            event_mask: u32::from(event_mask) as u16,
+            pointer_mode: GrabMode::ASYNC,
+            keyboard_mode: GrabMode::ASYNC,
+            confine_to: NONE,
+            cursor: cursor.cursor(),
+            time: CURRENT_TIME,
+        },
+    )?;
+    let keyboard = GrabKeyboardGuard::new(
+        conn,
+        GrabKeyboardRequest {
+            owner_events: false,
+            grab_window: root,
+            time: CURRENT_TIME,
+            pointer_mode: GrabMode::ASYNC,
+            keyboard_mode: GrabMode::ASYNC,
+        },
+    )?;
+    Ok((pointer, keyboard))
+}
+
+/// Let the user select a rectangle on the given screen.
+///
+/// This grabs the pointer and the keyboard and waits for the user to drag a rectangle with the
+/// first mouse button. The selected rectangle is returned in root window coordinates and
+/// includes the pixels at both corners. `None` is returned if the user cancelled the selection
+/// with `Escape` or another mouse button.
+///
+/// Events that arrive while this function runs and that are not needed for the selection are
+/// discarded. If the grab fails, e.g. because another client grabbed the pointer,
+/// [`SelectError::Grab`] is returned.
+pub fn select_region<C: Connection + ?Sized>(
+    conn: &C,
+    screen_num: usize,
+) -> Result<Option<Rectangle>, SelectError> {
+    let screen = &conn.setup().roots[screen_num];
+    let root = screen.root;
+    let mut mapping = KeyboardMapping::new(conn)?;
+
+    let gc_aux = CreateGCAux::new()
+        .function(GX::XOR)
+        .foreground(screen.white_pixel ^ screen.black_pixel)
+        .subwindow_mode(SubwindowMode::INCLUDE_INFERIORS);
+    let gc = GcontextWrapper::create_gc(conn, root, &gc_aux)?;
+    let event_mask =
+        EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE | EventMask::POINTER_MOTION;
+    let _grabs = grab_input(conn, root, event_mask)?;
+
+    let draw = |band: &RubberBand| -> Result<(), ConnectionError> {
+        let _ = xproto::poly_rectangle(conn, root, gc.gcontext(), &[band.outline()])?;
+        conn.flush()
+    };
+    let mut band: Option<RubberBand> = None;
+    let result = loop {
+        let event = conn.wait_for_event()?;
+        let _ = mapping.handle_event(conn, &event)?;
+        match event {
+            Event::ButtonPress(event) if event.detail == 1 && band.is_none() => {
+                let new_band = RubberBand::new(event.root_x, event.root_y);
+                draw(&new_band)?;
+                band = Some(new_band);
+            }
+            Event::MotionNotify(event) => {
+                if let Some(band) = band.as_mut() {
+                    if band.end != (event.root_x, event.root_y) {
+                        // Drawing the old rectangle again removes it
+                        draw(band)?;
+                        band.end = (event.root_x, event.root_y);
+                        draw(band)?;
+                    }
+                }
+            }
+            Event::ButtonRelease(event) if event.detail == 1 => {
+                if let Some(mut band) = band.take() {
+                    draw(&band)?;
+                    band.end = (event.root_x, event.root_y);
+                    break Some(band.rectangle());
+                }
+            }
+            Event::ButtonPress(_) => break cancel(&draw, band)?,
+            Event::KeyPress(event)
+                if mapping.keycode_to_keysym(event.detail, 0u16) == KEYSYM_ESCAPE =>
+            {
+                break cancel(&draw, band)?;
+            }
+            _ => {}
+        }
+    };
+    Ok(result)
+}
+
+/// Let the user pick a window on the given screen by clicking on it.
+///
+/// This grabs the pointer and the keyboard and waits for a click with the first mouse button,
+/// like `xwininfo` and `xprop` do. The clicked window is usually a frame of the window manager.
+/// Starting at the frame, the window below the pointer is followed down to the first window with
+/// a `WM_STATE` property, which is the client window. If there is no such window, the top-level
+/// window is returned, or the root window if the click was on the desktop.
+///
+/// `None` is returned if the user cancelled with `Escape` or another mouse button. Events that
+/// arrive while this function runs and that are not needed for picking are discarded.
+pub fn pick_window<C: Connection + ?Sized>(
+    conn: &C,
+    screen_num: usize,
+) -> Result<Option<Window>, SelectError> {
+    let root = conn.setup().roots[screen_num].root;
+    let mut mapping = KeyboardMapping::new(conn)?;
+    let wm_state = xproto::intern_atom(conn, false, b"WM_STATE")?;
+    let wm_state = wm_state.reply()?.atom;
+    let grabs = grab_input(conn, root, EventMask::BUTTON_PRESS)?;
+
+    let (x, y, top_level) = loop {
+        let event = conn.wait_for_event()?;
+        let _ = mapping.handle_event(conn, &event)?;
+        match event {
+            Event::ButtonPress(event) if event.detail == 1 => {
+                break (event.root_x, event.root_y, event.child)
+            }
+            Event::ButtonPress(_) => return Ok(None),
+            Event::KeyPress(event)
+                if mapping.keycode_to_keysym(event.detail, 0u16) == KEYSYM_ESCAPE =>
+            {
+                return Ok(None)
+            }
+            _ => {}
+        }
+    };
+    // Release the grabs before inspecting the windows
+    drop(grabs);
+    if top_level == NONE {
+        return Ok(Some(root));
+    }
+
+    let mut window = top_level;
+    loop {
+        let property =
+            xproto::get_property(conn, false, window, wm_state, AtomEnum::ANY, 0, 0)?.reply()?;
+        if property.type_ != NONE {
+            return Ok(Some(window));
+        }
+        let child = xproto::translate_coordinates(conn, root, window, x, y)?
+            .reply()?
+            .child;
+        if child == NONE {
+            return Ok(Some(top_level));
+        }
+        window = child;
+    }
+}
+
+/// Remove the rubber band, if any, and cancel the selection.
+fn cancel<F>(draw: &F, band: Option<RubberBand>) -> Result<Option<Rectangle>, ConnectionError>
+where
+    F: Fn(&RubberBand) -> Result<(), ConnectionError>,
+{
+    if let Some(band) = band {
+        draw(&band)?;
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::RubberBand;
+    use crate::protocol::xproto::Rectangle;
+
+    #[test]
+    fn rectangle_in_any_direction() {
+        let mut band = RubberBand::new(10, 20);
+        assert_eq!(
+            band.rectangle(),
+            Rectangle {
+                x: 10,
+                y: 20,
+                width: 1,
+                height: 1
+            }
+        );
+
+        band.end = (5, 30);
+        let expected = Rectangle {
+            x: 5,
+            y: 20,
+            width: 6,
+            height: 11,
+        };
+        assert_eq!(band.rectangle(), expected);
+        assert_eq!(
+            band.outline(),
+            Rectangle {
+                width: 5,
+                height: 10,
+                ..expected
+            }
+        );
+
+        // Dragging the other way around selects the same rectangle
+        let mut reverse = RubberBand::new(5, 30);
+        reverse.end = (10, 20);
+        assert_eq!(reverse.rectangle(), expected);
+    }
+}
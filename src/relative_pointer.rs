@@ -0,0 +1,264 @@
+//! Relative mouse mode based on XInput 2 raw motion events.
+//!
+//! First-person games want to know how far the mouse moved, not where the pointer is. Pointer
+//! positions stop changing at the edge of the screen and are subject to pointer acceleration.
+//! XI2 `RawMotion` events report the movement of the device itself, so they work no matter where
+//! the pointer is. [`RelativePointer`] selects these events, hides the pointer, confines it to
+//! the game's window so that clicks do not reach other windows, and sums up the movement until
+//! the game asks for it once per frame.
+//!
+//! This module is only available when the `xinput` feature is enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::protocol::xinput::ConnectionExt as _;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::relative_pointer::RelativePointer;
+//!
+//! fn play(conn: &impl Connection, window: Window) -> Result<(), ReplyOrIdError> {
+//!     conn.xinput_xi_query_version(2, 0)?.reply()?;
+//!     let mut pointer = RelativePointer::new(conn, window)?;
+//!     conn.flush()?;
+//!     loop {
+//!         while let Some(event) = conn.poll_for_event()? {
+//!             let _ = pointer.handle_event(&event);
+//!         }
+//!         let (dx, dy) = pointer.take_delta();
+//!         println!("Turning the camera by {}, {}", dx, dy);
+//!         // Render the frame here
+//!     }
+//! }
+//! ```
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::game_mode::create_invisible_cursor;
+use crate::protocol::xinput::{self, RawMotionEvent};
+use crate::protocol::xproto::{
+    self, ChangeWindowAttributesAux, Cursor, GrabMode, GrabStatus, Window,
+};
+use crate::protocol::Event;
+use crate::scroll_emulation::{fp3232_to_f64, valuator_values};
+use crate::CURRENT_TIME;
+use crate::NONE;
+
+/// Reports the movement of the mouse until this object is dropped.
+///
+/// Creating a `RelativePointer`:
+/// - selects XI2 `RawMotion` events of all master devices on the root window,
+/// - hides the pointer by giving the window an invisible cursor,
+/// - grabs the pointer with the window as `confine_to`.
+///
+/// Dropping it undoes all of this. Since XI2 event selections replace each other, this also
+/// removes other XI2 event selections of this client on the root window.
+#[derive(Debug)]
+pub struct RelativePointer<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    window: Window,
+    root: Window,
+    invisible_cursor: Option<Cursor>,
+    pointer_confined: bool,
+    accelerated: bool,
+    delta: (f64, f64),
+}
+
+impl<'c, C: Connection + ?Sized> RelativePointer<'c, C> {
+    /// Enter relative mouse mode for the given window.
+    ///
+    /// The XInput extension must already be initialised via `XIQueryVersion` with at least
+    /// version 2.0. The pointer can only be grabbed if the window is viewable and nobody else
+    /// grabbed it. A failing grab is not an error; use [`RelativePointer::is_pointer_confined`]
+    /// to check for it.
+    pub fn new(conn: &'c C, window: Window) -> Result<Self, ReplyOrIdError> {
+        let root = xproto::get_geometry(conn, window)?.reply()?.root;
+
+        // From here on, dropping `pointer` undoes everything that was already done
+        let mut pointer = Self {
+            conn,
+            window,
+            root,
+            invisible_cursor: None,
+            pointer_confined: false,
+            accelerated: false,
+            delta: (0.0, 0.0),
+        };
+
+        let _ = select_raw_motion(conn, root, true)?;
+
+        let cursor = create_invisible_cursor(conn, window)?;
+        pointer.invisible_cursor = Some(cursor);
+        let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+        let _ = xproto::change_window_attributes(conn, window, &aux)?;
+
+        let status = xproto::grab_pointer(
+            conn,
+            false,
+            window,
+            0u16,
+            GrabMode::ASYNC,
+            GrabMode::ASYNC,
+            window,
+            cursor,
+            CURRENT_TIME,
+        )?
+        .reply()?
+        .status;
+        pointer.pointer_confined = status == GrabStatus::SUCCESS;
+
+        Ok(pointer)
+    }
+
+    /// Get the window that is in relative mouse mode.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Check if the pointer was successfully grabbed.
+    pub fn is_pointer_confined(&self) -> bool {
+        self.pointer_confined
+    }
+
+    /// Set whether the movement includes pointer acceleration.
+    ///
+    /// By default, the unaccelerated movement of the device is reported, which is what most
+    /// games want.
+    pub fn set_accelerated(&mut self, accelerated: bool) {
+        self.accelerated = accelerated;
+    }
+
+    /// Handle an event.
+    ///
+    /// The movement from `RawMotion` events is added to the accumulated delta. Returns `true` if
+    /// the event was a `RawMotion` event.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::XinputRawMotion(event) => {
+                let (dx, dy) = raw_delta(event, self.accelerated);
+                self.delta.0 += dx;
+                self.delta.1 += dy;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the movement since the last call and reset it to zero.
+    pub fn take_delta(&mut self) -> (f64, f64) {
+        std::mem::replace(&mut self.delta, (0.0, 0.0))
+    }
+
+    /// Leave relative mouse mode and report any errors.
+    ///
+    /// This is the same as dropping this object, except that errors are not ignored.
+    pub fn restore(mut self) -> Result<(), ConnectionError> {
+        let result = self.do_restore();
+        std::mem::forget(self);
+        result
+    }
+
+    fn do_restore(&mut self) -> Result<(), ConnectionError> {
+        let conn = self.conn;
+        if self.pointer_confined {
+            let _ = xproto::ungrab_pointer(conn, CURRENT_TIME)?;
+            self.pointer_confined = false;
+        }
+        if let Some(cursor) = self.invisible_cursor.take() {
+            let aux = ChangeWindowAttributesAux::new().cursor(NONE);
+            let _ = xproto::change_window_attributes(conn, self.window, &aux)?;
+            let _ = xproto::free_cursor(conn, cursor)?;
+        }
+        let _ = select_raw_motion(conn, self.root, false)?;
+        Ok(())
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for RelativePointer<'_, C> {
+    fn drop(&mut self) {
+        let _ = self.do_restore();
+    }
+}
+
+/// Select or deselect `RawMotion` events of all master devices on the given root window.
+fn select_raw_motion<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    select: bool,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let mask = if select {
+        xinput::XIEventMask::RAW_MOTION.into()
+    } else {
+        0
+    };
+    let mask = xinput::EventMask {
+        deviceid: xinput::Device::ALL_MASTER.into(),
+        mask: vec![mask],
+    };
+    xinput::xi_select_events(conn, root, &[mask])
+}
+
+/// Get the movement along the valuators 0 (x) and 1 (y) of a `RawMotion` event.
+fn raw_delta(event: &RawMotionEvent, accelerated: bool) -> (f64, f64) {
+    let values = if accelerated {
+        &event.axisvalues
+    } else {
+        &event.axisvalues_raw
+    };
+    let mut delta = (0.0, 0.0);
+    for (number, value) in valuator_values(&event.valuator_mask, values) {
+        match number {
+            0 => delta.0 = fp3232_to_f64(value),
+            1 => delta.1 = fp3232_to_f64(value),
+            _ => {}
+        }
+    }
+    delta
+}
+
+#[cfg(test)]
+mod test {
+    use super::raw_delta;
+    use crate::protocol::xinput::{Fp3232, RawMotionEvent, RAW_MOTION_EVENT};
+
+    fn raw_motion(
+        valuator_mask: u32,
+        raw: Vec<Fp3232>,
+        accelerated: Vec<Fp3232>,
+    ) -> RawMotionEvent {
+        RawMotionEvent {
+            response_type: 35,
+            extension: 131,
+            sequence: 0,
+            length: 0,
+            event_type: RAW_MOTION_EVENT,
+            deviceid: 2,
+            time: 0,
+            detail: 0,
+            sourceid: 8,
+            flags: 0,
+            valuator_mask: vec![valuator_mask],
+            axisvalues: accelerated,
+            axisvalues_raw: raw,
+        }
+    }
+
+    fn fp(integral: i32, frac: u32) -> Fp3232 {
+        Fp3232 { integral, frac }
+    }
+
+    #[test]
+    fn delta_from_valuators() {
+        let event = raw_motion(
+            0b11,
+            vec![fp(3, 0), fp(-2, 1 << 31)],
+            vec![fp(6, 0), fp(-3, 0)],
+        );
+        assert_eq!(raw_delta(&event, false), (3.0, -1.5));
+        assert_eq!(raw_delta(&event, true), (6.0, -3.0));
+
+        // Only vertical movement, followed by a scroll valuator
+        let event = raw_motion(0b110, vec![fp(4, 0), fp(1, 0)], vec![fp(4, 0), fp(1, 0)]);
+        assert_eq!(raw_delta(&event, false), (0.0, 4.0));
+    }
+}
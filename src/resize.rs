@@ -0,0 +1,239 @@
+//! Debouncing `ConfigureNotify` events during interactive resizes.
+//!
+//! While the user drags the border of a window, the X11 server sends a `ConfigureNotify` event for
+//! every intermediate size. Applications with expensive layouts, e.g. text reflow, should only do
+//! cheap work for these and the full layout once the size stops changing. [`ResizeDebouncer`]
+//! reports each new size as [`ResizeEvent::InProgress`] and reports [`ResizeEvent::Settled`] once
+//! no new size arrived for a quiet period. A change of `_NET_WM_STATE`, e.g. when the window is
+//! maximized, settles the size immediately.
+//!
+//! ```
+//! use std::time::{Duration, Instant};
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, Window};
+//! use x11rb::resize::{ResizeDebouncer, ResizeEvent};
+//! use x11rb::rust_connection::RustConnection;
+//!
+//! fn event_loop(conn: &RustConnection, window: Window) -> Result<(), ReplyError> {
+//!     // PropertyChange and StructureNotify events have to be selected on the window
+//!     let net_wm_state = conn.intern_atom(false, b"_NET_WM_STATE")?.reply()?.atom;
+//!     let mut resize = ResizeDebouncer::new(window, net_wm_state, Duration::from_millis(150));
+//!     loop {
+//!         let timeout = resize.timeout(Instant::now()).unwrap_or(Duration::from_secs(60));
+//!         if let Some(event) = conn.wait_for_event_with_timeout(timeout)? {
+//!             if let Some(ResizeEvent::InProgress { width, height }) =
+//!                 resize.handle_event(&event, Instant::now())
+//!             {
+//!                 println!("Scaling the old content to {}x{}", width, height);
+//!             }
+//!         }
+//!         if let Some(ResizeEvent::Settled { width, height }) = resize.settle(Instant::now()) {
+//!             println!("Doing the full layout for {}x{}", width, height);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::protocol::xproto::{Atom, Window};
+use crate::protocol::Event;
+
+/// A change of the size of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeEvent {
+    /// The window got a new size, but more changes are expected.
+    InProgress {
+        /// The latest width of the window.
+        width: u16,
+        /// The latest height of the window.
+        height: u16,
+    },
+    /// The size of the window stopped changing.
+    Settled {
+        /// The final width of the window.
+        width: u16,
+        /// The final height of the window.
+        height: u16,
+    },
+}
+
+/// Debounces the size changes of one window.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResizeDebouncer {
+    window: Window,
+    net_wm_state: Atom,
+    quiet_period: Duration,
+    /// The last size that was reported as settled.
+    settled: Option<(u16, u16)>,
+    /// The latest size and when it arrived, if it is not settled yet.
+    pending: Option<((u16, u16), Instant)>,
+}
+
+impl ResizeDebouncer {
+    /// Debounce the size changes of the given window.
+    ///
+    /// `net_wm_state` is the atom `_NET_WM_STATE`. A size is considered settled when no new size
+    /// arrived for `quiet_period`.
+    pub fn new(window: Window, net_wm_state: Atom, quiet_period: Duration) -> Self {
+        Self {
+            window,
+            net_wm_state,
+            quiet_period,
+            settled: None,
+            pending: None,
+        }
+    }
+
+    /// Get the last size that was reported as settled.
+    pub fn settled_size(&self) -> Option<(u16, u16)> {
+        self.settled
+    }
+
+    /// Is a resize in progress?
+    pub fn is_resizing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Handle an event.
+    ///
+    /// `ConfigureNotify` events for the window that change its size are reported as
+    /// [`ResizeEvent::InProgress`]. A `PropertyNotify` event for `_NET_WM_STATE` of the window
+    /// settles a resize in progress immediately. All other events are ignored.
+    pub fn handle_event(&mut self, event: &Event, now: Instant) -> Option<ResizeEvent> {
+        match event {
+            Event::ConfigureNotify(event) if event.window == self.window => {
+                self.configure(event.width, event.height, now)
+            }
+            Event::PropertyNotify(event)
+                if event.window == self.window && event.atom == self.net_wm_state =>
+            {
+                self.settle_now()
+            }
+            _ => None,
+        }
+    }
+
+    /// Handle a new size of the window.
+    pub fn configure(&mut self, width: u16, height: u16, now: Instant) -> Option<ResizeEvent> {
+        let size = (width, height);
+        let latest = self.pending.map(|(size, _)| size).or(self.settled);
+        if latest == Some(size) {
+            // Only the position changed
+            return None;
+        }
+        self.pending = Some((size, now));
+        Some(ResizeEvent::InProgress { width, height })
+    }
+
+    /// Get the time until [`ResizeDebouncer::settle`] has to be called.
+    ///
+    /// Returns `None` if no resize is in progress.
+    pub fn timeout(&self, now: Instant) -> Option<Duration> {
+        let (_, last_change) = self.pending?;
+        Some((last_change + self.quiet_period).saturating_duration_since(now))
+    }
+
+    /// Report the size as settled if the quiet period passed.
+    ///
+    /// It is fine to call this function more often than necessary, e.g. after every event.
+    pub fn settle(&mut self, now: Instant) -> Option<ResizeEvent> {
+        if self.timeout(now)? > Duration::from_secs(0) {
+            return None;
+        }
+        self.settle_now()
+    }
+
+    /// Report the size as settled immediately, e.g. when the user released the mouse button.
+    ///
+    /// Returns `None` if no resize is in progress.
+    pub fn settle_now(&mut self) -> Option<ResizeEvent> {
+        let ((width, height), _) = self.pending.take()?;
+        self.settled = Some((width, height));
+        Some(ResizeEvent::Settled { width, height })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::{ResizeDebouncer, ResizeEvent};
+    use crate::protocol::xproto::{ConfigureNotifyEvent, Property, PropertyNotifyEvent};
+    use crate::protocol::Event;
+
+    fn configure(window: u32, width: u16, height: u16) -> Event {
+        Event::ConfigureNotify(ConfigureNotifyEvent {
+            response_type: 22,
+            sequence: 0,
+            event: window,
+            window,
+            above_sibling: 0,
+            x: 0,
+            y: 0,
+            width,
+            height,
+            border_width: 0,
+            override_redirect: false,
+        })
+    }
+
+    #[test]
+    fn settle_after_quiet_period() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut resize = ResizeDebouncer::new(1, 2, ms(100));
+        assert_eq!(resize.timeout(start), None);
+
+        assert_eq!(
+            resize.handle_event(&configure(1, 100, 50), start),
+            Some(ResizeEvent::InProgress {
+                width: 100,
+                height: 50
+            })
+        );
+        assert_eq!(resize.handle_event(&configure(3, 10, 10), start), None);
+        assert_eq!(resize.handle_event(&configure(1, 100, 50), start), None);
+        assert!(resize
+            .handle_event(&configure(1, 120, 60), start + ms(50))
+            .is_some());
+        assert_eq!(resize.timeout(start + ms(100)), Some(ms(50)));
+        assert_eq!(resize.settle(start + ms(100)), None);
+        assert_eq!(
+            resize.settle(start + ms(150)),
+            Some(ResizeEvent::Settled {
+                width: 120,
+                height: 60
+            })
+        );
+        assert_eq!(resize.settled_size(), Some((120, 60)));
+        assert!(!resize.is_resizing());
+        // A move without a size change is not a resize
+        assert_eq!(resize.handle_event(&configure(1, 120, 60), start), None);
+    }
+
+    #[test]
+    fn net_wm_state_settles() {
+        let start = Instant::now();
+        let mut resize = ResizeDebouncer::new(1, 2, Duration::from_secs(1));
+        let _ = resize.handle_event(&configure(1, 800, 600), start);
+        let state = Event::PropertyNotify(PropertyNotifyEvent {
+            response_type: 28,
+            sequence: 0,
+            window: 1,
+            atom: 2,
+            time: 0,
+            state: Property::NEW_VALUE,
+        });
+        assert_eq!(
+            resize.handle_event(&state, start),
+            Some(ResizeEvent::Settled {
+                width: 800,
+                height: 600
+            })
+        );
+        assert_eq!(resize.handle_event(&state, start), None);
+    }
+}
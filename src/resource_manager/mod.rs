@@ -4,7 +4,7 @@
 //! a value is needed):
 //! ```
 //! use x11rb::{connection::Connection, errors::ReplyError, resource_manager::Database};
-//! fn get_xft_dpi(conn: &impl Connection) -> Result<Option<u32>, ReplyError> {
+//! fn get_xft_dpi(conn: &(impl Connection + ?Sized)) -> Result<Option<u32>, ReplyError> {
 //!     let db = Database::new_from_default(conn)?;
 //!     let value = db.get_value("Xft.dpi", "");
 //!     Ok(value.ok().flatten())
@@ -28,6 +28,9 @@ use crate::protocol::xproto::{AtomEnum, ConnectionExt as _};
 
 mod matcher;
 mod parser;
+mod xft;
+
+pub use xft::{FontPattern, HintStyle, LcdFilter, SubpixelOrder, XftSettings};
 
 /// Maximum nesting of #include directives, same value as Xlib uses.
 /// After following this many `#include` directives, further includes are ignored.
@@ -91,7 +94,7 @@ impl Database {
     ///
     /// The behaviour of this function is equivalent to xcb-util-xrm's
     /// `xcb_xrm_database_from_default()`.
-    pub fn new_from_default(conn: &impl Connection) -> Result<Self, ReplyError> {
+    pub fn new_from_default(conn: &(impl Connection + ?Sized)) -> Result<Self, ReplyError> {
         let cur_dir = Path::new(".");
 
         // 1. Try to load the RESOURCE_MANAGER property
@@ -153,7 +156,9 @@ impl Database {
     /// This function returns an error if the `GetProperty` request to get the `RESOURCE_MANAGER`
     /// property fails. It returns `Ok(None)` if the property does not exist, has the wrong format,
     /// or is empty.
-    pub fn new_from_resource_manager(conn: &impl Connection) -> Result<Option<Self>, ReplyError> {
+    pub fn new_from_resource_manager(
+        conn: &(impl Connection + ?Sized),
+    ) -> Result<Option<Self>, ReplyError> {
         let max_length = 100_000_000; // This is what Xlib does, so it must be correct (tm)
         let window = conn.setup().roots[0].root;
         let property = conn
@@ -0,0 +1,340 @@
+//! Font settings from the `Xft.*` resources.
+//!
+//! Desktop environments publish their font rendering settings as resources, e.g. `Xft.antialias`
+//! and `Xft.hintstyle`. Fonts are described with fontconfig patterns like `DejaVu Sans-10:bold`.
+
+use std::str::FromStr;
+
+use super::Database;
+
+/// A fontconfig font pattern, e.g. `DejaVu Sans,Sans-10.5:bold:antialias=false`.
+///
+/// The syntax is `families-sizes:elements`. Families are separated by commas, as are sizes in
+/// points. Elements are either `name=value` pairs or constants like `bold` and `italic`. Weights
+/// and slants use the same numeric values as fontconfig, e.g. `200` for `bold`, so that they can
+/// directly be given to fontconfig.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FontPattern {
+    /// The font families, most preferred first.
+    pub families: Vec<String>,
+    /// The size in points.
+    pub size: Option<f64>,
+    /// The size in pixels.
+    pub pixel_size: Option<f64>,
+    /// The weight, e.g. `80` for regular or `200` for bold.
+    pub weight: Option<u32>,
+    /// The slant, `0` for roman, `100` for italic, or `110` for oblique.
+    pub slant: Option<u32>,
+    /// All other elements, in the order they appear in the pattern.
+    ///
+    /// Constants other than weights and slants, e.g. `mono`, are kept with an empty value.
+    pub properties: Vec<(String, String)>,
+}
+
+/// Fontconfig's names for weights.
+const WEIGHTS: &[(&str, u32)] = &[
+    ("thin", 0),
+    ("extralight", 40),
+    ("ultralight", 40),
+    ("light", 50),
+    ("demilight", 55),
+    ("semilight", 55),
+    ("book", 75),
+    ("regular", 80),
+    ("normal", 80),
+    ("medium", 100),
+    ("demibold", 180),
+    ("semibold", 180),
+    ("bold", 200),
+    ("extrabold", 205),
+    ("ultrabold", 205),
+    ("black", 210),
+    ("heavy", 210),
+];
+
+/// Fontconfig's names for slants.
+const SLANTS: &[(&str, u32)] = &[("roman", 0), ("italic", 100), ("oblique", 110)];
+
+fn lookup(table: &[(&str, u32)], name: &str) -> Option<u32> {
+    table
+        .iter()
+        .find(|(constant, _)| constant.eq_ignore_ascii_case(name))
+        .map(|&(_, value)| value)
+}
+
+/// Parse a weight or slant that is given as a name or as a number.
+fn parse_constant(table: &[(&str, u32)], value: &str) -> Option<u32> {
+    lookup(table, value).or_else(|| value.parse().ok())
+}
+
+/// Split `value` at the first unescaped character from `separators`.
+///
+/// Backslashes escape the following character and are removed from the first part.
+fn split_unescaped<'a>(value: &'a str, separators: &[char]) -> (String, Option<(char, &'a str)>) {
+    let mut result = String::new();
+    let mut chars = value.char_indices();
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            if let Some((_, escaped)) = chars.next() {
+                result.push(escaped);
+            }
+        } else if separators.contains(&c) {
+            return (result, Some((c, &value[index + c.len_utf8()..])));
+        } else {
+            result.push(c);
+        }
+    }
+    (result, None)
+}
+
+impl FontPattern {
+    /// Parse a font pattern.
+    ///
+    /// Returns `None` if a size, weight, or slant is not valid.
+    pub fn parse(pattern: &str) -> Option<Self> {
+        let mut result = Self::default();
+
+        // The families are separated by ',' and end at the first '-' or ':'
+        let mut next = Some((',', pattern));
+        while let Some((',', rest)) = next {
+            let (family, after) = split_unescaped(rest, &[',', '-', ':']);
+            if !family.is_empty() {
+                result.families.push(family);
+            }
+            next = after;
+        }
+
+        // The sizes are separated by ',' and end at the first ':'
+        if let Some(('-', rest)) = next {
+            let (sizes, after) = split_unescaped(rest, &[':']);
+            // Fontconfig only uses the first size
+            let size = sizes.split(',').next().unwrap_or_default();
+            if !size.is_empty() {
+                result.size = Some(size.parse().ok()?);
+            }
+            next = after;
+        }
+
+        // The elements are separated by ':'
+        while let Some((_, rest)) = next {
+            let (element, after) = split_unescaped(rest, &[':']);
+            next = after;
+            match element.split_once('=') {
+                Some((name, value)) => result.set(name.trim(), value.trim())?,
+                None => result.set_constant(element.trim()),
+            }
+        }
+        Some(result)
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Option<()> {
+        match name {
+            "family" => self.families.push(value.to_string()),
+            "size" => self.size = Some(value.parse().ok()?),
+            "pixelsize" => self.pixel_size = Some(value.parse().ok()?),
+            "weight" => self.weight = Some(parse_constant(WEIGHTS, value)?),
+            "slant" => self.slant = Some(parse_constant(SLANTS, value)?),
+            _ => self.properties.push((name.to_string(), value.to_string())),
+        }
+        Some(())
+    }
+
+    fn set_constant(&mut self, constant: &str) {
+        if constant.is_empty() {
+            return;
+        }
+        if let Some(weight) = lookup(WEIGHTS, constant) {
+            self.weight = Some(weight);
+        } else if let Some(slant) = lookup(SLANTS, constant) {
+            self.slant = Some(slant);
+        } else {
+            self.properties.push((constant.to_string(), String::new()));
+        }
+    }
+}
+
+/// How strongly glyph outlines are fitted to the pixel grid (`Xft.hintstyle`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintStyle {
+    /// `hintnone`
+    None,
+    /// `hintslight`
+    Slight,
+    /// `hintmedium`
+    Medium,
+    /// `hintfull`
+    Full,
+}
+
+impl FromStr for HintStyle {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "hintnone" => Ok(Self::None),
+            "hintslight" => Ok(Self::Slight),
+            "hintmedium" => Ok(Self::Medium),
+            "hintfull" => Ok(Self::Full),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The order of the subpixels of the screen (`Xft.rgba`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubpixelOrder {
+    /// `none`, no subpixel rendering
+    None,
+    /// `rgb`, horizontal red, green, blue
+    Rgb,
+    /// `bgr`, horizontal blue, green, red
+    Bgr,
+    /// `vrgb`, vertical red, green, blue
+    VRgb,
+    /// `vbgr`, vertical blue, green, red
+    VBgr,
+}
+
+impl FromStr for SubpixelOrder {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Self::None),
+            "rgb" => Ok(Self::Rgb),
+            "bgr" => Ok(Self::Bgr),
+            "vrgb" => Ok(Self::VRgb),
+            "vbgr" => Ok(Self::VBgr),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The filter for subpixel rendering (`Xft.lcdfilter`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LcdFilter {
+    /// `lcdnone`
+    None,
+    /// `lcddefault`
+    Default,
+    /// `lcdlight`
+    Light,
+    /// `lcdlegacy`
+    Legacy,
+}
+
+impl FromStr for LcdFilter {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "lcdnone" => Ok(Self::None),
+            "lcddefault" => Ok(Self::Default),
+            "lcdlight" => Ok(Self::Light),
+            "lcdlegacy" => Ok(Self::Legacy),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The font rendering settings from the `Xft.*` resources.
+///
+/// Settings that are not set or cannot be parsed are `None`, so that the renderer can use its own
+/// defaults.
+///
+/// ```
+/// use x11rb::resource_manager::{Database, HintStyle, XftSettings};
+///
+/// let db = Database::new_from_data(b"Xft.font: Sans-11\nXft.hintstyle: hintslight\n");
+/// let settings = XftSettings::from_database(&db);
+/// assert_eq!(settings.font.unwrap().families, ["Sans"]);
+/// assert_eq!(settings.hint_style, Some(HintStyle::Slight));
+/// assert_eq!(settings.antialias, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XftSettings {
+    /// The default font (`Xft.font`).
+    pub font: Option<FontPattern>,
+    /// The resolution in dots per inch (`Xft.dpi`).
+    pub dpi: Option<f64>,
+    /// Whether glyphs are anti-aliased (`Xft.antialias`).
+    pub antialias: Option<bool>,
+    /// Whether glyphs are hinted (`Xft.hinting`).
+    pub hinting: Option<bool>,
+    /// How strongly glyphs are hinted (`Xft.hintstyle`).
+    pub hint_style: Option<HintStyle>,
+    /// Whether the autohinter is used instead of the font's hints (`Xft.autohint`).
+    pub autohint: Option<bool>,
+    /// The subpixel order for subpixel rendering (`Xft.rgba`).
+    pub subpixel_order: Option<SubpixelOrder>,
+    /// The filter for subpixel rendering (`Xft.lcdfilter`).
+    pub lcd_filter: Option<LcdFilter>,
+}
+
+impl XftSettings {
+    /// Get the settings from a resource database.
+    pub fn from_database(database: &Database) -> Self {
+        let get = |name| database.get_string(name, "");
+        Self {
+            font: get("Xft.font").and_then(FontPattern::parse),
+            dpi: database.get_value("Xft.dpi", "").ok().flatten(),
+            antialias: database.get_bool("Xft.antialias", ""),
+            hinting: database.get_bool("Xft.hinting", ""),
+            hint_style: get("Xft.hintstyle").and_then(|value| value.parse().ok()),
+            autohint: database.get_bool("Xft.autohint", ""),
+            subpixel_order: get("Xft.rgba").and_then(|value| value.parse().ok()),
+            lcd_filter: get("Xft.lcdfilter").and_then(|value| value.parse().ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FontPattern, LcdFilter, SubpixelOrder, XftSettings};
+    use crate::resource_manager::Database;
+
+    #[test]
+    fn parse_patterns() {
+        let pattern =
+            FontPattern::parse("DejaVu Sans,Sans-10.5:bold:italic:antialias=false").unwrap();
+        assert_eq!(pattern.families, ["DejaVu Sans", "Sans"]);
+        assert_eq!(pattern.size, Some(10.5));
+        assert_eq!(pattern.weight, Some(200));
+        assert_eq!(pattern.slant, Some(100));
+        assert_eq!(
+            pattern.properties,
+            [("antialias".to_string(), "false".to_string())]
+        );
+
+        let pattern = FontPattern::parse("Foo\\-Bar:pixelsize=12:weight=medium:mono").unwrap();
+        assert_eq!(pattern.families, ["Foo-Bar"]);
+        assert_eq!(pattern.size, None);
+        assert_eq!(pattern.pixel_size, Some(12.0));
+        assert_eq!(pattern.weight, Some(100));
+        assert_eq!(pattern.properties, [("mono".to_string(), String::new())]);
+
+        let pattern = FontPattern::parse(":family=Monospace:slant=110").unwrap();
+        assert_eq!(pattern.families, ["Monospace"]);
+        assert_eq!(pattern.slant, Some(110));
+
+        assert_eq!(FontPattern::parse("Sans-big"), None);
+        assert_eq!(FontPattern::parse("Sans:weight=heavyish"), None);
+    }
+
+    #[test]
+    fn settings_from_database() {
+        let db = Database::new_from_data(
+            b"Xft.antialias: 1\nXft.hinting: false\nXft.rgba: vbgr\nXft.lcdfilter: lcddefault\n\
+              Xft.dpi: 144\nXft.autohint: maybe\n",
+        );
+        let settings = XftSettings::from_database(&db);
+        assert_eq!(settings.font, None);
+        assert_eq!(settings.dpi, Some(144.0));
+        assert_eq!(settings.antialias, Some(true));
+        assert_eq!(settings.hinting, Some(false));
+        assert_eq!(settings.hint_style, None);
+        assert_eq!(settings.autohint, None);
+        assert_eq!(settings.subpixel_order, Some(SubpixelOrder::VBgr));
+        assert_eq!(settings.lcd_filter, Some(LcdFilter::Default));
+    }
+}
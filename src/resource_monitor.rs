@@ -0,0 +1,473 @@
+//! Monitoring the resources that X11 clients use, similar to `xrestop`.
+//!
+//! Leaking pixmaps or windows in an X11 client makes the X11 server grow, which is hard to
+//! attribute without help. The X-Resource extension reports how many resources of which type each
+//! client owns and how much memory its pixmaps use. [`ResourceMonitor`] polls these numbers
+//! periodically, correlates each client with the title of one of its windows and with its process
+//! ID, and reports what changed since the last poll as [`UsageDelta`]s.
+//!
+//! ```
+//! use std::time::{Duration, Instant};
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::resource_monitor::ResourceMonitor;
+//!
+//! fn watch(conn: &impl Connection) -> Result<(), ReplyError> {
+//!     let mut monitor = ResourceMonitor::new(Duration::from_secs(2));
+//!     loop {
+//!         for delta in monitor.poll(conn, Instant::now())? {
+//!             let client = monitor.client(delta.resource_base);
+//!             let name = client.and_then(|client| client.name.as_deref());
+//!             println!(
+//!                 "{:#x} ({}): {:?}, {:+} resources, {:+} pixmap bytes",
+//!                 delta.resource_base,
+//!                 name.unwrap_or("?"),
+//!                 delta.change,
+//!                 delta.resources,
+//!                 delta.pixmap_bytes,
+//!             );
+//!         }
+//!         std::thread::sleep(monitor.timeout(Instant::now()));
+//!     }
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+use crate::atom_cache::AtomCache;
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::Cookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::res::{self, ClientIdMask, ClientIdSpec};
+use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
+
+/// The resources that one X11 client uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientUsage {
+    /// The first ID of the range of IDs of the client.
+    pub resource_base: u32,
+    /// The mask of the range of IDs of the client.
+    pub resource_mask: u32,
+    /// The process ID of the client, if it is known to the X11 server.
+    ///
+    /// This is only available for local clients and requires X-Resource 1.2.
+    pub pid: Option<u32>,
+    /// The title of one of the windows of the client.
+    pub name: Option<String>,
+    /// The number of resources of each type. Types are identified by atoms like `PIXMAP`.
+    pub resources: BTreeMap<Atom, u32>,
+    /// The number of bytes that the pixmaps of the client use.
+    pub pixmap_bytes: u64,
+}
+
+impl ClientUsage {
+    /// Get the number of resources of all types.
+    pub fn total_resources(&self) -> u64 {
+        self.resources.values().map(|&count| u64::from(count)).sum()
+    }
+
+    /// Check whether the given ID belongs to the range of IDs of this client.
+    pub fn owns(&self, id: u32) -> bool {
+        id & !self.resource_mask == self.resource_base
+    }
+}
+
+/// How the resource usage of a client changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageChange {
+    /// The client connected since the last poll.
+    Connected,
+    /// The client uses a different number of resources or pixmap bytes than before.
+    Changed,
+    /// The client disconnected since the last poll. The resources it used were freed.
+    Disconnected,
+}
+
+/// The change of the resource usage of a client between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageDelta {
+    /// The first ID of the range of IDs of the client.
+    pub resource_base: u32,
+    /// What happened to the client.
+    pub change: UsageChange,
+    /// The change of the number of resources of all types.
+    pub resources: i64,
+    /// The change of the number of bytes used by pixmaps.
+    pub pixmap_bytes: i64,
+    /// The change of the number of resources for each type whose count changed.
+    pub types: BTreeMap<Atom, i64>,
+}
+
+/// Periodically polls the resource usage of all X11 clients.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct ResourceMonitor {
+    interval: Duration,
+    last_poll: Option<Instant>,
+    clients: BTreeMap<u32, ClientUsage>,
+    atoms: AtomCache,
+}
+
+impl ResourceMonitor {
+    /// Create a new monitor that polls at most once per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_poll: None,
+            clients: BTreeMap::new(),
+            atoms: AtomCache::new(),
+        }
+    }
+
+    /// Get the usage of all clients as of the last poll, ordered by their resource base.
+    pub fn clients(&self) -> impl Iterator<Item = &ClientUsage> + '_ {
+        self.clients.values()
+    }
+
+    /// Get the usage of the client with the given resource base as of the last poll.
+    pub fn client(&self, resource_base: u32) -> Option<&ClientUsage> {
+        self.clients.get(&resource_base)
+    }
+
+    /// Get the usage of the client that owns the given ID as of the last poll.
+    pub fn client_owning(&self, id: u32) -> Option<&ClientUsage> {
+        self.clients.values().find(|client| client.owns(id))
+    }
+
+    /// Get the name of a resource type, e.g. `PIXMAP`.
+    ///
+    /// The names of all types that a poll encountered are cached.
+    pub fn type_name(&self, resource_type: Atom) -> Option<&[u8]> {
+        self.atoms.name(resource_type)
+    }
+
+    /// Get the time until [`ResourceMonitor::poll`] has to be called again.
+    pub fn timeout(&self, now: Instant) -> Duration {
+        match self.last_poll {
+            None => Duration::from_secs(0),
+            Some(last_poll) => (last_poll + self.interval).saturating_duration_since(now),
+        }
+    }
+
+    /// Poll the resource usage if the interval expired.
+    ///
+    /// This returns no deltas if the timeout did not expire yet, so it is fine to call this
+    /// function more often than necessary. The first poll reports all clients as
+    /// [`UsageChange::Connected`].
+    pub fn poll<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        now: Instant,
+    ) -> Result<Vec<UsageDelta>, ReplyError> {
+        if self.timeout(now) > Duration::from_secs(0) {
+            return Ok(Vec::new());
+        }
+        self.refresh(conn, now)
+    }
+
+    /// Poll the resource usage immediately, regardless of the interval.
+    ///
+    /// Returns [`ConnectionError::UnsupportedExtension`] if the X11 server does not support the
+    /// X-Resource extension.
+    pub fn refresh<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        now: Instant,
+    ) -> Result<Vec<UsageDelta>, ReplyError> {
+        let clients = query_usage(conn)?;
+        let mut types = clients
+            .values()
+            .flat_map(|client| client.resources.keys().copied())
+            .collect::<Vec<_>>();
+        types.sort_unstable();
+        types.dedup();
+        let _ = self.atoms.atom_names(conn, &types)?;
+        let deltas = diff(&self.clients, &clients);
+        self.clients = clients;
+        self.last_poll = Some(now);
+        Ok(deltas)
+    }
+}
+
+/// Query the resource usage of all clients.
+fn query_usage<C: Connection + ?Sized>(conn: &C) -> Result<BTreeMap<u32, ClientUsage>, ReplyError> {
+    if conn
+        .extension_information(res::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Err(ConnectionError::UnsupportedExtension.into());
+    }
+    // QueryClientIds was added in version 1.2
+    let version = res::query_version(conn, 1, 2)?.reply()?;
+    let has_client_ids = (version.server_major, version.server_minor) >= (1, 2);
+
+    let clients = res::query_clients(conn)?.reply()?.clients;
+    let cookies = clients
+        .iter()
+        .map(|client| {
+            Ok((
+                res::query_client_resources(conn, client.resource_base)?,
+                res::query_client_pixmap_bytes(conn, client.resource_base)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    let mut usage = BTreeMap::new();
+    for (client, (resources, pixmap_bytes)) in clients.into_iter().zip(cookies) {
+        let (resources, pixmap_bytes) = match (resources.reply(), pixmap_bytes.reply()) {
+            (Ok(resources), Ok(pixmap_bytes)) => (resources, pixmap_bytes),
+            // The client disconnected in the meantime
+            (Err(ReplyError::X11Error(_)), _) | (_, Err(ReplyError::X11Error(_))) => continue,
+            (Err(err), _) | (_, Err(err)) => return Err(err),
+        };
+        let _ = usage.insert(
+            client.resource_base,
+            ClientUsage {
+                resource_base: client.resource_base,
+                resource_mask: client.resource_mask,
+                pid: None,
+                name: None,
+                resources: resources
+                    .types
+                    .iter()
+                    .map(|ty| (ty.resource_type, ty.count))
+                    .collect(),
+                pixmap_bytes: u64::from(pixmap_bytes.bytes)
+                    | u64::from(pixmap_bytes.bytes_overflow) << 32,
+            },
+        );
+    }
+    if has_client_ids {
+        query_pids(conn, &mut usage)?;
+    }
+    query_names(conn, &mut usage)?;
+    Ok(usage)
+}
+
+/// Fill in the process IDs of the clients.
+fn query_pids<C: RequestConnection + ?Sized>(
+    conn: &C,
+    usage: &mut BTreeMap<u32, ClientUsage>,
+) -> Result<(), ReplyError> {
+    let specs = usage
+        .keys()
+        .map(|&client| ClientIdSpec {
+            client,
+            mask: ClientIdMask::LOCAL_CLIENT_PID.into(),
+        })
+        .collect::<Vec<_>>();
+    for id in res::query_client_ids(conn, &specs)?.reply()?.ids {
+        if let (Some(client), Some(&pid)) = (usage.get_mut(&id.spec.client), id.value.first()) {
+            client.pid = Some(pid);
+        }
+    }
+    Ok(())
+}
+
+/// Fill in the names of the clients from the titles of their windows.
+///
+/// The window tree is searched breadth-first, so the name of a top-level window is preferred
+/// over the names of its children. `_NET_WM_NAME` is preferred over `WM_NAME`.
+fn query_names<C: Connection + ?Sized>(
+    conn: &C,
+    usage: &mut BTreeMap<u32, ClientUsage>,
+) -> Result<(), ReplyError> {
+    let net_wm_name = xproto::intern_atom(conn, false, b"_NET_WM_NAME")?;
+    let utf8_string = xproto::intern_atom(conn, false, b"UTF8_STRING")?;
+    let (net_wm_name, utf8_string) = (net_wm_name.reply()?.atom, utf8_string.reply()?.atom);
+
+    let mut level = conn
+        .setup()
+        .roots
+        .iter()
+        .map(|screen| screen.root)
+        .collect::<Vec<_>>();
+    while !level.is_empty() {
+        // Windows that belong to a client that still needs a name
+        let mut candidates: HashMap<u32, Vec<Window>> = HashMap::new();
+        for &window in &level {
+            let owner = usage
+                .values()
+                .find(|client| client.name.is_none() && client.owns(window));
+            if let Some(owner) = owner {
+                candidates
+                    .entry(owner.resource_base)
+                    .or_default()
+                    .push(window);
+            }
+        }
+        let cookies = candidates
+            .iter()
+            .flat_map(|(&client, windows)| windows.iter().map(move |&window| (client, window)))
+            .map(|(client, window)| {
+                Ok((
+                    client,
+                    get_name(conn, window, net_wm_name, utf8_string)?,
+                    get_name(conn, window, AtomEnum::WM_NAME.into(), AtomEnum::ANY.into())?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+        let tree_cookies = level
+            .iter()
+            .map(|&window| xproto::query_tree(conn, window))
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+
+        for (client, net_name, name) in cookies {
+            let name = decode_name(net_name.reply())?.or(decode_name(name.reply())?);
+            if let Some(client) = usage.get_mut(&client) {
+                if client.name.is_none() {
+                    client.name = name;
+                }
+            }
+        }
+        let mut next_level = Vec::new();
+        for cookie in tree_cookies {
+            match cookie.reply() {
+                Ok(tree) => next_level.extend(tree.children),
+                // The window was destroyed in the meantime
+                Err(ReplyError::X11Error(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        level = next_level;
+    }
+    Ok(())
+}
+
+fn get_name<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    property: Atom,
+    type_: Atom,
+) -> Result<Cookie<'_, C, GetPropertyReply>, ConnectionError> {
+    xproto::get_property(conn, false, window, property, type_, 0, 256)
+}
+
+/// Decode the reply to [`get_name`]. Errors because the window was destroyed are ignored.
+fn decode_name(reply: Result<GetPropertyReply, ReplyError>) -> Result<Option<String>, ReplyError> {
+    match reply {
+        Ok(reply) if reply.format == 8 && !reply.value.is_empty() => {
+            Ok(Some(String::from_utf8_lossy(&reply.value).into_owned()))
+        }
+        Ok(_) | Err(ReplyError::X11Error(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Compute the changes between two polls.
+fn diff(old: &BTreeMap<u32, ClientUsage>, new: &BTreeMap<u32, ClientUsage>) -> Vec<UsageDelta> {
+    let empty = BTreeMap::new();
+    let mut deltas = Vec::new();
+    for (&resource_base, client) in new {
+        let (change, old_resources, old_pixmap_bytes) = match old.get(&resource_base) {
+            // A new client can reuse the resource base of a client that disconnected
+            Some(old) if old.resource_mask == client.resource_mask => {
+                (UsageChange::Changed, &old.resources, old.pixmap_bytes)
+            }
+            _ => (UsageChange::Connected, &empty, 0),
+        };
+        let delta = UsageDelta {
+            resource_base,
+            change,
+            resources: client.total_resources() as i64 - sum(old_resources) as i64,
+            pixmap_bytes: client.pixmap_bytes as i64 - old_pixmap_bytes as i64,
+            types: diff_types(old_resources, &client.resources),
+        };
+        if change == UsageChange::Connected || delta.pixmap_bytes != 0 || !delta.types.is_empty() {
+            deltas.push(delta);
+        }
+    }
+    for (&resource_base, client) in old {
+        if !new.contains_key(&resource_base) {
+            deltas.push(UsageDelta {
+                resource_base,
+                change: UsageChange::Disconnected,
+                resources: -(client.total_resources() as i64),
+                pixmap_bytes: -(client.pixmap_bytes as i64),
+                types: diff_types(&client.resources, &empty),
+            });
+        }
+    }
+    deltas
+}
+
+fn sum(resources: &BTreeMap<Atom, u32>) -> u64 {
+    resources.values().map(|&count| u64::from(count)).sum()
+}
+
+fn diff_types(old: &BTreeMap<Atom, u32>, new: &BTreeMap<Atom, u32>) -> BTreeMap<Atom, i64> {
+    old.keys()
+        .chain(new.keys())
+        .filter_map(|ty| {
+            let old = old.get(ty).copied().unwrap_or(0);
+            let new = new.get(ty).copied().unwrap_or(0);
+            if old == new {
+                None
+            } else {
+                Some((*ty, i64::from(new) - i64::from(old)))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::{diff, ClientUsage, UsageChange};
+
+    fn client(resource_base: u32, resources: &[(u32, u32)], pixmap_bytes: u64) -> ClientUsage {
+        ClientUsage {
+            resource_base,
+            resource_mask: 0x1f_ffff,
+            pid: None,
+            name: None,
+            resources: resources.iter().copied().collect(),
+            pixmap_bytes,
+        }
+    }
+
+    fn clients(clients: Vec<ClientUsage>) -> BTreeMap<u32, ClientUsage> {
+        clients
+            .into_iter()
+            .map(|client| (client.resource_base, client))
+            .collect()
+    }
+
+    #[test]
+    fn usage_deltas() {
+        let old = clients(vec![
+            client(0x200000, &[(1, 2), (2, 5)], 100),
+            client(0x400000, &[(1, 1)], 0),
+            client(0x600000, &[(2, 3)], 50),
+        ]);
+        let new = clients(vec![
+            client(0x200000, &[(1, 4)], 300),
+            client(0x400000, &[(1, 1)], 0),
+            client(0x800000, &[(3, 7)], 10),
+        ]);
+        let deltas = diff(&old, &new);
+        let changes = deltas
+            .iter()
+            .map(|delta| (delta.resource_base, delta.change))
+            .collect::<Vec<_>>();
+        // The unchanged client is not reported
+        assert_eq!(
+            changes,
+            [
+                (0x200000, UsageChange::Changed),
+                (0x800000, UsageChange::Connected),
+                (0x600000, UsageChange::Disconnected),
+            ]
+        );
+
+        assert_eq!(deltas[0].resources, -3);
+        assert_eq!(deltas[0].pixmap_bytes, 200);
+        assert_eq!(deltas[0].types, vec![(1, 2), (2, -5)].into_iter().collect());
+        assert_eq!(deltas[1].resources, 7);
+        assert_eq!(deltas[2].resources, -3);
+        assert_eq!(deltas[2].pixmap_bytes, -50);
+        assert_eq!(deltas[2].types, vec![(2, -3)].into_iter().collect());
+    }
+}
@@ -0,0 +1,362 @@
+//! A layer that refuses to send some requests.
+//!
+//! Applications that hand out access to their X11 connection to less trusted code (plugins,
+//! scripting engines, ...) might want to limit what this code can do. [`Restrictions`] is a
+//! [`Layer`] that fails with [`ConnectionError::RequestDenied`] when a denied request is sent.
+//! Denied requests never reach the X11 server.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::restricted_connection::{RequestCategory, RestrictedConnection, Restrictions};
+//!
+//! fn restrict<C: Connection>(conn: C) -> RestrictedConnection<C> {
+//!     conn.wrap(
+//!         Restrictions::new()
+//!             .deny(RequestCategory::Xtest)
+//!             .deny(RequestCategory::KeyboardGrabs)
+//!             .deny(RequestCategory::ScreenCapture),
+//!     )
+//! }
+//! ```
+//!
+//! Please note that this is not a security boundary against code that can access the wrapped
+//! connection (or its file descriptor) directly.
+
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+use crate::middleware::{Layer, Layered, OutgoingRequest};
+use crate::protocol::xproto::{GET_IMAGE_REQUEST, GRAB_KEYBOARD_REQUEST, GRAB_KEY_REQUEST};
+use crate::x11_utils::ExtensionInformation;
+
+/// Name of the XTEST extension
+const XTEST_NAME: &str = "XTEST";
+
+/// Name of the MIT-SHM extension
+const SHM_NAME: &str = "MIT-SHM";
+
+/// Minor opcode of MIT-SHM's GetImage request
+const SHM_GET_IMAGE_REQUEST: u8 = 4;
+
+/// Name of the X Input extension
+const XINPUT_NAME: &str = "XInputExtension";
+
+/// Minor opcode of XInput's GrabDevice request
+const XINPUT_GRAB_DEVICE_REQUEST: u8 = 13;
+
+/// Minor opcode of XInput's GrabDeviceKey request
+const XINPUT_GRAB_DEVICE_KEY_REQUEST: u8 = 15;
+
+/// Minor opcode of XInput's XIGrabDevice request
+const XINPUT_XI_GRAB_DEVICE_REQUEST: u8 = 51;
+
+/// Minor opcode of XInput's XIPassiveGrabDevice request
+const XINPUT_XI_PASSIVE_GRAB_DEVICE_REQUEST: u8 = 54;
+
+/// Name of the Composite extension
+const COMPOSITE_NAME: &str = "Composite";
+
+/// Minor opcode of Composite's NameWindowPixmap request
+const COMPOSITE_NAME_WINDOW_PIXMAP_REQUEST: u8 = 6;
+
+/// Name of the DRI3 extension
+const DRI3_NAME: &str = "DRI3";
+
+/// Minor opcode of DRI3's BufferFromPixmap request
+const DRI3_BUFFER_FROM_PIXMAP_REQUEST: u8 = 3;
+
+/// Minor opcode of DRI3's BuffersFromPixmap request
+const DRI3_BUFFERS_FROM_PIXMAP_REQUEST: u8 = 8;
+
+/// Predefined groups of requests that can be denied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RequestCategory {
+    /// All requests of the XTEST extension, which allows to fake input events.
+    ///
+    /// The XTEST extension is reported as missing by the wrapper.
+    Xtest,
+    /// The core `GrabKeyboard` and `GrabKey` requests and the `GrabDevice`, `GrabDeviceKey`,
+    /// `XIGrabDevice` and `XIPassiveGrabDevice` requests of the X Input extension.
+    ///
+    /// The X Input requests can grab any device, so pointer grabs via X Input are denied, too.
+    KeyboardGrabs,
+    /// Requests that read back the contents of windows: The core `GetImage` request, the
+    /// `GetImage` request of the MIT-SHM extension, the `NameWindowPixmap` request of the
+    /// Composite extension and the `BufferFromPixmap` and `BuffersFromPixmap` requests of the
+    /// DRI3 extension.
+    ///
+    /// Other extensions that give access to rendered contents, for example GLX, are not covered.
+    /// Deny them via [`Restrictions::deny_extension`] if needed.
+    ScreenCapture,
+}
+
+/// A rule describing a denied request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rule {
+    /// A core request with the given major opcode
+    Core(u8),
+    /// All requests of an extension
+    Extension(&'static str),
+    /// A request of an extension with the given minor opcode
+    ExtensionRequest(&'static str, u8),
+}
+
+/// A connection that refuses to send some requests.
+///
+/// Such a connection is created by wrapping a connection in [`Restrictions`] via
+/// [`Connection::wrap`](crate::connection::Connection::wrap).
+pub type RestrictedConnection<C> = Layered<C, Restrictions>;
+
+/// A [`Layer`] that refuses to send some requests.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Default)]
+pub struct Restrictions {
+    rules: Vec<Rule>,
+}
+
+impl Restrictions {
+    /// Create a new instance. Initially, no requests are denied.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deny all requests in the given category.
+    pub fn deny(self, category: RequestCategory) -> Self {
+        match category {
+            RequestCategory::Xtest => self.deny_extension(XTEST_NAME),
+            RequestCategory::KeyboardGrabs => self
+                .deny_core_request(GRAB_KEYBOARD_REQUEST)
+                .deny_core_request(GRAB_KEY_REQUEST)
+                .deny_extension_request(XINPUT_NAME, XINPUT_GRAB_DEVICE_REQUEST)
+                .deny_extension_request(XINPUT_NAME, XINPUT_GRAB_DEVICE_KEY_REQUEST)
+                .deny_extension_request(XINPUT_NAME, XINPUT_XI_GRAB_DEVICE_REQUEST)
+                .deny_extension_request(XINPUT_NAME, XINPUT_XI_PASSIVE_GRAB_DEVICE_REQUEST),
+            RequestCategory::ScreenCapture => self
+                .deny_core_request(GET_IMAGE_REQUEST)
+                .deny_extension_request(SHM_NAME, SHM_GET_IMAGE_REQUEST)
+                .deny_extension_request(COMPOSITE_NAME, COMPOSITE_NAME_WINDOW_PIXMAP_REQUEST)
+                .deny_extension_request(DRI3_NAME, DRI3_BUFFER_FROM_PIXMAP_REQUEST)
+                .deny_extension_request(DRI3_NAME, DRI3_BUFFERS_FROM_PIXMAP_REQUEST),
+        }
+    }
+
+    /// Deny the core request with the given major opcode.
+    pub fn deny_core_request(mut self, major_opcode: u8) -> Self {
+        self.rules.push(Rule::Core(major_opcode));
+        self
+    }
+
+    /// Deny all requests of the given extension.
+    ///
+    /// The extension is also reported as not present via `extension_information()`.
+    pub fn deny_extension(mut self, extension_name: &'static str) -> Self {
+        self.rules.push(Rule::Extension(extension_name));
+        self
+    }
+
+    /// Deny the request of the given extension with the given minor opcode.
+    pub fn deny_extension_request(
+        mut self,
+        extension_name: &'static str,
+        minor_opcode: u8,
+    ) -> Self {
+        self.rules
+            .push(Rule::ExtensionRequest(extension_name, minor_opcode));
+        self
+    }
+
+    fn is_extension_denied(&self, extension_name: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| matches!(rule, Rule::Extension(name) if *name == extension_name))
+    }
+}
+
+impl Layer for Restrictions {
+    fn on_request<C>(
+        &self,
+        conn: &C,
+        request: &mut OutgoingRequest<'_>,
+    ) -> Result<(), ConnectionError>
+    where
+        C: RequestConnection + ?Sized,
+    {
+        let (major, minor) = (request.major_opcode(), request.minor_opcode());
+        for rule in &self.rules {
+            let denied = match *rule {
+                Rule::Core(opcode) => major == opcode,
+                Rule::Extension(name) => has_major_opcode(conn, name, major)?,
+                Rule::ExtensionRequest(name, opcode) => {
+                    minor == opcode && has_major_opcode(conn, name, major)?
+                }
+            };
+            if denied {
+                return Err(ConnectionError::RequestDenied);
+            }
+        }
+        Ok(())
+    }
+
+    fn on_extension_information(
+        &self,
+        extension_name: &'static str,
+        info: Option<ExtensionInformation>,
+    ) -> Option<ExtensionInformation> {
+        if self.is_extension_denied(extension_name) {
+            None
+        } else {
+            info
+        }
+    }
+}
+
+/// Check if the given extension has the given major opcode.
+fn has_major_opcode<C: RequestConnection + ?Sized>(
+    conn: &C,
+    extension_name: &'static str,
+    major: u8,
+) -> Result<bool, ConnectionError> {
+    // Core requests cannot belong to an extension. This also avoids recursion when the
+    // QueryExtension request is sent.
+    if major < 128 {
+        return Ok(false);
+    }
+    Ok(conn
+        .extension_information(extension_name)?
+        .map(|info| info.major_opcode)
+        == Some(major))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::{RequestCategory, RestrictedConnection, Restrictions};
+    use crate::connection::{Connection, RequestConnection};
+    use crate::errors::ConnectionError;
+    use crate::protocol::xproto::{self, ConnectionExt as _};
+    use crate::testing::FakeConnection;
+    use crate::x11_utils::ExtensionInformation;
+
+    /// A connection that knows XTEST, MIT-SHM, XInputExtension, Composite and DRI3.
+    fn fake_connection() -> FakeConnection {
+        let conn = FakeConnection::default();
+        let extensions = [
+            ("XTEST", 130),
+            ("MIT-SHM", 131),
+            ("XInputExtension", 132),
+            ("Composite", 133),
+            ("DRI3", 134),
+        ];
+        for (name, major_opcode) in extensions {
+            let info = ExtensionInformation {
+                major_opcode,
+                first_event: 0,
+                first_error: 0,
+            };
+            conn.add_extension(name, info);
+        }
+        conn
+    }
+
+    /// Get the major opcodes of the requests that reached the connection.
+    fn sent_opcodes(conn: &RestrictedConnection<FakeConnection>) -> Vec<u8> {
+        conn.inner()
+            .sent_requests()
+            .iter()
+            .map(|request| request.major_opcode())
+            .collect()
+    }
+
+    fn assert_denied<T: std::fmt::Debug>(result: Result<T, ConnectionError>) {
+        match result {
+            Err(ConnectionError::RequestDenied) => {}
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn deny_core_requests() {
+        let conn = fake_connection().wrap(
+            Restrictions::new()
+                .deny(RequestCategory::KeyboardGrabs)
+                .deny(RequestCategory::ScreenCapture),
+        );
+        assert_denied(conn.grab_keyboard(
+            false,
+            0,
+            0u32,
+            xproto::GrabMode::ASYNC,
+            xproto::GrabMode::ASYNC,
+        ));
+        assert_denied(conn.get_image(xproto::ImageFormat::Z_PIXMAP, 0, 0, 0, 1, 1, !0));
+        // Pointer grabs are still allowed
+        let _ = conn.ungrab_pointer(0u32).unwrap();
+        assert_eq!(sent_opcodes(&conn), [xproto::UNGRAB_POINTER_REQUEST]);
+    }
+
+    #[test]
+    fn deny_extension() {
+        let conn = fake_connection().wrap(
+            Restrictions::new()
+                .deny(RequestCategory::Xtest)
+                .deny(RequestCategory::ScreenCapture),
+        );
+        assert_eq!(conn.extension_information("XTEST").unwrap(), None);
+        assert!(conn.extension_information("MIT-SHM").unwrap().is_some());
+
+        // A raw request with XTEST's major opcode is denied
+        assert_denied(
+            conn.send_request_without_reply(&[IoSlice::new(&[130, 0, 1, 0])], Vec::new()),
+        );
+        // MIT-SHM's GetImage is denied, but not other requests of MIT-SHM
+        assert_denied(
+            conn.send_request_without_reply(&[IoSlice::new(&[131, 4, 1, 0])], Vec::new()),
+        );
+        let _ = conn
+            .send_request_without_reply(&[IoSlice::new(&[131, 3, 1, 0])], Vec::new())
+            .unwrap();
+        assert_eq!(sent_opcodes(&conn), [131]);
+    }
+
+    /// Send a raw request with the given opcodes and check if it reaches the connection.
+    fn is_sent(conn: &RestrictedConnection<FakeConnection>, major: u8, minor: u8) -> bool {
+        let before = conn.inner().sent_requests().len();
+        match conn.send_request_without_reply(&[IoSlice::new(&[major, minor, 1, 0])], Vec::new()) {
+            Ok(_) => {
+                assert_eq!(conn.inner().sent_requests().len(), before + 1);
+                true
+            }
+            Err(ConnectionError::RequestDenied) => false,
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn deny_xinput_grabs() {
+        let conn = fake_connection().wrap(Restrictions::new().deny(RequestCategory::KeyboardGrabs));
+        // GrabDevice, GrabDeviceKey, XIGrabDevice, XIPassiveGrabDevice
+        for minor in [13, 15, 51, 54] {
+            assert!(!is_sent(&conn, 132, minor), "minor opcode {}", minor);
+        }
+        // UngrabDevice and XIUngrabDevice are still allowed
+        assert!(is_sent(&conn, 132, 14));
+        assert!(is_sent(&conn, 132, 52));
+        // The opcodes are not denied for other extensions
+        assert!(is_sent(&conn, 133, 13));
+    }
+
+    #[test]
+    fn deny_composite_and_dri3_capture() {
+        let conn = fake_connection().wrap(Restrictions::new().deny(RequestCategory::ScreenCapture));
+        // Composite's NameWindowPixmap
+        assert!(!is_sent(&conn, 133, 6));
+        // DRI3's BufferFromPixmap and BuffersFromPixmap
+        assert!(!is_sent(&conn, 134, 3));
+        assert!(!is_sent(&conn, 134, 8));
+        // Composite's RedirectWindow and DRI3's PixmapFromBuffer are still allowed
+        assert!(is_sent(&conn, 133, 1));
+        assert!(is_sent(&conn, 134, 2));
+    }
+}
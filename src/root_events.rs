@@ -0,0 +1,348 @@
+//! Sharing the root window's event mask between multiple components.
+//!
+//! Each client has exactly one event mask per window. When two independent components of a
+//! program (e.g. a panel and a tray implementation living in the same process) both call
+//! `ChangeWindowAttributes` on the root window, the second call replaces the event mask of the
+//! first one and events silently stop arriving.
+//!
+//! [`RootEventMultiplexer`] keeps track of the event mask that each component wants, selects the
+//! union of all masks on the root window, and tells which components are interested in a given
+//! event.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::EventMask;
+//! use x11rb::root_events::RootEventMultiplexer;
+//!
+//! fn run(conn: &impl Connection, screen_num: usize) -> Result<(), ReplyError> {
+//!     let root = conn.setup().roots[screen_num].root;
+//!     let mut mux = RootEventMultiplexer::new(conn, root)?;
+//!     let panel = mux.subscribe(conn, EventMask::PROPERTY_CHANGE)?;
+//!     let layout = mux.subscribe(conn, EventMask::STRUCTURE_NOTIFY)?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         for id in mux.interested(&event) {
+//!             if id == panel {
+//!                 // Let the panel handle the event
+//!             } else if id == layout {
+//!                 // Let the layout code handle the event
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, ChangeWindowAttributesAux, EventMask, Window};
+use crate::protocol::Event;
+
+/// Remove the event mask bits that cannot be shared between components.
+///
+/// Only one client can select these on a window at a time, so selecting them on the root window
+/// is the job of the window manager and not of any helper.
+fn without_exclusive_bits(mask: EventMask) -> u32 {
+    let exclusive =
+        EventMask::BUTTON_PRESS | EventMask::RESIZE_REDIRECT | EventMask::SUBSTRUCTURE_REDIRECT;
+    u32::from(mask) & !u32::from(exclusive)
+}
+
+/// Identifies a subscription of a [`RootEventMultiplexer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(usize);
+
+/// Shares the event mask of a root window between multiple components.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct RootEventMultiplexer {
+    root: Window,
+    /// The event mask that was selected before this instance was created.
+    base_mask: u32,
+    subscriptions: Vec<(SubscriptionId, u32)>,
+    next_id: usize,
+    /// The event mask that is currently selected on the root window.
+    selected: u32,
+}
+
+impl RootEventMultiplexer {
+    /// Create a new instance for the given root window.
+    ///
+    /// The current event mask of this client on the root window is queried and kept, so that
+    /// events selected by other code keep arriving.
+    pub fn new<C: RequestConnection + ?Sized>(conn: &C, root: Window) -> Result<Self, ReplyError> {
+        let base_mask = xproto::get_window_attributes(conn, root)?
+            .reply()?
+            .your_event_mask;
+        Ok(Self {
+            root,
+            base_mask,
+            subscriptions: Vec::new(),
+            next_id: 0,
+            selected: base_mask,
+        })
+    }
+
+    /// Get the root window.
+    pub fn root(&self) -> Window {
+        self.root
+    }
+
+    /// Get the event mask that is currently selected on the root window.
+    pub fn selected_mask(&self) -> EventMask {
+        self.selected.into()
+    }
+
+    /// Register interest in the events of the given mask.
+    ///
+    /// `ButtonPress`, `ResizeRedirect` and `SubstructureRedirect` can only be selected by one
+    /// client at a time and are ignored here.
+    pub fn subscribe<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        mask: EventMask,
+    ) -> Result<SubscriptionId, ConnectionError> {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.subscriptions.push((id, without_exclusive_bits(mask)));
+        self.update(conn)?;
+        Ok(id)
+    }
+
+    /// Change the event mask of an existing subscription.
+    ///
+    /// Unknown ids are ignored.
+    pub fn change<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        id: SubscriptionId,
+        mask: EventMask,
+    ) -> Result<(), ConnectionError> {
+        if let Some(entry) = self.subscriptions.iter_mut().find(|(i, _)| *i == id) {
+            entry.1 = without_exclusive_bits(mask);
+        }
+        self.update(conn)
+    }
+
+    /// Remove a subscription.
+    ///
+    /// Event mask bits that no other subscription needs are deselected on the root window.
+    pub fn unsubscribe<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        id: SubscriptionId,
+    ) -> Result<(), ConnectionError> {
+        self.subscriptions.retain(|(i, _)| *i != id);
+        self.update(conn)
+    }
+
+    /// Select the union of all event masks on the root window, if it changed.
+    fn update<C: RequestConnection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        let mask = self
+            .subscriptions
+            .iter()
+            .fold(self.base_mask, |acc, (_, mask)| acc | mask);
+        if mask != self.selected {
+            let aux = ChangeWindowAttributesAux::new().event_mask(mask);
+            let _ = xproto::change_window_attributes(conn, self.root, &aux)?;
+            self.selected = mask;
+        }
+        Ok(())
+    }
+
+    /// Get the subscriptions that are interested in the given event.
+    ///
+    /// Events that were not reported for the root window produce no subscriptions.
+    pub fn interested<'a>(&'a self, event: &Event) -> impl Iterator<Item = SubscriptionId> + 'a {
+        let bits = event_mask_bits(event, self.root);
+        self.subscriptions
+            .iter()
+            .filter(move |(_, mask)| mask & bits != 0)
+            .map(|(id, _)| *id)
+    }
+}
+
+/// Get the event mask bits that cause the given event to be reported for `window`.
+fn event_mask_bits(event: &Event, window: Window) -> u32 {
+    let structure = |event_window: Window, changed_window: Window| {
+        if event_window != window {
+            0
+        } else if changed_window == window {
+            u32::from(EventMask::STRUCTURE_NOTIFY)
+        } else {
+            u32::from(EventMask::SUBSTRUCTURE_NOTIFY)
+        }
+    };
+    let on_window = |event_window: Window, mask: EventMask| {
+        if event_window == window {
+            u32::from(mask)
+        } else {
+            0
+        }
+    };
+    match event {
+        Event::CirculateNotify(e) => structure(e.event, e.window),
+        Event::ConfigureNotify(e) => structure(e.event, e.window),
+        Event::DestroyNotify(e) => structure(e.event, e.window),
+        Event::GravityNotify(e) => structure(e.event, e.window),
+        Event::MapNotify(e) => structure(e.event, e.window),
+        Event::ReparentNotify(e) => structure(e.event, e.window),
+        Event::UnmapNotify(e) => structure(e.event, e.window),
+        Event::CreateNotify(e) => on_window(e.parent, EventMask::SUBSTRUCTURE_NOTIFY),
+        Event::PropertyNotify(e) => on_window(e.window, EventMask::PROPERTY_CHANGE),
+        Event::ColormapNotify(e) => on_window(e.window, EventMask::COLOR_MAP_CHANGE),
+        Event::FocusIn(e) => on_window(e.event, EventMask::FOCUS_CHANGE),
+        Event::FocusOut(e) => on_window(e.event, EventMask::FOCUS_CHANGE),
+        Event::EnterNotify(e) => on_window(e.event, EventMask::ENTER_WINDOW),
+        Event::LeaveNotify(e) => on_window(e.event, EventMask::LEAVE_WINDOW),
+        Event::KeyPress(e) => on_window(e.event, EventMask::KEY_PRESS),
+        Event::KeyRelease(e) => on_window(e.event, EventMask::KEY_RELEASE),
+        Event::ButtonRelease(e) => on_window(e.event, EventMask::BUTTON_RELEASE),
+        Event::Expose(e) => on_window(e.window, EventMask::EXPOSURE),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryInto;
+
+    use super::{event_mask_bits, without_exclusive_bits, RootEventMultiplexer, SubscriptionId};
+    use crate::protocol::xproto::{
+        self, EventMask, MapNotifyEvent, Property, PropertyNotifyEvent, MAP_NOTIFY_EVENT,
+        PROPERTY_NOTIFY_EVENT,
+    };
+    use crate::protocol::Event;
+    use crate::testing::{FakeConnection, Response};
+
+    const ROOT: u32 = 42;
+
+    /// Answer the next `GetWindowAttributes` request with the given event mask.
+    fn respond_with_mask(conn: &FakeConnection, mask: EventMask) {
+        let mut reply = vec![0; 44];
+        reply[36..40].copy_from_slice(&u32::from(mask).to_ne_bytes());
+        conn.respond(
+            xproto::GET_WINDOW_ATTRIBUTES_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+    }
+
+    /// Get the event masks that were set via `ChangeWindowAttributes` and forget all requests.
+    fn take_changed_masks(conn: &FakeConnection) -> Vec<u32> {
+        conn.take_sent_requests()
+            .iter()
+            .filter(|request| request.major_opcode() == xproto::CHANGE_WINDOW_ATTRIBUTES_REQUEST)
+            .map(|request| {
+                assert_eq!(request.bytes[4..8], ROOT.to_ne_bytes());
+                u32::from_ne_bytes(request.bytes[12..16].try_into().unwrap())
+            })
+            .collect()
+    }
+
+    fn map_notify(event: u32, window: u32) -> Event {
+        Event::MapNotify(MapNotifyEvent {
+            response_type: MAP_NOTIFY_EVENT,
+            sequence: 0,
+            event,
+            window,
+            override_redirect: false,
+        })
+    }
+
+    fn property_notify(window: u32) -> Event {
+        Event::PropertyNotify(PropertyNotifyEvent {
+            response_type: PROPERTY_NOTIFY_EVENT,
+            sequence: 0,
+            window,
+            atom: 1,
+            time: 0,
+            state: Property::NEW_VALUE,
+        })
+    }
+
+    #[test]
+    fn structure_vs_substructure() {
+        assert_eq!(
+            event_mask_bits(&map_notify(ROOT, ROOT), ROOT),
+            u32::from(EventMask::STRUCTURE_NOTIFY)
+        );
+        assert_eq!(
+            event_mask_bits(&map_notify(ROOT, 1), ROOT),
+            u32::from(EventMask::SUBSTRUCTURE_NOTIFY)
+        );
+        assert_eq!(event_mask_bits(&map_notify(1, 1), ROOT), 0);
+    }
+
+    #[test]
+    fn exclusive_bits_are_dropped() {
+        let mask = EventMask::BUTTON_PRESS
+            | EventMask::SUBSTRUCTURE_REDIRECT
+            | EventMask::PROPERTY_CHANGE
+            | EventMask::BUTTON_RELEASE;
+        assert_eq!(
+            without_exclusive_bits(mask),
+            u32::from(EventMask::PROPERTY_CHANGE | EventMask::BUTTON_RELEASE)
+        );
+    }
+
+    #[test]
+    fn fan_out() {
+        let mux = RootEventMultiplexer {
+            root: ROOT,
+            base_mask: 0,
+            subscriptions: vec![
+                (SubscriptionId(0), u32::from(EventMask::PROPERTY_CHANGE)),
+                (
+                    SubscriptionId(1),
+                    u32::from(EventMask::PROPERTY_CHANGE | EventMask::SUBSTRUCTURE_NOTIFY),
+                ),
+            ],
+            next_id: 2,
+            selected: 0,
+        };
+        let ids = |event| mux.interested(&event).collect::<Vec<_>>();
+        assert_eq!(
+            ids(property_notify(ROOT)),
+            [SubscriptionId(0), SubscriptionId(1)]
+        );
+        assert_eq!(ids(property_notify(1)), []);
+        assert_eq!(ids(map_notify(ROOT, 1)), [SubscriptionId(1)]);
+        assert_eq!(ids(map_notify(ROOT, ROOT)), []);
+    }
+
+    #[test]
+    fn subscribe_and_unsubscribe() {
+        let conn = FakeConnection::default();
+        respond_with_mask(&conn, EventMask::KEY_PRESS);
+        let mut mux = RootEventMultiplexer::new(&conn, ROOT).unwrap();
+        assert_eq!(take_changed_masks(&conn), []);
+
+        // The existing mask is kept and the exclusive bits are not selected
+        let panel = mux
+            .subscribe(&conn, EventMask::PROPERTY_CHANGE | EventMask::BUTTON_PRESS)
+            .unwrap();
+        let first = EventMask::KEY_PRESS | EventMask::PROPERTY_CHANGE;
+        assert_eq!(take_changed_masks(&conn), [u32::from(first)]);
+
+        // A second subscriber adds its bits
+        let layout = mux
+            .subscribe(
+                &conn,
+                EventMask::STRUCTURE_NOTIFY | EventMask::PROPERTY_CHANGE,
+            )
+            .unwrap();
+        let both = first | EventMask::STRUCTURE_NOTIFY;
+        assert_eq!(take_changed_masks(&conn), [u32::from(both)]);
+
+        // Unsubscribing keeps the bits that the other subscriber still needs
+        mux.unsubscribe(&conn, panel).unwrap();
+        assert_eq!(take_changed_masks(&conn), []);
+        assert_eq!(mux.selected_mask(), both);
+
+        // Only the bits from before the multiplexer existed remain
+        mux.unsubscribe(&conn, layout).unwrap();
+        assert_eq!(take_changed_masks(&conn), [u32::from(EventMask::KEY_PRESS)]);
+    }
+}
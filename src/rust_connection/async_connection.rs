@@ -0,0 +1,609 @@
+//! A variant of [`RustConnection`] for the tokio runtime.
+
+use std::io::IoSlice;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::sync::TryLockError;
+
+use tokio::io::unix::AsyncFd;
+use tokio::io::{Interest as IoInterest, Ready};
+
+use super::inner::{ConnectionInner, PollReply};
+use super::{
+    prepare_sync, BufWithFds, Buffer, DefaultStream, RawEventAndSeqNumber, ReplyFDKind,
+    RustConnection, Stream,
+};
+use crate::connection::{
+    compute_length_field, Connection, DiscardMode, EventAndSeqNumber, FlushMode, ReplyOrError,
+    RequestConnection, RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::Event;
+use crate::utils::OwnedFd;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+/// The file descriptor of the stream, registered with tokio.
+///
+/// The stream itself is owned by the `RustConnection`.
+#[derive(Debug)]
+struct StreamFd(RawFd);
+
+impl AsRawFd for StreamFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+/// A connection to an X11 server for use with the tokio runtime.
+///
+/// This wraps a [`RustConnection`]. Waiting for replies, errors and events, as well as flushing,
+/// happens in async functions that yield to the runtime instead of blocking the thread.
+///
+/// Sending a request only appends it to an unbounded write buffer and never blocks. Thus, all
+/// the request functions of x11rb can be used with this connection. The buffered requests are
+/// written by [`AsyncRustConnection::flush`], which is also done automatically before waiting for
+/// a reply.
+///
+/// The functions of [`RequestConnection`] and [`Connection`] that wait for something, e.g.
+/// [`Cookie::reply`], block the current thread like they do for [`RustConnection`]. Inside of
+/// async code, use [`AsyncRustConnection::reply`] and friends instead. Getting the information
+/// about an extension for the first time also blocks, because it needs a round trip to the X11
+/// server. [`RequestConnection::prefetch_extension_information`] can be used to hide this
+/// latency. Likewise, the first request that is too large for the length field of the core
+/// protocol blocks for a round trip to enable the BIG-REQUESTS extension, unless
+/// [`RequestConnection::prefetch_maximum_request_bytes`] was called and its reply was received.
+///
+/// The futures returned by [`AsyncRustConnection::reply`] and friends can be dropped before they
+/// complete, e.g. by `select!` or a timeout. The response to the request is then discarded, just
+/// like when the cookie is dropped.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::ConnectionExt;
+/// use x11rb::rust_connection::AsyncRustConnection;
+///
+/// let (conn, screen_num) = AsyncRustConnection::connect(None).await?;
+/// let root = conn.setup().roots[screen_num].root;
+/// let cookie = conn.get_geometry(root)?;
+/// let geometry = conn.reply(cookie).await?;
+/// println!("The root window is {}x{}", geometry.width, geometry.height);
+/// loop {
+///     let event = conn.wait_for_event().await?;
+///     println!("{:?}", event);
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncRustConnection<S: Stream + AsRawFd = DefaultStream> {
+    // This is declared before `conn` so that it is dropped before the stream is closed.
+    fd: AsyncFd<StreamFd>,
+    conn: RustConnection<S>,
+}
+
+impl AsyncRustConnection<DefaultStream> {
+    /// Establish a new connection.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// The connection setup is done on tokio's thread pool for blocking operations.
+    pub async fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        let dpy_name = dpy_name.map(str::to_string);
+        let (conn, screen) =
+            tokio::task::spawn_blocking(move || RustConnection::connect(dpy_name.as_deref()))
+                .await
+                .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))?;
+        Ok((Self::new(conn)?, screen))
+    }
+}
+
+impl<S: Stream + AsRawFd> AsyncRustConnection<S> {
+    /// Wrap an established connection.
+    ///
+    /// This registers the connection's stream with tokio and thus must be called from inside a
+    /// tokio runtime with IO enabled.
+    pub fn new(conn: RustConnection<S>) -> std::io::Result<Self> {
+        let fd = AsyncFd::with_interest(
+            StreamFd(conn.stream().as_raw_fd()),
+            IoInterest::READABLE | IoInterest::WRITABLE,
+        )?;
+        Ok(Self { fd, conn })
+    }
+
+    /// Get the wrapped connection.
+    pub fn connection(&self) -> &RustConnection<S> {
+        &self.conn
+    }
+
+    /// Set whether pending requests are flushed before waiting for an event.
+    ///
+    /// See [`RustConnection::set_flush_mode`].
+    pub fn set_flush_mode(&mut self, mode: FlushMode) {
+        self.conn.set_flush_mode(mode)
+    }
+
+    /// Write all buffered requests to the X11 server.
+    pub async fn flush(&self) -> Result<(), ConnectionError> {
+        loop {
+            if !self.conn.inner.lock().unwrap().write_buffer.needs_flush() {
+                return Ok(());
+            }
+            let mut guard = self
+                .fd
+                .ready(IoInterest::READABLE | IoInterest::WRITABLE)
+                .await?;
+            let mut clear = Ready::EMPTY;
+            {
+                let mut inner = self.conn.inner.lock().unwrap();
+                // The X11 server might only continue reading once its replies were read
+                if guard.ready().is_readable() && self.read_available(&mut inner)? {
+                    clear |= Ready::READABLE;
+                }
+                match inner.write_buffer.flush(&self.conn.stream) {
+                    Ok(()) => return Ok(()),
+                    Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        clear |= Ready::WRITABLE;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            if clear.is_empty() {
+                // Another thread is reading; give it a chance to finish
+                drop(guard);
+                tokio::task::yield_now().await;
+            } else {
+                guard.clear_ready_matching(clear);
+            }
+        }
+    }
+
+    /// Wait for the reply to a request.
+    ///
+    /// This is the async variant of [`Cookie::reply`].
+    pub async fn reply<R: TryParse>(&self, cookie: Cookie<'_, Self, R>) -> Result<R, ReplyError> {
+        // The cookie is kept alive while waiting, so that it discards the reply if this future is
+        // dropped before it completes.
+        let result = self.wait_for_reply_or_error(cookie.sequence_number()).await;
+        let _ = cookie.into_sequence_number();
+        let (buffer, _fds) = result?;
+        let result = R::try_parse(&buffer).map(|r| r.0);
+        self.release_buffer(buffer);
+        Ok(result?)
+    }
+
+    /// Wait for the reply to a request that contains file descriptors.
+    ///
+    /// This is the async variant of [`CookieWithFds::reply`].
+    pub async fn reply_with_fds<R: TryParseFd>(
+        &self,
+        cookie: CookieWithFds<'_, Self, R>,
+    ) -> Result<R, ReplyError> {
+        // See reply() for why the cookie is kept alive
+        let result = self.wait_for_reply_or_error(cookie.sequence_number()).await;
+        let _ = cookie.into_sequence_number();
+        let (buffer, mut fds) = result?;
+        let result = R::try_parse_fd(&buffer, &mut fds).map(|r| r.0);
+        self.release_buffer(buffer);
+        Ok(result?)
+    }
+
+    /// Check whether a request without a reply caused an error.
+    ///
+    /// This is the async variant of [`VoidCookie::check`].
+    pub async fn check(&self, cookie: VoidCookie<'_, Self>) -> Result<(), ReplyError> {
+        // See reply() for why the cookie is kept alive
+        let result = self.check_sequence(cookie.sequence_number()).await;
+        let _ = cookie.into_sequence_number();
+        result
+    }
+
+    /// Check whether the request with the given sequence number caused an error.
+    async fn check_sequence(&self, sequence: SequenceNumber) -> Result<(), ReplyError> {
+        {
+            let mut inner = self.conn.inner.lock().unwrap();
+            if inner.prepare_check_for_reply_or_error(sequence) {
+                buffer_sync(&mut inner);
+                assert!(!inner.prepare_check_for_reply_or_error(sequence));
+            }
+        }
+        self.flush().await?;
+        loop {
+            let reply = self
+                .conn
+                .inner
+                .lock()
+                .unwrap()
+                .poll_check_for_reply_or_error(sequence);
+            match reply {
+                PollReply::TryAgain => self.read().await?,
+                PollReply::NoReply => return Ok(()),
+                PollReply::Reply(error) => return Err(self.parse_and_release_error(error)),
+            }
+        }
+    }
+
+    /// Wait for a new event from the X11 server.
+    ///
+    /// This is the async variant of [`Connection::wait_for_event`].
+    pub async fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        let (event, _sequence) = self.wait_for_raw_event_with_sequence().await?;
+        Ok(self.conn.parse_and_release_event(event)?)
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server.
+    ///
+    /// This is the async variant of [`Connection::wait_for_raw_event_with_sequence`].
+    pub async fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        if self.conn.flush_mode == FlushMode::AutoFlush {
+            self.flush().await?;
+        }
+        loop {
+            let event = self
+                .conn
+                .inner
+                .lock()
+                .unwrap()
+                .poll_for_event_with_sequence();
+            if let Some(event) = event {
+                return Ok(event);
+            }
+            self.read().await?;
+        }
+    }
+
+    /// Wait for the reply or error to the request with the given sequence number.
+    async fn wait_for_reply_or_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<BufWithFds, ReplyError> {
+        self.flush().await?;
+        loop {
+            let reply = self
+                .conn
+                .inner
+                .lock()
+                .unwrap()
+                .poll_for_reply_or_error(sequence);
+            match reply {
+                Some((error, _fds)) if error[0] == 0 => {
+                    return Err(self.parse_and_release_error(error))
+                }
+                Some(reply) => return Ok(reply),
+                None => self.read().await?,
+            }
+        }
+    }
+
+    /// Parse an X11 error and give its buffer back to the [`super::BufferAllocator`].
+    fn parse_and_release_error(&self, error: Buffer) -> ReplyError {
+        let result = self.parse_error(&error);
+        self.release_buffer(error);
+        match result {
+            Ok(error) => error.into(),
+            Err(error) => error.into(),
+        }
+    }
+
+    /// Wait until the stream is readable and then read and enqueue all available packets.
+    async fn read(&self) -> Result<(), ConnectionError> {
+        let mut guard = self.fd.readable().await?;
+        let read = {
+            let mut inner = self.conn.inner.lock().unwrap();
+            self.read_available(&mut inner)?
+        };
+        if read {
+            guard.clear_ready();
+        } else {
+            // Another thread is reading; give it a chance to finish
+            drop(guard);
+            tokio::task::yield_now().await;
+        }
+        Ok(())
+    }
+
+    /// Read all available packets without blocking.
+    ///
+    /// Returns `false` if another thread is currently reading.
+    fn read_available(&self, inner: &mut ConnectionInner) -> std::io::Result<bool> {
+        match self.conn.packet_reader.try_lock() {
+            Ok(packet_reader) => {
+                self.conn.read_with_reader(inner, packet_reader)?;
+                Ok(true)
+            }
+            Err(TryLockError::WouldBlock) => Ok(false),
+            Err(TryLockError::Poisoned(e)) => panic!("{}", e),
+        }
+    }
+
+    /// Add a request to the write buffer without writing anything.
+    fn buffer_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        mut fds: Vec<OwnedFd>,
+        kind: ReplyFDKind,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let mut storage = Default::default();
+        let bufs = compute_length_field(self, bufs, &mut storage)?;
+
+        let mut inner = self.conn.inner.lock().unwrap();
+        loop {
+            match inner.send_request(kind) {
+                Some(seqno) => {
+                    inner.write_buffer.buffer_vectored(bufs, &mut fds);
+                    return Ok(seqno);
+                }
+                None => buffer_sync(&mut inner),
+            }
+        }
+    }
+}
+
+/// Add a `GetInputFocus` request whose reply is ignored to the write buffer.
+fn buffer_sync(inner: &mut ConnectionInner) {
+    let request = prepare_sync(inner);
+    inner
+        .write_buffer
+        .buffer_vectored(&[IoSlice::new(&request)], &mut Vec::new());
+}
+
+impl<S: Stream + AsRawFd> RequestConnection for AsyncRustConnection<S> {
+    type Buf = Buffer;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        Ok(Cookie::new(
+            self,
+            self.buffer_request(bufs, fds, ReplyFDKind::ReplyWithoutFDs)?,
+        ))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        Ok(CookieWithFds::new(
+            self,
+            self.buffer_request(bufs, fds, ReplyFDKind::ReplyWithFDs)?,
+        ))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(
+            self,
+            self.buffer_request(bufs, fds, ReplyFDKind::NoReply)?,
+        ))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.extension_information(extension_name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Buffer>, ConnectionError> {
+        self.conn.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Buffer>>, ConnectionError> {
+        self.conn.poll_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Buffer>, ConnectionError> {
+        self.conn.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        self.conn.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        self.conn.check_for_raw_error(sequence)
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.conn.parse_event(event)
+    }
+
+    fn release_buffer(&self, buffer: Buffer) {
+        self.conn.release_buffer(buffer)
+    }
+}
+
+impl<S: Stream + AsRawFd> Connection for AsyncRustConnection<S> {
+    fn wait_for_event_with_sequence(&self) -> Result<EventAndSeqNumber, ConnectionError> {
+        self.conn.wait_for_event_with_sequence()
+    }
+
+    fn poll_for_event_with_sequence(&self) -> Result<Option<EventAndSeqNumber>, ConnectionError> {
+        self.conn.poll_for_event_with_sequence()
+    }
+
+    fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        self.conn.wait_for_raw_event_with_sequence()
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        self.conn.poll_for_raw_event_with_sequence()
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.conn.flush()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.conn.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.conn.generate_id()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::io::{ErrorKind, Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    use super::AsyncRustConnection;
+    use crate::connection::RequestConnection;
+    use crate::protocol::xproto::ConnectionExt as _;
+    use crate::rust_connection::test::test_setup;
+    use crate::rust_connection::{DefaultStream, RustConnection};
+    use crate::testing::echo::EchoServer;
+
+    /// Poll the wrapped future once, like `select!` does with a branch that then loses.
+    struct PollOnce<F>(Pin<Box<F>>);
+
+    impl<F: Future> Future for PollOnce<F> {
+        type Output = Poll<F::Output>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            Poll::Ready(self.0.as_mut().poll(cx))
+        }
+    }
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn replies_in_concurrent_tasks() {
+        let (conn, server) = EchoServer::connect().unwrap();
+        runtime().block_on(async {
+            let conn = Arc::new(AsyncRustConnection::new(conn).unwrap());
+
+            let tasks = (0..2)
+                .map(|_| {
+                    let conn = Arc::clone(&conn);
+                    tokio::spawn(async move {
+                        for _ in 0..100 {
+                            let cookie = conn.get_input_focus().unwrap();
+                            let sequence = cookie.sequence_number();
+                            let reply = conn.reply(cookie).await.unwrap();
+                            assert_eq!(u64::from(reply.focus), sequence);
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            for task in tasks {
+                task.await.unwrap();
+            }
+            conn.flush().await.unwrap();
+        });
+        server.join();
+    }
+
+    #[test]
+    fn sending_does_not_write() {
+        let (client, mut server) = UnixStream::pair().unwrap();
+        server.set_nonblocking(true).unwrap();
+        runtime().block_on(async {
+            let stream = DefaultStream::from_unix_stream(client).unwrap();
+            let conn = RustConnection::for_connected_stream(stream, test_setup()).unwrap();
+            let conn = AsyncRustConnection::new(conn).unwrap();
+            for _ in 0..100 {
+                let _ = conn.get_input_focus().unwrap();
+            }
+            let error = server.read(&mut [0; 32]).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::WouldBlock);
+
+            conn.flush().await.unwrap();
+            let mut buffer = [0; 400];
+            server.read_exact(&mut buffer).unwrap();
+        });
+    }
+
+    #[test]
+    fn dropped_reply_future_discards_reply() {
+        let (client, mut server) = UnixStream::pair().unwrap();
+        runtime().block_on(async {
+            let stream = DefaultStream::from_unix_stream(client).unwrap();
+            let conn = RustConnection::for_connected_stream(stream, test_setup()).unwrap();
+            let conn = AsyncRustConnection::new(conn).unwrap();
+
+            let cookie = conn.get_input_focus().unwrap();
+            let sequence = cookie.sequence_number();
+            conn.flush().await.unwrap();
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            let poll = PollOnce(Box::pin(conn.reply(cookie))).await;
+            assert!(poll.is_pending());
+
+            // Once the event was received, the reply before it was also read
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            let mut event = [0; 32];
+            event[0] = crate::protocol::xproto::EXPOSE_EVENT;
+            event[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+            server.write_all(&event).unwrap();
+
+            let _ = conn.wait_for_event().await.unwrap();
+            let reply = conn.poll_for_reply_or_raw_error(sequence).unwrap();
+            assert!(reply.is_none());
+        });
+    }
+}
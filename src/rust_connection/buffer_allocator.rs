@@ -0,0 +1,121 @@
+//! Allocating the buffers that received packets are stored in.
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+/// Provides the buffers for packets that [`super::RustConnection`] receives.
+///
+/// Every reply, error, and event is stored in its own buffer. By default, these buffers come from
+/// the global allocator. Applications with soft real-time requirements, e.g. compositors, can
+/// instead hand out buffers from a pre-allocated pool via
+/// [`super::RustConnection::set_buffer_allocator`], so that receiving packets does not call into
+/// the global allocator.
+pub trait BufferAllocator: Debug + Send + Sync {
+    /// Get an empty buffer with a capacity of at least `len` bytes.
+    ///
+    /// The connection first requests a buffer for the fixed-size part of a packet. If the packet
+    /// turns out to be larger than the capacity of this buffer, a second buffer for the whole
+    /// packet is requested and the first one is released. Buffers with less capacity than
+    /// requested still work, but grow via the global allocator.
+    fn allocate(&self, len: usize) -> Vec<u8>;
+
+    /// Take back a buffer that is no longer needed.
+    ///
+    /// The connection calls this for buffers that it consumes itself, e.g. events that it
+    /// parses, replies that are parsed by a cookie, and replies that are discarded. Buffers that
+    /// are handed to the caller, e.g. raw events, can be returned via
+    /// [`crate::connection::RequestConnection::release_buffer`]. The default implementation frees
+    /// the buffer.
+    fn release(&self, buffer: Vec<u8>) {
+        drop(buffer);
+    }
+}
+
+/// The [`BufferAllocator`] that uses the global allocator.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct GlobalAllocator;
+
+impl BufferAllocator for GlobalAllocator {
+    fn allocate(&self, len: usize) -> Vec<u8> {
+        Vec::with_capacity(len)
+    }
+}
+
+/// A [`BufferAllocator`] that recycles a fixed number of pre-allocated buffers.
+///
+/// All buffers have the same capacity. Packets that do not fit into it, or that arrive while all
+/// buffers are in use, get a buffer from the global allocator instead. Such buffers are freed
+/// when they are released.
+#[derive(Debug)]
+pub struct BufferPool {
+    capacity: usize,
+    count: usize,
+    free: Mutex<Vec<Vec<u8>>>,
+}
+
+impl BufferPool {
+    /// Pre-allocate `count` buffers with `capacity` bytes each.
+    ///
+    /// Most packets are 32 bytes long, but replies like `GetImage` can be much larger.
+    pub fn new(count: usize, capacity: usize) -> Self {
+        let free = (0..count).map(|_| Vec::with_capacity(capacity)).collect();
+        Self {
+            capacity,
+            count,
+            free: Mutex::new(free),
+        }
+    }
+
+    /// Get the number of pre-allocated buffers that are currently not in use.
+    pub fn available(&self) -> usize {
+        self.free.lock().unwrap().len()
+    }
+}
+
+impl BufferAllocator for BufferPool {
+    fn allocate(&self, len: usize) -> Vec<u8> {
+        if len <= self.capacity {
+            if let Some(buffer) = self.free.lock().unwrap().pop() {
+                return buffer;
+            }
+        }
+        Vec::with_capacity(len)
+    }
+
+    fn release(&self, mut buffer: Vec<u8>) {
+        // Only take back buffers that were pre-allocated (or are equivalent)
+        if buffer.capacity() != self.capacity {
+            return;
+        }
+        let mut free = self.free.lock().unwrap();
+        if free.len() < self.count {
+            buffer.clear();
+            free.push(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BufferAllocator, BufferPool};
+
+    #[test]
+    fn pool_recycles_buffers() {
+        let pool = BufferPool::new(2, 64);
+        let first = pool.allocate(32);
+        let second = pool.allocate(32);
+        assert_eq!(pool.available(), 0);
+        let large = pool.allocate(100);
+        assert!(large.capacity() >= 100);
+
+        let ptr = first.as_ptr();
+        pool.release(first);
+        pool.release(large);
+        assert_eq!(pool.available(), 1);
+        let buffer = pool.allocate(16);
+        assert_eq!(buffer.as_ptr(), ptr);
+
+        pool.release(second);
+        assert_eq!(pool.available(), 1);
+    }
+}
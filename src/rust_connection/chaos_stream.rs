@@ -0,0 +1,227 @@
+//! A stream wrapper that behaves as unfavourably as the `Stream` trait allows.
+
+use std::io::{ErrorKind, Result};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::{PollMode, Stream};
+use crate::utils::OwnedFd;
+
+/// A [`Stream`] that transfers data in random pieces.
+///
+/// `ChaosStream` wraps another stream and makes use of everything that the contract of the
+/// [`Stream`] trait allows:
+///
+/// - Reads and writes transfer a random number of bytes, possibly only a single one.
+/// - Reads and writes randomly fail with [`ErrorKind::WouldBlock`], also right after
+///   [`Stream::poll`] said that the stream is ready.
+/// - Received file descriptors are handed out with a random piece of the data that they arrived
+///   with, at the latest with its last byte.
+///
+/// The randomness comes from a seed, so that a failure can be reproduced. Running the same test
+/// with many seeds finds the edge cases in code that reads and writes X11 packets:
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::rust_connection::{ChaosStream, DefaultStream, RustConnection};
+///
+/// for seed in 0..100 {
+///     let stream = DefaultStream::connect("", None, 0).unwrap();
+///     let stream = ChaosStream::new(stream, seed).would_block_percent(50);
+///     let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+///     // Run the code under test on the connection
+///     conn.flush().unwrap();
+/// }
+/// ```
+///
+/// This type is only available when the `testing` feature of the library is enabled.
+#[derive(Debug)]
+pub struct ChaosStream<S> {
+    inner: S,
+    would_block_percent: u8,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    /// The state of the xorshift random number generator. Never zero.
+    random: u64,
+    /// Data that was read from the inner stream, but not yet handed out.
+    pending: Vec<u8>,
+    /// How much of `pending` was already handed out.
+    position: usize,
+    /// File descriptors that arrived together with `pending`.
+    fds: Vec<OwnedFd>,
+}
+
+impl State {
+    fn next_random(&mut self) -> u64 {
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 7;
+        self.random ^= self.random << 17;
+        self.random
+    }
+
+    /// Get a random number in `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_random() % bound as u64) as usize
+    }
+
+    /// Get a random length in `1..=max`.
+    fn length(&mut self, max: usize) -> usize {
+        1 + self.below(max)
+    }
+
+    fn would_block(&mut self, percent: u8) -> bool {
+        self.below(100) < usize::from(percent)
+    }
+}
+
+impl<S: Stream> ChaosStream<S> {
+    /// Wrap the given stream.
+    ///
+    /// Reads and writes fail spuriously with [`ErrorKind::WouldBlock`] in 20% of the cases.
+    pub fn new(inner: S, seed: u64) -> Self {
+        Self {
+            inner,
+            would_block_percent: 20,
+            state: Mutex::new(State {
+                // xorshift gets stuck at zero
+                random: seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1,
+                pending: Vec::new(),
+                position: 0,
+                fds: Vec::new(),
+            }),
+        }
+    }
+
+    /// Set how often reads and writes fail spuriously with [`ErrorKind::WouldBlock`].
+    ///
+    /// Values above 99 are treated as 99, since the stream would never make progress otherwise.
+    pub fn would_block_percent(mut self, percent: u8) -> Self {
+        self.would_block_percent = percent.min(99);
+        self
+    }
+
+    /// Get a reference to the wrapped stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Get the wrapped stream back.
+    ///
+    /// Data and file descriptors that were read from the wrapped stream, but not yet handed out,
+    /// are lost.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Stream> Stream for ChaosStream<S> {
+    fn poll(&self, mode: PollMode, timeout: Option<Duration>) -> Result<()> {
+        {
+            let state = self.state.lock().unwrap();
+            if mode.readable() && state.position != state.pending.len() {
+                return Ok(());
+            }
+        }
+        self.inner.poll(mode, timeout)
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+        if buf.is_empty() {
+            return self.inner.read(buf, fd_storage);
+        }
+        let mut state = self.state.lock().unwrap();
+        if state.would_block(self.would_block_percent) {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+        if state.position == state.pending.len() {
+            let mut data = vec![0; buf.len()];
+            let mut fds = Vec::new();
+            let nread = self.inner.read(&mut data, &mut fds)?;
+            if nread == 0 {
+                fd_storage.append(&mut fds);
+                return Ok(0);
+            }
+            data.truncate(nread);
+            state.pending = data;
+            state.position = 0;
+            state.fds = fds;
+        }
+        let available = state.pending.len() - state.position;
+        let length = state.length(available.min(buf.len()));
+        let start = state.position;
+        buf[..length].copy_from_slice(&state.pending[start..start + length]);
+        state.position += length;
+        // File descriptors must not arrive later than the data that they were sent with
+        if state.position == state.pending.len() || state.below(2) == 0 {
+            fd_storage.append(&mut state.fds);
+        }
+        Ok(length)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize> {
+        if buf.is_empty() {
+            return self.inner.write(buf, fds);
+        }
+        let length = {
+            let mut state = self.state.lock().unwrap();
+            if state.would_block(self.would_block_percent) {
+                return Err(ErrorKind::WouldBlock.into());
+            }
+            state.length(buf.len())
+        };
+        self.inner.write(&buf[..length], fds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::io::{ErrorKind, Read, Result};
+    use std::time::Duration;
+
+    use super::{ChaosStream, PollMode, Stream};
+    use crate::utils::OwnedFd;
+
+    struct SliceStream<'a>(RefCell<&'a [u8]>);
+
+    impl Stream for SliceStream<'_> {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            Ok(())
+        }
+
+        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            self.0.borrow_mut().read(buf)
+        }
+
+        fn write(&self, buf: &[u8], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn data_is_fragmented_but_complete() {
+        let data = (0..=255).collect::<Vec<u8>>();
+        for seed in 0..20 {
+            let stream = ChaosStream::new(SliceStream(RefCell::new(&data)), seed);
+            let (mut read, mut reads, mut would_blocks) = (Vec::new(), 0, 0);
+            loop {
+                let mut buf = [0; 64];
+                match stream.read(&mut buf, &mut Vec::new()) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        read.extend_from_slice(&buf[..n]);
+                        reads += 1;
+                    }
+                    Err(ref e) if e.kind() == ErrorKind::WouldBlock => would_blocks += 1,
+                    Err(e) => panic!("Unexpected error: {:?}", e),
+                }
+            }
+            assert_eq!(read, data);
+            assert!(reads > 4, "Only {} reads with seed {}", reads, seed);
+            assert!(would_blocks > 0, "No WouldBlock with seed {}", seed);
+        }
+    }
+}
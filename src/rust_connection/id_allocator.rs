@@ -39,7 +39,7 @@ impl IDAllocator {
     /// Generate the next ID.
     ///
     /// The given connection is used to ask for more IDs if necessary.
-    pub(crate) fn generate_id<C: RequestConnection>(
+    pub(crate) fn generate_id<C: RequestConnection + ?Sized>(
         &mut self,
         conn: &C,
     ) -> Result<u32, ReplyOrIdError> {
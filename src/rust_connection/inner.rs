@@ -1,10 +1,13 @@
 //! A pure-rust implementation of a connection to an X11 server.
 
 use std::collections::VecDeque;
+use std::sync::Arc;
 
+use super::buffer_allocator::{BufferAllocator, GlobalAllocator};
+use super::waiters::{Interest, Waiters};
 use super::{BufWithFds, RawEventAndSeqNumber, ReplyFDKind, WriteBuffer};
 use crate::connection::{DiscardMode, SequenceNumber};
-use crate::utils::RawFdContainer;
+use crate::utils::OwnedFd;
 
 #[derive(Debug, Clone)]
 pub(crate) enum PollReply {
@@ -41,10 +44,16 @@ pub(crate) struct ConnectionInner {
     pending_replies: VecDeque<(SequenceNumber, BufWithFds)>,
 
     // FDs that were read, but not yet assigned to any reply
-    pending_fds: VecDeque<RawFdContainer>,
+    pending_fds: VecDeque<OwnedFd>,
 
     // Buffer used for writing into the stream.
     pub(super) write_buffer: WriteBuffer,
+
+    // Threads that wait for another thread to read from the stream.
+    pub(super) waiters: Waiters,
+
+    // Takes back the buffers of packets that are discarded
+    buffer_allocator: Arc<dyn BufferAllocator>,
 }
 
 impl ConnectionInner {
@@ -62,9 +71,16 @@ impl ConnectionInner {
             pending_replies: VecDeque::new(),
             pending_fds: VecDeque::new(),
             write_buffer: WriteBuffer::new(),
+            waiters: Waiters::default(),
+            buffer_allocator: Arc::new(GlobalAllocator),
         }
     }
 
+    /// Set the allocator that takes back the buffers of discarded packets.
+    pub(crate) fn set_buffer_allocator(&mut self, allocator: Arc<dyn BufferAllocator>) {
+        self.buffer_allocator = allocator;
+    }
+
     /// Send a request to the X11 server.
     ///
     /// When this returns `None`, a sync with the server is necessary. Afterwards, the caller
@@ -106,22 +122,19 @@ impl ConnectionInner {
         if let Some(entry) = self.sent_requests.iter_mut().find(|r| r.seqno == seqno) {
             entry.discard_mode = Some(mode);
         }
-        match mode {
-            DiscardMode::DiscardReplyAndError => self.pending_replies.retain(|r| r.0 != seqno),
-            DiscardMode::DiscardReply => {
-                if let Some(index) = self.pending_replies.iter().position(|r| r.0 == seqno) {
-                    while self
-                        .pending_replies
-                        .get(index)
-                        .filter(|r| r.0 == seqno)
-                        .is_some()
-                    {
-                        if let Some((_, packet)) = self.pending_replies.remove(index) {
-                            if packet.0[0] == 0 {
-                                // This is an error
-                                self.pending_events.push_back((seqno, packet.0));
-                            }
-                        }
+        if let Some(index) = self.pending_replies.iter().position(|r| r.0 == seqno) {
+            while self
+                .pending_replies
+                .get(index)
+                .filter(|r| r.0 == seqno)
+                .is_some()
+            {
+                if let Some((_, packet)) = self.pending_replies.remove(index) {
+                    if packet.0[0] == 0 && mode == DiscardMode::DiscardReply {
+                        // This is an error
+                        self.pending_events.push_back((seqno, packet.0));
+                    } else {
+                        self.buffer_allocator.release(packet.0);
                     }
                 }
             }
@@ -159,7 +172,7 @@ impl ConnectionInner {
     /// Add FDs that were received to the internal state.
     ///
     /// This must be called before the corresponding packets are enqueued.
-    pub(crate) fn enqueue_fds(&mut self, fds: Vec<RawFdContainer>) {
+    pub(crate) fn enqueue_fds(&mut self, fds: Vec<OwnedFd>) {
         self.pending_fds.extend(fds);
     }
 
@@ -174,6 +187,8 @@ impl ConnectionInner {
         let seqno = self
             .extract_sequence_number(&packet)
             .unwrap_or(self.last_sequence_read);
+        #[cfg(feature = "log")]
+        crate::logging::received_packet(seqno, &packet);
 
         // Remove all entries for older requests
         while let Some(request) = self.sent_requests.front() {
@@ -188,7 +203,9 @@ impl ConnectionInner {
             // It is an error. Let's see where we have to send it to.
             if let Some(request) = request {
                 match request.discard_mode {
-                    Some(DiscardMode::DiscardReplyAndError) => { /* This error should be ignored */
+                    Some(DiscardMode::DiscardReplyAndError) => {
+                        // This error should be ignored
+                        self.buffer_allocator.release(packet)
                     }
                     Some(DiscardMode::DiscardReply) => {
                         self.pending_events.push_back((seqno, packet))
@@ -222,6 +239,7 @@ impl ConnectionInner {
             // It is a reply
             if request.filter(|r| r.discard_mode.is_some()).is_some() {
                 // This reply should be discarded
+                self.buffer_allocator.release(packet);
             } else {
                 self.pending_replies.push_back((seqno, (packet, fds)));
             }
@@ -298,6 +316,25 @@ impl ConnectionInner {
         }
     }
 
+    /// Check if a thread with the given interest can make progress with the enqueued packets.
+    pub(crate) fn is_satisfied(&self, interest: Interest) -> bool {
+        interest.is_satisfied(
+            self.last_sequence_read,
+            !self.pending_events.is_empty(),
+            |seqno| has_pending_reply(&self.pending_replies, seqno),
+        )
+    }
+
+    /// Wake up all waiting threads that can make progress with the enqueued packets.
+    pub(crate) fn notify_satisfied_waiters(&mut self) {
+        let has_events = !self.pending_events.is_empty();
+        let pending_replies = &self.pending_replies;
+        self.waiters
+            .notify_satisfied(self.last_sequence_read, has_events, |seqno| {
+                has_pending_reply(pending_replies, seqno)
+            });
+    }
+
     /// Get a pending event.
     pub(crate) fn poll_for_event_with_sequence(&mut self) -> Option<RawEventAndSeqNumber> {
         self.pending_events
@@ -306,6 +343,15 @@ impl ConnectionInner {
     }
 }
 
+fn has_pending_reply(
+    pending_replies: &VecDeque<(SequenceNumber, BufWithFds)>,
+    seqno: SequenceNumber,
+) -> bool {
+    pending_replies
+        .iter()
+        .any(|(reply_seqno, _)| *reply_seqno == seqno)
+}
+
 #[cfg(test)]
 mod test {
     use super::{ConnectionInner, ReplyFDKind};
@@ -2,31 +2,55 @@
 
 use std::convert::TryInto;
 use std::io::IoSlice;
-use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+use std::task::Waker;
+use std::time::{Duration, Instant};
 
 use crate::connection::{
-    compute_length_field, Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
-    SequenceNumber,
+    compute_length_field, Connection, DiscardMode, EventAndSeqNumber, FlushMode, ReplyOrError,
+    RequestConnection, RequestKind, SequenceNumber,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{AuthorizationSource, SetupRejected};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
-use crate::extension_manager::ExtensionManager;
+use crate::extension_manager::{ExtensionManager, ServerIdentity};
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
 use crate::protocol::xproto::{Setup, SetupRequest, GET_INPUT_FOCUS_REQUEST};
-use crate::utils::RawFdContainer;
+use crate::protocol::Event;
+use crate::utils::OwnedFd;
 use crate::x11_utils::{ExtensionInformation, Serialize, TryParse, TryParseFd};
 
+#[cfg(all(feature = "tokio", unix))]
+mod async_connection;
+mod buffer_allocator;
+#[cfg(feature = "testing")]
+mod chaos_stream;
 mod id_allocator;
 mod inner;
 mod packet_reader;
 mod parse_display;
+mod parser;
+mod priority;
 mod stream;
+mod waiters;
 mod write_buffer;
-mod xauth;
-
+#[cfg(target_os = "linux")]
+mod wsl;
+pub mod xauth;
+mod xdm_auth;
+
+#[cfg(all(feature = "tokio", unix))]
+pub use async_connection::AsyncRustConnection;
+pub use buffer_allocator::{BufferAllocator, BufferPool};
+#[cfg(feature = "testing")]
+pub use chaos_stream::ChaosStream;
 use inner::PollReply;
 use packet_reader::PacketReader;
+pub use parser::Parser;
+pub use priority::Priority;
+use priority::PriorityLane;
 pub use stream::{DefaultStream, PollMode, Stream};
+use waiters::Interest;
 use write_buffer::WriteBuffer;
 
 type Buffer = <RustConnection as RequestConnection>::Buf;
@@ -51,9 +75,16 @@ pub(crate) enum ReplyFDKind {
     ReplyWithFDs,
 }
 
+/// Check whether the given deadline passed. `None` means that there is no deadline.
+fn expired(deadline: Option<Instant>) -> bool {
+    matches!(deadline, Some(deadline) if Instant::now() >= deadline)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum BlockingMode {
-    Blocking,
+    /// Wait until something was read that a thread with the given interest needs, or until the
+    /// deadline passed.
+    Blocking(Interest, Option<Instant>),
     NonBlocking,
 }
 
@@ -72,11 +103,13 @@ pub struct RustConnection<S: Stream = DefaultStream> {
     // This mutex is only locked with `try_lock` (never blocks), so a simpler
     // lock based only on a atomic variable would be more efficient.
     packet_reader: Mutex<PacketReader>,
-    reader_condition: Condvar,
+    buffer_allocator: Arc<dyn BufferAllocator>,
+    priority_lane: PriorityLane,
     id_allocator: Mutex<id_allocator::IDAllocator>,
     setup: Setup,
     extension_manager: Mutex<ExtensionManager>,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
+    flush_mode: FlushMode,
 }
 
 // Locking rules
@@ -88,6 +121,7 @@ pub struct RustConnection<S: Stream = DefaultStream> {
 // - maximum_request_bytes
 // - extension_manager
 // - id_allocator
+// - priority_lane (which is never held while locking another mutex)
 //
 // Then comes `inner`. This mutex protects the information about in-flight requests and packets
 // that were already read from the connection but not given out to callers. This mutex also
@@ -100,18 +134,44 @@ pub struct RustConnection<S: Stream = DefaultStream> {
 // The inner level is `packet_reader`. This mutex is only locked when `inner` is already held and
 // only with `try_lock()`. This ensures that there is only one reader. While actually reading, the
 // lock on `inner` is released so that other threads can make progress. If more threads want to
-// read while `read` is already locked, they sleep on `reader_condition`. The actual reader will
-// then notify this condition variable once it is done reading.
+// read while `read` is already locked, they register themselves in `inner.waiters` together with
+// what they are waiting for and sleep on their own condition variable.
 //
-// The condition variable is necessary since one thread may read packets that another thread waits
-// for. Thus, after reading something from the connection, all threads that wait for something have
-// to check if they are the intended recipient.
+// One thread may read packets that another thread waits for. Thus, after reading something from
+// the connection, the reader wakes up exactly those threads that can now make progress. All other
+// threads keep sleeping. If the reader is done afterwards because it got what it was waiting for,
+// it wakes up one of the remaining threads, which then becomes the next reader. If reading fails,
+// all threads are woken up.
 
 impl RustConnection<DefaultStream> {
     /// Establish a new connection.
     ///
     /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// Inside the Windows Subsystem for Linux, local displays are also looked for at the socket
+    /// of WSLg and via TCP on the Windows host, where X11 servers for Windows listen.
     pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        Self::connect_impl(dpy_name, None)
+    }
+
+    /// Establish a new connection with the given authorization information.
+    ///
+    /// This is like [`RustConnection::connect`], but `auth_name` and `auth_data` are used instead
+    /// of looking up an entry in `~/.Xauthority`. They have the same format as an entry there,
+    /// e.g. as printed by `xauth list`. Supported are `MIT-MAGIC-COOKIE-1` and
+    /// `XDM-AUTHORIZATION-1`; other protocols are passed to the X11 server unchanged.
+    pub fn connect_with_auth(
+        dpy_name: Option<&str>,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<(Self, usize), ConnectError> {
+        Self::connect_impl(dpy_name, Some((auth_name, auth_data)))
+    }
+
+    fn connect_impl(
+        dpy_name: Option<&str>,
+        auth: Option<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<(Self, usize), ConnectError> {
         // Parse display information
         let parsed_display =
             parse_display::parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
@@ -121,17 +181,62 @@ impl RustConnection<DefaultStream> {
         let stream =
             DefaultStream::connect(&*parsed_display.host, protocol, parsed_display.display)?;
         let screen = parsed_display.screen.into();
-
-        let (family, address) = stream.peer_addr()?;
-        let (auth_name, auth_data) = xauth::get_auth(family, &address, parsed_display.display)
-            // Ignore all errors while determining auth; instead we just try without auth info.
-            .unwrap_or(None)
-            .unwrap_or_else(|| (Vec::new(), Vec::new()));
-
-        Ok((
-            Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)?,
-            screen,
-        ))
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: crate::logging::CONNECT_TARGET,
+            "Connected to display {} on host {:?} via {}",
+            parsed_display.display,
+            parsed_display.host,
+            protocol.unwrap_or("the default protocol")
+        );
+
+        let (auth, auth_source) = match auth {
+            Some(auth) => (Some(auth), AuthorizationSource::Explicit),
+            None => {
+                let (family, address) = stream.peer_addr()?;
+                // Errors while determining auth are only reported if the server rejects us
+                xauth::get_auth(family, &address, parsed_display.display)
+            }
+        };
+        let (auth_name, auth_data) = match auth {
+            Some((name, data)) if name == xdm_auth::XDM_AUTHORIZATION_1 => {
+                match xdm_auth::authorization_data(&data, stream.local_addr()?) {
+                    Some(data) => (name, data),
+                    None => (Vec::new(), Vec::new()),
+                }
+            }
+            Some(auth) => auth,
+            None => (Vec::new(), Vec::new()),
+        };
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: crate::logging::CONNECT_TARGET,
+            "Using authorization protocol {:?} ({:?})",
+            String::from_utf8_lossy(&auth_name),
+            auth_source
+        );
+
+        let rejected = |authenticate, reason| {
+            ConnectError::SetupRejected(SetupRejected {
+                authenticate,
+                reason,
+                auth_name: auth_name.clone(),
+                auth_source,
+            })
+        };
+        let conn =
+            Self::connect_to_stream_with_auth_info(stream, screen, auth_name.clone(), auth_data)
+                .map_err(|err| {
+                    let err = match err {
+                        ConnectError::SetupFailed(err) => rejected(false, err.reason),
+                        ConnectError::SetupAuthenticate(err) => rejected(true, err.reason),
+                        err => err,
+                    };
+                    #[cfg(feature = "log")]
+                    log::warn!(target: crate::logging::CONNECT_TARGET, "{}", err);
+                    err
+                })?;
+        Ok((conn, screen))
     }
 }
 
@@ -162,6 +267,16 @@ impl<S: Stream> RustConnection<S> {
     ) -> Result<Self, ConnectError> {
         write_setup(&stream, auth_name, auth_data)?;
         let setup = read_setup(&stream)?;
+        #[cfg(feature = "log")]
+        log::info!(
+            target: crate::logging::CONNECT_TARGET,
+            "X11 server {:?} (release {}) speaks protocol version {}.{} and has {} screens",
+            String::from_utf8_lossy(&setup.vendor),
+            setup.release_number,
+            setup.protocol_major_version,
+            setup.protocol_minor_version,
+            setup.roots.len()
+        );
 
         // Check that we got a valid screen number
         if screen >= setup.roots.len() {
@@ -183,23 +298,254 @@ impl<S: Stream> RustConnection<S> {
 
     fn for_inner(
         stream: S,
-        inner: inner::ConnectionInner,
+        mut inner: inner::ConnectionInner,
         setup: Setup,
     ) -> Result<Self, ConnectError> {
         let allocator =
             id_allocator::IDAllocator::new(setup.resource_id_base, setup.resource_id_mask)?;
+        let buffer_allocator: Arc<dyn BufferAllocator> =
+            Arc::new(buffer_allocator::GlobalAllocator);
+        inner.set_buffer_allocator(Arc::clone(&buffer_allocator));
         Ok(RustConnection {
             inner: Mutex::new(inner),
             stream,
-            packet_reader: Mutex::new(PacketReader::new()),
-            reader_condition: Condvar::new(),
+            packet_reader: Mutex::new(PacketReader::new(Arc::clone(&buffer_allocator))),
+            buffer_allocator,
+            priority_lane: Default::default(),
             id_allocator: Mutex::new(allocator),
             setup,
             extension_manager: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
+            flush_mode: FlushMode::AutoFlush,
         })
     }
 
+    /// Set whether pending requests are flushed before waiting for an event.
+    ///
+    /// The default is [`FlushMode::AutoFlush`]. Waiting for a reply always flushes, independent
+    /// of this setting.
+    pub fn set_flush_mode(&mut self, mode: FlushMode) {
+        self.flush_mode = mode;
+    }
+
+    /// Get the current [`FlushMode`] of this connection.
+    pub fn flush_mode(&self) -> FlushMode {
+        self.flush_mode
+    }
+
+    /// Share extension information with other connections of this process to the same server.
+    ///
+    /// `display` is the display name that was used for connecting, e.g. the value of `$DISPLAY`.
+    /// Extensions that another sharing connection already queried are not queried again. This
+    /// should be done right after connecting. See
+    /// [`ServerIdentity`](crate::extension_manager::ServerIdentity) for the details.
+    pub fn share_extension_information(&mut self, display: &str) {
+        let identity = ServerIdentity::new(display, &self.setup);
+        self.extension_manager
+            .get_mut()
+            .unwrap()
+            .share_with(identity);
+    }
+
+    /// Set the allocator for the buffers of received packets.
+    ///
+    /// This should be done right after connecting, since the buffers of packets that were
+    /// already received are not affected. See [`BufferAllocator`] for details.
+    pub fn set_buffer_allocator(&mut self, allocator: Arc<dyn BufferAllocator>) {
+        self.packet_reader
+            .get_mut()
+            .unwrap()
+            .set_allocator(Arc::clone(&allocator));
+        self.inner
+            .get_mut()
+            .unwrap()
+            .set_buffer_allocator(Arc::clone(&allocator));
+        self.buffer_allocator = allocator;
+    }
+
+    /// Parse an event and give its buffer back to the [`BufferAllocator`].
+    fn parse_and_release_event(&self, event: Vec<u8>) -> Result<Event, ParseError> {
+        let result = self.parse_event(&event);
+        self.release_buffer(event);
+        result
+    }
+
+    /// Parse a reply or an error and give its buffer back to the [`BufferAllocator`].
+    fn parse_and_release_reply<R: TryParse>(&self, reply: Vec<u8>) -> Result<R, ReplyError> {
+        let result = if reply[0] == 0 {
+            match self.parse_error(&reply) {
+                Ok(error) => Err(error.into()),
+                Err(error) => Err(error.into()),
+            }
+        } else {
+            R::try_parse(&reply).map(|r| r.0).map_err(Into::into)
+        };
+        self.release_buffer(reply);
+        result
+    }
+
+    /// Get a view of this connection that sends latency-critical requests.
+    ///
+    /// See [`Priority`] for details.
+    pub fn priority(&self) -> Priority<'_, S> {
+        Priority::new(self)
+    }
+
+    /// Wait for a new event from the X11 server, but give up after the given timeout.
+    ///
+    /// Returns `None` if no event arrived in time. Like [`Connection::wait_for_event`], this
+    /// flushes pending requests first unless [`FlushMode::NoAutoFlush`] is set. Flushing is not
+    /// limited by the timeout.
+    pub fn wait_for_event_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Event>, ConnectionError> {
+        match self.wait_for_raw_event_with_timeout(timeout)? {
+            Some((event, _sequence)) => Ok(Some(self.parse_and_release_event(event)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Wait for a new raw event from the X11 server, but give up after the given timeout.
+    ///
+    /// See [`RustConnection::wait_for_event_with_timeout`] for details.
+    pub fn wait_for_raw_event_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut inner = self.inner.lock().unwrap();
+        if self.flush_mode == FlushMode::AutoFlush {
+            inner = self.flush_impl(inner)?;
+        }
+        loop {
+            if let Some(event) = inner.poll_for_event_with_sequence() {
+                return Ok(Some(event));
+            }
+            if expired(deadline) {
+                return Ok(None);
+            }
+            inner = self.read_packet_and_enqueue(
+                inner,
+                BlockingMode::Blocking(Interest::Event, deadline),
+            )?;
+        }
+    }
+
+    /// Wait for the reply to a request, but give up after the given timeout.
+    ///
+    /// If the reply did not arrive in time, the cookie is given back as `Ok(Err(cookie))`, so
+    /// that the caller can wait again later or drop it. Flushing the request is not limited by
+    /// the timeout.
+    pub fn wait_for_reply_with_timeout<'c, R: TryParse>(
+        &'c self,
+        cookie: Cookie<'c, Self, R>,
+        timeout: Duration,
+    ) -> Result<Result<R, Cookie<'c, Self, R>>, ReplyError> {
+        let deadline = Instant::now().checked_add(timeout);
+        let sequence = cookie.sequence_number();
+        let mut inner = self.inner.lock().unwrap();
+        inner = self.flush_impl(inner)?;
+        let reply = loop {
+            if let Some((reply, _fds)) = inner.poll_for_reply_or_error(sequence) {
+                break reply;
+            }
+            if expired(deadline) {
+                return Ok(Err(cookie));
+            }
+            inner = self.read_packet_and_enqueue(
+                inner,
+                BlockingMode::Blocking(Interest::Reply(sequence), deadline),
+            )?;
+        };
+        drop(inner);
+        // The reply was consumed, so the cookie must not discard it
+        let _ = cookie.into_sequence_number();
+        self.parse_and_release_reply(reply).map(Ok)
+    }
+
+    /// Get the reply to a request if it is available, and otherwise register a waker for it.
+    ///
+    /// This never blocks. If the reply was not received yet, the cookie is given back as
+    /// `Ok(Err(cookie))` and `waker` is woken once the reply was read from the connection. This is
+    /// the building block for futures and for integrating with custom reactors.
+    ///
+    /// Wakers are only woken when something reads from the connection. A single-threaded reactor
+    /// has to call [`RustConnection::read_available_packets`] whenever the stream becomes
+    /// readable. Pending requests are flushed first.
+    pub fn poll_for_reply_with_waker<'c, R: TryParse>(
+        &'c self,
+        cookie: Cookie<'c, Self, R>,
+        waker: &Waker,
+    ) -> Result<Result<R, Cookie<'c, Self, R>>, ReplyError> {
+        let sequence = cookie.sequence_number();
+        let mut inner = self.inner.lock().unwrap();
+        inner = self.flush_impl(inner)?;
+        let mut reply = inner.poll_for_reply_or_error(sequence);
+        if reply.is_none() {
+            inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            reply = inner.poll_for_reply_or_error(sequence);
+        }
+        let (reply, _fds) = match reply {
+            Some(reply) => reply,
+            None => {
+                inner
+                    .waiters
+                    .register_waker(Interest::Reply(sequence), waker);
+                return Ok(Err(cookie));
+            }
+        };
+        drop(inner);
+        // The reply was consumed, so the cookie must not discard it
+        let _ = cookie.into_sequence_number();
+        self.parse_and_release_reply(reply).map(Ok)
+    }
+
+    /// Get an event if one is available, and otherwise register a waker for the next event.
+    ///
+    /// This never blocks and does not flush. See [`RustConnection::poll_for_reply_with_waker`]
+    /// for when `waker` is woken.
+    pub fn poll_for_event_with_waker(
+        &self,
+        waker: &Waker,
+    ) -> Result<Option<Event>, ConnectionError> {
+        match self.poll_for_raw_event_with_waker(waker)? {
+            Some((event, _sequence)) => Ok(Some(self.parse_and_release_event(event)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get a raw event if one is available, and otherwise register a waker for the next event.
+    ///
+    /// See [`RustConnection::poll_for_event_with_waker`] for details.
+    pub fn poll_for_raw_event_with_waker(
+        &self,
+        waker: &Waker,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(event) = inner.poll_for_event_with_sequence() {
+            return Ok(Some(event));
+        }
+        inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+        let event = inner.poll_for_event_with_sequence();
+        if event.is_none() {
+            inner.waiters.register_waker(Interest::Event, waker);
+        }
+        Ok(event)
+    }
+
+    /// Read all packets that are available without blocking and wake the interested wakers.
+    ///
+    /// Call this when the stream became readable and wakers were registered with
+    /// [`RustConnection::poll_for_reply_with_waker`] or
+    /// [`RustConnection::poll_for_event_with_waker`]. This does nothing if another thread is
+    /// currently reading, since that thread wakes the wakers instead.
+    pub fn read_available_packets(&self) -> Result<(), ConnectionError> {
+        let inner = self.inner.lock().unwrap();
+        let _inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+        Ok(())
+    }
+
     /// Internal function for actually sending a request.
     ///
     /// This function "does the actual work" for `send_request_with_reply()` and
@@ -207,12 +553,49 @@ impl<S: Stream> RustConnection<S> {
     fn send_request(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
+        kind: ReplyFDKind,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let mut storage = Default::default();
+        let bufs = compute_length_field(self, bufs, &mut storage)?;
+
+        // Let latency-critical requests of other threads go first
+        self.priority_lane.wait_until_empty();
+
+        let (seqno, _inner) = self.write_request(bufs, fds, kind)?;
+        Ok(seqno)
+    }
+
+    /// Send a latency-critical request and flush it immediately.
+    ///
+    /// While this request is being sent, other threads wait in `send_request()` before locking
+    /// `inner`.
+    fn send_priority_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
         kind: ReplyFDKind,
     ) -> Result<SequenceNumber, ConnectionError> {
+        // `compute_length_field` might send a BigRequests request, which must not wait for the
+        // priority lane that we are about to enter
         let mut storage = Default::default();
         let bufs = compute_length_field(self, bufs, &mut storage)?;
 
+        let _guard = self.priority_lane.enter();
+        let (seqno, inner) = self.write_request(bufs, fds, kind)?;
+        let _inner = self.flush_impl(inner)?;
+        Ok(seqno)
+    }
+
+    /// Assign a sequence number to a request and write it.
+    ///
+    /// The request must already have its length field set.
+    fn write_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+        kind: ReplyFDKind,
+    ) -> Result<(SequenceNumber, MutexGuardInner<'_>), ConnectionError> {
         // Note: `inner` must be kept blocked until the request has been completely written
         // or buffered to avoid sending the data of different requests interleaved. For this
         // reason, `read_packet_and_enqueue` must always be called with `BlockingMode::NonBlocking`
@@ -223,8 +606,10 @@ impl<S: Stream> RustConnection<S> {
             match inner.send_request(kind) {
                 Some(seqno) => {
                     // Now actually send the buffers
-                    let _inner = self.write_all_vectored(inner, bufs, fds)?;
-                    return Ok(seqno);
+                    let inner = self.write_all_vectored(inner, bufs, fds)?;
+                    #[cfg(feature = "log")]
+                    crate::logging::sent_request(seqno, bufs);
+                    return Ok((seqno, inner));
                 }
                 None => {
                     inner = self.send_sync(inner)?;
@@ -242,18 +627,12 @@ impl<S: Stream> RustConnection<S> {
         &'a self,
         mut inner: MutexGuardInner<'a>,
     ) -> Result<MutexGuardInner<'a>, std::io::Error> {
-        let length = 1u16.to_ne_bytes();
-        let request = [
-            GET_INPUT_FOCUS_REQUEST,
-            0, /* pad */
-            length[0],
-            length[1],
-        ];
-
-        let seqno = inner
-            .send_request(ReplyFDKind::ReplyWithoutFDs)
-            .expect("Sending a HasResponse request should not be blocked by syncs");
-        inner.discard_reply(seqno, DiscardMode::DiscardReplyAndError);
+        let request = prepare_sync(&mut inner);
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: crate::logging::IO_TARGET,
+            "Sending GetInputFocus to keep track of sequence numbers"
+        );
         let inner = self.write_all_vectored(inner, &[IoSlice::new(&request)], Vec::new())?;
 
         Ok(inner)
@@ -265,11 +644,11 @@ impl<S: Stream> RustConnection<S> {
         &'a self,
         mut inner: MutexGuardInner<'a>,
         mut bufs: &[IoSlice<'_>],
-        mut fds: Vec<RawFdContainer>,
+        mut fds: Vec<OwnedFd>,
     ) -> std::io::Result<MutexGuardInner<'a>> {
         let mut partial_buf: &[u8] = &[];
         while !partial_buf.is_empty() || !bufs.is_empty() || !fds.is_empty() {
-            self.stream.poll(PollMode::ReadAndWritable)?;
+            self.stream.poll(PollMode::ReadAndWritable, None)?;
             let write_result = if !partial_buf.is_empty() {
                 inner
                     .write_buffer
@@ -326,7 +705,7 @@ impl<S: Stream> RustConnection<S> {
         mut inner: MutexGuardInner<'a>,
     ) -> std::io::Result<MutexGuardInner<'a>> {
         while inner.write_buffer.needs_flush() {
-            self.stream.poll(PollMode::ReadAndWritable)?;
+            self.stream.poll(PollMode::ReadAndWritable, None)?;
             match inner.write_buffer.flush(&self.stream) {
                 // Flush completed
                 Ok(()) => break,
@@ -349,6 +728,11 @@ impl<S: Stream> RustConnection<S> {
     /// reason, you need to pass in a `MutexGuard` to be dropped. This function locks the mutex
     /// again and returns a new `MutexGuard`.
     ///
+    /// In blocking mode, `mode` describes what the caller waits for. If another thread is already
+    /// reading, this function only returns once that thread read something that the caller is
+    /// interested in or once the caller should take over reading. Spurious returns are still
+    /// possible, so the caller must check for the packet it waits for and call this function again.
+    ///
     /// Note: If `mode` is `BlockingMode::Blocking`, the lock on `inner` will be temporarily
     /// released. While sending a request, `inner` must be kept locked to avoid sending the data
     /// of different requests interleaved. So, when `read_packet_and_enqueue` is called as part
@@ -362,63 +746,66 @@ impl<S: Stream> RustConnection<S> {
         match self.packet_reader.try_lock() {
             Err(TryLockError::WouldBlock) => {
                 // In non-blocking mode, we just return immediately
-                match mode {
+                let (interest, deadline) = match mode {
                     BlockingMode::NonBlocking => return Ok(inner),
-                    BlockingMode::Blocking => {}
-                }
+                    BlockingMode::Blocking(interest, deadline) => (interest, deadline),
+                };
 
                 // 1.1. Someone else is reading (other thread is at 2.2);
                 // wait for it. `Condvar::wait` will unlock `inner`, so
                 // the other thread can relock `inner` at 2.1.3 (and to allow
                 // other threads to arrive 0.1).
                 //
-                // When `wait` finishes, other thread has enqueued a packet,
-                // so the purpose of this function has been fulfilled. `wait`
-                // will relock `inner` when it returns.
-                Ok(self.reader_condition.wait(inner).unwrap())
+                // The other thread wakes us up at 2.4 once it enqueued
+                // something we are interested in, or at 2.5 when we should
+                // become the next reader. `wait` will relock `inner` when it
+                // returns.
+                let condvar = inner.waiters.register(interest);
+                let mut inner = match deadline {
+                    None => condvar.wait(inner).unwrap(),
+                    Some(deadline) => {
+                        let timeout = deadline.saturating_duration_since(Instant::now());
+                        condvar.wait_timeout(inner, timeout).unwrap().0
+                    }
+                };
+                inner.waiters.unregister(condvar);
+                Ok(inner)
             }
             Err(TryLockError::Poisoned(e)) => panic!("{}", e),
-            Ok(mut packet_reader) => {
-                // Make sure sleeping readers are woken up when we return
-                // (Even in case of errors)
-                let notify_on_drop = NotifyOnDrop(&self.reader_condition);
-
+            Ok(packet_reader) => {
                 // 2.1. Poll for read if mode is blocking.
-                if mode == BlockingMode::Blocking {
+                if let BlockingMode::Blocking(_, deadline) = mode {
                     // 2.1.1. Unlock `inner`, so other threads can use it while
                     // during the poll.
                     drop(inner);
                     // 2.1.2. Do the actual poll
-                    self.stream.poll(PollMode::Readable)?;
+                    let timeout =
+                        deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+                    let poll_result = self.stream.poll(PollMode::Readable, timeout);
                     // 2.1.3. Relock inner
                     inner = self.inner.lock().unwrap();
+                    if let Err(e) = poll_result {
+                        // Other threads have to notice the error themselves
+                        drop(packet_reader);
+                        inner.waiters.notify_all();
+                        return Err(e);
+                    }
                 }
 
-                // 2.2. Try to read as many packets as possible without blocking.
-                let mut fds = Vec::new();
-                let mut packets = Vec::new();
-                packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds)?;
-
-                // 2.3. Once `inner` has been relocked, drop the
-                // lock on `packet_reader`. While inner is locked, other
-                // threads cannot arrive at 0.1 anyways.
-                //
-                // `packet_reader` must be unlocked with `inner` is locked,
-                // otherwise it could let another thread wait on 2.1
-                // for a reply that has been read but not enqueued yet.
-                drop(packet_reader);
-
-                // 2.4. Actually enqueue the read packets.
-                inner.enqueue_fds(fds);
-                packets
-                    .into_iter()
-                    .for_each(|packet| inner.enqueue_packet(packet));
-
-                // 2.5. Notify the condvar by dropping the `notify_on_drop` object.
-                // The object would have been dropped when the function returns, so
-                // the explicit drop is not really needed. The purpose of having a
-                // explicit drop is to... make it explicit.
-                drop(notify_on_drop);
+                // 2.2. - 2.4. Read and enqueue as many packets as possible without blocking.
+                self.read_with_reader(&mut inner, packet_reader)?;
+
+                // 2.5. If we got what we were waiting for, we are done reading
+                // and someone else has to take over. Otherwise, the caller
+                // calls us again without unlocking `inner`, so that we stay
+                // the reader. In non-blocking mode, `inner` was never
+                // unlocked, so no other thread started waiting for us.
+                // The same applies if the caller gives up because its deadline passed.
+                if let BlockingMode::Blocking(interest, deadline) = mode {
+                    if inner.is_satisfied(interest) || expired(deadline) {
+                        inner.waiters.notify_next_reader();
+                    }
+                }
 
                 // 2.6. Return the locked `inner` back to the caller.
                 Ok(inner)
@@ -426,6 +813,46 @@ impl<S: Stream> RustConnection<S> {
         }
     }
 
+    /// Read packets without blocking and enqueue them.
+    ///
+    /// The caller must hold the lock on `inner` and pass in the lock on `packet_reader`. Afterwards,
+    /// all threads that can now make progress are woken up.
+    fn read_with_reader(
+        &self,
+        inner: &mut inner::ConnectionInner,
+        mut packet_reader: MutexGuard<'_, PacketReader>,
+    ) -> Result<(), std::io::Error> {
+        // 2.2. Try to read as many packets as possible without blocking.
+        let mut fds = Vec::new();
+        let mut packets = Vec::new();
+        let read_result = packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds);
+
+        // 2.3. Once `inner` has been relocked, drop the
+        // lock on `packet_reader`. While inner is locked, other
+        // threads cannot arrive at 0.1 anyways.
+        //
+        // `packet_reader` must be unlocked with `inner` is locked,
+        // otherwise it could let another thread wait on 2.1
+        // for a reply that has been read but not enqueued yet.
+        drop(packet_reader);
+
+        if let Err(e) = read_result {
+            #[cfg(feature = "log")]
+            log::debug!(target: crate::logging::IO_TARGET, "Reading failed: {}", e);
+            inner.waiters.notify_all();
+            return Err(e);
+        }
+
+        // 2.4. Actually enqueue the read packets and wake up the threads that
+        // can now make progress.
+        inner.enqueue_fds(fds);
+        packets
+            .into_iter()
+            .for_each(|packet| inner.enqueue_packet(packet));
+        inner.notify_satisfied_waiters();
+        Ok(())
+    }
+
     fn prefetch_maximum_request_bytes_impl(&self, max_bytes: &mut MutexGuard<'_, MaxRequestBytes>) {
         if let MaxRequestBytes::Unknown = **max_bytes {
             let request = self
@@ -448,7 +875,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
     fn send_request_with_reply<Reply>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<Cookie<'_, Self, Reply>, ConnectionError>
     where
         Reply: TryParse,
@@ -462,7 +889,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
     fn send_request_with_reply_with_fds<Reply>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<CookieWithFds<'_, Self, Reply>, ConnectionError>
     where
         Reply: TryParseFd,
@@ -476,7 +903,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
         Ok(VoidCookie::new(
             self,
@@ -518,6 +945,25 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         }
     }
 
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        let mut reply = inner.poll_for_reply_or_error(sequence);
+        if reply.is_none() {
+            inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            reply = inner.poll_for_reply_or_error(sequence);
+        }
+        Ok(reply.map(|reply| {
+            if reply.0[0] == 0 {
+                ReplyOrError::Error(reply.0)
+            } else {
+                ReplyOrError::Reply(reply.0)
+            }
+        }))
+    }
+
     fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
         inner = self.flush_impl(inner)?;
@@ -527,7 +973,10 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                 PollReply::NoReply => return Ok(None),
                 PollReply::Reply(buffer) => return Ok(Some(buffer)),
             }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            inner = self.read_packet_and_enqueue(
+                inner,
+                BlockingMode::Blocking(Interest::Reply(sequence), None),
+            )?;
         }
     }
 
@@ -548,7 +997,10 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                 PollReply::NoReply => return Ok(None),
                 PollReply::Reply(buffer) => return Ok(Some(buffer)),
             }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            inner = self.read_packet_and_enqueue(
+                inner,
+                BlockingMode::Blocking(Interest::Reply(sequence), None),
+            )?;
         }
     }
 
@@ -567,7 +1019,10 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                     return Ok(ReplyOrError::Reply(reply));
                 }
             }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            inner = self.read_packet_and_enqueue(
+                inner,
+                BlockingMode::Blocking(Interest::Reply(sequence), None),
+            )?;
         }
     }
 
@@ -610,20 +1065,40 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         crate::x11_utils::X11Error::try_parse(error, &*ext_mgr)
     }
 
-    fn parse_event(&self, event: &[u8]) -> Result<crate::protocol::Event, ParseError> {
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
         let ext_mgr = self.extension_manager.lock().unwrap();
-        crate::protocol::Event::parse(event, &*ext_mgr)
+        Event::parse(event, &*ext_mgr)
+    }
+
+    fn release_buffer(&self, buffer: Vec<u8>) {
+        self.buffer_allocator.release(buffer);
     }
 }
 
 impl<S: Stream> Connection for RustConnection<S> {
+    fn wait_for_event_with_sequence(&self) -> Result<EventAndSeqNumber, ConnectionError> {
+        let (event, seq) = self.wait_for_raw_event_with_sequence()?;
+        Ok((self.parse_and_release_event(event)?, seq))
+    }
+
+    fn poll_for_event_with_sequence(&self) -> Result<Option<EventAndSeqNumber>, ConnectionError> {
+        Ok(match self.poll_for_raw_event_with_sequence()? {
+            Some((event, seq)) => Some((self.parse_and_release_event(event)?, seq)),
+            None => None,
+        })
+    }
+
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
+        if self.flush_mode == FlushMode::AutoFlush {
+            inner = self.flush_impl(inner)?;
+        }
         loop {
             if let Some(event) = inner.poll_for_event_with_sequence() {
                 return Ok(event);
             }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            inner =
+                self.read_packet_and_enqueue(inner, BlockingMode::Blocking(Interest::Event, None))?;
         }
     }
 
@@ -654,6 +1129,24 @@ impl<S: Stream> Connection for RustConnection<S> {
     }
 }
 
+/// Assign a sequence number to a `GetInputFocus` request whose reply is ignored.
+///
+/// This ensures that a reply is expected (`ConnectionInner.next_reply_expected` increases). The
+/// returned request still has to be written.
+fn prepare_sync(inner: &mut inner::ConnectionInner) -> [u8; 4] {
+    let length = 1u16.to_ne_bytes();
+    let seqno = inner
+        .send_request(ReplyFDKind::ReplyWithoutFDs)
+        .expect("Sending a HasResponse request should not be blocked by syncs");
+    inner.discard_reply(seqno, DiscardMode::DiscardReplyAndError);
+    [
+        GET_INPUT_FOCUS_REQUEST,
+        0, /* pad */
+        length[0],
+        length[1],
+    ]
+}
+
 #[cfg(target_endian = "little")]
 fn byte_order() -> u8 {
     0x6c
@@ -681,7 +1174,7 @@ fn write_setup(
     let data = request.serialize();
     let mut nwritten = 0;
     while nwritten != data.len() {
-        write.poll(PollMode::Writable)?;
+        write.poll(PollMode::Writable, None)?;
         // poll returned successfully, so the stream is writable.
         match write.write(&data[nwritten..], &mut Vec::new()) {
             Ok(0) => {
@@ -736,25 +1229,20 @@ fn read_setup(stream: &impl Stream) -> Result<Setup, ConnectError> {
     }
 }
 
-/// Call `notify_all` on a condition variable when dropped.
-#[derive(Debug)]
-struct NotifyOnDrop<'a>(&'a Condvar);
-
-impl Drop for NotifyOnDrop<'_> {
-    fn drop(&mut self) {
-        self.0.notify_all();
-    }
-}
-
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
     use std::io::{Read, Result, Write};
+    use std::sync::Arc;
+    use std::time::Duration;
 
-    use super::{read_setup, PollMode, Stream};
+    use super::{read_setup, BufferPool, PollMode, RustConnection, Stream};
+    use crate::connection::{Connection, FlushMode};
     use crate::errors::ConnectError;
-    use crate::protocol::xproto::{ImageOrder, Setup, SetupAuthenticate, SetupFailed};
-    use crate::utils::RawFdContainer;
+    use crate::protocol::xproto::{
+        ConnectionExt as _, ImageOrder, Setup, SetupAuthenticate, SetupFailed, EXPOSE_EVENT,
+    };
+    use crate::utils::OwnedFd;
     use crate::x11_utils::Serialize;
 
     struct SliceStream<'a, 'b> {
@@ -763,15 +1251,15 @@ mod test {
     }
 
     impl<'a, 'b> Stream for SliceStream<'a, 'b> {
-        fn poll(&self, _mode: PollMode) -> Result<()> {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
             Ok(())
         }
 
-        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
             self.read_slice.borrow_mut().read(buf)
         }
 
-        fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize> {
             assert!(fds.is_empty());
             self.write_slice.borrow_mut().write(buf)
         }
@@ -849,4 +1337,306 @@ mod test {
             value => panic!("Unexpected value {:?}", value),
         }
     }
+
+    /// A stream that provides a single Expose event and remembers if something was written before
+    /// it was read.
+    #[derive(Debug, Default)]
+    struct EventStream {
+        written: RefCell<Vec<u8>>,
+        written_before_read: RefCell<Option<bool>>,
+    }
+
+    impl Stream for EventStream {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            Ok(())
+        }
+
+        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            let mut written_before_read = self.written_before_read.borrow_mut();
+            if written_before_read.is_some() {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            *written_before_read = Some(!self.written.borrow().is_empty());
+            let mut event = [0; 32];
+            event[0] = EXPOSE_EVENT;
+            (&event[..]).read(buf)
+        }
+
+        fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            assert!(fds.is_empty());
+            self.written.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    pub(super) fn test_setup() -> Setup {
+        Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0,
+            resource_id_mask: 0xff,
+            motion_buffer_size: 0,
+            maximum_request_length: 0xffff,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: vec![],
+            pixmap_formats: vec![],
+            roots: vec![],
+        }
+    }
+
+    fn connect_event_stream() -> RustConnection<EventStream> {
+        RustConnection::for_connected_stream(EventStream::default(), test_setup()).unwrap()
+    }
+
+    #[test]
+    fn wait_for_event_flushes() {
+        let conn = connect_event_stream();
+        assert_eq!(conn.flush_mode(), FlushMode::AutoFlush);
+        let _ = conn.no_operation().unwrap();
+        let event = conn.wait_for_raw_event().unwrap();
+        assert_eq!(event[0], EXPOSE_EVENT);
+        assert_eq!(*conn.stream().written_before_read.borrow(), Some(true));
+    }
+
+    #[test]
+    fn wait_for_event_no_auto_flush() {
+        let mut conn = connect_event_stream();
+        conn.set_flush_mode(FlushMode::NoAutoFlush);
+        let _ = conn.no_operation().unwrap();
+        let event = conn.wait_for_raw_event().unwrap();
+        assert_eq!(event[0], EXPOSE_EVENT);
+        assert_eq!(*conn.stream().written_before_read.borrow(), Some(false));
+        conn.flush().unwrap();
+        assert!(!conn.stream().written.borrow().is_empty());
+    }
+
+    #[test]
+    fn parsed_events_are_released() {
+        let mut conn = connect_event_stream();
+        let pool = Arc::new(BufferPool::new(1, 64));
+        conn.set_buffer_allocator(pool.clone());
+        assert_eq!(pool.available(), 0);
+        let event = conn.wait_for_event().unwrap();
+        assert_eq!(event.response_type(), EXPOSE_EVENT);
+        // The buffer of the event went back to the pool
+        assert_eq!(pool.available(), 1);
+    }
+
+    /// A stream that answers every `GetInputFocus` request with a reply.
+    #[derive(Debug, Default)]
+    struct InputFocusStream {
+        sequence: RefCell<u16>,
+        pending: RefCell<Vec<u8>>,
+    }
+
+    impl Stream for InputFocusStream {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            Ok(())
+        }
+
+        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            let mut pending = self.pending.borrow_mut();
+            if pending.is_empty() {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            let len = buf.len().min(pending.len());
+            buf[..len].copy_from_slice(&pending[..len]);
+            let _ = pending.drain(..len);
+            Ok(len)
+        }
+
+        fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            assert!(fds.is_empty());
+            // GetInputFocus requests are four bytes long
+            assert_eq!(buf.len() % 4, 0);
+            for _ in 0..buf.len() / 4 {
+                let mut sequence = self.sequence.borrow_mut();
+                *sequence = sequence.wrapping_add(1);
+                let mut reply = [0; 32];
+                reply[0] = 1;
+                reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                self.pending.borrow_mut().extend_from_slice(&reply);
+            }
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn parsed_and_discarded_replies_are_released() {
+        let mut conn =
+            RustConnection::for_connected_stream(InputFocusStream::default(), test_setup())
+                .unwrap();
+        let pool = Arc::new(BufferPool::new(2, 64));
+        conn.set_buffer_allocator(pool.clone());
+        for _ in 0..100 {
+            // A reply that is parsed by the cookie
+            let _ = conn.get_input_focus().unwrap().reply().unwrap();
+            // A reply that is discarded when it arrives
+            drop(conn.get_input_focus().unwrap());
+        }
+        // Make sure that the last discarded reply was received
+        let _ = conn.get_input_focus().unwrap().reply().unwrap();
+        // All buffers went back to the pool, except for the one for the next packet
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[test]
+    fn priority_request_is_flushed() {
+        let conn = connect_event_stream();
+        let _ = conn.no_operation().unwrap();
+        assert!(conn.stream().written.borrow().is_empty());
+        let _ = conn.priority().no_operation().unwrap();
+        // The earlier request is flushed together with the latency-critical one
+        assert_eq!(conn.stream().written.borrow().len(), 8);
+    }
+
+    /// A stream that answers with a reply carrying a single file descriptor.
+    #[cfg(all(unix, feature = "shm"))]
+    #[derive(Debug, Default)]
+    struct FdReplyStream {
+        replied: RefCell<bool>,
+    }
+
+    #[cfg(all(unix, feature = "shm"))]
+    impl Stream for FdReplyStream {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            Ok(())
+        }
+
+        fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            if self.replied.replace(true) {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            fd_storage.push(std::fs::File::open("/dev/null")?.into());
+            // A reply with nfd = 1 to the request with sequence number 1
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[1] = 1;
+            reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            (&reply[..]).read(buf)
+        }
+
+        fn write(&self, buf: &[u8], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            Ok(buf.len())
+        }
+    }
+
+    #[cfg(all(unix, feature = "shm"))]
+    #[test]
+    fn reply_with_fds() {
+        use crate::cookie::CookieWithFds;
+        use crate::protocol::shm::CreateSegmentReply;
+
+        let conn =
+            RustConnection::for_connected_stream(FdReplyStream::default(), test_setup()).unwrap();
+        // The content of the request does not matter, only its length field
+        let request = [0, 0, 1, 0];
+        let seqno = conn
+            .send_request(
+                &[std::io::IoSlice::new(&request)],
+                Vec::new(),
+                super::ReplyFDKind::ReplyWithFDs,
+            )
+            .unwrap();
+        assert_eq!(seqno, 1);
+        let reply = CookieWithFds::<_, CreateSegmentReply>::new(&conn, seqno)
+            .reply()
+            .unwrap();
+        assert_eq!(reply.nfd, 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_for_replies_from_many_threads() {
+        use crate::testing::echo::EchoServer;
+        use std::sync::Arc;
+
+        let (conn, server) = EchoServer::connect().unwrap();
+        let conn = Arc::new(conn);
+
+        let threads = (0..8)
+            .map(|_| {
+                let conn = Arc::clone(&conn);
+                std::thread::spawn(move || {
+                    for _ in 0..200 {
+                        let cookie = conn.get_input_focus().unwrap();
+                        let sequence = cookie.sequence_number();
+                        let reply = cookie.reply().unwrap();
+                        assert_eq!(u64::from(reply.focus), sequence);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        drop(conn);
+        server.join();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn poll_for_reply() {
+        use crate::testing::echo::EchoServer;
+
+        let (conn, server) = EchoServer::connect().unwrap();
+
+        // The request was not flushed yet, so there cannot be a reply
+        let mut cookie = match conn.get_input_focus().unwrap().poll_for_reply().unwrap() {
+            Ok(_) => panic!("Got a reply to a request that was not sent"),
+            Err(cookie) => cookie,
+        };
+        conn.flush().unwrap();
+        let reply = loop {
+            match cookie.poll_for_reply().unwrap() {
+                Ok(reply) => break reply,
+                Err(pending) => cookie = pending,
+            }
+            std::thread::yield_now();
+        };
+        assert_eq!(reply.focus, 1);
+
+        drop(conn);
+        server.join();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_with_timeout() {
+        use super::DefaultStream;
+
+        let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = RustConnection::for_connected_stream(stream, test_setup()).unwrap();
+        let timeout = Duration::from_millis(10);
+
+        assert!(conn.wait_for_event_with_timeout(timeout).unwrap().is_none());
+        let cookie =
+            match conn.wait_for_reply_with_timeout(conn.get_input_focus().unwrap(), timeout) {
+                Ok(Ok(_)) => panic!("Got a reply that was never sent"),
+                Ok(Err(cookie)) => cookie,
+                Err(e) => panic!("{:?}", e),
+            };
+
+        // Now the server answers
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+        let reply = conn
+            .wait_for_reply_with_timeout(cookie, Duration::from_secs(60))
+            .unwrap()
+            .unwrap_or_else(|_| panic!("The reply did not arrive"));
+        assert_eq!(reply.focus, 42);
+    }
 }
@@ -10,10 +10,22 @@ pub(crate) fn parse_display(dpy_name: Option<&str>) -> Option<ParsedDisplay> {
     // If no dpy name was provided, use the env var. If no env var exists, return None.
     match dpy_name {
         Some(dpy_name) => parse_display_impl(dpy_name),
-        None => parse_display_impl(&std::env::var("DISPLAY").ok()?),
+        None => parse_display_impl(&default_display()?),
     }
 }
 
+#[cfg(not(windows))]
+fn default_display() -> Option<String> {
+    std::env::var("DISPLAY").ok()
+}
+
+// X11 servers on Windows, e.g. Xming and VcXsrv, do not set DISPLAY and listen on TCP for
+// display 0 by default.
+#[cfg(windows)]
+fn default_display() -> Option<String> {
+    Some(std::env::var("DISPLAY").unwrap_or_else(|_| ":0".to_string()))
+}
+
 fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
     // Everything up to the last '/' is the protocol. This part is optional.
     let (protocol, remaining) = if let Some(pos) = dpy_name.rfind('/') {
@@ -73,7 +85,11 @@ mod test {
 
     fn test_missing_input() {
         std::env::remove_var("DISPLAY");
-        assert_eq!(parse_display(None), None);
+        if cfg!(windows) {
+            assert_eq!(parse_display(None), parse_display(Some(":0")));
+        } else {
+            assert_eq!(parse_display(None), None);
+        }
     }
 
     fn own_good_cases() {
@@ -97,6 +113,24 @@ mod test {
                     screen: 2,
                 },
             ),
+            (
+                "tcp/localhost:0.0",
+                ParsedDisplay {
+                    host: "localhost".to_string(),
+                    protocol: Some("tcp".to_string()),
+                    display: 0,
+                    screen: 0,
+                },
+            ),
+            (
+                "tcp/:2",
+                ParsedDisplay {
+                    host: "".to_string(),
+                    protocol: Some("tcp".to_string()),
+                    display: 2,
+                    screen: 0,
+                },
+            ),
             (
                 "a:b/c/foo:bar:1.2",
                 ParsedDisplay {
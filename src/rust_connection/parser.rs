@@ -0,0 +1,206 @@
+//! Split the byte stream from an X11 server into packets.
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use super::buffer_allocator::GlobalAllocator;
+use super::BufferAllocator;
+use crate::protocol::xproto::GE_GENERIC_EVENT;
+
+/// Minimal length of an X11 packet
+const MINIMAL_PACKET_LENGTH: usize = 32;
+
+/// Splits the bytes that an X11 server sends into replies, errors, and events.
+///
+/// This is the framing code that [`RustConnection`](super::RustConnection) uses. Errors and most
+/// events are 32 bytes long. Replies and generic events (`GE_GENERIC_EVENT`) are longer by the
+/// number of 4-byte units in their length field. The parser does not look at anything else, so it
+/// can be used by proxies, X11 servers written for tests, or connections for async runtimes.
+///
+/// Bytes can be fed in pieces of any size:
+///
+/// ```
+/// use x11rb::rust_connection::Parser;
+///
+/// let mut parser = Parser::new();
+/// let mut packets = Vec::new();
+/// // An event split into two pieces
+/// parser.feed(&[12; 20], &mut packets);
+/// assert!(packets.is_empty());
+/// parser.feed(&[12; 12], &mut packets);
+/// assert_eq!(packets, [vec![12; 32]]);
+/// ```
+///
+/// To avoid a copy, data can also be read directly into the parser via [`Parser::unfilled`] and
+/// [`Parser::advance`].
+#[derive(Debug)]
+pub struct Parser {
+    allocator: Arc<dyn BufferAllocator>,
+
+    // A packet that was partially read.
+    pending_packet: Vec<u8>,
+    // Up to where the packet is already read.
+    already_read: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser {
+    /// Create a parser whose packets are allocated by the global allocator.
+    pub fn new() -> Self {
+        Self::with_allocator(Arc::new(GlobalAllocator))
+    }
+
+    /// Create a parser that gets the buffers for packets from the given allocator.
+    pub fn with_allocator(allocator: Arc<dyn BufferAllocator>) -> Self {
+        Self {
+            pending_packet: new_packet(&*allocator),
+            allocator,
+            already_read: 0,
+        }
+    }
+
+    /// Use a different allocator for the buffers of future packets.
+    pub fn set_allocator(&mut self, allocator: Arc<dyn BufferAllocator>) {
+        if self.already_read == 0 {
+            let old = std::mem::replace(&mut self.pending_packet, new_packet(&*allocator));
+            self.allocator.release(old);
+        }
+        self.allocator = allocator;
+    }
+
+    /// Get the length of a packet from its first 32 bytes.
+    pub fn packet_length(header: &[u8; MINIMAL_PACKET_LENGTH]) -> usize {
+        MINIMAL_PACKET_LENGTH + extra_length(header)
+    }
+
+    /// Check if a packet was partially fed to the parser.
+    pub fn has_partial_packet(&self) -> bool {
+        self.already_read != 0
+    }
+
+    /// Parse the given bytes and append the packets that were completed to `out_packets`.
+    ///
+    /// The bytes of an incomplete packet at the end are kept until the next call.
+    pub fn feed(&mut self, mut data: &[u8], out_packets: &mut Vec<Vec<u8>>) {
+        while !data.is_empty() {
+            let unfilled = self.unfilled();
+            let to_copy = data.len().min(unfilled.len());
+            unfilled[..to_copy].copy_from_slice(&data[..to_copy]);
+            data = &data[to_copy..];
+            self.advance(to_copy, out_packets);
+        }
+    }
+
+    /// Get the part of the current packet that was not received yet.
+    ///
+    /// Until the first 32 bytes of a packet are known, its length is not known, so that this only
+    /// covers the rest of the first 32 bytes. Afterwards, it covers the rest of the packet.
+    pub fn unfilled(&mut self) -> &mut [u8] {
+        &mut self.pending_packet[self.already_read..]
+    }
+
+    /// Mark the first `nread` bytes of [`Parser::unfilled`] as received.
+    ///
+    /// If this completes the packet, it is appended to `out_packets`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nread` is larger than the slice returned by [`Parser::unfilled`].
+    pub fn advance(&mut self, nread: usize, out_packets: &mut Vec<Vec<u8>>) {
+        assert!(
+            self.already_read + nread <= self.pending_packet.len(),
+            "More bytes than the unfilled part of the packet were read"
+        );
+        self.already_read += nread;
+        // Do we still need to compute the length field? (length == MINIMAL_PACKET_LENGTH)
+        if self.already_read == MINIMAL_PACKET_LENGTH {
+            // Yes, then compute the packet length and resize the `Vec` to its final size.
+            let length = Self::packet_length(self.pending_packet[..].try_into().unwrap());
+            if self.pending_packet.capacity() < length {
+                let mut packet = self.allocator.allocate(length);
+                packet.extend_from_slice(&self.pending_packet);
+                let old = std::mem::replace(&mut self.pending_packet, packet);
+                self.allocator.release(old);
+            }
+            self.pending_packet.resize(length, 0);
+        }
+
+        // Has the packet been completely read?
+        if self.already_read == self.pending_packet.len() {
+            // Check that we really read the whole packet
+            let initial_packet = &self.pending_packet[0..MINIMAL_PACKET_LENGTH]
+                .try_into()
+                .unwrap();
+            let extra = extra_length(initial_packet);
+            assert_eq!(self.pending_packet.len(), MINIMAL_PACKET_LENGTH + extra);
+
+            out_packets.push(std::mem::replace(
+                &mut self.pending_packet,
+                new_packet(&*self.allocator),
+            ));
+            self.already_read = 0;
+        }
+    }
+}
+
+/// Get a buffer for the fixed-size part of the next packet.
+fn new_packet(allocator: &dyn BufferAllocator) -> Vec<u8> {
+    let mut packet = allocator.allocate(MINIMAL_PACKET_LENGTH);
+    packet.clear();
+    packet.resize(MINIMAL_PACKET_LENGTH, 0);
+    packet
+}
+
+// Compute the length beyond `MINIMAL_PACKET_LENGTH` of an X11 packet.
+fn extra_length(buffer: &[u8; MINIMAL_PACKET_LENGTH]) -> usize {
+    let response_type = buffer[0];
+
+    const REPLY: u8 = 1;
+    if response_type == REPLY || response_type & 0x7f == GE_GENERIC_EVENT {
+        let length_field = buffer[4..8].try_into().unwrap();
+        let length_field = u32::from_ne_bytes(length_field) as usize;
+        4 * length_field
+    } else {
+        // Fixed size packet: error or event that is not GE_GENERIC_EVENT
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Parser;
+    use crate::protocol::xproto::GE_GENERIC_EVENT;
+
+    #[test]
+    fn packet_lengths() {
+        let mut header = [0; 32];
+        header[4..8].copy_from_slice(&3u32.to_ne_bytes());
+        // Errors and events ignore the length field
+        for &(response_type, length) in &[
+            (0, 32),
+            (1, 44),
+            (12, 32),
+            (GE_GENERIC_EVENT, 44),
+            (GE_GENERIC_EVENT | 0x80, 44),
+        ] {
+            header[0] = response_type;
+            assert_eq!(Parser::packet_length(&header), length);
+        }
+
+        let mut parser = Parser::new();
+        let mut packets = Vec::new();
+        parser.feed(&header[..31], &mut packets);
+        assert!(parser.has_partial_packet());
+        parser.feed(&header[31..], &mut packets);
+        assert_eq!(parser.unfilled().len(), 12);
+        parser.feed(&[7; 13], &mut packets);
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].len(), 44);
+        assert!(parser.has_partial_packet());
+    }
+}
@@ -0,0 +1,237 @@
+//! Sending latency-critical requests ahead of other requests.
+
+use std::io::IoSlice;
+use std::sync::{Condvar, Mutex};
+
+use super::{BufWithFds, Buffer, ReplyFDKind, RustConnection, Stream};
+use crate::connection::{
+    DiscardMode, ReplyOrError, RequestConnection, RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError};
+use crate::utils::OwnedFd;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+/// Keeps track of latency-critical requests that are currently being sent.
+///
+/// While such a request is being sent, other requests wait before they are written. This way,
+/// threads that send many requests, e.g. for uploading a large image in pieces, cannot delay the
+/// latency-critical request for long.
+#[derive(Debug, Default)]
+pub(crate) struct PriorityLane {
+    pending: Mutex<usize>,
+    done: Condvar,
+}
+
+impl PriorityLane {
+    /// Mark a latency-critical request as pending until the returned guard is dropped.
+    pub(crate) fn enter(&self) -> PriorityGuard<'_> {
+        *self.pending.lock().unwrap() += 1;
+        PriorityGuard(self)
+    }
+
+    /// Wait until no latency-critical request is pending.
+    pub(crate) fn wait_until_empty(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        while *pending > 0 {
+            pending = self.done.wait(pending).unwrap();
+        }
+    }
+}
+
+/// A guard that keeps a latency-critical request pending.
+#[derive(Debug)]
+pub(crate) struct PriorityGuard<'a>(&'a PriorityLane);
+
+impl Drop for PriorityGuard<'_> {
+    fn drop(&mut self) {
+        let mut pending = self.0.pending.lock().unwrap();
+        *pending -= 1;
+        if *pending == 0 {
+            self.0.done.notify_all();
+        }
+    }
+}
+
+/// A view of a [`RustConnection`] that sends requests as latency-critical.
+///
+/// Instances of this type are created via [`RustConnection::priority`]. Requests sent through
+/// this type are written before the requests that other threads are about to send and are
+/// flushed immediately, independent of the [`FlushMode`](crate::connection::FlushMode). This
+/// keeps interactive round trips fast while another thread uploads lots of data.
+///
+/// The X11 server handles all requests in the order they were sent. Thus, requests that were
+/// already written or buffered by other threads are still sent before the latency-critical
+/// request.
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::ConnectionExt;
+/// use x11rb::rust_connection::RustConnection;
+///
+/// let (conn, screen_num) = RustConnection::connect(None)?;
+/// // ...other threads upload large images via `conn`...
+/// let pointer = conn.priority().query_pointer(conn.setup().roots[screen_num].root)?.reply()?;
+/// # let _ = pointer;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Priority<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+}
+
+impl<'a, S: Stream> Priority<'a, S> {
+    pub(crate) fn new(conn: &'a RustConnection<S>) -> Self {
+        Self { conn }
+    }
+
+    /// Get the underlying connection.
+    pub fn connection(&self) -> &'a RustConnection<S> {
+        self.conn
+    }
+}
+
+impl<S: Stream> RequestConnection for Priority<'_, S> {
+    type Buf = Buffer;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        Ok(Cookie::new(
+            self,
+            self.conn
+                .send_priority_request(bufs, fds, ReplyFDKind::ReplyWithoutFDs)?,
+        ))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        Ok(CookieWithFds::new(
+            self,
+            self.conn
+                .send_priority_request(bufs, fds, ReplyFDKind::ReplyWithFDs)?,
+        ))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<OwnedFd>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(
+            self,
+            self.conn
+                .send_priority_request(bufs, fds, ReplyFDKind::NoReply)?,
+        ))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.extension_information(extension_name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Buffer>, ConnectionError> {
+        self.conn.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Buffer>>, ConnectionError> {
+        self.conn.poll_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Buffer>, ConnectionError> {
+        self.conn.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        self.conn.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        self.conn.check_for_raw_error(sequence)
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<crate::protocol::Event, ParseError> {
+        self.conn.parse_event(event)
+    }
+
+    fn release_buffer(&self, buffer: Buffer) {
+        self.conn.release_buffer(buffer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::PriorityLane;
+
+    #[test]
+    fn wait_until_empty_without_pending() {
+        PriorityLane::default().wait_until_empty();
+    }
+
+    #[test]
+    fn wait_until_guard_dropped() {
+        let lane = Arc::new(PriorityLane::default());
+        let guard = lane.enter();
+        let waiter = {
+            let lane = Arc::clone(&lane);
+            thread::spawn(move || lane.wait_until_empty())
+        };
+        assert_eq!(*lane.pending.lock().unwrap(), 1);
+        drop(guard);
+        waiter.join().unwrap();
+        assert_eq!(*lane.pending.lock().unwrap(), 0);
+    }
+}
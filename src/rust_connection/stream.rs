@@ -1,16 +1,18 @@
+use std::convert::TryFrom;
 use std::io::{IoSlice, Result};
 use std::net::{Ipv4Addr, SocketAddr, TcpStream};
 #[cfg(unix)]
-use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, RawFd};
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
 #[cfg(windows)]
 use std::os::windows::io::{AsRawSocket, IntoRawSocket, RawSocket};
+use std::time::Duration;
 
 use super::xauth::Family;
+use crate::utils::OwnedFd;
 #[cfg(unix)]
-use crate::utils::nix_error_to_io;
-use crate::utils::RawFdContainer;
+use crate::utils::{nix_error_to_io, owned_fd_from_raw};
 
 /// The kind of operation that one want to poll for.
 #[derive(Debug, Clone, Copy)]
@@ -58,12 +60,16 @@ pub trait Stream {
     /// is neither readable nor writable. However, it shall not do it
     /// continuously, which would cause a 100% CPU usage.
     ///
+    /// If `timeout` is `Some`, this function shall return after at most roughly the given
+    /// duration, even if the stream is neither readable nor writable. `None` means to wait
+    /// without a time limit.
+    ///
     /// # Multithreading
     ///
     /// If `Self` is `Send + Sync` and `poll` is used concurrently from more than
     /// one thread, all threads should wake when the stream becomes readable (when
     /// `read` is `true`) or writable (when `write` is `true`).
-    fn poll(&self, mode: PollMode) -> Result<()>;
+    fn poll(&self, mode: PollMode, timeout: Option<Duration>) -> Result<()>;
 
     /// Read some bytes and FDs from this reader without blocking, returning how many bytes
     /// were read.
@@ -86,7 +92,7 @@ pub trait Stream {
     /// interleaved across threads.
     /// * Neither the data nor the file descriptors shall be duplicated.
     /// * The returned value shall always be the actual number of bytes read into `buf`.
-    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize>;
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<OwnedFd>) -> Result<usize>;
 
     /// Read the exact number of bytes required to fill `buf` and also some amount of FDs.
     ///
@@ -105,9 +111,9 @@ pub trait Stream {
     ///
     /// Same as `read`. In any case, if this function returns without error, `buf.len()` bytes
     /// should have been read into `buf`.
-    fn read_exact(&self, mut buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<()> {
+    fn read_exact(&self, mut buf: &mut [u8], fd_storage: &mut Vec<OwnedFd>) -> Result<()> {
         while !buf.is_empty() {
-            self.poll(PollMode::Readable)?;
+            self.poll(PollMode::Readable, None)?;
             match self.read(buf, fd_storage) {
                 Ok(0) => {
                     return Err(std::io::Error::new(
@@ -149,7 +155,7 @@ pub trait Stream {
     /// interleaved across threads.
     /// * Neither the data nor the file descriptors shall be duplicated.
     /// * The returned value shall always be the actual number of bytes written from `buf`.
-    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize>;
+    fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize>;
 
     /// Like `write`, except that it writes from a slice of buffers. Like `write`, this
     /// method must never block.
@@ -161,7 +167,7 @@ pub trait Stream {
     /// # Multithreading
     ///
     /// Same as `write`.
-    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<OwnedFd>) -> Result<usize> {
         for buf in bufs {
             if !buf.is_empty() {
                 return self.write(&**buf, fds);
@@ -213,12 +219,36 @@ impl DefaultStream {
                         }
                         Err(err) => error = Some(err),
                     }
+
+                    #[cfg(target_os = "linux")]
+                    {
+                        if super::wsl::is_wsl() {
+                            if let Ok(stream) =
+                                UnixStream::connect(super::wsl::wslg_socket(display))
+                            {
+                                return Self::from_unix_stream(stream);
+                            }
+                        }
+                    }
                 }
             }
 
-            if protocol.is_none() && host.is_empty() {
-                let stream = TcpStream::connect(("localhost", TCP_PORT_BASE + display))?;
-                Self::from_tcp_stream(stream)
+            if matches!(protocol, None | Some("tcp")) && host.is_empty() {
+                let port = TCP_PORT_BASE + display;
+                let result = TcpStream::connect(("localhost", port));
+                #[cfg(target_os = "linux")]
+                let result = result.or_else(|err| {
+                    // X11 servers on Windows are not reachable via localhost from WSL 2
+                    match super::wsl::windows_host() {
+                        Some(host) if super::wsl::is_wsl() => {
+                            let addr = SocketAddr::new(host, port);
+                            TcpStream::connect_timeout(&addr, Duration::from_secs(2))
+                                .map_err(|_| err)
+                        }
+                        _ => Err(err),
+                    }
+                });
+                Self::from_tcp_stream(result?)
             } else {
                 use crate::errors::ConnectError;
                 use std::io::{Error, ErrorKind};
@@ -296,6 +326,17 @@ impl DefaultStream {
             .unwrap_or_else(Vec::new);
         Ok((Family::LOCAL, hostname))
     }
+
+    /// Get the local address of a TCP connection.
+    ///
+    /// Returns `None` for unix sockets.
+    pub(crate) fn local_addr(&self) -> Result<Option<SocketAddr>> {
+        match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => stream.local_addr().map(Some),
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(_) => Ok(None),
+        }
+    }
 }
 
 #[cfg(unix)]
@@ -308,6 +349,16 @@ impl AsRawFd for DefaultStream {
     }
 }
 
+#[cfg(unix)]
+impl AsFd for DefaultStream {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => stream.as_fd(),
+            DefaultStreamInner::UnixStream(ref stream) => stream.as_fd(),
+        }
+    }
+}
+
 #[cfg(unix)]
 impl IntoRawFd for DefaultStream {
     fn into_raw_fd(self) -> RawFd {
@@ -340,7 +391,7 @@ impl IntoRawSocket for DefaultStream {
 fn do_write(
     stream: &DefaultStream,
     bufs: &[nix::sys::uio::IoVec<&[u8]>],
-    fds: &mut Vec<RawFdContainer>,
+    fds: &mut Vec<OwnedFd>,
 ) -> Result<usize> {
     use nix::sys::socket::{sendmsg, ControlMessage, MsgFlags};
 
@@ -377,8 +428,24 @@ fn do_write(
     Ok(res)
 }
 
+/// Convert a timeout to milliseconds for `poll()`, where -1 means to wait forever.
+///
+/// The value is rounded up, so that polling does not return early over and over again.
+fn timeout_to_millis(timeout: Option<Duration>) -> i32 {
+    match timeout {
+        None => -1,
+        Some(timeout) => {
+            let mut millis = timeout.as_millis();
+            if timeout.subsec_nanos() % 1_000_000 != 0 {
+                millis += 1;
+            }
+            i32::try_from(millis).unwrap_or(i32::MAX)
+        }
+    }
+}
+
 impl Stream for DefaultStream {
-    fn poll(&self, mode: PollMode) -> Result<()> {
+    fn poll(&self, mode: PollMode, timeout: Option<Duration>) -> Result<()> {
         #[cfg(unix)]
         {
             use nix::errno::Errno;
@@ -394,7 +461,7 @@ impl Stream for DefaultStream {
             let fd = self.as_raw_fd();
             let mut poll_fds = [PollFd::new(fd, poll_flags)];
             loop {
-                match poll(&mut poll_fds, -1) {
+                match poll(&mut poll_fds, timeout_to_millis(timeout)) {
                     Ok(_) => break,
                     Err(nix::Error::Sys(Errno::EINTR)) => {}
                     Err(e) => return Err(nix_error_to_io(e)),
@@ -421,13 +488,13 @@ impl Stream for DefaultStream {
                 events,
                 revents: 0,
             }];
-            let _ = wsa_poll(&mut poll_fds, -1)?;
+            let _ = wsa_poll(&mut poll_fds, timeout_to_millis(timeout))?;
             // Let the errors (POLLERR) be handled when trying to read or write.
             Ok(())
         }
     }
 
-    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
         #[cfg(unix)]
         {
             use nix::sys::{
@@ -450,14 +517,15 @@ impl Stream for DefaultStream {
                 }
             };
 
-            let fds_received = msg
-                .cmsgs()
-                .flat_map(|cmsg| match cmsg {
-                    ControlMessageOwned::ScmRights(r) => r,
-                    _ => Vec::new(),
-                })
-                .map(RawFdContainer::new);
-            fd_storage.extend(fds_received);
+            let fds_received = msg.cmsgs().flat_map(|cmsg| match cmsg {
+                ControlMessageOwned::ScmRights(r) => r,
+                _ => Vec::new(),
+            });
+            // Take ownership of all FDs before reporting an error, so that none of them leaks
+            let fds_received = fds_received.map(owned_fd_from_raw).collect::<Vec<_>>();
+            for fd in fds_received {
+                fd_storage.push(fd?);
+            }
 
             Ok(msg.bytes)
         }
@@ -483,7 +551,7 @@ impl Stream for DefaultStream {
         }
     }
 
-    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+    fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> Result<usize> {
         #[cfg(unix)]
         {
             do_write(self, &[nix::sys::uio::IoVec::from_slice(buf)], fds)
@@ -511,7 +579,7 @@ impl Stream for DefaultStream {
         }
     }
 
-    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<OwnedFd>) -> Result<usize> {
         #[cfg(unix)]
         {
             let bufs = bufs
@@ -543,3 +611,37 @@ impl Stream for DefaultStream {
         }
     }
 }
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::fs::File;
+    use std::os::unix::fs::MetadataExt;
+    use std::os::unix::net::UnixStream;
+
+    use super::{DefaultStream, PollMode, Stream};
+    use crate::utils::OwnedFd;
+
+    #[test]
+    fn pass_fds() {
+        let (a, b) = UnixStream::pair().unwrap();
+        let (a, b) = (
+            DefaultStream::from_unix_stream(a).unwrap(),
+            DefaultStream::from_unix_stream(b).unwrap(),
+        );
+
+        let file = File::open("/dev/null").unwrap();
+        let inode = file.metadata().unwrap().ino();
+        let mut fds = vec![OwnedFd::from(file)];
+        assert_eq!(a.write(b"fd", &mut fds).unwrap(), 2);
+        assert!(fds.is_empty());
+
+        b.poll(PollMode::Readable, None).unwrap();
+        let mut buf = [0; 2];
+        let mut received = Vec::new();
+        assert_eq!(b.read(&mut buf, &mut received).unwrap(), 2);
+        assert_eq!(&buf, b"fd");
+        assert_eq!(received.len(), 1);
+        let file = File::from(received.pop().unwrap());
+        assert_eq!(file.metadata().unwrap().ino(), inode);
+    }
+}
@@ -0,0 +1,224 @@
+//! Bookkeeping for threads that wait for another thread to read from the connection.
+
+use std::sync::{Arc, Condvar};
+use std::task::Waker;
+
+use crate::connection::SequenceNumber;
+
+/// What a thread waiting for packets is interested in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Interest {
+    /// The thread waits for the answer to the request with the given sequence number.
+    ///
+    /// Since the X11 server answers requests in order, the thread is done waiting once a reply
+    /// to this request is pending or a packet with a later sequence number was received. Some
+    /// requests cause more than one reply, so a packet with the same sequence number is not
+    /// enough.
+    Reply(SequenceNumber),
+    /// The thread waits for an event.
+    Event,
+}
+
+impl Interest {
+    /// Check if a thread with this interest can make progress.
+    ///
+    /// `has_reply` checks if a reply or an error to the request with the given sequence number is
+    /// pending.
+    pub(crate) fn is_satisfied(
+        self,
+        last_sequence_read: SequenceNumber,
+        has_events: bool,
+        has_reply: impl Fn(SequenceNumber) -> bool,
+    ) -> bool {
+        match self {
+            Interest::Reply(sequence) => last_sequence_read > sequence || has_reply(sequence),
+            Interest::Event => has_events,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Waiter {
+    interest: Interest,
+    condvar: Arc<Condvar>,
+}
+
+/// The threads that wait for the current reader.
+///
+/// Every waiting thread sleeps on its own condition variable. This allows the reader to only
+/// wake up the threads that received something instead of waking up all threads.
+///
+/// All condition variables must only be used together with the mutex that protects this struct.
+///
+/// Callers that cannot block register a [`Waker`] instead. Wakers are woken like threads, but
+/// they never become the next reader.
+#[derive(Debug, Default)]
+pub(crate) struct Waiters {
+    waiting: Vec<Waiter>,
+    // Condition variables that can be reused, so that waiting does not need to allocate
+    unused: Vec<Arc<Condvar>>,
+    wakers: Vec<(Interest, Waker)>,
+}
+
+impl Waiters {
+    /// Register a new waiting thread and get the condition variable it should wait on.
+    pub(crate) fn register(&mut self, interest: Interest) -> Arc<Condvar> {
+        let condvar = self.unused.pop().unwrap_or_default();
+        self.waiting.push(Waiter {
+            interest,
+            condvar: Arc::clone(&condvar),
+        });
+        condvar
+    }
+
+    /// Unregister a thread after its wait finished.
+    ///
+    /// This must be called even when the thread was woken up via one of the `notify_*` functions.
+    pub(crate) fn unregister(&mut self, condvar: Arc<Condvar>) {
+        self.waiting
+            .retain(|waiter| !Arc::ptr_eq(&waiter.condvar, &condvar));
+        self.unused.push(condvar);
+    }
+
+    /// Register a waker that is woken once something with the given interest was read.
+    ///
+    /// The waker is forgotten after it was woken. Registering a waker that would wake the same
+    /// task for the same interest again does nothing.
+    pub(crate) fn register_waker(&mut self, interest: Interest, waker: &Waker) {
+        let registered = self
+            .wakers
+            .iter()
+            .any(|(other, other_waker)| *other == interest && other_waker.will_wake(waker));
+        if !registered {
+            self.wakers.push((interest, waker.clone()));
+        }
+    }
+
+    /// Wake up all threads and wakers whose interest is satisfied.
+    pub(crate) fn notify_satisfied(
+        &mut self,
+        last_sequence_read: SequenceNumber,
+        has_events: bool,
+        has_reply: impl Fn(SequenceNumber) -> bool,
+    ) {
+        self.waiting.retain(|waiter| {
+            let satisfied =
+                waiter
+                    .interest
+                    .is_satisfied(last_sequence_read, has_events, &has_reply);
+            if satisfied {
+                waiter.condvar.notify_one();
+            }
+            !satisfied
+        });
+        let mut index = 0;
+        while index < self.wakers.len() {
+            if self.wakers[index]
+                .0
+                .is_satisfied(last_sequence_read, has_events, &has_reply)
+            {
+                self.wakers.swap_remove(index).1.wake();
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    /// Wake up a single thread so that it can take over reading from the connection.
+    pub(crate) fn notify_next_reader(&mut self) {
+        if let Some(waiter) = self.waiting.pop() {
+            waiter.condvar.notify_one();
+        }
+    }
+
+    /// Wake up all threads and wakers.
+    pub(crate) fn notify_all(&mut self) {
+        self.waiting
+            .drain(..)
+            .for_each(|waiter| waiter.condvar.notify_one());
+        self.wakers.drain(..).for_each(|(_, waker)| waker.wake());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    use super::{Interest, Waiters};
+
+    #[test]
+    fn interest_satisfied() {
+        let no_reply = |_| false;
+        assert!(!Interest::Reply(5).is_satisfied(4, true, no_reply));
+        assert!(Interest::Reply(5).is_satisfied(5, false, |seqno| seqno == 5));
+        assert!(Interest::Reply(5).is_satisfied(6, false, no_reply));
+        assert!(!Interest::Event.is_satisfied(10, false, no_reply));
+        assert!(Interest::Event.is_satisfied(0, true, no_reply));
+    }
+
+    #[test]
+    fn multiple_replies() {
+        // After the first of several replies was taken, more replies with the same sequence
+        // number might follow
+        assert!(!Interest::Reply(5).is_satisfied(5, false, |_| false));
+        assert!(Interest::Reply(5).is_satisfied(5, false, |seqno| seqno == 5));
+        assert!(!Interest::Reply(5).is_satisfied(5, false, |seqno| seqno == 4));
+    }
+
+    #[test]
+    fn notify_only_satisfied() {
+        let mut waiters = Waiters::default();
+        let _reply = waiters.register(Interest::Reply(3));
+        let event = waiters.register(Interest::Event);
+        waiters.notify_satisfied(3, false, |seqno| seqno == 3);
+        assert_eq!(waiters.waiting.len(), 1);
+        assert_eq!(waiters.waiting[0].interest, Interest::Event);
+
+        waiters.unregister(event);
+        assert!(waiters.waiting.is_empty());
+        assert_eq!(waiters.unused.len(), 1);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            let _ = self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn wake_satisfied_wakers() {
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut waiters = Waiters::default();
+        waiters.register_waker(Interest::Reply(3), &waker);
+        // Registering the same waker twice only wakes it once
+        waiters.register_waker(Interest::Reply(3), &waker);
+        waiters.register_waker(Interest::Event, &waker);
+
+        waiters.notify_satisfied(2, false, |_| false);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+        waiters.notify_satisfied(3, false, |seqno| seqno == 3);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        // Threads looking for the next reader are not interested in wakers
+        waiters.notify_next_reader();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        waiters.notify_all();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+        assert!(waiters.wakers.is_empty());
+    }
+
+    #[test]
+    fn condvars_are_reused() {
+        let mut waiters = Waiters::default();
+        let first = waiters.register(Interest::Event);
+        let first_ptr = Arc::as_ptr(&first);
+        waiters.unregister(first);
+        let second = waiters.register(Interest::Reply(1));
+        assert_eq!(Arc::as_ptr(&second), first_ptr);
+    }
+}
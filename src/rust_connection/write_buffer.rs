@@ -2,12 +2,12 @@ use std::collections::VecDeque;
 use std::io::IoSlice;
 
 use super::Stream;
-use crate::utils::RawFdContainer;
+use crate::utils::OwnedFd;
 
 #[derive(Debug)]
 pub(super) struct WriteBuffer {
     data_buf: VecDeque<u8>,
-    fd_buf: Vec<RawFdContainer>,
+    fd_buf: Vec<OwnedFd>,
 }
 
 impl WriteBuffer {
@@ -54,7 +54,7 @@ impl WriteBuffer {
     fn write_helper<W: Stream, F, G>(
         &mut self,
         stream: &W,
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
         write_buffer: F,
         write_inner: G,
         first_buffer: &[u8],
@@ -62,7 +62,7 @@ impl WriteBuffer {
     ) -> std::io::Result<usize>
     where
         F: FnOnce(&mut VecDeque<u8>),
-        G: FnOnce(&W, &mut Vec<RawFdContainer>) -> std::io::Result<usize>,
+        G: FnOnce(&W, &mut Vec<OwnedFd>) -> std::io::Result<usize>,
     {
         self.fd_buf.append(fds);
 
@@ -110,7 +110,7 @@ impl WriteBuffer {
         &mut self,
         stream: &impl Stream,
         buf: &[u8],
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
     ) -> std::io::Result<usize> {
         self.write_helper(
             stream,
@@ -126,7 +126,7 @@ impl WriteBuffer {
         &mut self,
         stream: &impl Stream,
         bufs: &[IoSlice<'_>],
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
     ) -> std::io::Result<usize> {
         let first_nonempty = bufs
             .iter()
@@ -147,6 +147,17 @@ impl WriteBuffer {
         )
     }
 
+    /// Append data and FDs to the buffer without writing anything.
+    ///
+    /// The buffer grows as needed. The data is written by the next flush.
+    #[cfg(all(feature = "tokio", unix))]
+    pub(super) fn buffer_vectored(&mut self, bufs: &[IoSlice<'_>], fds: &mut Vec<OwnedFd>) {
+        for buf in bufs.iter() {
+            self.data_buf.extend(&**buf);
+        }
+        self.fd_buf.append(fds);
+    }
+
     /// Returns `true` if there is buffered data or FDs.
     pub(super) fn needs_flush(&self) -> bool {
         !self.data_buf.is_empty() || !self.fd_buf.is_empty()
@@ -159,24 +170,26 @@ impl WriteBuffer {
 
 #[cfg(test)]
 mod test {
+    use std::cell::RefCell;
     use std::io::{Error, ErrorKind, IoSlice, Result};
+    use std::time::Duration;
 
     use super::super::{PollMode, Stream};
     use super::WriteBuffer;
-    use crate::utils::RawFdContainer;
+    use crate::utils::OwnedFd;
 
     struct WouldBlockWriter;
 
     impl Stream for WouldBlockWriter {
-        fn poll(&self, _mode: PollMode) -> Result<()> {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
             unimplemented!();
         }
 
-        fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
             unimplemented!();
         }
 
-        fn write(&self, _buf: &[u8], _fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        fn write(&self, _buf: &[u8], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
             Err(Error::new(ErrorKind::WouldBlock, "would block"))
         }
     }
@@ -206,4 +219,108 @@ mod test {
             Err(e) => panic!("Unexpected error: {:?}", e),
         }
     }
+
+    /// Remembers where the data passed to `write_vectored` lives.
+    #[derive(Default)]
+    struct RecordingWriter(RefCell<Vec<(*const u8, usize)>>);
+
+    impl Stream for RecordingWriter {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            unimplemented!();
+        }
+
+        fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            unimplemented!();
+        }
+
+        fn write(&self, _buf: &[u8], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            unimplemented!();
+        }
+
+        fn write_vectored(&self, bufs: &[IoSlice<'_>], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            let mut written = self.0.borrow_mut();
+            written.extend(bufs.iter().map(|buf| (buf.as_ptr(), buf.len())));
+            Ok(bufs.iter().map(|buf| buf.len()).sum())
+        }
+    }
+
+    // Large requests, e.g. PutImage, are written straight from the caller's buffers
+    #[test]
+    fn large_write_is_not_copied() {
+        let stream = RecordingWriter::default();
+        let mut write_buffer = WriteBuffer::with_capacity(16);
+        let (header, data) = ([1; 4], vec![2; 1000]);
+        let bufs = &[IoSlice::new(&header), IoSlice::new(&data)];
+        let written = write_buffer
+            .write_vectored(&stream, bufs, &mut Vec::new())
+            .unwrap();
+        assert_eq!(written, 1004);
+        assert_eq!(
+            *stream.0.borrow(),
+            [(header.as_ptr(), 4), (data.as_ptr(), 1000)]
+        );
+        assert!(!write_buffer.needs_flush());
+    }
+
+    /// Records everything that is written.
+    #[cfg(feature = "testing")]
+    #[derive(Default)]
+    struct VecWriter(RefCell<Vec<u8>>);
+
+    #[cfg(feature = "testing")]
+    impl Stream for VecWriter {
+        fn poll(&self, _mode: PollMode, _timeout: Option<Duration>) -> Result<()> {
+            Ok(())
+        }
+
+        fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> Result<usize> {
+            unimplemented!();
+        }
+
+        fn write(&self, buf: &[u8], _fds: &mut Vec<OwnedFd>) -> Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    // Partial writes and spurious WouldBlocks must neither lose nor duplicate data
+    #[cfg(feature = "testing")]
+    #[test]
+    fn fragmented_writes() {
+        use super::super::ChaosStream;
+
+        let requests = [4, 100, 8, 3000, 12, 64]
+            .iter()
+            .enumerate()
+            .map(|(i, &len)| vec![i as u8; len])
+            .collect::<Vec<_>>();
+        for seed in 0..50 {
+            let stream = ChaosStream::new(VecWriter::default(), seed);
+            let mut write_buffer = WriteBuffer::with_capacity(256);
+            for request in &requests {
+                let mut written = 0;
+                while written < request.len() {
+                    let bufs = &[IoSlice::new(&request[written..])];
+                    match write_buffer.write_vectored(&stream, bufs, &mut Vec::new()) {
+                        Ok(n) => written += n,
+                        Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                        Err(e) => panic!("Unexpected error: {:?}", e),
+                    }
+                }
+            }
+            loop {
+                match write_buffer.flush(&stream) {
+                    Ok(()) => break,
+                    Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                    Err(e) => panic!("Unexpected error: {:?}", e),
+                }
+            }
+            assert_eq!(
+                *stream.into_inner().0.borrow(),
+                requests.concat(),
+                "seed {}",
+                seed
+            );
+        }
+    }
 }
@@ -0,0 +1,82 @@
+//! Finding the X11 server when running inside the Windows Subsystem for Linux.
+//!
+//! WSLg provides an X11 server whose socket is in `/mnt/wslg/.X11-unix`. `/tmp/.X11-unix` is
+//! usually a link to this directory, but the link is lost if something else is mounted on `/tmp`.
+//!
+//! X11 servers that run on Windows, e.g. VcXsrv, listen on TCP on the Windows host. With WSL 2,
+//! this host is not reachable via `localhost`, but it is the name server in `/etc/resolv.conf`
+//! that WSL generates.
+
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+/// The directory with the socket of WSLg's X11 server.
+const WSLG_SOCKET_DIR: &str = "/mnt/wslg/.X11-unix";
+
+/// Check whether we are running inside WSL.
+pub(crate) fn is_wsl() -> bool {
+    std::env::var_os("WSL_DISTRO_NAME").is_some()
+        || std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .map(|release| is_wsl_kernel(&release))
+            .unwrap_or(false)
+}
+
+/// Check whether a kernel release, e.g. `5.15.90.1-microsoft-standard-WSL2`, belongs to WSL.
+fn is_wsl_kernel(release: &str) -> bool {
+    release.to_ascii_lowercase().contains("microsoft")
+}
+
+/// Get the path of the socket of WSLg's X11 server for the given display.
+pub(crate) fn wslg_socket(display: u16) -> PathBuf {
+    PathBuf::from(format!("{}/X{}", WSLG_SOCKET_DIR, display))
+}
+
+/// Get the address of the Windows host.
+pub(crate) fn windows_host() -> Option<IpAddr> {
+    let resolv_conf = std::fs::read_to_string("/etc/resolv.conf").ok()?;
+    first_nameserver(&resolv_conf)
+}
+
+/// Get the first name server from the contents of `/etc/resolv.conf`.
+fn first_nameserver(resolv_conf: &str) -> Option<IpAddr> {
+    resolv_conf.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        if words.next() == Some("nameserver") {
+            words.next()?.parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{first_nameserver, is_wsl_kernel, wslg_socket};
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::path::Path;
+
+    #[test]
+    fn kernel_release() {
+        assert!(is_wsl_kernel("5.15.90.1-microsoft-standard-WSL2\n"));
+        assert!(is_wsl_kernel("4.4.0-19041-Microsoft"));
+        assert!(!is_wsl_kernel("6.1.0-13-amd64"));
+    }
+
+    #[test]
+    fn nameserver() {
+        let resolv_conf = "# This file was automatically generated by WSL.\n\
+                           nameserver\n\
+                           nameserver 172.20.64.1\n\
+                           nameserver 8.8.8.8\n";
+        assert_eq!(
+            first_nameserver(resolv_conf),
+            Some(IpAddr::V4(Ipv4Addr::new(172, 20, 64, 1)))
+        );
+        assert_eq!(first_nameserver("search example.com\n"), None);
+    }
+
+    #[test]
+    fn socket_path() {
+        assert_eq!(wslg_socket(1), Path::new("/mnt/wslg/.X11-unix/X1"));
+    }
+}
@@ -1,29 +1,75 @@
 //! Helpers for working with `~/.Xauthority`.
-
+//!
+//! [`RustConnection`](super::RustConnection) reads this file automatically. The functions in this
+//! module are for programs that start their own X11 server, e.g. Xvfb or Xephyr for tests, and
+//! have to provide the authorization for it:
+//!
+//! ```no_run
+//! use x11rb::rust_connection::xauth::{self, AuthEntry, Family};
+//!
+//! # fn main() -> Result<(), std::io::Error> {
+//! let cookie = xauth::generate_cookie()?;
+//! let path = xauth::xauthority_file_name().expect("Neither XAUTHORITY nor HOME is set");
+//! // Allow connections to display :99 from any host
+//! xauth::add_entry(&path, AuthEntry::mit_magic_cookie(Family::WILD, Vec::new(), 99, cookie))?;
+//! // Now start e.g. `Xvfb :99 -auth <path>`
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Changes to the file are done under the same lock that libXau and `xauth` use.
+
+use std::convert::TryFrom;
 use std::io::Error;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
 
+use super::xdm_auth::XDM_AUTHORIZATION_1;
+use crate::errors::AuthorizationSource;
 use crate::protocol::xproto::Family as X11Family;
 
-const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+/// The name of the `MIT-MAGIC-COOKIE-1` authorization protocol.
+pub const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+
+/// The length of the cookies that [`generate_cookie`] creates, in bytes.
+pub const COOKIE_LENGTH: usize = 16;
+
+/// The supported authorization protocols, most preferred first.
+const AUTH_NAMES: [&[u8]; 2] = [MIT_MAGIC_COOKIE_1, XDM_AUTHORIZATION_1];
 
 /// A family describes how to interpret some bytes as an address in an `AuthEntry`.
 ///
-/// Compared to [`x11rb::protocol::xproto::Family`], this is a `u16` and not an `u8`.
+/// Compared to [`crate::protocol::xproto::Family`], this is a `u16` and not an `u8`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct Family(u16);
+pub struct Family(u16);
 
-#[allow(dead_code)] // Some of these constants are unused, but still serve as documentation
 impl Family {
-    pub(crate) const INTERNET: Self = Self(0);
-    pub(crate) const DEC_NET: Self = Self(1);
-    pub(crate) const CHAOS: Self = Self(2);
-    pub(crate) const SERVER_INTERPRETED: Self = Self(5);
-    pub(crate) const INTERNET6: Self = Self(6);
-    pub(crate) const WILD: Self = Self(65535);
-    pub(crate) const LOCAL: Self = Self(256);
-    pub(crate) const NETNAME: Self = Self(254);
-    pub(crate) const KRB5_PRINCIPAL: Self = Self(253);
-    pub(crate) const LOCAL_HOST: Self = Self(252);
+    /// The address is an IPv4 address.
+    pub const INTERNET: Self = Self(0);
+    /// The address is a DECnet address.
+    pub const DEC_NET: Self = Self(1);
+    /// The address is a Chaosnet address.
+    pub const CHAOS: Self = Self(2);
+    /// The address is interpreted by the server.
+    pub const SERVER_INTERPRETED: Self = Self(5);
+    /// The address is an IPv6 address.
+    pub const INTERNET6: Self = Self(6);
+    /// The entry matches any address.
+    pub const WILD: Self = Self(65535);
+    /// The address is the host name of a local connection.
+    pub const LOCAL: Self = Self(256);
+    /// The address is a Secure RPC netname.
+    pub const NETNAME: Self = Self(254);
+    /// The address is a Kerberos V5 principal.
+    pub const KRB5_PRINCIPAL: Self = Self(253);
+    /// The address is a local host name.
+    pub const LOCAL_HOST: Self = Self(252);
+}
+
+impl From<Family> for u16 {
+    fn from(value: Family) -> Self {
+        value.0
+    }
 }
 
 impl From<X11Family> for Family {
@@ -40,24 +86,93 @@ impl From<u16> for Family {
 
 /// A single entry of an `.Xauthority` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct AuthEntry {
-    family: Family,
-    address: Vec<u8>,
-    number: Vec<u8>,
-    name: Vec<u8>,
-    data: Vec<u8>,
+pub struct AuthEntry {
+    /// How to interpret `address`.
+    pub family: Family,
+    /// The address of the X11 server that this entry applies to.
+    pub address: Vec<u8>,
+    /// The display number as a decimal string. An empty number matches all displays.
+    pub number: Vec<u8>,
+    /// The name of the authorization protocol, e.g. [`MIT_MAGIC_COOKIE_1`].
+    pub name: Vec<u8>,
+    /// The secret data of the authorization protocol.
+    pub data: Vec<u8>,
+}
+
+impl AuthEntry {
+    /// Create an entry with a `MIT-MAGIC-COOKIE-1` cookie for the given display.
+    pub fn mit_magic_cookie(
+        family: Family,
+        address: Vec<u8>,
+        display: u16,
+        cookie: Vec<u8>,
+    ) -> Self {
+        Self {
+            family,
+            address,
+            number: display.to_string().into_bytes(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: cookie,
+        }
+    }
+
+    /// Check whether both entries are for the same server, display and protocol.
+    ///
+    /// An `.Xauthority` file should contain at most one of such entries.
+    fn replaces(&self, other: &AuthEntry) -> bool {
+        self.family == other.family
+            && self.address == other.address
+            && self.number == other.number
+            && self.name == other.name
+    }
+
+    /// Describe the entry like `xauth list`, but without the secret data.
+    fn describe(&self) -> String {
+        let address = match self.family {
+            Family::LOCAL => format!("{}/unix", String::from_utf8_lossy(&self.address)),
+            Family::INTERNET if self.address.len() == 4 => {
+                let octets = <[u8; 4]>::try_from(&self.address[..]).unwrap();
+                Ipv4Addr::from(octets).to_string()
+            }
+            Family::INTERNET6 if self.address.len() == 16 => {
+                let octets = <[u8; 16]>::try_from(&self.address[..]).unwrap();
+                Ipv6Addr::from(octets).to_string()
+            }
+            Family(family) => {
+                let hex = self.address.iter().map(|byte| format!("{:02x}", byte));
+                format!("#{:04x}#{}#", family, hex.collect::<String>())
+            }
+        };
+        format!(
+            "{}:{}  {}",
+            address,
+            String::from_utf8_lossy(&self.number),
+            String::from_utf8_lossy(&self.name)
+        )
+    }
 }
 
 mod file {
     //! Code for actually reading `~/.Xauthority`.
 
+    use std::convert::TryFrom;
     use std::env::var_os;
-    use std::fs::File;
-    use std::io::{BufReader, Error, ErrorKind, Read};
-    use std::path::PathBuf;
+    use std::ffi::OsString;
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::thread::sleep;
+    use std::time::Duration;
 
     use super::AuthEntry;
 
+    /// How often to try to get the lock on an `.Xauthority` file
+    const LOCK_RETRIES: u32 = 10;
+    /// How long to wait between two attempts to get the lock
+    const LOCK_TIMEOUT: Duration = Duration::from_secs(1);
+    /// Lock files older than this were left behind by a crashed process and are removed
+    const STALE_LOCK: Duration = Duration::from_secs(600);
+
     /// Read a single `u16` from an `~/.Xauthority` file.
     ///
     /// The file stores these entries in big endian.
@@ -103,15 +218,153 @@ mod file {
         }))
     }
 
+    /// Write a single `u16` to an `~/.Xauthority` file.
+    fn write_u16<W: Write>(write: &mut W, value: u16) -> Result<(), Error> {
+        write.write_all(&value.to_be_bytes())
+    }
+
+    /// Write a single "byte array" to an `~/.Xauthority` file.
+    fn write_string<W: Write>(write: &mut W, value: &[u8]) -> Result<(), Error> {
+        let length = u16::try_from(value.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "Xauthority field is too long"))?;
+        write_u16(write, length)?;
+        write.write_all(value)
+    }
+
+    /// Write a single entry to an `~/.Xauthority` file.
+    fn write_entry<W: Write>(write: &mut W, entry: &AuthEntry) -> Result<(), Error> {
+        write_u16(write, entry.family.into())?;
+        write_string(write, &entry.address)?;
+        write_string(write, &entry.number)?;
+        write_string(write, &entry.name)?;
+        write_string(write, &entry.data)
+    }
+
+    /// Append a suffix to a file name, e.g. `.Xauthority` becomes `.Xauthority-c`.
+    fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut name = OsString::from(path);
+        name.push(suffix);
+        name.into()
+    }
+
+    /// Write all entries to the given `.Xauthority` file, replacing its previous content.
+    ///
+    /// The entries are first written to a temporary file that then replaces the original file,
+    /// so that readers never see a partially written file. The file is only readable by its owner.
+    /// The caller should hold the [`XAuthorityLock`] for the file.
+    pub(crate) fn write_entries(path: &Path, entries: &[AuthEntry]) -> Result<(), Error> {
+        let temp_path = with_suffix(path, "-n");
+        let mut options = OpenOptions::new();
+        let _ = options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            let _ = options.mode(0o600);
+        }
+        let mut file = BufWriter::new(options.open(&temp_path)?);
+        let result = entries
+            .iter()
+            .try_for_each(|entry| write_entry(&mut file, entry))
+            .and_then(|()| file.flush());
+        drop(file);
+        match result.and_then(|()| fs::rename(&temp_path, path)) {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                let _ = fs::remove_file(&temp_path);
+                Err(error)
+            }
+        }
+    }
+
+    /// A lock on an `.Xauthority` file.
+    ///
+    /// This uses the same lock files as libXau's `XauLockAuth()`, so that `xauth` and other
+    /// programs do not change the file at the same time. The lock is released when this is
+    /// dropped.
+    #[derive(Debug)]
+    pub struct XAuthorityLock {
+        creat_name: PathBuf,
+        link_name: PathBuf,
+    }
+
+    impl XAuthorityLock {
+        /// Lock the given `.Xauthority` file.
+        ///
+        /// If some other process holds the lock, this waits for up to ten seconds and then fails
+        /// with [`ErrorKind::TimedOut`]. Lock files that are older than ten minutes are assumed
+        /// to be left over from a crashed process and are removed.
+        pub fn lock(path: &Path) -> Result<Self, Error> {
+            Self::lock_impl(path, LOCK_RETRIES, LOCK_TIMEOUT)
+        }
+
+        fn lock_impl(path: &Path, retries: u32, timeout: Duration) -> Result<Self, Error> {
+            let lock = Self {
+                creat_name: with_suffix(path, "-c"),
+                link_name: with_suffix(path, "-l"),
+            };
+            let stale = fs::metadata(&lock.creat_name)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .filter(|age| *age > STALE_LOCK)
+                .is_some();
+            if stale {
+                let _ = fs::remove_file(&lock.creat_name);
+                let _ = fs::remove_file(&lock.link_name);
+            }
+
+            let mut created = false;
+            for attempt in 0..retries {
+                if attempt > 0 {
+                    sleep(timeout);
+                }
+                if !created {
+                    match OpenOptions::new()
+                        .write(true)
+                        .create_new(true)
+                        .open(&lock.creat_name)
+                    {
+                        Ok(_) => created = true,
+                        Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                // Creating a hard link is atomic, even on network file systems
+                match fs::hard_link(&lock.creat_name, &lock.link_name) {
+                    Ok(()) => return Ok(lock),
+                    Err(e) if e.kind() == ErrorKind::NotFound => created = false,
+                    Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            // Do not remove the lock files in drop(), they belong to someone else
+            std::mem::forget(lock);
+            Err(Error::new(
+                ErrorKind::TimedOut,
+                "timeout while locking the Xauthority file",
+            ))
+        }
+    }
+
+    impl Drop for XAuthorityLock {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.creat_name);
+            let _ = fs::remove_file(&self.link_name);
+        }
+    }
+
     /// Get the file name for `~/.Xauthority` based on environment variables.
     ///
-    /// The code in libXau contains a special case for Windows (looks like cygwin) that is not
-    /// handled here (yet?).
-    fn get_xauthority_file_name() -> Option<PathBuf> {
+    /// The code in libXau contains a special case for Windows (looks like cygwin) that falls back
+    /// to the user's home directory when `HOME` is not set. Here, `USERPROFILE` is used for that.
+    pub(crate) fn get_xauthority_file_name() -> Option<PathBuf> {
         if let Some(name) = var_os("XAUTHORITY") {
             return Some(name.into());
         }
-        var_os("HOME").map(|prefix| {
+        let home = var_os("HOME");
+        #[cfg(windows)]
+        let home = home.or_else(|| var_os("USERPROFILE"));
+        home.map(|prefix| {
             let mut result = PathBuf::new();
             result.push(prefix);
             result.push(".Xauthority");
@@ -124,19 +377,9 @@ mod file {
     pub(crate) struct XAuthorityEntries(BufReader<File>);
 
     impl XAuthorityEntries {
-        /// Open `~/.Xauthority` for reading.
-        ///
-        /// This function returns `Ok(None)` when the location of the `.Xauthority` file could not
-        /// be determined. If opening the file failed (for example, because it does not exist),
-        /// that error is returned.
-        pub(crate) fn new() -> Result<Option<XAuthorityEntries>, Error> {
-            get_xauthority_file_name()
-                .map(File::open)
-                .transpose()?
-                // At this point we have Option<File> and errors while opening the file were
-                // returned to the caller.
-                .map(|file| Ok(XAuthorityEntries(BufReader::new(file))))
-                .transpose()
+        /// Open the given `.Xauthority` file for reading.
+        pub(crate) fn open(path: &Path) -> Result<XAuthorityEntries, Error> {
+            Ok(XAuthorityEntries(BufReader::new(File::open(path)?)))
         }
     }
 
@@ -150,9 +393,10 @@ mod file {
 
     #[cfg(test)]
     mod test {
-        use super::super::{AuthEntry, Family};
-        use super::read_entry;
-        use std::io::Cursor;
+        use super::super::{add_entry, AuthEntry, Family, MIT_MAGIC_COOKIE_1};
+        use super::{read_entry, write_entry, XAuthorityEntries, XAuthorityLock};
+        use std::io::{Cursor, ErrorKind};
+        use std::time::Duration;
 
         #[test]
         fn test_read() {
@@ -209,9 +453,141 @@ mod file {
             let entry = read_entry(&mut cursor).unwrap();
             assert_eq!(entry, None);
         }
+
+        #[test]
+        fn test_write() {
+            let entry = AuthEntry {
+                family: Family::LOCAL,
+                address: b"ZweiLED".to_vec(),
+                number: b"1".to_vec(),
+                name: b"bar".to_vec(),
+                data: u32::to_be_bytes(0xdead_beef).to_vec(),
+            };
+            let mut data = Vec::new();
+            write_entry(&mut data, &entry).unwrap();
+            assert_eq!(
+                data,
+                [
+                    0x01, 0x00, 0x00, 0x07, 0x5a, 0x77, 0x65, 0x69, 0x4c, 0x45, 0x44, 0x00, 0x01,
+                    0x31, 0x00, 0x03, 0x62, 0x61, 0x72, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef,
+                ]
+            );
+            let too_long = AuthEntry {
+                data: vec![0; 0x10000],
+                ..entry
+            };
+            let error = write_entry(&mut Vec::new(), &too_long).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        }
+
+        #[test]
+        fn test_add_entry() {
+            let dir = std::env::temp_dir().join(format!("x11rb-xauth-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(".Xauthority");
+
+            let entry = |display, cookie: &[u8]| {
+                AuthEntry::mit_magic_cookie(Family::WILD, Vec::new(), display, cookie.to_vec())
+            };
+            add_entry(&path, entry(1, b"first")).unwrap();
+            add_entry(&path, entry(2, b"other")).unwrap();
+            // The entry for the same display is replaced
+            add_entry(&path, entry(1, b"second")).unwrap();
+            let entries = XAuthorityEntries::open(&path)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(entries, [entry(2, b"other"), entry(1, b"second")]);
+            assert_eq!(entries[1].name, MIT_MAGIC_COOKIE_1);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+                assert_eq!(mode & 0o777, 0o600);
+            }
+
+            // Only one lock at a time
+            let lock = XAuthorityLock::lock(&path).unwrap();
+            let error = XAuthorityLock::lock_impl(&path, 2, Duration::from_millis(1)).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::TimedOut);
+            drop(lock);
+            drop(XAuthorityLock::lock(&path).unwrap());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
     }
 }
 
+pub use file::XAuthorityLock;
+
+/// Get the name of the `.Xauthority` file.
+///
+/// This is `$XAUTHORITY` if set and `$HOME/.Xauthority` otherwise. `None` is returned when
+/// neither is set.
+pub fn xauthority_file_name() -> Option<PathBuf> {
+    file::get_xauthority_file_name()
+}
+
+/// Read all entries of the given `.Xauthority` file.
+///
+/// A file that does not exist contains no entries.
+pub fn read_entries(path: &Path) -> Result<Vec<AuthEntry>, Error> {
+    match file::XAuthorityEntries::open(path) {
+        Ok(entries) => entries.collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Replace the content of the given `.Xauthority` file with the given entries.
+///
+/// The file is locked while it is written. The new file is only readable by its owner.
+pub fn write_entries(path: &Path, entries: &[AuthEntry]) -> Result<(), Error> {
+    let _lock = XAuthorityLock::lock(path)?;
+    file::write_entries(path, entries)
+}
+
+/// Add an entry to the given `.Xauthority` file, creating the file if necessary.
+///
+/// Like `xauth add`, this replaces an existing entry for the same address, display number and
+/// authorization protocol.
+pub fn add_entry(path: &Path, entry: AuthEntry) -> Result<(), Error> {
+    let _lock = XAuthorityLock::lock(path)?;
+    let mut entries = read_entries(path)?;
+    entries.retain(|old| !old.replaces(&entry));
+    entries.push(entry);
+    file::write_entries(path, &entries)
+}
+
+/// Generate a random cookie for the `MIT-MAGIC-COOKIE-1` protocol.
+///
+/// The cookie is [`COOKIE_LENGTH`] bytes long. On Unix, the random bytes are read from
+/// `/dev/urandom`.
+pub fn generate_cookie() -> Result<Vec<u8>, Error> {
+    let mut cookie = vec![0; COOKIE_LENGTH];
+    fill_random(&mut cookie)?;
+    Ok(cookie)
+}
+
+#[cfg(unix)]
+fn fill_random(buffer: &mut [u8]) -> Result<(), Error> {
+    use std::io::Read;
+    std::fs::File::open("/dev/urandom")?.read_exact(buffer)
+}
+
+#[cfg(not(unix))]
+fn fill_random(buffer: &mut [u8]) -> Result<(), Error> {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // The keys of RandomState come from the random number generator of the operating system
+    for chunk in buffer.chunks_mut(8) {
+        let random = RandomState::new().build_hasher().finish().to_ne_bytes();
+        chunk.copy_from_slice(&random[..chunk.len()]);
+    }
+    Ok(())
+}
+
 pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
 
 /// Get the authentication information necessary for connecting to the given display.
@@ -222,15 +598,33 @@ pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
 /// - `display` is the display number.
 ///
 /// If successful, this function returns that can be written to the X11 server as authorization
-/// protocol name and data, respectively.
+/// protocol name and data, respectively. Additionally, where this information was looked up is
+/// returned for error messages.
 pub(crate) fn get_auth(
     family: Family,
     address: &[u8],
     display: u16,
-) -> Result<Option<AuthInfo>, Error> {
-    match file::XAuthorityEntries::new()? {
-        None => Ok(None),
-        Some(entries) => get_auth_impl(entries, family, address, display),
+) -> (Option<AuthInfo>, AuthorizationSource) {
+    let path = match file::get_xauthority_file_name() {
+        Some(path) => path,
+        None => return (None, AuthorizationSource::NoFile),
+    };
+    let entry = file::XAuthorityEntries::open(&path)
+        .and_then(|entries| get_auth_impl(entries, family, address, display));
+    match entry {
+        Ok(entry) => {
+            let description = entry.as_ref().map(AuthEntry::describe);
+            let info = entry.map(|entry| (entry.name, entry.data));
+            let source = AuthorizationSource::File {
+                path,
+                entry: description,
+            };
+            (info, source)
+        }
+        Err(error) => {
+            let error = error.to_string();
+            (None, AuthorizationSource::UnreadableFile { path, error })
+        }
     }
 }
 
@@ -239,7 +633,7 @@ pub(crate) fn get_auth_impl(
     family: Family,
     address: &[u8],
     display: u16,
-) -> Result<Option<AuthInfo>, Error> {
+) -> Result<Option<AuthEntry>, Error> {
     fn address_matches(
         (family1, address1): (Family, &[u8]),
         (family2, address2): (Family, &[u8]),
@@ -261,22 +655,32 @@ pub(crate) fn get_auth_impl(
     let display = display.to_string();
     let display = display.as_bytes();
 
+    // Like XauGetBestAuthByAddr(), pick the first entry with the most preferred protocol
+    let mut best: Option<(usize, AuthEntry)> = None;
     for entry in entries {
         let entry = entry?;
 
         if address_matches((family, address), (entry.family, &entry.address))
             && display_number_matches(&entry.number, display)
-            && entry.name == MIT_MAGIC_COOKIE_1
         {
-            return Ok(Some((entry.name, entry.data)));
+            let preference = match AUTH_NAMES.iter().position(|name| *name == entry.name) {
+                Some(preference) => preference,
+                None => continue,
+            };
+            if preference == 0 {
+                return Ok(Some(entry));
+            }
+            if !matches!(best, Some((best, _)) if best <= preference) {
+                best = Some((preference, entry));
+            }
         }
     }
-    Ok(None)
+    Ok(best.map(|(_, entry)| entry))
 }
 
 #[cfg(test)]
 mod test {
-    use super::{get_auth_impl, AuthEntry, Family, MIT_MAGIC_COOKIE_1};
+    use super::{get_auth_impl, AuthEntry, Family, MIT_MAGIC_COOKIE_1, XDM_AUTHORIZATION_1};
 
     // Call the given function on a matching auth entry. The function can change the entry.
     // Afterwards, it should still be a match.
@@ -296,8 +700,8 @@ mod test {
         assert_eq!(
             get_auth_impl(entries.into_iter(), Family::LOCAL, b"whatever", 42)
                 .unwrap()
-                .unwrap(),
-            (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
+                .map(|entry| (entry.name, entry.data)),
+            Some((MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec()))
         );
     }
 
@@ -351,8 +755,8 @@ mod test {
         assert_eq!(
             get_auth_impl(entries.into_iter(), Family::WILD, &[], 42)
                 .unwrap()
-                .unwrap(),
-            (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
+                .map(|entry| (entry.name, entry.data)),
+            Some((MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec()))
         );
     }
 
@@ -373,6 +777,62 @@ mod test {
 
     #[test]
     fn protocol_mismatch() {
-        expect_mismatch(|entry| entry.name = b"XDM-AUTHORIZATION-1".to_vec());
+        expect_mismatch(|entry| entry.name = b"SUN-DES-1".to_vec());
+    }
+
+    #[test]
+    fn protocol_preference() {
+        let entry = |name: &[u8], data: &[u8]| AuthEntry {
+            family: Family::LOCAL,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: name.to_vec(),
+            data: data.to_vec(),
+        };
+        let entries = vec![
+            entry(b"SUN-DES-1", b"1"),
+            entry(XDM_AUTHORIZATION_1, b"2"),
+            entry(XDM_AUTHORIZATION_1, b"3"),
+            entry(MIT_MAGIC_COOKIE_1, b"4"),
+        ];
+        assert_eq!(
+            get_auth_impl(
+                entries.clone().into_iter().map(Ok),
+                Family::LOCAL,
+                b"whatever",
+                42
+            )
+            .unwrap(),
+            Some(entry(MIT_MAGIC_COOKIE_1, b"4"))
+        );
+        assert_eq!(
+            get_auth_impl(
+                entries.into_iter().take(3).map(Ok),
+                Family::LOCAL,
+                b"whatever",
+                42
+            )
+            .unwrap(),
+            Some(entry(XDM_AUTHORIZATION_1, b"2"))
+        );
+    }
+
+    #[test]
+    fn describe_entries() {
+        let mut entry = AuthEntry {
+            family: Family::LOCAL,
+            address: b"myhost".to_vec(),
+            number: b"0".to_vec(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: b"secret".to_vec(),
+        };
+        assert_eq!(entry.describe(), "myhost/unix:0  MIT-MAGIC-COOKIE-1");
+        entry.family = Family::INTERNET;
+        entry.address = vec![127, 0, 0, 1];
+        assert_eq!(entry.describe(), "127.0.0.1:0  MIT-MAGIC-COOKIE-1");
+        entry.family = Family::WILD;
+        entry.address = vec![0xab];
+        entry.number = Vec::new();
+        assert_eq!(entry.describe(), "#ffff#ab#:  MIT-MAGIC-COOKIE-1");
     }
 }
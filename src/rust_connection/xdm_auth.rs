@@ -0,0 +1,241 @@
+//! Support for the `XDM-AUTHORIZATION-1` authorization protocol.
+//!
+//! The `.Xauthority` entry for this protocol contains 16 bytes. The first half is sent back to the
+//! X11 server together with the client's address and the current time. The second half is a DES
+//! key that is used to encrypt all of this. See `Xsecurity(7)` for details.
+
+use std::convert::TryFrom;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(crate) const XDM_AUTHORIZATION_1: &[u8] = b"XDM-AUTHORIZATION-1";
+
+/// The initial permutation.
+const IP: [u8; 64] = [
+    58, 50, 42, 34, 26, 18, 10, 2, 60, 52, 44, 36, 28, 20, 12, 4, 62, 54, 46, 38, 30, 22, 14, 6,
+    64, 56, 48, 40, 32, 24, 16, 8, 57, 49, 41, 33, 25, 17, 9, 1, 59, 51, 43, 35, 27, 19, 11, 3, 61,
+    53, 45, 37, 29, 21, 13, 5, 63, 55, 47, 39, 31, 23, 15, 7,
+];
+
+/// The final permutation, the inverse of `IP`.
+const FP: [u8; 64] = [
+    40, 8, 48, 16, 56, 24, 64, 32, 39, 7, 47, 15, 55, 23, 63, 31, 38, 6, 46, 14, 54, 22, 62, 30,
+    37, 5, 45, 13, 53, 21, 61, 29, 36, 4, 44, 12, 52, 20, 60, 28, 35, 3, 43, 11, 51, 19, 59, 27,
+    34, 2, 42, 10, 50, 18, 58, 26, 33, 1, 41, 9, 49, 17, 57, 25,
+];
+
+/// The expansion of a half block to 48 bits.
+const E: [u8; 48] = [
+    32, 1, 2, 3, 4, 5, 4, 5, 6, 7, 8, 9, 8, 9, 10, 11, 12, 13, 12, 13, 14, 15, 16, 17, 16, 17, 18,
+    19, 20, 21, 20, 21, 22, 23, 24, 25, 24, 25, 26, 27, 28, 29, 28, 29, 30, 31, 32, 1,
+];
+
+/// The permutation of the S-box outputs.
+const P: [u8; 32] = [
+    16, 7, 20, 21, 29, 12, 28, 17, 1, 15, 23, 26, 5, 18, 31, 10, 2, 8, 24, 14, 32, 27, 3, 9, 19,
+    13, 30, 6, 22, 11, 4, 25,
+];
+
+/// The selection of 56 key bits, dropping the parity bits.
+const PC1: [u8; 56] = [
+    57, 49, 41, 33, 25, 17, 9, 1, 58, 50, 42, 34, 26, 18, 10, 2, 59, 51, 43, 35, 27, 19, 11, 3, 60,
+    52, 44, 36, 63, 55, 47, 39, 31, 23, 15, 7, 62, 54, 46, 38, 30, 22, 14, 6, 61, 53, 45, 37, 29,
+    21, 13, 5, 28, 20, 12, 4,
+];
+
+/// The selection of the 48 bits of a round key.
+const PC2: [u8; 48] = [
+    14, 17, 11, 24, 1, 5, 3, 28, 15, 6, 21, 10, 23, 19, 12, 4, 26, 8, 16, 7, 27, 20, 13, 2, 41, 52,
+    31, 37, 47, 55, 30, 40, 51, 45, 33, 48, 44, 49, 39, 56, 34, 53, 46, 42, 50, 36, 29, 32,
+];
+
+/// The number of bits that the key halves are rotated by before each round.
+const SHIFTS: [u32; 16] = [1, 1, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1];
+
+const S_BOXES: [[u8; 64]; 8] = [
+    [
+        14, 4, 13, 1, 2, 15, 11, 8, 3, 10, 6, 12, 5, 9, 0, 7, 0, 15, 7, 4, 14, 2, 13, 1, 10, 6, 12,
+        11, 9, 5, 3, 8, 4, 1, 14, 8, 13, 6, 2, 11, 15, 12, 9, 7, 3, 10, 5, 0, 15, 12, 8, 2, 4, 9,
+        1, 7, 5, 11, 3, 14, 10, 0, 6, 13,
+    ],
+    [
+        15, 1, 8, 14, 6, 11, 3, 4, 9, 7, 2, 13, 12, 0, 5, 10, 3, 13, 4, 7, 15, 2, 8, 14, 12, 0, 1,
+        10, 6, 9, 11, 5, 0, 14, 7, 11, 10, 4, 13, 1, 5, 8, 12, 6, 9, 3, 2, 15, 13, 8, 10, 1, 3, 15,
+        4, 2, 11, 6, 7, 12, 0, 5, 14, 9,
+    ],
+    [
+        10, 0, 9, 14, 6, 3, 15, 5, 1, 13, 12, 7, 11, 4, 2, 8, 13, 7, 0, 9, 3, 4, 6, 10, 2, 8, 5,
+        14, 12, 11, 15, 1, 13, 6, 4, 9, 8, 15, 3, 0, 11, 1, 2, 12, 5, 10, 14, 7, 1, 10, 13, 0, 6,
+        9, 8, 7, 4, 15, 14, 3, 11, 5, 2, 12,
+    ],
+    [
+        7, 13, 14, 3, 0, 6, 9, 10, 1, 2, 8, 5, 11, 12, 4, 15, 13, 8, 11, 5, 6, 15, 0, 3, 4, 7, 2,
+        12, 1, 10, 14, 9, 10, 6, 9, 0, 12, 11, 7, 13, 15, 1, 3, 14, 5, 2, 8, 4, 3, 15, 0, 6, 10, 1,
+        13, 8, 9, 4, 5, 11, 12, 7, 2, 14,
+    ],
+    [
+        2, 12, 4, 1, 7, 10, 11, 6, 8, 5, 3, 15, 13, 0, 14, 9, 14, 11, 2, 12, 4, 7, 13, 1, 5, 0, 15,
+        10, 3, 9, 8, 6, 4, 2, 1, 11, 10, 13, 7, 8, 15, 9, 12, 5, 6, 3, 0, 14, 11, 8, 12, 7, 1, 14,
+        2, 13, 6, 15, 0, 9, 10, 4, 5, 3,
+    ],
+    [
+        12, 1, 10, 15, 9, 2, 6, 8, 0, 13, 3, 4, 14, 7, 5, 11, 10, 15, 4, 2, 7, 12, 9, 5, 6, 1, 13,
+        14, 0, 11, 3, 8, 9, 14, 15, 5, 2, 8, 12, 3, 7, 0, 4, 10, 1, 13, 11, 6, 4, 3, 2, 12, 9, 5,
+        15, 10, 11, 14, 1, 7, 6, 0, 8, 13,
+    ],
+    [
+        4, 11, 2, 14, 15, 0, 8, 13, 3, 12, 9, 7, 5, 10, 6, 1, 13, 0, 11, 7, 4, 9, 1, 10, 14, 3, 5,
+        12, 2, 15, 8, 6, 1, 4, 11, 13, 12, 3, 7, 14, 10, 15, 6, 8, 0, 5, 9, 2, 6, 11, 13, 8, 1, 4,
+        10, 7, 9, 5, 0, 15, 14, 2, 3, 12,
+    ],
+    [
+        13, 2, 8, 4, 6, 15, 11, 1, 10, 9, 3, 14, 5, 0, 12, 7, 1, 15, 13, 8, 10, 3, 7, 4, 12, 5, 6,
+        11, 0, 14, 9, 2, 7, 11, 4, 1, 9, 12, 14, 2, 0, 6, 10, 13, 15, 3, 5, 8, 2, 1, 14, 7, 4, 10,
+        8, 13, 15, 12, 9, 0, 3, 5, 6, 11,
+    ],
+];
+
+/// Apply a DES permutation table.
+///
+/// The table lists the (one-based, most significant first) input bit for each output bit.
+fn permute(input: u64, input_bits: u32, table: &[u8]) -> u64 {
+    table.iter().fold(0, |result, &position| {
+        (result << 1) | ((input >> (input_bits - u32::from(position))) & 1)
+    })
+}
+
+/// Compute the 16 round keys for a DES key.
+fn key_schedule(key: u64) -> [u64; 16] {
+    const MASK: u64 = (1 << 28) - 1;
+    let key = permute(key, 64, &PC1);
+    let (mut c, mut d) = (key >> 28, key & MASK);
+    let mut result = [0; 16];
+    for (round_key, &shift) in result.iter_mut().zip(SHIFTS.iter()) {
+        c = ((c << shift) | (c >> (28 - shift))) & MASK;
+        d = ((d << shift) | (d >> (28 - shift))) & MASK;
+        *round_key = permute((c << 28) | d, 56, &PC2);
+    }
+    result
+}
+
+/// The DES round function.
+fn feistel(half: u64, round_key: u64) -> u64 {
+    let expanded = permute(half, 32, &E) ^ round_key;
+    let substituted = S_BOXES.iter().enumerate().fold(0, |result, (i, s_box)| {
+        let six = (expanded >> (42 - 6 * i)) & 0x3f;
+        let row = ((six & 0x20) >> 4) | (six & 1);
+        let column = (six >> 1) & 0xf;
+        (result << 4) | u64::from(s_box[usize::try_from(row * 16 + column).unwrap()])
+    });
+    permute(substituted, 32, &P)
+}
+
+/// Encrypt a single block with DES.
+fn des_encrypt(block: u64, round_keys: &[u64; 16]) -> u64 {
+    let block = permute(block, 64, &IP);
+    let (mut left, mut right) = (block >> 32, block & 0xffff_ffff);
+    for &round_key in round_keys.iter() {
+        let next = left ^ feistel(right, round_key);
+        left = right;
+        right = next;
+    }
+    permute((right << 32) | left, 64, &FP)
+}
+
+/// Turn the 56 bit key of an `.Xauthority` entry into a DES key.
+///
+/// Like `_XdmcpWrapperToOddParity()` in libXdmcp, this uses the last seven bytes of `key` and
+/// spreads them over eight bytes. The parity bits that DES ignores are left as zero.
+fn wrapper_to_des_key(key: [u8; 8]) -> u64 {
+    let bits = u64::from_be_bytes(key) & ((1 << 56) - 1);
+    (0..8).fold(0, |result, i| {
+        (result << 8) | (((bits >> (49 - 7 * i)) & 0x7f) << 1)
+    })
+}
+
+/// Encrypt data like `XdmcpWrap()` in libXdmcp.
+///
+/// This is DES in CBC mode with an all-zero initialization vector. The length of `data` must be
+/// a multiple of eight.
+fn wrap(data: &[u8], key: [u8; 8]) -> Vec<u8> {
+    debug_assert_eq!(data.len() % 8, 0);
+    let round_keys = key_schedule(wrapper_to_des_key(key));
+    let mut previous = 0;
+    data.chunks(8)
+        .flat_map(|chunk| {
+            let block = u64::from_be_bytes(<[u8; 8]>::try_from(chunk).unwrap());
+            previous = des_encrypt(block ^ previous, &round_keys);
+            previous.to_be_bytes()
+        })
+        .collect()
+}
+
+/// Get a fake address for connections that do not have an IPv4 address, e.g. unix sockets.
+///
+/// This follows what libxcb does: The address is counted down from `0xffffffff` and the port is
+/// the process ID.
+fn fake_address() -> (u32, u16) {
+    static NONCE: AtomicU32 = AtomicU32::new(0);
+    let address = u32::MAX - NONCE.fetch_add(1, Ordering::Relaxed);
+    // Truncating the process ID is fine; the server only uses this to tell clients apart.
+    (address, std::process::id() as u16)
+}
+
+/// Compute the authorization data that is sent to the X11 server.
+///
+/// `data` is the data from the `.Xauthority` entry. `local_address` is the client's end of the
+/// connection, or `None` for unix sockets. Returns `None` if the data has the wrong length or the
+/// connection uses IPv6, which this protocol does not support.
+pub(crate) fn authorization_data(
+    data: &[u8],
+    local_address: Option<SocketAddr>,
+) -> Option<Vec<u8>> {
+    if data.len() != 16 {
+        return None;
+    }
+    let (address, port) = match local_address {
+        None => fake_address(),
+        Some(SocketAddr::V4(addr)) => (u32::from(*addr.ip()), addr.port()),
+        Some(SocketAddr::V6(addr)) => (u32::from(addr.ip().to_ipv4_mapped()?), addr.port()),
+    };
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs() as u32)
+        .unwrap_or(0);
+
+    let mut plain = Vec::with_capacity(24);
+    plain.extend_from_slice(&data[..8]);
+    plain.extend_from_slice(&address.to_be_bytes());
+    plain.extend_from_slice(&port.to_be_bytes());
+    plain.extend_from_slice(&time.to_be_bytes());
+    plain.resize(24, 0);
+    Some(wrap(&plain, <[u8; 8]>::try_from(&data[8..]).unwrap()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{des_encrypt, key_schedule, wrap};
+
+    #[test]
+    fn des() {
+        let keys = key_schedule(0x1334_5779_9bbc_dff1);
+        assert_eq!(
+            des_encrypt(0x0123_4567_89ab_cdef, &keys),
+            0x85e8_1354_0f0a_b405
+        );
+        assert_eq!(des_encrypt(0, &key_schedule(0)), 0x8ca6_4de9_c1b1_23a7);
+    }
+
+    #[test]
+    fn wrap_like_libxdmcp() {
+        // The expected output was computed with XdmcpWrap() from libXdmcp
+        let data = (0..24).collect::<Vec<u8>>();
+        let key = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+        let expected = [
+            0x7b, 0x51, 0xf9, 0xfb, 0xf7, 0xd7, 0xa0, 0x54, 0xcf, 0x51, 0x94, 0xbe, 0x72, 0x76,
+            0x63, 0x6d, 0xa8, 0x94, 0x79, 0xfb, 0xeb, 0x5e, 0x7f, 0x70,
+        ];
+        assert_eq!(wrap(&data, key), expected);
+    }
+}
@@ -0,0 +1,203 @@
+//! Find out how a window's content can reach the screen.
+//!
+//! A media player or game can present its frames in different ways. With the Present extension,
+//! the X11 server can flip to a new buffer instead of copying it, and can do so without waiting
+//! for the vertical blank (an "async flip", which may tear). With DRI3 1.2, the X11 server
+//! additionally tells which buffer formats (DRM format modifiers) it can scan out directly for a
+//! window, e.g. because the window is fullscreen and not composited. Buffers with one of these
+//! modifiers can be flipped onto the screen without any copy.
+//!
+//! [`probe`] asks the X11 server for all of this and [`ScanoutCapabilities::strategy`] summarizes
+//! the result:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::scanout::{probe, PresentStrategy};
+//!
+//! fn choose(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+//!     let caps = probe(conn, window, 24, 32)?;
+//!     match caps.strategy() {
+//!         PresentStrategy::DirectScanout(modifiers) => {
+//!             println!("Allocate buffers with one of {:x?}", modifiers)
+//!         }
+//!         PresentStrategy::Present => println!("Use PresentPixmap"),
+//!         PresentStrategy::Copy => println!("Use CopyArea"),
+//!     }
+//!     if caps.async_flips {
+//!         println!("Tearing flips are possible");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The answer can change at any time, e.g. when the window becomes fullscreen or a compositor
+//! starts, so it is worth probing again after such changes. Without the `present` or `dri3`
+//! feature of this library, the corresponding extension is treated as unsupported.
+
+use crate::connection::Connection;
+use crate::errors::ReplyError;
+#[cfg(feature = "dri3")]
+use crate::protocol::dri3;
+#[cfg(feature = "present")]
+use crate::protocol::present;
+use crate::protocol::xproto::Window;
+
+/// What the X11 server supports for presenting the content of a window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanoutCapabilities {
+    /// The version of the Present extension, if it is supported.
+    pub present_version: Option<(u32, u32)>,
+    /// Whether `PresentPixmap` can flip without waiting for the vertical blank.
+    pub async_flips: bool,
+    /// Whether `PresentPixmap` can wait for a fence before presenting.
+    pub fences: bool,
+    /// The version of the DRI3 extension, if it is supported.
+    pub dri3_version: Option<(u32, u32)>,
+    /// The modifiers of buffers that can be scanned out directly for the window.
+    ///
+    /// This is empty if the window cannot be scanned out, e.g. because it is covered by other
+    /// windows, or if DRI3 1.2 is not supported.
+    pub window_modifiers: Vec<u64>,
+    /// The modifiers of buffers that can be used on the window's screen.
+    ///
+    /// Buffers with these modifiers can be presented, but might be copied.
+    pub screen_modifiers: Vec<u64>,
+}
+
+/// How a client should present its frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresentStrategy<'a> {
+    /// Present buffers with one of the given modifiers, so that the X11 server can flip them onto
+    /// the screen directly.
+    DirectScanout(&'a [u64]),
+    /// Present buffers with `PresentPixmap`. The X11 server flips them if it can and copies them
+    /// otherwise.
+    Present,
+    /// The Present extension is not available. Copy the frames into the window, e.g. with
+    /// `CopyArea`.
+    Copy,
+}
+
+impl ScanoutCapabilities {
+    /// Check whether buffers can be scanned out directly for the window.
+    pub fn direct_scanout(&self) -> bool {
+        self.present_version.is_some() && !self.window_modifiers.is_empty()
+    }
+
+    /// Get the best way to present frames with these capabilities.
+    pub fn strategy(&self) -> PresentStrategy<'_> {
+        if self.direct_scanout() {
+            PresentStrategy::DirectScanout(&self.window_modifiers)
+        } else if self.present_version.is_some() {
+            PresentStrategy::Present
+        } else {
+            PresentStrategy::Copy
+        }
+    }
+}
+
+/// Ask the X11 server how the content of a window can be presented.
+///
+/// `depth` and `bpp` describe the buffers that the client wants to present, e.g. 24 and 32 for
+/// XRGB8888. Extensions that the X11 server does not support are reported as `None` without an
+/// error.
+pub fn probe<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    depth: u8,
+    bpp: u8,
+) -> Result<ScanoutCapabilities, ReplyError> {
+    let mut caps = ScanoutCapabilities::default();
+    probe_present(conn, window, &mut caps)?;
+    probe_dri3(conn, window, depth, bpp, &mut caps)?;
+    Ok(caps)
+}
+
+#[cfg(feature = "present")]
+fn probe_present<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    caps: &mut ScanoutCapabilities,
+) -> Result<(), ReplyError> {
+    if conn
+        .extension_information(present::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(());
+    }
+    let version = present::query_version(conn, 1, 2)?.reply()?;
+    let capabilities = present::query_capabilities(conn, window)?
+        .reply()?
+        .capabilities;
+    let has = |capability: present::Capability| capabilities & u32::from(u8::from(capability)) != 0;
+    caps.present_version = Some((version.major_version, version.minor_version));
+    caps.async_flips = has(present::Capability::ASYNC);
+    caps.fences = has(present::Capability::FENCE);
+    Ok(())
+}
+
+#[cfg(not(feature = "present"))]
+fn probe_present<C: Connection + ?Sized>(
+    _conn: &C,
+    _window: Window,
+    _caps: &mut ScanoutCapabilities,
+) -> Result<(), ReplyError> {
+    Ok(())
+}
+
+#[cfg(feature = "dri3")]
+fn probe_dri3<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    depth: u8,
+    bpp: u8,
+    caps: &mut ScanoutCapabilities,
+) -> Result<(), ReplyError> {
+    if conn
+        .extension_information(dri3::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(());
+    }
+    let version = dri3::query_version(conn, 1, 2)?.reply()?;
+    let version = (version.major_version, version.minor_version);
+    caps.dri3_version = Some(version);
+    // Modifiers were added in version 1.2
+    if version >= (1, 2) {
+        let modifiers = dri3::get_supported_modifiers(conn, window, depth, bpp)?.reply()?;
+        caps.window_modifiers = modifiers.window_modifiers;
+        caps.screen_modifiers = modifiers.screen_modifiers;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "dri3"))]
+fn probe_dri3<C: Connection + ?Sized>(
+    _conn: &C,
+    _window: Window,
+    _depth: u8,
+    _bpp: u8,
+    _caps: &mut ScanoutCapabilities,
+) -> Result<(), ReplyError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PresentStrategy, ScanoutCapabilities};
+
+    #[test]
+    fn strategy() {
+        let mut caps = ScanoutCapabilities::default();
+        assert_eq!(caps.strategy(), PresentStrategy::Copy);
+        // Modifiers are useless without Present
+        caps.window_modifiers = vec![0];
+        assert_eq!(caps.strategy(), PresentStrategy::Copy);
+        caps.present_version = Some((1, 2));
+        assert_eq!(caps.strategy(), PresentStrategy::DirectScanout(&[0]));
+        caps.window_modifiers.clear();
+        assert_eq!(caps.strategy(), PresentStrategy::Present);
+    }
+}
@@ -0,0 +1,160 @@
+//! Worker threads that share one connection.
+//!
+//! A connection that is `Sync`, e.g. [`RustConnection`](crate::rust_connection::RustConnection),
+//! can be used from several threads at once. Waiting for replies in parallel hides the round trip
+//! to the X11 server, e.g. when fetching the contents of all windows for thumbnails. [`scope`]
+//! spawns such workers with [`std::thread::scope`] and joins all of them before it returns:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, GetGeometryReply, Window};
+//! use x11rb::scoped;
+//!
+//! fn geometries(
+//!     conn: &(impl Connection + Sync),
+//!     windows: &[Window],
+//! ) -> Vec<Result<GetGeometryReply, ReplyError>> {
+//!     scoped::scope(conn, |scope| {
+//!         let workers: Vec<_> = windows
+//!             .chunks(16)
+//!             .map(|chunk| {
+//!                 scope.spawn(move |conn| {
+//!                     // Send all requests before waiting for the first reply
+//!                     let cookies: Vec<_> =
+//!                         chunk.iter().map(|&window| conn.get_geometry(window)).collect();
+//!                     cookies
+//!                         .into_iter()
+//!                         .map(|cookie| Ok(cookie?.reply()?))
+//!                         .collect::<Vec<_>>()
+//!                 })
+//!             })
+//!             .collect();
+//!         workers
+//!             .into_iter()
+//!             .flat_map(|worker| worker.join().unwrap())
+//!             .collect()
+//!     })
+//! }
+//! ```
+//!
+//! The workers only get a reference to the connection that lives as long as the scope. Cookies
+//! that are created with it cannot leave the scope, so each of them is either waited for or
+//! dropped, which discards its reply, before [`scope`] returns:
+//!
+//! ```compile_fail
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::ConnectionExt as _;
+//! use x11rb::scoped;
+//!
+//! fn leak(conn: &(impl Connection + Sync)) {
+//!     let cookie = scoped::scope(conn, |scope| scope.conn().get_input_focus());
+//! }
+//! ```
+
+use std::fmt;
+use std::thread::{self, ScopedJoinHandle};
+
+use crate::connection::RequestConnection;
+
+/// A scope for spawning worker threads that share a connection.
+///
+/// See [`scope`].
+pub struct Scope<'scope, 'env, C: ?Sized> {
+    scope: &'scope thread::Scope<'scope, 'env>,
+    conn: &'scope C,
+}
+
+impl<C: ?Sized> fmt::Debug for Scope<'_, '_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+impl<'scope, 'env, C> Scope<'scope, 'env, C>
+where
+    C: RequestConnection + Sync + ?Sized,
+{
+    /// Get the connection for use by the thread that created the scope.
+    pub fn conn(&self) -> &'scope C {
+        self.conn
+    }
+
+    /// Spawn a worker thread that gets the connection as its argument.
+    ///
+    /// The thread is joined at the end of the scope at the latest. Use the returned handle to
+    /// get the worker's result. If the worker panics and the handle is not joined, [`scope`]
+    /// panics once all workers are finished.
+    pub fn spawn<F, T>(&self, f: F) -> ScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce(&'scope C) -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let conn = self.conn;
+        self.scope.spawn(move || f(conn))
+    }
+}
+
+/// Run `f` with a [`Scope`] for spawning worker threads that share `conn`.
+///
+/// All workers that were spawned in the scope are joined before this function returns. Cookies
+/// of requests sent in the scope cannot be part of the result, see the [module
+/// documentation](self).
+pub fn scope<'env, C, F, T>(conn: &'env C, f: F) -> T
+where
+    C: RequestConnection + Sync + ?Sized,
+    F: for<'a, 'scope> FnOnce(&'a Scope<'scope, 'env, C>) -> T,
+{
+    thread::scope(|scope| f(&Scope { scope, conn }))
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::scope;
+    use crate::protocol::xproto::{self, ConnectionExt as _};
+    use crate::testing::{FakeConnection, Response};
+
+    #[test]
+    fn workers_share_connection() {
+        let conn = FakeConnection::default();
+        for focus in 0..40u32 {
+            let mut reply = vec![0; 32];
+            reply[8..12].copy_from_slice(&focus.to_ne_bytes());
+            conn.respond(
+                xproto::GET_INPUT_FOCUS_REQUEST,
+                None,
+                Response::Reply(reply),
+            );
+        }
+        let mut focus = scope(&conn, |scope| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|conn| {
+                        let cookies: Vec<_> =
+                            (0..10).map(|_| conn.get_input_focus().unwrap()).collect();
+                        cookies
+                            .into_iter()
+                            .map(|cookie| cookie.reply().unwrap().focus)
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        focus.sort_unstable();
+        assert_eq!(focus, (0..40).collect::<Vec<_>>());
+        assert_eq!(conn.sent_requests().len(), 40);
+    }
+
+    #[test]
+    #[should_panic]
+    fn worker_panics_propagate() {
+        let conn = FakeConnection::default();
+        scope(&conn, |scope| {
+            let _ = scope.spawn(|_| panic!("worker failed"));
+        });
+    }
+}
@@ -0,0 +1,308 @@
+//! Keeping track of screen sizes and monitors that change at runtime.
+//!
+//! The [`Setup`](crate::protocol::xproto::Setup) that the X11 server sends when connecting
+//! describes the screens as they were at that time. With the RandR extension, the size of a screen
+//! changes when e.g. a monitor is plugged in or the resolution is changed, and monitors appear
+//! and disappear. The X11 protocol offers no way to get a new `Setup`. Instead, [`ScreenTracker`]
+//! starts with the information from the setup and updates it from RandR events.
+//!
+//! Every change increments a generation counter. Code that derives something from the screen
+//! information, e.g. a layout, can remember the generation it used and cheaply check whether it
+//! has to recompute.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::screen_info::ScreenTracker;
+//!
+//! fn run(conn: &impl Connection, screen_num: usize) -> Result<(), ReplyError> {
+//!     let mut tracker = ScreenTracker::new(conn)?;
+//!     let mut layout_generation = None;
+//!     loop {
+//!         let screen = &tracker.screens()[screen_num];
+//!         if layout_generation != Some(screen.generation) {
+//!             println!("Screen is now {}x{}", screen.width_in_pixels, screen.height_in_pixels);
+//!             layout_generation = Some(screen.generation);
+//!         }
+//!         let event = conn.wait_for_event()?;
+//!         let _ = tracker.handle_event(conn, &event)?;
+//!     }
+//! }
+//! ```
+//!
+//! The code in this module is only available when the `randr` feature of the library is enabled.
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::ReplyError;
+use crate::protocol::randr::{self, MonitorInfo, NotifyMask, Rotation, ScreenChangeNotifyEvent};
+use crate::protocol::xproto::{self, Screen, Window};
+use crate::protocol::Event;
+
+/// The current information about a screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenInfo {
+    /// The root window of the screen.
+    pub root: Window,
+    /// The width of the screen in pixels.
+    pub width_in_pixels: u16,
+    /// The height of the screen in pixels.
+    pub height_in_pixels: u16,
+    /// The physical width of the screen.
+    pub width_in_millimeters: u16,
+    /// The physical height of the screen.
+    pub height_in_millimeters: u16,
+    /// The active monitors of the screen.
+    ///
+    /// This is empty if the X11 server does not support RandR 1.5.
+    pub monitors: Vec<MonitorInfo>,
+    /// A counter that is incremented whenever the information about this screen changes.
+    pub generation: u64,
+}
+
+impl ScreenInfo {
+    fn from_screen(screen: &Screen) -> Self {
+        Self {
+            root: screen.root,
+            width_in_pixels: screen.width_in_pixels,
+            height_in_pixels: screen.height_in_pixels,
+            width_in_millimeters: screen.width_in_millimeters,
+            height_in_millimeters: screen.height_in_millimeters,
+            monitors: Vec::new(),
+            generation: 0,
+        }
+    }
+}
+
+/// Keeps the information about all screens up to date.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct ScreenTracker {
+    screens: Vec<ScreenInfo>,
+    generation: u64,
+    /// Whether the X11 server supports RandR 1.5 and thus `GetMonitors`.
+    has_monitors: bool,
+}
+
+impl ScreenTracker {
+    /// Start tracking the screens of the given connection.
+    ///
+    /// If the RandR extension is available, its events are selected on all root windows and the
+    /// current sizes and monitors are queried. Without RandR, the information from the setup is
+    /// used and never changes.
+    ///
+    /// This function waits for the replies to the necessary requests.
+    pub fn new<C: Connection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        let screens = conn
+            .setup()
+            .roots
+            .iter()
+            .map(ScreenInfo::from_screen)
+            .collect();
+        let mut tracker = Self {
+            screens,
+            generation: 0,
+            has_monitors: false,
+        };
+        if conn
+            .extension_information(randr::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Ok(tracker);
+        }
+        // Events about CRTCs and outputs are only sent to clients that use at least RandR 1.2
+        let version = randr::query_version(conn, 1, 5)?.reply()?;
+        tracker.has_monitors = (version.major_version, version.minor_version) >= (1, 5);
+        let mask = NotifyMask::SCREEN_CHANGE | NotifyMask::CRTC_CHANGE | NotifyMask::OUTPUT_CHANGE;
+        for screen in &tracker.screens {
+            randr::select_input(conn, screen.root, mask)?.check()?;
+        }
+        let _ = tracker.refresh(conn)?;
+        Ok(tracker)
+    }
+
+    /// Get the information about all screens, in the order of the setup.
+    pub fn screens(&self) -> &[ScreenInfo] {
+        &self.screens
+    }
+
+    /// Get the information about the screen with the given root window.
+    pub fn screen_for_root(&self, root: Window) -> Option<&ScreenInfo> {
+        self.screens.iter().find(|screen| screen.root == root)
+    }
+
+    /// Get a counter that is incremented whenever the information about any screen changes.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Query the size and monitors of all screens again.
+    ///
+    /// The physical size of the screens is not queried, because the X11 server only reports it
+    /// in the setup and in RandR events.
+    ///
+    /// Returns whether anything changed. This function waits for the replies to the necessary
+    /// requests.
+    pub fn refresh<C: RequestConnection + ?Sized>(&mut self, conn: &C) -> Result<bool, ReplyError> {
+        let mut changed = false;
+        for index in 0..self.screens.len() {
+            let root = self.screens[index].root;
+            let geometry = xproto::get_geometry(conn, root)?;
+            let monitors = if self.has_monitors {
+                Some(randr::get_monitors(conn, root, true)?)
+            } else {
+                None
+            };
+            let geometry = geometry.reply()?;
+            let monitors = match monitors {
+                Some(monitors) => monitors.reply()?.monitors,
+                None => Vec::new(),
+            };
+            changed |= self.update(index, |screen| {
+                screen.width_in_pixels = geometry.width;
+                screen.height_in_pixels = geometry.height;
+                screen.monitors = monitors;
+            });
+        }
+        Ok(changed)
+    }
+
+    /// Update the screen information from an event.
+    ///
+    /// A `ScreenChangeNotify` event updates the size of its screen. Other RandR events cause the
+    /// monitors to be queried again via [`ScreenTracker::refresh`].
+    ///
+    /// Returns whether anything changed.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ReplyError> {
+        match event {
+            Event::RandrScreenChangeNotify(event) => Ok(self.screen_changed(event)),
+            Event::RandrNotify(_) if self.has_monitors => self.refresh(conn),
+            _ => Ok(false),
+        }
+    }
+
+    /// Apply a `ScreenChangeNotify` event.
+    fn screen_changed(&mut self, event: &ScreenChangeNotifyEvent) -> bool {
+        let index = match self
+            .screens
+            .iter()
+            .position(|screen| screen.root == event.root)
+        {
+            Some(index) => index,
+            None => return false,
+        };
+        // The event contains the size before rotation
+        let rotated = event.rotation & u8::from(Rotation::ROTATE90 | Rotation::ROTATE270) != 0;
+        self.update(index, |screen| {
+            if rotated {
+                screen.width_in_pixels = event.height;
+                screen.height_in_pixels = event.width;
+                screen.width_in_millimeters = event.mheight;
+                screen.height_in_millimeters = event.mwidth;
+            } else {
+                screen.width_in_pixels = event.width;
+                screen.height_in_pixels = event.height;
+                screen.width_in_millimeters = event.mwidth;
+                screen.height_in_millimeters = event.mheight;
+            }
+        })
+    }
+
+    /// Change the screen with the given index and increment the generations if anything changed.
+    fn update(&mut self, index: usize, f: impl FnOnce(&mut ScreenInfo)) -> bool {
+        let screen = &mut self.screens[index];
+        let old = screen.clone();
+        f(screen);
+        if *screen == old {
+            return false;
+        }
+        self.generation += 1;
+        screen.generation = self.generation;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScreenInfo, ScreenTracker};
+    use crate::protocol::randr::{Rotation, ScreenChangeNotifyEvent};
+    use crate::protocol::render::SubPixel;
+
+    fn tracker() -> ScreenTracker {
+        let screen = |root| ScreenInfo {
+            root,
+            width_in_pixels: 1024,
+            height_in_pixels: 768,
+            width_in_millimeters: 270,
+            height_in_millimeters: 200,
+            monitors: Vec::new(),
+            generation: 0,
+        };
+        ScreenTracker {
+            screens: vec![screen(1), screen(2)],
+            generation: 0,
+            has_monitors: false,
+        }
+    }
+
+    fn screen_change(root: u32, rotation: Rotation) -> ScreenChangeNotifyEvent {
+        ScreenChangeNotifyEvent {
+            response_type: 0,
+            rotation: rotation.into(),
+            sequence: 0,
+            timestamp: 0,
+            config_timestamp: 0,
+            root,
+            request_window: root,
+            size_id: 0,
+            subpixel_order: SubPixel::UNKNOWN,
+            width: 1920,
+            height: 1080,
+            mwidth: 510,
+            mheight: 290,
+        }
+    }
+
+    #[test]
+    fn screen_change_updates_size() {
+        let mut tracker = tracker();
+        assert!(tracker.screen_changed(&screen_change(2, Rotation::ROTATE0)));
+        let screen = tracker.screen_for_root(2).unwrap();
+        assert_eq!(
+            (screen.width_in_pixels, screen.height_in_pixels),
+            (1920, 1080)
+        );
+        assert_eq!(
+            (screen.width_in_millimeters, screen.height_in_millimeters),
+            (510, 290)
+        );
+        assert_eq!(screen.generation, 1);
+        assert_eq!(tracker.screens()[0].generation, 0);
+        assert_eq!(tracker.generation(), 1);
+
+        // The same size again is not a change
+        assert!(!tracker.screen_changed(&screen_change(2, Rotation::ROTATE0)));
+        assert_eq!(tracker.generation(), 1);
+        // Unknown roots are ignored
+        assert!(!tracker.screen_changed(&screen_change(3, Rotation::ROTATE0)));
+    }
+
+    #[test]
+    fn screen_change_with_rotation() {
+        let mut tracker = tracker();
+        assert!(tracker.screen_changed(&screen_change(1, Rotation::ROTATE90)));
+        let screen = &tracker.screens()[0];
+        assert_eq!(
+            (screen.width_in_pixels, screen.height_in_pixels),
+            (1080, 1920)
+        );
+        assert_eq!(
+            (screen.width_in_millimeters, screen.height_in_millimeters),
+            (290, 510)
+        );
+    }
+}
@@ -0,0 +1,792 @@
+//! Saving the configuration of monitors and restoring it later.
+//!
+//! [`ScreenLayout::capture`] records which monitors are enabled, their modes, positions and
+//! rotations, which one is the primary monitor, and their gamma ramps. With the `serde` feature
+//! of the library, a [`ScreenLayout`] can be stored in any format that serde supports.
+//!
+//! [`ScreenLayout::apply`] restores such a configuration. Monitors are found by their EDID, so
+//! that a monitor that is now plugged into another port still gets its old configuration.
+//! Outputs without EDID are found by their name instead. Before anything is changed, the layout
+//! is checked against the monitors that are currently connected:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::screen_layout::{LayoutError, ScreenLayout};
+//!
+//! fn restore(
+//!     conn: &impl Connection,
+//!     screen_num: usize,
+//!     profiles: &[ScreenLayout],
+//! ) -> Result<bool, LayoutError> {
+//!     let root = conn.setup().roots[screen_num].root;
+//!     for profile in profiles {
+//!         match profile.apply(conn, root) {
+//!             Ok(()) => return Ok(true),
+//!             // This profile is for other monitors
+//!             Err(LayoutError::MissingMonitor(_)) => continue,
+//!             Err(err) => return Err(err),
+//!         }
+//!     }
+//!     Ok(false)
+//! }
+//! ```
+//!
+//! Connected outputs that are not part of the layout are disabled. Outputs that share a CRTC,
+//! i.e. clones, are restored with a CRTC each.
+//!
+//! The code in this module is only available when the `randr` feature of the library is enabled.
+
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::randr::{self, Crtc, Mode, ModeFlag, ModeInfo, Output, Rotation, SetConfig};
+use crate::protocol::xproto::{self, AtomEnum, Window};
+use crate::{CURRENT_TIME, NONE};
+
+/// The configuration of all connected outputs of a screen.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenLayout {
+    /// The outputs, in the order in which the X11 server lists them.
+    pub outputs: Vec<OutputLayout>,
+}
+
+/// The configuration of a single output.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLayout {
+    /// The name of the output, e.g. `HDMI-1`.
+    pub name: String,
+    /// The EDID of the connected monitor, if the driver provides it.
+    pub edid: Option<Vec<u8>>,
+    /// Whether this is the primary output.
+    pub primary: bool,
+    /// The configuration of the output, or `None` if it is disabled.
+    pub config: Option<OutputConfig>,
+}
+
+/// The configuration of an enabled output.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputConfig {
+    /// The horizontal position of the output on the screen.
+    pub x: i16,
+    /// The vertical position of the output on the screen.
+    pub y: i16,
+    /// The width of the mode, before rotation.
+    pub width: u16,
+    /// The height of the mode, before rotation.
+    pub height: u16,
+    /// The refresh rate of the mode in millihertz.
+    pub refresh_millihertz: u32,
+    /// The rotation and reflection, as a bitmask of [`Rotation`] values.
+    pub rotation: u16,
+    /// The gamma ramps of the output.
+    pub gamma: Option<Gamma>,
+}
+
+/// The gamma ramps of an output.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gamma {
+    /// The ramp for the red channel.
+    pub red: Vec<u16>,
+    /// The ramp for the green channel.
+    pub green: Vec<u16>,
+    /// The ramp for the blue channel.
+    pub blue: Vec<u16>,
+}
+
+/// An error that occurred while restoring a [`ScreenLayout`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LayoutError {
+    /// Sending a request or receiving its reply failed.
+    Reply(ReplyError),
+    /// The enabled output with the given name has no connected monitor.
+    MissingMonitor(String),
+    /// The monitor of the output with the given name does not support the mode.
+    MissingMode(String),
+    /// No CRTC is available for the output with the given name.
+    NoCrtc(String),
+    /// The X11 server does not support a screen of the given size.
+    ScreenSize(i32, i32),
+    /// The X11 server refused to configure a CRTC.
+    Refused(SetConfig),
+}
+
+impl std::error::Error for LayoutError {}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Reply(err) => err.fmt(f),
+            LayoutError::MissingMonitor(name) => {
+                write!(f, "The monitor of output {} is not connected", name)
+            }
+            LayoutError::MissingMode(name) => {
+                write!(
+                    f,
+                    "The monitor of output {} does not support the mode",
+                    name
+                )
+            }
+            LayoutError::NoCrtc(name) => write!(f, "No CRTC is available for output {}", name),
+            LayoutError::ScreenSize(width, height) => {
+                write!(f, "A screen size of {}x{} is not supported", width, height)
+            }
+            LayoutError::Refused(status) => {
+                write!(f, "Configuring a CRTC failed with status {:?}", status)
+            }
+        }
+    }
+}
+
+impl From<ReplyError> for LayoutError {
+    fn from(err: ReplyError) -> Self {
+        LayoutError::Reply(err)
+    }
+}
+
+impl From<ConnectionError> for LayoutError {
+    fn from(err: ConnectionError) -> Self {
+        LayoutError::Reply(err.into())
+    }
+}
+
+/// The refresh rate of a mode in millihertz.
+fn refresh_millihertz(mode: &ModeInfo) -> u32 {
+    let mut pixels = u64::from(mode.htotal) * u64::from(mode.vtotal);
+    let mut millipixels = u64::from(mode.dot_clock) * 1000;
+    if mode.mode_flags & u32::from(ModeFlag::DOUBLE_SCAN) != 0 {
+        pixels *= 2;
+    }
+    if mode.mode_flags & u32::from(ModeFlag::INTERLACE) != 0 {
+        millipixels *= 2;
+    }
+    if pixels == 0 {
+        return 0;
+    }
+    let refresh = millipixels / pixels;
+    refresh.try_into().unwrap_or(u32::MAX)
+}
+
+/// An output as it is currently configured.
+#[derive(Debug, Clone)]
+struct CurrentOutput {
+    id: Output,
+    name: String,
+    edid: Option<Vec<u8>>,
+    crtc: Crtc,
+    crtcs: Vec<Crtc>,
+    modes: Vec<Mode>,
+}
+
+/// A CRTC as it is currently configured.
+#[derive(Debug, Clone)]
+struct CurrentCrtc {
+    id: Crtc,
+    x: i16,
+    y: i16,
+    mode: Mode,
+    rotation: u16,
+    outputs: Vec<Output>,
+}
+
+/// The current configuration of a screen.
+#[derive(Debug, Clone)]
+struct Current {
+    config_timestamp: xproto::Timestamp,
+    /// Only the connected outputs
+    outputs: Vec<CurrentOutput>,
+    crtcs: Vec<CurrentCrtc>,
+    modes: Vec<ModeInfo>,
+    primary: Output,
+}
+
+impl Current {
+    fn query<C: RequestConnection + ?Sized>(conn: &C, root: Window) -> Result<Self, ReplyError> {
+        let resources = randr::get_screen_resources_current(conn, root)?;
+        let primary = randr::get_output_primary(conn, root)?;
+        let edid_atom = xproto::intern_atom(conn, true, b"EDID")?;
+        let resources = resources.reply()?;
+        let primary = primary.reply()?.output;
+        let edid_atom = edid_atom.reply()?.atom;
+        let timestamp = resources.config_timestamp;
+
+        let infos = resources
+            .outputs
+            .iter()
+            .map(|output| randr::get_output_info(conn, *output, timestamp))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut outputs = Vec::new();
+        for (id, info) in resources.outputs.iter().zip(infos) {
+            let info = info.reply()?;
+            if info.connection != randr::Connection::CONNECTED {
+                continue;
+            }
+            let edid = if edid_atom == NONE {
+                None
+            } else {
+                // EDIDs are 128 bytes per block; 1024 bytes are enough for the extension blocks
+                let reply = randr::get_output_property(
+                    conn,
+                    *id,
+                    edid_atom,
+                    AtomEnum::ANY,
+                    0,
+                    256,
+                    false,
+                    false,
+                )?
+                .reply()?;
+                Some(reply.data).filter(|data| !data.is_empty())
+            };
+            outputs.push(CurrentOutput {
+                id: *id,
+                name: String::from_utf8_lossy(&info.name).into_owned(),
+                edid,
+                crtc: info.crtc,
+                crtcs: info.crtcs,
+                modes: info.modes,
+            });
+        }
+
+        let infos = resources
+            .crtcs
+            .iter()
+            .map(|crtc| randr::get_crtc_info(conn, *crtc, timestamp))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut crtcs = Vec::new();
+        for (id, info) in resources.crtcs.iter().zip(infos) {
+            let info = info.reply()?;
+            crtcs.push(CurrentCrtc {
+                id: *id,
+                x: info.x,
+                y: info.y,
+                mode: info.mode,
+                rotation: info.rotation,
+                outputs: info.outputs,
+            });
+        }
+
+        Ok(Self {
+            config_timestamp: timestamp,
+            outputs,
+            crtcs,
+            modes: resources.modes,
+            primary,
+        })
+    }
+
+    fn mode_info(&self, mode: Mode) -> Option<&ModeInfo> {
+        self.modes.iter().find(|info| info.id == mode)
+    }
+}
+
+/// The configuration that one CRTC gets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Assignment {
+    crtc: Crtc,
+    output: Output,
+    mode: Mode,
+    x: i16,
+    y: i16,
+    rotation: u16,
+    gamma: Option<Gamma>,
+}
+
+/// The changes that restore a layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Plan {
+    assignments: Vec<Assignment>,
+    width: i32,
+    height: i32,
+    primary: Output,
+}
+
+impl ScreenLayout {
+    /// Record the current configuration of the outputs of the screen with the given root window.
+    ///
+    /// Only connected outputs are recorded. This function waits for the replies to the
+    /// necessary requests.
+    pub fn capture<C: RequestConnection + ?Sized>(
+        conn: &C,
+        root: Window,
+    ) -> Result<Self, ReplyError> {
+        let current = Current::query(conn, root)?;
+        let mut outputs = Vec::new();
+        for output in &current.outputs {
+            let crtc = current.crtcs.iter().find(|crtc| crtc.id == output.crtc);
+            let config = match crtc.and_then(|crtc| Some((crtc, current.mode_info(crtc.mode)?))) {
+                Some((crtc, mode)) => {
+                    let gamma = randr::get_crtc_gamma(conn, crtc.id)?.reply()?;
+                    Some(OutputConfig {
+                        x: crtc.x,
+                        y: crtc.y,
+                        width: mode.width,
+                        height: mode.height,
+                        refresh_millihertz: refresh_millihertz(mode),
+                        rotation: crtc.rotation,
+                        gamma: Some(Gamma {
+                            red: gamma.red,
+                            green: gamma.green,
+                            blue: gamma.blue,
+                        })
+                        .filter(|gamma| !gamma.red.is_empty()),
+                    })
+                }
+                None => None,
+            };
+            outputs.push(OutputLayout {
+                name: output.name.clone(),
+                edid: output.edid.clone(),
+                primary: output.id == current.primary,
+                config,
+            });
+        }
+        Ok(Self { outputs })
+    }
+
+    /// Check whether this layout can be applied to the currently connected monitors.
+    ///
+    /// This performs all the checks of [`ScreenLayout::apply`] without changing anything.
+    pub fn check<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        root: Window,
+    ) -> Result<(), LayoutError> {
+        let current = Current::query(conn, root)?;
+        let plan = self.plan(&current)?;
+        check_screen_size(conn, root, &plan)
+    }
+
+    /// Apply this layout to the screen with the given root window.
+    ///
+    /// Nothing is changed if the layout does not fit the currently connected monitors. The
+    /// server is grabbed while the changes are made. Gamma ramps are only restored if the CRTC
+    /// that the output now uses has a gamma ramp of the same size.
+    ///
+    /// This function waits for the replies to the necessary requests.
+    pub fn apply<C: Connection + ?Sized>(&self, conn: &C, root: Window) -> Result<(), LayoutError> {
+        let current = Current::query(conn, root)?;
+        let plan = self.plan(&current)?;
+        check_screen_size(conn, root, &plan)?;
+
+        let _ = xproto::grab_server(conn)?;
+        let result = apply_plan(conn, root, &current, &plan);
+        let _ = xproto::ungrab_server(conn)?;
+        conn.flush()?;
+        result
+    }
+
+    /// Decide which CRTCs, modes and outputs to use for this layout.
+    fn plan(&self, current: &Current) -> Result<Plan, LayoutError> {
+        let mut plan = Plan {
+            assignments: Vec::new(),
+            width: 0,
+            height: 0,
+            primary: NONE,
+        };
+        let mut matched: Vec<(&OutputLayout, &OutputConfig, &CurrentOutput, Mode)> = Vec::new();
+        for layout in &self.outputs {
+            let config = match &layout.config {
+                Some(config) => config,
+                None => continue,
+            };
+            let output = current
+                .outputs
+                .iter()
+                .filter(|output| matched.iter().all(|(_, _, used, _)| used.id != output.id))
+                .find(|output| match &layout.edid {
+                    Some(edid) => output.edid.as_ref() == Some(edid),
+                    None => output.name == layout.name,
+                })
+                .ok_or_else(|| LayoutError::MissingMonitor(layout.name.clone()))?;
+
+            // The mode with the right size and the closest refresh rate, if it is within 1 Hz
+            let mode = output
+                .modes
+                .iter()
+                .filter_map(|mode| current.mode_info(*mode))
+                .filter(|mode| (mode.width, mode.height) == (config.width, config.height))
+                .map(|mode| {
+                    let refresh = refresh_millihertz(mode);
+                    let difference = refresh.max(config.refresh_millihertz)
+                        - refresh.min(config.refresh_millihertz);
+                    (mode.id, difference)
+                })
+                .filter(|(_, difference)| *difference <= 1000)
+                .min_by_key(|(_, difference)| *difference)
+                .map(|(mode, _)| mode)
+                .ok_or_else(|| LayoutError::MissingMode(layout.name.clone()))?;
+            matched.push((layout, config, output, mode));
+        }
+
+        // Outputs keep their current CRTC if possible, to avoid needless changes
+        let mut crtcs = matched
+            .iter()
+            .map(|(_, _, output, _)| Some(output.crtc).filter(|crtc| output.crtcs.contains(crtc)))
+            .collect::<Vec<_>>();
+        for index in 0..matched.len() {
+            if crtcs[index].is_none() {
+                let (layout, _, output, _) = matched[index];
+                let free = output
+                    .crtcs
+                    .iter()
+                    .find(|crtc| !crtcs.contains(&Some(**crtc)));
+                crtcs[index] = Some(*free.ok_or_else(|| LayoutError::NoCrtc(layout.name.clone()))?);
+            }
+        }
+
+        for ((layout, config, output, mode), crtc) in matched.into_iter().zip(crtcs) {
+            let rotated =
+                config.rotation & u16::from(Rotation::ROTATE90 | Rotation::ROTATE270) != 0;
+            let (width, height) = if rotated {
+                (config.height, config.width)
+            } else {
+                (config.width, config.height)
+            };
+            plan.width = plan.width.max(i32::from(config.x) + i32::from(width));
+            plan.height = plan.height.max(i32::from(config.y) + i32::from(height));
+            if layout.primary {
+                plan.primary = output.id;
+            }
+            plan.assignments.push(Assignment {
+                crtc: crtc.unwrap(),
+                output: output.id,
+                mode,
+                x: config.x,
+                y: config.y,
+                rotation: config.rotation,
+                gamma: config.gamma.clone(),
+            });
+        }
+        Ok(plan)
+    }
+}
+
+/// Check that the X11 server supports the screen size of a plan.
+fn check_screen_size<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    plan: &Plan,
+) -> Result<(), LayoutError> {
+    let range = randr::get_screen_size_range(conn, root)?.reply()?;
+    let width = plan.width.max(i32::from(range.min_width));
+    let height = plan.height.max(i32::from(range.min_height));
+    if width > i32::from(range.max_width) || height > i32::from(range.max_height) {
+        return Err(LayoutError::ScreenSize(width, height));
+    }
+    Ok(())
+}
+
+/// Make the changes of a plan. The server should be grabbed.
+fn apply_plan<C: Connection + ?Sized>(
+    conn: &C,
+    root: Window,
+    current: &Current,
+    plan: &Plan,
+) -> Result<(), LayoutError> {
+    let set_crtc = |crtc, x, y, mode, rotation, outputs: &[Output]| -> Result<(), LayoutError> {
+        let status = randr::set_crtc_config(
+            conn,
+            crtc,
+            CURRENT_TIME,
+            current.config_timestamp,
+            x,
+            y,
+            mode,
+            rotation,
+            outputs,
+        )?
+        .reply()?
+        .status;
+        if status == SetConfig::SUCCESS {
+            Ok(())
+        } else {
+            Err(LayoutError::Refused(status))
+        }
+    };
+    let unchanged = |crtc: &CurrentCrtc| {
+        plan.assignments.iter().any(|a| {
+            a.crtc == crtc.id
+                && (a.x, a.y, a.mode, a.rotation) == (crtc.x, crtc.y, crtc.mode, crtc.rotation)
+                && crtc.outputs == [a.output]
+        })
+    };
+
+    // Disable everything that changes, so that the screen can shrink
+    for crtc in &current.crtcs {
+        if crtc.mode != NONE && !unchanged(crtc) {
+            set_crtc(crtc.id, 0, 0, NONE, u16::from(Rotation::ROTATE0), &[])?;
+        }
+    }
+
+    let range = randr::get_screen_size_range(conn, root)?.reply()?;
+    let width = plan.width.max(i32::from(range.min_width));
+    let height = plan.height.max(i32::from(range.min_height));
+    let (width, height) = (
+        u16::try_from(width).map_err(|_| LayoutError::ScreenSize(width, height))?,
+        u16::try_from(height).map_err(|_| LayoutError::ScreenSize(width, height))?,
+    );
+    // Keep the DPI from the setup, or use 96 DPI if the screen has no physical size
+    let screen = conn.setup().roots.iter().find(|screen| screen.root == root);
+    let millimeters = |pixels: u16, current_pixels: u16, current_mm: u16| {
+        if current_pixels == 0 || current_mm == 0 {
+            u32::from(pixels) * 254 / 960
+        } else {
+            u32::from(pixels) * u32::from(current_mm) / u32::from(current_pixels)
+        }
+    };
+    let (mm_width, mm_height) = match screen {
+        Some(screen) => (
+            millimeters(width, screen.width_in_pixels, screen.width_in_millimeters),
+            millimeters(
+                height,
+                screen.height_in_pixels,
+                screen.height_in_millimeters,
+            ),
+        ),
+        None => (millimeters(width, 0, 0), millimeters(height, 0, 0)),
+    };
+    randr::set_screen_size(conn, root, width, height, mm_width, mm_height)?.check()?;
+
+    for assignment in &plan.assignments {
+        let crtc = current.crtcs.iter().find(|crtc| crtc.id == assignment.crtc);
+        if !matches!(crtc, Some(crtc) if unchanged(crtc)) {
+            set_crtc(
+                assignment.crtc,
+                assignment.x,
+                assignment.y,
+                assignment.mode,
+                assignment.rotation,
+                &[assignment.output],
+            )?;
+        }
+        if let Some(gamma) = &assignment.gamma {
+            let size = randr::get_crtc_gamma_size(conn, assignment.crtc)?
+                .reply()?
+                .size;
+            if usize::from(size) == gamma.red.len() {
+                let _ = randr::set_crtc_gamma(
+                    conn,
+                    assignment.crtc,
+                    &gamma.red,
+                    &gamma.green,
+                    &gamma.blue,
+                )?;
+            }
+        }
+    }
+    let _ = randr::set_output_primary(conn, root, plan.primary)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        refresh_millihertz, Assignment, Current, CurrentCrtc, CurrentOutput, LayoutError,
+        OutputConfig, OutputLayout, ScreenLayout,
+    };
+    use crate::protocol::randr::{ModeFlag, ModeInfo, Rotation};
+
+    fn mode(id: u32, width: u16, height: u16, dot_clock: u32) -> ModeInfo {
+        ModeInfo {
+            id,
+            width,
+            height,
+            dot_clock,
+            hsync_start: 0,
+            hsync_end: 0,
+            htotal: 2000,
+            hskew: 0,
+            vsync_start: 0,
+            vsync_end: 0,
+            vtotal: 1000,
+            name_len: 0,
+            mode_flags: 0,
+        }
+    }
+
+    fn output(id: u32, name: &str, edid: Option<&[u8]>, crtc: u32) -> CurrentOutput {
+        CurrentOutput {
+            id,
+            name: name.to_string(),
+            edid: edid.map(<[u8]>::to_vec),
+            crtc,
+            crtcs: vec![10, 11],
+            modes: vec![1, 2, 3],
+        }
+    }
+
+    // Two monitors: DP-1 with EDID "a" on CRTC 10 and HDMI-1 without EDID, disabled
+    fn current() -> Current {
+        Current {
+            config_timestamp: 0,
+            outputs: vec![
+                output(20, "DP-1", Some(b"a"), 10),
+                output(21, "HDMI-1", None, 0),
+            ],
+            crtcs: vec![
+                CurrentCrtc {
+                    id: 10,
+                    x: 0,
+                    y: 0,
+                    mode: 1,
+                    rotation: Rotation::ROTATE0.into(),
+                    outputs: vec![20],
+                },
+                CurrentCrtc {
+                    id: 11,
+                    x: 0,
+                    y: 0,
+                    mode: 0,
+                    rotation: Rotation::ROTATE0.into(),
+                    outputs: vec![],
+                },
+            ],
+            // 1920x1080 at 60 Hz and 50 Hz, 1280x1024 at 60 Hz
+            modes: vec![
+                mode(1, 1920, 1080, 120_000_000),
+                mode(2, 1920, 1080, 100_000_000),
+                mode(3, 1280, 1024, 120_000_000),
+            ],
+            primary: 20,
+        }
+    }
+
+    fn layout(name: &str, edid: Option<&[u8]>, config: Option<OutputConfig>) -> OutputLayout {
+        OutputLayout {
+            name: name.to_string(),
+            edid: edid.map(<[u8]>::to_vec),
+            primary: false,
+            config,
+        }
+    }
+
+    fn config(x: i16, width: u16, height: u16, refresh_millihertz: u32) -> OutputConfig {
+        OutputConfig {
+            x,
+            y: 0,
+            width,
+            height,
+            refresh_millihertz,
+            rotation: Rotation::ROTATE0.into(),
+            gamma: None,
+        }
+    }
+
+    #[test]
+    fn refresh_rates() {
+        let mut info = mode(1, 1920, 1080, 148_500_000);
+        info.htotal = 2200;
+        info.vtotal = 1125;
+        assert_eq!(refresh_millihertz(&info), 60_000);
+        info.mode_flags = ModeFlag::INTERLACE.into();
+        assert_eq!(refresh_millihertz(&info), 120_000);
+        info.vtotal = 0;
+        assert_eq!(refresh_millihertz(&info), 0);
+    }
+
+    #[test]
+    fn monitors_are_matched_by_edid() {
+        // The monitor with EDID "a" was connected to HDMI-2 before
+        let layout = ScreenLayout {
+            outputs: vec![
+                layout("HDMI-2", Some(b"a"), Some(config(1280, 1920, 1080, 50_000))),
+                OutputLayout {
+                    primary: true,
+                    ..layout("HDMI-1", None, Some(config(0, 1280, 1024, 60_000)))
+                },
+            ],
+        };
+        let plan = layout.plan(&current()).unwrap();
+        assert_eq!((plan.width, plan.height), (3200, 1080));
+        assert_eq!(plan.primary, 21);
+        let assignment = |crtc, output, mode, x| Assignment {
+            crtc,
+            output,
+            mode,
+            x,
+            y: 0,
+            rotation: Rotation::ROTATE0.into(),
+            gamma: None,
+        };
+        assert_eq!(
+            plan.assignments,
+            [assignment(10, 20, 2, 1280), assignment(11, 21, 3, 0)]
+        );
+    }
+
+    #[test]
+    fn missing_monitors_and_modes() {
+        let check = |outputs| ScreenLayout { outputs }.plan(&current()).map(|_| ());
+        // A different monitor on DP-1
+        let result = check(vec![layout(
+            "DP-1",
+            Some(b"b"),
+            Some(config(0, 1920, 1080, 60_000)),
+        )]);
+        assert!(matches!(result, Err(LayoutError::MissingMonitor(name)) if name == "DP-1"));
+        // Disabled outputs do not need a monitor
+        assert!(check(vec![layout("DP-1", Some(b"b"), None)]).is_ok());
+        // The same monitor twice
+        let result = check(vec![
+            layout("DP-1", Some(b"a"), Some(config(0, 1920, 1080, 60_000))),
+            layout("DP-2", Some(b"a"), Some(config(0, 1920, 1080, 60_000))),
+        ]);
+        assert!(matches!(result, Err(LayoutError::MissingMonitor(name)) if name == "DP-2"));
+        // Refresh rates within 1 Hz match
+        assert!(check(vec![layout(
+            "HDMI-1",
+            None,
+            Some(config(0, 1920, 1080, 59_400))
+        )])
+        .is_ok());
+        let result = check(vec![layout(
+            "HDMI-1",
+            None,
+            Some(config(0, 1920, 1080, 55_000)),
+        )]);
+        assert!(matches!(result, Err(LayoutError::MissingMode(name)) if name == "HDMI-1"));
+        let result = check(vec![layout(
+            "HDMI-1",
+            None,
+            Some(config(0, 800, 600, 60_000)),
+        )]);
+        assert!(matches!(result, Err(LayoutError::MissingMode(_))));
+    }
+
+    #[test]
+    fn rotation_and_crtcs() {
+        let mut rotated = config(0, 1920, 1080, 60_000);
+        rotated.rotation = Rotation::ROTATE90.into();
+        let mut current = current();
+        current.outputs.push(output(22, "DP-2", Some(b"c"), 0));
+        let layout = ScreenLayout {
+            outputs: vec![
+                layout("HDMI-1", None, Some(rotated)),
+                layout("DP-1", Some(b"a"), Some(config(1080, 1920, 1080, 60_000))),
+                layout("DP-2", Some(b"c"), Some(config(3000, 1920, 1080, 60_000))),
+            ],
+        };
+        let result = layout.plan(&current);
+        assert!(matches!(result, Err(LayoutError::NoCrtc(name)) if name == "DP-2"));
+
+        let layout = ScreenLayout {
+            outputs: layout.outputs[..2].to_vec(),
+        };
+        let plan = layout.plan(&current).unwrap();
+        assert_eq!((plan.width, plan.height), (3000, 1920));
+        // DP-1 keeps its CRTC and HDMI-1 gets the free one
+        let crtcs = plan
+            .assignments
+            .iter()
+            .map(|a| (a.output, a.crtc))
+            .collect::<Vec<_>>();
+        assert_eq!(crtcs, [(21, 11), (20, 10)]);
+    }
+}
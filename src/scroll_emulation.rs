@@ -0,0 +1,393 @@
+//! Translating smooth scrolling into legacy scroll wheel button presses.
+//!
+//! With XInput 2.1, scrolling is reported as motion along scroll valuators, which allows smooth
+//! scrolling with touchpads. Legacy clients instead expect presses of the buttons 4 to 7. The X11
+//! server emulates these buttons for normal clients, but tools that forward input, e.g. to a
+//! nested X11 server or to a remote window, only see the XI2 events and have to do this
+//! themselves. [`ScrollEmulator`] accumulates the scroll valuators of XI2 `Motion` events and
+//! sends one button press and release each time a full scroll increment was reached.
+//!
+//! This module is only available when the `xinput` feature is enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::scroll_emulation::{Delivery, ScrollEmulator};
+//!
+//! fn forward_scrolling(conn: &impl Connection, source: Window, target: Window)
+//!     -> Result<(), ReplyError>
+//! {
+//!     let mut emulator = ScrollEmulator::new(conn, Delivery::SendEvent)?;
+//!     ScrollEmulator::select_events(conn, source)?;
+//!     conn.flush()?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         let _ = emulator.handle_event(conn, &event, target)?;
+//!         conn.flush()?;
+//!     }
+//! }
+//! ```
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xinput::{
+    self, DeviceClass, DeviceClassData, DeviceId, Fp3232, MotionEvent, ScrollType,
+};
+use crate::protocol::xproto::{self, Button, ButtonPressEvent, EventMask, Window};
+use crate::protocol::Event;
+use crate::NONE;
+
+/// A direction of scrolling, corresponding to one of the legacy scroll buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollDirection {
+    /// Scrolling up, button 4.
+    Up,
+    /// Scrolling down, button 5.
+    Down,
+    /// Scrolling left, button 6.
+    Left,
+    /// Scrolling right, button 7.
+    Right,
+}
+
+impl ScrollDirection {
+    /// Get the core protocol button that corresponds to this direction.
+    pub fn button(self) -> Button {
+        match self {
+            ScrollDirection::Up => 4,
+            ScrollDirection::Down => 5,
+            ScrollDirection::Left => 6,
+            ScrollDirection::Right => 7,
+        }
+    }
+}
+
+/// How the emulated button presses are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Send `ButtonPress` and `ButtonRelease` events to the target window via `SendEvent`.
+    ///
+    /// Note that some clients ignore events that were sent via `SendEvent`.
+    SendEvent,
+    /// Inject button presses via `FakeInput` from the XTEST extension.
+    ///
+    /// The X11 server delivers these like real button presses, so they go to the window under the
+    /// pointer instead of the target window.
+    #[cfg(feature = "xtest")]
+    Xtest,
+}
+
+/// The state of a single scroll valuator of a device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScrollValuator {
+    deviceid: DeviceId,
+    number: u16,
+    vertical: bool,
+    increment: f64,
+    /// The last value of the valuator, unknown after the device changed.
+    last: Option<f64>,
+    /// Scrolling that did not yet add up to a full increment, in units of the increment.
+    accumulated: f64,
+}
+
+/// Translates XI2 scroll valuators into presses of the buttons 4 to 7.
+#[derive(Debug, Clone)]
+pub struct ScrollEmulator {
+    delivery: Delivery,
+    valuators: Vec<ScrollValuator>,
+}
+
+impl ScrollEmulator {
+    /// Create a new emulator and query the scroll valuators of all master devices.
+    ///
+    /// The XInput extension must already be initialised via `XIQueryVersion` with at least
+    /// version 2.1, since the X11 server only reports scroll valuators to such clients.
+    pub fn new<C: RequestConnection + ?Sized>(
+        conn: &C,
+        delivery: Delivery,
+    ) -> Result<Self, ReplyError> {
+        let reply = xinput::xi_query_device(conn, xinput::Device::ALL_MASTER)?.reply()?;
+        let mut emulator = Self {
+            delivery,
+            valuators: Vec::new(),
+        };
+        for info in reply.infos {
+            emulator.update_device(info.deviceid, &info.classes);
+        }
+        Ok(emulator)
+    }
+
+    /// Select the XI2 events that [`handle_event()`](Self::handle_event) needs on a window.
+    ///
+    /// This selects `Motion` and `DeviceChanged` events of all master devices.
+    pub fn select_events<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<VoidCookie<'_, C>, ConnectionError> {
+        let mask = xinput::XIEventMask::MOTION | xinput::XIEventMask::DEVICE_CHANGED;
+        let mask = xinput::EventMask {
+            deviceid: xinput::Device::ALL_MASTER.into(),
+            mask: vec![mask.into()],
+        };
+        xinput::xi_select_events(conn, window, &[mask])
+    }
+
+    /// Handle an event and send the emulated button presses to `target`.
+    ///
+    /// `Motion` events are translated into button presses and `DeviceChanged` events update the
+    /// known scroll valuators. Returns the number of emulated button presses.
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+        target: Window,
+    ) -> Result<usize, ReplyError> {
+        match event {
+            Event::XinputMotion(event) => {
+                let directions = self.scroll(event);
+                for &direction in directions.iter() {
+                    self.send(conn, event, target, direction)?;
+                }
+                Ok(directions.len())
+            }
+            Event::XinputDeviceChanged(event) => {
+                self.update_device(event.deviceid, &event.classes);
+                Ok(0)
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// Accumulate the scroll valuators of a motion event and get the resulting button presses.
+    pub fn scroll(&mut self, event: &MotionEvent) -> Vec<ScrollDirection> {
+        let mut directions = Vec::new();
+        for (number, value) in valuator_values(&event.valuator_mask, &event.axisvalues) {
+            let valuator = self
+                .valuators
+                .iter_mut()
+                .find(|v| v.deviceid == event.deviceid && u32::from(v.number) == number);
+            let valuator = match valuator {
+                Some(valuator) => valuator,
+                None => continue,
+            };
+            let value = fp3232_to_f64(value);
+            if let Some(last) = valuator.last.replace(value) {
+                valuator.accumulated += (value - last) / valuator.increment;
+            }
+            let (negative, positive) = if valuator.vertical {
+                (ScrollDirection::Up, ScrollDirection::Down)
+            } else {
+                (ScrollDirection::Left, ScrollDirection::Right)
+            };
+            while valuator.accumulated >= 1.0 {
+                valuator.accumulated -= 1.0;
+                directions.push(positive);
+            }
+            while valuator.accumulated <= -1.0 {
+                valuator.accumulated += 1.0;
+                directions.push(negative);
+            }
+        }
+        directions
+    }
+
+    /// Replace the known scroll valuators of a device.
+    fn update_device(&mut self, deviceid: DeviceId, classes: &[DeviceClass]) {
+        self.valuators.retain(|v| v.deviceid != deviceid);
+        for class in classes {
+            if let DeviceClassData::Scroll(scroll) = &class.data {
+                let increment = fp3232_to_f64(scroll.increment);
+                if increment == 0.0 {
+                    continue;
+                }
+                self.valuators.push(ScrollValuator {
+                    deviceid,
+                    number: scroll.number,
+                    vertical: scroll.scroll_type == ScrollType::VERTICAL,
+                    increment,
+                    last: None,
+                    accumulated: 0.0,
+                });
+            }
+        }
+    }
+
+    /// Send a press and release of the button for the given direction.
+    fn send<C: Connection + ?Sized>(
+        &self,
+        conn: &C,
+        motion: &MotionEvent,
+        target: Window,
+        direction: ScrollDirection,
+    ) -> Result<(), ReplyError> {
+        match self.delivery {
+            Delivery::SendEvent => {
+                let (event_x, event_y) = if target == motion.event {
+                    (fp1616_to_i16(motion.event_x), fp1616_to_i16(motion.event_y))
+                } else {
+                    let reply = xproto::translate_coordinates(
+                        conn,
+                        motion.root,
+                        target,
+                        fp1616_to_i16(motion.root_x),
+                        fp1616_to_i16(motion.root_y),
+                    )?
+                    .reply()?;
+                    (reply.dst_x, reply.dst_y)
+                };
+                let mut event = ButtonPressEvent {
+                    response_type: xproto::BUTTON_PRESS_EVENT,
+                    detail: direction.button(),
+                    sequence: 0,
+                    time: motion.time,
+                    root: motion.root,
+                    event: target,
+                    child: NONE,
+                    root_x: fp1616_to_i16(motion.root_x),
+                    root_y: fp1616_to_i16(motion.root_y),
+                    event_x,
+                    event_y,
+                    state: motion.mods.effective as u16,
+                    same_screen: true,
+                };
+                let _ = xproto::send_event(conn, false, target, EventMask::BUTTON_PRESS, event)?;
+                event.response_type = xproto::BUTTON_RELEASE_EVENT;
+                let _ = xproto::send_event(conn, false, target, EventMask::BUTTON_RELEASE, event)?;
+            }
+            #[cfg(feature = "xtest")]
+            Delivery::Xtest => {
+                use crate::protocol::xtest;
+
+                let button = direction.button();
+                let press = xproto::BUTTON_PRESS_EVENT;
+                let release = xproto::BUTTON_RELEASE_EVENT;
+                let _ = xtest::fake_input(conn, press, button, 0, NONE, 0, 0, 0)?;
+                let _ = xtest::fake_input(conn, release, button, 0, NONE, 0, 0, 0)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pair the values of an XI2 event with the numbers of their valuators.
+pub(crate) fn valuator_values<'a>(
+    mask: &'a [u32],
+    values: &'a [Fp3232],
+) -> impl Iterator<Item = (u32, Fp3232)> + 'a {
+    let numbers = mask.iter().enumerate().flat_map(|(index, &bits)| {
+        (0..32)
+            .filter(move |bit| bits & (1 << bit) != 0)
+            .map(move |bit| 32 * index as u32 + bit)
+    });
+    numbers.zip(values.iter().copied())
+}
+
+pub(crate) fn fp3232_to_f64(value: Fp3232) -> f64 {
+    f64::from(value.integral) + f64::from(value.frac) / 4_294_967_296.0
+}
+
+fn fp1616_to_i16(value: xinput::Fp1616) -> i16 {
+    (value >> 16) as i16
+}
+
+#[cfg(test)]
+mod test {
+    use super::{valuator_values, Delivery, ScrollDirection, ScrollEmulator, ScrollValuator};
+    use crate::protocol::xinput::{Fp3232, GroupInfo, ModifierInfo, MotionEvent, MOTION_EVENT};
+
+    fn fp(value: f64) -> Fp3232 {
+        let integral = value.floor();
+        Fp3232 {
+            integral: integral as i32,
+            frac: ((value - integral) * 4_294_967_296.0) as u32,
+        }
+    }
+
+    fn motion(deviceid: u16, valuator_mask: Vec<u32>, axisvalues: Vec<Fp3232>) -> MotionEvent {
+        MotionEvent {
+            response_type: 35,
+            extension: 131,
+            sequence: 0,
+            length: 0,
+            event_type: MOTION_EVENT,
+            deviceid,
+            time: 0,
+            detail: 0,
+            root: 1,
+            event: 1,
+            child: 0,
+            root_x: 0,
+            root_y: 0,
+            event_x: 0,
+            event_y: 0,
+            sourceid: deviceid,
+            flags: 0,
+            mods: ModifierInfo {
+                base: 0,
+                latched: 0,
+                locked: 0,
+                effective: 0,
+            },
+            group: GroupInfo {
+                base: 0,
+                latched: 0,
+                locked: 0,
+                effective: 0,
+            },
+            button_mask: Vec::new(),
+            valuator_mask,
+            axisvalues,
+        }
+    }
+
+    fn emulator() -> ScrollEmulator {
+        let valuator = |number, vertical, increment| ScrollValuator {
+            deviceid: 2,
+            number,
+            vertical,
+            increment,
+            last: None,
+            accumulated: 0.0,
+        };
+        ScrollEmulator {
+            delivery: Delivery::SendEvent,
+            valuators: vec![valuator(2, false, 15.0), valuator(3, true, -15.0)],
+        }
+    }
+
+    #[test]
+    fn valuator_numbers() {
+        let values = [fp(1.0), fp(2.0), fp(3.0)];
+        let result = valuator_values(&[0b1001, 0b10], &values)
+            .map(|(number, value)| (number, value.integral))
+            .collect::<Vec<_>>();
+        assert_eq!(result, [(0, 1), (3, 2), (33, 3)]);
+    }
+
+    #[test]
+    fn accumulate_scrolling() {
+        let mut emulator = emulator();
+        // The first event only establishes the start value
+        assert_eq!(
+            emulator.scroll(&motion(2, vec![0b1100], vec![fp(100.0), fp(100.0)])),
+            []
+        );
+        assert_eq!(
+            emulator.scroll(&motion(2, vec![0b100], vec![fp(110.0)])),
+            []
+        );
+        assert_eq!(
+            emulator.scroll(&motion(2, vec![0b100], vec![fp(140.5)])),
+            [ScrollDirection::Right, ScrollDirection::Right]
+        );
+        // The increment of the vertical valuator is negative, so increasing values scroll up
+        assert_eq!(
+            emulator.scroll(&motion(2, vec![0b1000], vec![fp(115.0)])),
+            [ScrollDirection::Up]
+        );
+        // Other devices are ignored
+        assert_eq!(emulator.scroll(&motion(3, vec![0b1000], vec![fp(0.0)])), []);
+    }
+}
@@ -0,0 +1,394 @@
+//! Managing XInput 2 master devices for multi-seat setups.
+//!
+//! With XI2, every pair of a master pointer and a master keyboard is a separate seat with its own
+//! cursor and focus. Physical devices are slave devices that are attached to one of the masters
+//! or float freely. Kiosks with several users in front of one screen create one seat per user
+//! and attach each user's mouse and keyboard to it. This module lists the seats, changes the
+//! device hierarchy, and translates `Hierarchy` events into [`SeatChange`]s.
+//!
+//! This module is only available when the `xinput` feature is enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xinput::ConnectionExt as _;
+//! use x11rb::protocol::Event;
+//! use x11rb::seats::{add_seat, attach_slave, list_seats, seat_changes, select_hierarchy_events};
+//!
+//! fn second_seat(conn: &impl Connection, root: u32) -> Result<(), ReplyError> {
+//!     conn.xinput_xi_query_version(2, 0)?.reply()?;
+//!     select_hierarchy_events(conn, root)?.check()?;
+//!     add_seat(conn, "kiosk")?.check()?;
+//!     let topology = list_seats(conn)?;
+//!     let seat = topology.seats.iter().find(|seat| seat.name == "kiosk").unwrap();
+//!     if let Some(&mouse) = topology.floating.first() {
+//!         attach_slave(conn, mouse, seat.pointer)?.check()?;
+//!     }
+//!     loop {
+//!         if let Event::XinputHierarchy(event) = conn.wait_for_event()? {
+//!             for change in seat_changes(&event) {
+//!                 println!("{:?}", change);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xinput::{
+    self, ChangeMode, DeviceId, DeviceType, HierarchyChange, HierarchyChangeData,
+    HierarchyChangeDataAddMaster, HierarchyChangeDataAttachSlave, HierarchyChangeDataDetachSlave,
+    HierarchyChangeDataRemoveMaster, HierarchyEvent, HierarchyMask, XIDeviceInfo, XIEventMask,
+};
+use crate::protocol::xproto::Window;
+
+/// A slave device, i.e. a physical input device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlaveDevice {
+    /// The ID of the device.
+    pub deviceid: DeviceId,
+    /// The name of the device.
+    pub name: String,
+    /// Whether the device is enabled.
+    pub enabled: bool,
+}
+
+/// A pair of a master pointer and a master keyboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seat {
+    /// The name of the seat. This is the name of the master pointer without the `" pointer"`
+    /// suffix that the X11 server adds.
+    pub name: String,
+    /// The ID of the master pointer.
+    pub pointer: DeviceId,
+    /// The ID of the master keyboard.
+    pub keyboard: DeviceId,
+    /// The slave pointers that are attached to the master pointer.
+    pub slave_pointers: Vec<SlaveDevice>,
+    /// The slave keyboards that are attached to the master keyboard.
+    pub slave_keyboards: Vec<SlaveDevice>,
+}
+
+/// All seats and the slave devices that are not attached to any of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeatTopology {
+    /// The seats, ordered by the ID of their master pointer.
+    pub seats: Vec<Seat>,
+    /// The IDs of the floating slave devices.
+    pub floating: Vec<DeviceId>,
+}
+
+impl SeatTopology {
+    /// Build the topology from the information about all devices.
+    pub fn from_devices(devices: &[XIDeviceInfo]) -> Self {
+        let slave = |info: &XIDeviceInfo| SlaveDevice {
+            deviceid: info.deviceid,
+            name: String::from_utf8_lossy(&info.name).into_owned(),
+            enabled: info.enabled,
+        };
+        let slaves_of = |master: DeviceId, type_: DeviceType| {
+            devices
+                .iter()
+                .filter(|info| info.type_ == type_ && info.attachment == master)
+                .map(slave)
+                .collect()
+        };
+        let mut seats = devices
+            .iter()
+            .filter(|info| info.type_ == DeviceType::MASTER_POINTER)
+            .map(|info| {
+                let name = String::from_utf8_lossy(&info.name);
+                Seat {
+                    name: name.strip_suffix(" pointer").unwrap_or(&name).to_string(),
+                    pointer: info.deviceid,
+                    keyboard: info.attachment,
+                    slave_pointers: slaves_of(info.deviceid, DeviceType::SLAVE_POINTER),
+                    slave_keyboards: slaves_of(info.attachment, DeviceType::SLAVE_KEYBOARD),
+                }
+            })
+            .collect::<Vec<_>>();
+        seats.sort_by_key(|seat| seat.pointer);
+        let floating = devices
+            .iter()
+            .filter(|info| info.type_ == DeviceType::FLOATING_SLAVE)
+            .map(|info| info.deviceid)
+            .collect();
+        Self { seats, floating }
+    }
+
+    /// Find the seat that the given device belongs to.
+    ///
+    /// The device can be one of the masters or one of the attached slaves.
+    pub fn seat_of(&self, deviceid: DeviceId) -> Option<&Seat> {
+        self.seats.iter().find(|seat| {
+            seat.pointer == deviceid
+                || seat.keyboard == deviceid
+                || seat
+                    .slave_pointers
+                    .iter()
+                    .chain(seat.slave_keyboards.iter())
+                    .any(|slave| slave.deviceid == deviceid)
+        })
+    }
+}
+
+/// List all seats and floating devices.
+///
+/// The XInput extension must already be initialised via `XIQueryVersion` with at least version
+/// 2.0.
+pub fn list_seats<C: RequestConnection + ?Sized>(conn: &C) -> Result<SeatTopology, ReplyError> {
+    let reply = xinput::xi_query_device(conn, xinput::Device::ALL)?.reply()?;
+    Ok(SeatTopology::from_devices(&reply.infos))
+}
+
+/// Change the device hierarchy with a single change.
+fn change_hierarchy<C: RequestConnection + ?Sized>(
+    conn: &C,
+    data: HierarchyChangeData,
+    len: u16,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    xinput::xi_change_hierarchy(conn, &[HierarchyChange { len, data }])
+}
+
+/// Create a new seat with the given name.
+///
+/// The X11 server creates a master pointer called `"<name> pointer"` and a master keyboard called
+/// `"<name> keyboard"`. Use [`list_seats`] or `Hierarchy` events to get their IDs.
+pub fn add_seat<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    name: &str,
+) -> Result<VoidCookie<'c, C>, ConnectionError> {
+    let name = name.as_bytes().to_vec();
+    // The header, the fixed fields, and the padded name in units of four bytes
+    let padded_name = (name.len() + 3) & !3;
+    let len = (8 + padded_name) / 4;
+    let data = HierarchyChangeData::AddMaster(HierarchyChangeDataAddMaster {
+        send_core: true,
+        enable: true,
+        name,
+    });
+    change_hierarchy(conn, data, len as u16)
+}
+
+/// Remove a seat.
+///
+/// `seat` is the ID of its master pointer or keyboard. The slave devices of the seat are attached
+/// to the seat `return_to` if it is given and become floating otherwise.
+pub fn remove_seat<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    seat: DeviceId,
+    return_to: Option<&Seat>,
+) -> Result<VoidCookie<'c, C>, ConnectionError> {
+    let (return_mode, return_pointer, return_keyboard) = match return_to {
+        Some(other) => (ChangeMode::ATTACH, other.pointer, other.keyboard),
+        None => (ChangeMode::FLOAT, 0, 0),
+    };
+    let data = HierarchyChangeData::RemoveMaster(HierarchyChangeDataRemoveMaster {
+        deviceid: seat,
+        return_mode,
+        return_pointer,
+        return_keyboard,
+    });
+    change_hierarchy(conn, data, 3)
+}
+
+/// Attach a slave device to a master device.
+///
+/// A pointer has to be attached to a master pointer and a keyboard to a master keyboard.
+pub fn attach_slave<C: RequestConnection + ?Sized>(
+    conn: &C,
+    slave: DeviceId,
+    master: DeviceId,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let data = HierarchyChangeData::AttachSlave(HierarchyChangeDataAttachSlave {
+        deviceid: slave,
+        master,
+    });
+    change_hierarchy(conn, data, 2)
+}
+
+/// Detach a slave device from its master device, so that it becomes floating.
+pub fn detach_slave<C: RequestConnection + ?Sized>(
+    conn: &C,
+    slave: DeviceId,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let data = HierarchyChangeData::DetachSlave(HierarchyChangeDataDetachSlave { deviceid: slave });
+    change_hierarchy(conn, data, 2)
+}
+
+/// Select `Hierarchy` events on a window, usually the root window.
+pub fn select_hierarchy_events<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let mask = xinput::EventMask {
+        deviceid: xinput::Device::ALL.into(),
+        mask: vec![XIEventMask::HIERARCHY.into()],
+    };
+    xinput::xi_select_events(conn, window, &[mask])
+}
+
+/// A change of the device hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatChange {
+    /// A master device was added, e.g. via [`add_seat`].
+    MasterAdded(DeviceId),
+    /// A master device was removed.
+    MasterRemoved(DeviceId),
+    /// A slave device was plugged in.
+    SlaveAdded(DeviceId),
+    /// A slave device was unplugged.
+    SlaveRemoved(DeviceId),
+    /// A slave device was attached to a master device.
+    SlaveAttached {
+        /// The ID of the slave device.
+        slave: DeviceId,
+        /// The ID of the master device that it is now attached to.
+        master: DeviceId,
+    },
+    /// A slave device was detached and is now floating.
+    SlaveDetached(DeviceId),
+    /// A device was enabled.
+    Enabled(DeviceId),
+    /// A device was disabled.
+    Disabled(DeviceId),
+}
+
+/// Get the changes that a `Hierarchy` event reports.
+pub fn seat_changes(event: &HierarchyEvent) -> Vec<SeatChange> {
+    let mut changes = Vec::new();
+    for info in event.infos.iter() {
+        let flags = info.flags;
+        let has = |flag: HierarchyMask| flags & u32::from(flag) != 0;
+        let id = info.deviceid;
+        if has(HierarchyMask::MASTER_ADDED) {
+            changes.push(SeatChange::MasterAdded(id));
+        }
+        if has(HierarchyMask::SLAVE_ADDED) {
+            changes.push(SeatChange::SlaveAdded(id));
+        }
+        if has(HierarchyMask::SLAVE_DETACHED) {
+            changes.push(SeatChange::SlaveDetached(id));
+        }
+        if has(HierarchyMask::SLAVE_ATTACHED) {
+            changes.push(SeatChange::SlaveAttached {
+                slave: id,
+                master: info.attachment,
+            });
+        }
+        if has(HierarchyMask::DEVICE_ENABLED) {
+            changes.push(SeatChange::Enabled(id));
+        }
+        if has(HierarchyMask::DEVICE_DISABLED) {
+            changes.push(SeatChange::Disabled(id));
+        }
+        if has(HierarchyMask::SLAVE_REMOVED) {
+            changes.push(SeatChange::SlaveRemoved(id));
+        }
+        if has(HierarchyMask::MASTER_REMOVED) {
+            changes.push(SeatChange::MasterRemoved(id));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use super::{seat_changes, SeatChange, SeatTopology};
+    use crate::protocol::xinput::{
+        DeviceType, HierarchyEvent, HierarchyInfo, HierarchyMask, XIDeviceInfo, HIERARCHY_EVENT,
+    };
+
+    fn device(deviceid: u16, type_: DeviceType, attachment: u16, name: &str) -> XIDeviceInfo {
+        XIDeviceInfo {
+            deviceid,
+            type_,
+            attachment,
+            enabled: true,
+            name: name.as_bytes().to_vec(),
+            classes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn topology_from_devices() {
+        let devices = [
+            device(2, DeviceType::MASTER_POINTER, 3, "Virtual core pointer"),
+            device(3, DeviceType::MASTER_KEYBOARD, 2, "Virtual core keyboard"),
+            device(6, DeviceType::SLAVE_POINTER, 2, "Mouse A"),
+            device(7, DeviceType::SLAVE_KEYBOARD, 3, "Keyboard A"),
+            device(8, DeviceType::FLOATING_SLAVE, 0, "Mouse B"),
+            device(9, DeviceType::SLAVE_KEYBOARD, 11, "Keyboard B"),
+            device(11, DeviceType::MASTER_KEYBOARD, 10, "kiosk keyboard"),
+            device(10, DeviceType::MASTER_POINTER, 11, "kiosk pointer"),
+        ];
+        let topology = SeatTopology::from_devices(&devices);
+        assert_eq!(topology.floating, [8]);
+        let seats = topology
+            .seats
+            .iter()
+            .map(|seat| {
+                let slaves = seat
+                    .slave_pointers
+                    .iter()
+                    .chain(seat.slave_keyboards.iter())
+                    .map(|slave| slave.deviceid)
+                    .collect::<Vec<_>>();
+                (&seat.name[..], seat.pointer, seat.keyboard, slaves)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            seats,
+            [
+                ("Virtual core", 2, 3, vec![6, 7]),
+                ("kiosk", 10, 11, vec![9]),
+            ]
+        );
+        assert_eq!(topology.seat_of(9).map(|seat| seat.pointer), Some(10));
+        assert!(topology.seat_of(8).is_none());
+    }
+
+    #[test]
+    fn changes_from_event() {
+        let info = |deviceid, attachment, flags: HierarchyMask| HierarchyInfo {
+            deviceid,
+            attachment,
+            type_: DeviceType::SLAVE_POINTER,
+            enabled: true,
+            flags: u8::from(flags).into(),
+        };
+        let event = HierarchyEvent {
+            response_type: 35,
+            extension: 131,
+            sequence: 0,
+            length: 0,
+            event_type: HIERARCHY_EVENT,
+            deviceid: 0,
+            time: 0,
+            flags: 0,
+            infos: vec![
+                info(10, 11, HierarchyMask::MASTER_ADDED),
+                info(
+                    6,
+                    10,
+                    HierarchyMask::SLAVE_DETACHED | HierarchyMask::SLAVE_ATTACHED,
+                ),
+                info(8, 0, HierarchyMask::SLAVE_REMOVED),
+            ],
+        };
+        assert_eq!(
+            seat_changes(&event),
+            [
+                SeatChange::MasterAdded(10),
+                SeatChange::SlaveDetached(6),
+                SeatChange::SlaveAttached {
+                    slave: 6,
+                    master: 10
+                },
+                SeatChange::SlaveRemoved(8),
+            ]
+        );
+    }
+}
@@ -0,0 +1,600 @@
+//! The ICCCM selection protocol, which is used for the clipboard.
+//!
+//! Selections like `CLIPBOARD` and `PRIMARY` are owned by a window. Other clients ask the owner
+//! to convert the selection to some target type, e.g. `UTF8_STRING`, and the owner answers by
+//! writing the data into a property of the requestor's window. Large data is transferred in
+//! chunks via the `INCR` mechanism.
+//!
+//! [`SelectionOwner`] implements the owner side. It answers requests for `TARGETS`, `TIMESTAMP`,
+//! `MULTIPLE`, and all targets that were registered via [`SelectionOwner::register`].
+//! [`SelectionTransfer`] implements the requester side and reassembles `INCR` transfers.
+//!
+//! Both are driven by passing events to their `handle_event()` methods. For `INCR` transfers,
+//! the requester's window must have `PropertyChange` events selected.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{Atom, AtomEnum, Timestamp, Window};
+//! use x11rb::selection::{SelectionData, SelectionTransfer, TransferStatus};
+//!
+//! fn paste(
+//!     conn: &impl Connection,
+//!     window: Window,
+//!     clipboard: Atom,
+//!     utf8_string: Atom,
+//!     time: Timestamp,
+//! ) -> Result<Option<String>, ReplyError> {
+//!     let mut transfer = SelectionTransfer::start(conn, window, clipboard, utf8_string, time)?;
+//!     conn.flush()?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         match transfer.handle_event(conn, &event)? {
+//!             TransferStatus::Pending => {}
+//!             TransferStatus::Refused => return Ok(None),
+//!             TransferStatus::Finished(data) => {
+//!                 return Ok(Some(String::from_utf8_lossy(&data.data).into_owned()));
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangeWindowAttributesAux, EventMask, PropMode, Property,
+    SelectionNotifyEvent, SelectionRequestEvent, Timestamp, Window,
+};
+use crate::protocol::Event;
+use crate::{CURRENT_TIME, NONE};
+
+atom_manager! {
+    SelectionAtoms: SelectionAtomsCookie {
+        TARGETS,
+        MULTIPLE,
+        TIMESTAMP,
+        INCR,
+        ATOM_PAIR,
+        X11RB_SELECTION,
+    }
+}
+
+/// The size of the header of a `ChangeProperty` request.
+const CHANGE_PROPERTY_HEADER: usize = 24;
+
+/// The content of a selection, converted to some target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionData {
+    /// The type of the data, e.g. `UTF8_STRING` or `ATOM`.
+    pub type_: Atom,
+    /// The format of the data: 8, 16, or 32 bits per item.
+    pub format: u8,
+    /// The data in the native byte order.
+    pub data: Vec<u8>,
+}
+
+impl SelectionData {
+    /// Create selection data with format 8, e.g. for text.
+    pub fn new8(type_: impl Into<Atom>, data: Vec<u8>) -> Self {
+        Self {
+            type_: type_.into(),
+            format: 8,
+            data,
+        }
+    }
+
+    /// Create selection data with format 32, e.g. for lists of atoms.
+    pub fn new32(type_: impl Into<Atom>, data: &[u32]) -> Self {
+        Self {
+            type_: type_.into(),
+            format: 32,
+            data: data.iter().flat_map(|value| value.to_ne_bytes()).collect(),
+        }
+    }
+
+    /// Get the number of items in the data, as needed for `ChangeProperty`.
+    fn len_in_items(&self) -> u32 {
+        let item_size = usize::from(self.format / 8).max(1);
+        (self.data.len() / item_size) as u32
+    }
+}
+
+type Converter = Box<dyn FnMut() -> Option<SelectionData>>;
+
+/// An `INCR` transfer from the owner's side.
+#[derive(Debug, Clone)]
+struct OutgoingTransfer {
+    requestor: Window,
+    property: Atom,
+    type_: Atom,
+    format: u8,
+    data: Vec<u8>,
+    offset: usize,
+}
+
+/// The owner of a selection, answering conversion requests from other clients.
+pub struct SelectionOwner {
+    window: Window,
+    selection: Atom,
+    time: Timestamp,
+    atoms: SelectionAtoms,
+    converters: Vec<(Atom, Converter)>,
+    transfers: Vec<OutgoingTransfer>,
+    max_chunk_size: usize,
+    is_owner: bool,
+}
+
+impl fmt::Debug for SelectionOwner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let targets = self
+            .converters
+            .iter()
+            .map(|(target, _)| *target)
+            .collect::<Vec<_>>();
+        f.debug_struct("SelectionOwner")
+            .field("window", &self.window)
+            .field("selection", &self.selection)
+            .field("time", &self.time)
+            .field("targets", &targets)
+            .field("transfers", &self.transfers)
+            .field("is_owner", &self.is_owner)
+            .finish()
+    }
+}
+
+impl SelectionOwner {
+    /// Acquire ownership of a selection.
+    ///
+    /// `time` must be the timestamp of the event that caused this, not `CurrentTime`. Returns
+    /// `None` if the X11 server did not make `window` the owner, e.g. because `time` is older
+    /// than the time of the last change of the selection's owner.
+    pub fn acquire<C: Connection + ?Sized>(
+        conn: &C,
+        window: Window,
+        selection: Atom,
+        time: Timestamp,
+    ) -> Result<Option<Self>, ReplyError> {
+        let atoms = SelectionAtoms::new(conn)?;
+        let _ = xproto::set_selection_owner(conn, window, selection, time)?;
+        let owner = xproto::get_selection_owner(conn, selection)?;
+        let atoms = atoms.reply()?;
+        if owner.reply()?.owner != window {
+            return Ok(None);
+        }
+        let max_chunk_size = (conn.maximum_request_bytes() - CHANGE_PROPERTY_HEADER) & !3;
+        Ok(Some(Self {
+            window,
+            selection,
+            time,
+            atoms,
+            converters: Vec::new(),
+            transfers: Vec::new(),
+            max_chunk_size,
+            is_owner: true,
+        }))
+    }
+
+    /// Register a target that the selection can be converted to.
+    ///
+    /// The callback is called for every request for this target. Returning `None` refuses the
+    /// conversion. A previously registered callback for the same target is replaced.
+    pub fn register<F>(&mut self, target: Atom, converter: F)
+    where
+        F: FnMut() -> Option<SelectionData> + 'static,
+    {
+        self.converters.retain(|(t, _)| *t != target);
+        self.converters.push((target, Box::new(converter)));
+    }
+
+    /// Check if this client still owns the selection.
+    ///
+    /// This turns `false` when a `SelectionClear` event is handled.
+    pub fn is_owner(&self) -> bool {
+        self.is_owner
+    }
+
+    /// Give up ownership of the selection.
+    ///
+    /// Ongoing `INCR` transfers are still completed if events are passed to
+    /// [`handle_event()`](Self::handle_event).
+    pub fn release<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<(), ConnectionError> {
+        if self.is_owner {
+            self.is_owner = false;
+            let _ = xproto::set_selection_owner(conn, NONE, self.selection, self.time)?;
+        }
+        Ok(())
+    }
+
+    /// Handle `SelectionRequest`, `SelectionClear`, and `PropertyNotify` events.
+    ///
+    /// Returns whether the event was handled.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ReplyError> {
+        match event {
+            Event::SelectionRequest(event)
+                if event.owner == self.window && event.selection == self.selection =>
+            {
+                self.handle_request(conn, event)?;
+                Ok(true)
+            }
+            Event::SelectionClear(event)
+                if event.owner == self.window && event.selection == self.selection =>
+            {
+                self.is_owner = false;
+                Ok(true)
+            }
+            Event::PropertyNotify(event) if event.state == Property::DELETE => {
+                let index = self
+                    .transfers
+                    .iter()
+                    .position(|t| t.requestor == event.window && t.property == event.atom);
+                match index {
+                    Some(index) => {
+                        self.continue_transfer(conn, index)?;
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_request<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        request: &SelectionRequestEvent,
+    ) -> Result<(), ReplyError> {
+        // Obsolete clients use `None` as property
+        let property = if request.property == NONE {
+            request.target
+        } else {
+            request.property
+        };
+        let too_old = request.time != CURRENT_TIME && request.time < self.time;
+        let success = if !self.is_owner || too_old {
+            false
+        } else if request.target == self.atoms.MULTIPLE {
+            self.convert_multiple(conn, request.requestor, property)?
+        } else {
+            self.convert(conn, request.requestor, request.target, property)?
+        };
+        let event = SelectionNotifyEvent {
+            response_type: xproto::SELECTION_NOTIFY_EVENT,
+            sequence: 0,
+            time: request.time,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: if success { property } else { NONE },
+        };
+        let _ = xproto::send_event(conn, false, request.requestor, EventMask::NO_EVENT, event)?;
+        Ok(())
+    }
+
+    /// Convert all pairs of target and property listed in the `MULTIPLE` property.
+    fn convert_multiple<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        requestor: Window,
+        property: Atom,
+    ) -> Result<bool, ReplyError> {
+        let reply = xproto::get_property(
+            conn,
+            false,
+            requestor,
+            property,
+            self.atoms.ATOM_PAIR,
+            0,
+            u32::MAX,
+        )?
+        .reply()?;
+        let mut pairs = match reply.value32() {
+            Some(values) => values.collect::<Vec<_>>(),
+            None => return Ok(false),
+        };
+        for pair in pairs.chunks_exact_mut(2) {
+            let (target, property) = (pair[0], pair[1]);
+            let success = target != self.atoms.MULTIPLE
+                && property != NONE
+                && self.convert(conn, requestor, target, property)?;
+            if !success {
+                pair[1] = NONE;
+            }
+        }
+        let data = SelectionData::new32(self.atoms.ATOM_PAIR, &pairs);
+        self.write(conn, requestor, property, data)?;
+        Ok(true)
+    }
+
+    /// Convert the selection to a single target.
+    fn convert<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        requestor: Window,
+        target: Atom,
+        property: Atom,
+    ) -> Result<bool, ConnectionError> {
+        let data = if target == self.atoms.TARGETS {
+            Some(SelectionData::new32(AtomEnum::ATOM, &self.targets()))
+        } else if target == self.atoms.TIMESTAMP {
+            Some(SelectionData::new32(AtomEnum::INTEGER, &[self.time]))
+        } else {
+            self.converters
+                .iter_mut()
+                .find(|(t, _)| *t == target)
+                .and_then(|(_, converter)| converter())
+        };
+        match data {
+            Some(data) => {
+                self.write(conn, requestor, property, data)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Get the list of supported targets, as sent in reply to `TARGETS`.
+    fn targets(&self) -> Vec<Atom> {
+        let mut targets = vec![
+            self.atoms.TARGETS,
+            self.atoms.MULTIPLE,
+            self.atoms.TIMESTAMP,
+        ];
+        targets.extend(self.converters.iter().map(|(target, _)| *target));
+        targets
+    }
+
+    /// Write the converted data into the requestor's property, using `INCR` if it is too large.
+    fn write<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        requestor: Window,
+        property: Atom,
+        data: SelectionData,
+    ) -> Result<(), ConnectionError> {
+        if data.data.len() <= self.max_chunk_size {
+            let _ = xproto::change_property(
+                conn,
+                PropMode::REPLACE,
+                requestor,
+                property,
+                data.type_,
+                data.format,
+                data.len_in_items(),
+                &data.data,
+            )?;
+            return Ok(());
+        }
+        // The requestor starts the transfer by deleting the INCR property
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+        let _ = xproto::change_window_attributes(conn, requestor, &aux)?;
+        let size = u32::try_from(data.data.len()).unwrap_or(u32::MAX);
+        let _ = xproto::change_property(
+            conn,
+            PropMode::REPLACE,
+            requestor,
+            property,
+            self.atoms.INCR,
+            32,
+            1,
+            &size.to_ne_bytes(),
+        )?;
+        self.transfers
+            .retain(|t| t.requestor != requestor || t.property != property);
+        self.transfers.push(OutgoingTransfer {
+            requestor,
+            property,
+            type_: data.type_,
+            format: data.format,
+            data: data.data,
+            offset: 0,
+        });
+        Ok(())
+    }
+
+    /// Send the next chunk of an `INCR` transfer after the requestor deleted the property.
+    fn continue_transfer<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        index: usize,
+    ) -> Result<(), ConnectionError> {
+        let transfer = &mut self.transfers[index];
+        let chunk = next_chunk(&transfer.data, transfer.offset, self.max_chunk_size);
+        let item_size = usize::from(transfer.format / 8).max(1);
+        let _ = xproto::change_property(
+            conn,
+            PropMode::REPLACE,
+            transfer.requestor,
+            transfer.property,
+            transfer.type_,
+            transfer.format,
+            (chunk.len() / item_size) as u32,
+            chunk,
+        )?;
+        transfer.offset += chunk.len();
+        // The transfer ends with a zero-length property
+        if chunk.is_empty() {
+            let transfer = self.transfers.remove(index);
+            let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::NO_EVENT);
+            let _ = xproto::change_window_attributes(conn, transfer.requestor, &aux)?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the chunk of `data` that starts at `offset` and is at most `max_size` bytes long.
+fn next_chunk(data: &[u8], offset: usize, max_size: usize) -> &[u8] {
+    let end = data.len().min(offset.saturating_add(max_size));
+    &data[offset.min(end)..end]
+}
+
+/// The state of a [`SelectionTransfer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// The transfer is still running.
+    Pending,
+    /// The owner refused the conversion, or there is no owner.
+    Refused,
+    /// The transfer finished with the given data.
+    Finished(SelectionData),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferState {
+    WaitingForNotify,
+    Incr,
+    Done,
+}
+
+/// A request for the content of a selection.
+#[derive(Debug, Clone)]
+pub struct SelectionTransfer {
+    window: Window,
+    selection: Atom,
+    property: Atom,
+    incr: Atom,
+    state: TransferState,
+    data: Option<SelectionData>,
+}
+
+impl SelectionTransfer {
+    /// Ask the owner of a selection to convert it to the given target.
+    ///
+    /// The data is written to the `X11RB_SELECTION` property of `window`. For `INCR` transfers,
+    /// `window` must have `PropertyChange` events selected. `time` should be the timestamp of the
+    /// event that caused the request.
+    pub fn start<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+        selection: Atom,
+        target: Atom,
+        time: Timestamp,
+    ) -> Result<Self, ReplyError> {
+        let atoms = SelectionAtoms::new(conn)?.reply()?;
+        let property = atoms.X11RB_SELECTION;
+        let _ = xproto::convert_selection(conn, window, selection, target, property, time)?;
+        Ok(Self {
+            window,
+            selection,
+            property,
+            incr: atoms.INCR,
+            state: TransferState::WaitingForNotify,
+            data: None,
+        })
+    }
+
+    /// Handle `SelectionNotify` and `PropertyNotify` events for this transfer.
+    ///
+    /// Once the transfer is finished, it ignores all further events and keeps returning the same
+    /// result.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<TransferStatus, ReplyError> {
+        match (self.state, event) {
+            (TransferState::WaitingForNotify, Event::SelectionNotify(event))
+                if event.requestor == self.window && event.selection == self.selection =>
+            {
+                if event.property == NONE {
+                    self.state = TransferState::Done;
+                    return Ok(self.status());
+                }
+                let data = self.read_property(conn)?;
+                if data.type_ == self.incr {
+                    // Deleting the property while reading it started the transfer
+                    self.state = TransferState::Incr;
+                } else {
+                    self.state = TransferState::Done;
+                    self.data = Some(data);
+                }
+            }
+            (TransferState::Incr, Event::PropertyNotify(event))
+                if event.window == self.window
+                    && event.atom == self.property
+                    && event.state == Property::NEW_VALUE =>
+            {
+                let chunk = self.read_property(conn)?;
+                if chunk.data.is_empty() {
+                    self.state = TransferState::Done;
+                    if self.data.is_none() {
+                        self.data = Some(chunk);
+                    }
+                } else {
+                    match &mut self.data {
+                        Some(data) => data.data.extend_from_slice(&chunk.data),
+                        None => self.data = Some(chunk),
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(self.status())
+    }
+
+    /// Get the current state of the transfer.
+    pub fn status(&self) -> TransferStatus {
+        match (self.state, &self.data) {
+            (TransferState::Done, Some(data)) => TransferStatus::Finished(data.clone()),
+            (TransferState::Done, None) => TransferStatus::Refused,
+            _ => TransferStatus::Pending,
+        }
+    }
+
+    /// Read and delete the property that the owner wrote to.
+    fn read_property<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+    ) -> Result<SelectionData, ReplyError> {
+        let reply = xproto::get_property(
+            conn,
+            true,
+            self.window,
+            self.property,
+            AtomEnum::ANY,
+            0,
+            u32::MAX,
+        )?
+        .reply()?;
+        Ok(SelectionData {
+            type_: reply.type_,
+            format: reply.format,
+            data: reply.value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{next_chunk, SelectionData};
+
+    #[test]
+    fn chunks() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(next_chunk(&data, 0, 4), [1, 2, 3, 4]);
+        assert_eq!(next_chunk(&data, 4, 4), [5]);
+        assert_eq!(next_chunk(&data, 5, 4), []);
+        assert_eq!(next_chunk(&data, 8, 4), []);
+    }
+
+    #[test]
+    fn selection_data_items() {
+        let data = SelectionData::new32(4u32, &[1, 2]);
+        assert_eq!(data.format, 32);
+        assert_eq!(data.len_in_items(), 2);
+        assert_eq!(data.data.len(), 8);
+        let data = SelectionData::new8(31u32, b"text".to_vec());
+        assert_eq!(data.len_in_items(), 4);
+    }
+}
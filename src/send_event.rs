@@ -0,0 +1,236 @@
+//! A typed interface for `SendEvent` that avoids the common mistakes.
+//!
+//! `SendEvent` has a few traps. The event mask decides who receives the event: An empty mask
+//! sends it to the client that created the destination window, while a non-empty mask sends it to
+//! the clients that selected these events, and nobody might have. The `propagate` flag only
+//! matters for a non-empty mask. Messages for the window manager have to go to the root window with
+//! the `SubstructureRedirect` mask, even though they are about another window, which has to be
+//! named in the event. And the event's header has to be filled in correctly.
+//!
+//! [`send`] takes a [`Destination`] and a [`Delivery`] that spell out these choices. The functions
+//! [`send_to_root`] and [`send_protocol_message`] implement the two most common patterns, i.e.
+//! the EWMH requests to the window manager and the ICCCM `WM_PROTOCOLS` messages.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, Window};
+//! use x11rb::send_event::{client_message32, send_to_root};
+//!
+//! fn minimize(conn: &impl Connection, root: Window, window: Window) -> Result<(), ReplyError> {
+//!     let wm_change_state = conn.intern_atom(false, b"WM_CHANGE_STATE")?.reply()?.atom;
+//!     // 3 is IconicState
+//!     let event = client_message32(window, wm_change_state, [3, 0, 0, 0, 0]);
+//!     send_to_root(conn, root, event)?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ParseError};
+use crate::protocol::xproto::{self, Atom, ClientMessageEvent, EventMask, Timestamp, Window};
+
+/// The window that an event is sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Destination {
+    /// The given window.
+    Window(Window),
+    /// The window that contains the pointer.
+    PointerWindow,
+    /// The focus window, or the window that contains the pointer if it is inside the focus window.
+    InputFocus,
+}
+
+impl From<Window> for Destination {
+    fn from(window: Window) -> Self {
+        Destination::Window(window)
+    }
+}
+
+impl From<Destination> for Window {
+    fn from(destination: Destination) -> Window {
+        match destination {
+            Destination::Window(window) => window,
+            Destination::PointerWindow => 0,
+            Destination::InputFocus => 1,
+        }
+    }
+}
+
+/// Which clients receive a sent event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Send the event to the client that created the destination window.
+    ///
+    /// This is how messages to a specific application are sent, e.g. `WM_PROTOCOLS` messages.
+    Owner,
+    /// Send the event to the clients that selected any of the given events on the destination
+    /// window.
+    ///
+    /// If nobody selected them, the event is lost. An empty mask behaves like
+    /// [`Delivery::Owner`].
+    Selected(EventMask),
+    /// Like [`Delivery::Selected`], but if nobody selected the events on the destination window,
+    /// the event propagates to its ancestors like an input event.
+    ///
+    /// An empty mask behaves like [`Delivery::Owner`], since propagation needs an event mask.
+    Propagate(EventMask),
+}
+
+impl Delivery {
+    /// Get the `propagate` flag and the event mask of the `SendEvent` request.
+    fn flags(self) -> (bool, EventMask) {
+        match self {
+            Delivery::Owner => (false, EventMask::NO_EVENT),
+            Delivery::Selected(mask) => (false, mask),
+            Delivery::Propagate(mask) => (u32::from(mask) != 0, mask),
+        }
+    }
+}
+
+/// Send an event.
+///
+/// The header of the event is cleaned up before sending it: The sent-event flag is cleared and the
+/// sequence number is set to zero, since the X11 server fills in both. This makes it safe to
+/// forward an event that was received. Events with the code of an error or a reply are refused
+/// with [`ParseError::InvalidValue`] instead of being sent, since the X11 server would respond
+/// with a `Value` error.
+pub fn send<'c, C, D, E>(
+    conn: &'c C,
+    destination: D,
+    delivery: Delivery,
+    event: E,
+) -> Result<VoidCookie<'c, C>, ConnectionError>
+where
+    C: RequestConnection + ?Sized,
+    D: Into<Destination>,
+    E: Into<[u8; 32]>,
+{
+    let mut event = event.into();
+    event[0] &= 0x7f;
+    if event[0] < 2 {
+        return Err(ParseError::InvalidValue.into());
+    }
+    event[2..4].copy_from_slice(&[0, 0]);
+    let (propagate, mask) = delivery.flags();
+    xproto::send_event(conn, propagate, destination.into(), mask, event)
+}
+
+/// Build a client message with 32-bit data.
+///
+/// This is the format of nearly all ICCCM and EWMH messages. `window` is the window that the
+/// message is about; it does not have to be the window that the message is sent to.
+pub fn client_message32(window: Window, type_: Atom, data: [u32; 5]) -> ClientMessageEvent {
+    client_message(window, type_, 32, data.into())
+}
+
+/// Build a client message with 16-bit data.
+pub fn client_message16(window: Window, type_: Atom, data: [u16; 10]) -> ClientMessageEvent {
+    client_message(window, type_, 16, data.into())
+}
+
+/// Build a client message with 8-bit data.
+pub fn client_message8(window: Window, type_: Atom, data: [u8; 20]) -> ClientMessageEvent {
+    client_message(window, type_, 8, data.into())
+}
+
+fn client_message(
+    window: Window,
+    type_: Atom,
+    format: u8,
+    data: xproto::ClientMessageData,
+) -> ClientMessageEvent {
+    ClientMessageEvent {
+        response_type: xproto::CLIENT_MESSAGE_EVENT,
+        format,
+        sequence: 0,
+        window,
+        type_,
+        data,
+    }
+}
+
+/// Send a client message to the window manager.
+///
+/// The message is sent to the root window with the `SubstructureNotify` and
+/// `SubstructureRedirect` event masks, as required by the EWMH specification. The window that the
+/// message is about is the `window` field of the message.
+pub fn send_to_root<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    message: ClientMessageEvent,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let mask = EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT;
+    send(conn, root, Delivery::Selected(mask), message)
+}
+
+/// Send a `WM_PROTOCOLS` message to the application that owns a window.
+///
+/// `protocol` is e.g. `WM_DELETE_WINDOW`. The message contains the protocol, the timestamp and up
+/// to three more values, as described by the ICCCM.
+pub fn send_protocol_message<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    wm_protocols: Atom,
+    protocol: Atom,
+    time: Timestamp,
+    extra: [u32; 3],
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    let data = [protocol, time, extra[0], extra[1], extra[2]];
+    let message = client_message32(window, wm_protocols, data);
+    send(conn, window, Delivery::Owner, message)
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{client_message16, send, send_to_root, Delivery, Destination};
+    use crate::errors::{ConnectionError, ParseError};
+    use crate::protocol::xproto::{EventMask, ExposeEvent, SEND_EVENT_REQUEST};
+    use crate::testing::FakeConnection;
+
+    #[test]
+    fn header_is_cleaned() {
+        let conn = FakeConnection::default();
+        let event = ExposeEvent {
+            response_type: 0x80 | 12,
+            sequence: 42,
+            window: 7,
+            x: 0,
+            y: 0,
+            width: 1,
+            height: 1,
+            count: 0,
+        };
+        let _ = send(&conn, Destination::InputFocus, Delivery::Owner, event).unwrap();
+        let sent = conn.sent_requests();
+        let request = &sent[0].bytes;
+        assert_eq!(request[0], SEND_EVENT_REQUEST);
+        // propagate, destination, event mask
+        assert_eq!(request[1], 0);
+        assert_eq!(request[4..12], [1, 0, 0, 0, 0, 0, 0, 0][..]);
+        assert_eq!(request[12..16], [12, 0, 0, 0][..]);
+
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        match send(&conn, 7, Delivery::Owner, reply) {
+            Err(ConnectionError::ParseError(ParseError::InvalidValue)) => {}
+            r => panic!("Unexpected result: {:?}", r),
+        };
+    }
+
+    #[test]
+    fn root_message_mask() {
+        let conn = FakeConnection::default();
+        let message = client_message16(5, 9, [1; 10]);
+        let _ = send_to_root(&conn, 3, message).unwrap();
+        let sent = conn.sent_requests();
+        let request = &sent[0].bytes;
+        let mask = u32::from(EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT);
+        assert_eq!(request[4..8], 3u32.to_ne_bytes()[..]);
+        assert_eq!(request[8..12], mask.to_ne_bytes()[..]);
+        // format and window of the client message
+        assert_eq!(request[13], 16);
+        assert_eq!(request[16..20], 5u32.to_ne_bytes()[..]);
+    }
+}
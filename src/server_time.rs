@@ -0,0 +1,264 @@
+//! Converting between X11 server timestamps and [`Instant`]s.
+//!
+//! Events like `KeyPress` carry the time of the X11 server in milliseconds, which has an unknown
+//! offset to the clocks of the client. Measuring input latency or scheduling animations relative
+//! to input requires both in the same time domain. [`ServerClock`] estimates the offset by
+//! probing the server time: It appends nothing to a property, which makes the X11 server send a
+//! `PropertyNotify` event with the current server time. The probe with the shortest round trip
+//! gives the best estimate.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ChangeWindowAttributesAux, ConnectionExt as _, EventMask, Window};
+//! use x11rb::protocol::Event;
+//! use x11rb::server_time::ServerClock;
+//!
+//! fn latency(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+//!     let aux = ChangeWindowAttributesAux::new()
+//!         .event_mask(EventMask::PROPERTY_CHANGE | EventMask::KEY_PRESS);
+//!     conn.change_window_attributes(window, &aux)?;
+//!     let property = conn.intern_atom(false, b"_X11RB_TIME_PROBE")?.reply()?.atom;
+//!     let mut clock = ServerClock::new(window, property);
+//!     clock.calibrate(conn, 5)?;
+//!     loop {
+//!         if let Event::KeyPress(event) = conn.wait_for_event()? {
+//!             if let Some(pressed) = clock.to_instant(event.time) {
+//!                 println!("Key press took {:?} to arrive", pressed.elapsed());
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The server time wraps around after about 49.7 days. Conversions are done relative to the last
+//! probe and work for times up to 24.8 days away from it.
+
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, Atom, AtomEnum, PropMode, Timestamp, Window};
+use crate::protocol::Event;
+
+/// A point in time that is known in both time domains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sample {
+    instant: Instant,
+    time: Timestamp,
+    round_trip: Duration,
+}
+
+/// An estimate of the offset between the X11 server time and [`Instant`].
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerClock {
+    window: Window,
+    property: Atom,
+    /// When the last probe was sent, if its event did not arrive yet.
+    pending: Option<Instant>,
+    best: Option<Sample>,
+}
+
+impl ServerClock {
+    /// Create a new clock that probes via the given property of the given window.
+    ///
+    /// `PropertyChange` events have to be selected on the window. The property's value is not
+    /// changed by probes, but it is created if it does not exist yet.
+    pub fn new(window: Window, property: Atom) -> Self {
+        Self {
+            window,
+            property,
+            pending: None,
+            best: None,
+        }
+    }
+
+    /// Send a probe for the current server time.
+    ///
+    /// The resulting `PropertyNotify` event has to be passed to [`ServerClock::handle_event`].
+    pub fn probe<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        let _ = xproto::change_property(
+            conn,
+            PropMode::APPEND,
+            self.window,
+            self.property,
+            AtomEnum::STRING,
+            8,
+            0,
+            &[],
+        )?;
+        conn.flush()?;
+        self.pending = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Handle the `PropertyNotify` event of a probe.
+    ///
+    /// Returns `true` if the event belonged to a probe.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::PropertyNotify(event)
+                if event.window == self.window && event.atom == self.property =>
+            {
+                if let Some(sent) = self.pending.take() {
+                    self.add_sample(sent, Instant::now(), event.time);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Send the given number of probes and wait for their events.
+    ///
+    /// All other events that arrive in the meantime are discarded, so this should be called
+    /// before the application starts handling events, or on a separate connection.
+    pub fn calibrate<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        probes: usize,
+    ) -> Result<(), ReplyError> {
+        for _ in 0..probes {
+            self.probe(conn)?;
+            while !self.handle_event(&conn.wait_for_event()?) {}
+        }
+        Ok(())
+    }
+
+    /// Add a measurement of the server time.
+    ///
+    /// `time` is a server time that was generated after `sent` and received at `received`. The
+    /// measurement replaces the current estimate if its round trip is shorter.
+    pub fn add_sample(&mut self, sent: Instant, received: Instant, time: Timestamp) {
+        let round_trip = received.saturating_duration_since(sent);
+        if let Some(best) = self.best {
+            if best.round_trip <= round_trip {
+                return;
+            }
+        }
+        self.best = Some(Sample {
+            instant: sent + round_trip / 2,
+            time,
+            round_trip,
+        });
+    }
+
+    /// Was the offset estimated yet?
+    pub fn is_calibrated(&self) -> bool {
+        self.best.is_some()
+    }
+
+    /// Get the maximal error of the conversions, if the offset was estimated.
+    ///
+    /// This is half of the shortest round trip plus the one millisecond resolution of the
+    /// server time.
+    pub fn uncertainty(&self) -> Option<Duration> {
+        self.best
+            .map(|best| best.round_trip / 2 + Duration::from_millis(1))
+    }
+
+    /// Convert a server time into an [`Instant`].
+    ///
+    /// Returns `None` if the offset was not estimated yet or if the [`Instant`] cannot be
+    /// represented.
+    pub fn to_instant(&self, time: Timestamp) -> Option<Instant> {
+        let best = self.best?;
+        // Interpreting the difference as signed handles the wrap around of the server time
+        let diff = time.wrapping_sub(best.time) as i32;
+        let offset = Duration::from_millis(u64::from(diff.unsigned_abs()));
+        if diff >= 0 {
+            best.instant.checked_add(offset)
+        } else {
+            best.instant.checked_sub(offset)
+        }
+    }
+
+    /// Convert an [`Instant`] into a server time.
+    ///
+    /// Returns `None` if the offset was not estimated yet.
+    pub fn to_timestamp(&self, instant: Instant) -> Option<Timestamp> {
+        let best = self.best?;
+        let time = if instant >= best.instant {
+            let millis = (instant - best.instant).as_millis() as u32;
+            best.time.wrapping_add(millis)
+        } else {
+            let millis = (best.instant - instant).as_millis() as u32;
+            best.time.wrapping_sub(millis)
+        };
+        Some(time)
+    }
+
+    /// Get an estimate of the current server time.
+    ///
+    /// This can be used for requests that need a real timestamp instead of `CurrentTime`.
+    pub fn now(&self) -> Option<Timestamp> {
+        self.to_timestamp(Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::ServerClock;
+    use crate::protocol::xproto::{Property, PropertyNotifyEvent};
+    use crate::protocol::Event;
+
+    #[test]
+    fn best_sample_wins() {
+        let mut clock = ServerClock::new(1, 2);
+        assert!(!clock.is_calibrated());
+        assert_eq!(clock.to_timestamp(Instant::now()), None);
+
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        clock.add_sample(start, start + ms(10), 1000);
+        assert_eq!(clock.uncertainty(), Some(ms(6)));
+        clock.add_sample(start + ms(100), start + ms(102), 1200);
+        // A sample with a longer round trip is ignored
+        clock.add_sample(start + ms(200), start + ms(220), 5000);
+        assert_eq!(clock.uncertainty(), Some(ms(2)));
+
+        assert_eq!(clock.to_timestamp(start + ms(101)), Some(1200));
+        assert_eq!(clock.to_timestamp(start + ms(51)), Some(1150));
+        assert_eq!(clock.to_instant(1300), Some(start + ms(201)));
+        assert_eq!(clock.to_instant(1190), Some(start + ms(91)));
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut clock = ServerClock::new(1, 2);
+        let start = Instant::now();
+        clock.add_sample(start, start, u32::MAX - 9);
+        let later = start + Duration::from_millis(20);
+        assert_eq!(clock.to_timestamp(later), Some(10));
+        assert_eq!(clock.to_instant(10), Some(later));
+    }
+
+    #[test]
+    fn probe_event() {
+        let mut clock = ServerClock::new(1, 2);
+        let event = |window, atom| {
+            Event::PropertyNotify(PropertyNotifyEvent {
+                response_type: 28,
+                sequence: 0,
+                window,
+                atom,
+                time: 1234,
+                state: Property::NEW_VALUE,
+            })
+        };
+        assert!(!clock.handle_event(&event(1, 3)));
+        assert!(!clock.handle_event(&event(4, 2)));
+        // Events without a pending probe do not change the estimate
+        assert!(clock.handle_event(&event(1, 2)));
+        assert!(!clock.is_calibrated());
+
+        clock.pending = Some(Instant::now());
+        assert!(clock.handle_event(&event(1, 2)));
+        assert!(clock.is_calibrated());
+        assert!(clock.to_instant(1234).is_some());
+    }
+}
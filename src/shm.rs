@@ -0,0 +1,351 @@
+//! Shared memory segments for the MIT-SHM extension.
+//!
+//! The MIT-SHM extension allows to transfer images via memory that is shared between the client
+//! and the X11 server, which avoids copying the image data through the socket. Creating such a
+//! segment, attaching it to the X11 server, and cleaning up afterwards involves some unsafe code
+//! and differs between versions of the extension. [`ShmSegment`] takes care of this.
+//!
+//! This module is only available when the `shm` and `allow-unsafe-code` features are enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::protocol::shm::ConnectionExt as _;
+//! use x11rb::protocol::xproto::{Drawable, Gcontext, ImageFormat};
+//! use x11rb::shm::ShmSegment;
+//!
+//! fn draw(conn: &impl Connection, drawable: Drawable, gc: Gcontext, depth: u8)
+//!     -> Result<(), ReplyOrIdError>
+//! {
+//!     let (width, height) = (100, 100);
+//!     let mut segment = ShmSegment::new(conn, 4 * width * height, true)?;
+//!     // Fill the image with white pixels
+//!     segment.fill(0xff);
+//!     let _ = conn.shm_put_image(
+//!         drawable, gc, width as _, height as _, 0, 0, width as _, height as _, 0, 0,
+//!         depth, ImageFormat::Z_PIXMAP.into(), false, segment.seg(), 0,
+//!     )?;
+//!     // The segment is detached when it is dropped. This is only safe since `ShmPutImage` is
+//!     // guaranteed to be processed before `Detach`.
+//!     Ok(())
+//! }
+//! ```
+
+use std::fs::File;
+use std::io::Error as IOError;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::ptr::{null, null_mut, NonNull};
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::protocol::shm;
+
+/// A shared memory segment that is attached to the X11 server.
+///
+/// The memory is accessed via [`ShmSegment::read`] and [`ShmSegment::write`]. The segment is
+/// detached from the X11 server and unmapped when this object is dropped.
+///
+/// Note that the X11 server writes into the segment asynchronously when a request like
+/// `ShmGetImage` is processed. The content of the segment is only meaningful after the reply to
+/// such a request was received or after synchronising with the X11 server. Since the memory can
+/// change at any time, it is not available as a plain byte slice; the accessors use volatile
+/// reads and writes instead. [`ShmSegment::as_slice`] and [`ShmSegment::as_mut_slice`] provide
+/// slices for callers that can guarantee that the X11 server does not access the segment.
+#[derive(Debug)]
+pub struct ShmSegment<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    seg: shm::Seg,
+    mapping: Mapping,
+}
+
+impl<'c, C: Connection + ?Sized> ShmSegment<'c, C> {
+    /// Create a new shared memory segment of the given size and attach it to the X11 server.
+    ///
+    /// If the X11 server supports version 1.2 of the MIT-SHM extension, the segment is created
+    /// via `memfd_create()` and its file descriptor is sent via `AttachFd`. Otherwise, or on
+    /// systems without `memfd_create()`, a System V shared memory segment is created via
+    /// `shmget()` and attached via `Attach`. The latter only works if the X11 server runs on the
+    /// same machine.
+    ///
+    /// If `read_only` is true, the X11 server can only read from the segment.
+    ///
+    /// This returns [`ConnectionError::UnsupportedExtension`] if the X11 server does not support
+    /// the MIT-SHM extension. Since this function waits for the X11 server to attach the segment,
+    /// failing to attach it results in an error as well.
+    pub fn new(conn: &'c C, size: usize, read_only: bool) -> Result<Self, ReplyOrIdError> {
+        if conn
+            .extension_information(shm::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Err(ConnectionError::UnsupportedExtension.into());
+        }
+        let version = shm::query_version(conn)?.reply()?;
+        let supports_fds = (version.major_version, version.minor_version) >= (1, 2);
+        let seg = conn.generate_id()?;
+
+        let mapping = if supports_fds && cfg!(target_os = "linux") {
+            let (file, mapping) = Mapping::memfd(size).map_err(ConnectionError::from)?;
+            shm::attach_fd(conn, seg, file, read_only)?.check()?;
+            mapping
+        } else {
+            let (shmid, mapping) = Mapping::sysv(size).map_err(ConnectionError::from)?;
+            let result =
+                shm::attach(conn, seg, shmid as u32, read_only).map(|cookie| cookie.check());
+            // The segment is destroyed once everyone detached from it
+            let _ = unsafe { libc::shmctl(shmid, libc::IPC_RMID, null_mut()) };
+            result??;
+            mapping
+        };
+        Ok(Self { conn, seg, mapping })
+    }
+
+    /// Get the `Seg` that identifies this segment in requests.
+    pub fn seg(&self) -> shm::Seg {
+        self.seg
+    }
+
+    /// Get the size of the segment in bytes.
+    pub fn len(&self) -> usize {
+        self.mapping.len
+    }
+
+    /// Is the segment empty?
+    pub fn is_empty(&self) -> bool {
+        self.mapping.len == 0
+    }
+
+    /// Copy bytes from the segment, starting at `offset`, into `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is outside of the segment.
+    pub fn read(&self, offset: usize, buf: &mut [u8]) {
+        self.mapping.read(offset, buf)
+    }
+
+    /// Copy `data` into the segment, starting at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is outside of the segment.
+    pub fn write(&mut self, offset: usize, data: &[u8]) {
+        self.mapping.write(offset, data)
+    }
+
+    /// Set all bytes of the segment to `value`.
+    pub fn fill(&mut self, value: u8) {
+        self.mapping.fill(value)
+    }
+
+    /// Get the content of the segment as a byte slice.
+    ///
+    /// # Safety
+    ///
+    /// The X11 server must not write into the segment while the slice exists, e.g. because no
+    /// request that writes into it is sent or because their replies were already received.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        std::slice::from_raw_parts(self.mapping.ptr.as_ptr(), self.mapping.len)
+    }
+
+    /// Get the content of the segment as a mutable byte slice.
+    ///
+    /// # Safety
+    ///
+    /// The X11 server must not access the segment while the slice exists, e.g. because no request
+    /// that uses it is sent or because all such requests were already processed.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        std::slice::from_raw_parts_mut(self.mapping.ptr.as_ptr(), self.mapping.len)
+    }
+
+    /// Detach the segment from the X11 server and report any errors.
+    ///
+    /// This is the same as dropping this object, except that errors are not ignored. The `Detach`
+    /// request is flushed, so that the X11 server releases the segment right away. The memory is
+    /// unmapped in any case.
+    pub fn detach(self) -> Result<(), ConnectionError> {
+        let result = shm::detach(self.conn, self.seg).and_then(|_| self.conn.flush());
+        let mut this = std::mem::ManuallyDrop::new(self);
+        // Only drop the mapping, since dropping `self` would detach the segment again
+        unsafe { std::ptr::drop_in_place(&mut this.mapping) };
+        result
+    }
+}
+
+impl<C: Connection + ?Sized> Drop for ShmSegment<'_, C> {
+    fn drop(&mut self) {
+        let _ = shm::detach(self.conn, self.seg).and_then(|_| self.conn.flush());
+    }
+}
+
+/// Memory that is mapped into our address space and unmapped when this is dropped.
+#[derive(Debug)]
+struct Mapping {
+    ptr: NonNull<u8>,
+    len: usize,
+    /// The memory was mapped via `shmat()` instead of `mmap()`.
+    sysv: bool,
+}
+
+impl Mapping {
+    /// Create an anonymous file via `memfd_create()` and map it.
+    #[cfg(target_os = "linux")]
+    fn memfd(size: usize) -> Result<(File, Self), IOError> {
+        let fd = unsafe { libc::memfd_create(b"x11rb-shm\0".as_ptr().cast(), libc::MFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(IOError::last_os_error());
+        }
+        let file = unsafe { File::from_raw_fd(fd) };
+        file.set_len(size as u64)?;
+        let ptr = unsafe {
+            libc::mmap(
+                null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(IOError::last_os_error());
+        }
+        let mapping = Self {
+            ptr: NonNull::new(ptr.cast()).unwrap(),
+            len: size,
+            sysv: false,
+        };
+        Ok((file, mapping))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn memfd(_size: usize) -> Result<(File, Self), IOError> {
+        unreachable!("memfd_create() is only used on Linux")
+    }
+
+    /// Create a System V shared memory segment and attach it.
+    ///
+    /// The caller has to mark the segment for removal via `IPC_RMID`.
+    fn sysv(size: usize) -> Result<(libc::c_int, Self), IOError> {
+        let shmid = unsafe { libc::shmget(libc::IPC_PRIVATE, size, libc::IPC_CREAT | 0o600) };
+        if shmid < 0 {
+            return Err(IOError::last_os_error());
+        }
+        let ptr = unsafe { libc::shmat(shmid, null(), 0) };
+        if ptr as isize == -1 {
+            let error = IOError::last_os_error();
+            let _ = unsafe { libc::shmctl(shmid, libc::IPC_RMID, null_mut()) };
+            return Err(error);
+        }
+        let mapping = Self {
+            ptr: NonNull::new(ptr.cast()).unwrap(),
+            len: size,
+            sysv: true,
+        };
+        Ok((shmid, mapping))
+    }
+
+    /// Get a pointer to the byte at `offset`, checking that `len` bytes from there are mapped.
+    fn range(&self, offset: usize, len: usize) -> *mut u8 {
+        let end = offset.checked_add(len);
+        assert!(
+            matches!(end, Some(end) if end <= self.len),
+            "Range {}+{} is outside of the segment of size {}",
+            offset,
+            len,
+            self.len
+        );
+        // The memory can be changed by another process at any time, so it is only accessed via
+        // volatile reads and writes through raw pointers
+        unsafe { self.ptr.as_ptr().add(offset) }
+    }
+
+    fn read(&self, offset: usize, buf: &mut [u8]) {
+        let ptr = self.range(offset, buf.len());
+        for (index, byte) in buf.iter_mut().enumerate() {
+            *byte = unsafe { ptr.add(index).read_volatile() };
+        }
+    }
+
+    fn write(&mut self, offset: usize, data: &[u8]) {
+        let ptr = self.range(offset, data.len());
+        for (index, byte) in data.iter().enumerate() {
+            unsafe { ptr.add(index).write_volatile(*byte) };
+        }
+    }
+
+    fn fill(&mut self, value: u8) {
+        let ptr = self.range(0, self.len);
+        for index in 0..self.len {
+            unsafe { ptr.add(index).write_volatile(value) };
+        }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        let ptr = self.ptr.as_ptr().cast();
+        if self.sysv {
+            let _ = unsafe { libc::shmdt(ptr) };
+        } else {
+            let _ = unsafe { libc::munmap(ptr, self.len) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ptr::null_mut;
+
+    use super::Mapping;
+
+    fn fill(mapping: &mut Mapping) {
+        let data = (0..mapping.len)
+            .map(|index| index as u8)
+            .collect::<Vec<_>>();
+        mapping.write(0, &data);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn memfd_mapping_is_shared() {
+        use std::io::Read;
+
+        let (mut file, mut mapping) = Mapping::memfd(1000).unwrap();
+        fill(&mut mapping);
+        let mut content = Vec::new();
+        let _ = file.read_to_end(&mut content).unwrap();
+        assert_eq!(content.len(), 1000);
+        assert!(content.iter().enumerate().all(|(i, &b)| b == i as u8));
+    }
+
+    #[test]
+    fn sysv_mapping() {
+        let (shmid, mut mapping) = match Mapping::sysv(1000) {
+            Ok(result) => result,
+            // System V shared memory might not be available in sandboxes
+            Err(_) => return,
+        };
+        let _ = unsafe { libc::shmctl(shmid, libc::IPC_RMID, null_mut()) };
+        assert_eq!(mapping.len, 1000);
+        fill(&mut mapping);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn read_and_write() {
+        let (_file, mut mapping) = Mapping::memfd(16).unwrap();
+        mapping.fill(7);
+        mapping.write(14, &[1, 2]);
+        let mut buf = [0; 4];
+        mapping.read(12, &mut buf);
+        assert_eq!(buf, [7, 7, 1, 2]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[should_panic(expected = "outside of the segment")]
+    fn read_out_of_bounds() {
+        let (_file, mapping) = Mapping::memfd(16).unwrap();
+        mapping.read(14, &mut [0; 4]);
+    }
+}
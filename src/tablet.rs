@@ -0,0 +1,355 @@
+//! Decoding the pressure, tilt, and distance of a graphics tablet stylus.
+//!
+//! XInput 2 reports all axes of a device as numbered valuators. Which valuator is the pressure of
+//! a stylus depends on the device and is only known from the label atom of the valuator, e.g.
+//! `Abs Pressure`. The range of the values also differs between devices. [`Tablet`] looks up the
+//! valuators by their labels, normalizes their values to the range from 0 to 1, and remembers the
+//! last value of each axis, since XI2 events only contain the valuators that changed.
+//!
+//! This module is only available when the `xinput` feature is enabled.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::tablet::Tablet;
+//!
+//! fn draw(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+//!     let mut tablet = Tablet::new(conn)?;
+//!     Tablet::select_events(conn, window)?;
+//!     conn.flush()?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         if let Some(stylus) = tablet.handle_event(&event) {
+//!             println!("Pressure {:?}", stylus.pressure);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::atom_cache::intern_atoms;
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xinput::{self, DeviceClass, DeviceClassData, DeviceId, Fp3232, MotionEvent};
+use crate::protocol::xproto::{Atom, Window};
+use crate::protocol::Event;
+use crate::scroll_emulation::{fp3232_to_f64, valuator_values};
+use crate::NONE;
+
+/// A stylus axis that [`Tablet`] decodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// How hard the stylus is pressed onto the tablet.
+    Pressure,
+    /// The tilt of the stylus along the x axis.
+    TiltX,
+    /// The tilt of the stylus along the y axis.
+    TiltY,
+    /// The distance of the stylus from the tablet while hovering.
+    Distance,
+}
+
+impl Axis {
+    const ALL: [Axis; 4] = [Axis::Pressure, Axis::TiltX, Axis::TiltY, Axis::Distance];
+
+    /// Get the valuator label that the X11 server uses for this axis.
+    pub fn label(self) -> &'static str {
+        match self {
+            Axis::Pressure => "Abs Pressure",
+            Axis::TiltX => "Abs Tilt X",
+            Axis::TiltY => "Abs Tilt Y",
+            Axis::Distance => "Abs Distance",
+        }
+    }
+}
+
+/// The last known state of the stylus axes of a device.
+///
+/// All values are normalized to the range from 0 to 1. For the tilt, 0.5 means that the stylus
+/// is upright. Axes that the device does not have or did not report yet are `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StylusState {
+    /// The slave device that the stylus belongs to.
+    pub device: DeviceId,
+    /// The pressure of the stylus.
+    pub pressure: Option<f64>,
+    /// The tilt of the stylus along the x axis.
+    pub tilt_x: Option<f64>,
+    /// The tilt of the stylus along the y axis.
+    pub tilt_y: Option<f64>,
+    /// The distance of the stylus from the tablet.
+    pub distance: Option<f64>,
+}
+
+impl StylusState {
+    fn new(device: DeviceId) -> Self {
+        Self {
+            device,
+            pressure: None,
+            tilt_x: None,
+            tilt_y: None,
+            distance: None,
+        }
+    }
+
+    /// Get the value of the given axis.
+    pub fn get(&self, axis: Axis) -> Option<f64> {
+        match axis {
+            Axis::Pressure => self.pressure,
+            Axis::TiltX => self.tilt_x,
+            Axis::TiltY => self.tilt_y,
+            Axis::Distance => self.distance,
+        }
+    }
+
+    fn set(&mut self, axis: Axis, value: f64) {
+        let field = match axis {
+            Axis::Pressure => &mut self.pressure,
+            Axis::TiltX => &mut self.tilt_x,
+            Axis::TiltY => &mut self.tilt_y,
+            Axis::Distance => &mut self.distance,
+        };
+        *field = Some(value);
+    }
+}
+
+/// A valuator of a device that belongs to one of the stylus axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AxisValuator {
+    deviceid: DeviceId,
+    number: u16,
+    axis: Axis,
+    min: f64,
+    max: f64,
+}
+
+/// Decodes the stylus axes of XI2 events and tracks the stylus that is in proximity.
+#[derive(Debug, Clone)]
+pub struct Tablet {
+    /// The label atoms in the order of [`Axis::ALL`], `NONE` if the atom does not exist.
+    labels: [Atom; 4],
+    valuators: Vec<AxisValuator>,
+    states: Vec<StylusState>,
+    in_proximity: Option<DeviceId>,
+}
+
+impl Tablet {
+    /// Query the stylus axes of all input devices.
+    ///
+    /// The XInput extension must already be initialised via `XIQueryVersion`.
+    pub fn new<C: RequestConnection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        let labels = Axis::ALL
+            .iter()
+            .map(|axis| axis.label())
+            .collect::<Vec<_>>();
+        let labels = intern_atoms(conn, true, &labels)?;
+        let reply = xinput::xi_query_device(conn, xinput::Device::ALL)?.reply()?;
+        let mut tablet = Self {
+            labels: [labels[0], labels[1], labels[2], labels[3]],
+            valuators: Vec::new(),
+            states: Vec::new(),
+            in_proximity: None,
+        };
+        for info in reply.infos {
+            tablet.update_device(info.deviceid, &info.classes);
+        }
+        Ok(tablet)
+    }
+
+    /// Select the XI2 events that [`handle_event()`](Self::handle_event) needs on a window.
+    ///
+    /// This selects `Motion`, `ButtonPress`, and `ButtonRelease` events of all master devices and
+    /// `DeviceChanged` events of all devices.
+    pub fn select_events<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<VoidCookie<'_, C>, ConnectionError> {
+        let pointer = xinput::XIEventMask::MOTION
+            | xinput::XIEventMask::BUTTON_PRESS
+            | xinput::XIEventMask::BUTTON_RELEASE;
+        let masks = [
+            xinput::EventMask {
+                deviceid: xinput::Device::ALL_MASTER.into(),
+                mask: vec![pointer.into()],
+            },
+            xinput::EventMask {
+                deviceid: xinput::Device::ALL.into(),
+                mask: vec![xinput::XIEventMask::DEVICE_CHANGED.into()],
+            },
+        ];
+        xinput::xi_select_events(conn, window, &masks)
+    }
+
+    /// Handle an event and get the new state of the stylus if the event came from one.
+    ///
+    /// XI2 `Motion`, `ButtonPress`, and `ButtonRelease` events update the stylus axes and
+    /// `DeviceChanged` events update the known valuators. XI2 has no proximity events, so a
+    /// stylus is considered to be in proximity once it sent an event. If the application also
+    /// selects the XInput 1 `ProximityIn` and `ProximityOut` events, these are handled as well.
+    pub fn handle_event(&mut self, event: &Event) -> Option<StylusState> {
+        match event {
+            Event::XinputMotion(event)
+            | Event::XinputButtonPress(event)
+            | Event::XinputButtonRelease(event) => self.update(event),
+            Event::XinputDeviceChanged(event) => {
+                // The classes are those of the slave device that caused the event
+                self.update_device(event.sourceid, &event.classes);
+                None
+            }
+            Event::XinputProximityIn(event) => {
+                self.in_proximity = Some(event.device_id.into());
+                None
+            }
+            Event::XinputProximityOut(event) => {
+                let device = DeviceId::from(event.device_id);
+                if self.in_proximity == Some(device) {
+                    self.in_proximity = None;
+                }
+                self.states.retain(|state| state.device != device);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Update the stylus axes from a device event.
+    ///
+    /// Returns `None` if the device that caused the event does not have any stylus axes.
+    pub fn update(&mut self, event: &MotionEvent) -> Option<StylusState> {
+        self.apply(event.sourceid, &event.valuator_mask, &event.axisvalues)
+    }
+
+    /// Get the slave device of the stylus that is currently in proximity.
+    pub fn in_proximity(&self) -> Option<DeviceId> {
+        self.in_proximity
+    }
+
+    /// Get the last known state of the stylus of a device.
+    pub fn state(&self, device: DeviceId) -> Option<&StylusState> {
+        self.states.iter().find(|state| state.device == device)
+    }
+
+    fn apply(
+        &mut self,
+        deviceid: DeviceId,
+        mask: &[u32],
+        values: &[Fp3232],
+    ) -> Option<StylusState> {
+        if !self.valuators.iter().any(|v| v.deviceid == deviceid) {
+            return None;
+        }
+        let index = match self.states.iter().position(|s| s.device == deviceid) {
+            Some(index) => index,
+            None => {
+                self.states.push(StylusState::new(deviceid));
+                self.states.len() - 1
+            }
+        };
+        for (number, value) in valuator_values(mask, values) {
+            let valuator = self
+                .valuators
+                .iter()
+                .find(|v| v.deviceid == deviceid && u32::from(v.number) == number);
+            if let Some(valuator) = valuator {
+                let value = normalize(fp3232_to_f64(value), valuator.min, valuator.max);
+                self.states[index].set(valuator.axis, value);
+            }
+        }
+        self.in_proximity = Some(deviceid);
+        Some(self.states[index])
+    }
+
+    /// Replace the known stylus valuators of a device.
+    fn update_device(&mut self, deviceid: DeviceId, classes: &[DeviceClass]) {
+        self.valuators.retain(|v| v.deviceid != deviceid);
+        for class in classes {
+            if let DeviceClassData::Valuator(valuator) = &class.data {
+                let axis = Axis::ALL
+                    .iter()
+                    .zip(self.labels.iter())
+                    .find(|(_, &label)| label != NONE && label == valuator.label)
+                    .map(|(&axis, _)| axis);
+                if let Some(axis) = axis {
+                    self.valuators.push(AxisValuator {
+                        deviceid,
+                        number: valuator.number,
+                        axis,
+                        min: fp3232_to_f64(valuator.min),
+                        max: fp3232_to_f64(valuator.max),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Map a value from the range `min..=max` to the range from 0 to 1.
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{normalize, Axis, AxisValuator, Tablet};
+    use crate::protocol::xinput::Fp3232;
+
+    fn fp(value: i32) -> Fp3232 {
+        Fp3232 {
+            integral: value,
+            frac: 0,
+        }
+    }
+
+    fn tablet() -> Tablet {
+        let valuator = |number, axis, min, max| AxisValuator {
+            deviceid: 10,
+            number,
+            axis,
+            min,
+            max,
+        };
+        Tablet {
+            labels: [1, 2, 3, 4],
+            valuators: vec![
+                valuator(2, Axis::Pressure, 0.0, 2048.0),
+                valuator(3, Axis::TiltX, -64.0, 63.0),
+            ],
+            states: Vec::new(),
+            in_proximity: None,
+        }
+    }
+
+    #[test]
+    fn normalize_values() {
+        assert_eq!(normalize(512.0, 0.0, 2048.0), 0.25);
+        assert_eq!(normalize(0.0, -10.0, 10.0), 0.5);
+        assert_eq!(normalize(20.0, -10.0, 10.0), 1.0);
+        assert_eq!(normalize(5.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn remember_axes() {
+        let mut tablet = tablet();
+        // Other devices are not tablets
+        assert_eq!(tablet.apply(11, &[0b1100], &[fp(1), fp(1)]), None);
+        assert_eq!(tablet.in_proximity(), None);
+
+        let state = tablet
+            .apply(10, &[0b1101], &[fp(5), fp(1024), fp(-64)])
+            .unwrap();
+        assert_eq!(state.pressure, Some(0.5));
+        assert_eq!(state.tilt_x, Some(0.0));
+        assert_eq!(state.tilt_y, None);
+        assert_eq!(tablet.in_proximity(), Some(10));
+
+        // Only the pressure changed, the tilt is remembered
+        let state = tablet.apply(10, &[0b100], &[fp(2048)]).unwrap();
+        assert_eq!(state.get(Axis::Pressure), Some(1.0));
+        assert_eq!(state.get(Axis::TiltX), Some(0.0));
+        assert_eq!(tablet.state(10), Some(&state));
+    }
+}
@@ -0,0 +1,78 @@
+//! An in-process X11 server that answers every request.
+//!
+//! [`EchoServer`] runs in its own thread and answers every request with a `GetInputFocus` reply,
+//! no matter which request it was. This is enough to measure the overhead of
+//! [`RustConnection`] itself or to test how it copes with many threads waiting for replies:
+//!
+//! ```
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! use x11rb::testing::echo::EchoServer;
+//!
+//! let (conn, server) = EchoServer::connect().unwrap();
+//! let cookie = conn.get_input_focus().unwrap();
+//! let sequence = cookie.sequence_number();
+//! assert_eq!(u64::from(cookie.reply().unwrap().focus), sequence);
+//!
+//! // Closing the connection makes the server exit
+//! drop(conn);
+//! server.join();
+//! ```
+
+use std::io::{BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::thread::{self, JoinHandle};
+
+use crate::errors::ConnectError;
+use crate::rust_connection::{DefaultStream, RustConnection};
+
+/// A thread that plays the role of an X11 server.
+///
+/// The `focus` field of each reply contains the lower 16 bits of the sequence number of the
+/// request. The server exits when the client closes its end of the connection.
+#[derive(Debug)]
+pub struct EchoServer {
+    thread: JoinHandle<()>,
+}
+
+impl EchoServer {
+    /// Start a server and connect to it.
+    pub fn connect() -> Result<(RustConnection, Self), ConnectError> {
+        let (client, server) = UnixStream::pair()?;
+        let thread = thread::spawn(move || serve(server));
+        let stream = DefaultStream::from_unix_stream(client)?;
+        let conn = RustConnection::for_connected_stream(stream, super::default_setup())?;
+        Ok((conn, Self { thread }))
+    }
+
+    /// Wait for the server to exit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server received a request with an invalid length.
+    pub fn join(self) {
+        self.thread.join().unwrap()
+    }
+}
+
+fn serve(stream: UnixStream) {
+    let mut write = stream.try_clone().unwrap();
+    let mut read = BufReader::new(stream);
+    let mut sequence: u16 = 0;
+    let mut header = [0; 4];
+    while read.read_exact(&mut header).is_ok() {
+        let length = usize::from(u16::from_ne_bytes([header[2], header[3]])) * 4;
+        assert!(length >= 4, "BIG-REQUESTS is not supported");
+        let mut body = vec![0; length - 4];
+        if read.read_exact(&mut body).is_err() {
+            break;
+        }
+        sequence = sequence.wrapping_add(1);
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        reply[8..12].copy_from_slice(&u32::from(sequence).to_ne_bytes());
+        if write.write_all(&reply).is_err() {
+            break;
+        }
+    }
+}
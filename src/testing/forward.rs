@@ -0,0 +1,316 @@
+//! Sending input to a nested X11 server via XTEST.
+//!
+//! End-to-end tests of a window manager run it inside a nested server, see [`super::server`].
+//! The functions in this module fake key presses, button presses and pointer motion on such a
+//! server, so that a test can e.g. click on a window and check how the window manager reacts.
+//!
+//! [`InputForwarder`] replays input from a window on the host server into the nested server.
+//! This is useful to interact with a test by hand, or to record and replay input:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::testing::forward::InputForwarder;
+//! # fn host_window() -> u32 { 0 }
+//!
+//! let (host, _) = x11rb::connect(None).unwrap();
+//! // E.g. a server started with `x11rb::testing::server::TestServer::xephyr()`
+//! let (nested, screen_num) = x11rb::connect(Some(":1")).unwrap();
+//! let forwarder = InputForwarder::new(host_window(), nested.setup().roots[screen_num].root);
+//! forwarder.select_input(&host).unwrap();
+//! host.flush().unwrap();
+//! loop {
+//!     let event = host.wait_for_event().unwrap();
+//!     if forwarder.forward(&nested, &event).unwrap() {
+//!         nested.flush().unwrap();
+//!     }
+//! }
+//! ```
+//!
+//! The nested server has to support the XTEST extension, which Xvfb and Xephyr do by default.
+//! Keycodes are forwarded as they are, so the keyboard mapping of both servers should be the
+//! same. Xephyr copies the keyboard mapping of the host server when it starts.
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::{
+    self, Button, ChangeWindowAttributesAux, EventMask, Keycode, Window,
+};
+use crate::protocol::{xtest, Event};
+use crate::{CURRENT_TIME, NONE};
+
+/// Press or release a key.
+pub fn fake_key<C: RequestConnection + ?Sized>(
+    conn: &C,
+    keycode: Keycode,
+    pressed: bool,
+) -> Result<(), ConnectionError> {
+    let type_ = if pressed {
+        xproto::KEY_PRESS_EVENT
+    } else {
+        xproto::KEY_RELEASE_EVENT
+    };
+    let _ = xtest::fake_input(conn, type_, keycode, CURRENT_TIME, NONE, 0, 0, 0)?;
+    Ok(())
+}
+
+/// Press or release a pointer button.
+pub fn fake_button<C: RequestConnection + ?Sized>(
+    conn: &C,
+    button: Button,
+    pressed: bool,
+) -> Result<(), ConnectionError> {
+    let type_ = if pressed {
+        xproto::BUTTON_PRESS_EVENT
+    } else {
+        xproto::BUTTON_RELEASE_EVENT
+    };
+    let _ = xtest::fake_input(conn, type_, button, CURRENT_TIME, NONE, 0, 0, 0)?;
+    Ok(())
+}
+
+/// Move the pointer to the given position on the given root window.
+pub fn fake_motion<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    x: i16,
+    y: i16,
+) -> Result<(), ConnectionError> {
+    // Detail 0 means that the position is absolute
+    let _ = xtest::fake_input(
+        conn,
+        xproto::MOTION_NOTIFY_EVENT,
+        0,
+        CURRENT_TIME,
+        root,
+        x,
+        y,
+        0,
+    )?;
+    Ok(())
+}
+
+/// Move the pointer to the given position and click the given button there.
+pub fn fake_click<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    (x, y): (i16, i16),
+    button: Button,
+) -> Result<(), ConnectionError> {
+    fake_motion(conn, root, x, y)?;
+    fake_button(conn, button, true)?;
+    fake_button(conn, button, false)
+}
+
+/// Press and release the given keys one after another.
+pub fn fake_key_taps<C: RequestConnection + ?Sized>(
+    conn: &C,
+    keycodes: &[Keycode],
+) -> Result<(), ConnectionError> {
+    for &keycode in keycodes {
+        fake_key(conn, keycode, true)?;
+        fake_key(conn, keycode, false)?;
+    }
+    Ok(())
+}
+
+/// The kinds of input that an [`InputForwarder`] forwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForwardedInput {
+    /// Forward key presses and releases.
+    pub keys: bool,
+    /// Forward button presses and releases.
+    pub buttons: bool,
+    /// Forward pointer motion.
+    pub motion: bool,
+}
+
+impl Default for ForwardedInput {
+    fn default() -> Self {
+        Self {
+            keys: true,
+            buttons: true,
+            motion: true,
+        }
+    }
+}
+
+/// Replays input events of a window on the host server on a nested server.
+///
+/// Positions in the host window become positions on the root window of the nested server, so the
+/// host window should have the same size as the nested screen.
+#[derive(Debug, Clone, Copy)]
+pub struct InputForwarder {
+    host_window: Window,
+    nested_root: Window,
+    input: ForwardedInput,
+}
+
+impl InputForwarder {
+    /// Create a forwarder for all input on `host_window`.
+    pub fn new(host_window: Window, nested_root: Window) -> Self {
+        Self {
+            host_window,
+            nested_root,
+            input: ForwardedInput::default(),
+        }
+    }
+
+    /// Only forward the given kinds of input.
+    pub fn only(mut self, input: ForwardedInput) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Get the kinds of input that are forwarded.
+    pub fn input(&self) -> ForwardedInput {
+        self.input
+    }
+
+    /// Select the events that are forwarded on the host window.
+    ///
+    /// Only one client can select button presses on a window. Thus, this fails with an `Access`
+    /// error for the window that Xephyr shows its screen in. Use a window of your own instead or
+    /// do not forward buttons.
+    pub fn select_input<'c, C: RequestConnection + ?Sized>(
+        &self,
+        host: &'c C,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let mut mask = EventMask::NO_EVENT;
+        if self.input.keys {
+            mask = mask | EventMask::KEY_PRESS | EventMask::KEY_RELEASE;
+        }
+        if self.input.buttons {
+            mask = mask | EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE;
+        }
+        if self.input.motion {
+            mask = mask | EventMask::POINTER_MOTION;
+        }
+        let aux = ChangeWindowAttributesAux::new().event_mask(mask);
+        xproto::change_window_attributes(host, self.host_window, &aux)
+    }
+
+    /// Forward an event from the host server to the nested server.
+    ///
+    /// Returns whether the event was forwarded. Events for other windows and events of kinds that
+    /// are not forwarded are ignored. The caller has to flush the nested connection.
+    pub fn forward<C: RequestConnection + ?Sized>(
+        &self,
+        nested: &C,
+        event: &Event,
+    ) -> Result<bool, ConnectionError> {
+        let input = self.input;
+        match event {
+            Event::KeyPress(e) if input.keys && e.event == self.host_window => {
+                fake_key(nested, e.detail, true)?
+            }
+            Event::KeyRelease(e) if input.keys && e.event == self.host_window => {
+                fake_key(nested, e.detail, false)?
+            }
+            Event::ButtonPress(e) if input.buttons && e.event == self.host_window => {
+                fake_motion(nested, self.nested_root, e.event_x, e.event_y)?;
+                fake_button(nested, e.detail, true)?
+            }
+            Event::ButtonRelease(e) if input.buttons && e.event == self.host_window => {
+                fake_motion(nested, self.nested_root, e.event_x, e.event_y)?;
+                fake_button(nested, e.detail, false)?
+            }
+            Event::MotionNotify(e) if input.motion && e.event == self.host_window => {
+                fake_motion(nested, self.nested_root, e.event_x, e.event_y)?
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ForwardedInput, InputForwarder};
+    use crate::protocol::xproto::{self, ButtonPressEvent, KeyPressEvent};
+    use crate::protocol::{xtest, Event};
+    use crate::testing::FakeConnection;
+    use crate::x11_utils::ExtensionInformation;
+
+    const XTEST_OPCODE: u8 = 132;
+
+    // Get type, detail, root and position of the sent FakeInput requests
+    fn fake_inputs(conn: &FakeConnection) -> Vec<(u8, u8, u32, i16, i16)> {
+        conn.take_sent_requests()
+            .iter()
+            .map(|request| {
+                let bytes = &request.bytes;
+                assert_eq!(request.major_opcode(), XTEST_OPCODE);
+                assert_eq!(request.minor_opcode(), xtest::FAKE_INPUT_REQUEST);
+                let root = u32::from_ne_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+                let x = i16::from_ne_bytes([bytes[24], bytes[25]]);
+                let y = i16::from_ne_bytes([bytes[26], bytes[27]]);
+                (bytes[4], bytes[5], root, x, y)
+            })
+            .collect()
+    }
+
+    fn key_press(window: u32, keycode: u8) -> Event {
+        Event::KeyPress(KeyPressEvent {
+            response_type: xproto::KEY_PRESS_EVENT,
+            detail: keycode,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: window,
+            child: 0,
+            root_x: 0,
+            root_y: 0,
+            event_x: 0,
+            event_y: 0,
+            state: Default::default(),
+            same_screen: true,
+        })
+    }
+
+    #[test]
+    fn forward() {
+        let conn = FakeConnection::default();
+        let info = ExtensionInformation {
+            major_opcode: XTEST_OPCODE,
+            first_event: 0,
+            first_error: 0,
+        };
+        conn.add_extension(xtest::X11_EXTENSION_NAME, info);
+        let forwarder = InputForwarder::new(10, 99);
+
+        assert!(forwarder.forward(&conn, &key_press(10, 38)).unwrap());
+        assert!(!forwarder.forward(&conn, &key_press(11, 38)).unwrap());
+        let press = Event::ButtonPress(ButtonPressEvent {
+            response_type: xproto::BUTTON_PRESS_EVENT,
+            detail: 1,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: 10,
+            child: 0,
+            root_x: 0,
+            root_y: 0,
+            event_x: 5,
+            event_y: 7,
+            state: Default::default(),
+            same_screen: true,
+        });
+        assert!(forwarder.forward(&conn, &press).unwrap());
+        assert_eq!(
+            fake_inputs(&conn),
+            [
+                (xproto::KEY_PRESS_EVENT, 38, 0, 0, 0),
+                (xproto::MOTION_NOTIFY_EVENT, 0, 99, 5, 7),
+                (xproto::BUTTON_PRESS_EVENT, 1, 0, 0, 0),
+            ]
+        );
+
+        let forwarder = forwarder.only(ForwardedInput {
+            keys: false,
+            ..ForwardedInput::default()
+        });
+        assert!(!forwarder.forward(&conn, &key_press(10, 38)).unwrap());
+        assert!(fake_inputs(&conn).is_empty());
+    }
+}
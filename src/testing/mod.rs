@@ -0,0 +1,992 @@
+//! A fake connection for unit tests of X11 clients.
+//!
+//! Testing code that talks to an X11 server usually requires a running X11 server, e.g. Xvfb.
+//! [`FakeConnection`] instead implements [`Connection`] without any server. Tests script the
+//! responses to the requests that the code under test is expected to send, queue events, and
+//! afterwards check which requests were sent.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{self, ConnectionExt as _};
+//! use x11rb::testing::{FakeConnection, Response};
+//!
+//! // The code under test
+//! fn intern_and_bell(conn: &impl Connection) -> Result<u32, Box<dyn std::error::Error>> {
+//!     let atom = conn.intern_atom(false, b"FOO")?.reply()?.atom;
+//!     conn.bell(0)?;
+//!     Ok(atom)
+//! }
+//!
+//! let conn = FakeConnection::default();
+//! // The atom is at offset 8 of the reply
+//! let mut reply = vec![0; 32];
+//! reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+//! conn.respond(xproto::INTERN_ATOM_REQUEST, None, Response::Reply(reply));
+//!
+//! assert_eq!(intern_and_bell(&conn).unwrap(), 42);
+//! let opcodes = conn.sent_requests().iter().map(|r| r.major_opcode()).collect::<Vec<_>>();
+//! assert_eq!(opcodes, [xproto::INTERN_ATOM_REQUEST, xproto::BELL_REQUEST]);
+//! ```
+//!
+//! To test how the code under test copes with a misbehaving X11 server, [`FakeConnection::inject`]
+//! can make the connection fail, truncate or reorder responses, or deliver them late. See
+//! [`Fault`] for the details.
+//!
+//! For tests that need a real X11 server, the [`server`] module starts Xvfb or Xephyr. The
+//! `forward` module sends input to such a server via the XTEST extension and requires the
+//! `xtest` feature. The `visual` module compares screenshots with golden images and requires the
+//! `image` feature.
+//!
+//! Tests and benchmarks of [`RustConnection`](crate::rust_connection::RustConnection) itself can
+//! use the [`echo`] module, which runs a fake X11 server in a thread that answers every request.
+//!
+//! The code in this module is only available when the `testing` feature of the library is
+//! enabled.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{Error, ErrorKind, IoSlice};
+use std::sync::Mutex;
+
+use crate::connection::{
+    compute_length_field, BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError,
+    RequestConnection, RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    BackingStore, Depth, ImageOrder, Screen, Setup, VisualClass, Visualtype, KEYMAP_NOTIFY_EVENT,
+};
+use crate::protocol::Event;
+use crate::utils::OwnedFd;
+use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+#[cfg(unix)]
+pub mod echo;
+#[cfg(feature = "xtest")]
+pub mod forward;
+#[cfg(unix)]
+pub mod server;
+#[cfg(all(feature = "image", not(feature = "request-pruning")))]
+pub mod visual;
+
+/// A request that was sent via a [`FakeConnection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentRequest {
+    /// The sequence number of the request.
+    pub sequence: SequenceNumber,
+    /// The raw bytes of the request.
+    pub bytes: Vec<u8>,
+}
+
+impl SentRequest {
+    /// Get the major opcode of the request.
+    pub fn major_opcode(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Get the minor opcode of an extension request.
+    ///
+    /// For core requests, this byte has a request-specific meaning or is unused.
+    pub fn minor_opcode(&self) -> u8 {
+        self.bytes[1]
+    }
+}
+
+/// The scripted response to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// Answer with a reply containing the given bytes.
+    ///
+    /// The bytes are the complete reply as it is sent by the X11 server. The first byte, the
+    /// sequence number, and the length field are filled in by the connection. The reply is padded
+    /// with zeros to at least 32 bytes and to a multiple of four bytes.
+    Reply(Vec<u8>),
+    /// Answer with an X11 error.
+    Error {
+        /// The error code, e.g. [`crate::protocol::xproto::WINDOW_ERROR`].
+        error_code: u8,
+        /// The value that caused the error, e.g. the ID of a window.
+        bad_value: u32,
+    },
+}
+
+/// A misbehaviour of the X11 server, injected with [`FakeConnection::inject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The X11 server closes the connection.
+    ///
+    /// Afterwards, sending requests fails with an [`ErrorKind::UnexpectedEof`] I/O error. So does
+    /// waiting for responses and events, once the ones that were already received are used up.
+    Disconnect,
+    /// Cut the next response off after the given number of bytes.
+    ///
+    /// The length field still describes the complete response, so parsing it fails.
+    Truncate(usize),
+    /// Swap the next two responses.
+    ///
+    /// Each response is delivered for the other request, but still carries the sequence number of
+    /// the request that it was scripted for.
+    SwapResponses,
+    /// Let the next response arrive late.
+    ///
+    /// Polling for the response returns `None` the given number of times before the response is
+    /// delivered, as if it was still on its way. Waiting for the response is not affected.
+    Delay(usize),
+    /// Deliver the file descriptors of the next reply that has some together with the following
+    /// reply that has file descriptors.
+    ///
+    /// The first reply then lacks its file descriptors, so parsing it fails. The second reply gets
+    /// too many, with the delayed ones first.
+    DelayFds,
+}
+
+/// A response that waits for a matching request.
+#[derive(Debug)]
+struct Expectation {
+    major_opcode: u8,
+    minor_opcode: Option<u8>,
+    response: Response,
+    fds: Vec<OwnedFd>,
+}
+
+impl Expectation {
+    fn matches(&self, request: &[u8]) -> bool {
+        // Without a minor opcode, every request with the right major opcode matches
+        self.major_opcode == request[0] && self.minor_opcode.unwrap_or(request[1]) == request[1]
+    }
+
+    /// Build the packet that the X11 server would send for the given request.
+    fn into_packet(self, request: &[u8], sequence: SequenceNumber) -> Packet {
+        let mut bytes = match self.response {
+            Response::Reply(mut reply) => {
+                reply.resize(reply.len().max(32), 0);
+                while reply.len() % 4 != 0 {
+                    reply.push(0);
+                }
+                let length = (reply.len() - 32) / 4;
+                reply[0] = 1;
+                reply[4..8].copy_from_slice(&(length as u32).to_ne_bytes());
+                reply
+            }
+            Response::Error {
+                error_code,
+                bad_value,
+            } => {
+                let mut error = vec![0; 32];
+                error[1] = error_code;
+                error[4..8].copy_from_slice(&bad_value.to_ne_bytes());
+                error[8..10].copy_from_slice(&u16::from(request[1]).to_ne_bytes());
+                error[10] = request[0];
+                error
+            }
+        };
+        bytes[2..4].copy_from_slice(&(sequence as u16).to_ne_bytes());
+        Packet {
+            bytes,
+            fds: self.fds,
+        }
+    }
+}
+
+/// A reply or error, together with the file descriptors of a reply.
+#[derive(Debug)]
+struct Packet {
+    bytes: Vec<u8>,
+    fds: Vec<OwnedFd>,
+}
+
+/// The faults that were injected, but not yet applied.
+#[derive(Debug, Default)]
+struct Faults {
+    disconnected: bool,
+    truncate: Option<usize>,
+    swap: bool,
+    /// The request whose response is swapped with the next response.
+    swap_with: Option<SequenceNumber>,
+    delay: Option<usize>,
+    delay_fds: bool,
+    delayed_fds: Vec<OwnedFd>,
+}
+
+/// The error for operations on a connection that was closed by [`Fault::Disconnect`].
+fn disconnected() -> ConnectionError {
+    Error::new(
+        ErrorKind::UnexpectedEof,
+        "The X11 server closed the connection",
+    )
+    .into()
+}
+
+/// Drop the file descriptors of a reply.
+fn without_fds(packet: ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>) -> ReplyOrError<Vec<u8>> {
+    match packet {
+        ReplyOrError::Reply((reply, _)) => ReplyOrError::Reply(reply),
+        ReplyOrError::Error(error) => ReplyOrError::Error(error),
+    }
+}
+
+/// The extensions that a [`FakeConnection`] pretends to support.
+#[derive(Debug, Default)]
+struct Extensions(Vec<(&'static str, ExtensionInformation)>);
+
+impl ExtInfoProvider for Extensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .map(|&(name, info)| (name, info))
+            .find(|(_, info)| info.major_opcode == major_opcode)
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .map(|&(name, info)| (name, info))
+            .filter(|(_, info)| info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .map(|&(name, info)| (name, info))
+            .filter(|(_, info)| info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    last_sequence: SequenceNumber,
+    next_id: u32,
+    extensions: Extensions,
+    expectations: VecDeque<Expectation>,
+    sent: Vec<SentRequest>,
+    /// Replies and errors for sent requests that were not yet picked up.
+    packets: HashMap<SequenceNumber, Packet>,
+    /// The number of polls that each delayed packet still has to wait for.
+    delays: HashMap<SequenceNumber, usize>,
+    events: VecDeque<RawEventAndSeqNumber<Vec<u8>>>,
+    faults: Faults,
+}
+
+impl Inner {
+    /// Store the response to a request after applying the injected faults.
+    fn receive(&mut self, sequence: SequenceNumber, mut packet: Packet) {
+        if !packet.fds.is_empty() {
+            if std::mem::take(&mut self.faults.delay_fds) {
+                self.faults.delayed_fds.append(&mut packet.fds);
+            } else {
+                let mut fds = std::mem::take(&mut self.faults.delayed_fds);
+                fds.append(&mut packet.fds);
+                packet.fds = fds;
+            }
+        }
+        if let Some(length) = self.faults.truncate.take() {
+            packet.bytes.truncate(length);
+        }
+        if let Some(polls) = self.faults.delay.take() {
+            let _ = self.delays.insert(sequence, polls);
+        }
+        if let Some(other) = self.faults.swap_with.take() {
+            if let Some(other_packet) = self.packets.remove(&other) {
+                let _ = self.packets.insert(other, packet);
+                packet = other_packet;
+            }
+        } else if std::mem::take(&mut self.faults.swap) {
+            self.faults.swap_with = Some(sequence);
+        }
+        let _ = self.packets.insert(sequence, packet);
+    }
+
+    /// Take the reply with its file descriptors or the error for the given request.
+    fn take_packet_with_fds(
+        &mut self,
+        sequence: SequenceNumber,
+    ) -> Option<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>> {
+        let _ = self.delays.remove(&sequence);
+        self.packets.remove(&sequence).map(|packet| {
+            if packet.bytes.first() == Some(&0) {
+                ReplyOrError::Error(packet.bytes)
+            } else {
+                ReplyOrError::Reply((packet.bytes, packet.fds))
+            }
+        })
+    }
+
+    /// Take the reply or error for the given request.
+    fn take_packet(&mut self, sequence: SequenceNumber) -> Option<ReplyOrError<Vec<u8>>> {
+        self.take_packet_with_fds(sequence).map(without_fds)
+    }
+
+    /// Like `take_packet_with_fds()`, but for requests that must have a response.
+    fn expect_packet_with_fds(
+        &mut self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        if let Some(packet) = self.take_packet_with_fds(sequence) {
+            return Ok(packet);
+        }
+        if self.faults.disconnected {
+            return Err(disconnected());
+        }
+        let request = self
+            .sent
+            .iter()
+            .find(|request| request.sequence == sequence);
+        panic!(
+            "No response was scripted for request {} with major opcode {:?}",
+            sequence,
+            request.map(SentRequest::major_opcode)
+        )
+    }
+
+    /// Like `take_packet()`, but for requests that must have a response.
+    fn expect_packet(
+        &mut self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        self.expect_packet_with_fds(sequence).map(without_fds)
+    }
+}
+
+/// A [`Connection`] that does not talk to an X11 server.
+///
+/// Responses to requests are scripted with [`FakeConnection::respond`] and events are queued with
+/// [`FakeConnection::push_event`]. See the [module documentation](self) for an example.
+///
+/// Waiting for the reply of a request without a scripted response panics. Waiting for an event
+/// when no events are queued fails with an [`ErrorKind::UnexpectedEof`] I/O error, as if the X11
+/// server closed the connection. Misbehaviour of the X11 server can be injected with
+/// [`FakeConnection::inject`].
+#[derive(Debug)]
+pub struct FakeConnection {
+    setup: Setup,
+    max_id: u32,
+    id_increment: u32,
+    inner: Mutex<Inner>,
+}
+
+impl Default for FakeConnection {
+    /// Create a connection with a single 1024x768 screen.
+    fn default() -> Self {
+        Self::new(default_setup())
+    }
+}
+
+impl FakeConnection {
+    /// Create a connection with the given setup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `resource_id_mask` of the setup is zero.
+    pub fn new(setup: Setup) -> Self {
+        let mask = setup.resource_id_mask;
+        assert_ne!(mask, 0, "The resource ID mask must not be zero");
+        let inner = Inner {
+            last_sequence: 0,
+            next_id: setup.resource_id_base,
+            extensions: Default::default(),
+            expectations: Default::default(),
+            sent: Default::default(),
+            packets: Default::default(),
+            delays: Default::default(),
+            events: Default::default(),
+            faults: Default::default(),
+        };
+        Self {
+            max_id: setup.resource_id_base | mask,
+            id_increment: mask & (1 + !mask),
+            setup,
+            inner: Mutex::new(inner),
+        }
+    }
+
+    /// Pretend that the X11 server supports the given extension.
+    ///
+    /// Events and errors are parsed with this information, so that extension events can be
+    /// delivered via [`FakeConnection::push_event`].
+    pub fn add_extension(&self, extension_name: &'static str, info: ExtensionInformation) {
+        let mut inner = self.inner.lock().unwrap();
+        inner
+            .extensions
+            .0
+            .retain(|(name, _)| *name != extension_name);
+        inner.extensions.0.push((extension_name, info));
+    }
+
+    /// Script the response to the next request with the given opcodes.
+    ///
+    /// The response is used for the first request that is sent afterwards whose major opcode is
+    /// `major_opcode` and, if given, whose minor opcode is `minor_opcode`. Responses for the same
+    /// kind of request are used in the order in which they were scripted.
+    pub fn respond(&self, major_opcode: u8, minor_opcode: Option<u8>, response: Response) {
+        self.expect(Expectation {
+            major_opcode,
+            minor_opcode,
+            response,
+            fds: Vec::new(),
+        });
+    }
+
+    /// Script a reply with file descriptors to the next request with the given opcodes.
+    ///
+    /// This works like [`FakeConnection::respond`] with [`Response::Reply`]. The file descriptors
+    /// are handed out together with the reply, e.g. for the `CreateSegment` request of the MIT-SHM
+    /// extension.
+    pub fn respond_with_fds(
+        &self,
+        major_opcode: u8,
+        minor_opcode: Option<u8>,
+        reply: Vec<u8>,
+        fds: Vec<OwnedFd>,
+    ) {
+        self.expect(Expectation {
+            major_opcode,
+            minor_opcode,
+            response: Response::Reply(reply),
+            fds,
+        });
+    }
+
+    fn expect(&self, expectation: Expectation) {
+        self.inner
+            .lock()
+            .unwrap()
+            .expectations
+            .push_back(expectation);
+    }
+
+    /// Make the fake X11 server misbehave.
+    ///
+    /// Except for [`Fault::Disconnect`], each fault applies once, to the next scripted response
+    /// that is used by a request sent afterwards. Injecting the same fault again before that
+    /// replaces the earlier one.
+    pub fn inject(&self, fault: Fault) {
+        let faults = &mut self.inner.lock().unwrap().faults;
+        match fault {
+            Fault::Disconnect => faults.disconnected = true,
+            Fault::Truncate(length) => faults.truncate = Some(length),
+            Fault::SwapResponses => faults.swap = true,
+            Fault::Delay(polls) => faults.delay = Some(polls),
+            Fault::DelayFds => faults.delay_fds = true,
+        }
+    }
+
+    /// Get the number of scripted responses that were not used yet.
+    pub fn pending_responses(&self) -> usize {
+        self.inner.lock().unwrap().expectations.len()
+    }
+
+    /// Queue an event.
+    ///
+    /// The event is delivered with the sequence number of the last request that was sent before
+    /// this function was called.
+    pub fn push_event(&self, event: impl Into<[u8; 32]>) {
+        self.push_raw_event(event.into().to_vec())
+    }
+
+    /// Queue an event given as raw bytes, e.g. a generic event that is longer than 32 bytes.
+    ///
+    /// The sequence number of the event is filled in like for [`FakeConnection::push_event`].
+    pub fn push_raw_event(&self, mut event: Vec<u8>) {
+        let mut inner = self.inner.lock().unwrap();
+        let sequence = inner.last_sequence;
+        // KeymapNotify is the only event without a sequence number
+        if event[0] & 0x7f != KEYMAP_NOTIFY_EVENT {
+            event[2..4].copy_from_slice(&(sequence as u16).to_ne_bytes());
+        }
+        inner.events.push_back((event, sequence));
+    }
+
+    /// Get all requests that were sent so far, in the order in which they were sent.
+    pub fn sent_requests(&self) -> Vec<SentRequest> {
+        self.inner.lock().unwrap().sent.clone()
+    }
+
+    /// Get and forget all requests that were sent so far.
+    pub fn take_sent_requests(&self) -> Vec<SentRequest> {
+        std::mem::take(&mut self.inner.lock().unwrap().sent)
+    }
+
+    fn send_request(&self, bufs: &[IoSlice<'_>]) -> Result<SequenceNumber, ConnectionError> {
+        let mut storage = Default::default();
+        let bufs = compute_length_field(self, bufs, &mut storage)?;
+        let bytes = bufs
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<_>>();
+
+        let mut inner = self.inner.lock().unwrap();
+        if inner.faults.disconnected {
+            return Err(disconnected());
+        }
+        inner.last_sequence += 1;
+        let sequence = inner.last_sequence;
+        let position = inner
+            .expectations
+            .iter()
+            .position(|expectation| expectation.matches(&bytes));
+        if let Some(expectation) = position.and_then(|pos| inner.expectations.remove(pos)) {
+            let packet = expectation.into_packet(&bytes, sequence);
+            inner.receive(sequence, packet);
+        }
+        inner.sent.push(SentRequest { sequence, bytes });
+        Ok(sequence)
+    }
+}
+
+impl RequestConnection for FakeConnection {
+    type Buf = Vec<u8>;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        _fds: Vec<OwnedFd>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        Ok(Cookie::new(self, self.send_request(bufs)?))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        _fds: Vec<OwnedFd>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        Ok(CookieWithFds::new(self, self.send_request(bufs)?))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        _fds: Vec<OwnedFd>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(self, self.send_request(bufs)?))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, _kind: RequestKind, mode: DiscardMode) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(ReplyOrError::Error(error)) = inner.take_packet(sequence) {
+            if mode == DiscardMode::DiscardReply {
+                inner.events.push_back((error, sequence));
+            }
+        }
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        _extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        Ok(())
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        let inner = self.inner.lock().unwrap();
+        Ok(inner
+            .extensions
+            .0
+            .iter()
+            .find(|(name, _)| *name == extension_name)
+            .map(|&(_, info)| info))
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        self.inner.lock().unwrap().expect_packet(sequence)
+    }
+
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        // Scripted responses arrive immediately, unless they were delayed
+        if let Some(polls) = inner.delays.get_mut(&sequence) {
+            if *polls > 0 {
+                *polls -= 1;
+                return Ok(None);
+            }
+        }
+        inner.expect_packet(sequence).map(Some)
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.expect_packet(sequence)? {
+            ReplyOrError::Reply(reply) => Ok(Some(reply)),
+            ReplyOrError::Error(error) => {
+                inner.events.push_back((error, sequence));
+                Ok(None)
+            }
+        }
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        self.inner.lock().unwrap().expect_packet_with_fds(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.take_packet(sequence) {
+            Some(ReplyOrError::Error(error)) => Ok(Some(error)),
+            None if inner.faults.disconnected => Err(disconnected()),
+            Some(ReplyOrError::Reply(_)) | None => Ok(None),
+        }
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {}
+
+    fn maximum_request_bytes(&self) -> usize {
+        4 * usize::from(self.setup.maximum_request_length)
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        X11Error::try_parse(error, &self.inner.lock().unwrap().extensions)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        Event::parse(event, &self.inner.lock().unwrap().extensions)
+    }
+}
+
+impl Connection for FakeConnection {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.poll_for_raw_event_with_sequence()?.ok_or_else(|| {
+            Error::new(ErrorKind::UnexpectedEof, "No more events were queued").into()
+        })
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.events.pop_front() {
+            None if inner.faults.disconnected => Err(disconnected()),
+            event => Ok(event),
+        }
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        Ok(())
+    }
+
+    fn setup(&self) -> &Setup {
+        &self.setup
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.next_id > self.max_id {
+            return Err(ReplyOrIdError::IdsExhausted);
+        }
+        let id = inner.next_id;
+        inner.next_id = id.saturating_add(self.id_increment);
+        Ok(id)
+    }
+}
+
+/// The setup of [`FakeConnection::default`].
+fn default_setup() -> Setup {
+    let visual = Visualtype {
+        visual_id: 0x21,
+        class: VisualClass::TRUE_COLOR,
+        bits_per_rgb_value: 8,
+        colormap_entries: 256,
+        red_mask: 0xff_0000,
+        green_mask: 0xff00,
+        blue_mask: 0xff,
+    };
+    let screen = Screen {
+        root: 0x100,
+        default_colormap: 0x20,
+        white_pixel: 0xff_ffff,
+        black_pixel: 0,
+        current_input_masks: 0,
+        width_in_pixels: 1024,
+        height_in_pixels: 768,
+        width_in_millimeters: 271,
+        height_in_millimeters: 203,
+        min_installed_maps: 1,
+        max_installed_maps: 1,
+        root_visual: visual.visual_id,
+        backing_stores: BackingStore::NOT_USEFUL,
+        save_unders: false,
+        root_depth: 24,
+        allowed_depths: vec![Depth {
+            depth: 24,
+            visuals: vec![visual],
+        }],
+    };
+    Setup {
+        status: 1,
+        protocol_major_version: 11,
+        protocol_minor_version: 0,
+        length: 0,
+        release_number: 0,
+        resource_id_base: 0x0020_0000,
+        resource_id_mask: 0x001f_ffff,
+        motion_buffer_size: 256,
+        maximum_request_length: 0xffff,
+        image_byte_order: ImageOrder::LSB_FIRST,
+        bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+        bitmap_format_scanline_unit: 32,
+        bitmap_format_scanline_pad: 32,
+        min_keycode: 8,
+        max_keycode: 255,
+        vendor: b"x11rb".to_vec(),
+        pixmap_formats: Vec::new(),
+        roots: vec![screen],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::{FakeConnection, Fault, Response};
+    use crate::connection::{Connection, ReplyOrError, RequestConnection};
+    use crate::errors::{ConnectionError, ParseError, ReplyError};
+    use crate::protocol::xproto::{self, ConnectionExt as _};
+    use crate::protocol::{ErrorKind, Event};
+    use crate::x11_utils::ExtensionInformation;
+
+    #[test]
+    fn replies_and_errors() {
+        let conn = FakeConnection::default();
+        let mut reply = vec![0; 12];
+        reply[8..12].copy_from_slice(&7u32.to_ne_bytes());
+        conn.respond(
+            xproto::GET_INPUT_FOCUS_REQUEST,
+            None,
+            Response::Reply(reply),
+        );
+        conn.respond(
+            xproto::GET_GEOMETRY_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::DRAWABLE_ERROR,
+                bad_value: 5,
+            },
+        );
+
+        let window = conn.generate_id().unwrap();
+        assert_eq!(window, 0x0020_0000);
+        assert_eq!(conn.generate_id().unwrap(), 0x0020_0001);
+        let _ = conn.bell(0).unwrap();
+        let geometry = conn.get_geometry(5).unwrap();
+        assert_eq!(conn.get_input_focus().unwrap().reply().unwrap().focus, 7);
+        match geometry.reply() {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_kind, ErrorKind::Drawable);
+                assert_eq!(error.bad_value, 5);
+                assert_eq!(error.major_opcode, xproto::GET_GEOMETRY_REQUEST);
+                assert_eq!(error.sequence, 2);
+            }
+            r => panic!("Unexpected result: {:?}", r),
+        }
+        assert_eq!(conn.pending_responses(), 0);
+
+        let sent = conn.take_sent_requests();
+        let sent = sent
+            .iter()
+            .map(|request| (request.sequence, request.major_opcode()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sent,
+            [
+                (1, xproto::BELL_REQUEST),
+                (2, xproto::GET_GEOMETRY_REQUEST),
+                (3, xproto::GET_INPUT_FOCUS_REQUEST),
+            ]
+        );
+        assert!(conn.sent_requests().is_empty());
+    }
+
+    #[test]
+    fn unchecked_errors_become_events() {
+        let conn = FakeConnection::default();
+        conn.respond(
+            xproto::MAP_WINDOW_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::WINDOW_ERROR,
+                bad_value: 3,
+            },
+        );
+        conn.map_window(3).unwrap().ignore_error();
+        conn.map_window(3).unwrap().ignore_error();
+        assert!(conn.poll_for_event().unwrap().is_none());
+
+        conn.respond(
+            xproto::MAP_WINDOW_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::WINDOW_ERROR,
+                bad_value: 4,
+            },
+        );
+        // Dropping the cookie turns the error into an event
+        let _ = conn.map_window(4).unwrap();
+        conn.push_event(xproto::ExposeEvent {
+            response_type: xproto::EXPOSE_EVENT,
+            sequence: 0,
+            window: 4,
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+            count: 0,
+        });
+        match conn.wait_for_event_with_sequence().unwrap() {
+            (Event::Error(error), 3) => assert_eq!(error.bad_value, 4),
+            e => panic!("Unexpected event: {:?}", e),
+        }
+        match conn.wait_for_event_with_sequence().unwrap() {
+            (Event::Expose(event), 3) => {
+                assert_eq!(event.window, 4);
+                assert_eq!(event.sequence, 3);
+            }
+            e => panic!("Unexpected event: {:?}", e),
+        }
+        assert!(matches!(
+            conn.wait_for_event(),
+            Err(ConnectionError::IOError(_))
+        ));
+    }
+
+    #[test]
+    fn extension_requests() {
+        let conn = FakeConnection::default();
+        let info = ExtensionInformation {
+            major_opcode: 140,
+            first_event: 90,
+            first_error: 150,
+        };
+        conn.add_extension("XFIXES", info);
+        assert_eq!(conn.extension_information("XFIXES").unwrap(), Some(info));
+        assert_eq!(conn.extension_information("RANDR").unwrap(), None);
+
+        conn.respond(140, Some(1), Response::Reply(Vec::new()));
+        conn.respond(
+            140,
+            Some(0),
+            Response::Error {
+                error_code: 150,
+                bad_value: 0,
+            },
+        );
+        let cookie = conn
+            .send_request_without_reply(&[IoSlice::new(&[140, 0, 1, 0])], Vec::new())
+            .unwrap();
+        match cookie.check() {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_code, 150);
+                assert_eq!(error.major_opcode, 140);
+                assert_eq!(error.minor_opcode, 0);
+            }
+            r => panic!("Unexpected result: {:?}", r),
+        }
+        assert_eq!(conn.pending_responses(), 1);
+    }
+
+    #[test]
+    fn injected_faults() {
+        let conn = FakeConnection::default();
+        let focus_reply = |focus: u32| {
+            let mut reply = vec![0; 12];
+            reply[8..12].copy_from_slice(&focus.to_ne_bytes());
+            Response::Reply(reply)
+        };
+        for focus in 1..=5 {
+            conn.respond(xproto::GET_INPUT_FOCUS_REQUEST, None, focus_reply(focus));
+        }
+
+        conn.inject(Fault::Truncate(8));
+        match conn.get_input_focus().unwrap().reply() {
+            Err(ReplyError::ConnectionError(ConnectionError::ParseError(
+                ParseError::InsufficientData,
+            ))) => {}
+            r => panic!("Unexpected result: {:?}", r),
+        }
+
+        conn.inject(Fault::SwapResponses);
+        let first = conn.get_input_focus().unwrap();
+        let second = conn.get_input_focus().unwrap();
+        let second = second.reply().unwrap();
+        assert_eq!((second.focus, second.sequence), (2, 2));
+        assert_eq!(first.reply().unwrap().focus, 3);
+
+        conn.inject(Fault::Delay(2));
+        let sequence = conn.get_input_focus().unwrap().into_sequence_number();
+        assert!(conn
+            .poll_for_reply_or_raw_error(sequence)
+            .unwrap()
+            .is_none());
+        assert!(conn
+            .poll_for_reply_or_raw_error(sequence)
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            conn.poll_for_reply_or_raw_error(sequence).unwrap(),
+            Some(ReplyOrError::Reply(_))
+        ));
+
+        // Responses that were already received are still delivered after a disconnect
+        let cookie = conn.get_input_focus().unwrap();
+        conn.inject(Fault::Disconnect);
+        assert_eq!(cookie.reply().unwrap().focus, 5);
+        assert!(matches!(
+            conn.get_input_focus(),
+            Err(ConnectionError::IOError(_))
+        ));
+        assert!(matches!(
+            conn.poll_for_event(),
+            Err(ConnectionError::IOError(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn delayed_fds() {
+        use std::os::unix::io::OwnedFd;
+
+        let conn = FakeConnection::default();
+        let fd = || OwnedFd::from(std::fs::File::open("/dev/null").unwrap());
+        conn.respond_with_fds(140, None, Vec::new(), vec![fd()]);
+        conn.respond_with_fds(140, None, Vec::new(), vec![fd()]);
+        conn.inject(Fault::DelayFds);
+
+        let mut fd_counts = Vec::new();
+        for _ in 0..2 {
+            let sequence = conn
+                .send_request_with_reply::<xproto::GetInputFocusReply>(
+                    &[IoSlice::new(&[140, 0, 1, 0])],
+                    Vec::new(),
+                )
+                .unwrap()
+                .into_sequence_number();
+            match conn.wait_for_reply_with_fds_raw(sequence).unwrap() {
+                ReplyOrError::Reply((_, fds)) => fd_counts.push(fds.len()),
+                ReplyOrError::Error(e) => panic!("Unexpected error: {:?}", e),
+            }
+        }
+        assert_eq!(fd_counts, [0, 2]);
+    }
+}
@@ -0,0 +1,354 @@
+//! Starting an X11 server for integration tests.
+//!
+//! [`TestServer`] starts Xvfb or Xephyr on a free display, gives it a fresh
+//! `MIT-MAGIC-COOKIE-1` cookie, and stops it again when it is dropped:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::testing::server::TestServer;
+//!
+//! let server = TestServer::xvfb().unwrap();
+//! let (conn, screen_num) = server.connect().unwrap();
+//! println!("Running on {}", server.display_name());
+//! assert_eq!(conn.setup().roots.len(), screen_num + 1);
+//!
+//! // Other programs, e.g. the window manager under test, can use the server, too
+//! let mut wm = server.command("my-window-manager").spawn().unwrap();
+//! # let _ = wm.kill();
+//! ```
+//!
+//! The server picks the display number itself and reports it via its `-displayfd` argument, so
+//! that tests running in parallel do not race for display numbers. Only Unix is supported.
+
+use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
+use std::io::{Error, ErrorKind};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+
+use crate::errors::ConnectError;
+use crate::rust_connection::xauth::{self, AuthEntry, Family, MIT_MAGIC_COOKIE_1};
+use crate::rust_connection::RustConnection;
+use crate::utils::nix_error_to_io;
+
+/// How long a server may take to exit after SIGTERM before it is killed
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Used to give each server its own directory for the `.Xauthority` file
+static NEXT_SERVER: AtomicUsize = AtomicUsize::new(0);
+
+/// The X11 server to start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerKind {
+    /// Xvfb, a server without any output.
+    Xvfb,
+    /// Xephyr, a server that shows its screen in a window on another X11 server.
+    ///
+    /// This needs `$DISPLAY` to point to that other server.
+    Xephyr,
+}
+
+impl ServerKind {
+    fn program(self) -> &'static str {
+        match self {
+            ServerKind::Xvfb => "Xvfb",
+            ServerKind::Xephyr => "Xephyr",
+        }
+    }
+}
+
+/// Configuration for starting a [`TestServer`].
+#[derive(Debug, Clone)]
+pub struct TestServerBuilder {
+    kind: ServerKind,
+    program: OsString,
+    size: (u16, u16),
+    depth: u8,
+    args: Vec<OsString>,
+    timeout: Duration,
+}
+
+impl TestServerBuilder {
+    /// Start configuring a server of the given kind.
+    ///
+    /// By default, the server has a 1024x768 screen with depth 24 and has ten seconds to start.
+    pub fn new(kind: ServerKind) -> Self {
+        Self {
+            kind,
+            program: kind.program().into(),
+            size: (1024, 768),
+            depth: 24,
+            args: Vec::new(),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Set the program to run, e.g. if the server is not in `$PATH`.
+    pub fn program(mut self, program: impl Into<OsString>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Set the size of the screen in pixels.
+    pub fn screen_size(mut self, width: u16, height: u16) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Set the depth of the screen.
+    pub fn depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Add an argument for the server, e.g. `+extension GLX`.
+    pub fn arg(mut self, arg: impl Into<OsString>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Set how long to wait for the server to start.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Start the server and wait until it accepts connections.
+    pub fn spawn(&self) -> Result<TestServer, Error> {
+        let auth_dir = std::env::temp_dir().join(format!(
+            "x11rb-test-server-{}-{}",
+            std::process::id(),
+            NEXT_SERVER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&auth_dir)?;
+        let mut server = TestServer {
+            child: None,
+            display: 0,
+            auth_file: auth_dir.join("Xauthority"),
+            auth_dir,
+            cookie: xauth::generate_cookie()?,
+        };
+        // The server reads all entries of its -auth file, no matter the address or display
+        let entry = AuthEntry {
+            family: Family::WILD,
+            address: Vec::new(),
+            number: Vec::new(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: server.cookie.clone(),
+        };
+        xauth::write_entries(&server.auth_file, &[entry])?;
+
+        // The server writes its display number to the write end of this socket pair once it is
+        // ready. Both ends are created with FD_CLOEXEC, but the server has to inherit the write end.
+        let (read_end, write_end) = UnixStream::pair()?;
+        let _ = fcntl(write_end.as_raw_fd(), FcntlArg::F_SETFD(FdFlag::empty()))
+            .map_err(nix_error_to_io)?;
+
+        let (width, height) = self.size;
+        let screen = format!("{}x{}x{}", width, height, self.depth);
+        let mut command = Command::new(&self.program);
+        let _ = command
+            .arg("-displayfd")
+            .arg(write_end.as_raw_fd().to_string())
+            .arg("-auth")
+            .arg(&server.auth_file)
+            .args(["-nolisten", "tcp"]);
+        let _ = match self.kind {
+            ServerKind::Xvfb => command.args(["-screen", "0", screen.as_str()]),
+            ServerKind::Xephyr => command.args(["-screen", screen.as_str()]),
+        };
+        let _ = command
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        server.child = Some(command.spawn()?);
+        // Only the server may keep the write end open, so that we notice when it exits
+        drop(write_end);
+
+        server.display = read_display(&read_end, self.timeout)?;
+        Ok(server)
+    }
+}
+
+/// Read the display number that the server writes to its `-displayfd`.
+fn read_display(fd: &UnixStream, timeout: Duration) -> Result<u16, Error> {
+    let deadline = Instant::now() + timeout;
+    let mut output = Vec::new();
+    loop {
+        if let Some(line_end) = output.iter().position(|&byte| byte == b'\n') {
+            return parse_display_number(&output[..line_end]).ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, "X11 server reported no display")
+            });
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let millis = i32::try_from(remaining.as_millis()).unwrap_or(i32::MAX);
+        let mut poll_fds = [PollFd::new(fd.as_raw_fd(), PollFlags::POLLIN)];
+        if poll(&mut poll_fds, millis).map_err(nix_error_to_io)? == 0 {
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                "timeout while waiting for the X11 server to start",
+            ));
+        }
+        let mut buffer = [0; 16];
+        let length = nix::unistd::read(fd.as_raw_fd(), &mut buffer).map_err(nix_error_to_io)?;
+        if length == 0 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "X11 server exited before it was ready",
+            ));
+        }
+        output.extend_from_slice(&buffer[..length]);
+    }
+}
+
+fn parse_display_number(line: &[u8]) -> Option<u16> {
+    std::str::from_utf8(line).ok()?.trim().parse().ok()
+}
+
+/// A running X11 server for tests.
+///
+/// The server is killed when this is dropped.
+#[derive(Debug)]
+pub struct TestServer {
+    child: Option<Child>,
+    display: u16,
+    auth_dir: PathBuf,
+    auth_file: PathBuf,
+    cookie: Vec<u8>,
+}
+
+impl TestServer {
+    /// Start Xvfb with the default settings of [`TestServerBuilder`].
+    pub fn xvfb() -> Result<Self, Error> {
+        TestServerBuilder::new(ServerKind::Xvfb).spawn()
+    }
+
+    /// Start Xephyr with the default settings of [`TestServerBuilder`].
+    pub fn xephyr() -> Result<Self, Error> {
+        TestServerBuilder::new(ServerKind::Xephyr).spawn()
+    }
+
+    /// Get the number of the display that the server runs on.
+    pub fn display(&self) -> u16 {
+        self.display
+    }
+
+    /// Get the name of the display, e.g. `:1`, for use in `$DISPLAY`.
+    pub fn display_name(&self) -> String {
+        format!(":{}", self.display)
+    }
+
+    /// Get the `.Xauthority` file that contains the cookie for the server.
+    pub fn auth_file(&self) -> &Path {
+        &self.auth_file
+    }
+
+    /// Connect to the server.
+    pub fn connect(&self) -> Result<(RustConnection, usize), ConnectError> {
+        RustConnection::connect_with_auth(
+            Some(&self.display_name()),
+            MIT_MAGIC_COOKIE_1.to_vec(),
+            self.cookie.clone(),
+        )
+    }
+
+    /// Prepare a command that runs a program as a client of the server.
+    ///
+    /// This sets `$DISPLAY` and `$XAUTHORITY` for the program.
+    pub fn command(&self, program: impl AsRef<OsStr>) -> Command {
+        let mut command = Command::new(program);
+        let _ = command
+            .env("DISPLAY", self.display_name())
+            .env("XAUTHORITY", &self.auth_file);
+        command
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            // SIGTERM lets the server remove its lock file and socket
+            let deadline = Instant::now() + EXIT_TIMEOUT;
+            if kill(Pid::from_raw(child.id() as i32), Signal::SIGTERM).is_ok() {
+                while matches!(child.try_wait(), Ok(None)) && Instant::now() < deadline {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+            }
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        let _ = std::fs::remove_dir_all(&self.auth_dir);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_display_number, ServerKind, TestServerBuilder};
+    use crate::rust_connection::xauth::{read_entries, MIT_MAGIC_COOKIE_1};
+    use std::io::ErrorKind;
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::Duration;
+
+    #[test]
+    fn display_number() {
+        assert_eq!(parse_display_number(b"42"), Some(42));
+        assert_eq!(parse_display_number(b" 7\r"), Some(7));
+        assert_eq!(parse_display_number(b""), None);
+        assert_eq!(parse_display_number(b"x"), None);
+    }
+
+    // Run a shell script instead of a real X11 server
+    fn fake_server(name: &str, script: &str) -> TestServerBuilder {
+        let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+        std::fs::set_permissions(&path, PermissionsExt::from_mode(0o700)).unwrap();
+        TestServerBuilder::new(ServerKind::Xvfb)
+            .program(path)
+            .timeout(Duration::from_secs(5))
+    }
+
+    #[test]
+    fn displayfd_handshake() {
+        // The first two arguments are -displayfd and the file descriptor
+        let builder = fake_server("x11rb-fake-xvfb", "echo 42 >&$2\nexec sleep 60");
+        let server = builder.spawn().unwrap();
+        assert_eq!(server.display(), 42);
+        assert_eq!(server.display_name(), ":42");
+        let entries = read_entries(server.auth_file()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, MIT_MAGIC_COOKIE_1);
+        assert_eq!(entries[0].data, server.cookie);
+
+        let auth_dir = server.auth_dir.clone();
+        drop(server);
+        assert!(!auth_dir.exists());
+    }
+
+    #[test]
+    fn server_fails() {
+        let builder = fake_server("x11rb-failing-xvfb", "exit 1");
+        let error = builder.spawn().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let builder = fake_server("x11rb-slow-xvfb", "exec sleep 60");
+        let error = builder
+            .timeout(Duration::from_millis(10))
+            .spawn()
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TimedOut);
+
+        let builder = TestServerBuilder::new(ServerKind::Xvfb).program("/nonexistent/Xvfb");
+        let error = builder.spawn().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+}
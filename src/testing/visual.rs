@@ -0,0 +1,921 @@
+//! Comparing the contents of windows with golden images.
+//!
+//! Visual regression tests render something, take a screenshot, and compare it with a reference
+//! image, the "golden" image, that was checked into the repository. [`VisualTest`] starts an Xvfb
+//! server for this (see [`super::server`]), [`RgbImage::capture`] takes the screenshot, and
+//! [`compare_with_golden`] compares it:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::testing::visual::{compare_with_golden, Tolerance, VisualTest};
+//! # fn create_and_draw_window(_: &impl Connection, _: usize) -> u32 { 0 }
+//!
+//! let test = VisualTest::new(640, 480).unwrap();
+//! let window = create_and_draw_window(test.conn(), test.screen_num());
+//! let screenshot = test.capture(window).unwrap();
+//! compare_with_golden(&screenshot, "tests/golden/window.png", Tolerance::default()).unwrap();
+//! ```
+//!
+//! If the images differ, the screenshot and an image that highlights the differences are written
+//! next to the golden image, e.g. as `window.actual.png` and `window.diff.png`. When the
+//! `X11RB_UPDATE_GOLDEN` environment variable is set, the golden images are overwritten with the
+//! screenshots instead, which is how golden images are created in the first place.
+//!
+//! The comparison is perceptual: colors are compared in the YIQ color space, which weighs
+//! brightness more than hue, like the human eye does. Small differences, e.g. from different
+//! anti-aliasing, can be tolerated via [`Tolerance`].
+//!
+//! The code in this module is only available when the `image` feature of the library is enabled.
+//! [`VisualTest`] only exists on Unix.
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::connection::Connection;
+use crate::dump::{adler32, crc32, encode_png};
+use crate::errors::{ConnectError, ConnectionError, ReplyError, ReplyOrIdError};
+use crate::image::{Image, PixelLayout};
+use crate::protocol::xproto::{self, Drawable};
+
+/// The environment variable that makes [`compare_with_golden`] update the golden images.
+pub const UPDATE_GOLDEN_VAR: &str = "X11RB_UPDATE_GOLDEN";
+
+/// The largest possible difference between two colors in the YIQ color space.
+const MAX_YIQ_DIFFERENCE: f64 = 35215.0;
+
+/// An error of a visual regression test.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VisualError {
+    /// Starting the X11 server or accessing an image file failed.
+    Io(std::io::Error),
+    /// Connecting to the X11 server failed.
+    Connect(ConnectError),
+    /// Taking a screenshot failed.
+    Reply(ReplyOrIdError),
+    /// A golden image is not a PNG file that this module can read.
+    InvalidPng(&'static str),
+    /// The golden image does not exist.
+    MissingGolden(PathBuf),
+    /// The screenshot has a different size than the golden image.
+    SizeMismatch {
+        /// The path of the golden image.
+        golden: PathBuf,
+        /// The size of the golden image.
+        expected: (u16, u16),
+        /// The size of the screenshot.
+        actual: (u16, u16),
+    },
+    /// The screenshot differs from the golden image by more than the tolerance.
+    Mismatch {
+        /// The path of the golden image.
+        golden: PathBuf,
+        /// The number of pixels that differ.
+        different_pixels: usize,
+        /// The largest difference of a pixel, see [`Comparison::max_difference`].
+        max_difference: f64,
+    },
+}
+
+impl fmt::Display for VisualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VisualError::Io(err) => write!(f, "{}", err),
+            VisualError::Connect(err) => write!(f, "{}", err),
+            VisualError::Reply(err) => write!(f, "{}", err),
+            VisualError::InvalidPng(reason) => write!(f, "Invalid PNG file: {}", reason),
+            VisualError::MissingGolden(path) => write!(
+                f,
+                "Golden image {} does not exist, set {} to create it",
+                path.display(),
+                UPDATE_GOLDEN_VAR
+            ),
+            VisualError::SizeMismatch {
+                golden,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The screenshot is {}x{}, but golden image {} is {}x{}",
+                actual.0,
+                actual.1,
+                golden.display(),
+                expected.0,
+                expected.1
+            ),
+            VisualError::Mismatch {
+                golden,
+                different_pixels,
+                max_difference,
+            } => write!(
+                f,
+                "{} pixels differ from golden image {} (largest difference {:.3})",
+                different_pixels,
+                golden.display(),
+                max_difference
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VisualError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VisualError::Io(err) => Some(err),
+            VisualError::Connect(err) => Some(err),
+            VisualError::Reply(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for VisualError {
+    fn from(err: std::io::Error) -> Self {
+        VisualError::Io(err)
+    }
+}
+
+impl From<ConnectError> for VisualError {
+    fn from(err: ConnectError) -> Self {
+        VisualError::Connect(err)
+    }
+}
+
+impl From<ConnectionError> for VisualError {
+    fn from(err: ConnectionError) -> Self {
+        VisualError::Reply(err.into())
+    }
+}
+
+impl From<ReplyError> for VisualError {
+    fn from(err: ReplyError) -> Self {
+        VisualError::Reply(err.into())
+    }
+}
+
+impl From<ReplyOrIdError> for VisualError {
+    fn from(err: ReplyOrIdError) -> Self {
+        VisualError::Reply(err)
+    }
+}
+
+/// An image with 8 bits per color channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbImage {
+    width: u16,
+    height: u16,
+    data: Vec<u8>,
+}
+
+impl RgbImage {
+    /// Create an image from its pixels, given as red, green and blue bytes row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not have `3 * width * height` bytes.
+    pub fn new(width: u16, height: u16, data: Vec<u8>) -> Self {
+        assert_eq!(
+            data.len(),
+            3 * usize::from(width) * usize::from(height),
+            "The image data does not match the size"
+        );
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Convert an image from the X11 server.
+    pub fn from_image(image: &Image<'_>, layout: PixelLayout) -> Self {
+        let (width, height) = (image.width(), image.height());
+        let mut data = Vec::with_capacity(3 * usize::from(width) * usize::from(height));
+        for y in 0..height {
+            for x in 0..width {
+                let (red, green, blue) = layout.decode(image.get_pixel(x, y));
+                data.extend_from_slice(&[(red >> 8) as u8, (green >> 8) as u8, (blue >> 8) as u8]);
+            }
+        }
+        Self::new(width, height, data)
+    }
+
+    /// Take a screenshot of a window or pixmap.
+    ///
+    /// A window must be viewable and completely on screen. Parts of it that are obscured by other
+    /// windows might contain garbage.
+    pub fn capture<C: Connection + ?Sized>(
+        conn: &C,
+        drawable: Drawable,
+    ) -> Result<Self, ReplyError> {
+        let geometry = xproto::get_geometry(conn, drawable)?.reply()?;
+        let (image, layout) =
+            Image::get_with_layout(conn, drawable, 0, 0, geometry.width, geometry.height)?;
+        Ok(Self::from_image(&image, layout))
+    }
+
+    /// Get the width of the image.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Get the height of the image.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Get the pixels, as red, green and blue bytes row by row.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the color of the pixel at `(x, y)`.
+    pub fn pixel(&self, x: u16, y: u16) -> [u8; 3] {
+        let index = 3 * (usize::from(y) * usize::from(self.width) + usize::from(x));
+        [self.data[index], self.data[index + 1], self.data[index + 2]]
+    }
+
+    /// Encode the image as PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(u32::from(self.width), u32::from(self.height), &self.data)
+    }
+
+    /// Decode a PNG image.
+    ///
+    /// Only 8 bits per channel and no interlacing are supported. Alpha channels are ignored.
+    pub fn from_png(png: &[u8]) -> Result<Self, VisualError> {
+        png::decode(png).map_err(VisualError::InvalidPng)
+    }
+
+    /// Compare this image with the expected one.
+    ///
+    /// Returns `None` if the images have different sizes.
+    pub fn compare(&self, expected: &RgbImage, tolerance: Tolerance) -> Option<Comparison> {
+        if (self.width, self.height) != (expected.width, expected.height) {
+            return None;
+        }
+        let mut different_pixels = 0;
+        let mut max_difference: f64 = 0.0;
+        let mut diff = Vec::with_capacity(self.data.len());
+        for (actual, expected) in self.data.chunks_exact(3).zip(expected.data.chunks_exact(3)) {
+            let difference = color_difference(actual, expected);
+            max_difference = max_difference.max(difference);
+            if difference > tolerance.threshold {
+                different_pixels += 1;
+                diff.extend_from_slice(&[255, 0, 0]);
+            } else {
+                // A faded version of the expected image gives some orientation
+                let brightness = (yiq(expected).0 / 255.0).clamp(0.0, 1.0);
+                let faded = 255 - ((1.0 - brightness) * 64.0) as u8;
+                diff.extend_from_slice(&[faded; 3]);
+            }
+        }
+        Some(Comparison {
+            different_pixels,
+            max_difference,
+            matches: different_pixels <= tolerance.max_different_pixels,
+            diff: RgbImage::new(self.width, self.height, diff),
+        })
+    }
+}
+
+/// Convert an 8-bit RGB color to the YIQ color space.
+fn yiq(rgb: &[u8]) -> (f64, f64, f64) {
+    let (r, g, b) = (f64::from(rgb[0]), f64::from(rgb[1]), f64::from(rgb[2]));
+    (
+        0.298_895_31 * r + 0.586_622_47 * g + 0.114_482_23 * b,
+        0.595_977_99 * r - 0.274_176_10 * g - 0.321_801_89 * b,
+        0.211_470_17 * r - 0.522_617_11 * g + 0.311_146_94 * b,
+    )
+}
+
+/// Get the perceptual difference between two colors, from 0 for equal colors to almost 1 for black
+/// and white.
+fn color_difference(a: &[u8], b: &[u8]) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    let (a, b) = (yiq(a), yiq(b));
+    let (y, i, q) = (a.0 - b.0, a.1 - b.1, a.2 - b.2);
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+    (delta / MAX_YIQ_DIFFERENCE).sqrt().min(1.0)
+}
+
+/// How much a screenshot may differ from the golden image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// The perceptual difference, from 0 to 1, above which a pixel counts as different.
+    pub threshold: f64,
+    /// The number of pixels that may differ.
+    pub max_different_pixels: usize,
+}
+
+impl Tolerance {
+    /// Require that all pixels are exactly equal.
+    pub const EXACT: Self = Self {
+        threshold: 0.0,
+        max_different_pixels: 0,
+    };
+}
+
+impl Default for Tolerance {
+    /// Ignore very small color differences, but require all pixels to match otherwise.
+    fn default() -> Self {
+        Self {
+            threshold: 0.1,
+            max_different_pixels: 0,
+        }
+    }
+}
+
+/// The result of [`RgbImage::compare`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// The number of pixels whose difference is above the threshold.
+    pub different_pixels: usize,
+    /// The largest difference of a pixel, from 0 to 1.
+    pub max_difference: f64,
+    /// Whether the images match within the tolerance.
+    pub matches: bool,
+    /// An image in which the different pixels are red and all others are a faded version of the
+    /// expected image.
+    pub diff: RgbImage,
+}
+
+/// Compare a screenshot with a golden image.
+///
+/// If the images differ, the screenshot and the highlighted differences are written next to the
+/// golden image, see the [module documentation](self). If the [`UPDATE_GOLDEN_VAR`] environment
+/// variable is set, the golden image is overwritten with the screenshot instead.
+pub fn compare_with_golden(
+    actual: &RgbImage,
+    golden: impl AsRef<Path>,
+    tolerance: Tolerance,
+) -> Result<(), VisualError> {
+    let golden = golden.as_ref();
+    if std::env::var_os(UPDATE_GOLDEN_VAR).is_some() {
+        if let Some(dir) = golden.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(golden, actual.to_png())?;
+        return Ok(());
+    }
+    let expected = match std::fs::read(golden) {
+        Ok(png) => RgbImage::from_png(&png)?,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            std::fs::write(golden.with_extension("actual.png"), actual.to_png())?;
+            return Err(VisualError::MissingGolden(golden.to_path_buf()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let comparison = match actual.compare(&expected, tolerance) {
+        Some(comparison) => comparison,
+        None => {
+            std::fs::write(golden.with_extension("actual.png"), actual.to_png())?;
+            return Err(VisualError::SizeMismatch {
+                golden: golden.to_path_buf(),
+                expected: (expected.width, expected.height),
+                actual: (actual.width, actual.height),
+            });
+        }
+    };
+    if comparison.matches {
+        return Ok(());
+    }
+    std::fs::write(golden.with_extension("actual.png"), actual.to_png())?;
+    std::fs::write(golden.with_extension("diff.png"), comparison.diff.to_png())?;
+    Err(VisualError::Mismatch {
+        golden: golden.to_path_buf(),
+        different_pixels: comparison.different_pixels,
+        max_difference: comparison.max_difference,
+    })
+}
+
+/// An Xvfb server and a connection to it for visual regression tests.
+///
+/// The server is stopped when this is dropped.
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct VisualTest {
+    conn: crate::rust_connection::RustConnection,
+    screen_num: usize,
+    server: super::server::TestServer,
+}
+
+#[cfg(unix)]
+impl VisualTest {
+    /// Start Xvfb with a screen of the given size and depth 24 and connect to it.
+    pub fn new(width: u16, height: u16) -> Result<Self, VisualError> {
+        let builder = super::server::TestServerBuilder::new(super::server::ServerKind::Xvfb)
+            .screen_size(width, height);
+        Self::with_server(builder.spawn()?)
+    }
+
+    /// Connect to an already started server.
+    pub fn with_server(server: super::server::TestServer) -> Result<Self, VisualError> {
+        let (conn, screen_num) = server.connect()?;
+        Ok(Self {
+            conn,
+            screen_num,
+            server,
+        })
+    }
+
+    /// Get the connection to the server.
+    pub fn conn(&self) -> &crate::rust_connection::RustConnection {
+        &self.conn
+    }
+
+    /// Get the number of the screen that the connection uses.
+    pub fn screen_num(&self) -> usize {
+        self.screen_num
+    }
+
+    /// Get the server, e.g. to start the program under test as its client.
+    pub fn server(&self) -> &super::server::TestServer {
+        &self.server
+    }
+
+    /// Take a screenshot of a window, see [`RgbImage::capture`].
+    ///
+    /// All requests that were sent before are processed first.
+    pub fn capture(&self, window: Drawable) -> Result<RgbImage, VisualError> {
+        Ok(RgbImage::capture(&self.conn, window)?)
+    }
+
+    /// Wait until the window presents its next frame and take a screenshot of it.
+    ///
+    /// See [`crate::frame_capture::capture_next_frame`] for the details. This requires the
+    /// `present` feature of the library.
+    #[cfg(feature = "present")]
+    pub fn capture_next_frame(&self, window: xproto::Window) -> Result<RgbImage, VisualError> {
+        let visual = xproto::get_window_attributes(&self.conn, window)?
+            .reply()?
+            .visual;
+        let visual_type = self
+            .conn
+            .setup()
+            .roots
+            .iter()
+            .flat_map(|screen| &screen.allowed_depths)
+            .flat_map(|depth| &depth.visuals)
+            .find(|visual_type| visual_type.visual_id == visual)
+            .ok_or(ReplyError::from(crate::errors::ParseError::InvalidValue))?;
+        let layout = PixelLayout::from_visual_type(*visual_type).map_err(ReplyError::from)?;
+        let frame = crate::frame_capture::capture_next_frame(&self.conn, window)?;
+        Ok(RgbImage::from_image(&frame.image, layout))
+    }
+}
+
+/// A decoder for the PNG files that are used as golden images.
+mod png {
+    use super::{adler32, crc32, RgbImage};
+    use std::convert::TryFrom;
+
+    type Result<T> = std::result::Result<T, &'static str>;
+
+    pub(super) fn decode(png: &[u8]) -> Result<RgbImage> {
+        let mut rest = png
+            .strip_prefix(b"\x89PNG\r\n\x1a\n")
+            .ok_or("missing PNG signature")?;
+        let mut header = None;
+        let mut zlib = Vec::new();
+        loop {
+            if rest.len() < 12 {
+                return Err("truncated chunk");
+            }
+            let length = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            let chunk = rest.get(4..8 + length).ok_or("truncated chunk")?;
+            let crc = rest.get(8 + length..12 + length).ok_or("truncated chunk")?;
+            if crc32(chunk).to_be_bytes() != crc {
+                return Err("wrong chunk checksum");
+            }
+            let (kind, data) = chunk.split_at(4);
+            match kind {
+                b"IHDR" => header = Some(Header::parse(data)?),
+                b"IDAT" => zlib.extend_from_slice(data),
+                b"IEND" => break,
+                // Critical chunks have an uppercase first letter
+                _ if kind[0].is_ascii_uppercase() && kind != b"PLTE" => {
+                    return Err("unsupported critical chunk")
+                }
+                _ => {}
+            }
+            rest = &rest[12 + length..];
+        }
+        let header = header.ok_or("missing IHDR chunk")?;
+        let raw = zlib_decompress(&zlib)?;
+        header.unfilter(&raw)
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Header {
+        width: u16,
+        height: u16,
+        channels: usize,
+    }
+
+    impl Header {
+        fn parse(data: &[u8]) -> Result<Self> {
+            if data.len() != 13 {
+                return Err("invalid IHDR chunk");
+            }
+            let width = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+            let height = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
+            let (width, height) = u16::try_from(width)
+                .ok()
+                .zip(u16::try_from(height).ok())
+                .ok_or("image is too large")?;
+            if data[8] != 8 {
+                return Err("only 8 bits per channel are supported");
+            }
+            let channels = match data[9] {
+                0 => 1,
+                2 => 3,
+                4 => 2,
+                6 => 4,
+                _ => return Err("unsupported color type"),
+            };
+            if data[12] != 0 {
+                return Err("interlacing is not supported");
+            }
+            Ok(Self {
+                width,
+                height,
+                channels,
+            })
+        }
+
+        /// Undo the per-scanline filters and convert the pixels to RGB.
+        fn unfilter(self, raw: &[u8]) -> Result<RgbImage> {
+            let bpp = self.channels;
+            let stride = bpp * usize::from(self.width);
+            if raw.len() < (stride + 1) * usize::from(self.height) {
+                return Err("not enough image data");
+            }
+            let mut previous = vec![0; stride];
+            let mut line = vec![0; stride];
+            let mut data =
+                Vec::with_capacity(3 * usize::from(self.width) * usize::from(self.height));
+            for filtered in raw.chunks_exact(stride + 1).take(self.height.into()) {
+                let (filter, filtered) = (filtered[0], &filtered[1..]);
+                for i in 0..stride {
+                    let left = if i >= bpp { line[i - bpp] } else { 0 };
+                    let up = previous[i];
+                    let up_left = if i >= bpp { previous[i - bpp] } else { 0 };
+                    let predictor = match filter {
+                        0 => 0,
+                        1 => left,
+                        2 => up,
+                        3 => ((u16::from(left) + u16::from(up)) / 2) as u8,
+                        4 => paeth(left, up, up_left),
+                        _ => return Err("unknown filter type"),
+                    };
+                    line[i] = filtered[i].wrapping_add(predictor);
+                }
+                for pixel in line.chunks_exact(bpp) {
+                    match bpp {
+                        1 | 2 => data.extend_from_slice(&[pixel[0]; 3]),
+                        _ => data.extend_from_slice(&pixel[..3]),
+                    }
+                }
+                std::mem::swap(&mut previous, &mut line);
+            }
+            Ok(RgbImage::new(self.width, self.height, data))
+        }
+    }
+
+    fn paeth(a: u8, b: u8, c: u8) -> u8 {
+        let p = i16::from(a) + i16::from(b) - i16::from(c);
+        let (pa, pb, pc) = (
+            (p - i16::from(a)).abs(),
+            (p - i16::from(b)).abs(),
+            (p - i16::from(c)).abs(),
+        );
+        if pa <= pb && pa <= pc {
+            a
+        } else if pb <= pc {
+            b
+        } else {
+            c
+        }
+    }
+
+    pub(super) fn zlib_decompress(zlib: &[u8]) -> Result<Vec<u8>> {
+        if zlib.len() < 6 || zlib[0] & 0x0f != 8 || zlib[1] & 0x20 != 0 {
+            return Err("unsupported zlib stream");
+        }
+        if (u16::from(zlib[0]) << 8 | u16::from(zlib[1])) % 31 != 0 {
+            return Err("invalid zlib header");
+        }
+        let (deflate, checksum) = zlib[2..].split_at(zlib.len() - 6);
+        let data = inflate(deflate)?;
+        if adler32(&data).to_be_bytes() != checksum {
+            return Err("wrong zlib checksum");
+        }
+        Ok(data)
+    }
+
+    const LENGTH_BASE: [u16; 29] = [
+        3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+        131, 163, 195, 227, 258,
+    ];
+    const LENGTH_EXTRA: [u8; 29] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+    ];
+    const DISTANCE_BASE: [u16; 30] = [
+        1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+        2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+    ];
+    const DISTANCE_EXTRA: [u8; 30] = [
+        0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12,
+        13, 13,
+    ];
+    /// The order in which the code lengths of the code length alphabet are stored.
+    const CODE_LENGTH_ORDER: [usize; 19] = [
+        16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+    ];
+
+    /// Reads bits starting with the least significant bit of each byte.
+    #[derive(Debug)]
+    struct BitReader<'a> {
+        data: &'a [u8],
+        position: usize,
+    }
+
+    impl BitReader<'_> {
+        fn bits(&mut self, count: u8) -> Result<u32> {
+            let mut value = 0;
+            for i in 0..count {
+                let byte = self.data.get(self.position / 8).ok_or("truncated data")?;
+                let bit = (byte >> (self.position % 8)) & 1;
+                value |= u32::from(bit) << i;
+                self.position += 1;
+            }
+            Ok(value)
+        }
+
+        fn bytes(&mut self, count: usize) -> Result<&[u8]> {
+            self.position = (self.position + 7) & !7;
+            let start = self.position / 8;
+            let bytes = self
+                .data
+                .get(start..start + count)
+                .ok_or("truncated data")?;
+            self.position += 8 * count;
+            Ok(bytes)
+        }
+    }
+
+    /// A canonical Huffman code.
+    #[derive(Debug)]
+    struct Huffman {
+        /// The number of codes of each length
+        counts: [u16; 16],
+        /// The symbols, ordered by their codes
+        symbols: Vec<u16>,
+    }
+
+    impl Huffman {
+        fn new(lengths: &[u8]) -> Self {
+            let mut counts = [0; 16];
+            for &length in lengths {
+                counts[usize::from(length)] += 1;
+            }
+            counts[0] = 0;
+            let mut offsets = [0; 16];
+            for length in 1..15 {
+                offsets[length + 1] = offsets[length] + counts[length];
+            }
+            let mut symbols = vec![0; lengths.len()];
+            for (symbol, &length) in lengths.iter().enumerate() {
+                if length != 0 {
+                    let offset = &mut offsets[usize::from(length)];
+                    symbols[usize::from(*offset)] = symbol as u16;
+                    *offset += 1;
+                }
+            }
+            Self { counts, symbols }
+        }
+
+        fn decode(&self, reader: &mut BitReader<'_>) -> Result<u16> {
+            let (mut code, mut first, mut index) = (0u32, 0u32, 0u32);
+            for &count in &self.counts[1..] {
+                code |= reader.bits(1)?;
+                let count = u32::from(count);
+                if code < first + count {
+                    return Ok(self.symbols[(index + code - first) as usize]);
+                }
+                index += count;
+                first = (first + count) << 1;
+                code <<= 1;
+            }
+            Err("invalid Huffman code")
+        }
+    }
+
+    fn inflate(deflate: &[u8]) -> Result<Vec<u8>> {
+        let mut reader = BitReader {
+            data: deflate,
+            position: 0,
+        };
+        let mut output = Vec::new();
+        loop {
+            let last = reader.bits(1)? == 1;
+            match reader.bits(2)? {
+                0 => {
+                    let header = reader.bytes(4)?;
+                    let length = u16::from_le_bytes([header[0], header[1]]);
+                    if length != !u16::from_le_bytes([header[2], header[3]]) {
+                        return Err("invalid stored block");
+                    }
+                    output.extend_from_slice(reader.bytes(length.into())?);
+                }
+                1 => {
+                    let mut lengths = [0; 288];
+                    lengths[..144].fill(8);
+                    lengths[144..256].fill(9);
+                    lengths[256..280].fill(7);
+                    lengths[280..].fill(8);
+                    let literals = Huffman::new(&lengths);
+                    let distances = Huffman::new(&[5; 30]);
+                    inflate_block(&mut reader, &literals, &distances, &mut output)?;
+                }
+                2 => {
+                    let (literals, distances) = read_dynamic_codes(&mut reader)?;
+                    inflate_block(&mut reader, &literals, &distances, &mut output)?;
+                }
+                _ => return Err("invalid block type"),
+            }
+            if last {
+                return Ok(output);
+            }
+        }
+    }
+
+    fn read_dynamic_codes(reader: &mut BitReader<'_>) -> Result<(Huffman, Huffman)> {
+        let literal_count = reader.bits(5)? as usize + 257;
+        let distance_count = reader.bits(5)? as usize + 1;
+        let code_length_count = reader.bits(4)? as usize + 4;
+        let mut code_lengths = [0; 19];
+        for &index in &CODE_LENGTH_ORDER[..code_length_count] {
+            code_lengths[index] = reader.bits(3)? as u8;
+        }
+        let code_lengths = Huffman::new(&code_lengths);
+
+        let mut lengths = Vec::with_capacity(literal_count + distance_count);
+        while lengths.len() < literal_count + distance_count {
+            let (value, repeat) = match code_lengths.decode(reader)? {
+                symbol @ 0..=15 => (symbol as u8, 1),
+                16 => (
+                    *lengths
+                        .last()
+                        .ok_or("repeated code length without a previous one")?,
+                    3 + reader.bits(2)?,
+                ),
+                17 => (0, 3 + reader.bits(3)?),
+                _ => (0, 11 + reader.bits(7)?),
+            };
+            lengths.resize(lengths.len() + repeat as usize, value);
+        }
+        if lengths.len() != literal_count + distance_count {
+            return Err("too many code lengths");
+        }
+        let (literals, distances) = lengths.split_at(literal_count);
+        Ok((Huffman::new(literals), Huffman::new(distances)))
+    }
+
+    fn inflate_block(
+        reader: &mut BitReader<'_>,
+        literals: &Huffman,
+        distances: &Huffman,
+        output: &mut Vec<u8>,
+    ) -> Result<()> {
+        loop {
+            let symbol = usize::from(literals.decode(reader)?);
+            match symbol {
+                0..=255 => output.push(symbol as u8),
+                256 => return Ok(()),
+                _ => {
+                    let index = symbol - 257;
+                    let base = *LENGTH_BASE.get(index).ok_or("invalid length code")?;
+                    let length = usize::from(base) + reader.bits(LENGTH_EXTRA[index])? as usize;
+                    let index = usize::from(distances.decode(reader)?);
+                    let base = *DISTANCE_BASE.get(index).ok_or("invalid distance code")?;
+                    let distance = usize::from(base) + reader.bits(DISTANCE_EXTRA[index])? as usize;
+                    let start = output
+                        .len()
+                        .checked_sub(distance)
+                        .ok_or("distance is too far back")?;
+                    // The copied range may overlap with the bytes that are being written
+                    for i in start..start + length {
+                        output.push(output[i]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::png::zlib_decompress;
+    use super::{compare_with_golden, RgbImage, Tolerance, VisualError};
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn inflate() {
+        // Produced by zlib with fixed and with dynamic Huffman codes
+        let fixed = from_hex("78dacb48cdc9c957c8402701680308b1");
+        assert_eq!(zlib_decompress(&fixed).unwrap(), b"hello hello hello hello");
+        let dynamic =
+            from_hex("78da1dc6410d00000880c0acc0ec5fc1297c4e2041f963eaf49d748805394f0f4a");
+        assert_eq!(
+            zlib_decompress(&dynamic).unwrap(),
+            b"baaacbaabbabababcaeccbabaaaaaacbabbbeaca"
+        );
+        assert!(zlib_decompress(&fixed[..fixed.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let image = RgbImage::new(2, 2, (0..12).collect());
+        assert_eq!(RgbImage::from_png(&image.to_png()).unwrap(), image);
+    }
+
+    #[test]
+    fn compressed_png() {
+        // An RGBA image with the Sub and Paeth filters, compressed by zlib
+        let png = from_hex(concat!(
+            "89504e470d0a1a0a0000000d49484452000000030000000208060000009d74661a0000002249444154",
+            "78da63fccfc0f09ff13f432303e3ff06166e1139060d63b97ab980e87a006d4a07828260ed03000000",
+            "0049454e44ae426082",
+        ));
+        let image = RgbImage::from_png(&png).unwrap();
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.pixel(0, 0), [255, 0, 0]);
+        assert_eq!(image.pixel(1, 0), [0, 255, 0]);
+        assert_eq!(image.pixel(2, 0), [0, 0, 255]);
+        assert_eq!(image.pixel(0, 1), [10, 20, 30]);
+        assert_eq!(image.pixel(2, 1), [70, 80, 90]);
+    }
+
+    #[test]
+    fn perceptual_comparison() {
+        let expected = RgbImage::new(3, 1, vec![0, 0, 0, 100, 100, 100, 255, 255, 255]);
+        let actual = RgbImage::new(3, 1, vec![0, 0, 0, 102, 100, 100, 0, 0, 0]);
+
+        let comparison = actual.compare(&expected, Tolerance::default()).unwrap();
+        assert_eq!(comparison.different_pixels, 1);
+        assert!(comparison.max_difference > 0.9);
+        assert!(!comparison.matches);
+        assert_eq!(comparison.diff.pixel(2, 0), [255, 0, 0]);
+        assert_ne!(comparison.diff.pixel(1, 0), [255, 0, 0]);
+
+        let exact = actual.compare(&expected, Tolerance::EXACT).unwrap();
+        assert_eq!(exact.different_pixels, 2);
+        let lenient = Tolerance {
+            max_different_pixels: 1,
+            ..Tolerance::default()
+        };
+        assert!(actual.compare(&expected, lenient).unwrap().matches);
+        assert_eq!(
+            actual.compare(&RgbImage::new(1, 1, vec![0; 3]), lenient),
+            None
+        );
+    }
+
+    #[test]
+    fn golden_files() {
+        let dir = std::env::temp_dir().join(format!("x11rb-visual-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let golden = dir.join("image.png");
+        let image = RgbImage::new(1, 2, vec![1, 2, 3, 4, 5, 6]);
+        std::fs::write(&golden, image.to_png()).unwrap();
+        compare_with_golden(&image, &golden, Tolerance::EXACT).unwrap();
+
+        let other = RgbImage::new(1, 2, vec![1, 2, 3, 200, 5, 6]);
+        match compare_with_golden(&other, &golden, Tolerance::EXACT) {
+            Err(VisualError::Mismatch {
+                different_pixels, ..
+            }) => assert_eq!(different_pixels, 1),
+            result => panic!("Unexpected result {:?}", result),
+        }
+        let actual = std::fs::read(dir.join("image.actual.png")).unwrap();
+        assert_eq!(RgbImage::from_png(&actual).unwrap(), other);
+        assert!(dir.join("image.diff.png").exists());
+
+        let missing = dir.join("missing.png");
+        assert!(matches!(
+            compare_with_golden(&image, &missing, Tolerance::EXACT),
+            Err(VisualError::MissingGolden(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
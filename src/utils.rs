@@ -10,14 +10,21 @@
 //! `CSlice` is only available when the `allow-unsafe-code` feature is enabled.
 //!
 //!
-//! # RawFdContainer
+//! # OwnedFd
 //!
-//! [`RawFdContainer`] is a variant of [`std::os::unix::io::RawFd`] with ownership semantics. This
-//! means that the `RawFd` will be closed when the `RawFdContainer` is dropped.
+//! File descriptors that are sent to or received from the X11 server are represented as
+//! [`OwnedFd`]. On unix systems, this is a re-export of [`std::os::unix::io::OwnedFd`].
 //!
 //! On non-`cfg(unix)`-systems, this is an empty type without methods. It still exists as a type so
 //! that it can appear in interfaces, but it is not actually possible to construct an instance of
-//! `RawFdContainer`.
+//! `OwnedFd`.
+//!
+//!
+//! # RawFdContainer
+//!
+//! [`RawFdContainer`] is a variant of [`std::os::unix::io::RawFd`] with ownership semantics. It is
+//! a thin wrapper around `OwnedFd` that is only kept for compatibility, since x11rb's APIs use
+//! `OwnedFd` directly.
 
 #[cfg(feature = "allow-unsafe-code")]
 mod unsafe_code {
@@ -119,32 +126,77 @@ pub(crate) fn nix_error_to_io(e: nix::Error) -> std::io::Error {
 }
 
 #[cfg(unix)]
-mod raw_fd_container {
-    use std::mem::forget;
-    #[cfg(unix)]
-    use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
+pub use std::os::unix::io::OwnedFd;
 
-    use super::nix_error_to_io;
+/// A file descriptor that is closed on drop.
+///
+/// On unix systems, this is [`std::os::unix::io::OwnedFd`]. Other systems cannot pass file
+/// descriptors to the X11 server. There, this is an empty type that exists so that it can appear
+/// in interfaces, but it is not possible to construct an instance of it.
+#[cfg(not(unix))]
+#[derive(Debug)]
+pub enum OwnedFd {}
+
+/// Take ownership of `fd` without unsafe code.
+///
+/// `OwnedFd::from_raw_fd()` is unsafe. Instead, a new file descriptor is allocated and `fd` is
+/// duplicated onto it with `dup2()`. Afterwards, `fd` is closed, also on error.
+#[cfg(unix)]
+pub(crate) fn owned_fd_from_raw(fd: std::os::unix::io::RawFd) -> std::io::Result<OwnedFd> {
+    use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixStream;
+
+    let take = || {
+        // Any new file descriptor would do; a socket pair does not need a file system
+        let (target, _) = UnixStream::pair()?;
+        let target = OwnedFd::from(target);
+        let _ = nix::unistd::dup2(fd, target.as_raw_fd()).map_err(nix_error_to_io)?;
+        // dup2() clears FD_CLOEXEC
+        let _ = fcntl(target.as_raw_fd(), FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC))
+            .map_err(nix_error_to_io)?;
+        Ok(target)
+    };
+    let result = take();
+    let _ = nix::unistd::close(fd);
+    result
+}
+
+#[cfg(unix)]
+mod raw_fd_container {
+    use std::hash::{Hash, Hasher};
+    use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, OwnedFd, RawFd};
 
     /// A simple wrapper around RawFd that closes the fd on drop.
     ///
+    /// Internally, this is an [`OwnedFd`]. x11rb's APIs use `OwnedFd` directly, so this type is
+    /// only kept for compatibility and will be removed in a future release. Conversions from and to
+    /// `OwnedFd` are provided via `From`.
+    ///
     /// On non-unix systems, this type is empty and does not provide
     /// any method.
-    #[derive(Debug, Hash, PartialEq, Eq)]
-    pub struct RawFdContainer(RawFd);
-
-    impl Drop for RawFdContainer {
-        fn drop(&mut self) {
-            let _ = nix::unistd::close(self.0);
-        }
-    }
+    #[derive(Debug)]
+    pub struct RawFdContainer(OwnedFd);
 
     impl RawFdContainer {
         /// Create a new `RawFdContainer` for the given `RawFd`.
         ///
         /// The `RawFdContainer` takes ownership of the `RawFd` and closes it on drop.
+        ///
+        /// # Panics
+        ///
+        /// Without unsafe code, an `OwnedFd` for `fd` can only be created by duplicating `fd` onto
+        /// a new file descriptor. This function panics if that fails, e.g. because the process
+        /// reached its limit of open files. `fd` is closed in any case.
+        #[deprecated(
+            since = "0.9.0",
+            note = "Convert an `OwnedFd` into a `RawFdContainer` with `From` instead"
+        )]
         pub fn new(fd: RawFd) -> Self {
-            RawFdContainer(fd)
+            match super::owned_fd_from_raw(fd) {
+                Ok(fd) => RawFdContainer(fd),
+                Err(e) => panic!("Failed to take ownership of FD {}: {}", fd, e),
+            }
         }
 
         /// Tries to clone the `RawFdContainer` creating a new FD
@@ -152,9 +204,7 @@ mod raw_fd_container {
         /// of the `dup`ed version, whereas the original `RawFdContainer`
         /// will keep the ownership of its FD.
         pub fn try_clone(&self) -> Result<Self, std::io::Error> {
-            Ok(Self::new(
-                nix::unistd::dup(self.0).map_err(nix_error_to_io)?,
-            ))
+            Ok(RawFdContainer(self.0.try_clone()?))
         }
 
         /// Get the `RawFd` out of this `RawFdContainer`.
@@ -162,9 +212,14 @@ mod raw_fd_container {
         /// This function would be an implementation of `IntoRawFd` if that were possible. However, it
         /// causes a conflict with an `impl` from libcore...
         pub fn into_raw_fd(self) -> RawFd {
-            let fd = self.0;
-            forget(self);
-            fd
+            self.0.into_raw_fd()
+        }
+
+        /// Get the `OwnedFd` out of this `RawFdContainer`.
+        ///
+        /// This is the same as `OwnedFd::from(container)`.
+        pub fn into_owned_fd(self) -> OwnedFd {
+            self.0
         }
 
         /// Consumes the `RawFdContainer` and closes the wrapped FD with
@@ -174,19 +229,89 @@ mod raw_fd_container {
         /// the caller to handle errors.
         pub fn close(self) -> Result<(), std::io::Error> {
             let fd = self.into_raw_fd();
-            nix::unistd::close(fd).map_err(nix_error_to_io)
+            nix::unistd::close(fd).map_err(super::nix_error_to_io)
         }
     }
 
-    impl<T: IntoRawFd> From<T> for RawFdContainer {
+    // The `IntoRawFd` bound is only here so that this does not conflict with `impl From<T> for T`.
+    impl<T: IntoRawFd + Into<OwnedFd>> From<T> for RawFdContainer {
         fn from(fd: T) -> Self {
-            Self::new(fd.into_raw_fd())
+            RawFdContainer(fd.into())
+        }
+    }
+
+    impl From<RawFdContainer> for OwnedFd {
+        fn from(fd: RawFdContainer) -> Self {
+            fd.0
         }
     }
 
     impl AsRawFd for RawFdContainer {
         fn as_raw_fd(&self) -> RawFd {
-            self.0
+            self.0.as_raw_fd()
+        }
+    }
+
+    impl AsFd for RawFdContainer {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.0.as_fd()
+        }
+    }
+
+    // OwnedFd does not implement these traits, so compare the fd numbers like before
+    impl PartialEq for RawFdContainer {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_raw_fd() == other.as_raw_fd()
+        }
+    }
+
+    impl Eq for RawFdContainer {}
+
+    impl Hash for RawFdContainer {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_raw_fd().hash(state)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::RawFdContainer;
+        use std::fs::File;
+        use std::io::{Read, Write};
+        use std::os::unix::io::{AsFd, AsRawFd, IntoRawFd, OwnedFd};
+        use std::os::unix::net::UnixStream;
+
+        #[test]
+        fn owned_fd_roundtrip() {
+            let file = File::open("/dev/null").unwrap();
+            let owned = OwnedFd::from(file);
+            let raw = owned.as_raw_fd();
+            let container = RawFdContainer::from(owned);
+            assert_eq!(container.as_raw_fd(), raw);
+            assert_eq!(container.as_fd().as_raw_fd(), raw);
+            let owned = OwnedFd::from(container);
+            assert_eq!(owned.as_raw_fd(), raw);
+        }
+
+        #[test]
+        fn try_clone_creates_new_fd() {
+            let container = RawFdContainer::from(File::open("/dev/null").unwrap());
+            let clone = container.try_clone().unwrap();
+            assert_ne!(container.as_raw_fd(), clone.as_raw_fd());
+            clone.close().unwrap();
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn new_takes_ownership() {
+            let (a, mut b) = UnixStream::pair().unwrap();
+            let raw = a.into_raw_fd();
+            let container = RawFdContainer::new(raw);
+            let mut a = UnixStream::from(OwnedFd::from(container));
+            a.write_all(b"x").unwrap();
+            let mut buf = [0];
+            b.read_exact(&mut buf).unwrap();
+            assert_eq!(&buf, b"x");
         }
     }
 }
@@ -216,7 +341,7 @@ mod pretty_printer {
     /// match is used. Otherwise, the number is printed as a decimal.
     ///
     /// In alternate mode, the second string in the given array is used, else the first.
-    pub(crate) fn pretty_print_enum(
+    pub fn pretty_print_enum(
         fmt: &mut Formatter<'_>,
         value: u32,
         cases: &[(u32, &str, &str)],
@@ -239,7 +364,7 @@ mod pretty_printer {
     /// Any left-over number is printed as a decimal.
     ///
     /// In alternate mode, the second string in the given array is used, else the first.
-    pub(crate) fn pretty_print_bitmask(
+    pub fn pretty_print_bitmask(
         fmt: &mut Formatter<'_>,
         value: u32,
         cases: &[(u32, &str, &str)],
@@ -347,5 +472,7 @@ mod pretty_printer {
     }
 }
 
-pub(crate) use pretty_printer::{pretty_print_bitmask, pretty_print_enum};
+// Public for code generated outside of x11rb, see `x11rb-generator --out-of-tree`
+#[doc(hidden)]
+pub use pretty_printer::{pretty_print_bitmask, pretty_print_enum};
 pub use raw_fd_container::RawFdContainer;
@@ -0,0 +1,727 @@
+//! Predict when the next vertical blanks happen.
+//!
+//! Animation libraries want to start rendering a frame just early enough that it is ready at the
+//! next vertical blank ("vblank") of the monitor. The Present extension reports the time of past
+//! vblanks: each `PresentCompleteNotify` event contains the media stream counter (MSC), which
+//! counts the vblanks of a CRTC, and the unadjusted system time (UST) in microseconds at which
+//! this MSC started.
+//!
+//! [`VblankEstimator`] turns these samples into an estimate of the refresh interval and predicts
+//! future vblanks. Since the refresh rate of a monitor is never exactly its nominal value, the
+//! estimate is corrected with every new sample, so that it does not drift away over time.
+//! `PresentVblankTimer` asks the X11 server for the samples via `PresentNotifyMSC` (requires the
+//! `present` feature).
+//!
+//! Some X11 servers, e.g. some VNC servers, do not support Present. [`RefreshTimer`] then
+//! provides vblanks at the refresh rate of the monitor, as reported by RandR (requires the
+//! `randr` feature), without knowing their exact phase. [`FrameClock`] picks whatever is
+//! available, so that application code does not need to handle both cases:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::vsync::{FrameClock, VblankSource};
+//!
+//! fn animate(conn: &impl Connection, window: Window, now_ust: u64) -> Result<(), ReplyOrIdError> {
+//!     let mut clock = FrameClock::new(conn, window)?;
+//!     clock.start(conn)?;
+//!     loop {
+//!         if let Some(vblank) = clock.next_vblank(now_ust) {
+//!             println!("Render a frame for {} µs", vblank.ust);
+//!         }
+//!         let event = conn.wait_for_event()?;
+//!         let _ = clock.handle_event(conn, &event)?;
+//!     }
+//! }
+//! ```
+//!
+//! Animation frameworks only depend on the [`VblankSource`] trait, so that they can also be driven
+//! by other timers, e.g. in tests:
+//!
+//! ```
+//! use x11rb::vsync::{VblankEstimator, VblankSource};
+//!
+//! fn schedule(source: &impl VblankSource, now_ust: u64) {
+//!     for vblank in source.vblanks(now_ust).take(3) {
+//!         println!("Frame {} is shown at {} µs", vblank.msc, vblank.ust);
+//!     }
+//! }
+//!
+//! let mut estimator = VblankEstimator::new();
+//! // Samples of a 60 Hz monitor
+//! estimator.observe(100, 1_000_000);
+//! estimator.observe(101, 1_016_667);
+//! schedule(&estimator, 1_020_000);
+//! ```
+//!
+//! UST is measured with `CLOCK_MONOTONIC` on Linux, so a program can compare it with its own
+//! clock, e.g. via `clock_gettime`.
+
+#[cfg(feature = "randr")]
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use crate::connection::Connection;
+#[cfg(feature = "randr")]
+use crate::connection::RequestConnection;
+#[cfg(feature = "randr")]
+use crate::errors::ReplyError;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+#[cfg(feature = "present")]
+use crate::protocol::present;
+#[cfg(feature = "randr")]
+use crate::protocol::randr;
+#[cfg(feature = "randr")]
+use crate::protocol::xproto;
+use crate::protocol::xproto::Window;
+use crate::protocol::Event;
+
+/// How strongly a new sample corrects the time of the reference vblank, as a fraction of the
+/// prediction error.
+const PHASE_CORRECTION: f64 = 0.125;
+
+/// How strongly a new sample corrects the refresh interval. This is much smaller than
+/// [`PHASE_CORRECTION`], so that jitter does not disturb the interval (an alpha-beta filter).
+const RATE_CORRECTION: f64 = 1.0 / 256.0;
+
+/// A relative change of the measured interval above which the refresh rate is assumed to have
+/// changed, e.g. because the window moved to another monitor.
+const RATE_CHANGE: f64 = 0.25;
+
+/// The refresh interval that is assumed if nothing better is known (60 Hz).
+const DEFAULT_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// A vertical blank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vblank {
+    /// The value of the media stream counter during the frame that starts with this vblank.
+    pub msc: u64,
+    /// The time of the vblank in microseconds.
+    pub ust: u64,
+}
+
+/// Something that knows when vertical blanks happen.
+pub trait VblankSource {
+    /// Get the time between two vblanks, if it is known.
+    fn refresh_interval(&self) -> Option<Duration>;
+
+    /// Predict the first vblank after the given time in microseconds.
+    ///
+    /// Returns `None` if there is not enough information for a prediction yet.
+    fn next_vblank(&self, after_ust: u64) -> Option<Vblank>;
+
+    /// Predict all vblanks after the given time in microseconds.
+    ///
+    /// The iterator is empty if there is not enough information for a prediction yet. The
+    /// predictions are not updated when new information arrives, so a new iterator should be
+    /// created for every frame.
+    fn vblanks(&self, after_ust: u64) -> Vblanks {
+        let next = self.next_vblank(after_ust);
+        let interval = self
+            .refresh_interval()
+            .map_or(0.0, |interval| interval.as_secs_f64() * 1_000_000.0);
+        Vblanks {
+            first: next,
+            interval,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over predicted vblanks, created by [`VblankSource::vblanks`].
+#[derive(Debug, Clone, Copy)]
+pub struct Vblanks {
+    first: Option<Vblank>,
+    interval: f64,
+    index: u64,
+}
+
+impl Iterator for Vblanks {
+    type Item = Vblank;
+
+    fn next(&mut self) -> Option<Vblank> {
+        let first = self.first?;
+        let offset = (self.index as f64 * self.interval).round() as u64;
+        let vblank = Vblank {
+            msc: first.msc + self.index,
+            ust: first.ust + offset,
+        };
+        self.index += 1;
+        Some(vblank)
+    }
+}
+
+/// Estimates the refresh interval and phase of a monitor from observed vblanks.
+///
+/// Every observation corrects the estimated interval and the time of a reference vblank by a
+/// fraction of the prediction error, which smooths out jitter in the samples while following slow
+/// drift. A sample that does not fit at all, e.g. after the refresh rate changed or the MSC jumped
+/// because the window moved to another CRTC, restarts the estimation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VblankEstimator {
+    /// The reference vblank from which predictions are extrapolated, with a fractional UST.
+    anchor: Option<(u64, f64)>,
+    /// The estimated refresh interval in microseconds.
+    interval: Option<f64>,
+}
+
+impl VblankEstimator {
+    /// Create an estimator without any observations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget all observations.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Record that the vblank with the given MSC happened at the given UST in microseconds.
+    pub fn observe(&mut self, msc: u64, ust: u64) {
+        let ust_f = ust as f64;
+        let (anchor_msc, anchor_ust) = match self.anchor {
+            Some(anchor) if msc > anchor.0 && ust_f > anchor.1 => anchor,
+            Some(anchor) if msc == anchor.0 => {
+                // A repeated sample, e.g. of a PresentPixmap that completed in the same frame
+                return;
+            }
+            _ => {
+                // The first sample, or the MSC or UST went backwards
+                self.anchor = Some((msc, ust_f));
+                self.interval = None;
+                return;
+            }
+        };
+        let frames = (msc - anchor_msc) as f64;
+        let measured = (ust_f - anchor_ust) / frames;
+        let interval = match self.interval {
+            Some(interval) if ((measured - interval) / interval).abs() <= RATE_CHANGE => interval,
+            _ => {
+                self.anchor = Some((msc, ust_f));
+                self.interval = Some(measured);
+                return;
+            }
+        };
+        let predicted = anchor_ust + frames * interval;
+        let error = ust_f - predicted;
+        self.interval = Some(interval + RATE_CORRECTION * error / frames);
+        self.anchor = Some((msc, predicted + PHASE_CORRECTION * error));
+    }
+
+    /// Predict the UST in microseconds at which the given MSC starts.
+    ///
+    /// Returns `None` if there is not enough information for a prediction yet.
+    pub fn predict(&self, msc: u64) -> Option<u64> {
+        let (anchor_msc, anchor_ust) = self.anchor?;
+        let interval = self.interval?;
+        let frames = msc as f64 - anchor_msc as f64;
+        Some((anchor_ust + frames * interval).max(0.0).round() as u64)
+    }
+}
+
+impl VblankSource for VblankEstimator {
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.interval
+            .map(|interval| Duration::from_secs_f64(interval / 1_000_000.0))
+    }
+
+    fn next_vblank(&self, after_ust: u64) -> Option<Vblank> {
+        let (anchor_msc, anchor_ust) = self.anchor?;
+        let interval = self.interval?;
+        let frames = ((after_ust as f64 - anchor_ust) / interval).floor() + 1.0;
+        let msc = (anchor_msc as f64 + frames).max(0.0) as u64;
+        let mut vblank = Vblank {
+            msc,
+            ust: self.predict(msc)?,
+        };
+        // Rounding can put the prediction exactly onto `after_ust`
+        if vblank.ust <= after_ust {
+            vblank.msc += 1;
+            vblank.ust = self.predict(vblank.msc)?;
+        }
+        Some(vblank)
+    }
+}
+
+/// Samples the vblanks of the CRTC that shows a window via the Present extension.
+///
+/// This type is only available when the `present` feature of the library is enabled.
+///
+/// After [`start()`](Self::start), the timer asks for a `PresentCompleteNotify` event every
+/// [`sample_interval`](Self::sample_interval) vblanks. Pass all events to
+/// [`handle_event()`](Self::handle_event), which feeds the samples into a [`VblankEstimator`]
+/// and requests the next event. Completed `PresentPixmap` requests on the same window are used as
+/// samples as well.
+#[cfg(feature = "present")]
+#[derive(Debug, Clone, Copy)]
+pub struct PresentVblankTimer {
+    window: Window,
+    eid: present::Event,
+    serial: u32,
+    divisor: u64,
+    running: bool,
+    estimator: VblankEstimator,
+}
+
+#[cfg(feature = "present")]
+impl PresentVblankTimer {
+    /// Create a timer for the given window.
+    ///
+    /// This selects `PresentCompleteNotify` events on `window`. The Present extension must be
+    /// supported by the X11 server.
+    pub fn new<C: Connection + ?Sized>(conn: &C, window: Window) -> Result<Self, ReplyOrIdError> {
+        let eid = conn.generate_id()?;
+        let _ = present::select_input(conn, eid, window, present::EventMask::COMPLETE_NOTIFY)?;
+        Ok(Self {
+            window,
+            eid,
+            serial: 0,
+            divisor: 1,
+            running: false,
+            estimator: VblankEstimator::new(),
+        })
+    }
+
+    /// Set after how many vblanks a new sample is requested.
+    ///
+    /// Fewer samples mean fewer wakeups, but slower reactions to changes of the refresh rate.
+    /// Values below 1 are treated as 1.
+    pub fn sample_interval(mut self, vblanks: u64) -> Self {
+        self.divisor = vblanks.max(1);
+        self
+    }
+
+    /// Start requesting samples.
+    pub fn start<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        if !self.running {
+            self.running = true;
+            self.request_notify(conn)?;
+        }
+        Ok(())
+    }
+
+    /// Stop requesting samples.
+    ///
+    /// The estimate is kept, but becomes less accurate over time.
+    pub fn stop(&mut self) {
+        self.running = false;
+    }
+
+    /// Check if samples are requested.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Get the estimator that the samples are fed into.
+    pub fn estimator(&self) -> &VblankEstimator {
+        &self.estimator
+    }
+
+    /// Handle a `PresentCompleteNotify` event for this timer's window.
+    ///
+    /// Returns whether the event was handled.
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ConnectionError> {
+        let event = match event {
+            Event::PresentCompleteNotify(event) if event.event == self.eid => event,
+            _ => return Ok(false),
+        };
+        if event.mode == present::CompleteMode::SKIP {
+            // The pixmap was never shown, so the time does not belong to a vblank
+            return Ok(true);
+        }
+        self.estimator.observe(event.msc, event.ust);
+        let requested =
+            event.kind == present::CompleteKind::NOTIFY_MSC && event.serial == self.serial;
+        if requested && self.running {
+            self.request_notify(conn)?;
+        }
+        Ok(true)
+    }
+
+    fn request_notify<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        self.serial = self.serial.wrapping_add(1);
+        // A target MSC of 0 means "the next MSC that fits the divisor"
+        let _ = present::notify_msc(conn, self.window, self.serial, 0, self.divisor, 0)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "present")]
+impl VblankSource for PresentVblankTimer {
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.estimator.refresh_interval()
+    }
+
+    fn next_vblank(&self, after_ust: u64) -> Option<Vblank> {
+        self.estimator.next_vblank(after_ust)
+    }
+}
+
+/// Provides vblanks at a fixed refresh rate, without any feedback from the X11 server.
+///
+/// The vblanks are multiples of the refresh interval in microseconds. Their phase has nothing to do
+/// with the actual vblanks of the monitor, but frames are still paced at the right rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshTimer {
+    interval: Duration,
+}
+
+impl Default for RefreshTimer {
+    fn default() -> Self {
+        Self::new(DEFAULT_INTERVAL)
+    }
+}
+
+impl RefreshTimer {
+    /// Create a timer with the given refresh interval.
+    ///
+    /// An interval below one microsecond is treated as one microsecond.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_micros(1)),
+        }
+    }
+
+    /// Create a timer for the refresh rate of the monitor that shows the given window.
+    ///
+    /// The refresh rate is taken from the RandR mode of the CRTC that contains the center of the
+    /// window. Without RandR 1.3, or if the window is not on any CRTC, 60 Hz is assumed.
+    ///
+    /// This function is only available when the `randr` feature of the library is enabled. It
+    /// waits for the replies to the necessary requests.
+    #[cfg(feature = "randr")]
+    pub fn for_window<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<Self, ReplyError> {
+        Ok(refresh_interval(conn, window)?.map_or_else(Self::default, Self::new))
+    }
+}
+
+impl VblankSource for RefreshTimer {
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.interval)
+    }
+
+    fn next_vblank(&self, after_ust: u64) -> Option<Vblank> {
+        let interval = self.interval.as_secs_f64() * 1_000_000.0;
+        let mut msc = (after_ust as f64 / interval).floor() as u64 + 1;
+        let ust = |msc: u64| (msc as f64 * interval).round() as u64;
+        // Rounding can put the vblank exactly onto `after_ust`
+        if ust(msc) <= after_ust {
+            msc += 1;
+        }
+        Some(Vblank { msc, ust: ust(msc) })
+    }
+}
+
+/// Get the refresh interval of a RandR mode.
+///
+/// Returns `None` for modes without timing information.
+#[cfg(feature = "randr")]
+pub fn mode_interval(mode: &randr::ModeInfo) -> Option<Duration> {
+    let mut lines = u64::from(mode.vtotal);
+    if mode.mode_flags & u32::from(randr::ModeFlag::DOUBLE_SCAN) != 0 {
+        lines *= 2;
+    }
+    let mut nanos = u64::from(mode.htotal) * lines * 1_000_000_000;
+    if mode.mode_flags & u32::from(randr::ModeFlag::INTERLACE) != 0 {
+        // Each vblank only shows half of the lines
+        nanos /= 2;
+    }
+    if mode.dot_clock == 0 || nanos == 0 {
+        return None;
+    }
+    Some(Duration::from_nanos(nanos / u64::from(mode.dot_clock)))
+}
+
+/// Find the refresh interval of the CRTC that contains the center of the window.
+#[cfg(feature = "randr")]
+fn refresh_interval<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<Duration>, ReplyError> {
+    if conn
+        .extension_information(randr::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    // GetScreenResourcesCurrent was added in version 1.3
+    let version = randr::query_version(conn, 1, 3)?.reply()?;
+    if (version.major_version, version.minor_version) < (1, 3) {
+        return Ok(None);
+    }
+    let geometry = xproto::get_geometry(conn, window)?.reply()?;
+    let half = |size: u16| i16::try_from(size / 2).unwrap_or(i16::MAX);
+    let center = xproto::translate_coordinates(
+        conn,
+        window,
+        geometry.root,
+        half(geometry.width),
+        half(geometry.height),
+    )?
+    .reply()?;
+    let (x, y) = (i32::from(center.dst_x), i32::from(center.dst_y));
+    let resources = randr::get_screen_resources_current(conn, geometry.root)?.reply()?;
+    for &crtc in &resources.crtcs {
+        let info = randr::get_crtc_info(conn, crtc, resources.config_timestamp)?.reply()?;
+        let (left, top) = (i32::from(info.x), i32::from(info.y));
+        let inside = (left..left + i32::from(info.width)).contains(&x)
+            && (top..top + i32::from(info.height)).contains(&y);
+        if info.mode == 0 || !inside {
+            continue;
+        }
+        let mode = resources.modes.iter().find(|mode| mode.id == info.mode);
+        if let Some(interval) = mode.and_then(mode_interval) {
+            return Ok(Some(interval));
+        }
+    }
+    Ok(None)
+}
+
+/// A frame clock that uses the best timing source that the X11 server offers.
+///
+/// With the Present extension, vblanks are predicted from feedback of the X11 server via a
+/// `PresentVblankTimer`. Otherwise, a [`RefreshTimer`] paces frames at the refresh rate of the
+/// monitor. Both cases are handled by the same methods.
+#[derive(Debug, Clone, Copy)]
+pub enum FrameClock {
+    /// Vblanks are predicted from Present feedback.
+    ///
+    /// This variant only exists when the `present` feature of the library is enabled.
+    #[cfg(feature = "present")]
+    Present(PresentVblankTimer),
+    /// Vblanks are generated at a fixed refresh rate.
+    Timer(RefreshTimer),
+}
+
+impl FrameClock {
+    /// Create a frame clock for the given window.
+    ///
+    /// This uses the Present extension if the X11 server supports it. Otherwise, the refresh rate
+    /// is queried via RandR, if possible. This function waits for the replies to the necessary
+    /// requests.
+    pub fn new<C: Connection + ?Sized>(conn: &C, window: Window) -> Result<Self, ReplyOrIdError> {
+        if let Some(timer) = present_timer(conn, window)? {
+            return Ok(timer);
+        }
+        Ok(FrameClock::Timer(software_timer(conn, window)?))
+    }
+
+    /// Start following the vblanks.
+    ///
+    /// This requests Present feedback if it is used. A [`RefreshTimer`] needs nothing.
+    pub fn start<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        match self {
+            #[cfg(feature = "present")]
+            FrameClock::Present(timer) => timer.start(conn),
+            FrameClock::Timer(_) => {
+                let _ = conn;
+                Ok(())
+            }
+        }
+    }
+
+    /// Stop following the vblanks.
+    pub fn stop(&mut self) {
+        match self {
+            #[cfg(feature = "present")]
+            FrameClock::Present(timer) => timer.stop(),
+            FrameClock::Timer(_) => {}
+        }
+    }
+
+    /// Handle an event that might contain Present feedback.
+    ///
+    /// Returns whether the event was handled. Events are never handled by a [`RefreshTimer`].
+    pub fn handle_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ConnectionError> {
+        match self {
+            #[cfg(feature = "present")]
+            FrameClock::Present(timer) => timer.handle_event(conn, event),
+            FrameClock::Timer(_) => {
+                let _ = (conn, event);
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl VblankSource for FrameClock {
+    fn refresh_interval(&self) -> Option<Duration> {
+        match self {
+            #[cfg(feature = "present")]
+            FrameClock::Present(timer) => timer.refresh_interval(),
+            FrameClock::Timer(timer) => timer.refresh_interval(),
+        }
+    }
+
+    fn next_vblank(&self, after_ust: u64) -> Option<Vblank> {
+        match self {
+            #[cfg(feature = "present")]
+            FrameClock::Present(timer) => timer.next_vblank(after_ust),
+            FrameClock::Timer(timer) => timer.next_vblank(after_ust),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+fn present_timer<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<FrameClock>, ReplyOrIdError> {
+    if conn
+        .extension_information(present::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    Ok(Some(FrameClock::Present(PresentVblankTimer::new(
+        conn, window,
+    )?)))
+}
+
+#[cfg(not(feature = "present"))]
+fn present_timer<C: Connection + ?Sized>(
+    _conn: &C,
+    _window: Window,
+) -> Result<Option<FrameClock>, ReplyOrIdError> {
+    Ok(None)
+}
+
+#[cfg(feature = "randr")]
+fn software_timer<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<RefreshTimer, ReplyOrIdError> {
+    Ok(RefreshTimer::for_window(conn, window)?)
+}
+
+#[cfg(not(feature = "randr"))]
+fn software_timer<C: Connection + ?Sized>(
+    _conn: &C,
+    _window: Window,
+) -> Result<RefreshTimer, ReplyOrIdError> {
+    Ok(RefreshTimer::default())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{RefreshTimer, Vblank, VblankEstimator, VblankSource};
+
+    #[test]
+    fn needs_two_samples() {
+        let mut estimator = VblankEstimator::new();
+        assert_eq!(estimator.next_vblank(0), None);
+        assert_eq!(estimator.vblanks(0).next(), None);
+        estimator.observe(10, 1000);
+        assert_eq!(estimator.next_vblank(1000), None);
+        estimator.observe(12, 1100);
+        assert_eq!(
+            estimator.next_vblank(1100),
+            Some(Vblank { msc: 13, ust: 1150 })
+        );
+        assert_eq!(
+            estimator
+                .vblanks(1149)
+                .map(|v| v.ust)
+                .take(3)
+                .collect::<Vec<_>>(),
+            [1150, 1200, 1250]
+        );
+    }
+
+    #[test]
+    fn follows_drift() {
+        // A monitor that runs slightly slower than 60 Hz, with some jitter in the samples
+        let interval = 16_700.0;
+        let jitter = [0.0, 40.0, -30.0, 10.0, -50.0, 20.0];
+        let mut estimator = VblankEstimator::new();
+        estimator.observe(0, 1_000_000);
+        estimator.observe(1, 1_016_667);
+        for msc in 2..600u64 {
+            let ust = 1_000_000.0 + msc as f64 * interval + jitter[msc as usize % jitter.len()];
+            estimator.observe(msc, ust as u64);
+        }
+        let estimated = estimator.refresh_interval().unwrap().as_secs_f64() * 1_000_000.0;
+        assert!((estimated - interval).abs() < 1.0, "{}", estimated);
+        let expected = 1_000_000.0 + 700.0 * interval;
+        let predicted = estimator.predict(700).unwrap() as f64;
+        assert!((predicted - expected).abs() < 100.0, "{}", predicted);
+    }
+
+    #[test]
+    fn restarts_on_rate_change() {
+        let mut estimator = VblankEstimator::new();
+        estimator.observe(0, 0);
+        estimator.observe(1, 16_667);
+        // Switched to a 144 Hz monitor
+        estimator.observe(2, 23_611);
+        estimator.observe(3, 30_555);
+        let interval = estimator.refresh_interval().unwrap().as_micros();
+        assert!((6940..=6948).contains(&interval), "{}", interval);
+        // The MSC of another CRTC starts somewhere else
+        estimator.observe(1, 40_000);
+        assert_eq!(estimator.refresh_interval(), None);
+    }
+
+    #[test]
+    fn refresh_timer() {
+        let timer = RefreshTimer::new(Duration::from_micros(16_667));
+        assert_eq!(
+            timer.next_vblank(0),
+            Some(Vblank {
+                msc: 1,
+                ust: 16_667
+            })
+        );
+        assert_eq!(
+            timer
+                .vblanks(16_667)
+                .map(|v| v.ust)
+                .take(2)
+                .collect::<Vec<_>>(),
+            [33_334, 50_001]
+        );
+    }
+
+    #[cfg(feature = "randr")]
+    #[test]
+    fn mode_intervals() {
+        use super::mode_interval;
+        use crate::protocol::randr::{ModeFlag, ModeInfo};
+
+        // 1920x1080 at 60 Hz (CEA-861)
+        let mut mode = ModeInfo {
+            id: 1,
+            width: 1920,
+            height: 1080,
+            dot_clock: 148_500_000,
+            hsync_start: 2008,
+            hsync_end: 2052,
+            htotal: 2200,
+            hskew: 0,
+            vsync_start: 1084,
+            vsync_end: 1089,
+            vtotal: 1125,
+            name_len: 0,
+            mode_flags: 0,
+        };
+        assert_eq!(mode_interval(&mode), Some(Duration::from_nanos(16_666_666)));
+        mode.mode_flags = u32::from(ModeFlag::INTERLACE);
+        assert_eq!(mode_interval(&mode), Some(Duration::from_nanos(8_333_333)));
+        mode.dot_clock = 0;
+        assert_eq!(mode_interval(&mode), None);
+    }
+}
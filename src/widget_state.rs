@@ -0,0 +1,567 @@
+//! Tracking hover and focus of widgets that are drawn into a window.
+//!
+//! Programs without a toolkit often draw several interactive elements, e.g. buttons, into a single
+//! window. To highlight them correctly, they need to know which element is under the pointer and
+//! which one receives keyboard input. The X11 server only reports this per window, with `detail`
+//! and `mode` fields that are easy to get wrong. [`WidgetTracker`] turns these events into simple
+//! changes per registered rectangle ("widget"):
+//!
+//! - A widget is hovered while the pointer is inside of its window and its rectangle. Widgets
+//!   that are added later are on top of earlier ones.
+//! - While another client grabs the pointer, e.g. a window manager that moves a window or a popup
+//!   menu of another application, no widget is hovered. The X11 server reports this with
+//!   `LeaveNotify` and `EnterNotify` events with the `Grab` and `Ungrab` modes.
+//! - A widget has the focus if it was chosen with [`WidgetTracker::set_focus`] and its window has
+//!   the keyboard focus. The focus of the window is tracked like by
+//!   [`FocusTracker`](crate::focus::FocusTracker), so keyboard grabs do not take the focus away.
+//!
+//! Both core events and, with the `xinput` feature, the corresponding XI2 events are understood.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::protocol::xproto::{Rectangle, Window};
+//! use x11rb::widget_state::{WidgetChange, WidgetTracker};
+//!
+//! fn run(conn: &impl Connection, window: Window) -> Result<(), ConnectionError> {
+//!     // `window` was created with `WidgetTracker::event_mask()` in its event mask
+//!     let mut widgets = WidgetTracker::new();
+//!     let ok = Rectangle { x: 10, y: 10, width: 80, height: 30 };
+//!     let cancel = Rectangle { x: 100, y: 10, width: 80, height: 30 };
+//!     let _ = widgets.add_widget(1, window, ok);
+//!     let _ = widgets.add_widget(2, window, cancel);
+//!     let _ = widgets.set_focus(1);
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         for change in widgets.handle_event(&event) {
+//!             match change {
+//!                 WidgetChange::Hovered(id) => println!("Highlight {}", id),
+//!                 WidgetChange::Unhovered(id) => println!("Stop highlighting {}", id),
+//!                 WidgetChange::Focused(id) => println!("Draw focus ring around {}", id),
+//!                 WidgetChange::Unfocused(id) => println!("Remove focus ring of {}", id),
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::focus::FocusTracker;
+#[cfg(feature = "xinput")]
+use crate::protocol::xinput;
+#[cfg(feature = "xinput")]
+use crate::protocol::xproto::{
+    EnterNotifyEvent, NotifyDetail, NotifyMode, ENTER_NOTIFY_EVENT, LEAVE_NOTIFY_EVENT,
+};
+use crate::protocol::xproto::{EventMask, Rectangle, Window};
+use crate::protocol::Event;
+
+/// The identifier of a widget, chosen by the caller.
+pub type WidgetId = u32;
+
+/// A change of the state of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetChange {
+    /// The pointer entered the widget.
+    Hovered(WidgetId),
+    /// The pointer left the widget.
+    Unhovered(WidgetId),
+    /// The widget now receives keyboard input.
+    Focused(WidgetId),
+    /// The widget no longer receives keyboard input.
+    Unfocused(WidgetId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Widget {
+    id: WidgetId,
+    window: Window,
+    area: Rectangle,
+}
+
+impl Widget {
+    fn contains(&self, (x, y): (i16, i16)) -> bool {
+        let (x, y) = (i32::from(x), i32::from(y));
+        let (left, top) = (i32::from(self.area.x), i32::from(self.area.y));
+        (left..left + i32::from(self.area.width)).contains(&x)
+            && (top..top + i32::from(self.area.height)).contains(&y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WindowState {
+    window: Window,
+    /// The position of the pointer if it is inside of the window and not grabbed by others.
+    pointer: Option<(i16, i16)>,
+    /// The widget that receives keyboard input while the window has the focus.
+    focus: Option<WidgetId>,
+}
+
+/// Tracks which widgets are hovered and focused.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Default)]
+pub struct WidgetTracker {
+    widgets: Vec<Widget>,
+    windows: Vec<WindowState>,
+    focus: FocusTracker,
+    hovered: Vec<WidgetId>,
+    focused: Vec<WidgetId>,
+}
+
+impl WidgetTracker {
+    /// Create a new instance without any widgets.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the core events that have to be selected on the windows of the widgets.
+    pub fn event_mask() -> EventMask {
+        FocusTracker::event_mask() | EventMask::POINTER_MOTION
+    }
+
+    /// Get the XI2 events that have to be selected on the windows of the widgets.
+    ///
+    /// This is an alternative to [`WidgetTracker::event_mask`] for programs that use XI2 for
+    /// input.
+    #[cfg(feature = "xinput")]
+    pub fn xi_event_mask() -> xinput::XIEventMask {
+        xinput::XIEventMask::ENTER
+            | xinput::XIEventMask::LEAVE
+            | xinput::XIEventMask::FOCUS_IN
+            | xinput::XIEventMask::FOCUS_OUT
+            | xinput::XIEventMask::MOTION
+    }
+
+    /// Add a widget that covers the given area of a window.
+    ///
+    /// An existing widget with the same ID is replaced. The new widget is on top of all other
+    /// widgets of the window.
+    pub fn add_widget(
+        &mut self,
+        id: WidgetId,
+        window: Window,
+        area: Rectangle,
+    ) -> Vec<WidgetChange> {
+        self.widgets.retain(|widget| widget.id != id);
+        self.widgets.push(Widget { id, window, area });
+        if self.window(window).is_none() {
+            self.windows.push(WindowState {
+                window,
+                pointer: None,
+                focus: None,
+            });
+            self.focus.track(window);
+        }
+        self.changes()
+    }
+
+    /// Move or resize a widget.
+    pub fn set_area(&mut self, id: WidgetId, area: Rectangle) -> Vec<WidgetChange> {
+        if let Some(widget) = self.widgets.iter_mut().find(|widget| widget.id == id) {
+            widget.area = area;
+        }
+        self.changes()
+    }
+
+    /// Remove a widget.
+    ///
+    /// If it was hovered or focused, this is reported as a change.
+    pub fn remove_widget(&mut self, id: WidgetId) -> Vec<WidgetChange> {
+        self.widgets.retain(|widget| widget.id != id);
+        self.changes()
+    }
+
+    /// Stop tracking a window and all its widgets, e.g. because it was destroyed.
+    pub fn remove_window(&mut self, window: Window) -> Vec<WidgetChange> {
+        self.widgets.retain(|widget| widget.window != window);
+        self.windows.retain(|state| state.window != window);
+        self.focus.untrack(window);
+        self.changes()
+    }
+
+    /// Choose the widget that receives keyboard input while its window has the focus.
+    ///
+    /// This replaces the previously chosen widget of the same window.
+    pub fn set_focus(&mut self, id: WidgetId) -> Vec<WidgetChange> {
+        let window = self
+            .widgets
+            .iter()
+            .find(|widget| widget.id == id)
+            .map(|widget| widget.window);
+        if let Some(state) = window.and_then(|window| self.window(window)) {
+            state.focus = Some(id);
+        }
+        self.changes()
+    }
+
+    /// Check whether the given widget is hovered.
+    pub fn is_hovered(&self, id: WidgetId) -> bool {
+        self.hovered.contains(&id)
+    }
+
+    /// Check whether the given widget has the focus.
+    pub fn has_focus(&self, id: WidgetId) -> bool {
+        self.focused.contains(&id)
+    }
+
+    /// Handle an event and report how the state of the widgets changed.
+    ///
+    /// Events that are not about the windows of the widgets are ignored.
+    pub fn handle_event(&mut self, event: &Event) -> Vec<WidgetChange> {
+        #[cfg(feature = "xinput")]
+        {
+            if let Some(event) = core_event(event) {
+                return self.handle_event(&event);
+            }
+        }
+        let _ = self.focus.handle_event(event);
+        match event {
+            Event::EnterNotify(event) => {
+                let pointer = (event.event_x, event.event_y);
+                // The coordinates are meaningless if the pointer is on another screen
+                let same_screen = event.same_screen_focus & 2 != 0;
+                self.set_pointer(event.event, Some(pointer).filter(|_| same_screen));
+            }
+            Event::LeaveNotify(event) => self.set_pointer(event.event, None),
+            Event::MotionNotify(event) if event.same_screen => {
+                if let Some(state) = self.window(event.event) {
+                    if state.pointer.is_some() {
+                        state.pointer = Some((event.event_x, event.event_y));
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.changes()
+    }
+
+    fn window(&mut self, window: Window) -> Option<&mut WindowState> {
+        self.windows.iter_mut().find(|state| state.window == window)
+    }
+
+    fn set_pointer(&mut self, window: Window, pointer: Option<(i16, i16)>) {
+        if let Some(state) = self.window(window) {
+            state.pointer = pointer;
+        }
+    }
+
+    /// Compare the current state with the last reported state.
+    fn changes(&mut self) -> Vec<WidgetChange> {
+        let mut hovered = Vec::new();
+        let mut focused = Vec::new();
+        for state in &self.windows {
+            let widgets = self
+                .widgets
+                .iter()
+                .filter(|widget| widget.window == state.window);
+            if let Some(pointer) = state.pointer {
+                hovered.extend(
+                    widgets
+                        .clone()
+                        .rev()
+                        .find(|widget| widget.contains(pointer))
+                        .map(|widget| widget.id),
+                );
+            }
+            if self.focus.has_focus(state.window) {
+                focused.extend(
+                    widgets
+                        .map(|widget| widget.id)
+                        .filter(|&id| state.focus == Some(id)),
+                );
+            }
+        }
+
+        let mut changes = Vec::new();
+        diff(
+            &self.hovered,
+            &hovered,
+            WidgetChange::Unhovered,
+            &mut changes,
+        );
+        diff(
+            &self.focused,
+            &focused,
+            WidgetChange::Unfocused,
+            &mut changes,
+        );
+        diff(&hovered, &self.hovered, WidgetChange::Hovered, &mut changes);
+        diff(&focused, &self.focused, WidgetChange::Focused, &mut changes);
+        self.hovered = hovered;
+        self.focused = focused;
+        changes
+    }
+}
+
+/// Report all widgets that are in `old`, but not in `new`.
+fn diff(
+    old: &[WidgetId],
+    new: &[WidgetId],
+    change: fn(WidgetId) -> WidgetChange,
+    changes: &mut Vec<WidgetChange>,
+) {
+    changes.extend(
+        old.iter()
+            .filter(|id| !new.contains(id))
+            .map(|&id| change(id)),
+    );
+}
+
+/// Convert an XI2 crossing, focus or motion event into the corresponding core event.
+#[cfg(feature = "xinput")]
+fn core_event(event: &Event) -> Option<Event> {
+    use crate::protocol::xproto::{self, FocusInEvent, Motion, MotionNotifyEvent};
+
+    let crossing = |event: &xinput::EnterEvent, response_type| EnterNotifyEvent {
+        response_type,
+        detail: NotifyDetail::from(u8::from(event.detail)),
+        sequence: event.sequence,
+        time: event.time,
+        root: event.root,
+        event: event.event,
+        child: event.child,
+        root_x: fp1616_to_i16(event.root_x),
+        root_y: fp1616_to_i16(event.root_y),
+        event_x: fp1616_to_i16(event.event_x),
+        event_y: fp1616_to_i16(event.event_y),
+        state: 0,
+        mode: core_mode(event.mode),
+        same_screen_focus: u8::from(event.same_screen) << 1 | u8::from(event.focus),
+    };
+    let focus = |event: &xinput::FocusInEvent, response_type| FocusInEvent {
+        response_type,
+        detail: NotifyDetail::from(u8::from(event.detail)),
+        sequence: event.sequence,
+        event: event.event,
+        mode: core_mode(event.mode),
+    };
+    Some(match event {
+        Event::XinputEnter(event) => Event::EnterNotify(crossing(event, ENTER_NOTIFY_EVENT)),
+        Event::XinputLeave(event) => Event::LeaveNotify(crossing(event, LEAVE_NOTIFY_EVENT)),
+        Event::XinputFocusIn(event) => Event::FocusIn(focus(event, xproto::FOCUS_IN_EVENT)),
+        Event::XinputFocusOut(event) => Event::FocusOut(focus(event, xproto::FOCUS_OUT_EVENT)),
+        Event::XinputMotion(event) => Event::MotionNotify(MotionNotifyEvent {
+            response_type: xproto::MOTION_NOTIFY_EVENT,
+            detail: Motion::NORMAL,
+            sequence: event.sequence,
+            time: event.time,
+            root: event.root,
+            event: event.event,
+            child: event.child,
+            root_x: fp1616_to_i16(event.root_x),
+            root_y: fp1616_to_i16(event.root_y),
+            event_x: fp1616_to_i16(event.event_x),
+            event_y: fp1616_to_i16(event.event_y),
+            state: 0,
+            same_screen: true,
+        }),
+        _ => return None,
+    })
+}
+
+/// Get the core mode of an XI2 crossing or focus event.
+///
+/// XI2 tells passive grabs apart from active grabs. For the widgets, both are the same.
+#[cfg(feature = "xinput")]
+fn core_mode(mode: xinput::NotifyMode) -> NotifyMode {
+    match mode {
+        xinput::NotifyMode::PASSIVE_GRAB => NotifyMode::GRAB,
+        xinput::NotifyMode::PASSIVE_UNGRAB => NotifyMode::UNGRAB,
+        mode => NotifyMode::from(u8::from(mode)),
+    }
+}
+
+/// Round a 16.16 fixed point number down to an integer.
+#[cfg(feature = "xinput")]
+fn fp1616_to_i16(value: xinput::Fp1616) -> i16 {
+    (value >> 16) as i16
+}
+
+#[cfg(test)]
+mod test {
+    use super::{WidgetChange, WidgetTracker};
+    use crate::protocol::xproto::{
+        EnterNotifyEvent, FocusInEvent, Motion, MotionNotifyEvent, NotifyDetail, NotifyMode,
+        Rectangle, Window, ENTER_NOTIFY_EVENT, FOCUS_IN_EVENT, LEAVE_NOTIFY_EVENT,
+        MOTION_NOTIFY_EVENT,
+    };
+    use crate::protocol::Event;
+
+    const WINDOW: Window = 10;
+
+    fn crossing(enter: bool, mode: NotifyMode, (x, y): (i16, i16)) -> Event {
+        let event = EnterNotifyEvent {
+            response_type: if enter {
+                ENTER_NOTIFY_EVENT
+            } else {
+                LEAVE_NOTIFY_EVENT
+            },
+            detail: NotifyDetail::NONLINEAR,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: WINDOW,
+            child: 0,
+            root_x: x,
+            root_y: y,
+            event_x: x,
+            event_y: y,
+            state: 0,
+            mode,
+            same_screen_focus: 2,
+        };
+        if enter {
+            Event::EnterNotify(event)
+        } else {
+            Event::LeaveNotify(event)
+        }
+    }
+
+    fn motion((x, y): (i16, i16)) -> Event {
+        Event::MotionNotify(MotionNotifyEvent {
+            response_type: MOTION_NOTIFY_EVENT,
+            detail: Motion::NORMAL,
+            sequence: 0,
+            time: 0,
+            root: 1,
+            event: WINDOW,
+            child: 0,
+            root_x: x,
+            root_y: y,
+            event_x: x,
+            event_y: y,
+            state: 0,
+            same_screen: true,
+        })
+    }
+
+    fn tracker() -> WidgetTracker {
+        let mut tracker = WidgetTracker::new();
+        let area = |x| Rectangle {
+            x,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+        assert!(tracker.add_widget(1, WINDOW, area(0)).is_empty());
+        assert!(tracker.add_widget(2, WINDOW, area(20)).is_empty());
+        tracker
+    }
+
+    #[test]
+    fn hover_follows_pointer() {
+        let mut tracker = tracker();
+        let enter = crossing(true, NotifyMode::NORMAL, (5, 5));
+        assert_eq!(tracker.handle_event(&enter), [WidgetChange::Hovered(1)]);
+        assert_eq!(
+            tracker.handle_event(&motion((25, 5))),
+            [WidgetChange::Unhovered(1), WidgetChange::Hovered(2)]
+        );
+        assert!(tracker.handle_event(&motion((26, 5))).is_empty());
+        assert!(tracker.is_hovered(2));
+
+        // A widget that is added on top takes over
+        let area = Rectangle {
+            x: 25,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+        assert_eq!(
+            tracker.add_widget(3, WINDOW, area),
+            [WidgetChange::Unhovered(2), WidgetChange::Hovered(3)]
+        );
+        assert_eq!(
+            tracker.remove_widget(3),
+            [WidgetChange::Unhovered(3), WidgetChange::Hovered(2)]
+        );
+
+        let leave = crossing(false, NotifyMode::NORMAL, (50, 5));
+        assert_eq!(tracker.handle_event(&leave), [WidgetChange::Unhovered(2)]);
+        // Motion during an implicit grab after leaving the window does not hover anything
+        assert!(tracker.handle_event(&motion((5, 5))).is_empty());
+    }
+
+    #[test]
+    fn grabs_of_others_remove_hover() {
+        let mut tracker = tracker();
+        let _ = tracker.handle_event(&crossing(true, NotifyMode::NORMAL, (5, 5)));
+        let grab = crossing(false, NotifyMode::GRAB, (5, 5));
+        assert_eq!(tracker.handle_event(&grab), [WidgetChange::Unhovered(1)]);
+        let ungrab = crossing(true, NotifyMode::UNGRAB, (25, 5));
+        assert_eq!(tracker.handle_event(&ungrab), [WidgetChange::Hovered(2)]);
+    }
+
+    #[test]
+    fn focus_needs_focused_window() {
+        let mut tracker = tracker();
+        assert!(tracker.set_focus(2).is_empty());
+        let focus_in = Event::FocusIn(FocusInEvent {
+            response_type: FOCUS_IN_EVENT,
+            detail: NotifyDetail::NONLINEAR,
+            sequence: 0,
+            event: WINDOW,
+            mode: NotifyMode::NORMAL,
+        });
+        assert_eq!(tracker.handle_event(&focus_in), [WidgetChange::Focused(2)]);
+        assert_eq!(
+            tracker.set_focus(1),
+            [WidgetChange::Unfocused(2), WidgetChange::Focused(1)]
+        );
+        assert!(tracker.has_focus(1));
+        assert_eq!(tracker.remove_window(WINDOW), [WidgetChange::Unfocused(1)]);
+    }
+
+    #[cfg(feature = "xinput")]
+    #[test]
+    fn xi2_events() {
+        use crate::protocol::xinput;
+
+        let mut tracker = tracker();
+        let enter = xinput::EnterEvent {
+            response_type: crate::protocol::xproto::GE_GENERIC_EVENT,
+            extension: 131,
+            sequence: 0,
+            length: 0,
+            event_type: xinput::ENTER_EVENT,
+            deviceid: 2,
+            time: 0,
+            sourceid: 2,
+            mode: xinput::NotifyMode::PASSIVE_UNGRAB,
+            detail: xinput::NotifyDetail::NONLINEAR,
+            root: 1,
+            event: WINDOW,
+            child: 0,
+            root_x: 25 << 16,
+            root_y: 5 << 16,
+            event_x: (25 << 16) | 0x8000,
+            event_y: 5 << 16,
+            same_screen: true,
+            focus: false,
+            mods: xinput::ModifierInfo {
+                base: 0,
+                latched: 0,
+                locked: 0,
+                effective: 0,
+            },
+            group: xinput::GroupInfo {
+                base: 0,
+                latched: 0,
+                locked: 0,
+                effective: 0,
+            },
+            buttons: Vec::new(),
+        };
+        assert_eq!(
+            tracker.handle_event(&Event::XinputEnter(enter.clone())),
+            [WidgetChange::Hovered(2)]
+        );
+        let mut leave = enter;
+        leave.event_type = xinput::LEAVE_EVENT;
+        leave.mode = xinput::NotifyMode::PASSIVE_GRAB;
+        assert_eq!(
+            tracker.handle_event(&Event::XinputLeave(leave)),
+            [WidgetChange::Unhovered(2)]
+        );
+    }
+}
@@ -0,0 +1,507 @@
+//! Helpers for reparenting window managers.
+//!
+//! A reparenting window manager puts each client window into a frame window that it draws the
+//! decorations on. This involves a few subtle steps: The client has to be added to the save-set
+//! so that it survives a crash of the window manager, and when the client is unmanaged, it has
+//! to be reparented back to the root window. The client may already have destroyed its window at
+//! that point, which causes `Window` errors that must not be treated as fatal.
+//!
+//! When a window manager starts, there might already be top-level windows, e.g. because the
+//! previous window manager was replaced. [`adoptable_windows`] finds the windows that should be
+//! managed.
+//!
+//! Clients that do not react to `WM_DELETE_WINDOW` have to be killed eventually.
+//! [`close_window_gracefully`] escalates from asking nicely to `KillClient`.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyOrIdError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::window_manager::{Frame, FrameExtents};
+//!
+//! fn manage(conn: &impl Connection, root: Window, client: Window) -> Result<Frame, ReplyOrIdError> {
+//!     let extents = FrameExtents { left: 2, right: 2, top: 20, bottom: 2 };
+//!     Frame::create(conn, root, client, extents)
+//! }
+//!
+//! fn unmanage(conn: &impl Connection, frame: Frame) -> Result<(), ReplyOrIdError> {
+//!     // Works even if the client window was already destroyed
+//!     frame.unmanage(conn)?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::properties::{send_root_message, RequestSource};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, CreateWindowAux, EventMask, GetPropertyReply,
+    MapState, SetMode, Window, WindowClass,
+};
+use crate::protocol::ErrorKind;
+use crate::{COPY_DEPTH_FROM_PARENT, CURRENT_TIME};
+
+/// The size of the decorations around a client window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameExtents {
+    /// Width of the left border.
+    pub left: u16,
+    /// Width of the right border.
+    pub right: u16,
+    /// Height of the top border, e.g. the title bar.
+    pub top: u16,
+    /// Height of the bottom border.
+    pub bottom: u16,
+}
+
+impl FrameExtents {
+    /// Get the position of the client window inside of the frame.
+    ///
+    /// This fails with [`ParseError::ConversionFailed`] if `left` or `top` do not fit into a
+    /// window position.
+    fn client_position(&self) -> Result<(i16, i16), ParseError> {
+        let convert = |value: u16| i16::try_from(value).map_err(|_| ParseError::ConversionFailed);
+        Ok((convert(self.left)?, convert(self.top)?))
+    }
+}
+
+/// A frame window around a managed client window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    root: Window,
+    frame: Window,
+    client: Window,
+    extents: FrameExtents,
+}
+
+impl Frame {
+    /// Create a frame for the given client window and reparent the client into it.
+    ///
+    /// The frame is created at the current position of the client window, selects
+    /// `SubstructureRedirect`, `SubstructureNotify`, `Exposure` and `ButtonPress`, and is mapped
+    /// together with the client.
+    /// The client is added to the save-set, so that it is reparented back to the root window
+    /// should the window manager exit unexpectedly.
+    ///
+    /// Reparenting a mapped client window causes an `UnmapNotify` event that a window manager
+    /// has to ignore.
+    ///
+    /// If `extents.left` or `extents.top` is larger than `i16::MAX`, this fails with
+    /// [`ParseError::ConversionFailed`] before any request is sent.
+    pub fn create<C: Connection + ?Sized>(
+        conn: &C,
+        root: Window,
+        client: Window,
+        extents: FrameExtents,
+    ) -> Result<Self, ReplyOrIdError> {
+        let (client_x, client_y) = extents.client_position()?;
+        let geometry = xproto::get_geometry(conn, client)?.reply()?;
+        let frame = conn.generate_id()?;
+        let aux = CreateWindowAux::new().event_mask(
+            EventMask::SUBSTRUCTURE_REDIRECT
+                | EventMask::SUBSTRUCTURE_NOTIFY
+                | EventMask::EXPOSURE
+                | EventMask::BUTTON_PRESS,
+        );
+        let _ = xproto::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            frame,
+            root,
+            geometry.x,
+            geometry.y,
+            geometry
+                .width
+                .saturating_add(extents.left)
+                .saturating_add(extents.right),
+            geometry
+                .height
+                .saturating_add(extents.top)
+                .saturating_add(extents.bottom),
+            0,
+            WindowClass::INPUT_OUTPUT,
+            0,
+            &aux,
+        )?;
+        let _ = add_to_save_set(conn, client)?;
+        let _ = xproto::reparent_window(conn, client, frame, client_x, client_y)?;
+        let _ = xproto::map_window(conn, client)?;
+        let _ = xproto::map_window(conn, frame)?;
+        Ok(Self {
+            root,
+            frame,
+            client,
+            extents,
+        })
+    }
+
+    /// Get the frame window.
+    pub fn frame(&self) -> Window {
+        self.frame
+    }
+
+    /// Get the client window.
+    pub fn client(&self) -> Window {
+        self.client
+    }
+
+    /// Get the root window.
+    pub fn root(&self) -> Window {
+        self.root
+    }
+
+    /// Get the size of the decorations.
+    pub fn extents(&self) -> FrameExtents {
+        self.extents
+    }
+
+    /// Stop managing the client and destroy the frame.
+    ///
+    /// The client is reparented back to the root window at the position of the frame and
+    /// removed from the save-set. If the client window no longer exists, the resulting `Window`
+    /// errors are ignored. Other errors are returned, but the frame is destroyed in any case.
+    pub fn unmanage<C: Connection + ?Sized>(self, conn: &C) -> Result<(), ReplyError> {
+        let result = self.release_client(conn);
+        xproto::destroy_window(conn, self.frame)?.check()?;
+        result
+    }
+
+    fn release_client<C: Connection + ?Sized>(&self, conn: &C) -> Result<(), ReplyError> {
+        let (x, y) = match xproto::get_geometry(conn, self.frame)?.reply() {
+            Ok(geometry) => (geometry.x, geometry.y),
+            Err(_) => (0, 0),
+        };
+        // The extents were checked when the frame was created
+        let (client_x, client_y) = self.extents.client_position()?;
+        let x = x.saturating_add(client_x);
+        let y = y.saturating_add(client_y);
+        ignore_bad_window(xproto::reparent_window(conn, self.client, self.root, x, y)?.check())?;
+        ignore_bad_window(remove_from_save_set(conn, self.client)?.check())
+    }
+}
+
+/// Add a window to this client's save-set.
+///
+/// Windows in the save-set that were reparented by this client are reparented back to the
+/// closest ancestor that is not created by this client when this client disconnects. Unmapped
+/// windows in the save-set are also mapped.
+pub fn add_to_save_set<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    xproto::change_save_set(conn, SetMode::INSERT, window)
+}
+
+/// Remove a window from this client's save-set.
+pub fn remove_from_save_set<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    xproto::change_save_set(conn, SetMode::DELETE, window)
+}
+
+/// The state field of the ICCCM `WM_STATE` property.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WmStateValue {
+    /// The window is not managed.
+    Withdrawn,
+    /// The window is visible.
+    Normal,
+    /// The window is iconified.
+    Iconic,
+}
+
+/// The contents of the ICCCM `WM_STATE` property that window managers set on client windows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WmState {
+    /// The state of the window.
+    pub state: WmStateValue,
+    /// The window that represents the iconified window, or `NONE`.
+    pub icon: Window,
+}
+
+impl WmState {
+    /// Parse a `WM_STATE` property from a `GetPropertyReply`.
+    ///
+    /// `wm_state` is the atom for `WM_STATE`, which is also the property's type.
+    pub fn from_reply(reply: &GetPropertyReply, wm_state: Atom) -> Result<Self, ParseError> {
+        if reply.type_ != wm_state || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        let mut values = reply.value32().ok_or(ParseError::InvalidValue)?;
+        let state = match values.next().ok_or(ParseError::InsufficientData)? {
+            0 => WmStateValue::Withdrawn,
+            1 => WmStateValue::Normal,
+            3 => WmStateValue::Iconic,
+            _ => return Err(ParseError::InvalidValue),
+        };
+        let icon = values.next().unwrap_or(0);
+        Ok(Self { state, icon })
+    }
+}
+
+/// A top-level window that existed before the window manager started.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AdoptableWindow {
+    /// The window.
+    pub window: Window,
+    /// Whether the window is currently viewable.
+    pub viewable: bool,
+    /// The window's `WM_STATE` property, as set by a previous window manager.
+    pub wm_state: Option<WmState>,
+}
+
+/// Find the existing top-level windows that a newly started window manager should manage.
+///
+/// All children of the root window are examined. Override-redirect windows are skipped. The
+/// remaining windows are returned if they are viewable, or if a previous window manager set
+/// their `WM_STATE` to `Iconic`. Windows that vanish while this function runs are skipped.
+///
+/// The windows are returned in stacking order, from bottom to top.
+pub fn adoptable_windows<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+) -> Result<Vec<AdoptableWindow>, ReplyError> {
+    let wm_state = xproto::intern_atom(conn, false, b"WM_STATE")?;
+    let tree = xproto::query_tree(conn, root)?.reply()?;
+    let wm_state = wm_state.reply()?.atom;
+
+    // Send all requests first to avoid one round trip per window
+    let cookies = tree
+        .children
+        .iter()
+        .map(|&window| {
+            let attributes = xproto::get_window_attributes(conn, window)?;
+            let state = xproto::get_property(conn, false, window, wm_state, wm_state, 0, 2)?;
+            Ok((window, attributes, state))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+
+    let mut result = Vec::new();
+    for (window, attributes, state) in cookies {
+        let attributes = match ignore_window_error(attributes.reply())? {
+            Some(attributes) => attributes,
+            None => continue,
+        };
+        let state = ignore_window_error(state.reply())?
+            .and_then(|reply| WmState::from_reply(&reply, wm_state).ok());
+        if attributes.override_redirect {
+            continue;
+        }
+        let viewable = attributes.map_state == MapState::VIEWABLE;
+        let iconic = state.map(|s| s.state) == Some(WmStateValue::Iconic);
+        if viewable || iconic {
+            result.push(AdoptableWindow {
+                window,
+                viewable,
+                wm_state: state,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// The steps of [`close_window_gracefully`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CloseStep {
+    /// Ask the client to close the window via the ICCCM `WM_DELETE_WINDOW` protocol.
+    DeleteWindow,
+    /// Ask the window manager to close the window via `_NET_CLOSE_WINDOW`.
+    NetCloseWindow,
+    /// Forcefully disconnect the client via `KillClient`.
+    KillClient,
+}
+
+/// Close a window, escalating to more forceful methods if the client does not react.
+///
+/// First, `WM_DELETE_WINDOW` is sent if the client lists it in its `WM_PROTOCOLS`. If the window
+/// still exists after `timeout`, `_NET_CLOSE_WINDOW` is sent to the root window. If the window
+/// still exists after another `timeout`, the client is killed via `KillClient`. `progress` is
+/// called before each step. The step that closed the window is returned.
+///
+/// This blocks while waiting and polls whether the window still exists. No events are read from
+/// the connection.
+pub fn close_window_gracefully<C, F>(
+    conn: &C,
+    window: Window,
+    timeout: Duration,
+    mut progress: F,
+) -> Result<CloseStep, ReplyError>
+where
+    C: Connection + ?Sized,
+    F: FnMut(CloseStep),
+{
+    let wm_protocols = xproto::intern_atom(conn, false, b"WM_PROTOCOLS")?;
+    let wm_delete_window = xproto::intern_atom(conn, false, b"WM_DELETE_WINDOW")?;
+    let geometry = xproto::get_geometry(conn, window)?;
+    let (wm_protocols, wm_delete_window) =
+        (wm_protocols.reply()?.atom, wm_delete_window.reply()?.atom);
+    let root = match ignore_window_error(geometry.reply())? {
+        Some(geometry) => geometry.root,
+        // The window is already gone
+        None => return Ok(CloseStep::DeleteWindow),
+    };
+    let protocols = xproto::get_property(
+        conn,
+        false,
+        window,
+        wm_protocols,
+        AtomEnum::ATOM,
+        0,
+        u32::MAX,
+    )?;
+    let supports_delete = ignore_window_error(protocols.reply())?
+        .and_then(|reply| {
+            reply
+                .value32()
+                .map(|mut atoms| atoms.any(|atom| atom == wm_delete_window))
+        })
+        .unwrap_or(false);
+
+    if supports_delete {
+        progress(CloseStep::DeleteWindow);
+        let event = ClientMessageEvent {
+            response_type: xproto::CLIENT_MESSAGE_EVENT,
+            format: 32,
+            sequence: 0,
+            window,
+            type_: wm_protocols,
+            data: [wm_delete_window, CURRENT_TIME, 0, 0, 0].into(),
+        };
+        let _ = xproto::send_event(conn, false, window, EventMask::NO_EVENT, event)?;
+        if wait_for_destruction(conn, window, timeout)? {
+            return Ok(CloseStep::DeleteWindow);
+        }
+    }
+
+    progress(CloseStep::NetCloseWindow);
+    let source = RequestSource::Pager.into();
+    let _ = send_root_message(
+        conn,
+        root,
+        window,
+        b"_NET_CLOSE_WINDOW",
+        [CURRENT_TIME, source, 0, 0, 0],
+    )?;
+    if wait_for_destruction(conn, window, timeout)? {
+        return Ok(CloseStep::NetCloseWindow);
+    }
+
+    progress(CloseStep::KillClient);
+    ignore_bad_window(xproto::kill_client(conn, window)?.check())?;
+    Ok(CloseStep::KillClient)
+}
+
+/// Wait until a window is destroyed or the timeout expires.
+///
+/// Returns whether the window was destroyed.
+fn wait_for_destruction<C: Connection + ?Sized>(
+    conn: &C,
+    window: Window,
+    timeout: Duration,
+) -> Result<bool, ReplyError> {
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+    let start = Instant::now();
+    conn.flush()?;
+    loop {
+        let attributes = xproto::get_window_attributes(conn, window)?.reply();
+        if ignore_window_error(attributes)?.is_none() {
+            return Ok(true);
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Ok(false);
+        }
+        std::thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+    }
+}
+
+/// Turn a `Window` error into `None`.
+fn ignore_window_error<T>(result: Result<T, ReplyError>) -> Result<Option<T>, ReplyError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Window => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Treat a `Window` error as success.
+///
+/// This is used for requests on client windows that might have been destroyed concurrently.
+fn ignore_bad_window(result: Result<(), ReplyError>) -> Result<(), ReplyError> {
+    ignore_window_error(result).map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ignore_bad_window, Frame, FrameExtents, WmState, WmStateValue};
+    use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+    use crate::protocol::xproto::GetPropertyReply;
+    use crate::protocol::ErrorKind;
+    use crate::testing::FakeConnection;
+    use crate::x11_utils::X11Error;
+
+    fn x11_error(error_kind: ErrorKind) -> ReplyError {
+        ReplyError::X11Error(X11Error {
+            error_kind,
+            error_code: 0,
+            sequence: 0,
+            bad_value: 0,
+            minor_opcode: 0,
+            major_opcode: 0,
+            request_name: None,
+        })
+    }
+
+    #[test]
+    fn ignore_only_window_errors() {
+        assert!(ignore_bad_window(Err(x11_error(ErrorKind::Window))).is_ok());
+        assert!(ignore_bad_window(Err(x11_error(ErrorKind::Match))).is_err());
+        assert!(ignore_bad_window(Err(ConnectionError::UnknownError.into())).is_err());
+    }
+
+    #[test]
+    fn reject_too_large_extents() {
+        let conn = FakeConnection::default();
+        let extents = FrameExtents {
+            left: 2,
+            right: 2,
+            top: 40_000,
+            bottom: 2,
+        };
+        match Frame::create(&conn, 1, 2, extents) {
+            Err(ReplyOrIdError::ConnectionError(ConnectionError::ParseError(
+                ParseError::ConversionFailed,
+            ))) => {}
+            r => panic!("Unexpected result: {:?}", r),
+        }
+        assert!(conn.sent_requests().is_empty());
+    }
+
+    #[test]
+    fn parse_wm_state() {
+        let reply = |type_, value: &[u32]| GetPropertyReply {
+            format: 32,
+            sequence: 0,
+            length: 0,
+            type_,
+            bytes_after: 0,
+            value_len: value.len() as u32,
+            value: value.iter().flat_map(|v| v.to_ne_bytes()).collect(),
+        };
+        assert_eq!(
+            WmState::from_reply(&reply(42, &[3, 7]), 42),
+            Ok(WmState {
+                state: WmStateValue::Iconic,
+                icon: 7
+            })
+        );
+        assert!(WmState::from_reply(&reply(42, &[2, 0]), 42).is_err());
+        assert!(WmState::from_reply(&reply(1, &[1, 0]), 42).is_err());
+        assert!(WmState::from_reply(&reply(42, &[]), 42).is_err());
+    }
+}
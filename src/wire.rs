@@ -0,0 +1,153 @@
+//! Helpers for parsing the wire format of X11 structures by hand.
+//!
+//! The generated code in [`protocol`](crate::protocol) parses everything that is described by
+//! the XML protocol descriptions. Parsers for other structures, e.g. for extensions that x11rb
+//! does not know about or for the server side of a connection, can use the functions in this
+//! module to behave like the generated ones. Together with
+//! [`TryParse`](crate::x11_utils::TryParse), [`parse_list`](crate::x11_utils::parse_list) and
+//! [`parse_u8_list`](crate::x11_utils::parse_u8_list), they cover padding, alignment, length
+//! fields and switches:
+//!
+//! ```
+//! use x11rb::errors::ParseError;
+//! use x11rb::wire;
+//! use x11rb::x11_utils::TryParse;
+//!
+//! // A u8, padding to a multiple of four bytes, and a u32 that is only present if bit 0 of the
+//! // u8 is set
+//! fn parse(data: &[u8]) -> Result<(u8, Option<u32>), ParseError> {
+//!     let (mask, remaining) = u8::try_parse(data)?;
+//!     let remaining = wire::align(data, remaining, 4)?;
+//!     let (value, _) = wire::parse_bitcase(remaining, mask.into(), 1, u32::try_parse)?;
+//!     Ok((mask, value))
+//! }
+//!
+//! let value = u32::from_ne_bytes([42, 0, 0, 0]);
+//! assert_eq!(parse(&[1, 0, 0, 0, 42, 0, 0, 0]), Ok((1, Some(value))));
+//! assert_eq!(parse(&[0, 0, 0, 0]).unwrap().1, None);
+//! assert_eq!(parse(&[1, 0]), Err(ParseError::InsufficientData));
+//! ```
+
+use crate::errors::ParseError;
+use crate::x11_utils::TryIntoUSize;
+
+/// Skip `bytes` bytes of padding.
+///
+/// Fails with [`ParseError::InsufficientData`] if `data` is shorter than that.
+pub fn pad(data: &[u8], bytes: usize) -> Result<&[u8], ParseError> {
+    data.get(bytes..).ok_or(ParseError::InsufficientData)
+}
+
+/// Skip padding until the offset of `remaining` from `start` is a multiple of `alignment`.
+///
+/// `remaining` must be a suffix of `start`, usually what is left after parsing the first fields
+/// of `start`. Fails with [`ParseError::InsufficientData`] if `remaining` ends before the
+/// alignment is reached.
+///
+/// # Panics
+///
+/// Panics if `alignment` is zero.
+pub fn align<'a>(
+    start: &[u8],
+    remaining: &'a [u8],
+    alignment: usize,
+) -> Result<&'a [u8], ParseError> {
+    let offset = remaining.as_ptr() as usize - start.as_ptr() as usize;
+    pad(remaining, (alignment - (offset % alignment)) % alignment)
+}
+
+/// Compute the end of a reply or event that has a length field.
+///
+/// `length` counts the four byte units that follow the first `minimum` bytes. Fails with
+/// [`ParseError::InvalidExpression`] if the end does not fit into a `usize`.
+pub fn length_field_end(minimum: usize, length: u32) -> Result<usize, ParseError> {
+    length
+        .try_to_usize()?
+        .checked_mul(4)
+        .and_then(|length| length.checked_add(minimum))
+        .ok_or(ParseError::InvalidExpression)
+}
+
+/// Parse a `<bitcase>` of a switch.
+///
+/// The bitcase is present if `switch_expr` has any of the bits of `mask` set. In that case, it
+/// is parsed with `parse`. Otherwise, nothing is parsed and `None` is returned.
+pub fn parse_bitcase<'a, T>(
+    data: &'a [u8],
+    switch_expr: u32,
+    mask: u32,
+    parse: impl FnOnce(&'a [u8]) -> Result<(T, &'a [u8]), ParseError>,
+) -> Result<(Option<T>, &'a [u8]), ParseError> {
+    if switch_expr & mask != 0 {
+        let (value, remaining) = parse(data)?;
+        Ok((Some(value), remaining))
+    } else {
+        Ok((None, data))
+    }
+}
+
+/// Parse a `<case>` of a switch.
+///
+/// The case is present if `switch_expr` is equal to `value`. In that case, it is parsed with
+/// `parse`. Otherwise, nothing is parsed and `None` is returned.
+///
+/// At most one case of a switch is present. For a switch where no case is present, the generated
+/// parsers skip its data and produce its `InvalidValue` variant, or, with the `strict_parsing`
+/// feature, fail with [`ParseError::InvalidValue`].
+pub fn parse_case<'a, T>(
+    data: &'a [u8],
+    switch_expr: u32,
+    value: u32,
+    parse: impl FnOnce(&'a [u8]) -> Result<(T, &'a [u8]), ParseError>,
+) -> Result<(Option<T>, &'a [u8]), ParseError> {
+    parse_bitcase(data, u32::from(switch_expr == value), 1, parse)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{align, length_field_end, pad, parse_case};
+    use crate::errors::ParseError;
+    use crate::x11_utils::TryParse;
+
+    #[test]
+    fn padding_and_alignment() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(pad(&data, 3), Ok(&data[3..]));
+        assert_eq!(pad(&data, 9), Err(ParseError::InsufficientData));
+        assert_eq!(align(&data, &data[1..], 4), Ok(&data[4..]));
+        assert_eq!(align(&data, &data[4..], 4), Ok(&data[4..]));
+        assert_eq!(align(&data, &data[5..], 8), Ok(&data[8..]));
+        assert_eq!(
+            align(&data[..6], &data[5..6], 4),
+            Err(ParseError::InsufficientData)
+        );
+    }
+
+    #[test]
+    fn length_fields() {
+        assert_eq!(length_field_end(32, 0), Ok(32));
+        assert_eq!(length_field_end(32, 2), Ok(40));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(
+            length_field_end(32, u32::MAX),
+            Err(ParseError::InvalidExpression)
+        );
+    }
+
+    #[test]
+    fn cases() {
+        let data = [7, 8];
+        assert_eq!(
+            parse_case(&data, 2, 2, u8::try_parse),
+            Ok((Some(7), &data[1..]))
+        );
+        assert_eq!(
+            parse_case(&data, 2, 3, u8::try_parse),
+            Ok((None, &data[..]))
+        );
+        assert_eq!(
+            parse_case(&data[2..], 2, 2, u8::try_parse),
+            Err(ParseError::InsufficientData)
+        );
+    }
+}
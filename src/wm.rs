@@ -0,0 +1,1122 @@
+//! Typed helpers for the standard interactions with a window manager.
+//!
+//! Clients ask the window manager for changes by sending 32-bit `ClientMessage` events to the root
+//! window with the `SubstructureNotify` and `SubstructureRedirect` event masks. The five data
+//! elements of each message have a message-specific meaning that is defined by the ICCCM and the
+//! EWMH specification. The types in this module encode them:
+//!
+//! - [`NetWmState`] adds, removes or toggles states like `_NET_WM_STATE_FULLSCREEN`.
+//! - [`NetActiveWindow`] asks for a window to be activated.
+//! - [`NetCloseWindow`] asks for a window to be closed.
+//! - [`NetWmMoveResize`] starts moving or resizing a window interactively.
+//! - [`NetMoveResizeWindow`] moves or resizes a window to a given geometry.
+//! - [`NetCurrentDesktop`] asks for a switch to another desktop.
+//! - [`WmChangeState`] asks for a window to be iconified.
+//!
+//! Additionally, [`WindowSetup`] sets the properties that a new top-level window should have,
+//! e.g. its title and `WM_PROTOCOLS`, and [`detect_wm`] finds out which window manager is running
+//! and which hints it supports. The [`ewmh`](crate::ewmh) module sends the most common messages in
+//! one line.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, Window};
+//! use x11rb::wm::{NetWmState, StateAction, WindowSetup, WmAtoms};
+//!
+//! fn setup(conn: &impl Connection, root: Window, window: Window) -> Result<(), ReplyError> {
+//!     let atoms = WmAtoms::new(conn)?;
+//!     WindowSetup::new()
+//!         .title("Hello")
+//!         .delete_window(true)
+//!         .current_process()
+//!         .apply(conn, &atoms, window)?;
+//!     conn.map_window(window)?;
+//!
+//!     let fullscreen = conn.intern_atom(false, b"_NET_WM_STATE_FULLSCREEN")?.reply()?.atom;
+//!     NetWmState::new(window, StateAction::Add, fullscreen).send(conn, &atoms, root)?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashSet;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::properties::{self, RequestSource};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, GetPropertyReply, Gravity, PropMode, Timestamp,
+    Window,
+};
+use crate::protocol::ErrorKind;
+use crate::send_event::{client_message32, send_to_root};
+use crate::wrapper::ConnectionExt as _;
+use crate::{CURRENT_TIME, NONE};
+
+atom_manager! {
+    Atoms: AtomsCookie {
+        UTF8_STRING,
+        WM_PROTOCOLS,
+        WM_DELETE_WINDOW,
+        WM_TAKE_FOCUS,
+        WM_CHANGE_STATE,
+        _NET_WM_STATE,
+        _NET_ACTIVE_WINDOW,
+        _NET_CLOSE_WINDOW,
+        _NET_WM_MOVERESIZE,
+        _NET_MOVERESIZE_WINDOW,
+        _NET_CURRENT_DESKTOP,
+        _NET_WM_PID,
+        _NET_WM_NAME,
+        _NET_WM_PING,
+    }
+}
+
+atom_manager! {
+    DetectAtoms: DetectAtomsCookie {
+        _NET_SUPPORTING_WM_CHECK,
+        _NET_SUPPORTED,
+        _NET_WM_NAME,
+    }
+}
+
+/// The atoms that are used by the helpers in this module.
+#[derive(Debug, Clone, Copy)]
+pub struct WmAtoms(Atoms);
+
+impl WmAtoms {
+    /// Intern the atoms.
+    ///
+    /// This function waits for the replies to the `InternAtom` requests.
+    pub fn new<C: RequestConnection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        Ok(Self(Atoms::new(conn)?.reply()?))
+    }
+
+    /// Get the `WM_PROTOCOLS` atom.
+    ///
+    /// This is the type of the client messages that the window manager sends for the protocols
+    /// that a window lists in its `WM_PROTOCOLS` property.
+    pub fn wm_protocols(&self) -> Atom {
+        self.0.WM_PROTOCOLS
+    }
+
+    /// Get the `WM_DELETE_WINDOW` atom.
+    pub fn wm_delete_window(&self) -> Atom {
+        self.0.WM_DELETE_WINDOW
+    }
+
+    /// Get the `WM_TAKE_FOCUS` atom.
+    pub fn wm_take_focus(&self) -> Atom {
+        self.0.WM_TAKE_FOCUS
+    }
+
+    /// Get the `_NET_ACTIVE_WINDOW` atom.
+    pub fn net_active_window(&self) -> Atom {
+        self.0._NET_ACTIVE_WINDOW
+    }
+
+    /// Get the `_NET_CLOSE_WINDOW` atom.
+    pub fn net_close_window(&self) -> Atom {
+        self.0._NET_CLOSE_WINDOW
+    }
+
+    /// Get the `_NET_WM_MOVERESIZE` atom.
+    pub fn net_wm_moveresize(&self) -> Atom {
+        self.0._NET_WM_MOVERESIZE
+    }
+
+    /// Get the `_NET_MOVERESIZE_WINDOW` atom.
+    pub fn net_moveresize_window(&self) -> Atom {
+        self.0._NET_MOVERESIZE_WINDOW
+    }
+
+    /// Get the `_NET_CURRENT_DESKTOP` atom.
+    pub fn net_current_desktop(&self) -> Atom {
+        self.0._NET_CURRENT_DESKTOP
+    }
+
+    /// Check if the given event is a `WM_DELETE_WINDOW` message from the window manager.
+    ///
+    /// The window manager sends this message when the user wants to close the window.
+    pub fn is_delete_window(&self, event: &ClientMessageEvent) -> bool {
+        event.format == 32
+            && event.type_ == self.0.WM_PROTOCOLS
+            && event.data.as_data32()[0] == self.0.WM_DELETE_WINDOW
+    }
+}
+
+/// What a [`NetWmState`] message does with the states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateAction {
+    /// Remove the states.
+    Remove,
+    /// Add the states.
+    Add,
+    /// Add the states if they are not set and remove them otherwise.
+    Toggle,
+}
+
+impl From<StateAction> for u32 {
+    fn from(action: StateAction) -> u32 {
+        match action {
+            StateAction::Remove => 0,
+            StateAction::Add => 1,
+            StateAction::Toggle => 2,
+        }
+    }
+}
+
+/// A `_NET_WM_STATE` message that changes one or two states of a window.
+///
+/// Changing two states at once is used for `_NET_WM_STATE_MAXIMIZED_VERT` and
+/// `_NET_WM_STATE_MAXIMIZED_HORZ`, so that the window manager maximizes the window in a single
+/// step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetWmState {
+    /// The window whose state is changed.
+    pub window: Window,
+    /// Whether the states are added, removed or toggled.
+    pub action: StateAction,
+    /// The first state, e.g. `_NET_WM_STATE_FULLSCREEN`.
+    pub first: Atom,
+    /// The second state, or [`NONE`](crate::NONE).
+    pub second: Atom,
+    /// Who sends the request.
+    pub source: RequestSource,
+}
+
+impl NetWmState {
+    /// Create a message that changes a single state, sent by an application.
+    pub fn new(window: Window, action: StateAction, state: Atom) -> Self {
+        Self {
+            window,
+            action,
+            first: state,
+            second: NONE,
+            source: RequestSource::Application,
+        }
+    }
+
+    /// Also change the given state.
+    pub fn second(mut self, state: Atom) -> Self {
+        self.second = state;
+        self
+    }
+
+    /// Set who sends the request.
+    pub fn source(mut self, source: RequestSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        let data = [
+            self.action.into(),
+            self.first,
+            self.second,
+            self.source.into(),
+            0,
+        ];
+        client_message32(self.window, atoms.0._NET_WM_STATE, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// A `_NET_ACTIVE_WINDOW` message that asks the window manager to activate a window.
+///
+/// The window manager might switch to the window's desktop, raise the window and give it the
+/// input focus. To prevent stealing the focus, a window manager might instead only mark the
+/// window as demanding attention, especially if the timestamp is older than the user's last
+/// interaction with another window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetActiveWindow {
+    /// The window to activate.
+    pub window: Window,
+    /// Who sends the request.
+    pub source: RequestSource,
+    /// The time of the user interaction that caused the request.
+    pub timestamp: Timestamp,
+    /// The application's currently active window, or [`NONE`](crate::NONE).
+    pub currently_active: Window,
+}
+
+impl NetActiveWindow {
+    /// Create a message sent by an application without a timestamp.
+    pub fn new(window: Window) -> Self {
+        Self {
+            window,
+            source: RequestSource::Application,
+            timestamp: CURRENT_TIME,
+            currently_active: NONE,
+        }
+    }
+
+    /// Set who sends the request.
+    pub fn source(mut self, source: RequestSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Set the time of the user interaction that caused the request.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Set the application's currently active window.
+    pub fn currently_active(mut self, window: Window) -> Self {
+        self.currently_active = window;
+        self
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        let data = [
+            self.source.into(),
+            self.timestamp,
+            self.currently_active,
+            0,
+            0,
+        ];
+        client_message32(self.window, atoms.0._NET_ACTIVE_WINDOW, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// A `_NET_CLOSE_WINDOW` message that asks the window manager to close a window.
+///
+/// The window manager closes the window like the user clicked its close button, e.g. by sending
+/// `WM_DELETE_WINDOW`. This is usually sent by pagers and taskbars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetCloseWindow {
+    /// The window to close.
+    pub window: Window,
+    /// The time of the user interaction that caused the request.
+    pub timestamp: Timestamp,
+    /// Who sends the request.
+    pub source: RequestSource,
+}
+
+impl NetCloseWindow {
+    /// Create a message sent by a pager without a timestamp.
+    pub fn new(window: Window) -> Self {
+        Self {
+            window,
+            timestamp: CURRENT_TIME,
+            source: RequestSource::Pager,
+        }
+    }
+
+    /// Set the time of the user interaction that caused the request.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Set who sends the request.
+    pub fn source(mut self, source: RequestSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        let data = [self.timestamp, self.source.into(), 0, 0, 0];
+        client_message32(self.window, atoms.0._NET_CLOSE_WINDOW, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// What a [`NetWmMoveResize`] message does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveResizeDirection {
+    /// Resize the window at its top left corner.
+    SizeTopLeft,
+    /// Resize the window at its top edge.
+    SizeTop,
+    /// Resize the window at its top right corner.
+    SizeTopRight,
+    /// Resize the window at its right edge.
+    SizeRight,
+    /// Resize the window at its bottom right corner.
+    SizeBottomRight,
+    /// Resize the window at its bottom edge.
+    SizeBottom,
+    /// Resize the window at its bottom left corner.
+    SizeBottomLeft,
+    /// Resize the window at its left edge.
+    SizeLeft,
+    /// Move the window with the pointer.
+    Move,
+    /// Resize the window with the keyboard.
+    SizeKeyboard,
+    /// Move the window with the keyboard.
+    MoveKeyboard,
+    /// Cancel a move or resize that was started by this client, e.g. when the button was already
+    /// released before the window manager took over.
+    Cancel,
+}
+
+impl From<MoveResizeDirection> for u32 {
+    fn from(direction: MoveResizeDirection) -> u32 {
+        match direction {
+            MoveResizeDirection::SizeTopLeft => 0,
+            MoveResizeDirection::SizeTop => 1,
+            MoveResizeDirection::SizeTopRight => 2,
+            MoveResizeDirection::SizeRight => 3,
+            MoveResizeDirection::SizeBottomRight => 4,
+            MoveResizeDirection::SizeBottom => 5,
+            MoveResizeDirection::SizeBottomLeft => 6,
+            MoveResizeDirection::SizeLeft => 7,
+            MoveResizeDirection::Move => 8,
+            MoveResizeDirection::SizeKeyboard => 9,
+            MoveResizeDirection::MoveKeyboard => 10,
+            MoveResizeDirection::Cancel => 11,
+        }
+    }
+}
+
+/// A `_NET_WM_MOVERESIZE` message that lets the window manager move or resize a window.
+///
+/// Clients that draw their own decorations send this when the user presses a button on the title
+/// bar or the border. The window manager then follows the pointer until the button is released.
+/// The client has to release its pointer grab first, otherwise the window manager cannot grab the
+/// pointer; [`ewmh::request_moveresize`](crate::ewmh::request_moveresize) does this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetWmMoveResize {
+    /// The window to move or resize.
+    pub window: Window,
+    /// The x coordinate of the pointer relative to the root window.
+    pub x_root: i32,
+    /// The y coordinate of the pointer relative to the root window.
+    pub y_root: i32,
+    /// What to do with the window.
+    pub direction: MoveResizeDirection,
+    /// The button that is pressed, or zero for a keyboard-initiated action.
+    pub button: u32,
+    /// Who sends the request.
+    pub source: RequestSource,
+}
+
+impl NetWmMoveResize {
+    /// Create a message sent by an application.
+    pub fn new(
+        window: Window,
+        direction: MoveResizeDirection,
+        (x_root, y_root): (i32, i32),
+        button: u32,
+    ) -> Self {
+        Self {
+            window,
+            x_root,
+            y_root,
+            direction,
+            button,
+            source: RequestSource::Application,
+        }
+    }
+
+    /// Set who sends the request.
+    pub fn source(mut self, source: RequestSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        let data = [
+            self.x_root as u32,
+            self.y_root as u32,
+            self.direction.into(),
+            self.button,
+            self.source.into(),
+        ];
+        client_message32(self.window, atoms.0._NET_WM_MOVERESIZE, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// A `_NET_MOVERESIZE_WINDOW` message that asks the window manager to move or resize a window.
+///
+/// Unlike a `ConfigureWindow` request, the message says who sends it, so that the window manager
+/// can treat requests of pagers differently, and which gravity the position refers to. Only the
+/// values that were set are changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetMoveResizeWindow {
+    /// The window to move or resize.
+    pub window: Window,
+    /// The gravity of the position, or `None` to use the `win_gravity` of `WM_NORMAL_HINTS`.
+    pub gravity: Option<Gravity>,
+    /// The new position of the window.
+    pub position: Option<(i32, i32)>,
+    /// The new size of the window.
+    pub size: Option<(u32, u32)>,
+    /// Who sends the request.
+    pub source: RequestSource,
+}
+
+impl NetMoveResizeWindow {
+    /// Create a message sent by an application that does not change anything.
+    pub fn new(window: Window) -> Self {
+        Self {
+            window,
+            gravity: None,
+            position: None,
+            size: None,
+            source: RequestSource::Application,
+        }
+    }
+
+    /// Set the gravity of the position.
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.gravity = Some(gravity);
+        self
+    }
+
+    /// Set the new position of the window.
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Set the new size of the window.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set who sends the request.
+    pub fn source(mut self, source: RequestSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        // Bits 8 to 11 say which of the values are present
+        let mut flags = self.gravity.map_or(0, u32::from) | u32::from(self.source) << 12;
+        let (x, y) = self.position.unwrap_or_default();
+        let (width, height) = self.size.unwrap_or_default();
+        if self.position.is_some() {
+            flags |= 0x3 << 8;
+        }
+        if self.size.is_some() {
+            flags |= 0xc << 8;
+        }
+        let data = [flags, x as u32, y as u32, width, height];
+        client_message32(self.window, atoms.0._NET_MOVERESIZE_WINDOW, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// A `_NET_CURRENT_DESKTOP` message that asks the window manager to switch to another desktop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetCurrentDesktop {
+    /// The index of the desktop, starting at zero.
+    pub desktop: u32,
+    /// The time of the user interaction that caused the request.
+    pub timestamp: Timestamp,
+}
+
+impl NetCurrentDesktop {
+    /// Create a message without a timestamp.
+    pub fn new(desktop: u32) -> Self {
+        Self {
+            desktop,
+            timestamp: CURRENT_TIME,
+        }
+    }
+
+    /// Set the time of the user interaction that caused the request.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Get the event that is sent to the given root window.
+    ///
+    /// The message is about the root window itself.
+    pub fn event(&self, atoms: &WmAtoms, root: Window) -> ClientMessageEvent {
+        let data = [self.desktop, self.timestamp, 0, 0, 0];
+        client_message32(root, atoms.0._NET_CURRENT_DESKTOP, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms, root))
+    }
+}
+
+/// A `WM_CHANGE_STATE` message from the ICCCM that asks the window manager to iconify a window.
+///
+/// The ICCCM only defines this message for the transition from the normal to the iconic state.
+/// To de-iconify a window, map it or use [`NetActiveWindow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WmChangeState {
+    /// The window to iconify.
+    pub window: Window,
+}
+
+impl WmChangeState {
+    /// The value of `IconicState` in the ICCCM.
+    const ICONIC_STATE: u32 = 3;
+
+    /// Create a message that iconifies the given window.
+    pub fn iconify(window: Window) -> Self {
+        Self { window }
+    }
+
+    /// Get the event that is sent to the root window.
+    pub fn event(&self, atoms: &WmAtoms) -> ClientMessageEvent {
+        let data = [Self::ICONIC_STATE, 0, 0, 0, 0];
+        client_message32(self.window, atoms.0.WM_CHANGE_STATE, data)
+    }
+
+    /// Send the message to the given root window.
+    pub fn send<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atoms: &WmAtoms,
+        root: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        send_to_root(conn, root, self.event(atoms))
+    }
+}
+
+/// The properties that a new top-level window should have.
+///
+/// The properties have to be set before the window is mapped, because window managers read them
+/// when they start managing a window.
+#[derive(Debug, Clone, Default)]
+pub struct WindowSetup<'a> {
+    title: Option<&'a str>,
+    delete_window: bool,
+    take_focus: bool,
+    ping: bool,
+    process: Option<(u32, Vec<u8>)>,
+}
+
+impl<'a> WindowSetup<'a> {
+    /// Create a setup that does not set any properties.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the title of the window via `_NET_WM_NAME` and `WM_NAME`.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set whether `WM_DELETE_WINDOW` is listed in `WM_PROTOCOLS`.
+    ///
+    /// With this protocol, the window manager sends a client message when the user wants to close
+    /// the window (see [`WmAtoms::is_delete_window`]) instead of disconnecting the client.
+    pub fn delete_window(mut self, delete_window: bool) -> Self {
+        self.delete_window = delete_window;
+        self
+    }
+
+    /// Set whether `WM_TAKE_FOCUS` is listed in `WM_PROTOCOLS`.
+    pub fn take_focus(mut self, take_focus: bool) -> Self {
+        self.take_focus = take_focus;
+        self
+    }
+
+    /// Set whether `_NET_WM_PING` is listed in `WM_PROTOCOLS`.
+    ///
+    /// A client with this protocol has to answer ping messages of the window manager, which uses
+    /// them to detect hanging clients.
+    pub fn ping(mut self, ping: bool) -> Self {
+        self.ping = ping;
+        self
+    }
+
+    /// Set `_NET_WM_PID` to the given process ID and `WM_CLIENT_MACHINE` to the given host name.
+    ///
+    /// The EWMH specification requires `WM_CLIENT_MACHINE` to be set together with
+    /// `_NET_WM_PID`, because the process ID is only meaningful on that machine.
+    pub fn pid(mut self, pid: u32, hostname: &[u8]) -> Self {
+        self.process = Some((pid, hostname.to_vec()));
+        self
+    }
+
+    /// Set `_NET_WM_PID` and `WM_CLIENT_MACHINE` for the current process.
+    ///
+    /// Nothing is set if the host name is not valid UTF-8.
+    pub fn current_process(self) -> Self {
+        match gethostname::gethostname().to_str() {
+            Some(hostname) => self.pid(std::process::id(), hostname.as_bytes()),
+            None => self,
+        }
+    }
+
+    /// Get the value of `WM_PROTOCOLS`.
+    fn protocols(&self, atoms: &WmAtoms) -> Vec<Atom> {
+        [
+            (self.delete_window, atoms.0.WM_DELETE_WINDOW),
+            (self.take_focus, atoms.0.WM_TAKE_FOCUS),
+            (self.ping, atoms.0._NET_WM_PING),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|&(_, atom)| atom)
+        .collect()
+    }
+
+    /// Set the properties on the given window.
+    ///
+    /// `WM_PROTOCOLS` is always replaced, also if none of the protocols is enabled.
+    pub fn apply<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        atoms: &WmAtoms,
+        window: Window,
+    ) -> Result<(), ConnectionError> {
+        if let Some(title) = self.title {
+            let _ = conn.change_property8(
+                PropMode::REPLACE,
+                window,
+                atoms.0._NET_WM_NAME,
+                atoms.0.UTF8_STRING,
+                title.as_bytes(),
+            )?;
+            let _ = conn.change_property8(
+                PropMode::REPLACE,
+                window,
+                AtomEnum::WM_NAME,
+                AtomEnum::STRING,
+                title.as_bytes(),
+            )?;
+        }
+        let _ = conn.change_property32(
+            PropMode::REPLACE,
+            window,
+            atoms.0.WM_PROTOCOLS,
+            AtomEnum::ATOM,
+            &self.protocols(atoms),
+        )?;
+        if let Some((pid, hostname)) = &self.process {
+            let _ = conn.change_property32(
+                PropMode::REPLACE,
+                window,
+                atoms.0._NET_WM_PID,
+                AtomEnum::CARDINAL,
+                &[*pid],
+            )?;
+            let _ = conn.change_property8(
+                PropMode::REPLACE,
+                window,
+                AtomEnum::WM_CLIENT_MACHINE,
+                AtomEnum::STRING,
+                hostname,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The hints that a window manager lists in `_NET_SUPPORTED`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SupportedHints(HashSet<Atom>);
+
+impl SupportedHints {
+    /// Check whether the given hint is supported, e.g. `_NET_WM_STATE_FULLSCREEN`.
+    pub fn contains(&self, atom: Atom) -> bool {
+        self.0.contains(&atom)
+    }
+
+    /// Get the number of supported hints.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether no hints are supported.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the supported hints in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Atom> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+/// A running EWMH window manager, as found by [`detect_wm`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedWm {
+    check_window: Window,
+    name: Option<String>,
+    supported: SupportedHints,
+}
+
+impl DetectedWm {
+    /// Get the window that the window manager named in `_NET_SUPPORTING_WM_CHECK`.
+    ///
+    /// The window is destroyed when the window manager exits. Selecting `StructureNotify` on it
+    /// notices this.
+    pub fn check_window(&self) -> Window {
+        self.check_window
+    }
+
+    /// Get the name of the window manager from `_NET_WM_NAME` of the check window.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the hints that the window manager supports.
+    pub fn supported(&self) -> &SupportedHints {
+        &self.supported
+    }
+
+    /// Check whether the window manager supports the given hint.
+    ///
+    /// Applications should check this before they rely on an optional part of the EWMH
+    /// specification, e.g. `_NET_WM_MOVERESIZE` for client-side decorations.
+    pub fn wm_supports(&self, atom: Atom) -> bool {
+        self.supported.contains(atom)
+    }
+}
+
+/// Find the EWMH window manager that manages the given screen.
+///
+/// A window manager announces itself by setting `_NET_SUPPORTING_WM_CHECK` on the root window to
+/// a child window, which has the same property pointing to itself. A window manager that crashed
+/// leaves a stale property on the root window behind, so the check window is validated before it
+/// is trusted. `None` is returned if no window manager passes this check.
+///
+/// The name is read with any type, since some window managers set `_NET_WM_NAME` with the type
+/// `STRING` instead of `UTF8_STRING`. Invalid UTF-8 is replaced with U+FFFD.
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::protocol::xproto::ConnectionExt as _;
+/// use x11rb::wm::detect_wm;
+///
+/// fn can_go_fullscreen(conn: &impl Connection, screen_num: usize) -> Result<bool, ReplyError> {
+///     let fullscreen = conn.intern_atom(false, b"_NET_WM_STATE_FULLSCREEN")?;
+///     let fullscreen = fullscreen.reply()?.atom;
+///     Ok(match detect_wm(conn, screen_num)? {
+///         Some(wm) => wm.wm_supports(fullscreen),
+///         None => false,
+///     })
+/// }
+/// ```
+///
+/// This function waits for the replies to the necessary requests.
+pub fn detect_wm<C: Connection + ?Sized>(
+    conn: &C,
+    screen_num: usize,
+) -> Result<Option<DetectedWm>, ReplyError> {
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = DetectAtoms::new(conn)?.reply()?;
+    let check_window = match supporting_wm_check(conn, root, atoms._NET_SUPPORTING_WM_CHECK)? {
+        Some(window) => window,
+        None => return Ok(None),
+    };
+    if supporting_wm_check(conn, check_window, atoms._NET_SUPPORTING_WM_CHECK)?
+        != Some(check_window)
+    {
+        return Ok(None);
+    }
+    let name = match ignore_bad_window(conn.get_property_full(
+        false,
+        check_window,
+        atoms._NET_WM_NAME,
+        AtomEnum::ANY,
+    ))? {
+        Some(reply) if reply.format == 8 => {
+            Some(String::from_utf8_lossy(&reply.value).into_owned())
+        }
+        _ => None,
+    };
+    let supported = properties::get_atoms(conn, root, atoms._NET_SUPPORTED)?;
+    Ok(Some(DetectedWm {
+        check_window,
+        name,
+        supported: SupportedHints(supported.into_iter().collect()),
+    }))
+}
+
+/// Get the window that `_NET_SUPPORTING_WM_CHECK` of the given window names.
+///
+/// Returns `None` if the property is not set or if the window does not exist.
+fn supporting_wm_check<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+    property: Atom,
+) -> Result<Option<Window>, ReplyError> {
+    let reply = xproto::get_property(conn, false, window, property, AtomEnum::WINDOW, 0, 1)?;
+    Ok(ignore_bad_window(reply.reply())?
+        .and_then(|reply| reply.value32().and_then(|mut values| values.next()))
+        .filter(|&window| window != NONE))
+}
+
+/// Turn a `Window` error into `None`, e.g. for the check window of a window manager that exited.
+fn ignore_bad_window(
+    result: Result<GetPropertyReply, ReplyError>,
+) -> Result<Option<GetPropertyReply>, ReplyError> {
+    match result {
+        Ok(reply) => Ok(Some(reply)),
+        Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Window => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Atoms, MoveResizeDirection, NetActiveWindow, NetCloseWindow, NetCurrentDesktop,
+        NetMoveResizeWindow, NetWmMoveResize, NetWmState, StateAction, WindowSetup, WmAtoms,
+        WmChangeState,
+    };
+    use crate::properties::RequestSource;
+    use crate::protocol::xproto::{ClientMessageData, ClientMessageEvent, Gravity};
+
+    fn atoms() -> WmAtoms {
+        WmAtoms(Atoms {
+            UTF8_STRING: 1,
+            WM_PROTOCOLS: 2,
+            WM_DELETE_WINDOW: 3,
+            WM_TAKE_FOCUS: 4,
+            WM_CHANGE_STATE: 5,
+            _NET_WM_STATE: 6,
+            _NET_ACTIVE_WINDOW: 7,
+            _NET_CLOSE_WINDOW: 8,
+            _NET_WM_PID: 9,
+            _NET_WM_NAME: 10,
+            _NET_WM_PING: 11,
+            _NET_WM_MOVERESIZE: 12,
+            _NET_CURRENT_DESKTOP: 13,
+            _NET_MOVERESIZE_WINDOW: 14,
+        })
+    }
+
+    fn check(event: ClientMessageEvent, window: u32, type_: u32, data: [u32; 5]) {
+        assert_eq!(event.format, 32);
+        assert_eq!(event.window, window);
+        assert_eq!(event.type_, type_);
+        assert_eq!(event.data.as_data32(), data);
+    }
+
+    #[test]
+    fn net_wm_state() {
+        let message = NetWmState::new(42, StateAction::Toggle, 100)
+            .second(101)
+            .source(RequestSource::Pager);
+        check(message.event(&atoms()), 42, 6, [2, 100, 101, 2, 0]);
+        let message = NetWmState::new(42, StateAction::Remove, 100);
+        check(message.event(&atoms()), 42, 6, [0, 100, 0, 1, 0]);
+    }
+
+    #[test]
+    fn net_active_window() {
+        let message = NetActiveWindow::new(42)
+            .timestamp(1234)
+            .currently_active(43);
+        check(message.event(&atoms()), 42, 7, [1, 1234, 43, 0, 0]);
+    }
+
+    #[test]
+    fn net_close_window() {
+        let message = NetCloseWindow::new(42).timestamp(1234);
+        check(message.event(&atoms()), 42, 8, [1234, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn net_wm_moveresize() {
+        let message = NetWmMoveResize::new(42, MoveResizeDirection::SizeBottomRight, (-5, 20), 1);
+        check(
+            message.event(&atoms()),
+            42,
+            12,
+            [(-5i32) as u32, 20, 4, 1, 1],
+        );
+    }
+
+    #[test]
+    fn net_moveresize_window() {
+        let message = NetMoveResizeWindow::new(42).size(300, 200);
+        check(message.event(&atoms()), 42, 14, [0x1c00, 0, 0, 300, 200]);
+        let message = message
+            .position(-5, 20)
+            .gravity(Gravity::STATIC)
+            .source(RequestSource::Pager);
+        check(
+            message.event(&atoms()),
+            42,
+            14,
+            [0x2f0a, (-5i32) as u32, 20, 300, 200],
+        );
+    }
+
+    #[test]
+    fn net_current_desktop() {
+        let message = NetCurrentDesktop::new(3).timestamp(1234);
+        check(message.event(&atoms(), 1), 1, 13, [3, 1234, 0, 0, 0]);
+    }
+
+    #[test]
+    fn wm_change_state() {
+        check(
+            WmChangeState::iconify(42).event(&atoms()),
+            42,
+            5,
+            [3, 0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn protocols() {
+        let atoms = atoms();
+        let setup = WindowSetup::new().delete_window(true).ping(true);
+        assert_eq!(setup.protocols(&atoms), [3, 11]);
+        assert!(WindowSetup::new().protocols(&atoms).is_empty());
+    }
+
+    #[test]
+    fn delete_window_message() {
+        let atoms = atoms();
+        let mut event = ClientMessageEvent {
+            response_type: 33,
+            format: 32,
+            sequence: 0,
+            window: 42,
+            type_: 2,
+            data: ClientMessageData::from([3, 0, 0, 0, 0]),
+        };
+        assert!(atoms.is_delete_window(&event));
+        event.data = ClientMessageData::from([4, 0, 0, 0, 0]);
+        assert!(!atoms.is_delete_window(&event));
+    }
+
+    #[cfg(feature = "testing")]
+    fn property_reply(format: u8, values: &[u32]) -> Vec<u8> {
+        let mut reply = vec![0; 32];
+        reply[1] = format;
+        reply[16..20].copy_from_slice(&(values.len() as u32).to_ne_bytes());
+        for value in values {
+            reply.extend(value.to_ne_bytes());
+        }
+        reply
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn detect_wm() {
+        use super::detect_wm;
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let conn = FakeConnection::default();
+        for &atom in &[20u32, 21, 22] {
+            let mut reply = vec![0; 32];
+            reply[8..12].copy_from_slice(&atom.to_ne_bytes());
+            conn.respond(xproto::INTERN_ATOM_REQUEST, None, Response::Reply(reply));
+        }
+        let mut name = property_reply(8, &[]);
+        name[16..20].copy_from_slice(&4u32.to_ne_bytes());
+        name.extend(b"test");
+        for reply in [
+            property_reply(32, &[0x100]),
+            property_reply(32, &[0x100]),
+            name,
+            property_reply(32, &[30, 31]),
+        ] {
+            conn.respond(xproto::GET_PROPERTY_REQUEST, None, Response::Reply(reply));
+        }
+
+        let wm = detect_wm(&conn, 0).unwrap().unwrap();
+        assert_eq!(wm.check_window(), 0x100);
+        assert_eq!(wm.name(), Some("test"));
+        assert_eq!(wm.supported().len(), 2);
+        assert!(wm.wm_supports(31));
+        assert!(!wm.wm_supports(32));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn detect_wm_stale_check_window() {
+        use super::detect_wm;
+        use crate::protocol::xproto;
+        use crate::testing::{FakeConnection, Response};
+
+        let conn = FakeConnection::default();
+        for _ in 0..3 {
+            conn.respond(
+                xproto::INTERN_ATOM_REQUEST,
+                None,
+                Response::Reply(vec![0; 32]),
+            );
+        }
+        conn.respond(
+            xproto::GET_PROPERTY_REQUEST,
+            None,
+            Response::Reply(property_reply(32, &[0x100])),
+        );
+        // The window manager exited and its check window is gone
+        conn.respond(
+            xproto::GET_PROPERTY_REQUEST,
+            None,
+            Response::Error {
+                error_code: xproto::WINDOW_ERROR,
+                bad_value: 0x100,
+            },
+        );
+        assert_eq!(detect_wm(&conn, 0).unwrap(), None);
+        assert_eq!(conn.sent_requests().len(), 5);
+    }
+}
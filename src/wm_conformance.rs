@@ -0,0 +1,469 @@
+//! Checks that a window manager follows the ICCCM and the EWMH specification.
+//!
+//! The checks in this module act like a client of the window manager under test: They create and
+//! map windows, send requests to the window manager and wait for it to react. They are meant for
+//! the test suites of window managers that are written with x11rb. A typical test starts an X11
+//! server like Xvfb, starts the window manager on it and then runs the checks:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use x11rb::wm_conformance::WmConformance;
+//!
+//! let (conn, screen_num) = x11rb::connect(Some(":99")).unwrap();
+//! let report = WmConformance::new(&conn, screen_num)
+//!     .unwrap()
+//!     .timeout(Duration::from_secs(2))
+//!     .run_all();
+//! println!("{}", report);
+//! assert!(report.is_success());
+//! ```
+//!
+//! Since the window manager reacts asynchronously, each check waits up to a timeout for the
+//! expected state. A check that needs an optional feature of the EWMH specification is skipped if
+//! the window manager does not list it in `_NET_SUPPORTED`.
+//!
+//! The code in this module is only available when the `conformance` feature of the library is
+//! enabled.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::conformance::{ensure, CheckOutcome, ConformanceError};
+use crate::connection::Connection;
+use crate::properties::{RequestSource, WmHints};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ConnectionExt as _, EventMask, MapState, UnmapNotifyEvent, Window,
+};
+use crate::send_event::{send, Delivery};
+use crate::wm::{NetActiveWindow, WmAtoms};
+use crate::CURRENT_TIME;
+
+atom_manager! {
+    Atoms: AtomsCookie {
+        UTF8_STRING,
+        WM_STATE,
+        _NET_SUPPORTED,
+        _NET_SUPPORTING_WM_CHECK,
+        _NET_WM_NAME,
+        _NET_CLIENT_LIST,
+        _NET_ACTIVE_WINDOW,
+    }
+}
+
+/// The value of `WM_STATE` for a window in the Withdrawn state.
+const WITHDRAWN_STATE: u32 = 0;
+
+/// The value of `WM_STATE` for a window in the Normal state.
+const NORMAL_STATE: u32 = 1;
+
+/// How long to sleep between two looks at the state of the window manager.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The specification that a check is based on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spec {
+    /// The Inter-Client Communication Conventions Manual.
+    Icccm,
+    /// The Extended Window Manager Hints.
+    Ewmh,
+}
+
+impl fmt::Display for Spec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Spec::Icccm => f.write_str("ICCCM"),
+            Spec::Ewmh => f.write_str("EWMH"),
+        }
+    }
+}
+
+/// The result of a single check of a [`WmReport`].
+#[derive(Debug)]
+pub struct WmCheckResult {
+    /// The name of the check.
+    pub name: &'static str,
+    /// The specification that the check is based on.
+    pub spec: Spec,
+    /// The result of the check.
+    pub result: Result<CheckOutcome, ConformanceError>,
+}
+
+/// The results of [`WmConformance::run_all`].
+///
+/// The `Display` implementation prints one line per check.
+#[derive(Debug)]
+pub struct WmReport {
+    /// The name of the window manager as announced via `_NET_WM_NAME`, if any.
+    pub wm_name: Option<String>,
+    /// The results of the checks in the order in which they ran.
+    pub checks: Vec<WmCheckResult>,
+}
+
+impl WmReport {
+    /// Check if no check failed.
+    ///
+    /// Skipped checks do not count as failures.
+    pub fn is_success(&self) -> bool {
+        self.checks.iter().all(|check| check.result.is_ok())
+    }
+
+    /// Get the checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &WmCheckResult> + '_ {
+        self.checks.iter().filter(|check| check.result.is_err())
+    }
+}
+
+impl fmt::Display for WmReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.wm_name {
+            Some(name) => writeln!(f, "Window manager: {}", name)?,
+            None => writeln!(f, "Window manager: unknown")?,
+        }
+        for check in &self.checks {
+            write!(f, "[{}] {}: ", check.spec, check.name)?;
+            match &check.result {
+                Ok(CheckOutcome::Passed) => writeln!(f, "passed")?,
+                Ok(CheckOutcome::Skipped(reason)) => writeln!(f, "skipped ({})", reason)?,
+                Err(err) => writeln!(f, "FAILED: {}", err)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs conformance checks against the window manager of a screen.
+pub struct WmConformance<'c, C: Connection + ?Sized> {
+    conn: &'c C,
+    root: Window,
+    atoms: Atoms,
+    wm_atoms: WmAtoms,
+    timeout: Duration,
+}
+
+impl<C: Connection + ?Sized> fmt::Debug for WmConformance<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WmConformance")
+            .field("root", &self.root)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'c, C: Connection + ?Sized> WmConformance<'c, C> {
+    /// Prepare checks against the window manager of the given screen.
+    ///
+    /// The default timeout for the reactions of the window manager is one second.
+    pub fn new(conn: &'c C, screen_num: usize) -> Result<Self, ConformanceError> {
+        let root = conn
+            .setup()
+            .roots
+            .get(screen_num)
+            .ok_or_else(|| ConformanceError::Failed(format!("No screen {}", screen_num)))?
+            .root;
+        let atoms = Atoms::new(conn)?.reply()?;
+        let wm_atoms = WmAtoms::new(conn)?;
+        Ok(Self {
+            conn,
+            root,
+            atoms,
+            wm_atoms,
+            timeout: Duration::from_secs(1),
+        })
+    }
+
+    /// Set how long to wait for the window manager to react.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Get the name of the window manager as announced via `_NET_WM_NAME`.
+    pub fn wm_name(&self) -> Result<Option<String>, ConformanceError> {
+        let check_window = match self.supporting_wm_check(self.root)? {
+            Some(window) => window,
+            None => return Ok(None),
+        };
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                check_window,
+                self.atoms._NET_WM_NAME,
+                self.atoms.UTF8_STRING,
+                0,
+                1024,
+            )?
+            .reply()?;
+        Ok(String::from_utf8(reply.value).ok())
+    }
+
+    /// Run all checks.
+    pub fn run_all(&self) -> WmReport {
+        let checks = vec![
+            WmCheckResult {
+                name: "supporting_wm_check",
+                spec: Spec::Ewmh,
+                result: self.check_supporting_wm_check(),
+            },
+            WmCheckResult {
+                name: "supported",
+                spec: Spec::Ewmh,
+                result: self.check_supported(),
+            },
+            WmCheckResult {
+                name: "map_unmap",
+                spec: Spec::Icccm,
+                result: self.check_map_unmap(),
+            },
+            WmCheckResult {
+                name: "client_list",
+                spec: Spec::Ewmh,
+                result: self.check_client_list(),
+            },
+            WmCheckResult {
+                name: "focus",
+                spec: Spec::Ewmh,
+                result: self.check_focus(),
+            },
+        ];
+        WmReport {
+            wm_name: self.wm_name().ok().flatten(),
+            checks,
+        }
+    }
+
+    /// Check that the window manager announces itself via `_NET_SUPPORTING_WM_CHECK`.
+    ///
+    /// The property has to be set on the root window and name a child window, which has the same
+    /// property pointing to itself.
+    pub fn check_supporting_wm_check(&self) -> Result<CheckOutcome, ConformanceError> {
+        let window = self.supporting_wm_check(self.root)?.ok_or_else(|| {
+            ConformanceError::Failed("_NET_SUPPORTING_WM_CHECK is not set on the root".to_string())
+        })?;
+        let own = self.supporting_wm_check(window)?;
+        ensure(own == Some(window), || {
+            format!(
+                "_NET_SUPPORTING_WM_CHECK of window {} is {:?} instead of itself",
+                window, own
+            )
+        })?;
+        Ok(CheckOutcome::Passed)
+    }
+
+    /// Check that the window manager lists the supported hints in `_NET_SUPPORTED`.
+    pub fn check_supported(&self) -> Result<CheckOutcome, ConformanceError> {
+        let supported = self.supported()?;
+        ensure(!supported.is_empty(), || {
+            "_NET_SUPPORTED is missing or empty".to_string()
+        })?;
+        Ok(CheckOutcome::Passed)
+    }
+
+    /// Check that the window manager maintains `WM_STATE` when a window is mapped and unmapped.
+    ///
+    /// After mapping, the window has to become viewable and get `WM_STATE` with the Normal state.
+    /// After withdrawing it as described in ICCCM §4.1.4, `WM_STATE` has to be removed or set to
+    /// the Withdrawn state.
+    pub fn check_map_unmap(&self) -> Result<CheckOutcome, ConformanceError> {
+        self.with_window(|window| {
+            self.map_and_wait(window)?;
+            let attributes = self.conn.get_window_attributes(window)?.reply()?;
+            ensure(attributes.map_state == MapState::VIEWABLE, || {
+                format!(
+                    "Window has map state {:?} instead of viewable",
+                    attributes.map_state
+                )
+            })?;
+
+            self.withdraw(window)?;
+            self.wait_for("WM_STATE to become Withdrawn", || {
+                Ok(matches!(
+                    self.wm_state(window)?,
+                    None | Some(WITHDRAWN_STATE)
+                ))
+            })?;
+            Ok(CheckOutcome::Passed)
+        })
+    }
+
+    /// Check that the window manager maintains `_NET_CLIENT_LIST`.
+    ///
+    /// A mapped window has to appear in the list and disappear again after it is withdrawn.
+    pub fn check_client_list(&self) -> Result<CheckOutcome, ConformanceError> {
+        if !self.supported()?.contains(&self.atoms._NET_CLIENT_LIST) {
+            return Ok(CheckOutcome::Skipped("_NET_CLIENT_LIST is not supported"));
+        }
+        self.with_window(|window| {
+            self.map_and_wait(window)?;
+            self.wait_for("the window to appear in _NET_CLIENT_LIST", || {
+                Ok(self.client_list()?.contains(&window))
+            })?;
+            self.withdraw(window)?;
+            self.wait_for("the window to disappear from _NET_CLIENT_LIST", || {
+                Ok(!self.client_list()?.contains(&window))
+            })?;
+            Ok(CheckOutcome::Passed)
+        })
+    }
+
+    /// Check that the window manager focuses a window that is activated via `_NET_ACTIVE_WINDOW`.
+    ///
+    /// The request is sent with the source indication of a pager, which should not be subject to
+    /// focus stealing prevention. Afterwards, the window has to have the input focus and has to be
+    /// named in the `_NET_ACTIVE_WINDOW` property of the root window.
+    pub fn check_focus(&self) -> Result<CheckOutcome, ConformanceError> {
+        if !self.supported()?.contains(&self.atoms._NET_ACTIVE_WINDOW) {
+            return Ok(CheckOutcome::Skipped("_NET_ACTIVE_WINDOW is not supported"));
+        }
+        self.with_window(|window| {
+            let hints = WmHints {
+                input: Some(true),
+                ..WmHints::new()
+            };
+            let _ = hints.set(self.conn, window)?;
+            self.map_and_wait(window)?;
+            NetActiveWindow::new(window)
+                .source(RequestSource::Pager)
+                .timestamp(CURRENT_TIME)
+                .send(self.conn, &self.wm_atoms, self.root)?
+                .check()?;
+            self.wait_for("the window to get the input focus", || {
+                Ok(self.conn.get_input_focus()?.reply()?.focus == window)
+            })?;
+            self.wait_for("_NET_ACTIVE_WINDOW to name the window", || {
+                let active = self.window_property(self.root, self.atoms._NET_ACTIVE_WINDOW)?;
+                Ok(active.first() == Some(&window))
+            })?;
+            Ok(CheckOutcome::Passed)
+        })
+    }
+
+    /// Create a top-level window, run `f` with it and destroy the window again.
+    fn with_window(
+        &self,
+        f: impl FnOnce(Window) -> Result<CheckOutcome, ConformanceError>,
+    ) -> Result<CheckOutcome, ConformanceError> {
+        let window = self.conn.generate_id()?;
+        self.conn
+            .create_window(
+                0,
+                window,
+                self.root,
+                0,
+                0,
+                100,
+                100,
+                0,
+                xproto::WindowClass::INPUT_OUTPUT,
+                0,
+                &Default::default(),
+            )?
+            .check()?;
+        let result = f(window);
+        let _ = self.conn.destroy_window(window)?;
+        self.conn.flush()?;
+        result
+    }
+
+    /// Map a window and wait until the window manager put it into the Normal state.
+    fn map_and_wait(&self, window: Window) -> Result<(), ConformanceError> {
+        self.conn.map_window(window)?.check()?;
+        self.wait_for("WM_STATE to become Normal", || {
+            Ok(self.wm_state(window)? == Some(NORMAL_STATE))
+        })
+    }
+
+    /// Withdraw a window as described in ICCCM §4.1.4.
+    ///
+    /// The window is unmapped and a synthetic `UnmapNotify` is sent to the root window, so that
+    /// the window manager also notices if the window was not mapped yet.
+    fn withdraw(&self, window: Window) -> Result<(), ConformanceError> {
+        let _ = self.conn.unmap_window(window)?;
+        let event = UnmapNotifyEvent {
+            response_type: xproto::UNMAP_NOTIFY_EVENT,
+            sequence: 0,
+            event: self.root,
+            window,
+            from_configure: false,
+        };
+        let mask = EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT;
+        send(self.conn, self.root, Delivery::Selected(mask), event)?.check()?;
+        Ok(())
+    }
+
+    /// Wait until `condition` returns `true` or the timeout expires.
+    fn wait_for(
+        &self,
+        what: &str,
+        mut condition: impl FnMut() -> Result<bool, ConformanceError>,
+    ) -> Result<(), ConformanceError> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if condition()? {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(ConformanceError::Failed(format!(
+                    "Timed out waiting for {}",
+                    what
+                )));
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Get the state from the `WM_STATE` property of a window.
+    fn wm_state(&self, window: Window) -> Result<Option<u32>, ConformanceError> {
+        let reply = self
+            .conn
+            .get_property(false, window, self.atoms.WM_STATE, AtomEnum::ANY, 0, 2)?
+            .reply()?;
+        Ok(reply.value32().and_then(|mut values| values.next()))
+    }
+
+    /// Get the window that is named in `_NET_SUPPORTING_WM_CHECK` of the given window.
+    fn supporting_wm_check(&self, window: Window) -> Result<Option<Window>, ConformanceError> {
+        let value = self.window_property(window, self.atoms._NET_SUPPORTING_WM_CHECK)?;
+        Ok(value.first().copied())
+    }
+
+    /// Get the content of `_NET_SUPPORTED`.
+    fn supported(&self) -> Result<Vec<Atom>, ConformanceError> {
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                self.root,
+                self.atoms._NET_SUPPORTED,
+                AtomEnum::ATOM,
+                0,
+                u32::MAX,
+            )?
+            .reply()?;
+        Ok(reply
+            .value32()
+            .map(|values| values.collect())
+            .unwrap_or_default())
+    }
+
+    /// Get the content of `_NET_CLIENT_LIST`.
+    fn client_list(&self) -> Result<Vec<Window>, ConformanceError> {
+        self.window_property(self.root, self.atoms._NET_CLIENT_LIST)
+    }
+
+    /// Get the content of a property of type `WINDOW`.
+    fn window_property(
+        &self,
+        window: Window,
+        property: Atom,
+    ) -> Result<Vec<Window>, ConformanceError> {
+        let reply = self
+            .conn
+            .get_property(false, window, property, AtomEnum::WINDOW, 0, u32::MAX)?
+            .reply()?;
+        Ok(reply
+            .value32()
+            .map(|values| values.collect())
+            .unwrap_or_default())
+    }
+}
@@ -2,10 +2,18 @@
 
 use std::convert::TryInto;
 use std::marker::PhantomData;
+use std::mem::size_of;
 
 use super::cookie::VoidCookie;
 use super::errors::{ConnectionError, ReplyError};
 use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
+#[cfg(any(
+    not(feature = "request-pruning"),
+    x11rb_request = "xproto::GetProperty"
+))]
+use super::protocol::xproto::{AtomEnum, GetPropertyReply};
+#[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
+use super::protocol::xproto::{Drawable, Gcontext, ImageFormat};
 use super::x11_utils::TryParse;
 
 /// Iterator implementation used by `GetPropertyReply`.
@@ -16,6 +24,8 @@ use super::x11_utils::TryParse;
 pub struct PropertyIterator<'a, T>(&'a [u8], PhantomData<T>);
 
 impl<'a, T> PropertyIterator<'a, T> {
+    // Only used by `GetPropertyReply`, which might be pruned
+    #[cfg_attr(feature = "request-pruning", allow(dead_code))]
     pub(crate) fn new(value: &'a [u8]) -> Self {
         PropertyIterator(value, PhantomData)
     }
@@ -41,7 +51,7 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.0.len() / std::mem::size_of::<T>();
+        let size = self.0.len() / size_of::<T>();
         (size, Some(size))
     }
 }
@@ -50,6 +60,64 @@ impl<T: TryParse> std::iter::FusedIterator for PropertyIterator<'_, T> {}
 
 /// Extension trait that simplifies API use
 pub trait ConnectionExt: XProtoConnectionExt {
+    /// Get the complete value of a property.
+    ///
+    /// This takes the same arguments as [`get_property`](XProtoConnectionExt::get_property),
+    /// except for the offset and length. `GetProperty` requests are sent with increasing offsets
+    /// until the whole value was received, so large properties are not truncated. The returned
+    /// reply contains the complete value and its `bytes_after` field is zero.
+    ///
+    /// If the property does not exist or its type does not match `type_`, the first reply is
+    /// returned as-is. If `delete` is true, the X11 server deletes the property after the last
+    /// part of its value was read. If another client replaces the property in the meantime,
+    /// reading starts over.
+    ///
+    /// This function waits for the replies to the `GetProperty` requests.
+    #[cfg(any(
+        not(feature = "request-pruning"),
+        x11rb_request = "xproto::GetProperty"
+    ))]
+    fn get_property_full<A, B>(
+        &self,
+        delete: bool,
+        window: Window,
+        property: A,
+        type_: B,
+    ) -> Result<GetPropertyReply, ReplyError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        let (property, type_) = (property.into(), type_.into());
+        // The length is given in units of four bytes
+        let chunk_length = (self.maximum_request_bytes() / 4)
+            .try_into()
+            .unwrap_or(u32::MAX);
+        'restart: loop {
+            let mut result = self
+                .get_property(delete, window, property, type_, 0, chunk_length)?
+                .reply()?;
+            let type_mismatch = type_ != u32::from(AtomEnum::ANY) && result.type_ != type_;
+            if result.format == 0 || type_mismatch {
+                return Ok(result);
+            }
+            while result.bytes_after != 0 {
+                // All previous replies were complete, so this is a multiple of four
+                let offset = (result.value.len() / 4).try_into().unwrap_or(u32::MAX);
+                let chunk = self
+                    .get_property(delete, window, property, type_, offset, chunk_length)?
+                    .reply()?;
+                if chunk.type_ != result.type_ || chunk.format != result.format {
+                    continue 'restart;
+                }
+                result.value.extend_from_slice(&chunk.value);
+                result.value_len += chunk.value_len;
+                result.bytes_after = chunk.bytes_after;
+            }
+            return Ok(result);
+        }
+    }
+
     /// Change a property on a window with format 8.
     fn change_property8<A, B>(
         &self,
@@ -130,6 +198,97 @@ pub trait ConnectionExt: XProtoConnectionExt {
         )
     }
 
+    /// Upload an image via `PutImage`, split into as many requests as necessary.
+    ///
+    /// This takes the same arguments as [`put_image`](XProtoConnectionExt::put_image), but sends
+    /// several `PutImage` requests if the image does not fit into the server's maximum request
+    /// size. The image is split along scanlines, so each request contains complete, padded
+    /// scanlines and the padding does not have to be computed again.
+    ///
+    /// `data` has to contain `height` scanlines for `ZPixmap`, or `depth` planes of `height`
+    /// scanlines each for `XYPixmap`. For `XYBitmap`, there is just one plane. The length of a
+    /// scanline is computed from the length of `data`. If a single scanline does not fit into a
+    /// request, [`ConnectionError::MaximumRequestLengthExceeded`] is returned.
+    ///
+    /// If this function returns an error, the image could already be partially sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is not a multiple of the number of scanlines.
+    #[cfg(any(not(feature = "request-pruning"), x11rb_request = "xproto::PutImage"))]
+    #[allow(clippy::too_many_arguments)]
+    fn put_image_chunked(
+        &self,
+        format: ImageFormat,
+        drawable: Drawable,
+        gc: Gcontext,
+        width: u16,
+        height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        left_pad: u8,
+        depth: u8,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError> {
+        use std::borrow::Cow;
+        use std::convert::TryFrom;
+
+        if height == 0 {
+            return Ok(Vec::new());
+        }
+        let planes = if format == ImageFormat::XY_PIXMAP {
+            usize::from(depth)
+        } else {
+            1
+        };
+        let scanlines = planes * usize::from(height);
+        assert_eq!(
+            data.len() % scanlines,
+            0,
+            "`data` does not consist of complete scanlines"
+        );
+        let stride = data.len() / scanlines;
+        let plane_size = stride * usize::from(height);
+
+        let put_image_header = 24;
+        let max_data = self
+            .maximum_request_bytes()
+            .saturating_sub(put_image_header);
+        // Always send at least one scanline so that a too large request produces an error
+        let lines_per_request = (max_data / (stride * planes).max(1))
+            .clamp(1, usize::from(height))
+            .try_into()
+            .unwrap_or(u16::MAX);
+
+        let mut result = Vec::new();
+        let mut y_offset = 0;
+        while y_offset < height {
+            let dst_y = match i16::try_from(i32::from(dst_y) + i32::from(y_offset)) {
+                Ok(dst_y) => dst_y,
+                // The remaining scanlines are outside of any drawable
+                Err(_) => break,
+            };
+            let lines = lines_per_request.min(height - y_offset);
+            let start = usize::from(y_offset) * stride;
+            let end = start + usize::from(lines) * stride;
+            let chunk = if planes == 1 {
+                Cow::Borrowed(&data[start..end])
+            } else {
+                Cow::Owned(
+                    data.chunks_exact(plane_size)
+                        .flat_map(|plane| &plane[start..end])
+                        .copied()
+                        .collect(),
+                )
+            };
+            result.push(self.put_image(
+                format, drawable, gc, width, lines, dst_x, dst_y, left_pad, depth, &chunk,
+            )?);
+            y_offset += lines;
+        }
+        Ok(result)
+    }
+
     /// Synchronise with the X11 server.
     ///
     /// This function synchronises with the X11 server. This means that all requests that are still
@@ -4,11 +4,12 @@
 //! [`Serialize`] traits. These traits are used internally for parsing incoming data and producing
 //! outgoing data when talking with the X11 server.
 
-use std::convert::TryInto;
+use std::cell::Cell;
+use std::convert::{TryFrom, TryInto};
 
-use crate::errors::ParseError;
-use crate::protocol::ErrorKind;
-use crate::utils::RawFdContainer;
+use crate::errors::{BufferTooSmall, ParseError};
+use crate::protocol::{get_request_name, ErrorKind};
+use crate::utils::OwnedFd;
 
 /// Representation of an X11 error packet that was sent by the server.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +26,10 @@ pub struct X11Error {
     pub minor_opcode: u16,
     /// The major opcode of the request that caused this error.
     pub major_opcode: u8,
+    /// The name of the request that caused this error, e.g. `"xproto::CreateWindow"`.
+    ///
+    /// This is `None` if the request is not known to x11rb.
+    pub request_name: Option<&'static str>,
 }
 
 impl X11Error {
@@ -43,6 +48,7 @@ impl X11Error {
             Err(ParseError::InvalidValue)
         } else {
             let error_kind = ErrorKind::from_wire_error_code(error_code, ext_info_provider);
+            let request_name = get_request_name(ext_info_provider, major_opcode, minor_opcode);
             Ok(X11Error {
                 error_kind,
                 error_code,
@@ -50,11 +56,31 @@ impl X11Error {
                 bad_value,
                 minor_opcode,
                 major_opcode,
+                request_name,
             })
         }
     }
 }
 
+impl std::fmt::Display for X11Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} error (bad value {:#x}) in request ",
+            self.error_kind, self.bad_value
+        )?;
+        match self.request_name {
+            Some(name) => write!(f, "{} ", name)?,
+            None => f.write_str("with ")?,
+        }
+        write!(
+            f,
+            "(major opcode {}, minor opcode {}, sequence number {})",
+            self.major_opcode, self.minor_opcode, self.sequence
+        )
+    }
+}
+
 impl From<&X11Error> for [u8; 32] {
     fn from(input: &X11Error) -> Self {
         let sequence_bytes = input.sequence.serialize();
@@ -150,14 +176,14 @@ pub trait TryParseFd: Sized {
     /// be returned. Otherwise, an error is returned.
     fn try_parse_fd<'a>(
         value: &'a [u8],
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
     ) -> Result<(Self, &'a [u8]), ParseError>;
 }
 
 impl<T: TryParse> TryParseFd for T {
     fn try_parse_fd<'a>(
         value: &'a [u8],
-        _: &mut Vec<RawFdContainer>,
+        _: &mut Vec<OwnedFd>,
     ) -> Result<(Self, &'a [u8]), ParseError> {
         T::try_parse(value)
     }
@@ -231,7 +257,7 @@ pub trait Request {
     /// not be a reason why you need different behaviour.
     fn parse_reply<'a>(
         bytes: &'a [u8],
-        fds: &mut Vec<RawFdContainer>,
+        fds: &mut Vec<OwnedFd>,
     ) -> Result<(crate::protocol::Reply, &'a [u8]), ParseError> {
         let (reply, remaining) = Self::Reply::try_parse_fd(bytes, fds)?;
         Ok((reply.into(), remaining))
@@ -242,7 +268,7 @@ pub trait Request {
 pub type ReplyParsingFunction =
     for<'a> fn(
         &'a [u8],
-        &mut Vec<RawFdContainer>,
+        &mut Vec<OwnedFd>,
     ) -> Result<(crate::protocol::Reply, &'a [u8]), ParseError>;
 
 /// A type implementing this trait can be serialized into X11 raw bytes.
@@ -432,16 +458,81 @@ tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12);
 tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13);
 tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13 O:14);
 
+thread_local! {
+    /// The maximal length of lists in parsed data, see [`with_max_list_length`].
+    static MAX_LIST_LENGTH: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+/// Limit the number of entries of lists in data that is parsed by `f`.
+///
+/// The length of most lists is sent by the X11 server. A corrupt or malicious server could claim
+/// that a reply contains a list with billions of entries. With this limit, parsing such a list
+/// fails with [`ParseError::TooLarge`] instead.
+///
+/// The limit only applies to parsing that happens on the current thread while `f` runs. Parsing
+/// usually happens in the thread that waits for a reply or event, so the limit can be applied to
+/// some replies, e.g. from an untrusted connection:
+///
+/// ```
+/// use x11rb::errors::ParseError;
+/// use x11rb::protocol::xproto::GetPropertyReply;
+/// use x11rb::x11_utils::{with_max_list_length, TryParse};
+///
+/// // A GetProperty reply with format 8 and a value of 2^20 bytes
+/// let mut reply = vec![1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+/// reply.extend(&(1u32 << 20).to_ne_bytes());
+/// reply.extend(&[0; 12]);
+///
+/// let result = with_max_list_length(1 << 16, || GetPropertyReply::try_parse(&reply));
+/// assert_eq!(result.unwrap_err(), ParseError::TooLarge);
+/// ```
+///
+/// Independently of this limit, parsing never allocates memory for more list entries than there
+/// are bytes in the parsed data. By default, the length of lists is not limited.
+pub fn with_max_list_length<R>(max_length: usize, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous limit, even if `f` panics.
+    struct Restore(usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MAX_LIST_LENGTH.with(|max| max.set(self.0));
+        }
+    }
+
+    let _restore = Restore(MAX_LIST_LENGTH.with(|max| max.replace(max_length)));
+    f()
+}
+
+/// Get the limit for the number of entries of parsed lists, see [`with_max_list_length`].
+pub fn max_list_length() -> usize {
+    MAX_LIST_LENGTH.with(Cell::get)
+}
+
+/// Check the length of a list that is parsed from `data` and get the capacity to allocate for it.
+///
+/// Every list entry takes at least one byte, so the capacity is limited to the length of `data`.
+// Public for code generated outside of x11rb, see `x11rb-generator --out-of-tree`
+#[doc(hidden)]
+pub fn list_capacity(data: &[u8], list_length: usize) -> Result<usize, ParseError> {
+    if list_length > max_list_length() {
+        Err(ParseError::TooLarge)
+    } else {
+        Ok(list_length.min(data.len()))
+    }
+}
+
 /// Parse a list of objects from the given data.
 ///
 /// This function parses a list of objects where the length of the list was specified externally.
 /// The wire format for `list_length` instances of `T` will be read from the given data.
+///
+/// Fails with [`ParseError::TooLarge`] if `list_length` is larger than [`max_list_length`].
 pub fn parse_list<T>(data: &[u8], list_length: usize) -> Result<(Vec<T>, &[u8]), ParseError>
 where
     T: TryParse,
 {
     let mut remaining = data;
-    let mut result = Vec::with_capacity(list_length);
+    let mut result = Vec::with_capacity(list_capacity(data, list_length)?);
     for _ in 0..list_length {
         let (entry, new_remaining) = T::try_parse(remaining)?;
         result.push(entry);
@@ -451,14 +542,80 @@ where
 }
 
 /// Parse a list of `u8` from the given data.
+///
+/// Fails with [`ParseError::TooLarge`] if `list_length` is larger than [`max_list_length`].
 pub fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &[u8]), ParseError> {
-    if data.len() < list_length {
+    if list_length > max_list_length() {
+        Err(ParseError::TooLarge)
+    } else if data.len() < list_length {
         Err(ParseError::InsufficientData)
     } else {
         Ok(data.split_at(list_length))
     }
 }
 
+/// Serialize a request and append it to `bytes`.
+///
+/// `write_to` passes the bytes of the request to its argument and leaves the length field at
+/// zero. This function fills in the length field, using the BIG-REQUESTS encoding if the request
+/// is too long for the normal length field. Generated requests use this for their
+/// `serialize_into()` method.
+pub fn serialize_request_into(bytes: &mut Vec<u8>, write_to: impl FnOnce(&mut dyn FnMut(&[u8]))) {
+    let start = bytes.len();
+    write_to(&mut |data| bytes.extend_from_slice(data));
+    let length = bytes.len() - start;
+    assert_eq!(length % 4, 0);
+    match u16::try_from(length / 4) {
+        Ok(words) => bytes[start + 2..start + 4].copy_from_slice(&words.to_ne_bytes()),
+        Err(_) => {
+            // The length field stays zero and the real length follows the header
+            let words = big_request_length(length);
+            let _ = bytes.splice(start + 4..start + 4, words.to_ne_bytes());
+        }
+    }
+}
+
+/// Serialize a request into the beginning of `buffer`.
+///
+/// This is like [`serialize_request_into`], but needs the `wire_length` of the request in
+/// advance. Returns the number of bytes that were written.
+pub fn serialize_request_into_slice(
+    buffer: &mut [u8],
+    wire_length: usize,
+    write_to: impl FnOnce(&mut dyn FnMut(&[u8])),
+) -> Result<usize, BufferTooSmall> {
+    let words = u16::try_from(wire_length / 4).ok();
+    let required = if words.is_some() {
+        wire_length
+    } else {
+        wire_length + 4
+    };
+    if buffer.len() < required {
+        return Err(BufferTooSmall { required });
+    }
+    let mut written = 0;
+    write_to(&mut |data| {
+        buffer[written..written + data.len()].copy_from_slice(data);
+        written += data.len();
+    });
+    assert_eq!(written, wire_length);
+    match words {
+        Some(words) => buffer[2..4].copy_from_slice(&words.to_ne_bytes()),
+        None => {
+            buffer.copy_within(4..wire_length, 8);
+            buffer[4..8].copy_from_slice(&big_request_length(wire_length).to_ne_bytes());
+        }
+    }
+    Ok(required)
+}
+
+// The length field of a BIG-REQUESTS request, which counts its own four bytes
+fn big_request_length(length: usize) -> u32 {
+    (length / 4 + 1)
+        .try_into()
+        .expect("X11 request larger than 2^34 bytes?!?")
+}
+
 impl<T: Serialize> Serialize for [T] {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
@@ -474,7 +631,10 @@ impl<T: Serialize> Serialize for [T] {
 }
 
 // This macro is used by the generated code to implement `std::ops::BitOr` and
-// `std::ops::BitOrAssign`.
+// `std::ops::BitOrAssign`. It is exported for code generated outside of x11rb, see
+// `x11rb-generator --out-of-tree`.
+#[doc(hidden)]
+#[macro_export]
 macro_rules! bitmask_binop {
     ($t:ty, $u:ty) => {
         impl std::ops::BitOr for $t {
@@ -511,7 +671,9 @@ macro_rules! bitmask_binop {
 /// Wrapper around TryInto that produces a ParseError.
 ///
 /// This trait shortens `x.try_into().or(Err(ParseError::ConversionFailed))` to `x.try_to_usize()`.
-pub(crate) trait TryIntoUSize: TryInto<usize> {
+// Public for code generated outside of x11rb, see `x11rb-generator --out-of-tree`
+#[doc(hidden)]
+pub trait TryIntoUSize: TryInto<usize> {
     /// Attempt the conversion
     fn try_to_usize(self) -> Result<usize, ParseError> {
         self.try_into().or(Err(ParseError::ConversionFailed))
@@ -599,7 +761,7 @@ macro_rules! atom_manager {
         // Cookie version
         #[allow(non_snake_case)]
         #[derive(Debug)]
-        $vis struct $cookie_name<'a, C: $crate::protocol::xproto::ConnectionExt> {
+        $vis struct $cookie_name<'a, C: $crate::protocol::xproto::ConnectionExt + ?Sized> {
             phantom: std::marker::PhantomData<&'a C>,
             $(
                 $field_name: $crate::cookie::Cookie<'a, C, $crate::protocol::xproto::InternAtomReply>,
@@ -616,7 +778,7 @@ macro_rules! atom_manager {
         }
 
         impl $struct_name {
-            $vis fn new<C: $crate::protocol::xproto::ConnectionExt>(
+            $vis fn new<C: $crate::protocol::xproto::ConnectionExt + ?Sized>(
                 _conn: &C,
             ) -> ::std::result::Result<$cookie_name<'_, C>, $crate::errors::ConnectionError> {
                 Ok($cookie_name {
@@ -631,7 +793,7 @@ macro_rules! atom_manager {
             }
         }
 
-        impl<'a, C: $crate::protocol::xproto::ConnectionExt> $cookie_name<'a, C> {
+        impl<'a, C: $crate::protocol::xproto::ConnectionExt + ?Sized> $cookie_name<'a, C> {
             $vis fn reply(self) -> ::std::result::Result<$struct_name, $crate::errors::ReplyError> {
                 Ok($struct_name {
                     $(
@@ -7,7 +7,7 @@ use std::ffi::CStr;
 use std::io::{Error as IOError, ErrorKind, IoSlice};
 use std::os::raw::c_int;
 #[cfg(unix)]
-use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 use std::ptr::{null, null_mut};
 use std::sync::{
     atomic::{AtomicU64, Ordering},
@@ -17,14 +17,14 @@ use std::sync::{
 use libc::c_void;
 
 use crate::connection::{
-    compute_length_field, Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
-    SequenceNumber,
+    compute_length_field, Connection, DiscardMode, FlushMode, ReplyOrError, RequestConnection,
+    RequestKind, SequenceNumber,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
-use crate::extension_manager::ExtensionManager;
+use crate::extension_manager::{ExtensionManager, ServerIdentity};
 use crate::protocol::xproto::Setup;
-use crate::utils::{CSlice, RawFdContainer};
+use crate::utils::{CSlice, OwnedFd};
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 
 mod pending_errors;
@@ -50,6 +50,8 @@ pub struct XCBConnection {
     ext_mgr: Mutex<ExtensionManager>,
     errors: pending_errors::PendingErrors,
     maximum_sequence_received: AtomicU64,
+    last_sequence_sent: AtomicU64,
+    flush_mode: FlushMode,
 }
 
 impl XCBConnection {
@@ -114,6 +116,8 @@ impl XCBConnection {
                     ext_mgr: Default::default(),
                     errors: Default::default(),
                     maximum_sequence_received: AtomicU64::new(0),
+                    last_sequence_sent: AtomicU64::new(0),
+                    flush_mode: FlushMode::AutoFlush,
                 };
                 Ok((conn, screen as usize))
             }
@@ -141,9 +145,36 @@ impl XCBConnection {
             ext_mgr: Default::default(),
             errors: Default::default(),
             maximum_sequence_received: AtomicU64::new(0),
+            last_sequence_sent: AtomicU64::new(0),
+            flush_mode: FlushMode::AutoFlush,
         })
     }
 
+    /// Set whether pending requests are flushed before waiting for an event.
+    ///
+    /// The default is [`FlushMode::AutoFlush`]. libxcb itself does not flush in
+    /// `xcb_wait_for_event()`, so with [`FlushMode::NoAutoFlush`], this connection behaves like
+    /// plain libxcb. Waiting for a reply always flushes, independent of this setting.
+    pub fn set_flush_mode(&mut self, mode: FlushMode) {
+        self.flush_mode = mode;
+    }
+
+    /// Get the current [`FlushMode`] of this connection.
+    pub fn flush_mode(&self) -> FlushMode {
+        self.flush_mode
+    }
+
+    /// Share extension information with other connections of this process to the same server.
+    ///
+    /// `display` is the display name that was used for connecting, e.g. the value of `$DISPLAY`.
+    /// Extensions that another sharing connection already queried are not queried again. This
+    /// should be done right after connecting. See
+    /// [`ServerIdentity`](crate::extension_manager::ServerIdentity) for the details.
+    pub fn share_extension_information(&mut self, display: &str) {
+        let identity = ServerIdentity::new(display, &self.setup);
+        self.ext_mgr.get_mut().unwrap().share_with(identity);
+    }
+
     unsafe fn parse_setup(setup: *const raw_ffi::xcb_setup_t) -> Result<Setup, ParseError> {
         use std::slice::from_raw_parts;
 
@@ -170,7 +201,7 @@ impl XCBConnection {
     fn send_request(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
         has_reply: bool,
         reply_has_fds: bool,
     ) -> Result<SequenceNumber, ConnectionError> {
@@ -220,7 +251,7 @@ impl XCBConnection {
             #[cfg(unix)]
             {
                 // Convert the FDs into an array of ints. libxcb will close the FDs.
-                let mut fds: Vec<_> = fds.into_iter().map(RawFdContainer::into_raw_fd).collect();
+                let mut fds: Vec<_> = fds.into_iter().map(OwnedFd::into_raw_fd).collect();
                 let num_fds = fds.len().try_into().unwrap();
                 let fds_ptr = fds.as_mut_ptr();
                 unsafe {
@@ -236,16 +267,56 @@ impl XCBConnection {
             }
             #[cfg(not(unix))]
             {
-                unreachable!("it is not possible to create a `RawFdContainer` on non-unix");
+                unreachable!("it is not possible to create an `OwnedFd` on non-unix");
             }
         };
         if seqno == 0 {
             unsafe { Err(Self::connection_error_from_connection(self.conn.as_ptr())) }
         } else {
+            atomic_u64_max(&self.last_sequence_sent, seqno);
             Ok(seqno)
         }
     }
 
+    /// Get the sequence number of the last request that was sent via this connection.
+    ///
+    /// Requests that other libraries send directly via the `xcb_connection_t` from
+    /// [`XCBConnection::get_raw_xcb_connection`] are not counted.
+    pub fn last_sequence_sent(&self) -> SequenceNumber {
+        self.last_sequence_sent.load(Ordering::Relaxed)
+    }
+
+    /// Check whether the X11 server is known to have processed the request with the given
+    /// sequence number.
+    ///
+    /// This is the case once a reply or an error for this request or a later one was received.
+    /// The server processes requests in order, so all earlier requests were processed as well.
+    pub fn is_processed(&self, sequence: SequenceNumber) -> bool {
+        self.maximum_sequence_received.load(Ordering::Relaxed) >= sequence
+    }
+
+    /// Wait until the X11 server processed the request with the given sequence number.
+    ///
+    /// If [`XCBConnection::is_processed`] says so already, this returns immediately. Otherwise,
+    /// this sends a `GetInputFocus` request, flushes, and waits for its reply.
+    pub fn sync_to(&self, sequence: SequenceNumber) -> Result<(), ReplyError> {
+        if self.is_processed(sequence) {
+            return Ok(());
+        }
+        let _ = crate::protocol::xproto::get_input_focus(self)?.reply()?;
+        Ok(())
+    }
+
+    /// Wait until the X11 server processed all requests that were sent so far.
+    ///
+    /// Unlike [`ConnectionExt::sync`](crate::wrapper::ConnectionExt::sync), which always needs a
+    /// round trip, this returns immediately if no requests were sent since the last reply was
+    /// received, e.g. right after waiting for a reply or after another `sync()`. Use
+    /// [`Connection::flush`] to only send pending requests without waiting.
+    pub fn sync(&self) -> Result<(), ReplyError> {
+        self.sync_to(self.last_sequence_sent())
+    }
+
     /// Check if the underlying XCB connection is in an error state.
     pub fn has_error(&self) -> Option<ConnectionError> {
         unsafe {
@@ -353,7 +424,7 @@ impl RequestConnection for XCBConnection {
     fn send_request_with_reply<R>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<Cookie<'_, Self, R>, ConnectionError>
     where
         R: TryParse,
@@ -367,7 +438,7 @@ impl RequestConnection for XCBConnection {
     fn send_request_with_reply_with_fds<R>(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
     where
         R: TryParseFd,
@@ -381,7 +452,7 @@ impl RequestConnection for XCBConnection {
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice<'_>],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
         Ok(VoidCookie::new(
             self,
@@ -437,6 +508,23 @@ impl RequestConnection for XCBConnection {
         }
     }
 
+    fn poll_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<CSlice>>, ConnectionError> {
+        match self.poll_for_reply(sequence) {
+            Err(()) => Ok(None),
+            // libxcb reports neither a reply nor an error if the connection failed or if the
+            // reply was already taken
+            Ok(None) => match unsafe { raw_ffi::xcb_connection_has_error(self.conn.as_ptr()) } {
+                0 => Err(ConnectionError::UnknownError),
+                error => Err(Self::connection_error_from_c_error(error)),
+            },
+            Ok(Some(buffer)) if buffer[0] == 0 => Ok(Some(ReplyOrError::Error(buffer))),
+            Ok(Some(buffer)) => Ok(Some(ReplyOrError::Reply(buffer))),
+        }
+    }
+
     fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<CSlice>, ConnectionError> {
         match self.wait_for_reply_or_raw_error(sequence)? {
             ReplyOrError::Reply(reply) => Ok(Some(reply)),
@@ -466,7 +554,11 @@ impl RequestConnection for XCBConnection {
 
         // The number of FDs is in the second byte (= buffer[1]) in all replies.
         let fd_slice = unsafe { std::slice::from_raw_parts(fd_ptr, usize::from(buffer[1])) };
-        let fd_vec = fd_slice.iter().map(|&fd| RawFdContainer::new(fd)).collect();
+        // libxcb hands the ownership of these FDs to us
+        let fd_vec = fd_slice
+            .iter()
+            .map(|&fd| unsafe { OwnedFd::from_raw_fd(fd) })
+            .collect();
 
         Ok(ReplyOrError::Reply((buffer, fd_vec)))
     }
@@ -518,6 +610,9 @@ impl Connection for XCBConnection {
         if let Some(error) = self.errors.get(self) {
             return Ok((error.1, error.0));
         }
+        if self.flush_mode == FlushMode::AutoFlush {
+            self.flush()?;
+        }
         unsafe {
             let event = raw_ffi::xcb_wait_for_event(self.conn.as_ptr());
             if event.is_null() {
@@ -653,6 +748,16 @@ mod test {
         assert_eq!(screen, 0);
     }
 
+    #[test]
+    fn sync_without_requests() {
+        // The mock panics on any request, so this checks that nothing is sent
+        let str = CString::new("display name").unwrap();
+        let (conn, _) = XCBConnection::connect(Some(&str)).expect("Failed to 'connect'");
+        assert_eq!(conn.last_sequence_sent(), 0);
+        assert!(conn.is_processed(0));
+        conn.sync().unwrap();
+    }
+
     #[test]
     fn u64_max() {
         use std::sync::atomic::{AtomicU64, Ordering};
@@ -52,15 +52,18 @@ impl PendingErrors {
                 Ok(reply) => reply,
             };
 
-            let seqno2 = inner.in_flight.pop();
-            assert_eq!(Some(Reverse(seqno)), seqno2);
+            match result {
+                // It's a reply, just ignore it. Some requests like ListFontsWithInfo cause more
+                // than one reply, so keep polling until libxcb reports that none are left.
+                Some(reply) if reply[0] != 0 => {}
+                result => {
+                    let seqno2 = inner.in_flight.pop();
+                    assert_eq!(Some(Reverse(seqno)), seqno2);
 
-            if let Some(result) = result {
-                // Is this an error?
-                if result[0] == 0 {
-                    return Some((seqno, result));
-                } else {
-                    // It's a reply, just ignore it
+                    // Is this an error?
+                    if let Some(error) = result {
+                        return Some((seqno, error));
+                    }
                 }
             }
         }
@@ -0,0 +1,454 @@
+//! The plug side of the XEmbed protocol.
+//!
+//! XEmbed embeds a window of one application, the "plug", into a window of another application,
+//! the "socket" or embedder. This is how e.g. tray icons and some browser plugins work. The
+//! embedder reparents the plug into its socket window, or the plug reparents itself there, and
+//! afterwards both sides exchange `_XEMBED` client messages about activation and keyboard focus.
+//!
+//! [`Plug`] implements the plug side. The embedder forwards key events to the plug while it has
+//! the XEmbed focus, so the plug does not get the X11 input focus itself:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::xembed::{Plug, PlugEvent};
+//! use x11rb::CURRENT_TIME;
+//!
+//! fn run(conn: &impl Connection, window: Window, socket: Window) -> Result<(), ReplyError> {
+//!     // `Plug::event_mask()` was selected on `window`
+//!     let mut plug = Plug::new(conn, window)?;
+//!     plug.embed_into(conn, socket)?;
+//!     conn.flush()?;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         match plug.handle_event(&event) {
+//!             Some(PlugEvent::Embedded { embedder, .. }) => println!("Embedded into {}", embedder),
+//!             Some(PlugEvent::FocusIn(_)) => println!("Key events will arrive now"),
+//!             Some(PlugEvent::Unembedded) => return Ok(()),
+//!             _ => {}
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! See the [XEmbed specification](https://specifications.freedesktop.org/xembed-spec/) for
+//! details.
+
+use crate::connection::RequestConnection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, EventMask, PropMode, Timestamp, Window};
+use crate::protocol::Event;
+use crate::send_event::{self, Delivery};
+use crate::wrapper::ConnectionExt as _;
+
+atom_manager! {
+    XEmbedAtoms: XEmbedAtomsCookie {
+        _XEMBED,
+        _XEMBED_INFO,
+    }
+}
+
+/// The version of the XEmbed protocol that is implemented here.
+pub const XEMBED_VERSION: u32 = 0;
+
+/// The flag in `_XEMBED_INFO` that asks the embedder to map the plug.
+const XEMBED_MAPPED: u32 = 1 << 0;
+
+// The messages of the XEmbed protocol
+const XEMBED_EMBEDDED_NOTIFY: u32 = 0;
+const XEMBED_WINDOW_ACTIVATE: u32 = 1;
+const XEMBED_WINDOW_DEACTIVATE: u32 = 2;
+const XEMBED_REQUEST_FOCUS: u32 = 3;
+const XEMBED_FOCUS_IN: u32 = 4;
+const XEMBED_FOCUS_OUT: u32 = 5;
+const XEMBED_FOCUS_NEXT: u32 = 6;
+const XEMBED_FOCUS_PREV: u32 = 7;
+const XEMBED_MODALITY_ON: u32 = 10;
+const XEMBED_MODALITY_OFF: u32 = 11;
+
+/// Where the focus should go inside the plug when it gets the XEmbed focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDetail {
+    /// Keep the focus where it was.
+    Current,
+    /// Focus the first widget, e.g. because the user tabbed into the plug.
+    First,
+    /// Focus the last widget, e.g. because the user tabbed backwards into the plug.
+    Last,
+}
+
+/// A change of the XEmbed state of a [`Plug`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlugEvent {
+    /// The plug was embedded.
+    Embedded {
+        /// The window that the plug is embedded into.
+        embedder: Window,
+        /// The XEmbed version that both sides support.
+        version: u32,
+    },
+    /// The plug was taken out of its embedder.
+    Unembedded,
+    /// The toplevel window of the embedder got activated.
+    Activated,
+    /// The toplevel window of the embedder got deactivated.
+    Deactivated,
+    /// The plug got the XEmbed focus.
+    FocusIn(FocusDetail),
+    /// The plug lost the XEmbed focus.
+    FocusOut,
+    /// A modal dialog of the embedder was shown, so the plug should not react to input.
+    ModalityOn,
+    /// The modal dialog of the embedder was closed.
+    ModalityOff,
+}
+
+/// The plug side of an XEmbed connection.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct Plug {
+    window: Window,
+    atoms: XEmbedAtoms,
+    embedder: Option<Window>,
+    version: u32,
+    active: bool,
+    focused: bool,
+    modal: bool,
+}
+
+impl Plug {
+    /// Make a window usable as a plug.
+    ///
+    /// This sets the `_XEMBED_INFO` property of the window, which asks the embedder to map the
+    /// window once it is embedded.
+    pub fn new<C: RequestConnection + ?Sized>(
+        conn: &C,
+        window: Window,
+    ) -> Result<Self, ReplyError> {
+        let plug = Self {
+            window,
+            atoms: XEmbedAtoms::new(conn)?.reply()?,
+            embedder: None,
+            version: XEMBED_VERSION,
+            active: false,
+            focused: false,
+            modal: false,
+        };
+        let _ = plug.set_mapped(conn, true)?;
+        Ok(plug)
+    }
+
+    /// Get the events that have to be selected on the plug window.
+    ///
+    /// `StructureNotify` reports when the plug is reparented into or out of a socket.
+    pub fn event_mask() -> EventMask {
+        EventMask::STRUCTURE_NOTIFY
+    }
+
+    /// Get the plug window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the window that the plug is embedded into.
+    pub fn embedder(&self) -> Option<Window> {
+        self.embedder
+    }
+
+    /// Check whether the plug is embedded.
+    pub fn is_embedded(&self) -> bool {
+        self.embedder.is_some()
+    }
+
+    /// Get the XEmbed version that the embedder announced.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Check whether the toplevel window of the embedder is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Check whether the plug has the XEmbed focus, i.e. receives the embedder's key events.
+    pub fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    /// Check whether a modal dialog of the embedder blocks input.
+    pub fn is_modal(&self) -> bool {
+        self.modal
+    }
+
+    /// Reparent the plug window into a socket window.
+    ///
+    /// The embedder notices this and answers with an `XEMBED_EMBEDDED_NOTIFY` message, see
+    /// [`PlugEvent::Embedded`]. The plug window should be unmapped, since the embedder maps it.
+    pub fn embed_into<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        socket: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        xproto::reparent_window(conn, self.window, socket, 0, 0)
+    }
+
+    /// Ask the embedder to map or unmap the plug.
+    pub fn set_mapped<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        mapped: bool,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let flags = if mapped { XEMBED_MAPPED } else { 0 };
+        conn.change_property32(
+            PropMode::REPLACE,
+            self.window,
+            self.atoms._XEMBED_INFO,
+            self.atoms._XEMBED_INFO,
+            &[XEMBED_VERSION, flags],
+        )
+    }
+
+    /// Ask the embedder for the XEmbed focus, e.g. because the user clicked into the plug.
+    ///
+    /// Nothing is sent if the plug is not embedded.
+    pub fn request_focus<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        self.send(conn, time, XEMBED_REQUEST_FOCUS)
+    }
+
+    /// Tell the embedder that the focus should move to the next widget after the plug.
+    ///
+    /// This is sent when the user tabs out of the last widget of the plug.
+    pub fn focus_next<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        self.send(conn, time, XEMBED_FOCUS_NEXT)
+    }
+
+    /// Tell the embedder that the focus should move to the widget before the plug.
+    ///
+    /// This is sent when the user tabs backwards out of the first widget of the plug.
+    pub fn focus_prev<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        self.send(conn, time, XEMBED_FOCUS_PREV)
+    }
+
+    fn send<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        time: Timestamp,
+        message: u32,
+    ) -> Result<(), ConnectionError> {
+        if let Some(embedder) = self.embedder {
+            let data = [time, message, 0, 0, 0];
+            let event = send_event::client_message32(embedder, self.atoms._XEMBED, data);
+            let _ = send_event::send(conn, embedder, Delivery::Owner, event)?;
+        }
+        Ok(())
+    }
+
+    /// Handle an event and report how the XEmbed state changed.
+    pub fn handle_event(&mut self, event: &Event) -> Option<PlugEvent> {
+        match event {
+            Event::ClientMessage(event)
+                if event.window == self.window
+                    && event.type_ == self.atoms._XEMBED
+                    && event.format == 32 =>
+            {
+                let [_time, message, detail, data1, data2] = event.data.as_data32();
+                self.handle_message(message, detail, data1, data2)
+            }
+            Event::ReparentNotify(event) if event.window == self.window => {
+                if Some(event.parent) == self.embedder {
+                    None
+                } else {
+                    self.unembedded()
+                }
+            }
+            Event::DestroyNotify(event) if Some(event.window) == self.embedder => self.unembedded(),
+            _ => None,
+        }
+    }
+
+    fn handle_message(
+        &mut self,
+        message: u32,
+        detail: u32,
+        data1: u32,
+        data2: u32,
+    ) -> Option<PlugEvent> {
+        match message {
+            XEMBED_EMBEDDED_NOTIFY => {
+                self.embedder = Some(data1);
+                // The protocol version is the lower one of both sides
+                self.version = std::cmp::min(data2, XEMBED_VERSION);
+                Some(PlugEvent::Embedded {
+                    embedder: data1,
+                    version: self.version,
+                })
+            }
+            _ if self.embedder.is_none() => None,
+            XEMBED_WINDOW_ACTIVATE => {
+                self.active = true;
+                Some(PlugEvent::Activated)
+            }
+            XEMBED_WINDOW_DEACTIVATE => {
+                self.active = false;
+                Some(PlugEvent::Deactivated)
+            }
+            XEMBED_FOCUS_IN => {
+                self.focused = true;
+                let detail = match detail {
+                    1 => FocusDetail::First,
+                    2 => FocusDetail::Last,
+                    _ => FocusDetail::Current,
+                };
+                Some(PlugEvent::FocusIn(detail))
+            }
+            XEMBED_FOCUS_OUT => {
+                self.focused = false;
+                Some(PlugEvent::FocusOut)
+            }
+            XEMBED_MODALITY_ON => {
+                self.modal = true;
+                Some(PlugEvent::ModalityOn)
+            }
+            XEMBED_MODALITY_OFF => {
+                self.modal = false;
+                Some(PlugEvent::ModalityOff)
+            }
+            _ => None,
+        }
+    }
+
+    fn unembedded(&mut self) -> Option<PlugEvent> {
+        let _ = self.embedder.take()?;
+        self.active = false;
+        self.focused = false;
+        self.modal = false;
+        Some(PlugEvent::Unembedded)
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::{FocusDetail, Plug, PlugEvent, XEmbedAtoms, XEMBED_VERSION};
+    use crate::protocol::xproto::{self, ReparentNotifyEvent};
+    use crate::protocol::Event;
+    use crate::send_event::client_message32;
+    use crate::testing::FakeConnection;
+
+    const XEMBED: u32 = 300;
+    const PLUG: u32 = 10;
+    const SOCKET: u32 = 20;
+
+    fn plug() -> Plug {
+        Plug {
+            window: PLUG,
+            atoms: XEmbedAtoms {
+                _XEMBED: XEMBED,
+                _XEMBED_INFO: 301,
+            },
+            embedder: None,
+            version: XEMBED_VERSION,
+            active: false,
+            focused: false,
+            modal: false,
+        }
+    }
+
+    fn message(message: u32, detail: u32, data1: u32, data2: u32) -> Event {
+        Event::ClientMessage(client_message32(
+            PLUG,
+            XEMBED,
+            [0, message, detail, data1, data2],
+        ))
+    }
+
+    fn reparent(parent: u32) -> Event {
+        Event::ReparentNotify(ReparentNotifyEvent {
+            response_type: xproto::REPARENT_NOTIFY_EVENT,
+            sequence: 0,
+            event: PLUG,
+            window: PLUG,
+            parent,
+            x: 0,
+            y: 0,
+            override_redirect: false,
+        })
+    }
+
+    #[test]
+    fn handshake() {
+        let mut plug = plug();
+        // Messages before the embedding are ignored
+        assert_eq!(plug.handle_event(&message(4, 1, 0, 0)), None);
+        assert_eq!(plug.handle_event(&reparent(SOCKET)), None);
+        assert_eq!(
+            plug.handle_event(&message(0, 0, SOCKET, 1)),
+            Some(PlugEvent::Embedded {
+                embedder: SOCKET,
+                version: 0
+            })
+        );
+        assert_eq!(plug.embedder(), Some(SOCKET));
+        assert_eq!(
+            plug.handle_event(&message(1, 0, 0, 0)),
+            Some(PlugEvent::Activated)
+        );
+        assert_eq!(
+            plug.handle_event(&message(4, 2, 0, 0)),
+            Some(PlugEvent::FocusIn(FocusDetail::Last))
+        );
+        assert!(plug.is_active() && plug.has_focus());
+        assert_eq!(
+            plug.handle_event(&message(10, 0, 0, 0)),
+            Some(PlugEvent::ModalityOn)
+        );
+        assert!(plug.is_modal());
+        assert_eq!(
+            plug.handle_event(&message(5, 0, 0, 0)),
+            Some(PlugEvent::FocusOut)
+        );
+        assert!(!plug.has_focus());
+
+        // Moving the plug somewhere else ends the embedding
+        assert_eq!(plug.handle_event(&reparent(1)), Some(PlugEvent::Unembedded));
+        assert!(!plug.is_embedded() && !plug.is_active() && !plug.is_modal());
+        assert_eq!(plug.handle_event(&reparent(2)), None);
+    }
+
+    #[test]
+    fn focus_requests() {
+        let conn = FakeConnection::default();
+        let mut plug = plug();
+        plug.request_focus(&conn, 5).unwrap();
+        assert!(conn.take_sent_requests().is_empty());
+
+        let _ = plug.handle_event(&message(0, 0, SOCKET, 0));
+        plug.request_focus(&conn, 5).unwrap();
+        plug.focus_next(&conn, 6).unwrap();
+        let requests = conn.take_sent_requests();
+        assert_eq!(requests.len(), 2);
+        for (request, (time, message)) in requests.iter().zip([(5u32, 3u32), (6, 6)]) {
+            let bytes = &request.bytes;
+            assert_eq!(bytes[0], xproto::SEND_EVENT_REQUEST);
+            assert_eq!(bytes[4..8], SOCKET.to_ne_bytes());
+            // The client message starts at byte 12, its data at byte 24
+            assert_eq!(bytes[12], xproto::CLIENT_MESSAGE_EVENT);
+            assert_eq!(bytes[24..28], time.to_ne_bytes());
+            assert_eq!(bytes[28..32], message.to_ne_bytes());
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Decoding of `COMPOUND_TEXT`.
+//!
+//! Compound text is an ISO 2022 based encoding that switches between character sets with
+//! escape sequences. Input method servers use it for committed and preedit text unless UTF-8
+//! was negotiated. This decoder understands ASCII, the right half of ISO 8859-1 and UTF-8
+//! segments, which is what current input method servers produce. Characters from other
+//! character sets are replaced with U+FFFD.
+
+const ESC: u8 = 0x1b;
+const CSI: u8 = 0x9b;
+
+/// The character set that is used for bytes with the high bit set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RightHalf {
+    Latin1,
+    /// A character set that is not supported, with the number of bytes per character.
+    Unsupported(usize),
+}
+
+/// Decode compound text into a string.
+pub(crate) fn decode(mut data: &[u8]) -> String {
+    let mut result = String::new();
+    let mut left_supported = true;
+    let mut right = RightHalf::Latin1;
+    while let Some((&byte, rest)) = data.split_first() {
+        data = rest;
+        match byte {
+            ESC => {
+                // Escape sequences are ESC, intermediate bytes (0x20-0x2f), and a final byte
+                let len = data
+                    .iter()
+                    .position(|byte| !(0x20..0x30).contains(byte))
+                    .map_or(data.len(), |pos| pos + 1);
+                let (sequence, rest) = data.split_at(len);
+                data = rest;
+                match sequence {
+                    b"%G" => {
+                        let end = find_subslice(data, b"\x1b%@").unwrap_or(data.len());
+                        result.push_str(&String::from_utf8_lossy(&data[..end]));
+                        data = &data[(end + 3).min(data.len())..];
+                    }
+                    b"(B" | b"(J" => left_supported = true,
+                    b"-A" => right = RightHalf::Latin1,
+                    [b'(', ..] | [b'$', b'(', ..] | [b'$', b'B'] | [b'$', b'A'] => {
+                        left_supported = false
+                    }
+                    [b'-', ..] => right = RightHalf::Unsupported(1),
+                    [b'$', b')', ..] => right = RightHalf::Unsupported(2),
+                    _ => {}
+                }
+            }
+            CSI => {
+                // Control sequences for the text direction have parameters and a final byte
+                let len = data
+                    .iter()
+                    .position(|byte| (0x40..0x7f).contains(byte))
+                    .map_or(data.len(), |pos| pos + 1);
+                data = &data[len..];
+            }
+            b'\t' | b'\n' => result.push(char::from(byte)),
+            0x20..=0x7f if left_supported => result.push(char::from(byte)),
+            0x20..=0x7f => result.push(char::REPLACEMENT_CHARACTER),
+            0xa0..=0xff => match right {
+                RightHalf::Latin1 => result.push(char::from(byte)),
+                RightHalf::Unsupported(width) => {
+                    data = &data[(width - 1).min(data.len())..];
+                    result.push(char::REPLACEMENT_CHARACTER);
+                }
+            },
+            _ => {}
+        }
+    }
+    result
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::decode;
+
+    #[test]
+    fn ascii_and_latin1() {
+        assert_eq!(decode(b"abc"), "abc");
+        assert_eq!(decode(b"gr\xfc\xdfe\n"), "grüße\n");
+        assert_eq!(decode(b"\x1b-A\xe9"), "é");
+    }
+
+    #[test]
+    fn utf8_segment() {
+        assert_eq!(
+            decode("a\x1b%G日本\x1b%@b".as_bytes()),
+            "a日本b".to_string()
+        );
+        assert_eq!(decode("\x1b%Gö".as_bytes()), "ö");
+    }
+
+    #[test]
+    fn unsupported_charsets() {
+        // Two characters from JIS X 0208 in GR, then back to Latin-1
+        assert_eq!(
+            decode(b"\x1b$)B\xc6\xfc\xcb\xdc\x1b-A\xe4"),
+            "\u{fffd}\u{fffd}ä"
+        );
+        assert_eq!(decode(b"\x1b(Iab\x1b(Bc"), "\u{fffd}\u{fffd}c");
+    }
+
+    #[test]
+    fn direction_sequences_are_ignored() {
+        assert_eq!(decode(b"\x9b2]ab\x9b]"), "ab");
+    }
+}
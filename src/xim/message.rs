@@ -0,0 +1,673 @@
+//! Encoding and parsing of XIM protocol messages.
+//!
+//! Every message starts with a four byte header: the major and minor opcode, followed by the
+//! length of the rest of the message in units of four bytes. All numbers are sent in the byte
+//! order that the client announced in `XIM_CONNECT`. This implementation always uses the native
+//! byte order.
+
+use std::convert::TryFrom;
+
+use crate::errors::ParseError;
+use crate::x11_utils::{parse_list, parse_u8_list, TryParse};
+
+pub(crate) const CONNECT: u8 = 1;
+pub(crate) const CONNECT_REPLY: u8 = 2;
+pub(crate) const DISCONNECT: u8 = 3;
+pub(crate) const DISCONNECT_REPLY: u8 = 4;
+pub(crate) const ERROR: u8 = 20;
+pub(crate) const OPEN: u8 = 30;
+pub(crate) const OPEN_REPLY: u8 = 31;
+pub(crate) const CLOSE: u8 = 32;
+pub(crate) const CLOSE_REPLY: u8 = 33;
+pub(crate) const SET_EVENT_MASK: u8 = 37;
+pub(crate) const ENCODING_NEGOTIATION: u8 = 38;
+pub(crate) const ENCODING_NEGOTIATION_REPLY: u8 = 39;
+pub(crate) const CREATE_IC: u8 = 50;
+pub(crate) const CREATE_IC_REPLY: u8 = 51;
+pub(crate) const DESTROY_IC: u8 = 52;
+pub(crate) const DESTROY_IC_REPLY: u8 = 53;
+pub(crate) const SET_IC_VALUES: u8 = 54;
+pub(crate) const SET_IC_VALUES_REPLY: u8 = 55;
+pub(crate) const SET_IC_FOCUS: u8 = 58;
+pub(crate) const UNSET_IC_FOCUS: u8 = 59;
+pub(crate) const FORWARD_EVENT: u8 = 60;
+pub(crate) const SYNC: u8 = 61;
+pub(crate) const SYNC_REPLY: u8 = 62;
+pub(crate) const COMMIT: u8 = 63;
+pub(crate) const PREEDIT_START: u8 = 73;
+pub(crate) const PREEDIT_START_REPLY: u8 = 74;
+pub(crate) const PREEDIT_DRAW: u8 = 75;
+pub(crate) const PREEDIT_CARET: u8 = 76;
+pub(crate) const PREEDIT_CARET_REPLY: u8 = 77;
+pub(crate) const PREEDIT_DONE: u8 = 78;
+
+/// Flag in `XIM_FORWARD_EVENT` and `XIM_COMMIT`: The receiver has to answer with `XIM_SYNC_REPLY`.
+pub(crate) const FLAG_SYNCHRONOUS: u16 = 1;
+/// Flag in `XIM_COMMIT`: The message contains a string.
+const FLAG_LOOKUP_CHARS: u16 = 2;
+/// Flag in `XIM_COMMIT`: The message contains a keysym.
+const FLAG_LOOKUP_KEYSYM: u16 = 4;
+
+/// Flag in `XIM_PREEDIT_DRAW`: The message contains no string.
+const STATUS_NO_STRING: u32 = 1;
+
+/// The number of padding bytes that are needed after `len` bytes.
+fn pad(len: usize) -> usize {
+    (4 - len % 4) % 4
+}
+
+/// A helper for building a message.
+#[derive(Debug)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn new(major_opcode: u8) -> Self {
+        Self(vec![major_opcode, 0, 0, 0])
+    }
+
+    fn u8(mut self, value: u8) -> Self {
+        self.0.push(value);
+        self
+    }
+
+    fn u16(mut self, value: u16) -> Self {
+        self.0.extend_from_slice(&value.to_ne_bytes());
+        self
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.0.extend_from_slice(&value.to_ne_bytes());
+        self
+    }
+
+    fn bytes(mut self, value: &[u8]) -> Self {
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    /// Add padding so that the message has a length that is a multiple of four.
+    fn pad(mut self) -> Self {
+        let len = self.0.len() + pad(self.0.len());
+        self.0.resize(len, 0);
+        self
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let mut data = self.pad().0;
+        let length = u16::try_from((data.len() - 4) / 4).expect("XIM message too long");
+        data[2..4].copy_from_slice(&length.to_ne_bytes());
+        data
+    }
+}
+
+/// The byte that announces the byte order in `XIM_CONNECT`.
+fn byte_order() -> u8 {
+    if cfg!(target_endian = "little") {
+        b'l'
+    } else {
+        b'B'
+    }
+}
+
+/// `XIM_CONNECT` with protocol version 1.0 and without authentication.
+pub(crate) fn connect() -> Vec<u8> {
+    Writer::new(CONNECT)
+        .u8(byte_order())
+        .u8(0)
+        .u16(1)
+        .u16(0)
+        .u16(0)
+        .finish()
+}
+
+/// `XIM_DISCONNECT`
+pub(crate) fn disconnect() -> Vec<u8> {
+    Writer::new(DISCONNECT).finish()
+}
+
+/// `XIM_OPEN` for the given locale name.
+pub(crate) fn open(locale: &[u8]) -> Vec<u8> {
+    let len = u8::try_from(locale.len()).expect("locale name too long");
+    Writer::new(OPEN).u8(len).bytes(locale).finish()
+}
+
+/// `XIM_CLOSE`
+pub(crate) fn close(im: u16) -> Vec<u8> {
+    Writer::new(CLOSE).u16(im).u16(0).finish()
+}
+
+/// `XIM_ENCODING_NEGOTIATION` with the given encoding names in order of preference.
+pub(crate) fn encoding_negotiation(im: u16, encodings: &[&[u8]]) -> Vec<u8> {
+    let list = encodings.iter().fold(Vec::new(), |mut list, name| {
+        list.push(u8::try_from(name.len()).expect("encoding name too long"));
+        list.extend_from_slice(name);
+        list
+    });
+    let len = u16::try_from(list.len()).expect("too many encodings");
+    Writer::new(ENCODING_NEGOTIATION)
+        .u16(im)
+        .u16(len)
+        .bytes(&list)
+        .pad()
+        .u16(0)
+        .u16(0)
+        .finish()
+}
+
+/// Encode a list of attributes for `XIM_CREATE_IC` and `XIM_SET_IC_VALUES`.
+#[derive(Debug, Default)]
+pub(crate) struct AttributeList(Vec<u8>);
+
+impl AttributeList {
+    /// Add an attribute with the given value.
+    pub(crate) fn add(&mut self, id: u16, value: &[u8]) -> &mut Self {
+        let len = u16::try_from(value.len()).expect("attribute value too long");
+        self.0.extend_from_slice(&id.to_ne_bytes());
+        self.0.extend_from_slice(&len.to_ne_bytes());
+        self.0.extend_from_slice(value);
+        self.0.resize(self.0.len() + pad(value.len()), 0);
+        self
+    }
+
+    /// Add an attribute with a `CARD32` value.
+    pub(crate) fn add_u32(&mut self, id: u16, value: u32) -> &mut Self {
+        self.add(id, &value.to_ne_bytes())
+    }
+
+    /// Add a nested list of attributes, e.g. `preeditAttributes`.
+    pub(crate) fn add_nested(&mut self, id: u16, list: &AttributeList) -> &mut Self {
+        self.add(id, &list.0)
+    }
+
+    fn len(&self) -> u16 {
+        u16::try_from(self.0.len()).expect("attribute list too long")
+    }
+}
+
+/// `XIM_CREATE_IC`
+pub(crate) fn create_ic(im: u16, attributes: &AttributeList) -> Vec<u8> {
+    Writer::new(CREATE_IC)
+        .u16(im)
+        .u16(attributes.len())
+        .bytes(&attributes.0)
+        .finish()
+}
+
+/// `XIM_DESTROY_IC`
+pub(crate) fn destroy_ic(im: u16, ic: u16) -> Vec<u8> {
+    Writer::new(DESTROY_IC).u16(im).u16(ic).finish()
+}
+
+/// `XIM_SET_IC_VALUES`
+pub(crate) fn set_ic_values(im: u16, ic: u16, attributes: &AttributeList) -> Vec<u8> {
+    Writer::new(SET_IC_VALUES)
+        .u16(im)
+        .u16(ic)
+        .u16(attributes.len())
+        .u16(0)
+        .bytes(&attributes.0)
+        .finish()
+}
+
+/// `XIM_SET_IC_FOCUS` or `XIM_UNSET_IC_FOCUS`
+pub(crate) fn set_ic_focus(im: u16, ic: u16, focused: bool) -> Vec<u8> {
+    let opcode = if focused {
+        SET_IC_FOCUS
+    } else {
+        UNSET_IC_FOCUS
+    };
+    Writer::new(opcode).u16(im).u16(ic).finish()
+}
+
+/// `XIM_FORWARD_EVENT`
+pub(crate) fn forward_event(im: u16, ic: u16, flag: u16, serial: u16, event: &[u8; 32]) -> Vec<u8> {
+    Writer::new(FORWARD_EVENT)
+        .u16(im)
+        .u16(ic)
+        .u16(flag)
+        .u16(serial)
+        .bytes(event)
+        .finish()
+}
+
+/// `XIM_SYNC_REPLY`
+pub(crate) fn sync_reply(im: u16, ic: u16) -> Vec<u8> {
+    Writer::new(SYNC_REPLY).u16(im).u16(ic).finish()
+}
+
+/// `XIM_PREEDIT_START_REPLY` without a limit on the length of the preedit string.
+pub(crate) fn preedit_start_reply(im: u16, ic: u16) -> Vec<u8> {
+    Writer::new(PREEDIT_START_REPLY)
+        .u16(im)
+        .u16(ic)
+        .u32(u32::MAX)
+        .finish()
+}
+
+/// `XIM_PREEDIT_CARET_REPLY`
+pub(crate) fn preedit_caret_reply(im: u16, ic: u16, position: u32) -> Vec<u8> {
+    Writer::new(PREEDIT_CARET_REPLY)
+        .u16(im)
+        .u16(ic)
+        .u32(position)
+        .finish()
+}
+
+/// An attribute of an input method or input context as announced in `XIM_OPEN_REPLY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Attribute {
+    pub(crate) id: u16,
+    pub(crate) type_: u16,
+    pub(crate) name: Vec<u8>,
+}
+
+impl TryParse for Attribute {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u16::try_parse(value)?;
+        let (type_, remaining) = u16::try_parse(remaining)?;
+        let (len, remaining) = u16::try_parse(remaining)?;
+        let (name, remaining) = parse_u8_list(remaining, len.into())?;
+        let (_, remaining) = parse_u8_list(remaining, pad(2 + usize::from(len)))?;
+        let attribute = Attribute {
+            id,
+            type_,
+            name: name.to_vec(),
+        };
+        Ok((attribute, remaining))
+    }
+}
+
+/// Parse attributes from a list that is `len` bytes long.
+fn parse_attributes(data: &[u8], len: u16) -> Result<(Vec<Attribute>, &[u8]), ParseError> {
+    let (mut list, remaining) = parse_u8_list(data, len.into())?;
+    let mut attributes = Vec::new();
+    while !list.is_empty() {
+        let (attribute, rest) = Attribute::try_parse(list)?;
+        attributes.push(attribute);
+        list = rest;
+    }
+    Ok((attributes, remaining))
+}
+
+/// A message from the input method server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ServerMessage {
+    ConnectReply,
+    DisconnectReply,
+    Error {
+        im: Option<u16>,
+        ic: Option<u16>,
+        code: u16,
+        detail: Vec<u8>,
+    },
+    OpenReply {
+        im: u16,
+        im_attributes: Vec<Attribute>,
+        ic_attributes: Vec<Attribute>,
+    },
+    CloseReply,
+    SetEventMask {
+        ic: u16,
+        forward_mask: u32,
+        synchronous_mask: u32,
+    },
+    EncodingNegotiationReply {
+        index: i16,
+    },
+    CreateIcReply {
+        ic: u16,
+    },
+    DestroyIcReply {
+        ic: u16,
+    },
+    SetIcValuesReply,
+    ForwardEvent {
+        ic: u16,
+        flag: u16,
+        event: [u8; 32],
+    },
+    Sync {
+        ic: u16,
+    },
+    SyncReply {
+        ic: u16,
+    },
+    Commit {
+        ic: u16,
+        flag: u16,
+        keysym: Option<u32>,
+        text: Option<Vec<u8>>,
+    },
+    PreeditStart {
+        ic: u16,
+    },
+    PreeditDraw {
+        ic: u16,
+        caret: i32,
+        change_first: i32,
+        change_length: i32,
+        text: Option<Vec<u8>>,
+        feedback: Vec<u32>,
+    },
+    PreeditCaret {
+        ic: u16,
+        position: i32,
+        direction: u32,
+    },
+    PreeditDone {
+        ic: u16,
+    },
+    /// A message that this implementation does not handle.
+    Other {
+        major_opcode: u8,
+    },
+}
+
+impl ServerMessage {
+    /// Parse a message. Trailing data after the message is ignored.
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let (major_opcode, remaining) = u8::try_parse(data)?;
+        let (_minor_opcode, remaining) = u8::try_parse(remaining)?;
+        let (length, remaining) = u16::try_parse(remaining)?;
+        let (body, _) = parse_u8_list(remaining, 4 * usize::from(length))?;
+        let message = match major_opcode {
+            CONNECT_REPLY => ServerMessage::ConnectReply,
+            DISCONNECT_REPLY => ServerMessage::DisconnectReply,
+            ERROR => {
+                let ((im, ic, flag, code, len, _type), remaining) =
+                    <(u16, u16, u16, u16, u16, u16)>::try_parse(body)?;
+                let (detail, _) = parse_u8_list(remaining, len.into())?;
+                ServerMessage::Error {
+                    im: Some(im).filter(|_| flag & 1 != 0),
+                    ic: Some(ic).filter(|_| flag & 2 != 0),
+                    code,
+                    detail: detail.to_vec(),
+                }
+            }
+            OPEN_REPLY => {
+                let ((im, len), remaining) = <(u16, u16)>::try_parse(body)?;
+                let (im_attributes, remaining) = parse_attributes(remaining, len)?;
+                let ((len, _), remaining) = <(u16, u16)>::try_parse(remaining)?;
+                let (ic_attributes, _) = parse_attributes(remaining, len)?;
+                ServerMessage::OpenReply {
+                    im,
+                    im_attributes,
+                    ic_attributes,
+                }
+            }
+            CLOSE_REPLY => ServerMessage::CloseReply,
+            SET_EVENT_MASK => {
+                let ((_im, ic, forward_mask, synchronous_mask), _) =
+                    <(u16, u16, u32, u32)>::try_parse(body)?;
+                ServerMessage::SetEventMask {
+                    ic,
+                    forward_mask,
+                    synchronous_mask,
+                }
+            }
+            ENCODING_NEGOTIATION_REPLY => {
+                let ((_im, _category, index), _) = <(u16, u16, i16)>::try_parse(body)?;
+                ServerMessage::EncodingNegotiationReply { index }
+            }
+            CREATE_IC_REPLY => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::CreateIcReply { ic }
+            }
+            DESTROY_IC_REPLY => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::DestroyIcReply { ic }
+            }
+            SET_IC_VALUES_REPLY => ServerMessage::SetIcValuesReply,
+            FORWARD_EVENT => {
+                let ((_im, ic, flag, _serial), remaining) =
+                    <(u16, u16, u16, u16)>::try_parse(body)?;
+                let (event, _) = parse_u8_list(remaining, 32)?;
+                let mut data = [0; 32];
+                data.copy_from_slice(event);
+                ServerMessage::ForwardEvent {
+                    ic,
+                    flag,
+                    event: data,
+                }
+            }
+            SYNC => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::Sync { ic }
+            }
+            SYNC_REPLY => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::SyncReply { ic }
+            }
+            COMMIT => {
+                let ((_im, ic, flag), mut remaining) = <(u16, u16, u16)>::try_parse(body)?;
+                let mut keysym = None;
+                if flag & FLAG_LOOKUP_KEYSYM != 0 {
+                    let ((_, value), rest) = <(u16, u32)>::try_parse(remaining)?;
+                    keysym = Some(value);
+                    remaining = rest;
+                }
+                let mut text = None;
+                if flag & FLAG_LOOKUP_CHARS != 0 {
+                    let (len, rest) = u16::try_parse(remaining)?;
+                    let (value, _) = parse_u8_list(rest, len.into())?;
+                    text = Some(value.to_vec());
+                }
+                ServerMessage::Commit {
+                    ic,
+                    flag,
+                    keysym,
+                    text,
+                }
+            }
+            PREEDIT_START => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::PreeditStart { ic }
+            }
+            PREEDIT_DRAW => {
+                let ((_im, ic, caret, change_first, change_length, status), remaining) =
+                    <(u16, u16, i32, i32, i32, u32)>::try_parse(body)?;
+                let (len, remaining) = u16::try_parse(remaining)?;
+                let (text, remaining) = parse_u8_list(remaining, len.into())?;
+                let (_, remaining) = parse_u8_list(remaining, pad(2 + usize::from(len)))?;
+                let ((len, _), remaining) = <(u16, u16)>::try_parse(remaining)?;
+                let (feedback, _) = parse_list(remaining, usize::from(len) / 4)?;
+                ServerMessage::PreeditDraw {
+                    ic,
+                    caret,
+                    change_first,
+                    change_length,
+                    text: Some(text.to_vec()).filter(|_| status & STATUS_NO_STRING == 0),
+                    feedback,
+                }
+            }
+            PREEDIT_CARET => {
+                let ((_im, ic, position, direction), _) = <(u16, u16, i32, u32)>::try_parse(body)?;
+                ServerMessage::PreeditCaret {
+                    ic,
+                    position,
+                    direction,
+                }
+            }
+            PREEDIT_DONE => {
+                let ((_im, ic), _) = <(u16, u16)>::try_parse(body)?;
+                ServerMessage::PreeditDone { ic }
+            }
+            major_opcode => ServerMessage::Other { major_opcode },
+        };
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        connect, encoding_negotiation, open, set_ic_values, Attribute, AttributeList,
+        ServerMessage, Writer, COMMIT, OPEN_REPLY, PREEDIT_DRAW,
+    };
+
+    #[test]
+    fn encode_connect() {
+        let message = connect();
+        let mut expected = vec![1, 0];
+        expected.extend_from_slice(&2u16.to_ne_bytes());
+        expected.extend_from_slice(&[super::byte_order(), 0]);
+        for value in &[1u16, 0, 0] {
+            expected.extend_from_slice(&value.to_ne_bytes());
+        }
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn encode_open() {
+        let message = open(b"en_US");
+        let mut expected = vec![30, 0];
+        expected.extend_from_slice(&2u16.to_ne_bytes());
+        expected.extend_from_slice(b"\x05en_US\0\0");
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn encode_encoding_negotiation() {
+        let message = encoding_negotiation(3, &[b"UTF-8", b"COMPOUND_TEXT"]);
+        let mut expected = vec![38, 0];
+        expected.extend_from_slice(&7u16.to_ne_bytes());
+        expected.extend_from_slice(&3u16.to_ne_bytes());
+        expected.extend_from_slice(&20u16.to_ne_bytes());
+        expected.extend_from_slice(b"\x05UTF-8\x0dCOMPOUND_TEXT");
+        expected.extend_from_slice(&[0; 4]);
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn encode_nested_attributes() {
+        let mut nested = AttributeList::default();
+        let _ = nested.add(7, &[1, 2, 3, 4]);
+        let mut list = AttributeList::default();
+        let _ = list.add_nested(5, &nested).add(6, &[9]);
+        let message = set_ic_values(1, 2, &list);
+        let mut expected = vec![54, 0];
+        expected.extend_from_slice(&7u16.to_ne_bytes());
+        for value in &[1u16, 2, 20, 0, 5, 8, 7, 4] {
+            expected.extend_from_slice(&value.to_ne_bytes());
+        }
+        expected.extend_from_slice(&[1, 2, 3, 4]);
+        for value in &[6u16, 1] {
+            expected.extend_from_slice(&value.to_ne_bytes());
+        }
+        expected.extend_from_slice(&[9, 0, 0, 0]);
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn parse_open_reply() {
+        let message = Writer::new(OPEN_REPLY)
+            .u16(4)
+            .u16(16)
+            .u16(1)
+            .u16(3)
+            .u16(10)
+            .bytes(b"queryInput")
+            .u16(12)
+            .u16(0)
+            .u16(2)
+            .u16(5)
+            .u16(6)
+            .bytes(b"inputS")
+            .finish();
+        let expected = ServerMessage::OpenReply {
+            im: 4,
+            im_attributes: vec![Attribute {
+                id: 1,
+                type_: 3,
+                name: b"queryInput".to_vec(),
+            }],
+            ic_attributes: vec![Attribute {
+                id: 2,
+                type_: 5,
+                name: b"inputS".to_vec(),
+            }],
+        };
+        assert_eq!(ServerMessage::parse(&message), Ok(expected));
+    }
+
+    #[test]
+    fn parse_commit() {
+        let chars = Writer::new(COMMIT)
+            .u16(1)
+            .u16(2)
+            .u16(3)
+            .u16(2)
+            .bytes(b"hi")
+            .finish();
+        let expected = ServerMessage::Commit {
+            ic: 2,
+            flag: 3,
+            keysym: None,
+            text: Some(b"hi".to_vec()),
+        };
+        assert_eq!(ServerMessage::parse(&chars), Ok(expected));
+
+        let both = Writer::new(COMMIT)
+            .u16(1)
+            .u16(2)
+            .u16(6)
+            .u16(0)
+            .u32(0x61)
+            .u16(1)
+            .bytes(b"a")
+            .finish();
+        let expected = ServerMessage::Commit {
+            ic: 2,
+            flag: 6,
+            keysym: Some(0x61),
+            text: Some(b"a".to_vec()),
+        };
+        assert_eq!(ServerMessage::parse(&both), Ok(expected));
+    }
+
+    #[test]
+    fn parse_preedit_draw() {
+        let message = Writer::new(PREEDIT_DRAW)
+            .u16(1)
+            .u16(2)
+            .u32(3)
+            .u32(0)
+            .u32(0)
+            .u32(0)
+            .u16(3)
+            .bytes(b"abc")
+            .pad()
+            .u16(12)
+            .u16(0)
+            .u32(1)
+            .u32(2)
+            .u32(2)
+            .finish();
+        let expected = ServerMessage::PreeditDraw {
+            ic: 2,
+            caret: 3,
+            change_first: 0,
+            change_length: 0,
+            text: Some(b"abc".to_vec()),
+            feedback: vec![1, 2, 2],
+        };
+        assert_eq!(ServerMessage::parse(&message), Ok(expected));
+    }
+
+    #[test]
+    fn parse_truncated() {
+        let mut message = Writer::new(COMMIT).u16(1).u16(2).u16(2).u16(8).finish();
+        assert!(ServerMessage::parse(&message).is_err());
+        message.truncate(6);
+        assert!(ServerMessage::parse(&message).is_err());
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let message = Writer::new(200).u32(0).finish();
+        assert_eq!(
+            ServerMessage::parse(&message),
+            Ok(ServerMessage::Other { major_opcode: 200 })
+        );
+    }
+}
@@ -0,0 +1,975 @@
+//! A client for the X Input Method (XIM) protocol.
+//!
+//! Input methods allow typing text that cannot be entered with a single key press, e.g. Chinese,
+//! Japanese or Korean text. Most input method frameworks (IBus, Fcitx, ...) offer their service
+//! via XIM. The application forwards its key events to the input method server, which answers
+//! with composed text ("commit") and the text that is currently being composed ("preedit").
+//!
+//! [`XimClient`] implements the client side of this protocol on top of an X11 connection. Like
+//! the other helpers in this crate, it is driven by the application's event loop: All events are
+//! passed to [`XimClient::handle_event`], which returns the [`XimEvent`]s that are relevant for
+//! the application.
+//!
+//! The IM server is found via the owner of a selection like `@server=ibus`. [`find_input_method`]
+//! looks up the servers in the `XIM_SERVERS` property of the root window and picks the one that
+//! the user selected in `$XMODIFIERS`, e.g. `@im=ibus`. Connecting happens in the background:
+//! Once [`XimEvent::Opened`] arrives, input contexts can be created. An input context belongs to
+//! a window and tracks its composition state.
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::Window;
+//! use x11rb::xim::{find_input_method, locale_from_env, InputStyle, XimClient, XimError, XimEvent};
+//!
+//! fn run(conn: &impl Connection, root: Window, window: Window) -> Result<(), XimError> {
+//!     let im = match find_input_method(conn, root, None)? {
+//!         Some(im) => im,
+//!         None => return Ok(()),
+//!     };
+//!     let mut xim = XimClient::connect(conn, root, im.server, &locale_from_env())?;
+//!     let mut input_context = None;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         if let Some(ic) = input_context {
+//!             if xim.filter_key_event(conn, ic, &event)? {
+//!                 // The input method handles this key event
+//!                 continue;
+//!             }
+//!         }
+//!         match xim.handle_event(conn, &event)? {
+//!             Some(XimEvent::Opened) => {
+//!                 let style = InputStyle::PREEDIT_CALLBACKS | InputStyle::STATUS_NOTHING;
+//!                 xim.create_input_context(conn, style, window)?;
+//!             }
+//!             Some(XimEvent::InputContextCreated(ic)) => {
+//!                 xim.set_focus(conn, ic, true)?;
+//!                 input_context = Some(ic);
+//!             }
+//!             Some(XimEvent::Commit { text, .. }) => println!("Typed {}", text),
+//!             Some(XimEvent::ForwardedEvent { event, .. }) => {
+//!                 // The input method did not handle this event; process it as usual
+//!             }
+//!             _ => {}
+//!         }
+//!         conn.flush()?;
+//!     }
+//! }
+//! ```
+
+mod ctext;
+mod message;
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, CreateWindowAux, EventMask, Keysym, PropMode, Window,
+    WindowClass,
+};
+use crate::protocol::Event;
+use crate::{COPY_DEPTH_FROM_PARENT, COPY_FROM_PARENT, NONE};
+
+use message::{AttributeList, ServerMessage};
+
+atom_manager! {
+    XimAtoms: XimAtomsCookie {
+        _XIM_XCONNECT,
+        _XIM_PROTOCOL,
+        _XIM_MOREDATA,
+    }
+}
+
+/// The identifier of an input context.
+pub type InputContext = u16;
+
+/// An input method server that was found by [`find_input_method`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMethod {
+    /// The name of the input method, e.g. `ibus`.
+    pub name: String,
+    /// The owner of the input method's selection.
+    ///
+    /// This is the `server` argument for [`XimClient::connect`].
+    pub server: Window,
+}
+
+/// Find the input method server that should be used.
+///
+/// `modifiers` are the locale modifiers in the format of `$XMODIFIERS`, e.g. `@im=ibus`. If no
+/// modifiers are provided, the value from `$XMODIFIERS` is used.
+///
+/// The servers that are currently running are listed in the `XIM_SERVERS` property of `root`.
+/// If the modifiers name an input method, only this server is considered. Otherwise, the first
+/// running server is used. `None` is returned if no suitable server is running or if the
+/// modifiers contain `@im=none`.
+pub fn find_input_method<C: RequestConnection + ?Sized>(
+    conn: &C,
+    root: Window,
+    modifiers: Option<&str>,
+) -> Result<Option<InputMethod>, XimError> {
+    let env_modifiers;
+    let modifiers = match modifiers {
+        Some(modifiers) => modifiers,
+        None => {
+            env_modifiers = std::env::var("XMODIFIERS").unwrap_or_default();
+            &env_modifiers
+        }
+    };
+    let wanted = input_method_modifier(modifiers);
+    if wanted == Some("none") {
+        return Ok(None);
+    }
+
+    let servers = xproto::intern_atom(conn, true, b"XIM_SERVERS")?
+        .reply()?
+        .atom;
+    if servers == NONE {
+        return Ok(None);
+    }
+    let servers =
+        xproto::get_property(conn, false, root, servers, AtomEnum::ATOM, 0, u32::MAX)?.reply()?;
+    // Send all requests before waiting for the first reply
+    let cookies = servers
+        .value32()
+        .into_iter()
+        .flatten()
+        .map(|atom| {
+            Ok((
+                xproto::get_atom_name(conn, atom)?,
+                xproto::get_selection_owner(conn, atom)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    for (name, owner) in cookies {
+        let name = name.reply()?.name;
+        let owner = owner.reply()?.owner;
+        let name = match std::str::from_utf8(&name)
+            .ok()
+            .and_then(|name| name.strip_prefix("@server="))
+        {
+            Some(name) => name,
+            None => continue,
+        };
+        if owner == NONE || matches!(wanted, Some(wanted) if wanted != name) {
+            continue;
+        }
+        return Ok(Some(InputMethod {
+            name: name.to_string(),
+            server: owner,
+        }));
+    }
+    Ok(None)
+}
+
+/// Get the name of the input method from locale modifiers like `@im=ibus`.
+///
+/// Returns `None` if the modifiers do not select an input method.
+fn input_method_modifier(modifiers: &str) -> Option<&str> {
+    modifiers
+        .split('@')
+        .find_map(|modifier| modifier.strip_prefix("im="))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+/// Get the name of the locale for text input from the environment.
+///
+/// Like the C library, this uses the first non-empty variable of `$LC_ALL`, `$LC_CTYPE` and
+/// `$LANG`, and falls back to `C`. The result can be used as the `locale` argument for
+/// [`XimClient::connect`].
+pub fn locale_from_env() -> String {
+    crate::properties::locale_from_env()
+}
+
+/// The number of bytes that fit into a single `ClientMessage`.
+const CLIENT_MESSAGE_SIZE: usize = 20;
+
+/// The encodings that the client supports, in order of preference.
+const ENCODINGS: [&[u8]; 2] = [b"UTF-8", b"COMPOUND_TEXT"];
+
+/// How the input method presents the preedit text and its status.
+///
+/// A style consists of one `PREEDIT_*` and one `STATUS_*` constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputStyle(u32);
+
+impl InputStyle {
+    /// The application provides an area for the preedit text.
+    pub const PREEDIT_AREA: Self = Self(0x0001);
+    /// The application draws the preedit text based on [`XimEvent::PreeditDraw`].
+    pub const PREEDIT_CALLBACKS: Self = Self(0x0002);
+    /// The input method draws the preedit text at the position given with
+    /// [`XimClient::set_spot_location`].
+    pub const PREEDIT_POSITION: Self = Self(0x0004);
+    /// The input method draws the preedit text in its own window.
+    pub const PREEDIT_NOTHING: Self = Self(0x0008);
+    /// The preedit text is not shown.
+    pub const PREEDIT_NONE: Self = Self(0x0010);
+    /// The application provides an area for the status.
+    pub const STATUS_AREA: Self = Self(0x0100);
+    /// The application draws the status.
+    pub const STATUS_CALLBACKS: Self = Self(0x0200);
+    /// The input method shows the status in its own window.
+    pub const STATUS_NOTHING: Self = Self(0x0400);
+    /// The status is not shown.
+    pub const STATUS_NONE: Self = Self(0x0800);
+}
+
+impl std::ops::BitOr for InputStyle {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl From<InputStyle> for u32 {
+    fn from(style: InputStyle) -> u32 {
+        style.0
+    }
+}
+
+/// How a character of the preedit text should be highlighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Feedback(u32);
+
+impl Feedback {
+    /// Draw the character with foreground and background swapped.
+    pub const REVERSE: Self = Self(1);
+    /// Underline the character.
+    pub const UNDERLINE: Self = Self(2);
+    /// Highlight the character.
+    pub const HIGHLIGHT: Self = Self(4);
+    /// Use the primary highlighting of the application.
+    pub const PRIMARY: Self = Self(32);
+    /// Use the secondary highlighting of the application.
+    pub const SECONDARY: Self = Self(64);
+    /// Use the tertiary highlighting of the application.
+    pub const TERTIARY: Self = Self(128);
+
+    /// Check whether all bits of `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<u32> for Feedback {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Feedback> for u32 {
+    fn from(feedback: Feedback) -> u32 {
+        feedback.0
+    }
+}
+
+/// A change to the preedit text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreeditDraw {
+    /// The position of the caret in characters.
+    pub caret: i32,
+    /// The first character that changed.
+    pub change_first: i32,
+    /// The number of characters that are replaced.
+    pub change_length: i32,
+    /// The text that replaces the changed characters.
+    pub text: String,
+    /// The highlighting of each character of `text`.
+    pub feedback: Vec<Feedback>,
+}
+
+impl PreeditDraw {
+    /// Apply this change to the current preedit text.
+    pub fn apply(&self, preedit: &mut String) {
+        let mut chars = preedit.chars().collect::<Vec<_>>();
+        let first = usize::try_from(self.change_first)
+            .unwrap_or(0)
+            .min(chars.len());
+        let length = usize::try_from(self.change_length).unwrap_or(0);
+        let end = first.saturating_add(length).min(chars.len());
+        let _ = chars.splice(first..end, self.text.chars());
+        *preedit = chars.into_iter().collect();
+    }
+}
+
+/// Something that happened on the connection to the input method server.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XimEvent {
+    /// The input method is ready. Input contexts can now be created.
+    Opened,
+    /// An input context was created by [`XimClient::create_input_context`].
+    InputContextCreated(InputContext),
+    /// The input method produced text that should be inserted.
+    Commit {
+        /// The input context that the text is for.
+        input_context: InputContext,
+        /// The text, which might be empty if only a keysym was sent.
+        text: String,
+        /// A keysym that should be handled as if it was typed.
+        keysym: Option<Keysym>,
+    },
+    /// A key event that the input method did not handle.
+    ///
+    /// The application should handle this event as if it had come from the X11 server.
+    ForwardedEvent {
+        /// The input context that the event belongs to.
+        input_context: InputContext,
+        /// The event.
+        event: Event,
+    },
+    /// Composing text began and the application should show the preedit text.
+    PreeditStart(InputContext),
+    /// The preedit text changed.
+    PreeditDraw {
+        /// The input context that the preedit text belongs to.
+        input_context: InputContext,
+        /// The change.
+        draw: PreeditDraw,
+    },
+    /// The caret in the preedit text moved.
+    PreeditCaret {
+        /// The input context that the preedit text belongs to.
+        input_context: InputContext,
+        /// The new position of the caret in characters.
+        position: i32,
+        /// How the caret moved (`XIMCaretDirection`).
+        direction: u32,
+    },
+    /// Composing text ended and the preedit text should be hidden.
+    PreeditDone(InputContext),
+    /// The input method server reported an error.
+    Error {
+        /// The input context that the error is about, if any.
+        input_context: Option<InputContext>,
+        /// The error code, e.g. 1 for `BadAlloc`.
+        code: u16,
+        /// A description of the error.
+        detail: String,
+    },
+}
+
+/// An error while talking to the input method server.
+#[derive(Debug)]
+pub enum XimError {
+    /// An error on the X11 connection.
+    X11(ReplyOrIdError),
+    /// The input method server sent a message that could not be parsed.
+    InvalidMessage(ParseError),
+    /// The input method is not open yet.
+    NotOpen,
+    /// The input method does not support the attribute with the given name.
+    UnsupportedAttribute(&'static str),
+}
+
+impl std::error::Error for XimError {}
+
+impl std::fmt::Display for XimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XimError::X11(e) => write!(f, "{}", e),
+            XimError::InvalidMessage(e) => write!(f, "Invalid XIM message: {}", e),
+            XimError::NotOpen => f.write_str("The input method is not open"),
+            XimError::UnsupportedAttribute(name) => {
+                write!(
+                    f,
+                    "The input method does not support the attribute {}",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl From<ReplyOrIdError> for XimError {
+    fn from(err: ReplyOrIdError) -> Self {
+        XimError::X11(err)
+    }
+}
+
+impl From<ReplyError> for XimError {
+    fn from(err: ReplyError) -> Self {
+        XimError::X11(err.into())
+    }
+}
+
+impl From<ConnectionError> for XimError {
+    fn from(err: ConnectionError) -> Self {
+        XimError::X11(err.into())
+    }
+}
+
+impl From<ParseError> for XimError {
+    fn from(err: ParseError) -> Self {
+        XimError::InvalidMessage(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Waiting for the `_XIM_XCONNECT` answer of the server.
+    XConnect,
+    /// Waiting for `XIM_CONNECT_REPLY`.
+    Connect,
+    /// Waiting for `XIM_OPEN_REPLY`.
+    Open,
+    /// Waiting for `XIM_ENCODING_NEGOTIATION_REPLY`.
+    EncodingNegotiation,
+    /// The input method is open.
+    Ready,
+}
+
+/// The events of an input context that are forwarded to the server.
+#[derive(Debug, Clone, Copy)]
+struct ContextMasks {
+    ic: InputContext,
+    forward: u32,
+    synchronous: u32,
+}
+
+/// A connection to an input method server.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct XimClient {
+    atoms: XimAtoms,
+    /// The property that is used for sending messages that do not fit into a `ClientMessage`.
+    data_atom: Atom,
+    window: Window,
+    server: Window,
+    server_window: Window,
+    locale: Vec<u8>,
+    state: State,
+    im: u16,
+    ic_attributes: Vec<message::Attribute>,
+    utf8: bool,
+    contexts: Vec<ContextMasks>,
+    /// Data received via `_XIM_MOREDATA` for the next message.
+    partial: Vec<u8>,
+    /// Is the client waiting for `XIM_SYNC_REPLY` after a synchronous `XIM_FORWARD_EVENT`?
+    waiting_for_sync: bool,
+    /// Messages that have to wait until the synchronous `XIM_FORWARD_EVENT` is answered, and
+    /// whether they are a synchronous `XIM_FORWARD_EVENT` themselves.
+    queued: VecDeque<(Vec<u8>, bool)>,
+}
+
+impl XimClient {
+    /// Start connecting to an input method server.
+    ///
+    /// `server` is the owner of the input method's selection, e.g. `@server=ibus`, see
+    /// [`find_input_method`]. A window for the communication with the server is created as a
+    /// child of `root`. `locale` is the name of the locale that the input method should use, e.g.
+    /// `en_US.UTF-8`. Each client uses its own locale; [`locale_from_env`] gets the locale of the
+    /// process.
+    ///
+    /// The connection is set up while events are passed to [`XimClient::handle_event`]. It is
+    /// ready once [`XimEvent::Opened`] is returned.
+    pub fn connect<C: Connection + ?Sized>(
+        conn: &C,
+        root: Window,
+        server: Window,
+        locale: &str,
+    ) -> Result<Self, XimError> {
+        let atoms = XimAtoms::new(conn)?;
+        let window = conn.generate_id()?;
+        let _ = xproto::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            window,
+            root,
+            0,
+            0,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            COPY_FROM_PARENT,
+            &CreateWindowAux::new(),
+        )?;
+        let data_name = format!("_XIM_DATA_{:x}", window);
+        let data_atom = xproto::intern_atom(conn, false, data_name.as_bytes())?;
+        let atoms = atoms.reply()?;
+        let data_atom = data_atom.reply()?.atom;
+
+        let client = Self {
+            atoms,
+            data_atom,
+            window,
+            server,
+            server_window: server,
+            locale: locale.as_bytes().to_vec(),
+            state: State::XConnect,
+            im: 0,
+            ic_attributes: Vec::new(),
+            utf8: false,
+            contexts: Vec::new(),
+            partial: Vec::new(),
+            waiting_for_sync: false,
+            queued: VecDeque::new(),
+        };
+        let event = client_message(server, client.atoms._XIM_XCONNECT, [window, 0, 0, 0, 0]);
+        let _ = xproto::send_event(conn, false, server, EventMask::NO_EVENT, event)?;
+        Ok(client)
+    }
+
+    /// Get the window that the client uses for talking to the server.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the owner of the input method's selection.
+    pub fn server(&self) -> Window {
+        self.server
+    }
+
+    /// Is the input method open, so that input contexts can be created?
+    pub fn is_open(&self) -> bool {
+        self.state == State::Ready
+    }
+
+    /// Close the connection to the input method server.
+    ///
+    /// This destroys the window that was created by [`XimClient::connect`].
+    pub fn disconnect<C: RequestConnection + ?Sized>(self, conn: &C) -> Result<(), XimError> {
+        if self.state != State::XConnect {
+            if self.state == State::Ready {
+                self.send(conn, &message::close(self.im))?;
+            }
+            self.send(conn, &message::disconnect())?;
+        }
+        let _ = xproto::destroy_window(conn, self.window)?;
+        Ok(())
+    }
+
+    /// Create an input context for the given window.
+    ///
+    /// The input method must support the given style. The new input context is returned via
+    /// [`XimEvent::InputContextCreated`].
+    pub fn create_input_context<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        style: InputStyle,
+        window: Window,
+    ) -> Result<(), XimError> {
+        self.check_open()?;
+        let mut attributes = AttributeList::default();
+        let _ = attributes
+            .add_u32(self.ic_attribute("inputStyle")?, style.into())
+            .add_u32(self.ic_attribute("clientWindow")?, window)
+            .add_u32(self.ic_attribute("focusWindow")?, window);
+        self.send_request(conn, message::create_ic(self.im, &attributes), false)
+    }
+
+    /// Destroy an input context.
+    pub fn destroy_input_context<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        input_context: InputContext,
+    ) -> Result<(), XimError> {
+        self.check_open()?;
+        self.contexts.retain(|context| context.ic != input_context);
+        self.send_request(conn, message::destroy_ic(self.im, input_context), false)
+    }
+
+    /// Tell the input method whether the window of the input context has the keyboard focus.
+    pub fn set_focus<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        input_context: InputContext,
+        focused: bool,
+    ) -> Result<(), XimError> {
+        self.check_open()?;
+        let data = message::set_ic_focus(self.im, input_context, focused);
+        self.send_request(conn, data, false)
+    }
+
+    /// Set the position of the cursor for input contexts with [`InputStyle::PREEDIT_POSITION`].
+    ///
+    /// Input methods also use this position for placing their candidate window.
+    pub fn set_spot_location<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        input_context: InputContext,
+        x: i16,
+        y: i16,
+    ) -> Result<(), XimError> {
+        self.check_open()?;
+        let mut spot = [0; 4];
+        spot[..2].copy_from_slice(&x.to_ne_bytes());
+        spot[2..].copy_from_slice(&y.to_ne_bytes());
+        let mut nested = AttributeList::default();
+        let _ = nested.add(self.ic_attribute("spotLocation")?, &spot);
+        let mut attributes = AttributeList::default();
+        let _ = attributes.add_nested(self.ic_attribute("preeditAttributes")?, &nested);
+        let data = message::set_ic_values(self.im, input_context, &attributes);
+        self.send_request(conn, data, false)
+    }
+
+    /// Forward a key event to the input method, if it is interested in it.
+    ///
+    /// Returns `true` if the event was forwarded. The application must then not handle the event
+    /// itself. Instead, the input method answers with [`XimEvent::Commit`] or hands the event
+    /// back via [`XimEvent::ForwardedEvent`].
+    pub fn filter_key_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        input_context: InputContext,
+        event: &Event,
+    ) -> Result<bool, XimError> {
+        let (event, mask) = match event {
+            Event::KeyPress(event) => (event, EventMask::KEY_PRESS),
+            Event::KeyRelease(event) => (event, EventMask::KEY_RELEASE),
+            _ => return Ok(false),
+        };
+        let mask = u32::from(mask);
+        let context = match self.contexts.iter().find(|c| c.ic == input_context) {
+            Some(context) if self.is_open() && context.forward & mask != 0 => *context,
+            _ => return Ok(false),
+        };
+        let synchronous = context.synchronous & mask != 0;
+        let flag = if synchronous {
+            message::FLAG_SYNCHRONOUS
+        } else {
+            0
+        };
+        let data = message::forward_event(self.im, input_context, flag, 0, &event.into());
+        self.send_request(conn, data, synchronous)?;
+        Ok(true)
+    }
+
+    /// Handle an event.
+    ///
+    /// Events that do not belong to the connection to the input method server are ignored.
+    pub fn handle_event<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<Option<XimEvent>, XimError> {
+        let event = match event {
+            Event::ClientMessage(event) if event.window == self.window => event,
+            _ => return Ok(None),
+        };
+        if event.type_ == self.atoms._XIM_XCONNECT && self.state == State::XConnect {
+            self.server_window = event.data.as_data32()[0];
+            self.state = State::Connect;
+            self.send(conn, &message::connect())?;
+            return Ok(None);
+        }
+        if event.type_ == self.atoms._XIM_MOREDATA && event.format == 8 {
+            self.partial.extend_from_slice(&event.data.as_data8());
+            return Ok(None);
+        }
+        if event.type_ != self.atoms._XIM_PROTOCOL {
+            return Ok(None);
+        }
+        let data = if event.format == 32 {
+            let [length, property, ..] = event.data.as_data32();
+            let reply = xproto::get_property(
+                conn,
+                true,
+                self.window,
+                property,
+                AtomEnum::ANY,
+                0,
+                length.saturating_add(3) / 4,
+            )?
+            .reply()?;
+            let mut value = reply.value;
+            value.truncate(usize::try_from(length).unwrap_or(usize::MAX));
+            value
+        } else {
+            self.partial.extend_from_slice(&event.data.as_data8());
+            std::mem::take(&mut self.partial)
+        };
+        let message = ServerMessage::parse(&data)?;
+        self.handle_message(conn, message)
+    }
+
+    fn handle_message<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        message: ServerMessage,
+    ) -> Result<Option<XimEvent>, XimError> {
+        let event = match message {
+            ServerMessage::ConnectReply => {
+                self.state = State::Open;
+                self.send(conn, &message::open(&self.locale))?;
+                None
+            }
+            ServerMessage::OpenReply {
+                im, ic_attributes, ..
+            } => {
+                self.im = im;
+                self.ic_attributes = ic_attributes;
+                self.state = State::EncodingNegotiation;
+                self.send(conn, &message::encoding_negotiation(im, &ENCODINGS))?;
+                None
+            }
+            ServerMessage::EncodingNegotiationReply { index } => {
+                // The server answers with -1 if it supports none of the encodings. In that case,
+                // COMPOUND_TEXT is used.
+                self.utf8 = index == 0;
+                self.state = State::Ready;
+                Some(XimEvent::Opened)
+            }
+            ServerMessage::SetEventMask {
+                ic,
+                forward_mask,
+                synchronous_mask,
+            } => {
+                if let Some(context) = self.contexts.iter_mut().find(|c| c.ic == ic) {
+                    context.forward = forward_mask;
+                    context.synchronous = synchronous_mask;
+                }
+                None
+            }
+            ServerMessage::CreateIcReply { ic } => {
+                self.contexts.push(ContextMasks {
+                    ic,
+                    forward: EventMask::KEY_PRESS.into(),
+                    synchronous: 0,
+                });
+                Some(XimEvent::InputContextCreated(ic))
+            }
+            ServerMessage::Sync { ic } => {
+                self.send(conn, &message::sync_reply(self.im, ic))?;
+                None
+            }
+            ServerMessage::SyncReply { .. } => {
+                self.sync_done(conn)?;
+                None
+            }
+            ServerMessage::ForwardEvent { ic, flag, event } => {
+                let event = conn.parse_event(&event)?;
+                if flag & message::FLAG_SYNCHRONOUS != 0 {
+                    self.send(conn, &message::sync_reply(self.im, ic))?;
+                }
+                self.sync_done(conn)?;
+                Some(XimEvent::ForwardedEvent {
+                    input_context: ic,
+                    event,
+                })
+            }
+            ServerMessage::Commit {
+                ic,
+                flag,
+                keysym,
+                text,
+            } => {
+                if flag & message::FLAG_SYNCHRONOUS != 0 {
+                    self.send(conn, &message::sync_reply(self.im, ic))?;
+                }
+                self.sync_done(conn)?;
+                Some(XimEvent::Commit {
+                    input_context: ic,
+                    text: text.map(|text| self.decode(&text)).unwrap_or_default(),
+                    keysym,
+                })
+            }
+            ServerMessage::PreeditStart { ic } => {
+                self.send(conn, &message::preedit_start_reply(self.im, ic))?;
+                Some(XimEvent::PreeditStart(ic))
+            }
+            ServerMessage::PreeditDraw {
+                ic,
+                caret,
+                change_first,
+                change_length,
+                text,
+                feedback,
+            } => Some(XimEvent::PreeditDraw {
+                input_context: ic,
+                draw: PreeditDraw {
+                    caret,
+                    change_first,
+                    change_length,
+                    text: text.map(|text| self.decode(&text)).unwrap_or_default(),
+                    feedback: feedback.into_iter().map(Feedback::from).collect(),
+                },
+            }),
+            ServerMessage::PreeditCaret {
+                ic,
+                position,
+                direction,
+            } => {
+                let reply = message::preedit_caret_reply(self.im, ic, position as u32);
+                self.send(conn, &reply)?;
+                Some(XimEvent::PreeditCaret {
+                    input_context: ic,
+                    position,
+                    direction,
+                })
+            }
+            ServerMessage::PreeditDone { ic } => Some(XimEvent::PreeditDone(ic)),
+            ServerMessage::Error {
+                ic, code, detail, ..
+            } => Some(XimEvent::Error {
+                input_context: ic,
+                code,
+                detail: String::from_utf8_lossy(&detail).into_owned(),
+            }),
+            ServerMessage::DestroyIcReply { .. }
+            | ServerMessage::SetIcValuesReply
+            | ServerMessage::CloseReply
+            | ServerMessage::DisconnectReply
+            | ServerMessage::Other { .. } => None,
+        };
+        Ok(event)
+    }
+
+    fn check_open(&self) -> Result<(), XimError> {
+        if self.is_open() {
+            Ok(())
+        } else {
+            Err(XimError::NotOpen)
+        }
+    }
+
+    fn ic_attribute(&self, name: &'static str) -> Result<u16, XimError> {
+        self.ic_attributes
+            .iter()
+            .find(|attribute| attribute.name == name.as_bytes())
+            .map(|attribute| attribute.id)
+            .ok_or(XimError::UnsupportedAttribute(name))
+    }
+
+    fn decode(&self, text: &[u8]) -> String {
+        if self.utf8 {
+            String::from_utf8_lossy(text).into_owned()
+        } else {
+            ctext::decode(text)
+        }
+    }
+
+    /// Send a request, or queue it while a synchronous `XIM_FORWARD_EVENT` is not answered.
+    fn send_request<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        data: Vec<u8>,
+        synchronous: bool,
+    ) -> Result<(), XimError> {
+        if self.waiting_for_sync {
+            self.queued.push_back((data, synchronous));
+        } else {
+            self.send(conn, &data)?;
+            self.waiting_for_sync = synchronous;
+        }
+        Ok(())
+    }
+
+    /// Send the queued requests after a synchronous `XIM_FORWARD_EVENT` was answered.
+    fn sync_done<C: RequestConnection + ?Sized>(&mut self, conn: &C) -> Result<(), XimError> {
+        self.waiting_for_sync = false;
+        while let Some((data, synchronous)) = self.queued.pop_front() {
+            self.send(conn, &data)?;
+            if synchronous {
+                self.waiting_for_sync = true;
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Send a message to the server.
+    ///
+    /// Short messages are sent in a `ClientMessage`. Longer messages are appended to a property
+    /// of the server's window, and a `ClientMessage` tells the server about it.
+    fn send<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        data: &[u8],
+    ) -> Result<(), ConnectionError> {
+        let event = if data.len() <= CLIENT_MESSAGE_SIZE {
+            let mut bytes = [0; CLIENT_MESSAGE_SIZE];
+            bytes[..data.len()].copy_from_slice(data);
+            ClientMessageEvent {
+                response_type: xproto::CLIENT_MESSAGE_EVENT,
+                format: 8,
+                sequence: 0,
+                window: self.server_window,
+                type_: self.atoms._XIM_PROTOCOL,
+                data: bytes.into(),
+            }
+        } else {
+            let length = u32::try_from(data.len()).expect("XIM message too long");
+            let _ = xproto::change_property(
+                conn,
+                PropMode::APPEND,
+                self.server_window,
+                self.data_atom,
+                AtomEnum::STRING,
+                8,
+                length,
+                data,
+            )?;
+            client_message(
+                self.server_window,
+                self.atoms._XIM_PROTOCOL,
+                [length, self.data_atom, 0, 0, 0],
+            )
+        };
+        let _ = xproto::send_event(conn, false, self.server_window, EventMask::NO_EVENT, event)?;
+        Ok(())
+    }
+}
+
+fn client_message(window: Window, type_: Atom, data: [u32; 5]) -> ClientMessageEvent {
+    ClientMessageEvent {
+        response_type: xproto::CLIENT_MESSAGE_EVENT,
+        format: 32,
+        sequence: 0,
+        window,
+        type_,
+        data: data.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{input_method_modifier, Feedback, PreeditDraw};
+
+    #[test]
+    fn parse_input_method_modifier() {
+        assert_eq!(input_method_modifier("@im=ibus"), Some("ibus"));
+        assert_eq!(input_method_modifier("@foo=bar@im=fcitx"), Some("fcitx"));
+        assert_eq!(input_method_modifier("@im=none"), Some("none"));
+        assert_eq!(input_method_modifier("@im="), None);
+        assert_eq!(input_method_modifier(""), None);
+        assert_eq!(input_method_modifier("@foo=bar"), None);
+    }
+
+    fn draw(change_first: i32, change_length: i32, text: &str) -> PreeditDraw {
+        PreeditDraw {
+            caret: 0,
+            change_first,
+            change_length,
+            text: text.to_string(),
+            feedback: vec![Feedback::UNDERLINE; text.chars().count()],
+        }
+    }
+
+    #[test]
+    fn apply_preedit_draw() {
+        let mut preedit = String::new();
+        draw(0, 0, "にほ").apply(&mut preedit);
+        assert_eq!(preedit, "にほ");
+        draw(2, 0, "ん").apply(&mut preedit);
+        assert_eq!(preedit, "にほん");
+        draw(0, 3, "日本").apply(&mut preedit);
+        assert_eq!(preedit, "日本");
+        draw(1, 5, "").apply(&mut preedit);
+        assert_eq!(preedit, "日");
+    }
+
+    #[test]
+    fn feedback_contains() {
+        let feedback = Feedback::from(3);
+        assert!(feedback.contains(Feedback::REVERSE));
+        assert!(feedback.contains(Feedback::UNDERLINE));
+        assert!(!feedback.contains(Feedback::HIGHLIGHT));
+    }
+}
@@ -59,6 +59,7 @@ mod fake_stream {
     use std::io::{Error, ErrorKind};
     use std::sync::mpsc::{channel, Receiver, Sender};
     use std::sync::{Condvar, Mutex};
+    use std::time::Duration;
 
     use x11rb::connection::SequenceNumber;
     use x11rb::errors::ConnectError;
@@ -66,7 +67,7 @@ mod fake_stream {
         ImageOrder, Setup, CLIENT_MESSAGE_EVENT, GET_INPUT_FOCUS_REQUEST, SEND_EVENT_REQUEST,
     };
     use x11rb::rust_connection::{PollMode, RustConnection, Stream};
-    use x11rb::utils::RawFdContainer;
+    use x11rb::utils::OwnedFd;
 
     /// Create a new `RustConnection` connected to a fake stream
     pub(crate) fn connect() -> Result<RustConnection<FakeStream>, ConnectError> {
@@ -163,7 +164,7 @@ mod fake_stream {
     }
 
     impl Stream for FakeStream {
-        fn poll(&self, mode: PollMode) -> std::io::Result<()> {
+        fn poll(&self, mode: PollMode, _timeout: Option<Duration>) -> std::io::Result<()> {
             if mode.writable() {
                 Ok(())
             } else {
@@ -187,11 +188,7 @@ mod fake_stream {
             }
         }
 
-        fn read(
-            &self,
-            buf: &mut [u8],
-            _fd_storage: &mut Vec<RawFdContainer>,
-        ) -> std::io::Result<usize> {
+        fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<OwnedFd>) -> std::io::Result<usize> {
             let mut inner = self.inner.lock().unwrap();
             if inner.read.pending.is_empty() {
                 match inner.read.recv.try_recv() {
@@ -209,7 +206,7 @@ mod fake_stream {
             Ok(len)
         }
 
-        fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+        fn write(&self, buf: &[u8], fds: &mut Vec<OwnedFd>) -> std::io::Result<usize> {
             assert!(fds.is_empty());
 
             let mut inner = self.inner.lock().unwrap();
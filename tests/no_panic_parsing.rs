@@ -0,0 +1,69 @@
+// Run the fuzz targets in fuzz/ on pseudo-random data, so that panics while parsing are found
+// without a fuzzer.
+#![cfg(feature = "all-extensions")]
+
+use std::convert::TryFrom;
+
+#[path = "../fuzz/src/lib.rs"]
+mod harness;
+
+/// A xorshift random number generator, so that the test is deterministic
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // Random data that starts with the given bytes. Small lengths are likely.
+    fn data(&mut self, prefix: &[u8]) -> Vec<u8> {
+        let length = (self.next() % 64) as usize * (self.next() % 4 + 1) as usize;
+        let mut data = prefix.to_vec();
+        while data.len() < prefix.len() + length {
+            let value = self.next();
+            // Many small numbers, so that length fields describe the data that follows
+            let byte = if value % 4 == 0 {
+                value >> 8
+            } else {
+                value % 4
+            };
+            data.push(byte as u8);
+        }
+        data
+    }
+}
+
+#[test]
+fn events_and_errors() {
+    let mut random = Random(0x1234_5678_9abc_def0);
+    for code in 0..=255 {
+        for _ in 0..64 {
+            let mut data = random.data(&[code]);
+            harness::parse_event(&data);
+            data.resize(32, 0);
+            harness::parse_event(&data);
+        }
+    }
+}
+
+#[test]
+fn requests_and_replies() {
+    let mut random = Random(0x0fed_cba9_8765_4321);
+    for major_opcode in 0..=255 {
+        for minor_opcode in 0..64 {
+            // Request length, header, and then the rest of the request and the reply
+            let mut data = random.data(&[]);
+            let split = u16::try_from(data.len().min(128)).unwrap() + 4;
+            let words = u16::try_from((data.len().min(128) + 4) / 4).unwrap();
+            let mut prefix = split.to_ne_bytes().to_vec();
+            prefix.extend([major_opcode, minor_opcode]);
+            prefix.extend(words.to_ne_bytes());
+            prefix.append(&mut data);
+            harness::parse_request(&prefix);
+            harness::parse_reply(&prefix);
+        }
+    }
+}
@@ -134,9 +134,38 @@ fn parse_setup() -> Result<(), ParseError> {
     Ok(())
 }
 
-#[cfg(feature = "xinput")]
+/// Get the setup data from `get_setup_data()` with the given visual class.
+fn get_setup_data_with_visual_class(class: u8) -> Vec<u8> {
+    let mut s = get_setup_data();
+    // The class follows the visualid of the last visualtype entry
+    let offset = s.len() - 20;
+    assert_eq!(s[offset], 2);
+    s[offset] = class;
+    s
+}
+
+#[cfg(not(feature = "strict_parsing"))]
 #[test]
-fn parse_xi_get_property_reply_format_0() {
+fn parse_setup_unknown_visual_class() -> Result<(), ParseError> {
+    let setup = get_setup_data_with_visual_class(42);
+    let (setup, _) = Setup::try_parse(&*setup)?;
+    let visual = &setup.roots[0].allowed_depths[0].visuals[0];
+    assert_eq!(VisualClass::from(42u8), visual.class);
+    Ok(())
+}
+
+#[cfg(feature = "strict_parsing")]
+#[test]
+fn parse_setup_unknown_visual_class() {
+    let setup = get_setup_data_with_visual_class(42);
+    assert_eq!(
+        Setup::try_parse(&setup).unwrap_err(),
+        ParseError::InvalidValue
+    );
+}
+
+#[cfg(feature = "xinput")]
+fn get_xi_get_property_reply_format_0() -> Vec<u8> {
     let mut s = vec![
         1, // response_type
         0, // pad
@@ -148,6 +177,13 @@ fn parse_xi_get_property_reply_format_0() {
     s.extend(&0u32.to_ne_bytes()); // num_items
     s.push(0); // format
     s.extend(&[0; 11]); // pad
+    s
+}
+
+#[cfg(all(feature = "xinput", not(feature = "strict_parsing")))]
+#[test]
+fn parse_xi_get_property_reply_format_0() {
+    let s = get_xi_get_property_reply_format_0();
 
     use x11rb::protocol::xinput::{XIGetPropertyItems, XIGetPropertyReply};
     let empty: &[u8] = &[];
@@ -166,3 +202,208 @@ fn parse_xi_get_property_reply_format_0() {
         )),
     );
 }
+
+#[cfg(all(feature = "xinput", feature = "strict_parsing"))]
+#[test]
+fn parse_xi_get_property_reply_format_0() {
+    let s = get_xi_get_property_reply_format_0();
+
+    use x11rb::protocol::xinput::XIGetPropertyReply;
+    assert_eq!(
+        XIGetPropertyReply::try_parse(&s).unwrap_err(),
+        ParseError::InvalidValue
+    );
+}
+
+#[test]
+fn event_common_fields() {
+    use x11rb::protocol::xproto::{SelectionRequestEvent, SELECTION_REQUEST_EVENT};
+    use x11rb::protocol::Event;
+
+    let event = Event::SelectionRequest(SelectionRequestEvent {
+        response_type: SELECTION_REQUEST_EVENT | 0x80,
+        sequence: 7,
+        time: 1234,
+        owner: 0x10,
+        requestor: 0x20,
+        selection: 1,
+        target: 2,
+        property: 3,
+    });
+    assert_eq!(event.window(), Some(0x10));
+    assert_eq!(event.extension(), None);
+    assert_eq!(event.wire_sequence_number(), Some(7));
+    assert_eq!(event.server_time(), Some(1234));
+    assert!(event.sent_event());
+
+    let event = Event::Unknown(vec![200; 32]);
+    assert_eq!(event.window(), None);
+    assert_eq!(event.extension(), None);
+    assert_eq!(event.server_time(), None);
+}
+
+#[test]
+fn event_debug_shows_masks() {
+    use x11rb::protocol::xproto::{ButtonPressEvent, KeyButMask};
+
+    let event = ButtonPressEvent {
+        response_type: 4,
+        detail: 1,
+        sequence: 0,
+        time: 0,
+        root: 0,
+        event: 0,
+        child: 0,
+        root_x: 0,
+        root_y: 0,
+        event_x: 0,
+        event_y: 0,
+        state: u16::from(KeyButMask::SHIFT | KeyButMask::BUTTON1),
+        same_screen: true,
+    };
+    let debug = format!("{:?}", event);
+    assert!(debug.starts_with("ButtonPressEvent { response_type: 4, detail: 1,"));
+    assert!(debug.contains("state: SHIFT | BUTTON1,"));
+}
+
+#[test]
+fn x11_error_display() {
+    use x11rb::extension_manager::ExtensionManager;
+    use x11rb::x11_utils::X11Error;
+
+    // A Drawable error caused by a GetGeometry request
+    let mut data = vec![0, 9, 42, 0];
+    data.extend(0x1234u32.to_ne_bytes()); // bad value
+    data.extend(0u16.to_ne_bytes()); // minor opcode
+    data.push(14); // major opcode
+    data.extend([0; 21]);
+
+    let error = X11Error::try_parse(&data, &ExtensionManager::default()).unwrap();
+    assert_eq!(error.request_name, Some("xproto::GetGeometry"));
+    assert_eq!(
+        error.to_string(),
+        "Drawable error (bad value 0x1234) in request xproto::GetGeometry \
+         (major opcode 14, minor opcode 0, sequence number 42)"
+    );
+
+    // Extension requests are unknown without the extension information
+    data[10] = 140;
+    let error = X11Error::try_parse(&data, &ExtensionManager::default()).unwrap();
+    assert_eq!(error.request_name, None);
+}
+
+#[cfg(feature = "xfixes")]
+#[test]
+fn extension_event_common_fields() {
+    use x11rb::protocol::xfixes::{SelectionEvent, SelectionNotifyEvent};
+    use x11rb::protocol::Event;
+
+    let event = Event::XfixesSelectionNotify(SelectionNotifyEvent {
+        response_type: 87,
+        subtype: SelectionEvent::SET_SELECTION_OWNER,
+        sequence: 0,
+        window: 0x30,
+        owner: 0x40,
+        selection: 1,
+        timestamp: 10,
+        selection_timestamp: 5,
+    });
+    assert_eq!(event.window(), Some(0x30));
+    assert_eq!(event.extension(), Some("XFIXES"));
+    assert_eq!(event.server_time(), Some(10));
+    assert!(!event.sent_event());
+}
+
+#[cfg(feature = "xinput")]
+#[test]
+fn parse_xinput_generic_event() {
+    use x11rb::protocol::xinput::{TouchOwnershipFlags, TOUCH_OWNERSHIP_EVENT};
+    use x11rb::protocol::xproto::GE_GENERIC_EVENT;
+    use x11rb::protocol::Event;
+    use x11rb::x11_utils::{ExtInfoProvider, ExtensionInformation};
+
+    struct XInput;
+
+    impl ExtInfoProvider for XInput {
+        fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+            if major_opcode == 131 {
+                self.get_from_event_code(0)
+            } else {
+                None
+            }
+        }
+
+        fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+            let info = ExtensionInformation {
+                major_opcode: 131,
+                first_event: 66,
+                first_error: 129,
+            };
+            Some(("XInputExtension", info))
+        }
+
+        fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+    }
+
+    let mut data = vec![GE_GENERIC_EVENT, 131, 5, 0, 4, 0, 0, 0];
+    data.extend(TOUCH_OWNERSHIP_EVENT.to_ne_bytes());
+    data.extend(2u16.to_ne_bytes()); // deviceid
+    data.extend(1000u32.to_ne_bytes()); // time
+    data.extend(7u32.to_ne_bytes()); // touchid
+    data.extend(0x100u32.to_ne_bytes()); // root
+    data.extend(0x200u32.to_ne_bytes()); // event
+    data.extend(0u32.to_ne_bytes()); // child
+    data.extend(11u16.to_ne_bytes()); // sourceid
+    data.extend([0; 2]);
+    data.extend(0u32.to_ne_bytes()); // flags
+    data.extend([0; 8]);
+    assert_eq!(data.len(), 48);
+
+    let event = match Event::parse(&data, &XInput).unwrap() {
+        Event::XinputTouchOwnership(event) => event,
+        event => panic!("Unexpected event {:?}", event),
+    };
+    assert_eq!(event.sequence, 5);
+    assert_eq!((event.deviceid, event.sourceid, event.touchid), (2, 11, 7));
+    assert_eq!((event.root, event.event), (0x100, 0x200));
+    assert_eq!(event.flags, TouchOwnershipFlags::from(0u32));
+}
+
+#[test]
+fn parse_huge_lists() {
+    use x11rb::protocol::xproto::{GetMotionEventsReply, GetPropertyReply};
+    use x11rb::x11_utils::with_max_list_length;
+
+    // A GetMotionEvents reply claiming u32::MAX events, but without any data for them
+    let mut motion_events = vec![1, 0, 0, 0, 0, 0, 0, 0];
+    motion_events.extend(&u32::MAX.to_ne_bytes());
+    motion_events.extend(&[0; 20]);
+    // A GetProperty reply with format 8 and 2^31 bytes of value
+    let mut property = vec![1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    property.extend(&(1u32 << 31).to_ne_bytes());
+    property.extend(&[0; 12]);
+
+    // This must fail without trying to allocate memory for all the events
+    assert_eq!(
+        GetMotionEventsReply::try_parse(&motion_events).unwrap_err(),
+        ParseError::InsufficientData
+    );
+
+    with_max_list_length(1 << 16, || {
+        assert_eq!(
+            GetMotionEventsReply::try_parse(&motion_events).unwrap_err(),
+            ParseError::TooLarge
+        );
+        assert_eq!(
+            GetPropertyReply::try_parse(&property).unwrap_err(),
+            ParseError::TooLarge
+        );
+    });
+    // The limit no longer applies
+    assert_eq!(
+        GetMotionEventsReply::try_parse(&motion_events).unwrap_err(),
+        ParseError::InsufficientData
+    );
+}
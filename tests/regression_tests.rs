@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::io::IoSlice;
 use std::ops::Deref;
@@ -7,11 +8,14 @@ use x11rb::connection::{
     SequenceNumber,
 };
 use x11rb::cookie::{Cookie, CookieWithFds, VoidCookie};
-use x11rb::errors::{ConnectionError, ParseError, ReplyError};
+use x11rb::errors::{BufferTooSmall, ConnectionError, ParseError, ReplyError};
 use x11rb::protocol::xproto::{
-    ClientMessageData, ConnectionExt, KeymapNotifyEvent, Segment, SetupAuthenticate,
+    ChangeWindowAttributesAux, ChangeWindowAttributesRequest, ClientMessageData, ConnectionExt,
+    EventMask, GetInputFocusRequest, ImageFormat, InternAtomRequest, KeymapNotifyEvent,
+    PixmapWrapper, PolyText16Request, Segment, SetFontPathRequest, SetupAuthenticate, Str,
 };
-use x11rb::utils::RawFdContainer;
+use x11rb::utils::OwnedFd;
+use x11rb::wrapper::ConnectionExt as _;
 use x11rb::x11_utils::{ExtensionInformation, Serialize, TryParse, TryParseFd};
 
 #[derive(Debug)]
@@ -47,7 +51,7 @@ impl FakeConnection {
     fn internal_send_request(
         &self,
         bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<SequenceNumber, ConnectionError> {
         assert_eq!(fds.len(), 0);
 
@@ -65,7 +69,7 @@ impl RequestConnection for FakeConnection {
     fn send_request_with_reply<R>(
         &self,
         bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<Cookie<Self, R>, ConnectionError>
     where
         R: TryParse,
@@ -76,7 +80,7 @@ impl RequestConnection for FakeConnection {
     fn send_request_with_reply_with_fds<R>(
         &self,
         _bufs: &[IoSlice],
-        _fds: Vec<RawFdContainer>,
+        _fds: Vec<OwnedFd>,
     ) -> Result<CookieWithFds<Self, R>, ConnectionError>
     where
         R: TryParseFd,
@@ -87,7 +91,7 @@ impl RequestConnection for FakeConnection {
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice],
-        fds: Vec<RawFdContainer>,
+        fds: Vec<OwnedFd>,
     ) -> Result<VoidCookie<Self>, ConnectionError> {
         Ok(VoidCookie::new(
             self,
@@ -120,6 +124,13 @@ impl RequestConnection for FakeConnection {
         unimplemented!()
     }
 
+    fn poll_for_reply_or_raw_error(
+        &self,
+        _sequence: SequenceNumber,
+    ) -> Result<Option<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        unimplemented!()
+    }
+
     fn wait_for_reply(
         &self,
         _sequence: SequenceNumber,
@@ -246,6 +257,123 @@ fn test_too_large_request() {
     };
 }
 
+#[test]
+fn test_wire_length() -> Result<(), ConnectionError> {
+    let conn = FakeConnection::default();
+    let last_length = || conn.0.borrow().last().unwrap().data.len();
+
+    let request = GetInputFocusRequest;
+    assert_eq!(request.wire_length(), 4);
+    let _ = request.send(&conn)?;
+    assert_eq!(last_length(), 4);
+
+    let request = InternAtomRequest {
+        only_if_exists: false,
+        name: Cow::Borrowed(b"WM_NAME"),
+    };
+    assert_eq!(request.wire_length(), 16);
+    let _ = request.send(&conn)?;
+    assert_eq!(last_length(), 16);
+
+    let aux = ChangeWindowAttributesAux::new()
+        .background_pixel(1)
+        .event_mask(EventMask::EXPOSURE);
+    let request = ChangeWindowAttributesRequest {
+        window: 42,
+        value_list: Cow::Owned(aux),
+    };
+    assert_eq!(request.wire_length(), 20);
+    let _ = request.send(&conn)?;
+    assert_eq!(last_length(), 20);
+
+    let font = vec![
+        Str {
+            name: b"a".to_vec(),
+        },
+        Str {
+            name: b"bcd".to_vec(),
+        },
+    ];
+    let request = SetFontPathRequest {
+        font: Cow::Owned(font),
+    };
+    assert_eq!(request.wire_length(), 16);
+    let _ = request.send(&conn)?;
+    assert_eq!(last_length(), 16);
+
+    // BIG-REQUESTS adds four bytes that are not part of the wire length
+    let big_buffer = [0; (1 << 18) + 1];
+    let request = PolyText16Request {
+        drawable: 42,
+        gc: 0x1337,
+        x: 21,
+        y: 7,
+        items: Cow::Borrowed(&big_buffer),
+    };
+    assert_eq!(request.wire_length(), 16 + big_buffer.len() + 3);
+    assert!(request.wire_length() <= conn.maximum_request_bytes());
+    let _ = request.send(&conn)?;
+    assert_eq!(last_length(), 16 + big_buffer.len() + 3 + 4);
+    Ok(())
+}
+
+#[test]
+fn test_serialize_into() -> Result<(), ConnectionError> {
+    let conn = FakeConnection::default();
+    let last_request = || conn.0.borrow().last().unwrap().data.clone();
+
+    // Appending to a buffer keeps what is already there
+    let request = InternAtomRequest {
+        only_if_exists: true,
+        name: Cow::Borrowed(b"WM_NAME"),
+    };
+    let mut bytes = vec![1, 2, 3];
+    request.clone().serialize_into(&mut bytes);
+    let _ = request.clone().send(&conn)?;
+    assert_eq!(bytes[..3], [1, 2, 3]);
+    assert_eq!(bytes[3..], last_request()[..]);
+
+    let mut buffer = [0xff; 20];
+    assert_eq!(request.clone().serialize_into_slice(&mut buffer), Ok(16));
+    assert_eq!(buffer[..16], last_request()[..]);
+    assert_eq!(buffer[16..], [0xff; 4]);
+    assert_eq!(
+        request.serialize_into_slice(&mut buffer[..15]),
+        Err(BufferTooSmall { required: 16 })
+    );
+
+    // Long requests use BIG-REQUESTS
+    let big_buffer = vec![7; (1 << 18) + 1];
+    let request = PolyText16Request {
+        drawable: 42,
+        gc: 0x1337,
+        x: 21,
+        y: 7,
+        items: Cow::Borrowed(&big_buffer),
+    };
+    let _ = request.clone().send(&conn)?;
+    let expected = last_request();
+    assert_eq!(expected[2..4], [0, 0]);
+
+    let mut bytes = Vec::new();
+    request.clone().serialize_into(&mut bytes);
+    assert_eq!(bytes, expected);
+
+    let mut buffer = vec![0; expected.len()];
+    assert_eq!(
+        request.clone().serialize_into_slice(&mut buffer[1..]),
+        Err(BufferTooSmall {
+            required: expected.len()
+        })
+    );
+    assert_eq!(
+        request.serialize_into_slice(&mut buffer),
+        Ok(expected.len())
+    );
+    assert_eq!(buffer, expected);
+    Ok(())
+}
+
 #[test]
 fn test_send_event() -> Result<(), ConnectionError> {
     // Prepare the event
@@ -339,3 +467,67 @@ fn compile_test(conn: &impl RequestConnection) {
     use x11rb::protocol::xinput::{xi_query_device, Device};
     let _ = xi_query_device(conn, Device::ALL);
 }
+
+#[test]
+fn test_wrapper_frees_resource() {
+    let conn = FakeConnection::default();
+    let pixmap: u32 = 42;
+    {
+        let wrapper = PixmapWrapper::for_pixmap(&conn, pixmap);
+        assert_eq!(wrapper.pixmap(), pixmap);
+    }
+    // No request is sent for resources that are taken out of the wrapper
+    assert_eq!(PixmapWrapper::for_pixmap(&conn, 7).into_pixmap(), 7);
+
+    let mut expected = vec![x11rb::protocol::xproto::FREE_PIXMAP_REQUEST, 0];
+    expected.extend(&2u16.to_ne_bytes());
+    expected.extend(&pixmap.to_ne_bytes());
+    conn.check_requests(&[(false, expected)]);
+}
+
+#[test]
+fn test_put_image_chunked() -> Result<(), ConnectionError> {
+    let conn = FakeConnection::default();
+    let (width, height, depth, stride) = (4000u16, 200u16, 2u8, 2048);
+    // Two planes of 200 scanlines each; every scanline is filled with its plane and line number
+    let data = (0..2u8)
+        .flat_map(|plane| (0..height).flat_map(move |line| vec![plane ^ line as u8; stride]))
+        .collect::<Vec<_>>();
+    let cookies = conn.put_image_chunked(
+        ImageFormat::XY_PIXMAP,
+        42,
+        0x1337,
+        width,
+        height,
+        5,
+        10,
+        0,
+        depth,
+        &data,
+    )?;
+    assert_eq!(cookies.len(), 2);
+
+    // Both planes of 127 scanlines fit into the 2^19 bytes that FakeConnection allows
+    let requests = conn.0.borrow();
+    let mut expected_lines = [(0u16, 127u16), (127, 73)].iter();
+    for request in requests.iter() {
+        let &(first_line, lines) = expected_lines.next().unwrap();
+        // Skip the BIG-REQUESTS length field
+        let body = &request.data[8..];
+        assert_eq!(request.data[0], x11rb::protocol::xproto::PUT_IMAGE_REQUEST);
+        assert_eq!(request.data[1], u8::from(ImageFormat::XY_PIXMAP));
+        assert_eq!(&body[8..10], &width.to_ne_bytes());
+        assert_eq!(&body[10..12], &lines.to_ne_bytes());
+        assert_eq!(&body[14..16], &(10 + first_line as i16).to_ne_bytes());
+        assert_eq!(body[17], depth);
+        let expected_data = (0..2u8)
+            .flat_map(|plane| {
+                (first_line..first_line + lines)
+                    .flat_map(move |line| vec![plane ^ line as u8; stride])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(&body[20..], &expected_data[..]);
+    }
+    assert_eq!(requests.len(), 2);
+    Ok(())
+}
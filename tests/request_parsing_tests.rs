@@ -275,3 +275,25 @@ fn test_randr_get_output_property() {
         },
     );
 }
+
+#[cfg(feature = "sync")]
+#[test]
+fn test_sync_await_fence() {
+    use x11rb::protocol::sync::{AwaitFenceRequest, AWAIT_FENCE_REQUEST};
+    let header = RequestHeader {
+        major_opcode: 134,
+        minor_opcode: AWAIT_FENCE_REQUEST,
+        remaining_length: 3,
+    };
+    let mut body = vec![];
+    add_ne!(body, 0x0000_0001u32);
+    add_ne!(body, 0x0000_0002u32);
+    add_ne!(body, 0x0000_0003u32);
+    let r = AwaitFenceRequest::try_parse_request(header, &body).unwrap();
+    assert_eq!(
+        r,
+        AwaitFenceRequest {
+            fence_list: Cow::Owned(vec![1, 2, 3]),
+        },
+    );
+}
@@ -13,7 +13,7 @@ mod test {
     use x11rb::protocol::xproto::{Screen, Setup};
     use x11rb::protocol::Event;
     use x11rb::resource_manager::Database;
-    use x11rb::utils::RawFdContainer;
+    use x11rb::utils::OwnedFd;
     use x11rb::x11_utils::{ExtensionInformation, Serialize, TryParse, TryParseFd, X11Error};
 
     // Most tests in here are based on [1], which is: Copyright © 2016 Ingo Bürk
@@ -145,7 +145,7 @@ mod test {
         fn send_request_with_reply<R>(
             &self,
             _: &[IoSlice<'_>],
-            _: Vec<RawFdContainer>,
+            _: Vec<OwnedFd>,
         ) -> Result<Cookie<'_, Self, R>, ConnectionError>
         where
             R: TryParse,
@@ -156,7 +156,7 @@ mod test {
         fn send_request_with_reply_with_fds<R>(
             &self,
             _: &[IoSlice<'_>],
-            _: Vec<RawFdContainer>,
+            _: Vec<OwnedFd>,
         ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
         where
             R: TryParseFd,
@@ -167,7 +167,7 @@ mod test {
         fn send_request_without_reply(
             &self,
             _: &[IoSlice<'_>],
-            _: Vec<RawFdContainer>,
+            _: Vec<OwnedFd>,
         ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
             unimplemented!()
         }
@@ -215,6 +215,13 @@ mod test {
             Ok(ReplyOrError::Reply(reply))
         }
 
+        fn poll_for_reply_or_raw_error(
+            &self,
+            _sequence: SequenceNumber,
+        ) -> Result<Option<ReplyOrError<Self::Buf>>, ConnectionError> {
+            unimplemented!()
+        }
+
         fn wait_for_reply(&self, _: SequenceNumber) -> Result<Option<Self::Buf>, ConnectionError> {
             unimplemented!()
         }